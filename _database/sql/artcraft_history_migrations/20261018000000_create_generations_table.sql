@@ -0,0 +1,117 @@
+-- noinspection SqlDialectInspectionForFile
+--
+-- NB: Unlike the tasks database, the history database is *persistent*. It is never deleted
+--  or versioned by filename, so migrations here must only ever be appended. Never edit a
+--  migration (including its comments) once it has shipped.
+
+CREATE TABLE generations (
+    -- GenerationHistoryId token.
+    id TEXT NOT NULL PRIMARY KEY,
+
+    -- OPTIONAL.
+    -- The task (in the ephemeral tasks database) that tracked this generation.
+    -- The task may no longer exist.
+    task_id TEXT,
+
+    -- TaskType enum
+    -- e.g. image_generation, video_generation, etc.
+    generation_type TEXT NOT NULL,
+
+    -- TaskStatus enum
+    -- We only use pending, complete_success, complete_failure, and cancelled_by_user.
+    status TEXT NOT NULL,
+
+    -- GenerationProvider enum
+    provider TEXT NOT NULL,
+
+    -- OPTIONAL. TaskModelType enum
+    model_type TEXT,
+
+    -- OPTIONAL. The model name as the frontend sent it (eg. 'veo_3p1').
+    -- Kept verbatim since not every frontend model has a TaskModelType.
+    frontend_model TEXT,
+
+    -- OPTIONAL.
+    prompt TEXT,
+
+    -- OPTIONAL.
+    negative_prompt TEXT,
+
+    -- OPTIONAL. CommonAspectRatio (serialized)
+    aspect_ratio TEXT,
+
+    -- OPTIONAL. CommonResolution (serialized)
+    resolution TEXT,
+
+    -- OPTIONAL.
+    seed INTEGER,
+
+    -- OPTIONAL. Number of outputs requested.
+    batch_size INTEGER,
+
+    -- OPTIONAL. For video.
+    duration_seconds INTEGER,
+
+    -- JSON array of media file tokens used as references (images, frames, video, audio).
+    reference_media_tokens TEXT NOT NULL DEFAULT '[]',
+
+    -- The original Tauri command request (JSON), so the generation can be re-run.
+    -- Raw byte fields are stripped before saving.
+    request_json TEXT NOT NULL,
+
+    -- OPTIONAL. Cost in credits, as estimated when the generation was enqueued.
+    cost_in_credits INTEGER,
+
+    -- OPTIONAL. Set when the generation completes.
+    output_media_file_token TEXT,
+
+    -- OPTIONAL. Set when the generation completes (for batches).
+    output_batch_token TEXT,
+
+    -- OPTIONAL. Set when the generation completes.
+    output_cdn_url TEXT,
+
+    -- OPTIONAL. Set when the generation completes.
+    output_thumbnail_url_template TEXT,
+
+    -- JSON array of local file paths the output was saved to.
+    output_file_paths TEXT NOT NULL DEFAULT '[]',
+
+    -- OPTIONAL. Human-readable failure reason.
+    failure_message TEXT,
+
+    created_at INTEGER NOT NULL DEFAULT (unixepoch('now')),
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch('now')),
+    completed_at INTEGER DEFAULT NULL
+);
+
+-- Indices
+CREATE INDEX idx_generations_on_task_id ON generations(task_id);
+CREATE INDEX idx_generations_on_created_at ON generations(created_at);
+CREATE INDEX idx_generations_on_output_media_file_token ON generations(output_media_file_token);
+
+-- Full text search over prompts. We keep our own copy of the text (rather than an external
+-- content table) since the generations table isn't keyed by an integer rowid.
+CREATE VIRTUAL TABLE generations_fts USING fts5(
+    id UNINDEXED,
+    prompt,
+    negative_prompt,
+    frontend_model,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER generations_after_insert AFTER INSERT ON generations BEGIN
+    INSERT INTO generations_fts (id, prompt, negative_prompt, frontend_model)
+    VALUES (new.id, new.prompt, new.negative_prompt, new.frontend_model);
+END;
+
+CREATE TRIGGER generations_after_delete AFTER DELETE ON generations BEGIN
+    DELETE FROM generations_fts WHERE id = old.id;
+END;
+
+CREATE TRIGGER generations_after_update_of_text
+AFTER UPDATE OF prompt, negative_prompt, frontend_model ON generations BEGIN
+    UPDATE generations_fts
+    SET prompt = new.prompt, negative_prompt = new.negative_prompt, frontend_model = new.frontend_model
+    WHERE id = new.id;
+END;
@@ -23,6 +23,9 @@
 --      prompt_token
 --      queue_status_url
 --      queue_response_url
+--   tasks_v8.sqlite - added two fields (for batch generation):
+--      batch_id
+--      batch_index
 
 CREATE TABLE tasks (
     -- Task auto-incrementing primary key.
@@ -69,6 +72,16 @@ CREATE TABLE tasks (
     -- will be re-emitted back to the frontend.
     frontend_subscriber_payload TEXT,
 
+    -- OPTIONAL.
+    -- Set when the task was enqueued as part of a batch generation (TaskBatchId).
+    -- The frontend groups batch results into a comparison grid.
+    batch_id TEXT,
+
+    -- OPTIONAL.
+    -- The task's cell in the batch's expanded matrix (zero-indexed).
+    -- Tasks are enqueued concurrently, so this can differ from insertion order.
+    batch_index INTEGER,
+
     -- Whether the user has dismissed the task from view.
     is_dismissed_by_user INTEGER NOT NULL DEFAULT 0,
 
@@ -0,0 +1,50 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- Ledger of inbound third party webhooks (FAL, Beeble).
+-- Repeated deliveries of the same payload are acknowledged but not reprocessed,
+-- and the raw body is kept so staff can re-drive a delivery we failed to process.
+CREATE TABLE webhook_deliveries (
+  id BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+
+  -- Unique token identifier for this delivery.
+  token VARCHAR(32) NOT NULL,
+
+  -- Which third party sent the webhook (see `WebhookDeliveryProvider`).
+  provider VARCHAR(16) NOT NULL,
+
+  -- The provider's identifier for the job the webhook is about.
+  provider_request_id VARCHAR(128) NOT NULL,
+
+  -- Lowercase hex SHA-256 of the raw body.
+  -- Distinguishes a retried delivery from a genuinely different payload for the same job.
+  payload_sha256 VARCHAR(64) NOT NULL,
+
+  -- The raw body exactly as received.
+  raw_body MEDIUMTEXT NOT NULL,
+
+  -- Processing status (see `WebhookDeliveryStatus`).
+  status VARCHAR(16) NOT NULL,
+
+  -- How many times the provider sent this exact payload.
+  delivery_count INT(10) UNSIGNED NOT NULL DEFAULT 1,
+
+  -- The error from the most recent failed processing attempt.
+  maybe_last_error TEXT DEFAULT NULL,
+
+  -- Manual re-drives by staff.
+  redrive_count INT(10) UNSIGNED NOT NULL DEFAULT 0,
+  maybe_last_redrive_staff_user_token VARCHAR(32) DEFAULT NULL,
+
+  created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+  -- INDICES --
+  PRIMARY KEY (id),
+  UNIQUE KEY unique_token (token),
+  UNIQUE KEY unique_delivery (provider, provider_request_id, payload_sha256),
+  KEY index_status (status),
+  KEY index_created_at (created_at)
+
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_bin;
@@ -0,0 +1,34 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- TOTP second factor for user accounts. At most one record per user.
+-- Enrollment writes a record that isn't enabled until the user proves their
+-- authenticator app works; disabling the second factor deletes the record.
+CREATE TABLE user_two_factor_auth (
+  id BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+
+  -- The user this second factor belongs to.
+  user_token VARCHAR(32) NOT NULL,
+
+  -- Base32 encoded TOTP shared secret.
+  totp_secret VARCHAR(64) NOT NULL,
+
+  -- Set once the user confirms enrollment with a valid code.
+  -- Login only requires the second factor when this is set.
+  is_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+
+  -- The TOTP time step of the last accepted code.
+  -- Codes from this step or earlier are rejected to prevent replay.
+  maybe_last_used_time_step BIGINT(20) UNSIGNED DEFAULT NULL,
+
+  maybe_enabled_at DATETIME DEFAULT NULL,
+
+  created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+  -- INDICES --
+  PRIMARY KEY (id),
+  UNIQUE KEY unique_user_token (user_token)
+
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_bin;
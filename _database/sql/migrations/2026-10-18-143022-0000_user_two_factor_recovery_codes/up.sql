@@ -0,0 +1,25 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- Single-use recovery codes for users with two factor auth enabled.
+-- The codes themselves are only shown once; we store bcrypt hashes like passwords.
+CREATE TABLE user_two_factor_recovery_codes (
+  id BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+
+  -- The user these codes belong to.
+  user_token VARCHAR(32) NOT NULL,
+
+  -- bcrypt hash of the normalized code.
+  code_hash VARCHAR(60) NOT NULL,
+
+  -- Set when the code is redeemed. Used codes are kept until the set is regenerated.
+  maybe_used_at DATETIME DEFAULT NULL,
+
+  created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+  -- INDICES --
+  PRIMARY KEY (id),
+  KEY index_user_token (user_token)
+
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_bin;
@@ -0,0 +1,60 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- Personal access tokens let users call the API from scripts with
+-- `Authorization: Bearer <secret>` instead of a browser session cookie.
+-- This supersedes the legacy (plaintext) `api_tokens` table.
+CREATE TABLE user_personal_access_tokens (
+  id BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+
+  -- Public identifier for the token (eg. for listing and revoking it).
+  token VARCHAR(32) NOT NULL,
+
+  -- The user the token acts as.
+  user_token VARCHAR(32) NOT NULL,
+
+  -- User-supplied label, eg. "render farm".
+  name VARCHAR(64) NOT NULL,
+
+  -- The first few characters of the secret, so users can tell tokens apart.
+  secret_prefix VARCHAR(16) NOT NULL,
+
+  -- Hex encoded SHA-256 of the secret. The secret itself is only shown once.
+  -- (Secrets are long and random, so a fast hash is enough and lets us look them up.)
+  secret_hash CHAR(64) NOT NULL,
+
+  -- Comma separated set of `PersonalAccessTokenScope` values.
+  scopes VARCHAR(255) NOT NULL,
+
+  -- If set, wallet spend through this token may not exceed this many credits in total.
+  maybe_spend_limit_credits BIGINT(20) UNSIGNED DEFAULT NULL,
+
+  -- Wallet credits spent through this token.
+  credits_spent BIGINT(20) UNSIGNED NOT NULL DEFAULT 0,
+
+  -- Usage stats.
+  use_count BIGINT(20) UNSIGNED NOT NULL DEFAULT 0,
+  maybe_last_used_at DATETIME DEFAULT NULL,
+  maybe_last_used_ip_address VARCHAR(40) DEFAULT NULL,
+
+  -- If set, the token stops working at this time.
+  maybe_expires_at DATETIME DEFAULT NULL,
+
+  -- For abuse tracking.
+  -- Wide enough for IPv4/6
+  ip_address_creation VARCHAR(40) NOT NULL,
+
+  created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+  -- Revoked tokens stop working immediately, but are kept for their stats.
+  maybe_revoked_at DATETIME DEFAULT NULL,
+
+  -- INDICES --
+  PRIMARY KEY (id),
+  UNIQUE KEY (token),
+  UNIQUE KEY (secret_hash),
+  KEY fk_user_token (user_token)
+
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_bin;
@@ -0,0 +1,40 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- Moderator overrides for the rate limit policy engine.
+-- An override replaces the plan's rule for one user and one endpoint group.
+CREATE TABLE user_rate_limit_overrides (
+  id BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+
+  -- The user the override applies to. This covers their personal access tokens too.
+  user_token VARCHAR(32) NOT NULL,
+
+  -- A `RateLimitEndpointGroup` value, eg. "generation".
+  endpoint_group VARCHAR(16) NOT NULL,
+
+  -- If true, the user isn't rate limited for this group at all
+  -- and the request / window columns are ignored.
+  is_exempt BOOLEAN NOT NULL DEFAULT FALSE,
+
+  -- The replacement rule.
+  max_requests INT(10) UNSIGNED NOT NULL,
+  window_seconds INT(10) UNSIGNED NOT NULL,
+
+  -- Why the override exists, eg. "partner integration, see ticket".
+  maybe_note VARCHAR(255) DEFAULT NULL,
+
+  -- The moderator that last set the override.
+  creator_user_token VARCHAR(32) NOT NULL,
+
+  -- If set, the plan's rule applies again after this time.
+  maybe_expires_at DATETIME DEFAULT NULL,
+
+  created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+  -- INDICES --
+  PRIMARY KEY (id),
+  UNIQUE KEY (user_token, endpoint_group)
+
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_bin;
@@ -0,0 +1,8 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- When processing of the delivery was last claimed. Unlike `updated_at`, repeated
+-- deliveries don't bump this, so a stuck `processing` row can be reclaimed once it's stale.
+ALTER TABLE webhook_deliveries
+  ADD COLUMN claimed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP AFTER redrive_count;
@@ -0,0 +1,7 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- The third party's queue status URL, for providers (FAL) that cancel jobs by URL.
+ALTER TABLE generic_inference_jobs
+ADD COLUMN maybe_external_third_party_queue_url VARCHAR(255) DEFAULT NULL;
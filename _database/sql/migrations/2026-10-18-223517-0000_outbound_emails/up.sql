@@ -0,0 +1,62 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- Transactional email waiting to be sent (or already sent).
+-- The web servers insert fully rendered emails here; the outbound email worker polls
+-- for due rows, claims them, and retries transient failures with backoff.
+CREATE TABLE outbound_emails (
+  -- Not used for anything except replication.
+  id BIGINT(20) NOT NULL AUTO_INCREMENT,
+
+  -- Stays the same across retries. Also sent to providers that support it (Resend),
+  -- so a retry after an ambiguous failure can't deliver twice.
+  idempotency_key VARCHAR(128) NOT NULL,
+
+  -- Eg. "password_reset". For logs.
+  email_category VARCHAR(32) NOT NULL,
+
+  -- ========== RENDERED EMAIL ==========
+
+  from_address VARCHAR(255) NOT NULL,
+
+  -- JSON array of recipient addresses.
+  to_addresses_json TEXT NOT NULL,
+
+  maybe_reply_to_address VARCHAR(255) DEFAULT NULL,
+
+  subject VARCHAR(512) NOT NULL,
+
+  html_body MEDIUMTEXT NOT NULL,
+  text_body MEDIUMTEXT NOT NULL,
+
+  -- ========== DELIVERY STATE ==========
+
+  -- See `OutboundEmailStatus`.
+  status VARCHAR(16) NOT NULL DEFAULT 'pending',
+
+  -- Incremented when a worker claims the email, so crashed sends count too.
+  attempt_count SMALLINT UNSIGNED NOT NULL DEFAULT 0,
+
+  -- When the email is next eligible to be claimed. For `sending` rows, this is when the
+  -- claim expires (eg. the worker died mid-send).
+  next_attempt_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+  -- The error from the most recent failed attempt.
+  maybe_last_error VARCHAR(512) DEFAULT NULL,
+
+  -- Set once the provider accepts the email.
+  maybe_provider_message_id VARCHAR(255) DEFAULT NULL,
+  sent_at DATETIME DEFAULT NULL,
+
+  created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+
+  -- INDICES --
+  PRIMARY KEY (id),
+  UNIQUE KEY unique_idempotency_key (idempotency_key),
+  KEY index_status_next_attempt_at (status, next_attempt_at),
+  KEY index_email_category (email_category),
+  KEY index_created_at (created_at)
+
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_bin;
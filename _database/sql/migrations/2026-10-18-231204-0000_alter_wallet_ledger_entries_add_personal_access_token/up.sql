@@ -0,0 +1,9 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- The personal access token a deduction was made with (if any), so that
+-- refunding the entry can also release the spend counted against the token.
+ALTER TABLE wallet_ledger_entries
+ADD COLUMN
+maybe_personal_access_token VARCHAR(32) DEFAULT NULL;
@@ -0,0 +1,11 @@
+-- noinspection SqlDialectInspectionForFile
+-- noinspection SqlNoDataSourceInspectionForFile
+-- noinspection SqlResolveForFile
+
+-- The "hh_rl" (Happy Horse with a rate limit) feature flag is retired. Happy Horse
+-- generations are limited by the rate limit policy engine for everyone, so holders
+-- of the old flag get the plain "hh" flag. (Duplicates are collapsed on next write.)
+UPDATE users
+SET maybe_feature_flags = TRIM(BOTH ',' FROM
+  REPLACE(CONCAT(',', maybe_feature_flags, ','), ',hh_rl,', ',hh,'))
+WHERE FIND_IN_SET('hh_rl', maybe_feature_flags) > 0;
@@ -0,0 +1,37 @@
+use serde_derive::{Deserialize, Serialize};
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+use utoipa::ToSchema;
+
+pub const CANCEL_INFERENCE_JOB_PATH: &str = "/v1/jobs/job/{token}/cancel";
+
+#[derive(Deserialize, ToSchema)]
+pub struct CancelInferenceJobPathInfo {
+  pub token: InferenceJobToken,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CancelInferenceJobResponse {
+  pub success: bool,
+
+  pub outcome: CancelInferenceJobOutcome,
+
+  /// Whether credits spent on the job were returned to the user's wallet.
+  pub refunded: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CancelInferenceJobOutcome {
+  /// The job was stopped before it ran (or the provider confirmed it won't run).
+  Cancelled,
+
+  /// The provider is stopping the job, but hasn't confirmed it. The job ends as
+  /// cancelled or failed shortly, and its credits are refunded then.
+  CancellationRequested,
+
+  /// The job had already finished; nothing was changed.
+  AlreadyFinished,
+
+  /// The job is already running and can't be stopped. It will finish normally.
+  NotCancellable,
+}
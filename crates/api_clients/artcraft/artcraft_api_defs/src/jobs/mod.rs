@@ -1 +1,2 @@
+pub mod cancel_inference_job;
 pub mod list_session_jobs;
\ No newline at end of file
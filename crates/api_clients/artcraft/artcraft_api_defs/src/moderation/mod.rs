@@ -1,6 +1,9 @@
 pub mod alerts;
 pub mod jobs;
+pub mod provider_health;
 pub mod user;
+pub mod user_rate_limit_overrides;
 pub mod user_referrals;
 pub mod wallet_ledger_entries;
 pub mod wallets;
+pub mod webhook_deliveries;
@@ -0,0 +1,40 @@
+use serde_derive::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub const MODERATOR_GET_PROVIDER_HEALTH_PATH: &str = "/v1/moderation/provider_health";
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ModeratorGetProviderHealthResponse {
+  pub success: bool,
+
+  /// Providers that have received traffic since the server started.
+  pub providers: Vec<ModeratorProviderHealthDetails>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ModeratorProviderHealthDetails {
+  /// eg. "fal", "gmi_cloud", "seedance2_pro"
+  pub provider: String,
+
+  /// One of "closed" (healthy), "open" (refusing calls), or "half_open" (probing).
+  pub circuit_state: String,
+
+  /// Calls and failures in the rolling window.
+  pub window_call_count: u64,
+  pub window_failure_count: u64,
+  pub window_error_rate: f64,
+
+  pub consecutive_failures: u32,
+
+  pub latency_p50_millis: Option<u64>,
+  pub latency_p90_millis: Option<u64>,
+  pub latency_p99_millis: Option<u64>,
+
+  /// Lifetime counters for this server instance.
+  pub total_successes: u64,
+  pub total_failures: u64,
+  pub total_refused: u64,
+
+  /// If the circuit is open, how long until a probe request is allowed.
+  pub maybe_seconds_until_probe: Option<u64>,
+}
@@ -1,4 +1,5 @@
 pub mod list_subscribing_users_by_signup_date;
 pub mod list_users_by_signup_date;
+pub mod moderator_reset_user_two_factor;
 pub mod user_lookup;
 pub mod user_lookup_by_stripe_customer_id;
\ No newline at end of file
@@ -0,0 +1,18 @@
+use serde_derive::{Deserialize, Serialize};
+use tokens::tokens::users::UserToken;
+use utoipa::ToSchema;
+
+pub const MODERATOR_RESET_USER_TWO_FACTOR_PATH: &str = "/v1/moderation/users/{user_token}/two_factor/reset";
+
+#[derive(Deserialize, ToSchema)]
+pub struct ModeratorResetUserTwoFactorPathInfo {
+  pub user_token: UserToken,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ModeratorResetUserTwoFactorResponse {
+  pub success: bool,
+
+  /// False if the user didn't have two factor auth (or a pending enrollment) to remove.
+  pub had_two_factor: bool,
+}
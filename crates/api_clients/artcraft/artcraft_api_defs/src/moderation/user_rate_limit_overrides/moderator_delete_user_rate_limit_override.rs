@@ -0,0 +1,24 @@
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use serde_derive::{Deserialize, Serialize};
+use tokens::tokens::users::UserToken;
+use utoipa::ToSchema;
+
+pub const MODERATOR_DELETE_USER_RATE_LIMIT_OVERRIDE_PATH: &str = "/v1/moderation/users/{user_token}/rate_limit_overrides/delete";
+
+#[derive(Deserialize, ToSchema)]
+pub struct ModeratorDeleteUserRateLimitOverridePathInfo {
+  pub user_token: UserToken,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ModeratorDeleteUserRateLimitOverrideRequest {
+  pub endpoint_group: RateLimitEndpointGroup,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ModeratorDeleteUserRateLimitOverrideResponse {
+  pub success: bool,
+
+  /// False if the user had no override for the endpoint group.
+  pub had_override: bool,
+}
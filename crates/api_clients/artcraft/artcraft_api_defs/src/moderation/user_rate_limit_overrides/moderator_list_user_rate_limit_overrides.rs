@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use serde_derive::{Deserialize, Serialize};
+use tokens::tokens::users::UserToken;
+use utoipa::ToSchema;
+
+pub const MODERATOR_LIST_USER_RATE_LIMIT_OVERRIDES_PATH: &str = "/v1/moderation/users/{user_token}/rate_limit_overrides";
+
+#[derive(Deserialize, ToSchema)]
+pub struct ModeratorListUserRateLimitOverridesPathInfo {
+  pub user_token: UserToken,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ModeratorListUserRateLimitOverridesResponse {
+  pub success: bool,
+  pub overrides: Vec<UserRateLimitOverride>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct UserRateLimitOverride {
+  pub endpoint_group: RateLimitEndpointGroup,
+
+  /// If true, the user isn't rate limited for this group at all.
+  pub is_exempt: bool,
+
+  pub max_requests: u32,
+  pub window_seconds: u32,
+
+  pub maybe_note: Option<String>,
+
+  /// The moderator that last set the override.
+  pub creator_user_token: UserToken,
+
+  /// Expired overrides are listed, but no longer apply.
+  pub maybe_expires_at: Option<DateTime<Utc>>,
+  pub is_expired: bool,
+
+  pub updated_at: DateTime<Utc>,
+}
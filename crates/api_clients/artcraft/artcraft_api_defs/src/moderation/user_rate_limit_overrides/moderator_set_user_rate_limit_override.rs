@@ -0,0 +1,35 @@
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use serde_derive::{Deserialize, Serialize};
+use tokens::tokens::users::UserToken;
+use utoipa::ToSchema;
+
+pub const MODERATOR_SET_USER_RATE_LIMIT_OVERRIDE_PATH: &str = "/v1/moderation/users/{user_token}/rate_limit_overrides/set";
+
+#[derive(Deserialize, ToSchema)]
+pub struct ModeratorSetUserRateLimitOverridePathInfo {
+  pub user_token: UserToken,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ModeratorSetUserRateLimitOverrideRequest {
+  pub endpoint_group: RateLimitEndpointGroup,
+
+  /// Skip rate limiting for this group entirely. If set, the request and window values
+  /// may be omitted.
+  pub is_exempt: Option<bool>,
+
+  /// Requests allowed per window. Replaces the limit from the user's plan.
+  pub max_requests: Option<u32>,
+  pub window_seconds: Option<u32>,
+
+  /// If set, the override lapses after this many days.
+  pub maybe_expires_in_days: Option<u32>,
+
+  /// Why the override exists (shown to other moderators).
+  pub maybe_note: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ModeratorSetUserRateLimitOverrideResponse {
+  pub success: bool,
+}
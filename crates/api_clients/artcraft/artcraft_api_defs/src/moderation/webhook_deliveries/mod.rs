@@ -0,0 +1 @@
+pub mod moderator_redrive_webhook_delivery;
@@ -0,0 +1,21 @@
+use serde_derive::{Deserialize, Serialize};
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+use utoipa::ToSchema;
+
+pub const MODERATOR_REDRIVE_WEBHOOK_DELIVERY_PATH: &str = "/v1/moderation/webhook_delivery/{webhook_delivery_token}/redrive";
+
+#[derive(Deserialize, ToSchema)]
+pub struct ModeratorRedriveWebhookDeliveryPathInfo {
+  pub webhook_delivery_token: WebhookDeliveryToken,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ModeratorRedriveWebhookDeliveryResponse {
+  pub success: bool,
+
+  /// Whether the stored delivery was processed successfully this time.
+  pub delivery_succeeded: bool,
+
+  /// The processing error, if the re-drive failed again.
+  pub maybe_error: Option<String>,
+}
@@ -0,0 +1,18 @@
+use serde_derive::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ResendEmailVerificationResponse {
+  pub success: bool,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RedeemEmailVerificationRequest {
+  /// The token from the verification email link.
+  pub token: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RedeemEmailVerificationResponse {
+  pub success: bool,
+}
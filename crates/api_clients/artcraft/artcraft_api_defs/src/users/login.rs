@@ -17,11 +17,25 @@ pub struct LoginSuccessResponse {
   pub signed_session: String,
 }
 
+#[derive(Deserialize, ToSchema)]
+pub struct LoginTwoFactorRequest {
+  /// The challenge returned with a `TwoFactorRequired` login error.
+  pub two_factor_challenge: String,
+
+  /// A current authenticator code or an unused recovery code.
+  pub code: String,
+}
+
 #[derive(Copy, Clone, Debug, Serialize, ToSchema)]
 pub enum LoginErrorType {
   /// Account was created without a password and the user needs to create one
   AccountNeedsPassword,
   /// Invalid login credentials were supplied
   InvalidCredentials,
+  /// Too many login or second factor attempts; try again later
+  RateLimited,
   ServerError,
+  /// The credentials were correct, but the account has two factor auth enabled.
+  /// Complete the login with the `two_factor_challenge` at `/v1/login/two_factor`.
+  TwoFactorRequired,
 }
@@ -1,5 +1,8 @@
 pub mod change_password;
 pub mod edit_email;
+pub mod email_verification;
 pub mod edit_username;
 pub mod login;
-pub mod session_info;
\ No newline at end of file
+pub mod personal_access_tokens;
+pub mod session_info;
+pub mod two_factor;
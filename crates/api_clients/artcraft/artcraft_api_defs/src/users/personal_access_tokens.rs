@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+use serde_derive::{Deserialize, Serialize};
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreatePersonalAccessTokenRequest {
+  /// A label to tell tokens apart, eg. "render farm".
+  pub name: String,
+
+  /// What the token may be used for. At least one is required.
+  pub scopes: Vec<PersonalAccessTokenScope>,
+
+  /// If set, the token stops working after this many days.
+  pub maybe_expires_in_days: Option<u32>,
+
+  /// If set, the most wallet credits that may ever be spent through this token.
+  pub maybe_spend_limit_credits: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreatePersonalAccessTokenResponse {
+  pub success: bool,
+
+  pub token: PersonalAccessTokenToken,
+
+  /// Send as `Authorization: Bearer <secret>`. This is only ever shown once.
+  pub secret: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ListPersonalAccessTokensResponse {
+  pub success: bool,
+
+  /// Tokens that haven't been revoked (including expired ones), newest first.
+  pub personal_access_tokens: Vec<PersonalAccessTokenDetails>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PersonalAccessTokenDetails {
+  pub token: PersonalAccessTokenToken,
+  pub name: String,
+
+  /// The first few characters of the secret.
+  pub secret_prefix: String,
+
+  pub scopes: Vec<PersonalAccessTokenScope>,
+
+  pub maybe_spend_limit_credits: Option<u64>,
+  pub credits_spent: u64,
+
+  pub use_count: u64,
+  pub maybe_last_used_at: Option<DateTime<Utc>>,
+  pub maybe_last_used_ip_address: Option<String>,
+
+  pub maybe_expires_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RevokePersonalAccessTokenPathInfo {
+  pub token: PersonalAccessTokenToken,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RevokePersonalAccessTokenResponse {
+  pub success: bool,
+}
@@ -0,0 +1,63 @@
+use serde_derive::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GetTwoFactorStatusResponse {
+  pub success: bool,
+
+  /// Whether login requires a second factor.
+  pub is_enabled: bool,
+
+  /// How many single-use recovery codes are left.
+  pub unused_recovery_code_count: usize,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct StartTwoFactorEnrollmentResponse {
+  pub success: bool,
+
+  /// The base32 TOTP secret, for users who can't scan the QR code.
+  pub secret: String,
+
+  /// An `otpauth://` URI to render as a QR code for authenticator apps.
+  pub provisioning_uri: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ConfirmTwoFactorEnrollmentRequest {
+  /// A current code from the authenticator app.
+  pub code: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ConfirmTwoFactorEnrollmentResponse {
+  pub success: bool,
+
+  /// Single-use recovery codes. These are only ever shown once.
+  pub recovery_codes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DisableTwoFactorRequest {
+  /// A current authenticator code or an unused recovery code.
+  pub code: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DisableTwoFactorResponse {
+  pub success: bool,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RegenerateTwoFactorRecoveryCodesRequest {
+  /// A current authenticator code or an unused recovery code.
+  pub code: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RegenerateTwoFactorRecoveryCodesResponse {
+  pub success: bool,
+
+  /// The new recovery codes. All previous codes no longer work.
+  pub recovery_codes: Vec<String>,
+}
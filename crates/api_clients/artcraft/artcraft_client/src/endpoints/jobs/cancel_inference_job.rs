@@ -0,0 +1,24 @@
+use crate::credentials::storyteller_credential_set::StorytellerCredentialSet;
+use crate::error::storyteller_error::StorytellerError;
+use crate::utils::api_host::ApiHost;
+use crate::utils::basic_json_post_request::basic_json_post_request;
+use artcraft_api_defs::jobs::cancel_inference_job::CancelInferenceJobResponse;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+
+pub async fn cancel_inference_job(
+  api_host: &ApiHost,
+  maybe_creds: Option<&StorytellerCredentialSet>,
+  job_token: &InferenceJobToken,
+) -> Result<CancelInferenceJobResponse, StorytellerError> {
+  // NB: No request body; the job token is in the path.
+  Ok(basic_json_post_request(
+    api_host,
+    &get_url_path(job_token),
+    maybe_creds,
+    (),
+  ).await?)
+}
+
+fn get_url_path(token: &InferenceJobToken) -> String {
+  format!("/v1/jobs/job/{}/cancel", token.as_str())
+}
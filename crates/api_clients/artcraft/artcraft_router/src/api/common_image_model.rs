@@ -1,8 +1,11 @@
 use serde_derive::{Deserialize, Serialize};
+#[cfg(test)]
+use strum::EnumIter;
 
 /// Common image models supported by the router.
 /// Not all models are available through all providers.
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, derive(EnumIter))]
 #[serde(rename_all = "snake_case")]
 pub enum CommonImageModel {
   #[serde(rename = "flux_1_dev")]
@@ -36,3 +39,35 @@ pub enum CommonImageModel {
   #[serde(rename = "flux_2_lora_angles")]
   Flux2LoraAngles,
 }
+
+impl CommonImageModel {
+  /// All image models, in declaration order.
+  pub const ALL: &'static [CommonImageModel] = &[
+    Self::Flux1Dev,
+    Self::Flux1Schnell,
+    Self::FluxPro11,
+    Self::FluxPro11Ultra,
+    Self::GptImage1,
+    Self::GptImage1p5,
+    Self::GptImage2,
+    Self::NanoBanana,
+    Self::NanoBanana2,
+    Self::NanoBananaPro,
+    Self::Seedream4,
+    Self::Seedream4p5,
+    Self::Seedream5Lite,
+    Self::QwenEdit2511Angles,
+    Self::Flux2LoraAngles,
+  ];
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use strum::IntoEnumIterator;
+
+  #[test]
+  fn all_lists_every_variant() {
+    assert_eq!(CommonImageModel::ALL.len(), CommonImageModel::iter().count());
+  }
+}
@@ -1,8 +1,11 @@
 use serde_derive::{Deserialize, Serialize};
+#[cfg(test)]
+use strum::EnumIter;
 
 /// Common splat models supported by the router.
 /// Not all models are available through all providers.
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, derive(EnumIter))]
 #[serde(rename_all = "snake_case")]
 pub enum CommonSplatModel {
   #[serde(rename = "marble_0p1_mini")]
@@ -11,3 +14,22 @@ pub enum CommonSplatModel {
   #[serde(rename = "marble_0p1_plus")]
   Marble0p1Plus,
 }
+
+impl CommonSplatModel {
+  /// All splat models, in declaration order.
+  pub const ALL: &'static [CommonSplatModel] = &[
+    Self::Marble0p1Mini,
+    Self::Marble0p1Plus,
+  ];
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use strum::IntoEnumIterator;
+
+  #[test]
+  fn all_lists_every_variant() {
+    assert_eq!(CommonSplatModel::ALL.len(), CommonSplatModel::iter().count());
+  }
+}
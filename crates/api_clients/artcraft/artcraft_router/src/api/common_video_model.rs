@@ -1,8 +1,11 @@
 use serde_derive::{Deserialize, Serialize};
+#[cfg(test)]
+use strum::EnumIter;
 
 /// Common video models supported by the router.
 /// Not all models are available through all providers.
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, derive(EnumIter))]
 #[serde(rename_all = "snake_case")]
 pub enum CommonVideoModel {
   #[serde(rename = "grok_video")]
@@ -77,3 +80,44 @@ pub enum CommonVideoModel {
   #[serde(rename = "preview_model_fast")]
   PreviewModelFast,
 }
+
+impl CommonVideoModel {
+  /// All video models, in declaration order.
+  pub const ALL: &'static [CommonVideoModel] = &[
+    Self::GrokVideo,
+    Self::Kling16Pro,
+    Self::Kling21Pro,
+    Self::Kling21Master,
+    Self::Kling2p5TurboPro,
+    Self::Kling2p6Pro,
+    Self::Kling3p0Standard,
+    Self::Kling3p0Pro,
+    Self::Seedance10Lite,
+    Self::Seedance1p5Pro,
+    Self::Seedance2p0,
+    Self::Seedance2p0Fast,
+    Self::Seedance2p0Global,
+    Self::Seedance2p0FastGlobal,
+    Self::HappyHorse1p0,
+    Self::Sora2,
+    Self::Sora2Pro,
+    Self::Veo2,
+    Self::Veo3,
+    Self::Veo3Fast,
+    Self::Veo3p1,
+    Self::Veo3p1Fast,
+    Self::PreviewModel,
+    Self::PreviewModelFast,
+  ];
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use strum::IntoEnumIterator;
+
+  #[test]
+  fn all_lists_every_variant() {
+    assert_eq!(CommonVideoModel::ALL.len(), CommonVideoModel::iter().count());
+  }
+}
@@ -1,12 +1,42 @@
 use serde_derive::{Deserialize, Serialize};
+#[cfg(test)]
+use strum::EnumIter;
 
 /// The provider to route a generation request to.
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(test, derive(EnumIter))]
 #[serde(rename_all = "snake_case")]
 pub enum Provider {
   Artcraft,
   Fal,
   GmiCloud,
+  /// Offline stand-in that needs no credentials. For tests and local development.
+  Mock,
   Muapi,
+  Replicate,
   Seedance2Pro,
 }
+
+impl Provider {
+  /// All providers, in declaration order.
+  pub const ALL: &'static [Provider] = &[
+    Self::Artcraft,
+    Self::Fal,
+    Self::GmiCloud,
+    Self::Mock,
+    Self::Muapi,
+    Self::Replicate,
+    Self::Seedance2Pro,
+  ];
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use strum::IntoEnumIterator;
+
+  #[test]
+  fn all_lists_every_variant() {
+    assert_eq!(Provider::ALL.len(), Provider::iter().count());
+  }
+}
@@ -7,5 +7,8 @@ pub mod router_client;
 pub mod router_fal_client;
 pub mod router_fal_webhook_optional_client;
 pub mod router_gmicloud_client;
+pub mod router_mock_client;
 pub mod router_muapi_client;
+pub mod router_replicate_client;
 pub mod router_seedance2pro_client;
+pub mod video_failover_policy;
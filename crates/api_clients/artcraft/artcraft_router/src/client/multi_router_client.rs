@@ -2,17 +2,23 @@ use crate::client::router_artcraft_client::RouterArtcraftClient;
 use crate::client::router_fal_client::RouterFalClient;
 use crate::client::router_fal_webhook_optional_client::RouterFalWebhookOptionalClient;
 use crate::client::router_gmicloud_client::RouterGmiCloudClient;
+use crate::client::router_mock_client::RouterMockClient;
 use crate::client::router_muapi_client::RouterMuapiClient;
+use crate::client::router_replicate_client::RouterReplicateClient;
 use crate::client::router_seedance2pro_client::RouterSeedance2ProClient;
 use crate::errors::client_error::{ClientError, ClientType};
+use crate::health::provider_health_registry::ProviderHealthRegistry;
 
 pub struct MultiRouterClient {
   pub(crate) artcraft_client: Option<RouterArtcraftClient>,
   pub(crate) fal_client: Option<RouterFalClient>,
   pub(crate) fal_webhook_optional_client: Option<RouterFalWebhookOptionalClient>,
   pub(crate) gmicloud_client: Option<RouterGmiCloudClient>,
+  pub(crate) mock_client: Option<RouterMockClient>,
   pub(crate) muapi_client: Option<RouterMuapiClient>,
+  pub(crate) replicate_client: Option<RouterReplicateClient>,
   pub(crate) seedance2pro_client: Option<RouterSeedance2ProClient>,
+  pub(crate) health_registry: Option<ProviderHealthRegistry>,
 }
 
 impl MultiRouterClient {
@@ -36,13 +42,27 @@ impl MultiRouterClient {
       .ok_or(ClientError::ClientNotConfigured(ClientType::GmiCloud))
   }
 
+  pub fn get_mock_client_ref(&self) -> Result<&RouterMockClient, ClientError> {
+    self.mock_client.as_ref()
+      .ok_or(ClientError::ClientNotConfigured(ClientType::Mock))
+  }
+
   pub fn get_muapi_client_ref(&self) -> Result<&RouterMuapiClient, ClientError> {
     self.muapi_client.as_ref()
       .ok_or(ClientError::ClientNotConfigured(ClientType::Muapi))
   }
 
+  pub fn get_replicate_client_ref(&self) -> Result<&RouterReplicateClient, ClientError> {
+    self.replicate_client.as_ref()
+      .ok_or(ClientError::ClientNotConfigured(ClientType::Replicate))
+  }
+
   pub fn get_seedance2pro_client_ref(&self) -> Result<&RouterSeedance2ProClient, ClientError> {
     self.seedance2pro_client.as_ref()
       .ok_or(ClientError::ClientNotConfigured(ClientType::Seedance2Pro))
   }
+
+  pub fn get_health_registry(&self) -> Option<&ProviderHealthRegistry> {
+    self.health_registry.as_ref()
+  }
 }
@@ -3,16 +3,22 @@ use crate::client::router_artcraft_client::RouterArtcraftClient;
 use crate::client::router_fal_client::RouterFalClient;
 use crate::client::router_fal_webhook_optional_client::RouterFalWebhookOptionalClient;
 use crate::client::router_gmicloud_client::RouterGmiCloudClient;
+use crate::client::router_mock_client::RouterMockClient;
 use crate::client::router_muapi_client::RouterMuapiClient;
+use crate::client::router_replicate_client::RouterReplicateClient;
 use crate::client::router_seedance2pro_client::RouterSeedance2ProClient;
+use crate::health::provider_health_registry::ProviderHealthRegistry;
 
 pub struct MultiRouterClientBuilder {
   artcraft_client: Option<RouterArtcraftClient>,
   fal_client: Option<RouterFalClient>,
   fal_webhook_optional_client: Option<RouterFalWebhookOptionalClient>,
   gmicloud_client: Option<RouterGmiCloudClient>,
+  mock_client: Option<RouterMockClient>,
   muapi_client: Option<RouterMuapiClient>,
+  replicate_client: Option<RouterReplicateClient>,
   seedance2pro_client: Option<RouterSeedance2ProClient>,
+  health_registry: Option<ProviderHealthRegistry>,
 }
 
 impl MultiRouterClientBuilder {
@@ -22,8 +28,11 @@ impl MultiRouterClientBuilder {
       fal_client: None,
       fal_webhook_optional_client: None,
       gmicloud_client: None,
+      mock_client: None,
       muapi_client: None,
+      replicate_client: None,
       seedance2pro_client: None,
+      health_registry: None,
     }
   }
 
@@ -47,24 +56,44 @@ impl MultiRouterClientBuilder {
     self
   }
 
+  pub fn set_mock_client(mut self, client: RouterMockClient) -> Self {
+    self.mock_client = Some(client);
+    self
+  }
+
   pub fn set_muapi_client(mut self, client: RouterMuapiClient) -> Self {
     self.muapi_client = Some(client);
     self
   }
 
+  pub fn set_replicate_client(mut self, client: RouterReplicateClient) -> Self {
+    self.replicate_client = Some(client);
+    self
+  }
+
   pub fn set_seedance2pro_client(mut self, client: RouterSeedance2ProClient) -> Self {
     self.seedance2pro_client = Some(client);
     self
   }
 
+  /// Track provider health and refuse calls to providers whose circuit is open.
+  /// The registry is shared, so pass a clone of a long-lived instance.
+  pub fn set_health_registry(mut self, registry: ProviderHealthRegistry) -> Self {
+    self.health_registry = Some(registry);
+    self
+  }
+
   pub fn build(self) -> MultiRouterClient {
     MultiRouterClient {
       artcraft_client: self.artcraft_client,
       fal_client: self.fal_client,
       fal_webhook_optional_client: self.fal_webhook_optional_client,
       gmicloud_client: self.gmicloud_client,
+      mock_client: self.mock_client,
       muapi_client: self.muapi_client,
+      replicate_client: self.replicate_client,
       seedance2pro_client: self.seedance2pro_client,
+      health_registry: self.health_registry,
     }
   }
 }
@@ -1,11 +1,21 @@
+use crate::api::provider::Provider;
 use crate::client::multi_router_client::MultiRouterClient;
 use crate::client::router_artcraft_client::RouterArtcraftClient;
 use crate::client::router_fal_client::RouterFalClient;
 use crate::client::router_fal_webhook_optional_client::RouterFalWebhookOptionalClient;
 use crate::client::router_gmicloud_client::RouterGmiCloudClient;
+use crate::client::router_mock_client::RouterMockClient;
 use crate::client::router_muapi_client::RouterMuapiClient;
+use crate::client::router_replicate_client::RouterReplicateClient;
 use crate::client::router_seedance2pro_client::RouterSeedance2ProClient;
+use crate::client::video_failover_policy::VideoFailoverPolicy;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
 use crate::errors::client_error::{ClientError, ClientType};
+use crate::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+use crate::generate::generate_video_v2::video_generation_draft_context::VideoGenerationDraftContext;
+use crate::generate::generate_video_v2::video_generation_failover::{generate_video_with_failover, VideoFailoverResponse};
+use crate::health::provider_health_registry::{ProviderCallOutcome, ProviderHealthRegistry};
+use std::future::Future;
 
 pub enum RouterClient {
   Multi(MultiRouterClient),
@@ -13,7 +23,9 @@ pub enum RouterClient {
   Fal(RouterFalClient),
   FalWebhookOptional(RouterFalWebhookOptionalClient),
   GmiCloud(RouterGmiCloudClient),
+  Mock(RouterMockClient),
   Muapi(RouterMuapiClient),
+  Replicate(RouterReplicateClient),
   Seedance2Pro(RouterSeedance2ProClient),
 }
 
@@ -50,6 +62,14 @@ impl RouterClient {
     }
   }
 
+  pub fn get_mock_client_ref(&self) -> Result<&RouterMockClient, ClientError> {
+    match self {
+      RouterClient::Mock(client) => Ok(client),
+      RouterClient::Multi(multi) => multi.get_mock_client_ref(),
+      _ => Err(ClientError::ClientNotConfigured(ClientType::Mock)),
+    }
+  }
+
   pub fn get_muapi_client_ref(&self) -> Result<&RouterMuapiClient, ClientError> {
     match self {
       RouterClient::Muapi(client) => Ok(client),
@@ -58,6 +78,14 @@ impl RouterClient {
     }
   }
 
+  pub fn get_replicate_client_ref(&self) -> Result<&RouterReplicateClient, ClientError> {
+    match self {
+      RouterClient::Replicate(client) => Ok(client),
+      RouterClient::Multi(multi) => multi.get_replicate_client_ref(),
+      _ => Err(ClientError::ClientNotConfigured(ClientType::Replicate)),
+    }
+  }
+
   pub fn get_seedance2pro_client_ref(&self) -> Result<&RouterSeedance2ProClient, ClientError> {
     match self {
       RouterClient::Seedance2Pro(client) => Ok(client),
@@ -65,4 +93,123 @@ impl RouterClient {
       _ => Err(ClientError::ClientNotConfigured(ClientType::Seedance2Pro)),
     }
   }
+
+  /// Whether a client is configured that can send requests to the provider.
+  pub fn is_provider_configured(&self, provider: Provider) -> bool {
+    match provider {
+      Provider::Artcraft => self.get_artcraft_client_ref().is_ok(),
+      Provider::Fal => self.get_fal_client_ref().is_ok(),
+      Provider::GmiCloud => self.get_gmicloud_client_ref().is_ok(),
+      Provider::Mock => self.get_mock_client_ref().is_ok(),
+      Provider::Muapi => self.get_muapi_client_ref().is_ok(),
+      Provider::Replicate => self.get_replicate_client_ref().is_ok(),
+      Provider::Seedance2Pro => self.get_seedance2pro_client_ref().is_ok(),
+    }
+  }
+
+  /// Provider health tracking, if configured. Only multi-provider clients track health.
+  pub fn get_health_registry(&self) -> Option<&ProviderHealthRegistry> {
+    match self {
+      RouterClient::Multi(multi) => multi.get_health_registry(),
+      _ => None,
+    }
+  }
+
+  /// Run a call to a provider through its circuit breaker, recording latency and outcome.
+  /// Without a health registry the call is passed straight through.
+  pub(crate) async fn track_provider_call<T, F>(&self, provider: Provider, call: F) -> Result<T, ArtcraftRouterError>
+  where F: Future<Output = Result<T, ArtcraftRouterError>>
+  {
+    let registry = match self.get_health_registry() {
+      Some(registry) => registry,
+      None => return call.await,
+    };
+
+    // NB: If this future is dropped mid-call, the permit's Drop releases the probe slot.
+    let permit = match registry.admit(provider) {
+      Some(permit) => permit,
+      None => return Err(ArtcraftRouterError::ProviderCircuitOpen(provider)),
+    };
+
+    let result = call.await;
+    permit.finish(ProviderCallOutcome::from_result(&result));
+    result
+  }
+
+  /// Send a video request, failing over to equivalent provider variants in policy order.
+  /// The returned response reports which provider accepted the job.
+  pub async fn generate_video_with_failover(
+    &self,
+    builder: &GenerateVideoRequestBuilder,
+    policy: &VideoFailoverPolicy,
+    draft_context: VideoGenerationDraftContext<'_>,
+  ) -> Result<VideoFailoverResponse, ArtcraftRouterError> {
+    generate_video_with_failover(self, builder, policy, draft_context).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::task::Poll;
+  use std::time::Duration;
+
+  use super::*;
+  use crate::client::multi_router_client_builder::MultiRouterClientBuilder;
+  use crate::health::circuit_breaker_config::CircuitBreakerConfig;
+  use crate::health::circuit_state::CircuitState;
+
+  #[test]
+  fn replicate_client_is_configured() {
+    let client = RouterClient::Replicate(RouterReplicateClient::from_str("r8_test"));
+    assert!(client.get_replicate_client_ref().is_ok());
+    assert!(client.is_provider_configured(Provider::Replicate));
+    assert!(!client.is_provider_configured(Provider::Fal));
+  }
+
+  #[test]
+  fn multi_client_with_replicate_is_configured() {
+    let client = RouterClient::Multi(MultiRouterClientBuilder::new()
+      .set_replicate_client(RouterReplicateClient::from_str("r8_test"))
+      .build());
+    assert!(client.get_replicate_client_ref().is_ok());
+    assert!(client.is_provider_configured(Provider::Replicate));
+    assert!(client.get_muapi_client_ref().is_err());
+  }
+
+  #[tokio::test]
+  async fn cancelled_probe_releases_the_probe_slot() {
+    let registry = ProviderHealthRegistry::new(CircuitBreakerConfig {
+      open_duration: Duration::ZERO,
+      ..CircuitBreakerConfig::default()
+    });
+    for _ in 0..CircuitBreakerConfig::default().consecutive_failures_to_trip {
+      registry.record(Provider::Fal, Duration::from_millis(1), ProviderCallOutcome::Failure);
+    }
+
+    let client = RouterClient::Multi(MultiRouterClientBuilder::new()
+      .set_health_registry(registry.clone())
+      .build());
+
+    // Start a probe that never finishes, poll it once so it's admitted, then give up on it.
+    let mut probe = Box::pin(client.track_provider_call(Provider::Fal, std::future::pending::<Result<(), ArtcraftRouterError>>()));
+    std::future::poll_fn(|cx| {
+      assert!(probe.as_mut().poll(cx).is_pending());
+      Poll::Ready(())
+    }).await;
+    assert!(!registry.is_available(Provider::Fal), "the probe slot should be taken");
+    drop(probe);
+
+    let result = client.track_provider_call(Provider::Fal, async { Ok(()) }).await;
+    assert!(result.is_ok(), "a new probe should be admitted after the cancelled one");
+    assert_eq!(registry.snapshot(Provider::Fal).circuit_state, CircuitState::Closed);
+  }
+
+  #[test]
+  fn replicate_not_configured_by_default() {
+    let client = RouterClient::Multi(MultiRouterClientBuilder::new().build());
+    assert!(matches!(
+      client.get_replicate_client_ref(),
+      Err(ClientError::ClientNotConfigured(ClientType::Replicate))
+    ));
+  }
 }
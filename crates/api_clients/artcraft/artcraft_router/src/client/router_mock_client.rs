@@ -0,0 +1,59 @@
+use std::time::{Duration, Instant};
+
+use crate::mock::mock_job_queue::{MockJobQueue, MOCK_JOB_ID_PREFIX};
+use crate::mock::mock_job_status::MockJobStatus;
+use crate::mock::mock_media_kind::MockMediaKind;
+use crate::mock::mock_provider_config::MockProviderConfig;
+use crate::mock::mock_provider_error::MockProviderError;
+
+/// Client for `Provider::Mock`. Needs no credentials or network.
+/// Cheap to clone; clones share submitted jobs.
+#[derive(Clone)]
+pub struct RouterMockClient {
+  pub(crate) queue: MockJobQueue,
+}
+
+impl RouterMockClient {
+  pub fn new(config: MockProviderConfig) -> Self {
+    RouterMockClient { queue: MockJobQueue::new(config) }
+  }
+
+  /// Whether a provider job id was issued by the mock provider.
+  pub fn is_mock_job_id(job_id: &str) -> bool {
+    job_id.starts_with(MOCK_JOB_ID_PREFIX)
+  }
+
+  pub fn get_config(&self) -> &MockProviderConfig {
+    self.queue.config()
+  }
+
+  pub(crate) fn submit(&self, kind: MockMediaKind, count: u16) -> Result<Vec<String>, MockProviderError> {
+    self.queue.submit_at(kind, count, Instant::now())
+  }
+
+  pub fn get_job_status(&self, job_id: &str) -> Result<MockJobStatus, MockProviderError> {
+    self.queue.status_at(job_id, Instant::now())
+  }
+
+  /// Cancel a job. Returns the status it had when cancelled.
+  pub fn cancel_job(&self, job_id: &str) -> Result<MockJobStatus, MockProviderError> {
+    self.queue.cancel_at(job_id, Instant::now())
+  }
+
+  /// Poll until the job completes or fails.
+  pub async fn wait_for_job(&self, job_id: &str, poll_interval: Duration) -> Result<MockJobStatus, MockProviderError> {
+    loop {
+      let status = self.get_job_status(job_id)?;
+      if status.is_terminal() {
+        return Ok(status);
+      }
+      tokio::time::sleep(poll_interval).await;
+    }
+  }
+}
+
+impl Default for RouterMockClient {
+  fn default() -> Self {
+    Self::new(MockProviderConfig::default())
+  }
+}
@@ -0,0 +1,52 @@
+use replicate_client::creds::replicate_api_key::ReplicateApiKey;
+use replicate_client::requests::common::prediction::Prediction;
+use replicate_client::requests::context::request_context::RequestContext;
+use replicate_client::requests::predictions::cancel_prediction::cancel_prediction;
+use replicate_client::requests::traits::replicate_model_endpoint_trait::ReplicateModelEndpoint;
+
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::provider_error::ProviderError;
+
+pub struct RouterReplicateClient {
+  pub(crate) api_key: ReplicateApiKey,
+
+  /// If set, Replicate POSTs the prediction here when it completes.
+  pub(crate) webhook_url: Option<String>,
+}
+
+impl RouterReplicateClient {
+  pub fn new(api_key: ReplicateApiKey) -> Self {
+    Self { api_key, webhook_url: None }
+  }
+
+  pub fn from_str(api_key: &str) -> Self {
+    Self::new(ReplicateApiKey::from_str(api_key))
+  }
+
+  pub fn new_with_webhook(api_key: ReplicateApiKey, webhook_url: String) -> Self {
+    Self {
+      api_key,
+      webhook_url: Some(webhook_url),
+    }
+  }
+
+  /// Create a prediction, with the webhook if one is configured.
+  pub(crate) async fn create_prediction<T: ReplicateModelEndpoint>(&self, request: &T) -> Result<Prediction, ArtcraftRouterError> {
+    let context = RequestContext::new(&self.api_key);
+
+    let result = match &self.webhook_url {
+      Some(webhook_url) => request.send_webhook_request(&context, webhook_url).await,
+      None => request.send_request(&context).await,
+    };
+
+    result.map_err(|err| ArtcraftRouterError::from(ProviderError::Replicate(err)))
+  }
+
+  pub(crate) async fn cancel_prediction(&self, prediction_id: &str) -> Result<Prediction, ArtcraftRouterError> {
+    let context = RequestContext::new(&self.api_key);
+
+    cancel_prediction(&context, prediction_id)
+      .await
+      .map_err(|err| ArtcraftRouterError::from(ProviderError::Replicate(err)))
+  }
+}
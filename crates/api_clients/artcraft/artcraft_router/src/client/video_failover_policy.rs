@@ -0,0 +1,118 @@
+use crate::api::common_video_model::CommonVideoModel;
+use crate::api::provider::Provider;
+
+/// A (provider, model) pair that can fulfill a video generation request.
+///
+/// Some models are offered under different names depending on the provider
+/// (eg. "Seedance 2.0" on Kinovi is "Seedance 2.0 Global" on GmiCloud).
+#[derive(Copy, Clone, Debug)]
+pub struct VideoProviderVariant {
+  pub provider: Provider,
+  pub model: CommonVideoModel,
+}
+
+impl VideoProviderVariant {
+  pub fn new(provider: Provider, model: CommonVideoModel) -> Self {
+    Self { provider, model }
+  }
+}
+
+/// An ordered list of equivalent provider variants to try when sending a video request.
+///
+/// The first variant is the preferred provider. If it fails to accept the job, the
+/// next variant is re-planned from the original request and tried, and so on.
+#[derive(Clone, Debug)]
+pub struct VideoFailoverPolicy {
+  pub variants: Vec<VideoProviderVariant>,
+}
+
+impl VideoFailoverPolicy {
+  pub fn new(variants: Vec<VideoProviderVariant>) -> Self {
+    Self { variants }
+  }
+
+  /// The default ordering of equivalent providers for a model.
+  /// Models without known equivalents only route to Artcraft.
+  pub fn default_for_model(model: CommonVideoModel) -> Self {
+    use CommonVideoModel as M;
+    use Provider as P;
+
+    let variants = match model {
+      M::HappyHorse1p0 => vec![
+        VideoProviderVariant::new(P::Seedance2Pro, M::HappyHorse1p0),
+        VideoProviderVariant::new(P::Artcraft, M::HappyHorse1p0),
+      ],
+      M::Seedance2p0 => vec![
+        VideoProviderVariant::new(P::Seedance2Pro, M::Seedance2p0),
+        VideoProviderVariant::new(P::GmiCloud, M::Seedance2p0Global),
+        VideoProviderVariant::new(P::Artcraft, M::Seedance2p0),
+      ],
+      M::Seedance2p0Fast => vec![
+        VideoProviderVariant::new(P::Seedance2Pro, M::Seedance2p0Fast),
+        VideoProviderVariant::new(P::GmiCloud, M::Seedance2p0FastGlobal),
+        VideoProviderVariant::new(P::Artcraft, M::Seedance2p0Fast),
+      ],
+      M::Seedance2p0Global => vec![
+        VideoProviderVariant::new(P::GmiCloud, M::Seedance2p0Global),
+        VideoProviderVariant::new(P::Artcraft, M::Seedance2p0Global),
+        VideoProviderVariant::new(P::Seedance2Pro, M::Seedance2p0),
+      ],
+      M::Seedance2p0FastGlobal => vec![
+        VideoProviderVariant::new(P::GmiCloud, M::Seedance2p0FastGlobal),
+        VideoProviderVariant::new(P::Artcraft, M::Seedance2p0FastGlobal),
+        VideoProviderVariant::new(P::Seedance2Pro, M::Seedance2p0Fast),
+      ],
+      other => vec![
+        VideoProviderVariant::new(P::Artcraft, other),
+      ],
+    };
+
+    Self { variants }
+  }
+
+  /// Only try providers for which `filter` returns true, preserving order.
+  pub fn retain_providers<F: Fn(Provider) -> bool>(mut self, filter: F) -> Self {
+    self.variants.retain(|variant| filter(variant.provider));
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn seedance_2p0_prefers_kinovi() {
+    let policy = VideoFailoverPolicy::default_for_model(CommonVideoModel::Seedance2p0);
+    assert_eq!(policy.variants.len(), 3);
+    assert!(matches!(policy.variants[0].provider, Provider::Seedance2Pro));
+    assert!(matches!(policy.variants[1].provider, Provider::GmiCloud));
+    assert!(matches!(policy.variants[1].model, CommonVideoModel::Seedance2p0Global));
+    assert!(matches!(policy.variants[2].provider, Provider::Artcraft));
+  }
+
+  #[test]
+  fn happy_horse_has_kinovi_and_artcraft() {
+    let policy = VideoFailoverPolicy::default_for_model(CommonVideoModel::HappyHorse1p0);
+    assert_eq!(policy.variants.len(), 2);
+    assert!(matches!(policy.variants[0].provider, Provider::Seedance2Pro));
+    assert!(matches!(policy.variants[1].provider, Provider::Artcraft));
+  }
+
+  #[test]
+  fn models_without_equivalents_use_artcraft() {
+    let policy = VideoFailoverPolicy::default_for_model(CommonVideoModel::Veo3);
+    assert_eq!(policy.variants.len(), 1);
+    assert!(matches!(policy.variants[0].provider, Provider::Artcraft));
+    assert!(matches!(policy.variants[0].model, CommonVideoModel::Veo3));
+  }
+
+  #[test]
+  fn retain_providers_preserves_order() {
+    let policy = VideoFailoverPolicy::default_for_model(CommonVideoModel::Seedance2p0Fast)
+      .retain_providers(|provider| !matches!(provider, Provider::Artcraft));
+    assert_eq!(policy.variants.len(), 2);
+    assert!(matches!(policy.variants[0].provider, Provider::Seedance2Pro));
+    assert!(matches!(policy.variants[1].provider, Provider::GmiCloud));
+  }
+}
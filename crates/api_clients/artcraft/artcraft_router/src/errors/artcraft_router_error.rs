@@ -1,13 +1,17 @@
+use crate::api::provider::Provider;
 use crate::errors::client_error::ClientError;
 use crate::errors::download_error::DownloadError;
 use crate::errors::provider_error::ProviderError;
 use fal_client::error::fal_error_plus::FalErrorPlus;
+use replicate_client::error::replicate_error::ReplicateError;
+use replicate_client::error::replicate_specific_api_error::ReplicateSpecificApiError;
 use seedance2pro_client::error::seedance2pro_error::Seedance2ProError;
 use seedance2pro_client::error::seedance2pro_specific_api_error::Seedance2ProSpecificApiError;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use artcraft_client::error::api_error::ApiError;
 use artcraft_client::error::storyteller_error::StorytellerError;
+use crate::generate::generate_video_v2::video_generation_failover::VideoFailoverAttempt;
 
 #[derive(Debug)]
 pub enum ArtcraftRouterError {
@@ -31,6 +35,12 @@ pub enum ArtcraftRouterError {
   
   /// A billing error from an underlying provider.
   ProviderBillingError(ProviderError),
+
+  /// The provider's circuit breaker is open after repeated failures; the request was not sent.
+  ProviderCircuitOpen(Provider),
+
+  /// Every provider variant in a failover policy was tried, and none accepted the job.
+  FailoverExhausted(Vec<VideoFailoverAttempt>),
 }
 
 impl Error for ArtcraftRouterError {}
@@ -45,6 +55,14 @@ impl Display for ArtcraftRouterError {
       Self::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
       Self::Provider(e) => write!(f, "Provider error: {}", e),
       Self::ProviderBillingError(e) => write!(f, "Provider billing error: {}", e),
+      Self::ProviderCircuitOpen(provider) => write!(f, "Provider {:?} is unhealthy; circuit breaker is open", provider),
+      Self::FailoverExhausted(attempts) => {
+        write!(f, "All {} provider variants failed:", attempts.len())?;
+        for attempt in attempts {
+          write!(f, " [{}]", attempt)?;
+        }
+        Ok(())
+      }
     }
   }
 }
@@ -66,6 +84,7 @@ impl From<ProviderError> for ArtcraftRouterError {
     let is_billing_error = match &error {
       ProviderError::Fal(FalErrorPlus::FalBillingError(_)) => true,
       ProviderError::Seedance2Pro(Seedance2ProError::ApiSpecific(Seedance2ProSpecificApiError::BillingError { .. })) => true,
+      ProviderError::Replicate(ReplicateError::ApiSpecific(ReplicateSpecificApiError::BillingError { .. })) => true,
       ProviderError::Storyteller(StorytellerError::Api(ApiError::PaymentRequired(_))) => true,
       _ => false,
     };
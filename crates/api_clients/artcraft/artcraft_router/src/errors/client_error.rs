@@ -9,7 +9,9 @@ pub enum ClientType {
   Fal,
   FalWebhookOptional,
   GmiCloud,
+  Mock,
   Muapi,
+  Replicate,
   Seedance2Pro,
 }
 
@@ -20,7 +22,9 @@ impl Display for ClientType {
       Self::Fal => write!(f, "Fal"),
       Self::FalWebhookOptional => write!(f, "FalWebhookOptional"),
       Self::GmiCloud => write!(f, "GmiCloud"),
+      Self::Mock => write!(f, "Mock"),
       Self::Muapi => write!(f, "Muapi"),
+      Self::Replicate => write!(f, "Replicate"),
       Self::Seedance2Pro => write!(f, "Seedance2Pro"),
     }
   }
@@ -4,14 +4,18 @@ use artcraft_client::error::storyteller_error::StorytellerError;
 use fal_client::error::fal_error_plus::FalErrorPlus;
 use gmicloud_client::error::gmicloud_error::GmiCloudError;
 use muapi_client::error::muapi_error::MuapiError;
+use replicate_client::error::replicate_error::ReplicateError;
 use seedance2pro_client::error::seedance2pro_error::Seedance2ProError;
+use crate::mock::mock_provider_error::MockProviderError;
 
 #[derive(Debug)]
 pub enum ProviderError {
   Storyteller(StorytellerError),
   Fal(FalErrorPlus),
   GmiCloud(GmiCloudError),
+  Mock(MockProviderError),
   Muapi(MuapiError),
+  Replicate(ReplicateError),
   Seedance2Pro(Seedance2ProError),
 }
 
@@ -23,7 +27,9 @@ impl Display for ProviderError {
       Self::Storyteller(e) => write!(f, "Storyteller provider error: {}", e),
       Self::Fal(e) => write!(f, "Fal provider error: {}", e),
       Self::GmiCloud(e) => write!(f, "GmiCloud provider error: {}", e),
+      Self::Mock(e) => write!(f, "Mock provider error: {}", e),
       Self::Muapi(e) => write!(f, "Muapi provider error: {}", e),
+      Self::Replicate(e) => write!(f, "Replicate provider error: {}", e),
       Self::Seedance2Pro(e) => write!(f, "Seedance2Pro provider error: {}", e),
     }
   }
@@ -47,12 +53,24 @@ impl From<GmiCloudError> for ProviderError {
   }
 }
 
+impl From<MockProviderError> for ProviderError {
+  fn from(error: MockProviderError) -> Self {
+    Self::Mock(error)
+  }
+}
+
 impl From<MuapiError> for ProviderError {
   fn from(error: MuapiError) -> Self {
     Self::Muapi(error)
   }
 }
 
+impl From<ReplicateError> for ProviderError {
+  fn from(error: ReplicateError) -> Self {
+    Self::Replicate(error)
+  }
+}
+
 impl From<Seedance2ProError> for ProviderError {
   fn from(error: Seedance2ProError) -> Self {
     Self::Seedance2Pro(error)
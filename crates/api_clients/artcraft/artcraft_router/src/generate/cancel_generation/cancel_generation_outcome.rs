@@ -0,0 +1,16 @@
+/// What happened when we asked a provider to cancel a generation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CancelGenerationOutcome {
+  /// The provider confirmed the job was stopped (or will never run).
+  Cancelled,
+
+  /// The provider accepted the cancellation, but hasn't confirmed the job stopped.
+  /// The job's final status (and any refund) arrives the usual way: webhook or polling.
+  CancellationRequested,
+
+  /// The job finished before we asked.
+  AlreadyFinished,
+
+  /// The job can't be stopped: it's already running, or the provider has no cancel API.
+  NotCancellable,
+}
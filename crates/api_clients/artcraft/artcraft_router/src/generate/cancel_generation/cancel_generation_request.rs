@@ -0,0 +1,312 @@
+use artcraft_api_defs::jobs::cancel_inference_job::CancelInferenceJobOutcome;
+use artcraft_client::endpoints::jobs::cancel_inference_job::cancel_inference_job;
+use fal_client::creds::fal_api_key::FalApiKey;
+use fal_client::polling::cancel_job::cancel_job::{cancel_job, cancel_url_from_status_url, CancelJobArgs, FalCancelJobOutcome};
+use gmicloud_client::requests::cancel_request::cancel_request::{cancel_gmicloud_request, GmiCloudCancelOutcome};
+use log::warn;
+use replicate_client::requests::common::prediction::PredictionStatus;
+use seedance2pro_client::requests::cancel_order::cancel_order::{cancel_order, CancelOrderArgs, CancelOrderOutcome};
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+
+use crate::api::provider::Provider;
+use crate::client::router_client::RouterClient;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::client_error::ClientError;
+use crate::errors::provider_error::ProviderError;
+use crate::generate::cancel_generation::cancel_generation_outcome::CancelGenerationOutcome;
+use crate::generate::generate_image::generate_image_response::GenerateImageResponse;
+use crate::generate::generate_video::generate_video_response::GenerateVideoResponse;
+use crate::mock::mock_job_status::MockJobStatus;
+
+/// Everything needed to cancel a generation that was already submitted.
+///
+/// Build one from a response (`from_image_response`, `from_video_response`), or from
+/// the provider and job id stored alongside a task.
+#[derive(Clone, Debug)]
+pub struct CancelGenerationRequest {
+  pub provider: Provider,
+
+  /// The provider's id for the job: an Artcraft job token, a FAL request id,
+  /// a Replicate prediction id, etc.
+  pub provider_job_id: String,
+
+  /// FAL only: the queue status URL. FAL cancels by URL, and the cancel URL is
+  /// derived from this one.
+  pub maybe_queue_status_url: Option<String>,
+}
+
+impl CancelGenerationRequest {
+  /// Returns `None` if the response doesn't carry enough to cancel the job.
+  pub fn from_image_response(response: &GenerateImageResponse) -> Option<Self> {
+    match response {
+      GenerateImageResponse::Artcraft(p) => Some(Self::new(Provider::Artcraft, p.inference_job_token.as_str())),
+      GenerateImageResponse::Fal(p) => Some(Self {
+        provider: Provider::Fal,
+        provider_job_id: p.request_id.clone()?,
+        maybe_queue_status_url: Some(p.maybe_status_url.clone()?),
+      }),
+      GenerateImageResponse::Mock(p) => Some(Self::new(Provider::Mock, &p.job_id)),
+      GenerateImageResponse::Replicate(p) => Some(Self::new(Provider::Replicate, &p.prediction_id)),
+    }
+  }
+
+  /// Returns `None` if the response doesn't carry enough to cancel the job.
+  pub fn from_video_response(response: &GenerateVideoResponse) -> Option<Self> {
+    match response {
+      GenerateVideoResponse::Artcraft(p) => Some(Self::new(Provider::Artcraft, p.inference_job_token.as_str())),
+      GenerateVideoResponse::Mock(p) => Some(Self::new(Provider::Mock, &p.job_id)),
+      GenerateVideoResponse::Replicate(p) => Some(Self::new(Provider::Replicate, &p.prediction_id)),
+      GenerateVideoResponse::Fal(p) => Some(Self {
+        provider: Provider::Fal,
+        provider_job_id: p.request_id.clone()?,
+        maybe_queue_status_url: Some(p.maybe_status_url.clone()?),
+      }),
+      GenerateVideoResponse::GmiCloud(p) => Some(Self::new(Provider::GmiCloud, &p.request_id)),
+      GenerateVideoResponse::Seedance2Pro(p) => Some(Self::new(Provider::Seedance2Pro, &p.order_id)),
+      GenerateVideoResponse::Muapi(_) => None,
+    }
+  }
+
+  fn new(provider: Provider, provider_job_id: &str) -> Self {
+    Self {
+      provider,
+      provider_job_id: provider_job_id.to_string(),
+      maybe_queue_status_url: None,
+    }
+  }
+
+  pub async fn send(&self, client: &RouterClient) -> Result<CancelGenerationOutcome, ArtcraftRouterError> {
+    match self.provider {
+      Provider::Artcraft => self.cancel_artcraft(client).await,
+      Provider::Fal => self.cancel_fal(client).await,
+      Provider::GmiCloud => self.cancel_gmicloud(client).await,
+      Provider::Mock => self.cancel_mock(client),
+      Provider::Replicate => self.cancel_replicate(client).await,
+      Provider::Seedance2Pro => self.cancel_seedance2pro(client).await,
+      // Muapi has no cancel API.
+      Provider::Muapi => Ok(CancelGenerationOutcome::NotCancellable),
+    }
+  }
+
+  async fn cancel_artcraft(&self, client: &RouterClient) -> Result<CancelGenerationOutcome, ArtcraftRouterError> {
+    let artcraft_client = client.get_artcraft_client_ref()?;
+    let job_token = InferenceJobToken::new_from_str(&self.provider_job_id);
+
+    let response = cancel_inference_job(
+      &artcraft_client.api_host,
+      Some(&artcraft_client.credentials),
+      &job_token,
+    )
+      .await
+      .map_err(|err| ArtcraftRouterError::Provider(ProviderError::Storyteller(err)))?;
+
+    Ok(match response.outcome {
+      CancelInferenceJobOutcome::Cancelled => CancelGenerationOutcome::Cancelled,
+      CancelInferenceJobOutcome::CancellationRequested => CancelGenerationOutcome::CancellationRequested,
+      CancelInferenceJobOutcome::AlreadyFinished => CancelGenerationOutcome::AlreadyFinished,
+      CancelInferenceJobOutcome::NotCancellable => CancelGenerationOutcome::NotCancellable,
+    })
+  }
+
+  async fn cancel_fal(&self, client: &RouterClient) -> Result<CancelGenerationOutcome, ArtcraftRouterError> {
+    let maybe_cancel_url = self.maybe_queue_status_url.as_deref()
+      .and_then(cancel_url_from_status_url);
+
+    let Some(cancel_url) = maybe_cancel_url else {
+      warn!("No FAL queue URL for request {}; can't cancel it.", self.provider_job_id);
+      return Ok(CancelGenerationOutcome::NotCancellable);
+    };
+
+    let outcome = cancel_job(CancelJobArgs {
+      cancel_url: &cancel_url,
+      api_key: fal_api_key(client)?,
+    })
+      .await
+      .map_err(|err| ArtcraftRouterError::Provider(ProviderError::Fal(err)))?;
+
+    Ok(match outcome {
+      // Requests that already started may still finish. FAL reports the final status by webhook.
+      FalCancelJobOutcome::CancellationRequested => CancelGenerationOutcome::CancellationRequested,
+      FalCancelJobOutcome::AlreadyCompleted => CancelGenerationOutcome::AlreadyFinished,
+      FalCancelJobOutcome::NotFound => CancelGenerationOutcome::NotCancellable,
+    })
+  }
+
+  async fn cancel_gmicloud(&self, client: &RouterClient) -> Result<CancelGenerationOutcome, ArtcraftRouterError> {
+    let gmicloud_client = client.get_gmicloud_client_ref()?;
+
+    let outcome = cancel_gmicloud_request(&gmicloud_client.api_key, &self.provider_job_id)
+      .await
+      .map_err(|err| ArtcraftRouterError::Provider(ProviderError::GmiCloud(err)))?;
+
+    Ok(match outcome {
+      GmiCloudCancelOutcome::Cancelled => CancelGenerationOutcome::Cancelled,
+      GmiCloudCancelOutcome::CancellationRequested => CancelGenerationOutcome::CancellationRequested,
+      GmiCloudCancelOutcome::AlreadyFinished => CancelGenerationOutcome::AlreadyFinished,
+      GmiCloudCancelOutcome::NotCancellable | GmiCloudCancelOutcome::NotFound => CancelGenerationOutcome::NotCancellable,
+    })
+  }
+
+  fn cancel_mock(&self, client: &RouterClient) -> Result<CancelGenerationOutcome, ArtcraftRouterError> {
+    let status = client.get_mock_client_ref()?
+      .cancel_job(&self.provider_job_id)
+      .map_err(|err| ArtcraftRouterError::Provider(ProviderError::Mock(err)))?;
+
+    Ok(match status {
+      MockJobStatus::Completed(_) | MockJobStatus::Failed { .. } => CancelGenerationOutcome::AlreadyFinished,
+      MockJobStatus::Queued { .. } | MockJobStatus::Processing { .. } | MockJobStatus::Cancelled => CancelGenerationOutcome::Cancelled,
+    })
+  }
+
+  async fn cancel_replicate(&self, client: &RouterClient) -> Result<CancelGenerationOutcome, ArtcraftRouterError> {
+    let prediction = client.get_replicate_client_ref()?
+      .cancel_prediction(&self.provider_job_id)
+      .await?;
+
+    Ok(match prediction.status {
+      PredictionStatus::Canceled => CancelGenerationOutcome::Cancelled,
+      PredictionStatus::Succeeded | PredictionStatus::Failed | PredictionStatus::Aborted => CancelGenerationOutcome::AlreadyFinished,
+      PredictionStatus::Starting | PredictionStatus::Processing | PredictionStatus::Unknown => CancelGenerationOutcome::NotCancellable,
+    })
+  }
+
+  async fn cancel_seedance2pro(&self, client: &RouterClient) -> Result<CancelGenerationOutcome, ArtcraftRouterError> {
+    let seedance2pro_client = client.get_seedance2pro_client_ref()?;
+
+    let outcome = cancel_order(CancelOrderArgs {
+      session: &seedance2pro_client.session,
+      order_id: &self.provider_job_id,
+      host_override: None,
+    })
+      .await
+      .map_err(|err| ArtcraftRouterError::Provider(ProviderError::Seedance2Pro(err)))?;
+
+    Ok(match outcome {
+      CancelOrderOutcome::Cancelled => CancelGenerationOutcome::Cancelled,
+      CancelOrderOutcome::CancellationRequested => CancelGenerationOutcome::CancellationRequested,
+      CancelOrderOutcome::AlreadyFinished => CancelGenerationOutcome::AlreadyFinished,
+      CancelOrderOutcome::NotCancellable | CancelOrderOutcome::NotFound => CancelGenerationOutcome::NotCancellable,
+    })
+  }
+}
+
+fn fal_api_key(client: &RouterClient) -> Result<&FalApiKey, ClientError> {
+  match client.get_fal_client_ref() {
+    Ok(fal_client) => Ok(&fal_client.api_key),
+    Err(err) => client.get_fal_webhook_optional_client_ref()
+      .map(|fal_client| &fal_client.api_key)
+      .map_err(|_| err),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use super::*;
+  use crate::client::router_mock_client::RouterMockClient;
+  use crate::generate::generate_image::generate_image_response::{FalImageResponsePayload, MockImageResponsePayload};
+  use crate::generate::generate_video::generate_video_response::{FalVideoResponsePayload, GmiCloudVideoResponsePayload, Seedance2proVideoResponsePayload};
+  use crate::mock::mock_media_kind::MockMediaKind;
+  use crate::mock::mock_provider_config::MockProviderConfig;
+
+  fn slow_mock_client() -> RouterClient {
+    RouterClient::Mock(RouterMockClient::new(MockProviderConfig {
+      queue_delay: Duration::from_secs(60),
+      processing_delay: Duration::from_secs(60),
+      ..MockProviderConfig::default()
+    }))
+  }
+
+  #[tokio::test]
+  async fn cancel_mock_job_before_it_finishes() {
+    let client = slow_mock_client();
+    let job_id = client.get_mock_client_ref().unwrap().submit(MockMediaKind::Image, 1).unwrap().remove(0);
+
+    let request = CancelGenerationRequest::new(Provider::Mock, &job_id);
+    assert_eq!(request.send(&client).await.unwrap(), CancelGenerationOutcome::Cancelled);
+
+    let status = client.get_mock_client_ref().unwrap().get_job_status(&job_id).unwrap();
+    assert_eq!(status, MockJobStatus::Cancelled);
+  }
+
+  #[tokio::test]
+  async fn cancel_finished_mock_job() {
+    let client = RouterClient::Mock(RouterMockClient::new(MockProviderConfig::instant()));
+    let job_id = client.get_mock_client_ref().unwrap().submit(MockMediaKind::Video, 1).unwrap().remove(0);
+
+    let request = CancelGenerationRequest::new(Provider::Mock, &job_id);
+    assert_eq!(request.send(&client).await.unwrap(), CancelGenerationOutcome::AlreadyFinished);
+  }
+
+  #[tokio::test]
+  async fn providers_without_a_cancel_api() {
+    let client = slow_mock_client();
+    let request = CancelGenerationRequest::new(Provider::Muapi, "req_123");
+    assert_eq!(request.send(&client).await.unwrap(), CancelGenerationOutcome::NotCancellable);
+  }
+
+  #[tokio::test]
+  async fn gmicloud_requires_a_configured_client() {
+    let client = slow_mock_client();
+    let request = CancelGenerationRequest::new(Provider::GmiCloud, "req_123");
+    assert!(request.send(&client).await.is_err());
+  }
+
+  #[test]
+  fn from_image_response() {
+    let request = CancelGenerationRequest::from_image_response(&GenerateImageResponse::Mock(MockImageResponsePayload {
+      job_id: "mock_image_00000001".to_string(),
+      all_job_ids: vec!["mock_image_00000001".to_string()],
+    })).unwrap();
+    assert_eq!(request.provider, Provider::Mock);
+    assert_eq!(request.provider_job_id, "mock_image_00000001");
+
+    let request = CancelGenerationRequest::from_image_response(&GenerateImageResponse::Fal(FalImageResponsePayload {
+      request_id: Some("abc".to_string()),
+      gateway_request_id: None,
+      maybe_status_url: Some("https://queue.fal.run/fal-ai/flux/requests/abc/status".to_string()),
+      maybe_response_url: None,
+      maybe_outbound_request: None,
+    })).unwrap();
+    assert_eq!(request.provider, Provider::Fal);
+    assert!(request.maybe_queue_status_url.is_some());
+
+    // Webhook submissions have no queue URL to cancel with.
+    let request = CancelGenerationRequest::from_image_response(&GenerateImageResponse::Fal(FalImageResponsePayload {
+      request_id: Some("abc".to_string()),
+      gateway_request_id: None,
+      maybe_status_url: None,
+      maybe_response_url: None,
+      maybe_outbound_request: None,
+    }));
+    assert!(request.is_none());
+  }
+
+  #[test]
+  fn from_video_response() {
+    let request = CancelGenerationRequest::from_video_response(&GenerateVideoResponse::GmiCloud(GmiCloudVideoResponsePayload {
+      request_id: "req_123".to_string(),
+    })).unwrap();
+    assert_eq!(request.provider, Provider::GmiCloud);
+    assert_eq!(request.provider_job_id, "req_123");
+
+    let request = CancelGenerationRequest::from_video_response(&GenerateVideoResponse::Fal(FalVideoResponsePayload {
+      request_id: Some("abc".to_string()),
+      gateway_request_id: None,
+      maybe_status_url: Some("https://queue.fal.run/fal-ai/kling-video/requests/abc/status".to_string()),
+      maybe_outbound_request: None,
+    })).unwrap();
+    assert_eq!(request.provider, Provider::Fal);
+    assert!(request.maybe_queue_status_url.is_some());
+
+    let request = CancelGenerationRequest::from_video_response(&GenerateVideoResponse::Seedance2Pro(Seedance2proVideoResponsePayload {
+      order_id: "ord_1".to_string(),
+      task_id: "task_1".to_string(),
+      maybe_order_ids: None,
+      maybe_task_ids: None,
+    })).unwrap();
+    assert_eq!(request.provider, Provider::Seedance2Pro);
+    assert_eq!(request.provider_job_id, "ord_1");
+  }
+}
@@ -0,0 +1,2 @@
+pub mod cancel_generation_outcome;
+pub mod cancel_generation_request;
@@ -17,6 +17,9 @@ use crate::generate::generate_image_v2::providers::fal::gpt_image_1p5::build::bu
 use crate::generate::generate_image_v2::providers::fal::gpt_image_2::build::build_fal_gpt_image_2;
 use crate::generate::generate_image_v2::providers::fal::nano_banana_2::build::build_fal_nano_banana_2;
 use crate::generate::generate_image_v2::providers::fal::nano_banana_pro::build::build_fal_nano_banana_pro;
+use crate::generate::generate_image_v2::providers::mock::build::build_mock_image;
+use crate::generate::generate_image_v2::providers::replicate::flux_1_dev::build::build_replicate_flux_1_dev;
+use crate::generate::generate_image_v2::providers::replicate::flux_1_schnell::build::build_replicate_flux_1_schnell;
 use crate::generate::generate_image::plan::artcraft::plan_generate_image_artcraft_flux_1_dev::plan_generate_image_artcraft_flux_1_dev;
 use crate::generate::generate_image::plan::artcraft::plan_generate_image_artcraft_flux_1_schnell::plan_generate_image_artcraft_flux_1_schnell;
 use crate::generate::generate_image::plan::artcraft::plan_generate_image_artcraft_flux_2_lora_angles::plan_generate_image_artcraft_flux_2_lora_angles;
@@ -107,6 +110,9 @@ impl GenerateImageRequestBuilder {
       (Provider::Fal, CommonImageModel::GptImage2) => true,
       (Provider::Fal, CommonImageModel::NanoBanana2) => true,
       (Provider::Fal, CommonImageModel::NanoBananaPro) => true,
+      (Provider::Mock, _) => true,
+      (Provider::Replicate, CommonImageModel::Flux1Dev) => true,
+      (Provider::Replicate, CommonImageModel::Flux1Schnell) => true,
       _ => false,
     }
   }
@@ -120,6 +126,9 @@ impl GenerateImageRequestBuilder {
       (Provider::Fal, CommonImageModel::GptImage2) => build_fal_gpt_image_2(self),
       (Provider::Fal, CommonImageModel::NanoBanana2) => build_fal_nano_banana_2(self),
       (Provider::Fal, CommonImageModel::NanoBananaPro) => build_fal_nano_banana_pro(self),
+      (Provider::Mock, _) => build_mock_image(self),
+      (Provider::Replicate, CommonImageModel::Flux1Dev) => build_replicate_flux_1_dev(self),
+      (Provider::Replicate, CommonImageModel::Flux1Schnell) => build_replicate_flux_1_schnell(self),
       _ => self.unsupported_provider_and_model(),
     }
   }
@@ -25,10 +25,28 @@ pub struct FalImageResponsePayload {
   pub maybe_outbound_request: Option<Arc<dyn Debug + Send + Sync>>,
 }
 
+#[derive(Clone, Debug)]
+pub struct ReplicateImageResponsePayload {
+  pub prediction_id: String,
+
+  /// The outbound request that was sent to Replicate.
+  /// Stored as a trait object so any Request type can be captured.
+  /// Use `format!("{:?}", ...)` or `format!("{:#?}", ...)` to print.
+  pub maybe_outbound_request: Option<Arc<dyn Debug + Send + Sync>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MockImageResponsePayload {
+  pub job_id: String,
+  pub all_job_ids: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub enum GenerateImageResponse {
   Artcraft(ArtcraftImageResponsePayload),
   Fal(FalImageResponsePayload),
+  Mock(MockImageResponsePayload),
+  Replicate(ReplicateImageResponsePayload),
 }
 
 impl GenerateImageResponse {
@@ -45,4 +63,18 @@ impl GenerateImageResponse {
       _ => None,
     }
   }
+
+  pub fn get_mock_payload(&self) -> Option<MockImageResponsePayload> {
+    match self {
+      Self::Mock(p) => Some(p.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_replicate_payload(&self) -> Option<ReplicateImageResponsePayload> {
+    match self {
+      Self::Replicate(p) => Some(p.clone()),
+      _ => None,
+    }
+  }
 }
@@ -17,6 +17,12 @@ use crate::generate::generate_image_v2::providers::fal::nano_banana_2::cost::Fal
 use crate::generate::generate_image_v2::providers::fal::nano_banana_2::request::FalNanoBanana2RequestState;
 use crate::generate::generate_image_v2::providers::fal::nano_banana_pro::cost::FalNanoBananaProCostState;
 use crate::generate::generate_image_v2::providers::fal::nano_banana_pro::request::FalNanoBananaProRequestState;
+use crate::generate::generate_image_v2::providers::mock::cost::MockImageCostState;
+use crate::generate::generate_image_v2::providers::mock::request::MockImageRequestState;
+use crate::generate::generate_image_v2::providers::replicate::flux_1_dev::cost::ReplicateFlux1DevCostState;
+use crate::generate::generate_image_v2::providers::replicate::flux_1_dev::request::ReplicateFlux1DevRequestState;
+use crate::generate::generate_image_v2::providers::replicate::flux_1_schnell::cost::ReplicateFlux1SchnellCostState;
+use crate::generate::generate_image_v2::providers::replicate::flux_1_schnell::request::ReplicateFlux1SchnellRequestState;
 
 #[derive(Clone, Debug)]
 pub enum ImageGenerationRequest {
@@ -27,6 +33,9 @@ pub enum ImageGenerationRequest {
   FalGptImage2(FalGptImage2RequestState),
   FalNanoBanana2(FalNanoBanana2RequestState),
   FalNanoBananaPro(FalNanoBananaProRequestState),
+  Mock(MockImageRequestState),
+  ReplicateFlux1Dev(ReplicateFlux1DevRequestState),
+  ReplicateFlux1Schnell(ReplicateFlux1SchnellRequestState),
 }
 
 impl ImageGenerationRequest {
@@ -39,6 +48,9 @@ impl ImageGenerationRequest {
       Self::FalGptImage2(_) => Provider::Fal,
       Self::FalNanoBanana2(_) => Provider::Fal,
       Self::FalNanoBananaPro(_) => Provider::Fal,
+      Self::Mock(_) => Provider::Mock,
+      Self::ReplicateFlux1Dev(_) => Provider::Replicate,
+      Self::ReplicateFlux1Schnell(_) => Provider::Replicate,
     }
   }
 
@@ -65,10 +77,24 @@ impl ImageGenerationRequest {
       Self::FalNanoBananaPro(request) => {
         Ok(FalNanoBananaProCostState::from_request(request).estimate_cost())
       }
+      Self::Mock(request) => {
+        Ok(MockImageCostState::from_request(request).estimate_cost())
+      }
+      Self::ReplicateFlux1Dev(request) => {
+        Ok(ReplicateFlux1DevCostState::from_request(request).estimate_cost())
+      }
+      Self::ReplicateFlux1Schnell(request) => {
+        Ok(ReplicateFlux1SchnellCostState::from_request(request).estimate_cost())
+      }
     }
   }
 
+  /// Calls pass through the provider's circuit breaker if the client tracks health.
   pub async fn send_request(&self, client: &RouterClient) -> Result<GenerateImageResponse, ArtcraftRouterError> {
+    client.track_provider_call(self.get_provider(), self.send_request_untracked(client)).await
+  }
+
+  async fn send_request_untracked(&self, client: &RouterClient) -> Result<GenerateImageResponse, ArtcraftRouterError> {
     match self {
       Self::FalFlux1Dev(request) => {
         let fal_client = client.get_fal_webhook_optional_client_ref()?;
@@ -98,6 +124,18 @@ impl ImageGenerationRequest {
         let fal_client = client.get_fal_webhook_optional_client_ref()?;
         request.send(fal_client).await
       }
+      Self::Mock(request) => {
+        let mock_client = client.get_mock_client_ref()?;
+        request.send(mock_client).await
+      }
+      Self::ReplicateFlux1Dev(request) => {
+        let replicate_client = client.get_replicate_client_ref()?;
+        request.send(replicate_client).await
+      }
+      Self::ReplicateFlux1Schnell(request) => {
+        let replicate_client = client.get_replicate_client_ref()?;
+        request.send(replicate_client).await
+      }
     }
   }
 }
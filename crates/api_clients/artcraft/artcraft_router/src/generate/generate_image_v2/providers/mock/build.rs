@@ -0,0 +1,22 @@
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::client_error::ClientError;
+use crate::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
+use crate::generate::generate_image_v2::image_generation_draft_or_request::ImageGenerationDraftOrRequest;
+use crate::generate::generate_image_v2::image_generation_request::ImageGenerationRequest;
+use crate::generate::generate_image_v2::providers::mock::request::MockImageRequestState;
+
+pub fn build_mock_image(
+  builder: GenerateImageRequestBuilder,
+) -> Result<ImageGenerationDraftOrRequest, ArtcraftRouterError> {
+  let image_count = builder.image_batch_count.unwrap_or(1);
+
+  if image_count == 0 {
+    return Err(ArtcraftRouterError::Client(ClientError::UserRequestedZeroGenerations));
+  }
+
+  Ok(ImageGenerationDraftOrRequest::Request(ImageGenerationRequest::Mock(MockImageRequestState {
+    model: builder.model,
+    prompt: builder.prompt,
+    image_count,
+  })))
+}
@@ -0,0 +1,23 @@
+use crate::generate::generate_image::image_generation_cost_estimate::ImageGenerationCostEstimate;
+use crate::generate::generate_image_v2::providers::mock::request::MockImageRequestState;
+
+/// Mock generations are always free.
+pub struct MockImageCostState;
+
+impl MockImageCostState {
+  pub fn from_request(_request: &MockImageRequestState) -> Self {
+    Self
+  }
+
+  pub fn estimate_cost(&self) -> ImageGenerationCostEstimate {
+    ImageGenerationCostEstimate {
+      cost_in_credits: Some(0),
+      cost_in_usd_cents: Some(0),
+      is_free: true,
+      is_unlimited: true,
+      is_rate_limited: false,
+      has_watermark: false,
+      failures_are_refunded: Some(true),
+    }
+  }
+}
@@ -0,0 +1,5 @@
+//! Accepts any image model. See `crate::mock`.
+
+pub mod build;
+pub mod cost;
+pub mod request;
@@ -0,0 +1,85 @@
+use crate::api::common_image_model::CommonImageModel;
+use crate::client::router_mock_client::RouterMockClient;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::provider_error::ProviderError;
+use crate::generate::generate_image::generate_image_response::{
+  GenerateImageResponse, MockImageResponsePayload,
+};
+use crate::mock::mock_media_kind::MockMediaKind;
+
+#[derive(Clone, Debug)]
+pub struct MockImageRequestState {
+  /// The model the caller asked for. The mock ignores it, but it's useful in logs.
+  pub model: CommonImageModel,
+  pub prompt: Option<String>,
+  pub image_count: u16,
+}
+
+impl MockImageRequestState {
+  pub async fn send(&self, client: &RouterMockClient) -> Result<GenerateImageResponse, ArtcraftRouterError> {
+    let all_job_ids = client.submit(MockMediaKind::Image, self.image_count)
+      .map_err(|err| ArtcraftRouterError::Provider(ProviderError::Mock(err)))?;
+
+    Ok(GenerateImageResponse::Mock(MockImageResponsePayload {
+      job_id: all_job_ids[0].clone(),
+      all_job_ids,
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::api::provider::Provider;
+  use crate::client::router_client::RouterClient;
+  use crate::client::router_mock_client::RouterMockClient;
+  use crate::errors::artcraft_router_error::ArtcraftRouterError;
+  use crate::errors::provider_error::ProviderError;
+  use crate::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
+  use crate::generate::generate_image::generate_image_response::GenerateImageResponse;
+  use crate::generate::generate_image_v2::image_generation_draft_or_request::ImageGenerationDraftOrRequest;
+  use crate::mock::mock_failure_mode::MockFailureMode;
+  use crate::mock::mock_job_status::MockJobStatus;
+  use crate::mock::mock_provider_config::MockProviderConfig;
+  use crate::test_helpers::base_image_request;
+  use std::time::Duration;
+
+  fn mock_builder() -> GenerateImageRequestBuilder {
+    GenerateImageRequestBuilder {
+      provider: Provider::Mock,
+      ..base_image_request()
+    }
+  }
+
+  async fn send(client: &RouterClient) -> Result<GenerateImageResponse, ArtcraftRouterError> {
+    let request = match mock_builder().build2().unwrap() {
+      ImageGenerationDraftOrRequest::Request(request) => request,
+      _ => panic!("expected Request variant (Mock skips draft)"),
+    };
+    request.send_request(client).await
+  }
+
+  #[tokio::test]
+  async fn completes_with_placeholder_image() {
+    let mock_client = RouterMockClient::new(MockProviderConfig::instant());
+    let client = RouterClient::Mock(mock_client.clone());
+
+    let payload = send(&client).await.unwrap().get_mock_payload().expect("mock payload");
+    assert_eq!(payload.job_id, "mock_image_00000001");
+
+    let status = mock_client.wait_for_job(&payload.job_id, Duration::from_millis(1)).await.unwrap();
+    match status {
+      MockJobStatus::Completed(output) => assert_eq!(output.mime_type, "image/jpeg"),
+      other => panic!("expected completion, got {:?}", other),
+    }
+  }
+
+  #[tokio::test]
+  async fn rejected_submission_is_a_provider_error() {
+    let client = RouterClient::Mock(RouterMockClient::new(MockProviderConfig {
+      failure_mode: MockFailureMode::RejectAllSubmissions,
+      ..MockProviderConfig::instant()
+    }));
+    let result = send(&client).await;
+    assert!(matches!(result, Err(ArtcraftRouterError::Provider(ProviderError::Mock(_)))));
+  }
+}
@@ -1 +1,3 @@
 pub mod fal;
+pub mod mock;
+pub mod replicate;
@@ -0,0 +1,52 @@
+use replicate_client::requests::api::image::common::flux_aspect_ratio::FluxAspectRatio;
+use replicate_client::requests::api::image::common::flux_num_outputs::FluxNumOutputs;
+
+use crate::api::common_aspect_ratio::CommonAspectRatio;
+use crate::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::client_error::ClientError;
+
+/// Replicate's Flux models take one to four outputs.
+pub(super) fn plan_flux_num_outputs(
+  count: Option<u16>,
+  strategy: RequestMismatchMitigationStrategy,
+) -> Result<FluxNumOutputs, ArtcraftRouterError> {
+  let count = count.unwrap_or(1);
+  match count {
+    0 => Err(ArtcraftRouterError::Client(ClientError::UserRequestedZeroGenerations)),
+    1 => Ok(FluxNumOutputs::One),
+    2 => Ok(FluxNumOutputs::Two),
+    3 => Ok(FluxNumOutputs::Three),
+    4 => Ok(FluxNumOutputs::Four),
+    _ => match strategy {
+      RequestMismatchMitigationStrategy::ErrorOut => {
+        Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+          field: "image_batch_count",
+          value: format!("{}", count),
+        }))
+      }
+      _ => Ok(FluxNumOutputs::Four),
+    },
+  }
+}
+
+/// Replicate's Flux models take exact ratios, so most common ratios map one-to-one.
+/// `None` lets the model pick (1:1).
+pub(super) fn plan_flux_aspect_ratio(aspect_ratio: Option<CommonAspectRatio>) -> Option<FluxAspectRatio> {
+  use FluxAspectRatio as F;
+  match aspect_ratio? {
+    CommonAspectRatio::Auto | CommonAspectRatio::Auto2k
+    | CommonAspectRatio::Auto3k | CommonAspectRatio::Auto4k => None,
+    CommonAspectRatio::Square | CommonAspectRatio::SquareHd => Some(F::Square),
+    CommonAspectRatio::WideSixteenByNine | CommonAspectRatio::Wide => Some(F::WideSixteenNine),
+    CommonAspectRatio::WideTwentyOneByNine => Some(F::WideTwentyOneNine),
+    CommonAspectRatio::WideThreeByTwo => Some(F::WideThreeTwo),
+    CommonAspectRatio::WideFourByThree => Some(F::WideFourThree),
+    CommonAspectRatio::WideFiveByFour => Some(F::WideFiveFour),
+    CommonAspectRatio::TallNineBySixteen | CommonAspectRatio::Tall => Some(F::TallNineSixteen),
+    CommonAspectRatio::TallNineByTwentyOne => Some(F::TallNineTwentyOne),
+    CommonAspectRatio::TallTwoByThree => Some(F::TallTwoThree),
+    CommonAspectRatio::TallThreeByFour => Some(F::TallThreeFour),
+    CommonAspectRatio::TallFourByFive => Some(F::TallFourFive),
+  }
+}
@@ -0,0 +1,164 @@
+use replicate_client::requests::api::image::flux_1_dev::api::Flux1DevRequest;
+
+use crate::api::image_list_ref::ImageListRef;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::client_error::ClientError;
+use crate::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
+use crate::generate::generate_image_v2::image_generation_draft_or_request::ImageGenerationDraftOrRequest;
+use crate::generate::generate_image_v2::image_generation_request::ImageGenerationRequest;
+use crate::generate::generate_image_v2::providers::replicate::common::{plan_flux_aspect_ratio, plan_flux_num_outputs};
+use crate::generate::generate_image_v2::providers::replicate::flux_1_dev::request::ReplicateFlux1DevRequestState;
+
+pub fn build_replicate_flux_1_dev(
+  builder: GenerateImageRequestBuilder,
+) -> Result<ImageGenerationDraftOrRequest, ArtcraftRouterError> {
+  let strategy = builder.request_mismatch_mitigation_strategy;
+
+  let num_outputs = plan_flux_num_outputs(builder.image_batch_count, strategy)?;
+  let image_url = resolve_single_image_url(builder.image_inputs)?;
+
+  // In image-to-image mode the output follows the input's aspect ratio.
+  let aspect_ratio = match image_url {
+    Some(_) => None,
+    None => plan_flux_aspect_ratio(builder.aspect_ratio),
+  };
+
+  let request = Flux1DevRequest {
+    prompt: builder.prompt.unwrap_or_default(),
+    num_outputs,
+    aspect_ratio,
+    image_url,
+    prompt_strength: None,
+    seed: None,
+  };
+
+  Ok(ImageGenerationDraftOrRequest::Request(
+    ImageGenerationRequest::ReplicateFlux1Dev(ReplicateFlux1DevRequestState { request }),
+  ))
+}
+
+/// Flux 1 Dev image-to-image takes a single image URL.
+/// If multiple URLs are provided, we take the first one.
+fn resolve_single_image_url(
+  image_inputs: Option<ImageListRef>,
+) -> Result<Option<String>, ArtcraftRouterError> {
+  match image_inputs {
+    None => Ok(None),
+    Some(ImageListRef::Urls(urls)) => Ok(urls.into_iter().next()),
+    Some(ImageListRef::MediaFileTokens(_)) => {
+      Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+        field: "image_inputs",
+        value: "Replicate only supports image URLs, not media file tokens".to_string(),
+      }))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use replicate_client::requests::api::image::common::flux_aspect_ratio::FluxAspectRatio;
+  use replicate_client::requests::api::image::common::flux_num_outputs::FluxNumOutputs;
+
+  use super::*;
+  use crate::api::common_aspect_ratio::CommonAspectRatio;
+  use crate::api::common_image_model::CommonImageModel;
+  use crate::api::provider::Provider;
+  use crate::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+
+  fn base_builder() -> GenerateImageRequestBuilder {
+    GenerateImageRequestBuilder {
+      model: CommonImageModel::Flux1Dev,
+      provider: Provider::Replicate,
+      prompt: Some("a cat in space".to_string()),
+      image_inputs: None,
+      resolution: None,
+      aspect_ratio: None,
+      quality: None,
+      image_batch_count: None,
+      horizontal_angle: None,
+      vertical_angle: None,
+      zoom: None,
+      request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::ErrorOut,
+      generation_mode_mismatch_strategy: None,
+      idempotency_token: None,
+    }
+  }
+
+  fn unwrap_request(result: Result<ImageGenerationDraftOrRequest, ArtcraftRouterError>) -> Flux1DevRequest {
+    let ImageGenerationDraftOrRequest::Request(
+      ImageGenerationRequest::ReplicateFlux1Dev(state)
+    ) = result.expect("build should succeed") else {
+      panic!("expected ReplicateFlux1Dev variant")
+    };
+    state.request
+  }
+
+  #[test]
+  fn text_to_image_defaults() {
+    let req = unwrap_request(build_replicate_flux_1_dev(base_builder()));
+    assert_eq!(req.prompt, "a cat in space");
+    assert_eq!(req.num_outputs, FluxNumOutputs::One);
+    assert_eq!(req.aspect_ratio, None);
+    assert_eq!(req.image_url, None);
+  }
+
+  #[test]
+  fn aspect_ratio_maps_exactly() {
+    let req = unwrap_request(build_replicate_flux_1_dev(GenerateImageRequestBuilder {
+      aspect_ratio: Some(CommonAspectRatio::WideTwentyOneByNine),
+      ..base_builder()
+    }));
+    assert_eq!(req.aspect_ratio, Some(FluxAspectRatio::WideTwentyOneNine));
+  }
+
+  #[test]
+  fn image_url_switches_to_image_to_image() {
+    let req = unwrap_request(build_replicate_flux_1_dev(GenerateImageRequestBuilder {
+      image_inputs: Some(ImageListRef::Urls(vec![
+        "https://example.com/a.jpg".to_string(),
+        "https://example.com/b.jpg".to_string(),
+      ])),
+      aspect_ratio: Some(CommonAspectRatio::Square),
+      ..base_builder()
+    }));
+    assert_eq!(req.image_url.as_deref(), Some("https://example.com/a.jpg"));
+    assert_eq!(req.aspect_ratio, None);
+  }
+
+  #[test]
+  fn media_tokens_return_error() {
+    let result = build_replicate_flux_1_dev(GenerateImageRequestBuilder {
+      image_inputs: Some(ImageListRef::MediaFileTokens(vec![])),
+      ..base_builder()
+    });
+    assert!(matches!(result, Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption { .. }))));
+  }
+
+  #[test]
+  fn zero_is_error() {
+    let result = build_replicate_flux_1_dev(GenerateImageRequestBuilder {
+      image_batch_count: Some(0),
+      ..base_builder()
+    });
+    assert!(matches!(result, Err(ArtcraftRouterError::Client(ClientError::UserRequestedZeroGenerations))));
+  }
+
+  #[test]
+  fn over_four_error_out() {
+    let result = build_replicate_flux_1_dev(GenerateImageRequestBuilder {
+      image_batch_count: Some(7),
+      ..base_builder()
+    });
+    assert!(matches!(result, Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption { .. }))));
+  }
+
+  #[test]
+  fn over_four_clamps_with_upgrade() {
+    let req = unwrap_request(build_replicate_flux_1_dev(GenerateImageRequestBuilder {
+      image_batch_count: Some(7),
+      request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+      ..base_builder()
+    }));
+    assert_eq!(req.num_outputs, FluxNumOutputs::Four);
+  }
+}
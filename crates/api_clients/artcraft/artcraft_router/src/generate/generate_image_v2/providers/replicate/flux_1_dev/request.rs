@@ -0,0 +1,60 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use replicate_client::requests::api::image::flux_1_dev::api::Flux1DevRequest;
+
+use crate::client::router_replicate_client::RouterReplicateClient;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::generate::generate_image::generate_image_response::{
+  GenerateImageResponse, ReplicateImageResponsePayload,
+};
+
+#[derive(Clone, Debug)]
+pub struct ReplicateFlux1DevRequestState {
+  pub request: Flux1DevRequest,
+}
+
+impl ReplicateFlux1DevRequestState {
+  pub async fn send(&self, client: &RouterReplicateClient) -> Result<GenerateImageResponse, ArtcraftRouterError> {
+    let outbound: Arc<dyn Debug + Send + Sync> = Arc::new(self.request.clone());
+    let prediction = client.create_prediction(&self.request).await?;
+
+    Ok(GenerateImageResponse::Replicate(ReplicateImageResponsePayload {
+      prediction_id: prediction.id,
+      maybe_outbound_request: Some(outbound),
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use replicate_client::requests::api::image::common::flux_aspect_ratio::FluxAspectRatio;
+  use replicate_client::requests::api::image::common::flux_num_outputs::FluxNumOutputs;
+
+  use super::*;
+
+  fn client() -> RouterReplicateClient {
+    let secret = std::fs::read_to_string("/Users/bt/Artcraft/credentials/replicate_api_key.txt")
+      .expect("Failed to read replicate_api_key.txt");
+    RouterReplicateClient::from_str(&secret)
+  }
+
+  #[tokio::test]
+  #[ignore] // requires real API key, incurs cost
+  async fn send_text_to_image() {
+    let state = ReplicateFlux1DevRequestState {
+      request: Flux1DevRequest {
+        prompt: "a corgi wearing sunglasses on a surfboard".to_string(),
+        num_outputs: FluxNumOutputs::One,
+        aspect_ratio: Some(FluxAspectRatio::WideSixteenNine),
+        image_url: None,
+        prompt_strength: None,
+        seed: None,
+      },
+    };
+    let response = state.send(&client()).await.expect("send should succeed");
+    let payload = response.get_replicate_payload().expect("expected Replicate payload");
+    println!("prediction_id: {}", payload.prediction_id);
+    assert!(!payload.prediction_id.is_empty());
+  }
+}
@@ -0,0 +1,131 @@
+use replicate_client::requests::api::image::flux_1_schnell::api::Flux1SchnellRequest;
+
+use crate::api::image_list_ref::ImageListRef;
+use crate::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::client_error::ClientError;
+use crate::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
+use crate::generate::generate_image_v2::image_generation_draft_or_request::ImageGenerationDraftOrRequest;
+use crate::generate::generate_image_v2::image_generation_request::ImageGenerationRequest;
+use crate::generate::generate_image_v2::providers::replicate::common::{plan_flux_aspect_ratio, plan_flux_num_outputs};
+use crate::generate::generate_image_v2::providers::replicate::flux_1_schnell::request::ReplicateFlux1SchnellRequestState;
+
+pub fn build_replicate_flux_1_schnell(
+  builder: GenerateImageRequestBuilder,
+) -> Result<ImageGenerationDraftOrRequest, ArtcraftRouterError> {
+  let strategy = builder.request_mismatch_mitigation_strategy;
+
+  check_no_image_inputs(builder.image_inputs.as_ref(), strategy)?;
+
+  let request = Flux1SchnellRequest {
+    prompt: builder.prompt.unwrap_or_default(),
+    num_outputs: plan_flux_num_outputs(builder.image_batch_count, strategy)?,
+    aspect_ratio: plan_flux_aspect_ratio(builder.aspect_ratio),
+    seed: None,
+  };
+
+  Ok(ImageGenerationDraftOrRequest::Request(
+    ImageGenerationRequest::ReplicateFlux1Schnell(ReplicateFlux1SchnellRequestState { request }),
+  ))
+}
+
+/// Replicate's Flux Schnell is text-to-image only.
+fn check_no_image_inputs(
+  image_inputs: Option<&ImageListRef>,
+  strategy: RequestMismatchMitigationStrategy,
+) -> Result<(), ArtcraftRouterError> {
+  let has_images = match image_inputs {
+    None => false,
+    Some(ImageListRef::Urls(urls)) => !urls.is_empty(),
+    Some(ImageListRef::MediaFileTokens(tokens)) => !tokens.is_empty(),
+  };
+
+  match (has_images, strategy) {
+    (true, RequestMismatchMitigationStrategy::ErrorOut) => {
+      Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+        field: "image_inputs",
+        value: "Flux 1 Schnell on Replicate is text-to-image only".to_string(),
+      }))
+    }
+    _ => Ok(()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use replicate_client::requests::api::image::common::flux_aspect_ratio::FluxAspectRatio;
+  use replicate_client::requests::api::image::common::flux_num_outputs::FluxNumOutputs;
+
+  use super::*;
+  use crate::api::common_aspect_ratio::CommonAspectRatio;
+  use crate::api::common_image_model::CommonImageModel;
+  use crate::api::provider::Provider;
+
+  fn base_builder() -> GenerateImageRequestBuilder {
+    GenerateImageRequestBuilder {
+      model: CommonImageModel::Flux1Schnell,
+      provider: Provider::Replicate,
+      prompt: Some("a cat in space".to_string()),
+      image_inputs: None,
+      resolution: None,
+      aspect_ratio: None,
+      quality: None,
+      image_batch_count: None,
+      horizontal_angle: None,
+      vertical_angle: None,
+      zoom: None,
+      request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::ErrorOut,
+      generation_mode_mismatch_strategy: None,
+      idempotency_token: None,
+    }
+  }
+
+  fn unwrap_request(result: Result<ImageGenerationDraftOrRequest, ArtcraftRouterError>) -> Flux1SchnellRequest {
+    let ImageGenerationDraftOrRequest::Request(
+      ImageGenerationRequest::ReplicateFlux1Schnell(state)
+    ) = result.expect("build should succeed") else {
+      panic!("expected ReplicateFlux1Schnell variant")
+    };
+    state.request
+  }
+
+  #[test]
+  fn fields_passed_through() {
+    let req = unwrap_request(build_replicate_flux_1_schnell(GenerateImageRequestBuilder {
+      aspect_ratio: Some(CommonAspectRatio::TallNineBySixteen),
+      image_batch_count: Some(3),
+      ..base_builder()
+    }));
+    assert_eq!(req.prompt, "a cat in space");
+    assert_eq!(req.num_outputs, FluxNumOutputs::Three);
+    assert_eq!(req.aspect_ratio, Some(FluxAspectRatio::TallNineSixteen));
+  }
+
+  #[test]
+  fn image_inputs_error_out() {
+    let result = build_replicate_flux_1_schnell(GenerateImageRequestBuilder {
+      image_inputs: Some(ImageListRef::Urls(vec!["https://example.com/a.jpg".to_string()])),
+      ..base_builder()
+    });
+    assert!(matches!(result, Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption { .. }))));
+  }
+
+  #[test]
+  fn image_inputs_dropped_when_not_erroring() {
+    let req = unwrap_request(build_replicate_flux_1_schnell(GenerateImageRequestBuilder {
+      image_inputs: Some(ImageListRef::Urls(vec!["https://example.com/a.jpg".to_string()])),
+      request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+      ..base_builder()
+    }));
+    assert_eq!(req.prompt, "a cat in space");
+  }
+
+  #[test]
+  fn empty_image_list_is_fine() {
+    let result = build_replicate_flux_1_schnell(GenerateImageRequestBuilder {
+      image_inputs: Some(ImageListRef::Urls(vec![])),
+      ..base_builder()
+    });
+    assert!(result.is_ok());
+  }
+}
@@ -0,0 +1,70 @@
+use replicate_client::requests::traits::replicate_request_cost_calculator_trait::ReplicateRequestCostCalculator;
+
+use crate::generate::generate_image::image_generation_cost_estimate::ImageGenerationCostEstimate;
+use crate::generate::generate_image_v2::providers::replicate::flux_1_schnell::request::ReplicateFlux1SchnellRequestState;
+
+pub struct ReplicateFlux1SchnellCostState {
+  cost_in_usd_cents: u64,
+}
+
+impl ReplicateFlux1SchnellCostState {
+  pub fn from_request(request: &ReplicateFlux1SchnellRequestState) -> Self {
+    Self { cost_in_usd_cents: request.request.calculate_cost_in_cents() }
+  }
+
+  pub fn estimate_cost(&self) -> ImageGenerationCostEstimate {
+    ImageGenerationCostEstimate {
+      cost_in_credits: None,
+      cost_in_usd_cents: Some(self.cost_in_usd_cents),
+      is_free: false,
+      is_unlimited: false,
+      is_rate_limited: false,
+      has_watermark: false,
+      failures_are_refunded: None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::api::common_image_model::CommonImageModel;
+  use crate::api::provider::Provider;
+  use crate::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+  use crate::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
+
+  fn cost_cents(image_batch_count: u16) -> u64 {
+    let builder = GenerateImageRequestBuilder {
+      model: CommonImageModel::Flux1Schnell,
+      provider: Provider::Replicate,
+      prompt: Some("test".to_string()),
+      image_inputs: None,
+      resolution: None,
+      aspect_ratio: None,
+      quality: None,
+      image_batch_count: Some(image_batch_count),
+      horizontal_angle: None,
+      vertical_angle: None,
+      zoom: None,
+      request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::ErrorOut,
+      generation_mode_mismatch_strategy: None,
+      idempotency_token: None,
+    };
+    builder.build2()
+      .expect("build2 should succeed")
+      .estimate_cost()
+      .expect("estimate_cost should succeed")
+      .cost_in_usd_cents
+      .unwrap()
+  }
+
+  #[test]
+  fn one_image() {
+    // $0.003, rounded up
+    assert_eq!(cost_cents(1), 1);
+  }
+
+  #[test]
+  fn four_images() {
+    assert_eq!(cost_cents(4), 2);
+  }
+}
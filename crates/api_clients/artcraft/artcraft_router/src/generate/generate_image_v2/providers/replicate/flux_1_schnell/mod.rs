@@ -0,0 +1,3 @@
+pub mod build;
+pub mod cost;
+pub mod request;
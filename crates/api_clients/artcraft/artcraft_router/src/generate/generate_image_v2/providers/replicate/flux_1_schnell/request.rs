@@ -0,0 +1,27 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use replicate_client::requests::api::image::flux_1_schnell::api::Flux1SchnellRequest;
+
+use crate::client::router_replicate_client::RouterReplicateClient;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::generate::generate_image::generate_image_response::{
+  GenerateImageResponse, ReplicateImageResponsePayload,
+};
+
+#[derive(Clone, Debug)]
+pub struct ReplicateFlux1SchnellRequestState {
+  pub request: Flux1SchnellRequest,
+}
+
+impl ReplicateFlux1SchnellRequestState {
+  pub async fn send(&self, client: &RouterReplicateClient) -> Result<GenerateImageResponse, ArtcraftRouterError> {
+    let outbound: Arc<dyn Debug + Send + Sync> = Arc::new(self.request.clone());
+    let prediction = client.create_prediction(&self.request).await?;
+
+    Ok(GenerateImageResponse::Replicate(ReplicateImageResponsePayload {
+      prediction_id: prediction.id,
+      maybe_outbound_request: Some(outbound),
+    }))
+  }
+}
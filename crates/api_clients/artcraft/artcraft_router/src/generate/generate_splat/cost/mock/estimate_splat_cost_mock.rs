@@ -0,0 +1,16 @@
+use crate::generate::generate_splat::plan::mock::plan_generate_splat_mock::PlanMockSplat;
+use crate::generate::generate_splat::splat_generation_cost_estimate::SplatGenerationCostEstimate;
+
+pub(crate) fn estimate_splat_cost_mock(
+  _plan: &PlanMockSplat,
+) -> SplatGenerationCostEstimate {
+  SplatGenerationCostEstimate {
+    cost_in_credits: Some(0),
+    cost_in_usd_cents: Some(0),
+    is_free: true,
+    is_unlimited: true,
+    is_rate_limited: false,
+    has_watermark: false,
+    failures_are_refunded: Some(true),
+  }
+}
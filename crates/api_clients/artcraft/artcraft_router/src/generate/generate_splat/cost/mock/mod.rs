@@ -0,0 +1 @@
+pub mod estimate_splat_cost_mock;
@@ -1 +1,2 @@
-pub(crate) mod artcraft;
+pub mod artcraft;
+pub mod mock;
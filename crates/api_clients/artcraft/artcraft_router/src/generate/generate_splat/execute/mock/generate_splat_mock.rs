@@ -0,0 +1,55 @@
+use crate::client::router_mock_client::RouterMockClient;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::provider_error::ProviderError;
+use crate::generate::generate_splat::generate_splat_response::{
+  GenerateSplatResponse, MockSplatResponsePayload,
+};
+use crate::generate::generate_splat::plan::mock::plan_generate_splat_mock::PlanMockSplat;
+use crate::mock::mock_media_kind::MockMediaKind;
+
+pub async fn execute_mock_splat(
+  _plan: &PlanMockSplat,
+  mock_client: &RouterMockClient,
+) -> Result<GenerateSplatResponse, ArtcraftRouterError> {
+  let job_id = mock_client.submit(MockMediaKind::Splat, 1)
+    .map_err(|err| ArtcraftRouterError::Provider(ProviderError::Mock(err)))?
+    .remove(0);
+
+  Ok(GenerateSplatResponse::Mock(MockSplatResponsePayload { job_id }))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::api::common_splat_model::CommonSplatModel;
+  use crate::api::provider::Provider;
+  use crate::client::router_client::RouterClient;
+  use crate::client::router_mock_client::RouterMockClient;
+  use crate::generate::generate_splat::generate_splat_request::GenerateSplatRequest;
+  use crate::mock::mock_job_status::MockJobStatus;
+  use crate::mock::mock_provider_config::MockProviderConfig;
+
+  #[tokio::test]
+  async fn completes_with_placeholder_splat() {
+    let mock_client = RouterMockClient::new(MockProviderConfig::instant());
+    let client = RouterClient::Mock(mock_client.clone());
+
+    let plan = GenerateSplatRequest {
+      model: CommonSplatModel::Marble0p1Plus,
+      provider: Provider::Mock,
+      prompt: Some("a cozy room".to_string()),
+      reference_images: None,
+      idempotency_token: None,
+    }.build().expect("build should succeed");
+
+    assert!(plan.estimate_costs().is_free);
+
+    let response = plan.generate_splat(&client).await.expect("generate should succeed");
+    let payload = response.get_mock_payload().expect("mock payload");
+    assert_eq!(payload.job_id, "mock_splat_00000001");
+
+    match mock_client.get_job_status(&payload.job_id).unwrap() {
+      MockJobStatus::Completed(output) => assert!(output.media_path.ends_with("test_data/splat/ply/placeholder_cube.ply")),
+      other => panic!("expected completion, got {:?}", other),
+    }
+  }
+}
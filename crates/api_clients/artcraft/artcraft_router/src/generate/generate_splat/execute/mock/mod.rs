@@ -0,0 +1 @@
+pub mod generate_splat_mock;
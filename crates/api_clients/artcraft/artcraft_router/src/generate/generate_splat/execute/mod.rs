@@ -1 +1,2 @@
 pub mod artcraft;
+pub mod mock;
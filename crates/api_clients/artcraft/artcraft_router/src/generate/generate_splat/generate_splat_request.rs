@@ -4,6 +4,7 @@ use crate::api::provider::Provider;
 use crate::errors::artcraft_router_error::ArtcraftRouterError;
 use crate::generate::generate_splat::plan::artcraft::plan_generate_splat_artcraft_marble_0p1_mini::plan_generate_splat_artcraft_marble_0p1_mini;
 use crate::generate::generate_splat::plan::artcraft::plan_generate_splat_artcraft_marble_0p1_plus::plan_generate_splat_artcraft_marble_0p1_plus;
+use crate::generate::generate_splat::plan::mock::plan_generate_splat_mock::plan_generate_splat_mock;
 use crate::generate::generate_splat::splat_generation_plan::SplatGenerationPlan;
 
 pub struct GenerateSplatRequest {
@@ -29,6 +30,7 @@ impl GenerateSplatRequest {
   pub fn build(&self) -> Result<SplatGenerationPlan, ArtcraftRouterError> {
     match self.provider {
       Provider::Artcraft => self.build_artcraft(),
+      Provider::Mock => plan_generate_splat_mock(self),
       _ => self.unsupported_provider(),
     }
   }
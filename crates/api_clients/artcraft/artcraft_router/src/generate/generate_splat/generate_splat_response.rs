@@ -5,15 +5,29 @@ pub struct ArtcraftSplatResponsePayload {
   pub inference_job_token: InferenceJobToken,
 }
 
+#[derive(Clone, Debug)]
+pub struct MockSplatResponsePayload {
+  pub job_id: String,
+}
+
 #[derive(Clone, Debug)]
 pub enum GenerateSplatResponse {
   Artcraft(ArtcraftSplatResponsePayload),
+  Mock(MockSplatResponsePayload),
 }
 
 impl GenerateSplatResponse {
   pub fn get_artcraft_payload(&self) -> Option<ArtcraftSplatResponsePayload> {
     match self {
       Self::Artcraft(p) => Some(p.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_mock_payload(&self) -> Option<MockSplatResponsePayload> {
+    match self {
+      Self::Mock(p) => Some(p.clone()),
+      _ => None,
     }
   }
 }
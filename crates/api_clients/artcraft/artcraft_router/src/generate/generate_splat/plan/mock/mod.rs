@@ -0,0 +1 @@
+pub mod plan_generate_splat_mock;
@@ -0,0 +1,20 @@
+use crate::api::common_splat_model::CommonSplatModel;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::generate::generate_splat::generate_splat_request::GenerateSplatRequest;
+use crate::generate::generate_splat::splat_generation_plan::SplatGenerationPlan;
+
+#[derive(Debug, Clone)]
+pub struct PlanMockSplat {
+  /// The model the caller asked for. The mock ignores it, but it's useful in logs.
+  pub model: CommonSplatModel,
+  pub prompt: Option<String>,
+}
+
+pub fn plan_generate_splat_mock(
+  request: &GenerateSplatRequest,
+) -> Result<SplatGenerationPlan, ArtcraftRouterError> {
+  Ok(SplatGenerationPlan::Mock(PlanMockSplat {
+    model: request.model,
+    prompt: request.prompt.clone(),
+  }))
+}
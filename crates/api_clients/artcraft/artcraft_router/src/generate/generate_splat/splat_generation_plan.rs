@@ -2,17 +2,21 @@ use crate::client::router_client::RouterClient;
 use crate::errors::artcraft_router_error::ArtcraftRouterError;
 use crate::generate::generate_splat::cost::artcraft::estimate_splat_cost_artcraft_marble_0p1_mini::estimate_splat_cost_artcraft_marble_0p1_mini;
 use crate::generate::generate_splat::cost::artcraft::estimate_splat_cost_artcraft_marble_0p1_plus::estimate_splat_cost_artcraft_marble_0p1_plus;
+use crate::generate::generate_splat::cost::mock::estimate_splat_cost_mock::estimate_splat_cost_mock;
 use crate::generate::generate_splat::execute::artcraft::generate_splat_artcraft_marble_0p1_mini::execute_artcraft_marble_0p1_mini;
 use crate::generate::generate_splat::execute::artcraft::generate_splat_artcraft_marble_0p1_plus::execute_artcraft_marble_0p1_plus;
+use crate::generate::generate_splat::execute::mock::generate_splat_mock::execute_mock_splat;
 use crate::generate::generate_splat::generate_splat_response::GenerateSplatResponse;
 use crate::generate::generate_splat::plan::artcraft::plan_generate_splat_artcraft_marble_0p1_mini::PlanArtcraftMarble0p1Mini;
 use crate::generate::generate_splat::plan::artcraft::plan_generate_splat_artcraft_marble_0p1_plus::PlanArtcraftMarble0p1Plus;
+use crate::generate::generate_splat::plan::mock::plan_generate_splat_mock::PlanMockSplat;
 use crate::generate::generate_splat::splat_generation_cost_estimate::SplatGenerationCostEstimate;
 
 #[derive(Debug)]
 pub enum SplatGenerationPlan {
   ArtcraftMarble0p1Mini(PlanArtcraftMarble0p1Mini),
   ArtcraftMarble0p1Plus(PlanArtcraftMarble0p1Plus),
+  Mock(PlanMockSplat),
 }
 
 impl SplatGenerationPlan {
@@ -29,6 +33,10 @@ impl SplatGenerationPlan {
         let artcraft_client = client.get_artcraft_client_ref()?;
         execute_artcraft_marble_0p1_plus(plan, artcraft_client).await
       }
+      SplatGenerationPlan::Mock(plan) => {
+        let mock_client = client.get_mock_client_ref()?;
+        execute_mock_splat(plan, mock_client).await
+      }
     }
   }
 
@@ -40,6 +48,9 @@ impl SplatGenerationPlan {
       SplatGenerationPlan::ArtcraftMarble0p1Plus(plan) => {
         estimate_splat_cost_artcraft_marble_0p1_plus(plan)
       }
+      SplatGenerationPlan::Mock(plan) => {
+        estimate_splat_cost_mock(plan)
+      }
     }
   }
 }
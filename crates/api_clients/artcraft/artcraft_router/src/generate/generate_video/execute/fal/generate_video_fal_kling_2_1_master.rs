@@ -38,6 +38,7 @@ pub async fn execute_fal_kling_2_1_master(
   Ok(GenerateVideoResponse::Fal(FalVideoResponsePayload {
     request_id: webhook_response.request_id,
     gateway_request_id: webhook_response.gateway_request_id,
+    maybe_status_url: webhook_response.status_url,
     maybe_outbound_request: Some(outbound_request),
   }))
 }
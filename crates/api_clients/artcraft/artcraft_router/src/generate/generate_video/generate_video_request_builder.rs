@@ -59,6 +59,8 @@ use crate::generate::generate_video_v2::providers::kinovi::seedance_2p0::build::
 use crate::generate::generate_video_v2::providers::gmicloud::seedance_2p0_g::build::build_gmicloud_seedance_2p0_g;
 use crate::generate::generate_video_v2::providers::gmicloud::seedance_2p0_fast_g::build::build_gmicloud_seedance_2p0_fast_g;
 use crate::generate::generate_video_v2::providers::kinovi::seedance_2p0_fast::build::build_kinovi_seedance_2p0_fast;
+use crate::generate::generate_video_v2::providers::mock::build::build_mock_video;
+use crate::generate::generate_video_v2::providers::replicate::seedance_1p0_lite::build::build_replicate_seedance_1p0_lite;
 use crate::generate::generate_video_v2::video_generation_draft_or_request::VideoGenerationDraftOrRequest;
 
 /// Plan to either (1) generate a video or (2) determine how much it costs to generate that video.
@@ -163,6 +165,8 @@ impl GenerateVideoRequestBuilder {
       (Provider::Seedance2Pro, CommonVideoModel::HappyHorse1p0) => true,
       (Provider::Seedance2Pro, CommonVideoModel::Seedance2p0) => true,
       (Provider::Seedance2Pro, CommonVideoModel::Seedance2p0Fast) => true,
+      (Provider::Mock, _) => true,
+      (Provider::Replicate, CommonVideoModel::Seedance10Lite) => true,
       _ => false,
     }
   }
@@ -182,6 +186,8 @@ impl GenerateVideoRequestBuilder {
       (Provider::Seedance2Pro, CommonVideoModel::HappyHorse1p0) => build_kinovi_happy_horse_1p0(self),
       (Provider::Seedance2Pro, CommonVideoModel::Seedance2p0) => build_kinovi_seedance_2p0(self),
       (Provider::Seedance2Pro, CommonVideoModel::Seedance2p0Fast) => build_kinovi_seedance_2p0_fast(self),
+      (Provider::Mock, _) => build_mock_video(self),
+      (Provider::Replicate, CommonVideoModel::Seedance10Lite) => build_replicate_seedance_1p0_lite(self),
       _ => self.unsupported_provider_and_model(),
     }
   }
@@ -192,7 +198,9 @@ impl GenerateVideoRequestBuilder {
       Provider::Artcraft => self.build_artcraft(),
       Provider::Fal => self.build_fal(),
       Provider::GmiCloud => self.unsupported_provider(), // GmiCloud uses build2() only
+      Provider::Mock => self.unsupported_provider(), // Mock uses build2() only
       Provider::Muapi => self.build_muapi(),
+      Provider::Replicate => self.unsupported_provider(), // Replicate uses build2() only
       Provider::Seedance2Pro => self.build_seedance2pro(),
     }
   }
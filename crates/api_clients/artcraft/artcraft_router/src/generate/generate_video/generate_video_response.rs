@@ -28,6 +28,9 @@ pub struct FalVideoResponsePayload {
   pub request_id: Option<String>,
   pub gateway_request_id: Option<String>,
 
+  /// The queue status URL, used to cancel the request.
+  pub maybe_status_url: Option<String>,
+
   /// The outbound request that was sent to Fal.
   /// Stored as a trait object so any Request type can be captured.
   /// Use `format!("{:?}", ...)` or `format!("{:#?}", ...)` to print.
@@ -39,6 +42,22 @@ pub struct GmiCloudVideoResponsePayload {
   pub request_id: String,
 }
 
+#[derive(Clone, Debug)]
+pub struct ReplicateVideoResponsePayload {
+  pub prediction_id: String,
+
+  /// The outbound request that was sent to Replicate.
+  /// Stored as a trait object so any Request type can be captured.
+  /// Use `format!("{:?}", ...)` or `format!("{:#?}", ...)` to print.
+  pub maybe_outbound_request: Option<Arc<dyn Debug + Send + Sync>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MockVideoResponsePayload {
+  pub job_id: String,
+  pub all_job_ids: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub enum GenerateVideoResponse {
   Artcraft(ArtcraftVideoResponsePayload),
@@ -46,6 +65,8 @@ pub enum GenerateVideoResponse {
   Muapi(MuapiVideoResponsePayload),
   Seedance2Pro(Seedance2proVideoResponsePayload),
   Fal(FalVideoResponsePayload),
+  Mock(MockVideoResponsePayload),
+  Replicate(ReplicateVideoResponsePayload),
 }
 
 impl GenerateVideoResponse {
@@ -83,4 +104,18 @@ impl GenerateVideoResponse {
       _ => None,
     }
   }
+
+  pub fn get_mock_payload(&self) -> Option<MockVideoResponsePayload> {
+    match self {
+      Self::Mock(p) => Some(p.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_replicate_payload(&self) -> Option<ReplicateVideoResponsePayload> {
+    match self {
+      Self::Replicate(p) => Some(p.clone()),
+      _ => None,
+    }
+  }
 }
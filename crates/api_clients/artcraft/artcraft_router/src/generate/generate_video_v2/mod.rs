@@ -2,4 +2,5 @@ pub mod providers;
 pub mod video_generation_request;
 pub mod video_generation_draft;
 pub mod video_generation_draft_or_request;
-pub mod video_generation_draft_context;
\ No newline at end of file
+pub mod video_generation_draft_context;
+pub mod video_generation_failover;
@@ -0,0 +1,92 @@
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::client_error::ClientError;
+use crate::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+use crate::generate::generate_video_v2::providers::mock::request::MockVideoRequestState;
+use crate::generate::generate_video_v2::video_generation_draft_or_request::VideoGenerationDraftOrRequest;
+use crate::generate::generate_video_v2::video_generation_request::VideoGenerationRequest;
+
+pub fn build_mock_video(
+  builder: GenerateVideoRequestBuilder,
+) -> Result<VideoGenerationDraftOrRequest, ArtcraftRouterError> {
+  let video_count = builder.video_batch_count.unwrap_or(1);
+
+  if video_count == 0 {
+    return Err(ArtcraftRouterError::Client(ClientError::UserRequestedZeroGenerations));
+  }
+
+  Ok(VideoGenerationDraftOrRequest::Request(VideoGenerationRequest::Mock(MockVideoRequestState {
+    model: builder.model,
+    prompt: builder.prompt,
+    video_count,
+  })))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::common_video_model::CommonVideoModel;
+  use crate::api::provider::Provider;
+  use crate::client::router_client::RouterClient;
+  use crate::client::router_mock_client::RouterMockClient;
+  use crate::generate::generate_video::generate_video_response::GenerateVideoResponse;
+  use crate::mock::mock_provider_config::MockProviderConfig;
+
+  fn mock_builder(model: CommonVideoModel) -> GenerateVideoRequestBuilder {
+    GenerateVideoRequestBuilder {
+      model,
+      provider: Provider::Mock,
+      prompt: Some("A corgi running through a field of wildflowers at sunset.".to_string()),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn any_model_uses_new_builder() {
+    assert!(mock_builder(CommonVideoModel::Veo3).use_new_builder());
+    assert!(mock_builder(CommonVideoModel::Seedance2p0).use_new_builder());
+  }
+
+  #[test]
+  fn zero_videos_is_an_error() {
+    let builder = GenerateVideoRequestBuilder {
+      video_batch_count: Some(0),
+      ..mock_builder(CommonVideoModel::Veo3)
+    };
+    assert!(builder.build2().is_err());
+  }
+
+  #[test]
+  fn mock_is_free() {
+    let cost = mock_builder(CommonVideoModel::Sora2Pro).build2().unwrap().estimate_cost().unwrap();
+    assert!(cost.is_free);
+    assert_eq!(cost.cost_in_usd_cents, Some(0));
+  }
+
+  #[tokio::test]
+  async fn send_returns_mock_job_ids() {
+    let client = RouterClient::Mock(RouterMockClient::new(MockProviderConfig::instant()));
+    let builder = GenerateVideoRequestBuilder {
+      video_batch_count: Some(2),
+      ..mock_builder(CommonVideoModel::Kling2p6Pro)
+    };
+    let request = match builder.build2().unwrap() {
+      VideoGenerationDraftOrRequest::Request(request) => request,
+      _ => panic!("expected Request variant (Mock skips draft)"),
+    };
+    let response = request.send_request(&client).await.unwrap();
+    let payload = response.get_mock_payload().expect("mock payload");
+    assert_eq!(payload.job_id, "mock_video_00000001");
+    assert_eq!(payload.all_job_ids, vec!["mock_video_00000001", "mock_video_00000002"]);
+    assert!(matches!(response, GenerateVideoResponse::Mock(_)));
+  }
+
+  #[tokio::test]
+  async fn send_without_mock_client_fails() {
+    let client = RouterClient::Multi(crate::client::multi_router_client_builder::MultiRouterClientBuilder::new().build());
+    let request = match mock_builder(CommonVideoModel::Veo3).build2().unwrap() {
+      VideoGenerationDraftOrRequest::Request(request) => request,
+      _ => panic!("expected Request variant"),
+    };
+    assert!(request.send_request(&client).await.is_err());
+  }
+}
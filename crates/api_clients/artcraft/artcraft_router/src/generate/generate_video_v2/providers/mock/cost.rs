@@ -0,0 +1,23 @@
+use crate::generate::generate_video::video_generation_cost_estimate::VideoGenerationCostEstimate;
+use crate::generate::generate_video_v2::providers::mock::request::MockVideoRequestState;
+
+/// Mock generations are always free.
+pub struct MockVideoCostState;
+
+impl MockVideoCostState {
+  pub fn from_request(_request: &MockVideoRequestState) -> Self {
+    Self
+  }
+
+  pub fn estimate_cost(&self) -> VideoGenerationCostEstimate {
+    VideoGenerationCostEstimate {
+      cost_in_credits: Some(0),
+      cost_in_usd_cents: Some(0),
+      is_free: true,
+      is_unlimited: true,
+      is_rate_limited: false,
+      has_watermark: false,
+      failures_are_refunded: Some(true),
+    }
+  }
+}
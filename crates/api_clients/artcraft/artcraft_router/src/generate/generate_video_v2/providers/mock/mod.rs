@@ -0,0 +1,5 @@
+//! Accepts any video model. See `crate::mock`.
+
+pub mod build;
+pub mod cost;
+pub mod request;
@@ -0,0 +1,28 @@
+use crate::api::common_video_model::CommonVideoModel;
+use crate::client::router_mock_client::RouterMockClient;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::provider_error::ProviderError;
+use crate::generate::generate_video::generate_video_response::{
+  GenerateVideoResponse, MockVideoResponsePayload,
+};
+use crate::mock::mock_media_kind::MockMediaKind;
+
+#[derive(Clone, Debug)]
+pub struct MockVideoRequestState {
+  /// The model the caller asked for. The mock ignores it, but it's useful in logs.
+  pub model: CommonVideoModel,
+  pub prompt: Option<String>,
+  pub video_count: u16,
+}
+
+impl MockVideoRequestState {
+  pub async fn send(&self, client: &RouterMockClient) -> Result<GenerateVideoResponse, ArtcraftRouterError> {
+    let all_job_ids = client.submit(MockMediaKind::Video, self.video_count)
+      .map_err(|err| ArtcraftRouterError::Provider(ProviderError::Mock(err)))?;
+
+    Ok(GenerateVideoResponse::Mock(MockVideoResponsePayload {
+      job_id: all_job_ids[0].clone(),
+      all_job_ids,
+    }))
+  }
+}
@@ -1,3 +1,5 @@
 pub mod artcraft;
 pub mod gmicloud;
-pub mod kinovi;
\ No newline at end of file
+pub mod kinovi;
+pub mod mock;
+pub mod replicate;
@@ -0,0 +1 @@
+pub mod seedance_1p0_lite;
@@ -0,0 +1,299 @@
+use replicate_client::requests::api::video::seedance_1_lite::api::{
+  Seedance1LiteAspectRatio, Seedance1LiteRequest, Seedance1LiteResolution,
+  SEEDANCE_1_LITE_MAX_DURATION_SECONDS, SEEDANCE_1_LITE_MAX_REFERENCE_IMAGES, SEEDANCE_1_LITE_MIN_DURATION_SECONDS,
+};
+
+use crate::api::common_aspect_ratio::CommonAspectRatio;
+use crate::api::common_resolution::CommonResolution;
+use crate::api::image_list_ref::ImageListRef;
+use crate::api::image_ref::ImageRef;
+use crate::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::client_error::ClientError;
+use crate::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+use crate::generate::generate_video_v2::providers::replicate::seedance_1p0_lite::request::ReplicateSeedance1p0LiteRequestState;
+use crate::generate::generate_video_v2::video_generation_draft_or_request::VideoGenerationDraftOrRequest;
+use crate::generate::generate_video_v2::video_generation_request::VideoGenerationRequest;
+
+const DEFAULT_DURATION_SECONDS: u32 = 5;
+
+pub fn build_replicate_seedance_1p0_lite(
+  mut builder: GenerateVideoRequestBuilder,
+) -> Result<VideoGenerationDraftOrRequest, ArtcraftRouterError> {
+  let strategy = builder.request_mismatch_mitigation_strategy;
+
+  check_batch_count(builder.video_batch_count, strategy)?;
+
+  if builder.reference_videos.is_some() || builder.reference_audio.is_some() {
+    if let RequestMismatchMitigationStrategy::ErrorOut = strategy {
+      return Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+        field: "reference_videos/reference_audio",
+        value: "Seedance 1.0 Lite only takes image references".to_string(),
+      }));
+    }
+  }
+
+  let start_frame_url = resolve_url(builder.start_frame.take(), "start_frame")?;
+  let end_frame_url = resolve_url(builder.end_frame.take(), "end_frame")?;
+  let reference_image_urls = resolve_reference_image_urls(builder.reference_images.take(), strategy)?;
+
+  if !reference_image_urls.is_empty() && start_frame_url.is_some() {
+    return Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+      field: "reference_images",
+      value: "Seedance 1.0 Lite can't combine reference images with a start frame".to_string(),
+    }));
+  }
+
+  if end_frame_url.is_some() && start_frame_url.is_none() {
+    return Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+      field: "end_frame",
+      value: "Seedance 1.0 Lite requires a start frame with an end frame".to_string(),
+    }));
+  }
+
+  let request = Seedance1LiteRequest {
+    prompt: builder.prompt.take().unwrap_or_default(),
+    duration_seconds: plan_duration(builder.duration_seconds, strategy)?,
+    resolution: plan_resolution(builder.resolution),
+    aspect_ratio: plan_aspect_ratio(builder.aspect_ratio),
+    start_frame_url,
+    end_frame_url,
+    reference_image_urls,
+    camera_fixed: None,
+    seed: None,
+  };
+
+  let state = ReplicateSeedance1p0LiteRequestState { request };
+  Ok(VideoGenerationDraftOrRequest::Request(VideoGenerationRequest::ReplicateSeedance1p0Lite(state)))
+}
+
+/// Replicate predictions produce one video each.
+fn check_batch_count(
+  count: Option<u16>,
+  strategy: RequestMismatchMitigationStrategy,
+) -> Result<(), ArtcraftRouterError> {
+  match (count.unwrap_or(1), strategy) {
+    (0, _) => Err(ArtcraftRouterError::Client(ClientError::UserRequestedZeroGenerations)),
+    (1, _) => Ok(()),
+    (count, RequestMismatchMitigationStrategy::ErrorOut) => {
+      Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+        field: "video_batch_count",
+        value: format!("{}", count),
+      }))
+    }
+    _ => Ok(()),
+  }
+}
+
+fn plan_duration(
+  duration_seconds: Option<u16>,
+  strategy: RequestMismatchMitigationStrategy,
+) -> Result<u32, ArtcraftRouterError> {
+  let Some(duration) = duration_seconds.map(u32::from) else {
+    return Ok(DEFAULT_DURATION_SECONDS);
+  };
+
+  if (SEEDANCE_1_LITE_MIN_DURATION_SECONDS..=SEEDANCE_1_LITE_MAX_DURATION_SECONDS).contains(&duration) {
+    return Ok(duration);
+  }
+
+  match strategy {
+    RequestMismatchMitigationStrategy::ErrorOut => {
+      Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+        field: "duration_seconds",
+        value: format!("{}", duration),
+      }))
+    }
+    _ => Ok(duration.clamp(SEEDANCE_1_LITE_MIN_DURATION_SECONDS, SEEDANCE_1_LITE_MAX_DURATION_SECONDS)),
+  }
+}
+
+fn plan_resolution(resolution: Option<CommonResolution>) -> Seedance1LiteResolution {
+  match resolution {
+    None => Seedance1LiteResolution::SevenTwentyP,
+    Some(CommonResolution::FourEightyP) | Some(CommonResolution::HalfK) => Seedance1LiteResolution::FourEightyP,
+    Some(CommonResolution::SevenTwentyP) | Some(CommonResolution::OneK) => Seedance1LiteResolution::SevenTwentyP,
+    Some(CommonResolution::TenEightyP) | Some(CommonResolution::TwoK)
+    | Some(CommonResolution::ThreeK) | Some(CommonResolution::FourK) => Seedance1LiteResolution::TenEightyP,
+  }
+}
+
+fn plan_aspect_ratio(aspect_ratio: Option<CommonAspectRatio>) -> Option<Seedance1LiteAspectRatio> {
+  use Seedance1LiteAspectRatio as A;
+  match aspect_ratio? {
+    CommonAspectRatio::Auto | CommonAspectRatio::Auto2k
+    | CommonAspectRatio::Auto3k | CommonAspectRatio::Auto4k => None,
+    CommonAspectRatio::Square | CommonAspectRatio::SquareHd => Some(A::Square),
+    CommonAspectRatio::WideSixteenByNine | CommonAspectRatio::Wide => Some(A::SixteenByNine),
+    CommonAspectRatio::WideTwentyOneByNine => Some(A::TwentyOneByNine),
+    CommonAspectRatio::WideFourByThree | CommonAspectRatio::WideThreeByTwo
+    | CommonAspectRatio::WideFiveByFour => Some(A::FourByThree),
+    CommonAspectRatio::TallNineBySixteen | CommonAspectRatio::Tall => Some(A::NineBySixteen),
+    CommonAspectRatio::TallNineByTwentyOne => Some(A::NineByTwentyOne),
+    CommonAspectRatio::TallThreeByFour | CommonAspectRatio::TallTwoByThree
+    | CommonAspectRatio::TallFourByFive => Some(A::ThreeByFour),
+  }
+}
+
+fn resolve_url(image_ref: Option<ImageRef>, field: &'static str) -> Result<Option<String>, ArtcraftRouterError> {
+  match image_ref {
+    None => Ok(None),
+    Some(ImageRef::Url(url)) => Ok(Some(url)),
+    Some(ImageRef::MediaFileToken(_)) => {
+      Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+        field,
+        value: "Replicate only supports image URLs, not media file tokens".to_string(),
+      }))
+    }
+  }
+}
+
+fn resolve_reference_image_urls(
+  list_ref: Option<ImageListRef>,
+  strategy: RequestMismatchMitigationStrategy,
+) -> Result<Vec<String>, ArtcraftRouterError> {
+  let urls = match list_ref {
+    None => return Ok(Vec::new()),
+    Some(ImageListRef::Urls(urls)) => urls,
+    Some(ImageListRef::MediaFileTokens(_)) => {
+      return Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+        field: "reference_images",
+        value: "Replicate only supports image URLs, not media file tokens".to_string(),
+      }));
+    }
+  };
+
+  if urls.len() <= SEEDANCE_1_LITE_MAX_REFERENCE_IMAGES {
+    return Ok(urls);
+  }
+
+  match strategy {
+    RequestMismatchMitigationStrategy::ErrorOut => {
+      Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption {
+        field: "reference_images",
+        value: format!("{} images (max {})", urls.len(), SEEDANCE_1_LITE_MAX_REFERENCE_IMAGES),
+      }))
+    }
+    _ => Ok(urls.into_iter().take(SEEDANCE_1_LITE_MAX_REFERENCE_IMAGES).collect()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tokens::tokens::media_files::MediaFileToken;
+
+  use super::*;
+  use crate::api::common_video_model::CommonVideoModel;
+  use crate::api::provider::Provider;
+
+  fn base_builder() -> GenerateVideoRequestBuilder {
+    GenerateVideoRequestBuilder {
+      model: CommonVideoModel::Seedance10Lite,
+      provider: Provider::Replicate,
+      prompt: Some("a corgi surfing".to_string()),
+      video_batch_count: Some(1),
+      ..Default::default()
+    }
+  }
+
+  fn unwrap_request(result: Result<VideoGenerationDraftOrRequest, ArtcraftRouterError>) -> Seedance1LiteRequest {
+    match result.expect("build should succeed") {
+      VideoGenerationDraftOrRequest::Request(VideoGenerationRequest::ReplicateSeedance1p0Lite(state)) => state.request,
+      _ => panic!("expected ReplicateSeedance1p0Lite request"),
+    }
+  }
+
+  #[test]
+  fn defaults() {
+    let req = unwrap_request(build_replicate_seedance_1p0_lite(base_builder()));
+    assert_eq!(req.prompt, "a corgi surfing");
+    assert_eq!(req.duration_seconds, 5);
+    assert_eq!(req.resolution, Seedance1LiteResolution::SevenTwentyP);
+    assert_eq!(req.aspect_ratio, None);
+  }
+
+  #[test]
+  fn resolution_and_aspect_ratio() {
+    let req = unwrap_request(build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      resolution: Some(CommonResolution::TenEightyP),
+      aspect_ratio: Some(CommonAspectRatio::TallNineBySixteen),
+      ..base_builder()
+    }));
+    assert_eq!(req.resolution, Seedance1LiteResolution::TenEightyP);
+    assert_eq!(req.aspect_ratio, Some(Seedance1LiteAspectRatio::NineBySixteen));
+  }
+
+  #[test]
+  fn duration_out_of_range_errors_out() {
+    let result = build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      duration_seconds: Some(15),
+      ..base_builder()
+    });
+    assert!(matches!(result, Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption { field: "duration_seconds", .. }))));
+  }
+
+  #[test]
+  fn duration_out_of_range_clamps_with_upgrade() {
+    let req = unwrap_request(build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      duration_seconds: Some(15),
+      request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+      ..base_builder()
+    }));
+    assert_eq!(req.duration_seconds, 12);
+  }
+
+  #[test]
+  fn start_and_end_frames() {
+    let req = unwrap_request(build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      start_frame: Some(ImageRef::Url("https://example.com/start.png".to_string())),
+      end_frame: Some(ImageRef::Url("https://example.com/end.png".to_string())),
+      ..base_builder()
+    }));
+    assert_eq!(req.start_frame_url.as_deref(), Some("https://example.com/start.png"));
+    assert_eq!(req.end_frame_url.as_deref(), Some("https://example.com/end.png"));
+  }
+
+  #[test]
+  fn end_frame_without_start_frame_is_error() {
+    let result = build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      end_frame: Some(ImageRef::Url("https://example.com/end.png".to_string())),
+      ..base_builder()
+    });
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn media_file_tokens_rejected() {
+    let result = build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      start_frame: Some(ImageRef::MediaFileToken(MediaFileToken::new("mf_start".to_string()))),
+      ..base_builder()
+    });
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn reference_images_truncated_with_upgrade() {
+    let urls: Vec<String> = (0..6).map(|i| format!("https://example.com/{i}.png")).collect();
+    let req = unwrap_request(build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      reference_images: Some(ImageListRef::Urls(urls.clone())),
+      request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+      ..base_builder()
+    }));
+    assert_eq!(req.reference_image_urls, urls[..4].to_vec());
+
+    let result = build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      reference_images: Some(ImageListRef::Urls(urls)),
+      ..base_builder()
+    });
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn batch_of_two_errors_out() {
+    let result = build_replicate_seedance_1p0_lite(GenerateVideoRequestBuilder {
+      video_batch_count: Some(2),
+      ..base_builder()
+    });
+    assert!(matches!(result, Err(ArtcraftRouterError::Client(ClientError::ModelDoesNotSupportOption { field: "video_batch_count", .. }))));
+  }
+}
@@ -0,0 +1,67 @@
+use replicate_client::requests::traits::replicate_request_cost_calculator_trait::ReplicateRequestCostCalculator;
+
+use crate::generate::generate_video::video_generation_cost_estimate::VideoGenerationCostEstimate;
+use crate::generate::generate_video_v2::providers::replicate::seedance_1p0_lite::request::ReplicateSeedance1p0LiteRequestState;
+
+pub struct ReplicateSeedance1p0LiteCostState {
+  request: ReplicateSeedance1p0LiteRequestState,
+}
+
+impl ReplicateSeedance1p0LiteCostState {
+  pub fn from_request(request: &ReplicateSeedance1p0LiteRequestState) -> Self {
+    Self { request: request.clone() }
+  }
+
+  pub fn estimate_cost(&self) -> VideoGenerationCostEstimate {
+    VideoGenerationCostEstimate {
+      cost_in_credits: None,
+      cost_in_usd_cents: Some(self.request.request.calculate_cost_in_cents()),
+      is_free: false,
+      is_unlimited: false,
+      is_rate_limited: false,
+      has_watermark: false,
+      failures_are_refunded: None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::api::common_resolution::CommonResolution;
+  use crate::api::common_video_model::CommonVideoModel;
+  use crate::api::provider::Provider;
+  use crate::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+
+  fn cost_cents(resolution: Option<CommonResolution>, duration_seconds: u16) -> u64 {
+    let builder = GenerateVideoRequestBuilder {
+      model: CommonVideoModel::Seedance10Lite,
+      provider: Provider::Replicate,
+      resolution,
+      duration_seconds: Some(duration_seconds),
+      video_batch_count: Some(1),
+      ..Default::default()
+    };
+    builder.build2()
+      .expect("build2 should succeed")
+      .estimate_cost()
+      .expect("estimate_cost should succeed")
+      .cost_in_usd_cents
+      .unwrap()
+  }
+
+  #[test]
+  fn default_720p_5s() {
+    // 3.6 ¢/s * 5 = 18¢
+    assert_eq!(cost_cents(None, 5), 18);
+  }
+
+  #[test]
+  fn p480_10s() {
+    assert_eq!(cost_cents(Some(CommonResolution::FourEightyP), 10), 18);
+  }
+
+  #[test]
+  fn p1080_5s() {
+    assert_eq!(cost_cents(Some(CommonResolution::TenEightyP), 5), 36);
+  }
+}
@@ -0,0 +1,97 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use replicate_client::requests::api::video::seedance_1_lite::api::Seedance1LiteRequest;
+
+use crate::client::router_replicate_client::RouterReplicateClient;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::generate::generate_video::generate_video_response::{
+  GenerateVideoResponse, ReplicateVideoResponsePayload,
+};
+
+#[derive(Clone, Debug)]
+pub struct ReplicateSeedance1p0LiteRequestState {
+  pub request: Seedance1LiteRequest,
+}
+
+impl ReplicateSeedance1p0LiteRequestState {
+  pub async fn send(&self, client: &RouterReplicateClient) -> Result<GenerateVideoResponse, ArtcraftRouterError> {
+    let outbound: Arc<dyn Debug + Send + Sync> = Arc::new(self.request.clone());
+    let prediction = client.create_prediction(&self.request).await?;
+
+    Ok(GenerateVideoResponse::Replicate(ReplicateVideoResponsePayload {
+      prediction_id: prediction.id,
+      maybe_outbound_request: Some(outbound),
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::api::common_aspect_ratio::CommonAspectRatio;
+  use crate::api::common_resolution::CommonResolution;
+  use crate::api::common_video_model::CommonVideoModel;
+  use crate::api::image_ref::ImageRef;
+  use crate::api::provider::Provider;
+  use crate::client::router_client::RouterClient;
+  use crate::client::router_replicate_client::RouterReplicateClient;
+  use crate::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+  use crate::generate::generate_video::generate_video_response::GenerateVideoResponse;
+  use crate::generate::generate_video_v2::video_generation_draft_or_request::VideoGenerationDraftOrRequest;
+  use test_data::web::image_urls::GHOST_IMAGE_URL;
+
+  #[tokio::test]
+  #[ignore] // requires real API key, incurs costs
+  async fn test_text_to_video_480p() {
+    let response = run_pipeline(GenerateVideoRequestBuilder {
+      prompt: Some("A corgi running through a field of wildflowers at sunset.".to_string()),
+      aspect_ratio: Some(CommonAspectRatio::WideSixteenByNine),
+      resolution: Some(CommonResolution::FourEightyP),
+      duration_seconds: Some(5),
+      ..replicate_builder()
+    }).await;
+    assert!(matches!(response, GenerateVideoResponse::Replicate(_)));
+  }
+
+  #[tokio::test]
+  #[ignore] // requires real API key, incurs costs
+  async fn test_image_to_video_480p() {
+    let response = run_pipeline(GenerateVideoRequestBuilder {
+      prompt: Some("The ghost drifts slowly toward the camera.".to_string()),
+      start_frame: Some(ImageRef::Url(GHOST_IMAGE_URL.to_string())),
+      resolution: Some(CommonResolution::FourEightyP),
+      duration_seconds: Some(5),
+      ..replicate_builder()
+    }).await;
+    assert!(matches!(response, GenerateVideoResponse::Replicate(_)));
+  }
+
+  fn replicate_builder() -> GenerateVideoRequestBuilder {
+    GenerateVideoRequestBuilder {
+      model: CommonVideoModel::Seedance10Lite,
+      provider: Provider::Replicate,
+      video_batch_count: Some(1),
+      ..Default::default()
+    }
+  }
+
+  fn get_replicate_client() -> RouterClient {
+    let secret = std::fs::read_to_string("/Users/bt/Artcraft/credentials/replicate_api_key.txt")
+      .expect("Failed to read Replicate API key");
+    RouterClient::Replicate(RouterReplicateClient::from_str(&secret))
+  }
+
+  async fn run_pipeline(builder: GenerateVideoRequestBuilder) -> GenerateVideoResponse {
+    let client = get_replicate_client();
+    let draft_or_request = builder.build2().expect("build2 should succeed");
+    let request = match draft_or_request {
+      VideoGenerationDraftOrRequest::Request(r) => r,
+      _ => panic!("expected Request variant (Replicate skips draft)"),
+    };
+    let response = request.send_request(&client).await.expect("send_request should succeed");
+    if let Some(payload) = response.get_replicate_payload() {
+      println!("prediction_id={}", payload.prediction_id);
+    }
+    response
+  }
+}
@@ -0,0 +1,675 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use artcraft_client::error::api_error::ApiError;
+use artcraft_client::error::storyteller_error::StorytellerError;
+use cloudflare_errors::cloudflare_error::CloudflareError;
+use fal_client::error::api_generic_error::FalGenericApiError;
+use fal_client::error::fal_error::FalError;
+use fal_client::error::fal_error_plus::FalErrorPlus;
+use gmicloud_client::error::gmicloud_client_error::GmiCloudClientError;
+use gmicloud_client::error::gmicloud_error::GmiCloudError;
+use gmicloud_client::error::gmicloud_generic_api_error::GmiCloudGenericApiError;
+use log::{info, warn};
+use muapi_client::error::muapi_client_error::MuapiClientError;
+use muapi_client::error::muapi_error::MuapiError;
+use muapi_client::error::muapi_generic_api_error::MuapiGenericApiError;
+use replicate_client::error::replicate_client_error::ReplicateClientError;
+use replicate_client::error::replicate_error::ReplicateError;
+use replicate_client::error::replicate_generic_api_error::ReplicateGenericApiError;
+use replicate_client::error::replicate_specific_api_error::ReplicateSpecificApiError;
+use seedance2pro_client::error::seedance2pro_client_error::Seedance2ProClientError;
+use seedance2pro_client::error::seedance2pro_error::Seedance2ProError;
+use seedance2pro_client::error::seedance2pro_generic_api_error::Seedance2ProGenericApiError;
+use tokens::tokens::media_files::MediaFileToken;
+
+use crate::api::audio_list_ref::AudioListRef;
+use crate::api::common_video_model::CommonVideoModel;
+use crate::api::image_list_ref::ImageListRef;
+use crate::api::image_ref::ImageRef;
+use crate::api::provider::Provider;
+use crate::api::video_list_ref::VideoListRef;
+use crate::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+use crate::client::router_client::RouterClient;
+use crate::client::video_failover_policy::{VideoFailoverPolicy, VideoProviderVariant};
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::errors::client_error::ClientError;
+use crate::errors::provider_error::ProviderError;
+use crate::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+use crate::generate::generate_video::generate_video_response::GenerateVideoResponse;
+use crate::generate::generate_video::video_generation_cost_estimate::VideoGenerationCostEstimate;
+use crate::generate::generate_video_v2::video_generation_draft_context::VideoGenerationDraftContext;
+use crate::generate::generate_video_v2::video_generation_draft_or_request::VideoGenerationDraftOrRequest;
+use crate::generate::generate_video_v2::video_generation_request::VideoGenerationRequest;
+use crate::mock::mock_provider_error::MockProviderError;
+
+/// A provider variant that was tried, but did not accept the job.
+#[derive(Debug)]
+pub struct VideoFailoverAttempt {
+  pub variant: VideoProviderVariant,
+  pub outcome: VideoFailoverAttemptOutcome,
+}
+
+#[derive(Debug)]
+pub enum VideoFailoverAttemptOutcome {
+  /// The provider's client is not configured on the RouterClient.
+  ClientNotConfigured,
+
+  /// The re-planned request has a different cost than the preferred variant,
+  /// and the request mismatch mitigation strategy does not allow it.
+  CostMismatch {
+    preferred_cost_in_usd_cents: u64,
+    variant_cost_in_usd_cents: u64,
+  },
+
+  /// Planning, finalizing, or sending the request failed.
+  Failed(ArtcraftRouterError),
+}
+
+impl Display for VideoFailoverAttempt {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let provider = self.variant.provider;
+    let model = self.variant.model;
+    match &self.outcome {
+      VideoFailoverAttemptOutcome::ClientNotConfigured => {
+        write!(f, "{:?}/{:?}: client not configured", provider, model)
+      }
+      VideoFailoverAttemptOutcome::CostMismatch { preferred_cost_in_usd_cents, variant_cost_in_usd_cents } => {
+        write!(f, "{:?}/{:?}: cost mismatch ({} cents vs preferred {} cents)",
+          provider, model, variant_cost_in_usd_cents, preferred_cost_in_usd_cents)
+      }
+      VideoFailoverAttemptOutcome::Failed(err) => {
+        write!(f, "{:?}/{:?}: {}", provider, model, err)
+      }
+    }
+  }
+}
+
+/// The result of a successful failover-enabled video generation.
+#[derive(Debug)]
+pub struct VideoFailoverResponse {
+  /// The provider that accepted the job.
+  pub provider: Provider,
+
+  /// The model (as named by the accepting provider) that was used.
+  pub model: CommonVideoModel,
+
+  /// The provider's response.
+  pub response: GenerateVideoResponse,
+
+  /// The cost estimate of the request that was accepted.
+  pub cost_estimate: VideoGenerationCostEstimate,
+
+  /// Variants that were tried before the job was accepted, in order.
+  pub failed_attempts: Vec<VideoFailoverAttempt>,
+}
+
+/// Walk the policy's provider variants in order, re-planning the request for each one,
+/// until a provider accepts the job.
+///
+/// The first variant that plans successfully sets the reference price. Subsequent variants
+/// with a different price are only tried if the builder's `RequestMismatchMitigationStrategy`
+/// allows paying that difference.
+///
+/// If the client tracks provider health, variants whose circuit is open are moved to the
+/// back of the line (keeping policy order otherwise), so a known outage isn't tried first.
+pub async fn generate_video_with_failover(
+  client: &RouterClient,
+  builder: &GenerateVideoRequestBuilder,
+  policy: &VideoFailoverPolicy,
+  draft_context: VideoGenerationDraftContext<'_>,
+) -> Result<VideoFailoverResponse, ArtcraftRouterError> {
+  if policy.variants.is_empty() {
+    return Err(ArtcraftRouterError::InvalidInput("failover policy has no provider variants".to_string()));
+  }
+
+  let strategy = builder.request_mismatch_mitigation_strategy;
+  let mut preferred_cost_in_usd_cents: Option<u64> = None;
+  let mut failed_attempts = Vec::new();
+
+  for variant in order_by_health(client, policy) {
+    if !client.is_provider_configured(variant.provider) {
+      failed_attempts.push(VideoFailoverAttempt {
+        variant,
+        outcome: VideoFailoverAttemptOutcome::ClientNotConfigured,
+      });
+      continue;
+    }
+
+    let plan_result = plan_variant(builder, variant, &draft_context)
+      .and_then(|draft_or_request| {
+        let cost_estimate = draft_or_request.estimate_cost()?;
+        Ok((draft_or_request, cost_estimate))
+      });
+
+    let (draft_or_request, cost_estimate) = match plan_result {
+      Ok(planned) => planned,
+      Err(err) => {
+        warn!("Failover: could not plan {:?}/{:?}: {}", variant.provider, variant.model, err);
+        failed_attempts.push(VideoFailoverAttempt {
+          variant,
+          outcome: VideoFailoverAttemptOutcome::Failed(err),
+        });
+        continue;
+      }
+    };
+
+    if let Some(variant_cost) = cost_estimate.cost_in_usd_cents {
+      match preferred_cost_in_usd_cents {
+        None => preferred_cost_in_usd_cents = Some(variant_cost),
+        Some(preferred_cost) if !cost_difference_allowed(preferred_cost, variant_cost, strategy) => {
+          info!("Failover: skipping {:?}/{:?} due to cost mismatch ({} vs {} cents)",
+            variant.provider, variant.model, variant_cost, preferred_cost);
+          failed_attempts.push(VideoFailoverAttempt {
+            variant,
+            outcome: VideoFailoverAttemptOutcome::CostMismatch {
+              preferred_cost_in_usd_cents: preferred_cost,
+              variant_cost_in_usd_cents: variant_cost,
+            },
+          });
+          continue;
+        }
+        Some(_) => {}
+      }
+    }
+
+    let request = match finalize(client, draft_or_request, &draft_context).await {
+      Ok(request) => request,
+      Err(err) if is_caused_by_request(&err) => return Err(err),
+      Err(err) => {
+        // Nothing has been submitted yet, so any other variant is safe to try.
+        warn!("Failover: could not finalize {:?}/{:?}: {}", variant.provider, variant.model, err);
+        failed_attempts.push(VideoFailoverAttempt {
+          variant,
+          outcome: VideoFailoverAttemptOutcome::Failed(err),
+        });
+        continue;
+      }
+    };
+
+    match request.send_request(client).await {
+      Ok(response) => {
+        info!("Failover: {:?}/{:?} accepted the job after {} failed attempt(s)",
+          variant.provider, variant.model, failed_attempts.len());
+        return Ok(VideoFailoverResponse {
+          provider: variant.provider,
+          model: variant.model,
+          response,
+          cost_estimate,
+          failed_attempts,
+        });
+      }
+      Err(err) if !is_failover_eligible(&err) => return Err(err),
+      Err(err) => {
+        warn!("Failover: {:?}/{:?} failed to accept the job: {}", variant.provider, variant.model, err);
+        failed_attempts.push(VideoFailoverAttempt {
+          variant,
+          outcome: VideoFailoverAttemptOutcome::Failed(err),
+        });
+      }
+    }
+  }
+
+  Err(ArtcraftRouterError::FailoverExhausted(failed_attempts))
+}
+
+/// Policy order, with unavailable providers (open circuit) moved last.
+fn order_by_health(client: &RouterClient, policy: &VideoFailoverPolicy) -> Vec<VideoProviderVariant> {
+  let mut variants = policy.variants.clone();
+  if let Some(registry) = client.get_health_registry() {
+    // Stable sort: `false` (available) sorts before `true` (unavailable).
+    variants.sort_by_key(|variant| !registry.is_available(variant.provider));
+  }
+  variants
+}
+
+/// Re-plan the original request for the given provider variant.
+fn plan_variant(
+  builder: &GenerateVideoRequestBuilder,
+  variant: VideoProviderVariant,
+  draft_context: &VideoGenerationDraftContext<'_>,
+) -> Result<VideoGenerationDraftOrRequest, ArtcraftRouterError> {
+  let mut builder = builder.clone();
+  builder.provider = variant.provider;
+  builder.model = variant.model;
+
+  // GmiCloud takes URLs directly rather than media file tokens.
+  if matches!(variant.provider, Provider::GmiCloud) {
+    resolve_media_tokens_to_urls(&mut builder, draft_context.media_file_to_artcraft_url_map);
+  }
+
+  builder.build2()
+}
+
+async fn finalize(
+  client: &RouterClient,
+  draft_or_request: VideoGenerationDraftOrRequest,
+  draft_context: &VideoGenerationDraftContext<'_>,
+) -> Result<VideoGenerationRequest, ArtcraftRouterError> {
+  match draft_or_request {
+    VideoGenerationDraftOrRequest::Request(request) => Ok(request),
+    VideoGenerationDraftOrRequest::Draft(draft) => {
+      let context = VideoGenerationDraftContext {
+        client: Some(client),
+        media_file_to_artcraft_url_map: draft_context.media_file_to_artcraft_url_map,
+        character_token_to_kinovi_id_map: draft_context.character_token_to_kinovi_id_map,
+      };
+      draft.finalize(context).await
+    }
+  }
+}
+
+/// Whether a variant whose price differs from the preferred variant may be used.
+fn cost_difference_allowed(
+  preferred_cost_in_usd_cents: u64,
+  variant_cost_in_usd_cents: u64,
+  strategy: RequestMismatchMitigationStrategy,
+) -> bool {
+  match strategy {
+    RequestMismatchMitigationStrategy::PayMoreUpgrade => true,
+    RequestMismatchMitigationStrategy::PayLessDowngrade => variant_cost_in_usd_cents <= preferred_cost_in_usd_cents,
+    RequestMismatchMitigationStrategy::ErrorOut => variant_cost_in_usd_cents == preferred_cost_in_usd_cents,
+  }
+}
+
+/// Errors caused by the request itself will fail the same way on every provider.
+fn is_caused_by_request(error: &ArtcraftRouterError) -> bool {
+  matches!(error,
+    ArtcraftRouterError::InvalidInput(_)
+    | ArtcraftRouterError::Client(ClientError::UserRequestedZeroGenerations))
+}
+
+/// Whether a failed send may be retried on another provider without risking a duplicate job.
+///
+/// Only errors where the provider never saw the request (DNS, connection refused, errors
+/// building the request) or explicitly refused it with a 5xx/429 qualify. Timeouts, resets
+/// and unparseable responses are ambiguous: the provider may have accepted the job.
+fn is_failover_eligible(error: &ArtcraftRouterError) -> bool {
+  match error {
+    ArtcraftRouterError::InvalidInput(_) => false,
+    ArtcraftRouterError::Client(ClientError::UserRequestedZeroGenerations) => false,
+    ArtcraftRouterError::Client(_) => true,
+    ArtcraftRouterError::Download(_) => true,
+    ArtcraftRouterError::UnsupportedModel(_) => true,
+    ArtcraftRouterError::UnsupportedProviderAndModelForNewApi(_) => true,
+    ArtcraftRouterError::ProviderCircuitOpen(_) => true,
+    ArtcraftRouterError::Provider(err) => is_provider_error_failover_eligible(err),
+    ArtcraftRouterError::ProviderBillingError(_) => false,
+    ArtcraftRouterError::FailoverExhausted(_) => false,
+  }
+}
+
+fn is_provider_error_failover_eligible(error: &ProviderError) -> bool {
+  match error {
+    ProviderError::Storyteller(StorytellerError::Client(_)) => true,
+    ProviderError::Storyteller(StorytellerError::Api(err)) => match err {
+      ApiError::InternalServerError { .. } => true,
+      ApiError::TooManyRequests(_) => true,
+      ApiError::UncategorizedBadResponseWithStatusAndBody { status_code, .. } => is_retryable_status(status_code.as_u16()),
+      ApiError::CloudflareError(err) => is_cloudflare_error_failover_eligible(err),
+      // Connection errors (not timeouts) are mapped to `NetworkError`.
+      ApiError::NetworkError(_) => true,
+      ApiError::OtherReqwestError(err) => reqwest_error_never_sent(err),
+      _ => false,
+    },
+
+    ProviderError::Fal(err) => match err {
+      FalErrorPlus::ClientError(_) => true,
+      FalErrorPlus::UrlParseError(_) => true,
+      FalErrorPlus::UnhandledEndpoint(_) => true,
+      FalErrorPlus::ApiGeneric(FalGenericApiError::UncategorizedBadResponseWithStatusAndBody { status_code, .. }) => {
+        is_retryable_status(status_code.as_u16())
+      }
+      FalErrorPlus::FalError(FalError::RequestError(err)) => reqwest_error_never_sent(err),
+      FalErrorPlus::ReqwestError(err) => reqwest_error_never_sent(err),
+      _ => false,
+    },
+
+    ProviderError::GmiCloud(err) => match err {
+      GmiCloudError::Client(GmiCloudClientError::NoApiKeyPresent) => true,
+      GmiCloudError::Client(GmiCloudClientError::ReqwestError(err)) => reqwest_error_never_sent(err),
+      GmiCloudError::ApiGeneric(GmiCloudGenericApiError::UncategorizedBadResponseWithStatusAndBody { status_code, .. }) => {
+        is_retryable_status(*status_code)
+      }
+      GmiCloudError::ApiGeneric(GmiCloudGenericApiError::ReqwestError(err)) => reqwest_error_never_sent(err),
+      _ => false,
+    },
+
+    // The mock refuses submissions before creating a job.
+    ProviderError::Mock(err) => matches!(err, MockProviderError::SubmissionRejected),
+
+    ProviderError::Muapi(err) => match err {
+      MuapiError::Client(MuapiClientError::WreqClientError(err)) => wreq_error_never_sent(err),
+      MuapiError::Client(_) => true,
+      MuapiError::ApiGeneric(MuapiGenericApiError::CloudflareError(err)) => is_cloudflare_error_failover_eligible(err),
+      MuapiError::ApiGeneric(MuapiGenericApiError::UncategorizedBadResponseWithStatusAndBody { status_code, .. }) => {
+        is_retryable_status(status_code.as_u16())
+      }
+      MuapiError::ApiGeneric(MuapiGenericApiError::WreqError(err)) => wreq_error_never_sent(err),
+      _ => false,
+    },
+
+    ProviderError::Replicate(err) => match err {
+      ReplicateError::Client(ReplicateClientError::ReqwestError(err)) => reqwest_error_never_sent(err),
+      ReplicateError::Client(_) => true,
+      ReplicateError::ApiSpecific(ReplicateSpecificApiError::RateLimited { .. }) => true,
+      ReplicateError::ApiGeneric(ReplicateGenericApiError::UncategorizedBadResponseWithStatusAndBody { status_code, .. }) => {
+        is_retryable_status(*status_code)
+      }
+      ReplicateError::ApiGeneric(ReplicateGenericApiError::ReqwestError(err)) => reqwest_error_never_sent(err),
+      _ => false,
+    },
+
+    ProviderError::Seedance2Pro(err) => match err {
+      Seedance2ProError::Client(Seedance2ProClientError::WreqClientError(err)) => wreq_error_never_sent(err),
+      Seedance2ProError::Client(_) => true,
+      Seedance2ProError::ApiGeneric(Seedance2ProGenericApiError::CloudflareError(err)) => is_cloudflare_error_failover_eligible(err),
+      Seedance2ProError::ApiGeneric(Seedance2ProGenericApiError::UncategorizedBadResponseWithStatusAndBody { status_code, .. }) => {
+        is_retryable_status(status_code.as_u16())
+      }
+      Seedance2ProError::ApiGeneric(Seedance2ProGenericApiError::WreqError(err)) => wreq_error_never_sent(err),
+      _ => false,
+    },
+  }
+}
+
+/// An explicit refusal: the provider answered, and did not take the job.
+fn is_retryable_status(status_code: u16) -> bool {
+  status_code == 429 || (500..=599).contains(&status_code)
+}
+
+/// A 524 means Cloudflare delivered the request and the origin went quiet, so it's ambiguous.
+fn is_cloudflare_error_failover_eligible(error: &CloudflareError) -> bool {
+  match error {
+    CloudflareError::BadGateway502 => true,
+    CloudflareError::GatewayTimeout504 => true,
+    CloudflareError::TimeoutOccurred524 => false,
+    CloudflareError::MovedPermanently301 => false,
+    CloudflareError::ChallengeInterstitial403 => false,
+  }
+}
+
+/// The request failed before any bytes could have reached the provider.
+fn reqwest_error_never_sent(error: &reqwest::Error) -> bool {
+  if let Some(status) = error.status() {
+    return is_retryable_status(status.as_u16());
+  }
+  error.is_builder() || (error.is_connect() && !error.is_timeout())
+}
+
+/// The request failed before any bytes could have reached the provider.
+fn wreq_error_never_sent(error: &wreq::Error) -> bool {
+  if let Some(status) = error.status() {
+    return is_retryable_status(status.as_u16());
+  }
+  error.is_builder() || (error.is_connect() && !error.is_timeout() && !error.is_connection_reset())
+}
+
+/// Swap media file tokens for URLs using the pre-resolved map.
+/// Tokens missing from the map are left as-is (and will fail to plan).
+fn resolve_media_tokens_to_urls(
+  builder: &mut GenerateVideoRequestBuilder,
+  maybe_map: Option<&HashMap<MediaFileToken, String>>,
+) {
+  let map = match maybe_map {
+    Some(map) => map,
+    None => return,
+  };
+
+  let lookup_all = |tokens: &[MediaFileToken]| -> Option<Vec<String>> {
+    tokens.iter().map(|token| map.get(token).cloned()).collect()
+  };
+
+  if let Some(ImageRef::MediaFileToken(token)) = builder.start_frame.as_ref() {
+    if let Some(url) = map.get(token) {
+      builder.start_frame = Some(ImageRef::Url(url.clone()));
+    }
+  }
+
+  if let Some(ImageRef::MediaFileToken(token)) = builder.end_frame.as_ref() {
+    if let Some(url) = map.get(token) {
+      builder.end_frame = Some(ImageRef::Url(url.clone()));
+    }
+  }
+
+  if let Some(ImageListRef::MediaFileTokens(tokens)) = builder.reference_images.as_ref() {
+    if let Some(urls) = lookup_all(tokens) {
+      builder.reference_images = Some(ImageListRef::Urls(urls));
+    }
+  }
+
+  if let Some(VideoListRef::MediaFileTokens(tokens)) = builder.reference_videos.as_ref() {
+    if let Some(urls) = lookup_all(tokens) {
+      builder.reference_videos = Some(VideoListRef::Urls(urls));
+    }
+  }
+
+  if let Some(AudioListRef::MediaFileTokens(tokens)) = builder.reference_audio.as_ref() {
+    if let Some(urls) = lookup_all(tokens) {
+      builder.reference_audio = Some(AudioListRef::Urls(urls));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::client::multi_router_client_builder::MultiRouterClientBuilder;
+
+  fn base_builder() -> GenerateVideoRequestBuilder {
+    GenerateVideoRequestBuilder {
+      prompt: Some("a corgi surfing".to_string()),
+      duration_seconds: Some(5),
+      ..Default::default()
+    }
+  }
+
+  mod cost_tests {
+    use super::*;
+
+    #[test]
+    fn pay_more_allows_anything() {
+      assert!(cost_difference_allowed(100, 150, RequestMismatchMitigationStrategy::PayMoreUpgrade));
+      assert!(cost_difference_allowed(100, 50, RequestMismatchMitigationStrategy::PayMoreUpgrade));
+    }
+
+    #[test]
+    fn pay_less_only_allows_cheaper_or_equal() {
+      assert!(cost_difference_allowed(100, 100, RequestMismatchMitigationStrategy::PayLessDowngrade));
+      assert!(cost_difference_allowed(100, 50, RequestMismatchMitigationStrategy::PayLessDowngrade));
+      assert!(!cost_difference_allowed(100, 150, RequestMismatchMitigationStrategy::PayLessDowngrade));
+    }
+
+    #[test]
+    fn error_out_requires_equal_cost() {
+      assert!(cost_difference_allowed(100, 100, RequestMismatchMitigationStrategy::ErrorOut));
+      assert!(!cost_difference_allowed(100, 50, RequestMismatchMitigationStrategy::ErrorOut));
+      assert!(!cost_difference_allowed(100, 150, RequestMismatchMitigationStrategy::ErrorOut));
+    }
+  }
+
+  mod eligibility_tests {
+    use super::*;
+
+    #[test]
+    fn invalid_input_is_not_retried() {
+      assert!(!is_failover_eligible(&ArtcraftRouterError::InvalidInput("bad".to_string())));
+      assert!(!is_failover_eligible(&ArtcraftRouterError::Client(ClientError::UserRequestedZeroGenerations)));
+    }
+
+    #[test]
+    fn pre_send_errors_are_retried() {
+      assert!(is_failover_eligible(&ArtcraftRouterError::UnsupportedModel("x".to_string())));
+      assert!(is_failover_eligible(&ArtcraftRouterError::Client(ClientError::Seedance2ProOnlySupportsUrls)));
+      assert!(is_failover_eligible(&ArtcraftRouterError::ProviderCircuitOpen(Provider::Seedance2Pro)));
+      assert!(is_failover_eligible(&ArtcraftRouterError::Provider(ProviderError::GmiCloud(
+        GmiCloudError::Client(GmiCloudClientError::NoApiKeyPresent)))));
+    }
+
+    #[test]
+    fn explicit_5xx_and_429_are_retried() {
+      let gmicloud_error = |status_code| ArtcraftRouterError::Provider(ProviderError::GmiCloud(
+        GmiCloudError::ApiGeneric(GmiCloudGenericApiError::UncategorizedBadResponseWithStatusAndBody {
+          status_code,
+          body: "".to_string(),
+        })));
+      assert!(is_failover_eligible(&gmicloud_error(500)));
+      assert!(is_failover_eligible(&gmicloud_error(503)));
+      assert!(is_failover_eligible(&gmicloud_error(429)));
+      assert!(!is_failover_eligible(&gmicloud_error(400)));
+      assert!(!is_failover_eligible(&gmicloud_error(404)));
+
+      let replicate_rate_limited = ArtcraftRouterError::Provider(ProviderError::Replicate(
+        ReplicateError::ApiSpecific(ReplicateSpecificApiError::RateLimited { maybe_retry_after_seconds: None })));
+      assert!(is_failover_eligible(&replicate_rate_limited));
+    }
+
+    #[test]
+    fn cloudflare_origin_timeout_is_not_retried() {
+      let kinovi_error = |err| ArtcraftRouterError::Provider(ProviderError::Seedance2Pro(
+        Seedance2ProError::ApiGeneric(Seedance2ProGenericApiError::CloudflareError(err))));
+      assert!(is_failover_eligible(&kinovi_error(CloudflareError::BadGateway502)));
+      assert!(is_failover_eligible(&kinovi_error(CloudflareError::GatewayTimeout504)));
+      assert!(!is_failover_eligible(&kinovi_error(CloudflareError::TimeoutOccurred524)));
+    }
+
+    #[test]
+    fn ambiguous_errors_are_not_retried() {
+      let timeout = ArtcraftRouterError::Provider(ProviderError::Storyteller(
+        StorytellerError::Api(ApiError::Timeout("timed out".to_string()))));
+      assert!(!is_failover_eligible(&timeout));
+
+      let unparseable = ArtcraftRouterError::Provider(ProviderError::Seedance2Pro(
+        Seedance2ProError::ApiGeneric(Seedance2ProGenericApiError::UncategorizedBadResponse("<html>".to_string()))));
+      assert!(!is_failover_eligible(&unparseable));
+
+      let billing = ArtcraftRouterError::ProviderBillingError(ProviderError::Mock(MockProviderError::SubmissionRejected));
+      assert!(!is_failover_eligible(&billing));
+    }
+
+    #[test]
+    fn retryable_statuses() {
+      assert!(is_retryable_status(429));
+      assert!(is_retryable_status(500));
+      assert!(is_retryable_status(599));
+      assert!(!is_retryable_status(200));
+      assert!(!is_retryable_status(408));
+      assert!(!is_retryable_status(422));
+    }
+  }
+
+  mod plan_tests {
+    use super::*;
+
+    #[test]
+    fn gmicloud_variant_resolves_tokens_to_urls() {
+      let token = MediaFileToken::new("mf_start".to_string());
+      let map = HashMap::from([(token.clone(), "https://cdn.example.com/start.png".to_string())]);
+      let context = VideoGenerationDraftContext {
+        media_file_to_artcraft_url_map: Some(&map),
+        ..Default::default()
+      };
+      let builder = GenerateVideoRequestBuilder {
+        start_frame: Some(ImageRef::MediaFileToken(token)),
+        ..base_builder()
+      };
+      let variant = VideoProviderVariant::new(Provider::GmiCloud, CommonVideoModel::Seedance2p0Global);
+      let result = plan_variant(&builder, variant, &context);
+      assert!(matches!(result, Ok(VideoGenerationDraftOrRequest::Request(_))));
+    }
+
+    #[test]
+    fn gmicloud_variant_fails_without_url_map() {
+      let builder = GenerateVideoRequestBuilder {
+        start_frame: Some(ImageRef::MediaFileToken(MediaFileToken::new("mf_start".to_string()))),
+        ..base_builder()
+      };
+      let variant = VideoProviderVariant::new(Provider::GmiCloud, CommonVideoModel::Seedance2p0Global);
+      let result = plan_variant(&builder, variant, &VideoGenerationDraftContext::default());
+      assert!(result.is_err());
+    }
+
+    #[test]
+    fn kinovi_variant_plans_a_draft() {
+      let variant = VideoProviderVariant::new(Provider::Seedance2Pro, CommonVideoModel::Seedance2p0);
+      let result = plan_variant(&base_builder(), variant, &VideoGenerationDraftContext::default());
+      assert!(matches!(result, Ok(VideoGenerationDraftOrRequest::Draft(_))));
+    }
+
+    #[test]
+    fn partially_resolved_lists_keep_tokens() {
+      let known = MediaFileToken::new("mf_known".to_string());
+      let unknown = MediaFileToken::new("mf_unknown".to_string());
+      let map = HashMap::from([(known.clone(), "https://cdn.example.com/a.png".to_string())]);
+      let mut builder = GenerateVideoRequestBuilder {
+        reference_images: Some(ImageListRef::MediaFileTokens(vec![known, unknown])),
+        ..base_builder()
+      };
+      resolve_media_tokens_to_urls(&mut builder, Some(&map));
+      assert!(matches!(builder.reference_images, Some(ImageListRef::MediaFileTokens(_))));
+    }
+  }
+
+  mod failover_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn unconfigured_clients_are_skipped_and_exhausted() {
+      let client = RouterClient::Multi(MultiRouterClientBuilder::new().build());
+      let policy = VideoFailoverPolicy::default_for_model(CommonVideoModel::Seedance2p0);
+
+      let result = client.generate_video_with_failover(
+        &base_builder(),
+        &policy,
+        VideoGenerationDraftContext::default(),
+      ).await;
+
+      match result {
+        Err(ArtcraftRouterError::FailoverExhausted(attempts)) => {
+          assert_eq!(attempts.len(), 3);
+          assert!(attempts.iter().all(|attempt| {
+            matches!(attempt.outcome, VideoFailoverAttemptOutcome::ClientNotConfigured)
+          }));
+        }
+        other => panic!("expected FailoverExhausted, got {:?}", other),
+      }
+    }
+
+    #[test]
+    fn open_circuits_are_tried_last() {
+      use crate::health::provider_health_registry::{ProviderCallOutcome, ProviderHealthRegistry};
+      use std::time::Duration;
+
+      let registry = ProviderHealthRegistry::default();
+      for _ in 0..10 {
+        registry.record(Provider::Seedance2Pro, Duration::from_millis(10), ProviderCallOutcome::Failure);
+      }
+
+      let client = RouterClient::Multi(MultiRouterClientBuilder::new()
+        .set_health_registry(registry)
+        .build());
+      let policy = VideoFailoverPolicy::default_for_model(CommonVideoModel::Seedance2p0);
+
+      let providers: Vec<Provider> = order_by_health(&client, &policy)
+        .into_iter()
+        .map(|variant| variant.provider)
+        .collect();
+
+      assert!(matches!(providers.as_slice(), [Provider::GmiCloud, Provider::Artcraft, Provider::Seedance2Pro]));
+    }
+
+    #[tokio::test]
+    async fn empty_policy_is_invalid() {
+      let client = RouterClient::Multi(MultiRouterClientBuilder::new().build());
+      let policy = VideoFailoverPolicy::new(vec![]);
+
+      let result = client.generate_video_with_failover(
+        &base_builder(),
+        &policy,
+        VideoGenerationDraftContext::default(),
+      ).await;
+
+      assert!(matches!(result, Err(ArtcraftRouterError::InvalidInput(_))));
+    }
+  }
+}
@@ -27,6 +27,10 @@ use crate::generate::generate_video_v2::providers::kinovi::seedance_2p0::cost::K
 use crate::generate::generate_video_v2::providers::kinovi::seedance_2p0::request::KinoviSeedance2p0RequestState;
 use crate::generate::generate_video_v2::providers::kinovi::seedance_2p0_fast::cost::KinoviSeedance2p0FastCostState;
 use crate::generate::generate_video_v2::providers::kinovi::seedance_2p0_fast::request::KinoviSeedance2p0FastRequestState;
+use crate::generate::generate_video_v2::providers::mock::cost::MockVideoCostState;
+use crate::generate::generate_video_v2::providers::mock::request::MockVideoRequestState;
+use crate::generate::generate_video_v2::providers::replicate::seedance_1p0_lite::cost::ReplicateSeedance1p0LiteCostState;
+use crate::generate::generate_video_v2::providers::replicate::seedance_1p0_lite::request::ReplicateSeedance1p0LiteRequestState;
 
 #[derive(Clone, Debug)]
 pub enum VideoGenerationRequest {
@@ -42,6 +46,8 @@ pub enum VideoGenerationRequest {
   KinoviHappyHorse1p0(KinoviHappyHorse1p0RequestState),
   KinoviSeedance2p0(KinoviSeedance2p0RequestState),
   KinoviSeedance2p0Fast(KinoviSeedance2p0FastRequestState),
+  Mock(MockVideoRequestState),
+  ReplicateSeedance1p0Lite(ReplicateSeedance1p0LiteRequestState),
 }
 
 impl VideoGenerationRequest {
@@ -60,6 +66,8 @@ impl VideoGenerationRequest {
       Self::KinoviHappyHorse1p0(_) => Provider::Seedance2Pro,
       Self::KinoviSeedance2p0(_) => Provider::Seedance2Pro,
       Self::KinoviSeedance2p0Fast(_) => Provider::Seedance2Pro,
+      Self::Mock(_) => Provider::Mock,
+      Self::ReplicateSeedance1p0Lite(_) => Provider::Replicate,
     }
   }
 
@@ -78,12 +86,19 @@ impl VideoGenerationRequest {
       VideoGenerationRequest::KinoviHappyHorse1p0(request) => Ok(KinoviHappyHorse1p0CostState::from_request(request).estimate_cost()),
       VideoGenerationRequest::KinoviSeedance2p0(request) => Ok(KinoviSeedance2p0CostState::from_request(request).estimate_cost()),
       VideoGenerationRequest::KinoviSeedance2p0Fast(request) => Ok(KinoviSeedance2p0FastCostState::from_request(request).estimate_cost()),
+      VideoGenerationRequest::Mock(request) => Ok(MockVideoCostState::from_request(request).estimate_cost()),
+      VideoGenerationRequest::ReplicateSeedance1p0Lite(request) => Ok(ReplicateSeedance1p0LiteCostState::from_request(request).estimate_cost()),
     }
   }
 
   /// Send the video generation request
   /// If successful, returns the job IDs.
+  /// Calls pass through the provider's circuit breaker if the client tracks health.
   pub async fn send_request(&self, client: &RouterClient) -> Result<GenerateVideoResponse, ArtcraftRouterError> {
+    client.track_provider_call(self.get_provider(), self.send_request_untracked(client)).await
+  }
+
+  async fn send_request_untracked(&self, client: &RouterClient) -> Result<GenerateVideoResponse, ArtcraftRouterError> {
     match self {
       VideoGenerationRequest::ArtcraftHappyHorse1p0(request) => {
         let client_ref = client.get_artcraft_client_ref()?;
@@ -133,6 +148,14 @@ impl VideoGenerationRequest {
         let client_ref = client.get_seedance2pro_client_ref()?;
         request.send(client_ref).await
       },
+      VideoGenerationRequest::Mock(request) => {
+        let client_ref = client.get_mock_client_ref()?;
+        request.send(client_ref).await
+      },
+      VideoGenerationRequest::ReplicateSeedance1p0Lite(request) => {
+        let client_ref = client.get_replicate_client_ref()?;
+        request.send(client_ref).await
+      },
     }
   }
 }
@@ -1,3 +1,4 @@
+pub mod cancel_generation;
 pub mod generate_image;
 pub mod generate_image_v2;
 pub mod generate_splat;
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+/// Tuning for when a provider's circuit trips open and when it is retried.
+#[derive(Clone, Debug)]
+pub struct CircuitBreakerConfig {
+  /// Calls older than this no longer count towards the error rate or latency percentiles.
+  pub window: Duration,
+
+  /// The maximum number of calls kept per provider (oldest are dropped first).
+  pub max_samples: usize,
+
+  /// Don't trip on error rate until at least this many calls are in the window.
+  pub min_samples_to_trip: usize,
+
+  /// Trip the circuit when the error rate in the window reaches this fraction (0.0 - 1.0).
+  pub error_rate_to_trip: f64,
+
+  /// Trip the circuit after this many failures in a row, regardless of the error rate.
+  pub consecutive_failures_to_trip: u32,
+
+  /// How long an open circuit refuses calls before letting a single probe through.
+  pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+  fn default() -> Self {
+    Self {
+      window: Duration::from_secs(5 * 60),
+      max_samples: 200,
+      min_samples_to_trip: 10,
+      error_rate_to_trip: 0.5,
+      consecutive_failures_to_trip: 5,
+      open_duration: Duration::from_secs(60),
+    }
+  }
+}
@@ -0,0 +1,15 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// The state of a provider's circuit breaker.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+  /// The provider is healthy and receives traffic.
+  Closed,
+
+  /// The provider is failing and requests to it are refused.
+  Open,
+
+  /// The provider was failing; a single probe request is allowed through to test it.
+  HalfOpen,
+}
@@ -0,0 +1,4 @@
+pub mod circuit_breaker_config;
+pub mod circuit_state;
+pub mod provider_health_registry;
+pub mod provider_health_snapshot;
@@ -0,0 +1,629 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+
+use crate::api::provider::Provider;
+use crate::errors::artcraft_router_error::ArtcraftRouterError;
+use crate::health::circuit_breaker_config::CircuitBreakerConfig;
+use crate::health::circuit_state::CircuitState;
+use crate::health::provider_health_snapshot::ProviderHealthSnapshot;
+
+/// How a call to a provider should be counted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProviderCallOutcome {
+  /// The provider accepted the request.
+  Success,
+
+  /// The provider failed (API error, outage, billing problem).
+  Failure,
+
+  /// The call failed for reasons that aren't the provider's fault (bad input, missing client).
+  NotAttributable,
+}
+
+impl ProviderCallOutcome {
+  pub fn from_result<T>(result: &Result<T, ArtcraftRouterError>) -> Self {
+    match result {
+      Ok(_) => Self::Success,
+      Err(ArtcraftRouterError::Provider(_)) => Self::Failure,
+      Err(ArtcraftRouterError::ProviderBillingError(_)) => Self::Failure,
+      Err(_) => Self::NotAttributable,
+    }
+  }
+}
+
+/// Per-provider health tracking with a circuit breaker.
+///
+/// Cheap to clone; clones share the same state, so a single registry can be
+/// handed to every router client built over the lifetime of a server.
+#[derive(Clone)]
+pub struct ProviderHealthRegistry {
+  config: Arc<CircuitBreakerConfig>,
+  providers: Arc<Mutex<HashMap<Provider, ProviderHealthState>>>,
+}
+
+struct CallSample {
+  at: Instant,
+  latency: Duration,
+  success: bool,
+}
+
+struct ProviderHealthState {
+  samples: VecDeque<CallSample>,
+  consecutive_failures: u32,
+  circuit_state: CircuitState,
+  opened_at: Option<Instant>,
+  probe_in_flight: bool,
+  total_successes: u64,
+  total_failures: u64,
+  total_refused: u64,
+}
+
+impl ProviderHealthState {
+  fn new() -> Self {
+    Self {
+      samples: VecDeque::new(),
+      consecutive_failures: 0,
+      circuit_state: CircuitState::Closed,
+      opened_at: None,
+      probe_in_flight: false,
+      total_successes: 0,
+      total_failures: 0,
+      total_refused: 0,
+    }
+  }
+
+  fn prune(&mut self, now: Instant, config: &CircuitBreakerConfig) {
+    while let Some(oldest) = self.samples.front() {
+      let expired = now.saturating_duration_since(oldest.at) > config.window;
+      if expired || self.samples.len() > config.max_samples {
+        self.samples.pop_front();
+      } else {
+        break;
+      }
+    }
+  }
+
+  fn failure_count(&self) -> usize {
+    self.samples.iter().filter(|sample| !sample.success).count()
+  }
+
+  fn error_rate(&self) -> f64 {
+    if self.samples.is_empty() {
+      return 0.0;
+    }
+    self.failure_count() as f64 / self.samples.len() as f64
+  }
+
+  fn cooldown_elapsed(&self, now: Instant, config: &CircuitBreakerConfig) -> bool {
+    self.opened_at
+      .map(|opened_at| now.saturating_duration_since(opened_at) >= config.open_duration)
+      .unwrap_or(true)
+  }
+
+  fn should_trip(&self, config: &CircuitBreakerConfig) -> bool {
+    if self.consecutive_failures >= config.consecutive_failures_to_trip {
+      return true;
+    }
+    self.samples.len() >= config.min_samples_to_trip
+      && self.error_rate() >= config.error_rate_to_trip
+  }
+
+  fn open(&mut self, now: Instant) {
+    self.circuit_state = CircuitState::Open;
+    self.opened_at = Some(now);
+  }
+}
+
+impl ProviderHealthRegistry {
+  pub fn new(config: CircuitBreakerConfig) -> Self {
+    Self {
+      config: Arc::new(config),
+      providers: Arc::new(Mutex::new(HashMap::new())),
+    }
+  }
+
+  /// Ask to send a request to the provider, returning a permit to report the outcome with.
+  /// Returns None if the circuit is open (or a half-open probe is already in flight).
+  pub fn admit(&self, provider: Provider) -> Option<ProviderCallPermit> {
+    let admission = self.try_admit_at(provider, Instant::now())?;
+    Some(ProviderCallPermit {
+      registry: self.clone(),
+      provider,
+      started_at: Instant::now(),
+      is_probe: admission == Admission::Probe,
+      finished: false,
+    })
+  }
+
+  /// Whether the provider would currently be admitted, without claiming a probe slot.
+  /// Used to deprioritize unhealthy providers when choosing between equivalents.
+  pub fn is_available(&self, provider: Provider) -> bool {
+    self.is_available_at(provider, Instant::now())
+  }
+
+  /// Report the result of a call made without a permit. It counts towards the provider's
+  /// health, but never settles a half-open probe.
+  pub fn record(&self, provider: Provider, latency: Duration, outcome: ProviderCallOutcome) {
+    self.record_at(provider, Instant::now(), latency, outcome, false)
+  }
+
+  /// Health of a single provider. Providers that were never called report as healthy.
+  pub fn snapshot(&self, provider: Provider) -> ProviderHealthSnapshot {
+    self.snapshot_at(provider, Instant::now())
+  }
+
+  /// Health of every provider that has been called.
+  pub fn snapshot_all(&self) -> Vec<ProviderHealthSnapshot> {
+    let now = Instant::now();
+    let mut providers: Vec<Provider> = self.lock().keys().copied().collect();
+    providers.sort_by_key(|provider| format!("{:?}", provider));
+    providers.into_iter()
+      .map(|provider| self.snapshot_at(provider, now))
+      .collect()
+  }
+
+  /// Manually close a provider's circuit and forget its history (eg. after an on-call fix).
+  pub fn reset(&self, provider: Provider) {
+    self.lock().remove(&provider);
+  }
+
+  fn try_admit_at(&self, provider: Provider, now: Instant) -> Option<Admission> {
+    let mut providers = self.lock();
+    let state = providers.entry(provider).or_insert_with(ProviderHealthState::new);
+
+    let admission = match state.circuit_state {
+      CircuitState::Closed => Some(Admission::Call),
+      CircuitState::Open if state.cooldown_elapsed(now, &self.config) => {
+        info!("Provider {:?} circuit is half-open; allowing a probe request", provider);
+        state.circuit_state = CircuitState::HalfOpen;
+        state.probe_in_flight = true;
+        Some(Admission::Probe)
+      }
+      CircuitState::Open => None,
+      CircuitState::HalfOpen if state.probe_in_flight => None,
+      CircuitState::HalfOpen => {
+        state.probe_in_flight = true;
+        Some(Admission::Probe)
+      }
+    };
+
+    if admission.is_none() {
+      state.total_refused += 1;
+    }
+
+    admission
+  }
+
+  fn is_available_at(&self, provider: Provider, now: Instant) -> bool {
+    let providers = self.lock();
+    match providers.get(&provider) {
+      None => true,
+      Some(state) => match state.circuit_state {
+        CircuitState::Closed => true,
+        CircuitState::Open => state.cooldown_elapsed(now, &self.config),
+        CircuitState::HalfOpen => !state.probe_in_flight,
+      },
+    }
+  }
+
+  /// `is_probe` is whether the call was admitted as the half-open probe. Other calls (eg. slow
+  /// ones admitted before the circuit opened) are counted, but don't decide the probe.
+  fn record_at(&self, provider: Provider, now: Instant, latency: Duration, outcome: ProviderCallOutcome, is_probe: bool) {
+    let mut providers = self.lock();
+    let state = providers.entry(provider).or_insert_with(ProviderHealthState::new);
+
+    if is_probe {
+      state.probe_in_flight = false;
+    }
+
+    match outcome {
+      ProviderCallOutcome::NotAttributable => return,
+      ProviderCallOutcome::Success => {
+        state.total_successes += 1;
+        state.consecutive_failures = 0;
+        state.samples.push_back(CallSample { at: now, latency, success: true });
+
+        if is_probe && state.circuit_state == CircuitState::HalfOpen {
+          info!("Provider {:?} probe succeeded; closing circuit", provider);
+          state.circuit_state = CircuitState::Closed;
+          state.opened_at = None;
+          // Start over so the failures that tripped the circuit don't immediately re-trip it.
+          state.samples.clear();
+          state.samples.push_back(CallSample { at: now, latency, success: true });
+        }
+      }
+      ProviderCallOutcome::Failure => {
+        state.total_failures += 1;
+        state.consecutive_failures += 1;
+        state.samples.push_back(CallSample { at: now, latency, success: false });
+      }
+    }
+
+    state.prune(now, &self.config);
+
+    if outcome == ProviderCallOutcome::Failure {
+      match state.circuit_state {
+        CircuitState::HalfOpen if is_probe => {
+          warn!("Provider {:?} probe failed; re-opening circuit", provider);
+          state.open(now);
+        }
+        CircuitState::Closed if state.should_trip(&self.config) => {
+          warn!("Provider {:?} circuit tripped open (error rate {:.2}, {} consecutive failures)",
+            provider, state.error_rate(), state.consecutive_failures);
+          state.open(now);
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn snapshot_at(&self, provider: Provider, now: Instant) -> ProviderHealthSnapshot {
+    let providers = self.lock();
+
+    let state = match providers.get(&provider) {
+      Some(state) => state,
+      None => return empty_snapshot(provider),
+    };
+
+    let window_start = now.checked_sub(self.config.window);
+    let mut latencies: Vec<u64> = Vec::with_capacity(state.samples.len());
+    let mut window_failure_count = 0;
+
+    for sample in state.samples.iter() {
+      if window_start.is_some_and(|start| sample.at < start) {
+        continue;
+      }
+      latencies.push(sample.latency.as_millis() as u64);
+      if !sample.success {
+        window_failure_count += 1;
+      }
+    }
+
+    latencies.sort_unstable();
+
+    let window_call_count = latencies.len();
+    let window_error_rate = if window_call_count == 0 {
+      0.0
+    } else {
+      window_failure_count as f64 / window_call_count as f64
+    };
+
+    let seconds_until_probe = match (state.circuit_state, state.opened_at) {
+      (CircuitState::Open, Some(opened_at)) => {
+        let elapsed = now.saturating_duration_since(opened_at);
+        Some(self.config.open_duration.saturating_sub(elapsed).as_secs())
+      }
+      _ => None,
+    };
+
+    ProviderHealthSnapshot {
+      provider,
+      circuit_state: state.circuit_state,
+      window_call_count,
+      window_failure_count,
+      window_error_rate,
+      consecutive_failures: state.consecutive_failures,
+      latency_p50_millis: percentile(&latencies, 50),
+      latency_p90_millis: percentile(&latencies, 90),
+      latency_p99_millis: percentile(&latencies, 99),
+      total_successes: state.total_successes,
+      total_failures: state.total_failures,
+      total_refused: state.total_refused,
+      seconds_until_probe,
+    }
+  }
+
+  fn lock(&self) -> MutexGuard<'_, HashMap<Provider, ProviderHealthState>> {
+    // A panic while holding the lock can't leave the stats in a dangerous state.
+    self.providers.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+/// How `try_admit_at` let a call through.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Admission {
+  Call,
+
+  /// The single call allowed through a half-open circuit to test the provider.
+  Probe,
+}
+
+/// An admitted call to a provider. Report how it went with `finish`.
+///
+/// If the permit is dropped unfinished (eg. the request future was cancelled mid-call),
+/// the call is recorded as not attributable, which frees a half-open probe slot without
+/// counting against the provider.
+pub struct ProviderCallPermit {
+  registry: ProviderHealthRegistry,
+  provider: Provider,
+  started_at: Instant,
+  is_probe: bool,
+  finished: bool,
+}
+
+impl ProviderCallPermit {
+  pub fn finish(mut self, outcome: ProviderCallOutcome) {
+    self.finished = true;
+    self.registry.record_at(self.provider, Instant::now(), self.started_at.elapsed(), outcome, self.is_probe);
+  }
+}
+
+impl Drop for ProviderCallPermit {
+  fn drop(&mut self) {
+    if !self.finished {
+      warn!("Call to provider {:?} was abandoned before it finished", self.provider);
+      self.registry.record_at(self.provider, Instant::now(), self.started_at.elapsed(), ProviderCallOutcome::NotAttributable, self.is_probe);
+    }
+  }
+}
+
+impl Default for ProviderHealthRegistry {
+  fn default() -> Self {
+    Self::new(CircuitBreakerConfig::default())
+  }
+}
+
+fn empty_snapshot(provider: Provider) -> ProviderHealthSnapshot {
+  ProviderHealthSnapshot {
+    provider,
+    circuit_state: CircuitState::Closed,
+    window_call_count: 0,
+    window_failure_count: 0,
+    window_error_rate: 0.0,
+    consecutive_failures: 0,
+    latency_p50_millis: None,
+    latency_p90_millis: None,
+    latency_p99_millis: None,
+    total_successes: 0,
+    total_failures: 0,
+    total_refused: 0,
+    seconds_until_probe: None,
+  }
+}
+
+/// Nearest-rank percentile of an already sorted list.
+fn percentile(sorted: &[u64], percentile: usize) -> Option<u64> {
+  if sorted.is_empty() {
+    return None;
+  }
+  let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+  sorted.get(rank - 1).copied()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn config() -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+      window: Duration::from_secs(60),
+      max_samples: 100,
+      min_samples_to_trip: 4,
+      error_rate_to_trip: 0.5,
+      consecutive_failures_to_trip: 3,
+      open_duration: Duration::from_secs(30),
+    }
+  }
+
+  fn millis(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+  }
+
+  #[test]
+  fn unknown_provider_is_healthy() {
+    let registry = ProviderHealthRegistry::new(config());
+    let snapshot = registry.snapshot(Provider::Fal);
+    assert_eq!(snapshot.circuit_state, CircuitState::Closed);
+    assert_eq!(snapshot.window_call_count, 0);
+    assert!(registry.is_available(Provider::Fal));
+  }
+
+  #[test]
+  fn consecutive_failures_trip_the_circuit() {
+    let registry = ProviderHealthRegistry::new(config());
+    let now = Instant::now();
+    for _ in 0..3 {
+      assert!(registry.try_admit_at(Provider::Seedance2Pro, now).is_some());
+      registry.record_at(Provider::Seedance2Pro, now, millis(100), ProviderCallOutcome::Failure, false);
+    }
+    assert_eq!(registry.snapshot_at(Provider::Seedance2Pro, now).circuit_state, CircuitState::Open);
+    assert!(registry.try_admit_at(Provider::Seedance2Pro, now).is_none());
+    assert!(!registry.is_available_at(Provider::Seedance2Pro, now));
+    assert_eq!(registry.snapshot_at(Provider::Seedance2Pro, now).total_refused, 1);
+  }
+
+  #[test]
+  fn error_rate_trips_the_circuit() {
+    let registry = ProviderHealthRegistry::new(config());
+    let now = Instant::now();
+    let outcomes = [
+      ProviderCallOutcome::Failure,
+      ProviderCallOutcome::Success,
+      ProviderCallOutcome::Failure,
+      ProviderCallOutcome::Success,
+    ];
+    for outcome in outcomes {
+      registry.try_admit_at(Provider::GmiCloud, now);
+      registry.record_at(Provider::GmiCloud, now, millis(100), outcome, false);
+    }
+    assert_eq!(registry.snapshot_at(Provider::GmiCloud, now).circuit_state, CircuitState::Closed);
+
+    registry.try_admit_at(Provider::GmiCloud, now);
+    registry.record_at(Provider::GmiCloud, now, millis(100), ProviderCallOutcome::Failure, false);
+    assert_eq!(registry.snapshot_at(Provider::GmiCloud, now).circuit_state, CircuitState::Open);
+  }
+
+  #[test]
+  fn not_attributable_errors_are_ignored() {
+    let registry = ProviderHealthRegistry::new(config());
+    let now = Instant::now();
+    for _ in 0..10 {
+      registry.try_admit_at(Provider::Fal, now);
+      registry.record_at(Provider::Fal, now, millis(5), ProviderCallOutcome::NotAttributable, false);
+    }
+    let snapshot = registry.snapshot_at(Provider::Fal, now);
+    assert_eq!(snapshot.circuit_state, CircuitState::Closed);
+    assert_eq!(snapshot.window_call_count, 0);
+  }
+
+  #[test]
+  fn half_open_probe_success_closes_circuit() {
+    let registry = ProviderHealthRegistry::new(config());
+    let start = Instant::now();
+    for _ in 0..3 {
+      registry.try_admit_at(Provider::Muapi, start);
+      registry.record_at(Provider::Muapi, start, millis(100), ProviderCallOutcome::Failure, false);
+    }
+
+    let later = start + Duration::from_secs(31);
+    assert!(registry.is_available_at(Provider::Muapi, later));
+    assert_eq!(registry.try_admit_at(Provider::Muapi, later), Some(Admission::Probe));
+    assert_eq!(registry.snapshot_at(Provider::Muapi, later).circuit_state, CircuitState::HalfOpen);
+
+    // Only one probe at a time.
+    assert!(registry.try_admit_at(Provider::Muapi, later).is_none());
+
+    registry.record_at(Provider::Muapi, later, millis(100), ProviderCallOutcome::Success, true);
+    let snapshot = registry.snapshot_at(Provider::Muapi, later);
+    assert_eq!(snapshot.circuit_state, CircuitState::Closed);
+    assert_eq!(snapshot.window_failure_count, 0);
+    assert!(registry.try_admit_at(Provider::Muapi, later).is_some());
+  }
+
+  #[test]
+  fn half_open_probe_failure_reopens_circuit() {
+    let registry = ProviderHealthRegistry::new(config());
+    let start = Instant::now();
+    for _ in 0..3 {
+      registry.try_admit_at(Provider::Artcraft, start);
+      registry.record_at(Provider::Artcraft, start, millis(100), ProviderCallOutcome::Failure, false);
+    }
+
+    let later = start + Duration::from_secs(31);
+    assert_eq!(registry.try_admit_at(Provider::Artcraft, later), Some(Admission::Probe));
+    registry.record_at(Provider::Artcraft, later, millis(100), ProviderCallOutcome::Failure, true);
+
+    let snapshot = registry.snapshot_at(Provider::Artcraft, later);
+    assert_eq!(snapshot.circuit_state, CircuitState::Open);
+    assert_eq!(snapshot.seconds_until_probe, Some(30));
+    assert!(registry.try_admit_at(Provider::Artcraft, later + Duration::from_secs(1)).is_none());
+  }
+
+  #[test]
+  fn old_samples_leave_the_window() {
+    let registry = ProviderHealthRegistry::new(config());
+    let start = Instant::now();
+    registry.try_admit_at(Provider::Fal, start);
+    registry.record_at(Provider::Fal, start, millis(100), ProviderCallOutcome::Failure, false);
+
+    let later = start + Duration::from_secs(120);
+    let snapshot = registry.snapshot_at(Provider::Fal, later);
+    assert_eq!(snapshot.window_call_count, 0);
+    assert_eq!(snapshot.total_failures, 1);
+  }
+
+  #[test]
+  fn latency_percentiles() {
+    let registry = ProviderHealthRegistry::new(config());
+    let now = Instant::now();
+    for ms in 1..=100 {
+      registry.try_admit_at(Provider::Fal, now);
+      registry.record_at(Provider::Fal, now, millis(ms), ProviderCallOutcome::Success, false);
+    }
+    let snapshot = registry.snapshot_at(Provider::Fal, now);
+    assert_eq!(snapshot.latency_p50_millis, Some(50));
+    assert_eq!(snapshot.latency_p90_millis, Some(90));
+    assert_eq!(snapshot.latency_p99_millis, Some(99));
+  }
+
+  fn trip_with_instant_cooldown(provider: Provider) -> ProviderHealthRegistry {
+    let registry = ProviderHealthRegistry::new(CircuitBreakerConfig {
+      open_duration: Duration::ZERO,
+      ..config()
+    });
+    for _ in 0..3 {
+      registry.record(provider, millis(1), ProviderCallOutcome::Failure);
+    }
+    registry
+  }
+
+  #[test]
+  fn finished_probe_permit_closes_circuit() {
+    let registry = trip_with_instant_cooldown(Provider::Fal);
+    let permit = registry.admit(Provider::Fal).expect("probe should be admitted");
+    assert!(registry.admit(Provider::Fal).is_none());
+    permit.finish(ProviderCallOutcome::Success);
+    assert_eq!(registry.snapshot(Provider::Fal).circuit_state, CircuitState::Closed);
+  }
+
+  #[test]
+  fn dropped_probe_permit_frees_the_probe_slot() {
+    let registry = trip_with_instant_cooldown(Provider::Fal);
+    let permit = registry.admit(Provider::Fal).expect("probe should be admitted");
+    assert!(registry.admit(Provider::Fal).is_none());
+    drop(permit);
+
+    let snapshot = registry.snapshot(Provider::Fal);
+    assert_eq!(snapshot.circuit_state, CircuitState::HalfOpen);
+    assert_eq!(snapshot.total_failures, 3);
+    assert!(registry.admit(Provider::Fal).is_some());
+  }
+
+  #[test]
+  fn stale_call_does_not_settle_the_probe() {
+    let registry = ProviderHealthRegistry::new(CircuitBreakerConfig {
+      open_duration: Duration::ZERO,
+      ..config()
+    });
+
+    // A slow call admitted while the circuit was still closed.
+    let stale = registry.admit(Provider::Fal).expect("call should be admitted");
+    for _ in 0..3 {
+      registry.record(Provider::Fal, millis(1), ProviderCallOutcome::Failure);
+    }
+
+    let probe = registry.admit(Provider::Fal).expect("probe should be admitted");
+
+    // The stale call finishing neither closes the circuit nor frees the probe slot.
+    stale.finish(ProviderCallOutcome::Success);
+    assert_eq!(registry.snapshot(Provider::Fal).circuit_state, CircuitState::HalfOpen);
+    assert!(registry.admit(Provider::Fal).is_none());
+
+    probe.finish(ProviderCallOutcome::Success);
+    assert_eq!(registry.snapshot(Provider::Fal).circuit_state, CircuitState::Closed);
+  }
+
+  #[test]
+  fn stale_failure_does_not_reopen_a_half_open_circuit() {
+    let registry = ProviderHealthRegistry::new(config());
+    let start = Instant::now();
+    assert_eq!(registry.try_admit_at(Provider::Fal, start), Some(Admission::Call));
+    for _ in 0..3 {
+      registry.record_at(Provider::Fal, start, millis(100), ProviderCallOutcome::Failure, false);
+    }
+
+    let later = start + Duration::from_secs(31);
+    assert_eq!(registry.try_admit_at(Provider::Fal, later), Some(Admission::Probe));
+
+    registry.record_at(Provider::Fal, later, millis(100), ProviderCallOutcome::Failure, false);
+    assert_eq!(registry.snapshot_at(Provider::Fal, later).circuit_state, CircuitState::HalfOpen);
+
+    registry.record_at(Provider::Fal, later, millis(100), ProviderCallOutcome::Success, true);
+    assert_eq!(registry.snapshot_at(Provider::Fal, later).circuit_state, CircuitState::Closed);
+  }
+
+  #[test]
+  fn reset_forgets_history() {
+    let registry = ProviderHealthRegistry::new(config());
+    for _ in 0..3 {
+      registry.record(Provider::Fal, millis(1), ProviderCallOutcome::Failure);
+    }
+    assert!(!registry.is_available(Provider::Fal));
+    registry.reset(Provider::Fal);
+    assert!(registry.is_available(Provider::Fal));
+    assert!(registry.snapshot_all().is_empty());
+  }
+}
@@ -0,0 +1,38 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::api::provider::Provider;
+use crate::health::circuit_state::CircuitState;
+
+/// A point-in-time view of a provider's health, suitable for display.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProviderHealthSnapshot {
+  pub provider: Provider,
+
+  pub circuit_state: CircuitState,
+
+  /// Calls in the rolling window.
+  pub window_call_count: usize,
+
+  /// Failed calls in the rolling window.
+  pub window_failure_count: usize,
+
+  /// Fraction of failed calls in the rolling window (0.0 - 1.0).
+  pub window_error_rate: f64,
+
+  pub consecutive_failures: u32,
+
+  /// Latency percentiles of calls in the rolling window.
+  pub latency_p50_millis: Option<u64>,
+  pub latency_p90_millis: Option<u64>,
+  pub latency_p99_millis: Option<u64>,
+
+  /// Lifetime counters (since the registry was created).
+  pub total_successes: u64,
+  pub total_failures: u64,
+
+  /// Calls that were refused because the circuit was open.
+  pub total_refused: u64,
+
+  /// If the circuit is open, how long until a probe is allowed through.
+  pub seconds_until_probe: Option<u64>,
+}
@@ -2,6 +2,8 @@ pub mod api;
 pub mod client;
 pub mod errors;
 pub mod generate;
+pub mod health;
+pub mod mock;
 pub mod utils;
 
 #[cfg(test)]
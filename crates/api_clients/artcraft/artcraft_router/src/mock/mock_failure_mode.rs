@@ -0,0 +1,27 @@
+/// How the mock provider should misbehave.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MockFailureMode {
+  /// Every job is accepted and completes.
+  Never,
+
+  /// Every submission is rejected up front, as if the provider were down.
+  RejectAllSubmissions,
+
+  /// Jobs are accepted, then fail once they finish processing.
+  FailAllJobs,
+
+  /// Every nth job (by submission order, starting at 1) fails after processing.
+  FailEveryNthJob(u64),
+}
+
+impl MockFailureMode {
+  pub(crate) fn job_fails(&self, sequence: u64) -> bool {
+    match self {
+      Self::Never => false,
+      Self::RejectAllSubmissions => false,
+      Self::FailAllJobs => true,
+      Self::FailEveryNthJob(0) => false,
+      Self::FailEveryNthJob(n) => sequence % n == 0,
+    }
+  }
+}
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::mock::mock_failure_mode::MockFailureMode;
+use crate::mock::mock_job_status::{MockJobOutput, MockJobStatus};
+use crate::mock::mock_media_kind::MockMediaKind;
+use crate::mock::mock_provider_config::MockProviderConfig;
+use crate::mock::mock_provider_error::MockProviderError;
+
+/// Every mock job id starts with this.
+pub(crate) const MOCK_JOB_ID_PREFIX: &str = "mock_";
+
+/// Jobs submitted to the mock provider. Status is derived from elapsed time,
+/// so nothing runs in the background. Clones share the same jobs.
+#[derive(Clone)]
+pub(crate) struct MockJobQueue {
+  config: MockProviderConfig,
+  state: Arc<Mutex<MockJobQueueState>>,
+}
+
+#[derive(Default)]
+struct MockJobQueueState {
+  last_sequence: u64,
+  jobs: HashMap<String, MockJob>,
+}
+
+struct MockJob {
+  sequence: u64,
+  kind: MockMediaKind,
+  submitted_at: Instant,
+  fails: bool,
+  cancelled: bool,
+}
+
+impl MockJobQueue {
+  pub fn new(config: MockProviderConfig) -> Self {
+    Self {
+      config,
+      state: Arc::new(Mutex::new(MockJobQueueState::default())),
+    }
+  }
+
+  pub fn config(&self) -> &MockProviderConfig {
+    &self.config
+  }
+
+  /// Enqueue `count` jobs (at least one). Ids are sequential per queue, eg. `mock_video_00000001`.
+  pub fn submit_at(&self, kind: MockMediaKind, count: u16, now: Instant) -> Result<Vec<String>, MockProviderError> {
+    if self.config.failure_mode == MockFailureMode::RejectAllSubmissions {
+      return Err(MockProviderError::SubmissionRejected);
+    }
+
+    let mut state = self.lock();
+    let mut job_ids = Vec::new();
+
+    for _ in 0..count.max(1) {
+      state.last_sequence += 1;
+      let sequence = state.last_sequence;
+      let job_id = format!("{}{}_{:08}", MOCK_JOB_ID_PREFIX, kind.to_str(), sequence);
+
+      state.jobs.insert(job_id.clone(), MockJob {
+        sequence,
+        kind,
+        submitted_at: now,
+        fails: self.config.failure_mode.job_fails(sequence),
+        cancelled: false,
+      });
+
+      job_ids.push(job_id);
+    }
+
+    Ok(job_ids)
+  }
+
+  pub fn status_at(&self, job_id: &str, now: Instant) -> Result<MockJobStatus, MockProviderError> {
+    let state = self.lock();
+
+    let job = state.jobs.get(job_id)
+      .ok_or_else(|| MockProviderError::UnknownJob(job_id.to_string()))?;
+
+    if job.cancelled {
+      return Ok(MockJobStatus::Cancelled);
+    }
+
+    let elapsed = now.saturating_duration_since(job.submitted_at);
+
+    if elapsed < self.config.queue_delay {
+      let position = state.jobs.values()
+        .filter(|other| other.sequence < job.sequence)
+        .filter(|other| now.saturating_duration_since(other.submitted_at) < self.config.queue_delay)
+        .count();
+      return Ok(MockJobStatus::Queued { position: position as u32 });
+    }
+
+    let processing_elapsed = elapsed - self.config.queue_delay;
+
+    if processing_elapsed < self.config.processing_delay {
+      let progress = processing_elapsed.as_millis() * 100 / self.config.processing_delay.as_millis().max(1);
+      return Ok(MockJobStatus::Processing { progress_percent: progress.min(99) as u8 });
+    }
+
+    if job.fails {
+      return Ok(MockJobStatus::Failed {
+        reason: format!("Mock job {} failed (failure mode {:?})", job_id, self.config.failure_mode),
+      });
+    }
+
+    Ok(MockJobStatus::Completed(MockJobOutput {
+      media_path: self.config.media_root.join(job.kind.placeholder_path()),
+      mime_type: job.kind.placeholder_mime_type(),
+    }))
+  }
+
+  /// Cancel a job that hasn't finished. Returns the status it had, so callers can tell
+  /// whether it was actually stopped (anything non-terminal) or had already finished.
+  pub fn cancel_at(&self, job_id: &str, now: Instant) -> Result<MockJobStatus, MockProviderError> {
+    let status = self.status_at(job_id, now)?;
+
+    if !status.is_terminal() {
+      if let Some(job) = self.lock().jobs.get_mut(job_id) {
+        job.cancelled = true;
+      }
+    }
+
+    Ok(status)
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, MockJobQueueState> {
+    // A panic while holding the lock can't leave the map half-written, so keep going.
+    self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  fn queue(failure_mode: MockFailureMode) -> MockJobQueue {
+    MockJobQueue::new(MockProviderConfig {
+      queue_delay: Duration::from_secs(2),
+      processing_delay: Duration::from_secs(10),
+      failure_mode,
+      ..MockProviderConfig::default()
+    })
+  }
+
+  mod job_ids {
+    use super::*;
+
+    #[test]
+    fn sequential_and_deterministic() {
+      let queue = queue(MockFailureMode::Never);
+      let now = Instant::now();
+      assert_eq!(queue.submit_at(MockMediaKind::Video, 2, now).unwrap(), vec!["mock_video_00000001", "mock_video_00000002"]);
+      assert_eq!(queue.submit_at(MockMediaKind::Image, 1, now).unwrap(), vec!["mock_image_00000003"]);
+    }
+
+    #[test]
+    fn zero_count_still_submits_one() {
+      let queue = queue(MockFailureMode::Never);
+      assert_eq!(queue.submit_at(MockMediaKind::Splat, 0, Instant::now()).unwrap(), vec!["mock_splat_00000001"]);
+    }
+
+    #[test]
+    fn clones_share_jobs() {
+      let queue = queue(MockFailureMode::Never);
+      let now = Instant::now();
+      let job_ids = queue.clone().submit_at(MockMediaKind::Image, 1, now).unwrap();
+      assert!(queue.status_at(&job_ids[0], now).is_ok());
+    }
+
+    #[test]
+    fn unknown_job() {
+      let queue = queue(MockFailureMode::Never);
+      let result = queue.status_at("mock_video_00000001", Instant::now());
+      assert!(matches!(result, Err(MockProviderError::UnknownJob(_))));
+    }
+  }
+
+  mod lifecycle {
+    use super::*;
+
+    #[test]
+    fn queued_then_processing_then_completed() {
+      let queue = queue(MockFailureMode::Never);
+      let start = Instant::now();
+      let job_id = queue.submit_at(MockMediaKind::Video, 1, start).unwrap().remove(0);
+
+      assert_eq!(queue.status_at(&job_id, start).unwrap(), MockJobStatus::Queued { position: 0 });
+      assert_eq!(queue.status_at(&job_id, start + Duration::from_secs(7)).unwrap(), MockJobStatus::Processing { progress_percent: 50 });
+
+      match queue.status_at(&job_id, start + Duration::from_secs(12)).unwrap() {
+        MockJobStatus::Completed(output) => {
+          assert!(output.media_path.ends_with("test_data/video/mp4/golden_sun_garoh.mp4"));
+          assert_eq!(output.mime_type, "video/mp4");
+        }
+        other => panic!("expected completion, got {:?}", other),
+      }
+    }
+
+    #[test]
+    fn queue_position_counts_earlier_queued_jobs() {
+      let queue = queue(MockFailureMode::Never);
+      let start = Instant::now();
+      let job_ids = queue.submit_at(MockMediaKind::Image, 3, start).unwrap();
+      assert_eq!(queue.status_at(&job_ids[2], start).unwrap(), MockJobStatus::Queued { position: 2 });
+    }
+
+    #[test]
+    fn placeholder_media_exists() {
+      let queue = MockJobQueue::new(MockProviderConfig::instant());
+      let now = Instant::now();
+      for kind in [MockMediaKind::Image, MockMediaKind::Video, MockMediaKind::Splat] {
+        let job_id = queue.submit_at(kind, 1, now).unwrap().remove(0);
+        match queue.status_at(&job_id, now).unwrap() {
+          MockJobStatus::Completed(output) => assert!(output.media_path.is_file(), "missing {:?}", output.media_path),
+          other => panic!("expected completion, got {:?}", other),
+        }
+      }
+    }
+  }
+
+  mod failure_modes {
+    use super::*;
+
+    #[test]
+    fn reject_all_submissions() {
+      let queue = queue(MockFailureMode::RejectAllSubmissions);
+      let result = queue.submit_at(MockMediaKind::Video, 1, Instant::now());
+      assert!(matches!(result, Err(MockProviderError::SubmissionRejected)));
+    }
+
+    #[test]
+    fn fail_all_jobs_after_processing() {
+      let queue = queue(MockFailureMode::FailAllJobs);
+      let start = Instant::now();
+      let job_id = queue.submit_at(MockMediaKind::Video, 1, start).unwrap().remove(0);
+      assert!(matches!(queue.status_at(&job_id, start + Duration::from_secs(5)).unwrap(), MockJobStatus::Processing { .. }));
+      assert!(matches!(queue.status_at(&job_id, start + Duration::from_secs(12)).unwrap(), MockJobStatus::Failed { .. }));
+    }
+
+    #[test]
+    fn fail_every_nth_job() {
+      let queue = queue(MockFailureMode::FailEveryNthJob(3));
+      let start = Instant::now();
+      let done = start + Duration::from_secs(12);
+      let job_ids = queue.submit_at(MockMediaKind::Image, 6, start).unwrap();
+      let failed = job_ids.iter()
+        .map(|job_id| matches!(queue.status_at(job_id, done).unwrap(), MockJobStatus::Failed { .. }))
+        .collect::<Vec<_>>();
+      assert_eq!(failed, vec![false, false, true, false, false, true]);
+    }
+  }
+
+  mod cancellation {
+    use super::*;
+
+    #[test]
+    fn cancel_queued_job() {
+      let queue = queue(MockFailureMode::Never);
+      let start = Instant::now();
+      let job_id = queue.submit_at(MockMediaKind::Video, 1, start).unwrap().remove(0);
+
+      assert_eq!(queue.cancel_at(&job_id, start).unwrap(), MockJobStatus::Queued { position: 0 });
+      assert_eq!(queue.status_at(&job_id, start + Duration::from_secs(12)).unwrap(), MockJobStatus::Cancelled);
+    }
+
+    #[test]
+    fn cancel_finished_job_is_a_no_op() {
+      let queue = queue(MockFailureMode::Never);
+      let start = Instant::now();
+      let done = start + Duration::from_secs(12);
+      let job_id = queue.submit_at(MockMediaKind::Image, 1, start).unwrap().remove(0);
+
+      assert!(matches!(queue.cancel_at(&job_id, done).unwrap(), MockJobStatus::Completed(_)));
+      assert!(matches!(queue.status_at(&job_id, done).unwrap(), MockJobStatus::Completed(_)));
+    }
+  }
+}
@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+/// Where a mock job is in its (simulated) lifecycle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MockJobStatus {
+  /// Waiting to start. `position` counts earlier jobs that are also still queued.
+  Queued { position: u32 },
+
+  Processing { progress_percent: u8 },
+
+  Completed(MockJobOutput),
+
+  Failed { reason: String },
+
+  Cancelled,
+}
+
+impl MockJobStatus {
+  pub fn is_terminal(&self) -> bool {
+    matches!(self, Self::Completed(_) | Self::Failed { .. } | Self::Cancelled)
+  }
+}
+
+/// The placeholder "result" of a completed mock job.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockJobOutput {
+  pub media_path: PathBuf,
+  pub mime_type: &'static str,
+}
@@ -0,0 +1,55 @@
+// NB: These mirror `test_data::local_files`, which is a dev-only dependency. (Checked in tests.)
+const PLACEHOLDER_IMAGE_PATH : &str = "test_data/image/juno.jpg";
+const PLACEHOLDER_VIDEO_PATH : &str = "test_data/video/mp4/golden_sun_garoh.mp4";
+const PLACEHOLDER_SPLAT_PATH : &str = "test_data/splat/ply/placeholder_cube.ply";
+
+/// The kind of media a mock job produces.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MockMediaKind {
+  Image,
+  Video,
+  Splat,
+}
+
+impl MockMediaKind {
+  /// Used in job ids, eg. `mock_video_00000001`.
+  pub fn to_str(&self) -> &'static str {
+    match self {
+      Self::Image => "image",
+      Self::Video => "video",
+      Self::Splat => "splat",
+    }
+  }
+
+  /// The placeholder output, relative to the repository root.
+  pub fn placeholder_path(&self) -> &'static str {
+    match self {
+      Self::Image => PLACEHOLDER_IMAGE_PATH,
+      Self::Video => PLACEHOLDER_VIDEO_PATH,
+      Self::Splat => PLACEHOLDER_SPLAT_PATH,
+    }
+  }
+
+  pub fn placeholder_mime_type(&self) -> &'static str {
+    match self {
+      Self::Image => "image/jpeg",
+      Self::Video => "video/mp4",
+      Self::Splat => "application/ply",
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_data::local_files::image_files::JUNO_JPG_PATH;
+  use test_data::local_files::splat_files::PLACEHOLDER_CUBE_PLY_PATH;
+  use test_data::local_files::video_files::GOLDEN_SUN_GAROH_MP4_PATH;
+
+  #[test]
+  fn placeholder_paths_match_test_data() {
+    assert_eq!(MockMediaKind::Image.placeholder_path(), JUNO_JPG_PATH);
+    assert_eq!(MockMediaKind::Video.placeholder_path(), GOLDEN_SUN_GAROH_MP4_PATH);
+    assert_eq!(MockMediaKind::Splat.placeholder_path(), PLACEHOLDER_CUBE_PLY_PATH);
+  }
+}
@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::mock::mock_failure_mode::MockFailureMode;
+
+#[derive(Clone, Debug)]
+pub struct MockProviderConfig {
+  /// How long a job sits in the queue before it starts processing.
+  pub queue_delay: Duration,
+
+  /// How long a job processes before it completes (or fails).
+  pub processing_delay: Duration,
+
+  pub failure_mode: MockFailureMode,
+
+  /// The repository root that placeholder media paths are resolved against.
+  /// Defaults to this checkout; override it when running from a packaged build.
+  pub media_root: PathBuf,
+}
+
+impl MockProviderConfig {
+  /// Jobs complete as soon as they're submitted.
+  pub fn instant() -> Self {
+    Self {
+      queue_delay: Duration::ZERO,
+      processing_delay: Duration::ZERO,
+      ..Self::default()
+    }
+  }
+}
+
+impl Default for MockProviderConfig {
+  fn default() -> Self {
+    Self {
+      queue_delay: Duration::from_secs(2),
+      processing_delay: Duration::from_secs(5),
+      failure_mode: MockFailureMode::Never,
+      media_root: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../../.."),
+    }
+  }
+}
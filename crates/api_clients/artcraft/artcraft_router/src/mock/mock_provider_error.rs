@@ -0,0 +1,22 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum MockProviderError {
+  /// The mock was configured to reject submissions.
+  SubmissionRejected,
+
+  /// No job with this id was submitted to this client.
+  UnknownJob(String),
+}
+
+impl Error for MockProviderError {}
+
+impl Display for MockProviderError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::SubmissionRejected => write!(f, "Mock provider rejected the submission"),
+      Self::UnknownJob(job_id) => write!(f, "Mock provider has no job with id '{}'", job_id),
+    }
+  }
+}
@@ -0,0 +1,12 @@
+//! An offline stand-in for real generation providers (`Provider::Mock`).
+//!
+//! Accepts any image, video, or splat request, hands out deterministic job ids,
+//! walks each job through queued/processing states on a timer, and "completes"
+//! it with a placeholder file from the repository's `test_data` directory.
+
+pub mod mock_failure_mode;
+pub(crate) mod mock_job_queue;
+pub mod mock_job_status;
+pub mod mock_media_kind;
+pub mod mock_provider_config;
+pub mod mock_provider_error;
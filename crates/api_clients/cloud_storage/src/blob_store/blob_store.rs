@@ -0,0 +1,55 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use tokio::io::AsyncRead;
+
+use errors::AnyhowResult;
+
+use crate::bucket_object::bucket_byte_stream::BucketByteStream;
+use crate::bucket_object::bucket_object_head::BucketObjectHead;
+use crate::bucket_object::bucket_object_summary::BucketObjectSummary;
+
+/// Where a `BucketClient` actually keeps objects: a real S3-compatible bucket, a local
+/// directory, or memory. The latter two let the services run (and be tested) without
+/// cloud credentials.
+///
+/// Object names are full object paths, eg. the `bucket_paths` typified paths
+/// (`/media/a/b/c/d/e/abcde.../file.bin`). Bucket roots are applied by `BucketClient`
+/// before they get here.
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+  fn bucket_name(&self) -> String;
+
+  async fn put_bytes(&self, object_name: &str, bytes: &[u8], maybe_content_type: Option<&str>) -> AnyhowResult<()>;
+
+  /// Store everything from `reader`, without buffering it all in memory. Returns the size.
+  async fn put_stream(
+    &self,
+    object_name: &str,
+    reader: &mut (dyn AsyncRead + Unpin + Send),
+    content_type: &str,
+  ) -> AnyhowResult<u64>;
+
+  /// Errors if the object doesn't exist.
+  async fn get_bytes(&self, object_name: &str) -> AnyhowResult<Vec<u8>>;
+
+  /// The bytes `start..=end`, or `start..` without an end. `BucketClient` has already
+  /// checked that the range isn't empty.
+  async fn get_range(&self, object_name: &str, start: u64, maybe_end_inclusive: Option<u64>) -> AnyhowResult<Vec<u8>>;
+
+  async fn get_stream(&self, object_name: &str) -> AnyhowResult<BucketByteStream>;
+
+  async fn get_to_file(&self, object_name: &str, filesystem_path: &Path) -> AnyhowResult<()>;
+
+  async fn head(&self, object_name: &str) -> AnyhowResult<Option<BucketObjectHead>>;
+
+  /// Deleting an object that doesn't exist is not an error.
+  async fn delete(&self, object_name: &str) -> AnyhowResult<()>;
+
+  /// Every object whose name starts with `prefix`. Names come back as they were stored.
+  async fn list(&self, prefix: &str) -> AnyhowResult<Vec<BucketObjectSummary>>;
+
+  async fn presigned_get_url(&self, object_name: &str, expiry_secs: u32) -> AnyhowResult<String>;
+
+  async fn presigned_put_url(&self, object_name: &str, expiry_secs: u32) -> AnyhowResult<String>;
+}
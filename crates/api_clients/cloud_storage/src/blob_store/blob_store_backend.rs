@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use anyhow::bail;
+
+use errors::AnyhowResult;
+
+/// `s3` (default), `local`, or `memory`.
+pub const ENV_BLOB_STORE_BACKEND: &str = "BLOB_STORE_BACKEND";
+
+/// For `local`: each bucket gets a subdirectory named after it.
+pub const ENV_BLOB_STORE_LOCAL_DIRECTORY: &str = "BLOB_STORE_LOCAL_DIRECTORY";
+
+const DEFAULT_LOCAL_DIRECTORY: &str = "runtime_data/blob_store";
+
+/// Which `BlobStore` backs the bucket clients. See `BucketClient::with_env_backend_override`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlobStoreBackend {
+  S3,
+  LocalDirectory(PathBuf),
+  InMemory,
+}
+
+impl BlobStoreBackend {
+  pub fn from_env() -> AnyhowResult<Self> {
+    let backend = easyenv::get_env_string_or_default(ENV_BLOB_STORE_BACKEND, "s3");
+    let maybe_local_directory = easyenv::get_env_pathbuf_optional(ENV_BLOB_STORE_LOCAL_DIRECTORY);
+    Self::from_name(&backend, maybe_local_directory)
+  }
+
+  pub fn from_name(backend: &str, maybe_local_directory: Option<PathBuf>) -> AnyhowResult<Self> {
+    match backend.trim().to_ascii_lowercase().as_str() {
+      "s3" => Ok(Self::S3),
+      "local" => Ok(Self::LocalDirectory(
+        maybe_local_directory.unwrap_or_else(|| PathBuf::from(DEFAULT_LOCAL_DIRECTORY)))),
+      "memory" => Ok(Self::InMemory),
+      other => bail!("Unknown {} '{}'; expected s3, local, or memory", ENV_BLOB_STORE_BACKEND, other),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_backend_names() {
+    assert_eq!(BlobStoreBackend::from_name("s3", None).unwrap(), BlobStoreBackend::S3);
+    assert_eq!(BlobStoreBackend::from_name(" Memory ", None).unwrap(), BlobStoreBackend::InMemory);
+    assert_eq!(
+      BlobStoreBackend::from_name("local", Some(PathBuf::from("/tmp/blobs"))).unwrap(),
+      BlobStoreBackend::LocalDirectory(PathBuf::from("/tmp/blobs")),
+    );
+    assert_eq!(
+      BlobStoreBackend::from_name("local", None).unwrap(),
+      BlobStoreBackend::LocalDirectory(PathBuf::from(DEFAULT_LOCAL_DIRECTORY)),
+    );
+    assert!(BlobStoreBackend::from_name("gcs", None).is_err());
+  }
+}
@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::RwLock;
+
+use anyhow::{anyhow, bail};
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use errors::AnyhowResult;
+
+use crate::blob_store::blob_store::BlobStore;
+use crate::blob_store::object_name::{normalize_object_name, normalize_prefix};
+use crate::bucket_object::bucket_byte_stream::BucketByteStream;
+use crate::bucket_object::bucket_object_head::BucketObjectHead;
+use crate::bucket_object::bucket_object_summary::BucketObjectSummary;
+
+/// Objects kept in memory, for tests and throwaway local runs. Clones of the owning
+/// `BucketClient` share the same objects; nothing survives the process.
+pub struct InMemoryBlobStore {
+  bucket_name: String,
+  objects: RwLock<BTreeMap<String, StoredObject>>,
+}
+
+#[derive(Clone)]
+struct StoredObject {
+  bytes: Bytes,
+  maybe_content_type: Option<String>,
+  last_modified: DateTime<Utc>,
+}
+
+impl InMemoryBlobStore {
+  pub fn new(bucket_name: &str) -> Self {
+    Self {
+      bucket_name: bucket_name.to_string(),
+      objects: RwLock::new(BTreeMap::new()),
+    }
+  }
+
+  /// The names of every stored object, for test assertions.
+  pub fn object_names(&self) -> Vec<String> {
+    self.read_objects().keys().cloned().collect()
+  }
+
+  fn store(&self, object_name: &str, bytes: Bytes, maybe_content_type: Option<&str>) -> AnyhowResult<()> {
+    let key = normalize_object_name(object_name)?;
+
+    let object = StoredObject {
+      bytes,
+      maybe_content_type: maybe_content_type.map(|content_type| content_type.to_string()),
+      last_modified: Utc::now(),
+    };
+
+    self.objects.write()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+      .insert(key, object);
+
+    Ok(())
+  }
+
+  fn load(&self, object_name: &str) -> AnyhowResult<StoredObject> {
+    let key = normalize_object_name(object_name)?;
+
+    self.read_objects()
+      .get(&key)
+      .cloned()
+      .ok_or_else(|| anyhow!("File not found in bucket: {}", object_name))
+  }
+
+  fn read_objects(&self) -> std::sync::RwLockReadGuard<'_, BTreeMap<String, StoredObject>> {
+    self.objects.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[async_trait]
+impl BlobStore for InMemoryBlobStore {
+  fn bucket_name(&self) -> String {
+    self.bucket_name.clone()
+  }
+
+  async fn put_bytes(&self, object_name: &str, bytes: &[u8], maybe_content_type: Option<&str>) -> AnyhowResult<()> {
+    self.store(object_name, Bytes::copy_from_slice(bytes), maybe_content_type)
+  }
+
+  async fn put_stream(
+    &self,
+    object_name: &str,
+    reader: &mut (dyn AsyncRead + Unpin + Send),
+    content_type: &str,
+  ) -> AnyhowResult<u64> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).await?;
+    let size = bytes.len() as u64;
+    self.store(object_name, Bytes::from(bytes), Some(content_type))?;
+    Ok(size)
+  }
+
+  async fn get_bytes(&self, object_name: &str) -> AnyhowResult<Vec<u8>> {
+    Ok(self.load(object_name)?.bytes.to_vec())
+  }
+
+  async fn get_range(&self, object_name: &str, start: u64, maybe_end_inclusive: Option<u64>) -> AnyhowResult<Vec<u8>> {
+    let bytes = self.load(object_name)?.bytes;
+    let len = bytes.len() as u64;
+
+    if start >= len {
+      bail!("Range start {} is past the end of {} ({} bytes)", start, object_name, len);
+    }
+
+    let end_exclusive = maybe_end_inclusive.map(|end| (end + 1).min(len)).unwrap_or(len);
+
+    Ok(bytes.slice(start as usize..end_exclusive as usize).to_vec())
+  }
+
+  async fn get_stream(&self, object_name: &str) -> AnyhowResult<BucketByteStream> {
+    let bytes = self.load(object_name)?.bytes;
+    Ok(Box::pin(futures::stream::once(async move { Ok(bytes) })))
+  }
+
+  async fn get_to_file(&self, object_name: &str, filesystem_path: &Path) -> AnyhowResult<()> {
+    let bytes = self.load(object_name)?.bytes;
+    tokio::fs::write(filesystem_path, &bytes).await?;
+    Ok(())
+  }
+
+  async fn head(&self, object_name: &str) -> AnyhowResult<Option<BucketObjectHead>> {
+    let key = normalize_object_name(object_name)?;
+
+    let head = self.read_objects().get(&key).map(|object| BucketObjectHead {
+      maybe_content_length: Some(object.bytes.len() as u64),
+      maybe_content_type: object.maybe_content_type.clone(),
+      maybe_e_tag: None,
+      maybe_last_modified: Some(object.last_modified.to_rfc3339()),
+      metadata: Default::default(),
+    });
+
+    Ok(head)
+  }
+
+  async fn delete(&self, object_name: &str) -> AnyhowResult<()> {
+    let key = normalize_object_name(object_name)?;
+
+    self.objects.write()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+      .remove(&key);
+
+    Ok(())
+  }
+
+  async fn list(&self, prefix: &str) -> AnyhowResult<Vec<BucketObjectSummary>> {
+    let prefix = normalize_prefix(prefix)?;
+
+    let objects = self.read_objects()
+      .range(prefix.clone()..)
+      .take_while(|(key, _)| key.starts_with(&prefix))
+      .map(|(key, object)| BucketObjectSummary {
+        object_name: key.clone(),
+        size_bytes: object.bytes.len() as u64,
+        last_modified: object.last_modified.to_rfc3339(),
+        maybe_e_tag: None,
+      })
+      .collect();
+
+    Ok(objects)
+  }
+
+  async fn presigned_get_url(&self, object_name: &str, _expiry_secs: u32) -> AnyhowResult<String> {
+    bail!("In-memory bucket '{}' can't presign URLs (object: {})", self.bucket_name, object_name)
+  }
+
+  async fn presigned_put_url(&self, object_name: &str, _expiry_secs: u32) -> AnyhowResult<String> {
+    bail!("In-memory bucket '{}' can't presign URLs (object: {})", self.bucket_name, object_name)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use futures::StreamExt;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn put_get_list_delete() {
+    let store = InMemoryBlobStore::new("test");
+
+    store.put_bytes("/media/a/one.bin", b"0123456789", Some("application/octet-stream")).await.unwrap();
+    store.put_stream("media/b/two.bin", &mut &b"two"[..], "text/plain").await.unwrap();
+    store.put_bytes("media_other/three.bin", b"3", None).await.unwrap();
+
+    // Leading slashes don't matter, like S3.
+    assert_eq!(store.get_bytes("media/a/one.bin").await.unwrap(), b"0123456789");
+    assert_eq!(store.get_range("/media/a/one.bin", 2, Some(4)).await.unwrap(), b"234");
+    assert_eq!(store.get_range("/media/a/one.bin", 8, None).await.unwrap(), b"89");
+
+    let mut stream = store.get_stream("media/b/two.bin").await.unwrap();
+    assert_eq!(stream.next().await.unwrap().unwrap(), Bytes::from_static(b"two"));
+
+    let head = store.head("media/b/two.bin").await.unwrap().unwrap();
+    assert_eq!(head.maybe_content_length, Some(3));
+    assert_eq!(head.maybe_content_type.as_deref(), Some("text/plain"));
+
+    let listed: Vec<String> = store.list("/media/").await.unwrap()
+      .into_iter()
+      .map(|object| object.object_name)
+      .collect();
+    assert_eq!(listed, vec!["media/a/one.bin", "media/b/two.bin"]);
+
+    store.delete("media/a/one.bin").await.unwrap();
+    store.delete("media/a/one.bin").await.unwrap();
+    assert!(store.head("media/a/one.bin").await.unwrap().is_none());
+    assert!(store.get_bytes("media/a/one.bin").await.is_err());
+  }
+}
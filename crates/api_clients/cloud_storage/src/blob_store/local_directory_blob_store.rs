@@ -0,0 +1,317 @@
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::{anyhow, bail};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use tokio::fs::File;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+
+use errors::AnyhowResult;
+use mimetypes::mimetype_for_file::get_mimetype_for_file;
+
+use crate::blob_store::blob_store::BlobStore;
+use crate::blob_store::object_name::{normalize_object_name, normalize_prefix};
+use crate::bucket_object::bucket_byte_stream::BucketByteStream;
+use crate::bucket_object::bucket_object_head::BucketObjectHead;
+use crate::bucket_object::bucket_object_summary::BucketObjectSummary;
+
+/// In-progress writes go here, then get renamed into place, so readers never see a
+/// partial object. It's skipped when listing.
+const TEMP_DIRECTORY_NAME: &str = ".blob_store_tmp";
+
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Objects stored as files under a local directory, laid out exactly like their object
+/// paths (`/media/a/b/...` lands at `<root>/media/a/b/...`). For running the services
+/// offline.
+pub struct LocalDirectoryBlobStore {
+  bucket_name: String,
+  root_directory: PathBuf,
+}
+
+impl LocalDirectoryBlobStore {
+  pub fn new(bucket_name: &str, root_directory: &Path) -> AnyhowResult<Self> {
+    std::fs::create_dir_all(root_directory.join(TEMP_DIRECTORY_NAME))?;
+
+    Ok(Self {
+      bucket_name: bucket_name.to_string(),
+      root_directory: root_directory.to_path_buf(),
+    })
+  }
+
+  pub fn root_directory(&self) -> &Path {
+    &self.root_directory
+  }
+
+  fn path_for(&self, object_name: &str) -> AnyhowResult<PathBuf> {
+    let key = normalize_object_name(object_name)?;
+
+    if key == TEMP_DIRECTORY_NAME || key.starts_with(&format!("{}/", TEMP_DIRECTORY_NAME)) {
+      bail!("Reserved object name: {}", object_name);
+    }
+
+    Ok(self.root_directory.join(key))
+  }
+
+  fn temp_path(&self) -> PathBuf {
+    let counter = TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    self.root_directory
+      .join(TEMP_DIRECTORY_NAME)
+      .join(format!("{}-{}.tmp", std::process::id(), counter))
+  }
+
+  async fn open_existing(&self, object_name: &str) -> AnyhowResult<File> {
+    let path = self.path_for(object_name)?;
+
+    match File::open(&path).await {
+      Ok(file) => Ok(file),
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+        Err(anyhow!("File not found in bucket: {}", object_name))
+      }
+      Err(err) => Err(err.into()),
+    }
+  }
+
+  /// Copy `reader` to a temp file, then move it into place.
+  async fn write_from_reader(
+    &self,
+    object_name: &str,
+    reader: &mut (dyn AsyncRead + Unpin + Send),
+  ) -> AnyhowResult<u64> {
+    let path = self.path_for(object_name)?;
+    let temp_path = self.temp_path();
+
+    let result = async {
+      let mut temp_file = File::create(&temp_path).await?;
+      let size = tokio::io::copy(reader, &mut temp_file).await?;
+      temp_file.flush().await?;
+
+      if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+      }
+
+      tokio::fs::rename(&temp_path, &path).await?;
+
+      Ok(size)
+    }.await;
+
+    if result.is_err() {
+      let _ = tokio::fs::remove_file(&temp_path).await;
+    }
+
+    result
+  }
+}
+
+#[async_trait]
+impl BlobStore for LocalDirectoryBlobStore {
+  fn bucket_name(&self) -> String {
+    self.bucket_name.clone()
+  }
+
+  async fn put_bytes(&self, object_name: &str, bytes: &[u8], _maybe_content_type: Option<&str>) -> AnyhowResult<()> {
+    let mut reader = bytes;
+    self.write_from_reader(object_name, &mut reader).await?;
+    Ok(())
+  }
+
+  async fn put_stream(
+    &self,
+    object_name: &str,
+    reader: &mut (dyn AsyncRead + Unpin + Send),
+    _content_type: &str,
+  ) -> AnyhowResult<u64> {
+    self.write_from_reader(object_name, reader).await
+  }
+
+  async fn get_bytes(&self, object_name: &str) -> AnyhowResult<Vec<u8>> {
+    let mut file = self.open_existing(object_name).await?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).await?;
+    Ok(bytes)
+  }
+
+  async fn get_range(&self, object_name: &str, start: u64, maybe_end_inclusive: Option<u64>) -> AnyhowResult<Vec<u8>> {
+    let mut file = self.open_existing(object_name).await?;
+    let len = file.metadata().await?.len();
+
+    if start >= len {
+      bail!("Range start {} is past the end of {} ({} bytes)", start, object_name, len);
+    }
+
+    let end_exclusive = maybe_end_inclusive.map(|end| (end + 1).min(len)).unwrap_or(len);
+
+    file.seek(SeekFrom::Start(start)).await?;
+
+    let mut bytes = Vec::with_capacity((end_exclusive - start) as usize);
+    file.take(end_exclusive - start).read_to_end(&mut bytes).await?;
+
+    Ok(bytes)
+  }
+
+  async fn get_stream(&self, object_name: &str) -> AnyhowResult<BucketByteStream> {
+    let file = self.open_existing(object_name).await?;
+    Ok(Box::pin(ReaderStream::new(file).map(|item| item.map_err(anyhow::Error::from))))
+  }
+
+  async fn get_to_file(&self, object_name: &str, filesystem_path: &Path) -> AnyhowResult<()> {
+    let mut file = self.open_existing(object_name).await?;
+    let mut output_file = File::create(filesystem_path).await?;
+    tokio::io::copy(&mut file, &mut output_file).await?;
+    output_file.flush().await?;
+    Ok(())
+  }
+
+  async fn head(&self, object_name: &str) -> AnyhowResult<Option<BucketObjectHead>> {
+    let path = self.path_for(object_name)?;
+
+    let metadata = match tokio::fs::metadata(&path).await {
+      Ok(metadata) if metadata.is_file() => metadata,
+      Ok(_) => return Ok(None),
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+      Err(err) => return Err(err.into()),
+    };
+
+    Ok(Some(BucketObjectHead {
+      maybe_content_length: Some(metadata.len()),
+      maybe_content_type: get_mimetype_for_file(&path).ok().flatten().map(|mimetype| mimetype.to_string()),
+      maybe_e_tag: None,
+      maybe_last_modified: metadata.modified().ok().map(|time| DateTime::<Utc>::from(time).to_rfc3339()),
+      metadata: Default::default(),
+    }))
+  }
+
+  async fn delete(&self, object_name: &str) -> AnyhowResult<()> {
+    let path = self.path_for(object_name)?;
+
+    match tokio::fs::remove_file(&path).await {
+      Ok(()) => Ok(()),
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+      Err(err) => Err(err.into()),
+    }
+  }
+
+  async fn list(&self, prefix: &str) -> AnyhowResult<Vec<BucketObjectSummary>> {
+    let prefix = normalize_prefix(prefix)?;
+    let root_directory = self.root_directory.clone();
+
+    let mut objects = tokio::task::spawn_blocking(move || list_files(&root_directory, &prefix)).await??;
+    objects.sort_by(|a, b| a.object_name.cmp(&b.object_name));
+
+    Ok(objects)
+  }
+
+  async fn presigned_get_url(&self, object_name: &str, _expiry_secs: u32) -> AnyhowResult<String> {
+    let path = std::fs::canonicalize(self.path_for(object_name)?)?;
+    Ok(format!("file://{}", path.display()))
+  }
+
+  async fn presigned_put_url(&self, object_name: &str, _expiry_secs: u32) -> AnyhowResult<String> {
+    bail!("Local bucket '{}' can't presign upload URLs (object: {})", self.bucket_name, object_name)
+  }
+}
+
+fn list_files(root_directory: &Path, prefix: &str) -> AnyhowResult<Vec<BucketObjectSummary>> {
+  let mut objects = Vec::new();
+  let mut pending_directories = vec![root_directory.to_path_buf()];
+
+  while let Some(directory) = pending_directories.pop() {
+    for entry in std::fs::read_dir(&directory)? {
+      let entry = entry?;
+      let path = entry.path();
+      let file_type = entry.file_type()?;
+
+      let relative = path.strip_prefix(root_directory)?
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+
+      if file_type.is_dir() {
+        if relative != TEMP_DIRECTORY_NAME {
+          pending_directories.push(path);
+        }
+        continue;
+      }
+
+      if !file_type.is_file() || !relative.starts_with(prefix) {
+        continue;
+      }
+
+      let metadata = entry.metadata()?;
+
+      objects.push(BucketObjectSummary {
+        object_name: relative,
+        size_bytes: metadata.len(),
+        last_modified: metadata.modified()
+          .map(|time| DateTime::<Utc>::from(time).to_rfc3339())
+          .unwrap_or_default(),
+        maybe_e_tag: None,
+      });
+    }
+  }
+
+  Ok(objects)
+}
+
+#[cfg(test)]
+mod tests {
+  use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path::MediaFileBucketPath;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn lays_out_typified_paths_on_disk() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = LocalDirectoryBlobStore::new("public", dir.path()).unwrap();
+
+    let media_path = MediaFileBucketPath::from_object_hash("abcdefghijk", None, Some(".bin"));
+    store.put_bytes(media_path.get_full_object_path_str(), b"0123456789", None).await.unwrap();
+
+    let on_disk = dir.path().join("media/a/b/c/d/e/abcdefghijk/abcdefghijk.bin");
+    assert_eq!(std::fs::read(&on_disk).unwrap(), b"0123456789");
+
+    assert_eq!(store.get_bytes(media_path.get_full_object_path_str()).await.unwrap(), b"0123456789");
+    assert_eq!(store.get_range(media_path.get_full_object_path_str(), 3, Some(5)).await.unwrap(), b"345");
+
+    let head = store.head(media_path.get_full_object_path_str()).await.unwrap().unwrap();
+    assert_eq!(head.maybe_content_length, Some(10));
+
+    let listed = store.list("/media/a/").await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].object_name, "media/a/b/c/d/e/abcdefghijk/abcdefghijk.bin");
+
+    let download_path = dir.path().join("download.bin");
+    store.get_to_file(media_path.get_full_object_path_str(), &download_path).await.unwrap();
+    assert_eq!(std::fs::read(&download_path).unwrap(), b"0123456789");
+
+    store.delete(media_path.get_full_object_path_str()).await.unwrap();
+    assert!(store.head(media_path.get_full_object_path_str()).await.unwrap().is_none());
+    assert!(store.get_bytes(media_path.get_full_object_path_str()).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn streams_and_rejects_escapes() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = LocalDirectoryBlobStore::new("public", &dir.path().join("bucket")).unwrap();
+
+    let contents = vec![7u8; 100_000];
+    let size = store.put_stream("big.bin", &mut contents.as_slice(), "application/octet-stream").await.unwrap();
+    assert_eq!(size, 100_000);
+
+    let mut stream = store.get_stream("big.bin").await.unwrap();
+    let mut downloaded = Vec::new();
+    while let Some(chunk) = stream.next().await {
+      downloaded.extend_from_slice(&chunk.unwrap());
+    }
+    assert_eq!(downloaded, contents);
+
+    assert!(store.put_bytes("../escaped.bin", b"nope", None).await.is_err());
+    assert!(store.put_bytes(".blob_store_tmp/x", b"nope", None).await.is_err());
+    assert!(!dir.path().join("escaped.bin").exists());
+  }
+}
@@ -0,0 +1,6 @@
+pub mod blob_store;
+pub mod blob_store_backend;
+pub mod in_memory_blob_store;
+pub mod local_directory_blob_store;
+pub mod object_name;
+pub mod s3_blob_store;
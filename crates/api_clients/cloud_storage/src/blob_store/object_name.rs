@@ -0,0 +1,74 @@
+use anyhow::bail;
+
+use errors::AnyhowResult;
+
+/// Canonical form of an object name for the non-S3 stores: no leading slash, no empty
+/// or `.` segments. S3 treats `/media/a/file.bin` and `media/a/file.bin` as the same
+/// object, so the local stores must too. `..` is rejected so a name can't escape the
+/// store's directory.
+pub fn normalize_object_name(object_name: &str) -> AnyhowResult<String> {
+  let mut segments = Vec::new();
+
+  for segment in object_name.split('/') {
+    match segment {
+      "" | "." => continue,
+      ".." => bail!("Object names can't contain '..': {}", object_name),
+      segment if segment.contains('\\') => bail!("Object names can't contain '\\': {}", object_name),
+      segment => segments.push(segment),
+    }
+  }
+
+  if segments.is_empty() {
+    bail!("Empty object name: {:?}", object_name);
+  }
+
+  Ok(segments.join("/"))
+}
+
+/// Like `normalize_object_name`, but an empty prefix (list everything) is allowed and a
+/// trailing slash is kept, so `media/` doesn't match `media_files/...`.
+pub fn normalize_prefix(prefix: &str) -> AnyhowResult<String> {
+  if prefix.trim_matches('/').is_empty() {
+    return Ok(String::new());
+  }
+
+  let mut normalized = normalize_object_name(prefix)?;
+
+  if prefix.ends_with('/') {
+    normalized.push('/');
+  }
+
+  Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+  use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path::MediaFileBucketPath;
+
+  use super::*;
+
+  #[test]
+  fn normalizes_typified_paths() {
+    let path = MediaFileBucketPath::from_object_hash("abcdefghijk", None, Some(".mp4"));
+    assert_eq!(
+      normalize_object_name(path.get_full_object_path_str()).unwrap(),
+      "media/a/b/c/d/e/abcdefghijk/abcdefghijk.mp4",
+    );
+    assert_eq!(normalize_object_name("root//media/./x.bin").unwrap(), "root/media/x.bin");
+  }
+
+  #[test]
+  fn rejects_escapes_and_empty_names() {
+    assert!(normalize_object_name("/media/../../etc/passwd").is_err());
+    assert!(normalize_object_name("media\\..\\x").is_err());
+    assert!(normalize_object_name("/").is_err());
+  }
+
+  #[test]
+  fn prefixes() {
+    assert_eq!(normalize_prefix("").unwrap(), "");
+    assert_eq!(normalize_prefix("/").unwrap(), "");
+    assert_eq!(normalize_prefix("/media/").unwrap(), "media/");
+    assert_eq!(normalize_prefix("/media/a").unwrap(), "media/a");
+  }
+}
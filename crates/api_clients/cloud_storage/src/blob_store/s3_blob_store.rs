@@ -0,0 +1,267 @@
+use std::path::Path;
+
+use anyhow::{anyhow, bail};
+use async_trait::async_trait;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use http::header::RANGE;
+use http::HeaderValue;
+use log::{info, warn};
+use s3::bucket::{Bucket, CHUNK_SIZE};
+use s3::error::S3Error;
+use s3::serde_types::Part;
+use s3::utils::read_chunk_async;
+use tokio::fs::File;
+use tokio::io::AsyncRead;
+
+use errors::AnyhowResult;
+
+use crate::blob_store::blob_store::BlobStore;
+use crate::bucket_object::bucket_byte_stream::BucketByteStream;
+use crate::bucket_object::bucket_object_head::BucketObjectHead;
+use crate::bucket_object::bucket_object_summary::BucketObjectSummary;
+
+/// Multipart parts in flight at once. Each holds one `CHUNK_SIZE` (8 MiB) buffer, so
+/// this bounds the memory a streaming upload needs.
+const MULTIPART_UPLOAD_CONCURRENCY: usize = 4;
+
+/// An S3-compatible bucket (R2, GCS in interop mode, MinIO, ...).
+pub struct S3BlobStore {
+  bucket: Bucket,
+}
+
+impl S3BlobStore {
+  pub fn new(bucket: Bucket) -> Self {
+    Self { bucket }
+  }
+
+  async fn upload_parts(
+    &self,
+    key: &str,
+    upload_id: &str,
+    first_chunk: Vec<u8>,
+    reader: &mut (dyn AsyncRead + Unpin + Send),
+    content_type: &str,
+  ) -> AnyhowResult<(Vec<Part>, u64)> {
+    let mut in_flight = FuturesUnordered::new();
+    let mut parts = Vec::new();
+    let mut size = 0u64;
+    let mut part_number = 0u32;
+    let mut maybe_chunk = Some(first_chunk);
+
+    while let Some(chunk) = maybe_chunk.take() {
+      // A short chunk is the last one. An empty one means the previous part ended
+      // exactly on a chunk boundary, and S3 doesn't want an empty trailing part.
+      let is_last = chunk.len() < CHUNK_SIZE;
+
+      if !chunk.is_empty() {
+        size += chunk.len() as u64;
+        part_number += 1;
+        in_flight.push(self.bucket.put_multipart_chunk(chunk, key, part_number, upload_id, content_type));
+      }
+
+      if in_flight.len() >= MULTIPART_UPLOAD_CONCURRENCY {
+        if let Some(part) = in_flight.next().await {
+          parts.push(part?);
+        }
+      }
+
+      if !is_last {
+        maybe_chunk = Some(read_chunk_async(reader).await?);
+      }
+    }
+
+    while let Some(part) = in_flight.next().await {
+      parts.push(part?);
+    }
+
+    parts.sort_by_key(|part| part.part_number);
+
+    Ok((parts, size))
+  }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+  fn bucket_name(&self) -> String {
+    self.bucket.name().to_string()
+  }
+
+  async fn put_bytes(&self, object_name: &str, bytes: &[u8], maybe_content_type: Option<&str>) -> AnyhowResult<()> {
+    let response = match maybe_content_type {
+      Some(content_type) => self.bucket.put_object_with_content_type(object_name, bytes, content_type).await,
+      None => self.bucket.put_object(object_name, bytes).await,
+    };
+
+    let response = match response {
+      Ok(response) => response,
+      Err(err) => {
+        warn!("S3 upload error for bucket name {}: {:?}", self.bucket.name, err);
+        return Err(err.into());
+      }
+    };
+
+    let code = response.status_code();
+
+    if !(200..300).contains(&code) {
+      let body = String::from_utf8_lossy(response.bytes());
+      warn!("failed upload body: {}", body);
+      bail!("upload failed: {}", code);
+    }
+
+    Ok(())
+  }
+
+  async fn put_stream(
+    &self,
+    object_name: &str,
+    reader: &mut (dyn AsyncRead + Unpin + Send),
+    content_type: &str,
+  ) -> AnyhowResult<u64> {
+    let first_chunk = read_chunk_async(reader).await?;
+
+    if first_chunk.len() < CHUNK_SIZE {
+      let size = first_chunk.len() as u64;
+      self.put_bytes(object_name, &first_chunk, Some(content_type)).await?;
+      return Ok(size);
+    }
+
+    let upload = self.bucket.initiate_multipart_upload(object_name, content_type).await?;
+
+    info!("Started multipart upload {} to bucket: {}", upload.upload_id, upload.key);
+
+    let result = self.upload_parts(&upload.key, &upload.upload_id, first_chunk, reader, content_type).await;
+
+    match result {
+      Ok((parts, size)) => {
+        let part_count = parts.len();
+        self.bucket.complete_multipart_upload(&upload.key, &upload.upload_id, parts).await?;
+        info!("Uploaded {} bytes in {} parts to bucket: {}", size, part_count, upload.key);
+        Ok(size)
+      }
+      Err(err) => {
+        // Otherwise the uploaded parts linger (and are billed) until a lifecycle rule cleans them up.
+        if let Err(abort_err) = self.bucket.abort_upload(&upload.key, &upload.upload_id).await {
+          warn!("Could not abort multipart upload {} for {}: {:?}", upload.upload_id, upload.key, abort_err);
+        }
+        Err(err)
+      }
+    }
+  }
+
+  async fn get_bytes(&self, object_name: &str) -> AnyhowResult<Vec<u8>> {
+    let response = match self.bucket.get_object(object_name).await {
+      Ok(response) => response,
+      Err(S3Error::HttpFailWithBody(404, _)) => bail!("File not found in bucket: {}", object_name),
+      Err(err) => return Err(err.into()),
+    };
+
+    match response.status_code() {
+      404 => bail!("File not found in bucket: {}", object_name),
+      code => info!("download code: {}", code),
+    }
+
+    Ok(response.bytes().to_vec())
+  }
+
+  async fn get_range(&self, object_name: &str, start: u64, maybe_end_inclusive: Option<u64>) -> AnyhowResult<Vec<u8>> {
+    // NB: rust-s3's `get_object_range` panics on single byte ranges (`end == start`), so send
+    // the header ourselves. Extra headers are signed along with the rest of the request.
+    let mut headers = self.bucket.extra_headers.clone();
+    headers.insert(RANGE, HeaderValue::from_str(&range_header(start, maybe_end_inclusive))?);
+
+    let response = self.bucket.with_extra_headers(headers)?.get_object(object_name).await?;
+    Ok(response.bytes().to_vec())
+  }
+
+  async fn get_stream(&self, object_name: &str) -> AnyhowResult<BucketByteStream> {
+    let response = self.bucket.get_object_stream(object_name).await?;
+    Ok(Box::pin(response.bytes.map(|item| item.map_err(anyhow::Error::from))))
+  }
+
+  async fn get_to_file(&self, object_name: &str, filesystem_path: &Path) -> AnyhowResult<()> {
+    info!("creating file for bucket download: {:?}", filesystem_path);
+
+    let mut output_file = File::create(filesystem_path).await?;
+
+    info!("downloading from bucket (named '{}'), path: {}", &self.bucket.name, object_name);
+
+    let status_code = match self.bucket.get_object_to_writer(object_name, &mut output_file).await {
+      Ok(status_code) => status_code,
+      Err(S3Error::HttpFailWithBody(404, _)) => 404,
+      Err(err) => {
+        return Err(anyhow!("Error downloading from bucket (named '{}'): {:?}", &self.bucket.name, err));
+      }
+    };
+
+    if status_code == 404 {
+      warn!("File not found in bucket (named '{}'), path: {}", &self.bucket.name, object_name);
+      bail!("File not found in bucket: {}", object_name);
+    }
+
+    info!("download code: {}", status_code);
+
+    Ok(())
+  }
+
+  async fn head(&self, object_name: &str) -> AnyhowResult<Option<BucketObjectHead>> {
+    match self.bucket.head_object(object_name).await {
+      Ok((_, 404)) => Ok(None),
+      Ok((head, _)) => Ok(Some(BucketObjectHead::from(head))),
+      Err(S3Error::HttpFailWithBody(404, _)) => Ok(None),
+      Err(err) => Err(err.into()),
+    }
+  }
+
+  async fn delete(&self, object_name: &str) -> AnyhowResult<()> {
+    match self.bucket.delete_object(object_name).await {
+      Ok(_) => Ok(()),
+      Err(S3Error::HttpFailWithBody(404, _)) => Ok(()),
+      Err(err) => Err(err.into()),
+    }
+  }
+
+  async fn list(&self, prefix: &str) -> AnyhowResult<Vec<BucketObjectSummary>> {
+    let pages = self.bucket.list(prefix.to_string(), None).await?;
+
+    let objects = pages.into_iter()
+      .flat_map(|page| page.contents)
+      .map(|object| BucketObjectSummary {
+        object_name: object.key,
+        size_bytes: object.size,
+        last_modified: object.last_modified,
+        maybe_e_tag: object.e_tag,
+      })
+      .collect();
+
+    Ok(objects)
+  }
+
+  async fn presigned_get_url(&self, object_name: &str, expiry_secs: u32) -> AnyhowResult<String> {
+    Ok(self.bucket.presign_get(object_name, expiry_secs, None).await?)
+  }
+
+  async fn presigned_put_url(&self, object_name: &str, expiry_secs: u32) -> AnyhowResult<String> {
+    Ok(self.bucket.presign_put(object_name, expiry_secs, None, None).await?)
+  }
+}
+
+/// An HTTP `Range` header value for the bytes `start..=end`, or `start..` without an end.
+fn range_header(start: u64, maybe_end_inclusive: Option<u64>) -> String {
+  match maybe_end_inclusive {
+    Some(end) => format!("bytes={}-{}", start, end),
+    None => format!("bytes={}-", start),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn range_headers() {
+    assert_eq!(range_header(0, Some(99)), "bytes=0-99");
+    assert_eq!(range_header(10, Some(10)), "bytes=10-10");
+    assert_eq!(range_header(100, None), "bytes=100-");
+  }
+}
@@ -1,24 +1,36 @@
 use std::error::Error;
 use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::anyhow;
 use anyhow::bail;
-use log::{error, warn};
+use log::error;
 use log::{debug, info};
 use s3::bucket::Bucket;
 use s3::creds::Credentials;
-use s3::error::S3Error;
 use s3::region::Region;
-use s3::request::ResponseData;
 use tokio::fs::File;
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncRead, AsyncReadExt};
 
 use errors::AnyhowResult;
 
+use crate::blob_store::blob_store::BlobStore;
+use crate::blob_store::blob_store_backend::BlobStoreBackend;
+use crate::blob_store::in_memory_blob_store::InMemoryBlobStore;
+use crate::blob_store::local_directory_blob_store::LocalDirectoryBlobStore;
+use crate::blob_store::s3_blob_store::S3BlobStore;
+use crate::bucket_object::bucket_byte_stream::BucketByteStream;
+use crate::bucket_object::bucket_object_head::BucketObjectHead;
+use crate::bucket_object::bucket_object_summary::BucketObjectSummary;
+
+/// S3 (and GCS/MinIO in S3 mode) reject presigned URLs valid for longer than a week.
+const MAX_PRESIGNED_URL_EXPIRY: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
 #[derive(Clone)]
 pub struct BucketClient {
-  bucket: Bucket,
+  /// S3 in production; a local directory or memory when running offline or in tests.
+  store: Arc<dyn BlobStore>,
   /// If set, put all files under this root path.
   optional_bucket_root: Option<String>,
 }
@@ -41,7 +53,7 @@ impl Error for BucketClientError {}
 
 impl BucketClient {
   pub fn bucket_name(&self) -> String {
-    self.bucket.name().to_string()
+    self.store.bucket_name()
   }
 
   pub fn create(
@@ -82,12 +94,43 @@ impl BucketClient {
       }
     }
 
-    let optional_bucket_root = optional_bucket_root.map(|s| s.to_string());
-
-    Ok(Self {
-      bucket: *bucket, // NB: We don't need to keep this boxed on the heap.
+    Ok(Self::from_blob_store(
+      Arc::new(S3BlobStore::new(*bucket)), // NB: We don't need to keep this boxed on the heap.
       optional_bucket_root,
-    })
+    ))
+  }
+
+  /// Wrap any store, eg. an `InMemoryBlobStore` in a handler test.
+  pub fn from_blob_store(store: Arc<dyn BlobStore>, optional_bucket_root: Option<&str>) -> Self {
+    Self {
+      store,
+      optional_bucket_root: optional_bucket_root.map(|s| s.to_string()),
+    }
+  }
+
+  /// Swap the S3 bucket for a local directory or memory if `BLOB_STORE_BACKEND` says
+  /// so, to run the services without cloud credentials. Call on the result of `create`.
+  pub fn with_env_backend_override(self) -> AnyhowResult<Self> {
+    self.with_backend_override(BlobStoreBackend::from_env()?)
+  }
+
+  pub fn with_backend_override(self, backend: BlobStoreBackend) -> AnyhowResult<Self> {
+    let bucket_name = self.bucket_name();
+
+    let store: Arc<dyn BlobStore> = match backend {
+      BlobStoreBackend::S3 => return Ok(self),
+      BlobStoreBackend::LocalDirectory(directory) => {
+        let directory = directory.join(&bucket_name);
+        info!("Bucket '{}' is backed by local directory: {:?}", bucket_name, directory);
+        Arc::new(LocalDirectoryBlobStore::new(&bucket_name, &directory)?)
+      }
+      BlobStoreBackend::InMemory => {
+        info!("Bucket '{}' is backed by memory", bucket_name);
+        Arc::new(InMemoryBlobStore::new(&bucket_name))
+      }
+    };
+
+    Ok(Self { store, ..self })
   }
 
   fn get_rooted_object_name(&self, object_name: &str) -> String {
@@ -97,23 +140,24 @@ impl BucketClient {
     }
   }
 
+  /// Inverse of `get_rooted_object_name`, for keys that come back from a listing.
+  fn strip_bucket_root(&self, key: &str) -> String {
+    match &self.optional_bucket_root {
+      None => key.to_string(),
+      Some(root) => key.strip_prefix(root.as_str())
+        .and_then(|rest| rest.strip_prefix('/'))
+        .unwrap_or(key)
+        .to_string(),
+    }
+  }
+
   pub async fn upload_file(&self, object_name: &str, bytes: &[u8]) -> anyhow::Result<()> {
     debug!("Filename for bucket: {}", object_name);
 
     let object_name = self.get_rooted_object_name(object_name);
     debug!("Rooted filename for bucket: {}", object_name);
 
-    let response = self.bucket.put_object(&object_name, bytes).await?;
-
-    let body_bytes = response.bytes();
-    let code = response.status_code();
-
-    debug!("upload code for {}: {}", object_name, code);
-
-    if code != 200 {
-      let body = String::from_utf8_lossy(body_bytes);
-      warn!("failed upload body: {}", body);
-    }
+    self.store.put_bytes(&object_name, bytes, None).await?;
 
     info!("Successfully uploaded file to bucket: {}", object_name);
 
@@ -124,18 +168,9 @@ impl BucketClient {
     info!("Filename for bucket: {}", object_name);
     let object_name = self.get_rooted_object_name(object_name);
     info!("Rooted filename for bucket: {}", object_name);
-    let response = self.bucket.put_object_with_content_type(&object_name, bytes, content_type).await?;
-    let body_bytes = response.bytes();
-    let code = response.status_code();
-    info!("upload code: {}", code);
-    if code != 200 {
-      let body = String::from_utf8_lossy(body_bytes);
-      warn!("upload body: {}", body);
-      Err(anyhow!("upload failed: {}", code))
-    } else {
-      info!("upload success: {}", code);
-      Ok(())
-    }
+    self.store.put_bytes(&object_name, bytes, Some(content_type)).await?;
+    info!("upload success: {}", object_name);
+    Ok(())
   }
 
   #[deprecated = "Use upload_file instead above it returns an error we can surface and act on. upload_file_with_content_type_process"]
@@ -145,25 +180,13 @@ impl BucketClient {
     let object_name = self.get_rooted_object_name(object_name);
     info!("Rooted filename for bucket: {}", object_name);
 
-    let response = self.bucket.put_object_with_content_type(&object_name, bytes, content_type).await;
+    let response = self.store.put_bytes(&object_name, bytes, Some(content_type)).await;
 
     if let Err(err) = &response {
-      error!("S3 Upload Error for bucket name {}: {:?}", &self.bucket.name, err);
-    }
-
-    let response = response?;
-
-    let body_bytes = response.bytes();
-    let code = response.status_code();
-
-    info!("upload code: {}", code);
-
-    if code != 200 {
-      let body = String::from_utf8_lossy(body_bytes);
-      warn!("upload body: {}", body);
+      error!("S3 Upload Error for bucket name {}: {:?}", self.bucket_name(), err);
     }
 
-    Ok(())
+    response
   }
 
   pub async fn upload_filename<P: AsRef<Path>, Q: AsRef<Path>>(
@@ -209,19 +232,7 @@ impl BucketClient {
 
   pub async fn download_file(&self, path: &str) -> anyhow::Result<Vec<u8>> {
     info!("downloading from bucket: {}", path);
-
-    let response = self.bucket.get_object(path).await?;
-
-    let bytes = response.bytes();
-    let code = response.status_code();
-
-    match code {
-      404 => bail!("File not found in bucket: {}", path),
-      _ => {},
-    }
-
-    info!("download code: {}", code);
-    Ok(bytes.to_vec())
+    self.store.get_bytes(path).await
   }
 
   pub async fn download_file_to_disk<P: AsRef<Path>, Q: AsRef<Path>>(
@@ -234,31 +245,260 @@ impl BucketClient {
       .map(|s| s.to_string())
       .ok_or(anyhow!("could not convert object path to string"))?;
 
-    info!("creating file for bucket download: {:?}", filesystem_path.as_ref());
+    if let Err(err) = self.store.get_to_file(&object_path_str, filesystem_path.as_ref()).await {
+      error!("Error downloading from bucket (named '{}'), path {}: {:?}", self.bucket_name(), &object_path_str, err);
+      return Err(err);
+    }
 
-    let mut output_file = File::create(filesystem_path).await?;
+    Ok(())
+  }
 
-    let result = self.bucket.get_object_to_writer(&object_path_str, &mut output_file).await;
+  // NB: The methods below all apply the bucket root (if any), unlike the older
+  // `download_file*` methods, which take the full object path.
 
-    info!("downloading from bucket (named '{}'), path: {}", &self.bucket.name, &object_path_str);
+  /// Upload from a reader without buffering the whole thing in memory. On S3, anything
+  /// at least 8 MiB is sent as a multipart upload, with a few parts in flight at once;
+  /// a failed multipart upload is aborted so its parts don't linger in the bucket.
+  ///
+  /// Returns the number of bytes uploaded.
+  pub async fn upload_stream<R: AsyncRead + Unpin + Send>(
+    &self,
+    object_name: &str,
+    reader: &mut R,
+    content_type: &str,
+  ) -> AnyhowResult<u64> {
+    let object_name = self.get_rooted_object_name(object_name);
+    let size = self.store.put_stream(&object_name, reader, content_type).await?;
+    info!("Uploaded {} bytes to bucket: {}", size, object_name);
+    Ok(size)
+  }
+
+  /// Upload a file from disk, streaming it (see `upload_stream`).
+  pub async fn upload_file_path_streaming<P: AsRef<Path>>(
+    &self,
+    object_name: &str,
+    filesystem_path: P,
+    content_type: &str,
+  ) -> AnyhowResult<u64> {
+    let mut file = File::open(filesystem_path.as_ref()).await?;
+    self.upload_stream(object_name, &mut file, content_type).await
+  }
+
+  /// Stream an object's bytes as they arrive.
+  pub async fn download_stream(&self, object_name: &str) -> AnyhowResult<BucketByteStream> {
+    let object_name = self.get_rooted_object_name(object_name);
+    self.store.get_stream(&object_name).await
+  }
 
-    let status_code = match result {
-      Ok(status_code) => status_code,
-      Err(err) => {
-        return bail!("Error downloading from bucket (named '{}'): {:?}", &self.bucket.name, err)
+  /// Download the bytes `start..=end` of an object, or `start..` without an end.
+  pub async fn download_range(
+    &self,
+    object_name: &str,
+    start: u64,
+    maybe_end_inclusive: Option<u64>,
+  ) -> AnyhowResult<Vec<u8>> {
+    if let Some(end) = maybe_end_inclusive {
+      // NB: The end is inclusive, so `end == start` is a single byte.
+      if end < start {
+        bail!("Invalid byte range {}-{} for object: {}", start, end, object_name);
       }
-    };
+    }
 
-    match status_code {
-      404 => {
-        error!("File not found in bucket (named '{}'), path: {}", &self.bucket.name, &object_path_str);
-        bail!("File not found in bucket: {}", &object_path_str)
-      },
-      _ => {
-        info!("download code: {}", status_code);
-      },
+    let object_name = self.get_rooted_object_name(object_name);
+    self.store.get_range(&object_name, start, maybe_end_inclusive).await
+  }
+
+  /// A URL anyone can GET the object from until it expires (at most a week).
+  pub async fn presigned_get_url(&self, object_name: &str, expires_in: Duration) -> AnyhowResult<String> {
+    let expiry_secs = presigned_url_expiry_secs(expires_in)?;
+    let object_name = self.get_rooted_object_name(object_name);
+    self.store.presigned_get_url(&object_name, expiry_secs).await
+  }
+
+  /// A URL a client can PUT the object's bytes to directly, without going through us.
+  pub async fn presigned_put_url(&self, object_name: &str, expires_in: Duration) -> AnyhowResult<String> {
+    let expiry_secs = presigned_url_expiry_secs(expires_in)?;
+    let object_name = self.get_rooted_object_name(object_name);
+    self.store.presigned_put_url(&object_name, expiry_secs).await
+  }
+
+  /// Delete an object. Deleting one that doesn't exist is not an error.
+  pub async fn delete_object(&self, object_name: &str) -> AnyhowResult<()> {
+    let object_name = self.get_rooted_object_name(object_name);
+    self.store.delete(&object_name).await
+  }
+
+  /// Object metadata, or `None` if there's no such object.
+  pub async fn head_object(&self, object_name: &str) -> AnyhowResult<Option<BucketObjectHead>> {
+    let object_name = self.get_rooted_object_name(object_name);
+    self.store.head(&object_name).await
+  }
+
+  /// Every object whose name starts with `prefix`, following pagination.
+  pub async fn list_objects(&self, prefix: &str) -> AnyhowResult<Vec<BucketObjectSummary>> {
+    let rooted_prefix = self.get_rooted_object_name(prefix);
+
+    let objects = self.store.list(&rooted_prefix).await?
+      .into_iter()
+      .map(|object| BucketObjectSummary {
+        object_name: self.strip_bucket_root(&object.object_name),
+        ..object
+      })
+      .collect();
+
+    Ok(objects)
+  }
+}
+
+fn presigned_url_expiry_secs(expires_in: Duration) -> AnyhowResult<u32> {
+  if expires_in.as_secs() == 0 || expires_in > MAX_PRESIGNED_URL_EXPIRY {
+    bail!("Presigned URL expiry must be between one second and a week, got {:?}", expires_in);
+  }
+  Ok(expires_in.as_secs() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+  use futures::StreamExt;
+  use s3::bucket::CHUNK_SIZE;
+
+  use super::*;
+
+  fn offline_client(optional_bucket_root: Option<&str>) -> BucketClient {
+    BucketClient::create(
+      "access-key",
+      "secret-key",
+      "us-east-1",
+      "test-bucket",
+      "http://127.0.0.1:9000",
+      optional_bucket_root,
+      None,
+    ).unwrap()
+  }
+
+  #[test]
+  fn bucket_root_round_trip() {
+    let client = offline_client(Some("media"));
+    assert_eq!(client.get_rooted_object_name("a/b.spz"), "media/a/b.spz");
+    assert_eq!(client.strip_bucket_root("media/a/b.spz"), "a/b.spz");
+    assert_eq!(client.strip_bucket_root("mediafoo/b.spz"), "mediafoo/b.spz");
+
+    let client = offline_client(None);
+    assert_eq!(client.strip_bucket_root("a/b.spz"), "a/b.spz");
+  }
+
+  #[test]
+  fn presigned_url_expiry_bounds() {
+    assert_eq!(presigned_url_expiry_secs(Duration::from_secs(900)).unwrap(), 900);
+    assert_eq!(presigned_url_expiry_secs(MAX_PRESIGNED_URL_EXPIRY).unwrap(), 604_800);
+    assert!(presigned_url_expiry_secs(Duration::from_millis(500)).is_err());
+    assert!(presigned_url_expiry_secs(MAX_PRESIGNED_URL_EXPIRY + Duration::from_secs(1)).is_err());
+  }
+
+  #[tokio::test]
+  async fn presigned_urls_are_signed_locally() {
+    let client = offline_client(Some("media"));
+
+    let get_url = client.presigned_get_url("a/b.spz", Duration::from_secs(600)).await.unwrap();
+    assert!(get_url.starts_with("http://127.0.0.1:9000/test-bucket/media/a/b.spz?"), "{}", get_url);
+    assert!(get_url.contains("X-Amz-Expires=600"), "{}", get_url);
+    assert!(get_url.contains("X-Amz-Signature="), "{}", get_url);
+
+    let put_url = client.presigned_put_url("a/b.spz", Duration::from_secs(600)).await.unwrap();
+    assert_ne!(get_url, put_url);
+  }
+
+  #[tokio::test]
+  async fn download_range_rejects_empty_ranges() {
+    let client = offline_client(None);
+    assert!(client.download_range("a.bin", 10, Some(9)).await.is_err());
+    assert!(client.download_range("a.bin", 10, Some(5)).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn download_range_single_byte() {
+    let client = BucketClient::from_blob_store(Arc::new(InMemoryBlobStore::new("public")), None);
+    client.upload_file_with_content_type_process("a.bin", b"hello", "text/plain").await.unwrap();
+
+    assert_eq!(client.download_range("a.bin", 0, Some(0)).await.unwrap(), b"h");
+    assert_eq!(client.download_range("a.bin", 4, Some(4)).await.unwrap(), b"o");
+    assert_eq!(client.download_range("a.bin", 1, Some(3)).await.unwrap(), b"ell");
+    assert_eq!(client.download_range("a.bin", 3, None).await.unwrap(), b"lo");
+  }
+
+  #[tokio::test]
+  async fn in_memory_backend_applies_bucket_root() {
+    let store = Arc::new(InMemoryBlobStore::new("public"));
+    let client = BucketClient::from_blob_store(store.clone(), Some("root"));
+
+    client.upload_file_with_content_type_process("/media/a/file.bin", b"hello", "text/plain").await.unwrap();
+    assert_eq!(store.object_names(), vec!["root/media/a/file.bin"]);
+
+    // The older download methods take the full (rooted) path.
+    assert_eq!(client.download_file("root/media/a/file.bin").await.unwrap(), b"hello");
+    assert_eq!(client.download_range("/media/a/file.bin", 1, Some(3)).await.unwrap(), b"ell");
+
+    let listed = client.list_objects("/media/").await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].object_name, "media/a/file.bin");
+  }
+
+  #[test]
+  fn backend_override_keeps_the_bucket_name() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let client = offline_client(None)
+      .with_backend_override(BlobStoreBackend::LocalDirectory(dir.path().to_path_buf()))
+      .unwrap();
+    assert_eq!(client.bucket_name(), "test-bucket");
+    assert!(dir.path().join("test-bucket").is_dir());
+
+    let client = offline_client(None).with_backend_override(BlobStoreBackend::S3).unwrap();
+    assert_eq!(client.bucket_name(), "test-bucket");
+  }
+
+  /// Run against a local MinIO, eg:
+  ///
+  ///   docker run -p 9000:9000 minio/minio server /data
+  ///   mc alias set local http://127.0.0.1:9000 minioadmin minioadmin && mc mb local/artcraft-test
+  #[tokio::test]
+  #[ignore] // Manually run against MinIO
+  async fn minio_round_trip() {
+    let env = |name: &str, default: &str| std::env::var(name).unwrap_or_else(|_| default.to_string());
+
+    let client = BucketClient::create(
+      &env("MINIO_ACCESS_KEY", "minioadmin"),
+      &env("MINIO_SECRET_KEY", "minioadmin"),
+      "us-east-1",
+      &env("MINIO_BUCKET", "artcraft-test"),
+      &env("MINIO_ENDPOINT", "http://127.0.0.1:9000"),
+      Some("bucket_client_test"),
+      None,
+    ).unwrap();
+
+    // Big enough to take the multipart path, and not a multiple of the chunk size.
+    let contents: Vec<u8> = (0..(CHUNK_SIZE * 2 + 1234)).map(|i| (i % 251) as u8).collect();
+
+    let uploaded = client.upload_stream("big.bin", &mut contents.as_slice(), "application/octet-stream").await.unwrap();
+    assert_eq!(uploaded, contents.len() as u64);
+
+    let head = client.head_object("big.bin").await.unwrap().unwrap();
+    assert_eq!(head.maybe_content_length, Some(contents.len() as u64));
+
+    let range = client.download_range("big.bin", 100, Some(199)).await.unwrap();
+    assert_eq!(range, &contents[100..200]);
+
+    let mut stream = client.download_stream("big.bin").await.unwrap();
+    let mut downloaded = Vec::new();
+    while let Some(chunk) = stream.next().await {
+      downloaded.extend_from_slice(&chunk.unwrap());
     }
+    assert_eq!(downloaded, contents);
 
-    Ok(())
+    let listed = client.list_objects("big").await.unwrap();
+    assert!(listed.iter().any(|object| object.object_name == "big.bin" && object.size_bytes == contents.len() as u64));
+
+    client.delete_object("big.bin").await.unwrap();
+    assert!(client.head_object("big.bin").await.unwrap().is_none());
   }
 }
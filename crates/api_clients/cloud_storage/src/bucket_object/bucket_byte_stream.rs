@@ -0,0 +1,10 @@
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures::Stream;
+
+use errors::AnyhowResult;
+
+/// Object bytes as they arrive from the bucket, so large files never have to be held
+/// in memory all at once.
+pub type BucketByteStream = Pin<Box<dyn Stream<Item = AnyhowResult<Bytes>> + Send>>;
@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use s3::serde_types::HeadObjectResult;
+
+/// Object metadata from a HEAD request (no body is fetched).
+#[derive(Clone, Debug, Default)]
+pub struct BucketObjectHead {
+  pub maybe_content_length: Option<u64>,
+  pub maybe_content_type: Option<String>,
+  pub maybe_e_tag: Option<String>,
+  pub maybe_last_modified: Option<String>,
+
+  /// User metadata (`x-amz-meta-*`), without the prefix.
+  pub metadata: HashMap<String, String>,
+}
+
+impl From<HeadObjectResult> for BucketObjectHead {
+  fn from(head: HeadObjectResult) -> Self {
+    Self {
+      maybe_content_length: head.content_length.and_then(|length| u64::try_from(length).ok()),
+      maybe_content_type: head.content_type,
+      maybe_e_tag: head.e_tag,
+      maybe_last_modified: head.last_modified,
+      metadata: head.metadata.unwrap_or_default(),
+    }
+  }
+}
@@ -0,0 +1,12 @@
+/// One entry from a prefix listing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BucketObjectSummary {
+  /// The object name, relative to the client's bucket root (if it has one).
+  pub object_name: String,
+
+  pub size_bytes: u64,
+
+  pub last_modified: String,
+
+  pub maybe_e_tag: Option<String>,
+}
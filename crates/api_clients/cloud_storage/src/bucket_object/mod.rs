@@ -0,0 +1,3 @@
+pub mod bucket_byte_stream;
+pub mod bucket_object_head;
+pub mod bucket_object_summary;
@@ -1,2 +1,4 @@
+pub mod blob_store;
 pub mod bucket_client;
-pub mod remote_file_manager;
\ No newline at end of file
+pub mod bucket_object;
+pub mod remote_file_manager;
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use provider_http::base_url::BaseUrl;
+use provider_http::process_default::{shared_reqwest_client, ProcessDefault};
+
+use crate::error::client_error::FalClientError;
+use crate::error::fal_error_plus::FalErrorPlus;
+
+static DEFAULT_CONFIG: ProcessDefault<FalClientConfig> = ProcessDefault::new(FalClientConfig::production);
+
+/// Where FAL requests go, and the (pooled) HTTP client that sends them.
+///
+/// Requests use the process default unless one is passed in explicitly
+/// (`FalRequest::with_config`). Install a different default to route everything to a
+/// local stand-in server, or through a client with proxy settings.
+#[derive(Clone, Debug)]
+pub struct FalClientConfig {
+  /// Synchronous requests, `https://fal.run` in production.
+  pub run_base_url: BaseUrl,
+
+  /// Queued requests and their status/response/cancel URLs, `https://queue.fal.run` in production.
+  pub queue_base_url: BaseUrl,
+
+  pub http_client: reqwest::Client,
+}
+
+impl FalClientConfig {
+  pub fn production() -> Self {
+    Self {
+      run_base_url: BaseUrl::from_static("https://fal.run"),
+      queue_base_url: BaseUrl::from_static("https://queue.fal.run"),
+      http_client: shared_reqwest_client(),
+    }
+  }
+
+  /// Send both synchronous and queued requests to one server (eg. a local stand-in).
+  pub fn for_single_host(base_url: BaseUrl) -> Self {
+    Self {
+      run_base_url: base_url.clone(),
+      queue_base_url: base_url,
+      http_client: shared_reqwest_client(),
+    }
+  }
+
+  /// The process-wide default.
+  pub fn current() -> Arc<Self> {
+    DEFAULT_CONFIG.get()
+  }
+
+  /// Replace the process-wide default. Requests already built keep the old one.
+  pub fn install_default(config: Self) {
+    DEFAULT_CONFIG.set(config);
+  }
+
+  /// Queue URLs (status, response, cancel) carry our API key, so only send them to the queue host.
+  pub(crate) fn check_queue_url(&self, url: &str, url_kind: &str) -> Result<(), FalErrorPlus> {
+    if self.queue_base_url.owns_url(url) {
+      return Ok(());
+    }
+
+    let host = url::Url::parse(url)
+      .ok()
+      .and_then(|parsed| parsed.host_str().map(|host| host.to_string()))
+      .unwrap_or_default();
+
+    Err(FalErrorPlus::ClientError(FalClientError::InvalidUrl(format!(
+      "Expected host '{}' but got '{}' in {} URL: {}",
+      self.queue_base_url.host(),
+      host,
+      url_kind,
+      url,
+    ))))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn queue_url_check() {
+    let config = FalClientConfig::production();
+    assert!(config.check_queue_url("https://queue.fal.run/fal-ai/flux/requests/abc/status", "status").is_ok());
+
+    let err = config.check_queue_url("https://evil.example.com/fal-ai/flux/requests/abc/status", "status")
+      .unwrap_err()
+      .to_string();
+    assert!(err.contains("evil.example.com"), "{}", err);
+
+    let stand_in = FalClientConfig::for_single_host(BaseUrl::parse("http://127.0.0.1:8123").unwrap());
+    assert!(stand_in.check_queue_url("http://127.0.0.1:8123/fal-ai/flux/requests/abc/status", "status").is_ok());
+    assert!(stand_in.check_queue_url("https://queue.fal.run/fal-ai/flux/requests/abc/status", "status").is_err());
+  }
+}
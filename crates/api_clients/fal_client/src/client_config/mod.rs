@@ -0,0 +1 @@
+pub mod fal_client_config;
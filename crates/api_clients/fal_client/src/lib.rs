@@ -1,5 +1,6 @@
 //! A fal wrapper
 
+pub mod client_config;
 pub mod creds;
 pub mod error;
 pub mod polling;
@@ -0,0 +1,150 @@
+use crate::client_config::fal_client_config::FalClientConfig;
+use crate::creds::fal_api_key::FalApiKey;
+use crate::error::api_generic_error::FalGenericApiError;
+use crate::error::fal_error_plus::FalErrorPlus;
+use log::info;
+use reqwest::StatusCode;
+
+pub struct CancelJobArgs<'a> {
+  /// The queue "cancel" URL. Use `cancel_url_from_status_url` if you only kept the status URL.
+  pub cancel_url: &'a str,
+
+  pub api_key: &'a FalApiKey,
+}
+
+/// What FAL did with a cancellation request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FalCancelJobOutcome {
+  /// FAL accepted the cancellation. Queued requests never run; requests that
+  /// already started may still finish.
+  CancellationRequested,
+  /// The request finished before we asked.
+  AlreadyCompleted,
+  /// FAL doesn't know the request (wrong app, or it has expired).
+  NotFound,
+}
+
+/// Cancel a queued FAL job.
+///
+/// The `cancel_url` must point to the FAL queue host (`queue.fal.run` in production).
+pub async fn cancel_job(args: CancelJobArgs<'_>) -> Result<FalCancelJobOutcome, FalErrorPlus> {
+  let config = FalClientConfig::current();
+
+  config.check_queue_url(args.cancel_url, "cancel")?;
+
+  info!("Cancelling FAL job: {}", args.cancel_url);
+
+  let response = config.http_client
+    .put(args.cancel_url)
+    .header("Authorization", format!("Key {}", args.api_key.0))
+    .send()
+    .await?;
+
+  let http_status = response.status();
+  let body = response.text().await?;
+
+  classify_cancel_response(http_status, body)
+}
+
+/// Queue URLs look like `https://queue.fal.run/{app}/requests/{request_id}[/status]`.
+/// Returns `None` if the URL isn't a FAL queue request URL.
+pub fn cancel_url_from_status_url(status_url: &str) -> Option<String> {
+  let base = status_url.trim_end_matches('/');
+  let base = base.strip_suffix("/status").unwrap_or(base);
+
+  if !FalClientConfig::current().queue_base_url.owns_url(base) || !base.contains("/requests/") {
+    return None;
+  }
+
+  Some(format!("{}/cancel", base))
+}
+
+fn classify_cancel_response(http_status: StatusCode, body: String) -> Result<FalCancelJobOutcome, FalErrorPlus> {
+  // FAL answers with a small JSON body, eg. `{"status": "ALREADY_COMPLETED"}`.
+  let status = serde_json::from_str::<serde_json::Value>(&body)
+    .ok()
+    .and_then(|value| value.get("status").and_then(|status| status.as_str()).map(|status| status.to_string()));
+
+  match (http_status, status.as_deref()) {
+    (_, Some("CANCELLATION_REQUESTED")) => Ok(FalCancelJobOutcome::CancellationRequested),
+    (_, Some("ALREADY_COMPLETED")) => Ok(FalCancelJobOutcome::AlreadyCompleted),
+    (StatusCode::NOT_FOUND, _) => Ok(FalCancelJobOutcome::NotFound),
+    (http_status, _) if http_status.is_success() => Ok(FalCancelJobOutcome::CancellationRequested),
+    (http_status, _) => Err(FalErrorPlus::ApiGeneric(
+      FalGenericApiError::UncategorizedBadResponseWithStatusAndBody {
+        status_code: http_status,
+        body,
+      },
+    )),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn rejects_wrong_host() {
+    let api_key = FalApiKey::from_str("test-key");
+    let result = cancel_job(CancelJobArgs {
+      cancel_url: "https://evil.example.com/fal-ai/flux/requests/abc123/cancel",
+      api_key: &api_key,
+    }).await;
+    let err = format!("{}", result.unwrap_err());
+    assert!(err.contains("evil.example.com"), "error should mention the bad host: {}", err);
+  }
+
+  mod cancel_url {
+    use super::*;
+
+    #[test]
+    fn from_status_url() {
+      assert_eq!(
+        cancel_url_from_status_url("https://queue.fal.run/fal-ai/hunyuan3d-v3/requests/abc/status").as_deref(),
+        Some("https://queue.fal.run/fal-ai/hunyuan3d-v3/requests/abc/cancel"),
+      );
+    }
+
+    #[test]
+    fn from_bare_request_url() {
+      assert_eq!(
+        cancel_url_from_status_url("https://queue.fal.run/fal-ai/flux/requests/abc").as_deref(),
+        Some("https://queue.fal.run/fal-ai/flux/requests/abc/cancel"),
+      );
+    }
+
+    #[test]
+    fn rejects_other_urls() {
+      assert_eq!(cancel_url_from_status_url("https://fal.media/files/abc.png"), None);
+      assert_eq!(cancel_url_from_status_url("https://queue.fal.run/fal-ai/flux"), None);
+    }
+  }
+
+  mod classify {
+    use super::*;
+
+    #[test]
+    fn cancellation_requested() {
+      let outcome = classify_cancel_response(StatusCode::ACCEPTED, r#"{"status":"CANCELLATION_REQUESTED"}"#.to_string());
+      assert_eq!(outcome.unwrap(), FalCancelJobOutcome::CancellationRequested);
+    }
+
+    #[test]
+    fn already_completed() {
+      let outcome = classify_cancel_response(StatusCode::BAD_REQUEST, r#"{"status":"ALREADY_COMPLETED"}"#.to_string());
+      assert_eq!(outcome.unwrap(), FalCancelJobOutcome::AlreadyCompleted);
+    }
+
+    #[test]
+    fn not_found() {
+      let outcome = classify_cancel_response(StatusCode::NOT_FOUND, r#"{"status":"NOT_FOUND"}"#.to_string());
+      assert_eq!(outcome.unwrap(), FalCancelJobOutcome::NotFound);
+    }
+
+    #[test]
+    fn other_errors() {
+      let outcome = classify_cancel_response(StatusCode::UNAUTHORIZED, "nope".to_string());
+      assert!(outcome.is_err());
+    }
+  }
+}
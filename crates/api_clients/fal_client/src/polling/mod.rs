@@ -1,2 +1,3 @@
-pub mod poll_job_status;
+pub mod cancel_job;
 pub mod poll_job_response;
+pub mod poll_job_status;
@@ -1,7 +1,7 @@
+use crate::client_config::fal_client_config::FalClientConfig;
 use crate::creds::fal_api_key::FalApiKey;
 use crate::error::api_generic_error::FalGenericApiError;
 use crate::error::api_specific_error::FalSpecificApiError;
-use crate::error::client_error::FalClientError;
 use crate::error::fal_error_plus::FalErrorPlus;
 use crate::polling::poll_job_response::raw_response::RawIncompleteJobResponse;
 use crate::polling::poll_job_response::success_case_extractors::{
@@ -9,9 +9,6 @@ use crate::polling::poll_job_response::success_case_extractors::{
 };
 use log::info;
 use serde_json::Value;
-use url::Url;
-
-const EXPECTED_HOST: &str = "queue.fal.run";
 
 pub struct PollJobResponseArgs<'a> {
   /// This is the "response" URL (not the "status" URL).
@@ -38,27 +35,18 @@ pub struct PollJobResponse {
 
 /// Fetch the results of a completed FAL job.
 ///
-/// The `response_url` must point to `queue.fal.run`.
+/// The `response_url` must point to the FAL queue host (`queue.fal.run` in production).
 ///
 /// Returns `FalErrorPlus::ApiSpecific(IncompleteJob)` if the job is still
 /// in progress (FAL returns HTTP 400 with `"detail": "Request is still in progress"`).
 pub async fn poll_job_response(args: PollJobResponseArgs<'_>) -> Result<PollJobResponse, FalErrorPlus> {
-  let parsed = Url::parse(args.response_url)?;
-
-  let host = parsed.host_str().unwrap_or("");
+  let config = FalClientConfig::current();
 
-  if host != EXPECTED_HOST {
-    return Err(FalErrorPlus::ClientError(FalClientError::InvalidUrl(format!(
-      "Expected host '{}' but got '{}' in response URL: {}",
-      EXPECTED_HOST,
-      host,
-      args.response_url,
-    ))));
-  }
+  config.check_queue_url(args.response_url, "response")?;
 
   info!("Polling FAL job response: {}", args.response_url);
 
-  let response = reqwest::Client::new()
+  let response = config.http_client
     .get(args.response_url)
     .header("Authorization", format!("Key {}", args.api_key.0))
     .send()
@@ -1,12 +1,9 @@
+use crate::client_config::fal_client_config::FalClientConfig;
 use crate::creds::fal_api_key::FalApiKey;
 use crate::error::api_generic_error::FalGenericApiError;
-use crate::error::client_error::FalClientError;
 use crate::error::fal_error_plus::FalErrorPlus;
 use crate::polling::poll_job_status::raw_response::RawPollJobStatusResponse;
 use log::info;
-use url::Url;
-
-const EXPECTED_HOST: &str = "queue.fal.run";
 
 pub struct PollJobStatusArgs<'a> {
   /// This is the "status" URL, not the "response" URL. 
@@ -48,24 +45,15 @@ pub struct PollJobStatusResponse {
 
 /// Poll the status of a queued FAL job.
 ///
-/// The `status_url` must point to `queue.fal.run`.
+/// The `status_url` must point to the FAL queue host (`queue.fal.run` in production).
 pub async fn poll_job_status(args: PollJobStatusArgs<'_>) -> Result<PollJobStatusResponse, FalErrorPlus> {
-  let parsed = Url::parse(args.status_url)?;
+  let config = FalClientConfig::current();
 
-  let host = parsed.host_str().unwrap_or("");
-  
-  if host != EXPECTED_HOST {
-    return Err(FalErrorPlus::ClientError(FalClientError::InvalidUrl(format!(
-      "Expected host '{}' but got '{}' in status URL: {}",
-      EXPECTED_HOST,
-      host,
-      args.status_url,
-    ))));
-  }
+  config.check_queue_url(args.status_url, "status")?;
 
   info!("Polling FAL job status: {}", args.status_url);
 
-  let response = reqwest::Client::new()
+  let response = config.http_client
     .get(args.status_url)
     .header("Authorization", format!("Key {}", args.api_key.0))
     .send()
@@ -143,8 +131,9 @@ mod tests {
 
   #[test]
   fn accepts_valid_host() {
-    let parsed = Url::parse("https://queue.fal.run/fal-ai/flux/requests/019e18d8-8c36-7bc1-aa77-2bc2f70268c6").unwrap();
-    assert_eq!(parsed.host_str(), Some(EXPECTED_HOST));
+    let config = FalClientConfig::production();
+    let status_url = "https://queue.fal.run/fal-ai/flux/requests/019e18d8-8c36-7bc1-aa77-2bc2f70268c6";
+    assert!(config.check_queue_url(status_url, "status").is_ok());
   }
 
   // ── Parsing tests ──
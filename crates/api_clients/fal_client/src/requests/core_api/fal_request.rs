@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use reqwest::IntoUrl;
 use serde::{de::DeserializeOwned, Serialize};
 use log::info;
 
+use crate::client_config::fal_client_config::FalClientConfig;
 use crate::error::fal_error::FalError;
 use crate::requests::core_api::queue::Queue;
 use crate::requests::core_api::queue_response::QueueResponse;
@@ -17,6 +19,8 @@ use crate::requests::core_api::webhook_response::WebhookResponse;
 pub struct FalRequest<Params: Serialize, Response: DeserializeOwned> {
   /// The Reqwest Client to use to make requests
   pub client: reqwest::Client,
+  /// Where to send the request. Defaults to `FalClientConfig::current()`.
+  pub config: Arc<FalClientConfig>,
   /// The endpoint to make the request to
   pub endpoint: String,
   /// The parameters to send to the endpoint
@@ -29,8 +33,10 @@ pub struct FalRequest<Params: Serialize, Response: DeserializeOwned> {
 
 impl<Params: Serialize, Response: DeserializeOwned> FalRequest<Params, Response> {
   pub fn new(endpoint: impl Into<String>, params: Params) -> Self {
+    let config = FalClientConfig::current();
     Self {
-      client: reqwest::Client::new(),
+      client: config.http_client.clone(),
+      config,
       endpoint: endpoint.into(),
       params,
       api_key: std::env::var("FAL_API_KEY").ok(),
@@ -44,6 +50,13 @@ impl<Params: Serialize, Response: DeserializeOwned> FalRequest<Params, Response>
     self
   }
 
+  /// Send to specific hosts (and with that config's client)
+  pub fn with_config(mut self, config: Arc<FalClientConfig>) -> Self {
+    self.client = config.http_client.clone();
+    self.config = config;
+    self
+  }
+
   /// Use a specific API key to make requests
   pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
     self.api_key = Some(api_key.into());
@@ -54,7 +67,7 @@ impl<Params: Serialize, Response: DeserializeOwned> FalRequest<Params, Response>
   pub async fn send(self) -> Result<Response, FalError> {
     let response = self
       .client
-      .post(format!("https://fal.run/{}", self.endpoint))
+      .post(self.config.run_base_url.join(&self.endpoint))
       .json(&self.params)
       .header(
         "Authorization",
@@ -85,7 +98,7 @@ impl<Params: Serialize, Response: DeserializeOwned> FalRequest<Params, Response>
 
     let response = self
       .client
-      .post(format!("https://queue.fal.run/{}", self.endpoint))
+      .post(self.config.queue_base_url.join(&self.endpoint))
       .json(&self.params)
       .header("Authorization", format!("Key {}", &key))
       .header("Content-Type", "application/json")
@@ -110,7 +123,7 @@ impl<Params: Serialize, Response: DeserializeOwned> FalRequest<Params, Response>
 
     let url_encoded = url.into_url()?;
 
-    let request_url = format!("https://queue.fal.run/{}?fal_webhook={}", self.endpoint, url_encoded);
+    let request_url = format!("{}?fal_webhook={}", self.config.queue_base_url.join(&self.endpoint), url_encoded);
 
     info!("Sending request to FAL queue webhook: {}", request_url);
 
@@ -141,7 +154,7 @@ impl<Params: Serialize, Response: DeserializeOwned> FalRequest<Params, Response>
 
     let response = self
         .client
-        .post(format!("https://queue.fal.run/{}", self.endpoint))
+        .post(self.config.queue_base_url.join(&self.endpoint))
         .json(&self.params)
         .header("Authorization", format!("Key {}", &key))
         .header("Content-Type", "application/json")
@@ -162,3 +175,80 @@ impl<Params: Serialize, Response: DeserializeOwned> FalRequest<Params, Response>
     Ok(payload)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::io::{BufRead, BufReader, Read, Write};
+  use std::net::TcpListener;
+  use std::thread;
+
+  use provider_http::base_url::BaseUrl;
+  use serde::Deserialize;
+
+  use super::*;
+
+  #[derive(Serialize)]
+  struct Params {
+    prompt: String,
+  }
+
+  #[derive(Deserialize)]
+  struct Output {
+    ok: bool,
+  }
+
+  /// Answers a single request with `body`, and hands back the request line.
+  fn stand_in_server(body: &'static str) -> (BaseUrl, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = BaseUrl::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+
+    let handle = thread::spawn(move || {
+      let (stream, _) = listener.accept().unwrap();
+      let mut reader = BufReader::new(stream);
+
+      let mut request_line = String::new();
+      reader.read_line(&mut request_line).unwrap();
+
+      let mut content_length = 0;
+      loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        if header == "\r\n" {
+          break;
+        }
+        if let Some(value) = header.to_ascii_lowercase().strip_prefix("content-length:") {
+          content_length = value.trim().parse().unwrap();
+        }
+      }
+      let mut request_body = vec![0; content_length];
+      reader.read_exact(&mut request_body).unwrap();
+
+      let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        body.len(),
+        body,
+      );
+      reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+      request_line.trim().to_string()
+    });
+
+    (base_url, handle)
+  }
+
+  #[tokio::test]
+  async fn sends_to_configured_host() {
+    let (base_url, server) = stand_in_server(r#"{"ok": true}"#);
+    let config = Arc::new(FalClientConfig::for_single_host(base_url));
+
+    let output: Output = FalRequest::new("fal-ai/flux/dev", Params { prompt: "a cat".to_string() })
+      .with_config(config)
+      .with_api_key("test-key")
+      .send()
+      .await
+      .unwrap();
+
+    assert!(output.ok);
+    assert_eq!(server.join().unwrap(), "POST /fal-ai/flux/dev HTTP/1.1");
+  }
+}
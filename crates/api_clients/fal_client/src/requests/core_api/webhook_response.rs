@@ -5,4 +5,8 @@ pub struct WebhookResponse {
   pub status: Option<String>,
   pub request_id: Option<String>,
   pub gateway_request_id: Option<String>,
+
+  /// The queue status URL. FAL returns it even for webhook submissions,
+  /// and the cancel URL is derived from it.
+  pub status_url: Option<String>,
 }
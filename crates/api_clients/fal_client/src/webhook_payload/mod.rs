@@ -2,3 +2,4 @@ pub mod raw;
 pub mod hydrated;
 pub mod hydrate_webhook_contents;
 pub mod parse_raw_webhook_payload;
+pub mod signature;
@@ -0,0 +1,99 @@
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
+use ed25519_compact::PublicKey;
+use log::warn;
+use serde::Deserialize;
+
+use crate::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
+
+pub const FAL_JWKS_URL: &str = "https://rest.alpha.fal.ai/.well-known/jwks.json";
+
+/// FAL's webhook signing keys.
+#[derive(Clone, Debug)]
+pub struct FalJwks {
+  pub keys: Vec<PublicKey>,
+}
+
+#[derive(Deserialize)]
+struct RawJwks {
+  keys: Vec<RawJwk>,
+}
+
+#[derive(Deserialize)]
+struct RawJwk {
+  kty: Option<String>,
+  crv: Option<String>,
+  x: Option<String>,
+}
+
+impl FalJwks {
+  /// Parse a JWKS document, keeping only OKP/Ed25519 keys.
+  pub fn from_json(json: &str) -> Result<Self, FalWebhookSignatureError> {
+    let raw: RawJwks = serde_json::from_str(json)
+      .map_err(|err| FalWebhookSignatureError::JwksFetch(format!("invalid JWKS JSON: {}", err)))?;
+
+    let keys: Vec<PublicKey> = raw.keys.iter()
+      .filter(|jwk| jwk.kty.as_deref() == Some("OKP") && jwk.crv.as_deref() == Some("Ed25519"))
+      .filter_map(|jwk| {
+        let x = jwk.x.as_deref()?;
+        let key = BASE64_URL_SAFE_NO_PAD.decode(x).ok()
+          .and_then(|bytes| PublicKey::from_slice(&bytes).ok());
+        if key.is_none() {
+          warn!("Skipping malformed FAL JWKS key: {}", x);
+        }
+        key
+      })
+      .collect();
+
+    if keys.is_empty() {
+      return Err(FalWebhookSignatureError::NoKeys);
+    }
+
+    Ok(Self { keys })
+  }
+
+  pub async fn fetch(client: &reqwest::Client) -> Result<Self, FalWebhookSignatureError> {
+    let response = client.get(FAL_JWKS_URL)
+      .send()
+      .await
+      .map_err(|err| FalWebhookSignatureError::JwksFetch(err.to_string()))?;
+
+    let status = response.status();
+    let body = response.text()
+      .await
+      .map_err(|err| FalWebhookSignatureError::JwksFetch(err.to_string()))?;
+
+    if !status.is_success() {
+      return Err(FalWebhookSignatureError::JwksFetch(format!("status {}: {}", status, body)));
+    }
+
+    Self::from_json(&body)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use ed25519_compact::KeyPair;
+
+  #[test]
+  fn parses_ed25519_keys_and_skips_others() {
+    let key_pair = KeyPair::generate();
+    let x = BASE64_URL_SAFE_NO_PAD.encode(key_pair.pk.as_ref());
+    let json = format!(r#"{{"keys": [
+      {{"kty": "OKP", "crv": "Ed25519", "x": "{}"}},
+      {{"kty": "RSA", "n": "abc", "e": "AQAB"}},
+      {{"kty": "OKP", "crv": "Ed25519", "x": "not-a-key"}}
+    ]}}"#, x);
+
+    let jwks = FalJwks::from_json(&json).unwrap();
+    assert_eq!(jwks.keys.len(), 1);
+    assert_eq!(jwks.keys[0], key_pair.pk);
+  }
+
+  #[test]
+  fn no_usable_keys_is_an_error() {
+    let result = FalJwks::from_json(r#"{"keys": []}"#);
+    assert!(matches!(result, Err(FalWebhookSignatureError::NoKeys)));
+  }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ed25519_compact::PublicKey;
+use log::{info, warn};
+use tokio::sync::Mutex;
+
+use crate::webhook_payload::signature::fal_jwks::FalJwks;
+use crate::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
+
+/// FAL rotates keys rarely; refresh once a day.
+const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Don't refetch on a signature mismatch more often than this (forged requests
+/// shouldn't be able to make us hammer FAL's JWKS endpoint).
+const MIN_FORCED_REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Caches FAL's JWKS. Cheap to clone; clones share the cache.
+#[derive(Clone)]
+pub struct FalJwksCache {
+  source: JwksSource,
+  ttl: Duration,
+  cached: Arc<Mutex<Option<CachedJwks>>>,
+}
+
+#[derive(Clone)]
+enum JwksSource {
+  Remote(reqwest::Client),
+  Static,
+}
+
+struct CachedJwks {
+  jwks: FalJwks,
+  fetched_at: Instant,
+}
+
+impl FalJwksCache {
+  pub fn new(client: reqwest::Client) -> Self {
+    Self {
+      source: JwksSource::Remote(client),
+      ttl: DEFAULT_TTL,
+      cached: Arc::new(Mutex::new(None)),
+    }
+  }
+
+  /// A cache that always returns the given keys and never fetches (for tests and local development).
+  pub fn from_static(jwks: FalJwks) -> Self {
+    Self {
+      source: JwksSource::Static,
+      ttl: DEFAULT_TTL,
+      cached: Arc::new(Mutex::new(Some(CachedJwks { jwks, fetched_at: Instant::now() }))),
+    }
+  }
+
+  /// Return the cached keys, fetching them if absent or expired.
+  pub async fn get_keys(&self) -> Result<Vec<PublicKey>, FalWebhookSignatureError> {
+    self.get_keys_inner(false).await
+  }
+
+  /// Refetch the keys if they haven't been fetched recently (eg. after a signature
+  /// mismatch that may be due to key rotation). Returns the current keys.
+  pub async fn refresh_keys(&self) -> Result<Vec<PublicKey>, FalWebhookSignatureError> {
+    self.get_keys_inner(true).await
+  }
+
+  async fn get_keys_inner(&self, force: bool) -> Result<Vec<PublicKey>, FalWebhookSignatureError> {
+    let mut cached = self.cached.lock().await;
+
+    let client = match &self.source {
+      JwksSource::Static => {
+        return cached.as_ref()
+          .map(|cached| cached.jwks.keys.clone())
+          .ok_or(FalWebhookSignatureError::NoKeys);
+      }
+      JwksSource::Remote(client) => client,
+    };
+
+    let needs_fetch = match cached.as_ref() {
+      None => true,
+      Some(entry) if force => entry.fetched_at.elapsed() >= MIN_FORCED_REFRESH_INTERVAL,
+      Some(entry) => entry.fetched_at.elapsed() >= self.ttl,
+    };
+
+    if needs_fetch {
+      match FalJwks::fetch(client).await {
+        Ok(jwks) => {
+          info!("Fetched FAL JWKS ({} keys)", jwks.keys.len());
+          *cached = Some(CachedJwks { jwks, fetched_at: Instant::now() });
+        }
+        Err(err) => match cached.as_ref() {
+          // Prefer stale keys to rejecting every webhook while FAL's endpoint is down.
+          Some(_) => warn!("Failed to refresh FAL JWKS; using stale keys: {}", err),
+          None => return Err(err),
+        },
+      }
+    }
+
+    cached.as_ref()
+      .map(|cached| cached.jwks.keys.clone())
+      .ok_or(FalWebhookSignatureError::NoKeys)
+  }
+}
@@ -0,0 +1,40 @@
+use crate::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
+
+pub const FAL_WEBHOOK_REQUEST_ID_HEADER: &str = "X-Fal-Webhook-Request-Id";
+pub const FAL_WEBHOOK_USER_ID_HEADER: &str = "X-Fal-Webhook-User-Id";
+pub const FAL_WEBHOOK_TIMESTAMP_HEADER: &str = "X-Fal-Webhook-Timestamp";
+pub const FAL_WEBHOOK_SIGNATURE_HEADER: &str = "X-Fal-Webhook-Signature";
+
+/// The signing headers FAL attaches to each webhook delivery.
+#[derive(Clone, Debug)]
+pub struct FalWebhookHeaders {
+  pub request_id: String,
+  pub user_id: String,
+
+  /// Unix timestamp (seconds), as sent.
+  pub timestamp: String,
+
+  /// Hex-encoded ED25519 signature.
+  pub signature: String,
+}
+
+impl FalWebhookHeaders {
+  /// Read the headers with a lookup function, so this crate doesn't depend on a web framework.
+  /// The lookup should be case-insensitive, as HTTP header names are.
+  pub fn from_lookup<F>(lookup: F) -> Result<Self, FalWebhookSignatureError>
+    where F: Fn(&'static str) -> Option<String>
+  {
+    let get = |name: &'static str| {
+      lookup(name)
+        .filter(|value| !value.trim().is_empty())
+        .ok_or(FalWebhookSignatureError::MissingHeader(name))
+    };
+
+    Ok(Self {
+      request_id: get(FAL_WEBHOOK_REQUEST_ID_HEADER)?,
+      user_id: get(FAL_WEBHOOK_USER_ID_HEADER)?,
+      timestamp: get(FAL_WEBHOOK_TIMESTAMP_HEADER)?,
+      signature: get(FAL_WEBHOOK_SIGNATURE_HEADER)?,
+    })
+  }
+}
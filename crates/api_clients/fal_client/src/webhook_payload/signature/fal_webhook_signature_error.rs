@@ -0,0 +1,50 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum FalWebhookSignatureError {
+  /// A required `X-Fal-Webhook-*` header was absent or not valid UTF-8.
+  MissingHeader(&'static str),
+
+  /// The timestamp header was not a unix timestamp (seconds).
+  InvalidTimestamp(String),
+
+  /// The timestamp is too far from the current time (possibly a replay of an old delivery).
+  TimestampOutOfRange { skew_seconds: i64 },
+
+  /// The signature header was not a hex-encoded ED25519 signature.
+  InvalidSignatureEncoding,
+
+  /// The signature did not verify against any of FAL's published keys.
+  SignatureMismatch,
+
+  /// The request id was already seen inside the replay window.
+  Replayed { request_id: String },
+
+  /// The JWKS had no usable ED25519 keys.
+  NoKeys,
+
+  /// Failed to fetch or decode FAL's JWKS.
+  JwksFetch(String),
+
+  /// The replay guard's store was unavailable.
+  ReplayGuard(String),
+}
+
+impl Error for FalWebhookSignatureError {}
+
+impl Display for FalWebhookSignatureError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::MissingHeader(header) => write!(f, "Missing webhook header: {}", header),
+      Self::InvalidTimestamp(value) => write!(f, "Invalid webhook timestamp: {}", value),
+      Self::TimestampOutOfRange { skew_seconds } => write!(f, "Webhook timestamp out of range (skew: {}s)", skew_seconds),
+      Self::InvalidSignatureEncoding => write!(f, "Webhook signature is not a hex-encoded ED25519 signature"),
+      Self::SignatureMismatch => write!(f, "Webhook signature did not verify against any FAL key"),
+      Self::Replayed { request_id } => write!(f, "Webhook request id was already delivered: {}", request_id),
+      Self::NoKeys => write!(f, "FAL JWKS contained no usable ED25519 keys"),
+      Self::JwksFetch(msg) => write!(f, "Failed to fetch FAL JWKS: {}", msg),
+      Self::ReplayGuard(msg) => write!(f, "Failed to check webhook replay guard: {}", msg),
+    }
+  }
+}
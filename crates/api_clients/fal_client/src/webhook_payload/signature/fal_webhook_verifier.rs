@@ -0,0 +1,148 @@
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::warn;
+
+use crate::webhook_payload::signature::fal_jwks_cache::FalJwksCache;
+use crate::webhook_payload::signature::fal_webhook_headers::FalWebhookHeaders;
+use crate::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
+use crate::webhook_payload::signature::verify_fal_webhook_signature::{verify_fal_webhook_signature, DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS};
+use crate::webhook_payload::signature::webhook_replay_guard::WebhookReplayGuard;
+
+/// Authenticates incoming FAL webhook deliveries: signature, timestamp, and replay.
+/// Cheap to clone; clones share the key cache and replay guard.
+#[derive(Clone)]
+pub struct FalWebhookVerifier {
+  jwks: FalJwksCache,
+  replay_guard: Arc<dyn WebhookReplayGuard>,
+  max_skew_seconds: i64,
+}
+
+impl FalWebhookVerifier {
+  /// Verify against FAL's published keys, fetched on demand with `http_client`.
+  pub fn new(http_client: reqwest::Client, replay_guard: Arc<dyn WebhookReplayGuard>) -> Self {
+    Self::with_jwks_cache(FalJwksCache::new(http_client), replay_guard)
+  }
+
+  pub fn with_jwks_cache(jwks: FalJwksCache, replay_guard: Arc<dyn WebhookReplayGuard>) -> Self {
+    Self {
+      jwks,
+      replay_guard,
+      max_skew_seconds: DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS,
+    }
+  }
+
+  pub async fn verify(&self, headers: &FalWebhookHeaders, body: &[u8]) -> Result<(), FalWebhookSignatureError> {
+    let now = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_secs() as i64)
+      .unwrap_or(0);
+    self.verify_at(headers, body, now).await
+  }
+
+  pub async fn verify_at(
+    &self,
+    headers: &FalWebhookHeaders,
+    body: &[u8],
+    now_unix_seconds: i64,
+  ) -> Result<(), FalWebhookSignatureError> {
+    let keys = self.jwks.get_keys().await?;
+
+    let result = verify_fal_webhook_signature(headers, body, &keys, now_unix_seconds, self.max_skew_seconds);
+
+    if let Err(FalWebhookSignatureError::SignatureMismatch) = result {
+      // FAL may have rotated its keys since we cached them.
+      warn!("FAL webhook signature mismatch for request id {}; refreshing JWKS", headers.request_id);
+      let keys = self.jwks.refresh_keys().await?;
+      verify_fal_webhook_signature(headers, body, &keys, now_unix_seconds, self.max_skew_seconds)?;
+    } else {
+      result?;
+    }
+
+    // The timestamp parsed during verification, so this can't fail here.
+    let timestamp = headers.timestamp.trim().parse::<i64>().unwrap_or(now_unix_seconds);
+
+    if !self.replay_guard.check_and_record(&headers.signature, timestamp, now_unix_seconds).await? {
+      return Err(FalWebhookSignatureError::Replayed { request_id: headers.request_id.clone() });
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::webhook_payload::signature::fal_jwks::FalJwks;
+  use crate::webhook_payload::signature::in_memory_webhook_replay_guard::InMemoryWebhookReplayGuard;
+  use crate::webhook_payload::signature::verify_fal_webhook_signature::test_signing::sign;
+  use ed25519_compact::KeyPair;
+
+  const NOW: i64 = 1_760_000_000;
+  const BODY: &[u8] = br#"{"request_id":"req_1","gateway_request_id":"req_1","status":"OK","payload":{}}"#;
+
+  fn verifier(key_pair: &KeyPair) -> FalWebhookVerifier {
+    FalWebhookVerifier::with_jwks_cache(
+      FalJwksCache::from_static(FalJwks { keys: vec![key_pair.pk] }),
+      Arc::new(InMemoryWebhookReplayGuard::new(DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS)))
+  }
+
+  #[tokio::test]
+  async fn valid_delivery() {
+    let key_pair = KeyPair::generate();
+    let headers = sign(&key_pair, "req_1", NOW, BODY);
+    assert!(verifier(&key_pair).verify_at(&headers, BODY, NOW).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn replayed_delivery() {
+    let key_pair = KeyPair::generate();
+    let verifier = verifier(&key_pair);
+    let headers = sign(&key_pair, "req_1", NOW, BODY);
+
+    assert!(verifier.verify_at(&headers, BODY, NOW).await.is_ok());
+
+    let result = verifier.verify_at(&headers, BODY, NOW + 30).await;
+    assert!(matches!(result, Err(FalWebhookSignatureError::Replayed { .. })));
+  }
+
+  #[tokio::test]
+  async fn retried_delivery_is_not_a_replay() {
+    let key_pair = KeyPair::generate();
+    let verifier = verifier(&key_pair);
+
+    let first = sign(&key_pair, "req_1", NOW, BODY);
+    let retry = sign(&key_pair, "req_1", NOW + 60, BODY);
+
+    assert!(verifier.verify_at(&first, BODY, NOW).await.is_ok());
+    assert!(verifier.verify_at(&retry, BODY, NOW + 60).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn expired_delivery() {
+    let key_pair = KeyPair::generate();
+    let headers = sign(&key_pair, "req_1", NOW - 3600, BODY);
+    let result = verifier(&key_pair).verify_at(&headers, BODY, NOW).await;
+    assert!(matches!(result, Err(FalWebhookSignatureError::TimestampOutOfRange { .. })));
+  }
+
+  #[tokio::test]
+  async fn tampered_delivery() {
+    let key_pair = KeyPair::generate();
+    let headers = sign(&key_pair, "req_1", NOW, BODY);
+    let tampered = br#"{"request_id":"req_1","gateway_request_id":"req_1","status":"ERROR","payload":{}}"#;
+    let result = verifier(&key_pair).verify_at(&headers, tampered, NOW).await;
+    assert!(matches!(result, Err(FalWebhookSignatureError::SignatureMismatch)));
+  }
+
+  #[tokio::test]
+  async fn rejected_deliveries_are_not_recorded() {
+    let key_pair = KeyPair::generate();
+    let verifier = verifier(&key_pair);
+    let headers = sign(&key_pair, "req_1", NOW, BODY);
+
+    // A tampered copy with the same signature must not "use up" the genuine delivery.
+    assert!(verifier.verify_at(&headers, b"{}", NOW).await.is_err());
+    assert!(verifier.verify_at(&headers, BODY, NOW).await.is_ok());
+  }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
+use crate::webhook_payload::signature::webhook_replay_guard::{replay_key, WebhookReplayGuard};
+
+/// A replay guard for a single process (tests and local development).
+#[derive(Clone)]
+pub struct InMemoryWebhookReplayGuard {
+  /// Entries are kept this long past their timestamp (should cover the skew in both directions).
+  retention_seconds: i64,
+
+  /// Signature => delivery timestamp (unix seconds)
+  seen: Arc<Mutex<HashMap<String, i64>>>,
+}
+
+impl InMemoryWebhookReplayGuard {
+  pub fn new(max_skew_seconds: i64) -> Self {
+    Self {
+      retention_seconds: max_skew_seconds * 2,
+      seen: Arc::new(Mutex::new(HashMap::new())),
+    }
+  }
+
+  /// Record a delivery. Returns false if the same delivery was already recorded.
+  pub fn record(&self, signature: &str, timestamp: i64, now_unix_seconds: i64) -> bool {
+    let mut seen = self.seen.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let retention_seconds = self.retention_seconds;
+    seen.retain(|_, seen_timestamp| now_unix_seconds - *seen_timestamp <= retention_seconds);
+
+    let key = replay_key(signature);
+    if seen.contains_key(&key) {
+      return false;
+    }
+
+    seen.insert(key, timestamp);
+    true
+  }
+}
+
+#[async_trait]
+impl WebhookReplayGuard for InMemoryWebhookReplayGuard {
+  async fn check_and_record(
+    &self,
+    signature: &str,
+    timestamp: i64,
+    now_unix_seconds: i64,
+  ) -> Result<bool, FalWebhookSignatureError> {
+    Ok(self.record(signature, timestamp, now_unix_seconds))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn second_delivery_is_rejected() {
+    let guard = InMemoryWebhookReplayGuard::new(300);
+    assert!(guard.record("abcd", 1000, 1000));
+    assert!(!guard.record("abcd", 1000, 1010));
+    assert!(!guard.record("ABCD", 1000, 1010));
+    assert!(guard.record("ef01", 1000, 1010));
+  }
+
+  #[test]
+  fn entries_expire_after_retention() {
+    let guard = InMemoryWebhookReplayGuard::new(300);
+    assert!(guard.record("abcd", 1000, 1000));
+    assert!(guard.record("abcd", 1000, 1601));
+  }
+}
@@ -0,0 +1,11 @@
+//! FAL signs webhook deliveries with ED25519. The public keys are published as a JWKS.
+//! See https://docs.fal.ai/model-apis/model-endpoints/webhooks#verifying-webhook-signatures
+
+pub mod fal_jwks;
+pub mod fal_jwks_cache;
+pub mod fal_webhook_headers;
+pub mod fal_webhook_signature_error;
+pub mod fal_webhook_verifier;
+pub mod in_memory_webhook_replay_guard;
+pub mod verify_fal_webhook_signature;
+pub mod webhook_replay_guard;
@@ -0,0 +1,162 @@
+use ed25519_compact::{PublicKey, Signature};
+use sha2::{Digest, Sha256};
+
+use crate::webhook_payload::signature::fal_webhook_headers::FalWebhookHeaders;
+use crate::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
+
+/// FAL recommends rejecting deliveries more than five minutes from the current time.
+pub const DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS: i64 = 300;
+
+/// Verify a webhook delivery's signature and timestamp. This does not check for replays.
+///
+/// The signed message is the request id, user id, timestamp, and hex SHA-256 of the
+/// raw body, joined with newlines.
+pub fn verify_fal_webhook_signature(
+  headers: &FalWebhookHeaders,
+  body: &[u8],
+  keys: &[PublicKey],
+  now_unix_seconds: i64,
+  max_skew_seconds: i64,
+) -> Result<(), FalWebhookSignatureError> {
+  let timestamp = headers.timestamp.trim().parse::<i64>()
+    .map_err(|_| FalWebhookSignatureError::InvalidTimestamp(headers.timestamp.clone()))?;
+
+  let skew_seconds = now_unix_seconds - timestamp;
+  if skew_seconds.abs() > max_skew_seconds {
+    return Err(FalWebhookSignatureError::TimestampOutOfRange { skew_seconds });
+  }
+
+  let signature = hex::decode(headers.signature.trim())
+    .ok()
+    .and_then(|bytes| Signature::from_slice(&bytes).ok())
+    .ok_or(FalWebhookSignatureError::InvalidSignatureEncoding)?;
+
+  if keys.is_empty() {
+    return Err(FalWebhookSignatureError::NoKeys);
+  }
+
+  let message = signed_message(headers, body);
+
+  if keys.iter().any(|key| key.verify(&message, &signature).is_ok()) {
+    Ok(())
+  } else {
+    Err(FalWebhookSignatureError::SignatureMismatch)
+  }
+}
+
+pub(crate) fn signed_message(headers: &FalWebhookHeaders, body: &[u8]) -> Vec<u8> {
+  let body_hash = hex::encode(Sha256::digest(body));
+  format!("{}\n{}\n{}\n{}", headers.request_id, headers.user_id, headers.timestamp, body_hash)
+    .into_bytes()
+}
+
+#[cfg(test)]
+pub(crate) mod test_signing {
+  use ed25519_compact::KeyPair;
+
+  use super::signed_message;
+  use crate::webhook_payload::signature::fal_webhook_headers::FalWebhookHeaders;
+
+  /// Build headers as FAL would, signed with a locally generated key.
+  pub fn sign(key_pair: &KeyPair, request_id: &str, timestamp: i64, body: &[u8]) -> FalWebhookHeaders {
+    let mut headers = FalWebhookHeaders {
+      request_id: request_id.to_string(),
+      user_id: "user_123".to_string(),
+      timestamp: timestamp.to_string(),
+      signature: String::new(),
+    };
+    let signature = key_pair.sk.sign(signed_message(&headers, body), None);
+    headers.signature = hex::encode(signature.as_ref());
+    headers
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::test_signing::sign;
+  use ed25519_compact::KeyPair;
+
+  const NOW: i64 = 1_760_000_000;
+  const BODY: &[u8] = br#"{"request_id":"req_1","gateway_request_id":"req_1","status":"OK","payload":{}}"#;
+
+  fn verify(headers: &FalWebhookHeaders, body: &[u8], keys: &[PublicKey]) -> Result<(), FalWebhookSignatureError> {
+    verify_fal_webhook_signature(headers, body, keys, NOW, DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS)
+  }
+
+  #[test]
+  fn valid_signature() {
+    let key_pair = KeyPair::generate();
+    let headers = sign(&key_pair, "req_1", NOW - 10, BODY);
+    assert!(verify(&headers, BODY, &[key_pair.pk]).is_ok());
+  }
+
+  #[test]
+  fn any_published_key_may_sign() {
+    let old_key = KeyPair::generate();
+    let new_key = KeyPair::generate();
+    let headers = sign(&new_key, "req_1", NOW, BODY);
+    assert!(verify(&headers, BODY, &[old_key.pk, new_key.pk]).is_ok());
+  }
+
+  #[test]
+  fn expired_timestamp() {
+    let key_pair = KeyPair::generate();
+    let headers = sign(&key_pair, "req_1", NOW - 301, BODY);
+    let result = verify(&headers, BODY, &[key_pair.pk]);
+    assert!(matches!(result, Err(FalWebhookSignatureError::TimestampOutOfRange { skew_seconds: 301 })));
+  }
+
+  #[test]
+  fn future_timestamp() {
+    let key_pair = KeyPair::generate();
+    let headers = sign(&key_pair, "req_1", NOW + 600, BODY);
+    let result = verify(&headers, BODY, &[key_pair.pk]);
+    assert!(matches!(result, Err(FalWebhookSignatureError::TimestampOutOfRange { .. })));
+  }
+
+  #[test]
+  fn tampered_body() {
+    let key_pair = KeyPair::generate();
+    let headers = sign(&key_pair, "req_1", NOW, BODY);
+    let tampered = br#"{"request_id":"req_1","gateway_request_id":"req_1","status":"OK","payload":{"x":1}}"#;
+    let result = verify(&headers, tampered, &[key_pair.pk]);
+    assert!(matches!(result, Err(FalWebhookSignatureError::SignatureMismatch)));
+  }
+
+  #[test]
+  fn tampered_request_id() {
+    let key_pair = KeyPair::generate();
+    let mut headers = sign(&key_pair, "req_1", NOW, BODY);
+    headers.request_id = "req_2".to_string();
+    let result = verify(&headers, BODY, &[key_pair.pk]);
+    assert!(matches!(result, Err(FalWebhookSignatureError::SignatureMismatch)));
+  }
+
+  #[test]
+  fn wrong_key() {
+    let signer = KeyPair::generate();
+    let other = KeyPair::generate();
+    let headers = sign(&signer, "req_1", NOW, BODY);
+    let result = verify(&headers, BODY, &[other.pk]);
+    assert!(matches!(result, Err(FalWebhookSignatureError::SignatureMismatch)));
+  }
+
+  #[test]
+  fn malformed_signature() {
+    let key_pair = KeyPair::generate();
+    let mut headers = sign(&key_pair, "req_1", NOW, BODY);
+    headers.signature = "not-hex".to_string();
+    let result = verify(&headers, BODY, &[key_pair.pk]);
+    assert!(matches!(result, Err(FalWebhookSignatureError::InvalidSignatureEncoding)));
+  }
+
+  #[test]
+  fn malformed_timestamp() {
+    let key_pair = KeyPair::generate();
+    let mut headers = sign(&key_pair, "req_1", NOW, BODY);
+    headers.timestamp = "yesterday".to_string();
+    let result = verify(&headers, BODY, &[key_pair.pk]);
+    assert!(matches!(result, Err(FalWebhookSignatureError::InvalidTimestamp(_))));
+  }
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
+
+/// Remembers recently accepted deliveries so a captured request can't be re-sent
+/// while its timestamp is still inside the allowed skew.
+///
+/// Deliveries are keyed by signature rather than request id: FAL retries a failed
+/// delivery with the same request id but a fresh timestamp (and so a fresh signature),
+/// and those retries must still be accepted.
+///
+/// Servers behind a load balancer need a shared store (eg. Redis); the in-memory guard
+/// is for tests and local development.
+#[async_trait]
+pub trait WebhookReplayGuard: Send + Sync {
+  /// Record a delivery. Returns false if the same delivery was already recorded.
+  async fn check_and_record(
+    &self,
+    signature: &str,
+    timestamp: i64,
+    now_unix_seconds: i64,
+  ) -> Result<bool, FalWebhookSignatureError>;
+}
+
+/// Signatures are hex, so compare them case-insensitively.
+pub fn replay_key(signature: &str) -> String {
+  signature.trim().to_ascii_lowercase()
+}
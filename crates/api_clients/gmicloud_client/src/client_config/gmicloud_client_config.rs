@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use provider_http::base_url::BaseUrl;
+use provider_http::process_default::{shared_reqwest_client, ProcessDefault};
+
+static DEFAULT_CONFIG: ProcessDefault<GmiCloudClientConfig> = ProcessDefault::new(GmiCloudClientConfig::production);
+
+/// Where GmiCloud request-queue calls go, and the (pooled) HTTP client that sends them.
+///
+/// Calls use the process default unless `RequestContext::maybe_config` overrides it.
+#[derive(Clone, Debug)]
+pub struct GmiCloudClientConfig {
+  /// The request queue API root. `/requests` and `/requests/{id}` hang off of it.
+  pub base_url: BaseUrl,
+
+  pub http_client: reqwest::Client,
+}
+
+impl GmiCloudClientConfig {
+  pub fn production() -> Self {
+    Self::with_base_url(BaseUrl::from_static("https://console.gmicloud.ai/api/v1/ie/requestqueue/apikey"))
+  }
+
+  pub fn with_base_url(base_url: BaseUrl) -> Self {
+    Self {
+      base_url,
+      http_client: shared_reqwest_client(),
+    }
+  }
+
+  /// The process-wide default.
+  pub fn current() -> Arc<Self> {
+    DEFAULT_CONFIG.get()
+  }
+
+  /// Replace the process-wide default.
+  pub fn install_default(config: Self) {
+    DEFAULT_CONFIG.set(config);
+  }
+}
@@ -0,0 +1 @@
+pub mod gmicloud_client_config;
@@ -1,3 +1,4 @@
+pub mod client_config;
 pub mod creds;
 pub mod error;
 pub mod requests;
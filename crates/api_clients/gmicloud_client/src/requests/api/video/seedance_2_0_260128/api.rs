@@ -119,6 +119,7 @@ impl Seedance20Request {
     let context = RequestContext {
       api_key,
       maybe_timeout: None,
+      maybe_config: None,
     };
     self.send_request_with_context(&context).await
   }
@@ -117,6 +117,7 @@ impl Seedance20FastRequest {
     let context = RequestContext {
       api_key,
       maybe_timeout: None,
+      maybe_config: None,
     };
     self.send_request_with_context(&context).await
   }
@@ -0,0 +1,137 @@
+use log::{info, warn};
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::client_config::gmicloud_client_config::GmiCloudClientConfig;
+use crate::creds::gmicloud_api_key::GmiCloudApiKey;
+use crate::error::gmicloud_error::GmiCloudError;
+use crate::error::gmicloud_generic_api_error::GmiCloudGenericApiError;
+use crate::error::gmicloud_specific_api_error::GmiCloudSpecificApiError;
+
+/// What GmiCloud did with a cancellation request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GmiCloudCancelOutcome {
+  /// The request was removed from the queue and will never run.
+  Cancelled,
+
+  /// GmiCloud accepted the cancellation, but the request hasn't stopped yet.
+  /// Poll the request to learn how it ends.
+  CancellationRequested,
+
+  /// The request finished (succeeded or failed) before we asked.
+  AlreadyFinished,
+
+  /// The request is already processing and can't be stopped.
+  NotCancellable,
+
+  /// GmiCloud doesn't know the request.
+  NotFound,
+}
+
+/// The response from `POST /requests/{request_id}/cancel`.
+#[derive(Debug, Deserialize)]
+struct GmiCloudCancelResponse {
+  status: Option<String>,
+}
+
+/// Cancel a GmiCloud request that hasn't started processing.
+pub async fn cancel_gmicloud_request(
+  api_key: &GmiCloudApiKey,
+  request_id: &str,
+) -> Result<GmiCloudCancelOutcome, GmiCloudError> {
+  let config = GmiCloudClientConfig::current();
+  let url = config.base_url.join(&format!("requests/{}/cancel", request_id));
+
+  info!("Cancelling GmiCloud request: {}", request_id);
+
+  let response = config.http_client
+    .post(&url)
+    .header("Authorization", format!("Bearer {}", api_key.as_str()))
+    .send()
+    .await
+    .map_err(GmiCloudGenericApiError::from)?;
+
+  let status = response.status();
+  let body_text = response.text().await
+    .map_err(GmiCloudGenericApiError::from)?;
+
+  classify_cancel_response(status, body_text)
+}
+
+fn classify_cancel_response(status: StatusCode, body: String) -> Result<GmiCloudCancelOutcome, GmiCloudError> {
+  match status {
+    StatusCode::UNAUTHORIZED => return Err(GmiCloudSpecificApiError::Unauthorized.into()),
+    StatusCode::NOT_FOUND => return Ok(GmiCloudCancelOutcome::NotFound),
+    // The request is past the point where it can be cancelled.
+    StatusCode::CONFLICT => return Ok(GmiCloudCancelOutcome::NotCancellable),
+    status if !status.is_success() => {
+      warn!("GmiCloud cancel error: status={}, body={}", status, body);
+      return Err(GmiCloudGenericApiError::UncategorizedBadResponseWithStatusAndBody {
+        status_code: status.as_u16(),
+        body,
+      }.into());
+    }
+    _ => {}
+  }
+
+  let parsed: GmiCloudCancelResponse = serde_json::from_str(&body)
+    .map_err(|err| GmiCloudGenericApiError::SerdeResponseParseErrorWithBody(err, body))?;
+
+  // Same status strings as `GET /requests/{request_id}`.
+  Ok(match parsed.status.as_deref() {
+    Some("cancelled") => GmiCloudCancelOutcome::Cancelled,
+    Some("success") | Some("failed") => GmiCloudCancelOutcome::AlreadyFinished,
+    Some("processing") => GmiCloudCancelOutcome::NotCancellable,
+    _ => GmiCloudCancelOutcome::CancellationRequested,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn classify(status: StatusCode, body: &str) -> Result<GmiCloudCancelOutcome, GmiCloudError> {
+    classify_cancel_response(status, body.to_string())
+  }
+
+  #[test]
+  fn queued_request_is_cancelled() {
+    let outcome = classify(StatusCode::OK, r#"{"request_id":"abc","status":"cancelled"}"#).unwrap();
+    assert_eq!(outcome, GmiCloudCancelOutcome::Cancelled);
+  }
+
+  #[test]
+  fn unconfirmed_cancellation_is_pending() {
+    let outcome = classify(StatusCode::OK, r#"{"request_id":"abc","status":"dispatched"}"#).unwrap();
+    assert_eq!(outcome, GmiCloudCancelOutcome::CancellationRequested);
+
+    let outcome = classify(StatusCode::ACCEPTED, r#"{}"#).unwrap();
+    assert_eq!(outcome, GmiCloudCancelOutcome::CancellationRequested);
+  }
+
+  #[test]
+  fn finished_and_running_requests() {
+    let outcome = classify(StatusCode::OK, r#"{"status":"success"}"#).unwrap();
+    assert_eq!(outcome, GmiCloudCancelOutcome::AlreadyFinished);
+
+    let outcome = classify(StatusCode::OK, r#"{"status":"processing"}"#).unwrap();
+    assert_eq!(outcome, GmiCloudCancelOutcome::NotCancellable);
+
+    let outcome = classify(StatusCode::CONFLICT, r#"{"error":"request is processing"}"#).unwrap();
+    assert_eq!(outcome, GmiCloudCancelOutcome::NotCancellable);
+  }
+
+  #[test]
+  fn unknown_request() {
+    let outcome = classify(StatusCode::NOT_FOUND, "").unwrap();
+    assert_eq!(outcome, GmiCloudCancelOutcome::NotFound);
+  }
+
+  #[test]
+  fn errors() {
+    assert!(matches!(classify(StatusCode::UNAUTHORIZED, ""),
+      Err(GmiCloudError::ApiSpecific(GmiCloudSpecificApiError::Unauthorized))));
+    assert!(matches!(classify(StatusCode::INTERNAL_SERVER_ERROR, "oops"),
+      Err(GmiCloudError::ApiGeneric(GmiCloudGenericApiError::UncategorizedBadResponseWithStatusAndBody { status_code: 500, .. }))));
+  }
+}
@@ -3,14 +3,13 @@ use std::time::Duration;
 use log::warn;
 use serde::{Deserialize, Serialize};
 
+use crate::client_config::gmicloud_client_config::GmiCloudClientConfig;
 use crate::creds::gmicloud_api_key::GmiCloudApiKey;
 use crate::error::gmicloud_error::GmiCloudError;
 use crate::error::gmicloud_generic_api_error::GmiCloudGenericApiError;
 use crate::error::gmicloud_specific_api_error::GmiCloudSpecificApiError;
 use crate::requests::context::request_context::RequestContext;
 
-const BASE_URL: &str = "https://console.gmicloud.ai/api/v1/ie/requestqueue/apikey";
-
 /// Default request timeout. Video requests return quickly (async queue),
 /// but image requests block until completion and can take 60+ seconds.
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(180);
@@ -38,6 +37,7 @@ pub async fn create_gmicloud_request<P: Serialize>(
   let context = RequestContext {
     api_key,
     maybe_timeout: None,
+    maybe_config: None,
   };
   create_gmicloud_request_with_context(&context, body).await
 }
@@ -47,16 +47,21 @@ pub async fn create_gmicloud_request_with_context<P: Serialize>(
   context: &RequestContext<'_>,
   body: &GmiCloudCreateRequest<P>,
 ) -> Result<GmiCloudCreateResponse, GmiCloudError> {
-  let url = format!("{}/requests", BASE_URL);
-  let timeout = context.maybe_timeout.unwrap_or(DEFAULT_TIMEOUT);
+  let default_config;
+  let config = match context.maybe_config {
+    Some(config) => config,
+    None => {
+      default_config = GmiCloudClientConfig::current();
+      default_config.as_ref()
+    }
+  };
 
-  let client = reqwest::Client::builder()
-    .timeout(timeout)
-    .build()
-    .map_err(GmiCloudGenericApiError::from)?;
+  let url = config.base_url.join("requests");
+  let timeout = context.maybe_timeout.unwrap_or(DEFAULT_TIMEOUT);
 
-  let response = client
+  let response = config.http_client
     .post(&url)
+    .timeout(timeout)
     .header("Authorization", format!("Bearer {}", context.api_key.as_str()))
     .json(body)
     .send()
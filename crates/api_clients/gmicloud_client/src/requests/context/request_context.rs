@@ -1,8 +1,12 @@
 use std::time::Duration;
 
+use crate::client_config::gmicloud_client_config::GmiCloudClientConfig;
 use crate::creds::gmicloud_api_key::GmiCloudApiKey;
 
 pub struct RequestContext<'a> {
   pub api_key: &'a GmiCloudApiKey,
   pub maybe_timeout: Option<Duration>,
+
+  /// Send to this host / through this client instead of the process default.
+  pub maybe_config: Option<&'a GmiCloudClientConfig>,
 }
@@ -1,4 +1,5 @@
 pub mod api;
+pub mod cancel_request;
 pub mod common;
 pub mod context;
 pub mod poll_request_queue;
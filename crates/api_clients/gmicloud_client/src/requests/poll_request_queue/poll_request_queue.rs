@@ -1,13 +1,12 @@
 use log::warn;
 use serde::Deserialize;
 
+use crate::client_config::gmicloud_client_config::GmiCloudClientConfig;
 use crate::creds::gmicloud_api_key::GmiCloudApiKey;
 use crate::error::gmicloud_error::GmiCloudError;
 use crate::error::gmicloud_generic_api_error::GmiCloudGenericApiError;
 use crate::error::gmicloud_specific_api_error::GmiCloudSpecificApiError;
 
-const BASE_URL: &str = "https://console.gmicloud.ai/api/v1/ie/requestqueue/apikey";
-
 /// The response from `GET /requests/{request_id}`.
 #[derive(Debug, Deserialize)]
 pub struct GmiCloudPollResponse {
@@ -42,11 +41,21 @@ impl GmiCloudPollResponse {
     self.status == "dispatched" || self.status == "processing"
   }
 
+  /// Whether the request is still waiting in the queue (dispatched, not yet processing).
+  pub fn is_queued(&self) -> bool {
+    self.status == "dispatched"
+  }
+
   /// Whether the request has failed.
   pub fn is_failed(&self) -> bool {
     self.status == "failed"
   }
 
+  /// Whether the request was cancelled before it finished.
+  pub fn is_cancelled(&self) -> bool {
+    self.status == "cancelled"
+  }
+
   /// Get the video URL if the request completed successfully.
   pub fn video_url(&self) -> Option<&str> {
     self.outcome.as_ref()?.video_url.as_deref()
@@ -63,10 +72,10 @@ pub async fn poll_gmicloud_request(
   api_key: &GmiCloudApiKey,
   request_id: &str,
 ) -> Result<GmiCloudPollResponse, GmiCloudError> {
-  let url = format!("{}/requests/{}", BASE_URL, request_id);
+  let config = GmiCloudClientConfig::current();
+  let url = config.base_url.join(&format!("requests/{}", request_id));
 
-  let client = reqwest::Client::new();
-  let response = client
+  let response = config.http_client
     .get(&url)
     .header("Authorization", format!("Bearer {}", api_key.as_str()))
     .send()
@@ -159,6 +168,7 @@ mod tests {
       let response: GmiCloudPollResponse = serde_json::from_str(json).unwrap();
       assert!(!response.is_success());
       assert!(response.is_in_progress());
+      assert!(!response.is_queued());
       assert!(!response.is_failed());
       assert_eq!(response.video_url(), None);
       assert_eq!(response.outcome, None);
@@ -175,6 +185,7 @@ mod tests {
 
       let response: GmiCloudPollResponse = serde_json::from_str(json).unwrap();
       assert!(response.is_in_progress());
+      assert!(response.is_queued());
       assert!(!response.is_success());
     }
 
@@ -192,6 +203,21 @@ mod tests {
       assert!(!response.is_success());
       assert!(!response.is_in_progress());
     }
+
+    #[test]
+    fn deserialize_cancelled_response() {
+      let json = r#"{
+        "request_id": "abc",
+        "model": "seedance-2-0-260128",
+        "status": "cancelled",
+        "outcome": null
+      }"#;
+
+      let response: GmiCloudPollResponse = serde_json::from_str(json).unwrap();
+      assert!(response.is_cancelled());
+      assert!(!response.is_failed());
+      assert!(!response.is_in_progress());
+    }
   }
 
   mod live_api_tests {
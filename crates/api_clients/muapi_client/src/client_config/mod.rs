@@ -0,0 +1 @@
+pub mod muapi_client_config;
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use log::warn;
+use provider_http::base_url::BaseUrl;
+use provider_http::http_client_config::HttpClientConfig;
+use provider_http::process_default::ProcessDefault;
+use wreq::Client;
+
+static DEFAULT_CONFIG: ProcessDefault<MuapiClientConfig> = ProcessDefault::new(MuapiClientConfig::production);
+
+/// Where Muapi requests go, and the (pooled) HTTP client that sends them.
+///
+/// Install a different default to point the client at a local stand-in server.
+#[derive(Clone)]
+pub struct MuapiClientConfig {
+  /// The API root, `https://api.muapi.ai/api/v1` in production.
+  pub base_url: BaseUrl,
+
+  pub http_client: Client,
+}
+
+impl MuapiClientConfig {
+  pub fn production() -> Self {
+    Self::with_base_url(BaseUrl::from_static("https://api.muapi.ai/api/v1"))
+  }
+
+  /// Uses a client built from the `PROVIDER_HTTP_*` environment settings.
+  pub fn with_base_url(base_url: BaseUrl) -> Self {
+    Self {
+      base_url,
+      http_client: build_default_client(),
+    }
+  }
+
+  /// The process-wide default.
+  pub fn current() -> Arc<Self> {
+    DEFAULT_CONFIG.get()
+  }
+
+  /// Replace the process-wide default.
+  pub fn install_default(config: Self) {
+    DEFAULT_CONFIG.set(config);
+  }
+}
+
+fn build_default_client() -> Client {
+  HttpClientConfig::from_env()
+    .apply_to_wreq_builder(Client::builder())
+    .ok()
+    .and_then(|builder| builder.build().ok())
+    .unwrap_or_else(|| {
+      warn!("Ignoring bad provider HTTP settings for the Muapi client.");
+      Client::new()
+    })
+}
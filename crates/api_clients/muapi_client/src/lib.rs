@@ -2,6 +2,7 @@
 pub (crate) mod test_utils;
 
 pub mod api_types;
+pub mod client_config;
 pub mod creds;
 pub mod error;
 pub mod requests;
@@ -1,13 +1,10 @@
 use crate::api_types::request_id::RequestId;
+use crate::client_config::muapi_client_config::MuapiClientConfig;
 use crate::creds::muapi_session::MuapiSession;
-use crate::error::muapi_client_error::MuapiClientError;
 use crate::error::muapi_error::MuapiError;
 use crate::error::muapi_generic_api_error::MuapiGenericApiError;
 use crate::requests::poll_prediction_result::request_types::*;
 use log::info;
-use wreq::Client;
-
-const PREDICTIONS_BASE_URL: &str = "https://api.muapi.ai/api/v1/predictions";
 
 // --- Public types ---
 
@@ -67,17 +64,14 @@ pub struct PollPredictionResultApiResponse {
 pub async fn poll_prediction_result(
   args: PollPredictionResultArgs<'_>,
 ) -> Result<PollPredictionResultApiResponse, MuapiError> {
-  let url = format!("{}/{}/result", PREDICTIONS_BASE_URL, args.request_id.as_str());
+  let config = MuapiClientConfig::current();
+  let url = config.base_url.join(&format!("predictions/{}/result", args.request_id.as_str()));
 
   info!("Polling Muapi prediction result: {}", url);
 
   let api_key = args.session.api_key.as_str();
 
-  let client = Client::builder()
-    .build()
-    .map_err(|err| MuapiClientError::WreqClientError(err))?;
-
-  let response = client.get(&url)
+  let response = config.http_client.get(&url)
     .header("Content-Type", "application/json")
     .header("x-api-key", api_key)
     .send()
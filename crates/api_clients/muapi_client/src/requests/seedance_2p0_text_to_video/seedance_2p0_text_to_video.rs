@@ -1,13 +1,12 @@
 use crate::api_types::request_id::RequestId;
+use crate::client_config::muapi_client_config::MuapiClientConfig;
 use crate::creds::muapi_session::MuapiSession;
-use crate::error::muapi_client_error::MuapiClientError;
 use crate::error::muapi_error::MuapiError;
 use crate::error::muapi_generic_api_error::MuapiGenericApiError;
 use crate::requests::seedance_2p0_text_to_video::request_types::*;
 use log::info;
-use wreq::Client;
 
-const SEEDANCE_2P0_T2V_URL: &str = "https://api.muapi.ai/api/v1/seedance-v2.0-t2v";
+const SEEDANCE_2P0_T2V_PATH: &str = "seedance-v2.0-t2v";
 
 // --- Public enums ---
 
@@ -161,11 +160,9 @@ pub async fn seedance_2p0_text_to_video(
 
   let api_key = args.session.api_key.as_str();
 
-  let client = Client::builder()
-    .build()
-    .map_err(|err| MuapiClientError::WreqClientError(err))?;
+  let config = MuapiClientConfig::current();
 
-  let response = client.post(SEEDANCE_2P0_T2V_URL)
+  let response = config.http_client.post(config.base_url.join(SEEDANCE_2P0_T2V_PATH))
     .header("Content-Type", "application/json")
     .header("x-api-key", api_key)
     .json(&request_body)
@@ -0,0 +1 @@
+pub mod replicate_client_config;
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use provider_http::base_url::BaseUrl;
+use provider_http::process_default::{shared_reqwest_client, ProcessDefault};
+
+static DEFAULT_CONFIG: ProcessDefault<ReplicateClientConfig> = ProcessDefault::new(ReplicateClientConfig::production);
+
+/// Where Replicate requests go, and the (pooled) HTTP client that sends them.
+///
+/// Requests use the process default unless the `RequestContext` carries its own.
+#[derive(Clone, Debug)]
+pub struct ReplicateClientConfig {
+  /// The API root, `https://api.replicate.com/v1` in production.
+  pub base_url: BaseUrl,
+
+  pub http_client: reqwest::Client,
+}
+
+impl ReplicateClientConfig {
+  pub fn production() -> Self {
+    Self::with_base_url(BaseUrl::from_static("https://api.replicate.com/v1"))
+  }
+
+  pub fn with_base_url(base_url: BaseUrl) -> Self {
+    Self {
+      base_url,
+      http_client: shared_reqwest_client(),
+    }
+  }
+
+  /// The process-wide default.
+  pub fn current() -> Arc<Self> {
+    DEFAULT_CONFIG.get()
+  }
+
+  /// Replace the process-wide default.
+  pub fn install_default(config: Self) {
+    DEFAULT_CONFIG.set(config);
+  }
+}
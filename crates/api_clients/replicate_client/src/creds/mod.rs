@@ -0,0 +1 @@
+pub mod replicate_api_key;
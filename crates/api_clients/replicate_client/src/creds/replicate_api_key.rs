@@ -0,0 +1,18 @@
+/// A Replicate API token (`r8_...`) used for Bearer token authentication.
+#[derive(Clone)]
+pub struct ReplicateApiKey(pub String);
+
+impl ReplicateApiKey {
+  pub fn new(api_key: String) -> Self {
+    Self(api_key.trim().to_string())
+  }
+
+  #[allow(clippy::should_implement_trait)]
+  pub fn from_str(api_key: &str) -> Self {
+    Self(api_key.trim().to_string())
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
@@ -0,0 +1,4 @@
+pub mod replicate_client_error;
+pub mod replicate_error;
+pub mod replicate_generic_api_error;
+pub mod replicate_specific_api_error;
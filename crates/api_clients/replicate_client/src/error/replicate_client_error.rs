@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// Errors that occur client-side before/during request construction.
+#[derive(Debug)]
+pub enum ReplicateClientError {
+  /// The API key was not provided or is empty.
+  NoApiKeyPresent,
+
+  /// The request couldn't be converted to the model's input shape.
+  InvalidInput(String),
+
+  /// A local file couldn't be read for upload.
+  FileReadError(std::io::Error),
+
+  /// A reqwest client-level error.
+  ReqwestError(reqwest::Error),
+}
+
+impl fmt::Display for ReplicateClientError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+
+impl From<reqwest::Error> for ReplicateClientError {
+  fn from(err: reqwest::Error) -> Self {
+    Self::ReqwestError(err)
+  }
+}
@@ -0,0 +1,40 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::error::replicate_client_error::ReplicateClientError;
+use crate::error::replicate_generic_api_error::ReplicateGenericApiError;
+use crate::error::replicate_specific_api_error::ReplicateSpecificApiError;
+
+/// Top-level error type for the Replicate client.
+#[derive(Debug)]
+pub enum ReplicateError {
+  Client(ReplicateClientError),
+  ApiSpecific(ReplicateSpecificApiError),
+  ApiGeneric(ReplicateGenericApiError),
+}
+
+impl Error for ReplicateError {}
+
+impl fmt::Display for ReplicateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+
+impl From<ReplicateClientError> for ReplicateError {
+  fn from(err: ReplicateClientError) -> Self {
+    Self::Client(err)
+  }
+}
+
+impl From<ReplicateSpecificApiError> for ReplicateError {
+  fn from(err: ReplicateSpecificApiError) -> Self {
+    Self::ApiSpecific(err)
+  }
+}
+
+impl From<ReplicateGenericApiError> for ReplicateError {
+  fn from(err: ReplicateGenericApiError) -> Self {
+    Self::ApiGeneric(err)
+  }
+}
@@ -0,0 +1,26 @@
+use std::fmt;
+
+/// Well-known server-side errors with specific causes.
+#[derive(Debug)]
+pub enum ReplicateSpecificApiError {
+  /// The API token is invalid or expired.
+  Unauthorized,
+
+  /// The account is out of credit or has a billing problem (HTTP 402).
+  BillingError { status_code: u16, body: String },
+
+  /// Too many requests (HTTP 429). Replicate sends `retry_after` in the body.
+  RateLimited { maybe_retry_after_seconds: Option<u64> },
+
+  /// The model rejected the input (HTTP 422), eg. an out-of-range parameter.
+  InvalidInput(String),
+
+  /// The model or prediction doesn't exist (HTTP 404).
+  NotFound(String),
+}
+
+impl fmt::Display for ReplicateSpecificApiError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
@@ -0,0 +1,11 @@
+//! A Replicate wrapper
+//! See https://replicate.com/docs/reference/http
+
+pub mod client_config;
+pub mod creds;
+pub mod error;
+pub mod requests;
+pub mod webhook_payload;
+
+#[cfg(test)]
+mod test_utils;
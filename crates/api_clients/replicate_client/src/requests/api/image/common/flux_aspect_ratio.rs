@@ -0,0 +1,33 @@
+/// Aspect ratios accepted by Replicate's official Flux models.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FluxAspectRatio {
+  Square,
+  WideSixteenNine,
+  WideTwentyOneNine,
+  WideThreeTwo,
+  TallTwoThree,
+  TallFourFive,
+  WideFiveFour,
+  TallThreeFour,
+  WideFourThree,
+  TallNineSixteen,
+  TallNineTwentyOne,
+}
+
+impl FluxAspectRatio {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Square => "1:1",
+      Self::WideSixteenNine => "16:9",
+      Self::WideTwentyOneNine => "21:9",
+      Self::WideThreeTwo => "3:2",
+      Self::TallTwoThree => "2:3",
+      Self::TallFourFive => "4:5",
+      Self::WideFiveFour => "5:4",
+      Self::TallThreeFour => "3:4",
+      Self::WideFourThree => "4:3",
+      Self::TallNineSixteen => "9:16",
+      Self::TallNineTwentyOne => "9:21",
+    }
+  }
+}
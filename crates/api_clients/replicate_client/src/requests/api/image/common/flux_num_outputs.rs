@@ -0,0 +1,19 @@
+/// Replicate's Flux models return between one and four images.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FluxNumOutputs {
+  One,
+  Two,
+  Three,
+  Four,
+}
+
+impl FluxNumOutputs {
+  pub fn as_count(&self) -> u32 {
+    match self {
+      Self::One => 1,
+      Self::Two => 2,
+      Self::Three => 3,
+      Self::Four => 4,
+    }
+  }
+}
@@ -0,0 +1,2 @@
+pub mod flux_aspect_ratio;
+pub mod flux_num_outputs;
@@ -0,0 +1,88 @@
+use crate::error::replicate_error::ReplicateError;
+use crate::requests::api::image::common::flux_aspect_ratio::FluxAspectRatio;
+use crate::requests::api::image::common::flux_num_outputs::FluxNumOutputs;
+use crate::requests::api::image::flux_1_dev::raw_request::{Flux1DevInput, Flux1DevOutput};
+use crate::requests::traits::replicate_model_endpoint_trait::ReplicateModelEndpoint;
+
+#[derive(Clone, Debug)]
+pub struct Flux1DevRequest {
+  pub prompt: String,
+  pub num_outputs: FluxNumOutputs,
+
+  // Optional args
+  pub aspect_ratio: Option<FluxAspectRatio>,
+
+  /// Image-to-image source. Takes precedence over `aspect_ratio`.
+  pub image_url: Option<String>,
+  pub prompt_strength: Option<f64>,
+  pub seed: Option<i64>,
+}
+
+impl ReplicateModelEndpoint for Flux1DevRequest {
+  const MODEL : &'static str = "black-forest-labs/flux-dev";
+
+  type RawInput = Flux1DevInput;
+  type RawOutput = Flux1DevOutput;
+
+  fn to_raw_input(&self) -> Result<Self::RawInput, ReplicateError> {
+    Ok(Self::RawInput {
+      prompt: self.prompt.clone(),
+      aspect_ratio: self.aspect_ratio.map(|a| a.as_str().to_string()),
+      image: self.image_url.clone(),
+      prompt_strength: self.prompt_strength,
+      num_outputs: Some(self.num_outputs.as_count()),
+      seed: self.seed,
+      // Constants
+      output_format: Some("png".to_string()),
+      disable_safety_checker: Some(true),
+      ..Default::default()
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::requests::context::request_context::RequestContext;
+  use crate::test_utils::load_api_key;
+
+  #[test]
+  fn raw_input() {
+    let request = Flux1DevRequest {
+      prompt: "a corgi astronaut".to_string(),
+      num_outputs: FluxNumOutputs::Two,
+      aspect_ratio: Some(FluxAspectRatio::WideSixteenNine),
+      image_url: None,
+      prompt_strength: None,
+      seed: None,
+    };
+
+    let json = serde_json::to_value(request.to_raw_input().unwrap()).unwrap();
+    assert_eq!(json, serde_json::json!({
+      "prompt": "a corgi astronaut",
+      "aspect_ratio": "16:9",
+      "num_outputs": 2,
+      "output_format": "png",
+      "disable_safety_checker": true,
+    }));
+  }
+
+  #[tokio::test]
+  #[ignore] // manually test — requires real API key, incurs costs
+  async fn test_send_request() {
+    let api_key = load_api_key();
+    let request = Flux1DevRequest {
+      prompt: "a corgi astronaut on the moon".to_string(),
+      num_outputs: FluxNumOutputs::One,
+      aspect_ratio: Some(FluxAspectRatio::Square),
+      image_url: None,
+      prompt_strength: None,
+      seed: None,
+    };
+    let prediction = request.send_request(&RequestContext::new(&api_key)).await.unwrap();
+    println!("Prediction: {:?}", prediction.id);
+    assert!(!prediction.id.is_empty());
+  }
+
+  // NB: Pricing tests are in cost.rs
+}
@@ -0,0 +1,36 @@
+use crate::requests::api::image::flux_1_dev::api::Flux1DevRequest;
+use crate::requests::traits::replicate_request_cost_calculator_trait::{ReplicateRequestCostCalculator, UsdCents};
+
+/// $0.025 per output image, in tenths of a cent.
+const MILLS_PER_IMAGE: u64 = 25;
+
+impl ReplicateRequestCostCalculator for Flux1DevRequest {
+  fn calculate_cost_in_cents(&self) -> UsdCents {
+    (MILLS_PER_IMAGE * self.num_outputs.as_count() as u64).div_ceil(10)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::requests::api::image::common::flux_num_outputs::FluxNumOutputs;
+
+  fn make_request(num_outputs: FluxNumOutputs) -> Flux1DevRequest {
+    Flux1DevRequest {
+      prompt: "test".to_string(),
+      num_outputs,
+      aspect_ratio: None,
+      image_url: None,
+      prompt_strength: None,
+      seed: None,
+    }
+  }
+
+  #[test]
+  fn cost_by_batch_size() {
+    assert_eq!(make_request(FluxNumOutputs::One).calculate_cost_in_cents(), 3);
+    assert_eq!(make_request(FluxNumOutputs::Two).calculate_cost_in_cents(), 5);
+    assert_eq!(make_request(FluxNumOutputs::Three).calculate_cost_in_cents(), 8);
+    assert_eq!(make_request(FluxNumOutputs::Four).calculate_cost_in_cents(), 10);
+  }
+}
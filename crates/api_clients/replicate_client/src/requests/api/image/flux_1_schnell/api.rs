@@ -0,0 +1,79 @@
+use crate::error::replicate_error::ReplicateError;
+use crate::requests::api::image::common::flux_aspect_ratio::FluxAspectRatio;
+use crate::requests::api::image::common::flux_num_outputs::FluxNumOutputs;
+use crate::requests::api::image::flux_1_schnell::raw_request::{Flux1SchnellInput, Flux1SchnellOutput};
+use crate::requests::traits::replicate_model_endpoint_trait::ReplicateModelEndpoint;
+
+/// Text-to-image only; Schnell has no image-to-image mode on Replicate.
+#[derive(Clone, Debug)]
+pub struct Flux1SchnellRequest {
+  pub prompt: String,
+  pub num_outputs: FluxNumOutputs,
+
+  // Optional args
+  pub aspect_ratio: Option<FluxAspectRatio>,
+  pub seed: Option<i64>,
+}
+
+impl ReplicateModelEndpoint for Flux1SchnellRequest {
+  const MODEL : &'static str = "black-forest-labs/flux-schnell";
+
+  type RawInput = Flux1SchnellInput;
+  type RawOutput = Flux1SchnellOutput;
+
+  fn to_raw_input(&self) -> Result<Self::RawInput, ReplicateError> {
+    Ok(Self::RawInput {
+      prompt: self.prompt.clone(),
+      aspect_ratio: self.aspect_ratio.map(|a| a.as_str().to_string()),
+      num_outputs: Some(self.num_outputs.as_count()),
+      seed: self.seed,
+      // Constants
+      output_format: Some("png".to_string()),
+      disable_safety_checker: Some(true),
+      ..Default::default()
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::requests::context::request_context::RequestContext;
+  use crate::test_utils::load_api_key;
+
+  #[test]
+  fn raw_input() {
+    let request = Flux1SchnellRequest {
+      prompt: "a corgi astronaut".to_string(),
+      num_outputs: FluxNumOutputs::Four,
+      aspect_ratio: None,
+      seed: Some(42),
+    };
+
+    let json = serde_json::to_value(request.to_raw_input().unwrap()).unwrap();
+    assert_eq!(json, serde_json::json!({
+      "prompt": "a corgi astronaut",
+      "num_outputs": 4,
+      "seed": 42,
+      "output_format": "png",
+      "disable_safety_checker": true,
+    }));
+  }
+
+  #[tokio::test]
+  #[ignore] // manually test — requires real API key, incurs costs
+  async fn test_send_request() {
+    let api_key = load_api_key();
+    let request = Flux1SchnellRequest {
+      prompt: "a corgi astronaut on the moon".to_string(),
+      num_outputs: FluxNumOutputs::One,
+      aspect_ratio: Some(FluxAspectRatio::Square),
+      seed: None,
+    };
+    let prediction = request.send_request(&RequestContext::new(&api_key)).await.unwrap();
+    println!("Prediction: {:?}", prediction.id);
+    assert!(!prediction.id.is_empty());
+  }
+
+  // NB: Pricing tests are in cost.rs
+}
@@ -0,0 +1,32 @@
+use crate::requests::api::image::flux_1_schnell::api::Flux1SchnellRequest;
+use crate::requests::traits::replicate_request_cost_calculator_trait::{ReplicateRequestCostCalculator, UsdCents};
+
+/// $0.003 per output image, in tenths of a cent.
+const MILLS_PER_IMAGE: u64 = 3;
+
+impl ReplicateRequestCostCalculator for Flux1SchnellRequest {
+  fn calculate_cost_in_cents(&self) -> UsdCents {
+    (MILLS_PER_IMAGE * self.num_outputs.as_count() as u64).div_ceil(10)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::requests::api::image::common::flux_num_outputs::FluxNumOutputs;
+
+  fn make_request(num_outputs: FluxNumOutputs) -> Flux1SchnellRequest {
+    Flux1SchnellRequest {
+      prompt: "test".to_string(),
+      num_outputs,
+      aspect_ratio: None,
+      seed: None,
+    }
+  }
+
+  #[test]
+  fn rounds_up_to_a_cent() {
+    assert_eq!(make_request(FluxNumOutputs::One).calculate_cost_in_cents(), 1);
+    assert_eq!(make_request(FluxNumOutputs::Four).calculate_cost_in_cents(), 2);
+  }
+}
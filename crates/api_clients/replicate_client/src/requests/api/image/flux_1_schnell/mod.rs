@@ -0,0 +1,3 @@
+mod raw_request;
+pub mod api;
+pub mod cost;
@@ -0,0 +1,42 @@
+use serde_derive::Serialize;
+
+/// https://replicate.com/black-forest-labs/flux-schnell/api/schema
+#[derive(Debug, Serialize, Default)]
+pub struct Flux1SchnellInput {
+  pub prompt: String,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aspect_ratio: Option<String>,
+
+  /// 1 - 4
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub num_outputs: Option<u32>,
+
+  /// 1 - 4, default 4
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub num_inference_steps: Option<u32>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub seed: Option<i64>,
+
+  /// Options: webp, jpg, png
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub output_format: Option<String>,
+
+  /// 0 - 100. Ignored for png.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub output_quality: Option<u32>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub disable_safety_checker: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub go_fast: Option<bool>,
+
+  /// Options: "1", "0.25"
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub megapixels: Option<String>,
+}
+
+/// A list of image URLs.
+pub type Flux1SchnellOutput = Vec<String>;
@@ -0,0 +1,3 @@
+pub mod common;
+pub mod flux_1_dev;
+pub mod flux_1_schnell;
@@ -0,0 +1 @@
+pub mod seedance_1_lite;
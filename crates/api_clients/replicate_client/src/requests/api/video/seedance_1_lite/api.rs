@@ -0,0 +1,179 @@
+use crate::error::replicate_client_error::ReplicateClientError;
+use crate::error::replicate_error::ReplicateError;
+use crate::requests::api::video::seedance_1_lite::raw_request::{Seedance1LiteInput, Seedance1LiteOutput};
+use crate::requests::traits::replicate_model_endpoint_trait::ReplicateModelEndpoint;
+
+pub const SEEDANCE_1_LITE_MIN_DURATION_SECONDS: u32 = 2;
+pub const SEEDANCE_1_LITE_MAX_DURATION_SECONDS: u32 = 12;
+pub const SEEDANCE_1_LITE_MAX_REFERENCE_IMAGES: usize = 4;
+
+#[derive(Clone, Debug)]
+pub struct Seedance1LiteRequest {
+  pub prompt: String,
+
+  /// Seconds, 2 - 12
+  pub duration_seconds: u32,
+  pub resolution: Seedance1LiteResolution,
+
+  // Optional args
+  pub aspect_ratio: Option<Seedance1LiteAspectRatio>,
+  pub start_frame_url: Option<String>,
+  pub end_frame_url: Option<String>,
+  pub reference_image_urls: Vec<String>,
+  pub camera_fixed: Option<bool>,
+  pub seed: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Seedance1LiteResolution {
+  FourEightyP,
+  SevenTwentyP,
+  TenEightyP,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Seedance1LiteAspectRatio {
+  SixteenByNine,
+  FourByThree,
+  Square,
+  ThreeByFour,
+  NineBySixteen,
+  TwentyOneByNine,
+  NineByTwentyOne,
+}
+
+impl ReplicateModelEndpoint for Seedance1LiteRequest {
+  const MODEL : &'static str = "bytedance/seedance-1-lite";
+
+  type RawInput = Seedance1LiteInput;
+  type RawOutput = Seedance1LiteOutput;
+
+  fn to_raw_input(&self) -> Result<Self::RawInput, ReplicateError> {
+    if !(SEEDANCE_1_LITE_MIN_DURATION_SECONDS..=SEEDANCE_1_LITE_MAX_DURATION_SECONDS).contains(&self.duration_seconds) {
+      return Err(ReplicateClientError::InvalidInput(
+        format!("duration must be 2 - 12 seconds: {}", self.duration_seconds)).into());
+    }
+
+    if self.end_frame_url.is_some() && self.start_frame_url.is_none() {
+      return Err(ReplicateClientError::InvalidInput(
+        "an end frame requires a start frame".to_string()).into());
+    }
+
+    if !self.reference_image_urls.is_empty() && self.start_frame_url.is_some() {
+      return Err(ReplicateClientError::InvalidInput(
+        "reference images can't be combined with a start frame".to_string()).into());
+    }
+
+    if self.reference_image_urls.len() > SEEDANCE_1_LITE_MAX_REFERENCE_IMAGES {
+      return Err(ReplicateClientError::InvalidInput(
+        format!("at most 4 reference images: {}", self.reference_image_urls.len())).into());
+    }
+
+    let resolution = match self.resolution {
+      Seedance1LiteResolution::FourEightyP => "480p",
+      Seedance1LiteResolution::SevenTwentyP => "720p",
+      Seedance1LiteResolution::TenEightyP => "1080p",
+    };
+
+    let aspect_ratio = self.aspect_ratio
+        .map(|a| match a {
+          Seedance1LiteAspectRatio::SixteenByNine => "16:9",
+          Seedance1LiteAspectRatio::FourByThree => "4:3",
+          Seedance1LiteAspectRatio::Square => "1:1",
+          Seedance1LiteAspectRatio::ThreeByFour => "3:4",
+          Seedance1LiteAspectRatio::NineBySixteen => "9:16",
+          Seedance1LiteAspectRatio::TwentyOneByNine => "21:9",
+          Seedance1LiteAspectRatio::NineByTwentyOne => "9:21",
+        })
+        .map(|s| s.to_string());
+
+    let reference_images = if self.reference_image_urls.is_empty() {
+      None
+    } else {
+      Some(self.reference_image_urls.clone())
+    };
+
+    Ok(Self::RawInput {
+      prompt: self.prompt.clone(),
+      image: self.start_frame_url.clone(),
+      last_frame_image: self.end_frame_url.clone(),
+      reference_images,
+      duration: Some(self.duration_seconds),
+      resolution: Some(resolution.to_string()),
+      aspect_ratio,
+      camera_fixed: self.camera_fixed,
+      seed: self.seed,
+      fps: None,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::requests::context::request_context::RequestContext;
+  use crate::test_utils::load_api_key;
+
+  fn make_request() -> Seedance1LiteRequest {
+    Seedance1LiteRequest {
+      prompt: "a corgi surfing".to_string(),
+      duration_seconds: 5,
+      resolution: Seedance1LiteResolution::SevenTwentyP,
+      aspect_ratio: Some(Seedance1LiteAspectRatio::NineBySixteen),
+      start_frame_url: None,
+      end_frame_url: None,
+      reference_image_urls: Vec::new(),
+      camera_fixed: None,
+      seed: None,
+    }
+  }
+
+  #[test]
+  fn raw_input() {
+    let json = serde_json::to_value(make_request().to_raw_input().unwrap()).unwrap();
+    assert_eq!(json, serde_json::json!({
+      "prompt": "a corgi surfing",
+      "duration": 5,
+      "resolution": "720p",
+      "aspect_ratio": "9:16",
+    }));
+  }
+
+  #[test]
+  fn rejects_out_of_range_duration() {
+    let mut request = make_request();
+    request.duration_seconds = 1;
+    assert!(request.to_raw_input().is_err());
+    request.duration_seconds = 13;
+    assert!(request.to_raw_input().is_err());
+  }
+
+  #[test]
+  fn rejects_end_frame_without_start_frame() {
+    let mut request = make_request();
+    request.end_frame_url = Some("https://example.com/end.png".to_string());
+    assert!(request.to_raw_input().is_err());
+    request.start_frame_url = Some("https://example.com/start.png".to_string());
+    assert!(request.to_raw_input().is_ok());
+  }
+
+  #[test]
+  fn rejects_reference_images_with_start_frame() {
+    let mut request = make_request();
+    request.reference_image_urls = vec!["https://example.com/ref.png".to_string()];
+    assert!(request.to_raw_input().is_ok());
+    request.start_frame_url = Some("https://example.com/start.png".to_string());
+    assert!(request.to_raw_input().is_err());
+  }
+
+  #[tokio::test]
+  #[ignore] // manually test — requires real API key, incurs costs
+  async fn test_send_request() {
+    let api_key = load_api_key();
+    let prediction = make_request().send_request(&RequestContext::new(&api_key)).await.unwrap();
+    println!("Prediction: {:?}", prediction.id);
+    assert!(!prediction.id.is_empty());
+  }
+
+  // NB: Pricing tests are in cost.rs
+}
@@ -0,0 +1,52 @@
+use crate::requests::api::video::seedance_1_lite::api::{Seedance1LiteRequest, Seedance1LiteResolution};
+use crate::requests::traits::replicate_request_cost_calculator_trait::{ReplicateRequestCostCalculator, UsdCents};
+
+impl ReplicateRequestCostCalculator for Seedance1LiteRequest {
+  fn calculate_cost_in_cents(&self) -> UsdCents {
+    // Pricing is per second of output, in tenths of a cent:
+    // $0.018/s at 480p, $0.036/s at 720p, $0.072/s at 1080p.
+    let mills_per_second: u64 = match self.resolution {
+      Seedance1LiteResolution::FourEightyP => 18,
+      Seedance1LiteResolution::SevenTwentyP => 36,
+      Seedance1LiteResolution::TenEightyP => 72,
+    };
+    (mills_per_second * self.duration_seconds as u64).div_ceil(10)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn make_request(resolution: Seedance1LiteResolution, duration_seconds: u32) -> Seedance1LiteRequest {
+    Seedance1LiteRequest {
+      prompt: "test".to_string(),
+      duration_seconds,
+      resolution,
+      aspect_ratio: None,
+      start_frame_url: None,
+      end_frame_url: None,
+      reference_image_urls: Vec::new(),
+      camera_fixed: None,
+      seed: None,
+    }
+  }
+
+  #[test]
+  fn cost_480p() {
+    assert_eq!(make_request(Seedance1LiteResolution::FourEightyP, 5).calculate_cost_in_cents(), 9);
+    assert_eq!(make_request(Seedance1LiteResolution::FourEightyP, 10).calculate_cost_in_cents(), 18);
+  }
+
+  #[test]
+  fn cost_720p() {
+    assert_eq!(make_request(Seedance1LiteResolution::SevenTwentyP, 5).calculate_cost_in_cents(), 18);
+    assert_eq!(make_request(Seedance1LiteResolution::SevenTwentyP, 10).calculate_cost_in_cents(), 36);
+  }
+
+  #[test]
+  fn cost_1080p() {
+    assert_eq!(make_request(Seedance1LiteResolution::TenEightyP, 5).calculate_cost_in_cents(), 36);
+    assert_eq!(make_request(Seedance1LiteResolution::TenEightyP, 12).calculate_cost_in_cents(), 87);
+  }
+}
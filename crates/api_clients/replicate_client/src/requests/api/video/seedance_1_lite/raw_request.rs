@@ -0,0 +1,44 @@
+use serde_derive::Serialize;
+
+/// https://replicate.com/bytedance/seedance-1-lite/api/schema
+#[derive(Debug, Serialize, Default)]
+pub struct Seedance1LiteInput {
+  pub prompt: String,
+
+  /// Start frame. When set, `aspect_ratio` is ignored.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub image: Option<String>,
+
+  /// End frame. Only used with `image`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub last_frame_image: Option<String>,
+
+  /// 1 - 4 reference images. Can't be combined with `image`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reference_images: Option<Vec<String>>,
+
+  /// Seconds, 2 - 12
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub duration: Option<u32>,
+
+  /// Options: 480p, 720p, 1080p
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub resolution: Option<String>,
+
+  /// Options: 16:9, 4:3, 1:1, 3:4, 9:16, 21:9, 9:21
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aspect_ratio: Option<String>,
+
+  /// Options: 24
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fps: Option<u32>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub camera_fixed: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub seed: Option<i64>,
+}
+
+/// A single video URL.
+pub type Seedance1LiteOutput = String;
@@ -0,0 +1,2 @@
+pub mod prediction;
+pub mod send_request;
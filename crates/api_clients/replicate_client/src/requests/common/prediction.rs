@@ -0,0 +1,182 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// A Replicate prediction, as returned by the create/get/cancel endpoints and
+/// delivered to webhooks.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Prediction {
+  pub id: String,
+
+  /// `owner/name` of the model, eg. `black-forest-labs/flux-dev`.
+  pub model: Option<String>,
+
+  pub version: Option<String>,
+
+  pub status: PredictionStatus,
+
+  pub input: Option<serde_json::Value>,
+
+  /// Model-specific: a URL, a list of URLs, text, etc. Only set once the prediction succeeds
+  /// (or streams partial output).
+  pub output: Option<serde_json::Value>,
+
+  /// Usually a string, but Replicate doesn't promise that.
+  pub error: Option<serde_json::Value>,
+
+  pub logs: Option<String>,
+
+  pub metrics: Option<PredictionMetrics>,
+
+  pub urls: Option<PredictionUrls>,
+
+  pub created_at: Option<String>,
+  pub started_at: Option<String>,
+  pub completed_at: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PredictionStatus {
+  Starting,
+  Processing,
+  Succeeded,
+  Failed,
+  Canceled,
+  Aborted,
+  /// A status this client doesn't know about yet.
+  #[serde(other)]
+  Unknown,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PredictionMetrics {
+  pub predict_time: Option<f64>,
+  pub total_time: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PredictionUrls {
+  pub get: Option<String>,
+  pub cancel: Option<String>,
+  pub stream: Option<String>,
+  pub web: Option<String>,
+}
+
+impl PredictionStatus {
+  /// The prediction will not change again.
+  pub fn is_terminal(&self) -> bool {
+    matches!(self, Self::Succeeded | Self::Failed | Self::Canceled | Self::Aborted)
+  }
+}
+
+impl Prediction {
+  pub fn is_terminal(&self) -> bool {
+    self.status.is_terminal()
+  }
+
+  pub fn is_success(&self) -> bool {
+    self.status == PredictionStatus::Succeeded
+  }
+
+  /// Output file URLs, whether the model returns a single URL or a list of them.
+  pub fn output_urls(&self) -> Vec<String> {
+    match &self.output {
+      Some(serde_json::Value::String(url)) => vec![url.clone()],
+      Some(serde_json::Value::Array(items)) => items.iter()
+        .filter_map(|item| item.as_str())
+        .map(|url| url.to_string())
+        .collect(),
+      _ => Vec::new(),
+    }
+  }
+
+  pub fn error_message(&self) -> Option<String> {
+    match &self.error {
+      None | Some(serde_json::Value::Null) => None,
+      Some(serde_json::Value::String(message)) => Some(message.clone()),
+      Some(other) => Some(other.to_string()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn deserialize_succeeded_image_prediction() {
+    let json = r#"{
+      "id": "gm3qorzdhgbfurvjtvhg6dckhu",
+      "model": "black-forest-labs/flux-dev",
+      "version": "dp-4d0bcc010b3049749a251855f12800be",
+      "input": { "prompt": "a corgi" },
+      "logs": "",
+      "output": [
+        "https://replicate.delivery/xezq/abc/out-0.webp",
+        "https://replicate.delivery/xezq/abc/out-1.webp"
+      ],
+      "data_removed": false,
+      "error": null,
+      "status": "succeeded",
+      "created_at": "2025-01-01T00:00:00.000Z",
+      "started_at": "2025-01-01T00:00:01.000Z",
+      "completed_at": "2025-01-01T00:00:03.000Z",
+      "urls": {
+        "cancel": "https://api.replicate.com/v1/predictions/gm3qorzdhgbfurvjtvhg6dckhu/cancel",
+        "get": "https://api.replicate.com/v1/predictions/gm3qorzdhgbfurvjtvhg6dckhu",
+        "web": "https://replicate.com/p/gm3qorzdhgbfurvjtvhg6dckhu"
+      },
+      "metrics": { "predict_time": 1.9, "total_time": 3.0 }
+    }"#;
+
+    let prediction: Prediction = serde_json::from_str(json).unwrap();
+    assert!(prediction.is_success());
+    assert!(prediction.is_terminal());
+    assert_eq!(prediction.output_urls().len(), 2);
+    assert_eq!(prediction.error_message(), None);
+  }
+
+  #[test]
+  fn deserialize_single_url_output() {
+    let json = r#"{
+      "id": "abc",
+      "status": "succeeded",
+      "output": "https://replicate.delivery/xezq/abc/output.mp4"
+    }"#;
+
+    let prediction: Prediction = serde_json::from_str(json).unwrap();
+    assert_eq!(prediction.output_urls(), vec!["https://replicate.delivery/xezq/abc/output.mp4"]);
+  }
+
+  #[test]
+  fn deserialize_failed_prediction() {
+    let json = r#"{
+      "id": "abc",
+      "status": "failed",
+      "output": null,
+      "error": "NSFW content detected"
+    }"#;
+
+    let prediction: Prediction = serde_json::from_str(json).unwrap();
+    assert!(prediction.is_terminal());
+    assert!(!prediction.is_success());
+    assert!(prediction.output_urls().is_empty());
+    assert_eq!(prediction.error_message().as_deref(), Some("NSFW content detected"));
+  }
+
+  #[test]
+  fn in_progress_statuses_are_not_terminal() {
+    for status in ["starting", "processing"] {
+      let json = format!(r#"{{ "id": "abc", "status": "{}" }}"#, status);
+      let prediction: Prediction = serde_json::from_str(&json).unwrap();
+      assert!(!prediction.is_terminal(), "{status}");
+    }
+  }
+
+  #[test]
+  fn unknown_status_does_not_fail_parsing() {
+    let json = r#"{ "id": "abc", "status": "hibernating" }"#;
+    let prediction: Prediction = serde_json::from_str(json).unwrap();
+    assert_eq!(prediction.status, PredictionStatus::Unknown);
+    assert!(!prediction.is_terminal());
+  }
+}
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use log::warn;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use crate::client_config::replicate_client_config::ReplicateClientConfig;
+use crate::error::replicate_error::ReplicateError;
+use crate::error::replicate_generic_api_error::ReplicateGenericApiError;
+use crate::error::replicate_specific_api_error::ReplicateSpecificApiError;
+use crate::requests::context::request_context::RequestContext;
+
+/// Creating a prediction returns as soon as it's queued, so this only needs to cover
+/// slow uploads and network hiccups.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Start a request to `path` (relative to the API root) on the context's client.
+pub(crate) fn build_request(
+  context: &RequestContext<'_>,
+  method: Method,
+  path: &str,
+) -> reqwest::RequestBuilder {
+  let timeout = context.maybe_timeout.unwrap_or(DEFAULT_TIMEOUT);
+
+  let default_config;
+  let config = match context.maybe_config {
+    Some(config) => config,
+    None => {
+      default_config = ReplicateClientConfig::current();
+      default_config.as_ref()
+    }
+  };
+
+  config.http_client
+    .request(method, config.base_url.join(path))
+    .timeout(timeout)
+}
+
+/// Attach auth, send, classify errors, and parse the JSON body.
+pub(crate) async fn send_request<T: DeserializeOwned>(
+  context: &RequestContext<'_>,
+  request: reqwest::RequestBuilder,
+) -> Result<T, ReplicateError> {
+  let response = request
+    .header("Authorization", format!("Bearer {}", context.api_key.as_str()))
+    .send()
+    .await
+    .map_err(ReplicateGenericApiError::from)?;
+
+  let status = response.status();
+  let body_text = response.text().await
+    .map_err(ReplicateGenericApiError::from)?;
+
+  if !status.is_success() {
+    warn!("Replicate API error: status={}, body={}", status, body_text);
+    return Err(classify_error(status.as_u16(), body_text));
+  }
+
+  let parsed: T = serde_json::from_str(&body_text)
+    .map_err(|err| ReplicateGenericApiError::SerdeResponseParseErrorWithBody(err, body_text))?;
+
+  Ok(parsed)
+}
+
+/// Replicate errors are RFC 7807 problem documents: `{"title": ..., "detail": ..., "status": ...}`.
+fn classify_error(status_code: u16, body: String) -> ReplicateError {
+  match status_code {
+    401 => ReplicateSpecificApiError::Unauthorized.into(),
+    402 => ReplicateSpecificApiError::BillingError { status_code, body }.into(),
+    404 => ReplicateSpecificApiError::NotFound(extract_detail(&body).unwrap_or(body)).into(),
+    422 => ReplicateSpecificApiError::InvalidInput(extract_detail(&body).unwrap_or(body)).into(),
+    429 => ReplicateSpecificApiError::RateLimited {
+      maybe_retry_after_seconds: extract_retry_after(&body),
+    }.into(),
+    _ => ReplicateGenericApiError::UncategorizedBadResponseWithStatusAndBody { status_code, body }.into(),
+  }
+}
+
+fn extract_detail(body: &str) -> Option<String> {
+  let value: serde_json::Value = serde_json::from_str(body).ok()?;
+  value.get("detail")?.as_str().map(|detail| detail.to_string())
+}
+
+fn extract_retry_after(body: &str) -> Option<u64> {
+  let value: serde_json::Value = serde_json::from_str(body).ok()?;
+  value.get("retry_after")?.as_u64()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn classify_unauthorized() {
+    let err = classify_error(401, r#"{"detail":"Unauthenticated"}"#.to_string());
+    assert!(matches!(err, ReplicateError::ApiSpecific(ReplicateSpecificApiError::Unauthorized)));
+  }
+
+  #[test]
+  fn classify_billing() {
+    let err = classify_error(402, r#"{"title":"Insufficient credit","status":402}"#.to_string());
+    assert!(matches!(err, ReplicateError::ApiSpecific(ReplicateSpecificApiError::BillingError { status_code: 402, .. })));
+  }
+
+  #[test]
+  fn classify_invalid_input_uses_detail() {
+    let err = classify_error(422, r#"{"title":"Input validation failed","detail":"- num_outputs: Must be less than or equal to 4","status":422}"#.to_string());
+    let ReplicateError::ApiSpecific(ReplicateSpecificApiError::InvalidInput(detail)) = err else {
+      panic!("expected InvalidInput");
+    };
+    assert_eq!(detail, "- num_outputs: Must be less than or equal to 4");
+  }
+
+  #[test]
+  fn classify_rate_limited() {
+    let err = classify_error(429, r#"{"detail":"Request was throttled.","status":429,"retry_after":7}"#.to_string());
+    assert!(matches!(err, ReplicateError::ApiSpecific(ReplicateSpecificApiError::RateLimited { maybe_retry_after_seconds: Some(7) })));
+  }
+
+  #[test]
+  fn classify_unknown() {
+    let err = classify_error(503, "upstream unavailable".to_string());
+    assert!(matches!(err, ReplicateError::ApiGeneric(ReplicateGenericApiError::UncategorizedBadResponseWithStatusAndBody { status_code: 503, .. })));
+  }
+}
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use crate::client_config::replicate_client_config::ReplicateClientConfig;
+use crate::creds::replicate_api_key::ReplicateApiKey;
+
+pub struct RequestContext<'a> {
+  pub api_key: &'a ReplicateApiKey,
+  pub maybe_timeout: Option<Duration>,
+
+  /// Send to this host / through this client instead of the process default.
+  pub maybe_config: Option<&'a ReplicateClientConfig>,
+}
+
+impl<'a> RequestContext<'a> {
+  pub fn new(api_key: &'a ReplicateApiKey) -> Self {
+    Self { api_key, maybe_timeout: None, maybe_config: None }
+  }
+
+  pub fn with_config(mut self, config: &'a ReplicateClientConfig) -> Self {
+    self.maybe_config = Some(config);
+    self
+  }
+}
@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use reqwest::multipart::{Form, Part};
+use reqwest::Method;
+use serde_derive::Deserialize;
+
+use crate::error::replicate_client_error::ReplicateClientError;
+use crate::error::replicate_error::ReplicateError;
+use crate::error::replicate_generic_api_error::ReplicateGenericApiError;
+use crate::requests::common::send_request::{build_request, send_request};
+use crate::requests::context::request_context::RequestContext;
+
+/// A file uploaded to Replicate's file store. `urls.get` can be passed as a model input.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReplicateFile {
+  pub id: String,
+  pub name: Option<String>,
+  pub content_type: Option<String>,
+  pub size: Option<u64>,
+  pub urls: ReplicateFileUrls,
+  pub created_at: Option<String>,
+  pub expires_at: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReplicateFileUrls {
+  pub get: String,
+}
+
+pub async fn upload_file_bytes(
+  context: &RequestContext<'_>,
+  filename: &str,
+  content_type: &str,
+  bytes: Vec<u8>,
+) -> Result<ReplicateFile, ReplicateError> {
+  let part = Part::bytes(bytes)
+    .file_name(filename.to_string())
+    .mime_str(content_type)
+    .map_err(ReplicateGenericApiError::from)?;
+
+  let form = Form::new().part("content", part);
+
+  let request = build_request(context, Method::POST, "files")
+    .multipart(form);
+
+  send_request(context, request).await
+}
+
+pub async fn upload_file_path(
+  context: &RequestContext<'_>,
+  path: &Path,
+  content_type: &str,
+) -> Result<ReplicateFile, ReplicateError> {
+  let filename = path.file_name()
+    .and_then(|name| name.to_str())
+    .ok_or_else(|| ReplicateClientError::InvalidInput(format!("no filename in path: {:?}", path)))?
+    .to_string();
+
+  let bytes = std::fs::read(path)
+    .map_err(ReplicateClientError::FileReadError)?;
+
+  upload_file_bytes(context, &filename, content_type, bytes).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils::load_api_key;
+
+  #[test]
+  fn deserialize_file() {
+    let json = r#"{
+      "id": "MTQzODcyMDMtMGVmZS00NzY1LTk0OTEtMjk1M2VkYjI5ZTAx",
+      "name": "input.jpg",
+      "content_type": "image/jpeg",
+      "size": 12345,
+      "etag": "abc",
+      "checksums": { "sha256": "def" },
+      "metadata": {},
+      "created_at": "2025-01-01T00:00:00.000Z",
+      "expires_at": "2025-01-02T00:00:00.000Z",
+      "urls": { "get": "https://api.replicate.com/v1/files/MTQzODcyMDMtMGVmZS00NzY1LTk0OTEtMjk1M2VkYjI5ZTAx" }
+    }"#;
+
+    let file: ReplicateFile = serde_json::from_str(json).unwrap();
+    assert_eq!(file.size, Some(12345));
+    assert!(file.urls.get.ends_with("/files/MTQzODcyMDMtMGVmZS00NzY1LTk0OTEtMjk1M2VkYjI5ZTAx"));
+  }
+
+  #[tokio::test]
+  #[ignore] // Manually run against the live API
+  async fn test_upload_file() {
+    let api_key = load_api_key();
+    let context = RequestContext::new(&api_key);
+    let file = upload_file_bytes(&context, "test.txt", "text/plain", b"hello".to_vec()).await.unwrap();
+    println!("{:#?}", file);
+  }
+}
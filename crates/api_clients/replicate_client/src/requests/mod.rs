@@ -0,0 +1,6 @@
+pub mod api;
+pub mod common;
+pub mod context;
+pub mod files;
+pub mod predictions;
+pub mod traits;
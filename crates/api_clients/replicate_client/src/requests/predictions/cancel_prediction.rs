@@ -0,0 +1,17 @@
+use reqwest::Method;
+
+use crate::error::replicate_error::ReplicateError;
+use crate::requests::common::prediction::Prediction;
+use crate::requests::common::send_request::{build_request, send_request};
+use crate::requests::context::request_context::RequestContext;
+
+/// Cancel a running prediction. Cancelling one that already finished is a no-op;
+/// Replicate returns it unchanged.
+pub async fn cancel_prediction(
+  context: &RequestContext<'_>,
+  prediction_id: &str,
+) -> Result<Prediction, ReplicateError> {
+  let path = format!("predictions/{}/cancel", prediction_id);
+  let request = build_request(context, Method::POST, &path);
+  send_request(context, request).await
+}
@@ -0,0 +1,107 @@
+use reqwest::Method;
+use serde::Serialize;
+
+use crate::error::replicate_client_error::ReplicateClientError;
+use crate::error::replicate_error::ReplicateError;
+use crate::requests::common::prediction::Prediction;
+use crate::requests::common::send_request::{build_request, send_request};
+use crate::requests::context::request_context::RequestContext;
+
+/// Which prediction events Replicate should POST to the webhook.
+/// If unset, Replicate sends all of them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookEventType {
+  Start,
+  Output,
+  Logs,
+  Completed,
+}
+
+pub struct CreatePredictionArgs<'a, I: Serialize> {
+  /// `owner/name` of an official model, eg. `black-forest-labs/flux-dev`.
+  pub model: &'a str,
+  pub input: I,
+  pub maybe_webhook_url: Option<&'a str>,
+  pub maybe_webhook_events: Option<&'a [WebhookEventType]>,
+}
+
+#[derive(Serialize)]
+struct CreatePredictionBody<'a, I: Serialize> {
+  input: &'a I,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  webhook: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  webhook_events_filter: Option<&'a [WebhookEventType]>,
+}
+
+/// Queue a prediction against an official model. Returns immediately with the
+/// prediction in the `starting` state; poll it with `get_prediction` or wait for the webhook.
+pub async fn create_prediction<I: Serialize>(
+  context: &RequestContext<'_>,
+  args: CreatePredictionArgs<'_, I>,
+) -> Result<Prediction, ReplicateError> {
+  let path = model_predictions_path(args.model)?;
+
+  let body = CreatePredictionBody {
+    input: &args.input,
+    webhook: args.maybe_webhook_url,
+    webhook_events_filter: args.maybe_webhook_events,
+  };
+
+  let request = build_request(context, Method::POST, &path)
+    .json(&body);
+
+  send_request(context, request).await
+}
+
+fn model_predictions_path(model: &str) -> Result<String, ReplicateError> {
+  let Some((owner, name)) = model.split_once('/') else {
+    return Err(ReplicateClientError::InvalidInput(format!("model must be `owner/name`: {}", model)).into());
+  };
+
+  if owner.is_empty() || name.is_empty() || name.contains('/') {
+    return Err(ReplicateClientError::InvalidInput(format!("model must be `owner/name`: {}", model)).into());
+  }
+
+  Ok(format!("models/{}/{}/predictions", owner, name))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn model_path() {
+    assert_eq!(
+      model_predictions_path("black-forest-labs/flux-dev").unwrap(),
+      "models/black-forest-labs/flux-dev/predictions",
+    );
+    assert!(model_predictions_path("flux-dev").is_err());
+    assert!(model_predictions_path("a/b/c").is_err());
+    assert!(model_predictions_path("/flux-dev").is_err());
+  }
+
+  #[test]
+  fn body_serialization() {
+    let input = serde_json::json!({ "prompt": "a corgi" });
+    let events = [WebhookEventType::Start, WebhookEventType::Completed];
+
+    let body = CreatePredictionBody {
+      input: &input,
+      webhook: Some("https://example.com/webhooks/replicate"),
+      webhook_events_filter: Some(&events),
+    };
+
+    let json = serde_json::to_value(&body).unwrap();
+    assert_eq!(json, serde_json::json!({
+      "input": { "prompt": "a corgi" },
+      "webhook": "https://example.com/webhooks/replicate",
+      "webhook_events_filter": ["start", "completed"],
+    }));
+
+    let body = CreatePredictionBody { input: &input, webhook: None, webhook_events_filter: None };
+    let json = serde_json::to_value(&body).unwrap();
+    assert_eq!(json, serde_json::json!({ "input": { "prompt": "a corgi" } }));
+  }
+}
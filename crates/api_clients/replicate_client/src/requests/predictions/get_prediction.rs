@@ -0,0 +1,30 @@
+use reqwest::Method;
+
+use crate::error::replicate_error::ReplicateError;
+use crate::requests::common::prediction::Prediction;
+use crate::requests::common::send_request::{build_request, send_request};
+use crate::requests::context::request_context::RequestContext;
+
+pub async fn get_prediction(
+  context: &RequestContext<'_>,
+  prediction_id: &str,
+) -> Result<Prediction, ReplicateError> {
+  let path = format!("predictions/{}", prediction_id);
+  let request = build_request(context, Method::GET, &path);
+  send_request(context, request).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils::load_api_key;
+
+  #[tokio::test]
+  #[ignore] // Manually run against the live API
+  async fn test_get_prediction() {
+    let api_key = load_api_key();
+    let context = RequestContext::new(&api_key);
+    let prediction = get_prediction(&context, "REPLACE_ME").await.unwrap();
+    println!("{:#?}", prediction);
+  }
+}
@@ -0,0 +1,3 @@
+pub mod cancel_prediction;
+pub mod create_prediction;
+pub mod get_prediction;
@@ -0,0 +1,2 @@
+pub mod replicate_model_endpoint_trait;
+pub mod replicate_request_cost_calculator_trait;
@@ -0,0 +1,70 @@
+use std::future::Future;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::replicate_error::ReplicateError;
+use crate::error::replicate_generic_api_error::ReplicateGenericApiError;
+use crate::requests::common::prediction::Prediction;
+use crate::requests::context::request_context::RequestContext;
+use crate::requests::predictions::create_prediction::{create_prediction, CreatePredictionArgs, WebhookEventType};
+
+/// Only ask for the events we act on; `output` and `logs` events are noisy for
+/// long-running video models.
+const WEBHOOK_EVENTS: &[WebhookEventType] = &[WebhookEventType::Completed];
+
+pub trait ReplicateModelEndpoint: Sync {
+  /// Official model, eg. `black-forest-labs/flux-dev`
+  const MODEL : &'static str;
+
+  /// Shape of the model's `input`
+  type RawInput : Serialize + Send + Sync;
+
+  /// Shape of the model's `output` once the prediction succeeds
+  type RawOutput : DeserializeOwned;
+
+  fn get_model() -> &'static str {
+    Self::MODEL
+  }
+
+  /// Create a prediction and return right away. Poll it with `get_prediction`.
+  fn send_request(&self, context: &RequestContext<'_>) -> impl Future<Output = Result<Prediction, ReplicateError>> + Send {
+    async move {
+      let input = self.to_raw_input()?;
+      create_prediction(context, CreatePredictionArgs {
+        model: Self::MODEL,
+        input,
+        maybe_webhook_url: None,
+        maybe_webhook_events: None,
+      }).await
+    }
+  }
+
+  /// Create a prediction; Replicate will POST it to `webhook_url` when it completes.
+  fn send_webhook_request(&self, context: &RequestContext<'_>, webhook_url: &str) -> impl Future<Output = Result<Prediction, ReplicateError>> + Send {
+    async move {
+      let input = self.to_raw_input()?;
+      create_prediction(context, CreatePredictionArgs {
+        model: Self::MODEL,
+        input,
+        maybe_webhook_url: Some(webhook_url),
+        maybe_webhook_events: Some(WEBHOOK_EVENTS),
+      }).await
+    }
+  }
+
+  /// Parse the typed output of a succeeded prediction. Returns `None` if the
+  /// prediction has no output yet.
+  fn extract_output(prediction: &Prediction) -> Result<Option<Self::RawOutput>, ReplicateError> {
+    let Some(output) = prediction.output.as_ref() else {
+      return Ok(None);
+    };
+    let output = serde_json::from_value(output.clone())
+      .map_err(|err| ReplicateGenericApiError::SerdeResponseParseErrorWithBody(err, output.to_string()))?;
+    Ok(Some(output))
+  }
+
+  /// Convert request to the over-the-wire representation
+  /// This allows us to change the shape, types, etc.
+  fn to_raw_input(&self) -> Result<Self::RawInput, ReplicateError>;
+}
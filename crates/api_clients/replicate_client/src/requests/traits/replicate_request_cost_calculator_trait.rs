@@ -0,0 +1,8 @@
+/// Cost in pennies.
+pub type UsdCents = u64;
+
+pub trait ReplicateRequestCostCalculator {
+
+  /// Calculate the cost of the request.
+  fn calculate_cost_in_cents(&self) -> UsdCents;
+}
@@ -0,0 +1,10 @@
+use crate::creds::replicate_api_key::ReplicateApiKey;
+use std::fs::read_to_string;
+
+const API_KEY_PATH: &str = "/Users/bt/Artcraft/credentials/replicate_api_key.txt";
+
+pub fn load_api_key() -> ReplicateApiKey {
+  let secret = read_to_string(API_KEY_PATH)
+    .expect("Failed to read Replicate API key file");
+  ReplicateApiKey::from_str(&secret)
+}
@@ -0,0 +1,2 @@
+pub mod parse_webhook_payload;
+pub mod signature;
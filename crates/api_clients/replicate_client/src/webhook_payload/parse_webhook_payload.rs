@@ -0,0 +1,6 @@
+use crate::requests::common::prediction::Prediction;
+
+/// Replicate webhooks deliver the prediction itself, in the same shape as `get_prediction`.
+pub fn parse_webhook_payload(json: &str) -> Result<Prediction, serde_json::Error> {
+  serde_json::from_str(json)
+}
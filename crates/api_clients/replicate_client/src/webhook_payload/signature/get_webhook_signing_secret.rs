@@ -0,0 +1,21 @@
+use reqwest::Method;
+use serde_derive::Deserialize;
+
+use crate::error::replicate_error::ReplicateError;
+use crate::requests::common::send_request::{build_request, send_request};
+use crate::requests::context::request_context::RequestContext;
+
+#[derive(Deserialize)]
+struct WebhookSecretResponse {
+  key: String,
+}
+
+/// Fetch the account's webhook signing secret (`whsec_...`). It rarely changes, so
+/// callers should cache it.
+pub async fn get_webhook_signing_secret(context: &RequestContext<'_>) -> Result<String, ReplicateError> {
+  let request = build_request(context, Method::GET, "webhooks/default/secret");
+
+  let response: WebhookSecretResponse = send_request(context, request).await?;
+
+  Ok(response.key)
+}
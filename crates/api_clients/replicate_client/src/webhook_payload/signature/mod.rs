@@ -0,0 +1,8 @@
+//! Replicate signs webhook deliveries with HMAC-SHA256 (the Standard Webhooks scheme).
+//! The signing secret is per-account; fetch it with `get_webhook_signing_secret`.
+//! See https://replicate.com/docs/topics/webhooks/verify-webhook
+
+pub mod get_webhook_signing_secret;
+pub mod replicate_webhook_headers;
+pub mod replicate_webhook_signature_error;
+pub mod verify_replicate_webhook_signature;
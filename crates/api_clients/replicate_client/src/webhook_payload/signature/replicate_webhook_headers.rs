@@ -0,0 +1,38 @@
+use crate::webhook_payload::signature::replicate_webhook_signature_error::ReplicateWebhookSignatureError;
+
+pub const REPLICATE_WEBHOOK_ID_HEADER: &str = "webhook-id";
+pub const REPLICATE_WEBHOOK_TIMESTAMP_HEADER: &str = "webhook-timestamp";
+pub const REPLICATE_WEBHOOK_SIGNATURE_HEADER: &str = "webhook-signature";
+
+/// The signing headers Replicate attaches to each webhook delivery.
+#[derive(Clone, Debug)]
+pub struct ReplicateWebhookHeaders {
+  /// Unique per delivery; retries reuse it.
+  pub webhook_id: String,
+
+  /// Unix timestamp (seconds), as sent.
+  pub timestamp: String,
+
+  /// Space-separated `v1,<base64 signature>` entries.
+  pub signature: String,
+}
+
+impl ReplicateWebhookHeaders {
+  /// Read the headers with a lookup function, so this crate doesn't depend on a web framework.
+  /// The lookup should be case-insensitive, as HTTP header names are.
+  pub fn from_lookup<F>(lookup: F) -> Result<Self, ReplicateWebhookSignatureError>
+    where F: Fn(&'static str) -> Option<String>
+  {
+    let get = |name: &'static str| {
+      lookup(name)
+        .filter(|value| !value.trim().is_empty())
+        .ok_or(ReplicateWebhookSignatureError::MissingHeader(name))
+    };
+
+    Ok(Self {
+      webhook_id: get(REPLICATE_WEBHOOK_ID_HEADER)?,
+      timestamp: get(REPLICATE_WEBHOOK_TIMESTAMP_HEADER)?,
+      signature: get(REPLICATE_WEBHOOK_SIGNATURE_HEADER)?,
+    })
+  }
+}
@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum ReplicateWebhookSignatureError {
+  /// A required `webhook-*` header was absent or not valid UTF-8.
+  MissingHeader(&'static str),
+
+  /// The timestamp header was not a unix timestamp (seconds).
+  InvalidTimestamp(String),
+
+  /// The timestamp is too far from the current time (possibly a replay of an old delivery).
+  TimestampOutOfRange { skew_seconds: i64 },
+
+  /// The signing secret was not `whsec_<base64>`.
+  InvalidSecret,
+
+  /// No `v1` signature in the header matched.
+  SignatureMismatch,
+}
+
+impl Error for ReplicateWebhookSignatureError {}
+
+impl Display for ReplicateWebhookSignatureError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::MissingHeader(header) => write!(f, "Missing webhook header: {}", header),
+      Self::InvalidTimestamp(value) => write!(f, "Invalid webhook timestamp: {}", value),
+      Self::TimestampOutOfRange { skew_seconds } => write!(f, "Webhook timestamp out of range (skew: {}s)", skew_seconds),
+      Self::InvalidSecret => write!(f, "Replicate webhook signing secret is not `whsec_<base64>`"),
+      Self::SignatureMismatch => write!(f, "Webhook signature did not match"),
+    }
+  }
+}
@@ -0,0 +1,154 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ring::hmac;
+
+use crate::webhook_payload::signature::replicate_webhook_headers::ReplicateWebhookHeaders;
+use crate::webhook_payload::signature::replicate_webhook_signature_error::ReplicateWebhookSignatureError;
+
+/// Replicate recommends rejecting deliveries more than five minutes from the current time.
+pub const DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS: i64 = 300;
+
+const SECRET_PREFIX: &str = "whsec_";
+const SIGNATURE_VERSION: &str = "v1";
+
+/// Verify a webhook delivery's signature and timestamp. This does not check for replays.
+///
+/// The signed content is `{webhook-id}.{webhook-timestamp}.{raw body}`, MACed with the
+/// base64-decoded part of the `whsec_...` secret. The header may hold several signatures
+/// (during secret rotation); any match is accepted.
+pub fn verify_replicate_webhook_signature(
+  headers: &ReplicateWebhookHeaders,
+  body: &[u8],
+  signing_secret: &str,
+  now_unix_seconds: i64,
+  max_skew_seconds: i64,
+) -> Result<(), ReplicateWebhookSignatureError> {
+  let timestamp = headers.timestamp.trim().parse::<i64>()
+    .map_err(|_| ReplicateWebhookSignatureError::InvalidTimestamp(headers.timestamp.clone()))?;
+
+  let skew_seconds = now_unix_seconds - timestamp;
+  if skew_seconds.abs() > max_skew_seconds {
+    return Err(ReplicateWebhookSignatureError::TimestampOutOfRange { skew_seconds });
+  }
+
+  let key = signing_key(signing_secret)?;
+  let message = signed_content(headers, body);
+
+  let matched = headers.signature.split_whitespace()
+    .filter_map(|entry| entry.split_once(','))
+    .filter(|(version, _)| *version == SIGNATURE_VERSION)
+    .filter_map(|(_, encoded)| STANDARD.decode(encoded).ok())
+    .any(|signature| hmac::verify(&key, &message, &signature).is_ok());
+
+  if matched {
+    Ok(())
+  } else {
+    Err(ReplicateWebhookSignatureError::SignatureMismatch)
+  }
+}
+
+fn signing_key(signing_secret: &str) -> Result<hmac::Key, ReplicateWebhookSignatureError> {
+  let encoded = signing_secret.trim().strip_prefix(SECRET_PREFIX)
+    .ok_or(ReplicateWebhookSignatureError::InvalidSecret)?;
+
+  let secret = STANDARD.decode(encoded)
+    .map_err(|_| ReplicateWebhookSignatureError::InvalidSecret)?;
+
+  Ok(hmac::Key::new(hmac::HMAC_SHA256, &secret))
+}
+
+fn signed_content(headers: &ReplicateWebhookHeaders, body: &[u8]) -> Vec<u8> {
+  let mut message = format!("{}.{}.", headers.webhook_id, headers.timestamp).into_bytes();
+  message.extend_from_slice(body);
+  message
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const NOW: i64 = 1_760_000_000;
+  const SECRET: &str = "whsec_C2FVsBQIhrscChlQIMV+b5sSYspob7oD";
+  const BODY: &[u8] = br#"{"id":"abc","status":"succeeded","output":"https://replicate.delivery/out.mp4"}"#;
+
+  /// Build headers as Replicate would.
+  fn sign(webhook_id: &str, timestamp: i64, body: &[u8]) -> ReplicateWebhookHeaders {
+    let mut headers = ReplicateWebhookHeaders {
+      webhook_id: webhook_id.to_string(),
+      timestamp: timestamp.to_string(),
+      signature: String::new(),
+    };
+    let tag = hmac::sign(&signing_key(SECRET).unwrap(), &signed_content(&headers, body));
+    headers.signature = format!("v1,{}", STANDARD.encode(tag.as_ref()));
+    headers
+  }
+
+  fn verify(headers: &ReplicateWebhookHeaders, body: &[u8]) -> Result<(), ReplicateWebhookSignatureError> {
+    verify_replicate_webhook_signature(headers, body, SECRET, NOW, DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS)
+  }
+
+  #[test]
+  fn valid_signature() {
+    let headers = sign("msg_1", NOW - 10, BODY);
+    assert!(verify(&headers, BODY).is_ok());
+  }
+
+  #[test]
+  fn any_listed_signature_may_match() {
+    let mut headers = sign("msg_1", NOW, BODY);
+    headers.signature = format!("v1,bm90IGEgc2lnbmF0dXJl {}", headers.signature);
+    assert!(verify(&headers, BODY).is_ok());
+  }
+
+  #[test]
+  fn tampered_body() {
+    let headers = sign("msg_1", NOW, BODY);
+    let result = verify(&headers, br#"{"id":"abc","status":"failed"}"#);
+    assert!(matches!(result, Err(ReplicateWebhookSignatureError::SignatureMismatch)));
+  }
+
+  #[test]
+  fn wrong_secret() {
+    let headers = sign("msg_1", NOW, BODY);
+    let result = verify_replicate_webhook_signature(&headers, BODY, "whsec_b3RoZXIgc2VjcmV0", NOW, DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS);
+    assert!(matches!(result, Err(ReplicateWebhookSignatureError::SignatureMismatch)));
+  }
+
+  #[test]
+  fn unknown_signature_version_is_ignored() {
+    let mut headers = sign("msg_1", NOW, BODY);
+    headers.signature = headers.signature.replacen("v1,", "v2,", 1);
+    assert!(matches!(verify(&headers, BODY), Err(ReplicateWebhookSignatureError::SignatureMismatch)));
+  }
+
+  #[test]
+  fn expired_timestamp() {
+    let headers = sign("msg_1", NOW - 301, BODY);
+    let result = verify(&headers, BODY);
+    assert!(matches!(result, Err(ReplicateWebhookSignatureError::TimestampOutOfRange { skew_seconds: 301 })));
+  }
+
+  #[test]
+  fn invalid_timestamp() {
+    let mut headers = sign("msg_1", NOW, BODY);
+    headers.timestamp = "yesterday".to_string();
+    assert!(matches!(verify(&headers, BODY), Err(ReplicateWebhookSignatureError::InvalidTimestamp(_))));
+  }
+
+  #[test]
+  fn invalid_secret() {
+    let headers = sign("msg_1", NOW, BODY);
+    let result = verify_replicate_webhook_signature(&headers, BODY, "not-a-secret", NOW, DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS);
+    assert!(matches!(result, Err(ReplicateWebhookSignatureError::InvalidSecret)));
+  }
+
+  #[test]
+  fn headers_from_lookup() {
+    let headers = ReplicateWebhookHeaders::from_lookup(|name| match name {
+      "webhook-id" => Some("msg_1".to_string()),
+      "webhook-timestamp" => Some(NOW.to_string()),
+      _ => None,
+    });
+    assert!(matches!(headers, Err(ReplicateWebhookSignatureError::MissingHeader("webhook-signature"))));
+  }
+}
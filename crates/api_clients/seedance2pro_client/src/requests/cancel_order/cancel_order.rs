@@ -0,0 +1,181 @@
+use crate::creds::seedance2pro_session::Seedance2ProSession;
+use crate::error::seedance2pro_error::Seedance2ProError;
+use crate::error::seedance2pro_generic_api_error::Seedance2ProGenericApiError;
+use crate::requests::cancel_order::request_types::*;
+use crate::requests::kinovi_host::{KinoviHost, resolve_host};
+use crate::utils::categorize_seedance2pro_error::categorize_seedance2pro_error;
+use crate::utils::common_headers::FIREFOX_USER_AGENT;
+use crate::utils::shared_client::shared_client;
+use log::info;
+use wreq::StatusCode;
+
+// --- Args & response ---
+
+pub struct CancelOrderArgs<'a> {
+  pub session: &'a Seedance2ProSession,
+
+  /// The order to cancel (`GenerateVideoResponse::order_id`).
+  pub order_id: &'a str,
+
+  /// Override the default host (kinovi.ai).
+  pub host_override: Option<KinoviHost>,
+}
+
+/// What Kinovi did with a cancellation request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CancelOrderOutcome {
+  /// The order was cancelled before it started. Kinovi returns the credits.
+  Cancelled,
+
+  /// Kinovi accepted the cancellation, but didn't report the order's new status.
+  /// Poll the order to learn how it ends.
+  CancellationRequested,
+
+  /// The order finished (completed or failed) before we asked.
+  AlreadyFinished,
+
+  /// The order is already processing and can't be stopped.
+  NotCancellable,
+
+  /// Kinovi doesn't know the order, or it belongs to another account.
+  NotFound,
+}
+
+// --- Implementation ---
+
+pub async fn cancel_order(args: CancelOrderArgs<'_>) -> Result<CancelOrderOutcome, Seedance2ProError> {
+  let host = resolve_host(args.host_override.as_ref());
+  let base_url = host.api_base_url();
+  let cancel_order_url = format!("{}/api/trpc/userOrder.cancelOrder?batch=1", base_url);
+
+  info!("Cancelling order: {}", args.order_id);
+
+  let request_body = BatchRequest {
+    zero: BatchRequestInner {
+      json: BatchRequestJson {
+        order_id: args.order_id.to_string(),
+      },
+    },
+  };
+
+  let client = shared_client();
+
+  let cookie = args.session.cookies.as_str();
+  let referer = format!("{}/app/gallery", base_url);
+
+  let response = client.post(&cancel_order_url)
+    .header("User-Agent", FIREFOX_USER_AGENT)
+    .header("Accept", "*/*")
+    .header("Accept-Language", "en-US,en;q=0.9")
+    .header("Accept-Encoding", "gzip, deflate, br, zstd")
+    .header("Referer", &referer)
+    .header("Content-Type", "application/json")
+    .header("x-trpc-source", "client")
+    .header("Origin", base_url)
+    .header("Connection", "keep-alive")
+    .header("Cookie", cookie)
+    .header("Sec-Fetch-Dest", "empty")
+    .header("Sec-Fetch-Mode", "cors")
+    .header("Sec-Fetch-Site", "same-origin")
+    .header("Priority", "u=4")
+    .header("TE", "trailers")
+    .json(&request_body)
+    .send()
+    .await
+    .map_err(|err| Seedance2ProGenericApiError::WreqError(err))?;
+
+  let status = response.status();
+  let response_body = response.text()
+    .await
+    .map_err(|err| Seedance2ProGenericApiError::WreqError(err))?;
+
+  info!("Cancel order response status: {}, body: {}", status, response_body);
+
+  classify_cancel_response(status, response_body)
+}
+
+fn classify_cancel_response(status: StatusCode, body: String) -> Result<CancelOrderOutcome, Seedance2ProError> {
+  // tRPC reports errors in the batch body as well as the HTTP status.
+  let maybe_item = serde_json::from_str::<Vec<BatchResponseItem>>(&body)
+    .ok()
+    .and_then(|items| items.into_iter().next());
+
+  if !status.is_success() {
+    let maybe_error_code = maybe_item.as_ref()
+      .and_then(|item| item.error.as_ref())
+      .and_then(|error| error.json.data.as_ref())
+      .and_then(|data| data.code.as_deref());
+
+    return match (status, maybe_error_code) {
+      (StatusCode::NOT_FOUND, _) | (_, Some("NOT_FOUND")) => Ok(CancelOrderOutcome::NotFound),
+      (StatusCode::CONFLICT, _) | (_, Some("CONFLICT")) | (_, Some("PRECONDITION_FAILED")) => {
+        Ok(CancelOrderOutcome::NotCancellable)
+      }
+      _ => Err(categorize_seedance2pro_error(status, body)),
+    };
+  }
+
+  let item = maybe_item
+    .ok_or_else(|| Seedance2ProGenericApiError::UncategorizedBadResponse(body.clone()))?;
+
+  let maybe_task_status = item.result
+    .and_then(|result| result.data.json.task_status);
+
+  // Same status strings as `userOrder.getOrders`.
+  Ok(match maybe_task_status.as_deref() {
+    Some("CANCELLED") => CancelOrderOutcome::Cancelled,
+    Some("COMPLETED") | Some("FAILED") => CancelOrderOutcome::AlreadyFinished,
+    Some("PROCESSING") => CancelOrderOutcome::NotCancellable,
+    _ => CancelOrderOutcome::CancellationRequested,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::error::seedance2pro_specific_api_error::Seedance2ProSpecificApiError;
+
+  fn classify(status: StatusCode, body: &str) -> Result<CancelOrderOutcome, Seedance2ProError> {
+    classify_cancel_response(status, body.to_string())
+  }
+
+  #[test]
+  fn pending_order_is_cancelled() {
+    let body = r#"[{"result":{"data":{"json":{"orderId":"ord_1","taskStatus":"CANCELLED"}}}}]"#;
+    assert_eq!(classify(StatusCode::OK, body).unwrap(), CancelOrderOutcome::Cancelled);
+  }
+
+  #[test]
+  fn unconfirmed_cancellation_is_pending() {
+    let body = r#"[{"result":{"data":{"json":{"success":true}}}}]"#;
+    assert_eq!(classify(StatusCode::OK, body).unwrap(), CancelOrderOutcome::CancellationRequested);
+  }
+
+  #[test]
+  fn finished_and_running_orders() {
+    let body = r#"[{"result":{"data":{"json":{"taskStatus":"COMPLETED"}}}}]"#;
+    assert_eq!(classify(StatusCode::OK, body).unwrap(), CancelOrderOutcome::AlreadyFinished);
+
+    let body = r#"[{"result":{"data":{"json":{"taskStatus":"PROCESSING"}}}}]"#;
+    assert_eq!(classify(StatusCode::OK, body).unwrap(), CancelOrderOutcome::NotCancellable);
+
+    let body = r#"[{"error":{"json":{"message":"Order is processing","code":-32009,"data":{"code":"CONFLICT","httpStatus":409}}}}]"#;
+    assert_eq!(classify(StatusCode::CONFLICT, body).unwrap(), CancelOrderOutcome::NotCancellable);
+  }
+
+  #[test]
+  fn unknown_order() {
+    let body = r#"[{"error":{"json":{"message":"Order not found","code":-32004,"data":{"code":"NOT_FOUND","httpStatus":404}}}}]"#;
+    assert_eq!(classify(StatusCode::NOT_FOUND, body).unwrap(), CancelOrderOutcome::NotFound);
+  }
+
+  #[test]
+  fn errors() {
+    assert!(matches!(classify(StatusCode::INTERNAL_SERVER_ERROR, "oops"),
+      Err(Seedance2ProError::ApiGeneric(Seedance2ProGenericApiError::UncategorizedBadResponseWithStatusAndBody { .. }))));
+    assert!(matches!(classify(StatusCode::BAD_REQUEST, "credits not enough"),
+      Err(Seedance2ProError::ApiSpecific(Seedance2ProSpecificApiError::BillingError { .. }))));
+    assert!(matches!(classify(StatusCode::OK, "<html>"),
+      Err(Seedance2ProError::ApiGeneric(Seedance2ProGenericApiError::UncategorizedBadResponse(_)))));
+  }
+}
@@ -0,0 +1,2 @@
+pub mod cancel_order;
+mod request_types;
@@ -0,0 +1,56 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug)]
+pub (super) struct BatchRequest {
+  #[serde(rename = "0")]
+  pub zero: BatchRequestInner,
+}
+
+#[derive(Serialize, Debug)]
+pub (super) struct BatchRequestInner {
+  pub json: BatchRequestJson,
+}
+
+#[derive(Serialize, Debug)]
+pub (super) struct BatchRequestJson {
+  #[serde(rename = "orderId")]
+  pub order_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub (super) struct BatchResponseItem {
+  pub result: Option<BatchResponseResult>,
+  pub error: Option<BatchResponseError>,
+}
+
+#[derive(Deserialize, Debug)]
+pub (super) struct BatchResponseResult {
+  pub data: BatchResponseData,
+}
+
+#[derive(Deserialize, Debug)]
+pub (super) struct BatchResponseData {
+  pub json: CancelOrderResponseJson,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub (super) struct CancelOrderResponseJson {
+  pub task_status: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub (super) struct BatchResponseError {
+  pub json: BatchResponseErrorJson,
+}
+
+#[derive(Deserialize, Debug)]
+pub (super) struct BatchResponseErrorJson {
+  pub data: Option<BatchResponseErrorData>,
+}
+
+/// tRPC error data, eg. `{"code":"NOT_FOUND","httpStatus":404}`.
+#[derive(Deserialize, Debug)]
+pub (super) struct BatchResponseErrorData {
+  pub code: Option<String>,
+}
@@ -1,5 +1,4 @@
 use crate::creds::seedance2pro_session::Seedance2ProSession;
-use crate::error::seedance2pro_client_error::Seedance2ProClientError;
 use crate::error::seedance2pro_error::Seedance2ProError;
 use crate::error::seedance2pro_generic_api_error::Seedance2ProGenericApiError;
 use crate::error::seedance2pro_specific_api_error::Seedance2ProSpecificApiError;
@@ -7,9 +6,8 @@ use crate::requests::generate_video::request_types::*;
 use crate::requests::kinovi_host::{KinoviHost, resolve_host};
 use crate::utils::categorize_seedance2pro_error::categorize_seedance2pro_error;
 use crate::utils::common_headers::FIREFOX_USER_AGENT;
+use crate::utils::shared_client::shared_client;
 use log::info;
-use wreq::Client;
-use wreq_util::Emulation;
 
 // --- Request args ---
 
@@ -358,10 +356,7 @@ pub async fn generate_video(args: GenerateVideoArgs<'_>) -> Result<GenerateVideo
 
   let cookie = args.session.cookies.as_str();
 
-  let client = Client::builder()
-    .emulation(Emulation::Firefox143)
-    .build()
-    .map_err(|err| Seedance2ProClientError::WreqClientError(err))?;
+  let client = shared_client();
 
   let referer = format!("{}/", base_url);
 
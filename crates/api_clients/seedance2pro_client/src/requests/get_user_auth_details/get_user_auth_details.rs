@@ -5,9 +5,8 @@ use crate::error::seedance2pro_generic_api_error::Seedance2ProGenericApiError;
 use crate::requests::get_user_auth_details::request_types::*;
 use crate::requests::kinovi_host::{KinoviHost, resolve_host};
 use crate::utils::common_headers::FIREFOX_USER_AGENT;
+use crate::utils::shared_client::shared_client;
 use log::info;
-use wreq::Client;
-use wreq_util::Emulation;
 
 // --- Args & response ---
 
@@ -40,10 +39,7 @@ pub async fn get_user_auth_details(
 
   info!("Fetching user auth details...");
 
-  let client = Client::builder()
-    .emulation(Emulation::Firefox143)
-    .build()
-    .map_err(|err| Seedance2ProClientError::WreqClientError(err))?;
+  let client = shared_client();
 
   let cookie = args.session.cookies.as_str();
   let referer = format!("{}/pricing", base_url);
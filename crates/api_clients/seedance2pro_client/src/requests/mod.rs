@@ -1,3 +1,4 @@
+pub mod cancel_order;
 pub mod generate_character;
 pub mod generate_video;
 pub mod workflow_run_task;
@@ -1,13 +1,11 @@
 use crate::creds::seedance2pro_session::Seedance2ProSession;
-use crate::error::seedance2pro_client_error::Seedance2ProClientError;
 use crate::error::seedance2pro_error::Seedance2ProError;
 use crate::error::seedance2pro_generic_api_error::Seedance2ProGenericApiError;
 use crate::requests::kinovi_host::{KinoviHost, resolve_host};
 use crate::requests::poll_characters::request_types::*;
 use crate::utils::common_headers::FIREFOX_USER_AGENT;
+use crate::utils::shared_client::shared_client;
 use log::info;
-use wreq::Client;
-use wreq_util::Emulation;
 
 // --- Public args ---
 
@@ -137,10 +135,7 @@ pub async fn poll_characters(args: PollCharactersArgs<'_>) -> Result<PollCharact
   let cookie = args.session.cookies.as_str();
   let referer = format!("{}/app/characters", base_url);
 
-  let client = Client::builder()
-    .emulation(Emulation::Firefox143)
-    .build()
-    .map_err(|err| Seedance2ProClientError::WreqClientError(err))?;
+  let client = shared_client();
 
   let response = client.get(&url)
     .header("User-Agent", FIREFOX_USER_AGENT)
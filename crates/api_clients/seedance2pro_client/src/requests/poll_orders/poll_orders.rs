@@ -6,10 +6,9 @@ use crate::requests::kinovi_host::{KinoviHost, resolve_host};
 use crate::requests::poll_orders::failure_reason::FailureReason;
 use crate::requests::poll_orders::request_types::*;
 use crate::utils::common_headers::FIREFOX_USER_AGENT;
+use crate::utils::shared_client::shared_client;
 use chrono::{DateTime, Utc};
 use log::info;
-use wreq::Client;
-use wreq_util::Emulation;
 
 
 // --- Args & response ---
@@ -46,6 +45,8 @@ pub enum TaskStatus {
   Completed,
   /// The task failed. `fail_reason` will contain the reason.
   Failed,
+  /// The task was cancelled before it finished (e.g. via `cancel_order`).
+  Cancelled,
   /// An unrecognised status string was returned by the server.
   Unknown(String),
 }
@@ -57,12 +58,13 @@ impl TaskStatus {
       "PROCESSING" => Self::Processing,
       "COMPLETED" => Self::Completed,
       "FAILED" => Self::Failed,
+      "CANCELLED" => Self::Cancelled,
       other => Self::Unknown(other.to_string()),
     }
   }
 
   pub fn is_terminal(&self) -> bool {
-    matches!(self, Self::Completed | Self::Failed)
+    matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
   }
 }
 
@@ -111,10 +113,7 @@ pub async fn poll_orders(args: PollOrdersArgs<'_>) -> Result<PollOrdersResponse,
 
   let input_json = build_input_json(args.cursor);
 
-  let client = Client::builder()
-    .emulation(Emulation::Firefox143)
-    .build()
-    .map_err(|err| Seedance2ProClientError::WreqClientError(err))?;
+  let client = shared_client();
 
   let cookie = args.session.cookies.as_str();
   let referer = format!("{}/app/gallery", base_url);
@@ -1,15 +1,13 @@
 use crate::creds::seedance2pro_session::Seedance2ProSession;
-use crate::error::seedance2pro_client_error::Seedance2ProClientError;
 use crate::error::seedance2pro_error::Seedance2ProError;
 use crate::error::seedance2pro_generic_api_error::Seedance2ProGenericApiError;
 use crate::requests::kinovi_host::{KinoviHost, resolve_host};
 use crate::requests::prepare_file_upload::request_types::*;
 use crate::utils::common_headers::FIREFOX_USER_AGENT;
+use crate::utils::shared_client::shared_client;
 use chrono::Utc;
 use log::info;
 use rand::Rng;
-use wreq::Client;
-use wreq_util::Emulation;
 
 /// Generates a material path based on the current time.
 /// Format: `materials/YYYYMMDD/<unix_millis>-<random_hex>.<extension>`
@@ -49,10 +47,7 @@ pub async fn prepare_file_upload(args: PrepareFileUploadArgs<'_>) -> Result<Prep
 
   info!("Preparing file upload with path: {}", material_path);
 
-  let client = Client::builder()
-    .emulation(Emulation::Firefox143)
-    .build()
-    .map_err(|err| Seedance2ProClientError::WreqClientError(err))?;
+  let client = shared_client();
 
   let request_body = BatchRequest {
     zero: BatchRequestInner {
@@ -3,10 +3,9 @@ use crate::error::seedance2pro_error::Seedance2ProError;
 use crate::error::seedance2pro_generic_api_error::Seedance2ProGenericApiError;
 use crate::requests::kinovi_host::{KinoviHost, resolve_host};
 use crate::utils::common_headers::FIREFOX_USER_AGENT;
+use crate::utils::shared_client::shared_client;
 use log::info;
 use url::Url;
-use wreq::Client;
-use wreq_util::Emulation;
 
 pub struct UploadFileArgs {
   /// The signed upload URL returned by `prepare_file_upload`.
@@ -41,10 +40,7 @@ pub async fn upload_file(args: UploadFileArgs) -> Result<UploadFileResponse, See
 
   info!("Uploading file to: {}", args.upload_url);
 
-  let client = Client::builder()
-    .emulation(Emulation::Firefox143)
-    .build()
-    .map_err(|err| Seedance2ProClientError::WreqClientError(err))?;
+  let client = shared_client();
 
   let referer = format!("{}/", base_url);
 
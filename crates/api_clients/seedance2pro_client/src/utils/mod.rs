@@ -1,2 +1,3 @@
 pub mod categorize_seedance2pro_error;
 pub mod common_headers;
+pub mod shared_client;
@@ -0,0 +1,35 @@
+use log::warn;
+use provider_http::http_client_config::HttpClientConfig;
+use provider_http::process_default::ProcessDefault;
+use wreq::Client;
+use wreq_util::Emulation;
+
+static SHARED_CLIENT: ProcessDefault<Client> = ProcessDefault::new(build_default_client);
+
+/// The pooled, Firefox-emulating client every Kinovi request goes through.
+///
+/// Session cookies are sent per request as a header, so sharing one client between
+/// accounts is safe.
+pub fn shared_client() -> Client {
+  SHARED_CLIENT.get().as_ref().clone()
+}
+
+/// Replace the shared client (eg. to add proxy settings). Pair with
+/// `KinoviHost::CustomHost` to talk to a local stand-in server.
+pub fn install_shared_client(client: Client) {
+  SHARED_CLIENT.set(client);
+}
+
+fn build_default_client() -> Client {
+  HttpClientConfig::from_env()
+    .apply_to_wreq_builder(Client::builder().emulation(Emulation::Firefox143))
+    .ok()
+    .and_then(|builder| builder.build().ok())
+    .unwrap_or_else(|| {
+      warn!("Ignoring bad provider HTTP settings for the Kinovi client.");
+      Client::builder()
+        .emulation(Emulation::Firefox143)
+        .build()
+        .unwrap_or_else(|_| Client::new())
+    })
+}
@@ -0,0 +1,158 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use clap::Args;
+use log::info;
+
+use artcraft_router::api::common_aspect_ratio::CommonAspectRatio;
+use artcraft_router::api::common_image_model::CommonImageModel;
+use artcraft_router::api::common_quality::CommonQuality;
+use artcraft_router::api::common_resolution::CommonResolution;
+use artcraft_router::api::provider::Provider;
+use artcraft_router::client::generation_mode_mismatch_strategy::GenerationModeMismatchStrategy;
+use artcraft_router::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+use artcraft_router::client::router_client::RouterClient;
+use artcraft_router::errors::artcraft_router_error::ArtcraftRouterError;
+use artcraft_router::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
+use artcraft_router::generate::generate_image::generate_image_response::GenerateImageResponse;
+use artcraft_router::generate::generate_image_v2::image_generation_draft_context::ImageGenerationDraftContext;
+use artcraft_router::generate::generate_image_v2::image_generation_draft_or_request::ImageGenerationDraftOrRequest;
+
+use crate::inputs::input_refs::InputRefs;
+use crate::inputs::upload_inputs::InputKind;
+use crate::outputs::pending_job::PendingJob;
+use crate::utils::cost_summary::CostSummary;
+use crate::utils::serde_names::parse_serde_name;
+use super::provider_session::ProviderSession;
+use super::state::ToolState;
+use super::submit_args::SubmitArgs;
+
+#[derive(Args)]
+#[command(after_help = "\
+EXAMPLES:
+  artcraft-router generate_image --model nano_banana_pro --prompt \"A corgi at the lake\"
+  artcraft-router generate_image --model gpt_image_1p5 --prompt \"Make it night\" --image photo.png --provider fal
+  artcraft-router generate_image --model flux_1_dev --prompt \"Test\" --count 4 --estimate-only
+")]
+pub struct GenerateImageArgs {
+  /// Image model, eg. "nano_banana_pro".
+  #[arg(long)]
+  pub model: String,
+
+  #[arg(long)]
+  pub prompt: Option<String>,
+
+  /// Input image (repeatable).
+  #[arg(long = "image")]
+  pub images: Vec<PathBuf>,
+
+  /// Eg. "square", "wide_sixteen_by_nine".
+  #[arg(long)]
+  pub aspect_ratio: Option<String>,
+
+  /// Eg. "one_k", "two_k".
+  #[arg(long)]
+  pub resolution: Option<String>,
+
+  /// "high", "medium" or "low".
+  #[arg(long)]
+  pub quality: Option<String>,
+
+  /// Number of images to generate.
+  #[arg(long)]
+  pub count: Option<u16>,
+
+  #[command(flatten)]
+  pub submit: SubmitArgs,
+}
+
+pub async fn run(state: &ToolState, args: GenerateImageArgs) -> anyhow::Result<()> {
+  let model: CommonImageModel = parse_serde_name(&args.model, "image model")?;
+  let provider: Provider = parse_serde_name(&args.submit.provider, "provider")?;
+
+  // NB: Estimates only look at how many inputs there are, so the local paths stand in for uploads.
+  let placeholder_inputs = InputRefs::Urls(args.images.iter()
+    .map(|path| path.to_string_lossy().to_string())
+    .collect());
+
+  let estimate = estimate_cost(build_request(&args, model, provider, &placeholder_inputs)?)
+    .map_err(|err| anyhow!("Can't plan this request: {}", err))?;
+  println!("Estimated cost: {}", estimate);
+
+  if args.submit.estimate_only {
+    return Ok(());
+  }
+
+  let session = ProviderSession::connect(state, provider)?;
+  let inputs = session.resolve_inputs(state, InputKind::Image, &args.images).await?;
+
+  let request = build_request(&args, model, provider, &inputs)?;
+
+  info!("Submitting {:?} via {:?} ...", model, provider);
+
+  let response = submit(request, &session.client).await?;
+
+  session.follow_job(state, PendingJob::from_image_response(&response), args.submit.no_wait).await
+}
+
+fn build_request(
+  args: &GenerateImageArgs,
+  model: CommonImageModel,
+  provider: Provider,
+  inputs: &InputRefs,
+) -> anyhow::Result<GenerateImageRequestBuilder> {
+  Ok(GenerateImageRequestBuilder {
+    model,
+    provider,
+    prompt: args.prompt.clone(),
+    image_inputs: inputs.image_list(),
+    resolution: args.resolution.as_deref()
+      .map(|value| parse_serde_name::<CommonResolution>(value, "resolution"))
+      .transpose()?,
+    aspect_ratio: args.aspect_ratio.as_deref()
+      .map(|value| parse_serde_name::<CommonAspectRatio>(value, "aspect ratio"))
+      .transpose()?,
+    quality: args.quality.as_deref()
+      .map(|value| parse_serde_name::<CommonQuality>(value, "quality"))
+      .transpose()?,
+    image_batch_count: args.count,
+    horizontal_angle: None,
+    vertical_angle: None,
+    zoom: None,
+    request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+    generation_mode_mismatch_strategy: Some(GenerationModeMismatchStrategy::GenerateAnyway),
+    idempotency_token: args.submit.idempotency_token.clone(),
+  })
+}
+
+pub fn estimate_cost(request: GenerateImageRequestBuilder) -> Result<CostSummary, ArtcraftRouterError> {
+  let estimate = if request.use_new_builder() {
+    request.build2()
+      .and_then(|draft_or_request| draft_or_request.estimate_cost())
+  } else {
+    request.build()
+      .map(|plan| plan.estimate_costs())
+  };
+  estimate.map(CostSummary::from)
+}
+
+async fn submit(request: GenerateImageRequestBuilder, client: &RouterClient) -> anyhow::Result<GenerateImageResponse> {
+  let result = if request.use_new_builder() {
+    match request.build2() {
+      Err(err) => Err(err),
+      Ok(ImageGenerationDraftOrRequest::Request(request)) => request.send_request(client).await,
+      Ok(ImageGenerationDraftOrRequest::Draft(draft)) => {
+        match draft.finalize(ImageGenerationDraftContext::default()).await {
+          Ok(request) => request.send_request(client).await,
+          Err(err) => Err(err),
+        }
+      }
+    }
+  } else {
+    match request.build() {
+      Ok(plan) => plan.generate_image(client).await,
+      Err(err) => Err(err),
+    }
+  };
+  result.map_err(|err| anyhow!("Image generation failed: {}", err))
+}
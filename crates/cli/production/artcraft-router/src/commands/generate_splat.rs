@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use clap::Args;
+use log::info;
+
+use artcraft_router::api::common_splat_model::CommonSplatModel;
+use artcraft_router::api::provider::Provider;
+use artcraft_router::errors::artcraft_router_error::ArtcraftRouterError;
+use artcraft_router::generate::generate_splat::generate_splat_request::GenerateSplatRequest;
+
+use crate::inputs::input_refs::InputRefs;
+use crate::inputs::upload_inputs::InputKind;
+use crate::outputs::pending_job::PendingJob;
+use crate::utils::cost_summary::CostSummary;
+use crate::utils::serde_names::parse_serde_name;
+use super::provider_session::ProviderSession;
+use super::state::ToolState;
+use super::submit_args::SubmitArgs;
+
+#[derive(Args)]
+#[command(after_help = "\
+EXAMPLES:
+  artcraft-router generate_splat --model marble_0p1_mini --prompt \"A cozy cabin interior\"
+  artcraft-router generate_splat --model marble_0p1_plus --reference-image room.png
+  artcraft-router generate_splat --model marble_0p1_plus --prompt \"Test\" --estimate-only
+")]
+pub struct GenerateSplatArgs {
+  /// Splat model, eg. "marble_0p1_mini".
+  #[arg(long)]
+  pub model: String,
+
+  #[arg(long)]
+  pub prompt: Option<String>,
+
+  /// Reference image (repeatable).
+  #[arg(long = "reference-image")]
+  pub reference_images: Vec<PathBuf>,
+
+  #[command(flatten)]
+  pub submit: SubmitArgs,
+}
+
+pub async fn run(state: &ToolState, args: GenerateSplatArgs) -> anyhow::Result<()> {
+  let model: CommonSplatModel = parse_serde_name(&args.model, "splat model")?;
+  let provider: Provider = parse_serde_name(&args.submit.provider, "provider")?;
+
+  // NB: Estimates only look at how many inputs there are, so the local paths stand in for uploads.
+  let placeholder_inputs = InputRefs::Urls(args.reference_images.iter()
+    .map(|path| path.to_string_lossy().to_string())
+    .collect());
+
+  let estimate = estimate_cost(&build_request(&args, model, provider, &placeholder_inputs))
+    .map_err(|err| anyhow!("Can't plan this request: {}", err))?;
+  println!("Estimated cost: {}", estimate);
+
+  if args.submit.estimate_only {
+    return Ok(());
+  }
+
+  let session = ProviderSession::connect(state, provider)?;
+  let inputs = session.resolve_inputs(state, InputKind::Image, &args.reference_images).await?;
+
+  let request = build_request(&args, model, provider, &inputs);
+
+  info!("Submitting {:?} via {:?} ...", model, provider);
+
+  let response = request.build()
+    .map_err(|err| anyhow!("Can't plan this request: {}", err))?
+    .generate_splat(&session.client)
+    .await
+    .map_err(|err| anyhow!("Splat generation failed: {}", err))?;
+
+  session.follow_job(state, PendingJob::from_splat_response(&response), args.submit.no_wait).await
+}
+
+fn build_request(
+  args: &GenerateSplatArgs,
+  model: CommonSplatModel,
+  provider: Provider,
+  inputs: &InputRefs,
+) -> GenerateSplatRequest {
+  GenerateSplatRequest {
+    model,
+    provider,
+    prompt: args.prompt.clone(),
+    reference_images: inputs.image_list(),
+    idempotency_token: args.submit.idempotency_token.clone(),
+  }
+}
+
+pub fn estimate_cost(request: &GenerateSplatRequest) -> Result<CostSummary, ArtcraftRouterError> {
+  request.build()
+    .map(|plan| CostSummary::from(plan.estimate_costs()))
+}
@@ -0,0 +1,210 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use clap::Args;
+use log::info;
+
+use artcraft_router::api::common_aspect_ratio::CommonAspectRatio;
+use artcraft_router::api::common_resolution::CommonResolution;
+use artcraft_router::api::common_video_model::CommonVideoModel;
+use artcraft_router::api::provider::Provider;
+use artcraft_router::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+use artcraft_router::client::router_client::RouterClient;
+use artcraft_router::errors::artcraft_router_error::ArtcraftRouterError;
+use artcraft_router::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+use artcraft_router::generate::generate_video::generate_video_response::GenerateVideoResponse;
+use artcraft_router::generate::generate_video_v2::video_generation_draft_context::VideoGenerationDraftContext;
+use artcraft_router::generate::generate_video_v2::video_generation_draft_or_request::VideoGenerationDraftOrRequest;
+
+use crate::inputs::input_refs::InputRefs;
+use crate::inputs::upload_inputs::InputKind;
+use crate::outputs::pending_job::PendingJob;
+use crate::utils::cost_summary::CostSummary;
+use crate::utils::serde_names::parse_serde_name;
+use super::provider_session::ProviderSession;
+use super::state::ToolState;
+use super::submit_args::SubmitArgs;
+
+#[derive(Args)]
+#[command(after_help = "\
+EXAMPLES:
+  artcraft-router generate_video --model seedance_2p0 --prompt \"A corgi at the lake\" --duration 5
+  artcraft-router generate_video --model kling_2p6_pro --prompt \"Dancing\" --start-frame frame.png
+  artcraft-router generate_video --model veo_3p1 --prompt \"Test\" --estimate-only
+")]
+pub struct GenerateVideoArgs {
+  /// Video model, eg. "seedance_2p0".
+  #[arg(long)]
+  pub model: String,
+
+  #[arg(long)]
+  pub prompt: Option<String>,
+
+  #[arg(long)]
+  pub negative_prompt: Option<String>,
+
+  /// Start frame image.
+  #[arg(long)]
+  pub start_frame: Option<PathBuf>,
+
+  /// End frame image.
+  #[arg(long)]
+  pub end_frame: Option<PathBuf>,
+
+  /// Reference image (repeatable).
+  #[arg(long = "reference-image")]
+  pub reference_images: Vec<PathBuf>,
+
+  /// Reference video (repeatable).
+  #[arg(long = "reference-video")]
+  pub reference_videos: Vec<PathBuf>,
+
+  /// Reference audio (repeatable).
+  #[arg(long = "reference-audio")]
+  pub reference_audio: Vec<PathBuf>,
+
+  /// Eg. "wide_sixteen_by_nine".
+  #[arg(long)]
+  pub aspect_ratio: Option<String>,
+
+  /// Eg. "seven_twenty_p".
+  #[arg(long)]
+  pub resolution: Option<String>,
+
+  /// Duration in seconds.
+  #[arg(long)]
+  pub duration: Option<u16>,
+
+  /// Number of videos to generate.
+  #[arg(long)]
+  pub count: Option<u16>,
+
+  /// Turn generated audio on or off, for models that support it.
+  #[arg(long)]
+  pub generate_audio: Option<bool>,
+
+  #[command(flatten)]
+  pub submit: SubmitArgs,
+}
+
+/// Input files, resolved per slot.
+struct VideoInputs {
+  start_frame: InputRefs,
+  end_frame: InputRefs,
+  reference_images: InputRefs,
+  reference_videos: InputRefs,
+  reference_audio: InputRefs,
+}
+
+pub async fn run(state: &ToolState, args: GenerateVideoArgs) -> anyhow::Result<()> {
+  let model: CommonVideoModel = parse_serde_name(&args.model, "video model")?;
+  let provider: Provider = parse_serde_name(&args.submit.provider, "provider")?;
+
+  // NB: Estimates only look at which inputs are present, so the local paths stand in for uploads.
+  let placeholder_inputs = VideoInputs {
+    start_frame: local_paths(args.start_frame.as_slice()),
+    end_frame: local_paths(args.end_frame.as_slice()),
+    reference_images: local_paths(&args.reference_images),
+    reference_videos: local_paths(&args.reference_videos),
+    reference_audio: local_paths(&args.reference_audio),
+  };
+
+  let estimate = estimate_cost(build_request(&args, model, provider, &placeholder_inputs)?)
+    .map_err(|err| anyhow!("Can't plan this request: {}", err))?;
+  println!("Estimated cost: {}", estimate);
+
+  if args.submit.estimate_only {
+    return Ok(());
+  }
+
+  let session = ProviderSession::connect(state, provider)?;
+
+  let inputs = VideoInputs {
+    start_frame: session.resolve_inputs(state, InputKind::Image, args.start_frame.as_slice()).await?,
+    end_frame: session.resolve_inputs(state, InputKind::Image, args.end_frame.as_slice()).await?,
+    reference_images: session.resolve_inputs(state, InputKind::Image, &args.reference_images).await?,
+    reference_videos: session.resolve_inputs(state, InputKind::Video, &args.reference_videos).await?,
+    reference_audio: session.resolve_inputs(state, InputKind::Audio, &args.reference_audio).await?,
+  };
+
+  let request = build_request(&args, model, provider, &inputs)?;
+
+  info!("Submitting {:?} via {:?} ...", model, provider);
+
+  let response = submit(request, &session.client).await?;
+
+  session.follow_job(state, PendingJob::from_video_response(&response), args.submit.no_wait).await
+}
+
+fn local_paths(paths: &[PathBuf]) -> InputRefs {
+  InputRefs::Urls(paths.iter()
+    .map(|path| path.to_string_lossy().to_string())
+    .collect())
+}
+
+fn build_request(
+  args: &GenerateVideoArgs,
+  model: CommonVideoModel,
+  provider: Provider,
+  inputs: &VideoInputs,
+) -> anyhow::Result<GenerateVideoRequestBuilder> {
+  Ok(GenerateVideoRequestBuilder {
+    model,
+    provider,
+    prompt: args.prompt.clone(),
+    negative_prompt: args.negative_prompt.clone(),
+    start_frame: inputs.start_frame.first_image(),
+    end_frame: inputs.end_frame.first_image(),
+    reference_images: inputs.reference_images.image_list(),
+    reference_videos: inputs.reference_videos.video_list(),
+    reference_audio: inputs.reference_audio.audio_list(),
+    reference_character_tokens: None,
+    resolution: args.resolution.as_deref()
+      .map(|value| parse_serde_name::<CommonResolution>(value, "resolution"))
+      .transpose()?,
+    aspect_ratio: args.aspect_ratio.as_deref()
+      .map(|value| parse_serde_name::<CommonAspectRatio>(value, "aspect ratio"))
+      .transpose()?,
+    duration_seconds: args.duration,
+    video_batch_count: args.count,
+    generate_audio: args.generate_audio,
+    request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+    idempotency_token: args.submit.idempotency_token.clone(),
+  })
+}
+
+pub fn estimate_cost(request: GenerateVideoRequestBuilder) -> Result<CostSummary, ArtcraftRouterError> {
+  let estimate = if request.use_new_builder() {
+    request.build2()
+      .and_then(|draft_or_request| draft_or_request.estimate_cost())
+  } else {
+    request.build()
+      .map(|plan| plan.estimate_costs())
+  };
+  estimate.map(CostSummary::from)
+}
+
+async fn submit(request: GenerateVideoRequestBuilder, client: &RouterClient) -> anyhow::Result<GenerateVideoResponse> {
+  let result = if request.use_new_builder() {
+    match request.build2() {
+      Err(err) => Err(err),
+      Ok(VideoGenerationDraftOrRequest::Request(request)) => request.send_request(client).await,
+      Ok(VideoGenerationDraftOrRequest::Draft(draft)) => {
+        let draft_context = VideoGenerationDraftContext {
+          client: Some(client),
+          ..Default::default()
+        };
+        match draft.finalize(draft_context).await {
+          Ok(request) => request.send_request(client).await,
+          Err(err) => Err(err),
+        }
+      }
+    }
+  } else {
+    match request.build() {
+      Ok(plan) => plan.generate_video(client).await,
+      Err(err) => Err(err),
+    }
+  };
+  result.map_err(|err| anyhow!("Video generation failed: {}", err))
+}
@@ -0,0 +1,122 @@
+use anyhow::anyhow;
+use clap::Args;
+
+use artcraft_router::api::common_image_model::CommonImageModel;
+use artcraft_router::api::common_splat_model::CommonSplatModel;
+use artcraft_router::api::common_video_model::CommonVideoModel;
+use artcraft_router::api::provider::Provider;
+use artcraft_router::client::generation_mode_mismatch_strategy::GenerationModeMismatchStrategy;
+use artcraft_router::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+use artcraft_router::errors::artcraft_router_error::ArtcraftRouterError;
+use artcraft_router::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
+use artcraft_router::generate::generate_splat::generate_splat_request::GenerateSplatRequest;
+use artcraft_router::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+
+use crate::utils::cost_summary::CostSummary;
+use crate::utils::serde_names::{parse_serde_name, serde_name};
+use super::generate_image;
+use super::generate_splat;
+use super::generate_video;
+
+/// Prompt used to plan the default request for each model.
+const PLACEHOLDER_PROMPT: &str = "A corgi at the lake";
+
+#[derive(Args)]
+#[command(after_help = "\
+EXAMPLES:
+  artcraft-router list_models
+  artcraft-router list_models --kind video --provider artcraft
+")]
+pub struct ListModelsArgs {
+  /// Only list "image", "video" or "splat" models.
+  #[arg(long)]
+  pub kind: Option<String>,
+
+  /// Only list models for this provider, eg. "artcraft".
+  #[arg(long)]
+  pub provider: Option<String>,
+}
+
+pub fn run(args: ListModelsArgs) -> anyhow::Result<()> {
+  let maybe_provider: Option<Provider> = args.provider.as_deref()
+    .map(|value| parse_serde_name(value, "provider"))
+    .transpose()?;
+
+  let (list_images, list_videos, list_splats) = match args.kind.as_deref() {
+    None => (true, true, true),
+    Some("image") => (true, false, false),
+    Some("video") => (false, true, false),
+    Some("splat") => (false, false, true),
+    Some(other) => return Err(anyhow!("Unknown kind '{}'. Use 'image', 'video' or 'splat'.", other)),
+  };
+
+  let providers: Vec<Provider> = match maybe_provider {
+    Some(provider) => vec![provider],
+    None => Provider::ALL.to_vec(),
+  };
+
+  println!("{:<6}  {:<24}  {:<12}  {}", "KIND", "MODEL", "PROVIDER", "DEFAULT COST");
+
+  for &provider in providers.iter() {
+    if list_images {
+      for &model in CommonImageModel::ALL {
+        let request = GenerateImageRequestBuilder {
+          model,
+          provider,
+          prompt: Some(PLACEHOLDER_PROMPT.to_string()),
+          image_inputs: None,
+          resolution: None,
+          aspect_ratio: None,
+          quality: None,
+          image_batch_count: None,
+          horizontal_angle: None,
+          vertical_angle: None,
+          zoom: None,
+          request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+          generation_mode_mismatch_strategy: Some(GenerationModeMismatchStrategy::GenerateAnyway),
+          idempotency_token: None,
+        };
+        print_row("image", &serde_name(&model), provider, generate_image::estimate_cost(request));
+      }
+    }
+
+    if list_videos {
+      for &model in CommonVideoModel::ALL {
+        let request = GenerateVideoRequestBuilder {
+          model,
+          provider,
+          prompt: Some(PLACEHOLDER_PROMPT.to_string()),
+          ..Default::default()
+        };
+        print_row("video", &serde_name(&model), provider, generate_video::estimate_cost(request));
+      }
+    }
+
+    if list_splats {
+      for &model in CommonSplatModel::ALL {
+        let request = GenerateSplatRequest {
+          model,
+          provider,
+          prompt: Some(PLACEHOLDER_PROMPT.to_string()),
+          reference_images: None,
+          idempotency_token: None,
+        };
+        print_row("splat", &serde_name(&model), provider, generate_splat::estimate_cost(&request));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Print one model/provider pair, skipping combinations the router can't plan at all.
+fn print_row(kind: &str, model: &str, provider: Provider, estimate: Result<CostSummary, ArtcraftRouterError>) {
+  let cost = match estimate {
+    Ok(cost) => cost.to_string(),
+    Err(ArtcraftRouterError::UnsupportedModel(_)) => return,
+    Err(ArtcraftRouterError::UnsupportedProviderAndModelForNewApi(_)) => return,
+    // NB: The combination exists, but the default request is missing something (eg. a start frame).
+    Err(_) => "(needs inputs)".to_string(),
+  };
+  println!("{:<6}  {:<24}  {:<12}  {}", kind, model, serde_name(&provider), cost);
+}
@@ -0,0 +1,9 @@
+pub mod generate_image;
+pub mod generate_splat;
+pub mod generate_video;
+pub mod list_models;
+pub mod run;
+
+mod provider_session;
+mod state;
+mod submit_args;
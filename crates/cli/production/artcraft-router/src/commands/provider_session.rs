@@ -0,0 +1,175 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use log::{info, warn};
+
+use artcraft_client::credentials::storyteller_credential_set::StorytellerCredentialSet;
+use artcraft_router::api::provider::Provider;
+use artcraft_router::client::multi_router_client_builder::MultiRouterClientBuilder;
+use artcraft_router::client::router_artcraft_client::RouterArtcraftClient;
+use artcraft_router::client::router_client::RouterClient;
+use artcraft_router::client::router_fal_client::RouterFalClient;
+use artcraft_router::client::router_fal_webhook_optional_client::RouterFalWebhookOptionalClient;
+use artcraft_router::client::router_mock_client::RouterMockClient;
+use artcraft_router::mock::mock_provider_config::MockProviderConfig;
+
+use crate::credentials::artcraft_credentials::load_artcraft_credentials;
+use crate::credentials::fal_api_key::load_fal_api_key;
+use crate::inputs::input_refs::InputRefs;
+use crate::inputs::upload_inputs::{upload_inputs, InputKind, InputTarget};
+use crate::outputs::pending_job::PendingJob;
+use crate::outputs::save_outputs::save_outputs;
+use crate::outputs::wait_for_outputs::{wait_for_outputs, WaitContext};
+use super::state::ToolState;
+
+/// Some older FAL plans submit through webhook endpoints and need somewhere to deliver results.
+const FAL_WEBHOOK_URL_ENV_VAR: &str = "FAL_WEBHOOK_URL";
+
+/// A router client for one provider, plus the credentials we need to upload inputs and poll jobs.
+pub struct ProviderSession {
+  pub provider: Provider,
+  pub client: RouterClient,
+  maybe_artcraft_creds: Option<StorytellerCredentialSet>,
+  maybe_fal_api_key: Option<String>,
+}
+
+impl ProviderSession {
+  pub fn connect(state: &ToolState, provider: Provider) -> anyhow::Result<Self> {
+    match provider {
+      Provider::Artcraft => {
+        let creds = load_artcraft_credentials(&state.credentials_dir)?;
+        Ok(Self {
+          provider,
+          client: RouterClient::Artcraft(RouterArtcraftClient::new(state.api_host.clone(), creds.clone())),
+          maybe_artcraft_creds: Some(creds),
+          maybe_fal_api_key: None,
+        })
+      }
+      Provider::Fal => {
+        let api_key = load_fal_api_key(&state.credentials_dir)?;
+
+        let mut builder = MultiRouterClientBuilder::new()
+          .set_fal_webhook_optional_client(RouterFalWebhookOptionalClient::from_str(&api_key));
+
+        if let Ok(webhook_url) = std::env::var(FAL_WEBHOOK_URL_ENV_VAR) {
+          builder = builder.set_fal_client(RouterFalClient::new_from_raw_key(&api_key, webhook_url));
+        }
+
+        // NB: Local inputs are hosted on Artcraft so FAL can fetch them. That's optional.
+        let maybe_artcraft_creds = match load_artcraft_credentials(&state.credentials_dir) {
+          Ok(creds) => Some(creds),
+          Err(err) => {
+            info!("No Artcraft credentials; input files can't be uploaded: {}", err);
+            None
+          }
+        };
+
+        Ok(Self {
+          provider,
+          client: RouterClient::Multi(builder.build()),
+          maybe_artcraft_creds,
+          maybe_fal_api_key: Some(api_key),
+        })
+      }
+      Provider::Mock => {
+        Ok(Self {
+          provider,
+          client: RouterClient::Mock(RouterMockClient::new(MockProviderConfig::default())),
+          maybe_artcraft_creds: None,
+          maybe_fal_api_key: None,
+        })
+      }
+      _ => Err(anyhow!(
+        "Provider {:?} isn't supported by artcraft-router yet. Use artcraft, fal or mock.",
+        provider
+      )),
+    }
+  }
+
+  /// Turn local input files into references the provider can read.
+  pub async fn resolve_inputs(
+    &self,
+    state: &ToolState,
+    kind: InputKind,
+    paths: &[PathBuf],
+  ) -> anyhow::Result<InputRefs> {
+    if paths.is_empty() {
+      return Ok(InputRefs::Urls(Vec::new()));
+    }
+
+    let target = match (self.provider, self.maybe_artcraft_creds.as_ref()) {
+      (Provider::Mock, _) => InputTarget::LocalPaths,
+      (Provider::Artcraft, Some(creds)) => InputTarget::ArtcraftTokens(creds),
+      (_, Some(creds)) => InputTarget::ArtcraftCdnUrls(creds),
+      (provider, None) => {
+        return Err(anyhow!(
+          "Input files are uploaded to Artcraft before they're sent to {:?}, which needs Artcraft credentials.",
+          provider
+        ));
+      }
+    };
+
+    upload_inputs(&state.api_host, target, kind, paths).await
+  }
+
+  /// Print the job ids, then (unless `no_wait`) wait for the results and download them.
+  pub async fn follow_job(&self, state: &ToolState, job: PendingJob, no_wait: bool) -> anyhow::Result<()> {
+    let job_ids = job.job_ids();
+
+    for job_id in job_ids.iter() {
+      println!("Submitted: {}", job_id);
+    }
+
+    if no_wait {
+      return Ok(());
+    }
+
+    if let PendingJob::Untracked { provider, .. } = &job {
+      warn!("Can't poll {:?} jobs from this tool; check the provider for results.", provider);
+      return Ok(());
+    }
+
+    let context = WaitContext {
+      api_host: &state.api_host,
+      client: &self.client,
+      maybe_artcraft_creds: self.maybe_artcraft_creds.as_ref(),
+      maybe_fal_api_key: self.maybe_fal_api_key.as_deref(),
+      poll_interval: state.poll_interval,
+      timeout: state.timeout,
+    };
+
+    let outputs = wait_for_outputs(&context, &job).await?;
+
+    if outputs.is_empty() {
+      return Err(anyhow!("The generation finished without any outputs"));
+    }
+
+    let prefix = sanitize_prefix(job_ids.first().map(|id| id.as_str()).unwrap_or("output"));
+    let saved = save_outputs(&state.output_dir, &prefix, &outputs).await?;
+
+    for path in saved.iter() {
+      println!("Saved: {}", path.display());
+    }
+
+    Ok(())
+  }
+}
+
+/// Keep only filename-safe characters from a job id.
+fn sanitize_prefix(job_id: &str) -> String {
+  job_id.chars()
+    .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sanitize_job_ids_for_filenames() {
+    assert_eq!(sanitize_prefix("jinf_abc123"), "jinf_abc123");
+    assert_eq!(sanitize_prefix("0b6e-42f1"), "0b6e-42f1");
+    assert_eq!(sanitize_prefix("a:b c"), "a_b_c");
+  }
+}
@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use clap::{Args, Parser, Subcommand};
+use log::info;
+
+use artcraft_client::utils::api_host::ApiHost;
+
+use crate::credentials::credentials_dir::default_credentials_dir;
+use super::generate_image;
+use super::generate_splat;
+use super::generate_video;
+use super::list_models;
+use super::state::ToolState;
+
+#[derive(Parser)]
+#[command(name = "artcraft-router", about = "Estimate, submit and download generations through the Artcraft router")]
+pub struct Cli {
+  #[command(flatten)]
+  pub global: GlobalArgs,
+
+  #[command(subcommand)]
+  pub command: Command,
+}
+
+#[derive(Args)]
+pub struct GlobalArgs {
+  /// Target environment: "dev" / "development" (localhost:12345) or "prod" / "production" (api.storyteller.ai).
+  #[arg(long, global = true, default_value = "prod")]
+  pub environment: String,
+
+  /// The desktop app's credential directory. [default: ~/Artcraft/credentials]
+  #[arg(long, global = true)]
+  pub credentials_dir: Option<PathBuf>,
+
+  /// Where downloaded results are written.
+  #[arg(long, global = true, default_value = ".")]
+  pub output_dir: PathBuf,
+
+  /// Seconds between job status checks.
+  #[arg(long, global = true, default_value_t = 5)]
+  pub poll_interval_seconds: u64,
+
+  /// Give up waiting for results after this many seconds.
+  #[arg(long, global = true, default_value_t = 1800)]
+  pub timeout_seconds: u64,
+}
+
+#[derive(Subcommand)]
+#[command(rename_all = "snake_case")]
+pub enum Command {
+  /// List the models each provider supports, with the cost of a default request
+  ListModels(list_models::ListModelsArgs),
+
+  /// Generate images
+  GenerateImage(generate_image::GenerateImageArgs),
+
+  /// Generate videos
+  GenerateVideo(generate_video::GenerateVideoArgs),
+
+  /// Generate gaussian splats
+  GenerateSplat(generate_splat::GenerateSplatArgs),
+}
+
+pub async fn run(cli: Cli) -> anyhow::Result<()> {
+  let state = build_state(cli.global)?;
+
+  match cli.command {
+    Command::ListModels(args) => list_models::run(args),
+    Command::GenerateImage(args) => generate_image::run(&state, args).await,
+    Command::GenerateVideo(args) => generate_video::run(&state, args).await,
+    Command::GenerateSplat(args) => generate_splat::run(&state, args).await,
+  }
+}
+
+fn build_state(args: GlobalArgs) -> anyhow::Result<ToolState> {
+  let credentials_dir = match args.credentials_dir {
+    Some(dir) => dir,
+    None => default_credentials_dir()?,
+  };
+
+  Ok(ToolState {
+    api_host: parse_api_host(&args.environment)?,
+    credentials_dir,
+    output_dir: args.output_dir,
+    poll_interval: Duration::from_secs(args.poll_interval_seconds.max(1)),
+    timeout: Duration::from_secs(args.timeout_seconds),
+  })
+}
+
+fn parse_api_host(environment: &str) -> anyhow::Result<ApiHost> {
+  match environment {
+    "dev" | "development" => {
+      info!("Environment: development (localhost:12345)");
+      Ok(ApiHost::Localhost { port: 12345 })
+    }
+    "prod" | "production" => {
+      info!("Environment: production (api.storyteller.ai)");
+      Ok(ApiHost::Storyteller)
+    }
+    other => Err(anyhow!(
+      "Unknown environment '{}'. Use 'dev', 'development', 'prod', or 'production'.",
+      other
+    )),
+  }
+}
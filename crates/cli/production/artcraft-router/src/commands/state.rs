@@ -0,0 +1,12 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use artcraft_client::utils::api_host::ApiHost;
+
+pub struct ToolState {
+  pub api_host: ApiHost,
+  pub credentials_dir: PathBuf,
+  pub output_dir: PathBuf,
+  pub poll_interval: Duration,
+  pub timeout: Duration,
+}
@@ -0,0 +1,21 @@
+use clap::Args;
+
+/// Flags shared by the `generate_*` subcommands.
+#[derive(Args)]
+pub struct SubmitArgs {
+  /// Provider to route through. Run `list_models` to see which providers support a model.
+  #[arg(long, default_value = "artcraft")]
+  pub provider: String,
+
+  /// Print the cost estimate and exit without uploading or submitting anything.
+  #[arg(long)]
+  pub estimate_only: bool,
+
+  /// Print the job ids after submitting instead of waiting for the results.
+  #[arg(long)]
+  pub no_wait: bool,
+
+  /// Idempotency token (auto-generated if not specified).
+  #[arg(long)]
+  pub idempotency_token: Option<String>,
+}
@@ -0,0 +1,59 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::anyhow;
+use log::info;
+
+use artcraft_client::credentials::storyteller_avt_cookie::StorytellerAvtCookie;
+use artcraft_client::credentials::storyteller_credential_set::StorytellerCredentialSet;
+use artcraft_client::credentials::storyteller_session_cookie::StorytellerSessionCookie;
+
+/// A full cookie header, eg. "session=...; visitor=...".
+const COOKIES_ENV_VAR: &str = "ARTCRAFT_COOKIES";
+
+/// NB: These are the desktop app's file names. They're stored in plaintext.
+const SESSION_COOKIE_FILE: &str = "artcraft_session.txt";
+const AVT_COOKIE_FILE: &str = "artcraft_avt.txt";
+
+/// Read Artcraft credentials from `ARTCRAFT_COOKIES`, falling back to the desktop app's login.
+pub fn load_artcraft_credentials(credentials_dir: &Path) -> anyhow::Result<StorytellerCredentialSet> {
+  if let Ok(cookies) = std::env::var(COOKIES_ENV_VAR) {
+    info!("Reading Artcraft credentials from {}", COOKIES_ENV_VAR);
+    return StorytellerCredentialSet::parse_multi_cookie_header(cookies.trim())
+      .map_err(|err| anyhow!("Failed to parse {}: {:?}", COOKIES_ENV_VAR, err))?
+      .ok_or_else(|| anyhow!("{} contained no recognized cookies (expected session=... and/or visitor=...)", COOKIES_ENV_VAR));
+  }
+
+  info!("Reading Artcraft credentials from {:?}", credentials_dir);
+
+  let session = read_trimmed(&credentials_dir.join(SESSION_COOKIE_FILE))?
+    .map(StorytellerSessionCookie::new);
+
+  let avt = read_trimmed(&credentials_dir.join(AVT_COOKIE_FILE))?
+    .map(StorytellerAvtCookie::new);
+
+  let creds = StorytellerCredentialSet::initialize(avt, session);
+
+  if creds.is_empty() {
+    return Err(anyhow!(
+      "No Artcraft credentials found. Log in with the desktop app, point --credentials-dir at its \
+       credential directory, or set {} to a cookie header.",
+      COOKIES_ENV_VAR
+    ));
+  }
+
+  Ok(creds)
+}
+
+fn read_trimmed(path: &Path) -> anyhow::Result<Option<String>> {
+  if !path.exists() {
+    return Ok(None);
+  }
+  let contents = fs::read_to_string(path)
+    .map_err(|err| anyhow!("Failed to read {:?}: {}", path, err))?;
+  let contents = contents.trim();
+  if contents.is_empty() {
+    return Ok(None);
+  }
+  Ok(Some(contents.to_string()))
+}
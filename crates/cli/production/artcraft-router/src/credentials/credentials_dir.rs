@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use directories::UserDirs;
+
+/// NB: Keep in sync with the desktop app's data directory layout.
+const DESKTOP_DATA_DIR: &str = "Artcraft";
+const DESKTOP_CREDENTIALS_DIR: &str = "credentials";
+
+/// The desktop app's credential directory (eg. `~/Artcraft/credentials`).
+pub fn default_credentials_dir() -> anyhow::Result<PathBuf> {
+  Ok(UserDirs::new()
+    .ok_or_else(|| anyhow!("could not determine user home directory"))?
+    .home_dir()
+    .join(DESKTOP_DATA_DIR)
+    .join(DESKTOP_CREDENTIALS_DIR))
+}
@@ -0,0 +1,47 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::anyhow;
+use log::info;
+
+const FAL_API_KEY_ENV_VAR: &str = "FAL_API_KEY";
+
+/// NB: The desktop app's credential vault file name (plus the pre-vault name).
+const FAL_API_KEY_FILES: &[&str] = &["fal.api_key.txt", "fal_api_key.txt"];
+
+/// The desktop app's vault encrypts files with this suffix, using a key we can't read.
+const ENCRYPTED_SUFFIX: &str = ".enc";
+
+/// Read the FAL API key from `FAL_API_KEY`, falling back to a plaintext desktop credential file.
+pub fn load_fal_api_key(credentials_dir: &Path) -> anyhow::Result<String> {
+  if let Ok(api_key) = std::env::var(FAL_API_KEY_ENV_VAR) {
+    let api_key = api_key.trim();
+    if !api_key.is_empty() {
+      info!("Reading FAL API key from {}", FAL_API_KEY_ENV_VAR);
+      return Ok(api_key.to_string());
+    }
+  }
+
+  for filename in FAL_API_KEY_FILES {
+    let path = credentials_dir.join(filename);
+    if path.exists() {
+      info!("Reading FAL API key from {:?}", path);
+      let contents = fs::read_to_string(&path)
+        .map_err(|err| anyhow!("Failed to read {:?}: {}", path, err))?;
+      return Ok(contents.trim().to_string());
+    }
+  }
+
+  let encrypted = FAL_API_KEY_FILES.iter()
+    .any(|filename| credentials_dir.join(format!("{}{}", filename, ENCRYPTED_SUFFIX)).exists());
+
+  if encrypted {
+    return Err(anyhow!(
+      "The desktop app's FAL API key is encrypted in its credential vault and can't be read \
+       outside the app. Set {} instead.",
+      FAL_API_KEY_ENV_VAR
+    ));
+  }
+
+  Err(anyhow!("No FAL API key found. Set {}.", FAL_API_KEY_ENV_VAR))
+}
@@ -0,0 +1,3 @@
+pub mod artcraft_credentials;
+pub mod credentials_dir;
+pub mod fal_api_key;
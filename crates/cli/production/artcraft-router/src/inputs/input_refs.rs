@@ -0,0 +1,87 @@
+use artcraft_router::api::audio_list_ref::AudioListRef;
+use artcraft_router::api::image_list_ref::ImageListRef;
+use artcraft_router::api::image_ref::ImageRef;
+use artcraft_router::api::video_list_ref::VideoListRef;
+use tokens::tokens::media_files::MediaFileToken;
+
+/// Local input files, resolved into whatever form the target provider accepts.
+#[derive(Clone, Debug)]
+pub enum InputRefs {
+  /// Uploaded to Artcraft; used by the Artcraft provider.
+  MediaFileTokens(Vec<MediaFileToken>),
+
+  /// Public URLs for third-party providers, or the local paths themselves
+  /// when nothing is sent anywhere (estimates, mock provider).
+  Urls(Vec<String>),
+}
+
+impl InputRefs {
+  fn is_empty(&self) -> bool {
+    match self {
+      Self::MediaFileTokens(tokens) => tokens.is_empty(),
+      Self::Urls(urls) => urls.is_empty(),
+    }
+  }
+
+  pub fn image_list(&self) -> Option<ImageListRef> {
+    if self.is_empty() {
+      return None;
+    }
+    Some(match self {
+      Self::MediaFileTokens(tokens) => ImageListRef::MediaFileTokens(tokens.clone()),
+      Self::Urls(urls) => ImageListRef::Urls(urls.clone()),
+    })
+  }
+
+  pub fn video_list(&self) -> Option<VideoListRef> {
+    if self.is_empty() {
+      return None;
+    }
+    Some(match self {
+      Self::MediaFileTokens(tokens) => VideoListRef::MediaFileTokens(tokens.clone()),
+      Self::Urls(urls) => VideoListRef::Urls(urls.clone()),
+    })
+  }
+
+  pub fn audio_list(&self) -> Option<AudioListRef> {
+    if self.is_empty() {
+      return None;
+    }
+    Some(match self {
+      Self::MediaFileTokens(tokens) => AudioListRef::MediaFileTokens(tokens.clone()),
+      Self::Urls(urls) => AudioListRef::Urls(urls.clone()),
+    })
+  }
+
+  /// For single-image slots (eg. start and end frames).
+  pub fn first_image(&self) -> Option<ImageRef> {
+    match self {
+      Self::MediaFileTokens(tokens) => tokens.first().cloned().map(ImageRef::MediaFileToken),
+      Self::Urls(urls) => urls.first().cloned().map(ImageRef::Url),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty_inputs_are_omitted() {
+    let refs = InputRefs::Urls(vec![]);
+    assert!(refs.image_list().is_none());
+    assert!(refs.video_list().is_none());
+    assert!(refs.audio_list().is_none());
+    assert!(refs.first_image().is_none());
+  }
+
+  #[test]
+  fn keeps_the_resolved_form() {
+    let refs = InputRefs::MediaFileTokens(vec![MediaFileToken::new_from_str("mf_one")]);
+    assert!(matches!(refs.image_list(), Some(ImageListRef::MediaFileTokens(tokens)) if tokens.len() == 1));
+    assert!(matches!(refs.first_image(), Some(ImageRef::MediaFileToken(_))));
+
+    let refs = InputRefs::Urls(vec!["https://cdn.example/a.png".to_string()]);
+    assert!(matches!(refs.first_image(), Some(ImageRef::Url(url)) if url == "https://cdn.example/a.png"));
+  }
+}
@@ -0,0 +1,2 @@
+pub mod input_refs;
+pub mod upload_inputs;
@@ -0,0 +1,116 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use log::info;
+
+use artcraft_client::credentials::storyteller_credential_set::StorytellerCredentialSet;
+use artcraft_client::endpoints::media_files::get_media_file::get_media_file;
+use artcraft_client::endpoints::media_files::legacy_upload_media_file_from_file::{legacy_upload_media_file_from_file, LegacyUploadMediaFileFromFileArgs};
+use artcraft_client::endpoints::media_files::upload_image_media_file_from_file::{upload_image_media_file_from_file, UploadImageFromFileArgs};
+use artcraft_client::endpoints::media_files::upload_video_media_file_from_file::{upload_video_media_file_from_file, UploadVideoFromFileArgs};
+use artcraft_client::utils::api_host::ApiHost;
+use tokens::tokens::media_files::MediaFileToken;
+
+use crate::inputs::input_refs::InputRefs;
+
+#[derive(Clone, Copy, Debug)]
+pub enum InputKind {
+  Image,
+  Video,
+  Audio,
+}
+
+/// Where local input files end up.
+#[derive(Clone, Copy)]
+pub enum InputTarget<'a> {
+  /// Nothing is sent anywhere (estimates, mock provider); keep the local paths.
+  LocalPaths,
+
+  /// Upload to Artcraft and reference the files by media file token.
+  ArtcraftTokens(&'a StorytellerCredentialSet),
+
+  /// Upload to Artcraft and hand the provider their public CDN URLs.
+  ArtcraftCdnUrls(&'a StorytellerCredentialSet),
+}
+
+pub async fn upload_inputs(
+  api_host: &ApiHost,
+  target: InputTarget<'_>,
+  kind: InputKind,
+  paths: &[PathBuf],
+) -> anyhow::Result<InputRefs> {
+  for path in paths {
+    if !path.is_file() {
+      return Err(anyhow!("Input file does not exist: {:?}", path));
+    }
+  }
+
+  match target {
+    InputTarget::LocalPaths => {
+      Ok(InputRefs::Urls(paths.iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect()))
+    }
+    InputTarget::ArtcraftTokens(creds) => {
+      let mut tokens = Vec::with_capacity(paths.len());
+      for path in paths {
+        tokens.push(upload_file(api_host, creds, kind, path).await?);
+      }
+      Ok(InputRefs::MediaFileTokens(tokens))
+    }
+    InputTarget::ArtcraftCdnUrls(creds) => {
+      let mut urls = Vec::with_capacity(paths.len());
+      for path in paths {
+        let token = upload_file(api_host, creds, kind, path).await?;
+        let media_file = get_media_file(api_host, &token).await
+          .map_err(|err| anyhow!("Failed to look up uploaded file {}: {}", token.as_str(), err))?;
+        urls.push(media_file.media_file.media_links.cdn_url.to_string());
+      }
+      Ok(InputRefs::Urls(urls))
+    }
+  }
+}
+
+async fn upload_file(
+  api_host: &ApiHost,
+  creds: &StorytellerCredentialSet,
+  kind: InputKind,
+  path: &Path,
+) -> anyhow::Result<MediaFileToken> {
+  info!("Uploading {:?} input {:?} ...", kind, path);
+
+  let token = match kind {
+    InputKind::Image => {
+      upload_image_media_file_from_file(UploadImageFromFileArgs {
+        api_host,
+        maybe_creds: Some(creds),
+        path,
+        is_intermediate_system_file: true,
+        maybe_prompt_token: None,
+        maybe_generation_provider: None,
+        maybe_batch_token: None,
+      }).await.map(|response| response.media_file_token)
+    }
+    InputKind::Video => {
+      upload_video_media_file_from_file(UploadVideoFromFileArgs {
+        api_host,
+        maybe_creds: Some(creds),
+        path,
+        maybe_prompt_token: None,
+        maybe_generation_provider: None,
+      }).await.map(|response| response.media_file_token)
+    }
+    InputKind::Audio => {
+      legacy_upload_media_file_from_file(LegacyUploadMediaFileFromFileArgs {
+        api_host,
+        maybe_creds: Some(creds),
+        path,
+        maybe_generation_provider: None,
+      }).await.map(|response| response.media_file_token)
+    }
+  }.map_err(|err| anyhow!("Failed to upload {:?}: {}", path, err))?;
+
+  info!("Uploaded {:?} as {}", path, token.as_str());
+
+  Ok(token)
+}
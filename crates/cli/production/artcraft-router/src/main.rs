@@ -0,0 +1,17 @@
+mod commands;
+mod credentials;
+mod inputs;
+mod outputs;
+mod utils;
+
+use clap::Parser;
+use commands::run::Cli;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+  let cli = Cli::parse();
+
+  easyenv::init_env_logger(Some("info"));
+
+  commands::run::run(cli).await
+}
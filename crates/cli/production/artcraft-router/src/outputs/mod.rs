@@ -0,0 +1,3 @@
+pub mod pending_job;
+pub mod save_outputs;
+pub mod wait_for_outputs;
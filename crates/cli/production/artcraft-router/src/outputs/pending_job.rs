@@ -0,0 +1,101 @@
+use artcraft_router::api::provider::Provider;
+use artcraft_router::generate::generate_image::generate_image_response::GenerateImageResponse;
+use artcraft_router::generate::generate_splat::generate_splat_response::GenerateSplatResponse;
+use artcraft_router::generate::generate_video::generate_video_response::GenerateVideoResponse;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+
+/// A submitted generation, in the form we need to follow it to completion.
+#[derive(Clone, Debug)]
+pub enum PendingJob {
+  Artcraft(Vec<InferenceJobToken>),
+
+  Fal {
+    maybe_request_id: Option<String>,
+    status_url: String,
+    maybe_response_url: Option<String>,
+  },
+
+  Mock(Vec<String>),
+
+  /// Submitted, but this tool can't follow the provider's queue.
+  Untracked {
+    provider: Provider,
+    job_id: String,
+  },
+}
+
+impl PendingJob {
+  pub fn from_image_response(response: &GenerateImageResponse) -> Self {
+    match response {
+      GenerateImageResponse::Artcraft(payload) => Self::Artcraft(vec![payload.inference_job_token.clone()]),
+      GenerateImageResponse::Fal(payload) => match &payload.maybe_status_url {
+        Some(status_url) => Self::Fal {
+          maybe_request_id: payload.request_id.clone(),
+          status_url: status_url.clone(),
+          maybe_response_url: payload.maybe_response_url.clone(),
+        },
+        None => Self::Untracked {
+          provider: Provider::Fal,
+          job_id: payload.request_id.clone().unwrap_or_default(),
+        },
+      },
+      GenerateImageResponse::Mock(payload) => Self::Mock(payload.all_job_ids.clone()),
+      GenerateImageResponse::Replicate(payload) => Self::Untracked {
+        provider: Provider::Replicate,
+        job_id: payload.prediction_id.clone(),
+      },
+    }
+  }
+
+  pub fn from_video_response(response: &GenerateVideoResponse) -> Self {
+    match response {
+      GenerateVideoResponse::Artcraft(payload) => {
+        if payload.all_inference_job_tokens.is_empty() {
+          Self::Artcraft(vec![payload.inference_job_token.clone()])
+        } else {
+          Self::Artcraft(payload.all_inference_job_tokens.clone())
+        }
+      }
+      GenerateVideoResponse::Mock(payload) => Self::Mock(payload.all_job_ids.clone()),
+      GenerateVideoResponse::Fal(payload) => Self::Untracked {
+        provider: Provider::Fal,
+        job_id: payload.request_id.clone().unwrap_or_default(),
+      },
+      GenerateVideoResponse::GmiCloud(payload) => Self::Untracked {
+        provider: Provider::GmiCloud,
+        job_id: payload.request_id.clone(),
+      },
+      GenerateVideoResponse::Muapi(payload) => Self::Untracked {
+        provider: Provider::Muapi,
+        job_id: payload.request_id.to_string(),
+      },
+      GenerateVideoResponse::Seedance2Pro(payload) => Self::Untracked {
+        provider: Provider::Seedance2Pro,
+        job_id: payload.task_id.clone(),
+      },
+      GenerateVideoResponse::Replicate(payload) => Self::Untracked {
+        provider: Provider::Replicate,
+        job_id: payload.prediction_id.clone(),
+      },
+    }
+  }
+
+  pub fn from_splat_response(response: &GenerateSplatResponse) -> Self {
+    match response {
+      GenerateSplatResponse::Artcraft(payload) => Self::Artcraft(vec![payload.inference_job_token.clone()]),
+      GenerateSplatResponse::Mock(payload) => Self::Mock(vec![payload.job_id.clone()]),
+    }
+  }
+
+  /// Job identifiers to print after submission.
+  pub fn job_ids(&self) -> Vec<String> {
+    match self {
+      Self::Artcraft(tokens) => tokens.iter().map(|token| token.to_string()).collect(),
+      Self::Fal { maybe_request_id, status_url, .. } => {
+        vec![maybe_request_id.clone().unwrap_or_else(|| status_url.clone())]
+      }
+      Self::Mock(job_ids) => job_ids.clone(),
+      Self::Untracked { job_id, .. } => vec![job_id.clone()],
+    }
+  }
+}
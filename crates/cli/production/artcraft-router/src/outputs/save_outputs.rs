@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use log::info;
+
+use artcraft_router::utils::download_file::download_file;
+
+use crate::outputs::wait_for_outputs::OutputMedia;
+
+/// Download (or copy) each output into `output_dir`, named `<prefix>_<n>.<ext>`.
+pub async fn save_outputs(output_dir: &Path, prefix: &str, outputs: &[OutputMedia]) -> anyhow::Result<Vec<PathBuf>> {
+  fs::create_dir_all(output_dir)
+    .map_err(|err| anyhow!("Failed to create output directory {:?}: {}", output_dir, err))?;
+
+  let mut saved = Vec::with_capacity(outputs.len());
+
+  for (index, output) in outputs.iter().enumerate() {
+    let path = match output {
+      OutputMedia::Url(url) => {
+        info!("Downloading {} ...", url);
+        let bytes = download_file(url).await
+          .map_err(|err| anyhow!("Failed to download {}: {}", url, err))?;
+        let path = output_dir.join(output_filename(prefix, index, extension_for_url(url)));
+        fs::write(&path, bytes)
+          .map_err(|err| anyhow!("Failed to write {:?}: {}", path, err))?;
+        path
+      }
+      OutputMedia::LocalFile(source) => {
+        let extension = source.extension().and_then(|ext| ext.to_str());
+        let path = output_dir.join(output_filename(prefix, index, extension));
+        fs::copy(source, &path)
+          .map_err(|err| anyhow!("Failed to copy {:?} to {:?}: {}", source, path, err))?;
+        path
+      }
+    };
+    saved.push(path);
+  }
+
+  Ok(saved)
+}
+
+fn output_filename(prefix: &str, index: usize, maybe_extension: Option<&str>) -> String {
+  match maybe_extension {
+    Some(extension) => format!("{}_{}.{}", prefix, index, extension),
+    None => format!("{}_{}", prefix, index),
+  }
+}
+
+/// The extension of the URL's last path segment, ignoring any query string.
+fn extension_for_url(url: &str) -> Option<&str> {
+  let path = url.split(['?', '#']).next()?;
+  let filename = path.rsplit('/').next()?;
+  let (_stem, extension) = filename.rsplit_once('.')?;
+  let is_plausible = !extension.is_empty()
+    && extension.len() <= 5
+    && extension.chars().all(|c| c.is_ascii_alphanumeric());
+  is_plausible.then_some(extension)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn extension_from_cdn_url() {
+    assert_eq!(extension_for_url("https://cdn.example/media/a/b/output.mp4"), Some("mp4"));
+    assert_eq!(extension_for_url("https://cdn.example/image.png?width=512#top"), Some("png"));
+    assert_eq!(extension_for_url("https://cdn.example/files/no_extension"), None);
+    assert_eq!(extension_for_url("https://cdn.example/files/weird.not-an-ext"), None);
+  }
+
+  #[test]
+  fn filename_with_and_without_extension() {
+    assert_eq!(output_filename("job", 0, Some("png")), "job_0.png");
+    assert_eq!(output_filename("job", 2, None), "job_2");
+  }
+}
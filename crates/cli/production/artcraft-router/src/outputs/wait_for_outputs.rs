@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use log::{info, warn};
+
+use artcraft_client::credentials::storyteller_credential_set::StorytellerCredentialSet;
+use artcraft_client::endpoints::jobs::list_session_jobs::{list_session_jobs, States};
+use artcraft_client::utils::api_host::ApiHost;
+use artcraft_router::client::router_client::RouterClient;
+use artcraft_router::mock::mock_job_status::MockJobStatus;
+use enums::common::job_status_plus::JobStatusPlus;
+use fal_client::creds::fal_api_key::FalApiKey;
+use fal_client::polling::poll_job_response::poll_job_response::{poll_job_response, PollJobResponseArgs};
+use fal_client::polling::poll_job_status::poll_job_status::{poll_job_status, FalJobStatus, PollJobStatusArgs};
+
+use crate::outputs::pending_job::PendingJob;
+
+/// A finished result, either hosted somewhere or already on disk (mock provider).
+#[derive(Clone, Debug)]
+pub enum OutputMedia {
+  Url(String),
+  LocalFile(PathBuf),
+}
+
+pub struct WaitContext<'a> {
+  pub api_host: &'a ApiHost,
+  pub client: &'a RouterClient,
+  pub maybe_artcraft_creds: Option<&'a StorytellerCredentialSet>,
+  pub maybe_fal_api_key: Option<&'a str>,
+  pub poll_interval: Duration,
+  pub timeout: Duration,
+}
+
+/// Poll the provider until every job in `job` finishes, returning the outputs.
+pub async fn wait_for_outputs(context: &WaitContext<'_>, job: &PendingJob) -> anyhow::Result<Vec<OutputMedia>> {
+  let deadline = Instant::now() + context.timeout;
+
+  match job {
+    PendingJob::Artcraft(job_tokens) => {
+      let creds = context.maybe_artcraft_creds
+        .ok_or_else(|| anyhow!("Artcraft credentials are required to poll Artcraft jobs"))?;
+      let job_tokens = job_tokens.iter().map(|token| token.to_string()).collect();
+      wait_for_artcraft(context, creds, job_tokens, deadline).await
+    }
+    PendingJob::Fal { status_url, maybe_response_url, .. } => {
+      let api_key = context.maybe_fal_api_key
+        .ok_or_else(|| anyhow!("A FAL API key is required to poll FAL jobs"))?;
+      let api_key = FalApiKey::from_str(api_key);
+      wait_for_fal(context, &api_key, status_url, maybe_response_url.as_deref(), deadline).await
+    }
+    PendingJob::Mock(job_ids) => {
+      wait_for_mock(context, job_ids, deadline).await
+    }
+    PendingJob::Untracked { provider, job_id } => {
+      Err(anyhow!("Can't poll {:?} jobs from this tool yet. Job id: {}", provider, job_id))
+    }
+  }
+}
+
+async fn wait_for_artcraft(
+  context: &WaitContext<'_>,
+  creds: &StorytellerCredentialSet,
+  mut remaining: HashSet<String>,
+  deadline: Instant,
+) -> anyhow::Result<Vec<OutputMedia>> {
+  let mut outputs = Vec::new();
+
+  while !remaining.is_empty() {
+    check_deadline(deadline)?;
+
+    let response = list_session_jobs(context.api_host, Some(creds), States::All).await
+      .map_err(|err| anyhow!("Failed to list Artcraft jobs: {}", err))?;
+
+    for job in response.jobs.iter() {
+      if !remaining.contains(job.job_token.as_str()) {
+        continue;
+      }
+
+      match job.status.status {
+        JobStatusPlus::CompleteSuccess => {
+          remaining.remove(job.job_token.as_str());
+          match &job.maybe_result {
+            Some(result) => outputs.push(OutputMedia::Url(result.media_links.cdn_url.to_string())),
+            None => warn!("Job {} succeeded without a result", job.job_token.as_str()),
+          }
+        }
+        JobStatusPlus::CompleteFailure
+        | JobStatusPlus::Dead
+        | JobStatusPlus::CancelledByUser
+        | JobStatusPlus::CancelledBySystem => {
+          return Err(anyhow!(
+            "Job {} ended with status {:?}: {}",
+            job.job_token.as_str(),
+            job.status.status,
+            job.status.maybe_failure_message.as_deref().unwrap_or("no failure message"),
+          ));
+        }
+        _ => {
+          info!("Job {} is {:?} ({}%)", job.job_token.as_str(), job.status.status, job.status.progress_percentage);
+        }
+      }
+    }
+
+    if !remaining.is_empty() {
+      tokio::time::sleep(context.poll_interval).await;
+    }
+  }
+
+  Ok(outputs)
+}
+
+async fn wait_for_fal(
+  context: &WaitContext<'_>,
+  api_key: &FalApiKey,
+  status_url: &str,
+  maybe_response_url: Option<&str>,
+  deadline: Instant,
+) -> anyhow::Result<Vec<OutputMedia>> {
+  let polled_response_url = loop {
+    check_deadline(deadline)?;
+
+    let status = poll_job_status(PollJobStatusArgs { status_url, api_key }).await
+      .map_err(|err| anyhow!("Failed to poll FAL job status: {}", err))?;
+
+    match status.status {
+      FalJobStatus::Completed => break status.response_url,
+      FalJobStatus::InQueue | FalJobStatus::InProgress => {
+        info!("FAL job is {:?} (queue position {:?})", status.status, status.maybe_queue_position);
+      }
+      FalJobStatus::Unknown(ref status) => warn!("FAL job has unknown status: {}", status),
+    }
+
+    tokio::time::sleep(context.poll_interval).await;
+  };
+
+  // NB: Prefer the URL returned at submission, like the desktop app does.
+  let response_url = maybe_response_url
+    .map(|url| url.to_string())
+    .or(polled_response_url)
+    .ok_or_else(|| anyhow!("FAL job completed without a response URL"))?;
+
+  let response = poll_job_response(PollJobResponseArgs { response_url: &response_url, api_key }).await
+    .map_err(|err| anyhow!("Failed to fetch FAL job response: {}", err))?;
+
+  let contents = response.extracted_contents
+    .ok_or_else(|| anyhow!("FAL job response had no media: {}", response.raw_body))?;
+
+  let mut urls = Vec::new();
+
+  if let Some(images) = &contents.images {
+    urls.extend(images.iter().filter_map(|image| image.url.clone()));
+  }
+  if urls.is_empty() {
+    urls.extend(contents.image.and_then(|image| image.url));
+    urls.extend(contents.video.and_then(|video| video.url));
+    urls.extend(contents.model_glb.and_then(|glb| glb.url));
+  }
+
+  Ok(urls.into_iter().map(OutputMedia::Url).collect())
+}
+
+async fn wait_for_mock(
+  context: &WaitContext<'_>,
+  job_ids: &[String],
+  deadline: Instant,
+) -> anyhow::Result<Vec<OutputMedia>> {
+  let mock_client = context.client.get_mock_client_ref()
+    .map_err(|err| anyhow!("Mock client not configured: {}", err))?;
+
+  let mut outputs = Vec::with_capacity(job_ids.len());
+
+  for job_id in job_ids {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    let status = tokio::time::timeout(remaining, mock_client.wait_for_job(job_id, context.poll_interval)).await
+      .map_err(|_elapsed| anyhow!("Timed out waiting for mock job {}", job_id))?
+      .map_err(|err| anyhow!("Mock job {} failed: {}", job_id, err))?;
+
+    match status {
+      MockJobStatus::Completed(output) => outputs.push(OutputMedia::LocalFile(output.media_path)),
+      MockJobStatus::Failed { reason } => return Err(anyhow!("Mock job {} failed: {}", job_id, reason)),
+      status => return Err(anyhow!("Mock job {} ended as {:?}", job_id, status)),
+    }
+  }
+
+  Ok(outputs)
+}
+
+fn check_deadline(deadline: Instant) -> anyhow::Result<()> {
+  if Instant::now() >= deadline {
+    return Err(anyhow!("Timed out waiting for the generation to finish. Raise --timeout-seconds to wait longer."));
+  }
+  Ok(())
+}
@@ -0,0 +1,94 @@
+use std::fmt::{Display, Formatter};
+
+use artcraft_router::generate::generate_image::image_generation_cost_estimate::ImageGenerationCostEstimate;
+use artcraft_router::generate::generate_splat::splat_generation_cost_estimate::SplatGenerationCostEstimate;
+use artcraft_router::generate::generate_video::video_generation_cost_estimate::VideoGenerationCostEstimate;
+
+/// The parts of the router's (per media type) cost estimates that we print.
+pub struct CostSummary {
+  pub cost_in_credits: Option<u64>,
+  pub cost_in_usd_cents: Option<u64>,
+  pub is_free: bool,
+  pub is_unlimited: bool,
+  pub has_watermark: bool,
+}
+
+impl From<ImageGenerationCostEstimate> for CostSummary {
+  fn from(estimate: ImageGenerationCostEstimate) -> Self {
+    Self {
+      cost_in_credits: estimate.cost_in_credits,
+      cost_in_usd_cents: estimate.cost_in_usd_cents,
+      is_free: estimate.is_free,
+      is_unlimited: estimate.is_unlimited,
+      has_watermark: estimate.has_watermark,
+    }
+  }
+}
+
+impl From<VideoGenerationCostEstimate> for CostSummary {
+  fn from(estimate: VideoGenerationCostEstimate) -> Self {
+    Self {
+      cost_in_credits: estimate.cost_in_credits,
+      cost_in_usd_cents: estimate.cost_in_usd_cents,
+      is_free: estimate.is_free,
+      is_unlimited: estimate.is_unlimited,
+      has_watermark: estimate.has_watermark,
+    }
+  }
+}
+
+impl From<SplatGenerationCostEstimate> for CostSummary {
+  fn from(estimate: SplatGenerationCostEstimate) -> Self {
+    Self {
+      cost_in_credits: estimate.cost_in_credits,
+      cost_in_usd_cents: estimate.cost_in_usd_cents,
+      is_free: estimate.is_free,
+      is_unlimited: estimate.is_unlimited,
+      has_watermark: estimate.has_watermark,
+    }
+  }
+}
+
+impl Display for CostSummary {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    if self.is_free {
+      write!(f, "free")?;
+    } else if self.is_unlimited {
+      write!(f, "unlimited")?;
+    } else {
+      match (self.cost_in_credits, self.cost_in_usd_cents) {
+        (Some(credits), Some(cents)) => write!(f, "{} credits (${}.{:02})", credits, cents / 100, cents % 100)?,
+        (Some(credits), None) => write!(f, "{} credits", credits)?,
+        (None, Some(cents)) => write!(f, "${}.{:02}", cents / 100, cents % 100)?,
+        (None, None) => write!(f, "unknown cost")?,
+      }
+    }
+    if self.has_watermark {
+      write!(f, ", watermarked")?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn summary(cost_in_credits: Option<u64>, cost_in_usd_cents: Option<u64>) -> CostSummary {
+    CostSummary {
+      cost_in_credits,
+      cost_in_usd_cents,
+      is_free: false,
+      is_unlimited: false,
+      has_watermark: false,
+    }
+  }
+
+  #[test]
+  fn formats_credits_and_dollars() {
+    assert_eq!(summary(Some(40), Some(405)).to_string(), "40 credits ($4.05)");
+    assert_eq!(summary(Some(40), None).to_string(), "40 credits");
+    assert_eq!(summary(None, Some(7)).to_string(), "$0.07");
+    assert_eq!(summary(None, None).to_string(), "unknown cost");
+  }
+}
@@ -0,0 +1,2 @@
+pub mod cost_summary;
+pub mod serde_names;
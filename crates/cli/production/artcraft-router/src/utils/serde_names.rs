@@ -0,0 +1,50 @@
+use anyhow::anyhow;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Parse a router enum (model, provider, aspect ratio, ...) from its serialized name,
+/// eg. "nano_banana_pro" or "wide_sixteen_by_nine".
+pub fn parse_serde_name<T: DeserializeOwned>(value: &str, what: &str) -> anyhow::Result<T> {
+  let value = value.trim();
+  serde_json::from_value(Value::String(value.to_string()))
+    .map_err(|_err| anyhow!("Unknown {} '{}'. Run `artcraft-router list_models` for supported names.", what, value))
+}
+
+/// The serialized name of a router enum, as accepted by `parse_serde_name`.
+pub fn serde_name<T: Serialize>(value: &T) -> String {
+  match serde_json::to_value(value) {
+    Ok(Value::String(name)) => name,
+    _ => "unknown".to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use artcraft_router::api::common_image_model::CommonImageModel;
+  use artcraft_router::api::provider::Provider;
+
+  #[test]
+  fn parses_serialized_names() {
+    let model: CommonImageModel = parse_serde_name("nano_banana_pro", "image model").unwrap();
+    assert!(matches!(model, CommonImageModel::NanoBananaPro));
+
+    let provider: Provider = parse_serde_name("  fal ", "provider").unwrap();
+    assert_eq!(provider, Provider::Fal);
+  }
+
+  #[test]
+  fn rejects_unknown_names() {
+    let result: anyhow::Result<Provider> = parse_serde_name("nonexistent", "provider");
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn round_trips() {
+    for provider in Provider::ALL {
+      let parsed: Provider = parse_serde_name(&serde_name(provider), "provider").unwrap();
+      assert_eq!(&parsed, provider);
+    }
+  }
+}
@@ -10,6 +10,8 @@ pub struct ParsedArgs {
   pub mysql_environment: Environment,
   pub elasticsearch_environment: Environment,
   pub action: Action,
+  pub maybe_index: Option<String>,
+  pub delete_old_index: bool,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -37,6 +39,16 @@ pub enum Action {
   /// For now, this is just media files that are relevant to the engine.
   ReindexMediaFiles,
   SearchMediaFiles,
+
+  /// Show which index each alias points to, and any migration in progress.
+  IndexStatus,
+
+  /// Attach each read alias to its existing physical index (eg. `media_files_v1`) if
+  /// nothing is behind it yet. Run before deploying readers that search the alias.
+  BootstrapAliases,
+
+  /// Abandon an in-progress reindex of `--index`, deleting the new index.
+  AbortReindex,
 }
 
 #[derive(Parser, Debug)]
@@ -50,6 +62,12 @@ pub struct Args {
 
   #[arg(name="elasticsearch", long="elasticsearch", help="production or development")]
   elasticsearch: Option<String>,
+
+  #[arg(name="index", long="index", help="index alias, eg. media_files (for abort_reindex)")]
+  index: Option<String>,
+
+  #[arg(name="delete-old-index", long="delete-old-index", help="delete the previous index after a reindex swaps the alias")]
+  delete_old_index: bool,
 }
 
 pub fn parse_cli_args() -> AnyhowResult<ParsedArgs> {
@@ -59,6 +77,8 @@ pub fn parse_cli_args() -> AnyhowResult<ParsedArgs> {
     mysql_environment: to_environment(args.mysql.as_deref())?,
     elasticsearch_environment: to_environment(args.elasticsearch.as_deref())?,
     action: args.action,
+    maybe_index: args.index,
+    delete_old_index: args.delete_old_index,
   })
 }
 
@@ -5,6 +5,14 @@
 //!
 //! The intent is to be able to quickly populate documents and indices.
 //!
+//! Reindexing is zero-downtime: searches read through an alias (eg. `media_files`), and a
+//! reindex builds the declared `media_files_vN` next to the live index, backfills it from
+//! MySQL in resumable batches (es-update-job dual-writes in the meantime), then atomically
+//! swaps the alias. Pass `--delete-old-index` to drop the previous index afterwards.
+//!
+//! Indices that predate the aliases need `--action bootstrap_aliases` once before readers
+//! that search the alias are deployed (a reindex or es-update-job will also do it).
+//!
 
 use elasticsearch::http::transport::Transport;
 use elasticsearch::Elasticsearch;
@@ -20,6 +28,7 @@ use errors::AnyhowResult;
 
 use crate::cli_args::{parse_cli_args, Action, Environment};
 use crate::plans::create_all_tts_documents::create_all_tts_documents;
+use crate::plans::indices::{abort_index_migration, bootstrap_index_aliases, print_index_status};
 use crate::plans::media_files::create_dimensional_media_file_documents::create_dimensional_media_file_documents;
 use crate::plans::media_files::test_search_media_files_documents::test_search_media_files;
 use crate::plans::model_weights::create_all_model_weight_documents::create_all_model_weight_documents;
@@ -49,7 +58,7 @@ pub async fn main() -> AnyhowResult<()> {
   match args.action {
     Action::ReindexTts => {
       info!("Reindexing TTS...");
-      create_all_tts_documents(&mysql, &elasticsearch).await?;
+      create_all_tts_documents(&mysql, &elasticsearch, args.delete_old_index).await?;
     }
     Action::SearchTts => {
       info!("Searching TTS...");
@@ -57,7 +66,7 @@ pub async fn main() -> AnyhowResult<()> {
     }
     Action::ReindexModelWeights => {
       info!("Reindexing model weights...");
-      create_all_model_weight_documents(&mysql, &elasticsearch).await?;
+      create_all_model_weight_documents(&mysql, &elasticsearch, args.delete_old_index).await?;
     }
     Action::SearchModelWeights => {
       info!("Searching model weights...");
@@ -73,12 +82,21 @@ pub async fn main() -> AnyhowResult<()> {
     }
     Action::ReindexMediaFiles => {
       info!("Reindexing media files...");
-      create_dimensional_media_file_documents(&mysql, &elasticsearch).await?;
+      create_dimensional_media_file_documents(&mysql, &elasticsearch, args.delete_old_index).await?;
     }
     Action::SearchMediaFiles => {
       info!("Search media files...");
       test_search_media_files(&elasticsearch).await?;
     }
+    Action::IndexStatus => {
+      print_index_status(&elasticsearch).await?;
+    }
+    Action::BootstrapAliases => {
+      bootstrap_index_aliases(&elasticsearch).await?;
+    }
+    Action::AbortReindex => {
+      abort_index_migration(&elasticsearch, args.maybe_index.as_deref()).await?;
+    }
   }
 
   info!("Done!");
@@ -1,24 +1,27 @@
-use std::fs::read_to_string;
-
-use elasticsearch::{BulkOperation, BulkParts, Elasticsearch};
-use log::{error, info};
-use serde_json::Value;
+use elasticsearch::Elasticsearch;
+use log::info;
 use sqlx::{MySql, Pool};
 
-use elasticsearch_schema::documents::tts_model_document::{TTS_MODEL_INDEX, TtsModelDocument};
-use elasticsearch_schema::traits::document::Document;
-use elasticsearch_schema::utils::create_index_if_not_exists::{create_index_if_not_exists, CreateIndexArgs};
+use elasticsearch_schema::documents::tts_model_document::{TTS_MODEL_VERSIONED_INDEX, TtsModelDocument};
+use elasticsearch_schema::indices::index_migration::{begin_migration, complete_migration, set_backfill_progress, BackfillProgress};
+use elasticsearch_schema::utils::bulk_index_documents::bulk_index_documents;
 use errors::AnyhowResult;
 use mysql_queries::queries::tts::tts_models::list_tts_models::{list_tts_models, TtsModelRecordForList};
-use storyteller_root::get_storyteller_rust_root;
 use tokens::tokens::tts_models::TtsModelToken;
 use tokens::tokens::users::UserToken;
 
-pub async fn create_all_tts_documents(mysql: &Pool<MySql>, elasticsearch: &Elasticsearch) -> AnyhowResult<()> {
+/// Build (or refresh) the declared TTS model index version, then swap the alias to it.
+/// Safe to rerun: the backfill resumes from the last batch that was written.
+pub async fn create_all_tts_documents(
+  mysql: &Pool<MySql>,
+  elasticsearch: &Elasticsearch,
+  delete_old_index: bool,
+) -> AnyhowResult<()> {
   info!("Create all TTS documents.");
 
   const SCOPE_USERNAME : Option<&str> = None;
   const REQUIRE_MOD_APPROVED : bool = false;
+  const PAGE_SIZE : usize = 1000;
 
   // TODO(bt,2023-10-26): Paginate this query (!!)
   let all_tts_models = list_tts_models(
@@ -27,19 +30,42 @@ pub async fn create_all_tts_documents(mysql: &Pool<MySql>, elasticsearch: &Elast
     REQUIRE_MOD_APPROVED
   ).await?;
 
-  create_tts_model_index(&elasticsearch, true).await?;
+  let plan = begin_migration(elasticsearch, &TTS_MODEL_VERSIONED_INDEX).await?;
+
+  // NB: The query isn't paginated, so the cursor is a count of records already written.
+  let mut cursor = (plan.start_cursor as usize).min(all_tts_models.len());
+
+  for batch in all_tts_models[cursor..].chunks(PAGE_SIZE) {
+    info!("Cursor: {cursor}");
+
+    let documents = batch.iter()
+        .map(document_from_record)
+        .collect::<Vec<_>>();
 
-  for record in all_tts_models {
-    create_document_from_record(elasticsearch, &record).await?;
+    bulk_index_documents(elasticsearch, &[plan.target_index.clone()], &documents).await?;
+
+    cursor += batch.len();
+
+    set_backfill_progress(elasticsearch, &plan.target_index, BackfillProgress {
+      mapping_version: TTS_MODEL_VERSIONED_INDEX.version,
+      backfill_cursor: cursor as u64,
+      backfill_complete: false,
+    }).await?;
   }
 
+  set_backfill_progress(elasticsearch, &plan.target_index, BackfillProgress {
+    mapping_version: TTS_MODEL_VERSIONED_INDEX.version,
+    backfill_cursor: cursor as u64,
+    backfill_complete: true,
+  }).await?;
+
+  complete_migration(elasticsearch, &TTS_MODEL_VERSIONED_INDEX, &plan, delete_old_index).await?;
+
   Ok(())
 }
 
-async fn create_document_from_record(elasticsearch: &Elasticsearch, record: &TtsModelRecordForList) -> AnyhowResult<()> {
-  info!("Create record for {:?} - {:?}", record.model_token, record.title);
-
-  let document = TtsModelDocument {
+fn document_from_record(record: &TtsModelRecordForList) -> TtsModelDocument {
+  TtsModelDocument {
     token: TtsModelToken::new_from_str(&record.model_token),
     title: record.title.clone(),
     ietf_language_tag: record.ietf_language_tag.clone(),
@@ -50,54 +76,5 @@ async fn create_document_from_record(elasticsearch: &Elasticsearch, record: &Tts
     creator_set_visibility: record.creator_set_visibility,
     created_at: record.created_at,
     updated_at: record.updated_at,
-  };
-
-  let op : BulkOperation<_> = BulkOperation::index(&document)
-      .id(document.get_document_id())
-      .into();
-
-  let response = elasticsearch
-      .bulk(BulkParts::Index(TTS_MODEL_INDEX))
-      .body(vec![op])
-      .send()
-      .await?;
-
-  let json: Value = response.json().await?;
-
-  let had_errors = json["errors"].as_bool().unwrap_or(false);
-  if had_errors {
-    let failed: Vec<&Value> = json["items"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter(|v| !v["error"].is_null())
-        .collect();
-
-    // TODO: retry failures
-    error!("Errors during indexing. Failures: {}", failed.len());
   }
-
-  Ok(())
-}
-
-// NB: Adapted from elasticsearch crate examples source
-async fn create_tts_model_index(client: &Elasticsearch, _delete: bool) -> AnyhowResult<()> {
-
-  info!("Creating TTS model index...");
-
-  let index_path = get_storyteller_rust_root()
-      .join("_database/elasticsearch/index_definitions/tts_models_v1.json");
-
-  info!("Reading index file: {:?}", index_path);
-
-  let index_definition = read_to_string(index_path)?;
-
-  create_index_if_not_exists(CreateIndexArgs {
-    client,
-    index_name: TTS_MODEL_INDEX,
-    index_definition: &index_definition,
-    delete_existing: true,
-  }).await?;
-
-  Ok(())
 }
@@ -0,0 +1,57 @@
+use elasticsearch::Elasticsearch;
+use log::info;
+
+use elasticsearch_schema::indices::all_versioned_indices::{ALL_VERSIONED_INDICES, find_versioned_index};
+use elasticsearch_schema::indices::index_migration::{abort_migration, bootstrap_read_alias, get_backfill_progress};
+use elasticsearch_schema::indices::index_state::get_index_state;
+use elasticsearch_schema::indices::versioned_index::VersionedIndex;
+use errors::{anyhow, AnyhowResult};
+
+/// Print where each alias points, and how far along any migration is.
+pub async fn print_index_status(elasticsearch: &Elasticsearch) -> AnyhowResult<()> {
+  for index in ALL_VERSIONED_INDICES.iter() {
+    let state = get_index_state(elasticsearch, index).await?;
+
+    println!("{} (declared: {})", index.alias, index.index_name());
+    println!("  live: {:?}", state.live_indices);
+
+    for migrating in state.migrating_indices.iter() {
+      let progress = get_backfill_progress(elasticsearch, migrating).await?;
+      println!("  migrating: {} (cursor = {}, complete = {})",
+        migrating, progress.backfill_cursor, progress.backfill_complete);
+    }
+  }
+
+  Ok(())
+}
+
+/// Make sure every read alias resolves, so searches through it don't 404.
+pub async fn bootstrap_index_aliases(elasticsearch: &Elasticsearch) -> AnyhowResult<()> {
+  for index in ALL_VERSIONED_INDICES.iter() {
+    match bootstrap_read_alias(elasticsearch, index).await? {
+      Some(index_name) => info!("Attached {} to {}", index.alias, index_name),
+      None => info!("Nothing to bootstrap for {}", index.alias),
+    }
+  }
+
+  Ok(())
+}
+
+/// Stop dual-writing to, and delete, the index a migration was building.
+pub async fn abort_index_migration(elasticsearch: &Elasticsearch, maybe_alias: Option<&str>) -> AnyhowResult<()> {
+  let index = require_versioned_index(maybe_alias)?;
+  info!("Aborting migration of {}", index.alias);
+  abort_migration(elasticsearch, &index).await
+}
+
+fn require_versioned_index(maybe_alias: Option<&str>) -> AnyhowResult<VersionedIndex> {
+  let choices = ALL_VERSIONED_INDICES.iter()
+      .map(|index| index.alias)
+      .collect::<Vec<_>>();
+
+  let alias = maybe_alias
+      .ok_or_else(|| anyhow!("--index is required; choices: {:?}", choices))?;
+
+  find_versioned_index(alias)
+      .ok_or_else(|| anyhow!("unknown index: \"{}\" choices: {:?}", alias, choices))
+}
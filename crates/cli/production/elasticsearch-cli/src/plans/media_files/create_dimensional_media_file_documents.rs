@@ -1,37 +1,34 @@
 use std::collections::HashSet;
-use std::fs::read_to_string;
 use std::iter::FromIterator;
 
-use elasticsearch::{BulkOperation, BulkParts, Elasticsearch};
-use log::{error, info, warn};
-use serde_json::Value;
+use elasticsearch::Elasticsearch;
+use log::{info, warn};
 use sqlx::{MySql, Pool};
 
-use elasticsearch_schema::documents::media_file_document::{MEDIA_FILE_INDEX, MediaFileDocument};
-use elasticsearch_schema::traits::document::Document;
-use elasticsearch_schema::utils::create_index_if_not_exists::{create_index_if_not_exists, CreateIndexArgs};
+use elasticsearch_schema::documents::media_file_document::{MEDIA_FILE_VERSIONED_INDEX, MediaFileDocument};
+use elasticsearch_schema::indices::index_migration::{begin_migration, complete_migration, set_backfill_progress, BackfillProgress};
+use elasticsearch_schema::utils::bulk_index_documents::bulk_index_documents;
 use enums::by_table::media_files::media_file_class::MediaFileClass;
 use enums::by_table::media_files::media_file_engine_category::MediaFileEngineCategory;
 use enums::by_table::media_files::media_file_type::MediaFileType;
 use errors::AnyhowResult;
 use mysql_queries::queries::media_files::list::list_media_files_for_elastic_search_backfill_using_cursor::{list_media_files_for_elastic_search_backfill_using_cursor, ListArgs, MediaFileForElasticsearchRecord};
-use mysql_queries::queries::model_weights::list::list_model_weights_for_elastic_search_backfill_using_cursor::{list_model_weights_for_elastic_search_backfill_using_cursor, ModelWeightForElasticsearchRecord};
-use storyteller_root::get_storyteller_rust_root;
 
+/// Build (or refresh) the declared media file index version, then swap the alias to it.
+/// Safe to rerun: the backfill resumes from the last batch that was written.
 pub async fn create_dimensional_media_file_documents(
   mysql: &Pool<MySql>,
-  elasticsearch: &Elasticsearch
+  elasticsearch: &Elasticsearch,
+  delete_old_index: bool,
 ) -> AnyhowResult<()> {
 
   info!("Create dimensional media file documents.");
 
-  // TODO(bt, 2024-01-10): expose this as a CLI flag
-  const DELETE_EXISTING_INDEX : bool = true;
   const PAGE_SIZE : usize = 1000;
 
-  create_media_file_index(&elasticsearch, DELETE_EXISTING_INDEX).await?;
+  let plan = begin_migration(elasticsearch, &MEDIA_FILE_VERSIONED_INDEX).await?;
 
-  let mut cursor = 0;
+  let mut cursor = plan.start_cursor as usize;
 
   loop {
     info!("Cursor: {cursor}");
@@ -87,20 +84,34 @@ pub async fn create_dimensional_media_file_documents(
       }
     }
 
-    for result in results {
-      create_document_from_record(elasticsearch, result).await?;
-    }
+    let documents = results.into_iter()
+        .map(document_from_record)
+        .collect::<Vec<_>>();
+
+    bulk_index_documents(elasticsearch, &[plan.target_index.clone()], &documents).await?;
+
+    set_backfill_progress(elasticsearch, &plan.target_index, BackfillProgress {
+      mapping_version: MEDIA_FILE_VERSIONED_INDEX.version,
+      backfill_cursor: cursor as u64,
+      backfill_complete: false,
+    }).await?;
   }
 
+  set_backfill_progress(elasticsearch, &plan.target_index, BackfillProgress {
+    mapping_version: MEDIA_FILE_VERSIONED_INDEX.version,
+    backfill_cursor: cursor as u64,
+    backfill_complete: true,
+  }).await?;
+
+  complete_migration(elasticsearch, &MEDIA_FILE_VERSIONED_INDEX, &plan, delete_old_index).await?;
+
   Ok(())
 }
 
-async fn create_document_from_record(elasticsearch: &Elasticsearch, record: MediaFileForElasticsearchRecord) -> AnyhowResult<()> {
-  info!("Create record for {:?}", record.token);
-
+fn document_from_record(record: MediaFileForElasticsearchRecord) -> MediaFileDocument {
   let is_deleted = record.user_deleted_at.is_some() || record.mod_deleted_at.is_some();
 
-  let document = MediaFileDocument {
+  MediaFileDocument {
     token: record.token,
 
     media_class: record.media_class,
@@ -141,55 +152,5 @@ async fn create_document_from_record(elasticsearch: &Elasticsearch, record: Medi
     database_read_time: record.database_read_time,
 
     is_deleted,
-  };
-
-  let op : BulkOperation<_> = BulkOperation::index(&document)
-      .id(document.get_document_id())
-      .into();
-
-  let response = elasticsearch
-      .bulk(BulkParts::Index(MEDIA_FILE_INDEX))
-      .body(vec![op])
-      .send()
-      .await?;
-
-  let json: Value = response.json().await?;
-
-  let had_errors = json["errors"].as_bool().unwrap_or(false);
-
-  if had_errors {
-    let failed: Vec<&Value> = json["items"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter(|v| !v["error"].is_null())
-        .collect();
-
-    // TODO: retry failures
-    error!("Errors during indexing. Failures: {}", failed.len());
   }
-
-  Ok(())
-}
-
-// NB: Adapted from elasticsearch crate examples source
-async fn create_media_file_index(client: &Elasticsearch, delete_existing: bool) -> AnyhowResult<()> {
-
-  info!("Creating model media file index...");
-
-  let index_path = get_storyteller_rust_root()
-      .join("_database/elasticsearch/index_definitions/media_files_v1.json");
-
-  info!("Reading index file: {:?}", index_path);
-
-  let index_definition = read_to_string(index_path)?;
-
-  create_index_if_not_exists(CreateIndexArgs {
-    client,
-    index_name: MEDIA_FILE_INDEX,
-    index_definition: &index_definition,
-    delete_existing,
-  }).await?;
-
-  Ok(())
 }
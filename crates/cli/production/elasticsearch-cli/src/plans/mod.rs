@@ -1,3 +1,4 @@
 pub mod create_all_tts_documents;
+pub mod indices;
 pub mod media_files;
-pub mod model_weights;
\ No newline at end of file
+pub mod model_weights;
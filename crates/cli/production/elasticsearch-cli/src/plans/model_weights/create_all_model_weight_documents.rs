@@ -1,33 +1,30 @@
-use std::fs::read_to_string;
-
-use elasticsearch::{BulkOperation, BulkParts, Elasticsearch};
-use log::{error, info, warn};
-use serde_json::Value;
+use elasticsearch::Elasticsearch;
+use log::{info, warn};
 use sqlx::{MySql, Pool};
 
-use elasticsearch_schema::documents::model_weight_document::{MODEL_WEIGHT_INDEX, ModelWeightDocument};
-use elasticsearch_schema::traits::document::Document;
-use elasticsearch_schema::utils::create_index_if_not_exists::{create_index_if_not_exists, CreateIndexArgs};
+use elasticsearch_schema::documents::model_weight_document::{MODEL_WEIGHT_VERSIONED_INDEX, ModelWeightDocument};
+use elasticsearch_schema::indices::index_migration::{begin_migration, complete_migration, set_backfill_progress, BackfillProgress};
+use elasticsearch_schema::utils::bulk_index_documents::bulk_index_documents;
 use enums::by_table::model_weights::weights_category::WeightsCategory;
 use errors::AnyhowResult;
 use mysql_queries::queries::model_weights::list::list_model_weights_for_elastic_search_backfill_using_cursor::{list_model_weights_for_elastic_search_backfill_using_cursor, ModelWeightForElasticsearchRecord};
 use primitives::numerics::u64_to_i32_saturating::u64_to_i32_saturating;
-use storyteller_root::get_storyteller_rust_root;
 
+/// Build (or refresh) the declared model weight index version, then swap the alias to it.
+/// Safe to rerun: the backfill resumes from the last batch that was written.
 pub async fn create_all_model_weight_documents(
   mysql: &Pool<MySql>,
-  elasticsearch: &Elasticsearch
+  elasticsearch: &Elasticsearch,
+  delete_old_index: bool,
 ) -> AnyhowResult<()> {
 
   info!("Create all model weight documents.");
 
-  // TODO(bt, 2024-01-10): expose this as a CLI flag
-  const DELETE_EXISTING_INDEX : bool = true;
   const PAGE_SIZE : u64 = 1000;
 
-  create_model_weight_model_index(&elasticsearch, DELETE_EXISTING_INDEX).await?;
+  let plan = begin_migration(elasticsearch, &MODEL_WEIGHT_VERSIONED_INDEX).await?;
 
-  let mut cursor = 0;
+  let mut cursor = plan.start_cursor;
 
   loop {
     let results = list_model_weights_for_elastic_search_backfill_using_cursor(mysql, PAGE_SIZE, cursor).await?;
@@ -47,17 +44,31 @@ pub async fn create_all_model_weight_documents(
       }
     }
 
-    for result in results {
-      create_document_from_record(elasticsearch, result).await?;
-    }
+    let documents = results.into_iter()
+        .map(document_from_record)
+        .collect::<Vec<_>>();
+
+    bulk_index_documents(elasticsearch, &[plan.target_index.clone()], &documents).await?;
+
+    set_backfill_progress(elasticsearch, &plan.target_index, BackfillProgress {
+      mapping_version: MODEL_WEIGHT_VERSIONED_INDEX.version,
+      backfill_cursor: cursor,
+      backfill_complete: false,
+    }).await?;
   }
 
+  set_backfill_progress(elasticsearch, &plan.target_index, BackfillProgress {
+    mapping_version: MODEL_WEIGHT_VERSIONED_INDEX.version,
+    backfill_cursor: cursor,
+    backfill_complete: true,
+  }).await?;
+
+  complete_migration(elasticsearch, &MODEL_WEIGHT_VERSIONED_INDEX, &plan, delete_old_index).await?;
+
   Ok(())
 }
 
-async fn create_document_from_record(elasticsearch: &Elasticsearch, record: ModelWeightForElasticsearchRecord) -> AnyhowResult<()> {
-  info!("Create record for {:?} - {:?}", record.token, record.title);
-
+fn document_from_record(record: ModelWeightForElasticsearchRecord) -> ModelWeightDocument {
   let is_deleted = record.user_deleted_at.is_some() || record.mod_deleted_at.is_some();
 
   let maybe_ietf_language_tag = record.maybe_ietf_language_tag
@@ -78,7 +89,7 @@ async fn create_document_from_record(elasticsearch: &Elasticsearch, record: Mode
       })
       .map(|t| t.to_string());
 
-  let document = ModelWeightDocument {
+  ModelWeightDocument {
     token: record.token,
 
     creator_set_visibility: record.creator_set_visibility,
@@ -117,54 +128,5 @@ async fn create_document_from_record(elasticsearch: &Elasticsearch, record: Mode
 
     database_read_time: record.database_read_time,
     is_deleted,
-  };
-
-  let op : BulkOperation<_> = BulkOperation::index(&document)
-      .id(document.get_document_id())
-      .into();
-
-  let response = elasticsearch
-      .bulk(BulkParts::Index(MODEL_WEIGHT_INDEX))
-      .body(vec![op])
-      .send()
-      .await?;
-
-  let json: Value = response.json().await?;
-
-  let had_errors = json["errors"].as_bool().unwrap_or(false);
-  if had_errors {
-    let failed: Vec<&Value> = json["items"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter(|v| !v["error"].is_null())
-        .collect();
-
-    // TODO: retry failures
-    error!("Errors during indexing. Failures: {}", failed.len());
   }
-
-  Ok(())
-}
-
-// NB: Adapted from elasticsearch crate examples source
-async fn create_model_weight_model_index(client: &Elasticsearch, delete_existing: bool) -> AnyhowResult<()> {
-
-  info!("Creating model weight model index...");
-
-  let index_path = get_storyteller_rust_root()
-      .join("_database/elasticsearch/index_definitions/model_weights_v1.json");
-
-  info!("Reading index file: {:?}", index_path);
-
-  let index_definition = read_to_string(index_path)?;
-
-  create_index_if_not_exists(CreateIndexArgs {
-    client,
-    index_name: MODEL_WEIGHT_INDEX,
-    index_definition: &index_definition,
-    delete_existing,
-  }).await?;
-
-  Ok(())
 }
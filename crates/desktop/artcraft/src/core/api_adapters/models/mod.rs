@@ -1 +1,2 @@
-pub mod image;
\ No newline at end of file
+pub mod image;
+pub mod video;
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod tauri_video_model_to_router_model;
@@ -0,0 +1,29 @@
+use artcraft_router::api::common_video_model::CommonVideoModel;
+
+use crate::core::commands::generate::generate_video::request::TauriVideoModel;
+
+/// Map TauriVideoModel to the artcraft_router's CommonVideoModel.
+pub fn tauri_video_model_to_router_model(model: TauriVideoModel) -> CommonVideoModel {
+  match model {
+    TauriVideoModel::GrokVideo => CommonVideoModel::GrokVideo,
+    TauriVideoModel::Kling16Pro => CommonVideoModel::Kling16Pro,
+    TauriVideoModel::Kling21Pro => CommonVideoModel::Kling21Pro,
+    TauriVideoModel::Kling21Master => CommonVideoModel::Kling21Master,
+    TauriVideoModel::Kling2p5TurboPro => CommonVideoModel::Kling2p5TurboPro,
+    TauriVideoModel::Kling2p6Pro => CommonVideoModel::Kling2p6Pro,
+    TauriVideoModel::Kling3p0Standard => CommonVideoModel::Kling3p0Standard,
+    TauriVideoModel::Kling3p0Pro => CommonVideoModel::Kling3p0Pro,
+    TauriVideoModel::HappyHorse1p0 => CommonVideoModel::HappyHorse1p0,
+    TauriVideoModel::Seedance10Lite => CommonVideoModel::Seedance10Lite,
+    TauriVideoModel::Seedance1p5Pro => CommonVideoModel::Seedance1p5Pro,
+    TauriVideoModel::Seedance2p0 => CommonVideoModel::Seedance2p0,
+    TauriVideoModel::Seedance2p0Fast => CommonVideoModel::Seedance2p0Fast,
+    TauriVideoModel::Sora2 => CommonVideoModel::Sora2,
+    TauriVideoModel::Sora2Pro => CommonVideoModel::Sora2Pro,
+    TauriVideoModel::Veo2 => CommonVideoModel::Veo2,
+    TauriVideoModel::Veo3 => CommonVideoModel::Veo3,
+    TauriVideoModel::Veo3Fast => CommonVideoModel::Veo3Fast,
+    TauriVideoModel::Veo3p1 => CommonVideoModel::Veo3p1,
+    TauriVideoModel::Veo3p1Fast => CommonVideoModel::Veo3p1Fast,
+  }
+}
@@ -3,6 +3,7 @@ use crate::core::artcraft_error::ArtcraftError;
 use crate::core::commands::response::failure_response_wrapper::{CommandErrorResponseWrapper, CommandErrorStatus};
 use crate::core::commands::response::shorthand::{Response, ResponseOrErrorType};
 use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::generation_history::record_generation_outcome::record_generation_output_file_path;
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::state::app_preferences::app_preferences::AppPreferences;
 use crate::core::state::data_dir::app_data_root::AppDataRoot;
@@ -100,5 +101,11 @@ pub async fn handle_request(
 
   info!("downloaded to: {:?}", download_path);
 
+  record_generation_output_file_path(
+    app,
+    &request.media_token,
+    &download_path.to_string_lossy(),
+  ).await;
+
   Ok(())
 }
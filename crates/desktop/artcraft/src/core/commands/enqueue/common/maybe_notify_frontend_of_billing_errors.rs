@@ -33,6 +33,7 @@ fn billing_error(
     BillingProvider::Kinovi => GenerationProvider::Artcraft, // NB: We don't support Kinovi yet.
     BillingProvider::Midjourney => GenerationProvider::Midjourney,
     BillingProvider::Muapi=> GenerationProvider::Artcraft, // NB: We don't support Muapi yet.
+    BillingProvider::Replicate => GenerationProvider::Artcraft, // NB: We don't support Replicate yet.
     BillingProvider::Sora => GenerationProvider::Sora,
   };
   warn!("Billing issue with: {:?}", provider);
@@ -98,6 +98,7 @@ pub enum BillingProvider {
   Kinovi,
   Midjourney,
   Muapi,
+  Replicate,
   Sora,
 }
 
@@ -238,6 +239,8 @@ impl From<ArtcraftRouterError> for GenerateError {
           ProviderError::Muapi(_) => BillingProvider::Muapi,
           ProviderError::Seedance2Pro(_) => BillingProvider::Kinovi,
           ProviderError::Storyteller(_) => BillingProvider::Artcraft,
+          ProviderError::Mock(_) => BillingProvider::Artcraft,
+          ProviderError::Replicate(_) => BillingProvider::Replicate,
         };
         Self::BillingIssue(BillingIssueReason { provider })
       },
@@ -245,8 +248,12 @@ impl From<ArtcraftRouterError> for GenerateError {
       ArtcraftRouterError::Provider(ProviderError::Fal(_)) => Self::FalNoLongerSupported,
       ArtcraftRouterError::Provider(ProviderError::Muapi(_)) => Self::ArtcraftRouterNotYetSupportedProvider("muapi"),
       ArtcraftRouterError::Provider(ProviderError::Seedance2Pro(_)) => Self::ArtcraftRouterNotYetSupportedProvider("seedance2pro"),
+      ArtcraftRouterError::Provider(ProviderError::Mock(_)) => Self::ArtcraftRouterNotYetSupportedProvider("mock"),
+      ArtcraftRouterError::Provider(ProviderError::Replicate(_)) => Self::ArtcraftRouterNotYetSupportedProvider("replicate"),
       ArtcraftRouterError::UnsupportedModel(model) => Self::NotYetImplemented(format!("Unsupported model: {}", model)),
       ArtcraftRouterError::UnsupportedProviderAndModelForNewApi(message) => Self::ArtcraftRouterNotYetSupportedProvider("unsupported model for new router API"),
+      ArtcraftRouterError::FailoverExhausted(_) => Self::NoProviderAvailable,
+      ArtcraftRouterError::ProviderCircuitOpen(_) => Self::NoProviderAvailable,
     }
   }
 }
@@ -21,14 +21,18 @@ pub(super) async fn handle_artcraft_splat_via_router(
   model: CommonSplatModel,
   generation_model: GenerationModel,
 ) -> Result<TaskEnqueueSuccess, GenerateError> {
-  let client = RouterClient::Artcraft(RouterArtcraftClient::new(
-    app_env_configs.storyteller_host.clone(),
-    creds.clone(),
-  ));
+  // NB: With the mock provider enabled, nothing reaches Artcraft; the mock task poller finishes the task.
+  let (client, provider) = match app_env_configs.mock_provider.as_ref() {
+    Some(mock) => (RouterClient::Mock(mock.clone()), Provider::Mock),
+    None => (RouterClient::Artcraft(RouterArtcraftClient::new(
+      app_env_configs.storyteller_host.clone(),
+      creds.clone(),
+    )), Provider::Artcraft),
+  };
 
   let router_request = GenerateSplatRequest {
     model,
-    provider: Provider::Artcraft,
+    provider,
     prompt: request.prompt.clone(),
     reference_images: request.image_media_tokens.clone().map(ImageListRef::MediaFileTokens),
     idempotency_token: None,
@@ -51,6 +55,7 @@ pub(super) async fn handle_artcraft_splat_via_router(
 
   let job_id = response.get_artcraft_payload()
       .map(|p| p.inference_job_token.to_string())
+      .or_else(|| response.get_mock_payload().map(|p| p.job_id))
       .ok_or(GenerateError::ResponseHadNoJobTokens)?;
 
   Ok(TaskEnqueueSuccess {
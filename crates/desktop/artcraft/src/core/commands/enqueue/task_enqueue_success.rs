@@ -1,3 +1,4 @@
+use crate::core::commands::generate::generate_batch::task_batch_slot::TaskBatchSlot;
 use crate::core::events::generation_events::common::{GenerationAction, GenerationModel, GenerationServiceProvider};
 use crate::core::state::task_database::TaskDatabase;
 use enums::common::generation_provider::GenerationProvider;
@@ -43,25 +44,9 @@ impl TaskEnqueueSuccess{
     }
   }
   
-  pub async fn insert_into_task_database(&self, task_database: &TaskDatabase) -> Result<TaskId, SqliteTasksError> {
-    self.insert_into_task_database_with_frontend_payload(
-      task_database,
-      None,
-      None,
-      None,
-    ).await
-  }
-
-  // TODO: This belongs somewhere else, not as a method of an event struct.
-  pub async fn insert_into_task_database_with_frontend_payload(
-    &self,
-    task_database: &TaskDatabase,
-    frontend_caller: Option<TauriCommandCaller>,
-    frontend_subscriber_id: Option<&str>,
-    frontend_subscriber_payload: Option<&str>,
-  ) -> Result<TaskId, SqliteTasksError> {
+  pub fn task_model_type(&self) -> Option<TaskModelType> {
     // TODO: Move this mapping elsewhere, or remove the other models.
-    let model_type = match self.model {
+    match self.model {
       None => None,
       Some(GenerationModel::Flux1Dev) => Some(TaskModelType::Flux1Dev),
       Some(GenerationModel::FluxDevJuggernaut) => Some(TaskModelType::FluxDevJuggernaut),
@@ -115,7 +100,44 @@ impl TaskEnqueueSuccess{
       Some(GenerationModel::Kling1_6) => Some(TaskModelType::Kling16Pro), // NB: `VideoModel::Kling16Pro`.
       Some(GenerationModel::Kling2_0) => None, // TODO: unused elsewhere?
       Some(GenerationModel::Sora) => None, // TODO: unused elsewhere?
-    };
+    }
+  }
+
+  pub async fn insert_into_task_database(&self, task_database: &TaskDatabase) -> Result<TaskId, SqliteTasksError> {
+    self.insert_into_task_database_with_frontend_payload(
+      task_database,
+      None,
+      None,
+      None,
+    ).await
+  }
+
+  // TODO: This belongs somewhere else, not as a method of an event struct.
+  pub async fn insert_into_task_database_with_frontend_payload(
+    &self,
+    task_database: &TaskDatabase,
+    frontend_caller: Option<TauriCommandCaller>,
+    frontend_subscriber_id: Option<&str>,
+    frontend_subscriber_payload: Option<&str>,
+  ) -> Result<TaskId, SqliteTasksError> {
+    self.insert_into_task_database_with_frontend_payload_and_batch(
+      task_database,
+      frontend_caller,
+      frontend_subscriber_id,
+      frontend_subscriber_payload,
+      None,
+    ).await
+  }
+
+  pub async fn insert_into_task_database_with_frontend_payload_and_batch(
+    &self,
+    task_database: &TaskDatabase,
+    frontend_caller: Option<TauriCommandCaller>,
+    frontend_subscriber_id: Option<&str>,
+    frontend_subscriber_payload: Option<&str>,
+    task_batch: Option<&TaskBatchSlot>,
+  ) -> Result<TaskId, SqliteTasksError> {
+    let model_type = self.task_model_type();
 
     create_task(CreateTaskArgs {
       db: task_database.get_connection(),
@@ -130,6 +152,8 @@ impl TaskEnqueueSuccess{
       frontend_caller,
       frontend_subscriber_id,
       frontend_subscriber_payload,
+      batch_id: task_batch.map(|slot| &slot.batch_id),
+      batch_index: task_batch.map(|slot| slot.batch_index),
     }).await
   }
 }
\ No newline at end of file
@@ -78,6 +78,8 @@ fn provider_to_generation_provider(provider: Provider) -> GenerationProvider {
     // Unused providers -> ArtCraft
     Provider::Muapi => GenerationProvider::Artcraft,
     Provider::Seedance2Pro => GenerationProvider::Artcraft ,
+    Provider::Mock => GenerationProvider::Artcraft,
+    Provider::Replicate => GenerationProvider::Artcraft,
   }
 }
 
@@ -0,0 +1,279 @@
+use crate::core::commands::generate::generate_batch::task_batch_slot::TaskBatchSlot;
+use enums::common::generation_provider::GenerationProvider;
+use futures::future::join_all;
+use log::warn;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager};
+use tokens::tokens::sqlite::tasks::TaskId;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Holds the per-provider concurrency permits of batch cells until their tasks finish.
+///
+/// A permit is acquired before a cell is submitted and parked under its batch slot.
+/// Once the generate command creates the task row, the permit moves to the task id,
+/// and it is only released when the task reaches a terminal state (see
+/// `record_generation_success` / `record_generation_failure`).
+#[derive(Clone, Default)]
+pub struct BatchTaskThrottle {
+  inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+  /// Keyed by (batch id, batch index): submitted, but no task row yet.
+  by_slot: HashMap<(String, u32), OwnedSemaphorePermit>,
+  /// Keyed by task id: the task is running at the provider.
+  by_task: HashMap<String, OwnedSemaphorePermit>,
+}
+
+impl BatchTaskThrottle {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Called by the generate commands once the cell's task row exists.
+  pub fn bind_task(&self, slot: &TaskBatchSlot, task_id: &TaskId) {
+    let Ok(mut inner) = self.inner.lock() else {
+      return;
+    };
+    if let Some(permit) = inner.by_slot.remove(&slot_key(slot)) {
+      inner.by_task.insert(task_id.as_str().to_string(), permit);
+    }
+  }
+
+  /// Called from the task-completion path. Returns whether a permit was released.
+  pub fn release_task(&self, task_id: &TaskId) -> bool {
+    let Ok(mut inner) = self.inner.lock() else {
+      return false;
+    };
+    inner.by_task.remove(task_id.as_str()).is_some()
+  }
+
+  /// Drop every held permit, e.g. when the task queue is wiped.
+  pub fn release_all(&self) {
+    if let Ok(mut inner) = self.inner.lock() {
+      inner.by_slot.clear();
+      inner.by_task.clear();
+    }
+  }
+
+  fn park(&self, slot: &TaskBatchSlot, permit: OwnedSemaphorePermit) {
+    if let Ok(mut inner) = self.inner.lock() {
+      inner.by_slot.insert(slot_key(slot), permit);
+    }
+  }
+
+  /// Release a permit that never got bound to a task (submission failed, or no task row was created).
+  fn release_slot(&self, slot: &TaskBatchSlot) {
+    if let Ok(mut inner) = self.inner.lock() {
+      inner.by_slot.remove(&slot_key(slot));
+    }
+  }
+
+  /// Submit each cell once its provider has a free permit. Returns the number submitted.
+  ///
+  /// No more than `max_concurrent_per_provider` tasks per provider are in flight at once:
+  /// submission waits until earlier tasks for that provider finish, not merely until they're sent.
+  pub async fn submit_throttled<T, F, Fut>(
+    &self,
+    cells: Vec<(GenerationProvider, TaskBatchSlot, T)>,
+    max_concurrent_per_provider: usize,
+    submit: F,
+  ) -> usize
+  where
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = bool>,
+  {
+    let mut semaphores: HashMap<GenerationProvider, Arc<Semaphore>> = HashMap::new();
+
+    let futures = cells.into_iter()
+        .map(|(provider, slot, cell)| {
+          let semaphore = semaphores.entry(provider)
+              .or_insert_with(|| Arc::new(Semaphore::new(max_concurrent_per_provider)))
+              .clone();
+          let submit = &submit;
+
+          async move {
+            // NB: The semaphore is never closed, so this can't fail.
+            let Ok(permit) = semaphore.acquire_owned().await else {
+              return false;
+            };
+            self.park(&slot, permit);
+            let succeeded = submit(cell).await;
+            if !succeeded {
+              warn!("Batch cell {} failed to enqueue", slot.batch_index);
+            }
+            // If the cell was bound to a task the permit has already moved; otherwise free it.
+            self.release_slot(&slot);
+            succeeded
+          }
+        })
+        .collect::<Vec<_>>();
+
+    join_all(futures).await
+        .into_iter()
+        .filter(|succeeded| *succeeded)
+        .count()
+  }
+}
+
+/// Release a finished task's batch permit, if it holds one. Fails open.
+pub fn release_batch_task_permit(app: &AppHandle, task_id: &TaskId) {
+  if let Some(throttle) = app.try_state::<BatchTaskThrottle>() {
+    throttle.release_task(task_id);
+  }
+}
+
+/// Hand a freshly created batch task's permit over to its task id. Fails open.
+pub fn bind_batch_task_permit(app: &AppHandle, maybe_slot: Option<&TaskBatchSlot>, task_id: &TaskId) {
+  let Some(slot) = maybe_slot else {
+    return;
+  };
+  if let Some(throttle) = app.try_state::<BatchTaskThrottle>() {
+    throttle.bind_task(slot, task_id);
+  }
+}
+
+fn slot_key(slot: &TaskBatchSlot) -> (String, u32) {
+  (slot.batch_id.as_str().to_string(), slot.batch_index)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use tokens::tokens::sqlite::task_batches::TaskBatchId;
+
+  fn slot(batch_id: &TaskBatchId, index: u32) -> TaskBatchSlot {
+    TaskBatchSlot {
+      batch_id: batch_id.clone(),
+      batch_index: index,
+    }
+  }
+
+  fn task_id(index: u32) -> TaskId {
+    TaskId::new_from_str(&format!("task_{}", index))
+  }
+
+  fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("runtime should build")
+  }
+
+  async fn settle() {
+    for _ in 0..20 {
+      tokio::task::yield_now().await;
+    }
+  }
+
+  #[test]
+  fn never_runs_more_than_the_limit_until_tasks_finish() {
+    runtime().block_on(async {
+      let throttle = BatchTaskThrottle::new();
+      let batch_id = TaskBatchId::generate();
+      let running = Arc::new(AtomicUsize::new(0));
+      let max_running = Arc::new(AtomicUsize::new(0));
+      let started = Arc::new(Mutex::new(Vec::new()));
+
+      let cells = (0..6u32)
+          .map(|index| (GenerationProvider::Fal, slot(&batch_id, index), index))
+          .collect::<Vec<_>>();
+
+      let submit = {
+        let throttle = throttle.clone();
+        let batch_id = batch_id.clone();
+        let running = running.clone();
+        let max_running = max_running.clone();
+        let started = started.clone();
+        move |index: u32| {
+          let throttle = throttle.clone();
+          let batch_id = batch_id.clone();
+          let running = running.clone();
+          let max_running = max_running.clone();
+          let started = started.clone();
+          async move {
+            let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+            max_running.fetch_max(now_running, Ordering::SeqCst);
+            throttle.bind_task(&slot(&batch_id, index), &task_id(index));
+            started.lock().unwrap().push(index);
+            true
+          }
+        }
+      };
+
+      let submitter = {
+        let throttle = throttle.clone();
+        tokio::spawn(async move {
+          throttle.submit_throttled(cells, 2, submit).await
+        })
+      };
+
+      let mut finished = 0;
+      while finished < 6 {
+        settle().await;
+        assert!(running.load(Ordering::SeqCst) <= 2);
+
+        // Submission alone must not free a slot: only two tasks get started.
+        let next = started.lock().unwrap().get(finished).copied();
+        let Some(index) = next else {
+          panic!("expected a started task to finish");
+        };
+        assert_eq!(started.lock().unwrap().len(), (finished + 2).min(6));
+
+        running.fetch_sub(1, Ordering::SeqCst);
+        assert!(throttle.release_task(&task_id(index)));
+        finished += 1;
+      }
+
+      assert_eq!(submitter.await.unwrap(), 6);
+      assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    });
+  }
+
+  #[test]
+  fn failed_submissions_free_their_permit() {
+    runtime().block_on(async {
+      let throttle = BatchTaskThrottle::new();
+      let batch_id = TaskBatchId::generate();
+
+      let cells = (0..4u32)
+          .map(|index| (GenerationProvider::Artcraft, slot(&batch_id, index), index))
+          .collect::<Vec<_>>();
+
+      let submitted = throttle.submit_throttled(cells, 1, |_index| async { false }).await;
+
+      assert_eq!(submitted, 0);
+      assert!(throttle.inner.lock().unwrap().by_slot.is_empty());
+      assert!(throttle.inner.lock().unwrap().by_task.is_empty());
+    });
+  }
+
+  #[test]
+  fn providers_are_throttled_independently() {
+    runtime().block_on(async {
+      let throttle = BatchTaskThrottle::new();
+      let batch_id = TaskBatchId::generate();
+
+      let cells = vec![
+        (GenerationProvider::Fal, slot(&batch_id, 0), 0u32),
+        (GenerationProvider::Artcraft, slot(&batch_id, 1), 1u32),
+      ];
+
+      let binder = throttle.clone();
+      let bid = batch_id.clone();
+      let submitted = throttle.submit_throttled(cells, 1, move |index| {
+        binder.bind_task(&slot(&bid, index), &task_id(index));
+        async { true }
+      }).await;
+
+      // Both finish submitting even though neither task has completed.
+      assert_eq!(submitted, 2);
+      assert!(throttle.release_task(&task_id(0)));
+      assert!(throttle.release_task(&task_id(1)));
+      assert!(!throttle.release_task(&task_id(1)));
+    });
+  }
+}
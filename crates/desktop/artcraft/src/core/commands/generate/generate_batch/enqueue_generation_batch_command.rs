@@ -0,0 +1,160 @@
+use crate::core::commands::generate::generate_batch::batch_task_throttle::BatchTaskThrottle;
+use crate::core::commands::generate::generate_batch::estimate_generation_batch_cost::estimate_generation_batch_cost;
+use crate::core::commands::generate::generate_batch::expand_generation_batch::{expand_generation_batch, BatchCellRequest, ExpandGenerationBatchError, MAX_BATCH_CELLS};
+use crate::core::commands::generate::generate_batch::generation_batch_request::{
+  EnqueueGenerationBatchErrorType, EnqueueGenerationBatchRequest, EnqueueGenerationBatchResponse, GenerationBatchEstimate,
+};
+use crate::core::commands::generate::generate_batch::task_batch_slot::TaskBatchSlot;
+use crate::core::commands::generate::generate_image::generate_image_command::generate_image_command;
+use crate::core::commands::generate::generate_video::generate_video_command::generate_video_command;
+use crate::core::commands::response::failure_response_wrapper::{CommandErrorResponseWrapper, CommandErrorStatus};
+use crate::core::commands::response::shorthand::Response;
+use enums::common::generation_provider::GenerationProvider;
+use log::info;
+use tauri::{AppHandle, Manager};
+use tokens::tokens::sqlite::task_batches::TaskBatchId;
+
+const DEFAULT_MAX_CONCURRENT_PER_PROVIDER: usize = 2;
+
+/// Expand a matrix of prompts × models × aspect ratios into one task per cell.
+///
+/// The whole batch is priced up front and refused if it would exceed a spend cap.
+/// Cells are enqueued in the background, throttled per provider: a cell only starts once
+/// an earlier task for the same provider has finished. Results show up in the task queue
+/// grouped by `batch_id`.
+#[tauri::command]
+pub async fn enqueue_generation_batch_command(
+  request: EnqueueGenerationBatchRequest,
+  app: AppHandle,
+) -> Response<EnqueueGenerationBatchResponse, EnqueueGenerationBatchErrorType, GenerationBatchEstimate> {
+
+  info!("enqueue_generation_batch_command called, request: {:?}", request);
+
+  let cells = match expand_generation_batch(request.matrix, request.prompts) {
+    Ok(cells) => cells,
+    Err(ExpandGenerationBatchError::EmptyBatch) => {
+      return Err(error_response(EnqueueGenerationBatchErrorType::EmptyBatch, "The batch has no cells.".to_string(), None));
+    }
+    Err(ExpandGenerationBatchError::TooManyCells(count)) => {
+      let message = format!("The batch has {} cells; the limit is {}.", count, MAX_BATCH_CELLS);
+      return Err(error_response(EnqueueGenerationBatchErrorType::TooManyCells, message, None));
+    }
+  };
+
+  let (costs, estimate) = estimate_generation_batch_cost(&cells);
+
+  let has_spend_cap = request.max_cost_in_credits.is_some() || request.max_cost_in_usd_cents.is_some();
+
+  if has_spend_cap && estimate.unpriced_cell_count > 0 {
+    let message = format!("{} cell(s) can't be priced, so the spend cap can't be enforced.", estimate.unpriced_cell_count);
+    return Err(error_response(EnqueueGenerationBatchErrorType::UnpricedCells, message, Some(estimate)));
+  }
+
+  if let Some(max_credits) = request.max_cost_in_credits {
+    if estimate.total_cost_in_credits > max_credits {
+      let message = format!("The batch costs {} credits, over the cap of {}.", estimate.total_cost_in_credits, max_credits);
+      return Err(error_response(EnqueueGenerationBatchErrorType::SpendCapExceeded, message, Some(estimate)));
+    }
+  }
+
+  if let Some(max_cents) = request.max_cost_in_usd_cents {
+    if estimate.total_cost_in_usd_cents > max_cents {
+      let message = format!("The batch costs {} cents, over the cap of {}.", estimate.total_cost_in_usd_cents, max_cents);
+      return Err(error_response(EnqueueGenerationBatchErrorType::SpendCapExceeded, message, Some(estimate)));
+    }
+  }
+
+  if request.dry_run.unwrap_or(false) {
+    return Ok(EnqueueGenerationBatchResponse {
+      batch_id: None,
+      estimate,
+    }.into());
+  }
+
+  let batch_id = TaskBatchId::generate();
+
+  let cells = cells.into_iter()
+      .zip(costs.iter())
+      .enumerate()
+      .map(|(index, (mut cell, cost))| {
+        let slot = TaskBatchSlot {
+          batch_id: batch_id.clone(),
+          batch_index: index as u32,
+        };
+        cell.set_task_batch(slot.clone());
+        cell.set_estimated_cost_in_credits(cost.cost_in_credits);
+        (cell.provider(), slot, cell)
+      })
+      .collect::<Vec<_>>();
+
+  let max_concurrent = request.max_concurrent_per_provider
+      .unwrap_or(DEFAULT_MAX_CONCURRENT_PER_PROVIDER)
+      .max(1);
+
+  info!("Enqueueing batch {} with {} cells", batch_id.as_str(), cells.len());
+
+  tauri::async_runtime::spawn(enqueue_cells(app, batch_id.clone(), cells, max_concurrent));
+
+  Ok(EnqueueGenerationBatchResponse {
+    batch_id: Some(batch_id),
+    estimate,
+  }.into())
+}
+
+async fn enqueue_cells(
+  app: AppHandle,
+  batch_id: TaskBatchId,
+  cells: Vec<(GenerationProvider, TaskBatchSlot, BatchCellRequest)>,
+  max_concurrent_per_provider: usize,
+) {
+  let cell_count = cells.len();
+  let throttle = app.state::<BatchTaskThrottle>().inner().clone();
+
+  let enqueued = throttle.submit_throttled(cells, max_concurrent_per_provider, |cell| enqueue_cell(&app, cell)).await;
+
+  info!("Batch {} enqueued {} of {} cells", batch_id.as_str(), enqueued, cell_count);
+}
+
+/// The generate commands record the task, the history entry, and notify the frontend of failures.
+async fn enqueue_cell(app: &AppHandle, cell: BatchCellRequest) -> bool {
+  match cell {
+    BatchCellRequest::Image(request) => {
+      generate_image_command(
+        request,
+        app.clone(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+      ).await.is_ok()
+    }
+    BatchCellRequest::Video(request) => {
+      generate_video_command(
+        request,
+        app.clone(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+      ).await.is_ok()
+    }
+  }
+}
+
+fn error_response(
+  error_type: EnqueueGenerationBatchErrorType,
+  message: String,
+  estimate: Option<GenerationBatchEstimate>,
+) -> CommandErrorResponseWrapper<EnqueueGenerationBatchErrorType, GenerationBatchEstimate> {
+  CommandErrorResponseWrapper {
+    status: CommandErrorStatus::BadRequest,
+    error_message: Some(message),
+    error_type: Some(error_type),
+    error_details: estimate,
+  }
+}
@@ -0,0 +1,174 @@
+use crate::core::api_adapters::models::image::tauri_image_model_to_router_model::tauri_image_model_to_router_model;
+use crate::core::api_adapters::models::video::tauri_video_model_to_router_model::tauri_video_model_to_router_model;
+use crate::core::commands::generate::generate_batch::expand_generation_batch::BatchCellRequest;
+use crate::core::commands::generate::generate_batch::generation_batch_request::{GenerationBatchCellSummary, GenerationBatchEstimate};
+use crate::core::commands::generate::generate_image::providers::artcraft_router::utils::convert_enums_to_router::{convert_aspect_ratio, convert_quality, convert_resolution};
+use crate::core::commands::generate::generate_image::tauri_generate_image_request::TauriGenerateImageRequest;
+use crate::core::commands::generate::generate_video::request::TauriGenerateVideoRequest;
+use artcraft_router::api::audio_list_ref::AudioListRef;
+use artcraft_router::api::character_list_ref::CharacterListRef;
+use artcraft_router::api::image_list_ref::ImageListRef;
+use artcraft_router::api::image_ref::ImageRef;
+use artcraft_router::api::provider::Provider;
+use artcraft_router::api::video_list_ref::VideoListRef;
+use artcraft_router::client::generation_mode_mismatch_strategy::GenerationModeMismatchStrategy;
+use artcraft_router::client::request_mismatch_mitigation_strategy::RequestMismatchMitigationStrategy;
+use artcraft_router::errors::artcraft_router_error::ArtcraftRouterError;
+use artcraft_router::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
+use artcraft_router::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+use enums::common::generation_provider::GenerationProvider;
+use log::warn;
+
+/// What one cell is expected to cost. Both amounts are `None` if we couldn't price it.
+#[derive(Clone, Debug, Default)]
+pub struct BatchCellCost {
+  pub cost_in_credits: Option<u64>,
+  pub cost_in_usd_cents: Option<u64>,
+  pub is_free: bool,
+}
+
+impl BatchCellCost {
+  pub fn is_priced(&self) -> bool {
+    self.is_free || self.cost_in_credits.is_some() || self.cost_in_usd_cents.is_some()
+  }
+}
+
+/// Price every cell with the router's cost estimators. This is local and doesn't hit the network.
+pub fn estimate_generation_batch_cost(cells: &[BatchCellRequest]) -> (Vec<BatchCellCost>, GenerationBatchEstimate) {
+  let costs: Vec<BatchCellCost> = cells.iter()
+      .map(estimate_cell_cost)
+      .collect();
+
+  let summaries = cells.iter()
+      .zip(costs.iter())
+      .enumerate()
+      .map(|(index, (cell, cost))| GenerationBatchCellSummary {
+        index: index as u32,
+        prompt: cell.prompt().map(|prompt| prompt.to_string()),
+        model: cell.model_name(),
+        aspect_ratio: cell.aspect_ratio_name(),
+        provider: cell.provider(),
+        cost_in_credits: cost.cost_in_credits,
+        cost_in_usd_cents: cost.cost_in_usd_cents,
+        is_free: cost.is_free,
+      })
+      .collect();
+
+  let estimate = GenerationBatchEstimate {
+    total_cost_in_credits: costs.iter().filter_map(|cost| cost.cost_in_credits).sum(),
+    total_cost_in_usd_cents: costs.iter().filter_map(|cost| cost.cost_in_usd_cents).sum(),
+    unpriced_cell_count: costs.iter().filter(|cost| !cost.is_priced()).count(),
+    cells: summaries,
+  };
+
+  (costs, estimate)
+}
+
+fn estimate_cell_cost(cell: &BatchCellRequest) -> BatchCellCost {
+  // NB: Providers that don't go through the router (Grok, Sora, Midjourney, ...) can't be priced.
+  let provider = match cell.provider() {
+    GenerationProvider::Artcraft => Provider::Artcraft,
+    GenerationProvider::Fal => Provider::Fal,
+    _ => return BatchCellCost::default(),
+  };
+
+  let result = match cell {
+    BatchCellRequest::Image(request) => estimate_image_cost(request, provider),
+    BatchCellRequest::Video(request) => estimate_video_cost(request, provider),
+  };
+
+  match result {
+    Ok(cost) => cost,
+    Err(err) => {
+      warn!("Could not estimate cost for batch cell {:?} / {:?}: {:?}", cell.model_name(), provider, err);
+      BatchCellCost::default()
+    }
+  }
+}
+
+fn estimate_image_cost(
+  request: &TauriGenerateImageRequest,
+  provider: Provider,
+) -> Result<BatchCellCost, ArtcraftRouterError> {
+  let Some(model) = request.model.and_then(tauri_image_model_to_router_model) else {
+    return Ok(BatchCellCost::default());
+  };
+
+  // NB: Raw-byte inputs get uploaded at enqueue time, but only their presence matters for pricing.
+  let image_inputs: Vec<_> = request.canvas_image_media_token.iter()
+      .chain(request.image_media_tokens.iter().flatten())
+      .cloned()
+      .collect();
+
+  let builder = GenerateImageRequestBuilder {
+    model,
+    provider,
+    prompt: request.prompt.clone(),
+    image_inputs: Some(image_inputs)
+        .filter(|inputs| !inputs.is_empty())
+        .map(ImageListRef::MediaFileTokens),
+    resolution: request.resolution.map(convert_resolution),
+    aspect_ratio: request.aspect_ratio.map(convert_aspect_ratio),
+    quality: request.quality.map(convert_quality),
+    image_batch_count: request.batch_size.map(|n| n as u16),
+    horizontal_angle: request.adjust_horizontal_angle,
+    vertical_angle: request.adjust_vertical_angle,
+    zoom: request.adjust_zoom,
+    request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+    generation_mode_mismatch_strategy: Some(GenerationModeMismatchStrategy::GenerateAnyway),
+    idempotency_token: None,
+  };
+
+  let estimate = if builder.use_new_builder() {
+    builder.build2()?.estimate_cost()?
+  } else {
+    builder.build()?.estimate_costs()
+  };
+
+  Ok(BatchCellCost {
+    cost_in_credits: estimate.cost_in_credits,
+    cost_in_usd_cents: estimate.cost_in_usd_cents,
+    is_free: estimate.is_free,
+  })
+}
+
+fn estimate_video_cost(
+  request: &TauriGenerateVideoRequest,
+  provider: Provider,
+) -> Result<BatchCellCost, ArtcraftRouterError> {
+  let Some(model) = request.model.map(tauri_video_model_to_router_model) else {
+    return Ok(BatchCellCost::default());
+  };
+
+  let builder = GenerateVideoRequestBuilder {
+    model,
+    provider,
+    prompt: request.prompt.clone(),
+    negative_prompt: request.negative_prompt.clone(),
+    start_frame: request.start_frame_image_media_token.clone().map(ImageRef::MediaFileToken),
+    end_frame: request.end_frame_image_media_token.clone().map(ImageRef::MediaFileToken),
+    reference_images: request.reference_image_media_tokens.clone().map(ImageListRef::MediaFileTokens),
+    reference_videos: request.reference_video_media_tokens.clone().map(VideoListRef::MediaFileTokens),
+    reference_audio: request.reference_audio_media_tokens.clone().map(AudioListRef::MediaFileTokens),
+    reference_character_tokens: request.reference_character_tokens.clone().map(CharacterListRef::CharacterTokens),
+    resolution: request.resolution,
+    aspect_ratio: request.aspect_ratio,
+    duration_seconds: request.duration_seconds,
+    video_batch_count: request.video_batch_count,
+    generate_audio: request.generate_audio,
+    request_mismatch_mitigation_strategy: RequestMismatchMitigationStrategy::PayMoreUpgrade,
+    idempotency_token: None,
+  };
+
+  let estimate = if builder.use_new_builder() {
+    builder.build2()?.estimate_cost()?
+  } else {
+    builder.build()?.estimate_costs()
+  };
+
+  Ok(BatchCellCost {
+    cost_in_credits: estimate.cost_in_credits,
+    cost_in_usd_cents: estimate.cost_in_usd_cents,
+    is_free: estimate.is_free,
+  })
+}
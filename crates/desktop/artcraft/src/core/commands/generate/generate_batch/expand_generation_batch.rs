@@ -0,0 +1,198 @@
+use crate::core::commands::generate::generate_batch::generation_batch_request::GenerationBatchMatrix;
+use crate::core::commands::generate::generate_batch::task_batch_slot::TaskBatchSlot;
+use crate::core::commands::generate::generate_image::tauri_generate_image_request::TauriGenerateImageRequest;
+use crate::core::commands::generate::generate_video::generate_video_command::resolve_video_provider;
+use crate::core::commands::generate::generate_video::request::TauriGenerateVideoRequest;
+use crate::core::generation_history::record_enqueued_generation::serialized_name;
+use enums::common::generation_provider::GenerationProvider;
+
+/// Keeps a fat-fingered matrix from enqueueing hundreds of jobs.
+pub const MAX_BATCH_CELLS: usize = 64;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExpandGenerationBatchError {
+  EmptyBatch,
+  TooManyCells(usize),
+}
+
+/// A single generation from the batch, ready to hand to its generate command.
+#[derive(Clone, Debug)]
+pub enum BatchCellRequest {
+  Image(TauriGenerateImageRequest),
+  Video(TauriGenerateVideoRequest),
+}
+
+impl BatchCellRequest {
+  /// The provider the generate command will route this cell to.
+  pub fn provider(&self) -> GenerationProvider {
+    match self {
+      Self::Image(request) => request.provider.unwrap_or(GenerationProvider::Artcraft),
+      Self::Video(request) => match request.model {
+        Some(model) => resolve_video_provider(model, request.provider),
+        None => request.provider.unwrap_or(GenerationProvider::Artcraft),
+      },
+    }
+  }
+
+  pub fn prompt(&self) -> Option<&str> {
+    match self {
+      Self::Image(request) => request.prompt.as_deref(),
+      Self::Video(request) => request.prompt.as_deref(),
+    }
+  }
+
+  pub fn model_name(&self) -> Option<String> {
+    match self {
+      Self::Image(request) => request.model.as_ref().and_then(serialized_name),
+      Self::Video(request) => request.model.as_ref().and_then(serialized_name),
+    }
+  }
+
+  pub fn aspect_ratio_name(&self) -> Option<String> {
+    match self {
+      Self::Image(request) => request.aspect_ratio.as_ref().and_then(serialized_name),
+      Self::Video(request) => request.aspect_ratio.as_ref().and_then(serialized_name),
+    }
+  }
+
+  pub fn set_task_batch(&mut self, task_batch: TaskBatchSlot) {
+    match self {
+      Self::Image(request) => request.task_batch = Some(task_batch),
+      Self::Video(request) => request.task_batch = Some(task_batch),
+    }
+  }
+
+  pub fn set_estimated_cost_in_credits(&mut self, cost_in_credits: Option<u64>) {
+    match self {
+      Self::Image(request) => request.estimated_cost_in_credits = cost_in_credits,
+      Self::Video(request) => request.estimated_cost_in_credits = cost_in_credits,
+    }
+  }
+}
+
+/// Expand the matrix into cells, ordered prompt-major, then model, then aspect ratio.
+pub fn expand_generation_batch(
+  matrix: GenerationBatchMatrix,
+  prompts: Vec<String>,
+) -> Result<Vec<BatchCellRequest>, ExpandGenerationBatchError> {
+  match matrix {
+    GenerationBatchMatrix::Image { template, models, aspect_ratios } => {
+      let prompts = axis(prompts, template.prompt.clone());
+      let models = axis(models, template.model);
+      let aspect_ratios = axis(aspect_ratios, template.aspect_ratio);
+
+      check_cell_count(prompts.len() * models.len() * aspect_ratios.len())?;
+
+      let mut cells = Vec::new();
+      for prompt in prompts.iter() {
+        for model in models.iter() {
+          for aspect_ratio in aspect_ratios.iter() {
+            let mut request = template.clone();
+            request.prompt = prompt.clone();
+            request.model = *model;
+            request.aspect_ratio = *aspect_ratio;
+            cells.push(BatchCellRequest::Image(request));
+          }
+        }
+      }
+      Ok(cells)
+    }
+    GenerationBatchMatrix::Video { template, models, aspect_ratios } => {
+      let prompts = axis(prompts, template.prompt.clone());
+      let models = axis(models, template.model);
+      let aspect_ratios = axis(aspect_ratios, template.aspect_ratio);
+
+      check_cell_count(prompts.len() * models.len() * aspect_ratios.len())?;
+
+      let mut cells = Vec::new();
+      for prompt in prompts.iter() {
+        for model in models.iter() {
+          for aspect_ratio in aspect_ratios.iter() {
+            let mut request = template.clone();
+            request.prompt = prompt.clone();
+            request.model = *model;
+            request.aspect_ratio = *aspect_ratio;
+            cells.push(BatchCellRequest::Video(request));
+          }
+        }
+      }
+      Ok(cells)
+    }
+  }
+}
+
+/// An empty axis is a single cell holding the template's value.
+fn axis<T>(values: Vec<T>, template_value: Option<T>) -> Vec<Option<T>> {
+  if values.is_empty() {
+    vec![template_value]
+  } else {
+    values.into_iter().map(Some).collect()
+  }
+}
+
+fn check_cell_count(count: usize) -> Result<(), ExpandGenerationBatchError> {
+  if count == 0 {
+    Err(ExpandGenerationBatchError::EmptyBatch)
+  } else if count > MAX_BATCH_CELLS {
+    Err(ExpandGenerationBatchError::TooManyCells(count))
+  } else {
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::commands::generate::generate_image::tauri_image_model::TauriImageModel;
+  use enums::common::generation::common_aspect_ratio::CommonAspectRatio;
+
+  fn image_template() -> TauriGenerateImageRequest {
+    serde_json::from_value(serde_json::json!({
+      "model": "nano_banana",
+      "prompt": "a lighthouse",
+    })).unwrap()
+  }
+
+  fn image_matrix(models: Vec<TauriImageModel>, aspect_ratios: Vec<CommonAspectRatio>) -> GenerationBatchMatrix {
+    GenerationBatchMatrix::Image {
+      template: image_template(),
+      models,
+      aspect_ratios,
+    }
+  }
+
+  #[test]
+  fn empty_axes_use_the_template() {
+    let cells = expand_generation_batch(image_matrix(vec![], vec![]), vec![]).unwrap();
+    assert_eq!(cells.len(), 1);
+    assert_eq!(cells[0].prompt(), Some("a lighthouse"));
+    assert_eq!(cells[0].model_name().as_deref(), Some("nano_banana"));
+  }
+
+  #[test]
+  fn expands_prompt_major() {
+    let matrix = image_matrix(
+      vec![TauriImageModel::NanoBanana, TauriImageModel::Seedream4],
+      vec![CommonAspectRatio::Square, CommonAspectRatio::WideSixteenByNine, CommonAspectRatio::TallNineBySixteen],
+    );
+    let prompts = vec!["one".to_string(), "two".to_string()];
+
+    let cells = expand_generation_batch(matrix, prompts).unwrap();
+    assert_eq!(cells.len(), 12);
+
+    assert_eq!(cells[0].prompt(), Some("one"));
+    assert_eq!(cells[5].prompt(), Some("one"));
+    assert_eq!(cells[6].prompt(), Some("two"));
+
+    assert_eq!(cells[0].model_name(), cells[2].model_name());
+    assert_ne!(cells[2].model_name(), cells[3].model_name());
+    assert_ne!(cells[0].aspect_ratio_name(), cells[1].aspect_ratio_name());
+  }
+
+  #[test]
+  fn rejects_oversized_batches() {
+    let prompts = (0..MAX_BATCH_CELLS + 1).map(|i| i.to_string()).collect();
+    let result = expand_generation_batch(image_matrix(vec![], vec![]), prompts);
+    assert_eq!(result.unwrap_err(), ExpandGenerationBatchError::TooManyCells(MAX_BATCH_CELLS + 1));
+  }
+}
@@ -0,0 +1,109 @@
+use crate::core::commands::generate::generate_image::tauri_generate_image_request::TauriGenerateImageRequest;
+use crate::core::commands::generate::generate_image::tauri_image_model::TauriImageModel;
+use crate::core::commands::generate::generate_video::request::{TauriGenerateVideoRequest, TauriVideoModel};
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use artcraft_router::api::common_aspect_ratio::CommonAspectRatio as RouterAspectRatio;
+use enums::common::generation::common_aspect_ratio::CommonAspectRatio as EnumsAspectRatio;
+use enums::common::generation_provider::GenerationProvider;
+use serde_derive::{Deserialize, Serialize};
+use tokens::tokens::sqlite::task_batches::TaskBatchId;
+
+// ── Request ──
+
+#[derive(Deserialize, Debug)]
+pub struct EnqueueGenerationBatchRequest {
+  /// What to generate, and the models / aspect ratios to sweep.
+  pub matrix: GenerationBatchMatrix,
+
+  /// Prompts to sweep. If empty, every cell uses the template's prompt.
+  #[serde(default)]
+  pub prompts: Vec<String>,
+
+  /// Refuse the whole batch if the estimated total exceeds this many credits.
+  pub max_cost_in_credits: Option<u64>,
+
+  /// Refuse the whole batch if the estimated total exceeds this many cents.
+  /// (Providers billed to the user's own API keys are priced in USD.)
+  pub max_cost_in_usd_cents: Option<u64>,
+
+  /// How many requests to have in flight against any one provider.
+  pub max_concurrent_per_provider: Option<usize>,
+
+  /// Expand and estimate the batch, but don't enqueue anything.
+  pub dry_run: Option<bool>,
+}
+
+/// The cells are the cartesian product `prompts × models × aspect_ratios`.
+/// Empty axes fall back to the template's value.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GenerationBatchMatrix {
+  Image {
+    template: TauriGenerateImageRequest,
+    #[serde(default)]
+    models: Vec<TauriImageModel>,
+    #[serde(default)]
+    aspect_ratios: Vec<EnumsAspectRatio>,
+  },
+  Video {
+    template: TauriGenerateVideoRequest,
+    #[serde(default)]
+    models: Vec<TauriVideoModel>,
+    #[serde(default)]
+    aspect_ratios: Vec<RouterAspectRatio>,
+  },
+}
+
+// ── Response ──
+
+#[derive(Serialize)]
+pub struct EnqueueGenerationBatchResponse {
+  /// Tasks in the batch carry this id (absent on a dry run).
+  pub batch_id: Option<TaskBatchId>,
+
+  pub estimate: GenerationBatchEstimate,
+}
+
+impl SerializeMarker for EnqueueGenerationBatchResponse {}
+
+#[derive(Serialize, Debug)]
+pub struct GenerationBatchEstimate {
+  /// Sum over the cells priced in credits.
+  pub total_cost_in_credits: u64,
+
+  /// Sum over the cells priced in USD.
+  pub total_cost_in_usd_cents: u64,
+
+  /// Cells we couldn't price. A batch with a spend cap can't contain any.
+  pub unpriced_cell_count: usize,
+
+  pub cells: Vec<GenerationBatchCellSummary>,
+}
+
+/// One cell of the comparison grid. `index` matches `batch_index` on the task.
+#[derive(Serialize, Debug)]
+pub struct GenerationBatchCellSummary {
+  pub index: u32,
+  pub prompt: Option<String>,
+  pub model: Option<String>,
+  pub aspect_ratio: Option<String>,
+  pub provider: GenerationProvider,
+  pub cost_in_credits: Option<u64>,
+  pub cost_in_usd_cents: Option<u64>,
+  pub is_free: bool,
+}
+
+// ── Error ──
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum EnqueueGenerationBatchErrorType {
+  /// The matrix expanded to nothing.
+  EmptyBatch,
+  /// The matrix expanded to more cells than we allow in one batch.
+  TooManyCells,
+  /// A spend cap was set, but some cells couldn't be priced.
+  UnpricedCells,
+  /// The estimate exceeds a spend cap.
+  SpendCapExceeded,
+}
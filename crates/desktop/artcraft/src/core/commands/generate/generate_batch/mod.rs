@@ -0,0 +1,6 @@
+pub mod batch_task_throttle;
+pub mod enqueue_generation_batch_command;
+pub mod estimate_generation_batch_cost;
+pub mod expand_generation_batch;
+pub mod generation_batch_request;
+pub mod task_batch_slot;
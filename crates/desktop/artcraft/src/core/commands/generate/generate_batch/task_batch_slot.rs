@@ -0,0 +1,9 @@
+use tokens::tokens::sqlite::task_batches::TaskBatchId;
+
+/// Where a single generation sits within a batch.
+/// The batch command sets this on each expanded request so the task row can be grouped.
+#[derive(Clone, Debug)]
+pub struct TaskBatchSlot {
+  pub batch_id: TaskBatchId,
+  pub batch_index: u32,
+}
@@ -1,3 +1,4 @@
+use crate::core::commands::generate::generate_batch::batch_task_throttle::bind_batch_task_permit;
 use crate::core::commands::enqueue::common::notify_frontend_of_errors::notify_frontend_of_errors;
 use crate::core::commands::enqueue::generate_error::{GenerateError, MissingCredentialsReason};
 use crate::core::commands::enqueue::task_enqueue_success::TaskEnqueueSuccess;
@@ -11,6 +12,7 @@ use crate::core::commands::response::shorthand::Response;
 use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
 use crate::core::events::functional_events::credits_balance_changed_event::CreditsBalanceChangedEvent;
 use crate::core::events::generation_events::generation_enqueue_success_event::GenerationEnqueueSuccessEvent;
+use crate::core::generation_history::record_enqueued_generation::{record_enqueued_generation, serialized_name, EnqueuedGenerationDetails};
 use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::state::task_database::TaskDatabase;
@@ -77,16 +79,23 @@ async fn handle_success_behavior(
 
   // Insert into task database
   let db_result = success
-      .insert_into_task_database_with_frontend_payload(
+      .insert_into_task_database_with_frontend_payload_and_batch(
         &task_database,
         request.frontend_caller,
         request.frontend_subscriber_id.as_deref(),
         request.frontend_subscriber_payload.as_deref(),
+        request.task_batch.as_ref(),
       )
       .await;
 
-  if let Err(err) = db_result {
-    error!("Failed to create task in database: {:?}", err);
+  match db_result {
+    Ok(task_id) => {
+      bind_batch_task_permit(app, request.task_batch.as_ref(), &task_id);
+      record_enqueued_generation(app, &task_id, &success, request, generation_history_details(request)).await;
+    }
+    Err(err) => {
+      error!("Failed to create task in database: {:?}", err);
+    }
   }
 
   /*
@@ -117,6 +126,27 @@ async fn handle_success_behavior(
   Ok(TauriGenerateImageResponse {}.into())
 }
 
+fn generation_history_details(request: &TauriGenerateImageRequest) -> EnqueuedGenerationDetails<'_> {
+  let reference_media_tokens = request.canvas_image_media_token.iter()
+      .chain(request.image_media_tokens.iter().flatten())
+      .chain(request.scene_image_media_token.iter())
+      .chain(request.inpainting_mask_image_media_token.iter())
+      .cloned()
+      .collect();
+
+  EnqueuedGenerationDetails {
+    frontend_model: request.model.as_ref().and_then(serialized_name),
+    prompt: request.prompt.as_deref(),
+    negative_prompt: None,
+    aspect_ratio: request.aspect_ratio.as_ref().and_then(serialized_name),
+    resolution: request.resolution.as_ref().and_then(serialized_name),
+    batch_size: request.batch_size,
+    duration_seconds: None,
+    reference_media_tokens,
+    cost_in_credits: request.estimated_cost_in_credits,
+  }
+}
+
 async fn handle_error_behavior(
   app: &AppHandle,
   err: GenerateError,
@@ -6,12 +6,14 @@ use enums::tauri::ux::tauri_command_caller::TauriCommandCaller;
 use serde_derive::{Deserialize, Serialize};
 use tokens::tokens::media_files::MediaFileToken;
 
+use crate::core::commands::generate::generate_batch::task_batch_slot::TaskBatchSlot;
 use crate::core::commands::generate::generate_image::tauri_image_model::TauriImageModel;
 use crate::core::commands::response::success_response_wrapper::SerializeMarker;
 
 // ── Request ──
 
-#[derive(Deserialize, Debug)]
+/// NB: This is also serialized into the generation history so it can be re-run.
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct TauriGenerateImageRequest {
   /// The provider to use (defaults to Artcraft/Storyteller).
   /// Not all (provider, model) combinations are valid.
@@ -47,6 +49,7 @@ pub struct TauriGenerateImageRequest {
 
   /// Supply this *XOR* `canvas_image_media_token`.
   /// Raw bytes of a canvas image.
+  #[serde(skip_serializing)] // NB: Too large to keep in the generation history.
   pub canvas_image_raw_bytes: Option<Vec<u8>>,
 
   /// Supply this *XOR* `scene_image_raw_bytes`.
@@ -55,6 +58,7 @@ pub struct TauriGenerateImageRequest {
 
   /// Supply this *XOR* `scene_image_media_token`.
   /// Raw bytes of a scene image.
+  #[serde(skip_serializing)] // NB: Too large to keep in the generation history.
   pub scene_image_raw_bytes: Option<Vec<u8>>,
 
   // ── Inpainting ──
@@ -65,6 +69,7 @@ pub struct TauriGenerateImageRequest {
 
   /// Supply this *XOR* `inpainting_mask_image_media_token`.
   /// The mask to focus the edit (raw bytes).
+  #[serde(skip_serializing)] // NB: Too large to keep in the generation history.
   pub inpainting_mask_image_raw_bytes: Option<Vec<u8>>,
 
   // ── Angle adjustment (for edit models like QwenEdit, Flux2LoraAngles) ──
@@ -81,6 +86,10 @@ pub struct TauriGenerateImageRequest {
   /// Turn on the system prompt.
   pub enable_system_prompt: Option<bool>,
 
+  /// The cost the frontend quoted the user (from `estimate_image_cost_command`).
+  /// This is only recorded in the generation history.
+  pub estimated_cost_in_credits: Option<u64>,
+
   // ── Frontend metadata ──
 
   /// Name of the frontend caller.
@@ -91,6 +100,10 @@ pub struct TauriGenerateImageRequest {
 
   /// A frontend-defined payload sent back as a Tauri event on task completion.
   pub frontend_subscriber_payload: Option<String>,
+
+  /// Set by the batch command. Not part of the frontend API.
+  #[serde(skip)]
+  pub task_batch: Option<TaskBatchSlot>,
 }
 
 // ── Response ──
@@ -1,11 +1,11 @@
 use enums::common::generation::common_model_type::CommonModelType;
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 /// Unified image model enum covering text-to-image, image edit, and inpainting.
 ///
 /// This is used in the Tauri command bridge.
 /// Don't change the serializations without coordinating with the frontend.
-#[derive(Deserialize, Debug, Copy, Clone)]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum TauriImageModel {
   // Text-to-image models
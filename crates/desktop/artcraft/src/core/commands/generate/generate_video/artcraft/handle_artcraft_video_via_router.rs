@@ -28,10 +28,14 @@ pub(super) async fn handle_artcraft_video_via_router(
   model: CommonVideoModel,
   generation_model: GenerationModel,
 ) -> Result<TaskEnqueueSuccess, GenerateError> {
-  let client = RouterClient::Artcraft(RouterArtcraftClient::new(
-    app_env_configs.storyteller_host.clone(),
-    creds.clone(),
-  ));
+  // NB: With the mock provider enabled, nothing reaches Artcraft; the mock task poller finishes the task.
+  let (client, provider) = match app_env_configs.mock_provider.as_ref() {
+    Some(mock) => (RouterClient::Mock(mock.clone()), Provider::Mock),
+    None => (RouterClient::Artcraft(RouterArtcraftClient::new(
+      app_env_configs.storyteller_host.clone(),
+      creds.clone(),
+    )), Provider::Artcraft),
+  };
 
   let start_frame = request.image_media_token.clone().map(ImageRef::MediaFileToken);
   let end_frame = request.end_frame_image_media_token.clone().map(ImageRef::MediaFileToken);
@@ -44,7 +48,7 @@ pub(super) async fn handle_artcraft_video_via_router(
 
   let router_request = GenerateVideoRequestBuilder {
     model,
-    provider: Provider::Artcraft,
+    provider,
     prompt: request.prompt.clone(),
     start_frame,
     end_frame,
@@ -72,6 +76,7 @@ pub(super) async fn handle_artcraft_video_via_router(
 
   let job_id = response.get_artcraft_payload()
     .map(|p| p.inference_job_token.to_string())
+    .or_else(|| response.get_mock_payload().map(|p| p.job_id))
     .ok_or(GenerateError::ResponseHadNoJobTokens)?;
 
   Ok(TaskEnqueueSuccess {
@@ -101,7 +106,7 @@ async fn generate_via_v1(
   Ok(response)
 }
 
-/// V2 pipeline: build2 → send_request (Artcraft skips draft phase).
+/// V2 pipeline: build2 → send_request (Artcraft and Mock skip the draft phase).
 async fn generate_via_v2(
   router_request: GenerateVideoRequestBuilder,
   client: &RouterClient,
@@ -111,7 +116,7 @@ async fn generate_via_v2(
   let request = match draft_or_request {
     VideoGenerationDraftOrRequest::Request(r) => r,
     VideoGenerationDraftOrRequest::Draft(_) => {
-      error!("Unexpected Draft variant for Artcraft provider");
+      error!("Unexpected Draft variant for Artcraft/Mock provider");
       return Err(GenerateError::NotYetImplemented("Artcraft provider should not produce a draft request".to_string()));
     }
   };
@@ -1,3 +1,4 @@
+use crate::core::commands::generate::generate_batch::batch_task_throttle::bind_batch_task_permit;
 use crate::core::commands::enqueue::common::notify_frontend_of_errors::notify_frontend_of_errors;
 use crate::core::commands::enqueue::generate_error::{BadInputReason, GenerateError, MissingCredentialsReason};
 use crate::core::commands::enqueue::task_enqueue_success::TaskEnqueueSuccess;
@@ -13,6 +14,7 @@ use crate::core::commands::response::shorthand::Response;
 use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
 use crate::core::events::functional_events::credits_balance_changed_event::CreditsBalanceChangedEvent;
 use crate::core::events::generation_events::generation_enqueue_success_event::GenerationEnqueueSuccessEvent;
+use crate::core::generation_history::record_enqueued_generation::{record_enqueued_generation, serialized_name, EnqueuedGenerationDetails};
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::state::artcraft_usage_tracker::artcraft_usage_tracker::ArtcraftUsageTracker;
 use crate::core::state::artcraft_usage_tracker::artcraft_usage_type::{ArtcraftUsagePage, ArtcraftUsageType};
@@ -148,10 +150,7 @@ async fn handle_request(
     }
   };
 
-  let provider = match (model, request.provider) {
-    (TauriVideoModel::GrokVideo, _) => GenerationProvider::Grok,
-    _ => request.provider.unwrap_or(GenerationProvider::Artcraft),
-  };
+  let provider = resolve_video_provider(model, request.provider);
 
   info!("generate video with {:?} via provider {:?}", &model, &provider);
 
@@ -190,16 +189,23 @@ async fn handle_request(
   };
 
   let result = success_event
-    .insert_into_task_database_with_frontend_payload(
+    .insert_into_task_database_with_frontend_payload_and_batch(
       task_database,
       request.frontend_caller,
       request.frontend_subscriber_id.as_deref(),
-      request.frontend_subscriber_payload.as_deref()
+      request.frontend_subscriber_payload.as_deref(),
+      request.task_batch.as_ref(),
     )
     .await;
 
-  if let Err(err) = result {
-    error!("Failed to create task in database: {:?}", err);
+  match result {
+    Ok(task_id) => {
+      bind_batch_task_permit(app, request.task_batch.as_ref(), &task_id);
+      record_enqueued_generation(app, &task_id, &success_event, &request, generation_history_details(&request)).await;
+    }
+    Err(err) => {
+      error!("Failed to create task in database: {:?}", err);
+    }
   }
 
   if let Err(err) = artcraft_usage_tracker.record_video_generation(1, ArtcraftUsageType::ImageToResult, ArtcraftUsagePage::VideoPage) {
@@ -208,3 +214,34 @@ async fn handle_request(
 
   Ok(success_event)
 }
+
+/// Grok video is only available through Grok. Everything else defaults to Artcraft.
+pub fn resolve_video_provider(model: TauriVideoModel, provider: Option<GenerationProvider>) -> GenerationProvider {
+  match model {
+    TauriVideoModel::GrokVideo => GenerationProvider::Grok,
+    _ => provider.unwrap_or(GenerationProvider::Artcraft),
+  }
+}
+
+fn generation_history_details(request: &TauriGenerateVideoRequest) -> EnqueuedGenerationDetails<'_> {
+  // NB: The deprecated `image_media_token` mirrors `start_frame_image_media_token`.
+  let reference_media_tokens = request.start_frame_image_media_token.iter()
+      .chain(request.end_frame_image_media_token.iter())
+      .chain(request.reference_image_media_tokens.iter().flatten())
+      .chain(request.reference_video_media_tokens.iter().flatten())
+      .chain(request.reference_audio_media_tokens.iter().flatten())
+      .cloned()
+      .collect();
+
+  EnqueuedGenerationDetails {
+    frontend_model: request.model.as_ref().and_then(serialized_name),
+    prompt: request.prompt.as_deref(),
+    negative_prompt: request.negative_prompt.as_deref(),
+    aspect_ratio: request.aspect_ratio.as_ref().and_then(serialized_name),
+    resolution: request.resolution.as_ref().and_then(serialized_name),
+    batch_size: request.video_batch_count.map(|count| count as u32),
+    duration_seconds: request.duration_seconds.map(|seconds| seconds as u32),
+    reference_media_tokens,
+    cost_in_credits: request.estimated_cost_in_credits,
+  }
+}
@@ -6,11 +6,12 @@ use serde_derive::{Deserialize, Serialize};
 use tokens::tokens::characters::CharacterToken;
 use tokens::tokens::media_files::MediaFileToken;
 
+use crate::core::commands::generate::generate_batch::task_batch_slot::TaskBatchSlot;
 use crate::core::commands::response::success_response_wrapper::SerializeMarker;
 
 /// This is used in the Tauri command bridge.
 /// Don't change the serializations without coordinating with the frontend.
-#[derive(Deserialize, Debug, Copy, Clone)]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum TauriVideoModel {
   #[serde(rename = "grok_video")]
@@ -74,7 +75,8 @@ pub enum TauriVideoModel {
   Veo3p1Fast,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+/// NB: This is also serialized into the generation history so it can be re-run.
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct TauriGenerateVideoRequest {
   pub provider: Option<GenerationProvider>,
   pub model: Option<TauriVideoModel>,
@@ -100,6 +102,10 @@ pub struct TauriGenerateVideoRequest {
   pub generate_audio: Option<bool>,
   pub video_batch_count: Option<u16>,
 
+  /// The cost the frontend quoted the user (from `estimate_video_cost_command`).
+  /// This is only recorded in the generation history.
+  pub estimated_cost_in_credits: Option<u64>,
+
   #[deprecated(note = "Use start_frame_image_media_token instead")]
   pub sora_orientation: Option<SoraOrientation>,
 
@@ -109,16 +115,20 @@ pub struct TauriGenerateVideoRequest {
   pub frontend_caller: Option<TauriCommandCaller>,
   pub frontend_subscriber_id: Option<String>,
   pub frontend_subscriber_payload: Option<String>,
+
+  /// Set by the batch command. Not part of the frontend API.
+  #[serde(skip)]
+  pub task_batch: Option<TaskBatchSlot>,
 }
 
-#[derive(Deserialize, Debug, Clone, Copy)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum SoraOrientation {
   Portrait,
   Landscape,
 }
 
-#[derive(Deserialize, Debug, Clone, Copy)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum GrokAspectRatio {
   Portrait,
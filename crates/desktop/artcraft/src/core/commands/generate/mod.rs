@@ -1,3 +1,4 @@
+pub mod generate_batch;
 pub mod generate_image;
 pub mod generate_video;
 pub mod common;
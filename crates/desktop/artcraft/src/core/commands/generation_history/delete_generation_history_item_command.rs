@@ -0,0 +1,43 @@
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::state::generation_history_database::GenerationHistoryDatabase;
+use log::{error, info};
+use serde_derive::{Deserialize, Serialize};
+use sqlite_history::queries::delete_generation::delete_generation;
+use tauri::State;
+use tokens::tokens::sqlite::generation_history::GenerationHistoryId;
+
+#[derive(Deserialize)]
+pub struct DeleteGenerationHistoryItemRequest {
+  id: GenerationHistoryId,
+}
+
+#[derive(Serialize)]
+pub struct DeleteGenerationHistoryItemResponse {
+  /// False if the item was already gone.
+  deleted: bool,
+}
+
+impl SerializeMarker for DeleteGenerationHistoryItemResponse {}
+
+/// Remove an item from the library. The generated media itself is left alone.
+#[tauri::command]
+pub async fn delete_generation_history_item_command(
+  request: DeleteGenerationHistoryItemRequest,
+  history_database: State<'_, GenerationHistoryDatabase>,
+) -> ResponseOrErrorMessage<DeleteGenerationHistoryItemResponse> {
+
+  info!("delete_generation_history_item_command called for: {:?}", request.id);
+
+  let result = delete_generation(history_database.get_connection(), &request.id).await;
+
+  match result {
+    Ok(deleted) => Ok(DeleteGenerationHistoryItemResponse {
+      deleted,
+    }.into()),
+    Err(err) => {
+      error!("delete_generation_history_item_command failed: {:?}", err);
+      Err("delete_generation_history_item_command failed".into())
+    }
+  }
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use enums::common::generation_provider::GenerationProvider;
+use enums::tauri::tasks::task_model_type::TaskModelType;
+use enums::tauri::tasks::task_status::TaskStatus;
+use enums::tauri::tasks::task_type::TaskType;
+use serde_derive::Serialize;
+use sqlite_history::queries::generation::Generation;
+use tokens::tokens::batch_generations::BatchGenerationToken;
+use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::sqlite::generation_history::GenerationHistoryId;
+use tokens::tokens::sqlite::tasks::TaskId;
+
+#[derive(Serialize)]
+pub struct GenerationHistoryItem {
+  pub id: GenerationHistoryId,
+  pub task_id: Option<TaskId>,
+
+  pub generation_type: TaskType,
+  pub status: TaskStatus,
+  pub provider: GenerationProvider,
+  pub model_type: Option<TaskModelType>,
+
+  /// The model name the frontend sent (eg. "nano_banana_pro").
+  pub frontend_model: Option<String>,
+
+  pub prompt: Option<String>,
+  pub negative_prompt: Option<String>,
+  pub aspect_ratio: Option<String>,
+  pub resolution: Option<String>,
+  pub seed: Option<i64>,
+  pub batch_size: Option<u32>,
+  pub duration_seconds: Option<u32>,
+  pub reference_media_tokens: Vec<MediaFileToken>,
+
+  /// The original generate request, so the frontend can restore its settings.
+  pub request: Option<serde_json::Value>,
+
+  pub cost_in_credits: Option<u64>,
+
+  pub output_media_file_token: Option<MediaFileToken>,
+  pub output_batch_token: Option<BatchGenerationToken>,
+  pub output_cdn_url: Option<String>,
+  pub output_thumbnail_url_template: Option<String>,
+
+  /// Where the user has downloaded the output to.
+  pub output_file_paths: Vec<String>,
+
+  pub failure_message: Option<String>,
+
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+  pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<Generation> for GenerationHistoryItem {
+  fn from(generation: Generation) -> Self {
+    Self {
+      id: generation.id,
+      task_id: generation.task_id,
+      generation_type: generation.generation_type,
+      status: generation.status,
+      provider: generation.provider,
+      model_type: generation.model_type,
+      frontend_model: generation.frontend_model,
+      prompt: generation.prompt,
+      negative_prompt: generation.negative_prompt,
+      aspect_ratio: generation.aspect_ratio,
+      resolution: generation.resolution,
+      seed: generation.seed,
+      batch_size: generation.batch_size,
+      duration_seconds: generation.duration_seconds,
+      reference_media_tokens: generation.reference_media_tokens,
+      request: serde_json::from_str(&generation.request_json).ok(),
+      cost_in_credits: generation.cost_in_credits,
+      output_media_file_token: generation.output_media_file_token,
+      output_batch_token: generation.output_batch_token,
+      output_cdn_url: generation.output_cdn_url,
+      output_thumbnail_url_template: generation.output_thumbnail_url_template,
+      output_file_paths: generation.output_file_paths,
+      failure_message: generation.failure_message,
+      created_at: generation.created_at,
+      updated_at: generation.updated_at,
+      completed_at: generation.completed_at,
+    }
+  }
+}
@@ -0,0 +1,43 @@
+use crate::core::commands::generation_history::generation_history_item::GenerationHistoryItem;
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::state::generation_history_database::GenerationHistoryDatabase;
+use log::{error, info};
+use serde_derive::{Deserialize, Serialize};
+use sqlite_history::queries::get_generation_by_id::get_generation_by_id;
+use tauri::State;
+use tokens::tokens::sqlite::generation_history::GenerationHistoryId;
+
+#[derive(Deserialize)]
+pub struct GetGenerationHistoryItemRequest {
+  id: GenerationHistoryId,
+}
+
+#[derive(Serialize)]
+pub struct GetGenerationHistoryItemResponse {
+  generation: GenerationHistoryItem,
+}
+
+impl SerializeMarker for GetGenerationHistoryItemResponse {}
+
+#[tauri::command]
+pub async fn get_generation_history_item_command(
+  request: GetGenerationHistoryItemRequest,
+  history_database: State<'_, GenerationHistoryDatabase>,
+) -> ResponseOrErrorMessage<GetGenerationHistoryItemResponse> {
+
+  info!("get_generation_history_item_command called for: {:?}", request.id);
+
+  let result = get_generation_by_id(history_database.get_connection(), &request.id).await;
+
+  match result {
+    Ok(Some(generation)) => Ok(GetGenerationHistoryItemResponse {
+      generation: generation.into(),
+    }.into()),
+    Ok(None) => Err("generation not found".into()),
+    Err(err) => {
+      error!("get_generation_history_item_command failed: {:?}", err);
+      Err("get_generation_history_item_command failed".into())
+    }
+  }
+}
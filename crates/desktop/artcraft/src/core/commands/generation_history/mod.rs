@@ -0,0 +1,5 @@
+pub mod delete_generation_history_item_command;
+pub mod generation_history_item;
+pub mod get_generation_history_item_command;
+pub mod rerun_generation_command;
+pub mod search_generation_history_command;
@@ -0,0 +1,122 @@
+use crate::core::commands::generate::generate_image::generate_image_command::generate_image_command;
+use crate::core::commands::generate::generate_image::tauri_generate_image_request::TauriGenerateImageRequest;
+use crate::core::commands::generate::generate_video::generate_video_command::generate_video_command;
+use crate::core::commands::generate::generate_video::request::TauriGenerateVideoRequest;
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::state::generation_history_database::GenerationHistoryDatabase;
+use enums::tauri::tasks::task_type::TaskType;
+use log::{error, info};
+use serde_derive::{Deserialize, Serialize};
+use sqlite_history::queries::get_generation_by_id::get_generation_by_id;
+use tauri::{AppHandle, Manager, State};
+use tokens::tokens::sqlite::generation_history::GenerationHistoryId;
+
+#[derive(Deserialize)]
+pub struct RerunGenerationRequest {
+  id: GenerationHistoryId,
+
+  /// Optionally replace the original prompt.
+  prompt: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct RerunGenerationResponse {
+}
+
+impl SerializeMarker for RerunGenerationResponse {}
+
+/// Enqueue a past generation again with the same settings (and the same reference media).
+/// The new generation shows up as its own item in the history.
+///
+/// Canvas, scene, and mask images that were sent as raw bytes aren't kept in the history,
+/// so those generations re-run without them.
+#[tauri::command]
+pub async fn rerun_generation_command(
+  request: RerunGenerationRequest,
+  app: AppHandle,
+  history_database: State<'_, GenerationHistoryDatabase>,
+) -> ResponseOrErrorMessage<RerunGenerationResponse> {
+
+  info!("rerun_generation_command called for: {:?}", request.id);
+
+  let generation = match get_generation_by_id(history_database.get_connection(), &request.id).await {
+    Ok(Some(generation)) => generation,
+    Ok(None) => return Err("generation not found".into()),
+    Err(err) => {
+      error!("rerun_generation_command failed to load generation: {:?}", err);
+      return Err("rerun_generation_command failed".into())
+    }
+  };
+
+  let maybe_error_message = match generation.generation_type {
+    TaskType::ImageGeneration | TaskType::ImageInpaintEdit => {
+      let mut generate_request: TauriGenerateImageRequest = match serde_json::from_str(&generation.request_json) {
+        Ok(generate_request) => generate_request,
+        Err(err) => {
+          error!("rerun_generation_command could not read image request: {:?}", err);
+          return Err("this generation can't be re-run".into())
+        }
+      };
+
+      if let Some(prompt) = request.prompt {
+        generate_request.prompt = Some(prompt);
+      }
+
+      // NB: The subscriber belonged to the original UI request.
+      generate_request.frontend_subscriber_id = None;
+      generate_request.frontend_subscriber_payload = None;
+
+      generate_image_command(
+        generate_request,
+        app.clone(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+      ).await.err().map(|err| err.error_message)
+    }
+    TaskType::VideoGeneration => {
+      let mut generate_request: TauriGenerateVideoRequest = match serde_json::from_str(&generation.request_json) {
+        Ok(generate_request) => generate_request,
+        Err(err) => {
+          error!("rerun_generation_command could not read video request: {:?}", err);
+          return Err("this generation can't be re-run".into())
+        }
+      };
+
+      if let Some(prompt) = request.prompt {
+        generate_request.prompt = Some(prompt);
+      }
+
+      // NB: The subscriber belonged to the original UI request.
+      generate_request.frontend_subscriber_id = None;
+      generate_request.frontend_subscriber_payload = None;
+
+      generate_video_command(
+        generate_request,
+        app.clone(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+        app.state(),
+      ).await.err().map(|err| err.error_message)
+    }
+    other => {
+      info!("rerun_generation_command can't re-run {:?} generations", other);
+      return Err("this generation can't be re-run".into())
+    }
+  };
+
+  match maybe_error_message {
+    None => Ok(RerunGenerationResponse {}.into()),
+    Some(maybe_message) => {
+      Err(maybe_message.unwrap_or_else(|| "rerun_generation_command failed".to_string()).into())
+    }
+  }
+}
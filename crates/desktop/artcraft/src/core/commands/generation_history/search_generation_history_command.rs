@@ -0,0 +1,82 @@
+use crate::core::commands::generation_history::generation_history_item::GenerationHistoryItem;
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::state::generation_history_database::GenerationHistoryDatabase;
+use chrono::{DateTime, Utc};
+use enums::common::generation_provider::GenerationProvider;
+use enums::tauri::tasks::task_model_type::TaskModelType;
+use enums::tauri::tasks::task_status::TaskStatus;
+use enums::tauri::tasks::task_type::TaskType;
+use errors::AnyhowResult;
+use log::{debug, error};
+use serde_derive::{Deserialize, Serialize};
+use sqlite_history::queries::search_generations::{search_generations, SearchGenerationsArgs};
+use tauri::State;
+
+/// With no query or filters, this lists the whole library (newest first).
+#[derive(Deserialize, Debug)]
+pub struct SearchGenerationHistoryRequest {
+  /// Free text matched against prompts and model names.
+  pub query: Option<String>,
+
+  pub generation_type: Option<TaskType>,
+  pub provider: Option<GenerationProvider>,
+  pub model_type: Option<TaskModelType>,
+  pub status: Option<TaskStatus>,
+  pub created_after: Option<DateTime<Utc>>,
+  pub created_before: Option<DateTime<Utc>>,
+
+  pub limit: Option<u32>,
+  pub offset: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct SearchGenerationHistoryResponse {
+  pub generations: Vec<GenerationHistoryItem>,
+}
+
+impl SerializeMarker for SearchGenerationHistoryResponse {}
+
+#[tauri::command]
+pub async fn search_generation_history_command(
+  request: SearchGenerationHistoryRequest,
+  history_database: State<'_, GenerationHistoryDatabase>,
+) -> ResponseOrErrorMessage<SearchGenerationHistoryResponse> {
+
+  // NB: This is debug because the frontend searches as the user types.
+  debug!("search_generation_history_command called: {:?}", request);
+
+  let generations = match handle_request(&request, &history_database).await {
+    Ok(generations) => generations,
+    Err(err) => {
+      error!("search_generation_history_command failed: {:?}", err);
+      return Err("search_generation_history_command failed".into())
+    }
+  };
+
+  Ok(SearchGenerationHistoryResponse {
+    generations,
+  }.into())
+}
+
+pub async fn handle_request(
+  request: &SearchGenerationHistoryRequest,
+  history_database: &GenerationHistoryDatabase,
+) -> AnyhowResult<Vec<GenerationHistoryItem>> {
+  let generations = search_generations(SearchGenerationsArgs {
+    db: history_database.get_connection(),
+    maybe_query: request.query.as_deref(),
+    maybe_generation_type: request.generation_type,
+    maybe_provider: request.provider,
+    maybe_model_type: request.model_type,
+    maybe_status: request.status,
+    maybe_created_after: request.created_after,
+    maybe_created_before: request.created_before,
+    maybe_limit: request.limit,
+    maybe_offset: request.offset,
+  }).await?;
+
+  Ok(generations.into_iter()
+      .map(GenerationHistoryItem::from)
+      .collect())
+}
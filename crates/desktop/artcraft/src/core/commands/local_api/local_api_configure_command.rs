@@ -0,0 +1,71 @@
+use crate::core::commands::local_api::local_api_status::LocalApiStatus;
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::local_api::local_api_config::LocalApiConfig;
+use crate::core::local_api::local_api_server::LocalApiServer;
+use crate::core::state::data_dir::app_data_root::AppDataRoot;
+use errors::AnyhowResult;
+use log::{error, info};
+use serde_derive::Deserialize;
+use tauri::{AppHandle, State};
+
+#[derive(Deserialize, Debug)]
+pub struct LocalApiConfigureRequest {
+  /// Turn the server on or off.
+  pub enabled: Option<bool>,
+
+  /// Change the port. The server restarts if it's running.
+  pub port: Option<u16>,
+
+  /// Invalidate the old token. The server restarts if it's running.
+  pub rotate_token: Option<bool>,
+}
+
+#[tauri::command]
+pub async fn local_api_configure_command(
+  request: LocalApiConfigureRequest,
+  app: AppHandle,
+  app_data_root: State<'_, AppDataRoot>,
+  local_api_server: State<'_, LocalApiServer>,
+) -> ResponseOrErrorMessage<LocalApiStatus> {
+
+  info!("local_api_configure_command called: {:?}", request);
+
+  match handle_request(request, &app, &app_data_root, &local_api_server).await {
+    Ok(status) => Ok(status.into()),
+    Err(err) => {
+      error!("local_api_configure_command failed: {:?}", err);
+      Err(format!("Could not configure the local API: {}", err).into())
+    }
+  }
+}
+
+async fn handle_request(
+  request: LocalApiConfigureRequest,
+  app: &AppHandle,
+  app_data_root: &AppDataRoot,
+  local_api_server: &LocalApiServer,
+) -> AnyhowResult<LocalApiStatus> {
+  let mut config = LocalApiConfig::load_or_default(app_data_root);
+
+  if let Some(enabled) = request.enabled {
+    config.enabled = enabled;
+  }
+
+  if let Some(port) = request.port {
+    config.port = port;
+  }
+
+  if request.rotate_token.unwrap_or(false) || (config.enabled && config.token.is_empty()) {
+    config.rotate_token()?;
+  }
+
+  config.save(app_data_root)?;
+
+  if config.enabled {
+    local_api_server.start(app, &config).await?;
+  } else {
+    local_api_server.stop().await;
+  }
+
+  Ok(LocalApiStatus::from_config(&config, local_api_server))
+}
@@ -0,0 +1,15 @@
+use crate::core::commands::local_api::local_api_status::LocalApiStatus;
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::local_api::local_api_config::LocalApiConfig;
+use crate::core::local_api::local_api_server::LocalApiServer;
+use crate::core::state::data_dir::app_data_root::AppDataRoot;
+use tauri::State;
+
+#[tauri::command]
+pub async fn local_api_get_status_command(
+  app_data_root: State<'_, AppDataRoot>,
+  local_api_server: State<'_, LocalApiServer>,
+) -> ResponseOrErrorMessage<LocalApiStatus> {
+  let config = LocalApiConfig::load_or_default(&app_data_root);
+  Ok(LocalApiStatus::from_config(&config, &local_api_server).into())
+}
@@ -0,0 +1,30 @@
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::local_api::local_api_config::LocalApiConfig;
+use crate::core::local_api::local_api_server::LocalApiServer;
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+pub struct LocalApiStatus {
+  pub enabled: bool,
+
+  /// Whether the server is listening. It can be enabled but not running if the port is taken.
+  pub running: bool,
+
+  pub port: u16,
+
+  /// Shown to the user so they can paste it into their scripts.
+  pub token: Option<String>,
+}
+
+impl SerializeMarker for LocalApiStatus {}
+
+impl LocalApiStatus {
+  pub fn from_config(config: &LocalApiConfig, server: &LocalApiServer) -> Self {
+    Self {
+      enabled: config.enabled,
+      running: server.running_port().is_some(),
+      port: config.port,
+      token: Some(config.token.clone()).filter(|token| !token.is_empty()),
+    }
+  }
+}
@@ -0,0 +1,3 @@
+pub mod local_api_configure_command;
+pub mod local_api_get_status_command;
+pub mod local_api_status;
@@ -5,8 +5,10 @@ pub mod download;
 pub mod enqueue;
 pub mod flip_image;
 pub mod generate;
+pub mod generation_history;
 pub mod get_app_info_command;
 pub mod load_without_cors_command;
+pub mod local_api;
 pub mod media_files;
 pub mod platform_info_command;
 pub mod providers;
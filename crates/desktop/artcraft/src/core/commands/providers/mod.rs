@@ -1,4 +1,10 @@
 pub mod deprecated;
 pub mod provider_list_command;
 pub mod provider_set_api_key_command;
-pub mod provider_clear_command;
\ No newline at end of file
+pub mod provider_clear_command;
+pub mod provider_export_credentials_command;
+pub mod provider_import_credentials_command;
+pub mod provider_vault_reset_command;
+pub mod provider_vault_set_passphrase_command;
+pub mod provider_vault_status_command;
+pub mod provider_vault_unlock_command;
\ No newline at end of file
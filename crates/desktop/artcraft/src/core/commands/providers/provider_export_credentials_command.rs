@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCacheError;
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+use log::{error, info};
+use serde_derive::{Deserialize, Serialize};
+use tauri::State;
+
+#[derive(Deserialize)]
+pub struct ProviderExportCredentialsRequest {
+  /// Protects the bundle. Needed again to import it.
+  pub passphrase: String,
+
+  /// Where to write the bundle.
+  pub path: PathBuf,
+}
+
+#[derive(Serialize)]
+pub struct ProviderExportCredentialsResponse {}
+
+impl SerializeMarker for ProviderExportCredentialsResponse {}
+
+#[tauri::command]
+pub async fn provider_export_credentials_command(
+  request: ProviderExportCredentialsRequest,
+  credential_cache: State<'_, ProviderCredentialLoadingCache>,
+) -> ResponseOrErrorMessage<ProviderExportCredentialsResponse> {
+  info!("provider_export_credentials_command called; path: {:?}", request.path);
+
+  if request.passphrase.is_empty() {
+    return Err("A passphrase is required to export credentials".into());
+  }
+
+  let cache = credential_cache.inner().clone();
+  let passphrase = request.passphrase;
+
+  // NB: The passphrase KDF takes a noticeable fraction of a second; keep it off the async runtime.
+  let result = tauri::async_runtime::spawn_blocking(move || cache.export_bundle(&passphrase))
+    .await
+    .map_err(|err| {
+      error!("Credential export task failed: {:?}", err);
+      "Failed to export provider credentials"
+    })?;
+
+  let bundle = result
+    .map_err(|err| {
+      error!("Failed to export provider credentials: {:?}", err);
+      match err {
+        ProviderCredentialLoadingCacheError::VaultError(CredentialVaultError::Locked) => "Unlock your credentials first",
+        _ => "Failed to export provider credentials",
+      }
+    })?;
+
+  std::fs::write(&request.path, bundle)
+    .map_err(|err| {
+      error!("Failed to write credential bundle: {:?}", err);
+      "Failed to write credential bundle"
+    })?;
+
+  Ok(ProviderExportCredentialsResponse {}.into())
+}
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::providers::credentials::provider_credential_key::ProviderCredentialKey;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCacheError;
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+use log::{error, info};
+use serde_derive::{Deserialize, Serialize};
+use tauri::State;
+
+#[derive(Deserialize)]
+pub struct ProviderImportCredentialsRequest {
+  /// The passphrase the bundle was exported with.
+  pub passphrase: String,
+
+  /// The bundle to read.
+  pub path: PathBuf,
+}
+
+#[derive(Serialize)]
+pub struct ProviderImportCredentialsResponse {
+  pub imported: Vec<ProviderCredentialKey>,
+}
+
+impl SerializeMarker for ProviderImportCredentialsResponse {}
+
+#[tauri::command]
+pub async fn provider_import_credentials_command(
+  request: ProviderImportCredentialsRequest,
+  credential_cache: State<'_, ProviderCredentialLoadingCache>,
+) -> ResponseOrErrorMessage<ProviderImportCredentialsResponse> {
+  info!("provider_import_credentials_command called; path: {:?}", request.path);
+
+  let bundle = std::fs::read(&request.path)
+    .map_err(|err| {
+      error!("Failed to read credential bundle: {:?}", err);
+      "Failed to read credential bundle"
+    })?;
+
+  let cache = credential_cache.inner().clone();
+  let passphrase = request.passphrase;
+
+  // NB: The passphrase KDF takes a noticeable fraction of a second; keep it off the async runtime.
+  let result = tauri::async_runtime::spawn_blocking(move || cache.import_bundle(&bundle, &passphrase))
+    .await
+    .map_err(|err| {
+      error!("Credential import task failed: {:?}", err);
+      "Failed to import provider credentials"
+    })?;
+
+  let imported = result
+    .map_err(|err| {
+      error!("Failed to import provider credentials: {:?}", err);
+      match err {
+        ProviderCredentialLoadingCacheError::VaultError(CredentialVaultError::WrongKey) => "Wrong passphrase for credential bundle",
+        ProviderCredentialLoadingCacheError::VaultError(CredentialVaultError::Locked) => "Unlock your credentials first",
+        _ => "Failed to import provider credentials",
+      }
+    })?;
+
+  Ok(ProviderImportCredentialsResponse { imported }.into())
+}
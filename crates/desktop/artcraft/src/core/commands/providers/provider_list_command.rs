@@ -8,16 +8,6 @@ use log::{info, warn};
 use serde_derive::Serialize;
 use tauri::State;
 
-/// All known credential keys. Add new ones here as providers are added.
-const ALL_KEYS: &[ProviderCredentialKey] = &[
-  ProviderCredentialKey::FalApiKey,
-  ProviderCredentialKey::ReplicateApiKey,
-  ProviderCredentialKey::GrokWebLogin,
-  ProviderCredentialKey::HiggsfieldWebLogin,
-  ProviderCredentialKey::MidjourneyLogin,
-  ProviderCredentialKey::RunwayWebLogin,
-];
-
 const REDACTED_KEY_VISIBLE_CHARS: usize = 6;
 
 #[derive(Serialize)]
@@ -53,9 +43,9 @@ pub async fn provider_list_command(
 ) -> ResponseOrErrorMessage<ProviderListResponse> {
   info!("provider_list_command called");
 
-  let mut providers = Vec::with_capacity(ALL_KEYS.len());
+  let mut providers = Vec::with_capacity(ProviderCredentialKey::ALL.len());
 
-  for &key in ALL_KEYS {
+  for &key in ProviderCredentialKey::ALL {
     let maybe_payload = match credential_cache.get_credentials(key) {
       Ok(payload) => payload,
       Err(err) => {
@@ -0,0 +1,31 @@
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
+use log::{error, info, warn};
+use serde_derive::Serialize;
+use tauri::State;
+
+#[derive(Serialize)]
+pub struct ProviderVaultResetResponse {}
+
+impl SerializeMarker for ProviderVaultResetResponse {}
+
+/// Discard every stored provider credential and start over with an empty vault.
+/// For a forgotten passphrase or a vault that won't open; the user confirms first.
+#[tauri::command]
+pub async fn provider_vault_reset_command(
+  credential_cache: State<'_, ProviderCredentialLoadingCache>,
+) -> ResponseOrErrorMessage<ProviderVaultResetResponse> {
+  info!("provider_vault_reset_command called");
+  warn!("User requested a credential vault reset");
+
+  credential_cache.vault_slot().reset()
+    .map_err(|err| {
+      error!("Failed to reset credential vault: {:?}", err);
+      "Failed to reset credentials"
+    })?;
+
+  credential_cache.invalidate_all();
+
+  Ok(ProviderVaultResetResponse {}.into())
+}
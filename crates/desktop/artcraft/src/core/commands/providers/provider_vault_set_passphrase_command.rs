@@ -0,0 +1,54 @@
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+use log::{error, info};
+use serde_derive::{Deserialize, Serialize};
+use tauri::State;
+
+#[derive(Deserialize)]
+pub struct ProviderVaultSetPassphraseRequest {
+  /// The new passphrase, or none to go back to the machine-bound key.
+  pub maybe_passphrase: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ProviderVaultSetPassphraseResponse {}
+
+impl SerializeMarker for ProviderVaultSetPassphraseResponse {}
+
+/// Protect stored credentials with a passphrase (asked for on every launch), or remove
+/// the passphrase. The vault must be unlocked.
+#[tauri::command]
+pub async fn provider_vault_set_passphrase_command(
+  request: ProviderVaultSetPassphraseRequest,
+  credential_cache: State<'_, ProviderCredentialLoadingCache>,
+) -> ResponseOrErrorMessage<ProviderVaultSetPassphraseResponse> {
+  info!("provider_vault_set_passphrase_command called; passphrase: {}", request.maybe_passphrase.is_some());
+
+  if request.maybe_passphrase.as_deref().is_some_and(|passphrase| passphrase.is_empty()) {
+    return Err("The passphrase can't be empty".into());
+  }
+
+  let slot = credential_cache.vault_slot().clone();
+
+  // NB: The passphrase KDF takes a noticeable fraction of a second; keep it off the async runtime.
+  let result = tauri::async_runtime::spawn_blocking(move || slot.set_passphrase(request.maybe_passphrase.as_deref()))
+    .await
+    .map_err(|err| {
+      error!("Credential vault passphrase task failed: {:?}", err);
+      "Failed to change the credential passphrase"
+    })?;
+
+  result.map_err(|err| {
+    error!("Failed to change credential vault passphrase: {:?}", err);
+    match err {
+      CredentialVaultError::Locked => "Unlock your credentials first",
+      _ => "Failed to change the credential passphrase",
+    }
+  })?;
+
+  credential_cache.invalidate_all();
+
+  Ok(ProviderVaultSetPassphraseResponse {}.into())
+}
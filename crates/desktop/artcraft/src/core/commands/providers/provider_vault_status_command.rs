@@ -0,0 +1,27 @@
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
+use crate::core::providers::credentials::vault::credential_vault_slot::CredentialVaultStatus;
+use log::info;
+use serde_derive::Serialize;
+use tauri::State;
+
+#[derive(Serialize)]
+pub struct ProviderVaultStatusResponse {
+  pub status: CredentialVaultStatus,
+}
+
+impl SerializeMarker for ProviderVaultStatusResponse {}
+
+/// Whether the credential vault is locked (and why), and whether stored credentials had
+/// to be discarded at startup, so the frontend can tell the user.
+#[tauri::command]
+pub async fn provider_vault_status_command(
+  credential_cache: State<'_, ProviderCredentialLoadingCache>,
+) -> ResponseOrErrorMessage<ProviderVaultStatusResponse> {
+  info!("provider_vault_status_command called");
+
+  Ok(ProviderVaultStatusResponse {
+    status: credential_cache.vault_slot().status(),
+  }.into())
+}
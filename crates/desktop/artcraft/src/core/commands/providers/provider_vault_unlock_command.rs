@@ -0,0 +1,48 @@
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+use log::{error, info};
+use serde_derive::{Deserialize, Serialize};
+use tauri::State;
+
+#[derive(Deserialize)]
+pub struct ProviderVaultUnlockRequest {
+  pub passphrase: String,
+}
+
+#[derive(Serialize)]
+pub struct ProviderVaultUnlockResponse {}
+
+impl SerializeMarker for ProviderVaultUnlockResponse {}
+
+/// Open a passphrase-protected credential vault.
+#[tauri::command]
+pub async fn provider_vault_unlock_command(
+  request: ProviderVaultUnlockRequest,
+  credential_cache: State<'_, ProviderCredentialLoadingCache>,
+) -> ResponseOrErrorMessage<ProviderVaultUnlockResponse> {
+  info!("provider_vault_unlock_command called");
+
+  let slot = credential_cache.vault_slot().clone();
+
+  // NB: The passphrase KDF takes a noticeable fraction of a second; keep it off the async runtime.
+  let result = tauri::async_runtime::spawn_blocking(move || slot.unlock(&request.passphrase))
+    .await
+    .map_err(|err| {
+      error!("Credential vault unlock task failed: {:?}", err);
+      "Failed to unlock credentials"
+    })?;
+
+  result.map_err(|err| {
+    error!("Failed to unlock credential vault: {:?}", err);
+    match err {
+      CredentialVaultError::WrongKey => "Wrong passphrase",
+      _ => "Failed to unlock credentials",
+    }
+  })?;
+
+  credential_cache.invalidate_all();
+
+  Ok(ProviderVaultUnlockResponse {}.into())
+}
@@ -0,0 +1,184 @@
+use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
+use crate::core::commands::response::success_response_wrapper::SerializeMarker;
+use crate::core::generation_history::record_generation_outcome::record_generation_failure;
+use crate::core::providers::credentials::payload::provider_credential_payload::ProviderCredentialPayload;
+use crate::core::providers::credentials::provider_credential_key::ProviderCredentialKey;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
+use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
+use crate::core::state::task_database::TaskDatabase;
+use crate::services::storyteller::state::storyteller_credential_manager::StorytellerCredentialManager;
+use anyhow::anyhow;
+use artcraft_router::api::provider::Provider;
+use artcraft_router::client::router_artcraft_client::RouterArtcraftClient;
+use artcraft_router::client::router_client::RouterClient;
+use artcraft_router::client::router_fal_webhook_optional_client::RouterFalWebhookOptionalClient;
+use artcraft_router::client::router_mock_client::RouterMockClient;
+use artcraft_router::generate::cancel_generation::cancel_generation_outcome::CancelGenerationOutcome;
+use artcraft_router::generate::cancel_generation::cancel_generation_request::CancelGenerationRequest;
+use enums::common::generation_provider::GenerationProvider;
+use enums::tauri::tasks::task_status::TaskStatus;
+use errors::AnyhowResult;
+use log::{error, info};
+use serde_derive::{Deserialize, Serialize};
+use sqlite_tasks::queries::get_task_by_id::{get_task_by_id, GetTaskByIdArgs};
+use sqlite_tasks::queries::task::Task;
+use sqlite_tasks::queries::update_task_status::{update_task_status, UpdateTaskArgs};
+use tauri::{AppHandle, State};
+use tokens::tokens::sqlite::tasks::TaskId;
+
+#[derive(Deserialize)]
+pub struct CancelTaskRequest {
+  task: TaskId,
+}
+
+#[derive(Serialize)]
+pub struct CancelTaskResponse {
+  outcome: CancelTaskOutcome,
+}
+
+impl SerializeMarker for CancelTaskResponse {}
+
+#[derive(Serialize, Copy, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum CancelTaskOutcome {
+  Cancelled,
+  /// The provider accepted the request but hasn't confirmed it yet; the task keeps
+  /// its status until the provider reports it as finished.
+  CancellationRequested,
+  AlreadyFinished,
+  NotCancellable,
+}
+
+/// Ask the task's provider to stop it. The task is only marked cancelled if the
+/// provider confirms; otherwise it keeps running and finishes normally.
+#[tauri::command]
+pub async fn cancel_task_command(
+  request: CancelTaskRequest,
+  app: AppHandle,
+  app_env_configs: State<'_, AppEnvConfigs>,
+  task_database: State<'_, TaskDatabase>,
+  credential_cache: State<'_, ProviderCredentialLoadingCache>,
+  storyteller_creds_manager: State<'_, StorytellerCredentialManager>,
+) -> ResponseOrErrorMessage<CancelTaskResponse> {
+
+  info!("cancel_task_command called for task: {:?}", request.task);
+
+  let result = handle_request(
+    &request.task,
+    &app_env_configs,
+    &task_database,
+    &credential_cache,
+    &storyteller_creds_manager,
+  ).await;
+
+  let outcome = match result {
+    Ok(outcome) => outcome,
+    Err(err) => {
+      error!("cancel_task_command failed: {:?}", err);
+      return Err("cancel_task_command failed".into())
+    }
+  };
+
+  if let CancelTaskOutcome::Cancelled = outcome {
+    record_generation_failure(&app, &request.task, TaskStatus::CancelledByUser, None).await;
+  }
+
+  Ok(CancelTaskResponse {
+    outcome,
+  }.into())
+}
+
+pub async fn handle_request(
+  task_id: &TaskId,
+  app_env_configs: &AppEnvConfigs,
+  task_database: &TaskDatabase,
+  credential_cache: &ProviderCredentialLoadingCache,
+  storyteller_creds_manager: &StorytellerCredentialManager,
+) -> AnyhowResult<CancelTaskOutcome> {
+  let task = get_task_by_id(GetTaskByIdArgs {
+    db: task_database.get_connection(),
+    task_id,
+  }).await?
+    .ok_or_else(|| anyhow!("no such task: {:?}", task_id))?;
+
+  match task.status {
+    TaskStatus::Pending | TaskStatus::Started | TaskStatus::AttemptFailed => {}
+    _ => return Ok(CancelTaskOutcome::AlreadyFinished),
+  }
+
+  let Some(provider_job_id) = task.provider_job_id.clone() else {
+    return Ok(CancelTaskOutcome::NotCancellable);
+  };
+
+  let Some((provider, client)) = build_router_client(&task, app_env_configs, credential_cache, storyteller_creds_manager)? else {
+    return Ok(CancelTaskOutcome::NotCancellable);
+  };
+
+  let cancel_request = CancelGenerationRequest {
+    provider,
+    provider_job_id,
+    maybe_queue_status_url: task.queue_status_url.clone(),
+  };
+
+  let outcome = match cancel_request.send(&client).await? {
+    CancelGenerationOutcome::Cancelled => CancelTaskOutcome::Cancelled,
+    CancelGenerationOutcome::CancellationRequested => return Ok(CancelTaskOutcome::CancellationRequested),
+    CancelGenerationOutcome::AlreadyFinished => return Ok(CancelTaskOutcome::AlreadyFinished),
+    CancelGenerationOutcome::NotCancellable => return Ok(CancelTaskOutcome::NotCancellable),
+  };
+
+  update_task_status(UpdateTaskArgs {
+    db: task_database.get_connection(),
+    task_id,
+    status: TaskStatus::CancelledByUser,
+  }).await?;
+
+  Ok(outcome)
+}
+
+/// Returns `None` for providers we can't cancel (web logins, etc).
+fn build_router_client(
+  task: &Task,
+  app_env_configs: &AppEnvConfigs,
+  credential_cache: &ProviderCredentialLoadingCache,
+  storyteller_creds_manager: &StorytellerCredentialManager,
+) -> AnyhowResult<Option<(Provider, RouterClient)>> {
+  match task.provider {
+    GenerationProvider::Artcraft => {
+      // NB: Mock tasks are stored as Artcraft tasks; see `mock_task_polling_thread`.
+      if let Some(mock) = maybe_mock_client(task, app_env_configs) {
+        return Ok(Some((Provider::Mock, RouterClient::Mock(mock))));
+      }
+      let creds = storyteller_creds_manager.get_credentials_required()?;
+      let client = RouterClient::Artcraft(RouterArtcraftClient::new(
+        app_env_configs.storyteller_host.clone(),
+        creds,
+      ));
+      Ok(Some((Provider::Artcraft, client)))
+    }
+    GenerationProvider::Fal => {
+      let payload = credential_cache.get_credentials(ProviderCredentialKey::FalApiKey)
+        .map_err(|err| anyhow!("Failed to load credentials: {:?}", err))?;
+      match payload {
+        Some(ProviderCredentialPayload::ApiKey(api_key)) => {
+          let client = RouterClient::FalWebhookOptional(RouterFalWebhookOptionalClient::from_str(api_key.as_str()));
+          Ok(Some((Provider::Fal, client)))
+        }
+        _ => Err(anyhow!("No FAL API key")),
+      }
+    }
+    GenerationProvider::Grok
+      | GenerationProvider::Midjourney
+      | GenerationProvider::Sora
+      | GenerationProvider::WorldLabs => Ok(None),
+  }
+}
+
+fn maybe_mock_client(task: &Task, app_env_configs: &AppEnvConfigs) -> Option<RouterMockClient> {
+  let is_mock_job = task.provider_job_id.as_deref()
+      .is_some_and(RouterMockClient::is_mock_job_id);
+  if !is_mock_job {
+    return None;
+  }
+  app_env_configs.mock_provider.clone()
+}
@@ -22,6 +22,7 @@ use enums::tauri::tasks::task_failure_type::TaskFailureType;
 use enums::tauri::tasks::task_media_file_class::TaskMediaFileClass;
 use tokens::tokens::batch_generations::BatchGenerationToken;
 use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::sqlite::task_batches::TaskBatchId;
 use tokens::tokens::sqlite::tasks::TaskId;
 
 #[derive(Serialize)]
@@ -40,6 +41,12 @@ pub struct TaskQueueItem {
   pub provider: Option<GenerationProvider>,
   pub provider_job_id: Option<String>,
 
+  /// Set if the task was enqueued by a batch generation.
+  pub batch_id: Option<TaskBatchId>,
+
+  /// The task's cell in the batch, for laying out the comparison grid.
+  pub batch_index: Option<u32>,
+
   /// If the item is done, these will be filled out.
   pub completed_item: Option<CompletedItemData>,
 
@@ -161,6 +168,8 @@ pub async fn handle_request(
       model_type: task.model_type,
       provider: task.provider,
       provider_job_id: task.provider_job_id,
+      batch_id: task.batch_id,
+      batch_index: task.batch_index,
       created_at: task.created_at,
       updated_at: task.updated_at,
       completed_at: task.completed_at,
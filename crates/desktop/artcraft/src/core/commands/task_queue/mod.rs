@@ -1,3 +1,4 @@
+pub mod cancel_task_command;
 pub mod get_task_queue_command;
 pub mod mark_task_as_dismissed_command;
 pub mod tasks_nuke_all_command;
\ No newline at end of file
@@ -1,3 +1,4 @@
+use crate::core::commands::generate::generate_batch::batch_task_throttle::BatchTaskThrottle;
 use crate::core::commands::response::shorthand::ResponseOrErrorMessage;
 use crate::core::commands::response::success_response_wrapper::SerializeMarker;
 use crate::core::events::generation_events::generation_enqueue_success_event::GenerationEnqueueSuccessEvent;
@@ -30,6 +31,7 @@ impl SerializeMarker for TasksNukeAllResponse {}
 #[tauri::command]
 pub async fn tasks_nuke_all_command(
   task_database: State<'_, TaskDatabase>,
+  batch_task_throttle: State<'_, BatchTaskThrottle>,
 ) -> ResponseOrErrorMessage<TasksNukeAllResponse> {
 
   info!("tasks_nuke_all_command called");
@@ -43,6 +45,9 @@ pub async fn tasks_nuke_all_command(
     return Err("tasks_nuke_all_command failed".into())
   }
 
+  // The wiped tasks will never report completion, so don't let them hold up running batches.
+  batch_task_throttle.release_all();
+
   Ok(TasksNukeAllResponse{
     success: true,
   }.into())
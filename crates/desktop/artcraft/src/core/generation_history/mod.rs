@@ -0,0 +1,2 @@
+pub mod record_enqueued_generation;
+pub mod record_generation_outcome;
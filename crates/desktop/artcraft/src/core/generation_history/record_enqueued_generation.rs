@@ -0,0 +1,77 @@
+use crate::core::commands::enqueue::task_enqueue_success::TaskEnqueueSuccess;
+use crate::core::state::generation_history_database::GenerationHistoryDatabase;
+use log::{error, info};
+use serde::Serialize;
+use sqlite_history::queries::insert_generation::{insert_generation, InsertGenerationArgs};
+use tauri::{AppHandle, Manager};
+use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::sqlite::tasks::TaskId;
+
+/// What the user asked for, as shown (and searched) in their generation history.
+pub struct EnqueuedGenerationDetails<'a> {
+  pub frontend_model: Option<String>,
+  pub prompt: Option<&'a str>,
+  pub negative_prompt: Option<&'a str>,
+  pub aspect_ratio: Option<String>,
+  pub resolution: Option<String>,
+  pub batch_size: Option<u32>,
+  pub duration_seconds: Option<u32>,
+  pub reference_media_tokens: Vec<MediaFileToken>,
+  pub cost_in_credits: Option<u64>,
+}
+
+/// Add a newly enqueued generation to the user's history.
+/// The full `request` is kept so the generation can be re-run later.
+///
+/// This fails open: history is never allowed to break generation.
+pub async fn record_enqueued_generation<R: Serialize>(
+  app: &AppHandle,
+  task_id: &TaskId,
+  success: &TaskEnqueueSuccess,
+  request: &R,
+  details: EnqueuedGenerationDetails<'_>,
+) {
+  let Some(history_database) = app.try_state::<GenerationHistoryDatabase>() else {
+    error!("Generation history database is not available; not recording task {:?}", task_id);
+    return;
+  };
+
+  let request_json = match serde_json::to_string(request) {
+    Ok(json) => json,
+    Err(err) => {
+      error!("Could not serialize request for generation history: {:?}", err);
+      return;
+    }
+  };
+
+  let result = insert_generation(InsertGenerationArgs {
+    db: history_database.get_connection(),
+    task_id: Some(task_id),
+    generation_type: success.task_type,
+    provider: success.provider,
+    model_type: success.task_model_type(),
+    frontend_model: details.frontend_model.as_deref(),
+    prompt: details.prompt,
+    negative_prompt: details.negative_prompt,
+    aspect_ratio: details.aspect_ratio.as_deref(),
+    resolution: details.resolution.as_deref(),
+    seed: None, // NB: None of the generate commands let the user pick a seed (yet).
+    batch_size: details.batch_size,
+    duration_seconds: details.duration_seconds,
+    reference_media_tokens: &details.reference_media_tokens,
+    request_json: &request_json,
+    cost_in_credits: details.cost_in_credits,
+  }).await;
+
+  match result {
+    Ok(id) => info!("Recorded generation history item {:?} for task {:?}", id, task_id),
+    Err(err) => error!("Failed to record generation history: {:?}", err),
+  }
+}
+
+/// The wire name of a frontend enum (eg. a model or aspect ratio), for display and filtering.
+pub fn serialized_name<T: Serialize>(value: &T) -> Option<String> {
+  serde_json::to_value(value)
+      .ok()
+      .and_then(|value| value.as_str().map(|name| name.to_string()))
+}
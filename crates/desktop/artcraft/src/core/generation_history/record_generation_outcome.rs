@@ -0,0 +1,85 @@
+use crate::core::commands::generate::generate_batch::batch_task_throttle::release_batch_task_permit;
+use crate::core::state::generation_history_database::GenerationHistoryDatabase;
+use enums::tauri::tasks::task_status::TaskStatus;
+use log::error;
+use sqlite_history::queries::add_generation_output_file_path::add_generation_output_file_path;
+use sqlite_history::queries::mark_generation_complete::{mark_generation_complete, MarkGenerationCompleteArgs};
+use sqlite_history::queries::mark_generation_unsuccessful::{mark_generation_unsuccessful, MarkGenerationUnsuccessfulArgs};
+use tauri::{AppHandle, Manager};
+use tokens::tokens::batch_generations::BatchGenerationToken;
+use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::sqlite::tasks::TaskId;
+
+pub struct GenerationOutputs<'a> {
+  pub task_id: &'a TaskId,
+  pub maybe_batch_token: Option<&'a BatchGenerationToken>,
+  pub maybe_media_file_token: Option<&'a MediaFileToken>,
+  pub maybe_cdn_url: Option<&'a str>,
+  pub maybe_thumbnail_url_template: Option<&'a str>,
+}
+
+/// Record a task's outputs in the generation history. Fails open.
+/// (Tasks enqueued before history existed, or by other commands, simply aren't found.)
+/// This is also where a finished batch task gives back its provider concurrency permit.
+pub async fn record_generation_success(app: &AppHandle, outputs: GenerationOutputs<'_>) {
+  release_batch_task_permit(app, outputs.task_id);
+
+  let Some(history_database) = app.try_state::<GenerationHistoryDatabase>() else {
+    return;
+  };
+
+  let result = mark_generation_complete(MarkGenerationCompleteArgs {
+    db: history_database.get_connection(),
+    task_id: outputs.task_id,
+    maybe_batch_token: outputs.maybe_batch_token,
+    maybe_media_file_token: outputs.maybe_media_file_token,
+    maybe_cdn_url: outputs.maybe_cdn_url,
+    maybe_thumbnail_url_template: outputs.maybe_thumbnail_url_template,
+  }).await;
+
+  if let Err(err) = result {
+    error!("Failed to record generation success in history: {:?}", err);
+  }
+}
+
+/// Record that a task failed or was cancelled in the generation history. Fails open.
+pub async fn record_generation_failure(
+  app: &AppHandle,
+  task_id: &TaskId,
+  status: TaskStatus,
+  maybe_failure_message: Option<&str>,
+) {
+  release_batch_task_permit(app, task_id);
+
+  let Some(history_database) = app.try_state::<GenerationHistoryDatabase>() else {
+    return;
+  };
+
+  let result = mark_generation_unsuccessful(MarkGenerationUnsuccessfulArgs {
+    db: history_database.get_connection(),
+    task_id,
+    status,
+    maybe_failure_message,
+  }).await;
+
+  if let Err(err) = result {
+    error!("Failed to record generation failure in history: {:?}", err);
+  }
+}
+
+/// Remember where the user saved a generated file. Fails open.
+pub async fn record_generation_output_file_path(app: &AppHandle, media_file_token: &MediaFileToken, file_path: &str) {
+  let Some(history_database) = app.try_state::<GenerationHistoryDatabase>() else {
+    return;
+  };
+
+  let result = add_generation_output_file_path(
+    history_database.get_connection(),
+    media_file_token,
+    file_path,
+  ).await;
+
+  if let Err(err) = result {
+    error!("Failed to record generation output file path in history: {:?}", err);
+  }
+}
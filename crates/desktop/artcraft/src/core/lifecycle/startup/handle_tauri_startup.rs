@@ -1,6 +1,8 @@
+use crate::core::lifecycle::startup::tasks::bootstrap_generation_history_database::bootstrap_generation_history_database;
 use crate::core::lifecycle::startup::tasks::bootstrap_task_database::bootstrap_task_database;
 use crate::core::lifecycle::startup::tasks::initially_size_and_position_windows::initially_size_and_position_windows;
 use crate::core::lifecycle::startup::tasks::load_provider_priority_state::load_provider_priority_state;
+use crate::core::lifecycle::startup::tasks::start_local_api_if_enabled::start_local_api_if_enabled;
 use crate::core::lifecycle::startup::tasks::set_app_log_level::set_app_log_level;
 use crate::core::lifecycle::startup::tasks::spawn_discord_presence_thread::spawn_discord_presence_thread;
 use crate::core::lifecycle::startup::tasks::spawn_main_window_thread::spawn_main_window_thread;
@@ -23,6 +25,7 @@ use crate::services::worldlabs::state::worldlabs_bearer_bridge::WorldlabsBearerB
 use crate::services::worldlabs::state::worldlabs_credential_manager::WorldlabsCredentialManager;
 use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
 use crate::core::threads::third_party_task_polling_thread::third_party_task_polling_thread::third_party_task_polling_thread;
+use crate::services::mock_provider::threads::mock_task_polling_thread::mock_task_polling_thread;
 use crate::services::worldlabs::threads::worldlabs_marble_task_polling::worldlabs_marble_task_polling;
 use errors::AnyhowResult;
 use tauri::{AppHandle, Manager};
@@ -52,6 +55,10 @@ pub async fn handle_tauri_startup(
   let task_database =
       bootstrap_task_database(&app, &root).await?;
 
+  bootstrap_generation_history_database(&app, &root).await?;
+
+  start_local_api_if_enabled(&app, &root).await;
+
   load_provider_priority_state(
     &app,
     &root,
@@ -119,6 +126,14 @@ pub async fn handle_tauri_startup(
     storyteller_creds_manager.clone(),
   ));
 
+  if let Some(mock_client) = app_env_configs.mock_provider.clone() {
+    tauri::async_runtime::spawn(mock_task_polling_thread(
+      app.clone(),
+      task_database.clone(),
+      mock_client,
+    ));
+  }
+
   tauri::async_runtime::spawn(third_party_task_polling_thread(
     app.clone(),
     app_env_configs.clone(),
@@ -0,0 +1,10 @@
+use crate::core::state::data_dir::app_data_root::AppDataRoot;
+use crate::core::state::generation_history_database::GenerationHistoryDatabase;
+use errors::AnyhowResult;
+use tauri::{AppHandle, Manager};
+
+pub async fn bootstrap_generation_history_database(app: &AppHandle, root: &AppDataRoot) -> AnyhowResult<GenerationHistoryDatabase> {
+  let history_database = GenerationHistoryDatabase::connect(root).await?;
+  app.manage(history_database.clone());
+  Ok(history_database)
+}
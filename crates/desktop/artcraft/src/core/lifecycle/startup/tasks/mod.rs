@@ -1,3 +1,4 @@
+pub (super) mod bootstrap_generation_history_database;
 pub (super) mod bootstrap_task_database;
 pub (super) mod set_app_log_level;
 pub (super) mod initially_size_and_position_windows;
@@ -6,3 +7,4 @@ pub (super) mod spawn_main_window_thread;
 pub (super) mod spawn_sora_task_polling_thread;
 pub (super) mod spawn_storyteller_threads;
 pub (super) mod load_provider_priority_state;
+pub (super) mod start_local_api_if_enabled;
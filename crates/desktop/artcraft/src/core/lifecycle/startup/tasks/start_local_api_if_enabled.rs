@@ -0,0 +1,22 @@
+use crate::core::local_api::local_api_config::LocalApiConfig;
+use crate::core::local_api::local_api_server::LocalApiServer;
+use crate::core::state::data_dir::app_data_root::AppDataRoot;
+use log::error;
+use tauri::{AppHandle, Manager};
+
+/// The local API is opt-in. A failure to start it (eg. the port is taken) shouldn't stop the app.
+pub async fn start_local_api_if_enabled(app: &AppHandle, root: &AppDataRoot) {
+  app.manage(LocalApiServer::default());
+
+  let config = LocalApiConfig::load_or_default(root);
+
+  if !config.enabled {
+    return;
+  }
+
+  let server = app.state::<LocalApiServer>();
+
+  if let Err(err) = server.start(app, &config).await {
+    error!("Could not start the local API: {:?}", err);
+  }
+}
@@ -0,0 +1,113 @@
+use crate::core::local_api::local_api_state::LocalApiState;
+use actix_web::dev::Payload;
+use actix_web::error::{ErrorInternalServerError, ErrorUnauthorized};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::{web, FromRequest, HttpRequest};
+use std::future::{ready, Ready};
+
+/// Extractor that rejects requests without the local API bearer token.
+pub struct LocalApiAuth;
+
+impl FromRequest for LocalApiAuth {
+  type Error = actix_web::Error;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+    let Some(state) = request.app_data::<web::Data<LocalApiState>>() else {
+      return ready(Err(ErrorInternalServerError("local API is misconfigured")));
+    };
+
+    ready(authorize(request, &state.token))
+  }
+}
+
+/// An empty `expected_token` (the API was never enabled) matches nothing.
+fn authorize(request: &HttpRequest, expected_token: &str) -> Result<LocalApiAuth, actix_web::Error> {
+  let maybe_token = request.headers()
+      .get(AUTHORIZATION)
+      .and_then(|value| value.to_str().ok())
+      .and_then(|value| value.strip_prefix("Bearer "));
+
+  match maybe_token {
+    Some(token) if !expected_token.is_empty() && constant_time_eq(token.as_bytes(), expected_token.as_bytes()) => {
+      Ok(LocalApiAuth)
+    }
+    _ => Err(ErrorUnauthorized("missing or invalid bearer token")),
+  }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  if a.len() != b.len() {
+    return false;
+  }
+  a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use actix_web::http::StatusCode;
+  use actix_web::test::TestRequest;
+
+  const TOKEN: &str = "0123456789abcdef";
+
+  /// None if the request is let through.
+  fn rejection(maybe_authorization: Option<&str>, expected_token: &str) -> Option<StatusCode> {
+    let mut request = TestRequest::default();
+    if let Some(authorization) = maybe_authorization {
+      request = request.insert_header((AUTHORIZATION, authorization));
+    }
+    authorize(&request.to_http_request(), expected_token)
+        .err()
+        .map(|err| err.as_response_error().status_code())
+  }
+
+  #[test]
+  fn correct_token_is_accepted() {
+    assert_eq!(rejection(Some(&format!("Bearer {TOKEN}")), TOKEN), None);
+  }
+
+  #[test]
+  fn missing_header_is_rejected() {
+    assert_eq!(rejection(None, TOKEN), Some(StatusCode::UNAUTHORIZED));
+  }
+
+  #[test]
+  fn wrong_token_is_rejected() {
+    assert_eq!(rejection(Some("Bearer fedcba9876543210"), TOKEN), Some(StatusCode::UNAUTHORIZED));
+    assert_eq!(rejection(Some(&format!("Bearer {TOKEN}0")), TOKEN), Some(StatusCode::UNAUTHORIZED));
+    assert_eq!(rejection(Some("Bearer "), TOKEN), Some(StatusCode::UNAUTHORIZED));
+  }
+
+  #[test]
+  fn empty_token_matches_nothing() {
+    assert_eq!(rejection(Some("Bearer "), ""), Some(StatusCode::UNAUTHORIZED));
+    assert_eq!(rejection(None, ""), Some(StatusCode::UNAUTHORIZED));
+  }
+
+  #[test]
+  fn other_schemes_are_rejected() {
+    assert_eq!(rejection(Some(TOKEN), TOKEN), Some(StatusCode::UNAUTHORIZED));
+    assert_eq!(rejection(Some(&format!("Basic {TOKEN}")), TOKEN), Some(StatusCode::UNAUTHORIZED));
+    assert_eq!(rejection(Some(&format!("bearer {TOKEN}")), TOKEN), Some(StatusCode::UNAUTHORIZED));
+    assert_eq!(rejection(Some(&format!("Bearer  {TOKEN}")), TOKEN), Some(StatusCode::UNAUTHORIZED));
+  }
+
+  #[test]
+  fn missing_state_is_a_server_error() {
+    let request = TestRequest::default()
+        .insert_header((AUTHORIZATION, format!("Bearer {TOKEN}")))
+        .to_http_request();
+    let result = LocalApiAuth::from_request(&request, &mut Payload::None).into_inner();
+    let status = result.err().map(|err| err.as_response_error().status_code());
+    assert_eq!(status, Some(StatusCode::INTERNAL_SERVER_ERROR));
+  }
+
+  #[test]
+  fn test_constant_time_eq() {
+    assert!(constant_time_eq(b"abc123", b"abc123"));
+    assert!(!constant_time_eq(b"abc123", b"abc124"));
+    assert!(!constant_time_eq(b"abc123", b"abc1234"));
+    assert!(constant_time_eq(b"", b""));
+  }
+}
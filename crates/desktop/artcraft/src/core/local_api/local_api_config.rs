@@ -0,0 +1,140 @@
+use crate::core::providers::credentials::vault::vault_key::write_private_file;
+use crate::core::state::data_dir::app_data_root::AppDataRoot;
+use errors::{anyhow, AnyhowResult};
+use log::{info, warn};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_derive::{Deserialize, Serialize};
+
+pub const DEFAULT_LOCAL_API_PORT: u16 = 47820;
+
+const TOKEN_BYTES: usize = 32;
+
+/// Stored alongside the provider credentials, since the token grants spending the user's credits.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LocalApiConfig {
+  /// The server only runs when the user opts in.
+  pub enabled: bool,
+
+  /// Port on 127.0.0.1.
+  pub port: u16,
+
+  /// Bearer token that scripts must send.
+  pub token: String,
+}
+
+impl LocalApiConfig {
+  pub fn load_or_default(root: &AppDataRoot) -> Self {
+    let path = root.credentials_dir().get_local_api_config_path();
+    if !path.exists() {
+      return Self::new_disabled();
+    }
+
+    let result = std::fs::read_to_string(&path)
+        .map_err(|err| anyhow!(err))
+        .and_then(|contents| serde_json::from_str::<Self>(&contents).map_err(|err| anyhow!(err)));
+
+    match result {
+      Ok(config) => config,
+      Err(err) => {
+        warn!("Could not read local API config (it will be reset): {:?}", err);
+        Self::new_disabled()
+      }
+    }
+  }
+
+  pub fn save(&self, root: &AppDataRoot) -> AnyhowResult<()> {
+    let path = root.credentials_dir().get_local_api_config_path();
+    let contents = serde_json::to_vec_pretty(self)?;
+    write_private_file(&path, &contents)?;
+    Ok(())
+  }
+
+  pub fn rotate_token(&mut self) -> AnyhowResult<()> {
+    info!("Rotating local API token");
+    self.token = generate_token()?;
+    Ok(())
+  }
+
+  fn new_disabled() -> Self {
+    Self {
+      enabled: false,
+      port: DEFAULT_LOCAL_API_PORT,
+      // NB: A token is generated when the user enables the API.
+      token: String::new(),
+    }
+  }
+}
+
+fn generate_token() -> AnyhowResult<String> {
+  let mut bytes = [0u8; TOKEN_BYTES];
+  SystemRandom::new()
+      .fill(&mut bytes)
+      .map_err(|_| anyhow!("could not generate random token"))?;
+
+  Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn data_root() -> (tempfile::TempDir, AppDataRoot) {
+    let dir = tempfile::tempdir().unwrap();
+    let root = AppDataRoot::create_existing(dir.path()).unwrap();
+    (dir, root)
+  }
+
+  #[test]
+  fn disabled_by_default() {
+    let (_dir, root) = data_root();
+    let config = LocalApiConfig::load_or_default(&root);
+    assert!(!config.enabled);
+    assert_eq!(config.port, DEFAULT_LOCAL_API_PORT);
+    assert!(config.token.is_empty());
+    assert!(!root.credentials_dir().get_local_api_config_path().exists());
+  }
+
+  #[test]
+  fn save_and_load_round_trip() {
+    let (_dir, root) = data_root();
+
+    let mut config = LocalApiConfig::load_or_default(&root);
+    config.enabled = true;
+    config.port = 51234;
+    config.rotate_token().unwrap();
+    config.save(&root).unwrap();
+
+    let loaded = LocalApiConfig::load_or_default(&root);
+    assert!(loaded.enabled);
+    assert_eq!(loaded.port, 51234);
+    assert_eq!(loaded.token, config.token);
+  }
+
+  #[test]
+  fn rotate_token_replaces_the_token() {
+    let (_dir, root) = data_root();
+
+    let mut config = LocalApiConfig::load_or_default(&root);
+    config.rotate_token().unwrap();
+    let first = config.token.clone();
+    assert_eq!(first.len(), TOKEN_BYTES * 2);
+    assert!(first.chars().all(|c| c.is_ascii_hexdigit()));
+
+    config.rotate_token().unwrap();
+    assert_ne!(config.token, first);
+
+    config.save(&root).unwrap();
+    assert_eq!(LocalApiConfig::load_or_default(&root).token, config.token);
+  }
+
+  #[test]
+  fn unreadable_config_resets_to_disabled() {
+    let (_dir, root) = data_root();
+    let path = root.credentials_dir().get_local_api_config_path();
+    std::fs::write(&path, b"{ not json").unwrap();
+
+    let config = LocalApiConfig::load_or_default(&root);
+    assert!(!config.enabled);
+    assert!(config.token.is_empty());
+  }
+}
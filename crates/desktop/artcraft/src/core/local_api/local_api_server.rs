@@ -0,0 +1,113 @@
+use crate::core::local_api::local_api_config::LocalApiConfig;
+use crate::core::local_api::local_api_state::LocalApiState;
+use crate::core::local_api::routes::configure_routes;
+use actix_web::dev::ServerHandle;
+use actix_web::{web, App, HttpServer};
+use errors::{anyhow, AnyhowResult};
+use log::{error, info};
+use std::net::Ipv4Addr;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+/// Seconds to let in-flight requests (eg. an enqueue) finish when stopping.
+const SHUTDOWN_TIMEOUT_SECONDS: u64 = 5;
+
+/// Owns the running local API server, if any. Managed as Tauri state.
+#[derive(Default)]
+pub struct LocalApiServer {
+  running: Mutex<Option<RunningServer>>,
+}
+
+struct RunningServer {
+  handle: ServerHandle,
+  port: u16,
+}
+
+impl LocalApiServer {
+  /// Start (or restart) the server with the given config.
+  pub async fn start(&self, app: &AppHandle, config: &LocalApiConfig) -> AnyhowResult<()> {
+    self.stop().await;
+
+    if config.token.is_empty() {
+      return Err(anyhow!("the local API has no token"));
+    }
+
+    let state = web::Data::new(LocalApiState {
+      app: app.clone(),
+      token: config.token.clone(),
+    });
+
+    let port = config.port;
+
+    // NB: actix runs its own (single-threaded) runtime, so the server lives on its own thread.
+    // The handlers hop over to Tauri's runtime to do the actual work.
+    let (started_tx, started_rx) = tokio::sync::oneshot::channel::<AnyhowResult<ServerHandle>>();
+
+    std::thread::Builder::new()
+        .name("local-api-server".to_string())
+        .spawn(move || {
+          let system = actix_web::rt::System::new();
+
+          let result = system.block_on(async move {
+            let server = HttpServer::new(move || {
+              App::new()
+                  .app_data(state.clone())
+                  .configure(configure_routes)
+            })
+                .workers(2)
+                .shutdown_timeout(SHUTDOWN_TIMEOUT_SECONDS)
+                .bind((Ipv4Addr::LOCALHOST, port));
+
+            let server = match server {
+              Ok(server) => server.run(),
+              Err(err) => {
+                let _ = started_tx.send(Err(anyhow!("could not bind 127.0.0.1:{}: {:?}", port, err)));
+                return Ok(());
+              }
+            };
+
+            let _ = started_tx.send(Ok(server.handle()));
+
+            server.await
+          });
+
+          if let Err(err) = result {
+            error!("Local API server exited with error: {:?}", err);
+          }
+        })?;
+
+    let handle = started_rx.await
+        .map_err(|_| anyhow!("local API server thread exited before starting"))??;
+
+    info!("Local API listening on http://127.0.0.1:{}", port);
+
+    let mut running = self.running.lock()
+        .map_err(|err| anyhow!("lock poisoned: {:?}", err))?;
+
+    *running = Some(RunningServer { handle, port });
+
+    Ok(())
+  }
+
+  pub async fn stop(&self) {
+    let maybe_running = match self.running.lock() {
+      Ok(mut running) => running.take(),
+      Err(err) => {
+        error!("Local API lock poisoned: {:?}", err);
+        return;
+      }
+    };
+
+    if let Some(running) = maybe_running {
+      info!("Stopping local API on port {}", running.port);
+      running.handle.stop(true).await;
+    }
+  }
+
+  /// The port the server is listening on, if it's running.
+  pub fn running_port(&self) -> Option<u16> {
+    self.running.lock()
+        .ok()
+        .and_then(|running| running.as_ref().map(|running| running.port))
+  }
+}
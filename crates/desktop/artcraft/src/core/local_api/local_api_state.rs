@@ -0,0 +1,8 @@
+use tauri::AppHandle;
+
+/// Shared with every request handler.
+#[derive(Clone)]
+pub struct LocalApiState {
+  pub app: AppHandle,
+  pub token: String,
+}
@@ -0,0 +1,12 @@
+//! An opt-in HTTP API on the loopback interface, so scripts can drive the app headlessly.
+//!
+//! Every route (except `/v1/health`) requires `Authorization: Bearer <token>`. Request and
+//! response bodies are the same JSON the corresponding Tauri commands use; the handlers call
+//! those commands directly, so credentials, the task database, and the generation history
+//! behave exactly as they do for the UI.
+
+pub mod local_api_auth;
+pub mod local_api_config;
+pub mod local_api_server;
+pub mod local_api_state;
+pub mod routes;
@@ -0,0 +1,45 @@
+use crate::core::commands::response::failure_response_wrapper::{CommandErrorResponseWrapper, CommandErrorStatus};
+use crate::core::commands::response::success_response_wrapper::CommandSuccessResponseWrapper;
+use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
+use log::error;
+use serde::Serialize;
+use std::future::Future;
+
+/// Run a Tauri command on Tauri's runtime rather than actix's.
+/// The HTTP clients and database pools in the app state belong to Tauri's runtime.
+pub async fn run_command<F, S, E, P>(command: F) -> HttpResponse
+where
+  F: Future<Output = Result<CommandSuccessResponseWrapper<S>, CommandErrorResponseWrapper<E, P>>> + Send + 'static,
+  S: Serialize + Send + 'static,
+  E: Serialize + Send + 'static,
+  P: Serialize + Send + 'static,
+{
+  match tauri::async_runtime::spawn(command).await {
+    Ok(response) => to_http_response(response),
+    Err(err) => {
+      error!("Local API command failed to run: {:?}", err);
+      HttpResponse::InternalServerError().finish()
+    }
+  }
+}
+
+/// The body is the same JSON the frontend receives from the command.
+pub fn to_http_response<S: Serialize, E: Serialize, P: Serialize>(
+  response: Result<CommandSuccessResponseWrapper<S>, CommandErrorResponseWrapper<E, P>>,
+) -> HttpResponse {
+  match response {
+    Ok(success) => HttpResponse::Ok().json(success),
+    Err(failure) => HttpResponse::build(status_code(&failure.status)).json(failure),
+  }
+}
+
+fn status_code(status: &CommandErrorStatus) -> StatusCode {
+  match status {
+    CommandErrorStatus::BadRequest => StatusCode::BAD_REQUEST,
+    // NB: 401 is reserved for a bad local API token. This means the app's own provider login is missing.
+    CommandErrorStatus::Unauthorized => StatusCode::FORBIDDEN,
+    CommandErrorStatus::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+    CommandErrorStatus::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+  }
+}
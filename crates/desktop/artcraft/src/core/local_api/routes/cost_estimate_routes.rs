@@ -0,0 +1,44 @@
+use crate::core::commands::cost_estimate::estimate_image_cost_command::estimate_image_cost_command;
+use crate::core::commands::cost_estimate::estimate_splat_cost_command::estimate_splat_cost_command;
+use crate::core::commands::cost_estimate::estimate_video_cost_command::estimate_video_cost_command;
+use crate::core::local_api::local_api_auth::LocalApiAuth;
+use crate::core::local_api::local_api_state::LocalApiState;
+use crate::core::local_api::routes::command_response::run_command;
+use actix_web::{web, HttpResponse};
+use artcraft_api_defs::generate::cost_estimate::estimate_image_cost::EstimateImageCostRequest;
+use artcraft_api_defs::generate::cost_estimate::estimate_splat_cost::EstimateSplatCostRequest;
+use artcraft_api_defs::generate::cost_estimate::estimate_video_cost::EstimateVideoCostRequest;
+use tauri::Manager;
+
+pub async fn estimate_image_cost(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+  request: web::Json<EstimateImageCostRequest>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    estimate_image_cost_command(request.into_inner(), app.state()).await
+  }).await
+}
+
+pub async fn estimate_video_cost(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+  request: web::Json<EstimateVideoCostRequest>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    estimate_video_cost_command(request.into_inner(), app.state()).await
+  }).await
+}
+
+pub async fn estimate_splat_cost(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+  request: web::Json<EstimateSplatCostRequest>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    estimate_splat_cost_command(request.into_inner(), app.state()).await
+  }).await
+}
@@ -0,0 +1,24 @@
+use crate::core::commands::download::download_media_file_command::{download_media_file_command, DownloadMediaFileRequest};
+use crate::core::local_api::local_api_auth::LocalApiAuth;
+use crate::core::local_api::local_api_state::LocalApiState;
+use crate::core::local_api::routes::command_response::run_command;
+use actix_web::{web, HttpResponse};
+use tauri::Manager;
+
+/// Downloads into the user's configured download directory, same as the "download" button.
+pub async fn download_media_file(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+  request: web::Json<DownloadMediaFileRequest>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    download_media_file_command(
+      request.into_inner(),
+      app.clone(),
+      app.state(),
+      app.state(),
+      app.state(),
+    ).await
+  }).await
+}
@@ -0,0 +1,73 @@
+use crate::core::commands::enqueue::image_to_gaussian::enqueue_image_to_gaussian_command::{
+  enqueue_image_to_gaussian_command, EnqueueImageToGaussianRequest,
+};
+use crate::core::commands::generate::generate_image::generate_image_command::generate_image_command;
+use crate::core::commands::generate::generate_image::tauri_generate_image_request::TauriGenerateImageRequest;
+use crate::core::commands::generate::generate_video::generate_video_command::generate_video_command;
+use crate::core::commands::generate::generate_video::request::TauriGenerateVideoRequest;
+use crate::core::local_api::local_api_auth::LocalApiAuth;
+use crate::core::local_api::local_api_state::LocalApiState;
+use crate::core::local_api::routes::command_response::run_command;
+use actix_web::{web, HttpResponse};
+use tauri::Manager;
+
+pub async fn generate_image(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+  request: web::Json<TauriGenerateImageRequest>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    generate_image_command(
+      request.into_inner(),
+      app.clone(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+    ).await
+  }).await
+}
+
+pub async fn generate_video(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+  request: web::Json<TauriGenerateVideoRequest>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    generate_video_command(
+      request.into_inner(),
+      app.clone(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+    ).await
+  }).await
+}
+
+pub async fn generate_gaussian(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+  request: web::Json<EnqueueImageToGaussianRequest>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    enqueue_image_to_gaussian_command(
+      request.into_inner(),
+      app.clone(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+    ).await
+  }).await
+}
@@ -0,0 +1,12 @@
+use actix_web::HttpResponse;
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+struct HealthResponse {
+  success: bool,
+}
+
+/// Unauthenticated, so scripts can check whether the app is running.
+pub async fn health() -> HttpResponse {
+  HttpResponse::Ok().json(HealthResponse { success: true })
+}
@@ -0,0 +1,26 @@
+use actix_web::web;
+
+pub mod command_response;
+pub mod cost_estimate_routes;
+pub mod download_routes;
+pub mod generate_routes;
+pub mod health_route;
+pub mod task_events_route;
+pub mod task_routes;
+
+pub fn configure_routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/v1")
+        .route("/health", web::get().to(health_route::health))
+        .route("/generate/image", web::post().to(generate_routes::generate_image))
+        .route("/generate/video", web::post().to(generate_routes::generate_video))
+        .route("/generate/gaussian", web::post().to(generate_routes::generate_gaussian))
+        .route("/cost_estimate/image", web::post().to(cost_estimate_routes::estimate_image_cost))
+        .route("/cost_estimate/video", web::post().to(cost_estimate_routes::estimate_video_cost))
+        .route("/cost_estimate/splat", web::post().to(cost_estimate_routes::estimate_splat_cost))
+        .route("/tasks", web::get().to(task_routes::list_tasks))
+        .route("/tasks/cancel", web::post().to(task_routes::cancel_task))
+        .route("/tasks/events", web::get().to(task_events_route::task_events))
+        .route("/download/media_file", web::post().to(download_routes::download_media_file))
+  );
+}
@@ -0,0 +1,142 @@
+use crate::core::commands::task_queue::get_task_queue_command::{handle_request, TaskQueueItem};
+use crate::core::local_api::local_api_auth::LocalApiAuth;
+use crate::core::local_api::local_api_state::LocalApiState;
+use crate::core::state::task_database::TaskDatabase;
+use actix_web::{web, Responder};
+use actix_web_lab::sse;
+use chrono::{DateTime, Utc};
+use enums::tauri::tasks::task_status::TaskStatus;
+use log::{error, info};
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(1_000);
+
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Server-sent events with a "task" event every time a task is created or changes status.
+/// The payload is the same as an item from `/v1/tasks`.
+pub async fn task_events(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+) -> impl Responder {
+  let (sender, receiver) = tokio::sync::mpsc::channel(16);
+
+  tauri::async_runtime::spawn(poll_task_events(state.app.clone(), sender));
+
+  sse::Sse::from_infallible_receiver(receiver)
+      .with_keep_alive(KEEP_ALIVE_INTERVAL)
+}
+
+async fn poll_task_events(app: AppHandle, sender: tokio::sync::mpsc::Sender<sse::Event>) {
+  // NB: Keyed by task id.
+  let mut last_seen: HashMap<String, (TaskStatus, DateTime<Utc>)> = HashMap::new();
+
+  loop {
+    let task_database = app.state::<TaskDatabase>();
+
+    match handle_request(&task_database).await {
+      Err(err) => error!("Local API could not list tasks: {:?}", err),
+      Ok(tasks) => {
+        for task in tasks.iter() {
+          if !has_changed(&mut last_seen, task) {
+            continue;
+          }
+
+          let data = match sse::Data::new_json(task) {
+            Ok(data) => data.event("task"),
+            Err(err) => {
+              error!("Local API could not serialize task: {:?}", err);
+              continue;
+            }
+          };
+
+          if sender.send(data.into()).await.is_err() {
+            info!("Local API task event listener disconnected");
+            return;
+          }
+        }
+      }
+    }
+
+    if sender.is_closed() {
+      return;
+    }
+
+    tokio::time::sleep(POLL_INTERVAL).await;
+  }
+}
+
+fn has_changed(last_seen: &mut HashMap<String, (TaskStatus, DateTime<Utc>)>, task: &TaskQueueItem) -> bool {
+  let current = (task.task_status, task.updated_at);
+  match last_seen.insert(task.id.as_str().to_string(), current) {
+    Some(previous) => previous != current,
+    None => true,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::TimeZone;
+  use enums::tauri::tasks::task_type::TaskType;
+  use tokens::tokens::sqlite::tasks::TaskId;
+
+  fn task(id: &str, task_status: TaskStatus, updated_at_secs: i64) -> TaskQueueItem {
+    let updated_at = Utc.timestamp_opt(updated_at_secs, 0).unwrap();
+    TaskQueueItem {
+      id: TaskId(id.to_string()),
+      task_status,
+      task_type: TaskType::ImageGeneration,
+      model_type: None,
+      provider: None,
+      provider_job_id: None,
+      batch_id: None,
+      batch_index: None,
+      completed_item: None,
+      failure_reason: None,
+      created_at: updated_at,
+      updated_at,
+      completed_at: None,
+    }
+  }
+
+  #[test]
+  fn new_task_is_sent() {
+    let mut last_seen = HashMap::new();
+    assert!(has_changed(&mut last_seen, &task("task_a", TaskStatus::Pending, 100)));
+  }
+
+  #[test]
+  fn unchanged_task_is_not_resent() {
+    let mut last_seen = HashMap::new();
+    assert!(has_changed(&mut last_seen, &task("task_a", TaskStatus::Pending, 100)));
+    assert!(!has_changed(&mut last_seen, &task("task_a", TaskStatus::Pending, 100)));
+    assert!(!has_changed(&mut last_seen, &task("task_a", TaskStatus::Pending, 100)));
+  }
+
+  #[test]
+  fn status_change_is_sent() {
+    let mut last_seen = HashMap::new();
+    has_changed(&mut last_seen, &task("task_a", TaskStatus::Pending, 100));
+    assert!(has_changed(&mut last_seen, &task("task_a", TaskStatus::Started, 100)));
+    assert!(!has_changed(&mut last_seen, &task("task_a", TaskStatus::Started, 100)));
+  }
+
+  #[test]
+  fn update_without_status_change_is_sent() {
+    let mut last_seen = HashMap::new();
+    has_changed(&mut last_seen, &task("task_a", TaskStatus::Started, 100));
+    assert!(has_changed(&mut last_seen, &task("task_a", TaskStatus::Started, 101)));
+  }
+
+  #[test]
+  fn tasks_are_tracked_separately() {
+    let mut last_seen = HashMap::new();
+    assert!(has_changed(&mut last_seen, &task("task_a", TaskStatus::Pending, 100)));
+    assert!(has_changed(&mut last_seen, &task("task_b", TaskStatus::Pending, 100)));
+    assert!(has_changed(&mut last_seen, &task("task_a", TaskStatus::CompleteSuccess, 200)));
+    assert!(!has_changed(&mut last_seen, &task("task_b", TaskStatus::Pending, 100)));
+  }
+}
@@ -0,0 +1,35 @@
+use crate::core::commands::task_queue::cancel_task_command::{cancel_task_command, CancelTaskRequest};
+use crate::core::commands::task_queue::get_task_queue_command::get_task_queue_command;
+use crate::core::local_api::local_api_auth::LocalApiAuth;
+use crate::core::local_api::local_api_state::LocalApiState;
+use crate::core::local_api::routes::command_response::run_command;
+use actix_web::{web, HttpResponse};
+use tauri::Manager;
+
+pub async fn list_tasks(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    get_task_queue_command(app.clone(), app.state(), app.state()).await
+  }).await
+}
+
+pub async fn cancel_task(
+  _auth: LocalApiAuth,
+  state: web::Data<LocalApiState>,
+  request: web::Json<CancelTaskRequest>,
+) -> HttpResponse {
+  let app = state.app.clone();
+  run_command(async move {
+    cancel_task_command(
+      request.into_inner(),
+      app.clone(),
+      app.state(),
+      app.state(),
+      app.state(),
+      app.state(),
+    ).await
+  }).await
+}
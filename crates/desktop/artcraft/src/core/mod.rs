@@ -2,7 +2,9 @@ pub mod api_adapters;
 pub mod artcraft_error;
 pub mod commands;
 pub mod events;
+pub mod generation_history;
 pub mod lifecycle;
+pub mod local_api;
 pub mod providers;
 pub mod state;
 pub mod threads;
@@ -2,3 +2,4 @@ pub mod payload;
 pub mod provider_credential_key;
 pub mod provider_credential_loading_cache;
 pub mod provider_credential_type;
+pub mod vault;
@@ -14,14 +14,22 @@ impl ApiKeyData {
     self.0.as_str()
   }
 
+  pub fn from_file_contents(contents: &str) -> Self {
+    Self::from_str(contents)
+  }
+
+  pub fn to_file_contents(&self) -> String {
+    self.0.trim().to_string()
+  }
+
   pub fn load_from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, ApiKeyDataError> {
     let contents = std::fs::read_to_string(file_path)
       .map_err(ApiKeyDataError::IoError)?;
-    Ok(Self(contents.trim().to_string()))
+    Ok(Self::from_file_contents(&contents))
   }
 
   pub fn save_to_file<P: AsRef<Path>>(&self, file_path: P) -> Result<(), ApiKeyDataError> {
-    std::fs::write(file_path, self.0.trim())
+    std::fs::write(file_path, self.to_file_contents())
       .map_err(ApiKeyDataError::IoError)?;
     Ok(())
   }
@@ -31,11 +31,8 @@ impl WebLoginData {
     }
   }
 
-  pub fn load_from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, WebLoginDataError> {
-    let contents = std::fs::read_to_string(file_path)
-      .map_err(WebLoginDataError::IoError)?;
-
-    let serializable: WebLoginDataSerializable = serde_json::from_str(&contents)
+  pub fn from_file_contents(contents: &str) -> Result<Self, WebLoginDataError> {
+    let serializable: WebLoginDataSerializable = serde_json::from_str(contents)
       .map_err(WebLoginDataError::DeserializeError)?;
 
     Ok(Self {
@@ -48,7 +45,7 @@ impl WebLoginData {
     })
   }
 
-  pub fn save_to_file<P: AsRef<Path>>(&self, file_path: P) -> Result<(), WebLoginDataError> {
+  pub fn to_file_contents(&self) -> Result<String, WebLoginDataError> {
     let serializable = WebLoginDataSerializable {
       version: CURRENT_VERSION,
       cookies_header: self.cookies_header.clone(),
@@ -59,8 +56,19 @@ impl WebLoginData {
       updated_at: self.updated_at,
     };
 
-    let contents = serde_json::to_string_pretty(&serializable)
-      .map_err(WebLoginDataError::SerializeError)?;
+    serde_json::to_string_pretty(&serializable)
+      .map_err(WebLoginDataError::SerializeError)
+  }
+
+  pub fn load_from_file<P: AsRef<Path>>(file_path: P) -> Result<Self, WebLoginDataError> {
+    let contents = std::fs::read_to_string(file_path)
+      .map_err(WebLoginDataError::IoError)?;
+
+    Self::from_file_contents(&contents)
+  }
+
+  pub fn save_to_file<P: AsRef<Path>>(&self, file_path: P) -> Result<(), WebLoginDataError> {
+    let contents = self.to_file_contents()?;
 
     std::fs::write(file_path, contents)
       .map_err(WebLoginDataError::IoError)?;
@@ -20,6 +20,16 @@ pub enum ProviderCredentialKey {
 
 
 impl ProviderCredentialKey {
+  /// All known credential keys. Add new ones here as providers are added.
+  pub const ALL: &'static [ProviderCredentialKey] = &[
+    Self::FalApiKey,
+    Self::ReplicateApiKey,
+    Self::GrokWebLogin,
+    Self::HiggsfieldWebLogin,
+    Self::MidjourneyLogin,
+    Self::RunwayWebLogin,
+  ];
+
   pub fn get_type(&self) -> ProviderCredentialType {
     match self {
       // Api keys
@@ -33,6 +43,8 @@ impl ProviderCredentialKey {
     }
   }
 
+  /// Legacy plaintext filename. Credentials are now stored encrypted by the
+  /// credential vault; these files are only read to migrate them.
   pub fn get_filename(&self) -> &'static str {
     match self {
       // Api keys
@@ -11,8 +11,9 @@ use crate::core::providers::credentials::payload::provider_credential_payload::P
 use crate::core::providers::credentials::payload::web_login::{WebLoginData, WebLoginDataError};
 use crate::core::providers::credentials::provider_credential_key::ProviderCredentialKey;
 use crate::core::providers::credentials::provider_credential_type::ProviderCredentialType;
-use crate::core::state::data_dir::app_data_root::AppDataRoot;
-use crate::core::state::data_dir::trait_data_subdir::DataSubdir;
+use crate::core::providers::credentials::vault::credential_bundle::{export_credential_bundle, import_credential_bundle};
+use crate::core::providers::credentials::vault::credential_vault_slot::CredentialVaultSlot;
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
 
 struct CacheEntry {
   payload: ProviderCredentialPayload,
@@ -22,27 +23,33 @@ struct CacheEntry {
 #[derive(Clone)]
 pub struct ProviderCredentialLoadingCache {
   inner: Arc<RwLock<HashMap<ProviderCredentialKey, CacheEntry>>>,
-  app_data_root: AppDataRoot,
+  vault_slot: CredentialVaultSlot,
   ttl: Duration,
 }
 
 impl ProviderCredentialLoadingCache {
-  pub fn new(app_data_root: AppDataRoot) -> Self {
+  pub fn new(vault_slot: CredentialVaultSlot) -> Self {
     Self {
       inner: Arc::new(RwLock::new(HashMap::new())),
-      app_data_root,
+      vault_slot,
       ttl: Duration::from_secs(300), // 5 minutes
     }
   }
 
-  pub fn new_with_ttl(app_data_root: AppDataRoot, ttl: Duration) -> Self {
+  pub fn new_with_ttl(vault_slot: CredentialVaultSlot, ttl: Duration) -> Self {
     Self {
       inner: Arc::new(RwLock::new(HashMap::new())),
-      app_data_root,
+      vault_slot,
       ttl,
     }
   }
 
+  /// The vault behind the cache. Call `invalidate_all` after unlocking, re-keying or
+  /// resetting it.
+  pub fn vault_slot(&self) -> &CredentialVaultSlot {
+    &self.vault_slot
+  }
+
   pub fn get_credentials(
     &self,
     key: ProviderCredentialKey,
@@ -59,23 +66,27 @@ impl ProviderCredentialLoadingCache {
       }
     }
 
-    // Cache miss or expired — load from disk.
-    let file_path = self.app_data_root.credentials_dir().path().join(key.get_filename());
+    // Cache miss or expired — load from the vault.
+    let maybe_plaintext = self.vault_slot.vault()
+      .and_then(|vault| vault.read(key))
+      .map_err(ProviderCredentialLoadingCacheError::VaultError)?;
 
-    if !file_path.exists() {
-      return Ok(None);
-    }
+    let plaintext = match maybe_plaintext {
+      Some(plaintext) => plaintext,
+      None => return Ok(None),
+    };
 
-    info!("Loading credential from disk: {:?}", file_path);
+    info!("Loaded credential from vault: {:?}", key);
+
+    let contents = String::from_utf8(plaintext)
+      .map_err(|_| ProviderCredentialLoadingCacheError::InvalidUtf8)?;
 
     let payload = match key.get_type() {
       ProviderCredentialType::ApiKey => {
-        let data = ApiKeyData::load_from_file(&file_path)
-          .map_err(ProviderCredentialLoadingCacheError::ApiKeyError)?;
-        ProviderCredentialPayload::ApiKey(data)
+        ProviderCredentialPayload::ApiKey(ApiKeyData::from_file_contents(&contents))
       }
       ProviderCredentialType::WebLogin => {
-        let data = WebLoginData::load_from_file(&file_path)
+        let data = WebLoginData::from_file_contents(&contents)
           .map_err(ProviderCredentialLoadingCacheError::WebLoginError)?;
         ProviderCredentialPayload::WebLogin(data)
       }
@@ -100,24 +111,25 @@ impl ProviderCredentialLoadingCache {
     key: ProviderCredentialKey,
     payload: ProviderCredentialPayload,
   ) -> Result<(), ProviderCredentialLoadingCacheError> {
-    let file_path = self.app_data_root.credentials_dir().path().join(key.get_filename());
-
-    info!("Saving credential to disk: {:?}", file_path);
+    info!("Saving credential to vault: {:?}", key);
 
-    match (&payload, key.get_type()) {
+    let contents = match (&payload, key.get_type()) {
       (ProviderCredentialPayload::ApiKey(data), ProviderCredentialType::ApiKey) => {
-        data.save_to_file(&file_path)
-          .map_err(ProviderCredentialLoadingCacheError::ApiKeyError)?;
+        data.to_file_contents()
       }
       (ProviderCredentialPayload::WebLogin(data), ProviderCredentialType::WebLogin) => {
-        data.save_to_file(&file_path)
-          .map_err(ProviderCredentialLoadingCacheError::WebLoginError)?;
+        data.to_file_contents()
+          .map_err(ProviderCredentialLoadingCacheError::WebLoginError)?
       }
       _ => {
         warn!("Credential type mismatch for key {:?}", key);
         return Err(ProviderCredentialLoadingCacheError::TypeMismatch);
       }
-    }
+    };
+
+    self.vault_slot.vault()
+      .and_then(|vault| vault.write(key, contents.as_bytes()))
+      .map_err(ProviderCredentialLoadingCacheError::VaultError)?;
 
     // Update cache.
     {
@@ -133,30 +145,61 @@ impl ProviderCredentialLoadingCache {
     Ok(())
   }
 
-  /// Invalidate a cached entry, forcing a re-read from disk on next access.
+  /// Invalidate a cached entry, forcing a re-read from the vault on next access.
   pub fn invalidate(&self, key: ProviderCredentialKey) {
     if let Ok(mut cache) = self.inner.write() {
       cache.remove(&key);
     }
   }
-  
-  pub fn delete_credentials(&self, key: ProviderCredentialKey) -> Result<(), ProviderCredentialLoadingCacheError> {
-    let file_path = self.app_data_root.credentials_dir().path().join(key.get_filename());
-    
-    if file_path.exists() {
-      std::fs::remove_file(file_path)
-          .map_err(|e| ProviderCredentialLoadingCacheError::IoError(e))?;
+
+  /// Drop every cached entry.
+  pub fn invalidate_all(&self) {
+    if let Ok(mut cache) = self.inner.write() {
+      cache.clear();
     }
-    
+  }
+
+  pub fn delete_credentials(&self, key: ProviderCredentialKey) -> Result<(), ProviderCredentialLoadingCacheError> {
+    self.vault_slot.vault()
+      .and_then(|vault| vault.delete(key))
+      .map_err(ProviderCredentialLoadingCacheError::VaultError)?;
+
     self.invalidate(key);
-    
+
     Ok(())
   }
+
+  /// Export every stored credential as a passphrase-encrypted bundle.
+  /// Runs PBKDF2; call off the async runtime.
+  pub fn export_bundle(&self, passphrase: &str) -> Result<Vec<u8>, ProviderCredentialLoadingCacheError> {
+    self.vault_slot.vault()
+      .and_then(|vault| export_credential_bundle(&vault, passphrase))
+      .map_err(ProviderCredentialLoadingCacheError::VaultError)
+  }
+
+  /// Import a bundle from `export_bundle`, replacing any credentials it contains.
+  /// Runs PBKDF2; call off the async runtime.
+  pub fn import_bundle(
+    &self,
+    bundle: &[u8],
+    passphrase: &str,
+  ) -> Result<Vec<ProviderCredentialKey>, ProviderCredentialLoadingCacheError> {
+    let imported = self.vault_slot.vault()
+      .and_then(|vault| import_credential_bundle(&vault, bundle, passphrase))
+      .map_err(ProviderCredentialLoadingCacheError::VaultError)?;
+
+    for key in imported.iter() {
+      self.invalidate(*key);
+    }
+
+    Ok(imported)
+  }
 }
 
 #[derive(Debug)]
 pub enum ProviderCredentialLoadingCacheError {
-  IoError(std::io::Error),
+  VaultError(CredentialVaultError),
+  InvalidUtf8,
   ApiKeyError(ApiKeyDataError),
   WebLoginError(WebLoginDataError),
   TypeMismatch,
@@ -166,7 +209,7 @@ pub enum ProviderCredentialLoadingCacheError {
 impl Error for ProviderCredentialLoadingCacheError {
   fn source(&self) -> Option<&(dyn Error + 'static)> {
     match self {
-      Self::IoError(err) => Some(err),
+      Self::VaultError(err) => Some(err),
       Self::ApiKeyError(err) => Some(err),
       Self::WebLoginError(err) => Some(err),
       _ => None,
@@ -177,7 +220,8 @@ impl Error for ProviderCredentialLoadingCacheError {
 impl Display for ProviderCredentialLoadingCacheError {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
-      Self::IoError(e) => write!(f, "IO error: {}", e),
+      Self::VaultError(e) => write!(f, "Credential vault error: {}", e),
+      Self::InvalidUtf8 => write!(f, "Credential is not valid UTF-8"),
       Self::ApiKeyError(e) => write!(f, "API key error: {}", e),
       Self::WebLoginError(e) => write!(f, "Web login error: {}", e),
       Self::TypeMismatch => write!(f, "Credential type does not match key type"),
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::core::providers::credentials::provider_credential_key::ProviderCredentialKey;
+use crate::core::providers::credentials::vault::credential_vault::CredentialVault;
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+use crate::core::providers::credentials::vault::vault_cipher;
+use crate::core::providers::credentials::vault::vault_key::{random_bytes, VaultKey, PASSPHRASE_KDF_ITERATIONS, SALT_LEN};
+
+const BUNDLE_VERSION: u8 = 1;
+const BUNDLE_KDF: &str = "pbkdf2-hmac-sha256";
+const BUNDLE_CONTEXT: &[u8] = b"artcraft credential bundle";
+
+/// Don't let a hostile bundle make us spin forever.
+const MAX_KDF_ITERATIONS: u32 = 10_000_000;
+
+/// A passphrase-encrypted export of every credential in the vault, for moving
+/// credentials to another machine. The vault itself is machine-bound, so this is the
+/// only supported way to copy it.
+#[derive(Serialize, Deserialize)]
+struct CredentialBundleEnvelope {
+  version: u8,
+  kdf: String,
+  kdf_iterations: u32,
+  salt: String,
+  ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CredentialBundleContents {
+  /// Credential file contents, base64 encoded.
+  credentials: HashMap<ProviderCredentialKey, String>,
+}
+
+/// Export all stored credentials as an encrypted bundle (JSON bytes).
+pub fn export_credential_bundle(
+  vault: &CredentialVault,
+  passphrase: &str,
+) -> Result<Vec<u8>, CredentialVaultError> {
+  let mut credentials = HashMap::new();
+
+  for &key in ProviderCredentialKey::ALL {
+    if let Some(plaintext) = vault.read(key)? {
+      credentials.insert(key, STANDARD.encode(plaintext));
+    }
+  }
+
+  let contents = serde_json::to_vec(&CredentialBundleContents { credentials })
+    .map_err(CredentialVaultError::SerializationError)?;
+
+  let salt = random_bytes(SALT_LEN)?;
+  let bundle_key = VaultKey::from_passphrase(passphrase, &salt, PASSPHRASE_KDF_ITERATIONS);
+  let ciphertext = vault_cipher::seal(&bundle_key, BUNDLE_CONTEXT, &contents)?;
+
+  let envelope = CredentialBundleEnvelope {
+    version: BUNDLE_VERSION,
+    kdf: BUNDLE_KDF.to_string(),
+    kdf_iterations: PASSPHRASE_KDF_ITERATIONS,
+    salt: STANDARD.encode(salt),
+    ciphertext: STANDARD.encode(ciphertext),
+  };
+
+  serde_json::to_vec_pretty(&envelope)
+    .map_err(CredentialVaultError::SerializationError)
+}
+
+/// Import a bundle from `export_credential_bundle`, overwriting any credentials it
+/// contains. Returns the keys that were imported.
+pub fn import_credential_bundle(
+  vault: &CredentialVault,
+  bundle: &[u8],
+  passphrase: &str,
+) -> Result<Vec<ProviderCredentialKey>, CredentialVaultError> {
+  let envelope: CredentialBundleEnvelope = serde_json::from_slice(bundle)
+    .map_err(CredentialVaultError::SerializationError)?;
+
+  if envelope.version != BUNDLE_VERSION || envelope.kdf != BUNDLE_KDF {
+    return Err(CredentialVaultError::Corrupted("unsupported bundle version"));
+  }
+
+  if envelope.kdf_iterations > MAX_KDF_ITERATIONS {
+    return Err(CredentialVaultError::Corrupted("unreasonable bundle KDF iterations"));
+  }
+
+  let salt = decode_base64(&envelope.salt)?;
+  let ciphertext = decode_base64(&envelope.ciphertext)?;
+
+  let bundle_key = VaultKey::from_passphrase(passphrase, &salt, envelope.kdf_iterations);
+  let contents = vault_cipher::open(&bundle_key, BUNDLE_CONTEXT, &ciphertext)?;
+
+  let contents: CredentialBundleContents = serde_json::from_slice(&contents)
+    .map_err(CredentialVaultError::SerializationError)?;
+
+  // Decode everything before writing anything, so a bad bundle doesn't half-import.
+  let credentials = contents.credentials.into_iter()
+    .map(|(key, encoded)| Ok((key, decode_base64(&encoded)?)))
+    .collect::<Result<Vec<_>, CredentialVaultError>>()?;
+
+  for (key, plaintext) in credentials.iter() {
+    vault.write(*key, plaintext)?;
+  }
+
+  Ok(credentials.into_iter().map(|(key, _)| key).collect())
+}
+
+fn decode_base64(encoded: &str) -> Result<Vec<u8>, CredentialVaultError> {
+  STANDARD.decode(encoded)
+    .map_err(|_| CredentialVaultError::Corrupted("invalid base64 in bundle"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::providers::credentials::vault::vault_key::VaultKeySource;
+
+  #[test]
+  fn export_then_import_on_another_vault() {
+    let source_dir = tempfile::tempdir().unwrap();
+    let source = CredentialVault::open(source_dir.path(), &VaultKeySource::MachineKey).unwrap();
+    source.write(ProviderCredentialKey::FalApiKey, b"sk-fal").unwrap();
+    source.write(ProviderCredentialKey::RunwayWebLogin, b"{\"version\": 1}").unwrap();
+
+    let bundle = export_credential_bundle(&source, "moving day").unwrap();
+    assert!(!bundle.windows(6).any(|window| window == b"sk-fal"));
+
+    let dest_dir = tempfile::tempdir().unwrap();
+    let dest = CredentialVault::open(dest_dir.path(), &VaultKeySource::MachineKey).unwrap();
+
+    let imported = import_credential_bundle(&dest, &bundle, "moving day").unwrap();
+    assert_eq!(imported.len(), 2);
+    assert_eq!(dest.read(ProviderCredentialKey::FalApiKey).unwrap().unwrap(), b"sk-fal");
+    assert_eq!(dest.read(ProviderCredentialKey::RunwayWebLogin).unwrap().unwrap(), b"{\"version\": 1}");
+
+    let result = import_credential_bundle(&dest, &bundle, "wrong passphrase");
+    assert!(matches!(result, Err(CredentialVaultError::WrongKey)));
+  }
+}
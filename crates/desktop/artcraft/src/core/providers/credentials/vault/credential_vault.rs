@@ -0,0 +1,379 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use log::{info, warn};
+
+use crate::core::providers::credentials::provider_credential_key::ProviderCredentialKey;
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+use crate::core::providers::credentials::vault::vault_cipher;
+use crate::core::providers::credentials::vault::vault_key::{load_or_create_random_file, random_bytes, write_private_file, VaultKey, VaultKeySource, PASSPHRASE_KDF_ITERATIONS, SALT_LEN, VAULT_KEY_LEN};
+
+const MACHINE_SECRET_FILENAME: &str = "credential_vault.machine_key";
+const PASSPHRASE_SALT_FILENAME: &str = "credential_vault.salt";
+const CHECK_FILENAME: &str = "credential_vault.check";
+
+const CHECK_CONTEXT: &[u8] = b"credential_vault.check";
+const CHECK_PLAINTEXT: &[u8] = b"artcraft credential vault";
+
+const ENCRYPTED_SUFFIX: &str = ".enc";
+const REKEY_STAGING_SUFFIX: &str = ".rekey";
+
+/// Encrypted at-rest storage for provider credentials.
+///
+/// Each credential is stored as `<legacy filename>.enc` in the credentials directory.
+/// Plaintext files from older versions are picked up by `migrate_plaintext_files`.
+#[derive(Clone)]
+pub struct CredentialVault {
+  credentials_dir: PathBuf,
+  key: Arc<VaultKey>,
+}
+
+impl CredentialVault {
+  /// Open the vault, creating its key material on first use. Fails with `WrongKey` if
+  /// the vault was previously created with a different key.
+  pub fn open(credentials_dir: &Path, key_source: &VaultKeySource) -> Result<Self, CredentialVaultError> {
+    std::fs::create_dir_all(credentials_dir)?;
+
+    let key = match key_source {
+      VaultKeySource::MachineKey => {
+        VaultKey::from_machine_secret_file(&credentials_dir.join(MACHINE_SECRET_FILENAME))?
+      }
+      VaultKeySource::Passphrase(passphrase) => {
+        let salt = load_or_create_random_file(&credentials_dir.join(PASSPHRASE_SALT_FILENAME), SALT_LEN)?;
+        VaultKey::from_passphrase(passphrase, &salt, PASSPHRASE_KDF_ITERATIONS)
+      }
+    };
+
+    let vault = Self {
+      credentials_dir: credentials_dir.to_path_buf(),
+      key: Arc::new(key),
+    };
+
+    vault.verify_or_create_check_file()?;
+
+    Ok(vault)
+  }
+
+  /// Whether the vault was set up with a passphrase (and so can't be opened until the
+  /// user enters it).
+  pub fn is_passphrase_protected(credentials_dir: &Path) -> bool {
+    credentials_dir.join(PASSPHRASE_SALT_FILENAME).exists()
+  }
+
+  /// Discard every encrypted credential along with the vault's key material, so the next
+  /// `open` starts an empty vault. Plaintext files from older versions are left alone.
+  pub fn reset(credentials_dir: &Path) -> Result<(), CredentialVaultError> {
+    warn!("Resetting credential vault in {:?}; stored credentials are discarded.", credentials_dir);
+    Self::remove_encrypted_files(credentials_dir)?;
+
+    for filename in [PASSPHRASE_SALT_FILENAME, MACHINE_SECRET_FILENAME] {
+      let path = credentials_dir.join(filename);
+      if path.exists() {
+        std::fs::remove_file(path)?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Re-encrypt every stored credential under a new key source (eg. to add or remove a
+  /// passphrase). Slow with a passphrase; call off the async runtime.
+  ///
+  /// The new files are written next to the old ones and renamed into place, so a failed
+  /// write leaves the old vault as it was.
+  pub fn rekey(&self, key_source: &VaultKeySource) -> Result<Self, CredentialVaultError> {
+    let mut credentials = Vec::new();
+
+    for &key in ProviderCredentialKey::ALL {
+      if let Some(plaintext) = self.read(key)? {
+        credentials.push((key, plaintext));
+      }
+    }
+
+    let (key_material_filename, stale_key_material_filename, key_material, new_key) = match key_source {
+      VaultKeySource::MachineKey => {
+        let secret = random_bytes(VAULT_KEY_LEN)?;
+        let key = VaultKey::from_machine_secret_on_this_machine(&secret);
+        (MACHINE_SECRET_FILENAME, PASSPHRASE_SALT_FILENAME, secret, key)
+      }
+      VaultKeySource::Passphrase(passphrase) => {
+        let salt = random_bytes(SALT_LEN)?;
+        let key = VaultKey::from_passphrase(passphrase, &salt, PASSPHRASE_KDF_ITERATIONS);
+        (PASSPHRASE_SALT_FILENAME, MACHINE_SECRET_FILENAME, salt, key)
+      }
+    };
+
+    let vault = Self {
+      credentials_dir: self.credentials_dir.clone(),
+      key: Arc::new(new_key),
+    };
+
+    let mut files = Vec::new();
+
+    for (key, plaintext) in credentials.iter() {
+      let sealed = vault_cipher::seal(&vault.key, key.get_filename().as_bytes(), plaintext)?;
+      files.push((vault.encrypted_path(*key), sealed));
+    }
+
+    files.push((
+      self.credentials_dir.join(CHECK_FILENAME),
+      vault_cipher::seal(&vault.key, CHECK_CONTEXT, CHECK_PLAINTEXT)?,
+    ));
+
+    // NB: The key material is renamed last, so it replaces the old key only once everything
+    // encrypted under it is in place.
+    files.push((self.credentials_dir.join(key_material_filename), key_material));
+
+    let mut staged = Vec::with_capacity(files.len());
+
+    for (path, contents) in files.iter() {
+      let staged_path = rekey_staging_path(path);
+      if let Err(err) = write_private_file(&staged_path, contents) {
+        warn!("Failed to stage re-keyed vault file {:?}: {:?}. The vault is unchanged.", staged_path, err);
+        for staged_path in staged.iter().chain(std::iter::once(&staged_path)) {
+          let _ = std::fs::remove_file(staged_path);
+        }
+        return Err(err);
+      }
+      staged.push(staged_path);
+    }
+
+    for ((path, _), staged_path) in files.iter().zip(staged.iter()) {
+      std::fs::rename(staged_path, path)?;
+    }
+
+    let stale_key_material_path = self.credentials_dir.join(stale_key_material_filename);
+    if stale_key_material_path.exists() {
+      std::fs::remove_file(stale_key_material_path)?;
+    }
+
+    Ok(vault)
+  }
+
+  pub fn read(&self, key: ProviderCredentialKey) -> Result<Option<Vec<u8>>, CredentialVaultError> {
+    let path = self.encrypted_path(key);
+
+    if !path.exists() {
+      return Ok(None);
+    }
+
+    let sealed = std::fs::read(&path)?;
+    let plaintext = vault_cipher::open(&self.key, key.get_filename().as_bytes(), &sealed)?;
+
+    Ok(Some(plaintext))
+  }
+
+  pub fn write(&self, key: ProviderCredentialKey, plaintext: &[u8]) -> Result<(), CredentialVaultError> {
+    let sealed = vault_cipher::seal(&self.key, key.get_filename().as_bytes(), plaintext)?;
+    write_private_file(&self.encrypted_path(key), &sealed)
+  }
+
+  /// Remove the credential, including any leftover plaintext copy.
+  pub fn delete(&self, key: ProviderCredentialKey) -> Result<(), CredentialVaultError> {
+    for path in [self.encrypted_path(key), self.plaintext_path(key)] {
+      if path.exists() {
+        std::fs::remove_file(path)?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Encrypt any plaintext credential files left by older versions, then delete them.
+  /// Returns the keys that were migrated.
+  pub fn migrate_plaintext_files(&self) -> Result<Vec<ProviderCredentialKey>, CredentialVaultError> {
+    let mut migrated = Vec::new();
+
+    for &key in ProviderCredentialKey::ALL {
+      let plaintext_path = self.plaintext_path(key);
+
+      if !plaintext_path.exists() {
+        continue;
+      }
+
+      if self.encrypted_path(key).exists() {
+        // The encrypted copy wins; the plaintext one is stale.
+        warn!("Removing stale plaintext credential file: {:?}", plaintext_path);
+        std::fs::remove_file(&plaintext_path)?;
+        continue;
+      }
+
+      info!("Encrypting plaintext credential file: {:?}", plaintext_path);
+
+      let plaintext = std::fs::read(&plaintext_path)?;
+      self.write(key, &plaintext)?;
+      std::fs::remove_file(&plaintext_path)?;
+
+      migrated.push(key);
+    }
+
+    Ok(migrated)
+  }
+
+  fn verify_or_create_check_file(&self) -> Result<(), CredentialVaultError> {
+    let check_path = self.credentials_dir.join(CHECK_FILENAME);
+
+    if check_path.exists() {
+      let sealed = std::fs::read(&check_path)?;
+      let plaintext = vault_cipher::open(&self.key, CHECK_CONTEXT, &sealed)?;
+
+      if plaintext != CHECK_PLAINTEXT {
+        return Err(CredentialVaultError::WrongKey);
+      }
+
+      return Ok(());
+    }
+
+    let sealed = vault_cipher::seal(&self.key, CHECK_CONTEXT, CHECK_PLAINTEXT)?;
+    write_private_file(&check_path, &sealed)
+  }
+
+  fn remove_encrypted_files(credentials_dir: &Path) -> Result<(), CredentialVaultError> {
+    let check_path = credentials_dir.join(CHECK_FILENAME);
+    let encrypted_paths = ProviderCredentialKey::ALL.iter()
+      .map(|key| credentials_dir.join(format!("{}{}", key.get_filename(), ENCRYPTED_SUFFIX)));
+
+    for path in encrypted_paths.chain(std::iter::once(check_path)) {
+      if path.exists() {
+        std::fs::remove_file(path)?;
+      }
+    }
+    Ok(())
+  }
+
+  fn encrypted_path(&self, key: ProviderCredentialKey) -> PathBuf {
+    self.credentials_dir.join(format!("{}{}", key.get_filename(), ENCRYPTED_SUFFIX))
+  }
+
+  fn plaintext_path(&self, key: ProviderCredentialKey) -> PathBuf {
+    self.credentials_dir.join(key.get_filename())
+  }
+}
+
+/// Where `rekey` writes the new copy of `path` before renaming it into place.
+fn rekey_staging_path(path: &Path) -> PathBuf {
+  let mut staging_path = path.as_os_str().to_os_string();
+  staging_path.push(REKEY_STAGING_SUFFIX);
+  PathBuf::from(staging_path)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn write_read_delete() {
+    let dir = tempfile::tempdir().unwrap();
+    let vault = CredentialVault::open(dir.path(), &VaultKeySource::MachineKey).unwrap();
+
+    assert!(vault.read(ProviderCredentialKey::FalApiKey).unwrap().is_none());
+
+    vault.write(ProviderCredentialKey::FalApiKey, b"sk-fal").unwrap();
+    assert_eq!(vault.read(ProviderCredentialKey::FalApiKey).unwrap().unwrap(), b"sk-fal");
+
+    let on_disk = std::fs::read(dir.path().join("fal.api_key.txt.enc")).unwrap();
+    assert!(!on_disk.windows(6).any(|window| window == b"sk-fal"));
+
+    vault.delete(ProviderCredentialKey::FalApiKey).unwrap();
+    assert!(vault.read(ProviderCredentialKey::FalApiKey).unwrap().is_none());
+  }
+
+  #[test]
+  fn reopening_with_the_same_machine_key_works() {
+    let dir = tempfile::tempdir().unwrap();
+    CredentialVault::open(dir.path(), &VaultKeySource::MachineKey).unwrap()
+      .write(ProviderCredentialKey::ReplicateApiKey, b"r8_abc").unwrap();
+
+    let reopened = CredentialVault::open(dir.path(), &VaultKeySource::MachineKey).unwrap();
+    assert_eq!(reopened.read(ProviderCredentialKey::ReplicateApiKey).unwrap().unwrap(), b"r8_abc");
+  }
+
+  #[test]
+  fn wrong_passphrase_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    CredentialVault::open(dir.path(), &VaultKeySource::Passphrase("correct".to_string())).unwrap();
+
+    let result = CredentialVault::open(dir.path(), &VaultKeySource::Passphrase("wrong".to_string()));
+    assert!(matches!(result, Err(CredentialVaultError::WrongKey)));
+  }
+
+  #[test]
+  fn reset_discards_credentials_under_an_old_key() {
+    let dir = tempfile::tempdir().unwrap();
+    CredentialVault::open(dir.path(), &VaultKeySource::Passphrase("old".to_string())).unwrap()
+      .write(ProviderCredentialKey::FalApiKey, b"sk-fal").unwrap();
+    assert!(CredentialVault::is_passphrase_protected(dir.path()));
+
+    CredentialVault::reset(dir.path()).unwrap();
+    assert!(!CredentialVault::is_passphrase_protected(dir.path()));
+
+    let vault = CredentialVault::open(dir.path(), &VaultKeySource::Passphrase("new".to_string())).unwrap();
+    assert!(vault.read(ProviderCredentialKey::FalApiKey).unwrap().is_none());
+  }
+
+  #[test]
+  fn rekey_moves_credentials_between_key_sources() {
+    let dir = tempfile::tempdir().unwrap();
+    let vault = CredentialVault::open(dir.path(), &VaultKeySource::MachineKey).unwrap();
+    vault.write(ProviderCredentialKey::FalApiKey, b"sk-fal").unwrap();
+
+    let passphrase = VaultKeySource::Passphrase("hunter2".to_string());
+    let vault = vault.rekey(&passphrase).unwrap();
+    assert!(CredentialVault::is_passphrase_protected(dir.path()));
+    assert!(matches!(CredentialVault::open(dir.path(), &VaultKeySource::MachineKey), Err(CredentialVaultError::WrongKey)));
+
+    let reopened = CredentialVault::open(dir.path(), &passphrase).unwrap();
+    assert_eq!(reopened.read(ProviderCredentialKey::FalApiKey).unwrap().unwrap(), b"sk-fal");
+
+    let vault = vault.rekey(&VaultKeySource::MachineKey).unwrap();
+    assert!(!CredentialVault::is_passphrase_protected(dir.path()));
+    assert_eq!(vault.read(ProviderCredentialKey::FalApiKey).unwrap().unwrap(), b"sk-fal");
+  }
+
+  #[test]
+  fn failed_rekey_leaves_the_old_vault_intact() {
+    let dir = tempfile::tempdir().unwrap();
+    let vault = CredentialVault::open(dir.path(), &VaultKeySource::MachineKey).unwrap();
+    vault.write(ProviderCredentialKey::FalApiKey, b"sk-fal").unwrap();
+    vault.write(ProviderCredentialKey::ReplicateApiKey, b"r8_abc").unwrap();
+
+    // A directory in the way of a staged file makes that write fail.
+    let blocked_path = rekey_staging_path(&vault.encrypted_path(ProviderCredentialKey::ReplicateApiKey));
+    std::fs::create_dir(&blocked_path).unwrap();
+
+    let result = vault.rekey(&VaultKeySource::Passphrase("hunter2".to_string()));
+    assert!(matches!(result, Err(CredentialVaultError::IoError(_))));
+
+    assert!(!CredentialVault::is_passphrase_protected(dir.path()));
+    assert!(!rekey_staging_path(&dir.path().join(CHECK_FILENAME)).exists());
+
+    let reopened = CredentialVault::open(dir.path(), &VaultKeySource::MachineKey).unwrap();
+    assert_eq!(reopened.read(ProviderCredentialKey::FalApiKey).unwrap().unwrap(), b"sk-fal");
+    assert_eq!(reopened.read(ProviderCredentialKey::ReplicateApiKey).unwrap().unwrap(), b"r8_abc");
+
+    // Once the obstruction is gone, rekeying works.
+    std::fs::remove_dir(&blocked_path).unwrap();
+    let passphrase = VaultKeySource::Passphrase("hunter2".to_string());
+    reopened.rekey(&passphrase).unwrap();
+    let rekeyed = CredentialVault::open(dir.path(), &passphrase).unwrap();
+    assert_eq!(rekeyed.read(ProviderCredentialKey::ReplicateApiKey).unwrap().unwrap(), b"r8_abc");
+  }
+
+  #[test]
+  fn migrates_plaintext_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("fal.api_key.txt"), "sk-legacy").unwrap();
+    std::fs::write(dir.path().join("grok.web_login.toml"), "{\"version\": 1}").unwrap();
+
+    let vault = CredentialVault::open(dir.path(), &VaultKeySource::MachineKey).unwrap();
+
+    let mut migrated = vault.migrate_plaintext_files().unwrap();
+    migrated.sort_by_key(|key| key.get_filename());
+    assert_eq!(migrated, vec![ProviderCredentialKey::FalApiKey, ProviderCredentialKey::GrokWebLogin]);
+
+    assert!(!dir.path().join("fal.api_key.txt").exists());
+    assert!(!dir.path().join("grok.web_login.toml").exists());
+    assert_eq!(vault.read(ProviderCredentialKey::FalApiKey).unwrap().unwrap(), b"sk-legacy");
+
+    // Nothing left to do the second time around.
+    assert!(vault.migrate_plaintext_files().unwrap().is_empty());
+  }
+}
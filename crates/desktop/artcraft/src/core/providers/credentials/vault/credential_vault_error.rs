@@ -0,0 +1,50 @@
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum CredentialVaultError {
+  IoError(std::io::Error),
+
+  /// The vault (or a bundle) was encrypted with a different key or passphrase.
+  WrongKey,
+
+  /// A file or bundle was truncated, tampered with, or isn't ours.
+  Corrupted(&'static str),
+
+  SerializationError(serde_json::Error),
+
+  /// The OS random number generator failed.
+  RandomnessUnavailable,
+
+  /// The vault hasn't been unlocked (no passphrase entered yet, or it failed to open).
+  Locked,
+}
+
+impl Error for CredentialVaultError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      Self::IoError(err) => Some(err),
+      Self::SerializationError(err) => Some(err),
+      _ => None,
+    }
+  }
+}
+
+impl Display for CredentialVaultError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::IoError(e) => write!(f, "IO error: {}", e),
+      Self::WrongKey => write!(f, "Wrong vault key or passphrase"),
+      Self::Corrupted(reason) => write!(f, "Corrupted credential data: {}", reason),
+      Self::SerializationError(e) => write!(f, "Serialization error: {}", e),
+      Self::RandomnessUnavailable => write!(f, "Secure random number generator unavailable"),
+      Self::Locked => write!(f, "Credential vault is locked"),
+    }
+  }
+}
+
+impl From<std::io::Error> for CredentialVaultError {
+  fn from(err: std::io::Error) -> Self {
+    Self::IoError(err)
+  }
+}
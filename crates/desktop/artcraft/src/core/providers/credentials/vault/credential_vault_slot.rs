@@ -0,0 +1,233 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use log::{error, info, warn};
+use serde_derive::Serialize;
+
+use crate::core::providers::credentials::vault::credential_vault::CredentialVault;
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+use crate::core::providers::credentials::vault::vault_key::VaultKeySource;
+
+/// Why the vault can't be read right now.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialVaultLockReason {
+  /// The vault is protected by a passphrase that hasn't been entered yet.
+  NeedsPassphrase,
+
+  /// The vault failed to open (eg. corrupt key material). Resetting it discards the
+  /// stored credentials.
+  Unavailable,
+}
+
+/// What the frontend needs to know about the vault.
+#[derive(Clone, Debug, Serialize)]
+pub struct CredentialVaultStatus {
+  /// Set while credentials can't be read or saved.
+  pub maybe_lock_reason: Option<CredentialVaultLockReason>,
+
+  pub is_passphrase_protected: bool,
+
+  /// The vault key changed since the last run (eg. a new machine id), so credentials
+  /// that could no longer be decrypted were discarded at startup.
+  pub credentials_were_reset: bool,
+
+  /// Why the vault failed to open, if it did.
+  pub maybe_error: Option<String>,
+}
+
+/// The credential vault, which may be locked and can be swapped out at runtime (unlock,
+/// passphrase change, reset). Cheap to clone; clones share the same vault.
+#[derive(Clone)]
+pub struct CredentialVaultSlot {
+  credentials_dir: PathBuf,
+  state: Arc<RwLock<SlotState>>,
+}
+
+struct SlotState {
+  maybe_vault: Option<CredentialVault>,
+  maybe_lock_reason: Option<CredentialVaultLockReason>,
+  credentials_were_reset: bool,
+  maybe_error: Option<String>,
+}
+
+impl CredentialVaultSlot {
+  /// Open the vault for app startup. Never fails: a vault that can't be opened starts
+  /// locked, and a passphrase-protected vault waits for `unlock` (so startup never runs
+  /// the passphrase KDF).
+  pub fn open_at_startup(credentials_dir: &Path) -> Self {
+    let mut state = SlotState {
+      maybe_vault: None,
+      maybe_lock_reason: None,
+      credentials_were_reset: false,
+      maybe_error: None,
+    };
+
+    if CredentialVault::is_passphrase_protected(credentials_dir) {
+      info!("Credential vault is passphrase protected; waiting for unlock.");
+      state.maybe_lock_reason = Some(CredentialVaultLockReason::NeedsPassphrase);
+    } else {
+      match open_machine_vault(credentials_dir) {
+        Ok((vault, credentials_were_reset)) => {
+          migrate_plaintext_files(&vault);
+          state.maybe_vault = Some(vault);
+          state.credentials_were_reset = credentials_were_reset;
+        }
+        Err(err) => {
+          error!("Credential vault failed to open; starting locked: {:?}", err);
+          state.maybe_lock_reason = Some(CredentialVaultLockReason::Unavailable);
+          state.maybe_error = Some(err.to_string());
+        }
+      }
+    }
+
+    Self {
+      credentials_dir: credentials_dir.to_path_buf(),
+      state: Arc::new(RwLock::new(state)),
+    }
+  }
+
+  /// The open vault, or `Locked`.
+  pub fn vault(&self) -> Result<CredentialVault, CredentialVaultError> {
+    self.read_state().maybe_vault.clone().ok_or(CredentialVaultError::Locked)
+  }
+
+  pub fn status(&self) -> CredentialVaultStatus {
+    let state = self.read_state();
+    CredentialVaultStatus {
+      maybe_lock_reason: state.maybe_lock_reason,
+      is_passphrase_protected: CredentialVault::is_passphrase_protected(&self.credentials_dir),
+      credentials_were_reset: state.credentials_were_reset,
+      maybe_error: state.maybe_error.clone(),
+    }
+  }
+
+  /// Open a passphrase-protected vault. Runs PBKDF2; call off the async runtime.
+  pub fn unlock(&self, passphrase: &str) -> Result<(), CredentialVaultError> {
+    let vault = CredentialVault::open(&self.credentials_dir, &VaultKeySource::Passphrase(passphrase.to_string()))?;
+    migrate_plaintext_files(&vault);
+    self.replace_vault(vault);
+    Ok(())
+  }
+
+  /// Protect the vault with a passphrase, or go back to the machine key with `None`.
+  /// The vault must be unlocked. Runs PBKDF2; call off the async runtime.
+  pub fn set_passphrase(&self, maybe_passphrase: Option<&str>) -> Result<(), CredentialVaultError> {
+    let key_source = match maybe_passphrase {
+      Some(passphrase) => VaultKeySource::Passphrase(passphrase.to_string()),
+      None => VaultKeySource::MachineKey,
+    };
+    let vault = self.vault()?.rekey(&key_source)?;
+    self.replace_vault(vault);
+    Ok(())
+  }
+
+  /// Discard every stored credential and start an empty machine-key vault. The way out
+  /// of a forgotten passphrase or a corrupt vault.
+  pub fn reset(&self) -> Result<(), CredentialVaultError> {
+    CredentialVault::reset(&self.credentials_dir)?;
+    let vault = CredentialVault::open(&self.credentials_dir, &VaultKeySource::MachineKey)?;
+    self.replace_vault(vault);
+    Ok(())
+  }
+
+  fn replace_vault(&self, vault: CredentialVault) {
+    let mut state = self.state.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    state.maybe_vault = Some(vault);
+    state.maybe_lock_reason = None;
+    state.maybe_error = None;
+  }
+
+  fn read_state(&self) -> std::sync::RwLockReadGuard<'_, SlotState> {
+    self.state.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+/// Open the machine-key vault. If its credentials were sealed under a different key
+/// (eg. the machine id changed) they can never be read again, so they're discarded and
+/// the caller is told, rather than refusing to start.
+fn open_machine_vault(credentials_dir: &Path) -> Result<(CredentialVault, bool), CredentialVaultError> {
+  match CredentialVault::open(credentials_dir, &VaultKeySource::MachineKey) {
+    Ok(vault) => Ok((vault, false)),
+    Err(CredentialVaultError::WrongKey) => {
+      warn!("Credential vault key changed; discarding unreadable credentials.");
+      CredentialVault::reset(credentials_dir)?;
+      let vault = CredentialVault::open(credentials_dir, &VaultKeySource::MachineKey)?;
+      Ok((vault, true))
+    }
+    Err(err) => Err(err),
+  }
+}
+
+/// Plaintext files from older versions are encrypted as soon as there's a vault to put
+/// them in. Failing to do so isn't fatal; the files are retried on the next open.
+fn migrate_plaintext_files(vault: &CredentialVault) {
+  match vault.migrate_plaintext_files() {
+    Ok(migrated) if !migrated.is_empty() => info!("Encrypted plaintext credentials: {:?}", migrated),
+    Ok(_) => {}
+    Err(err) => warn!("Failed to encrypt plaintext credentials: {:?}", err),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::providers::credentials::provider_credential_key::ProviderCredentialKey;
+
+  #[test]
+  fn fresh_vault_is_unlocked() {
+    let dir = tempfile::tempdir().unwrap();
+    let slot = CredentialVaultSlot::open_at_startup(dir.path());
+    let status = slot.status();
+    assert_eq!(status.maybe_lock_reason, None);
+    assert!(!status.credentials_were_reset);
+    assert!(slot.vault().is_ok());
+  }
+
+  #[test]
+  fn corrupt_vault_starts_locked() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("credential_vault.machine_key"), b"too short").unwrap();
+
+    let slot = CredentialVaultSlot::open_at_startup(dir.path());
+    let status = slot.status();
+    assert_eq!(status.maybe_lock_reason, Some(CredentialVaultLockReason::Unavailable));
+    assert!(status.maybe_error.is_some());
+    assert!(matches!(slot.vault(), Err(CredentialVaultError::Locked)));
+
+    slot.reset().unwrap();
+    assert!(slot.vault().is_ok());
+  }
+
+  #[test]
+  fn changed_machine_key_reports_the_reset() {
+    let dir = tempfile::tempdir().unwrap();
+    CredentialVault::open(dir.path(), &VaultKeySource::MachineKey).unwrap()
+      .write(ProviderCredentialKey::FalApiKey, b"sk-fal").unwrap();
+
+    // Simulate a new machine: same directory, different machine secret.
+    std::fs::remove_file(dir.path().join("credential_vault.machine_key")).unwrap();
+
+    let slot = CredentialVaultSlot::open_at_startup(dir.path());
+    assert!(slot.status().credentials_were_reset);
+    assert!(slot.vault().unwrap().read(ProviderCredentialKey::FalApiKey).unwrap().is_none());
+  }
+
+  #[test]
+  fn passphrase_vault_waits_for_unlock() {
+    let dir = tempfile::tempdir().unwrap();
+    let slot = CredentialVaultSlot::open_at_startup(dir.path());
+    slot.vault().unwrap().write(ProviderCredentialKey::FalApiKey, b"sk-fal").unwrap();
+    slot.set_passphrase(Some("hunter2")).unwrap();
+
+    let slot = CredentialVaultSlot::open_at_startup(dir.path());
+    assert_eq!(slot.status().maybe_lock_reason, Some(CredentialVaultLockReason::NeedsPassphrase));
+    assert!(slot.status().is_passphrase_protected);
+    assert!(matches!(slot.vault(), Err(CredentialVaultError::Locked)));
+
+    assert!(matches!(slot.unlock("wrong"), Err(CredentialVaultError::WrongKey)));
+    slot.unlock("hunter2").unwrap();
+    assert_eq!(slot.status().maybe_lock_reason, None);
+    assert_eq!(slot.vault().unwrap().read(ProviderCredentialKey::FalApiKey).unwrap().unwrap(), b"sk-fal");
+  }
+}
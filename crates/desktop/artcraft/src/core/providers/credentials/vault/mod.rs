@@ -0,0 +1,6 @@
+pub mod credential_bundle;
+pub mod credential_vault;
+pub mod credential_vault_error;
+pub mod credential_vault_slot;
+pub mod vault_cipher;
+pub mod vault_key;
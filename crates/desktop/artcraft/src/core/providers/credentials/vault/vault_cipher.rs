@@ -0,0 +1,104 @@
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+use crate::core::providers::credentials::vault::vault_key::{random_bytes, VaultKey};
+
+/// Identifies (and versions) our encrypted blobs.
+const MAGIC: &[u8; 4] = b"ACV1";
+
+/// Encrypt with ChaCha20-Poly1305 under a fresh random nonce.
+///
+/// Layout: `MAGIC || nonce || ciphertext || tag`. The `context` (eg. the credential
+/// name) is authenticated but not stored, so a blob can't be swapped into another slot.
+pub fn seal(key: &VaultKey, context: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, CredentialVaultError> {
+  let nonce_bytes = random_bytes(NONCE_LEN)?;
+
+  let nonce = Nonce::try_assume_unique_for_key(&nonce_bytes)
+    .map_err(|_| CredentialVaultError::RandomnessUnavailable)?;
+
+  let mut in_out = plaintext.to_vec();
+
+  aead_key(key).seal_in_place_append_tag(nonce, Aad::from(context), &mut in_out)
+    .map_err(|_| CredentialVaultError::Corrupted("encryption failed"))?;
+
+  let mut sealed = Vec::with_capacity(MAGIC.len() + NONCE_LEN + in_out.len());
+  sealed.extend_from_slice(MAGIC);
+  sealed.extend_from_slice(&nonce_bytes);
+  sealed.extend_from_slice(&in_out);
+
+  Ok(sealed)
+}
+
+/// Decrypt a blob from `seal`. Fails with `WrongKey` if the key or context don't match.
+pub fn open(key: &VaultKey, context: &[u8], sealed: &[u8]) -> Result<Vec<u8>, CredentialVaultError> {
+  let body = sealed.strip_prefix(MAGIC.as_slice())
+    .ok_or(CredentialVaultError::Corrupted("missing vault header"))?;
+
+  if body.len() < NONCE_LEN + CHACHA20_POLY1305.tag_len() {
+    return Err(CredentialVaultError::Corrupted("truncated ciphertext"));
+  }
+
+  let (nonce_bytes, ciphertext) = body.split_at(NONCE_LEN);
+
+  let nonce = Nonce::try_assume_unique_for_key(nonce_bytes)
+    .map_err(|_| CredentialVaultError::Corrupted("bad nonce"))?;
+
+  let mut in_out = ciphertext.to_vec();
+
+  let plaintext_len = aead_key(key).open_in_place(nonce, Aad::from(context), &mut in_out)
+    .map_err(|_| CredentialVaultError::WrongKey)?
+    .len();
+
+  in_out.truncate(plaintext_len);
+
+  Ok(in_out)
+}
+
+pub fn is_sealed(bytes: &[u8]) -> bool {
+  bytes.starts_with(MAGIC)
+}
+
+fn aead_key(key: &VaultKey) -> LessSafeKey {
+  let unbound = UnboundKey::new(&CHACHA20_POLY1305, key.as_bytes())
+    .expect("vault keys are the right length for ChaCha20-Poly1305");
+  LessSafeKey::new(unbound)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn key(byte: u8) -> VaultKey {
+    VaultKey::from_machine_secret(&[byte; 32], None)
+  }
+
+  #[test]
+  fn round_trip() {
+    let sealed = seal(&key(1), b"fal", b"sk-secret").unwrap();
+    assert!(is_sealed(&sealed));
+    assert!(!sealed.windows(9).any(|window| window == b"sk-secret"));
+    assert_eq!(open(&key(1), b"fal", &sealed).unwrap(), b"sk-secret");
+  }
+
+  #[test]
+  fn nonces_are_fresh() {
+    assert_ne!(seal(&key(1), b"fal", b"same").unwrap(), seal(&key(1), b"fal", b"same").unwrap());
+  }
+
+  #[test]
+  fn wrong_key_or_context_is_rejected() {
+    let sealed = seal(&key(1), b"fal", b"sk-secret").unwrap();
+    assert!(matches!(open(&key(2), b"fal", &sealed), Err(CredentialVaultError::WrongKey)));
+    assert!(matches!(open(&key(1), b"replicate", &sealed), Err(CredentialVaultError::WrongKey)));
+  }
+
+  #[test]
+  fn tampering_is_rejected() {
+    let mut sealed = seal(&key(1), b"fal", b"sk-secret").unwrap();
+    let last = sealed.len() - 1;
+    sealed[last] ^= 1;
+    assert!(open(&key(1), b"fal", &sealed).is_err());
+    assert!(open(&key(1), b"fal", b"ACV1short").is_err());
+    assert!(open(&key(1), b"fal", b"sk-plaintext").is_err());
+  }
+}
@@ -0,0 +1,222 @@
+use std::num::NonZeroU32;
+use std::path::Path;
+
+use log::{info, warn};
+use ring::hkdf;
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::core::providers::credentials::vault::credential_vault_error::CredentialVaultError;
+
+pub const VAULT_KEY_LEN: usize = 32;
+pub const SALT_LEN: usize = 16;
+
+/// OWASP's current recommendation for PBKDF2-HMAC-SHA256.
+pub const PASSPHRASE_KDF_ITERATIONS: u32 = 600_000;
+
+const MACHINE_KEY_HKDF_INFO: &[u8] = b"artcraft credential vault v1";
+
+/// Where the vault's encryption key comes from.
+pub enum VaultKeySource {
+  /// Derived from a user passphrase (PBKDF2-HMAC-SHA256).
+  Passphrase(String),
+
+  /// A random secret kept in the credentials directory, bound to this machine's
+  /// platform id (IOPlatformUUID on macOS, MachineGuid on Windows, machine-id on
+  /// Linux) when one is available. Nothing leaves the process, so this works
+  /// without a keychain or secret service.
+  MachineKey,
+}
+
+/// A 256-bit symmetric key. Zeroed on drop.
+#[derive(Clone)]
+pub struct VaultKey([u8; VAULT_KEY_LEN]);
+
+impl VaultKey {
+  pub fn from_passphrase(passphrase: &str, salt: &[u8], iterations: u32) -> Self {
+    let iterations = NonZeroU32::new(iterations.max(1)).expect("iterations are at least one");
+    let mut key = [0u8; VAULT_KEY_LEN];
+    pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, iterations, salt, passphrase.as_bytes(), &mut key);
+    Self(key)
+  }
+
+  /// Load (or create) the machine secret at `secret_file_path` and bind it to the
+  /// platform machine id.
+  pub fn from_machine_secret_file(secret_file_path: &Path) -> Result<Self, CredentialVaultError> {
+    let secret = load_or_create_random_file(secret_file_path, VAULT_KEY_LEN)?;
+    Ok(Self::from_machine_secret_on_this_machine(&secret))
+  }
+
+  /// Bind a machine secret (that the caller persists) to the platform machine id.
+  pub fn from_machine_secret_on_this_machine(secret: &[u8]) -> Self {
+    let maybe_machine_id = read_machine_id();
+
+    if maybe_machine_id.is_none() {
+      warn!("No platform machine id available; the credential vault key is not bound to this machine.");
+    }
+
+    Self::from_machine_secret(secret, maybe_machine_id.as_deref())
+  }
+
+  pub(super) fn from_machine_secret(secret: &[u8], maybe_machine_id: Option<&str>) -> Self {
+    let salt = hkdf::Salt::new(hkdf::HKDF_SHA256, maybe_machine_id.unwrap_or("").as_bytes());
+
+    let mut key = [0u8; VAULT_KEY_LEN];
+
+    salt.extract(secret)
+      .expand(&[MACHINE_KEY_HKDF_INFO], KeyLen(VAULT_KEY_LEN))
+      .and_then(|okm| okm.fill(&mut key))
+      .expect("HKDF output length is valid for SHA-256");
+
+    Self(key)
+  }
+
+  pub(super) fn as_bytes(&self) -> &[u8; VAULT_KEY_LEN] {
+    &self.0
+  }
+}
+
+impl Drop for VaultKey {
+  fn drop(&mut self) {
+    self.0.fill(0);
+  }
+}
+
+struct KeyLen(usize);
+
+impl hkdf::KeyType for KeyLen {
+  fn len(&self) -> usize {
+    self.0
+  }
+}
+
+pub fn random_bytes(len: usize) -> Result<Vec<u8>, CredentialVaultError> {
+  let mut bytes = vec![0u8; len];
+  SystemRandom::new().fill(&mut bytes)
+    .map_err(|_| CredentialVaultError::RandomnessUnavailable)?;
+  Ok(bytes)
+}
+
+/// Read a file of exactly `len` random bytes, creating it (owner read/write only) if missing.
+pub fn load_or_create_random_file(path: &Path, len: usize) -> Result<Vec<u8>, CredentialVaultError> {
+  if path.exists() {
+    let bytes = std::fs::read(path)?;
+    if bytes.len() != len {
+      return Err(CredentialVaultError::Corrupted("unexpected vault key file length"));
+    }
+    return Ok(bytes);
+  }
+
+  info!("Creating credential vault key file: {:?}", path);
+
+  let bytes = random_bytes(len)?;
+  write_private_file(path, &bytes)?;
+  Ok(bytes)
+}
+
+/// Write a file atomically, readable only by the current user where the platform supports it.
+pub fn write_private_file(path: &Path, contents: &[u8]) -> Result<(), CredentialVaultError> {
+  let tmp_path = path.with_extension("tmp");
+
+  {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::OpenOptionsExt;
+      options.mode(0o600);
+    }
+
+    use std::io::Write;
+    let mut file = options.open(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+  }
+
+  std::fs::rename(&tmp_path, path)?;
+  Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn read_machine_id() -> Option<String> {
+  ["/etc/machine-id", "/var/lib/dbus/machine-id"].iter()
+    .filter_map(|path| std::fs::read_to_string(path).ok())
+    .map(|id| id.trim().to_string())
+    .find(|id| !id.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+fn read_machine_id() -> Option<String> {
+  let output = std::process::Command::new("ioreg")
+    .args(["-rd1", "-c", "IOPlatformExpertDevice"])
+    .output()
+    .ok()?;
+
+  // Line looks like: `"IOPlatformUUID" = "01234567-89AB-CDEF-0123-456789ABCDEF"`
+  String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .find(|line| line.contains("IOPlatformUUID"))
+    .and_then(|line| line.rsplit('"').nth(1))
+    .map(|id| id.to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn read_machine_id() -> Option<String> {
+  let output = std::process::Command::new("reg")
+    .args(["query", r"HKLM\SOFTWARE\Microsoft\Cryptography", "/v", "MachineGuid"])
+    .output()
+    .ok()?;
+
+  // Line looks like: `    MachineGuid    REG_SZ    01234567-89ab-cdef-0123-456789abcdef`
+  String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .find(|line| line.contains("MachineGuid"))
+    .and_then(|line| line.split_whitespace().last())
+    .map(|id| id.to_string())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn read_machine_id() -> Option<String> {
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn passphrase_key_depends_on_salt_and_passphrase() {
+    let a = VaultKey::from_passphrase("hunter2", b"salt-salt-salt-1", 1_000);
+    let b = VaultKey::from_passphrase("hunter2", b"salt-salt-salt-1", 1_000);
+    let c = VaultKey::from_passphrase("hunter2", b"salt-salt-salt-2", 1_000);
+    let d = VaultKey::from_passphrase("hunter3", b"salt-salt-salt-1", 1_000);
+
+    assert_eq!(a.as_bytes(), b.as_bytes());
+    assert_ne!(a.as_bytes(), c.as_bytes());
+    assert_ne!(a.as_bytes(), d.as_bytes());
+  }
+
+  #[test]
+  fn machine_key_is_bound_to_machine_id() {
+    let secret = [7u8; VAULT_KEY_LEN];
+    let a = VaultKey::from_machine_secret(&secret, Some("machine-a"));
+    let b = VaultKey::from_machine_secret(&secret, Some("machine-b"));
+    let a_again = VaultKey::from_machine_secret(&secret, Some("machine-a"));
+
+    assert_ne!(a.as_bytes(), b.as_bytes());
+    assert_eq!(a.as_bytes(), a_again.as_bytes());
+  }
+
+  #[test]
+  fn random_file_is_created_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("vault.key");
+
+    let first = load_or_create_random_file(&path, VAULT_KEY_LEN).unwrap();
+    let second = load_or_create_random_file(&path, VAULT_KEY_LEN).unwrap();
+
+    assert_eq!(first.len(), VAULT_KEY_LEN);
+    assert_eq!(first, second);
+  }
+}
@@ -2,10 +2,17 @@ use crate::core::state::app_env_configs::app_env_configs_serializeable::{AppEnvC
 use crate::core::state::data_dir::app_data_root::AppDataRoot;
 use errors::AnyhowResult;
 use artcraft_client::utils::api_host::ApiHost;
+use artcraft_router::client::router_mock_client::RouterMockClient;
+use artcraft_router::mock::mock_provider_config::MockProviderConfig;
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct AppEnvConfigs {
   pub storyteller_host: ApiHost,
+
+  /// Set when generations should go to the mock provider. Built once, so every
+  /// clone shares the same submitted jobs.
+  pub mock_provider: Option<RouterMockClient>,
 }
 
 impl AppEnvConfigs {
@@ -30,8 +37,31 @@ impl AppEnvConfigs {
     
     println!("Using storyteller API host: {:?}", storyteller_api);
 
+    let mock_provider = input.as_ref()
+        .filter(|i| i.use_mock_provider.unwrap_or(false))
+        .map(mock_provider_from_configs);
+
+    if mock_provider.is_some() {
+      println!("Using the mock generation provider.");
+    }
+
     Ok(Self {
       storyteller_host: storyteller_api,
+      mock_provider,
     })
   }
 }
+
+fn mock_provider_from_configs(input: &AppEnvConfigsSerializable) -> RouterMockClient {
+  let mut config = MockProviderConfig::default();
+  if let Some(millis) = input.mock_provider_queue_delay_millis {
+    config.queue_delay = Duration::from_millis(millis);
+  }
+  if let Some(millis) = input.mock_provider_processing_delay_millis {
+    config.processing_delay = Duration::from_millis(millis);
+  }
+  if let Some(media_root) = input.mock_provider_media_root.as_ref() {
+    config.media_root = media_root.clone();
+  }
+  RouterMockClient::new(config)
+}
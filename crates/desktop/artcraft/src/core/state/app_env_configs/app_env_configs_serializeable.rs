@@ -1,6 +1,7 @@
 use crate::core::state::data_dir::app_data_root::AppDataRoot;
 use errors::AnyhowResult;
 use serde_derive::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 const CURRENT_VERSION: &str = "1";
 
@@ -18,6 +19,13 @@ pub struct AppEnvConfigsSerializable {
   pub version: Option<String>,
   pub storyteller_port: Option<u32>,
   pub storyteller_host: Option<StorytellerApiHost>,
+
+  /// Send router-based generations to the offline mock provider instead of Artcraft.
+  pub use_mock_provider: Option<bool>,
+  pub mock_provider_queue_delay_millis: Option<u64>,
+  pub mock_provider_processing_delay_millis: Option<u64>,
+  /// Where the placeholder media lives; defaults to the checkout the app was built from.
+  pub mock_provider_media_root: Option<PathBuf>,
 }
 
 impl AppEnvConfigsSerializable {
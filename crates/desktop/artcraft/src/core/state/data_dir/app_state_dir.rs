@@ -28,6 +28,12 @@ impl AppStateDir {
     self.path.join(format!("tasks_v{TASK_DATABASE_VERSION}.sqlite"))
   }
 
+  pub fn get_history_sqlite_database_path(&self) -> PathBuf {
+    // NB: Unlike tasks, this is the user's library. It's never versioned or thrown away;
+    // its migrations are append-only.
+    self.path.join("history.sqlite")
+  }
+
   pub fn get_window_size_config_file(&self) -> PathBuf {
     self.path.join("window_size.json")
   }
@@ -0,0 +1,21 @@
+use crate::core::state::data_dir::app_data_root::AppDataRoot;
+use errors::AnyhowResult;
+use sqlite_history::connection::HistoryDbConnection;
+
+/// The user's persistent library of past generations (prompts, settings, and outputs).
+#[derive(Clone)]
+pub struct GenerationHistoryDatabase {
+  connection: HistoryDbConnection,
+}
+
+impl GenerationHistoryDatabase {
+  pub async fn connect(root: &AppDataRoot) -> AnyhowResult<Self> {
+    let path = root.state_dir().get_history_sqlite_database_path();
+    let connection = HistoryDbConnection::connect_and_migrate(path).await?;
+    Ok(Self { connection })
+  }
+
+  pub fn get_connection(&self) -> &HistoryDbConnection {
+    &self.connection
+  }
+}
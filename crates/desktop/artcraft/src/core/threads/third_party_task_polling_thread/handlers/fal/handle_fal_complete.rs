@@ -1,3 +1,4 @@
+use crate::core::generation_history::record_generation_outcome::{record_generation_success, GenerationOutputs};
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::threads::third_party_task_polling_thread::events::notify_frontend_of_completion::{
   notify_frontend_of_completion, CompletionData,
@@ -163,6 +164,14 @@ async fn handle_fal_complete_inner(
   }).await?;
 
   if updated {
+    record_generation_success(app_handle, GenerationOutputs {
+      task_id: &task.id,
+      maybe_batch_token: maybe_batch_token.as_ref(),
+      maybe_media_file_token: maybe_primary_media_file_token.as_ref(),
+      maybe_cdn_url: maybe_cdn_url_str.as_deref(),
+      maybe_thumbnail_url_template: maybe_thumbnail_url_template.as_deref(),
+    }).await;
+
     if let Some(primary_token) = maybe_primary_media_file_token {
       let completion = CompletionData {
         primary_media_file_token: primary_token,
@@ -1,6 +1,7 @@
 use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
 use crate::core::events::generation_events::common::{GenerationAction, GenerationServiceProvider};
 use crate::core::events::generation_events::generation_failed_event::GenerationFailedEvent;
+use crate::core::generation_history::record_generation_outcome::record_generation_failure;
 use crate::core::state::task_database::TaskDatabase;
 use enums::tauri::tasks::task_status::TaskStatus;
 use enums::tauri::tasks::task_type::TaskType;
@@ -27,6 +28,8 @@ pub async fn handle_fal_failure(
     error!("[FalPolling] Failed to update task status for {}: {:?}", task.id.as_str(), err);
   }
 
+  record_generation_failure(app_handle, &task.id, TaskStatus::CompleteFailure, Some(reason)).await;
+
   let action = task_type_to_generation_action(task.task_type);
 
   let event = GenerationFailedEvent {
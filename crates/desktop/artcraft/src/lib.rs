@@ -15,27 +15,45 @@ use crate::core::commands::download::download_url_command::download_url_command;
 use crate::core::commands::enqueue::image_bg_removal::enqueue_image_bg_removal_command::enqueue_image_bg_removal_command;
 use crate::core::commands::enqueue::image_to_gaussian::enqueue_image_to_gaussian_command::enqueue_image_to_gaussian_command;
 use crate::core::commands::enqueue::image_to_object::enqueue_image_to_3d_object_command::enqueue_image_to_3d_object_command;
+use crate::core::commands::generate::generate_batch::batch_task_throttle::BatchTaskThrottle;
+use crate::core::commands::generate::generate_batch::enqueue_generation_batch_command::enqueue_generation_batch_command;
 use crate::core::commands::generate::generate_image::generate_image_command::generate_image_command;
 use crate::core::commands::generate::generate_video::generate_video_command::generate_video_command;
+use crate::core::commands::generation_history::delete_generation_history_item_command::delete_generation_history_item_command;
+use crate::core::commands::generation_history::get_generation_history_item_command::get_generation_history_item_command;
+use crate::core::commands::generation_history::rerun_generation_command::rerun_generation_command;
+use crate::core::commands::generation_history::search_generation_history_command::search_generation_history_command;
 use crate::core::commands::flip_image::flip_image;
 use crate::core::commands::get_app_info_command::get_app_info_command;
 use crate::core::commands::load_without_cors_command::load_without_cors_command;
+use crate::core::commands::local_api::local_api_configure_command::local_api_configure_command;
+use crate::core::commands::local_api::local_api_get_status_command::local_api_get_status_command;
 use crate::core::commands::media_files::media_file_delete_command::media_file_delete_command;
 use crate::core::commands::platform_info_command::platform_info_command;
 use crate::core::commands::providers::deprecated::get_provider_order_command::get_provider_order_command;
 use crate::core::commands::providers::deprecated::set_provider_order_command::set_provider_order_command;
 use crate::core::commands::providers::provider_clear_command::provider_clear_command;
+use crate::core::commands::providers::provider_export_credentials_command::provider_export_credentials_command;
+use crate::core::commands::providers::provider_import_credentials_command::provider_import_credentials_command;
 use crate::core::commands::providers::provider_list_command::provider_list_command;
 use crate::core::commands::providers::provider_set_api_key_command::provider_set_api_key_command;
+use crate::core::commands::providers::provider_vault_reset_command::provider_vault_reset_command;
+use crate::core::commands::providers::provider_vault_set_passphrase_command::provider_vault_set_passphrase_command;
+use crate::core::commands::providers::provider_vault_status_command::provider_vault_status_command;
+use crate::core::commands::providers::provider_vault_unlock_command::provider_vault_unlock_command;
+use crate::core::commands::task_queue::cancel_task_command::cancel_task_command;
 use crate::core::commands::task_queue::get_task_queue_command::get_task_queue_command;
 use crate::core::commands::task_queue::mark_task_as_dismissed_command::mark_task_as_dismissed_command;
 use crate::core::commands::task_queue::tasks_nuke_all_command::tasks_nuke_all_command;
 use crate::core::lifecycle::startup::handle_tauri_startup::handle_tauri_startup;
 use crate::core::lifecycle::startup::setup_main_window::setup_main_window;
+use crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache;
+use crate::core::providers::credentials::vault::credential_vault_slot::CredentialVaultSlot;
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::state::app_preferences::app_preferences_manager::load_app_preferences_or_default;
 use crate::core::state::artcraft_platform_info::ArtcraftPlatformInfo;
 use crate::core::state::data_dir::app_data_root::AppDataRoot;
+use crate::core::state::data_dir::trait_data_subdir::DataSubdir;
 use crate::core::state::provider_priority::ProviderPriorityStore;
 use crate::core::threads::discord_presence_thread::discord_presence_thread;
 use crate::core::threads::main_window_thread::main_window_thread::main_window_thread;
@@ -96,7 +114,13 @@ pub fn run() {
   println!("Loading app preferences...");
   let app_preferences = load_app_preferences_or_default(&app_data_root);
   
-  let provider_credential_cache = crate::core::providers::credentials::provider_credential_loading_cache::ProviderCredentialLoadingCache::new(app_data_root.clone());
+  println!("Opening credential vault...");
+  // NB: Never fails. A vault that can't be opened starts locked, and the frontend learns
+  // why (or that credentials were discarded) from `provider_vault_status_command`.
+  let credential_vault_slot = CredentialVaultSlot::open_at_startup(app_data_root.credentials_dir().path());
+  println!("Credential vault status: {:?}", credential_vault_slot.status());
+
+  let provider_credential_cache = ProviderCredentialLoadingCache::new(credential_vault_slot);
   let provider_credential_cache_2 = provider_credential_cache.clone();
 
   // NB: tauri-plugin-http stores the credentials on disk, so we can defer to that for now.
@@ -196,6 +220,7 @@ pub fn run() {
     .manage(app_preferences)
     .manage(artcraft_platform_info)
     .manage(artcraft_usage_tracker)
+    .manage(BatchTaskThrottle::new())
     .manage(grok_creds_manager)
     .manage(grok_prompt_queue)
     .manage(midjourney_creds_manager)
@@ -209,10 +234,13 @@ pub fn run() {
   // TODO: Break this out into another module, because RustRover/IntelliJ lags with these macros.
   //  My first attempt at naively doing this didn't work because the macros can't find their codegen'd targets.
   let builder = builder.invoke_handler(tauri::generate_handler![
+    cancel_task_command,
     check_sora_session_command,
+    delete_generation_history_item_command,
     download_directory_reveal_command,
     download_media_file_command,
     download_url_command,
+    enqueue_generation_batch_command,
     enqueue_image_bg_removal_command,
     enqueue_image_to_3d_object_command,
     enqueue_image_to_gaussian_command,
@@ -224,15 +252,26 @@ pub fn run() {
     generate_video_command,
     get_app_info_command,
     get_app_preferences_command,
+    get_generation_history_item_command,
     get_provider_order_command,
     get_task_queue_command,
     provider_clear_command,
+    provider_export_credentials_command,
+    provider_import_credentials_command,
     provider_list_command,
     provider_set_api_key_command,
+    provider_vault_reset_command,
+    provider_vault_set_passphrase_command,
+    provider_vault_status_command,
+    provider_vault_unlock_command,
+    rerun_generation_command,
+    search_generation_history_command,
     grok_clear_credentials_command,
     grok_get_credential_info_command,
     grok_open_login_command,
     load_without_cors_command,
+    local_api_configure_command,
+    local_api_get_status_command,
     mark_task_as_dismissed_command,
     media_file_delete_command,
     midjourney_clear_credentials_command,
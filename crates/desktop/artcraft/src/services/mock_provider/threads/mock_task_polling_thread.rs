@@ -0,0 +1,164 @@
+use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
+use crate::core::events::generation_events::generation_complete_event::GenerationCompleteEvent;
+use crate::core::events::generation_events::generation_failed_event::GenerationFailedEvent;
+use crate::core::generation_history::record_generation_outcome::{record_generation_failure, record_generation_success, GenerationOutputs};
+use crate::core::state::task_database::TaskDatabase;
+use crate::core::utils::enum_conversion::generation_provider::to_generation_service_provider;
+use crate::core::utils::enum_conversion::task_type::to_generation_action;
+use crate::core::utils::task_database_pending_statuses::TASK_DATABASE_PENDING_STATUSES;
+use artcraft_router::client::router_mock_client::RouterMockClient;
+use artcraft_router::mock::mock_job_status::{MockJobOutput, MockJobStatus};
+use enums::common::generation_provider::GenerationProvider;
+use enums::tauri::tasks::task_failure_type::TaskFailureType;
+use enums::tauri::tasks::task_media_file_class::TaskMediaFileClass;
+use enums::tauri::tasks::task_status::TaskStatus;
+use errors::AnyhowResult;
+use log::{error, info};
+use sqlite_tasks::queries::list_tasks_by_provider_and_status::{list_tasks_by_provider_and_status, ListTasksByProviderAndStatusArgs};
+use sqlite_tasks::queries::task::Task;
+use sqlite_tasks::queries::update_successful_task_status_with_metadata::{update_successful_task_status_with_metadata, UpdateSuccessfulTaskArgs};
+use sqlite_tasks::queries::update_task_status_with_rich_failure::{update_task_status_with_rich_failure, UpdateTaskWithRichFailureArgs};
+use std::time::Duration;
+use tauri::AppHandle;
+use url::Url;
+
+/// Finishes tasks that were sent to the mock provider (see `AppEnvConfigs::mock_provider`).
+///
+/// Mock tasks are stored as Artcraft tasks, but the Storyteller poller only touches jobs the
+/// server knows about, so nothing else would ever complete them.
+pub async fn mock_task_polling_thread(
+  app_handle: AppHandle,
+  task_database: TaskDatabase,
+  mock_client: RouterMockClient,
+) -> ! {
+  loop {
+    if let Err(err) = poll_mock_tasks(&app_handle, &task_database, &mock_client).await {
+      error!("An error occurred polling mock tasks: {:?}", err);
+    }
+    tokio::time::sleep(Duration::from_millis(1_000)).await;
+  }
+}
+
+async fn poll_mock_tasks(
+  app_handle: &AppHandle,
+  task_database: &TaskDatabase,
+  mock_client: &RouterMockClient,
+) -> AnyhowResult<()> {
+  let local_tasks = list_tasks_by_provider_and_status(ListTasksByProviderAndStatusArgs {
+    db: task_database.get_connection(),
+    provider: GenerationProvider::Artcraft,
+    task_statuses: &TASK_DATABASE_PENDING_STATUSES,
+  }).await?;
+
+  for task in local_tasks.tasks.iter() {
+    let Some(job_id) = task.provider_job_id.as_deref() else {
+      continue;
+    };
+
+    if !RouterMockClient::is_mock_job_id(job_id) {
+      continue;
+    }
+
+    // NB: Mock jobs only live in memory, so tasks from a previous run are reported as failed.
+    let status = match mock_client.get_job_status(job_id) {
+      Ok(status) => status,
+      Err(err) => MockJobStatus::Failed { reason: err.to_string() },
+    };
+
+    match status {
+      MockJobStatus::Queued { .. } | MockJobStatus::Processing { .. } => continue,
+      MockJobStatus::Completed(output) => handle_completed_task(app_handle, task_database, task, &output).await?,
+      MockJobStatus::Failed { reason } => handle_failed_task(app_handle, task_database, task, TaskStatus::CompleteFailure, Some(&reason)).await?,
+      MockJobStatus::Cancelled => handle_failed_task(app_handle, task_database, task, TaskStatus::CancelledByProvider, None).await?,
+    }
+  }
+
+  Ok(())
+}
+
+async fn handle_completed_task(
+  app_handle: &AppHandle,
+  task_database: &TaskDatabase,
+  task: &Task,
+  output: &MockJobOutput,
+) -> AnyhowResult<()> {
+  info!("Mock task completed: {:?}", task.id);
+
+  // The placeholder media is on local disk, so point the frontend at it directly.
+  let maybe_media_url = Url::from_file_path(&output.media_path)
+      .ok()
+      .map(|url| url.to_string());
+
+  let updated = update_successful_task_status_with_metadata(UpdateSuccessfulTaskArgs {
+    db: task_database.get_connection(),
+    task_id: &task.id,
+    maybe_batch_token: None,
+    maybe_primary_media_file_token: None,
+    maybe_primary_media_file_class: Some(media_file_class(output)),
+    maybe_primary_media_file_cdn_url: maybe_media_url.as_deref(),
+    maybe_primary_media_file_thumbnail_url_template: None,
+  }).await?;
+
+  if !updated {
+    return Ok(());
+  }
+
+  record_generation_success(app_handle, GenerationOutputs {
+    task_id: &task.id,
+    maybe_batch_token: None,
+    maybe_media_file_token: None,
+    maybe_cdn_url: maybe_media_url.as_deref(),
+    maybe_thumbnail_url_template: None,
+  }).await;
+
+  let event = GenerationCompleteEvent {
+    action: Some(to_generation_action(task.task_type)),
+    service: to_generation_service_provider(task.provider),
+    model: None,
+  };
+
+  event.send_infallible(app_handle);
+
+  Ok(())
+}
+
+async fn handle_failed_task(
+  app_handle: &AppHandle,
+  task_database: &TaskDatabase,
+  task: &Task,
+  status: TaskStatus,
+  maybe_failure_message: Option<&str>,
+) -> AnyhowResult<()> {
+  info!("Mock task ended without a result: {:?} ({:?})", task.id, status);
+
+  update_task_status_with_rich_failure(UpdateTaskWithRichFailureArgs {
+    db: task_database.get_connection(),
+    task_id: &task.id,
+    status,
+    maybe_failure_type: Some(TaskFailureType::GenerationFailed),
+    maybe_failure_message,
+  }).await?;
+
+  record_generation_failure(app_handle, &task.id, status, maybe_failure_message).await;
+
+  let event = GenerationFailedEvent {
+    action: to_generation_action(task.task_type),
+    service: to_generation_service_provider(task.provider),
+    model: None,
+    reason: maybe_failure_message.map(|message| message.to_string()),
+  };
+
+  event.send_infallible(app_handle);
+
+  Ok(())
+}
+
+fn media_file_class(output: &MockJobOutput) -> TaskMediaFileClass {
+  if output.mime_type.starts_with("video/") {
+    TaskMediaFileClass::Video
+  } else if output.mime_type.starts_with("image/") {
+    TaskMediaFileClass::Image
+  } else {
+    TaskMediaFileClass::Dimensional
+  }
+}
@@ -0,0 +1 @@
+pub mod mock_task_polling_thread;
@@ -1,5 +1,6 @@
 pub mod grok;
 pub mod midjourney;
+pub mod mock_provider;
 pub mod sora;
 pub mod storyteller;
 pub mod worldlabs;
\ No newline at end of file
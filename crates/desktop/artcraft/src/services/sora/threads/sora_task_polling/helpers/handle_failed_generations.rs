@@ -1,6 +1,7 @@
 use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
 use crate::core::events::generation_events::common::{GenerationAction, GenerationServiceProvider};
 use crate::core::events::generation_events::generation_failed_event::GenerationFailedEvent;
+use crate::core::generation_history::record_generation_outcome::record_generation_failure;
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::state::data_dir::app_data_root::AppDataRoot;
 use crate::core::state::task_database::TaskDatabase;
@@ -51,6 +52,13 @@ pub async fn handle_classic_failed_generations(
         task_id: &local_task.id,
         status: task_status::TaskStatus::CompleteFailure,
       }).await?;
+
+      record_generation_failure(
+        app_handle,
+        &local_task.id,
+        task_status::TaskStatus::CompleteFailure,
+        task.reason.as_deref(),
+      ).await;
     }
   }
 
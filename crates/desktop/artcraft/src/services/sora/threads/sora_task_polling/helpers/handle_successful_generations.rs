@@ -2,6 +2,7 @@ use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
 use crate::core::events::generation_events::common::{GenerationAction, GenerationServiceProvider};
 use crate::core::events::generation_events::generation_complete_event::GenerationCompleteEvent;
 use crate::core::events::generation_events::generation_failed_event::GenerationFailedEvent;
+use crate::core::generation_history::record_generation_outcome::{record_generation_success, GenerationOutputs};
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::state::data_dir::app_data_root::AppDataRoot;
 use crate::core::state::data_dir::trait_data_subdir::DataSubdir;
@@ -169,6 +170,14 @@ pub async fn handle_classic_successful_generations(
       }).await?;
 
       if updated {
+        record_generation_success(app_handle, GenerationOutputs {
+          task_id: &local_task.id,
+          maybe_batch_token: None,
+          maybe_media_file_token: maybe_primary_media_file_token.as_ref(),
+          maybe_cdn_url: maybe_cdn_url.as_deref(),
+          maybe_thumbnail_url_template: maybe_thumbnail_url_template.as_deref(),
+        }).await;
+
         // If anything breaks with queries, don't spam events.
         let event = GenerationCompleteEvent {
           action: Some(match generation_type {
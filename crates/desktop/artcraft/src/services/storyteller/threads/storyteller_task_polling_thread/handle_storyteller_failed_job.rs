@@ -1,5 +1,6 @@
 use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
 use crate::core::events::generation_events::generation_failed_event::GenerationFailedEvent;
+use crate::core::generation_history::record_generation_outcome::record_generation_failure;
 use crate::core::state::task_database::TaskDatabase;
 use crate::core::utils::enum_conversion::generation_provider::to_generation_service_provider;
 use crate::core::utils::enum_conversion::task_type::to_generation_action;
@@ -36,6 +37,8 @@ pub async fn handle_failed_job(
     maybe_failure_message,
   }).await?;
 
+  record_generation_failure(app_handle, &task.id, TaskStatus::CompleteFailure, maybe_failure_message).await;
+
   let service = to_generation_service_provider(task.provider);
   let action = to_generation_action(task.task_type);
 
@@ -2,6 +2,7 @@ use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
 use crate::core::events::generation_events::common::GenerationAction;
 use crate::core::events::generation_events::generation_complete_event::GenerationCompleteEvent;
 use crate::core::events::sendable_event_trait::SendableEvent;
+use crate::core::generation_history::record_generation_outcome::{record_generation_success, GenerationOutputs};
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::state::task_database::TaskDatabase;
 use crate::core::utils::enum_conversion::generation_provider::to_generation_service_provider;
@@ -32,25 +33,36 @@ pub async fn handle_successful_job(
       .as_ref()
       .map(|result| MediaFileToken::new_from_str(&result.entity_token));
 
+  let maybe_batch_token = job.maybe_result
+      .as_ref()
+      .and_then(|result| result.maybe_batch_token.as_ref());
+
+  let maybe_cdn_url = job.maybe_result
+      .as_ref()
+      .map(|result| result.media_links.cdn_url.as_str());
+
   let updated = update_successful_task_status_with_metadata(UpdateSuccessfulTaskArgs {
     db: task_database.get_connection(),
     task_id: &task.id,
-    maybe_batch_token: job.maybe_result
-        .as_ref()
-        .map(|result| result.maybe_batch_token.as_ref())
-        .flatten(),
+    maybe_batch_token,
     maybe_primary_media_file_token: maybe_primary_media_file_token.as_ref(),
     maybe_primary_media_file_class: get_media_file_class(job),
     maybe_primary_media_file_thumbnail_url_template: get_thumbnail_template(job),
-    maybe_primary_media_file_cdn_url: job.maybe_result
-        .as_ref()
-        .map(|result| result.media_links.cdn_url.as_str()),
+    maybe_primary_media_file_cdn_url: maybe_cdn_url,
   }).await?;
 
   if !updated {
     return Ok(()); // If anything breaks with queries, don't spam events.
   }
 
+  record_generation_success(app_handle, GenerationOutputs {
+    task_id: &task.id,
+    maybe_batch_token,
+    maybe_media_file_token: maybe_primary_media_file_token.as_ref(),
+    maybe_cdn_url,
+    maybe_thumbnail_url_template: get_thumbnail_template(job),
+  }).await;
+
   send_additional_success_events(app_handle, app_env_configs, creds, job, task).await;
 
   let service = to_generation_service_provider(task.provider);
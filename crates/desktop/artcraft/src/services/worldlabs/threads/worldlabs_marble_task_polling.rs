@@ -2,6 +2,7 @@ use crate::core::commands::enqueue::generate_error::{GenerateError, MissingCrede
 use crate::core::events::basic_sendable_event_trait::BasicSendableEvent;
 use crate::core::events::generation_events::common::{GenerationAction, GenerationServiceProvider};
 use crate::core::events::generation_events::generation_complete_event::GenerationCompleteEvent;
+use crate::core::generation_history::record_generation_outcome::{record_generation_success, GenerationOutputs};
 use crate::core::state::app_env_configs::app_env_configs::AppEnvConfigs;
 use crate::core::state::data_dir::app_data_root::AppDataRoot;
 use crate::core::state::data_dir::trait_data_subdir::DataSubdir;
@@ -330,6 +331,14 @@ async fn upload_spz_splat(
     return Ok(()); // If anything breaks with queries, don't spam events.
   }
 
+  record_generation_success(app_handle, GenerationOutputs {
+    task_id: &local_task.id,
+    maybe_batch_token: None,
+    maybe_media_file_token: maybe_primary_media_file_token.as_ref(),
+    maybe_cdn_url: maybe_cdn_url.as_deref(),
+    maybe_thumbnail_url_template: maybe_thumbnail_url_template.as_deref(),
+  }).await;
+
   let event = GenerationCompleteEvent {
     //media_file_token: result.media_file_token,
     action: Some(GenerationAction::GenerateGaussian),
@@ -75,6 +75,13 @@ fn do_build_cors_config(is_production: bool) -> Cors {
         actix_http::header::HeaderName::from_static("x-requested-with"), // Tabulator Ajax sends
         actix_http::header::HeaderName::from_static("session"), // Custom header sent by Three.js Storyteller Studio
       ])
+      .expose_headers(vec![
+        // Rate limit policy headers, so the frontends can back off.
+        actix_http::header::HeaderName::from_static("ratelimit-limit"),
+        actix_http::header::HeaderName::from_static("ratelimit-remaining"),
+        actix_http::header::HeaderName::from_static("ratelimit-reset"),
+        actix_http::header::RETRY_AFTER,
+      ])
       .max_age(3600)
 }
 
@@ -22,6 +22,17 @@ struct AsyncThreadKillSignalInternal {
 
 impl AsyncThreadKillSignal {
 
+  /// No TTL: the thread stays alive until `mark_thread_for_kill` is called.
+  pub fn new() -> Self {
+    Self {
+      internal: Arc::new(RwLock::new(AsyncThreadKillSignalInternal {
+        last_refreshed: Instant::now(),
+        ttl: None,
+        manually_killed: false,
+      }))
+    }
+  }
+
   pub fn new_with_ttl(ttl: Duration) -> Self {
     Self {
       internal: Arc::new(RwLock::new(AsyncThreadKillSignalInternal {
@@ -76,3 +87,9 @@ impl AsyncThreadKillSignal {
     }
   }
 }
+
+impl Default for AsyncThreadKillSignal {
+  fn default() -> Self {
+    Self::new()
+  }
+}
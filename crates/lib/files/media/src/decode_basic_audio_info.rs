@@ -139,8 +139,11 @@ fn find_audio_track(format: &Box<dyn FormatReader>) -> Option<&Track> {
   // Video files include multiple streams, and they will need to be searched.
 
   for track in format.tracks() {
+    if track.codec_params.video.is_some() {
+      continue; // NB: Video is demuxed, but not decoded.
+    }
     match track.codec_params.codec {
-      symphonia::core::codecs::CODEC_TYPE_NULL => continue, // NB: Eg. unsupported, video, etc.
+      symphonia::core::codecs::CODEC_TYPE_NULL => continue, // NB: Eg. unsupported, etc.
       _ => return Some(track),
     }
   }
@@ -23,3 +23,4 @@ pub (crate) mod decode_webm_opus_info;
 pub (crate) mod open_media_source_stream;
 
 pub mod decode_basic_audio_info;
+pub mod probe;
@@ -0,0 +1,75 @@
+use errors::{anyhow, AnyhowResult};
+
+/// Big endian reader over an in-memory slice. Every read is bounds checked, since the
+/// slices we parse come straight out of (possibly malformed) user files.
+pub struct ByteCursor<'a> {
+  bytes: &'a [u8],
+  position: usize,
+}
+
+impl<'a> ByteCursor<'a> {
+  pub fn new(bytes: &'a [u8]) -> Self {
+    Self { bytes, position: 0 }
+  }
+
+  pub fn remaining(&self) -> usize {
+    self.bytes.len() - self.position
+  }
+
+  pub fn take(&mut self, len: usize) -> AnyhowResult<&'a [u8]> {
+    if self.remaining() < len {
+      return Err(anyhow!("unexpected end of data (wanted {} bytes, have {})", len, self.remaining()));
+    }
+    let slice = &self.bytes[self.position..self.position + len];
+    self.position += len;
+    Ok(slice)
+  }
+
+  pub fn skip(&mut self, len: usize) -> AnyhowResult<()> {
+    self.take(len).map(|_| ())
+  }
+
+  pub fn u8(&mut self) -> AnyhowResult<u8> {
+    Ok(self.take(1)?[0])
+  }
+
+  pub fn u16(&mut self) -> AnyhowResult<u16> {
+    let bytes = self.take(2)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+  }
+
+  pub fn u32(&mut self) -> AnyhowResult<u32> {
+    let bytes = self.take(4)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+  }
+
+  pub fn i32(&mut self) -> AnyhowResult<i32> {
+    Ok(self.u32()? as i32)
+  }
+
+  pub fn u64(&mut self) -> AnyhowResult<u64> {
+    let high = self.u32()? as u64;
+    let low = self.u32()? as u64;
+    Ok(high << 32 | low)
+  }
+
+  pub fn fourcc(&mut self) -> AnyhowResult<[u8; 4]> {
+    let bytes = self.take(4)?;
+    Ok([bytes[0], bytes[1], bytes[2], bytes[3]])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reads_big_endian() {
+    let mut cursor = ByteCursor::new(&[0x00, 0x01, 0x00, 0x00, 0x00, 0x02, b'a', b'v', b'c', b'1']);
+    assert_eq!(cursor.u16().unwrap(), 1);
+    assert_eq!(cursor.u32().unwrap(), 2);
+    assert_eq!(&cursor.fourcc().unwrap(), b"avc1");
+    assert_eq!(cursor.remaining(), 0);
+    assert!(cursor.u8().is_err());
+  }
+}
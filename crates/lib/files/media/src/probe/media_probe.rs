@@ -0,0 +1,139 @@
+/// The container formats we can probe natively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MediaContainer {
+  Mp4,
+  Mov,
+  WebM,
+  Matroska,
+  Gif,
+
+  /// Only produced by the ffprobe fallback, which doesn't tell us the container.
+  Other,
+}
+
+impl MediaContainer {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      MediaContainer::Mp4 => "mp4",
+      MediaContainer::Mov => "mov",
+      MediaContainer::WebM => "webm",
+      MediaContainer::Matroska => "mkv",
+      MediaContainer::Gif => "gif",
+      MediaContainer::Other => "other",
+    }
+  }
+}
+
+/// Everything we know about a media file without decoding it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MediaProbe {
+  pub container: MediaContainer,
+
+  /// Duration of the whole file, if the container declares one (or we could work it out).
+  pub maybe_duration_millis: Option<u64>,
+
+  pub video_tracks: Vec<VideoTrackInfo>,
+
+  pub audio_tracks: Vec<AudioTrackInfo>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VideoTrackInfo {
+  /// Short codec name, eg. "h264", "hevc", "vp9", "av1", "gif".
+  /// Codecs we don't recognize are reported by their container identifier.
+  pub codec: String,
+
+  /// Stored (coded) width, before rotation.
+  pub width: u32,
+
+  /// Stored (coded) height, before rotation.
+  pub height: u32,
+
+  pub maybe_frame_rate: Option<f64>,
+
+  /// Clockwise rotation to apply on playback: 0, 90, 180, or 270.
+  pub rotation_degrees: u16,
+
+  pub maybe_duration_millis: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioTrackInfo {
+  /// Short codec name, eg. "aac", "opus", "mp3", "pcm".
+  pub codec: String,
+
+  pub maybe_sample_rate: Option<u32>,
+
+  pub maybe_channels: Option<u32>,
+
+  pub maybe_duration_millis: Option<u64>,
+
+  /// ISO 639-2 language code, if the track declares one.
+  pub maybe_language: Option<String>,
+}
+
+impl MediaProbe {
+  /// The track we'd call "the video": the longest one, then the largest.
+  pub fn primary_video_track(&self) -> Option<&VideoTrackInfo> {
+    self.video_tracks.iter()
+        .max_by_key(|track| (track.maybe_duration_millis.unwrap_or(0), track.width as u64 * track.height as u64))
+  }
+
+  pub fn has_video(&self) -> bool {
+    !self.video_tracks.is_empty()
+  }
+
+  pub fn has_audio(&self) -> bool {
+    !self.audio_tracks.is_empty()
+  }
+
+  /// Display dimensions (ie. after rotation) of the primary video track.
+  pub fn maybe_display_dimensions(&self) -> Option<(u32, u32)> {
+    self.primary_video_track().map(|track| track.display_dimensions())
+  }
+
+  pub fn maybe_frame_rate(&self) -> Option<f64> {
+    self.primary_video_track().and_then(|track| track.maybe_frame_rate)
+  }
+}
+
+impl VideoTrackInfo {
+  /// Width and height as the video is shown, ie. swapped for portrait rotations.
+  pub fn display_dimensions(&self) -> (u32, u32) {
+    match self.rotation_degrees {
+      90 | 270 => (self.height, self.width),
+      _ => (self.width, self.height),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn video(width: u32, height: u32, duration: u64, rotation_degrees: u16) -> VideoTrackInfo {
+    VideoTrackInfo {
+      codec: "h264".to_string(),
+      width,
+      height,
+      maybe_frame_rate: Some(30.0),
+      rotation_degrees,
+      maybe_duration_millis: Some(duration),
+    }
+  }
+
+  #[test]
+  fn primary_video_track_is_longest() {
+    let probe = MediaProbe {
+      container: MediaContainer::Mp4,
+      maybe_duration_millis: Some(2000),
+      video_tracks: vec![video(1920, 1080, 1000, 0), video(640, 480, 2000, 90)],
+      audio_tracks: vec![],
+    };
+
+    assert_eq!(probe.primary_video_track().map(|track| track.width), Some(640));
+    assert_eq!(probe.maybe_display_dimensions(), Some((480, 640)));
+    assert!(probe.has_video());
+    assert!(!probe.has_audio());
+  }
+}
@@ -0,0 +1,10 @@
+pub(crate) mod byte_cursor;
+pub(crate) mod probe_gif;
+pub(crate) mod probe_isobmff;
+pub(crate) mod probe_matroska;
+pub(crate) mod symphonia_tracks;
+
+pub mod media_probe;
+pub mod probe_media;
+#[cfg(feature = "ffprobe")]
+pub mod probe_media_or_ffprobe;
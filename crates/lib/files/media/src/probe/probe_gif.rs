@@ -0,0 +1,166 @@
+use errors::{bail, AnyhowResult};
+
+use crate::probe::byte_cursor::ByteCursor;
+use crate::probe::media_probe::{MediaContainer, MediaProbe, VideoTrackInfo};
+
+/// Browsers play frames with a delay below 2 centiseconds at 10 centiseconds.
+const MIN_FRAME_DELAY_CENTIS: u64 = 2;
+const BROWSER_FRAME_DELAY_CENTIS: u64 = 10;
+
+/// Probe a GIF by walking its blocks (without decompressing the frames).
+///
+/// Files truncated before the trailer are reported up to the last complete frame,
+/// since browsers and ffmpeg will still play them.
+pub(crate) fn probe_gif(bytes: &[u8]) -> AnyhowResult<MediaProbe> {
+  let mut cursor = ByteCursor::new(bytes);
+
+  let signature = cursor.take(6)?;
+  if signature != b"GIF87a" && signature != b"GIF89a" {
+    bail!("not a GIF");
+  }
+
+  let width = u16::from_le_bytes([cursor.u8()?, cursor.u8()?]) as u32;
+  let height = u16::from_le_bytes([cursor.u8()?, cursor.u8()?]) as u32;
+  let flags = cursor.u8()?;
+  cursor.skip(2)?; // background color, aspect ratio
+  if flags & 0x80 != 0 {
+    cursor.skip(color_table_len(flags))?;
+  }
+
+  let mut frame_count = 0u64;
+  let mut total_delay_centis = 0u64;
+  let mut pending_delay_centis = None;
+
+  // NB: Any read error past the header means truncation; keep what we've counted.
+  let _ = (|| -> AnyhowResult<()> {
+    loop {
+      match cursor.u8()? {
+        // Extension
+        0x21 => {
+          let label = cursor.u8()?;
+          if label == 0xf9 {
+            let block_len = cursor.u8()? as usize;
+            let block = cursor.take(block_len)?;
+            if block.len() >= 3 {
+              pending_delay_centis = Some(u16::from_le_bytes([block[1], block[2]]) as u64);
+            }
+          }
+          skip_sub_blocks(&mut cursor)?;
+        }
+        // Image descriptor
+        0x2c => {
+          cursor.skip(8)?; // left, top, width, height
+          let image_flags = cursor.u8()?;
+          if image_flags & 0x80 != 0 {
+            cursor.skip(color_table_len(image_flags))?;
+          }
+          cursor.skip(1)?; // LZW minimum code size
+          skip_sub_blocks(&mut cursor)?;
+
+          let delay = pending_delay_centis.take().unwrap_or(0);
+          total_delay_centis += if delay < MIN_FRAME_DELAY_CENTIS { BROWSER_FRAME_DELAY_CENTIS } else { delay };
+          frame_count += 1;
+        }
+        // Trailer
+        0x3b => return Ok(()),
+        other => bail!("unexpected GIF block: {:#x}", other),
+      }
+    }
+  })();
+
+  if frame_count == 0 {
+    bail!("GIF has no frames");
+  }
+
+  // A single frame is a still image; it has no meaningful duration or rate.
+  let (maybe_duration_millis, maybe_frame_rate) = if frame_count > 1 {
+    let duration_millis = total_delay_centis * 10;
+    (Some(duration_millis), Some(frame_count as f64 * 1000.0 / duration_millis as f64))
+  } else {
+    (None, None)
+  };
+
+  Ok(MediaProbe {
+    container: MediaContainer::Gif,
+    maybe_duration_millis,
+    video_tracks: vec![VideoTrackInfo {
+      codec: "gif".to_string(),
+      width,
+      height,
+      maybe_frame_rate,
+      rotation_degrees: 0,
+      maybe_duration_millis,
+    }],
+    audio_tracks: vec![],
+  })
+}
+
+fn color_table_len(flags: u8) -> usize {
+  3 * (1 << ((flags & 0x07) + 1))
+}
+
+fn skip_sub_blocks(cursor: &mut ByteCursor) -> AnyhowResult<()> {
+  loop {
+    let len = cursor.u8()? as usize;
+    if len == 0 {
+      return Ok(());
+    }
+    cursor.skip(len)?;
+  }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use super::*;
+
+  /// A minimal animated GIF with 1x1 frames and the given delays (in centiseconds).
+  pub(crate) fn animated_gif(width: u16, height: u16, delays: &[u16], with_trailer: bool) -> Vec<u8> {
+    let mut bytes = b"GIF89a".to_vec();
+    bytes.extend_from_slice(&width.to_le_bytes());
+    bytes.extend_from_slice(&height.to_le_bytes());
+    bytes.extend_from_slice(&[0x80, 0, 0]); // global color table of 2 entries
+    bytes.extend_from_slice(&[0, 0, 0, 255, 255, 255]);
+    for delay in delays {
+      bytes.extend_from_slice(&[0x21, 0xf9, 4, 0]);
+      bytes.extend_from_slice(&delay.to_le_bytes());
+      bytes.extend_from_slice(&[0, 0]);
+      bytes.extend_from_slice(&[0x2c, 0, 0, 0, 0, 1, 0, 1, 0, 0]);
+      bytes.extend_from_slice(&[2, 2, 0x4c, 0x01, 0]);
+    }
+    if with_trailer {
+      bytes.push(0x3b);
+    }
+    bytes
+  }
+
+  #[test]
+  fn animated() {
+    let probe = probe_gif(&animated_gif(320, 240, &[5, 5, 5, 5], true)).unwrap();
+    assert_eq!(probe.container, MediaContainer::Gif);
+    assert_eq!(probe.maybe_duration_millis, Some(200));
+    assert_eq!(probe.maybe_display_dimensions(), Some((320, 240)));
+    assert_eq!(probe.maybe_frame_rate(), Some(20.0));
+  }
+
+  #[test]
+  fn tiny_delays_play_at_browser_speed() {
+    let probe = probe_gif(&animated_gif(1, 1, &[0, 1], true)).unwrap();
+    assert_eq!(probe.maybe_duration_millis, Some(200));
+  }
+
+  #[test]
+  fn truncated() {
+    let mut bytes = animated_gif(1, 1, &[10, 10, 10], false);
+    bytes.truncate(bytes.len() - 3);
+    let probe = probe_gif(&bytes).unwrap();
+    assert_eq!(probe.maybe_duration_millis, Some(200));
+  }
+
+  #[test]
+  fn still_image() {
+    let probe = probe_gif(&animated_gif(16, 9, &[0], true)).unwrap();
+    assert_eq!(probe.maybe_duration_millis, None);
+    assert_eq!(probe.maybe_frame_rate(), None);
+    assert!(probe_gif(b"GIF89a").is_err());
+  }
+}
@@ -0,0 +1,43 @@
+use symphonia::core::formats::{FormatOptions, FormatReader};
+use symphonia::core::io::MediaSourceStream;
+use symphonia::default::formats::IsoMp4Reader;
+
+use errors::AnyhowResult;
+
+use crate::probe::media_probe::{MediaContainer, MediaProbe};
+use crate::probe::symphonia_tracks::{timestamp_millis, video_and_audio_tracks};
+
+/// Box types that can start an ISO base media (MP4/MOV) file.
+pub(crate) const LEADING_BOX_TYPES: [&[u8; 4]; 7] = [b"ftyp", b"moov", b"mdat", b"wide", b"free", b"skip", b"pnot"];
+
+/// Probe an MP4 or MOV (QuickTime) file with Symphonia's demuxer. Only the moov box is read;
+/// media data is seeked over, so the source must be seekable.
+///
+/// `header` is the start of the file, which holds the ftyp box that tells MP4 and MOV apart.
+pub(crate) fn probe_isobmff(media_source_stream: MediaSourceStream, header: &[u8]) -> AnyhowResult<MediaProbe> {
+  let reader = IsoMp4Reader::try_new(media_source_stream, &FormatOptions::default())?;
+  let (video_tracks, audio_tracks) = video_and_audio_tracks(reader.tracks());
+
+  // NB: Fragmented files often leave the movie duration empty.
+  let maybe_duration_millis = reader.movie_duration()
+      .and_then(|(duration, time_base)| timestamp_millis(duration, time_base))
+      .or_else(|| {
+        video_tracks.iter().filter_map(|track| track.maybe_duration_millis)
+            .chain(audio_tracks.iter().filter_map(|track| track.maybe_duration_millis))
+            .max()
+      });
+
+  Ok(MediaProbe {
+    container: container_from_header(header),
+    maybe_duration_millis,
+    video_tracks,
+    audio_tracks,
+  })
+}
+
+fn container_from_header(header: &[u8]) -> MediaContainer {
+  match header.get(4..12) {
+    Some(b"ftypqt  ") => MediaContainer::Mov,
+    _ => MediaContainer::Mp4,
+  }
+}
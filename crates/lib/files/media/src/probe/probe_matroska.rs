@@ -0,0 +1,44 @@
+use symphonia::core::formats::{FormatOptions, FormatReader};
+use symphonia::core::io::MediaSourceStream;
+use symphonia::default::formats::MkvReader;
+
+use errors::AnyhowResult;
+
+use crate::probe::media_probe::{MediaContainer, MediaProbe};
+use crate::probe::symphonia_tracks::video_and_audio_tracks;
+
+pub(crate) const EBML_MAGIC: [u8; 4] = [0x1a, 0x45, 0xdf, 0xa3];
+
+/// Probe a Matroska or WebM file with Symphonia's demuxer, which reads the EBML header,
+/// segment Info, and Tracks, then stops at the first cluster.
+///
+/// The duration is left empty if the segment doesn't declare one, which is typical of
+/// browser (MediaRecorder) recordings. Working it out requires reading every cluster.
+pub(crate) fn probe_matroska(media_source_stream: MediaSourceStream) -> AnyhowResult<MediaProbe> {
+  let reader = MkvReader::try_new(media_source_stream, &FormatOptions::default())?;
+
+  let container = match reader.doc_type() {
+    "webm" => MediaContainer::WebM,
+    _ => MediaContainer::Matroska,
+  };
+
+  let (video_tracks, mut audio_tracks) = video_and_audio_tracks(reader.tracks());
+
+  for track in audio_tracks.iter_mut() {
+    if track.maybe_language.is_none() {
+      track.maybe_language = Some("eng".to_string()); // NB: Matroska's default
+    }
+  }
+
+  // NB: Every track carries the segment's duration.
+  let maybe_duration_millis = video_tracks.iter().filter_map(|track| track.maybe_duration_millis)
+      .chain(audio_tracks.iter().filter_map(|track| track.maybe_duration_millis))
+      .max();
+
+  Ok(MediaProbe {
+    container,
+    maybe_duration_millis,
+    video_tracks,
+    audio_tracks,
+  })
+}
@@ -0,0 +1,214 @@
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use log::warn;
+use symphonia::core::io::MediaSourceStream;
+
+use errors::{bail, AnyhowResult};
+
+use crate::decode_webm_opus_info::decode_mkv_or_webm;
+use crate::open_media_source_stream::{open_bytes_media_source_stream, open_file_media_source_stream};
+use crate::probe::media_probe::{MediaContainer, MediaProbe};
+use crate::probe::probe_gif::probe_gif;
+use crate::probe::probe_isobmff::{probe_isobmff, LEADING_BOX_TYPES};
+use crate::probe::probe_matroska::{probe_matroska, EBML_MAGIC};
+
+/// GIFs are read whole; anything larger isn't a GIF we'd accept anyway.
+const MAX_GIF_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Enough to sniff the format and read an MP4's major brand.
+const HEADER_LEN: usize = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SniffedFormat {
+  IsoBmff,
+  Matroska,
+  Gif,
+}
+
+/// Probe a video (or animated GIF) file on disk for its container, duration, and tracks.
+///
+/// Only the headers are read: MP4/MOV skip over the media data, and WebM/MKV stop at the first
+/// cluster (unless the file doesn't declare a duration, in which case the clusters are scanned).
+pub fn probe_media_file<P: AsRef<Path>>(path: P) -> AnyhowResult<MediaProbe> {
+  let mut file = File::open(&path)?;
+  let header = read_header(&mut file)?;
+
+  let mut probe = match sniff_format(&header)? {
+    SniffedFormat::IsoBmff => probe_isobmff(seekable_media_source_stream(file), &header)?,
+    SniffedFormat::Matroska => probe_matroska(seekable_media_source_stream(file))?,
+    SniffedFormat::Gif => {
+      if file.metadata()?.len() > MAX_GIF_BYTES {
+        bail!("GIF is too large to probe");
+      }
+      let mut bytes = Vec::new();
+      file.read_to_end(&mut bytes)?;
+      probe_gif(&bytes)?
+    }
+  };
+
+  if needs_matroska_duration(&probe) {
+    probe.maybe_duration_millis = matroska_duration_by_scanning(open_file_media_source_stream(&path));
+  }
+
+  Ok(probe)
+}
+
+/// Probe an in-memory video (or animated GIF) for its container, duration, and tracks.
+pub fn probe_media_bytes(bytes: &[u8]) -> AnyhowResult<MediaProbe> {
+  let header = &bytes[..bytes.len().min(HEADER_LEN)];
+
+  let mut probe = match sniff_format(header)? {
+    SniffedFormat::IsoBmff => probe_isobmff(seekable_media_source_stream(Cursor::new(bytes.to_vec())), header)?,
+    SniffedFormat::Matroska => probe_matroska(seekable_media_source_stream(Cursor::new(bytes.to_vec())))?,
+    SniffedFormat::Gif => probe_gif(bytes)?,
+  };
+
+  if needs_matroska_duration(&probe) {
+    probe.maybe_duration_millis = matroska_duration_by_scanning(open_bytes_media_source_stream(bytes));
+  }
+
+  Ok(probe)
+}
+
+/// NB: Unlike `open_file_media_source_stream`, the demuxers can seek this (eg. past an MP4's
+/// media data to a trailing moov box).
+fn seekable_media_source_stream<S>(source: S) -> MediaSourceStream
+  where S: symphonia::core::io::MediaSource + 'static
+{
+  MediaSourceStream::new(Box::new(source), Default::default())
+}
+
+fn read_header<R: Read + Seek>(reader: &mut R) -> AnyhowResult<Vec<u8>> {
+  let mut header = Vec::with_capacity(HEADER_LEN);
+  reader.take(HEADER_LEN as u64).read_to_end(&mut header)?;
+  reader.seek(SeekFrom::Start(0))?;
+  Ok(header)
+}
+
+fn sniff_format(header: &[u8]) -> AnyhowResult<SniffedFormat> {
+  if header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a") {
+    return Ok(SniffedFormat::Gif);
+  }
+  if header.starts_with(&EBML_MAGIC) {
+    return Ok(SniffedFormat::Matroska);
+  }
+  if header.len() >= 8 && LEADING_BOX_TYPES.iter().any(|box_type| header[4..8] == box_type[..]) {
+    return Ok(SniffedFormat::IsoBmff);
+  }
+
+  bail!("unrecognized media format")
+}
+
+fn needs_matroska_duration(probe: &MediaProbe) -> bool {
+  probe.maybe_duration_millis.is_none()
+      && matches!(probe.container, MediaContainer::WebM | MediaContainer::Matroska)
+}
+
+/// Browser (MediaRecorder) recordings don't declare a duration, so find the last timestamp.
+fn matroska_duration_by_scanning(media_source_stream: AnyhowResult<MediaSourceStream>) -> Option<u64> {
+  match media_source_stream.and_then(decode_mkv_or_webm) {
+    Ok(info) => info.duration_millis,
+    Err(err) => {
+      warn!("Could not scan matroska file for its duration: {:?}", err);
+      None
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use errors::AnyhowResult;
+
+  use crate::probe::media_probe::MediaContainer;
+  use crate::probe::probe_gif::tests::animated_gif;
+  use crate::probe::probe_media::{probe_media_bytes, probe_media_file};
+
+  fn test_file(path_from_repo_root: &str) -> PathBuf {
+    // https://doc.rust-lang.org/cargo/reference/environment-variables.html
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(format!("../../../../{}", path_from_repo_root));
+    path
+  }
+
+  #[test]
+  fn mp4_h264_video_aac_audio() -> AnyhowResult<()> {
+    let probe = probe_media_file(test_file("test_data/video/mp4/golden_sun_garoh.mp4"))?;
+    assert_eq!(probe.container, MediaContainer::Mp4);
+    assert_eq!(probe.maybe_duration_millis, Some(15168));
+    assert_eq!(probe.maybe_display_dimensions(), Some((640, 480)));
+    assert_eq!(probe.maybe_frame_rate().map(|fps| fps.round()), Some(30.0));
+    assert_eq!(probe.primary_video_track().map(|track| track.codec.as_str()), Some("h264"));
+    assert_eq!(probe.audio_tracks.len(), 1);
+    assert_eq!(probe.audio_tracks[0].codec, "aac");
+    assert_eq!(probe.audio_tracks[0].maybe_sample_rate, Some(16000));
+    assert_eq!(probe.audio_tracks[0].maybe_channels, Some(2));
+    Ok(())
+  }
+
+  #[test]
+  fn mov_h264_video_aac_audio() -> AnyhowResult<()> {
+    let probe = probe_media_file(test_file("test_data/video/mov/majoras_mask_intro.mov"))?;
+    assert_eq!(probe.container, MediaContainer::Mov);
+    assert_eq!(probe.maybe_duration_millis, Some(30030));
+    assert_eq!(probe.maybe_display_dimensions(), Some((320, 240)));
+    assert_eq!(probe.maybe_frame_rate().map(|fps| (fps * 100.0).round()), Some(2997.0));
+    assert_eq!(probe.primary_video_track().map(|track| track.codec.as_str()), Some("h264"));
+    assert_eq!(probe.audio_tracks[0].codec, "aac");
+    Ok(())
+  }
+
+  #[test]
+  fn mkv_h264_video_opus_audio() -> AnyhowResult<()> {
+    let probe = probe_media_file(test_file("test_data/video/mkv/fake_you.mkv"))?;
+    assert_eq!(probe.container, MediaContainer::Matroska);
+    assert_eq!(probe.maybe_duration_millis, Some(15008));
+    assert_eq!(probe.maybe_display_dimensions(), Some((320, 240)));
+    assert_eq!(probe.maybe_frame_rate(), Some(25.0));
+    assert_eq!(probe.primary_video_track().map(|track| track.codec.as_str()), Some("h264"));
+    assert_eq!(probe.audio_tracks[0].codec, "opus");
+    assert_eq!(probe.audio_tracks[0].maybe_language.as_deref(), Some("spa"));
+    Ok(())
+  }
+
+  #[test]
+  fn webm_vp9_video_opus_audio() -> AnyhowResult<()> {
+    let bytes = std::fs::read(test_file("test_data/video/webm/laser_pong.webm"))?;
+    let probe = probe_media_bytes(&bytes)?;
+    assert_eq!(probe.container, MediaContainer::WebM);
+    assert_eq!(probe.maybe_duration_millis, Some(10017));
+    assert_eq!(probe.maybe_display_dimensions(), Some((320, 240)));
+    assert_eq!(probe.primary_video_track().map(|track| track.codec.as_str()), Some("vp9"));
+    assert_eq!(probe.audio_tracks[0].codec, "opus");
+    Ok(())
+  }
+
+  #[test]
+  fn browser_recording_without_declared_duration() -> AnyhowResult<()> {
+    let probe = probe_media_file(test_file("test_data/browser_api_recording/chromium_web_audio_upload.bin"))?;
+    assert_eq!(probe.container, MediaContainer::WebM);
+    assert!(!probe.has_video());
+    assert_eq!(probe.audio_tracks[0].codec, "opus");
+    assert_eq!(probe.audio_tracks[0].maybe_sample_rate, Some(48000));
+    assert_eq!(probe.maybe_duration_millis, Some(9119)); // NB: Scanned from the clusters.
+    Ok(())
+  }
+
+  #[test]
+  fn gif() -> AnyhowResult<()> {
+    let probe = probe_media_bytes(&animated_gif(64, 48, &[4, 4, 4], true))?;
+    assert_eq!(probe.container, MediaContainer::Gif);
+    assert_eq!(probe.maybe_duration_millis, Some(120));
+    assert_eq!(probe.maybe_display_dimensions(), Some((64, 48)));
+    Ok(())
+  }
+
+  #[test]
+  fn unrecognized() {
+    assert!(probe_media_bytes(b"").is_err());
+    assert!(probe_media_bytes(b"RIFF\0\0\0\0WAVEfmt ").is_err());
+  }
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use log::warn;
+
+use errors::AnyhowResult;
+use ffmpeg_utils::ffprobe::ffprobe_get_info::ffprobe_get_info;
+
+use crate::probe::media_probe::{MediaContainer, MediaProbe, VideoTrackInfo};
+use crate::probe::probe_media::probe_media_file;
+
+/// Probe natively, falling back to ffprobe for containers we can't parse ourselves.
+///
+/// The ffprobe result is much thinner: no container, codecs, rotation, or audio tracks.
+pub fn probe_media_file_or_ffprobe<P: AsRef<Path>>(path: P) -> AnyhowResult<MediaProbe> {
+  let native_err = match probe_media_file(&path) {
+    Ok(probe) => return Ok(probe),
+    Err(err) => err,
+  };
+
+  warn!("Native media probe failed; falling back to ffprobe: {:?}", native_err);
+
+  let info = ffprobe_get_info(&path)?;
+  let maybe_duration_millis = info.duration.map(|duration| duration.millis as u64);
+  let maybe_frame_rate = info.frame_rate.map(|frame_rate| frame_rate.fps as f64);
+
+  let video_tracks = info.dimensions
+      .map(|dimensions| VideoTrackInfo {
+        codec: "unknown".to_string(),
+        width: dimensions.width as u32,
+        height: dimensions.height as u32,
+        maybe_frame_rate,
+        rotation_degrees: 0,
+        maybe_duration_millis,
+      })
+      .into_iter()
+      .collect();
+
+  Ok(MediaProbe {
+    container: MediaContainer::Other,
+    maybe_duration_millis,
+    video_tracks,
+    audio_tracks: vec![],
+  })
+}
@@ -0,0 +1,92 @@
+use std::convert::TryFrom;
+
+use symphonia::core::audio::Layout;
+use symphonia::core::codecs::{CodecParameters, CodecType, CODEC_TYPE_AV1, CODEC_TYPE_EAC3, CODEC_TYPE_H264, CODEC_TYPE_HEVC, CODEC_TYPE_MJPEG, CODEC_TYPE_MP2, CODEC_TYPE_MPEG4_VISUAL, CODEC_TYPE_NULL, CODEC_TYPE_PRORES, CODEC_TYPE_THEORA, CODEC_TYPE_VP8, CODEC_TYPE_VP9};
+use symphonia::core::formats::Track;
+use symphonia::core::units::TimeBase;
+
+use crate::probe::media_probe::{AudioTrackInfo, VideoTrackInfo};
+
+/// Split the tracks of a Symphonia demuxer into video and audio tracks.
+///
+/// NB: Symphonia only fills in video parameters for video tracks and a sample rate for audio
+/// tracks, so anything else (subtitles, timecodes, etc.) is skipped.
+pub(crate) fn video_and_audio_tracks(tracks: &[Track]) -> (Vec<VideoTrackInfo>, Vec<AudioTrackInfo>) {
+  let mut video_tracks = Vec::new();
+  let mut audio_tracks = Vec::new();
+
+  for track in tracks {
+    let params = &track.codec_params;
+
+    if let Some(video) = params.video.as_ref() {
+      video_tracks.push(VideoTrackInfo {
+        codec: codec_name(params.codec),
+        width: video.width,
+        height: video.height,
+        maybe_frame_rate: video.frame_rate,
+        rotation_degrees: video.rotation,
+        maybe_duration_millis: duration_millis(params),
+      });
+    } else if let Some(sample_rate) = params.sample_rate {
+      audio_tracks.push(AudioTrackInfo {
+        codec: codec_name(params.codec),
+        maybe_sample_rate: Some(sample_rate),
+        maybe_channels: channel_count(params),
+        maybe_duration_millis: duration_millis(params),
+        maybe_language: track.language.clone().filter(|lang| lang != "und"),
+      });
+    }
+  }
+
+  (video_tracks, audio_tracks)
+}
+
+fn duration_millis(params: &CodecParameters) -> Option<u64> {
+  params.n_frames.zip(params.time_base)
+      .and_then(|(n_frames, time_base)| timestamp_millis(n_frames, time_base))
+}
+
+/// Convert a (non-zero, known) duration in `time_base` units to milliseconds.
+pub(crate) fn timestamp_millis(timestamp: u64, time_base: TimeBase) -> Option<u64> {
+  if timestamp == 0 || timestamp == u64::MAX {
+    return None;
+  }
+  let millis = timestamp as u128 * time_base.numer as u128 * 1000 / time_base.denom as u128;
+  u64::try_from(millis).ok()
+}
+
+fn channel_count(params: &CodecParameters) -> Option<u32> {
+  if let Some(channels) = params.channels {
+    return Some(channels.count() as u32);
+  }
+  params.channel_layout.map(|layout| match layout {
+    Layout::Mono => 1,
+    Layout::Stereo => 2,
+    Layout::TwoPointOne => 3,
+    Layout::FivePointOne => 6,
+  })
+}
+
+fn codec_name(codec: CodecType) -> String {
+  let name = match codec {
+    CODEC_TYPE_H264 => "h264",
+    CODEC_TYPE_HEVC => "hevc",
+    CODEC_TYPE_VP8 => "vp8",
+    CODEC_TYPE_VP9 => "vp9",
+    CODEC_TYPE_AV1 => "av1",
+    CODEC_TYPE_MPEG4_VISUAL => "mpeg4",
+    CODEC_TYPE_MJPEG => "mjpeg",
+    CODEC_TYPE_PRORES => "prores",
+    CODEC_TYPE_THEORA => "theora",
+    // NB: Demuxed, but there's no decoder registered for these.
+    CODEC_TYPE_MP2 => "mp2",
+    CODEC_TYPE_EAC3 => "eac3",
+    CODEC_TYPE_NULL => "unknown",
+    _ => match symphonia::default::get_codecs().get_codec(codec) {
+      Some(descriptor) if descriptor.short_name.starts_with("pcm_") => "pcm",
+      Some(descriptor) => descriptor.short_name,
+      None => "unknown",
+    },
+  };
+  name.to_string()
+}
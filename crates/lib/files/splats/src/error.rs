@@ -0,0 +1,84 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum SplatError {
+  /// The gzip stream of an SPZ file could not be decoded.
+  InvalidCompression(std::io::Error),
+
+  /// The file ended before all of the declared points were read.
+  Truncated { expected_bytes: u64, actual_bytes: u64 },
+
+  /// The file has more data than the header declares.
+  TrailingData,
+
+  /// The SPZ header has the wrong magic number.
+  InvalidMagic(u32),
+
+  /// The SPZ version is not one we know how to read.
+  UnsupportedVersion(u32),
+
+  /// Spherical harmonics degree outside of 0-3.
+  InvalidShDegree(u8),
+
+  /// Fixed point positions with too many fractional bits to fit in 24 bits.
+  InvalidFractionalBits(u8),
+
+  /// The file declares more points than we accept.
+  TooManyPoints(u64),
+
+  /// The file declares zero points.
+  NoPoints,
+
+  /// The PLY file is not a valid gaussian splat.
+  InvalidPly(String),
+
+  /// The PLY file is valid, but uses a layout we don't support (eg. ASCII encoding).
+  UnsupportedPly(String),
+
+  IoError(std::io::Error),
+  ImageError(images::image::ImageError),
+}
+
+impl SplatError {
+  /// Whether the error is the fault of the file (as opposed to our own failure).
+  pub fn is_invalid_input(&self) -> bool {
+    !matches!(self, SplatError::IoError(_) | SplatError::ImageError(_))
+  }
+}
+
+impl Error for SplatError {}
+
+impl Display for SplatError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SplatError::InvalidCompression(err) => write!(f, "SPZ file is not a valid gzip stream: {}", err),
+      SplatError::Truncated { expected_bytes, actual_bytes } => {
+        write!(f, "splat file is truncated (expected {} bytes, got {})", expected_bytes, actual_bytes)
+      }
+      SplatError::TrailingData => write!(f, "splat file has unexpected trailing data"),
+      SplatError::InvalidMagic(magic) => write!(f, "SPZ file has invalid magic number: {:#010x}", magic),
+      SplatError::UnsupportedVersion(version) => write!(f, "unsupported SPZ version: {}", version),
+      SplatError::InvalidShDegree(degree) => write!(f, "invalid spherical harmonics degree: {}", degree),
+      SplatError::InvalidFractionalBits(bits) => write!(f, "invalid SPZ fractional bits: {}", bits),
+      SplatError::TooManyPoints(count) => write!(f, "splat has too many points: {}", count),
+      SplatError::NoPoints => write!(f, "splat has no points"),
+      SplatError::InvalidPly(reason) => write!(f, "invalid PLY splat: {}", reason),
+      SplatError::UnsupportedPly(reason) => write!(f, "unsupported PLY splat: {}", reason),
+      SplatError::IoError(err) => write!(f, "IO Error: {}", err),
+      SplatError::ImageError(err) => write!(f, "Image Error: {}", err),
+    }
+  }
+}
+
+impl From<std::io::Error> for SplatError {
+  fn from(err: std::io::Error) -> Self {
+    SplatError::IoError(err)
+  }
+}
+
+impl From<images::image::ImageError> for SplatError {
+  fn from(err: images::image::ImageError) -> Self {
+    SplatError::ImageError(err)
+  }
+}
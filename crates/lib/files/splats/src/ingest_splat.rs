@@ -0,0 +1,84 @@
+use crate::error::SplatError;
+use crate::ply::convert_ply_to_spz::convert_ply_to_spz;
+use crate::preview::density_preview::{DEFAULT_PREVIEW_SIZE, render_density_preview_png};
+use crate::splat_format::SplatFormat;
+use crate::splat_metadata::SplatMetadata;
+use crate::spz::spz_file::SpzFile;
+
+pub const SPZ_MIME_TYPE: &str = "application/gzip";
+pub const PREVIEW_MIME_TYPE: &str = "image/png";
+
+/// A splat that's been validated and is ready to store.
+pub struct IngestedSplat {
+  /// What we were handed.
+  pub source_format: SplatFormat,
+
+  /// The file to store. For SPZ input this is the original file, untouched.
+  pub spz_bytes: Vec<u8>,
+
+  pub metadata: SplatMetadata,
+
+  /// A top-down point density PNG, usable as a cover image.
+  pub preview_png_bytes: Vec<u8>,
+}
+
+/// Validate an uploaded or downloaded splat, convert it to SPZ if needed, and build its preview.
+///
+/// This is CPU bound (decompression, conversion, and a pass over every point), so async callers
+/// should run it on a blocking thread.
+pub fn ingest_splat(bytes: Vec<u8>, source_format: SplatFormat) -> Result<IngestedSplat, SplatError> {
+  let (spz, spz_bytes) = match source_format {
+    SplatFormat::Spz => {
+      let spz = SpzFile::decode(&bytes)?;
+      (spz, bytes)
+    }
+    SplatFormat::Ply => {
+      let spz = convert_ply_to_spz(&bytes)?;
+      let spz_bytes = spz.encode()?;
+      (spz, spz_bytes)
+    }
+  };
+
+  let preview_png_bytes = render_density_preview_png(&spz, DEFAULT_PREVIEW_SIZE)?;
+
+  Ok(IngestedSplat {
+    source_format,
+    spz_bytes,
+    metadata: spz.metadata(),
+    preview_png_bytes,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ply::convert_ply_to_spz::tests::ply_with_positions;
+  use crate::spz::spz_file::tests::spz_with_positions;
+
+  #[test]
+  fn ply_is_converted() {
+    let ply = ply_with_positions(&[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]]);
+    let ingested = ingest_splat(ply, SplatFormat::Ply).expect("should ingest");
+
+    assert_eq!(SplatFormat::from_bytes(&ingested.spz_bytes), Some(SplatFormat::Spz));
+    assert_eq!(ingested.metadata.point_count, 2);
+    assert_eq!(ingested.metadata.sh_degree, 1);
+    assert_eq!(ingested.metadata.maybe_bounding_box.unwrap().max, [1.0, 2.0, 3.0]);
+    assert!(ingested.preview_png_bytes.starts_with(b"\x89PNG"));
+  }
+
+  #[test]
+  fn spz_is_kept_as_is() {
+    let spz_bytes = spz_with_positions(&[[0.0, 0.0, 0.0]]).encode().unwrap();
+    let ingested = ingest_splat(spz_bytes.clone(), SplatFormat::Spz).expect("should ingest");
+    assert_eq!(ingested.spz_bytes, spz_bytes);
+    assert_eq!(ingested.metadata.point_count, 1);
+  }
+
+  #[test]
+  fn wrong_format_is_rejected() {
+    let spz_bytes = spz_with_positions(&[[0.0, 0.0, 0.0]]).encode().unwrap();
+    let result = ingest_splat(spz_bytes, SplatFormat::Ply);
+    assert!(result.err().is_some_and(|err| err.is_invalid_input()));
+  }
+}
@@ -0,0 +1,31 @@
+//! splats
+//!
+//! Parsing, validation, and conversion of Gaussian splat files. We store splats as SPZ
+//! (gzip-compressed, quantized gaussians), but users also bring the much larger PLY files that
+//! most training tools emit, so those get converted on the way in.
+//!
+//! Nothing here renders; the preview is a top-down point density image.
+//!
+
+// Never allow these
+#![forbid(private_bounds)]
+#![forbid(private_interfaces)]
+#![forbid(unused_must_use)] // NB: It's unsafe to not close/check some things
+
+// Okay to toggle
+#![forbid(unreachable_patterns)]
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+// Always allow
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+pub mod error;
+pub mod ingest_splat;
+pub mod ply;
+pub mod preview;
+pub mod splat_format;
+pub mod splat_metadata;
+pub mod spz;
@@ -0,0 +1,313 @@
+use crate::error::SplatError;
+use crate::ply::ply_header::{PlyProperty, PlyVertexLayout};
+use crate::spz::spz_file::SpzFile;
+use crate::spz::spz_header::{MAX_SPZ_POINTS, SpzHeader, sh_coefficients_for_degree};
+
+/// The SPZ version we write. Version 2 is the most widely supported by viewers.
+const OUTPUT_SPZ_VERSION: u32 = 2;
+
+/// The reference encoder's precision (1/4096 units). We only drop below this for huge scenes.
+const DEFAULT_FRACTIONAL_BITS: u8 = 12;
+
+const COLOR_SCALE: f32 = 0.15;
+
+/// Quantization for the first order harmonics and for everything above it.
+const SH1_BITS: u32 = 5;
+const SH_REST_BITS: u32 = 4;
+
+/// Convert a binary gaussian splat PLY (the layout written by the original 3DGS trainer) to SPZ.
+pub fn convert_ply_to_spz(ply_bytes: &[u8]) -> Result<SpzFile, SplatError> {
+  let layout = PlyVertexLayout::parse(ply_bytes)?;
+
+  if layout.vertex_count == 0 {
+    return Err(SplatError::NoPoints);
+  }
+  if layout.vertex_count > MAX_SPZ_POINTS as usize {
+    return Err(SplatError::TooManyPoints(layout.vertex_count as u64));
+  }
+
+  let body_len = layout.vertex_count * layout.stride;
+  let available = ply_bytes.len() - layout.body_offset;
+  if available < body_len {
+    return Err(SplatError::Truncated {
+      expected_bytes: (layout.body_offset + body_len) as u64,
+      actual_bytes: ply_bytes.len() as u64,
+    });
+  }
+
+  let properties = GaussianProperties::from_layout(&layout)?;
+  let records = || ply_bytes[layout.body_offset..layout.body_offset + body_len].chunks_exact(layout.stride);
+
+  let mut max_abs_position = 0.0f32;
+  for record in records() {
+    for property in &properties.position {
+      let value = property.read(record);
+      if !value.is_finite() {
+        return Err(SplatError::InvalidPly("non-finite gaussian position".to_string()));
+      }
+      max_abs_position = max_abs_position.max(value.abs());
+    }
+  }
+
+  let header = SpzHeader {
+    version: OUTPUT_SPZ_VERSION,
+    num_points: layout.vertex_count as u32,
+    sh_degree: properties.sh_degree,
+    fractional_bits: fractional_bits_for_extent(max_abs_position),
+    flags: 0,
+  };
+
+  let num_points = layout.vertex_count;
+  let sh_coefficients = sh_coefficients_for_degree(header.sh_degree);
+
+  let mut positions = Vec::with_capacity(num_points * 9);
+  let mut alphas = Vec::with_capacity(num_points);
+  let mut colors = Vec::with_capacity(num_points * 3);
+  let mut scales = Vec::with_capacity(num_points * 3);
+  let mut rotations = Vec::with_capacity(num_points * 3);
+  let mut sh = Vec::with_capacity(num_points * sh_coefficients * 3);
+
+  let position_scale = (1u32 << header.fractional_bits) as f32;
+
+  for record in records() {
+    for property in &properties.position {
+      positions.extend_from_slice(&encode_fixed24(property.read(record) * position_scale));
+    }
+
+    alphas.push(to_u8(sigmoid(properties.opacity.read(record)) * 255.0));
+
+    for property in &properties.color {
+      colors.push(to_u8(property.read(record) * (COLOR_SCALE * 255.0) + 0.5 * 255.0));
+    }
+
+    for property in &properties.scale {
+      scales.push(to_u8((property.read(record) + 10.0) * 16.0));
+    }
+
+    // NB: PLY stores rotations as (w, x, y, z).
+    let [w, x, y, z] = properties.rotation.each_ref().map(|property| property.read(record));
+    rotations.extend_from_slice(&encode_rotation([x, y, z, w]));
+
+    // NB: PLY stores harmonics channel-major (all red coefficients, then green, then blue),
+    // while SPZ interleaves the channels for each coefficient.
+    for coefficient in 0..sh_coefficients {
+      let bits = if coefficient < 3 { SH1_BITS } else { SH_REST_BITS };
+      for channel in 0..3 {
+        let value = properties.sh_rest[channel * sh_coefficients + coefficient].read(record);
+        sh.push(quantize_sh(value, bits));
+      }
+    }
+  }
+
+  let mut payload = positions;
+  payload.extend_from_slice(&alphas);
+  payload.extend_from_slice(&colors);
+  payload.extend_from_slice(&scales);
+  payload.extend_from_slice(&rotations);
+  payload.extend_from_slice(&sh);
+
+  SpzFile::from_parts(header, payload)
+}
+
+struct GaussianProperties<'a> {
+  position: [&'a PlyProperty; 3],
+  opacity: &'a PlyProperty,
+  color: [&'a PlyProperty; 3],
+  scale: [&'a PlyProperty; 3],
+  rotation: [&'a PlyProperty; 4],
+  sh_rest: Vec<&'a PlyProperty>,
+  sh_degree: u8,
+}
+
+impl<'a> GaussianProperties<'a> {
+  fn from_layout(layout: &'a PlyVertexLayout) -> Result<Self, SplatError> {
+    let sh_rest_count = layout.properties.iter()
+        .filter(|property| property.name.starts_with("f_rest_"))
+        .count();
+
+    let sh_degree = match sh_rest_count {
+      0 => 0,
+      9 => 1,
+      24 => 2,
+      45 => 3,
+      count => return Err(SplatError::InvalidPly(format!("unexpected number of f_rest properties: {}", count))),
+    };
+
+    let sh_rest = (0..sh_rest_count)
+        .map(|i| layout.require_property(&format!("f_rest_{}", i)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Self {
+      position: [
+        layout.require_property("x")?,
+        layout.require_property("y")?,
+        layout.require_property("z")?,
+      ],
+      opacity: layout.require_property("opacity")?,
+      color: [
+        layout.require_property("f_dc_0")?,
+        layout.require_property("f_dc_1")?,
+        layout.require_property("f_dc_2")?,
+      ],
+      scale: [
+        layout.require_property("scale_0")?,
+        layout.require_property("scale_1")?,
+        layout.require_property("scale_2")?,
+      ],
+      rotation: [
+        layout.require_property("rot_0")?,
+        layout.require_property("rot_1")?,
+        layout.require_property("rot_2")?,
+        layout.require_property("rot_3")?,
+      ],
+      sh_rest,
+      sh_degree,
+    })
+  }
+}
+
+/// The most precision that still fits the largest coordinate in a signed 24-bit integer.
+fn fractional_bits_for_extent(max_abs_position: f32) -> u8 {
+  let limit = (1u32 << 23) as f32;
+  let mut bits = DEFAULT_FRACTIONAL_BITS;
+  while bits > 0 && max_abs_position * (1u32 << bits) as f32 >= limit {
+    bits -= 1;
+  }
+  bits
+}
+
+fn encode_fixed24(scaled: f32) -> [u8; 3] {
+  let max = ((1 << 23) - 1) as f32;
+  let fixed = scaled.round().clamp(-max - 1.0, max) as i32;
+  let bytes = fixed.to_le_bytes();
+  [bytes[0], bytes[1], bytes[2]]
+}
+
+/// Quaternion (x, y, z, w) to SPZ v2's three component form; w is implied by normalization.
+fn encode_rotation(quaternion: [f32; 4]) -> [u8; 3] {
+  let norm = quaternion.iter().map(|c| c * c).sum::<f32>().sqrt();
+  let [mut x, mut y, mut z, w] = if norm > 0.0 && norm.is_finite() {
+    quaternion.map(|c| c / norm)
+  } else {
+    [0.0, 0.0, 0.0, 1.0]
+  };
+  if w < 0.0 {
+    x = -x;
+    y = -y;
+    z = -z;
+  }
+  [x, y, z].map(|c| to_u8(c * 127.5 + 127.5))
+}
+
+fn quantize_sh(value: f32, bits: u32) -> u8 {
+  let bucket_size = 1i32 << (8 - bits);
+  let quantized = (value * 128.0).round() as i32 + 128;
+  let bucketed = (quantized + bucket_size / 2) / bucket_size * bucket_size;
+  bucketed.clamp(0, 255) as u8
+}
+
+fn sigmoid(value: f32) -> f32 {
+  1.0 / (1.0 + (-value).exp())
+}
+
+fn to_u8(value: f32) -> u8 {
+  value.round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use super::*;
+
+  /// A degree 1 gaussian splat PLY, one gaussian per position.
+  pub(crate) fn ply_with_positions(positions: &[[f32; 3]]) -> Vec<u8> {
+    let mut properties = vec!["x", "y", "z", "nx", "ny", "nz", "f_dc_0", "f_dc_1", "f_dc_2"]
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    properties.extend((0..9).map(|i| format!("f_rest_{}", i)));
+    properties.extend(["opacity", "scale_0", "scale_1", "scale_2", "rot_0", "rot_1", "rot_2", "rot_3"]
+        .into_iter()
+        .map(|name| name.to_string()));
+
+    let mut bytes = format!("ply\nformat binary_little_endian 1.0\nelement vertex {}\n", positions.len())
+        .into_bytes();
+    for property in &properties {
+      bytes.extend_from_slice(format!("property float {}\n", property).as_bytes());
+    }
+    bytes.extend_from_slice(b"end_header\n");
+
+    for position in positions {
+      let mut values = position.to_vec();
+      values.extend([0.0; 3]); // normals
+      values.extend([1.0, 0.0, -1.0]); // f_dc
+      values.extend([0.1; 9]); // f_rest
+      values.push(10.0); // opacity (logit)
+      values.extend([-4.0; 3]); // log scales
+      values.extend([-1.0, 0.0, 0.0, 0.0]); // rotation (w, x, y, z)
+      for value in values {
+        bytes.extend_from_slice(&value.to_le_bytes());
+      }
+    }
+    bytes
+  }
+
+  #[test]
+  fn converts_gaussians() {
+    let ply = ply_with_positions(&[[0.5, 1.0, -2.0], [3.0, -4.0, 5.0]]);
+    let spz = convert_ply_to_spz(&ply).expect("should convert");
+
+    assert_eq!(spz.num_points(), 2);
+    assert_eq!(spz.header().version, 2);
+    assert_eq!(spz.header().sh_degree, 1);
+    assert_eq!(spz.header().fractional_bits, 12);
+    assert_eq!(spz.position(0), [0.5, 1.0, -2.0]);
+    assert_eq!(spz.position(1), [3.0, -4.0, 5.0]);
+    assert!(spz.opacity(0) > 0.99);
+
+    let color = spz.color_rgb(0);
+    assert!(color[0] > 0.5 && color[2] < 0.5);
+
+    // Survives being written out and read back.
+    let decoded = SpzFile::decode(&spz.encode().unwrap()).expect("should decode");
+    assert_eq!(decoded.metadata(), spz.metadata());
+  }
+
+  #[test]
+  fn reduces_precision_for_large_scenes() {
+    let ply = ply_with_positions(&[[5000.0, 0.0, 0.0], [-0.25, 0.0, 0.0]]);
+    let spz = convert_ply_to_spz(&ply).expect("should convert");
+    assert_eq!(spz.header().fractional_bits, 10);
+    assert_eq!(spz.position(0)[0], 5000.0);
+    assert_eq!(spz.position(1)[0], -0.25);
+  }
+
+  #[test]
+  fn rejects_truncated_body() {
+    let ply = ply_with_positions(&[[0.0; 3]; 3]);
+    let result = convert_ply_to_spz(&ply[..ply.len() - 1]);
+    assert!(matches!(result, Err(SplatError::Truncated { .. })));
+  }
+
+  #[test]
+  fn rejects_plain_point_clouds() {
+    let mut ply = b"ply\nformat binary_little_endian 1.0\nelement vertex 1\n\
+        property float x\nproperty float y\nproperty float z\nend_header\n".to_vec();
+    ply.extend_from_slice(&[0u8; 12]);
+    assert!(matches!(convert_ply_to_spz(&ply), Err(SplatError::InvalidPly(_))));
+  }
+
+  #[test]
+  fn rotation_encoding() {
+    assert_eq!(encode_rotation([0.0, 0.0, 0.0, 1.0]), [128, 128, 128]);
+    assert_eq!(encode_rotation([0.0, 0.0, 0.0, -2.0]), [128, 128, 128]);
+    assert_eq!(encode_rotation([1.0, 0.0, 0.0, 0.0]), [255, 128, 128]);
+  }
+
+  #[test]
+  fn sh_quantization() {
+    assert_eq!(quantize_sh(0.0, SH1_BITS), 128);
+    assert_eq!(quantize_sh(10.0, SH1_BITS), 255);
+    assert_eq!(quantize_sh(-10.0, SH_REST_BITS), 0);
+    assert_eq!(quantize_sh(0.1, SH_REST_BITS) % 16, 0);
+  }
+}
@@ -0,0 +1,2 @@
+pub mod convert_ply_to_spz;
+pub mod ply_header;
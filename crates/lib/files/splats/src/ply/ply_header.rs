@@ -0,0 +1,233 @@
+use crate::error::SplatError;
+
+/// Headers are a few dozen short lines; don't scan a huge file looking for one.
+const MAX_HEADER_LEN: usize = 64 * 1024;
+
+const END_HEADER: &[u8] = b"end_header";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlyScalarType {
+  Int8,
+  UInt8,
+  Int16,
+  UInt16,
+  Int32,
+  UInt32,
+  Float32,
+  Float64,
+}
+
+impl PlyScalarType {
+  fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "char" | "int8" => Some(PlyScalarType::Int8),
+      "uchar" | "uint8" => Some(PlyScalarType::UInt8),
+      "short" | "int16" => Some(PlyScalarType::Int16),
+      "ushort" | "uint16" => Some(PlyScalarType::UInt16),
+      "int" | "int32" => Some(PlyScalarType::Int32),
+      "uint" | "uint32" => Some(PlyScalarType::UInt32),
+      "float" | "float32" => Some(PlyScalarType::Float32),
+      "double" | "float64" => Some(PlyScalarType::Float64),
+      _ => None,
+    }
+  }
+
+  pub fn size(&self) -> usize {
+    match self {
+      PlyScalarType::Int8 | PlyScalarType::UInt8 => 1,
+      PlyScalarType::Int16 | PlyScalarType::UInt16 => 2,
+      PlyScalarType::Int32 | PlyScalarType::UInt32 | PlyScalarType::Float32 => 4,
+      PlyScalarType::Float64 => 8,
+    }
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct PlyProperty {
+  pub name: String,
+  pub scalar_type: PlyScalarType,
+
+  /// Byte offset within a vertex record.
+  pub offset: usize,
+}
+
+impl PlyProperty {
+  /// Read this property out of a single vertex record.
+  pub fn read(&self, record: &[u8]) -> f32 {
+    let bytes = &record[self.offset..self.offset + self.scalar_type.size()];
+    match self.scalar_type {
+      PlyScalarType::Int8 => bytes[0] as i8 as f32,
+      PlyScalarType::UInt8 => bytes[0] as f32,
+      PlyScalarType::Int16 => i16::from_le_bytes([bytes[0], bytes[1]]) as f32,
+      PlyScalarType::UInt16 => u16::from_le_bytes([bytes[0], bytes[1]]) as f32,
+      PlyScalarType::Int32 => i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32,
+      PlyScalarType::UInt32 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32,
+      PlyScalarType::Float32 => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+      PlyScalarType::Float64 => f64::from_le_bytes([
+        bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+      ]) as f32,
+    }
+  }
+}
+
+/// The layout of the "vertex" element of a binary little endian PLY file.
+///
+/// Gaussian splat PLYs store one gaussian per vertex. We only support files where the vertex
+/// element comes first, which is what every trainer we've seen writes.
+#[derive(Clone, Debug)]
+pub struct PlyVertexLayout {
+  pub vertex_count: usize,
+
+  /// Size of one vertex record in bytes.
+  pub stride: usize,
+
+  pub properties: Vec<PlyProperty>,
+
+  /// Offset of the first vertex record (ie. the length of the header).
+  pub body_offset: usize,
+}
+
+impl PlyVertexLayout {
+  pub fn parse(bytes: &[u8]) -> Result<Self, SplatError> {
+    let header_end = find_header_end(bytes)?;
+    let header = std::str::from_utf8(&bytes[..header_end])
+        .map_err(|_| SplatError::InvalidPly("header is not valid text".to_string()))?;
+
+    let mut lines = header.lines().map(|line| line.trim());
+
+    if lines.next() != Some("ply") {
+      return Err(SplatError::InvalidPly("missing 'ply' magic".to_string()));
+    }
+
+    let mut maybe_vertex_count = None;
+    let mut in_vertex_element = false;
+    let mut properties: Vec<PlyProperty> = Vec::new();
+    let mut stride = 0;
+
+    for line in lines {
+      let tokens: Vec<&str> = line.split_whitespace().collect();
+      match tokens.as_slice() {
+        [] | ["comment", ..] | ["obj_info", ..] | ["end_header"] => {}
+        ["format", "binary_little_endian", "1.0"] => {}
+        ["format", format, ..] => {
+          return Err(SplatError::UnsupportedPly(format!("format '{}'", format)));
+        }
+        ["element", "vertex", count] => {
+          if maybe_vertex_count.is_some() {
+            return Err(SplatError::InvalidPly("duplicate vertex element".to_string()));
+          }
+          let count = count.parse::<usize>()
+              .map_err(|_| SplatError::InvalidPly(format!("bad vertex count '{}'", count)))?;
+          maybe_vertex_count = Some(count);
+          in_vertex_element = true;
+        }
+        ["element", name, _count] => {
+          if maybe_vertex_count.is_none() {
+            return Err(SplatError::UnsupportedPly(format!("element '{}' before vertices", name)));
+          }
+          // Anything after the vertex data doesn't affect where the vertices are.
+          in_vertex_element = false;
+        }
+        ["property", "list", ..] if in_vertex_element => {
+          return Err(SplatError::UnsupportedPly("list property on vertices".to_string()));
+        }
+        ["property", type_name, name] if in_vertex_element => {
+          let scalar_type = PlyScalarType::from_name(type_name)
+              .ok_or_else(|| SplatError::InvalidPly(format!("unknown property type '{}'", type_name)))?;
+          properties.push(PlyProperty {
+            name: name.to_string(),
+            scalar_type,
+            offset: stride,
+          });
+          stride += scalar_type.size();
+        }
+        ["property", ..] => {}
+        _ => return Err(SplatError::InvalidPly(format!("unexpected header line '{}'", line))),
+      }
+    }
+
+    let vertex_count = maybe_vertex_count
+        .ok_or_else(|| SplatError::InvalidPly("no vertex element".to_string()))?;
+
+    if stride == 0 {
+      return Err(SplatError::InvalidPly("vertices have no properties".to_string()));
+    }
+
+    Ok(Self {
+      vertex_count,
+      stride,
+      properties,
+      body_offset: header_end,
+    })
+  }
+
+  pub fn property(&self, name: &str) -> Option<&PlyProperty> {
+    self.properties.iter().find(|property| property.name == name)
+  }
+
+  pub fn require_property(&self, name: &str) -> Result<&PlyProperty, SplatError> {
+    self.property(name)
+        .ok_or_else(|| SplatError::InvalidPly(format!("missing vertex property '{}'", name)))
+  }
+}
+
+/// Offset just past the newline that ends the "end_header" line.
+fn find_header_end(bytes: &[u8]) -> Result<usize, SplatError> {
+  let search = &bytes[..bytes.len().min(MAX_HEADER_LEN)];
+
+  let position = search.windows(END_HEADER.len())
+      .position(|window| window == END_HEADER)
+      .ok_or_else(|| SplatError::InvalidPly("missing end_header".to_string()))?;
+
+  let after = position + END_HEADER.len();
+  match &bytes[after..] {
+    [b'\n', ..] => Ok(after + 1),
+    [b'\r', b'\n', ..] => Ok(after + 2),
+    _ => Err(SplatError::InvalidPly("malformed end_header".to_string())),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_layout() {
+    let mut bytes = b"ply\nformat binary_little_endian 1.0\ncomment made by a trainer\n\
+        element vertex 2\nproperty float x\nproperty double y\nproperty uchar z\n\
+        element face 0\nproperty list uchar int vertex_indices\nend_header\n".to_vec();
+    let header_len = bytes.len();
+    bytes.extend_from_slice(&[0u8; 26]);
+
+    let layout = PlyVertexLayout::parse(&bytes).expect("should parse");
+    assert_eq!(layout.vertex_count, 2);
+    assert_eq!(layout.stride, 13);
+    assert_eq!(layout.body_offset, header_len);
+    assert_eq!(layout.require_property("y").unwrap().offset, 4);
+    assert_eq!(layout.require_property("z").unwrap().offset, 12);
+    assert!(layout.require_property("opacity").is_err());
+  }
+
+  #[test]
+  fn reads_values() {
+    let property = PlyProperty { name: "x".to_string(), scalar_type: PlyScalarType::Float32, offset: 2 };
+    let mut record = vec![0u8, 0u8];
+    record.extend_from_slice(&1.5f32.to_le_bytes());
+    assert_eq!(property.read(&record), 1.5);
+
+    let property = PlyProperty { name: "y".to_string(), scalar_type: PlyScalarType::Int16, offset: 0 };
+    assert_eq!(property.read(&(-3i16).to_le_bytes()), -3.0);
+  }
+
+  #[test]
+  fn rejects_ascii() {
+    let bytes = b"ply\nformat ascii 1.0\nelement vertex 1\nproperty float x\nend_header\n1.0\n";
+    assert!(matches!(PlyVertexLayout::parse(bytes), Err(SplatError::UnsupportedPly(_))));
+  }
+
+  #[test]
+  fn rejects_missing_header_end() {
+    let bytes = b"ply\nformat binary_little_endian 1.0\nelement vertex 1\nproperty float x\n";
+    assert!(matches!(PlyVertexLayout::parse(bytes), Err(SplatError::InvalidPly(_))));
+  }
+}
@@ -0,0 +1,177 @@
+use std::io::Cursor;
+
+use images::image::{ImageFormat, Rgb, RgbImage};
+
+use crate::error::SplatError;
+use crate::spz::spz_file::SpzFile;
+
+pub const DEFAULT_PREVIEW_SIZE: u32 = 512;
+
+/// Framing only looks at a sample of the points; that's plenty to find the bulk of the scene.
+const FRAMING_SAMPLE_LIMIT: usize = 100_000;
+
+/// Generated scenes often have a few far away points (sky, stray floaters) that would otherwise
+/// shrink the interesting part of the scene to a dot.
+const FRAMING_PERCENTILE: f32 = 0.01;
+
+const FRAMING_MARGIN: f32 = 1.05;
+
+/// A top-down (looking along the Y axis) image of point density, tinted by the average color of
+/// the points that land in each pixel. Nothing is rendered; this is just a histogram.
+pub fn render_density_preview(spz: &SpzFile, size: u32) -> RgbImage {
+  let size = size.max(1);
+  let pixels = (size * size) as usize;
+
+  let mut density = vec![0.0f32; pixels];
+  let mut color_sums = vec![[0.0f32; 3]; pixels];
+
+  let Some(frame) = Frame::for_splat(spz) else {
+    return RgbImage::new(size, size);
+  };
+
+  for index in 0..spz.num_points() {
+    let opacity = spz.opacity(index);
+    if opacity <= 0.0 {
+      continue;
+    }
+
+    let [x, _, z] = spz.position(index);
+    let Some((column, row)) = frame.to_pixel(x, z, size) else {
+      continue;
+    };
+
+    let pixel = row * size as usize + column;
+    let color = spz.color_rgb(index);
+    density[pixel] += opacity;
+    for channel in 0..3 {
+      color_sums[pixel][channel] += color[channel] * opacity;
+    }
+  }
+
+  // Log scaling so sparse regions are still visible next to dense ones.
+  let max_density = density.iter().cloned().fold(0.0f32, f32::max);
+  let log_max = (1.0 + max_density).ln();
+
+  let mut image = RgbImage::new(size, size);
+  if log_max <= 0.0 {
+    return image;
+  }
+
+  for (pixel, (weight, color_sum)) in density.iter().zip(color_sums.iter()).enumerate() {
+    if *weight <= 0.0 {
+      continue;
+    }
+    let brightness = (1.0 + weight).ln() / log_max;
+    let rgb = color_sum.map(|sum| ((sum / weight) * brightness * 255.0).round().clamp(0.0, 255.0) as u8);
+    image.put_pixel(pixel as u32 % size, pixel as u32 / size, Rgb(rgb));
+  }
+
+  image
+}
+
+pub fn render_density_preview_png(spz: &SpzFile, size: u32) -> Result<Vec<u8>, SplatError> {
+  let image = render_density_preview(spz, size);
+  let mut output_bytes: Vec<u8> = Vec::new();
+  image.write_to(&mut Cursor::new(&mut output_bytes), ImageFormat::Png)?;
+  Ok(output_bytes)
+}
+
+/// A square region of the XZ plane.
+struct Frame {
+  min_x: f32,
+  min_z: f32,
+  span: f32,
+}
+
+impl Frame {
+  fn for_splat(spz: &SpzFile) -> Option<Self> {
+    let step = (spz.num_points() / FRAMING_SAMPLE_LIMIT).max(1);
+
+    let mut xs = Vec::new();
+    let mut zs = Vec::new();
+    for index in (0..spz.num_points()).step_by(step) {
+      let [x, _, z] = spz.position(index);
+      if x.is_finite() && z.is_finite() {
+        xs.push(x);
+        zs.push(z);
+      }
+    }
+
+    let (low_x, high_x) = percentile_range(&mut xs)?;
+    let (low_z, high_z) = percentile_range(&mut zs)?;
+
+    let mut span = (high_x - low_x).max(high_z - low_z) * FRAMING_MARGIN;
+    if span <= 0.0 {
+      span = 1.0; // eg. a single point
+    }
+
+    Some(Self {
+      min_x: (low_x + high_x - span) / 2.0,
+      min_z: (low_z + high_z - span) / 2.0,
+      span,
+    })
+  }
+
+  fn to_pixel(&self, x: f32, z: f32, size: u32) -> Option<(usize, usize)> {
+    let u = (x - self.min_x) / self.span;
+    let v = (z - self.min_z) / self.span;
+    if !(0.0..1.0).contains(&u) || !(0.0..1.0).contains(&v) {
+      return None;
+    }
+    let column = ((u * size as f32) as usize).min(size as usize - 1);
+    let row = ((v * size as f32) as usize).min(size as usize - 1);
+    Some((column, row))
+  }
+}
+
+fn percentile_range(values: &mut [f32]) -> Option<(f32, f32)> {
+  if values.is_empty() {
+    return None;
+  }
+  values.sort_unstable_by(|a, b| a.total_cmp(b));
+  let last = values.len() - 1;
+  let low = (last as f32 * FRAMING_PERCENTILE) as usize;
+  let high = (last as f32 * (1.0 - FRAMING_PERCENTILE)).ceil() as usize;
+  Some((values[low], values[high.min(last)]))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::spz::spz_file::tests::spz_with_positions;
+  use images::image::ImageReader;
+
+  #[test]
+  fn plots_points_from_above() {
+    // Two clusters separated along X. Y shouldn't matter.
+    let mut positions = vec![[-1.0, 0.0, 0.0]; 50];
+    positions.extend(vec![[1.0, 100.0, 0.0]; 50]);
+    let spz = spz_with_positions(&positions);
+
+    let image = render_density_preview(&spz, 64);
+    assert_eq!(image.dimensions(), (64, 64));
+
+    let lit = image.pixels().filter(|pixel| pixel.0 != [0, 0, 0]).count();
+    assert_eq!(lit, 2);
+
+    let left = image.get_pixel(1, 32).0;
+    let middle = image.get_pixel(31, 32).0;
+    assert_ne!(left, [0, 0, 0]);
+    assert_eq!(middle, [0, 0, 0]);
+  }
+
+  #[test]
+  fn single_point() {
+    let spz = spz_with_positions(&[[3.0, 3.0, 3.0]]);
+    let image = render_density_preview(&spz, 16);
+    assert_eq!(image.pixels().filter(|pixel| pixel.0 != [0, 0, 0]).count(), 1);
+  }
+
+  #[test]
+  fn encodes_png() {
+    let spz = spz_with_positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 1.0]]);
+    let png = render_density_preview_png(&spz, 32).expect("should encode");
+    let decoded = ImageReader::new(Cursor::new(png)).with_guessed_format().unwrap().decode().unwrap();
+    assert_eq!(decoded.width(), 32);
+  }
+}
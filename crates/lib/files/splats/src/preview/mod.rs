@@ -0,0 +1 @@
+pub mod density_preview;
@@ -0,0 +1,56 @@
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const PLY_MAGIC: &[u8] = b"ply";
+
+/// The splat file formats we accept as input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplatFormat {
+  /// Niantic's compressed format; this is what we store.
+  Spz,
+
+  /// The "standard" 3DGS training output (binary little endian, one vertex per gaussian).
+  Ply,
+}
+
+impl SplatFormat {
+  pub fn from_filename(filename: &str) -> Option<Self> {
+    let filename = filename.to_ascii_lowercase();
+    if filename.ends_with(".spz") {
+      Some(SplatFormat::Spz)
+    } else if filename.ends_with(".ply") {
+      Some(SplatFormat::Ply)
+    } else {
+      None
+    }
+  }
+
+  pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    if bytes.starts_with(&GZIP_MAGIC) {
+      Some(SplatFormat::Spz)
+    } else if bytes.starts_with(PLY_MAGIC) {
+      Some(SplatFormat::Ply)
+    } else {
+      None
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn from_filename() {
+    assert_eq!(SplatFormat::from_filename("scene.spz"), Some(SplatFormat::Spz));
+    assert_eq!(SplatFormat::from_filename("SCENE.CERAMIC.SPZ"), Some(SplatFormat::Spz));
+    assert_eq!(SplatFormat::from_filename("point_cloud.ply"), Some(SplatFormat::Ply));
+    assert_eq!(SplatFormat::from_filename("scene.glb"), None);
+    assert_eq!(SplatFormat::from_filename("spz"), None);
+  }
+
+  #[test]
+  fn from_bytes() {
+    assert_eq!(SplatFormat::from_bytes(&[0x1f, 0x8b, 0x08]), Some(SplatFormat::Spz));
+    assert_eq!(SplatFormat::from_bytes(b"ply\nformat binary_little_endian 1.0\n"), Some(SplatFormat::Ply));
+    assert_eq!(SplatFormat::from_bytes(b"glTF"), None);
+  }
+}
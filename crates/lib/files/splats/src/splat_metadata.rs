@@ -0,0 +1,76 @@
+/// Summary of a validated splat.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SplatMetadata {
+  /// SPZ version of the stored file (PLY uploads are converted to the version we write).
+  pub spz_version: u32,
+
+  pub point_count: u32,
+
+  /// Spherical harmonics degree (0-3). Zero means view-independent color.
+  pub sh_degree: u8,
+
+  pub antialiased: bool,
+
+  /// Absent only if no point has a finite position.
+  pub maybe_bounding_box: Option<BoundingBox>,
+}
+
+/// Axis aligned bounding box in splat coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+  pub min: [f32; 3],
+  pub max: [f32; 3],
+}
+
+impl BoundingBox {
+  /// Non-finite points are skipped.
+  pub fn from_points<I: IntoIterator<Item = [f32; 3]>>(points: I) -> Option<Self> {
+    let mut maybe_box: Option<BoundingBox> = None;
+    for point in points {
+      if !point.iter().all(|value| value.is_finite()) {
+        continue;
+      }
+      match maybe_box.as_mut() {
+        None => maybe_box = Some(BoundingBox { min: point, max: point }),
+        Some(bounds) => {
+          for (axis, value) in point.into_iter().enumerate() {
+            bounds.min[axis] = bounds.min[axis].min(value);
+            bounds.max[axis] = bounds.max[axis].max(value);
+          }
+        }
+      }
+    }
+    maybe_box
+  }
+
+  pub fn size(&self) -> [f32; 3] {
+    [
+      self.max[0] - self.min[0],
+      self.max[1] - self.min[1],
+      self.max[2] - self.min[2],
+    ]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn bounding_box_skips_non_finite_points() {
+    let bounds = BoundingBox::from_points([
+      [1.0, -2.0, 3.0],
+      [f32::NAN, 100.0, 100.0],
+      [-1.0, 4.0, 0.5],
+    ]).expect("should have bounds");
+
+    assert_eq!(bounds.min, [-1.0, -2.0, 0.5]);
+    assert_eq!(bounds.max, [1.0, 4.0, 3.0]);
+    assert_eq!(bounds.size(), [2.0, 6.0, 2.5]);
+  }
+
+  #[test]
+  fn bounding_box_of_nothing() {
+    assert_eq!(BoundingBox::from_points([[f32::INFINITY, 0.0, 0.0]]), None);
+  }
+}
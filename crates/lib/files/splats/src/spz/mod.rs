@@ -0,0 +1,2 @@
+pub mod spz_file;
+pub mod spz_header;
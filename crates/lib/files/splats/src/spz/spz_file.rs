@@ -0,0 +1,297 @@
+use std::io::{ErrorKind, Read, Write};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::error::SplatError;
+use crate::splat_metadata::{BoundingBox, SplatMetadata};
+use crate::spz::spz_header::{SPZ_HEADER_SIZE, SpzHeader};
+
+/// Colors are stored as the SH DC term, scaled by this factor.
+const COLOR_SCALE: f32 = 0.15;
+
+/// Zeroth order spherical harmonics basis constant.
+const SH_C0: f32 = 0.282_094_8;
+
+/// A validated SPZ file, held decompressed.
+///
+/// Only the attributes needed for inspection and previews are decoded; everything else stays in
+/// its packed form.
+#[derive(Clone, Debug)]
+pub struct SpzFile {
+  header: SpzHeader,
+  payload: Vec<u8>,
+}
+
+impl SpzFile {
+  /// Decompress and validate an SPZ file.
+  /// Rejects bad headers, truncated data, and data beyond what the header declares.
+  pub fn decode(gzip_bytes: &[u8]) -> Result<Self, SplatError> {
+    let mut decoder = GzDecoder::new(gzip_bytes);
+
+    let mut header_bytes = Vec::with_capacity(SPZ_HEADER_SIZE);
+    read_at_most(&mut decoder, SPZ_HEADER_SIZE, &mut header_bytes)?;
+
+    let header_bytes: [u8; SPZ_HEADER_SIZE] = header_bytes.as_slice()
+        .try_into()
+        .map_err(|_| SplatError::Truncated {
+          expected_bytes: SPZ_HEADER_SIZE as u64,
+          actual_bytes: header_bytes.len() as u64,
+        })?;
+
+    let header = SpzHeader::from_bytes(&header_bytes)?;
+    let expected_len = header.payload_len();
+
+    // NB: Read one byte past the end so we notice trailing data. This also makes the decoder
+    // hit the end of the stream, which is where it verifies the gzip checksum.
+    let mut payload = Vec::with_capacity(expected_len);
+    read_at_most(&mut decoder, expected_len + 1, &mut payload)?;
+
+    if payload.len() < expected_len {
+      return Err(SplatError::Truncated {
+        expected_bytes: (SPZ_HEADER_SIZE + expected_len) as u64,
+        actual_bytes: (SPZ_HEADER_SIZE + payload.len()) as u64,
+      });
+    }
+
+    if payload.len() > expected_len {
+      return Err(SplatError::TrailingData);
+    }
+
+    Ok(Self { header, payload })
+  }
+
+  pub(crate) fn from_parts(header: SpzHeader, payload: Vec<u8>) -> Result<Self, SplatError> {
+    header.validate()?;
+    if payload.len() != header.payload_len() {
+      return Err(SplatError::Truncated {
+        expected_bytes: header.payload_len() as u64,
+        actual_bytes: payload.len() as u64,
+      });
+    }
+    Ok(Self { header, payload })
+  }
+
+  /// Compress back into an SPZ file.
+  pub fn encode(&self) -> Result<Vec<u8>, SplatError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&self.header.to_bytes())?;
+    encoder.write_all(&self.payload)?;
+    Ok(encoder.finish()?)
+  }
+
+  pub fn header(&self) -> &SpzHeader {
+    &self.header
+  }
+
+  pub fn num_points(&self) -> usize {
+    self.header.num_points as usize
+  }
+
+  pub fn metadata(&self) -> SplatMetadata {
+    SplatMetadata {
+      spz_version: self.header.version,
+      point_count: self.header.num_points,
+      sh_degree: self.header.sh_degree,
+      antialiased: self.header.is_antialiased(),
+      maybe_bounding_box: BoundingBox::from_points((0..self.num_points()).map(|i| self.position(i))),
+    }
+  }
+
+  pub fn position(&self, index: usize) -> [f32; 3] {
+    let stride = self.header.position_bytes_per_point();
+    let bytes = &self.payload[index * stride..(index + 1) * stride];
+
+    if self.header.version == 1 {
+      return [
+        f16_to_f32(u16::from_le_bytes([bytes[0], bytes[1]])),
+        f16_to_f32(u16::from_le_bytes([bytes[2], bytes[3]])),
+        f16_to_f32(u16::from_le_bytes([bytes[4], bytes[5]])),
+      ];
+    }
+
+    let scale = 1.0 / (1u32 << self.header.fractional_bits) as f32;
+    [
+      fixed24_to_i32(&bytes[0..3]) as f32 * scale,
+      fixed24_to_i32(&bytes[3..6]) as f32 * scale,
+      fixed24_to_i32(&bytes[6..9]) as f32 * scale,
+    ]
+  }
+
+  /// Opacity in [0, 1].
+  pub fn opacity(&self, index: usize) -> f32 {
+    self.payload[self.alphas_offset() + index] as f32 / 255.0
+  }
+
+  /// Base (view independent) color in [0, 1].
+  pub fn color_rgb(&self, index: usize) -> [f32; 3] {
+    let offset = self.colors_offset() + index * 3;
+    let channel = |byte: u8| {
+      let dc = (byte as f32 / 255.0 - 0.5) / COLOR_SCALE;
+      (0.5 + SH_C0 * dc).clamp(0.0, 1.0)
+    };
+    [
+      channel(self.payload[offset]),
+      channel(self.payload[offset + 1]),
+      channel(self.payload[offset + 2]),
+    ]
+  }
+
+  fn alphas_offset(&self) -> usize {
+    self.num_points() * self.header.position_bytes_per_point()
+  }
+
+  fn colors_offset(&self) -> usize {
+    self.alphas_offset() + self.num_points()
+  }
+}
+
+/// Read until `limit` bytes or the end of the stream, whichever comes first.
+/// A stream that ends early is not an error here; callers check the length.
+fn read_at_most<R: Read>(reader: &mut R, limit: usize, buffer: &mut Vec<u8>) -> Result<(), SplatError> {
+  match reader.take(limit as u64).read_to_end(buffer) {
+    Ok(_) => Ok(()),
+    // NB: The gzip decoder reports a cut off stream as UnexpectedEof.
+    Err(err) if err.kind() == ErrorKind::UnexpectedEof => Ok(()),
+    Err(err) => Err(SplatError::InvalidCompression(err)),
+  }
+}
+
+fn fixed24_to_i32(bytes: &[u8]) -> i32 {
+  let mut value = bytes[0] as i32 | (bytes[1] as i32) << 8 | (bytes[2] as i32) << 16;
+  if value & 0x80_0000 != 0 {
+    value |= !0xff_ffff; // Sign extend
+  }
+  value
+}
+
+fn f16_to_f32(half: u16) -> f32 {
+  let sign = if half & 0x8000 != 0 { -1.0 } else { 1.0 };
+  let exponent = ((half >> 10) & 0x1f) as u32;
+  let mantissa = (half & 0x3ff) as u32;
+
+  let magnitude = match exponent {
+    0 => mantissa as f32 * (1.0 / (1u32 << 24) as f32), // Subnormal
+    31 if mantissa == 0 => f32::INFINITY,
+    31 => f32::NAN,
+    _ => f32::from_bits(((exponent + 127 - 15) << 23) | (mantissa << 13)),
+  };
+
+  sign * magnitude
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use super::*;
+
+  /// Build a small valid SPZ (v2, degree 0) with the given positions.
+  pub(crate) fn spz_with_positions(positions: &[[f32; 3]]) -> SpzFile {
+    let header = SpzHeader {
+      version: 2,
+      num_points: positions.len() as u32,
+      sh_degree: 0,
+      fractional_bits: 12,
+      flags: 0,
+    };
+    let mut payload = Vec::with_capacity(header.payload_len());
+    for position in positions {
+      for value in position {
+        let fixed = (value * 4096.0).round() as i32;
+        payload.extend_from_slice(&fixed.to_le_bytes()[0..3]);
+      }
+    }
+    payload.extend(positions.iter().map(|_| 255u8)); // alphas
+    payload.extend(positions.iter().flat_map(|_| [200u8, 128, 50])); // colors
+    payload.extend(positions.iter().flat_map(|_| [100u8; 3])); // scales
+    payload.extend(positions.iter().flat_map(|_| [128u8; 3])); // rotations
+    SpzFile::from_parts(header, payload).expect("should be valid")
+  }
+
+  #[test]
+  fn round_trip() {
+    let spz = spz_with_positions(&[[1.0, -2.5, 3.25], [-100.0, 0.0, 0.001]]);
+    let bytes = spz.encode().expect("should encode");
+    let decoded = SpzFile::decode(&bytes).expect("should decode");
+
+    assert_eq!(decoded.num_points(), 2);
+    assert_eq!(decoded.position(0), [1.0, -2.5, 3.25]);
+    assert_eq!(decoded.position(1)[0], -100.0);
+    assert_eq!(decoded.opacity(0), 1.0);
+
+    let color = decoded.color_rgb(0);
+    assert!(color[0] > color[1] && color[1] > color[2]);
+
+    let metadata = decoded.metadata();
+    assert_eq!(metadata.point_count, 2);
+    assert_eq!(metadata.spz_version, 2);
+    assert_eq!(metadata.sh_degree, 0);
+    let bounds = metadata.maybe_bounding_box.expect("bounds");
+    assert_eq!(bounds.min[0], -100.0);
+    assert_eq!(bounds.max[2], 3.25);
+  }
+
+  #[test]
+  fn rejects_truncated_file() {
+    let spz = spz_with_positions(&[[1.0, 2.0, 3.0]; 10]);
+
+    // Valid gzip stream, but with fewer points than declared.
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&spz.header().to_bytes()).unwrap();
+    encoder.write_all(&spz.payload[..spz.payload.len() - 5]).unwrap();
+    let short = encoder.finish().unwrap();
+
+    match SpzFile::decode(&short) {
+      Err(SplatError::Truncated { expected_bytes, actual_bytes }) => {
+        assert_eq!(expected_bytes - actual_bytes, 5);
+      }
+      other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Cut off gzip stream
+    let bytes = spz.encode().unwrap();
+    assert!(SpzFile::decode(&bytes[..bytes.len() / 2]).is_err());
+  }
+
+  #[test]
+  fn rejects_trailing_data() {
+    let spz = spz_with_positions(&[[1.0, 2.0, 3.0]]);
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&spz.header().to_bytes()).unwrap();
+    encoder.write_all(&spz.payload).unwrap();
+    encoder.write_all(&[0u8; 7]).unwrap();
+    let long = encoder.finish().unwrap();
+
+    assert!(matches!(SpzFile::decode(&long), Err(SplatError::TrailingData)));
+  }
+
+  #[test]
+  fn rejects_non_gzip() {
+    assert!(matches!(SpzFile::decode(b"not a splat"), Err(SplatError::InvalidCompression(_))));
+  }
+
+  #[test]
+  fn rejects_corrupt_checksum() {
+    let spz = spz_with_positions(&[[1.0, 2.0, 3.0]; 4]);
+    let mut bytes = spz.encode().unwrap();
+    let crc_offset = bytes.len() - 8;
+    bytes[crc_offset] ^= 0xff;
+    assert!(SpzFile::decode(&bytes).is_err());
+  }
+
+  #[test]
+  fn fixed24() {
+    assert_eq!(fixed24_to_i32(&[0x00, 0x10, 0x00]), 4096);
+    assert_eq!(fixed24_to_i32(&[0xff, 0xff, 0xff]), -1);
+    assert_eq!(fixed24_to_i32(&[0x00, 0x00, 0x80]), -(1 << 23));
+  }
+
+  #[test]
+  fn half_floats() {
+    assert_eq!(f16_to_f32(0x3c00), 1.0);
+    assert_eq!(f16_to_f32(0xc000), -2.0);
+    assert_eq!(f16_to_f32(0x3800), 0.5);
+    assert_eq!(f16_to_f32(0x0000), 0.0);
+    assert!(f16_to_f32(0x7c00).is_infinite());
+  }
+}
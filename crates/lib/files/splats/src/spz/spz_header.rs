@@ -0,0 +1,181 @@
+use crate::error::SplatError;
+
+/// "NGSP", little endian.
+pub const SPZ_MAGIC: u32 = 0x5053474e;
+
+/// Size of the (uncompressed) header that precedes the gaussian data.
+pub const SPZ_HEADER_SIZE: usize = 16;
+
+pub const MIN_SUPPORTED_SPZ_VERSION: u32 = 1;
+pub const MAX_SUPPORTED_SPZ_VERSION: u32 = 3;
+
+/// Same ceiling as the reference implementation's loader.
+pub const MAX_SPZ_POINTS: u32 = 10_000_000;
+
+pub const MAX_SH_DEGREE: u8 = 3;
+
+/// Positions are 24-bit fixed point, so anything beyond this leaves no integer bits.
+const MAX_FRACTIONAL_BITS: u8 = 23;
+
+const FLAG_ANTIALIASED: u8 = 0x1;
+
+/// The fixed 16 byte header at the start of the (decompressed) SPZ stream.
+///
+/// The gaussian data that follows is laid out by attribute rather than by point:
+/// positions, alphas, colors, scales, rotations, then spherical harmonics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpzHeader {
+  pub version: u32,
+  pub num_points: u32,
+  pub sh_degree: u8,
+  pub fractional_bits: u8,
+  pub flags: u8,
+}
+
+impl SpzHeader {
+  pub fn from_bytes(bytes: &[u8; SPZ_HEADER_SIZE]) -> Result<Self, SplatError> {
+    let magic = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    if magic != SPZ_MAGIC {
+      return Err(SplatError::InvalidMagic(magic));
+    }
+
+    let header = SpzHeader {
+      version: u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+      num_points: u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+      sh_degree: bytes[12],
+      fractional_bits: bytes[13],
+      flags: bytes[14],
+      // NB: bytes[15] is reserved.
+    };
+
+    header.validate()?;
+
+    Ok(header)
+  }
+
+  pub fn to_bytes(&self) -> [u8; SPZ_HEADER_SIZE] {
+    let mut bytes = [0u8; SPZ_HEADER_SIZE];
+    bytes[0..4].copy_from_slice(&SPZ_MAGIC.to_le_bytes());
+    bytes[4..8].copy_from_slice(&self.version.to_le_bytes());
+    bytes[8..12].copy_from_slice(&self.num_points.to_le_bytes());
+    bytes[12] = self.sh_degree;
+    bytes[13] = self.fractional_bits;
+    bytes[14] = self.flags;
+    bytes
+  }
+
+  pub fn validate(&self) -> Result<(), SplatError> {
+    if !(MIN_SUPPORTED_SPZ_VERSION..=MAX_SUPPORTED_SPZ_VERSION).contains(&self.version) {
+      return Err(SplatError::UnsupportedVersion(self.version));
+    }
+    if self.num_points == 0 {
+      return Err(SplatError::NoPoints);
+    }
+    if self.num_points > MAX_SPZ_POINTS {
+      return Err(SplatError::TooManyPoints(self.num_points as u64));
+    }
+    if self.sh_degree > MAX_SH_DEGREE {
+      return Err(SplatError::InvalidShDegree(self.sh_degree));
+    }
+    if self.fractional_bits > MAX_FRACTIONAL_BITS {
+      return Err(SplatError::InvalidFractionalBits(self.fractional_bits));
+    }
+    Ok(())
+  }
+
+  pub fn is_antialiased(&self) -> bool {
+    self.flags & FLAG_ANTIALIASED != 0
+  }
+
+  /// Version 1 stored positions as float16; later versions use 24-bit fixed point.
+  pub fn position_bytes_per_point(&self) -> usize {
+    if self.version == 1 { 6 } else { 9 }
+  }
+
+  /// Version 3 switched from three components to a "smallest three" encoding.
+  pub fn rotation_bytes_per_point(&self) -> usize {
+    if self.version >= 3 { 4 } else { 3 }
+  }
+
+  pub fn sh_bytes_per_point(&self) -> usize {
+    sh_coefficients_for_degree(self.sh_degree) * 3
+  }
+
+  /// Size of the gaussian data that must follow the header.
+  pub fn payload_len(&self) -> usize {
+    let per_point = self.position_bytes_per_point()
+        + 1 // alpha
+        + 3 // color
+        + 3 // scale
+        + self.rotation_bytes_per_point()
+        + self.sh_bytes_per_point();
+    self.num_points as usize * per_point
+  }
+}
+
+/// Number of spherical harmonics coefficients (per color channel) beyond the DC term.
+pub fn sh_coefficients_for_degree(sh_degree: u8) -> usize {
+  match sh_degree {
+    1 => 3,
+    2 => 8,
+    3 => 15,
+    _ => 0,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn header() -> SpzHeader {
+    SpzHeader {
+      version: 2,
+      num_points: 1000,
+      sh_degree: 3,
+      fractional_bits: 12,
+      flags: FLAG_ANTIALIASED,
+    }
+  }
+
+  #[test]
+  fn round_trip() {
+    let bytes = header().to_bytes();
+    assert_eq!(&bytes[0..4], b"NGSP");
+    let parsed = SpzHeader::from_bytes(&bytes).expect("should parse");
+    assert_eq!(parsed, header());
+    assert!(parsed.is_antialiased());
+  }
+
+  #[test]
+  fn payload_len() {
+    // 9 position + 1 alpha + 3 color + 3 scale + 3 rotation + 45 sh
+    assert_eq!(header().payload_len(), 1000 * 64);
+
+    let v3 = SpzHeader { version: 3, sh_degree: 0, ..header() };
+    assert_eq!(v3.payload_len(), 1000 * 20);
+
+    let v1 = SpzHeader { version: 1, sh_degree: 1, ..header() };
+    assert_eq!(v1.payload_len(), 1000 * 25);
+  }
+
+  #[test]
+  fn rejects_bad_magic() {
+    let mut bytes = header().to_bytes();
+    bytes[0] = b'X';
+    assert!(matches!(SpzHeader::from_bytes(&bytes), Err(SplatError::InvalidMagic(_))));
+  }
+
+  #[test]
+  fn rejects_invalid_fields() {
+    let cases = [
+      (SpzHeader { version: 9, ..header() }, "version"),
+      (SpzHeader { num_points: 0, ..header() }, "no points"),
+      (SpzHeader { num_points: MAX_SPZ_POINTS + 1, ..header() }, "too many points"),
+      (SpzHeader { sh_degree: 4, ..header() }, "sh degree"),
+      (SpzHeader { fractional_bits: 24, ..header() }, "fractional bits"),
+    ];
+    for (header, case) in cases {
+      assert!(SpzHeader::from_bytes(&header.to_bytes()).is_err(), "case: {}", case);
+    }
+  }
+}
@@ -1,16 +1,16 @@
-use std::sync::Arc;
 use log::error;
-use mysql_queries::queries::generic_inference::gmicloud::list_pending_gmicloud_jobs::PendingGmiCloudJob;
 use pager::client::pager::Pager;
 use pager::notification::notification_details_builder::NotificationDetailsBuilder;
 use pager::notification::notification_urgency::NotificationUrgency;
 
+use crate::polling::polling_job::PendingJob;
+
 /// Enqueue a pager alert for the error, then return it as `Err`.
 pub fn alert_pager_and_return_err<T>(
   pager: &Pager,
   title: &str,
   error: Box<dyn std::error::Error + Send + Sync + 'static>,
-  job: Option<&PendingGmiCloudJob>,
+  job: Option<&dyn PendingJob>,
 ) -> anyhow::Result<T> {
   let err_message = format!("{:#}", error);
 
@@ -20,9 +20,9 @@ pub fn alert_pager_and_return_err<T>(
 
   if let Some(job) = job {
     builder = builder
-      .set_inference_job_token(Some(job.job_token.to_string()))
-      .set_third_party_id(Some(job.request_id.clone()))
-      .set_user_token(job.maybe_creator_user_token.as_ref().map(|t| t.to_string()));
+      .set_inference_job_token(Some(job.job_token().to_string()))
+      .set_third_party_id(Some(job.third_party_id().to_string()))
+      .set_user_token(job.maybe_user_token().map(|t| t.to_string()));
   }
 
   let notification = builder.build();
@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use errors::AnyhowResult;
+use jobs_common::poll_backoff::PollBackoffConfig;
+
+const DEFAULT_BIND_ADDRESS: &str = "0.0.0.0:12345";
+const DEFAULT_NUM_WORKERS: usize = 2;
+
+/// Everything the runner needs besides the job itself.
+///
+/// Start from `with_defaults`, adjust any defaults in code, then call
+/// `with_env_overrides` so the environment has the final say.
+#[derive(Clone, Debug)]
+pub struct JobRunnerConfig {
+  /// Name of the binary, eg. "gmicloud-job". Used for logs, metrics, and the pager.
+  pub app_name: String,
+
+  /// How long to sleep after a successful poll iteration.
+  pub poll_interval_success: Duration,
+
+  /// How long to sleep after a failed poll iteration.
+  pub poll_interval_failure: Duration,
+
+  /// Maximum number of jobs polled (and processed) at once.
+  pub max_concurrent_polls: usize,
+
+  /// Per-job poll backoff and the maximum job lifetime.
+  pub poll_backoff: PollBackoffConfig,
+
+  /// How long to wait for in-flight polls to finish once shutdown starts.
+  pub shutdown_drain_timeout: Duration,
+
+  pub http_bind_address: String,
+  pub http_num_workers: usize,
+
+  /// The health check reports unhealthy at this many consecutive failures.
+  pub consecutive_failure_unhealthy_threshold: u64,
+}
+
+impl JobRunnerConfig {
+  pub fn with_defaults(app_name: &str) -> Self {
+    Self {
+      app_name: app_name.to_string(),
+      poll_interval_success: Duration::from_secs(3),
+      poll_interval_failure: Duration::from_secs(15),
+      max_concurrent_polls: 8,
+      poll_backoff: PollBackoffConfig {
+        initial_interval: Duration::from_secs(5),
+        max_interval: Duration::from_secs(60 * 2),
+        max_job_lifetime: Duration::from_secs(60 * 60 * 3),
+      },
+      shutdown_drain_timeout: Duration::from_secs(30),
+      http_bind_address: DEFAULT_BIND_ADDRESS.to_string(),
+      http_num_workers: DEFAULT_NUM_WORKERS,
+      consecutive_failure_unhealthy_threshold: 3,
+    }
+  }
+
+  /// Override settings from the environment, using the current values as defaults.
+  ///
+  /// Job settings are read from `{env_prefix}_POLL_INTERVAL_SUCCESS_MILLIS`,
+  /// `{env_prefix}_POLL_INTERVAL_FAILURE_MILLIS`, `{env_prefix}_MAX_CONCURRENT_POLLS`,
+  /// `{env_prefix}_POLL_BACKOFF_INITIAL_SECONDS`, `{env_prefix}_POLL_BACKOFF_MAX_SECONDS`,
+  /// `{env_prefix}_MAX_JOB_LIFETIME_SECONDS` and `{env_prefix}_SHUTDOWN_DRAIN_SECONDS`.
+  /// The HTTP server settings are shared by every job and aren't prefixed.
+  pub fn with_env_overrides(mut self, env_prefix: &str) -> AnyhowResult<Self> {
+    let env_name = |suffix: &str| format!("{}_{}", env_prefix, suffix);
+
+    self.poll_interval_success = Duration::from_millis(easyenv::get_env_num(
+      &env_name("POLL_INTERVAL_SUCCESS_MILLIS"),
+      self.poll_interval_success.as_millis() as u64,
+    )?);
+
+    self.poll_interval_failure = Duration::from_millis(easyenv::get_env_num(
+      &env_name("POLL_INTERVAL_FAILURE_MILLIS"),
+      self.poll_interval_failure.as_millis() as u64,
+    )?);
+
+    self.max_concurrent_polls = easyenv::get_env_num(
+      &env_name("MAX_CONCURRENT_POLLS"),
+      self.max_concurrent_polls,
+    )?;
+
+    self.poll_backoff.initial_interval = easyenv::get_env_duration_seconds_or_default(
+      &env_name("POLL_BACKOFF_INITIAL_SECONDS"),
+      self.poll_backoff.initial_interval,
+    );
+
+    self.poll_backoff.max_interval = easyenv::get_env_duration_seconds_or_default(
+      &env_name("POLL_BACKOFF_MAX_SECONDS"),
+      self.poll_backoff.max_interval,
+    );
+
+    self.poll_backoff.max_job_lifetime = easyenv::get_env_duration_seconds_or_default(
+      &env_name("MAX_JOB_LIFETIME_SECONDS"),
+      self.poll_backoff.max_job_lifetime,
+    );
+
+    self.shutdown_drain_timeout = easyenv::get_env_duration_seconds_or_default(
+      &env_name("SHUTDOWN_DRAIN_SECONDS"),
+      self.shutdown_drain_timeout,
+    );
+
+    self.http_bind_address = easyenv::get_env_string_or_default(
+      "HTTP_BIND_ADDRESS",
+      &self.http_bind_address,
+    );
+
+    self.http_num_workers = easyenv::get_env_num(
+      "HTTP_NUM_WORKERS",
+      self.http_num_workers,
+    )?;
+
+    self.consecutive_failure_unhealthy_threshold = easyenv::get_env_num(
+      "CONSECUTIVE_FAILURE_UNHEALTHY_THRESHOLD",
+      self.consecutive_failure_unhealthy_threshold,
+    )?;
+
+    Ok(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn env_overrides_use_prefix_and_keep_code_defaults() {
+    // NB: Unique prefix so this can't collide with other tests' environment.
+    unsafe {
+      std::env::set_var("JOB_RUNNER_CONFIG_TEST_MAX_CONCURRENT_POLLS", "3");
+      std::env::set_var("JOB_RUNNER_CONFIG_TEST_MAX_JOB_LIFETIME_SECONDS", "60");
+    }
+
+    let mut config = JobRunnerConfig::with_defaults("test-job");
+    config.poll_interval_success = Duration::from_millis(1_234);
+
+    let config = config.with_env_overrides("JOB_RUNNER_CONFIG_TEST").unwrap();
+
+    assert_eq!(config.max_concurrent_polls, 3);
+    assert_eq!(config.poll_backoff.max_job_lifetime, Duration::from_secs(60));
+    assert_eq!(config.poll_interval_success, Duration::from_millis(1_234));
+    assert_eq!(config.poll_interval_failure, Duration::from_secs(15));
+  }
+}
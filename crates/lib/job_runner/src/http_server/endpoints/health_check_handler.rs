@@ -5,6 +5,7 @@ use actix_web::http::header::ContentType;
 use actix_web::http::StatusCode;
 use actix_web::{web, HttpRequest, HttpResponse};
 use log::error;
+use serde::Serialize;
 
 use actix_helpers::response_serializers::error_to_json_http_response::error_to_json_http_response;
 use pager::notification::notification_details_builder::NotificationDetailsBuilder;
@@ -22,6 +23,19 @@ pub struct HealthCheckResponse {
   pub total_success_count: u64,
   pub total_failure_ratio: f32,
   pub total_success_ratio: f32,
+
+  /// Pending jobs found in the database on the last poll iteration.
+  pub pending_job_count: u64,
+  /// Jobs polled on the last iteration; the rest were backing off.
+  pub polled_job_count: u64,
+  pub deferred_job_count: u64,
+  /// Jobs failed for exceeding the maximum job lifetime since startup.
+  pub total_expired_job_count: u64,
+
+  /// Provider status call latency, over the most recent polls.
+  pub maybe_last_poll_latency_millis: Option<u64>,
+  pub maybe_average_poll_latency_millis: Option<u64>,
+  pub maybe_max_poll_latency_millis: Option<u64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -59,6 +73,14 @@ pub async fn get_health_check_handler(
       HealthCheckError::ServerError
     })?;
 
+  let poll_stats = server_state
+    .poll_stats
+    .get_status()
+    .map_err(|e| {
+      error!("Error serving health check poll stats: {:?}", e);
+      HealthCheckError::ServerError
+    })?;
+
   let total_tries = job_stats
     .total_failure_count
     .saturating_add(job_stats.total_success_count);
@@ -80,14 +102,12 @@ pub async fn get_health_check_handler(
 
   if !is_healthy {
     let notification = NotificationDetailsBuilder::from_title(
-          format!("Health check unhealthy on {}", server_state.hostname))
+          format!("Health check unhealthy on {}", server_state.app_name))
         .set_description(Some(format!(
           "Health check returned unhealthy.\n\n\
-             Hostname: {}\n\
              Consecutive failure count: {}\n\
              Total failure count: {}\n\
              Total success count: {}",
-          server_state.hostname,
           job_stats.consecutive_failure_count,
           job_stats.total_failure_count,
           job_stats.total_success_count,
@@ -111,6 +131,13 @@ pub async fn get_health_check_handler(
     total_success_count: job_stats.total_success_count,
     total_failure_ratio,
     total_success_ratio,
+    pending_job_count: poll_stats.pending_job_count,
+    polled_job_count: poll_stats.polled_job_count,
+    deferred_job_count: poll_stats.deferred_job_count,
+    total_expired_job_count: poll_stats.total_expired_job_count,
+    maybe_last_poll_latency_millis: poll_stats.maybe_last_poll_latency_millis,
+    maybe_average_poll_latency_millis: poll_stats.maybe_average_poll_latency_millis,
+    maybe_max_poll_latency_millis: poll_stats.maybe_max_poll_latency_millis,
   };
 
   let body = serde_json::to_string(&response).map_err(|_e| HealthCheckError::ServerError)?;
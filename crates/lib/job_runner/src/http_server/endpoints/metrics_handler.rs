@@ -0,0 +1,106 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use log::error;
+
+use jobs_common::job_stats::SuccessAndFailureStats;
+use jobs_common::poll_stats::PollStatsSnapshot;
+
+use crate::http_server::http_server_shared_state::HttpServerSharedState;
+
+/// Job and poll stats in the Prometheus text exposition format.
+pub async fn get_metrics_handler(
+  _http_request: HttpRequest,
+  server_state: web::Data<Arc<HttpServerSharedState>>,
+) -> HttpResponse {
+  let stats = server_state.job_stats.get_status()
+    .and_then(|job_stats| Ok((job_stats, server_state.poll_stats.get_status()?)));
+
+  let (job_stats, poll_stats) = match stats {
+    Ok(stats) => stats,
+    Err(err) => {
+      error!("Error serving metrics: {:?}", err);
+      return HttpResponse::InternalServerError().finish();
+    }
+  };
+
+  let body = render_metrics(
+    &server_state.app_name,
+    server_state.is_ready.get(),
+    &job_stats,
+    &poll_stats,
+  );
+
+  HttpResponse::Ok()
+    .content_type("text/plain; version=0.0.4")
+    .body(body)
+}
+
+fn render_metrics(
+  app_name: &str,
+  is_ready: bool,
+  job_stats: &SuccessAndFailureStats,
+  poll_stats: &PollStatsSnapshot,
+) -> String {
+  let mut metrics = vec![
+    ("job_runner_ready", "gauge", "Whether the main loop is running.", is_ready as u64),
+    ("job_runner_success_total", "counter", "Jobs completed successfully.", job_stats.total_success_count),
+    ("job_runner_failure_total", "counter", "Failed poll iterations and job processing attempts.", job_stats.total_failure_count),
+    ("job_runner_consecutive_failures", "gauge", "Failures since the last success.", job_stats.consecutive_failure_count),
+    ("job_runner_expired_total", "counter", "Jobs failed for exceeding the maximum job lifetime.", poll_stats.total_expired_job_count),
+    ("job_runner_pending_jobs", "gauge", "Pending jobs found on the last poll iteration.", poll_stats.pending_job_count),
+    ("job_runner_polled_jobs", "gauge", "Jobs polled on the last poll iteration.", poll_stats.polled_job_count),
+    ("job_runner_deferred_jobs", "gauge", "Jobs backing off on the last poll iteration.", poll_stats.deferred_job_count),
+  ];
+
+  if let Some(millis) = poll_stats.maybe_average_poll_latency_millis {
+    metrics.push(("job_runner_poll_latency_average_millis", "gauge", "Average provider poll latency over recent polls.", millis));
+  }
+
+  if let Some(millis) = poll_stats.maybe_max_poll_latency_millis {
+    metrics.push(("job_runner_poll_latency_max_millis", "gauge", "Max provider poll latency over recent polls.", millis));
+  }
+
+  let mut body = String::new();
+
+  for (name, metric_type, help, value) in metrics {
+    // NB: Writing to a String can't fail.
+    let _ = writeln!(body, "# HELP {} {}", name, help);
+    let _ = writeln!(body, "# TYPE {} {}", name, metric_type);
+    let _ = writeln!(body, "{}{{app=\"{}\"}} {}", name, app_name, value);
+  }
+
+  body
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn renders_labelled_metrics() {
+    let job_stats = SuccessAndFailureStats {
+      total_success_count: 7,
+      total_failure_count: 2,
+      consecutive_success_count: 1,
+      consecutive_failure_count: 0,
+      maybe_current_job: None,
+    };
+
+    let poll_stats = PollStatsSnapshot {
+      pending_job_count: 4,
+      maybe_average_poll_latency_millis: Some(120),
+      ..Default::default()
+    };
+
+    let body = render_metrics("gmicloud-job", true, &job_stats, &poll_stats);
+
+    assert!(body.contains("# TYPE job_runner_success_total counter\n"));
+    assert!(body.contains("job_runner_ready{app=\"gmicloud-job\"} 1\n"));
+    assert!(body.contains("job_runner_success_total{app=\"gmicloud-job\"} 7\n"));
+    assert!(body.contains("job_runner_pending_jobs{app=\"gmicloud-job\"} 4\n"));
+    assert!(body.contains("job_runner_poll_latency_average_millis{app=\"gmicloud-job\"} 120\n"));
+    assert!(!body.contains("job_runner_poll_latency_max_millis"));
+  }
+}
@@ -0,0 +1,3 @@
+pub mod health_check_handler;
+pub mod metrics_handler;
+pub mod readiness_handler;
@@ -0,0 +1,18 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+
+use crate::http_server::http_server_shared_state::HttpServerSharedState;
+
+/// Ready once the main loop is running. Goes unready as soon as shutdown starts,
+/// so k8s stops routing to the pod while in-flight jobs drain.
+pub async fn get_readiness_handler(
+  _http_request: HttpRequest,
+  server_state: web::Data<Arc<HttpServerSharedState>>,
+) -> HttpResponse {
+  if server_state.is_ready.get() && !server_state.shutdown.is_shutting_down() {
+    HttpResponse::Ok().body("ready")
+  } else {
+    HttpResponse::ServiceUnavailable().body("not ready")
+  }
+}
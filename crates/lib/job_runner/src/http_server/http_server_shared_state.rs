@@ -0,0 +1,22 @@
+use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
+use jobs_common::job_stats::JobStats;
+use jobs_common::poll_stats::PollStats;
+use pager::client::pager::Pager;
+
+use crate::shutdown::shutdown_signal::ShutdownSignal;
+
+#[derive(Clone)]
+pub struct HttpServerSharedState {
+  pub app_name: String,
+  pub job_stats: JobStats,
+  pub poll_stats: PollStats,
+  pub consecutive_failure_unhealthy_threshold: u64,
+
+  /// True while the main loop is running; false during startup and shutdown.
+  pub is_ready: RelaxedAtomicBool,
+
+  pub shutdown: ShutdownSignal,
+
+  /// Pages when the health check reports unhealthy.
+  pub pager: Pager,
+}
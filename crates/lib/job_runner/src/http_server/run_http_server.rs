@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use actix_web::dev::Server;
+use actix_web::middleware::Logger;
+use actix_web::{web, App, HttpResponse, HttpServer};
+use log::info;
+
+use errors::AnyhowResult;
+
+use crate::http_server::endpoints::health_check_handler::get_health_check_handler;
+use crate::http_server::endpoints::metrics_handler::get_metrics_handler;
+use crate::http_server::endpoints::readiness_handler::get_readiness_handler;
+use crate::http_server::http_server_shared_state::HttpServerSharedState;
+
+pub struct CreateServerArgs {
+  pub bind_address: String,
+  pub num_workers: usize,
+  pub server_state: HttpServerSharedState,
+}
+
+pub fn run_http_server(args: CreateServerArgs) -> AnyhowResult<Server> {
+  let server_state_arc = web::Data::new(Arc::new(args.server_state));
+
+  info!("Starting HTTP service (for k8s health checking) on {}.", args.bind_address);
+
+  let log_format = "[%{HOSTNAME}e] IP=[%{X-Forwarded-For}i] \"%r\" %s %b \"%{Referer}i\" \"%{User-Agent}i\" %T";
+
+  let handle = HttpServer::new(move || {
+    App::new()
+      .app_data(server_state_arc.clone())
+      .wrap(Logger::new(log_format)
+        .exclude("/_status")
+        .exclude("/_ready")
+        .exclude("/_metrics"))
+      .service(
+        web::resource("/")
+          .route(web::get().to(HttpResponse::Ok))
+          .route(web::head().to(HttpResponse::Ok)),
+      )
+      .service(
+        web::resource("/_status")
+          .route(web::get().to(get_health_check_handler))
+          .route(web::head().to(HttpResponse::Ok)),
+      )
+      .service(
+        web::resource("/_ready")
+          .route(web::get().to(get_readiness_handler))
+          .route(web::head().to(get_readiness_handler)),
+      )
+      .service(
+        web::resource("/_metrics")
+          .route(web::get().to(get_metrics_handler)),
+      )
+  })
+    .bind(args.bind_address)?
+    .workers(args.num_workers)
+    // Actix would otherwise install its own signal handlers; the runner owns shutdown.
+    .disable_signals()
+    .run();
+
+  Ok(handle)
+}
+
+pub async fn launch_http_server(args: CreateServerArgs) -> AnyhowResult<()> {
+  run_http_server(args)?.await?;
+  Ok(())
+}
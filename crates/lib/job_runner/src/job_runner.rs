@@ -0,0 +1,168 @@
+use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
+use errors::AnyhowResult;
+use futures::future::join_all;
+use jobs_common::job_stats::JobStats;
+use jobs_common::poll_stats::PollStats;
+use log::{info, warn};
+use pager::client::pager::Pager;
+use pager::worker::pager_worker::PagerWorker;
+
+use crate::config::job_runner_config::JobRunnerConfig;
+use crate::http_server::http_server_shared_state::HttpServerSharedState;
+use crate::http_server::run_http_server::{launch_http_server, CreateServerArgs};
+use crate::polling::periodic_task::{run_periodic_task, PeriodicTask};
+use crate::polling::polling_job::PollingJob;
+use crate::polling::polling_loop::run_polling_loop;
+use crate::shutdown::shutdown_signal::ShutdownSignal;
+
+/// Stats and shutdown state shared between the runner and the job.
+/// Clones share state.
+#[derive(Clone)]
+pub struct JobRunnerContext {
+  pub job_stats: JobStats,
+  pub poll_stats: PollStats,
+  pub shutdown: ShutdownSignal,
+}
+
+impl JobRunnerContext {
+  pub(crate) fn new() -> Self {
+    Self {
+      job_stats: JobStats::new(),
+      poll_stats: PollStats::new(),
+      shutdown: ShutdownSignal::new(),
+    }
+  }
+}
+
+/// Runs a `PollingJob` (or a set of `PeriodicTask`s) along with the pager worker and the
+/// health check server.
+///
+/// ```ignore
+/// let (pager, pager_worker) = build_pager(...);
+/// let runner = JobRunner::new(config, pager, pager_worker);
+/// let job = MyProviderJob { pager: runner.pager().clone(), ... };
+/// runner.run(job).await?;
+/// ```
+pub struct JobRunner {
+  config: JobRunnerConfig,
+  pager: Pager,
+  pager_worker: PagerWorker,
+  context: JobRunnerContext,
+}
+
+impl JobRunner {
+  pub fn new(config: JobRunnerConfig, pager: Pager, pager_worker: PagerWorker) -> Self {
+    let context = JobRunnerContext::new();
+    Self {
+      config,
+      pager,
+      pager_worker,
+      context,
+    }
+  }
+
+  pub fn config(&self) -> &JobRunnerConfig {
+    &self.config
+  }
+
+  pub fn pager(&self) -> &Pager {
+    &self.pager
+  }
+
+  pub fn context(&self) -> &JobRunnerContext {
+    &self.context
+  }
+
+  /// Run until SIGTERM / Ctrl-C (or the HTTP server dies), then drain in-flight jobs.
+  pub async fn run<J: PollingJob>(self, job: J) -> AnyhowResult<()> {
+    let JobRunner { config, pager, pager_worker, context } = self;
+
+    let is_ready = start_services(&config, &pager, pager_worker, &context);
+
+    run_polling_loop(&job, &config, &context).await;
+
+    finish(&config, &pager, &is_ready);
+    Ok(())
+  }
+
+  /// Like `run`, for workers built from `PeriodicTask`s instead of a single `PollingJob`.
+  /// The tasks run concurrently; each stops once its current iteration ends after shutdown.
+  pub async fn run_periodic_tasks(self, tasks: Vec<Box<dyn PeriodicTask>>) -> AnyhowResult<()> {
+    let JobRunner { config, pager, pager_worker, context } = self;
+
+    let is_ready = start_services(&config, &pager, pager_worker, &context);
+
+    let loops = tasks.into_iter()
+      .map(|mut task| {
+        let context = context.clone();
+        async move { run_periodic_task(task.as_mut(), &context).await }
+      });
+
+    join_all(loops).await;
+
+    finish(&config, &pager, &is_ready);
+    Ok(())
+  }
+}
+
+/// Spawn the pager worker and the HTTP server, and start listening for shutdown signals.
+/// Returns the readiness flag, already set.
+fn start_services(
+  config: &JobRunnerConfig,
+  pager: &Pager,
+  pager_worker: PagerWorker,
+  context: &JobRunnerContext,
+) -> RelaxedAtomicBool {
+  info!("Spawning pager worker.");
+
+  // NB: The pager worker blocks on a Condvar, so it gets its own OS thread.
+  std::thread::spawn(move || {
+    let rt = tokio::runtime::Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .expect("pager worker tokio runtime");
+    rt.block_on(pager_worker.run());
+  });
+
+  let is_ready = RelaxedAtomicBool::new(false);
+
+  let create_server_args = CreateServerArgs {
+    bind_address: config.http_bind_address.clone(),
+    num_workers: config.http_num_workers,
+    server_state: HttpServerSharedState {
+      app_name: config.app_name.clone(),
+      job_stats: context.job_stats.clone(),
+      poll_stats: context.poll_stats.clone(),
+      consecutive_failure_unhealthy_threshold: config.consecutive_failure_unhealthy_threshold,
+      is_ready: is_ready.clone(),
+      shutdown: context.shutdown.clone(),
+      pager: pager.clone(),
+    },
+  };
+
+  let server_shutdown = context.shutdown.clone();
+
+  std::thread::spawn(move || {
+    let actix_runtime = actix_web::rt::System::new();
+    let result = actix_runtime.block_on(launch_http_server(create_server_args));
+
+    warn!("Server thread is shut down: {:?}", result);
+    server_shutdown.trigger();
+  });
+
+  context.shutdown.listen_for_signals();
+
+  info!("{} is ready.", config.app_name);
+  is_ready.set(true);
+
+  is_ready
+}
+
+fn finish(config: &JobRunnerConfig, pager: &Pager, is_ready: &RelaxedAtomicBool) {
+  is_ready.set(false);
+
+  info!("Shutting down pager worker...");
+  pager.shutdown_worker();
+
+  info!("{} has shut down.", config.app_name);
+}
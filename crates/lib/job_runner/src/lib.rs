@@ -0,0 +1,22 @@
+//! job_runner
+//!
+//! Shared plumbing for the provider polling workers (gmicloud-job, worldlabs-job, ...):
+//! env config, pager wiring, the health/readiness/metrics HTTP server, graceful
+//! shutdown, and a polling loop driven by the `PollingJob` trait.
+//!
+//! A worker implements `PollingJob` for its provider and hands it to `JobRunner::run`.
+//! Workers whose loops don't poll one job at a time (order-list scans, backfills, account
+//! checks) implement `PeriodicTask` and use `JobRunner::run_periodic_tasks` instead.
+
+// Never allow these
+#![forbid(private_bounds)]
+#![forbid(private_interfaces)]
+#![forbid(unused_must_use)]
+
+pub mod alert_on_error;
+pub mod config;
+pub mod http_server;
+pub mod job_runner;
+pub mod polling;
+pub mod shutdown;
+pub mod startup;
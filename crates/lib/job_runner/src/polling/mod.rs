@@ -0,0 +1,3 @@
+pub mod periodic_task;
+pub mod polling_job;
+pub(crate) mod polling_loop;
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use log::{error, warn};
+
+use errors::AnyhowResult;
+
+use crate::job_runner::JobRunnerContext;
+
+/// A loop that doesn't fit `PollingJob`, eg. a provider that can only list all of its orders,
+/// a backfill over a table, or a periodic account check.
+///
+/// The runner calls `run_iteration` until shutdown, sleeping in between. Sleeps wake early
+/// when shutdown starts; long iterations should check `context.shutdown` themselves.
+#[async_trait]
+pub trait PeriodicTask: Send + 'static {
+  /// Human readable task name, eg. "Kinovi video polling". Used in logs.
+  fn name(&self) -> &'static str;
+
+  /// Run one iteration. Returns how long to sleep before the next one.
+  ///
+  /// On `Err` the runner logs the error, counts a failure in the job stats and sleeps for
+  /// `failure_delay`. Tasks that page on errors should do so before returning.
+  async fn run_iteration(&mut self, context: &JobRunnerContext) -> AnyhowResult<Duration>;
+
+  /// How long to sleep after a failed iteration.
+  fn failure_delay(&self) -> Duration;
+}
+
+pub(crate) async fn run_periodic_task(task: &mut dyn PeriodicTask, context: &JobRunnerContext) {
+  while !context.shutdown.is_shutting_down() {
+    let sleep_duration = match task.run_iteration(context).await {
+      Ok(sleep_duration) => sleep_duration,
+      Err(err) => {
+        error!("Error in {} iteration: {:?}", task.name(), err);
+        let _ = context.job_stats.increment_failure_count();
+        task.failure_delay()
+      }
+    };
+
+    context.shutdown.sleep(sleep_duration).await;
+  }
+
+  warn!("{} loop is shut down.", task.name());
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  use super::*;
+
+  struct CountingTask {
+    iterations: Arc<AtomicUsize>,
+    fail_every_other: bool,
+  }
+
+  #[async_trait]
+  impl PeriodicTask for CountingTask {
+    fn name(&self) -> &'static str { "Counting" }
+
+    async fn run_iteration(&mut self, context: &JobRunnerContext) -> AnyhowResult<Duration> {
+      let iteration = self.iterations.fetch_add(1, Ordering::SeqCst) + 1;
+      if iteration == 3 {
+        context.shutdown.trigger();
+      }
+      if self.fail_every_other && iteration % 2 == 0 {
+        return Err(anyhow::anyhow!("iteration {} failed", iteration));
+      }
+      Ok(Duration::from_millis(1))
+    }
+
+    fn failure_delay(&self) -> Duration {
+      Duration::from_millis(1)
+    }
+  }
+
+  #[tokio::test]
+  async fn runs_until_shutdown_and_counts_failures() {
+    let iterations = Arc::new(AtomicUsize::new(0));
+    let mut task = CountingTask { iterations: iterations.clone(), fail_every_other: true };
+    let context = JobRunnerContext::new();
+
+    tokio::time::timeout(Duration::from_secs(5), run_periodic_task(&mut task, &context)).await
+      .expect("loop should stop on shutdown");
+
+    assert_eq!(iterations.load(Ordering::SeqCst), 3);
+    assert_eq!(context.job_stats.get_status().unwrap().total_failure_count, 1);
+  }
+
+  #[tokio::test]
+  async fn does_not_start_after_shutdown() {
+    let iterations = Arc::new(AtomicUsize::new(0));
+    let mut task = CountingTask { iterations: iterations.clone(), fail_every_other: false };
+    let context = JobRunnerContext::new();
+
+    context.shutdown.trigger();
+    run_periodic_task(&mut task, &context).await;
+
+    assert_eq!(iterations.load(Ordering::SeqCst), 0);
+  }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use errors::AnyhowResult;
+use jobs_common::poll_backoff::ObservedJobState;
+
+/// A pending job row, as listed from the database.
+pub trait PendingJob: Send + Sync {
+  /// Our inference job token.
+  fn job_token(&self) -> &str;
+
+  /// The provider's id for the job (request id, operation id, order id, ...).
+  fn third_party_id(&self) -> &str;
+
+  /// When the job was created. Drives poll backoff and expiry.
+  fn created_at(&self) -> DateTime<Utc>;
+
+  fn maybe_user_token(&self) -> Option<&str> {
+    None
+  }
+}
+
+/// What the provider said about a job.
+pub enum PollStatus<T> {
+  /// Not done yet. Check again once the backoff elapses.
+  Pending(ObservedJobState),
+
+  /// Done. The output is handed to `PollingJob::on_success`.
+  Succeeded(T),
+
+  /// The provider gave up on the job.
+  Failed { reason: String },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobFailureCause {
+  /// The provider reported the job as failed.
+  Provider,
+
+  /// The job didn't finish within the maximum job lifetime.
+  Expired,
+}
+
+#[derive(Clone, Debug)]
+pub struct JobFailure {
+  /// User-safe failure reason, suitable for the job record.
+  pub reason: String,
+  pub cause: JobFailureCause,
+}
+
+/// A provider polling worker.
+///
+/// The runner lists pending jobs, polls the ones whose backoff has elapsed (a bounded
+/// number at a time), and calls the hooks as jobs finish. Jobs that outlive the maximum
+/// job lifetime are handed to `on_failure` with `JobFailureCause::Expired`.
+#[async_trait]
+pub trait PollingJob: Send + Sync + 'static {
+  type Job: PendingJob;
+  type Output: Send;
+
+  /// Human readable provider name, eg. "GmiCloud". Used in logs and failure reasons.
+  fn provider_name(&self) -> &'static str;
+
+  /// All non-terminal jobs for this provider.
+  async fn list_pending(&self) -> AnyhowResult<Vec<Self::Job>>;
+
+  /// Ask the provider about a single job. An `Err` is treated as a transient poll error.
+  async fn poll(&self, job: &Self::Job) -> AnyhowResult<PollStatus<Self::Output>>;
+
+  /// Store the result and mark the job complete. On `Err` the job stays pending and
+  /// processing is retried on a later poll; it is never expired.
+  async fn on_success(&self, job: &Self::Job, output: Self::Output) -> AnyhowResult<()>;
+
+  /// Mark the job failed (and refund it, etc.). Errors should be handled here.
+  async fn on_failure(&self, job: &Self::Job, failure: &JobFailure);
+}
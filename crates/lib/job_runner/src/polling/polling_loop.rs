@@ -0,0 +1,333 @@
+use std::time::Instant;
+
+use chrono::Utc;
+use futures::future;
+use futures::stream::{self, StreamExt};
+use log::{error, info, warn};
+
+use errors::AnyhowResult;
+use jobs_common::poll_backoff::{job_age_since, ObservedJobState, PollBackoff};
+
+use crate::config::job_runner_config::JobRunnerConfig;
+use crate::job_runner::JobRunnerContext;
+use crate::polling::polling_job::{JobFailure, JobFailureCause, PendingJob, PollStatus, PollingJob};
+
+pub(crate) async fn run_polling_loop<J: PollingJob>(
+  job: &J,
+  config: &JobRunnerConfig,
+  context: &JobRunnerContext,
+) {
+  let mut poll_backoff = PollBackoff::new(config.poll_backoff.clone());
+
+  while !context.shutdown.is_shutting_down() {
+    let result = run_poll_iteration(job, config, context, &mut poll_backoff).await;
+
+    let sleep_duration = match result {
+      Ok(_) => config.poll_interval_success,
+      Err(err) => {
+        error!("Error in {} poll iteration: {:?}", job.provider_name(), err);
+        let _ = context.job_stats.increment_failure_count();
+        config.poll_interval_failure
+      }
+    };
+
+    context.shutdown.sleep(sleep_duration).await;
+  }
+
+  warn!("{} job runner main loop is shut down.", job.provider_name());
+}
+
+pub(crate) async fn run_poll_iteration<J: PollingJob>(
+  job: &J,
+  config: &JobRunnerConfig,
+  context: &JobRunnerContext,
+  poll_backoff: &mut PollBackoff,
+) -> AnyhowResult<()> {
+  let pending_jobs = job.list_pending().await?;
+
+  poll_backoff.retain_pending(pending_jobs.iter().map(|pending| pending.job_token()));
+
+  let now = Instant::now();
+  let now_utc = Utc::now();
+
+  // Only poll jobs whose backoff has elapsed. Jobs past their lifetime are always due.
+  let due_jobs = pending_jobs.iter()
+    .map(|pending| (pending, job_age_since(pending.created_at(), now_utc)))
+    .filter(|(pending, job_age)| poll_backoff.is_due(pending.job_token(), *job_age, now))
+    .map(|(pending, job_age)| (pending, poll_backoff.is_expired(job_age)))
+    .collect::<Vec<_>>();
+
+  let _ = context.poll_stats.record_iteration(
+    pending_jobs.len(),
+    due_jobs.len(),
+    pending_jobs.len() - due_jobs.len(),
+  );
+
+  if due_jobs.is_empty() {
+    return Ok(());
+  }
+
+  info!("Polling {} of {} pending {} job(s).", due_jobs.len(), pending_jobs.len(), job.provider_name());
+
+  // Once shutdown starts, don't pick up any more jobs; let the in-flight ones finish.
+  let shutdown = context.shutdown.clone();
+
+  let poll_all = stream::iter(due_jobs)
+    .take_while(move |_| future::ready(!shutdown.is_shutting_down()))
+    .map(|(pending, is_expired)| async move {
+      (pending, poll_one(job, config, context, pending, is_expired).await)
+    })
+    .buffer_unordered(config.max_concurrent_polls.max(1))
+    .collect::<Vec<_>>();
+
+  let drain_deadline = async {
+    context.shutdown.wait().await;
+    tokio::time::sleep(config.shutdown_drain_timeout).await;
+  };
+
+  let results = tokio::select! {
+    results = poll_all => results,
+    _ = drain_deadline => {
+      warn!(
+        "{} jobs still in flight after the {:?} shutdown drain timeout. Abandoning them.",
+        job.provider_name(), config.shutdown_drain_timeout
+      );
+      return Ok(());
+    }
+  };
+
+  let polled_at = Instant::now();
+
+  for (pending, maybe_still_pending) in results {
+    match maybe_still_pending {
+      Some(observed) => poll_backoff.record_poll(pending.job_token(), observed, polled_at),
+      None => poll_backoff.forget(pending.job_token()),
+    }
+  }
+
+  Ok(())
+}
+
+/// Poll a single job and process it if it finished. Returns the observed state if the job
+/// is still pending afterwards (including when the poll or processing failed).
+async fn poll_one<J: PollingJob>(
+  job: &J,
+  config: &JobRunnerConfig,
+  context: &JobRunnerContext,
+  pending: &J::Job,
+  is_expired: bool,
+) -> Option<ObservedJobState> {
+  let poll_start = Instant::now();
+  let poll_result = job.poll(pending).await;
+  let _ = context.poll_stats.record_poll_latency(poll_start.elapsed());
+
+  let status = match poll_result {
+    Ok(status) => status,
+    Err(err) => {
+      warn!(
+        "Error polling {} job {} (third party id {}): {:?}",
+        job.provider_name(), pending.job_token(), pending.third_party_id(), err
+      );
+      return expire_or_keep(job, config, context, pending, is_expired, ObservedJobState::Unknown).await;
+    }
+  };
+
+  match status {
+    PollStatus::Pending(observed) => {
+      expire_or_keep(job, config, context, pending, is_expired, observed).await
+    }
+    PollStatus::Failed { reason } => {
+      info!(
+        "{} job {} (third party id {}) failed: {}",
+        job.provider_name(), pending.job_token(), pending.third_party_id(), reason
+      );
+      job.on_failure(pending, &JobFailure { reason, cause: JobFailureCause::Provider }).await;
+      None
+    }
+    PollStatus::Succeeded(output) => {
+      info!(
+        "{} job {} (third party id {}) completed, processing.",
+        job.provider_name(), pending.job_token(), pending.third_party_id()
+      );
+
+      if let Err(err) = job.on_success(pending, output).await {
+        warn!(
+          "Error processing completed {} job {}: {:?}",
+          job.provider_name(), pending.job_token(), err
+        );
+        let _ = context.job_stats.increment_failure_count();
+        // The provider finished, so retry processing rather than expiring the job.
+        return Some(ObservedJobState::Unknown);
+      }
+
+      let _ = context.job_stats.increment_success_count();
+      None
+    }
+  }
+}
+
+async fn expire_or_keep<J: PollingJob>(
+  job: &J,
+  config: &JobRunnerConfig,
+  context: &JobRunnerContext,
+  pending: &J::Job,
+  is_expired: bool,
+  observed: ObservedJobState,
+) -> Option<ObservedJobState> {
+  if !is_expired {
+    return Some(observed);
+  }
+
+  let job_age = job_age_since(pending.created_at(), Utc::now());
+
+  warn!(
+    "{} job {} (third party id {}) expired after {} minutes.",
+    job.provider_name(), pending.job_token(), pending.third_party_id(), job_age.as_secs() / 60
+  );
+
+  let _ = context.poll_stats.increment_expired_count();
+
+  let failure = JobFailure {
+    reason: expired_reason(job.provider_name(), config.poll_backoff.max_job_lifetime.as_secs() / 60),
+    cause: JobFailureCause::Expired,
+  };
+
+  job.on_failure(pending, &failure).await;
+  None
+}
+
+fn expired_reason(provider_name: &str, max_job_lifetime_minutes: u64) -> String {
+  format!("{} generation did not finish within {} minutes", provider_name, max_job_lifetime_minutes)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Mutex;
+  use std::time::Duration;
+
+  use async_trait::async_trait;
+  use chrono::{DateTime, Utc};
+
+  use super::*;
+
+  struct FakeJob {
+    token: String,
+    created_at: DateTime<Utc>,
+    outcome: &'static str,
+  }
+
+  impl PendingJob for FakeJob {
+    fn job_token(&self) -> &str { &self.token }
+    fn third_party_id(&self) -> &str { &self.token }
+    fn created_at(&self) -> DateTime<Utc> { self.created_at }
+  }
+
+  #[derive(Default)]
+  struct FakeProvider {
+    jobs: Vec<(&'static str, i64, &'static str)>,
+    successes: Mutex<Vec<String>>,
+    failures: Mutex<Vec<(String, JobFailureCause)>>,
+  }
+
+  #[async_trait]
+  impl PollingJob for FakeProvider {
+    type Job = FakeJob;
+    type Output = String;
+
+    fn provider_name(&self) -> &'static str { "Fake" }
+
+    async fn list_pending(&self) -> AnyhowResult<Vec<FakeJob>> {
+      Ok(self.jobs.iter()
+        .map(|(token, age_secs, outcome)| FakeJob {
+          token: token.to_string(),
+          created_at: Utc::now() - chrono::Duration::seconds(*age_secs),
+          outcome,
+        })
+        .collect())
+    }
+
+    async fn poll(&self, job: &FakeJob) -> AnyhowResult<PollStatus<String>> {
+      Ok(match job.outcome {
+        "success" => PollStatus::Succeeded(job.token.clone()),
+        "failed" => PollStatus::Failed { reason: "nope".to_string() },
+        _ => PollStatus::Pending(ObservedJobState::Running),
+      })
+    }
+
+    async fn on_success(&self, _job: &FakeJob, output: String) -> AnyhowResult<()> {
+      self.successes.lock().unwrap().push(output);
+      Ok(())
+    }
+
+    async fn on_failure(&self, job: &FakeJob, failure: &JobFailure) {
+      self.failures.lock().unwrap().push((job.token.clone(), failure.cause));
+    }
+  }
+
+  fn test_config() -> JobRunnerConfig {
+    let mut config = JobRunnerConfig::with_defaults("fake-job");
+    config.poll_backoff.initial_interval = Duration::from_secs(5);
+    config.poll_backoff.max_job_lifetime = Duration::from_secs(60 * 60);
+    config
+  }
+
+  #[tokio::test]
+  async fn dispatches_hooks_and_defers_young_jobs() {
+    let provider = FakeProvider {
+      jobs: vec![
+        ("young", 1, "success"),
+        ("done", 60, "success"),
+        ("failed", 60, "failed"),
+        ("running", 60, "running"),
+        ("stuck", 2 * 60 * 60, "running"),
+      ],
+      ..Default::default()
+    };
+    let config = test_config();
+    let context = JobRunnerContext::new();
+    let mut backoff = PollBackoff::new(config.poll_backoff.clone());
+
+    run_poll_iteration(&provider, &config, &context, &mut backoff).await.unwrap();
+
+    assert_eq!(*provider.successes.lock().unwrap(), vec!["done".to_string()]);
+
+    let mut failures = provider.failures.lock().unwrap().clone();
+    failures.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(failures, vec![
+      ("failed".to_string(), JobFailureCause::Provider),
+      ("stuck".to_string(), JobFailureCause::Expired),
+    ]);
+
+    let poll_stats = context.poll_stats.get_status().unwrap();
+    assert_eq!(poll_stats.pending_job_count, 5);
+    assert_eq!(poll_stats.polled_job_count, 4);
+    assert_eq!(poll_stats.deferred_job_count, 1);
+    assert_eq!(poll_stats.total_expired_job_count, 1);
+
+    assert_eq!(context.job_stats.get_status().unwrap().total_success_count, 1);
+  }
+
+  #[tokio::test]
+  async fn polls_nothing_after_shutdown() {
+    let provider = FakeProvider {
+      jobs: vec![("done", 60, "success")],
+      ..Default::default()
+    };
+    let config = test_config();
+    let context = JobRunnerContext::new();
+    let mut backoff = PollBackoff::new(config.poll_backoff.clone());
+
+    context.shutdown.trigger();
+    run_poll_iteration(&provider, &config, &context, &mut backoff).await.unwrap();
+
+    assert!(provider.successes.lock().unwrap().is_empty());
+  }
+
+  #[test]
+  fn expired_reason_mentions_provider_and_lifetime() {
+    assert_eq!(
+      expired_reason("GmiCloud", 180),
+      "GmiCloud generation did not finish within 180 minutes"
+    );
+  }
+}
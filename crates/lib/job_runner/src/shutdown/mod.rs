@@ -0,0 +1 @@
+pub mod shutdown_signal;
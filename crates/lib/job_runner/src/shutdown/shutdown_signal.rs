@@ -0,0 +1,137 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use concurrency::async_thread_kill_signal::AsyncThreadKillSignal;
+use log::{info, warn};
+use tokio::sync::Notify;
+
+/// Graceful shutdown for the runner.
+///
+/// Wraps an `AsyncThreadKillSignal` (so it can be checked from any thread) with a
+/// `Notify` so that sleeping tasks wake up as soon as shutdown starts.
+#[derive(Clone)]
+pub struct ShutdownSignal {
+  kill_signal: AsyncThreadKillSignal,
+  notify: Arc<Notify>,
+}
+
+impl ShutdownSignal {
+  pub fn new() -> Self {
+    Self {
+      kill_signal: AsyncThreadKillSignal::new(),
+      notify: Arc::new(Notify::new()),
+    }
+  }
+
+  /// Begin shutting down. Safe to call more than once.
+  pub fn trigger(&self) {
+    if let Err(err) = self.kill_signal.mark_thread_for_kill() {
+      warn!("Error marking shutdown: {:?}", err);
+    }
+    self.notify.notify_waiters();
+  }
+
+  pub fn is_shutting_down(&self) -> bool {
+    // NB: A poisoned lock means something already panicked, so shut down.
+    !self.kill_signal.is_alive().unwrap_or(false)
+  }
+
+  /// Resolves once shutdown has started.
+  pub async fn wait(&self) {
+    loop {
+      let notified = self.notify.notified();
+      tokio::pin!(notified);
+
+      // Register before checking, so a trigger in between can't be missed.
+      notified.as_mut().enable();
+
+      if self.is_shutting_down() {
+        return;
+      }
+
+      notified.await;
+    }
+  }
+
+  /// Sleep for `duration`, waking early if shutdown starts.
+  pub async fn sleep(&self, duration: Duration) {
+    tokio::select! {
+      _ = tokio::time::sleep(duration) => {}
+      _ = self.wait() => {}
+    }
+  }
+
+  /// Trigger shutdown on Ctrl-C or SIGTERM (which is what k8s sends).
+  pub fn listen_for_signals(&self) {
+    let shutdown = self.clone();
+
+    tokio::spawn(async move {
+      wait_for_signal().await;
+      info!("Received shutdown signal. Draining in-flight work...");
+      shutdown.trigger();
+    });
+  }
+}
+
+impl Default for ShutdownSignal {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() {
+  use tokio::signal::unix::{signal, SignalKind};
+
+  let mut sigterm = match signal(SignalKind::terminate()) {
+    Ok(sigterm) => sigterm,
+    Err(err) => {
+      warn!("Error listening for SIGTERM: {:?}", err);
+      let _ = tokio::signal::ctrl_c().await;
+      return;
+    }
+  };
+
+  tokio::select! {
+    _ = tokio::signal::ctrl_c() => {}
+    _ = sigterm.recv() => {}
+  }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_signal() {
+  if let Err(err) = tokio::signal::ctrl_c().await {
+    warn!("Error listening for shutdown signal: {:?}", err);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn trigger_wakes_sleepers() {
+    let shutdown = ShutdownSignal::new();
+    assert!(!shutdown.is_shutting_down());
+
+    let sleeper = {
+      let shutdown = shutdown.clone();
+      tokio::spawn(async move { shutdown.sleep(Duration::from_secs(600)).await })
+    };
+
+    shutdown.trigger();
+
+    tokio::time::timeout(Duration::from_secs(5), sleeper).await
+      .expect("sleeper should wake on shutdown")
+      .unwrap();
+
+    assert!(shutdown.is_shutting_down());
+  }
+
+  #[tokio::test]
+  async fn wait_returns_if_already_triggered() {
+    let shutdown = ShutdownSignal::new();
+    shutdown.trigger();
+    tokio::time::timeout(Duration::from_secs(5), shutdown.wait()).await.unwrap();
+  }
+}
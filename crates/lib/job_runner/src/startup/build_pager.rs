@@ -3,11 +3,14 @@ use pager::client::pager::Pager;
 use pager::client::pager_builder::PagerBuilder;
 use pager::worker::pager_worker::PagerWorker;
 use rootly_client::creds::rootly_api_key::RootlyApiKey;
-use rootly_config::services::ROOTLY_SERVICE_ID_SEEDANCE2_PRO_JOB;
 use rootly_config::urgencies::{ROOTLY_URGENCY_ID_HIGH, ROOTLY_URGENCY_ID_LOW, ROOTLY_URGENCY_ID_MEDIUM};
 use shared_env_var_config::paging::{env_enable_paging_default_false, env_optional_rootly_api_key, env_optional_rootly_notification_target_id, env_optional_rootly_notification_target_type};
 
+/// Build the pager from the `ENABLE_PAGING` / `ROOTLY_*` env vars.
+/// `rootly_service_id` comes from `rootly_config::services`.
 pub fn build_pager(
+  app_name: &str,
+  rootly_service_id: &str,
   server_environment: server_environment::ServerEnvironment,
   hostname: &str,
 ) -> (Pager, PagerWorker) {
@@ -22,10 +25,10 @@ pub fn build_pager(
   };
 
   let builder = PagerBuilder::new()
-    .application_name("gmicloud-job".to_string())
+    .application_name(app_name.to_string())
     .environment(environment.to_string())
     .hostname(hostname.to_string())
-    .service_id(ROOTLY_SERVICE_ID_SEEDANCE2_PRO_JOB.to_string());
+    .service_id(rootly_service_id.to_string());
 
   if !is_paging_enabled {
     warn!("ENABLE_PAGING is false. Pager will be NoOp.");
@@ -3,5 +3,8 @@
 pub mod job_progress_reporter;
 pub mod job_stats; // NB: This seems valuable for all jobs
 pub mod noop_logger;
+pub mod poll_backoff;
+pub mod poll_stats;
 pub mod redis_job_status_logger;
+pub mod refund_failed_job;
 pub mod semi_persistent_cache_dir;
\ No newline at end of file
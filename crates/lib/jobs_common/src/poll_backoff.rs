@@ -0,0 +1,248 @@
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+
+/// Older jobs are never polled more often than once per this fraction of their age.
+const AGE_INTERVAL_DIVISOR: u32 = 10;
+
+/// Cap on the exponent so the interval math can't overflow.
+const MAX_DOUBLINGS: u32 = 16;
+
+/// What a provider last told us about a job that hasn't finished yet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObservedJobState {
+  /// Waiting in the provider's queue. These back off one step further than running jobs.
+  Queued,
+  /// The provider is working on it.
+  Running,
+  /// The poll failed, or the provider didn't report a state.
+  Unknown,
+}
+
+#[derive(Clone, Debug)]
+pub struct PollBackoffConfig {
+  /// Jobs younger than this aren't polled, and it's the interval after a state change.
+  pub initial_interval: Duration,
+
+  /// Upper bound on the interval between polls of a single job.
+  pub max_interval: Duration,
+
+  /// Jobs older than this are failed (and refunded) if they still aren't done.
+  pub max_job_lifetime: Duration,
+}
+
+/// Per-job polling schedule for the provider polling loops.
+///
+/// Each time a job is observed in the same state as last time its interval doubles, starting
+/// from `initial_interval`. The interval is also never shorter than a tenth of the job's age,
+/// and never longer than `max_interval`. This is owned by the main loop and is not shared.
+pub struct PollBackoff {
+  config: PollBackoffConfig,
+  jobs: HashMap<String, JobPollState>,
+}
+
+struct JobPollState {
+  last_polled_at: Instant,
+  last_state: ObservedJobState,
+  unchanged_polls: u32,
+}
+
+impl PollBackoff {
+  pub fn new(config: PollBackoffConfig) -> Self {
+    Self {
+      config,
+      jobs: HashMap::new(),
+    }
+  }
+
+  pub fn config(&self) -> &PollBackoffConfig {
+    &self.config
+  }
+
+  /// Whether the job has outlived `max_job_lifetime`.
+  pub fn is_expired(&self, job_age: Duration) -> bool {
+    job_age >= self.config.max_job_lifetime
+  }
+
+  /// Whether the job should be polled now. Expired jobs are always due so they get a final
+  /// poll before being failed; they may have finished since we last looked.
+  pub fn is_due(&self, job_token: &str, job_age: Duration, now: Instant) -> bool {
+    if self.is_expired(job_age) {
+      return true;
+    }
+
+    match self.jobs.get(job_token) {
+      None => job_age >= self.config.initial_interval,
+      Some(state) => now.saturating_duration_since(state.last_polled_at) >= self.interval(state, job_age),
+    }
+  }
+
+  /// Record a poll of a job that is still pending.
+  pub fn record_poll(&mut self, job_token: &str, observed: ObservedJobState, now: Instant) {
+    match self.jobs.get_mut(job_token) {
+      Some(state) => {
+        if state.last_state == observed {
+          state.unchanged_polls = state.unchanged_polls.saturating_add(1);
+        } else {
+          state.last_state = observed;
+          state.unchanged_polls = 0;
+        }
+        state.last_polled_at = now;
+      }
+      None => {
+        self.jobs.insert(job_token.to_string(), JobPollState {
+          last_polled_at: now,
+          last_state: observed,
+          unchanged_polls: 0,
+        });
+      }
+    }
+  }
+
+  /// Stop tracking a job once it reaches a terminal state.
+  pub fn forget(&mut self, job_token: &str) {
+    self.jobs.remove(job_token);
+  }
+
+  /// Drop tracking for any job not in `pending_job_tokens` (eg. finished elsewhere or cancelled).
+  pub fn retain_pending<'a>(&mut self, pending_job_tokens: impl IntoIterator<Item = &'a str>) {
+    let pending = pending_job_tokens.into_iter().collect::<HashSet<_>>();
+    self.jobs.retain(|job_token, _| pending.contains(job_token.as_str()));
+  }
+
+  fn interval(&self, state: &JobPollState, job_age: Duration) -> Duration {
+    let mut doublings = state.unchanged_polls;
+    if state.last_state == ObservedJobState::Queued {
+      doublings = doublings.saturating_add(1);
+    }
+
+    let exponential = self.config.initial_interval
+      .saturating_mul(1 << doublings.min(MAX_DOUBLINGS));
+
+    let age_floor = job_age / AGE_INTERVAL_DIVISOR;
+
+    exponential.max(age_floor).min(self.config.max_interval)
+  }
+}
+
+/// Age of a job from its database `created_at`. Clock skew yields zero rather than an error.
+pub fn job_age_since(created_at: DateTime<Utc>, now: DateTime<Utc>) -> Duration {
+  (now - created_at).to_std().unwrap_or(Duration::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SECOND: Duration = Duration::from_secs(1);
+
+  fn backoff() -> PollBackoff {
+    PollBackoff::new(PollBackoffConfig {
+      initial_interval: 2 * SECOND,
+      max_interval: 60 * SECOND,
+      max_job_lifetime: 3600 * SECOND,
+    })
+  }
+
+  mod first_poll {
+    use super::*;
+
+    #[test]
+    fn just_submitted_jobs_wait() {
+      let backoff = backoff();
+      assert!(!backoff.is_due("job", SECOND, Instant::now()));
+      assert!(backoff.is_due("job", 2 * SECOND, Instant::now()));
+    }
+
+    #[test]
+    fn expired_jobs_are_always_due() {
+      let mut backoff = backoff();
+      let now = Instant::now();
+      backoff.record_poll("job", ObservedJobState::Running, now);
+      assert!(backoff.is_expired(3600 * SECOND));
+      assert!(backoff.is_due("job", 3600 * SECOND, now));
+    }
+  }
+
+  mod intervals {
+    use super::*;
+
+    #[test]
+    fn doubles_while_state_is_unchanged() {
+      let mut backoff = backoff();
+      let start = Instant::now();
+      let age = 10 * SECOND;
+
+      backoff.record_poll("job", ObservedJobState::Running, start);
+      assert!(!backoff.is_due("job", age, start + SECOND));
+      assert!(backoff.is_due("job", age, start + 2 * SECOND));
+
+      backoff.record_poll("job", ObservedJobState::Running, start);
+      assert!(!backoff.is_due("job", age, start + 3 * SECOND));
+      assert!(backoff.is_due("job", age, start + 4 * SECOND));
+    }
+
+    #[test]
+    fn queued_jobs_back_off_further() {
+      let mut backoff = backoff();
+      let start = Instant::now();
+      backoff.record_poll("job", ObservedJobState::Queued, start);
+      assert!(!backoff.is_due("job", 10 * SECOND, start + 3 * SECOND));
+      assert!(backoff.is_due("job", 10 * SECOND, start + 4 * SECOND));
+    }
+
+    #[test]
+    fn state_change_resets() {
+      let mut backoff = backoff();
+      let start = Instant::now();
+      for _ in 0..5 {
+        backoff.record_poll("job", ObservedJobState::Queued, start);
+      }
+      backoff.record_poll("job", ObservedJobState::Running, start);
+      assert!(backoff.is_due("job", 10 * SECOND, start + 2 * SECOND));
+    }
+
+    #[test]
+    fn older_jobs_are_polled_less() {
+      let mut backoff = backoff();
+      let start = Instant::now();
+      backoff.record_poll("job", ObservedJobState::Running, start);
+      assert!(!backoff.is_due("job", 300 * SECOND, start + 29 * SECOND));
+      assert!(backoff.is_due("job", 300 * SECOND, start + 30 * SECOND));
+    }
+
+    #[test]
+    fn capped_at_max_interval() {
+      let mut backoff = backoff();
+      let start = Instant::now();
+      for _ in 0..40 {
+        backoff.record_poll("job", ObservedJobState::Queued, start);
+      }
+      assert!(backoff.is_due("job", 3000 * SECOND, start + 60 * SECOND));
+    }
+  }
+
+  mod tracking {
+    use super::*;
+
+    #[test]
+    fn retain_pending_drops_finished_jobs() {
+      let mut backoff = backoff();
+      let start = Instant::now();
+      backoff.record_poll("a", ObservedJobState::Running, start);
+      backoff.record_poll("b", ObservedJobState::Running, start);
+      backoff.retain_pending(["a"]);
+      // "b" is treated as never polled again.
+      assert!(backoff.is_due("b", 2 * SECOND, start));
+      assert!(!backoff.is_due("a", 2 * SECOND, start));
+    }
+
+    #[test]
+    fn job_age_ignores_clock_skew() {
+      let now = Utc::now();
+      assert_eq!(job_age_since(now + chrono::Duration::seconds(5), now), Duration::ZERO);
+      assert_eq!(job_age_since(now - chrono::Duration::seconds(5), now), 5 * SECOND);
+    }
+  }
+}
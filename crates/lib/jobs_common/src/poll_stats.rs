@@ -0,0 +1,155 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use anyhow::anyhow;
+
+use errors::AnyhowResult;
+
+/// Number of recent provider polls the latency numbers are computed over.
+const LATENCY_WINDOW: usize = 100;
+
+/// Queue depth and provider poll latency for the health check.
+/// Like `JobStats`, this uses interior mutability to be easy to copy around.
+#[derive(Clone, Default)]
+pub struct PollStats {
+  inner: Arc<RwLock<PollStatsInner>>,
+}
+
+#[derive(Default)]
+struct PollStatsInner {
+  pending_job_count: u64,
+  polled_job_count: u64,
+  deferred_job_count: u64,
+  total_expired_job_count: u64,
+  recent_poll_latencies: VecDeque<Duration>,
+}
+
+/// Public result type.
+#[derive(Default, Debug, Clone)]
+pub struct PollStatsSnapshot {
+  /// Pending jobs found in the database on the last iteration.
+  pub pending_job_count: u64,
+
+  /// Jobs polled on the last iteration.
+  pub polled_job_count: u64,
+
+  /// Jobs skipped on the last iteration because they were backing off.
+  pub deferred_job_count: u64,
+
+  /// Jobs failed for exceeding the maximum job lifetime since startup.
+  pub total_expired_job_count: u64,
+
+  pub maybe_last_poll_latency_millis: Option<u64>,
+  pub maybe_average_poll_latency_millis: Option<u64>,
+  pub maybe_max_poll_latency_millis: Option<u64>,
+}
+
+impl PollStats {
+  pub fn new() -> Self {
+    Self {
+      inner: Arc::new(RwLock::new(PollStatsInner::default())),
+    }
+  }
+
+  pub fn get_status(&self) -> AnyhowResult<PollStatsSnapshot> {
+    // NB: lock errors can't be moved between threads, so we change their type
+    let lock = self.inner.read()
+        .map_err(|e| anyhow!("lock read error: {:?}", e))?;
+
+    let latencies = &lock.recent_poll_latencies;
+
+    let maybe_average_poll_latency_millis = if latencies.is_empty() {
+      None
+    } else {
+      let total = latencies.iter().sum::<Duration>();
+      Some((total / latencies.len() as u32).as_millis() as u64)
+    };
+
+    Ok(PollStatsSnapshot {
+      pending_job_count: lock.pending_job_count,
+      polled_job_count: lock.polled_job_count,
+      deferred_job_count: lock.deferred_job_count,
+      total_expired_job_count: lock.total_expired_job_count,
+      maybe_last_poll_latency_millis: latencies.back().map(|d| d.as_millis() as u64),
+      maybe_average_poll_latency_millis,
+      maybe_max_poll_latency_millis: latencies.iter().max().map(|d| d.as_millis() as u64),
+    })
+  }
+
+  /// Record the outcome of a poll iteration.
+  pub fn record_iteration(&self, pending_job_count: usize, polled_job_count: usize, deferred_job_count: usize) -> AnyhowResult<()> {
+    let mut lock = self.inner.write()
+        .map_err(|e| anyhow!("lock error: {:?}", e))?;
+
+    lock.pending_job_count = pending_job_count as u64;
+    lock.polled_job_count = polled_job_count as u64;
+    lock.deferred_job_count = deferred_job_count as u64;
+
+    Ok(())
+  }
+
+  /// Record how long a single provider status call took.
+  pub fn record_poll_latency(&self, latency: Duration) -> AnyhowResult<()> {
+    let mut lock = self.inner.write()
+        .map_err(|e| anyhow!("lock error: {:?}", e))?;
+
+    if lock.recent_poll_latencies.len() >= LATENCY_WINDOW {
+      lock.recent_poll_latencies.pop_front();
+    }
+
+    lock.recent_poll_latencies.push_back(latency);
+
+    Ok(())
+  }
+
+  pub fn increment_expired_count(&self) -> AnyhowResult<()> {
+    let mut lock = self.inner.write()
+        .map_err(|e| anyhow!("lock error: {:?}", e))?;
+
+    lock.total_expired_job_count = lock.total_expired_job_count.saturating_add(1);
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_latency_before_first_poll() {
+    let stats = PollStats::new();
+    let status = stats.get_status().unwrap();
+    assert_eq!(status.maybe_average_poll_latency_millis, None);
+    assert_eq!(status.maybe_max_poll_latency_millis, None);
+  }
+
+  #[test]
+  fn latency_window() {
+    let stats = PollStats::new();
+    stats.record_poll_latency(Duration::from_millis(5_000)).unwrap();
+    for _ in 0..LATENCY_WINDOW {
+      stats.record_poll_latency(Duration::from_millis(100)).unwrap();
+    }
+    stats.record_poll_latency(Duration::from_millis(300)).unwrap();
+
+    let status = stats.get_status().unwrap();
+    assert_eq!(status.maybe_last_poll_latency_millis, Some(300));
+    assert_eq!(status.maybe_average_poll_latency_millis, Some(102));
+    assert_eq!(status.maybe_max_poll_latency_millis, Some(300));
+  }
+
+  #[test]
+  fn clones_share_stats() {
+    let stats = PollStats::new();
+    stats.clone().record_iteration(10, 4, 6).unwrap();
+    stats.clone().increment_expired_count().unwrap();
+
+    let status = stats.get_status().unwrap();
+    assert_eq!(status.pending_job_count, 10);
+    assert_eq!(status.polled_job_count, 4);
+    assert_eq!(status.deferred_job_count, 6);
+    assert_eq!(status.total_expired_job_count, 1);
+  }
+}
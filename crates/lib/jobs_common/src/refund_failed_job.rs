@@ -0,0 +1,227 @@
+//! Refund the wallet charge of a job that a provider reported as failed.
+//!
+//! The polling jobs refund *before* marking the job failed, so that a crash or error between
+//! the two can never leave a failed job with no refund. If the refund fails with a transient
+//! (database) error, the caller must leave the job pending so it is retried on the next poll
+//! cycle. Permanent errors will never succeed on retry, so the caller should alert and mark the
+//! job failed anyway.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use log::{error, info, warn};
+use sqlx::MySqlPool;
+
+use mysql_queries::errors::select_exactly_one_error::SelectExactlyOneError;
+use mysql_queries::queries::wallets::refund::try_to_refund_ledger_entry::{try_to_refund_ledger_entry, WalletRefundOutcome};
+use mysql_queries::queries::wallets::refund::wallet_refund_error::WalletRefundError;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+use tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RefundFailedJobOutcome {
+  /// The charge was refunded.
+  Refunded { refund_amount: u64 },
+
+  /// The charge had already been refunded (e.g. on an earlier poll that crashed before marking the job).
+  AlreadyRefunded,
+
+  /// The job has no ledger entry, e.g. it was submitted before billing was wired up.
+  NotCharged,
+}
+
+#[derive(Debug)]
+pub enum RefundFailedJobError {
+  BeginTransaction(sqlx::Error),
+  Refund(WalletRefundError),
+  Commit(sqlx::Error),
+}
+
+impl RefundFailedJobError {
+  /// Whether the refund might succeed on a later poll. If not, retrying would just re-poll and
+  /// re-refund the job forever, so the job should be marked failed anyway.
+  pub fn is_transient(&self) -> bool {
+    match self {
+      Self::BeginTransaction(_) => true,
+      Self::Commit(_) => true,
+      Self::Refund(err) => match err {
+        WalletRefundError::SqlxError(_) => true,
+        WalletRefundError::SelectError(SelectExactlyOneError::DatabaseError(_)) => true,
+        WalletRefundError::SelectError(SelectExactlyOneError::NotFound) => false,
+        WalletRefundError::LedgerEntryNotFound => false,
+        WalletRefundError::WalletNotFound => false,
+        WalletRefundError::NotADeductEntry(_) => false,
+      },
+    }
+  }
+}
+
+impl Error for RefundFailedJobError {}
+
+impl Display for RefundFailedJobError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::BeginTransaction(err) => write!(f, "failed to begin refund transaction: {}", err),
+      Self::Refund(err) => write!(f, "failed to refund ledger entry: {}", err),
+      Self::Commit(err) => write!(f, "failed to commit refund transaction: {}", err),
+    }
+  }
+}
+
+/// Refund a failed job's charge. On a transient error the job should NOT be marked failed yet.
+pub async fn refund_failed_job(
+  mysql_pool: &MySqlPool,
+  job_token: &InferenceJobToken,
+  maybe_ledger_entry_token: Option<&WalletLedgerEntryToken>,
+) -> Result<RefundFailedJobOutcome, RefundFailedJobError> {
+  let ledger_token = match maybe_ledger_entry_token {
+    Some(token) => token,
+    None => {
+      warn!("Job {} has no wallet ledger entry token; skipping refund.", job_token.as_str());
+      return Ok(RefundFailedJobOutcome::NotCharged);
+    }
+  };
+
+  let mut transaction = mysql_pool.begin()
+      .await
+      .map_err(|err| {
+        error!(
+          "Failed to begin refund transaction for job {} (ledger {}): {:?}. \
+           Job will NOT be marked failed yet and will be retried next poll.",
+          job_token.as_str(), ledger_token.as_str(), err
+        );
+        RefundFailedJobError::BeginTransaction(err)
+      })?;
+
+  let refund_result = try_to_refund_ledger_entry(ledger_token, &mut transaction).await;
+
+  match settle_refund(refund_result) {
+    RefundSettlement::Commit(outcome) => {
+      transaction.commit()
+          .await
+          .map_err(|err| {
+            error!(
+              "Failed to commit refund transaction for job {} (ledger {}): {:?}. \
+               Job will NOT be marked failed yet and will be retried next poll.",
+              job_token.as_str(), ledger_token.as_str(), err
+            );
+            RefundFailedJobError::Commit(err)
+          })?;
+      info!("Refunded failed job {} (ledger {}): {:?}", job_token.as_str(), ledger_token.as_str(), outcome);
+      Ok(outcome)
+    }
+    RefundSettlement::Rollback(outcome) => {
+      info!(
+        "Ledger entry {} for job {} was already refunded; proceeding to mark job failed.",
+        ledger_token.as_str(), job_token.as_str()
+      );
+      let _ = transaction.rollback().await;
+      Ok(outcome)
+    }
+    RefundSettlement::Abort(err) => {
+      let err = RefundFailedJobError::Refund(err);
+      if err.is_transient() {
+        error!(
+          "Failed to refund ledger entry {} for job {}: {:?}. \
+           Job will NOT be marked failed yet and will be retried next poll.",
+          ledger_token.as_str(), job_token.as_str(), err
+        );
+      } else {
+        error!(
+          "Failed to refund ledger entry {} for job {}: {:?}. \
+           This won't succeed on retry; the job should be marked failed without a refund.",
+          ledger_token.as_str(), job_token.as_str(), err
+        );
+      }
+      let _ = transaction.rollback().await;
+      Err(err)
+    }
+  }
+}
+
+/// What to do with the refund transaction once the refund query has run.
+#[derive(Debug)]
+enum RefundSettlement {
+  Commit(RefundFailedJobOutcome),
+  Rollback(RefundFailedJobOutcome),
+  Abort(WalletRefundError),
+}
+
+fn settle_refund(result: Result<WalletRefundOutcome, WalletRefundError>) -> RefundSettlement {
+  match result {
+    Ok(WalletRefundOutcome::Refunded(summary)) => RefundSettlement::Commit(RefundFailedJobOutcome::Refunded {
+      refund_amount: summary.refund_amount,
+    }),
+    // Idempotent: nothing was written, so there's nothing to commit.
+    Ok(WalletRefundOutcome::AlreadyRefunded) => RefundSettlement::Rollback(RefundFailedJobOutcome::AlreadyRefunded),
+    Err(err) => RefundSettlement::Abort(err),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::by_table::wallet_ledger_entries::wallet_ledger_entry_type::WalletLedgerEntryType;
+  use mysql_queries::queries::wallets::refund::try_to_refund_ledger_entry::WalletRefundSummary;
+  use tokens::tokens::wallets::WalletToken;
+
+  fn summary(refund_amount: u64) -> WalletRefundSummary {
+    WalletRefundSummary {
+      wallet_token: WalletToken::new_from_str("wallet_test"),
+      original_ledger_entry_token: WalletLedgerEntryToken::new_from_str("wle_original"),
+      refund_ledger_entry_token: WalletLedgerEntryToken::new_from_str("wle_refund"),
+      refund_amount,
+      banked_credits_before: 10,
+      banked_credits_after: 10 + refund_amount,
+      maybe_released_personal_access_token: None,
+    }
+  }
+
+  #[test]
+  fn refunded_is_committed() {
+    match settle_refund(Ok(WalletRefundOutcome::Refunded(summary(150)))) {
+      RefundSettlement::Commit(outcome) => assert_eq!(outcome, RefundFailedJobOutcome::Refunded { refund_amount: 150 }),
+      other => panic!("unexpected settlement: {:?}", other),
+    }
+  }
+
+  #[test]
+  fn already_refunded_is_rolled_back_and_proceeds() {
+    match settle_refund(Ok(WalletRefundOutcome::AlreadyRefunded)) {
+      RefundSettlement::Rollback(outcome) => assert_eq!(outcome, RefundFailedJobOutcome::AlreadyRefunded),
+      other => panic!("unexpected settlement: {:?}", other),
+    }
+  }
+
+  #[test]
+  fn refund_errors_roll_back_the_transaction() {
+    match settle_refund(Err(WalletRefundError::WalletNotFound)) {
+      RefundSettlement::Abort(WalletRefundError::WalletNotFound) => {}
+      other => panic!("unexpected settlement: {:?}", other),
+    }
+  }
+
+  #[test]
+  fn database_errors_are_transient() {
+    assert!(RefundFailedJobError::BeginTransaction(sqlx::Error::PoolTimedOut).is_transient());
+    assert!(RefundFailedJobError::Commit(sqlx::Error::PoolTimedOut).is_transient());
+    assert!(RefundFailedJobError::Refund(WalletRefundError::SqlxError(sqlx::Error::PoolTimedOut)).is_transient());
+    assert!(RefundFailedJobError::Refund(WalletRefundError::SelectError(
+      SelectExactlyOneError::DatabaseError(sqlx::Error::PoolClosed)
+    )).is_transient());
+  }
+
+  #[test]
+  fn missing_or_invalid_ledger_state_is_permanent() {
+    assert!(!RefundFailedJobError::Refund(WalletRefundError::LedgerEntryNotFound).is_transient());
+    assert!(!RefundFailedJobError::Refund(WalletRefundError::WalletNotFound).is_transient());
+    assert!(!RefundFailedJobError::Refund(WalletRefundError::NotADeductEntry(WalletLedgerEntryType::CreditBanked)).is_transient());
+    assert!(!RefundFailedJobError::Refund(WalletRefundError::SelectError(SelectExactlyOneError::NotFound)).is_transient());
+  }
+
+  #[test]
+  fn error_display() {
+    let err = RefundFailedJobError::Refund(WalletRefundError::LedgerEntryNotFound);
+    assert_eq!(err.to_string(), "failed to refund ledger entry: Ledger entry not found");
+  }
+}
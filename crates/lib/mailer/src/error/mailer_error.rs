@@ -0,0 +1,33 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Top-level error type for the mailer library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MailerError {
+  /// Worth trying again later (network errors, rate limits, provider 5xx, SMTP 4xx).
+  Transient(String),
+
+  /// Retrying won't help (bad address, malformed message, rejected by the provider).
+  Permanent(String),
+
+  /// The outbound email table couldn't be read or written.
+  Queue(String),
+}
+
+impl MailerError {
+  pub fn is_retryable(&self) -> bool {
+    matches!(self, Self::Transient(_))
+  }
+}
+
+impl Error for MailerError {}
+
+impl Display for MailerError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Transient(msg) => write!(f, "Transient mailer error: {}", msg),
+      Self::Permanent(msg) => write!(f, "Permanent mailer error: {}", msg),
+      Self::Queue(msg) => write!(f, "Outbound email queue error: {}", msg),
+    }
+  }
+}
@@ -0,0 +1 @@
+pub mod mailer_error;
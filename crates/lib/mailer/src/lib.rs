@@ -0,0 +1,12 @@
+//! mailer
+//!
+//! Transactional email: typed, branded messages, a `Mailer` trait with Resend, SMTP,
+//! and local (file / in-memory) backends, and an outbound queue (the `outbound_emails` table)
+//! that retries.
+//!
+
+pub mod error;
+pub mod message;
+pub mod queue;
+pub mod templates;
+pub mod transport;
@@ -0,0 +1,33 @@
+/// A fully rendered email, ready for any `Mailer`.
+///
+/// Usually built from a typed message with `EmailTemplate::render`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutboundEmail {
+  /// Eg. `ArtCraft <noreply@getartcraft.com>`
+  pub from: String,
+
+  pub to: Vec<String>,
+
+  pub maybe_reply_to: Option<String>,
+
+  pub subject: String,
+
+  pub html_body: String,
+
+  /// Plain text alternative for clients that don't render HTML.
+  pub text_body: String,
+
+  /// Stays the same across retries, so providers that support it (Resend) don't
+  /// deliver the same email twice.
+  pub idempotency_key: String,
+
+  /// Eg. "password_reset". For logs.
+  pub category: String,
+}
+
+/// Result of a successfully sent email.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SentEmail {
+  /// A backend-specific identifier for the sent email, if the backend provides one.
+  pub maybe_provider_message_id: Option<String>,
+}
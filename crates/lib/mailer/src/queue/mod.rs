@@ -0,0 +1,3 @@
+pub mod outbound_email_queue;
+pub mod outbound_email_worker;
+pub mod retry_policy;
@@ -0,0 +1,78 @@
+use log::{debug, info};
+use sqlx::MySqlPool;
+
+use mysql_queries::queries::outbound_emails::insert_outbound_email::{insert_outbound_email, InsertOutboundEmailArgs};
+
+use crate::error::mailer_error::MailerError;
+use crate::message::outbound_email::OutboundEmail;
+
+/// The main programmer interface for sending email. Enqueueing writes the rendered email
+/// to the `outbound_emails` table; an `OutboundEmailWorker` (on any host) sends it and
+/// retries transient failures. Queued email survives restarts and deploys.
+///
+/// Cheap to clone.
+#[derive(Clone)]
+pub struct OutboundEmailQueue {
+  mysql_pool: MySqlPool,
+}
+
+impl OutboundEmailQueue {
+  pub fn new(mysql_pool: MySqlPool) -> Self {
+    Self { mysql_pool }
+  }
+
+  /// Enqueueing the same email (same idempotency key) twice only sends it once.
+  pub async fn enqueue(&self, email: &OutboundEmail) -> Result<(), MailerError> {
+    debug!("Enqueueing {} email ({})", email.category, email.idempotency_key);
+
+    let to_addresses_json = encode_recipients(&email.to)?;
+
+    let inserted = insert_outbound_email(InsertOutboundEmailArgs {
+      idempotency_key: &email.idempotency_key,
+      email_category: &email.category,
+      from_address: &email.from,
+      to_addresses_json: &to_addresses_json,
+      maybe_reply_to_address: email.maybe_reply_to.as_deref(),
+      subject: &email.subject,
+      html_body: &email.html_body,
+      text_body: &email.text_body,
+    }, &self.mysql_pool)
+        .await
+        .map_err(|err| MailerError::Queue(format!("could not insert email: {}", err)))?;
+
+    if !inserted {
+      info!("{} email ({}) was already enqueued", email.category, email.idempotency_key);
+    }
+
+    Ok(())
+  }
+}
+
+pub(crate) fn encode_recipients(to: &[String]) -> Result<String, MailerError> {
+  serde_json::to_string(to)
+      .map_err(|err| MailerError::Queue(format!("could not encode recipients: {}", err)))
+}
+
+pub(crate) fn decode_recipients(json: &str) -> Result<Vec<String>, MailerError> {
+  serde_json::from_str(json)
+      .map_err(|err| MailerError::Queue(format!("could not decode recipients: {}", err)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn recipients_round_trip() {
+    let to = vec!["a@example.com".to_string(), "b@example.com".to_string()];
+    let json = encode_recipients(&to).unwrap();
+
+    assert_eq!(json, r#"["a@example.com","b@example.com"]"#);
+    assert_eq!(decode_recipients(&json).unwrap(), to);
+  }
+
+  #[test]
+  fn bad_recipients_are_an_error() {
+    assert!(decode_recipients("a@example.com").is_err());
+  }
+}
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{error, info, warn};
+use sqlx::MySqlPool;
+
+use mysql_queries::queries::outbound_emails::_keys::OutboundEmailId;
+use mysql_queries::queries::outbound_emails::claim_outbound_email::claim_outbound_email;
+use mysql_queries::queries::outbound_emails::list_due_outbound_emails::{list_due_outbound_emails, DueOutboundEmail};
+use mysql_queries::queries::outbound_emails::mark_outbound_email_failed::{mark_outbound_email_failed, OutboundEmailFailure};
+use mysql_queries::queries::outbound_emails::mark_outbound_email_sent::mark_outbound_email_sent;
+
+use crate::error::mailer_error::MailerError;
+use crate::message::outbound_email::{OutboundEmail, SentEmail};
+use crate::queue::outbound_email_queue::decode_recipients;
+use crate::queue::retry_policy::{RetryDecision, RetryPolicy};
+use crate::transport::mailer::Mailer;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const BATCH_SIZE: u32 = 20;
+
+/// How long a worker's claim on an email lasts. If the worker dies mid-send, another
+/// worker picks the email up after this. Much longer than any provider call should take.
+const CLAIM_DURATION: Duration = Duration::from_secs(60 * 5);
+
+/// Sends emails from the `outbound_emails` table, rescheduling transient failures.
+///
+/// Any number of workers can run at once (eg. one per web server); each email is
+/// claimed before it's sent.
+pub struct OutboundEmailWorker {
+  mysql_pool: MySqlPool,
+  mailer: Arc<dyn Mailer>,
+  retry_policy: RetryPolicy,
+}
+
+/// The result of one send attempt.
+#[derive(Debug, PartialEq, Eq)]
+enum AttemptOutcome {
+  Sent(SentEmail),
+  RetryAfter { backoff: Duration, error: MailerError },
+  GiveUp { error: MailerError },
+}
+
+impl OutboundEmailWorker {
+  pub fn new(mysql_pool: MySqlPool, mailer: Arc<dyn Mailer>, retry_policy: RetryPolicy) -> Self {
+    Self {
+      mysql_pool,
+      mailer,
+      retry_policy,
+    }
+  }
+
+  /// Poll for due emails forever.
+  pub async fn run(self) {
+    info!("Outbound email worker started (mailer: {}).", self.mailer.name());
+
+    loop {
+      let sleep = match self.send_due_emails().await {
+        // A full batch means there's probably more waiting.
+        Ok(count) if count >= BATCH_SIZE as usize => Duration::ZERO,
+        Ok(_) => POLL_INTERVAL,
+        Err(err) => {
+          error!("Error polling outbound emails: {}", err);
+          POLL_INTERVAL
+        }
+      };
+
+      tokio::time::sleep(sleep).await;
+    }
+  }
+
+  /// Send one batch of due emails. Returns how many were due.
+  pub async fn send_due_emails(&self) -> Result<usize, MailerError> {
+    let due_emails = list_due_outbound_emails(BATCH_SIZE, &self.mysql_pool)
+        .await
+        .map_err(|err| MailerError::Queue(format!("could not list due emails: {}", err)))?;
+
+    let count = due_emails.len();
+
+    for row in due_emails {
+      if let Err(err) = self.process_row(row).await {
+        error!("Error processing outbound email: {}", err);
+      }
+    }
+
+    Ok(count)
+  }
+
+  async fn process_row(&self, row: DueOutboundEmail) -> Result<(), MailerError> {
+    let id = row.id;
+    let attempts_made = row.attempt_count as u32;
+
+    // Only reachable if every attempt so far died mid-send (eg. the worker crashed).
+    if !self.retry_policy.has_attempts_left(attempts_made) {
+      warn!("Giving up on {} email ({}) after {} unfinished attempt(s)", row.email_category, row.idempotency_key, attempts_made);
+      return self.mark_failed(id, OutboundEmailFailure::Dead {
+        error: "ran out of attempts without a send finishing",
+      }).await;
+    }
+
+    let claimed = claim_outbound_email(id, CLAIM_DURATION.as_secs(), &self.mysql_pool)
+        .await
+        .map_err(|err| MailerError::Queue(format!("could not claim email: {}", err)))?;
+
+    if !claimed {
+      // Another worker got it.
+      return Ok(());
+    }
+
+    let email = match email_from_row(row) {
+      Ok(email) => email,
+      Err(err) => {
+        return self.mark_failed(id, OutboundEmailFailure::Dead { error: &err.to_string() }).await;
+      }
+    };
+
+    // NB: If the send succeeds but recording it fails, the claim expires and the email is
+    // retried. Providers that honor the idempotency key (Resend) won't deliver it twice.
+    match attempt_send(self.mailer.as_ref(), &self.retry_policy, &email, attempts_made + 1).await {
+      AttemptOutcome::Sent(sent) => {
+        mark_outbound_email_sent(id, sent.maybe_provider_message_id.as_deref(), &self.mysql_pool)
+            .await
+            .map_err(|err| MailerError::Queue(format!("could not mark email sent: {}", err)))
+      }
+      AttemptOutcome::RetryAfter { backoff, error } => {
+        self.mark_failed(id, OutboundEmailFailure::Retry {
+          retry_after_seconds: backoff.as_secs(),
+          error: &error.to_string(),
+        }).await
+      }
+      AttemptOutcome::GiveUp { error } => {
+        self.mark_failed(id, OutboundEmailFailure::Dead { error: &error.to_string() }).await
+      }
+    }
+  }
+
+  async fn mark_failed(&self, id: OutboundEmailId, failure: OutboundEmailFailure<'_>) -> Result<(), MailerError> {
+    mark_outbound_email_failed(id, failure, &self.mysql_pool)
+        .await
+        .map_err(|err| MailerError::Queue(format!("could not mark email failed: {}", err)))
+  }
+}
+
+async fn attempt_send(
+  mailer: &dyn Mailer,
+  retry_policy: &RetryPolicy,
+  email: &OutboundEmail,
+  attempt: u32,
+) -> AttemptOutcome {
+  let error = match mailer.send(email).await {
+    Ok(sent) => {
+      info!(
+        "Sent {} email ({}) via {} on attempt {}: {:?}",
+        email.category, email.idempotency_key, mailer.name(), attempt, sent.maybe_provider_message_id,
+      );
+      return AttemptOutcome::Sent(sent);
+    }
+    Err(error) => error,
+  };
+
+  match retry_policy.decide(attempt, &error) {
+    RetryDecision::RetryAfter(backoff) => {
+      warn!(
+        "Failed to send {} email ({}) on attempt {}; retrying in {:?}: {}",
+        email.category, email.idempotency_key, attempt, backoff, error,
+      );
+      AttemptOutcome::RetryAfter { backoff, error }
+    }
+    RetryDecision::GiveUp => {
+      error!(
+        "Giving up on {} email ({}) to {:?} after {} attempt(s): {}",
+        email.category, email.idempotency_key, email.to, attempt, error,
+      );
+      AttemptOutcome::GiveUp { error }
+    }
+  }
+}
+
+fn email_from_row(row: DueOutboundEmail) -> Result<OutboundEmail, MailerError> {
+  Ok(OutboundEmail {
+    from: row.from_address,
+    to: decode_recipients(&row.to_addresses_json)?,
+    maybe_reply_to: row.maybe_reply_to_address,
+    subject: row.subject,
+    html_body: row.html_body,
+    text_body: row.text_body,
+    idempotency_key: row.idempotency_key,
+    category: row.email_category,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::transport::in_memory_mailer::InMemoryMailer;
+  use crate::templates::email_branding::EmailBranding;
+  use crate::templates::email_template::EmailTemplate;
+  use crate::templates::messages::email_verification_email::EmailVerificationEmail;
+
+  use super::*;
+
+  fn retries(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+      max_attempts,
+      initial_backoff: Duration::from_secs(10),
+      max_backoff: Duration::from_secs(60),
+    }
+  }
+
+  fn email() -> OutboundEmail {
+    EmailVerificationEmail { verification_url: "https://example.com/verify?token=abc".to_string() }
+        .render(EmailBranding::ArtCraft, "user@example.com")
+  }
+
+  #[tokio::test]
+  async fn sends_the_email() {
+    let mailer = InMemoryMailer::new();
+    let email = email();
+
+    let outcome = attempt_send(&mailer, &retries(3), &email, 1).await;
+
+    assert_eq!(outcome, AttemptOutcome::Sent(SentEmail::default()));
+    assert_eq!(mailer.sent_emails(), vec![email]);
+  }
+
+  #[tokio::test]
+  async fn schedules_a_retry_for_transient_failures() {
+    let mailer = InMemoryMailer::new();
+    mailer.fail_next_with(MailerError::Transient("timeout".to_string()));
+
+    let outcome = attempt_send(&mailer, &retries(3), &email(), 2).await;
+
+    assert_eq!(outcome, AttemptOutcome::RetryAfter {
+      backoff: Duration::from_secs(20),
+      error: MailerError::Transient("timeout".to_string()),
+    });
+    assert!(mailer.sent_emails().is_empty());
+  }
+
+  #[tokio::test]
+  async fn gives_up_on_the_last_attempt() {
+    let mailer = InMemoryMailer::new();
+    mailer.fail_next_with(MailerError::Transient("timeout".to_string()));
+
+    let outcome = attempt_send(&mailer, &retries(3), &email(), 3).await;
+
+    assert_eq!(outcome, AttemptOutcome::GiveUp { error: MailerError::Transient("timeout".to_string()) });
+  }
+
+  #[tokio::test]
+  async fn does_not_retry_permanent_failures() {
+    let mailer = InMemoryMailer::new();
+    mailer.fail_next_with(MailerError::Permanent("bad address".to_string()));
+
+    let outcome = attempt_send(&mailer, &retries(3), &email(), 1).await;
+
+    assert_eq!(outcome, AttemptOutcome::GiveUp { error: MailerError::Permanent("bad address".to_string()) });
+    assert_eq!(mailer.attempt_count(), 1);
+  }
+}
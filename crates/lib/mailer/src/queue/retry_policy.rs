@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use crate::error::mailer_error::MailerError;
+
+/// How the outbound worker retries transient failures: exponential backoff between
+/// attempts, up to a maximum number of attempts.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+  /// Total attempts, including the first. Must be at least 1.
+  pub max_attempts: u32,
+
+  /// Wait before the second attempt. Doubles for each subsequent attempt.
+  pub initial_backoff: Duration,
+
+  pub max_backoff: Duration,
+}
+
+/// What to do with an email after a failed attempt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RetryDecision {
+  RetryAfter(Duration),
+  GiveUp,
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    Self {
+      max_attempts: 6,
+      initial_backoff: Duration::from_secs(10),
+      max_backoff: Duration::from_secs(60 * 10),
+    }
+  }
+}
+
+impl RetryPolicy {
+  /// How long to wait after failed attempt number `attempt` (starting from 1).
+  pub fn backoff_after_attempt(&self, attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(31);
+    self.initial_backoff
+        .checked_mul(1u32 << exponent)
+        .unwrap_or(self.max_backoff)
+        .min(self.max_backoff)
+  }
+
+  /// Retry transient errors until `max_attempts` attempts have been made.
+  pub fn decide(&self, attempt: u32, error: &MailerError) -> RetryDecision {
+    if error.is_retryable() && self.has_attempts_left(attempt) {
+      RetryDecision::RetryAfter(self.backoff_after_attempt(attempt))
+    } else {
+      RetryDecision::GiveUp
+    }
+  }
+
+  /// Whether another attempt is allowed after `attempts_made` attempts.
+  pub fn has_attempts_left(&self, attempts_made: u32) -> bool {
+    attempts_made < self.max_attempts
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn backoff_doubles_and_caps() {
+    let policy = RetryPolicy {
+      max_attempts: 10,
+      initial_backoff: Duration::from_secs(10),
+      max_backoff: Duration::from_secs(60),
+    };
+
+    assert_eq!(policy.backoff_after_attempt(1), Duration::from_secs(10));
+    assert_eq!(policy.backoff_after_attempt(2), Duration::from_secs(20));
+    assert_eq!(policy.backoff_after_attempt(3), Duration::from_secs(40));
+    assert_eq!(policy.backoff_after_attempt(4), Duration::from_secs(60));
+    assert_eq!(policy.backoff_after_attempt(100), Duration::from_secs(60));
+  }
+
+  #[test]
+  fn retries_transient_errors_until_attempts_run_out() {
+    let policy = RetryPolicy {
+      max_attempts: 3,
+      initial_backoff: Duration::from_secs(10),
+      max_backoff: Duration::from_secs(60),
+    };
+    let error = MailerError::Transient("timeout".to_string());
+
+    assert_eq!(policy.decide(1, &error), RetryDecision::RetryAfter(Duration::from_secs(10)));
+    assert_eq!(policy.decide(2, &error), RetryDecision::RetryAfter(Duration::from_secs(20)));
+    assert_eq!(policy.decide(3, &error), RetryDecision::GiveUp);
+  }
+
+  #[test]
+  fn never_retries_permanent_errors() {
+    let policy = RetryPolicy::default();
+    let error = MailerError::Permanent("bad address".to_string());
+
+    assert_eq!(policy.decide(1, &error), RetryDecision::GiveUp);
+  }
+}
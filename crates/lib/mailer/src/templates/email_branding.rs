@@ -0,0 +1,41 @@
+/// Which product an email is sent on behalf of. Controls the sender, names, and links.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmailBranding {
+  ArtCraft,
+  FakeYou,
+  Storyteller,
+}
+
+impl EmailBranding {
+  pub fn from_address(&self) -> &'static str {
+    match self {
+      Self::ArtCraft => "ArtCraft <noreply@getartcraft.com>",
+      Self::FakeYou => "FakeYou <noreply@fakeyou.com>",
+      Self::Storyteller => "FakeYou <noreply@fakeyou.com>",
+    }
+  }
+
+  pub fn platform_name(&self) -> &'static str {
+    match self {
+      Self::ArtCraft => "ArtCraft",
+      Self::FakeYou => "FakeYou",
+      Self::Storyteller => "Storyteller.ai",
+    }
+  }
+
+  pub fn team_name(&self) -> &'static str {
+    match self {
+      Self::ArtCraft => "ArtCraft Team",
+      Self::FakeYou => "FakeYou Team",
+      Self::Storyteller => "Storyteller.ai Team",
+    }
+  }
+
+  pub fn website_url(&self) -> &'static str {
+    match self {
+      Self::ArtCraft => "https://getartcraft.com",
+      Self::FakeYou => "https://fakeyou.com",
+      Self::Storyteller => "https://storyteller.ai",
+    }
+  }
+}
@@ -0,0 +1,39 @@
+use uuid_utils::uuid::generate_random_uuid;
+
+use crate::message::outbound_email::OutboundEmail;
+use crate::templates::email_branding::EmailBranding;
+use crate::templates::layout::{wrap_html_body, wrap_text_body};
+
+/// A typed transactional message that can be rendered for any branding.
+///
+/// Implementors only provide the message-specific content; the shared layout
+/// (sign-off and footer) is applied by `render`.
+pub trait EmailTemplate {
+  /// Eg. "password_reset". For logs, and as the idempotency key prefix.
+  fn category(&self) -> &'static str;
+
+  fn subject(&self, branding: EmailBranding) -> String;
+
+  /// The body HTML, without the shared layout. Interpolated values must be escaped
+  /// with `escape_html`.
+  fn html_content(&self, branding: EmailBranding) -> String;
+
+  /// The plain text equivalent of `html_content`.
+  fn text_content(&self, branding: EmailBranding) -> String;
+
+  /// Render a complete email addressed to `to_address`.
+  fn render(&self, branding: EmailBranding, to_address: &str) -> OutboundEmail {
+    let subject = self.subject(branding);
+
+    OutboundEmail {
+      from: branding.from_address().to_string(),
+      to: vec![to_address.to_string()],
+      maybe_reply_to: None,
+      html_body: wrap_html_body(branding, &subject, &self.html_content(branding)),
+      text_body: wrap_text_body(branding, &self.text_content(branding)),
+      subject,
+      idempotency_key: format!("{}-{}", self.category(), generate_random_uuid()),
+      category: self.category().to_string(),
+    }
+  }
+}
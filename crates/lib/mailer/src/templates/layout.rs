@@ -0,0 +1,73 @@
+use crate::templates::email_branding::EmailBranding;
+
+/// Escape text for interpolation into HTML element content or quoted attributes.
+pub fn escape_html(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for c in text.chars() {
+    match c {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&#39;"),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+/// The shared HTML document around every message body.
+pub fn wrap_html_body(branding: EmailBranding, subject: &str, content_html: &str) -> String {
+  let title = escape_html(subject);
+  let team_name = escape_html(branding.team_name());
+  let platform_name = escape_html(branding.platform_name());
+  let website_url = escape_html(branding.website_url());
+
+  format!(r#"<!DOCTYPE html>
+<html>
+  <head>
+    <meta charset="utf-8" />
+    <title>{title}</title>
+  </head>
+  <body style="font-family: sans-serif; line-height: 1.5;">
+    {content_html}
+    <p>
+      Thank You,
+      <br />
+      <br />
+      {team_name}
+    </p>
+    <p style="font-size: small; color: #888888;">
+      <a href="{website_url}">{platform_name}</a>
+    </p>
+  </body>
+</html>
+"#)
+}
+
+/// The shared plain text around every message body.
+pub fn wrap_text_body(branding: EmailBranding, content_text: &str) -> String {
+  format!(
+    "{}\n\nThank You,\n\n{}\n{}\n",
+    content_text.trim_end(),
+    branding.team_name(),
+    branding.website_url(),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn escapes_html() {
+    assert_eq!(escape_html(r#"<a href="x">Tom & Jerry's</a>"#), "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;");
+    assert_eq!(escape_html("plain"), "plain");
+  }
+
+  #[test]
+  fn text_layout_signs_off_with_team_name() {
+    let text = wrap_text_body(EmailBranding::FakeYou, "Hello!\n");
+    assert_eq!(text, "Hello!\n\nThank You,\n\nFakeYou Team\nhttps://fakeyou.com\n");
+  }
+}
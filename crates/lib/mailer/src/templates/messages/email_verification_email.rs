@@ -0,0 +1,46 @@
+use crate::templates::email_branding::EmailBranding;
+use crate::templates::email_template::EmailTemplate;
+use crate::templates::layout::escape_html;
+
+pub struct EmailVerificationEmail {
+  /// Link to the site's verification page, including the signed token.
+  pub verification_url: String,
+}
+
+impl EmailTemplate for EmailVerificationEmail {
+  fn category(&self) -> &'static str {
+    "email_verification"
+  }
+
+  fn subject(&self, branding: EmailBranding) -> String {
+    format!("Verify your {} email address", branding.platform_name())
+  }
+
+  fn html_content(&self, branding: EmailBranding) -> String {
+    let platform = escape_html(branding.platform_name());
+    let link = escape_html(&self.verification_url);
+
+    format!(r#"
+    <p>
+      Please confirm the email address for your {platform} account.
+      If you didn't create an account or change your email address, you can safely ignore this email.
+    </p>
+    <p>
+      <a href="{link}">Click here to verify your email address!</a>
+    </p>
+    <p>
+      This link will expire, but you can request a new one from your account settings.
+    </p>"#)
+  }
+
+  fn text_content(&self, branding: EmailBranding) -> String {
+    format!(
+      "Please confirm the email address for your {} account.\n\
+      If you didn't create an account or change your email address, you can safely ignore this email.\n\n\
+      Verify your email address here: {}\n\n\
+      This link will expire, but you can request a new one from your account settings.\n",
+      branding.platform_name(),
+      self.verification_url,
+    )
+  }
+}
@@ -0,0 +1,46 @@
+use crate::templates::email_branding::EmailBranding;
+use crate::templates::email_template::EmailTemplate;
+use crate::templates::layout::escape_html;
+
+pub struct LowCreditWarningEmail {
+  pub credits_remaining: u64,
+
+  /// Where the user can buy more credits.
+  pub top_up_url: String,
+}
+
+impl EmailTemplate for LowCreditWarningEmail {
+  fn category(&self) -> &'static str {
+    "low_credit_warning"
+  }
+
+  fn subject(&self, branding: EmailBranding) -> String {
+    format!("You're running low on {} credits", branding.platform_name())
+  }
+
+  fn html_content(&self, branding: EmailBranding) -> String {
+    let platform = escape_html(branding.platform_name());
+    let link = escape_html(&self.top_up_url);
+    let credits = self.credits_remaining;
+
+    format!(r#"
+    <p>
+      Your {platform} account has {credits} credits remaining.
+      Once they run out, new generations will be paused until you add more.
+    </p>
+    <p>
+      <a href="{link}">Click here to top up your credits.</a>
+    </p>"#)
+  }
+
+  fn text_content(&self, branding: EmailBranding) -> String {
+    format!(
+      "Your {} account has {} credits remaining.\n\
+      Once they run out, new generations will be paused until you add more.\n\n\
+      Top up your credits here: {}\n",
+      branding.platform_name(),
+      self.credits_remaining,
+      self.top_up_url,
+    )
+  }
+}
@@ -0,0 +1,4 @@
+pub mod email_verification_email;
+pub mod low_credit_warning_email;
+pub mod password_reset_email;
+pub mod purchase_receipt_email;
@@ -0,0 +1,92 @@
+use crate::templates::email_branding::EmailBranding;
+use crate::templates::email_template::EmailTemplate;
+use crate::templates::layout::escape_html;
+
+pub struct PasswordResetEmail {
+  /// Link to the site's password reset form, including the token.
+  pub reset_url: String,
+
+  /// The same secret, for users who can't click the link.
+  pub reset_code: String,
+}
+
+impl EmailTemplate for PasswordResetEmail {
+  fn category(&self) -> &'static str {
+    "password_reset"
+  }
+
+  fn subject(&self, branding: EmailBranding) -> String {
+    format!("{} Password Reset", branding.platform_name())
+  }
+
+  fn html_content(&self, branding: EmailBranding) -> String {
+    let platform = escape_html(branding.platform_name());
+    let link = escape_html(&self.reset_url);
+    let code = escape_html(&self.reset_code);
+
+    format!(r#"
+    <p>
+      We received a request to reset your password on {platform}.
+      If this wasn't you, you can safely ignore this email.
+    </p>
+    <p>
+      <a href="{link}">Click here to reset your password!</a>
+    </p>
+    <p>
+      If you can't click the link, here's the secret reset code: {code}
+    </p>"#)
+  }
+
+  fn text_content(&self, branding: EmailBranding) -> String {
+    format!(
+      "We received a request to reset your password on {}.\n\
+      If this wasn't you, you can safely ignore this email.\n\n\
+      Reset your password here: {}\n\n\
+      Or use this secret reset code: {}\n",
+      branding.platform_name(),
+      self.reset_url,
+      self.reset_code,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn email() -> PasswordResetEmail {
+    PasswordResetEmail {
+      reset_url: "https://getartcraft.com/forgot-password/verify?token=abc&x=1".to_string(),
+      reset_code: "abc".to_string(),
+    }
+  }
+
+  #[test]
+  fn renders_for_branding() {
+    let rendered = email().render(EmailBranding::FakeYou, "user@example.com");
+
+    assert_eq!(rendered.from, "FakeYou <noreply@fakeyou.com>");
+    assert_eq!(rendered.to, vec!["user@example.com".to_string()]);
+    assert_eq!(rendered.subject, "FakeYou Password Reset");
+    assert_eq!(rendered.category, "password_reset");
+    assert!(rendered.idempotency_key.starts_with("password_reset-"));
+    assert!(rendered.html_body.contains("FakeYou Team"));
+    assert!(rendered.text_body.contains("Or use this secret reset code: abc"));
+  }
+
+  #[test]
+  fn escapes_link_in_html_but_not_text() {
+    let rendered = email().render(EmailBranding::ArtCraft, "user@example.com");
+
+    assert!(rendered.html_body.contains(r#"href="https://getartcraft.com/forgot-password/verify?token=abc&amp;x=1""#));
+    assert!(rendered.text_body.contains("https://getartcraft.com/forgot-password/verify?token=abc&x=1"));
+  }
+
+  #[test]
+  fn each_render_gets_a_new_idempotency_key() {
+    let first = email().render(EmailBranding::ArtCraft, "user@example.com");
+    let second = email().render(EmailBranding::ArtCraft, "user@example.com");
+
+    assert_ne!(first.idempotency_key, second.idempotency_key);
+  }
+}
@@ -0,0 +1,147 @@
+use crate::templates::email_branding::EmailBranding;
+use crate::templates::email_template::EmailTemplate;
+use crate::templates::layout::escape_html;
+
+pub struct PurchaseReceiptEmail {
+  /// Eg. "ArtCraft Pro (monthly)" or "1,000 credits"
+  pub item_description: String,
+
+  /// In the currency's minor unit (eg. cents).
+  pub amount_minor_units: u64,
+
+  /// ISO 4217 code, eg. "usd". Case insensitive.
+  pub currency: String,
+
+  pub maybe_credits_granted: Option<u64>,
+
+  /// Eg. a Stripe hosted receipt or invoice page.
+  pub maybe_receipt_url: Option<String>,
+}
+
+impl PurchaseReceiptEmail {
+  pub fn formatted_amount(&self) -> String {
+    format_amount(self.amount_minor_units, &self.currency)
+  }
+}
+
+impl EmailTemplate for PurchaseReceiptEmail {
+  fn category(&self) -> &'static str {
+    "purchase_receipt"
+  }
+
+  fn subject(&self, branding: EmailBranding) -> String {
+    format!("Your {} receipt", branding.platform_name())
+  }
+
+  fn html_content(&self, branding: EmailBranding) -> String {
+    let platform = escape_html(branding.platform_name());
+    let item = escape_html(&self.item_description);
+    let amount = escape_html(&self.formatted_amount());
+
+    let mut html = format!(r#"
+    <p>
+      Thanks for your purchase on {platform}!
+    </p>
+    <p>
+      <strong>{item}</strong>: {amount}
+    </p>"#);
+
+    if let Some(credits) = self.maybe_credits_granted {
+      html.push_str(&format!(r#"
+    <p>
+      {credits} credits have been added to your account.
+    </p>"#));
+    }
+
+    if let Some(receipt_url) = self.maybe_receipt_url.as_deref() {
+      let link = escape_html(receipt_url);
+      html.push_str(&format!(r#"
+    <p>
+      <a href="{link}">View your full receipt.</a>
+    </p>"#));
+    }
+
+    html
+  }
+
+  fn text_content(&self, branding: EmailBranding) -> String {
+    let mut text = format!(
+      "Thanks for your purchase on {}!\n\n{}: {}\n",
+      branding.platform_name(),
+      self.item_description,
+      self.formatted_amount(),
+    );
+
+    if let Some(credits) = self.maybe_credits_granted {
+      text.push_str(&format!("\n{} credits have been added to your account.\n", credits));
+    }
+
+    if let Some(receipt_url) = self.maybe_receipt_url.as_deref() {
+      text.push_str(&format!("\nView your full receipt here: {}\n", receipt_url));
+    }
+
+    text
+  }
+}
+
+/// Format an amount in minor units for display, eg. `1234, "usd"` => `$12.34`.
+/// Assumes two decimal places, which holds for every currency we sell in.
+fn format_amount(amount_minor_units: u64, currency: &str) -> String {
+  let currency = currency.trim().to_ascii_uppercase();
+  let major = amount_minor_units / 100;
+  let minor = amount_minor_units % 100;
+
+  let symbol = match currency.as_str() {
+    "USD" | "CAD" | "AUD" => Some("$"),
+    "EUR" => Some("€"),
+    "GBP" => Some("£"),
+    _ => None,
+  };
+
+  match symbol {
+    Some(symbol) if currency == "USD" => format!("{symbol}{major}.{minor:02}"),
+    Some(symbol) => format!("{symbol}{major}.{minor:02} {currency}"),
+    None => format!("{major}.{minor:02} {currency}"),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn formats_amounts() {
+    assert_eq!(format_amount(1234, "usd"), "$12.34");
+    assert_eq!(format_amount(5, "USD"), "$0.05");
+    assert_eq!(format_amount(1000, "eur"), "€10.00 EUR");
+    assert_eq!(format_amount(999, "cad"), "$9.99 CAD");
+    assert_eq!(format_amount(250, "sek"), "2.50 SEK");
+  }
+
+  #[test]
+  fn optional_sections() {
+    let receipt = PurchaseReceiptEmail {
+      item_description: "1,000 credits".to_string(),
+      amount_minor_units: 1000,
+      currency: "usd".to_string(),
+      maybe_credits_granted: None,
+      maybe_receipt_url: None,
+    };
+
+    let rendered = receipt.render(EmailBranding::ArtCraft, "user@example.com");
+    assert_eq!(rendered.subject, "Your ArtCraft receipt");
+    assert!(rendered.text_body.contains("1,000 credits: $10.00"));
+    assert!(!rendered.text_body.contains("added to your account"));
+    assert!(!rendered.html_body.contains("View your full receipt"));
+
+    let receipt = PurchaseReceiptEmail {
+      maybe_credits_granted: Some(1000),
+      maybe_receipt_url: Some("https://pay.stripe.com/receipts/abc".to_string()),
+      ..receipt
+    };
+
+    let rendered = receipt.render(EmailBranding::ArtCraft, "user@example.com");
+    assert!(rendered.text_body.contains("1000 credits have been added to your account."));
+    assert!(rendered.html_body.contains(r#"<a href="https://pay.stripe.com/receipts/abc">"#));
+  }
+}
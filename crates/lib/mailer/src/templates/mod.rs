@@ -0,0 +1,4 @@
+pub mod email_branding;
+pub mod email_template;
+pub mod layout;
+pub mod messages;
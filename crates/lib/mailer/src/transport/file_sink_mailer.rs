@@ -0,0 +1,114 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use log::info;
+
+use errors::AnyhowResult;
+
+use crate::error::mailer_error::MailerError;
+use crate::message::outbound_email::{OutboundEmail, SentEmail};
+use crate::transport::mailer::Mailer;
+use crate::transport::smtp_mailer::build_mime_message;
+
+/// Writes each email to a local directory as an `.eml` file (openable in most mail
+/// clients) instead of sending it. For running the services offline.
+pub struct FileSinkMailer {
+  directory: PathBuf,
+}
+
+impl FileSinkMailer {
+  pub fn new(directory: &Path) -> AnyhowResult<Self> {
+    std::fs::create_dir_all(directory)?;
+
+    Ok(Self {
+      directory: directory.to_path_buf(),
+    })
+  }
+
+  pub fn directory(&self) -> &Path {
+    &self.directory
+  }
+}
+
+#[async_trait]
+impl Mailer for FileSinkMailer {
+  fn name(&self) -> &'static str {
+    "file"
+  }
+
+  async fn send(&self, email: &OutboundEmail) -> Result<SentEmail, MailerError> {
+    let message = build_mime_message(email)?;
+
+    let filename = format!(
+      "{}_{}.eml",
+      Utc::now().format("%Y%m%dT%H%M%S%.3f"),
+      email.idempotency_key,
+    );
+
+    let path = self.directory.join(filename);
+
+    tokio::fs::write(&path, message.formatted())
+        .await
+        .map_err(|err| MailerError::Transient(format!("Error writing email to {:?}: {}", path, err)))?;
+
+    info!("Wrote {} email to {:?}", email.category, path);
+
+    Ok(SentEmail {
+      maybe_provider_message_id: Some(path.to_string_lossy().to_string()),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn writes_eml_file() {
+    let directory = tempfile::tempdir().unwrap();
+    let mailer = FileSinkMailer::new(directory.path()).unwrap();
+
+    let email = OutboundEmail {
+      from: "ArtCraft <noreply@getartcraft.com>".to_string(),
+      to: vec!["user@example.com".to_string()],
+      maybe_reply_to: None,
+      subject: "Hello".to_string(),
+      html_body: "<p>Hi there</p>".to_string(),
+      text_body: "Hi there".to_string(),
+      idempotency_key: "test-key".to_string(),
+      category: "test".to_string(),
+    };
+
+    let sent = mailer.send(&email).await.unwrap();
+    let path = PathBuf::from(sent.maybe_provider_message_id.unwrap());
+
+    assert!(path.to_string_lossy().ends_with("_test-key.eml"));
+
+    let contents = std::fs::read_to_string(path).unwrap();
+    assert!(contents.contains("To: user@example.com"));
+    assert!(contents.contains("Subject: Hello"));
+    assert!(contents.contains("<p>Hi there</p>"));
+  }
+
+  #[tokio::test]
+  async fn invalid_address_is_permanent() {
+    let directory = tempfile::tempdir().unwrap();
+    let mailer = FileSinkMailer::new(directory.path()).unwrap();
+
+    let email = OutboundEmail {
+      from: "ArtCraft <noreply@getartcraft.com>".to_string(),
+      to: vec!["not an address".to_string()],
+      maybe_reply_to: None,
+      subject: "Hello".to_string(),
+      html_body: "<p>Hi there</p>".to_string(),
+      text_body: "Hi there".to_string(),
+      idempotency_key: "test-key".to_string(),
+      category: "test".to_string(),
+    };
+
+    let result = mailer.send(&email).await;
+
+    assert!(matches!(result, Err(MailerError::Permanent(_))));
+  }
+}
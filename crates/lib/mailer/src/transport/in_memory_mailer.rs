@@ -0,0 +1,67 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::error::mailer_error::MailerError;
+use crate::message::outbound_email::{OutboundEmail, SentEmail};
+use crate::transport::mailer::Mailer;
+
+/// Keeps emails in memory, for tests and throwaway local runs. Nothing is delivered.
+#[derive(Default)]
+pub struct InMemoryMailer {
+  state: Mutex<InMemoryMailerState>,
+}
+
+#[derive(Default)]
+struct InMemoryMailerState {
+  sent: Vec<OutboundEmail>,
+  attempts: usize,
+  queued_failures: VecDeque<MailerError>,
+}
+
+impl InMemoryMailer {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Every successfully "sent" email, in order, for test assertions.
+  pub fn sent_emails(&self) -> Vec<OutboundEmail> {
+    self.lock().sent.clone()
+  }
+
+  /// Number of calls to `send`, including failed ones.
+  pub fn attempt_count(&self) -> usize {
+    self.lock().attempts
+  }
+
+  /// Make the next call to `send` fail with `error`. Calls can be stacked.
+  pub fn fail_next_with(&self, error: MailerError) {
+    self.lock().queued_failures.push_back(error);
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, InMemoryMailerState> {
+    self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[async_trait]
+impl Mailer for InMemoryMailer {
+  fn name(&self) -> &'static str {
+    "memory"
+  }
+
+  async fn send(&self, email: &OutboundEmail) -> Result<SentEmail, MailerError> {
+    let mut state = self.lock();
+
+    state.attempts += 1;
+
+    if let Some(error) = state.queued_failures.pop_front() {
+      return Err(error);
+    }
+
+    state.sent.push(email.clone());
+
+    Ok(SentEmail::default())
+  }
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::error::mailer_error::MailerError;
+use crate::message::outbound_email::{OutboundEmail, SentEmail};
+
+/// Something that delivers (or pretends to deliver) email: Resend, an SMTP relay,
+/// a local directory, or memory. The latter two let the services run (and be tested)
+/// without provider credentials.
+///
+/// Most callers shouldn't call this directly, but should enqueue on an
+/// `OutboundEmailQueue`, which retries transient failures.
+#[async_trait]
+pub trait Mailer: Send + Sync {
+  /// Short name for logs, eg. "resend".
+  fn name(&self) -> &'static str;
+
+  async fn send(&self, email: &OutboundEmail) -> Result<SentEmail, MailerError>;
+}
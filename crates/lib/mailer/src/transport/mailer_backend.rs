@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::bail;
+use log::info;
+
+use errors::AnyhowResult;
+
+use crate::transport::file_sink_mailer::FileSinkMailer;
+use crate::transport::in_memory_mailer::InMemoryMailer;
+use crate::transport::mailer::Mailer;
+use crate::transport::resend_mailer::ResendMailer;
+use crate::transport::smtp_mailer::SmtpMailer;
+
+/// `resend`, `smtp`, `file`, or `memory`. Each service picks its own default.
+pub const ENV_MAILER_BACKEND: &str = "MAILER_BACKEND";
+
+/// For `file`: where `.eml` files are written.
+pub const ENV_MAILER_FILE_DIRECTORY: &str = "MAILER_FILE_DIRECTORY";
+
+const DEFAULT_FILE_DIRECTORY: &str = "runtime_data/outbound_emails";
+
+/// Which `Mailer` delivers email. Provider credentials are read from the environment
+/// when the mailer is built.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MailerBackend {
+  /// Requires `RESEND_API_KEY`.
+  Resend,
+  /// Requires `SMTP_RELAY`, `SMTP_USERNAME`, and `SMTP_PASSWORD`.
+  Smtp,
+  FileSink(PathBuf),
+  InMemory,
+}
+
+impl MailerBackend {
+  pub fn from_env_or_default(default_backend: &str) -> AnyhowResult<Self> {
+    let backend = easyenv::get_env_string_or_default(ENV_MAILER_BACKEND, default_backend);
+    let maybe_file_directory = easyenv::get_env_pathbuf_optional(ENV_MAILER_FILE_DIRECTORY);
+    Self::from_name(&backend, maybe_file_directory)
+  }
+
+  pub fn from_name(backend: &str, maybe_file_directory: Option<PathBuf>) -> AnyhowResult<Self> {
+    match backend.trim().to_ascii_lowercase().as_str() {
+      "resend" => Ok(Self::Resend),
+      "smtp" => Ok(Self::Smtp),
+      "file" => Ok(Self::FileSink(
+        maybe_file_directory.unwrap_or_else(|| PathBuf::from(DEFAULT_FILE_DIRECTORY)))),
+      "memory" => Ok(Self::InMemory),
+      other => bail!("Unknown {} '{}'; expected resend, smtp, file, or memory", ENV_MAILER_BACKEND, other),
+    }
+  }
+
+  pub fn build(&self) -> AnyhowResult<Arc<dyn Mailer>> {
+    let mailer: Arc<dyn Mailer> = match self {
+      Self::Resend => {
+        Arc::new(ResendMailer::new(&easyenv::get_env_string_required("RESEND_API_KEY")?))
+      }
+      Self::Smtp => {
+        Arc::new(SmtpMailer::new(
+          &easyenv::get_env_string_required("SMTP_RELAY")?,
+          easyenv::get_env_string_required("SMTP_USERNAME")?,
+          easyenv::get_env_string_required("SMTP_PASSWORD")?,
+        )?)
+      }
+      Self::FileSink(directory) => {
+        info!("Outbound email will be written to local directory: {:?}", directory);
+        Arc::new(FileSinkMailer::new(directory)?)
+      }
+      Self::InMemory => {
+        info!("Outbound email will be kept in memory and not delivered");
+        Arc::new(InMemoryMailer::new())
+      }
+    };
+
+    Ok(mailer)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_backend_names() {
+    assert_eq!(MailerBackend::from_name("resend", None).unwrap(), MailerBackend::Resend);
+    assert_eq!(MailerBackend::from_name(" SMTP ", None).unwrap(), MailerBackend::Smtp);
+    assert_eq!(MailerBackend::from_name("memory", None).unwrap(), MailerBackend::InMemory);
+    assert_eq!(
+      MailerBackend::from_name("file", Some(PathBuf::from("/tmp/emails"))).unwrap(),
+      MailerBackend::FileSink(PathBuf::from("/tmp/emails")),
+    );
+    assert_eq!(
+      MailerBackend::from_name("file", None).unwrap(),
+      MailerBackend::FileSink(PathBuf::from(DEFAULT_FILE_DIRECTORY)),
+    );
+    assert!(MailerBackend::from_name("sendgrid", None).is_err());
+  }
+}
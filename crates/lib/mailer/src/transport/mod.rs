@@ -0,0 +1,6 @@
+pub mod file_sink_mailer;
+pub mod in_memory_mailer;
+pub mod mailer;
+pub mod mailer_backend;
+pub mod resend_mailer;
+pub mod smtp_mailer;
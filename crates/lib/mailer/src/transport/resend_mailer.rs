@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use resend_rs::types::CreateEmailBaseOptions;
+use resend_rs::Resend;
+
+use crate::error::mailer_error::MailerError;
+use crate::message::outbound_email::{OutboundEmail, SentEmail};
+use crate::transport::mailer::Mailer;
+
+/// Sends email through the Resend API.
+pub struct ResendMailer {
+  resend: Resend,
+}
+
+impl ResendMailer {
+  pub fn new(api_key: &str) -> Self {
+    Self {
+      resend: Resend::new(api_key),
+    }
+  }
+}
+
+#[async_trait]
+impl Mailer for ResendMailer {
+  fn name(&self) -> &'static str {
+    "resend"
+  }
+
+  async fn send(&self, email: &OutboundEmail) -> Result<SentEmail, MailerError> {
+    let mut options = CreateEmailBaseOptions::new(&email.from, &email.to, &email.subject)
+        .with_html(&email.html_body)
+        .with_text(&email.text_body);
+
+    if let Some(reply_to) = email.maybe_reply_to.as_deref() {
+      options = options.with_reply(reply_to);
+    }
+
+    let response = self.resend.emails
+        .send(options.with_idempotency_key(&email.idempotency_key))
+        .await
+        .map_err(classify_resend_error)?;
+
+    Ok(SentEmail {
+      maybe_provider_message_id: Some(response.id.to_string()),
+    })
+  }
+}
+
+fn classify_resend_error(err: resend_rs::Error) -> MailerError {
+  match err {
+    resend_rs::Error::Http(_) => MailerError::Transient(err.to_string()),
+    resend_rs::Error::RateLimit { .. } => MailerError::Transient(err.to_string()),
+    resend_rs::Error::Resend(ref response)
+      if response.status_code == 429 || response.status_code >= 500 => MailerError::Transient(err.to_string()),
+    // NB: This includes unparseable responses. The email may have been accepted, so
+    // don't risk sending it twice.
+    _ => MailerError::Permanent(err.to_string()),
+  }
+}
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Message, SmtpTransport, Transport};
+
+use errors::AnyhowResult;
+
+use crate::error::mailer_error::MailerError;
+use crate::message::outbound_email::{OutboundEmail, SentEmail};
+use crate::transport::mailer::Mailer;
+
+/// Sends email through an SMTP relay.
+#[derive(Clone)]
+pub struct SmtpMailer {
+  transport: SmtpTransport,
+}
+
+impl SmtpMailer {
+
+  // NB: To set this up for Gmail:
+  //
+  //  - https://support.google.com/a/answer/2956491?hl=en&fl=1&sjid=8333642123536524043-NA
+  //
+  // "Less secure apps" setting, which must be enabled at the organization level and within the
+  // individual user account:
+  //  - https://support.google.com/mail/thread/5621336/bad-credentials-using-gmail-smtp?hl=en
+  //
+  pub fn new(relay: &str, username: String, password: String) -> AnyhowResult<Self> {
+    Ok(Self {
+      transport: SmtpTransport::relay(relay)?
+          .credentials(Credentials::new(username, password))
+          .build()
+    })
+  }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+  fn name(&self) -> &'static str {
+    "smtp"
+  }
+
+  async fn send(&self, email: &OutboundEmail) -> Result<SentEmail, MailerError> {
+    let message = build_mime_message(email)?;
+    let transport = self.transport.clone();
+
+    // NB: The pooled SMTP transport is blocking.
+    let response = tokio::task::spawn_blocking(move || transport.send(&message))
+        .await
+        .map_err(|err| MailerError::Transient(format!("SMTP send task failed: {}", err)))?
+        .map_err(|err| {
+          if err.is_permanent() {
+            MailerError::Permanent(err.to_string())
+          } else {
+            MailerError::Transient(err.to_string())
+          }
+        })?;
+
+    let maybe_provider_message_id = response.message()
+        .next()
+        .map(|line| line.to_string());
+
+    Ok(SentEmail {
+      maybe_provider_message_id,
+    })
+  }
+}
+
+/// Build a multipart (plain text + HTML) MIME message. Shared with the file sink so
+/// local emails look exactly like what would go over the wire.
+pub(crate) fn build_mime_message(email: &OutboundEmail) -> Result<Message, MailerError> {
+  let mut builder = Message::builder()
+      .from(parse_mailbox(&email.from)?)
+      .subject(&email.subject);
+
+  for to in email.to.iter() {
+    builder = builder.to(parse_mailbox(to)?);
+  }
+
+  if let Some(reply_to) = email.maybe_reply_to.as_deref() {
+    builder = builder.reply_to(parse_mailbox(reply_to)?);
+  }
+
+  builder
+      .multipart(MultiPart::alternative_plain_html(email.text_body.clone(), email.html_body.clone()))
+      .map_err(|err| MailerError::Permanent(format!("Error constructing email: {}", err)))
+}
+
+fn parse_mailbox(address: &str) -> Result<Mailbox, MailerError> {
+  address.parse()
+      .map_err(|err| MailerError::Permanent(format!("Invalid email address {:?}: {}", address, err)))
+}
@@ -0,0 +1,97 @@
+use std::fmt::{Display, Formatter};
+
+use url::Url;
+
+use crate::provider_http_error::ProviderHttpError;
+
+/// The root of a provider's API, eg. `https://queue.fal.run` or `http://127.0.0.1:8123/v1`.
+///
+/// Always http(s), never with a trailing slash, so paths can be appended with `join`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BaseUrl(String);
+
+impl BaseUrl {
+  pub fn parse(url: &str) -> Result<Self, ProviderHttpError> {
+    let parsed = Url::parse(url)
+      .map_err(|_| ProviderHttpError::InvalidBaseUrl(url.to_string()))?;
+
+    match parsed.scheme() {
+      "http" | "https" => {}
+      _ => return Err(ProviderHttpError::InvalidBaseUrl(url.to_string())),
+    }
+
+    if parsed.host_str().is_none() || parsed.query().is_some() || parsed.fragment().is_some() {
+      return Err(ProviderHttpError::InvalidBaseUrl(url.to_string()));
+    }
+
+    Ok(Self(url.trim_end_matches('/').to_string()))
+  }
+
+  /// For the hardcoded production hosts, which are known to be valid.
+  pub fn from_static(url: &'static str) -> Self {
+    Self::parse(url).expect("static base URLs are valid")
+  }
+
+  /// Append a path. A leading slash on `path` is optional.
+  pub fn join(&self, path: &str) -> String {
+    format!("{}/{}", self.0, path.trim_start_matches('/'))
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+
+  /// The host, without scheme or port.
+  pub fn host(&self) -> String {
+    Url::parse(&self.0)
+      .ok()
+      .and_then(|url| url.host_str().map(|host| host.to_string()))
+      .unwrap_or_default()
+  }
+
+  /// True if `url` lives under this base URL (same scheme, host, and port).
+  pub fn owns_url(&self, url: &str) -> bool {
+    match (Url::parse(&self.0), Url::parse(url)) {
+      (Ok(base), Ok(other)) => base.scheme() == other.scheme()
+        && base.host_str() == other.host_str()
+        && base.port_or_known_default() == other.port_or_known_default(),
+      _ => false,
+    }
+  }
+}
+
+impl Display for BaseUrl {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_and_join() {
+    let base = BaseUrl::parse("https://api.replicate.com/v1/").unwrap();
+    assert_eq!(base.as_str(), "https://api.replicate.com/v1");
+    assert_eq!(base.join("predictions"), "https://api.replicate.com/v1/predictions");
+    assert_eq!(base.join("/predictions"), "https://api.replicate.com/v1/predictions");
+    assert_eq!(base.host(), "api.replicate.com");
+  }
+
+  #[test]
+  fn local_stand_in() {
+    let base = BaseUrl::parse("http://127.0.0.1:8123").unwrap();
+    assert_eq!(base.host(), "127.0.0.1");
+    assert!(base.owns_url("http://127.0.0.1:8123/fal-ai/flux/requests/abc/status"));
+    assert!(!base.owns_url("http://127.0.0.1:9999/fal-ai/flux/requests/abc/status"));
+    assert!(!base.owns_url("https://127.0.0.1:8123/fal-ai/flux/requests/abc/status"));
+  }
+
+  #[test]
+  fn rejects_bad_urls() {
+    assert!(BaseUrl::parse("queue.fal.run").is_err());
+    assert!(BaseUrl::parse("ftp://queue.fal.run").is_err());
+    assert!(BaseUrl::parse("https://queue.fal.run?x=1").is_err());
+  }
+}
@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use crate::provider_http_error::ProviderHttpError;
+
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Environment variables read by `HttpClientConfig::from_env`.
+pub const ENV_PROXY_URL: &str = "PROVIDER_HTTP_PROXY_URL";
+pub const ENV_USER_AGENT: &str = "PROVIDER_HTTP_USER_AGENT";
+pub const ENV_TIMEOUT_SECS: &str = "PROVIDER_HTTP_TIMEOUT_SECS";
+
+/// How to build the pooled HTTP client that provider calls share.
+#[derive(Clone, Debug)]
+pub struct HttpClientConfig {
+  /// Whole-request timeout. Individual requests can still set a shorter or longer one.
+  pub maybe_timeout: Option<Duration>,
+
+  pub connect_timeout: Duration,
+
+  /// How long idle pooled connections are kept for reuse.
+  pub pool_idle_timeout: Duration,
+
+  /// Send all traffic through this proxy (eg. a corporate egress proxy).
+  /// Without one, reqwest still honors the system `HTTPS_PROXY` settings.
+  pub maybe_proxy_url: Option<String>,
+
+  pub maybe_user_agent: Option<String>,
+}
+
+impl Default for HttpClientConfig {
+  fn default() -> Self {
+    Self {
+      maybe_timeout: None,
+      connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+      pool_idle_timeout: DEFAULT_POOL_IDLE_TIMEOUT,
+      maybe_proxy_url: None,
+      maybe_user_agent: None,
+    }
+  }
+}
+
+impl HttpClientConfig {
+  /// Defaults, overridden by any `PROVIDER_HTTP_*` environment variables.
+  pub fn from_env() -> Self {
+    let env = |name: &str| std::env::var(name).ok().filter(|value| !value.trim().is_empty());
+
+    Self {
+      maybe_timeout: env(ENV_TIMEOUT_SECS)
+        .and_then(|secs| secs.trim().parse::<u64>().ok())
+        .map(Duration::from_secs),
+      maybe_proxy_url: env(ENV_PROXY_URL),
+      maybe_user_agent: env(ENV_USER_AGENT),
+      ..Self::default()
+    }
+  }
+
+  pub fn build_reqwest_client(&self) -> Result<reqwest::Client, ProviderHttpError> {
+    self.apply_to_reqwest_builder(reqwest::Client::builder())?
+      .build()
+      .map_err(|err| ProviderHttpError::ClientBuildError(err.to_string()))
+  }
+
+  pub fn apply_to_reqwest_builder(
+    &self,
+    builder: reqwest::ClientBuilder,
+  ) -> Result<reqwest::ClientBuilder, ProviderHttpError> {
+    let mut builder = builder
+      .connect_timeout(self.connect_timeout)
+      .pool_idle_timeout(self.pool_idle_timeout);
+
+    if let Some(timeout) = self.maybe_timeout {
+      builder = builder.timeout(timeout);
+    }
+
+    if let Some(proxy_url) = self.maybe_proxy_url.as_deref() {
+      let proxy = reqwest::Proxy::all(proxy_url)
+        .map_err(|_| ProviderHttpError::InvalidProxyUrl(proxy_url.to_string()))?;
+      builder = builder.proxy(proxy);
+    }
+
+    if let Some(user_agent) = self.maybe_user_agent.as_deref() {
+      builder = builder.user_agent(user_agent);
+    }
+
+    Ok(builder)
+  }
+
+  /// For the browser-emulating clients. Leaves the user agent alone when the builder
+  /// is emulating a browser and none was configured.
+  #[cfg(feature = "wreq")]
+  pub fn apply_to_wreq_builder(
+    &self,
+    builder: wreq::ClientBuilder,
+  ) -> Result<wreq::ClientBuilder, ProviderHttpError> {
+    let mut builder = builder
+      .connect_timeout(self.connect_timeout)
+      .pool_idle_timeout(self.pool_idle_timeout);
+
+    if let Some(timeout) = self.maybe_timeout {
+      builder = builder.timeout(timeout);
+    }
+
+    if let Some(proxy_url) = self.maybe_proxy_url.as_deref() {
+      let proxy = wreq::Proxy::all(proxy_url)
+        .map_err(|_| ProviderHttpError::InvalidProxyUrl(proxy_url.to_string()))?;
+      builder = builder.proxy(proxy);
+    }
+
+    if let Some(user_agent) = self.maybe_user_agent.as_deref() {
+      builder = builder.user_agent(user_agent);
+    }
+
+    Ok(builder)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn builds_with_proxy_and_user_agent() {
+    let config = HttpClientConfig {
+      maybe_timeout: Some(Duration::from_secs(30)),
+      maybe_proxy_url: Some("http://egress.internal:3128".to_string()),
+      maybe_user_agent: Some("artcraft/1.0".to_string()),
+      ..HttpClientConfig::default()
+    };
+    assert!(config.build_reqwest_client().is_ok());
+  }
+
+  #[test]
+  fn rejects_bad_proxy() {
+    let config = HttpClientConfig {
+      maybe_proxy_url: Some("not a url".to_string()),
+      ..HttpClientConfig::default()
+    };
+    assert!(matches!(config.build_reqwest_client(), Err(ProviderHttpError::InvalidProxyUrl(_))));
+  }
+}
@@ -0,0 +1,12 @@
+//! HTTP plumbing shared by the provider API clients (FAL, GmiCloud, Muapi, Replicate, etc).
+//!
+//!  - `BaseUrl`: a validated, injectable API root, so clients can be pointed at a local
+//!    stand-in server or a different region.
+//!  - `HttpClientConfig`: timeouts, egress proxy, and user agent.
+//!  - `ProcessDefault`: a process-wide, swappable default, so a pooled client can be
+//!    shared across calls instead of paying for a TLS handshake every time.
+
+pub mod base_url;
+pub mod http_client_config;
+pub mod process_default;
+pub mod provider_http_error;
@@ -0,0 +1,79 @@
+use std::sync::{Arc, OnceLock, RwLock};
+
+use log::warn;
+
+use crate::http_client_config::HttpClientConfig;
+use crate::provider_http_error::ProviderHttpError;
+
+/// A process-wide default that is built lazily and can be replaced at startup (or by
+/// an integration test pointing a client at a local stand-in server).
+///
+/// Readers get a cheap `Arc` clone, so a value fetched before `set` keeps working.
+pub struct ProcessDefault<T> {
+  init: fn() -> T,
+  cell: OnceLock<RwLock<Arc<T>>>,
+}
+
+impl<T> ProcessDefault<T> {
+  pub const fn new(init: fn() -> T) -> Self {
+    Self { init, cell: OnceLock::new() }
+  }
+
+  pub fn get(&self) -> Arc<T> {
+    let lock = self.lock();
+    let value = lock.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    value.clone()
+  }
+
+  pub fn set(&self, value: T) {
+    let lock = self.lock();
+    let mut current = lock.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    *current = Arc::new(value);
+  }
+
+  fn lock(&self) -> &RwLock<Arc<T>> {
+    self.cell.get_or_init(|| RwLock::new(Arc::new((self.init)())))
+  }
+}
+
+static SHARED_REQWEST_CLIENT: ProcessDefault<reqwest::Client> = ProcessDefault::new(build_default_reqwest_client);
+
+/// The pooled reqwest client shared by the provider crates. Clones share the pool.
+pub fn shared_reqwest_client() -> reqwest::Client {
+  SHARED_REQWEST_CLIENT.get().as_ref().clone()
+}
+
+/// Rebuild the shared client, eg. to route through an egress proxy. Clients that were
+/// already handed out keep their old settings.
+pub fn configure_shared_reqwest_client(config: &HttpClientConfig) -> Result<(), ProviderHttpError> {
+  SHARED_REQWEST_CLIENT.set(config.build_reqwest_client()?);
+  Ok(())
+}
+
+fn build_default_reqwest_client() -> reqwest::Client {
+  HttpClientConfig::from_env()
+    .build_reqwest_client()
+    .unwrap_or_else(|err| {
+      warn!("Ignoring bad provider HTTP settings: {}", err);
+      reqwest::Client::new()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  static COUNTER: ProcessDefault<u32> = ProcessDefault::new(|| 1);
+
+  #[test]
+  fn lazily_initialized_and_replaceable() {
+    let before = COUNTER.get();
+    assert_eq!(*before, 1);
+
+    COUNTER.set(2);
+    assert_eq!(*COUNTER.get(), 2);
+
+    // Old handles are unaffected.
+    assert_eq!(*before, 1);
+  }
+}
@@ -0,0 +1,26 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum ProviderHttpError {
+  /// The base URL couldn't be parsed, or isn't http(s).
+  InvalidBaseUrl(String),
+
+  /// The proxy URL was rejected.
+  InvalidProxyUrl(String),
+
+  /// The underlying HTTP library couldn't build a client.
+  ClientBuildError(String),
+}
+
+impl Error for ProviderHttpError {}
+
+impl Display for ProviderHttpError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidBaseUrl(url) => write!(f, "Invalid base URL: {}", url),
+      Self::InvalidProxyUrl(url) => write!(f, "Invalid proxy URL: {}", url),
+      Self::ClientBuildError(reason) => write!(f, "Could not build HTTP client: {}", reason),
+    }
+  }
+}
@@ -0,0 +1,10 @@
+//! two_factor
+//!
+//! TOTP (RFC 6238) second factors and single-use recovery codes for user accounts.
+//! Storage and the login flow live with the callers; this crate has no database access.
+//!
+
+pub mod recovery_codes;
+pub mod totp_authenticator;
+pub mod two_factor_code;
+pub mod two_factor_error;
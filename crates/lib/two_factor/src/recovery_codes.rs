@@ -0,0 +1,87 @@
+use password::bcrypt_confirm_password::bcrypt_confirm_password;
+use password::bcrypt_hash_password::bcrypt_hash_password;
+use rand::seq::IndexedRandom;
+
+use crate::two_factor_error::TwoFactorError;
+
+/// How many recovery codes a user gets at a time.
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Lowercase, without look-alike characters (0/o, 1/l/i).
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// Characters on either side of the dash, eg. "x7kqm-2nd4p".
+const RECOVERY_CODE_HALF_LENGTH: usize = 5;
+
+/// Generate a fresh set of recovery codes, formatted for display.
+/// Only show these to the user once; store `hash_recovery_code` of each.
+pub fn generate_recovery_codes() -> Vec<String> {
+  let mut rng = rand::rng();
+
+  (0..RECOVERY_CODE_COUNT)
+      .map(|_| {
+        let mut code = String::with_capacity(RECOVERY_CODE_HALF_LENGTH * 2 + 1);
+        for i in 0..RECOVERY_CODE_HALF_LENGTH * 2 {
+          if i == RECOVERY_CODE_HALF_LENGTH {
+            code.push('-');
+          }
+          let c = RECOVERY_CODE_ALPHABET.choose(&mut rng).copied().unwrap_or(b'a');
+          code.push(c as char);
+        }
+        code
+      })
+      .collect()
+}
+
+/// Users retype these codes; ignore case, whitespace, and dashes.
+pub fn normalize_recovery_code(code: &str) -> String {
+  code.chars()
+      .filter(|c| !c.is_whitespace() && *c != '-')
+      .map(|c| c.to_ascii_lowercase())
+      .collect()
+}
+
+/// Recovery codes are stored the same way as passwords.
+pub fn hash_recovery_code(code: &str) -> Result<String, TwoFactorError> {
+  Ok(bcrypt_hash_password(normalize_recovery_code(code))?)
+}
+
+pub fn recovery_code_matches(code: &str, bcrypt_hash: &str) -> bool {
+  bcrypt_confirm_password(normalize_recovery_code(code), bcrypt_hash)
+      .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+
+  use crate::recovery_codes::{generate_recovery_codes, hash_recovery_code, normalize_recovery_code, recovery_code_matches, RECOVERY_CODE_COUNT};
+
+  #[test]
+  fn test_generate() {
+    let codes = generate_recovery_codes();
+    assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+    assert_eq!(codes.iter().collect::<HashSet<_>>().len(), RECOVERY_CODE_COUNT);
+
+    for code in codes {
+      assert_eq!(code.len(), 11);
+      assert_eq!(code.chars().nth(5), Some('-'));
+    }
+  }
+
+  #[test]
+  fn test_normalize() {
+    assert_eq!(normalize_recovery_code(" X7KQM-2nd4p\n"), "x7kqm2nd4p");
+    assert_eq!(normalize_recovery_code("x7kqm 2nd4p"), "x7kqm2nd4p");
+  }
+
+  #[test]
+  fn test_hash_round_trip() {
+    // NB: bcrypt is slow; keep this to a single hash.
+    let hash = hash_recovery_code("x7kqm-2nd4p").unwrap();
+
+    assert!(recovery_code_matches("X7KQM2ND4P", &hash));
+    assert!(!recovery_code_matches("x7kqm-2nd4q", &hash));
+    assert!(!recovery_code_matches("x7kqm-2nd4p", "not a hash"));
+  }
+}
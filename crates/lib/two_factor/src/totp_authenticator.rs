@@ -0,0 +1,122 @@
+use constant_time_eq::constant_time_eq;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+use crate::two_factor_error::TwoFactorError;
+
+/// Six digit codes with a 30 second step; what every authenticator app expects.
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP_SECONDS: u64 = 30;
+
+/// Accept codes from one step either side of now to tolerate clock drift.
+const ALLOWED_SKEW_STEPS: u64 = 1;
+
+/// Generates and checks codes for a single user's TOTP secret.
+pub struct TotpAuthenticator {
+  totp: TOTP,
+}
+
+impl TotpAuthenticator {
+  /// A new random 160-bit secret, base32 encoded (the form stored and shown to users).
+  pub fn generate_secret_base32() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+  }
+
+  /// `issuer` and `account_name` only label the entry in the user's authenticator app.
+  pub fn new(secret_base32: &str, issuer: &str, account_name: &str) -> Result<Self, TwoFactorError> {
+    let secret = Secret::Encoded(secret_base32.to_string())
+        .to_bytes()
+        .map_err(|err| TwoFactorError::InvalidSecret(format!("{:?}", err)))?;
+
+    // NB: The otpauth URI format reserves ':' to separate the issuer from the account name.
+    let totp = TOTP::new(
+      Algorithm::SHA1,
+      TOTP_DIGITS,
+      ALLOWED_SKEW_STEPS as u8,
+      TOTP_STEP_SECONDS,
+      secret,
+      Some(issuer.replace(':', "")),
+      account_name.replace(':', ""),
+    ).map_err(|err| TwoFactorError::InvalidSecret(format!("{:?}", err)))?;
+
+    Ok(Self { totp })
+  }
+
+  /// The `otpauth://` URI that clients render as a QR code for enrollment.
+  pub fn provisioning_uri(&self) -> String {
+    self.totp.get_url()
+  }
+
+  /// Check a code at `unix_time`. Returns the time step the code belongs to, which
+  /// callers persist so the same code can't be replayed within the skew window.
+  pub fn verify_code(&self, code: &str, unix_time: u64) -> Option<u64> {
+    let code = code.trim();
+
+    if code.len() != TOTP_DIGITS || !code.chars().all(|c| c.is_ascii_digit()) {
+      return None;
+    }
+
+    let current_step = unix_time / TOTP_STEP_SECONDS;
+    let first_step = current_step.saturating_sub(ALLOWED_SKEW_STEPS);
+    let last_step = current_step + ALLOWED_SKEW_STEPS;
+
+    (first_step..=last_step).find(|step| {
+      let expected = self.totp.generate(step * TOTP_STEP_SECONDS);
+      constant_time_eq(expected.as_bytes(), code.as_bytes())
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::totp_authenticator::TotpAuthenticator;
+
+  // NB: The RFC 6238 SHA1 test secret, "12345678901234567890", base32 encoded.
+  const RFC_SECRET : &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+  fn authenticator() -> TotpAuthenticator {
+    TotpAuthenticator::new(RFC_SECRET, "ArtCraft", "user@example.com").unwrap()
+  }
+
+  #[test]
+  fn test_rfc_6238_vectors() {
+    // The RFC publishes 8 digit codes; we use the low 6 digits.
+    let authenticator = authenticator();
+    assert_eq!(authenticator.verify_code("287082", 59), Some(1));
+    assert_eq!(authenticator.verify_code("081804", 1111111109), Some(37037036));
+    assert_eq!(authenticator.verify_code("050471", 1111111111), Some(37037037));
+  }
+
+  #[test]
+  fn test_skew() {
+    let authenticator = authenticator();
+    // The code for step 37037036 is accepted one step later, but not two.
+    assert_eq!(authenticator.verify_code("081804", 1111111109 + 30), Some(37037036));
+    assert_eq!(authenticator.verify_code("081804", 1111111109 + 60), None);
+  }
+
+  #[test]
+  fn test_rejects_malformed_codes() {
+    let authenticator = authenticator();
+    assert_eq!(authenticator.verify_code("", 59), None);
+    assert_eq!(authenticator.verify_code("28708", 59), None);
+    assert_eq!(authenticator.verify_code("2870822", 59), None);
+    assert_eq!(authenticator.verify_code("28708a", 59), None);
+  }
+
+  #[test]
+  fn test_generated_secret_round_trip() {
+    let secret = TotpAuthenticator::generate_secret_base32();
+    let authenticator = TotpAuthenticator::new(&secret, "ArtCraft", "someone").unwrap();
+
+    let code = authenticator.totp.generate(1_700_000_000);
+    assert_eq!(authenticator.verify_code(&code, 1_700_000_000), Some(1_700_000_000 / 30));
+  }
+
+  #[test]
+  fn test_provisioning_uri() {
+    let uri = TotpAuthenticator::new(RFC_SECRET, "Art:Craft", "user:name").unwrap().provisioning_uri();
+    assert!(uri.starts_with("otpauth://totp/ArtCraft:username?"));
+    assert!(uri.contains(&format!("secret={}", RFC_SECRET)));
+    assert!(uri.contains("issuer=ArtCraft"));
+  }
+}
@@ -0,0 +1,40 @@
+/// What the user typed at a second factor prompt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TwoFactorCode {
+  /// Six digits from an authenticator app.
+  Totp(String),
+  /// One of the user's single-use recovery codes.
+  RecoveryCode(String),
+}
+
+impl TwoFactorCode {
+  /// A single input field accepts either kind of code; they can't be confused.
+  pub fn parse(input: &str) -> Option<Self> {
+    let input = input.trim();
+
+    if input.is_empty() {
+      return None;
+    }
+
+    let digits: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if digits.len() == 6 && digits.chars().all(|c| c.is_ascii_digit()) {
+      Some(Self::Totp(digits))
+    } else {
+      Some(Self::RecoveryCode(input.to_string()))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::two_factor_code::TwoFactorCode;
+
+  #[test]
+  fn test_parse() {
+    assert_eq!(TwoFactorCode::parse(" 123456 "), Some(TwoFactorCode::Totp("123456".to_string())));
+    assert_eq!(TwoFactorCode::parse("123 456"), Some(TwoFactorCode::Totp("123456".to_string())));
+    assert_eq!(TwoFactorCode::parse("x7kqm-2nd4p"), Some(TwoFactorCode::RecoveryCode("x7kqm-2nd4p".to_string())));
+    assert_eq!(TwoFactorCode::parse("   "), None);
+  }
+}
@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use password::errors::password_hash_error::PasswordHashError;
+
+#[derive(Debug)]
+pub enum TwoFactorError {
+  /// The stored TOTP secret could not be decoded, or the TOTP parameters were rejected.
+  InvalidSecret(String),
+  /// Failure to hash a recovery code.
+  HashError(PasswordHashError),
+}
+
+impl Display for TwoFactorError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidSecret(reason) => write!(f, "invalid TOTP secret: {}", reason),
+      Self::HashError(e) => write!(f, "recovery code hash error: {}", e),
+    }
+  }
+}
+
+impl Error for TwoFactorError {}
+
+impl From<PasswordHashError> for TwoFactorError {
+  fn from(value: PasswordHashError) -> Self {
+    Self::HashError(value)
+  }
+}
@@ -12,11 +12,18 @@ use enums::common::visibility::Visibility;
 use tokens::tokens::media_files::MediaFileToken;
 use tokens::tokens::users::UserToken;
 
+use crate::indices::versioned_index::VersionedIndex;
 use crate::traits::document::Document;
 
-/// The current name for the index.
-/// We may need to perform migrations in the future
-pub const MEDIA_FILE_INDEX: &str = "media_files_v1";
+/// The alias searches go through. The physical index behind it is `media_files_vN`.
+pub const MEDIA_FILE_INDEX: &str = "media_files";
+
+/// Bump the version (and add a new definition file) to migrate to a new mapping.
+pub const MEDIA_FILE_VERSIONED_INDEX: VersionedIndex = VersionedIndex {
+  alias: MEDIA_FILE_INDEX,
+  version: 1,
+  definition: include_str!("../../../../../../_database/elasticsearch/index_definitions/media_files_v1.json"),
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MediaFileDocument {
@@ -10,11 +10,18 @@ use tokens::tokens::media_files::MediaFileToken;
 use tokens::tokens::model_weights::ModelWeightToken;
 use tokens::tokens::users::UserToken;
 
+use crate::indices::versioned_index::VersionedIndex;
 use crate::traits::document::Document;
 
-/// The current name for the index.
-/// We may need to perform migrations in the future
-pub const MODEL_WEIGHT_INDEX: &str = "model_weights_v1";
+/// The alias searches go through. The physical index behind it is `model_weights_vN`.
+pub const MODEL_WEIGHT_INDEX: &str = "model_weights";
+
+/// Bump the version (and add a new definition file) to migrate to a new mapping.
+pub const MODEL_WEIGHT_VERSIONED_INDEX: VersionedIndex = VersionedIndex {
+  alias: MODEL_WEIGHT_INDEX,
+  version: 1,
+  definition: include_str!("../../../../../../_database/elasticsearch/index_definitions/model_weights_v1.json"),
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ModelWeightDocument {
@@ -7,12 +7,18 @@ use enums::common::visibility::Visibility;
 use tokens::tokens::tts_models::TtsModelToken;
 use tokens::tokens::users::UserToken;
 
+use crate::indices::versioned_index::VersionedIndex;
 use crate::traits::document::Document;
 
-/// The current name for the TTS model index.
-/// We may need to perform migrations in the future, so this may grow to keeping
-/// tabs of multiple indices in the future.
-pub const TTS_MODEL_INDEX : &str = "tts_model_v1";
+/// The alias searches go through. The physical index behind it is `tts_model_vN`.
+pub const TTS_MODEL_INDEX: &str = "tts_model";
+
+/// Bump the version (and add a new definition file) to migrate to a new mapping.
+pub const TTS_MODEL_VERSIONED_INDEX: VersionedIndex = VersionedIndex {
+  alias: TTS_MODEL_INDEX,
+  version: 1,
+  definition: include_str!("../../../../../../_database/elasticsearch/index_definitions/tts_models_v1.json"),
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct TtsModelDocument {
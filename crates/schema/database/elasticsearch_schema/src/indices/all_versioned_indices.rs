@@ -0,0 +1,49 @@
+use crate::documents::media_file_document::MEDIA_FILE_VERSIONED_INDEX;
+use crate::documents::model_weight_document::MODEL_WEIGHT_VERSIONED_INDEX;
+use crate::documents::tts_model_document::TTS_MODEL_VERSIONED_INDEX;
+use crate::indices::versioned_index::VersionedIndex;
+
+pub const ALL_VERSIONED_INDICES : [VersionedIndex; 3] = [
+  MEDIA_FILE_VERSIONED_INDEX,
+  MODEL_WEIGHT_VERSIONED_INDEX,
+  TTS_MODEL_VERSIONED_INDEX,
+];
+
+/// Look up a versioned index by its alias, eg. "media_files".
+pub fn find_versioned_index(alias: &str) -> Option<VersionedIndex> {
+  ALL_VERSIONED_INDICES.iter()
+      .find(|index| index.alias == alias)
+      .copied()
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::Value;
+
+  use crate::indices::all_versioned_indices::{ALL_VERSIONED_INDICES, find_versioned_index};
+
+  #[test]
+  fn test_definitions_have_mappings() {
+    for index in ALL_VERSIONED_INDICES.iter() {
+      let definition : Value = serde_json::from_str(index.definition).unwrap();
+      assert!(definition["mappings"].is_object(), "{} has no mappings", index.alias);
+    }
+  }
+
+  #[test]
+  fn test_aliases_never_collide_with_index_names() {
+    for index in ALL_VERSIONED_INDICES.iter() {
+      for other in ALL_VERSIONED_INDICES.iter() {
+        assert_ne!(index.alias, other.index_name());
+        assert_ne!(index.migration_alias(), other.index_name());
+      }
+    }
+  }
+
+  #[test]
+  fn test_find_versioned_index() {
+    assert_eq!(find_versioned_index("media_files").map(|index| index.alias), Some("media_files"));
+    assert_eq!(find_versioned_index("tts_model").map(|index| index.index_name()), Some("tts_model_v1".to_string()));
+    assert!(find_versioned_index("media_files_v1").is_none());
+  }
+}
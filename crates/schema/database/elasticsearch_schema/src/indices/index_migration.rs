@@ -0,0 +1,439 @@
+use elasticsearch::Elasticsearch;
+use elasticsearch::indices::{IndicesCreateParts, IndicesDeleteParts, IndicesExistsParts, IndicesGetMappingParts, IndicesPutMappingParts};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use errors::{AnyhowResult, anyhow, bail};
+
+use crate::indices::index_state::{get_index_state, IndexState};
+use crate::indices::versioned_index::VersionedIndex;
+
+/// Backfill bookkeeping, stored in the target index's mapping `_meta` so that an
+/// interrupted backfill resumes where it left off (even from another machine).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackfillProgress {
+  pub mapping_version: u32,
+
+  /// The last MySQL cursor that was fully written to the index.
+  pub backfill_cursor: u64,
+
+  pub backfill_complete: bool,
+}
+
+/// What `begin_migration` set up.
+#[derive(Clone, Debug)]
+pub struct MigrationPlan {
+  /// The index being backfilled.
+  pub target_index: String,
+
+  /// Indices the read alias pointed to before the migration (not including the target).
+  pub previous_indices: Vec<String>,
+
+  /// Where the backfill should (re)start.
+  pub start_cursor: u64,
+}
+
+/// Point the read alias at the existing physical index if nothing is behind it yet.
+///
+/// Indices created before aliases existed (eg. `media_files_v1`) are served directly, so
+/// the alias has to be attached before readers switch to it. Picks the newest existing
+/// version that isn't mid-migration. Returns the index the alias was attached to, if any.
+pub async fn bootstrap_read_alias(client: &Elasticsearch, index: &VersionedIndex) -> AnyhowResult<Option<String>> {
+  let state = get_index_state(client, index).await?;
+
+  if !state.live_indices.is_empty() {
+    return Ok(None);
+  }
+
+  for candidate in bootstrap_candidates(index, &state) {
+    if !index_exists(client, &candidate).await? {
+      continue;
+    }
+
+    info!("Attaching alias {} to existing index {}", index.alias, candidate);
+
+    update_aliases(client, vec![
+      json!({ "add": { "index": &candidate, "alias": index.alias } }),
+    ]).await?;
+
+    return Ok(Some(candidate));
+  }
+
+  Ok(None)
+}
+
+/// Create the declared version of the index (if needed) and start routing writes to it.
+///
+/// If the read alias already points at the declared version, this is a refresh: nothing
+/// is re-pointed, and the backfill starts over unless an earlier refresh was interrupted.
+pub async fn begin_migration(client: &Elasticsearch, index: &VersionedIndex) -> AnyhowResult<MigrationPlan> {
+  let target_index = index.index_name();
+
+  // NB: Must happen before anything else, or readers of the alias 404 for the whole backfill.
+  bootstrap_read_alias(client, index).await?;
+
+  let state = get_index_state(client, index).await?;
+
+  info!("Index state for {}: {:?}", index.alias, state);
+
+  if let Some(other) = state.migrating_indices.iter().find(|name| **name != target_index) {
+    bail!("A migration of {} to {} is already underway; finish or abort it first.", index.alias, other);
+  }
+
+  if !index_exists(client, &target_index).await? {
+    create_versioned_index(client, index).await?;
+  }
+
+  let previous_indices = state.live_indices.iter()
+      .filter(|name| **name != target_index)
+      .cloned()
+      .collect::<Vec<_>>();
+
+  if state.live_indices.contains(&target_index) {
+    let progress = progress_to_resume(get_backfill_progress(client, &target_index).await?, true);
+    info!("{} already serves {}; refreshing documents in place from cursor {}.",
+      index.alias, target_index, progress.backfill_cursor);
+    return Ok(MigrationPlan {
+      target_index,
+      previous_indices,
+      start_cursor: progress.backfill_cursor,
+    });
+  }
+
+  if !state.is_migrating() {
+    info!("Dual-writing {} into {} via {}", index.alias, target_index, index.migration_alias());
+    update_aliases(client, vec![
+      json!({ "add": { "index": &target_index, "alias": index.migration_alias() } }),
+    ]).await?;
+  }
+
+  // NB: Dual-writes keep already-backfilled documents current, so a rerun picks up at the cursor.
+  let progress = progress_to_resume(get_backfill_progress(client, &target_index).await?, false);
+
+  Ok(MigrationPlan {
+    target_index,
+    previous_indices,
+    start_cursor: progress.backfill_cursor,
+  })
+}
+
+/// Atomically point the read alias at the target and stop dual-writing.
+/// The backfill must have been marked complete.
+pub async fn complete_migration(
+  client: &Elasticsearch,
+  index: &VersionedIndex,
+  plan: &MigrationPlan,
+  delete_previous_indices: bool,
+) -> AnyhowResult<()> {
+  let progress = get_backfill_progress(client, &plan.target_index).await?;
+
+  if !progress.backfill_complete {
+    bail!("Backfill of {} isn't complete (cursor = {}); not swapping the alias.",
+      plan.target_index, progress.backfill_cursor);
+  }
+
+  let state = get_index_state(client, index).await?;
+  let actions = alias_swap_actions(index, &state, &plan.target_index);
+
+  if !actions.is_empty() {
+    info!("Swapping alias {} to {}", index.alias, plan.target_index);
+    update_aliases(client, actions).await?;
+  }
+
+  if delete_previous_indices {
+    for previous in plan.previous_indices.iter() {
+      delete_index(client, previous).await?;
+    }
+  } else if !plan.previous_indices.is_empty() {
+    info!("Keeping previous indices {:?}; delete them once the new index checks out.", plan.previous_indices);
+  }
+
+  Ok(())
+}
+
+/// Stop dual-writing and drop the index that was being built. Readers are untouched.
+pub async fn abort_migration(client: &Elasticsearch, index: &VersionedIndex) -> AnyhowResult<()> {
+  let state = get_index_state(client, index).await?;
+
+  if !state.is_migrating() {
+    info!("No migration of {} is underway.", index.alias);
+    return Ok(());
+  }
+
+  let actions = state.migrating_indices.iter()
+      .map(|name| json!({ "remove": { "index": name, "alias": index.migration_alias() } }))
+      .collect::<Vec<_>>();
+
+  update_aliases(client, actions).await?;
+
+  for name in state.migrating_indices.iter() {
+    if state.live_indices.contains(name) {
+      warn!("Not deleting {} because {} still reads from it.", name, index.alias);
+      continue;
+    }
+    delete_index(client, name).await?;
+  }
+
+  Ok(())
+}
+
+/// Physical indices that could be serving readers directly, newest first.
+fn bootstrap_candidates(index: &VersionedIndex, state: &IndexState) -> Vec<String> {
+  (1..=index.version).rev()
+      .map(|version| index.index_name_for_version(version))
+      .filter(|name| !state.migrating_indices.contains(name))
+      .collect()
+}
+
+/// Where a backfill should pick up. A refresh whose last pass finished starts over, since
+/// the point is to rewrite every document; anything interrupted resumes at its cursor.
+fn progress_to_resume(progress: BackfillProgress, is_refresh: bool) -> BackfillProgress {
+  if is_refresh && progress.backfill_complete {
+    BackfillProgress {
+      backfill_cursor: 0,
+      backfill_complete: false,
+      ..progress
+    }
+  } else {
+    progress
+  }
+}
+
+/// Remove the read alias from everything but the target, add it to the target, and drop
+/// the migration alias, all in one request so readers never see a gap.
+fn alias_swap_actions(index: &VersionedIndex, state: &IndexState, target_index: &str) -> Vec<Value> {
+  let mut actions = Vec::new();
+
+  for live in state.live_indices.iter().filter(|name| *name != target_index) {
+    actions.push(json!({ "remove": { "index": live, "alias": index.alias } }));
+  }
+
+  if !state.live_indices.iter().any(|name| name == target_index) {
+    actions.push(json!({ "add": { "index": target_index, "alias": index.alias } }));
+  }
+
+  for migrating in state.migrating_indices.iter() {
+    actions.push(json!({ "remove": { "index": migrating, "alias": index.migration_alias() } }));
+  }
+
+  actions
+}
+
+pub async fn get_backfill_progress(client: &Elasticsearch, index_name: &str) -> AnyhowResult<BackfillProgress> {
+  let response = client
+      .indices()
+      .get_mapping(IndicesGetMappingParts::Index(&[index_name]))
+      .send()
+      .await?;
+
+  if !response.status_code().is_success() {
+    bail!("Error reading mapping for {}: {}", index_name, response.text().await?);
+  }
+
+  let json: Value = response.json().await?;
+
+  let progress = match json.pointer(&format!("/{}/mappings/_meta", index_name)) {
+    Some(meta) => serde_json::from_value(meta.clone()).unwrap_or_default(),
+    None => BackfillProgress::default(),
+  };
+
+  Ok(progress)
+}
+
+pub async fn set_backfill_progress(client: &Elasticsearch, index_name: &str, progress: BackfillProgress) -> AnyhowResult<()> {
+  // NB: Elasticsearch replaces `_meta` wholesale, so we always write every field.
+  let response = client
+      .indices()
+      .put_mapping(IndicesPutMappingParts::Index(&[index_name]))
+      .body(json!({ "_meta": progress }))
+      .send()
+      .await?;
+
+  if !response.status_code().is_success() {
+    bail!("Error saving backfill progress for {}: {}", index_name, response.text().await?);
+  }
+
+  Ok(())
+}
+
+async fn create_versioned_index(client: &Elasticsearch, index: &VersionedIndex) -> AnyhowResult<()> {
+  let index_name = index.index_name();
+
+  info!("Creating Elasticsearch index: {:?}", index_name);
+
+  let definition = definition_with_meta(index)?;
+
+  let response = client
+      .indices()
+      .create(IndicesCreateParts::Index(&index_name))
+      .body(definition)
+      .send()
+      .await?;
+
+  if !response.status_code().is_success() {
+    bail!("Error while creating index {}: {}", index_name, response.text().await?);
+  }
+
+  Ok(())
+}
+
+fn definition_with_meta(index: &VersionedIndex) -> AnyhowResult<Value> {
+  // NB: As in `create_index_if_not_exists`, the body has to be a bound `Value`, not a string.
+  let mut definition : Value = serde_json::from_str(index.definition)?;
+
+  let mappings = definition.get_mut("mappings")
+      .and_then(|mappings| mappings.as_object_mut())
+      .ok_or_else(|| anyhow!("Index definition for {} has no mappings", index.alias))?;
+
+  let progress = BackfillProgress {
+    mapping_version: index.version,
+    ..Default::default()
+  };
+
+  mappings.insert("_meta".to_string(), serde_json::to_value(progress)?);
+
+  Ok(definition)
+}
+
+async fn index_exists(client: &Elasticsearch, index_name: &str) -> AnyhowResult<bool> {
+  let response = client
+      .indices()
+      .exists(IndicesExistsParts::Index(&[index_name]))
+      .send()
+      .await?;
+
+  Ok(response.status_code().is_success())
+}
+
+async fn delete_index(client: &Elasticsearch, index_name: &str) -> AnyhowResult<()> {
+  info!("Deleting Elasticsearch index: {:?}", index_name);
+
+  let response = client
+      .indices()
+      .delete(IndicesDeleteParts::Index(&[index_name]))
+      .send()
+      .await?;
+
+  if !response.status_code().is_success() {
+    bail!("Error deleting index {}: {}", index_name, response.text().await?);
+  }
+
+  Ok(())
+}
+
+async fn update_aliases(client: &Elasticsearch, actions: Vec<Value>) -> AnyhowResult<()> {
+  let response = client
+      .indices()
+      .update_aliases()
+      .body(json!({ "actions": actions }))
+      .send()
+      .await?;
+
+  if !response.status_code().is_success() {
+    bail!("Error updating aliases: {}", response.text().await?);
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use crate::indices::index_migration::{alias_swap_actions, bootstrap_candidates, definition_with_meta, progress_to_resume, BackfillProgress};
+  use crate::indices::index_state::IndexState;
+  use crate::indices::versioned_index::VersionedIndex;
+
+  const INDEX : VersionedIndex = VersionedIndex {
+    alias: "media_files",
+    version: 2,
+    definition: r#"{ "settings": {}, "mappings": { "properties": { "token": { "type": "keyword" } } } }"#,
+  };
+
+  #[test]
+  fn test_swap_from_previous_version() {
+    let state = IndexState {
+      live_indices: vec!["media_files_v1".to_string()],
+      migrating_indices: vec!["media_files_v2".to_string()],
+    };
+    assert_eq!(alias_swap_actions(&INDEX, &state, "media_files_v2"), vec![
+      json!({ "remove": { "index": "media_files_v1", "alias": "media_files" } }),
+      json!({ "add": { "index": "media_files_v2", "alias": "media_files" } }),
+      json!({ "remove": { "index": "media_files_v2", "alias": "media_files_migrating" } }),
+    ]);
+  }
+
+  #[test]
+  fn test_swap_without_existing_alias() {
+    let state = IndexState {
+      live_indices: vec![],
+      migrating_indices: vec!["media_files_v2".to_string()],
+    };
+    assert_eq!(alias_swap_actions(&INDEX, &state, "media_files_v2"), vec![
+      json!({ "add": { "index": "media_files_v2", "alias": "media_files" } }),
+      json!({ "remove": { "index": "media_files_v2", "alias": "media_files_migrating" } }),
+    ]);
+  }
+
+  #[test]
+  fn test_swap_is_noop_when_already_live() {
+    let state = IndexState {
+      live_indices: vec!["media_files_v2".to_string()],
+      migrating_indices: vec![],
+    };
+    assert!(alias_swap_actions(&INDEX, &state, "media_files_v2").is_empty());
+  }
+
+  #[test]
+  fn test_bootstrap_candidates_newest_first() {
+    let state = IndexState {
+      live_indices: vec![],
+      migrating_indices: vec![],
+    };
+    assert_eq!(bootstrap_candidates(&INDEX, &state), vec!["media_files_v2", "media_files_v1"]);
+  }
+
+  #[test]
+  fn test_bootstrap_candidates_skip_migration_target() {
+    let state = IndexState {
+      live_indices: vec![],
+      migrating_indices: vec!["media_files_v2".to_string()],
+    };
+    assert_eq!(bootstrap_candidates(&INDEX, &state), vec!["media_files_v1"]);
+  }
+
+  #[test]
+  fn test_interrupted_refresh_resumes() {
+    let progress = BackfillProgress { mapping_version: 2, backfill_cursor: 5000, backfill_complete: false };
+    assert_eq!(progress_to_resume(progress, true), progress);
+  }
+
+  #[test]
+  fn test_finished_refresh_starts_over() {
+    let progress = BackfillProgress { mapping_version: 2, backfill_cursor: 5000, backfill_complete: true };
+    assert_eq!(progress_to_resume(progress, true), BackfillProgress {
+      mapping_version: 2,
+      backfill_cursor: 0,
+      backfill_complete: false,
+    });
+  }
+
+  #[test]
+  fn test_migration_resumes_even_when_complete() {
+    // Interrupted between finishing the backfill and swapping the alias.
+    let progress = BackfillProgress { mapping_version: 2, backfill_cursor: 5000, backfill_complete: true };
+    assert_eq!(progress_to_resume(progress, false), progress);
+  }
+
+  #[test]
+  fn test_definition_with_meta() {
+    let definition = definition_with_meta(&INDEX).unwrap();
+    assert_eq!(definition["mappings"]["_meta"], json!({
+      "mapping_version": 2,
+      "backfill_cursor": 0,
+      "backfill_complete": false,
+    }));
+    assert_eq!(definition["mappings"]["properties"]["token"]["type"], json!("keyword"));
+  }
+}
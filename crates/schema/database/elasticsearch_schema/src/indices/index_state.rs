@@ -0,0 +1,107 @@
+use elasticsearch::Elasticsearch;
+use elasticsearch::http::StatusCode;
+use elasticsearch::indices::IndicesGetAliasParts;
+use serde_json::Value;
+
+use errors::{AnyhowResult, bail};
+
+use crate::indices::versioned_index::VersionedIndex;
+
+/// Where a versioned index's aliases currently point.
+#[derive(Clone, Debug)]
+pub struct IndexState {
+  /// Indices behind the read alias. Normally exactly one.
+  pub live_indices: Vec<String>,
+
+  /// Indices behind the migration alias. Empty unless a migration is underway.
+  pub migrating_indices: Vec<String>,
+}
+
+impl IndexState {
+  /// Every index a new or updated document must be written to.
+  pub fn write_indices(&self) -> Vec<String> {
+    let mut indices = self.live_indices.clone();
+    for index in self.migrating_indices.iter() {
+      if !indices.contains(index) {
+        indices.push(index.clone());
+      }
+    }
+    indices
+  }
+
+  pub fn is_migrating(&self) -> bool {
+    !self.migrating_indices.is_empty()
+  }
+}
+
+pub async fn get_index_state(client: &Elasticsearch, index: &VersionedIndex) -> AnyhowResult<IndexState> {
+  Ok(IndexState {
+    live_indices: get_alias_indices(client, index.alias).await?,
+    migrating_indices: get_alias_indices(client, &index.migration_alias()).await?,
+  })
+}
+
+/// The physical indices an alias points to, or nothing if the alias doesn't exist.
+pub async fn get_alias_indices(client: &Elasticsearch, alias: &str) -> AnyhowResult<Vec<String>> {
+  let response = client
+      .indices()
+      .get_alias(IndicesGetAliasParts::Name(&[alias]))
+      .send()
+      .await?;
+
+  if response.status_code() == StatusCode::NOT_FOUND {
+    return Ok(Vec::new());
+  }
+
+  if !response.status_code().is_success() {
+    bail!("Error reading alias {}: {}", alias, response.text().await?);
+  }
+
+  let json: Value = response.json().await?;
+
+  Ok(indices_from_alias_response(&json))
+}
+
+fn indices_from_alias_response(json: &Value) -> Vec<String> {
+  let mut indices = json.as_object()
+      .map(|object| object.keys().cloned().collect::<Vec<_>>())
+      .unwrap_or_default();
+  indices.sort();
+  indices
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use crate::indices::index_state::{indices_from_alias_response, IndexState};
+
+  #[test]
+  fn test_indices_from_alias_response() {
+    let response = json!({
+      "media_files_v2": { "aliases": { "media_files": {} } },
+      "media_files_v1": { "aliases": { "media_files": {} } },
+    });
+    assert_eq!(indices_from_alias_response(&response), vec!["media_files_v1", "media_files_v2"]);
+    assert!(indices_from_alias_response(&json!({})).is_empty());
+  }
+
+  #[test]
+  fn test_write_indices_include_migration_target() {
+    let state = IndexState {
+      live_indices: vec!["media_files_v1".to_string()],
+      migrating_indices: vec!["media_files_v2".to_string()],
+    };
+    assert!(state.is_migrating());
+    assert_eq!(state.write_indices(), vec!["media_files_v1", "media_files_v2"]);
+  }
+
+  #[test]
+  fn test_write_indices_are_not_duplicated() {
+    let state = IndexState {
+      live_indices: vec!["media_files_v2".to_string()],
+      migrating_indices: vec!["media_files_v2".to_string()],
+    };
+    assert_eq!(state.write_indices(), vec!["media_files_v2"]);
+  }
+}
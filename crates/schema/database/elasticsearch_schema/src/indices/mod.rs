@@ -0,0 +1,4 @@
+pub mod all_versioned_indices;
+pub mod index_migration;
+pub mod index_state;
+pub mod versioned_index;
@@ -0,0 +1,67 @@
+/// An index that readers only ever reach through an alias.
+///
+/// Physical indices are named `{alias}_v{version}`. Bumping `version` (along with a new
+/// definition file) lets us build the new index next to the live one, backfill it, then
+/// atomically move the alias over without any search downtime.
+#[derive(Clone, Copy, Debug)]
+pub struct VersionedIndex {
+  /// The alias searches and document paths go through.
+  pub alias: &'static str,
+
+  /// The mapping version new indices are created with.
+  pub version: u32,
+
+  /// Settings and mappings for `version`, from `_database/elasticsearch/index_definitions`.
+  pub definition: &'static str,
+}
+
+impl VersionedIndex {
+  /// The physical index for the declared mapping version.
+  pub fn index_name(&self) -> String {
+    self.index_name_for_version(self.version)
+  }
+
+  pub fn index_name_for_version(&self, version: u32) -> String {
+    format!("{}_v{}", self.alias, version)
+  }
+
+  /// While a migration is underway, this alias points at the index being built.
+  /// Writers send documents to both aliases so the new index doesn't fall behind.
+  pub fn migration_alias(&self) -> String {
+    format!("{}_migrating", self.alias)
+  }
+
+  /// Read the version back out of a physical index name.
+  pub fn version_of_index(&self, index_name: &str) -> Option<u32> {
+    index_name.strip_prefix(self.alias)
+        .and_then(|suffix| suffix.strip_prefix("_v"))
+        .and_then(|version| version.parse::<u32>().ok())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::indices::versioned_index::VersionedIndex;
+
+  const INDEX : VersionedIndex = VersionedIndex {
+    alias: "media_files",
+    version: 2,
+    definition: "{}",
+  };
+
+  #[test]
+  fn test_index_names() {
+    assert_eq!(INDEX.index_name(), "media_files_v2");
+    assert_eq!(INDEX.index_name_for_version(1), "media_files_v1");
+    assert_eq!(INDEX.migration_alias(), "media_files_migrating");
+  }
+
+  #[test]
+  fn test_version_of_index() {
+    assert_eq!(INDEX.version_of_index("media_files_v1"), Some(1));
+    assert_eq!(INDEX.version_of_index("media_files_v12"), Some(12));
+    assert_eq!(INDEX.version_of_index("media_files"), None);
+    assert_eq!(INDEX.version_of_index("media_files_vx"), None);
+    assert_eq!(INDEX.version_of_index("model_weights_v1"), None);
+  }
+}
@@ -20,6 +20,7 @@
 #![allow(non_snake_case)]
 
 pub mod documents;
+pub mod indices;
 pub mod searches;
 pub mod traits;
 pub mod utils;
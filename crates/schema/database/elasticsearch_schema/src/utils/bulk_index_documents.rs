@@ -0,0 +1,86 @@
+use elasticsearch::{BulkOperation, BulkParts, Elasticsearch};
+use log::{error, warn};
+use serde::Serialize;
+use serde_json::Value;
+
+use errors::{AnyhowResult, bail};
+
+use crate::indices::index_migration::bootstrap_read_alias;
+use crate::indices::index_state::get_index_state;
+use crate::indices::versioned_index::VersionedIndex;
+use crate::traits::document::Document;
+
+/// Write documents to every index behind the read and migration aliases.
+/// Writers should go through this so an in-progress migration doesn't miss updates.
+pub async fn index_documents<D: Document + Serialize>(
+  client: &Elasticsearch,
+  index: &VersionedIndex,
+  documents: &[D],
+) -> AnyhowResult<()> {
+  let write_indices = get_write_indices(client, index).await?;
+  bulk_index_documents(client, &write_indices, documents).await
+}
+
+/// The indices writers should target right now. Long-running writers can look this up
+/// once per batch rather than once per document.
+pub async fn get_write_indices(client: &Elasticsearch, index: &VersionedIndex) -> AnyhowResult<Vec<String>> {
+  let mut write_indices = get_index_state(client, index).await?.write_indices();
+
+  if write_indices.is_empty() && bootstrap_read_alias(client, index).await?.is_some() {
+    write_indices = get_index_state(client, index).await?.write_indices();
+  }
+
+  if write_indices.is_empty() {
+    warn!("No index behind alias {}; documents won't be written until elasticsearch-cli reindexes it.", index.alias);
+  }
+
+  Ok(write_indices)
+}
+
+/// Write documents to each of the named physical indices.
+pub async fn bulk_index_documents<D: Document + Serialize>(
+  client: &Elasticsearch,
+  index_names: &[String],
+  documents: &[D],
+) -> AnyhowResult<()> {
+  if documents.is_empty() {
+    return Ok(());
+  }
+
+  for index_name in index_names {
+    let ops = documents.iter()
+        .map(|document| BulkOperation::index(document)
+            .id(document.get_document_id())
+            .into())
+        .collect::<Vec<BulkOperation<_>>>();
+
+    let response = client
+        .bulk(BulkParts::Index(index_name))
+        .body(ops)
+        .send()
+        .await?;
+
+    if !response.status_code().is_success() {
+      bail!("Error writing to index {}: {}", index_name, response.text().await?);
+    }
+
+    let json: Value = response.json().await?;
+
+    let had_errors = json["errors"].as_bool().unwrap_or(false);
+
+    if had_errors {
+      let failures = json["items"]
+          .as_array()
+          .map(|items| items.iter()
+              .filter(|item| !item["index"]["error"].is_null())
+              .count())
+          .unwrap_or(0);
+
+      error!("Errors during indexing into {}. Failures: {}", index_name, failures);
+
+      bail!("Errors during indexing into {}. Failures: {}", index_name, failures);
+    }
+  }
+
+  Ok(())
+}
@@ -1 +1,2 @@
-pub mod create_index_if_not_exists;
\ No newline at end of file
+pub mod bulk_index_documents;
+pub mod create_index_if_not_exists;
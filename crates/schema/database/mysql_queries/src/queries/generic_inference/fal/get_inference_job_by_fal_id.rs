@@ -12,6 +12,7 @@ use tokens::tokens::generic_inference_jobs::InferenceJobToken;
 use tokens::tokens::non_unique::debug_logs_event_token::DebugLogEventToken;
 use tokens::tokens::prompts::PromptToken;
 use tokens::tokens::users::UserToken;
+use tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken;
 
 use crate::helpers::boolean_converters::i8_to_bool;
 use crate::payloads::generic_inference_args::generic_inference_args::{GenericInferenceArgs, PolymorphicInferenceArgs};
@@ -32,6 +33,8 @@ pub struct FalJobDetails {
   pub maybe_prompt_token: Option<PromptToken>,
   pub maybe_debug_log_event_token: Option<DebugLogEventToken>,
 
+  pub maybe_wallet_ledger_entry_token: Option<WalletLedgerEntryToken>,
+
   pub created_at: DateTime<Utc>,
   pub updated_at: DateTime<Utc>,
 }
@@ -55,6 +58,8 @@ struct RawJobRecord {
   maybe_prompt_token: Option<PromptToken>,
   maybe_debug_log_event_token: Option<DebugLogEventToken>,
 
+  maybe_wallet_ledger_entry_token: Option<WalletLedgerEntryToken>,
+
   created_at: DateTime<Utc>,
   updated_at: DateTime<Utc>,
 }
@@ -93,6 +98,8 @@ SELECT
     jobs.maybe_prompt_token as `maybe_prompt_token: tokens::tokens::prompts::PromptToken`,
     jobs.maybe_debug_log_event_token as `maybe_debug_log_event_token: tokens::tokens::non_unique::debug_logs_event_token::DebugLogEventToken`,
 
+    jobs.maybe_wallet_ledger_entry_token as `maybe_wallet_ledger_entry_token: tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken`,
+
     jobs.created_at,
     jobs.updated_at
 
@@ -134,6 +141,7 @@ fn raw_record_to_public_result(record: RawJobRecord) -> AnyhowResult<FalJobDetai
     creator_ip_address: record.creator_ip_address,
     maybe_prompt_token: record.maybe_prompt_token,
     maybe_debug_log_event_token: record.maybe_debug_log_event_token,
+    maybe_wallet_ledger_entry_token: record.maybe_wallet_ledger_entry_token,
     created_at: record.created_at,
     updated_at: record.updated_at,
   })
@@ -2,4 +2,4 @@ pub mod get_inference_job_by_fal_id;
 pub mod insert_generic_inference_job_for_fal_queue;
 pub mod insert_generic_inference_job_for_fal_queue_mock_failure;
 pub mod insert_generic_inference_job_for_fal_queue_with_apriori_job_token;
-pub mod mark_fal_generic_inference_job_successfully_done;
\ No newline at end of file
+pub mod mark_fal_generic_inference_job_successfully_done;pub mod set_fal_job_queue_status_url;
@@ -0,0 +1,30 @@
+use anyhow::anyhow;
+use sqlx::{MySql, Transaction};
+
+use errors::AnyhowResult;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+
+/// Store the FAL queue status URL on a job, so it can be cancelled later.
+/// FAL cancels by URL, and the cancel URL is derived from this one.
+pub async fn set_fal_job_queue_status_url(
+  job_token: &InferenceJobToken,
+  queue_status_url: &str,
+  transaction: &mut Transaction<'_, MySql>,
+) -> AnyhowResult<()> {
+  sqlx::query!(
+        r#"
+UPDATE generic_inference_jobs
+SET
+  maybe_external_third_party_queue_url = ?
+WHERE token = ?
+LIMIT 1
+        "#,
+        queue_status_url,
+        job_token,
+    )
+      .execute(&mut **transaction)
+      .await
+      .map_err(|err| anyhow!("error setting FAL queue status url: {:?}", err))?;
+
+  Ok(())
+}
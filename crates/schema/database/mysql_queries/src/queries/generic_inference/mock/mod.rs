@@ -0,0 +1 @@
+pub mod set_job_external_third_party_mock;
@@ -0,0 +1,30 @@
+use anyhow::anyhow;
+use sqlx::{MySql, Transaction};
+
+use enums::by_table::generic_inference_jobs::inference_job_external_third_party::InferenceJobExternalThirdParty;
+use errors::AnyhowResult;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+
+/// Mark a job as belonging to the mock provider. Mock jobs are inserted with the FAL queue
+/// query, but must not be mistaken for FAL jobs (eg. when cancelling them).
+pub async fn set_job_external_third_party_mock(
+  job_token: &InferenceJobToken,
+  transaction: &mut Transaction<'_, MySql>,
+) -> AnyhowResult<()> {
+  sqlx::query!(
+        r#"
+UPDATE generic_inference_jobs
+SET
+  maybe_external_third_party = ?
+WHERE token = ?
+LIMIT 1
+        "#,
+        InferenceJobExternalThirdParty::Mock.to_str(),
+        job_token,
+    )
+      .execute(&mut **transaction)
+      .await
+      .map_err(|err| anyhow!("error marking job as a mock job: {:?}", err))?;
+
+  Ok(())
+}
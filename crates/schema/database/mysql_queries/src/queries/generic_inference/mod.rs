@@ -2,6 +2,7 @@ pub mod beeble;
 pub mod fal;
 pub mod gmicloud;
 pub mod job;
+pub mod mock;
 pub mod moderation;
 pub mod seedance2pro;
 pub mod web;
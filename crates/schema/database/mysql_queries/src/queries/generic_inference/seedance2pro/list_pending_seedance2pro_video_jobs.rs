@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use log::warn;
 use sqlx::MySqlPool;
 
@@ -26,6 +27,9 @@ pub struct PendingSeedance2ProJob {
   pub maybe_prompt_token: Option<PromptToken>,
 
   pub maybe_wallet_ledger_entry_token: Option<WalletLedgerEntryToken>,
+
+  /// Used to back off polling and to expire jobs that never finish.
+  pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Default)]
@@ -38,6 +42,7 @@ struct RawRecord {
   creator_set_visibility: Visibility,
   maybe_prompt_token: Option<PromptToken>,
   maybe_wallet_ledger_entry_token: Option<WalletLedgerEntryToken>,
+  created_at: DateTime<Utc>,
 }
 
 /// Returns all non-terminal Seedance2Pro video jobs that have an associated order_id.
@@ -57,7 +62,8 @@ SELECT
     jobs.creator_ip_address,
     jobs.creator_set_visibility as `creator_set_visibility: enums::common::visibility::Visibility`,
     jobs.maybe_prompt_token as `maybe_prompt_token: tokens::tokens::prompts::PromptToken`,
-    jobs.maybe_wallet_ledger_entry_token as `maybe_wallet_ledger_entry_token: tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken`
+    jobs.maybe_wallet_ledger_entry_token as `maybe_wallet_ledger_entry_token: tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken`,
+    jobs.created_at
 
 FROM generic_inference_jobs as jobs
 
@@ -94,6 +100,7 @@ LIMIT 25000
         creator_set_visibility: record.creator_set_visibility,
         maybe_prompt_token: record.maybe_prompt_token,
         maybe_wallet_ledger_entry_token: record.maybe_wallet_ledger_entry_token,
+        created_at: record.created_at,
       })
     })
     .collect();
@@ -0,0 +1,61 @@
+use anyhow::anyhow;
+use sqlx::pool::PoolConnection;
+use sqlx::MySql;
+
+use enums::by_table::generic_inference_jobs::inference_job_external_third_party::InferenceJobExternalThirdParty;
+use enums::common::job_status_plus::JobStatusPlus;
+use errors::AnyhowResult;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+use tokens::tokens::users::UserToken;
+use tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken;
+
+/// Everything needed to authorize, cancel, and refund a job.
+#[derive(Debug)]
+pub struct InferenceJobForCancellation {
+  pub job_token: InferenceJobToken,
+
+  pub status: JobStatusPlus,
+
+  /// Set when the job runs on a third party (FAL, GmiCloud, etc.) rather than our own workers.
+  pub maybe_external_third_party: Option<InferenceJobExternalThirdParty>,
+  pub maybe_external_third_party_id: Option<String>,
+
+  /// FAL only: the queue status URL, which the cancel URL is derived from.
+  pub maybe_external_third_party_queue_url: Option<String>,
+
+  pub maybe_creator_user_token: Option<UserToken>,
+  pub creator_ip_address: String,
+
+  pub maybe_wallet_ledger_entry_token: Option<WalletLedgerEntryToken>,
+}
+
+/// Returns Ok(None) when the record cannot be found.
+pub async fn get_inference_job_for_cancellation(
+  job_token: &InferenceJobToken,
+  mysql_connection: &mut PoolConnection<MySql>,
+) -> AnyhowResult<Option<InferenceJobForCancellation>> {
+  let maybe_record = sqlx::query_as!(
+    InferenceJobForCancellation,
+    r#"
+SELECT
+    jobs.token as `job_token: tokens::tokens::generic_inference_jobs::InferenceJobToken`,
+    jobs.status as `status: enums::common::job_status_plus::JobStatusPlus`,
+    jobs.maybe_external_third_party as `maybe_external_third_party: enums::by_table::generic_inference_jobs::inference_job_external_third_party::InferenceJobExternalThirdParty`,
+    jobs.maybe_external_third_party_id,
+    jobs.maybe_external_third_party_queue_url,
+    jobs.maybe_creator_user_token as `maybe_creator_user_token: tokens::tokens::users::UserToken`,
+    jobs.creator_ip_address,
+    jobs.maybe_wallet_ledger_entry_token as `maybe_wallet_ledger_entry_token: tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken`
+
+FROM generic_inference_jobs as jobs
+
+WHERE jobs.token = ?
+    "#,
+    job_token,
+  )
+    .fetch_optional(&mut **mysql_connection)
+    .await
+    .map_err(|err| anyhow!("error querying job for cancellation: {:?}", err))?;
+
+  Ok(maybe_record)
+}
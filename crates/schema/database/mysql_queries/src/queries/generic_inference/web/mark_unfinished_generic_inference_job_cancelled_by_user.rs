@@ -0,0 +1,60 @@
+use anyhow::anyhow;
+use sqlx::{MySql, Transaction};
+
+use errors::AnyhowResult;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+
+/// Mark a job as cancelled by the user, but only if it hasn't finished.
+///
+/// Unlike `mark_generic_inference_job_cancelled_by_user`, this reports whether the job
+/// was actually cancelled, so the caller can refund it in the same transaction. With
+/// `only_if_not_started`, jobs that a worker already picked up are left alone.
+///
+/// Returns true if the job was cancelled.
+pub async fn mark_unfinished_generic_inference_job_cancelled_by_user(
+  job_token: &InferenceJobToken,
+  only_if_not_started: bool,
+  transaction: &mut Transaction<'_, MySql>,
+) -> AnyhowResult<bool> {
+  let query_result = if only_if_not_started {
+    sqlx::query!(
+        r#"
+UPDATE generic_inference_jobs
+SET
+  status = 'cancelled_by_user',
+  retry_at = NULL
+WHERE token = ?
+AND status IN (
+  'pending',
+  'attempt_failed'
+ )
+        "#,
+        job_token,
+    )
+      .execute(&mut **transaction)
+      .await
+  } else {
+    sqlx::query!(
+        r#"
+UPDATE generic_inference_jobs
+SET
+  status = 'cancelled_by_user',
+  retry_at = NULL
+WHERE token = ?
+AND status IN (
+  'pending',
+  'started',
+  'attempt_failed'
+ )
+        "#,
+        job_token,
+    )
+      .execute(&mut **transaction)
+      .await
+  };
+
+  let result = query_result
+      .map_err(|err| anyhow!("error with cancel job query: {:?}", err))?;
+
+  Ok(result.rows_affected() > 0)
+}
@@ -1,5 +1,6 @@
 pub mod batch_get_inference_job_status;
 pub mod dismiss_finished_jobs_for_user;
+pub mod get_inference_job_for_cancellation;
 pub mod get_inference_job_status;
 pub mod get_pending_inference_job_count;
 pub mod insert_generic_inference_job;
@@ -9,4 +10,5 @@ pub mod list_user_jobs_for_moderation;
 pub mod kill_jobs_in_development;
 pub mod list_session_jobs;
 pub mod mark_generic_inference_job_cancelled_by_user;
-pub mod mark_generic_inference_job_successfully_done_by_token;
\ No newline at end of file
+pub mod mark_generic_inference_job_successfully_done_by_token;
+pub mod mark_unfinished_generic_inference_job_cancelled_by_user;
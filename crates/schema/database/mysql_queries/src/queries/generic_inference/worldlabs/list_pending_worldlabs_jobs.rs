@@ -1,4 +1,5 @@
 use anyhow::anyhow;
+use chrono::{DateTime, Utc};
 use log::warn;
 use sqlx::MySqlPool;
 
@@ -27,6 +28,9 @@ pub struct PendingWorldlabsJob {
   pub maybe_prompt_token: Option<PromptToken>,
 
   pub maybe_wallet_ledger_entry_token: Option<WalletLedgerEntryToken>,
+
+  /// Used to back off polling and to expire jobs that never finish.
+  pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Default)]
@@ -39,6 +43,7 @@ struct RawRecord {
   creator_set_visibility: Visibility,
   maybe_prompt_token: Option<PromptToken>,
   maybe_wallet_ledger_entry_token: Option<WalletLedgerEntryToken>,
+  created_at: DateTime<Utc>,
 }
 
 /// Returns all non-terminal WorldLabs jobs that have an associated operation_id.
@@ -54,7 +59,8 @@ SELECT
     jobs.creator_ip_address,
     jobs.creator_set_visibility as `creator_set_visibility: enums::common::visibility::Visibility`,
     jobs.maybe_prompt_token as `maybe_prompt_token: tokens::tokens::prompts::PromptToken`,
-    jobs.maybe_wallet_ledger_entry_token as `maybe_wallet_ledger_entry_token: tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken`
+    jobs.maybe_wallet_ledger_entry_token as `maybe_wallet_ledger_entry_token: tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken`,
+    jobs.created_at
 
 FROM generic_inference_jobs as jobs
 
@@ -90,6 +96,7 @@ LIMIT 25000
         creator_set_visibility: record.creator_set_visibility,
         maybe_prompt_token: record.maybe_prompt_token,
         maybe_wallet_ledger_entry_token: record.maybe_wallet_ledger_entry_token,
+        created_at: record.created_at,
       })
     })
     .collect();
@@ -25,6 +25,7 @@ pub mod media_uploads;
 pub mod model_categories;
 pub mod model_weight_usage_counts;
 pub mod model_weights;
+pub mod outbound_emails;
 pub mod prompt_context_items;
 pub mod prompts;
 pub mod public_event_feed;
@@ -46,4 +47,5 @@ pub mod voice_designer;
 pub mod w2l;
 pub mod wallet_ledger_entries;
 pub mod wallets;
-pub mod web_referrals;
\ No newline at end of file
+pub mod web_referrals;
+pub mod webhook_deliveries;
\ No newline at end of file
@@ -0,0 +1,6 @@
+//! Primary and foreign keys
+
+/// Outbound emails are internal, so we'll use IDs as primary keys.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Debug, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(transparent)]
+pub struct OutboundEmailId(pub i64);
@@ -0,0 +1,42 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::outbound_emails::outbound_email_status::OutboundEmailStatus;
+
+use crate::queries::outbound_emails::_keys::OutboundEmailId;
+
+/// Claim a due email for sending and count the attempt. The claim expires after
+/// `claim_seconds`, so the email is retried if the worker dies mid-send.
+///
+/// Returns false if another worker got there first (or the email isn't due).
+pub async fn claim_outbound_email<'e, 'c: 'e, E>(
+  id: OutboundEmailId,
+  claim_seconds: u64,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  let result = sqlx::query!(
+    r#"
+UPDATE outbound_emails
+SET
+  status = ?,
+  attempt_count = attempt_count + 1,
+  next_attempt_at = NOW() + INTERVAL ? SECOND
+WHERE id = ?
+  AND status IN (?, ?, ?)
+  AND next_attempt_at <= NOW()
+LIMIT 1
+    "#,
+    OutboundEmailStatus::Sending.to_str(),
+    claim_seconds,
+    id.0,
+    OutboundEmailStatus::Pending.to_str(),
+    OutboundEmailStatus::AttemptFailed.to_str(),
+    OutboundEmailStatus::Sending.to_str(),
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(result.rows_affected() == 1)
+}
@@ -0,0 +1,59 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::outbound_emails::outbound_email_status::OutboundEmailStatus;
+
+pub struct InsertOutboundEmailArgs<'a> {
+  pub idempotency_key: &'a str,
+  pub email_category: &'a str,
+  pub from_address: &'a str,
+
+  /// JSON array of recipient addresses.
+  pub to_addresses_json: &'a str,
+
+  pub maybe_reply_to_address: Option<&'a str>,
+  pub subject: &'a str,
+  pub html_body: &'a str,
+  pub text_body: &'a str,
+}
+
+/// Enqueue a rendered email for the outbound email worker.
+/// Returns false if an email with the same idempotency key was already enqueued.
+pub async fn insert_outbound_email<'e, 'c: 'e, E>(
+  args: InsertOutboundEmailArgs<'_>,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  let result = sqlx::query!(
+    r#"
+INSERT INTO outbound_emails
+SET
+  idempotency_key = ?,
+  email_category = ?,
+  from_address = ?,
+  to_addresses_json = ?,
+  maybe_reply_to_address = ?,
+  subject = ?,
+  html_body = ?,
+  text_body = ?,
+  status = ?
+ON DUPLICATE KEY UPDATE
+  id = id
+    "#,
+    args.idempotency_key,
+    args.email_category,
+    args.from_address,
+    args.to_addresses_json,
+    args.maybe_reply_to_address,
+    args.subject,
+    args.html_body,
+    args.text_body,
+    OutboundEmailStatus::Pending.to_str(),
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  // MySQL reports one affected row for a fresh insert and none for the no-op update.
+  Ok(result.rows_affected() == 1)
+}
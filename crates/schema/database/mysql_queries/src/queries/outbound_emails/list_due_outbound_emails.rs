@@ -0,0 +1,61 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::outbound_emails::outbound_email_status::OutboundEmailStatus;
+
+use crate::queries::outbound_emails::_keys::OutboundEmailId;
+
+/// table: outbound_emails
+pub struct DueOutboundEmail {
+  pub id: OutboundEmailId,
+  pub idempotency_key: String,
+  pub email_category: String,
+  pub from_address: String,
+  pub to_addresses_json: String,
+  pub maybe_reply_to_address: Option<String>,
+  pub subject: String,
+  pub html_body: String,
+  pub text_body: String,
+  pub status: OutboundEmailStatus,
+
+  /// Attempts made so far, including ones that crashed mid-send.
+  pub attempt_count: u16,
+}
+
+/// Emails ready to be (re)sent: new ones, transient failures whose backoff has elapsed,
+/// and `sending` rows whose claim expired. Oldest first.
+pub async fn list_due_outbound_emails<'e, 'c: 'e, E>(
+  limit: u32,
+  mysql_executor: E,
+) -> Result<Vec<DueOutboundEmail>, sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  sqlx::query_as!(
+    DueOutboundEmail,
+    r#"
+SELECT
+  id as `id: OutboundEmailId`,
+  idempotency_key,
+  email_category,
+  from_address,
+  to_addresses_json,
+  maybe_reply_to_address,
+  subject,
+  html_body,
+  text_body,
+  status as `status: OutboundEmailStatus`,
+  attempt_count
+FROM outbound_emails
+WHERE status IN (?, ?, ?)
+  AND next_attempt_at <= NOW()
+ORDER BY next_attempt_at ASC
+LIMIT ?
+    "#,
+    OutboundEmailStatus::Pending.to_str(),
+    OutboundEmailStatus::AttemptFailed.to_str(),
+    OutboundEmailStatus::Sending.to_str(),
+    limit,
+  )
+    .fetch_all(mysql_executor)
+    .await
+}
@@ -0,0 +1,52 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::outbound_emails::outbound_email_status::OutboundEmailStatus;
+
+use crate::queries::outbound_emails::_keys::OutboundEmailId;
+
+pub enum OutboundEmailFailure<'a> {
+  /// Try again once `retry_after_seconds` have passed.
+  Retry { retry_after_seconds: u64, error: &'a str },
+
+  /// Give up on the email.
+  Dead { error: &'a str },
+}
+
+pub async fn mark_outbound_email_failed<'e, 'c: 'e, E>(
+  id: OutboundEmailId,
+  failure: OutboundEmailFailure<'_>,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  let (status, retry_after_seconds, error) = match failure {
+    OutboundEmailFailure::Retry { retry_after_seconds, error } =>
+      (OutboundEmailStatus::AttemptFailed, retry_after_seconds, error),
+    OutboundEmailFailure::Dead { error } =>
+      (OutboundEmailStatus::Dead, 0, error),
+  };
+
+  // Max length of column is 512 characters
+  let error: String = error.trim().chars().take(512).collect();
+
+  sqlx::query!(
+    r#"
+UPDATE outbound_emails
+SET
+  status = ?,
+  maybe_last_error = ?,
+  next_attempt_at = NOW() + INTERVAL ? SECOND
+WHERE id = ?
+LIMIT 1
+    "#,
+    status.to_str(),
+    error,
+    retry_after_seconds,
+    id.0,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,34 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::outbound_emails::outbound_email_status::OutboundEmailStatus;
+
+use crate::queries::outbound_emails::_keys::OutboundEmailId;
+
+pub async fn mark_outbound_email_sent<'e, 'c: 'e, E>(
+  id: OutboundEmailId,
+  maybe_provider_message_id: Option<&str>,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  sqlx::query!(
+    r#"
+UPDATE outbound_emails
+SET
+  status = ?,
+  maybe_last_error = NULL,
+  maybe_provider_message_id = ?,
+  sent_at = NOW()
+WHERE id = ?
+LIMIT 1
+    "#,
+    OutboundEmailStatus::Sent.to_str(),
+    maybe_provider_message_id,
+    id.0,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod _keys;
+pub mod claim_outbound_email;
+pub mod insert_outbound_email;
+pub mod list_due_outbound_emails;
+pub mod mark_outbound_email_failed;
+pub mod mark_outbound_email_sent;
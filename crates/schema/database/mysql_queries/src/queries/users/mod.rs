@@ -2,9 +2,12 @@ pub mod user;
 pub mod user_badges;
 pub mod user_bookmarks;
 pub mod user_password_resets;
+pub mod user_personal_access_tokens;
 pub mod user_profiles;
+pub mod user_rate_limit_overrides;
 pub mod user_ratings;
 pub mod user_roles;
 pub mod user_sessions;
 pub mod user_stripe_customer_links;
 pub mod user_subscriptions;
+pub mod user_two_factor;
@@ -0,0 +1,34 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+/// Mark the user's email address as confirmed, but only if it's still the address
+/// the verification was issued for. Returns false if no user record matched.
+pub async fn set_email_confirmed<'e, 'c, E>(
+  user_token: &'e UserToken,
+  email_address: &'e str,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  // NB: Always bumping the version means re-confirming an already
+  // confirmed address still reports an affected row.
+  let query_result = sqlx::query!(
+    r#"
+UPDATE users
+SET
+    email_confirmed = true,
+    version = version + 1
+
+WHERE users.token = ?
+AND users.email_address = ?
+LIMIT 1
+    "#,
+    user_token,
+    email_address,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(query_result.rows_affected() > 0)
+}
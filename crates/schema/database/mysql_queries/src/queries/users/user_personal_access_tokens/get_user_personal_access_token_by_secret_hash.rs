@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use tokens::tokens::users::UserToken;
+
+pub struct UserPersonalAccessTokenForAuth {
+  pub token: PersonalAccessTokenToken,
+  pub user_token: UserToken,
+
+  /// Comma separated `PersonalAccessTokenScope` values.
+  pub scopes: String,
+
+  pub maybe_expires_at: Option<DateTime<Utc>>,
+}
+
+/// Look up a non-revoked token by the hash of its secret. Expiry is left to the caller.
+pub async fn get_user_personal_access_token_by_secret_hash<'e, 'c, E>(
+  secret_hash: &'e str,
+  mysql_executor: E,
+) -> Result<Option<UserPersonalAccessTokenForAuth>, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let result = sqlx::query_as!(
+    UserPersonalAccessTokenForAuth,
+    r#"
+SELECT
+  token as `token: tokens::tokens::personal_access_tokens::PersonalAccessTokenToken`,
+  user_token as `user_token: tokens::tokens::users::UserToken`,
+  scopes,
+  maybe_expires_at as `maybe_expires_at: DateTime<Utc>`
+FROM user_personal_access_tokens
+WHERE secret_hash = ?
+AND maybe_revoked_at IS NULL
+LIMIT 1
+    "#,
+    secret_hash,
+  )
+    .fetch_one(mysql_executor)
+    .await;
+
+  match result {
+    Ok(record) => Ok(Some(record)),
+    Err(sqlx::Error::RowNotFound) => Ok(None),
+    Err(err) => Err(err),
+  }
+}
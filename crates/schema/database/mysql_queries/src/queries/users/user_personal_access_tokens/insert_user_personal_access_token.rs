@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use tokens::tokens::users::UserToken;
+
+pub struct InsertUserPersonalAccessTokenArgs<'a> {
+  pub token: &'a PersonalAccessTokenToken,
+  pub user_token: &'a UserToken,
+  pub name: &'a str,
+
+  /// The first few characters of the secret, shown in listings.
+  pub secret_prefix: &'a str,
+
+  /// Hex encoded SHA-256 of the secret.
+  pub secret_hash: &'a str,
+
+  /// Comma separated `PersonalAccessTokenScope` values.
+  pub scopes: &'a str,
+
+  pub maybe_spend_limit_credits: Option<u64>,
+  pub maybe_expires_at: Option<DateTime<Utc>>,
+  pub ip_address_creation: &'a str,
+}
+
+pub async fn insert_user_personal_access_token<'e, 'c, E>(
+  args: InsertUserPersonalAccessTokenArgs<'e>,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query!(
+    r#"
+INSERT INTO user_personal_access_tokens
+SET
+  token = ?,
+  user_token = ?,
+  name = ?,
+  secret_prefix = ?,
+  secret_hash = ?,
+  scopes = ?,
+  maybe_spend_limit_credits = ?,
+  maybe_expires_at = ?,
+  ip_address_creation = ?
+    "#,
+    args.token,
+    args.user_token,
+    args.name,
+    args.secret_prefix,
+    args.secret_hash,
+    args.scopes,
+    args.maybe_spend_limit_credits,
+    args.maybe_expires_at,
+    args.ip_address_creation,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use tokens::tokens::users::UserToken;
+
+pub struct UserPersonalAccessTokenListItem {
+  pub token: PersonalAccessTokenToken,
+  pub name: String,
+  pub secret_prefix: String,
+
+  /// Comma separated `PersonalAccessTokenScope` values.
+  pub scopes: String,
+
+  pub maybe_spend_limit_credits: Option<u64>,
+  pub credits_spent: u64,
+
+  pub use_count: u64,
+  pub maybe_last_used_at: Option<DateTime<Utc>>,
+  pub maybe_last_used_ip_address: Option<String>,
+
+  pub maybe_expires_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+}
+
+/// Tokens that haven't been revoked (including expired ones), newest first.
+pub async fn list_user_personal_access_tokens<'e, 'c, E>(
+  user_token: &'e UserToken,
+  mysql_executor: E,
+) -> Result<Vec<UserPersonalAccessTokenListItem>, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query_as!(
+    UserPersonalAccessTokenListItem,
+    r#"
+SELECT
+  token as `token: tokens::tokens::personal_access_tokens::PersonalAccessTokenToken`,
+  name,
+  secret_prefix,
+  scopes,
+  maybe_spend_limit_credits,
+  credits_spent,
+  use_count,
+  maybe_last_used_at as `maybe_last_used_at: DateTime<Utc>`,
+  maybe_last_used_ip_address,
+  maybe_expires_at as `maybe_expires_at: DateTime<Utc>`,
+  created_at as `created_at: DateTime<Utc>`
+FROM user_personal_access_tokens
+WHERE user_token = ?
+AND maybe_revoked_at IS NULL
+ORDER BY id DESC
+    "#,
+    user_token,
+  )
+    .fetch_all(mysql_executor)
+    .await
+}
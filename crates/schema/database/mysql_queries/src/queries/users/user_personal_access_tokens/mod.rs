@@ -0,0 +1,7 @@
+pub mod get_user_personal_access_token_by_secret_hash;
+pub mod insert_user_personal_access_token;
+pub mod list_user_personal_access_tokens;
+pub mod record_user_personal_access_token_use;
+pub mod release_user_personal_access_token_spend;
+pub mod reserve_user_personal_access_token_spend;
+pub mod revoke_user_personal_access_token;
@@ -0,0 +1,30 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+
+pub async fn record_user_personal_access_token_use<'e, 'c, E>(
+  token: &'e PersonalAccessTokenToken,
+  ip_address: &'e str,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query!(
+    r#"
+UPDATE user_personal_access_tokens
+SET
+  use_count = use_count + 1,
+  maybe_last_used_at = NOW(),
+  maybe_last_used_ip_address = ?
+
+WHERE token = ?
+LIMIT 1
+    "#,
+    ip_address,
+    token,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,31 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+
+/// Undo a `reserve_user_personal_access_token_spend` when the charge didn't go through.
+pub async fn release_user_personal_access_token_spend<'e, 'c, E>(
+  token: &'e PersonalAccessTokenToken,
+  credits: u64,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  // NB: The column is unsigned, so clamp rather than underflow.
+  sqlx::query!(
+    r#"
+UPDATE user_personal_access_tokens
+SET
+  credits_spent = IF(credits_spent > ?, credits_spent - ?, 0)
+
+WHERE token = ?
+LIMIT 1
+    "#,
+    credits,
+    credits,
+    token,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,36 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+
+/// Count `credits` against the token's spend limit. Returns false (and changes nothing)
+/// if that would take the token over its limit. Tokens without a limit always succeed.
+pub async fn reserve_user_personal_access_token_spend<'e, 'c, E>(
+  token: &'e PersonalAccessTokenToken,
+  credits: u64,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let query_result = sqlx::query!(
+    r#"
+UPDATE user_personal_access_tokens
+SET
+  credits_spent = credits_spent + ?
+
+WHERE token = ?
+AND maybe_revoked_at IS NULL
+AND (
+  maybe_spend_limit_credits IS NULL
+  OR credits_spent + ? <= maybe_spend_limit_credits
+)
+LIMIT 1
+    "#,
+    credits,
+    token,
+    credits,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(query_result.rows_affected() > 0)
+}
@@ -0,0 +1,32 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use tokens::tokens::users::UserToken;
+
+/// Returns false if the token doesn't exist, belongs to someone else, or is already revoked.
+pub async fn revoke_user_personal_access_token<'e, 'c, E>(
+  token: &'e PersonalAccessTokenToken,
+  user_token: &'e UserToken,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let query_result = sqlx::query!(
+    r#"
+UPDATE user_personal_access_tokens
+SET
+  maybe_revoked_at = NOW()
+
+WHERE token = ?
+AND user_token = ?
+AND maybe_revoked_at IS NULL
+LIMIT 1
+    "#,
+    token,
+    user_token,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(query_result.rows_affected() > 0)
+}
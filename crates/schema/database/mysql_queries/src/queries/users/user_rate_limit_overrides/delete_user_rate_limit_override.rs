@@ -0,0 +1,28 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use tokens::tokens::users::UserToken;
+
+/// Returns false if the user had no override for the endpoint group.
+pub async fn delete_user_rate_limit_override<'e, 'c, E>(
+  user_token: &'e UserToken,
+  endpoint_group: RateLimitEndpointGroup,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let query_result = sqlx::query!(
+    r#"
+DELETE FROM user_rate_limit_overrides
+WHERE user_token = ?
+AND endpoint_group = ?
+LIMIT 1
+    "#,
+    user_token,
+    endpoint_group.to_str(),
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(query_result.rows_affected() > 0)
+}
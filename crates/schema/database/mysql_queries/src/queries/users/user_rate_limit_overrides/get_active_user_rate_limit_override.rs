@@ -0,0 +1,44 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use tokens::tokens::users::UserToken;
+
+pub struct ActiveUserRateLimitOverride {
+  pub is_exempt: bool,
+  pub max_requests: u32,
+  pub window_seconds: u32,
+}
+
+/// The user's override for the endpoint group, unless it has expired.
+pub async fn get_active_user_rate_limit_override<'e, 'c, E>(
+  user_token: &'e UserToken,
+  endpoint_group: RateLimitEndpointGroup,
+  mysql_executor: E,
+) -> Result<Option<ActiveUserRateLimitOverride>, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let result = sqlx::query_as!(
+    ActiveUserRateLimitOverride,
+    r#"
+SELECT
+  is_exempt as `is_exempt: bool`,
+  max_requests,
+  window_seconds
+FROM user_rate_limit_overrides
+WHERE user_token = ?
+AND endpoint_group = ?
+AND (maybe_expires_at IS NULL OR maybe_expires_at > NOW())
+LIMIT 1
+    "#,
+    user_token,
+    endpoint_group.to_str(),
+  )
+    .fetch_one(mysql_executor)
+    .await;
+
+  match result {
+    Ok(record) => Ok(Some(record)),
+    Err(sqlx::Error::RowNotFound) => Ok(None),
+    Err(err) => Err(err),
+  }
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, MySql};
+
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use tokens::tokens::users::UserToken;
+
+pub struct UserRateLimitOverrideListItem {
+  pub endpoint_group: RateLimitEndpointGroup,
+  pub is_exempt: bool,
+  pub max_requests: u32,
+  pub window_seconds: u32,
+  pub maybe_note: Option<String>,
+  pub creator_user_token: UserToken,
+  pub maybe_expires_at: Option<DateTime<Utc>>,
+  pub updated_at: DateTime<Utc>,
+}
+
+/// All of a user's overrides, including expired ones (which no longer apply).
+pub async fn list_user_rate_limit_overrides<'e, 'c, E>(
+  user_token: &'e UserToken,
+  mysql_executor: E,
+) -> Result<Vec<UserRateLimitOverrideListItem>, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query_as!(
+    UserRateLimitOverrideListItem,
+    r#"
+SELECT
+  endpoint_group as `endpoint_group: enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup`,
+  is_exempt as `is_exempt: bool`,
+  max_requests,
+  window_seconds,
+  maybe_note,
+  creator_user_token as `creator_user_token: tokens::tokens::users::UserToken`,
+  maybe_expires_at as `maybe_expires_at: DateTime<Utc>`,
+  updated_at as `updated_at: DateTime<Utc>`
+FROM user_rate_limit_overrides
+WHERE user_token = ?
+ORDER BY endpoint_group ASC
+    "#,
+    user_token,
+  )
+    .fetch_all(mysql_executor)
+    .await
+}
@@ -0,0 +1,4 @@
+pub mod delete_user_rate_limit_override;
+pub mod get_active_user_rate_limit_override;
+pub mod list_user_rate_limit_overrides;
+pub mod upsert_user_rate_limit_override;
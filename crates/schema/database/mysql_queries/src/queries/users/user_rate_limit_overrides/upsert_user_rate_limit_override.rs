@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, MySql};
+
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use tokens::tokens::users::UserToken;
+
+pub struct UpsertUserRateLimitOverrideArgs<'a> {
+  pub user_token: &'a UserToken,
+  pub endpoint_group: RateLimitEndpointGroup,
+
+  /// If true, `max_requests` and `window_seconds` are ignored.
+  pub is_exempt: bool,
+
+  pub max_requests: u32,
+  pub window_seconds: u32,
+
+  pub maybe_note: Option<&'a str>,
+
+  /// The moderator setting the override.
+  pub creator_user_token: &'a UserToken,
+
+  pub maybe_expires_at: Option<DateTime<Utc>>,
+}
+
+/// Each user has at most one override per endpoint group; setting it again replaces it.
+pub async fn upsert_user_rate_limit_override<'e, 'c, E>(
+  args: UpsertUserRateLimitOverrideArgs<'e>,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query!(
+    r#"
+INSERT INTO user_rate_limit_overrides
+SET
+  user_token = ?,
+  endpoint_group = ?,
+  is_exempt = ?,
+  max_requests = ?,
+  window_seconds = ?,
+  maybe_note = ?,
+  creator_user_token = ?,
+  maybe_expires_at = ?
+ON DUPLICATE KEY UPDATE
+  is_exempt = VALUES(is_exempt),
+  max_requests = VALUES(max_requests),
+  window_seconds = VALUES(window_seconds),
+  maybe_note = VALUES(maybe_note),
+  creator_user_token = VALUES(creator_user_token),
+  maybe_expires_at = VALUES(maybe_expires_at)
+    "#,
+    args.user_token,
+    args.endpoint_group.to_str(),
+    args.is_exempt,
+    args.max_requests,
+    args.window_seconds,
+    args.maybe_note,
+    args.creator_user_token,
+    args.maybe_expires_at,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -179,61 +179,7 @@ WHERE user_sessions.token = ?
       .await; // TODO: This will return error if it doesn't exist
 
   match maybe_user_record {
-    Ok(raw_user_record) => {
-      let result_user_record = SessionUserRecord {
-        user_token: UserToken::new(raw_user_record.user_token),
-        username: raw_user_record.username,
-        display_name: raw_user_record.display_name,
-        email_address: raw_user_record.email_address,
-        email_gravatar_hash: raw_user_record.email_gravatar_hash,
-        // Onboarding
-        email_confirmed: i8_to_bool(raw_user_record.email_confirmed),
-        email_confirmed_by_google: i8_to_bool(raw_user_record.email_confirmed_by_google),
-        email_is_synthetic: i8_to_bool(raw_user_record.email_is_synthetic),
-        is_without_password: i8_to_bool(raw_user_record.is_without_password),
-        username_is_not_customized: i8_to_bool(raw_user_record.username_is_not_customized),
-        // Premium features
-        maybe_stripe_customer_id: raw_user_record.maybe_stripe_customer_id,
-        maybe_loyalty_program_key: raw_user_record.maybe_loyalty_program_key,
-        // Preference
-        disable_gravatar: i8_to_bool(raw_user_record.disable_gravatar),
-        auto_play_audio_preference: nullable_i8_to_optional_bool(raw_user_record.auto_play_audio_preference),
-        auto_play_video_preference: nullable_i8_to_optional_bool(raw_user_record.auto_play_video_preference),
-        user_role_slug: raw_user_record.user_role_slug,
-        preferred_tts_result_visibility: raw_user_record.preferred_tts_result_visibility,
-        preferred_w2l_result_visibility: raw_user_record.preferred_w2l_result_visibility,
-
-        is_banned: i8_to_bool(raw_user_record.is_banned),
-
-        can_access_studio: i8_to_bool(raw_user_record.can_access_studio),
-        maybe_feature_flags: raw_user_record.maybe_feature_flags,
-
-        // Usage
-        can_use_tts: nullable_i8_to_bool_default_false(raw_user_record.can_use_tts),
-        can_use_w2l: nullable_i8_to_bool_default_false(raw_user_record.can_use_w2l),
-        can_delete_own_tts_results: nullable_i8_to_bool_default_false(raw_user_record.can_delete_own_tts_results),
-        can_delete_own_w2l_results: nullable_i8_to_bool_default_false(raw_user_record.can_delete_own_w2l_results),
-        can_delete_own_account: nullable_i8_to_bool_default_false(raw_user_record.can_delete_own_account),
-        // Contribution
-        can_upload_tts_models: nullable_i8_to_bool_default_false(raw_user_record.can_upload_tts_models),
-        can_upload_w2l_templates: nullable_i8_to_bool_default_false(raw_user_record.can_upload_w2l_templates),
-        can_delete_own_tts_models: nullable_i8_to_bool_default_false(raw_user_record.can_delete_own_tts_models),
-        can_delete_own_w2l_templates: nullable_i8_to_bool_default_false(raw_user_record.can_delete_own_w2l_templates),
-        // Moderation
-        can_approve_w2l_templates: nullable_i8_to_bool_default_false(raw_user_record.can_approve_w2l_templates),
-        can_edit_other_users_profiles: nullable_i8_to_bool_default_false(raw_user_record.can_edit_other_users_profiles),
-        can_edit_other_users_tts_models: nullable_i8_to_bool_default_false(raw_user_record.can_edit_other_users_tts_models),
-        can_edit_other_users_w2l_templates: nullable_i8_to_bool_default_false(raw_user_record.can_edit_other_users_w2l_templates),
-        can_delete_other_users_tts_models: nullable_i8_to_bool_default_false(raw_user_record.can_delete_other_users_tts_models),
-        can_delete_other_users_tts_results: nullable_i8_to_bool_default_false(raw_user_record.can_delete_other_users_tts_results),
-        can_delete_other_users_w2l_templates: nullable_i8_to_bool_default_false(raw_user_record.can_delete_other_users_w2l_templates),
-        can_delete_other_users_w2l_results: nullable_i8_to_bool_default_false(raw_user_record.can_delete_other_users_w2l_results),
-        can_ban_users: nullable_i8_to_bool_default_false(raw_user_record.can_ban_users),
-        can_delete_users: nullable_i8_to_bool_default_false(raw_user_record.can_delete_users) ,
-      };
-
-      Ok(Some(result_user_record))
-    },
+    Ok(raw_user_record) => Ok(Some(raw_user_record.into_session_user_record())),
     Err(sqlx::Error::RowNotFound) => {
       warn!("Valid cookie; invalid session: {}", session_token);
       Ok(None)
@@ -246,60 +192,116 @@ WHERE user_sessions.token = ?
 
 }
 
-struct SessionUserRawDbRecord {
-  user_token: String,
-  username: String,
-  display_name: String,
+pub(crate) struct SessionUserRawDbRecord {
+  pub(crate) user_token: String,
+  pub(crate) username: String,
+  pub(crate) display_name: String,
 
-  email_address: String,
-  email_gravatar_hash: String,
+  pub(crate) email_address: String,
+  pub(crate) email_gravatar_hash: String,
 
-  email_confirmed: i8,
-  email_confirmed_by_google: i8,
-  email_is_synthetic: i8,
-  is_without_password: i8,
-  username_is_not_customized: i8,
+  pub(crate) email_confirmed: i8,
+  pub(crate) email_confirmed_by_google: i8,
+  pub(crate) email_is_synthetic: i8,
+  pub(crate) is_without_password: i8,
+  pub(crate) username_is_not_customized: i8,
 
-  maybe_stripe_customer_id: Option<String>,
-  maybe_loyalty_program_key: Option<String>,
+  pub(crate) maybe_stripe_customer_id: Option<String>,
+  pub(crate) maybe_loyalty_program_key: Option<String>,
 
-  disable_gravatar: i8,
-  auto_play_audio_preference: Option<i8>,
-  auto_play_video_preference: Option<i8>,
-  preferred_tts_result_visibility: Visibility,
-  preferred_w2l_result_visibility: Visibility,
+  pub(crate) disable_gravatar: i8,
+  pub(crate) auto_play_audio_preference: Option<i8>,
+  pub(crate) auto_play_video_preference: Option<i8>,
+  pub(crate) preferred_tts_result_visibility: Visibility,
+  pub(crate) preferred_w2l_result_visibility: Visibility,
 
-  user_role_slug: String,
-  is_banned: i8,
+  pub(crate) user_role_slug: String,
+  pub(crate) is_banned: i8,
 
   // Feature / Rollout Flags
-  can_access_studio: i8,
+  pub(crate) can_access_studio: i8,
 
-  maybe_feature_flags: Option<String>,
+  pub(crate) maybe_feature_flags: Option<String>,
 
   // NB: These are `Option` due to the JOIN not being compile-time assured.
   // Usage
-  can_use_tts: Option<i8>,
-  can_use_w2l: Option<i8>,
-  can_delete_own_tts_results: Option<i8>,
-  can_delete_own_w2l_results: Option<i8>,
-  can_delete_own_account: Option<i8>,
+  pub(crate) can_use_tts: Option<i8>,
+  pub(crate) can_use_w2l: Option<i8>,
+  pub(crate) can_delete_own_tts_results: Option<i8>,
+  pub(crate) can_delete_own_w2l_results: Option<i8>,
+  pub(crate) can_delete_own_account: Option<i8>,
 
   // Contribution
-  can_upload_tts_models: Option<i8>,
-  can_upload_w2l_templates: Option<i8>,
-  can_delete_own_tts_models: Option<i8>,
-  can_delete_own_w2l_templates: Option<i8>,
+  pub(crate) can_upload_tts_models: Option<i8>,
+  pub(crate) can_upload_w2l_templates: Option<i8>,
+  pub(crate) can_delete_own_tts_models: Option<i8>,
+  pub(crate) can_delete_own_w2l_templates: Option<i8>,
 
   // Moderation
-  can_approve_w2l_templates: Option<i8>,
-  can_edit_other_users_profiles: Option<i8>,
-  can_edit_other_users_tts_models: Option<i8>,
-  can_edit_other_users_w2l_templates: Option<i8>,
-  can_delete_other_users_tts_models: Option<i8>,
-  can_delete_other_users_tts_results: Option<i8>,
-  can_delete_other_users_w2l_templates: Option<i8>,
-  can_delete_other_users_w2l_results: Option<i8>,
-  can_ban_users: Option<i8>,
-  can_delete_users: Option<i8>,
+  pub(crate) can_approve_w2l_templates: Option<i8>,
+  pub(crate) can_edit_other_users_profiles: Option<i8>,
+  pub(crate) can_edit_other_users_tts_models: Option<i8>,
+  pub(crate) can_edit_other_users_w2l_templates: Option<i8>,
+  pub(crate) can_delete_other_users_tts_models: Option<i8>,
+  pub(crate) can_delete_other_users_tts_results: Option<i8>,
+  pub(crate) can_delete_other_users_w2l_templates: Option<i8>,
+  pub(crate) can_delete_other_users_w2l_results: Option<i8>,
+  pub(crate) can_ban_users: Option<i8>,
+  pub(crate) can_delete_users: Option<i8>,
+}
+
+impl SessionUserRawDbRecord {
+  pub(crate) fn into_session_user_record(self) -> SessionUserRecord {
+    SessionUserRecord {
+      user_token: UserToken::new(self.user_token),
+      username: self.username,
+      display_name: self.display_name,
+      email_address: self.email_address,
+      email_gravatar_hash: self.email_gravatar_hash,
+      // Onboarding
+      email_confirmed: i8_to_bool(self.email_confirmed),
+      email_confirmed_by_google: i8_to_bool(self.email_confirmed_by_google),
+      email_is_synthetic: i8_to_bool(self.email_is_synthetic),
+      is_without_password: i8_to_bool(self.is_without_password),
+      username_is_not_customized: i8_to_bool(self.username_is_not_customized),
+      // Premium features
+      maybe_stripe_customer_id: self.maybe_stripe_customer_id,
+      maybe_loyalty_program_key: self.maybe_loyalty_program_key,
+      // Preference
+      disable_gravatar: i8_to_bool(self.disable_gravatar),
+      auto_play_audio_preference: nullable_i8_to_optional_bool(self.auto_play_audio_preference),
+      auto_play_video_preference: nullable_i8_to_optional_bool(self.auto_play_video_preference),
+      user_role_slug: self.user_role_slug,
+      preferred_tts_result_visibility: self.preferred_tts_result_visibility,
+      preferred_w2l_result_visibility: self.preferred_w2l_result_visibility,
+
+      is_banned: i8_to_bool(self.is_banned),
+
+      can_access_studio: i8_to_bool(self.can_access_studio),
+      maybe_feature_flags: self.maybe_feature_flags,
+
+      // Usage
+      can_use_tts: nullable_i8_to_bool_default_false(self.can_use_tts),
+      can_use_w2l: nullable_i8_to_bool_default_false(self.can_use_w2l),
+      can_delete_own_tts_results: nullable_i8_to_bool_default_false(self.can_delete_own_tts_results),
+      can_delete_own_w2l_results: nullable_i8_to_bool_default_false(self.can_delete_own_w2l_results),
+      can_delete_own_account: nullable_i8_to_bool_default_false(self.can_delete_own_account),
+      // Contribution
+      can_upload_tts_models: nullable_i8_to_bool_default_false(self.can_upload_tts_models),
+      can_upload_w2l_templates: nullable_i8_to_bool_default_false(self.can_upload_w2l_templates),
+      can_delete_own_tts_models: nullable_i8_to_bool_default_false(self.can_delete_own_tts_models),
+      can_delete_own_w2l_templates: nullable_i8_to_bool_default_false(self.can_delete_own_w2l_templates),
+      // Moderation
+      can_approve_w2l_templates: nullable_i8_to_bool_default_false(self.can_approve_w2l_templates),
+      can_edit_other_users_profiles: nullable_i8_to_bool_default_false(self.can_edit_other_users_profiles),
+      can_edit_other_users_tts_models: nullable_i8_to_bool_default_false(self.can_edit_other_users_tts_models),
+      can_edit_other_users_w2l_templates: nullable_i8_to_bool_default_false(self.can_edit_other_users_w2l_templates),
+      can_delete_other_users_tts_models: nullable_i8_to_bool_default_false(self.can_delete_other_users_tts_models),
+      can_delete_other_users_tts_results: nullable_i8_to_bool_default_false(self.can_delete_other_users_tts_results),
+      can_delete_other_users_w2l_templates: nullable_i8_to_bool_default_false(self.can_delete_other_users_w2l_templates),
+      can_delete_other_users_w2l_results: nullable_i8_to_bool_default_false(self.can_delete_other_users_w2l_results),
+      can_ban_users: nullable_i8_to_bool_default_false(self.can_ban_users),
+      can_delete_users: nullable_i8_to_bool_default_false(self.can_delete_users) ,
+    }
+  }
 }
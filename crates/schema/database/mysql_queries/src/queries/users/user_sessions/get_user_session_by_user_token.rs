@@ -0,0 +1,90 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+use crate::queries::users::user_sessions::get_user_session_by_token::{SessionUserRawDbRecord, SessionUserRecord};
+
+/// The same record as `get_user_session_by_token`, for callers that authenticate a user
+/// without a session (eg. personal access tokens).
+pub async fn get_user_session_by_user_token<'e, 'c : 'e, E>(
+  mysql_executor: E,
+  user_token: &UserToken,
+) -> Result<Option<SessionUserRecord>, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let maybe_user_record = sqlx::query_as!(
+      SessionUserRawDbRecord,
+        r#"
+SELECT
+    users.token as user_token,
+    users.username,
+    users.display_name,
+
+    users.email_address,
+    users.email_gravatar_hash,
+    
+    users.email_confirmed,
+    users.email_confirmed_by_google,
+    users.email_is_synthetic,
+    users.is_without_password,
+    users.username_is_not_customized,
+
+    users.maybe_stripe_customer_id,
+    users.maybe_loyalty_program_key,
+
+    users.disable_gravatar,
+    users.auto_play_audio_preference,
+    users.auto_play_video_preference,
+    users.preferred_tts_result_visibility as `preferred_tts_result_visibility: enums::common::visibility::Visibility`,
+    users.preferred_w2l_result_visibility as `preferred_w2l_result_visibility: enums::common::visibility::Visibility`,
+
+    users.user_role_slug,
+    users.is_banned,
+
+    users.can_access_studio,
+    users.maybe_feature_flags,
+
+    user_roles.can_use_tts,
+    user_roles.can_use_w2l,
+    user_roles.can_delete_own_tts_results,
+    user_roles.can_delete_own_w2l_results,
+    user_roles.can_delete_own_account,
+
+    user_roles.can_upload_tts_models,
+    user_roles.can_upload_w2l_templates,
+    user_roles.can_delete_own_tts_models,
+    user_roles.can_delete_own_w2l_templates,
+
+    user_roles.can_approve_w2l_templates,
+    user_roles.can_edit_other_users_profiles,
+    user_roles.can_edit_other_users_tts_models,
+    user_roles.can_edit_other_users_w2l_templates,
+    user_roles.can_delete_other_users_tts_models,
+    user_roles.can_delete_other_users_tts_results,
+    user_roles.can_delete_other_users_w2l_templates,
+    user_roles.can_delete_other_users_w2l_results,
+    user_roles.can_ban_users,
+    user_roles.can_delete_users
+
+FROM users
+LEFT OUTER JOIN user_roles
+    ON users.user_role_slug = user_roles.slug
+WHERE users.token = ?
+    AND users.user_deleted_at IS NULL
+    AND users.mod_deleted_at IS NULL
+        "#,
+        user_token,
+    )
+      .fetch_one(mysql_executor)
+      .await;
+
+  match maybe_user_record {
+    Ok(raw_user_record) => Ok(Some(raw_user_record.into_session_user_record())),
+    Err(sqlx::Error::RowNotFound) => Ok(None),
+    Err(err) => Err(err),
+  }
+}
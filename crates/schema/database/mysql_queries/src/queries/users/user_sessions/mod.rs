@@ -5,3 +5,4 @@ pub mod create_user_session_with_transactor;
 pub mod delete_user_session;
 pub mod get_user_session_by_token;
 pub mod get_user_session_by_token_light;
+pub mod get_user_session_by_user_token;
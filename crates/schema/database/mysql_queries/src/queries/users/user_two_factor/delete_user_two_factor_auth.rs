@@ -0,0 +1,25 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+/// Turn off two factor auth (or abandon a pending enrollment).
+/// Returns false if the user had no record.
+pub async fn delete_user_two_factor_auth<'e, 'c, E>(
+  user_token: &'e UserToken,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let query_result = sqlx::query!(
+    r#"
+DELETE FROM user_two_factor_auth
+WHERE user_token = ?
+LIMIT 1
+    "#,
+    user_token,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(query_result.rows_affected() > 0)
+}
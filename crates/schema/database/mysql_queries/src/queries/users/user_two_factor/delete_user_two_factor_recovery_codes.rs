@@ -0,0 +1,23 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+/// Remove all of a user's recovery codes, used or not.
+pub async fn delete_user_two_factor_recovery_codes<'e, 'c, E>(
+  user_token: &'e UserToken,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query!(
+    r#"
+DELETE FROM user_two_factor_recovery_codes
+WHERE user_token = ?
+    "#,
+    user_token,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,36 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+/// Finish enrollment. Only applies to a pending record with the secret the
+/// confirmation code was checked against. Returns false if nothing matched.
+pub async fn enable_user_two_factor_auth<'e, 'c, E>(
+  user_token: &'e UserToken,
+  totp_secret: &'e str,
+  used_time_step: u64,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let query_result = sqlx::query!(
+    r#"
+UPDATE user_two_factor_auth
+SET
+  is_enabled = TRUE,
+  maybe_enabled_at = NOW(),
+  maybe_last_used_time_step = ?
+
+WHERE user_token = ?
+AND totp_secret = ?
+AND is_enabled = FALSE
+LIMIT 1
+    "#,
+    used_time_step,
+    user_token,
+    totp_secret,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(query_result.rows_affected() > 0)
+}
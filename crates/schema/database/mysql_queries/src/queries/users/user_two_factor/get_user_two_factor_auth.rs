@@ -0,0 +1,42 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+pub struct UserTwoFactorAuth {
+  /// Base32 encoded TOTP secret.
+  pub totp_secret: String,
+
+  /// False while enrollment is pending confirmation.
+  pub is_enabled: bool,
+
+  pub maybe_last_used_time_step: Option<u64>,
+}
+
+pub async fn get_user_two_factor_auth<'e, 'c, E>(
+  user_token: &'e UserToken,
+  mysql_executor: E,
+) -> Result<Option<UserTwoFactorAuth>, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let result = sqlx::query_as!(
+    UserTwoFactorAuth,
+    r#"
+SELECT
+  totp_secret,
+  is_enabled as `is_enabled: bool`,
+  maybe_last_used_time_step
+FROM user_two_factor_auth
+WHERE user_token = ?
+LIMIT 1
+    "#,
+    user_token,
+  )
+    .fetch_one(mysql_executor)
+    .await;
+
+  match result {
+    Ok(record) => Ok(Some(record)),
+    Err(sqlx::Error::RowNotFound) => Ok(None),
+    Err(err) => Err(err),
+  }
+}
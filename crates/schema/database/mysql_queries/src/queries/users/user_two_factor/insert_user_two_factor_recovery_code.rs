@@ -0,0 +1,26 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+pub async fn insert_user_two_factor_recovery_code<'e, 'c, E>(
+  user_token: &'e UserToken,
+  code_hash: &'e str,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query!(
+    r#"
+INSERT INTO user_two_factor_recovery_codes
+SET
+  user_token = ?,
+  code_hash = ?
+    "#,
+    user_token,
+    code_hash,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,33 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+pub struct UnusedRecoveryCode {
+  pub id: u64,
+
+  /// bcrypt hash of the normalized code.
+  pub code_hash: String,
+}
+
+pub async fn list_unused_user_two_factor_recovery_codes<'e, 'c, E>(
+  user_token: &'e UserToken,
+  mysql_executor: E,
+) -> Result<Vec<UnusedRecoveryCode>, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query_as!(
+    UnusedRecoveryCode,
+    r#"
+SELECT
+  id,
+  code_hash
+FROM user_two_factor_recovery_codes
+WHERE user_token = ?
+AND maybe_used_at IS NULL
+ORDER BY id ASC
+    "#,
+    user_token,
+  )
+    .fetch_all(mysql_executor)
+    .await
+}
@@ -0,0 +1,31 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+/// Redeem a recovery code. Returns false if it was already used (eg. by a concurrent request).
+pub async fn mark_user_two_factor_recovery_code_used<'e, 'c, E>(
+  user_token: &'e UserToken,
+  recovery_code_id: u64,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let query_result = sqlx::query!(
+    r#"
+UPDATE user_two_factor_recovery_codes
+SET
+  maybe_used_at = NOW()
+
+WHERE id = ?
+AND user_token = ?
+AND maybe_used_at IS NULL
+LIMIT 1
+    "#,
+    recovery_code_id,
+    user_token,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(query_result.rows_affected() > 0)
+}
@@ -0,0 +1,33 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+/// Record that a TOTP code was accepted. Returns false if a code from the same
+/// (or a later) time step was already used, in which case the code is a replay.
+pub async fn mark_user_two_factor_time_step_used<'e, 'c, E>(
+  user_token: &'e UserToken,
+  used_time_step: u64,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let query_result = sqlx::query!(
+    r#"
+UPDATE user_two_factor_auth
+SET
+  maybe_last_used_time_step = ?
+
+WHERE user_token = ?
+AND is_enabled = TRUE
+AND (maybe_last_used_time_step IS NULL OR maybe_last_used_time_step < ?)
+LIMIT 1
+    "#,
+    used_time_step,
+    user_token,
+    used_time_step,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(query_result.rows_affected() > 0)
+}
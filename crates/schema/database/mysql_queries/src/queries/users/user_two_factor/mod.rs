@@ -0,0 +1,9 @@
+pub mod delete_user_two_factor_auth;
+pub mod delete_user_two_factor_recovery_codes;
+pub mod enable_user_two_factor_auth;
+pub mod get_user_two_factor_auth;
+pub mod insert_user_two_factor_recovery_code;
+pub mod list_unused_user_two_factor_recovery_codes;
+pub mod mark_user_two_factor_recovery_code_used;
+pub mod mark_user_two_factor_time_step_used;
+pub mod upsert_pending_user_two_factor_auth;
@@ -0,0 +1,33 @@
+use sqlx::{Executor, MySql};
+
+use tokens::tokens::users::UserToken;
+
+/// Start (or restart) enrollment with a new secret. Has no effect if the user
+/// already has two factor auth enabled.
+pub async fn upsert_pending_user_two_factor_auth<'e, 'c, E>(
+  user_token: &'e UserToken,
+  totp_secret: &'e str,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  sqlx::query!(
+    r#"
+INSERT INTO user_two_factor_auth
+SET
+  user_token = ?,
+  totp_secret = ?,
+  is_enabled = FALSE
+
+ON DUPLICATE KEY UPDATE
+  maybe_last_used_time_step = IF(is_enabled, maybe_last_used_time_step, NULL),
+  totp_secret = IF(is_enabled, totp_secret, VALUES(totp_secret))
+    "#,
+    user_token,
+    totp_secret,
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
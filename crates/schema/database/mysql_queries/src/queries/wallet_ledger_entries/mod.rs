@@ -1,4 +1,4 @@
 pub (crate) mod internal_insert_wallet_ledger_entry;
 pub mod internal_insert_wallet_created_ledger_entry;
 pub mod get_wallet_ledger_entry_for_moderation;
-pub mod list_wallet_ledger_entries_by_wallet;
\ No newline at end of file
+pub mod list_wallet_ledger_entries_by_wallet;pub mod set_wallet_ledger_entry_personal_access_token;
@@ -0,0 +1,28 @@
+use sqlx::MySql;
+
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken;
+
+/// Record which personal access token a deduction was made with, so a later refund of the
+/// entry can release the spend counted against the token.
+pub async fn set_wallet_ledger_entry_personal_access_token(
+  ledger_entry_token: &WalletLedgerEntryToken,
+  personal_access_token: &PersonalAccessTokenToken,
+  transaction: &mut sqlx::Transaction<'_, MySql>,
+) -> Result<(), sqlx::Error> {
+  sqlx::query!(
+    r#"
+UPDATE wallet_ledger_entries
+SET
+  maybe_personal_access_token = ?
+WHERE token = ?
+LIMIT 1
+    "#,
+    personal_access_token.as_str(),
+    ledger_entry_token.as_str(),
+  )
+    .execute(&mut **transaction)
+    .await?;
+
+  Ok(())
+}
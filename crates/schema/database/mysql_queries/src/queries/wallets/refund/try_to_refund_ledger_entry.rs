@@ -2,9 +2,11 @@ use log::info;
 use sqlx::MySql;
 
 use enums::by_table::wallet_ledger_entries::wallet_ledger_entry_type::WalletLedgerEntryType;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
 use tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken;
 use tokens::tokens::wallets::WalletToken;
 
+use crate::queries::users::user_personal_access_tokens::release_user_personal_access_token_spend::release_user_personal_access_token_spend;
 use crate::queries::wallet_ledger_entries::internal_insert_wallet_ledger_entry::InsertWalletLedgerEntry;
 use crate::queries::wallets::internal_select_wallet_balance_for_update::internal_select_wallet_balance_for_update;
 use crate::queries::wallets::refund::wallet_refund_error::WalletRefundError;
@@ -25,6 +27,8 @@ pub struct WalletRefundSummary {
   pub refund_amount: u64,
   pub banked_credits_before: u64,
   pub banked_credits_after: u64,
+  /// The personal access token the original charge was made with, whose spend was released.
+  pub maybe_released_personal_access_token: Option<PersonalAccessTokenToken>,
 }
 
 /// Refund a wallet ledger entry, crediting the amount back as banked (durable) credits.
@@ -35,6 +39,9 @@ pub struct WalletRefundSummary {
 /// We always refund into banked credits rather than monthly credits. Refunding into monthly
 /// credits near a billing cycle cutoff could create race conditions with the monthly refill job,
 /// so banked credits are the safer, permanent choice.
+///
+/// If the charge was made with a personal access token, the refunded amount is also released
+/// from the token's spend (in the same transaction) so the refund doesn't eat into its limit.
 pub async fn try_to_refund_ledger_entry(
   ledger_entry_token: &WalletLedgerEntryToken,
   transaction: &mut sqlx::Transaction<'_, MySql>,
@@ -124,6 +131,15 @@ LIMIT 1
     ledger_entry_token.as_str(),
   ).execute(&mut **transaction).await?;
 
+  // Step 8: Give the refunded credits back to the personal access token's spend limit.
+  if let Some(release) = personal_access_token_release(&original_entry, refund_amount) {
+    release_user_personal_access_token_spend(
+      release.token,
+      release.credits,
+      &mut **transaction,
+    ).await?;
+  }
+
   info!(
     "Refunded ledger entry {} → new refund entry {}; wallet {} banked credits: {} → {}",
     ledger_entry_token.as_str(),
@@ -140,6 +156,7 @@ LIMIT 1
     refund_amount,
     banked_credits_before,
     banked_credits_after,
+    maybe_released_personal_access_token: original_entry.maybe_personal_access_token,
   }))
 }
 
@@ -149,6 +166,7 @@ struct LedgerEntryForUpdate {
   wallet_token: WalletToken,
   entry_type: WalletLedgerEntryType,
   is_refunded: bool,
+  maybe_personal_access_token: Option<PersonalAccessTokenToken>,
   /// Negative for deductions, positive for credits.
   credits_delta: i64,
   banked_credits_before: u64,
@@ -168,6 +186,7 @@ async fn select_ledger_entry_for_update(
     wallet_token: WalletToken,
     entry_type: String,
     is_refunded: bool,
+    maybe_personal_access_token: Option<PersonalAccessTokenToken>,
     credits_delta: i32,
     banked_credits_before: u32,
     banked_credits_after: u32,
@@ -182,6 +201,7 @@ SELECT
   wallet_token as `wallet_token: tokens::tokens::wallets::WalletToken`,
   entry_type,
   is_refunded as `is_refunded: bool`,
+  maybe_personal_access_token as `maybe_personal_access_token: tokens::tokens::personal_access_tokens::PersonalAccessTokenToken`,
   credits_delta,
   banked_credits_before,
   banked_credits_after,
@@ -210,6 +230,7 @@ FOR UPDATE
     wallet_token: raw.wallet_token,
     entry_type,
     is_refunded: raw.is_refunded,
+    maybe_personal_access_token: raw.maybe_personal_access_token,
     credits_delta: raw.credits_delta as i64,
     banked_credits_before: raw.banked_credits_before as u64,
     banked_credits_after: raw.banked_credits_after as u64,
@@ -217,3 +238,56 @@ FOR UPDATE
     monthly_credits_after: raw.monthly_credits_after as u64,
   })
 }
+
+struct PersonalAccessTokenRelease<'a> {
+  token: &'a PersonalAccessTokenToken,
+  credits: u64,
+}
+
+/// The token spend to release when refunding an entry, if it was charged through a token.
+fn personal_access_token_release(
+  entry: &LedgerEntryForUpdate,
+  refund_amount: u64,
+) -> Option<PersonalAccessTokenRelease<'_>> {
+  match entry.maybe_personal_access_token.as_ref() {
+    Some(token) if refund_amount > 0 => Some(PersonalAccessTokenRelease { token, credits: refund_amount }),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn entry(maybe_personal_access_token: Option<&str>) -> LedgerEntryForUpdate {
+    LedgerEntryForUpdate {
+      wallet_token: WalletToken::new_from_str("wallet_test"),
+      entry_type: WalletLedgerEntryType::DeductBanked,
+      is_refunded: false,
+      maybe_personal_access_token: maybe_personal_access_token.map(PersonalAccessTokenToken::new_from_str),
+      credits_delta: -150,
+      banked_credits_before: 200,
+      banked_credits_after: 50,
+      monthly_credits_before: 0,
+      monthly_credits_after: 0,
+    }
+  }
+
+  #[test]
+  fn releases_the_refund_amount_against_the_funding_token() {
+    let entry = entry(Some("pat_test"));
+    let release = personal_access_token_release(&entry, 150).expect("should release");
+    assert_eq!(release.token.as_str(), "pat_test");
+    assert_eq!(release.credits, 150);
+  }
+
+  #[test]
+  fn nothing_to_release_for_session_charges() {
+    assert!(personal_access_token_release(&entry(None), 150).is_none());
+  }
+
+  #[test]
+  fn nothing_to_release_for_zero_refunds() {
+    assert!(personal_access_token_release(&entry(Some("pat_test")), 0).is_none());
+  }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, MySql};
+
+use enums::by_table::webhook_deliveries::webhook_delivery_provider::WebhookDeliveryProvider;
+use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+use tokens::tokens::users::UserToken;
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+
+pub struct WebhookDelivery {
+  pub token: WebhookDeliveryToken,
+  pub provider: WebhookDeliveryProvider,
+  pub provider_request_id: String,
+  pub payload_sha256: String,
+  pub raw_body: String,
+  pub status: WebhookDeliveryStatus,
+  pub delivery_count: u32,
+  pub maybe_last_error: Option<String>,
+  pub redrive_count: u32,
+  pub maybe_last_redrive_staff_user_token: Option<UserToken>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+pub async fn get_webhook_delivery_by_token<'e, 'c: 'e, E>(
+  token: &WebhookDeliveryToken,
+  mysql_executor: E,
+) -> Result<Option<WebhookDelivery>, sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  let result = sqlx::query_as!(
+    WebhookDelivery,
+    r#"
+SELECT
+  token as `token: WebhookDeliveryToken`,
+  provider as `provider: WebhookDeliveryProvider`,
+  provider_request_id,
+  payload_sha256,
+  raw_body,
+  status as `status: WebhookDeliveryStatus`,
+  delivery_count,
+  maybe_last_error,
+  redrive_count,
+  maybe_last_redrive_staff_user_token as `maybe_last_redrive_staff_user_token: UserToken`,
+  created_at as `created_at: DateTime<Utc>`,
+  updated_at as `updated_at: DateTime<Utc>`
+FROM webhook_deliveries
+WHERE token = ?
+LIMIT 1
+    "#,
+    token.as_str(),
+  )
+    .fetch_one(mysql_executor)
+    .await;
+
+  match result {
+    Ok(delivery) => Ok(Some(delivery)),
+    Err(sqlx::Error::RowNotFound) => Ok(None),
+    Err(err) => Err(err),
+  }
+}
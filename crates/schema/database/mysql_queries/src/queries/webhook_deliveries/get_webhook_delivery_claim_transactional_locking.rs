@@ -0,0 +1,63 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::webhook_deliveries::webhook_delivery_provider::WebhookDeliveryProvider;
+use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+
+pub struct WebhookDeliveryClaimState {
+  pub token: WebhookDeliveryToken,
+  pub status: WebhookDeliveryStatus,
+
+  /// Seconds since processing was last claimed, measured by the database clock.
+  pub claim_age_seconds: i64,
+}
+
+/// Look up a recorded delivery by its unique key, locking the row (`SELECT ... FOR UPDATE`)
+/// so the caller can decide whether to reclaim it without racing a concurrent delivery.
+/// Must be called inside a transaction.
+pub async fn get_webhook_delivery_claim_transactional_locking<'e, 'c: 'e, E>(
+  provider: WebhookDeliveryProvider,
+  provider_request_id: &str,
+  payload_sha256: &str,
+  mysql_executor: E,
+) -> Result<Option<WebhookDeliveryClaimState>, sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  let result = sqlx::query_as!(
+    InternalClaimStateRecord,
+    r#"
+SELECT
+  token as `token: WebhookDeliveryToken`,
+  status as `status: WebhookDeliveryStatus`,
+  TIMESTAMPDIFF(SECOND, claimed_at, NOW()) as claim_age_seconds
+FROM webhook_deliveries
+WHERE provider = ?
+  AND provider_request_id = ?
+  AND payload_sha256 = ?
+LIMIT 1
+FOR UPDATE
+    "#,
+    provider.to_str(),
+    provider_request_id,
+    payload_sha256,
+  )
+    .fetch_one(mysql_executor)
+    .await;
+
+  match result {
+    Ok(record) => Ok(Some(WebhookDeliveryClaimState {
+      token: record.token,
+      status: record.status,
+      claim_age_seconds: record.claim_age_seconds.unwrap_or(0),
+    })),
+    Err(sqlx::Error::RowNotFound) => Ok(None),
+    Err(err) => Err(err),
+  }
+}
+
+struct InternalClaimStateRecord {
+  token: WebhookDeliveryToken,
+  status: WebhookDeliveryStatus,
+  claim_age_seconds: Option<i64>,
+}
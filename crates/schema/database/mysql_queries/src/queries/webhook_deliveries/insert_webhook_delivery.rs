@@ -0,0 +1,67 @@
+use std::marker::PhantomData;
+
+use sqlx::{Executor, MySql};
+
+use enums::by_table::webhook_deliveries::webhook_delivery_provider::WebhookDeliveryProvider;
+use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+
+pub struct InsertWebhookDeliveryArgs<'a, 'c: 'a, E>
+  where E: 'a + Executor<'c, Database = MySql>
+{
+  pub provider: WebhookDeliveryProvider,
+  pub provider_request_id: &'a str,
+  pub payload_sha256: &'a str,
+  pub raw_body: &'a str,
+
+  pub mysql_executor: E,
+  pub phantom: PhantomData<&'c E>,
+}
+
+pub enum InsertWebhookDeliveryResult {
+  /// First time we've seen this payload; the caller owns processing it.
+  Inserted(WebhookDeliveryToken),
+
+  /// The same payload was delivered before. Its delivery count was bumped.
+  AlreadyRecorded,
+}
+
+/// Record an inbound webhook, or count a repeated delivery of the same payload.
+/// The insert is atomic, so concurrent duplicate deliveries can't both be processed.
+pub async fn insert_webhook_delivery<'a, 'c, E>(
+  args: InsertWebhookDeliveryArgs<'a, 'c, E>,
+) -> Result<InsertWebhookDeliveryResult, sqlx::Error>
+  where E: 'a + Executor<'c, Database = MySql>
+{
+  let token = WebhookDeliveryToken::generate();
+
+  let result = sqlx::query!(
+    r#"
+INSERT INTO webhook_deliveries
+SET
+  token = ?,
+  provider = ?,
+  provider_request_id = ?,
+  payload_sha256 = ?,
+  raw_body = ?,
+  status = ?
+ON DUPLICATE KEY UPDATE
+  delivery_count = delivery_count + 1
+    "#,
+    token.as_str(),
+    args.provider.to_str(),
+    args.provider_request_id,
+    args.payload_sha256,
+    args.raw_body,
+    WebhookDeliveryStatus::Processing.to_str(),
+  )
+    .execute(args.mysql_executor)
+    .await?;
+
+  // MySQL reports one affected row for a fresh insert and two for an update.
+  if result.rows_affected() == 1 {
+    Ok(InsertWebhookDeliveryResult::Inserted(token))
+  } else {
+    Ok(InsertWebhookDeliveryResult::AlreadyRecorded)
+  }
+}
@@ -0,0 +1,39 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+use tokens::tokens::users::UserToken;
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+
+/// Claim a delivery for a staff re-drive, moving it back to `processing`.
+/// Deliveries that already succeeded are never claimed.
+/// Returns false if the delivery doesn't exist or already succeeded.
+pub async fn mark_webhook_delivery_redriven<'e, 'c: 'e, E>(
+  token: &WebhookDeliveryToken,
+  staff_user_token: &UserToken,
+  mysql_executor: E,
+) -> Result<bool, sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  let result = sqlx::query!(
+    r#"
+UPDATE webhook_deliveries
+SET
+  status = ?,
+  redrive_count = redrive_count + 1,
+  maybe_last_redrive_staff_user_token = ?,
+  claimed_at = NOW()
+WHERE token = ?
+  AND status != ?
+LIMIT 1
+    "#,
+    WebhookDeliveryStatus::Processing.to_str(),
+    staff_user_token.as_str(),
+    token.as_str(),
+    WebhookDeliveryStatus::Succeeded.to_str(),
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(result.rows_affected() > 0)
+}
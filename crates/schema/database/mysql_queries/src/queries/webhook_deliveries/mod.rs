@@ -0,0 +1,6 @@
+pub mod get_webhook_delivery_by_token;
+pub mod get_webhook_delivery_claim_transactional_locking;
+pub mod insert_webhook_delivery;
+pub mod mark_webhook_delivery_redriven;
+pub mod reclaim_webhook_delivery;
+pub mod update_webhook_delivery_status;
@@ -0,0 +1,31 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+
+/// Move a failed (or stale in-flight) delivery back to `processing` and restart its lease,
+/// so a provider retry can process it again.
+pub async fn reclaim_webhook_delivery<'e, 'c: 'e, E>(
+  token: &WebhookDeliveryToken,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  sqlx::query!(
+    r#"
+UPDATE webhook_deliveries
+SET
+  status = ?,
+  claimed_at = NOW()
+WHERE token = ?
+LIMIT 1
+    "#,
+    WebhookDeliveryStatus::Processing.to_str(),
+    token.as_str(),
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,34 @@
+use sqlx::{Executor, MySql};
+
+use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+
+/// Record the outcome of processing a delivery.
+/// `maybe_error` replaces the last error (pass `None` on success to clear it).
+pub async fn update_webhook_delivery_status<'e, 'c: 'e, E>(
+  token: &WebhookDeliveryToken,
+  status: WebhookDeliveryStatus,
+  maybe_error: Option<&str>,
+  mysql_executor: E,
+) -> Result<(), sqlx::Error>
+where
+  E: 'e + Executor<'c, Database = MySql>,
+{
+  sqlx::query!(
+    r#"
+UPDATE webhook_deliveries
+SET
+  status = ?,
+  maybe_last_error = ?
+WHERE token = ?
+LIMIT 1
+    "#,
+    status.to_str(),
+    maybe_error,
+    token.as_str(),
+  )
+    .execute(mysql_executor)
+    .await?;
+
+  Ok(())
+}
@@ -0,0 +1,96 @@
+use errors::AnyhowResult;
+use log::{error, info};
+use sqlx::migrate::MigrateError;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode};
+use sqlx::{Pool, Sqlite, SqlitePool};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone)]
+pub struct HistoryDbConnection {
+  pool: Pool<Sqlite>,
+}
+
+impl HistoryDbConnection {
+  /// Open (or create) the history database and run any pending migrations.
+  ///
+  /// Unlike the tasks database, history is never deleted. If the database can't be migrated
+  /// (eg. it's corrupt, or from a newer build of the app), it's moved aside so the user's
+  /// library can still be recovered, and a fresh database is created in its place.
+  pub async fn connect_and_migrate<P: AsRef<Path>>(database_file: P) -> AnyhowResult<Self> {
+    {
+      match run_migrations(&database_file).await {
+        Ok(pool) => return Ok(Self { pool }),
+        Err(err) => {
+          error!("Error running SQLite history migrations: {:?}", err);
+        }
+      }
+
+      // NB: Scope change here is an attempt to drop the open file connection for Windows.
+      // (See the same dance in `sqlite_tasks`.)
+    }
+
+    move_database_aside(database_file.as_ref());
+
+    let pool = run_migrations(database_file).await?;
+
+    Ok(Self { pool })
+  }
+
+  pub fn get_pool(&self) -> &Pool<Sqlite> {
+    &self.pool
+  }
+
+  #[cfg(test)]
+  pub(crate) async fn connect_in_memory() -> AnyhowResult<Self> {
+    // NB: Every connection to ":memory:" is a different database, so only allow one.
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+
+    sqlx::migrate!("../../../../_database/sql/artcraft_history_migrations").run(&pool).await?;
+
+    Ok(Self { pool })
+  }
+}
+
+async fn run_migrations<P: AsRef<Path>>(database_file: P) -> Result<SqlitePool, MigrateError> {
+  let connection_options = SqliteConnectOptions::new()
+      .filename(database_file)
+      .create_if_missing(true)
+      .journal_mode(SqliteJournalMode::Wal);
+
+  let pool = SqlitePool::connect_with(connection_options).await?;
+
+  // NB: These migrations are append-only. The database is persistent, so we can never
+  // rewrite history the way the tasks database does.
+  sqlx::migrate!("../../../../_database/sql/artcraft_history_migrations").run(&pool).await?;
+
+  Ok(pool)
+}
+
+fn move_database_aside(database_file: &Path) {
+  let now = chrono::Utc::now().timestamp();
+
+  // NB: WAL mode keeps two sidecar files that belong with the database.
+  for suffix in ["", "-wal", "-shm"] {
+    let from = append_to_path(database_file, suffix);
+    if !from.exists() {
+      continue;
+    }
+
+    let to = append_to_path(database_file, &format!(".broken-{now}{suffix}"));
+
+    info!("Moving unusable history database {:?} to {:?}", from, to);
+
+    if let Err(err) = std::fs::rename(&from, &to) {
+      error!("Error moving history database file aside: {:?}", err);
+    }
+  }
+}
+
+fn append_to_path(path: &Path, suffix: &str) -> PathBuf {
+  let mut path = path.as_os_str().to_owned();
+  path.push(suffix);
+  PathBuf::from(path)
+}
@@ -0,0 +1,40 @@
+use enums::error::enum_error::EnumError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum SqliteHistoryError {
+  SqlxError(sqlx::Error),
+  EnumError(EnumError),
+  JsonError(serde_json::Error),
+}
+
+impl Error for SqliteHistoryError {}
+
+impl Display for SqliteHistoryError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SqliteHistoryError::SqlxError(err) => write!(f, "SQLx error: {:?}", err),
+      SqliteHistoryError::EnumError(err) => write!(f, "Error parsing enum: {:?}", err),
+      SqliteHistoryError::JsonError(err) => write!(f, "Error parsing JSON column: {:?}", err),
+    }
+  }
+}
+
+impl From<sqlx::Error> for SqliteHistoryError {
+  fn from(err: sqlx::Error) -> Self {
+    SqliteHistoryError::SqlxError(err)
+  }
+}
+
+impl From<EnumError> for SqliteHistoryError {
+  fn from(err: EnumError) -> Self {
+    SqliteHistoryError::EnumError(err)
+  }
+}
+
+impl From<serde_json::Error> for SqliteHistoryError {
+  fn from(err: serde_json::Error) -> Self {
+    SqliteHistoryError::JsonError(err)
+  }
+}
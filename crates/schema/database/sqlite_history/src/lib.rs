@@ -0,0 +1,26 @@
+//! sqlite_history
+//!
+//! The desktop app's persistent, searchable library of past generations.
+//!
+//! This is a separate database from `sqlite_tasks`: the tasks database is ephemeral and gets
+//! blown away on schema changes, whereas this one must survive app upgrades.
+//!
+
+// Never allow these
+#![forbid(private_bounds)]
+#![forbid(private_interfaces)]
+#![forbid(unused_must_use)] // NB: It's unsafe to not close/check some things
+
+// Okay to toggle
+#![forbid(unreachable_patterns)]
+#![forbid(unused_imports)]
+#![deny(unused_mut)] // NB: Not `forbid`, since `#[tokio::test]` allows it
+#![forbid(unused_variables)]
+
+// Always allow
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+pub mod connection;
+pub mod error;
+pub mod queries;
@@ -0,0 +1,66 @@
+use crate::connection::HistoryDbConnection;
+use crate::error::SqliteHistoryError;
+use tokens::tokens::media_files::MediaFileToken;
+
+/// Remember that a generation's output was saved to disk (eg. the user downloaded it).
+/// Returns true if rows were updated.
+pub async fn add_generation_output_file_path(
+  db: &HistoryDbConnection,
+  output_media_file_token: &MediaFileToken,
+  file_path: &str,
+) -> Result<bool, SqliteHistoryError> {
+  // NB: The same file may be downloaded repeatedly; only record each path once.
+  let result = sqlx::query(r#"
+    UPDATE generations
+    SET
+      output_file_paths = json_insert(output_file_paths, '$[#]', ?),
+      updated_at = unixepoch('now')
+    WHERE output_media_file_token = ?
+    AND NOT EXISTS (
+      SELECT 1 FROM json_each(generations.output_file_paths) WHERE value = ?
+    )
+  "#)
+      .bind(file_path)
+      .bind(output_media_file_token.as_str())
+      .bind(file_path)
+      .execute(db.get_pool())
+      .await?;
+
+  Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::queries::get_generation_by_id::get_generation_by_id;
+  use crate::queries::insert_generation::tests::insert_test_generation;
+  use crate::queries::mark_generation_complete::{mark_generation_complete, MarkGenerationCompleteArgs};
+  use enums::common::generation_provider::GenerationProvider;
+  use enums::tauri::tasks::task_type::TaskType;
+  use tokens::tokens::sqlite::tasks::TaskId;
+
+  #[tokio::test]
+  async fn appends_each_path_once() {
+    let db = HistoryDbConnection::connect_in_memory().await.unwrap();
+    let task_id = TaskId::generate();
+    let id = insert_test_generation(&db, &task_id, TaskType::ImageGeneration, GenerationProvider::Grok, "fox").await;
+    let media_file_token = MediaFileToken::new_from_str("m_output");
+
+    mark_generation_complete(MarkGenerationCompleteArgs {
+      db: &db,
+      task_id: &task_id,
+      maybe_batch_token: None,
+      maybe_media_file_token: Some(&media_file_token),
+      maybe_cdn_url: None,
+      maybe_thumbnail_url_template: None,
+    }).await.unwrap();
+
+    assert!(add_generation_output_file_path(&db, &media_file_token, "/downloads/fox.png").await.unwrap());
+    assert!(add_generation_output_file_path(&db, &media_file_token, "/downloads/fox (1).png").await.unwrap());
+    assert!(!add_generation_output_file_path(&db, &media_file_token, "/downloads/fox.png").await.unwrap());
+    assert!(!add_generation_output_file_path(&db, &MediaFileToken::new_from_str("m_other"), "/x.png").await.unwrap());
+
+    let generation = get_generation_by_id(&db, &id).await.unwrap().unwrap();
+    assert_eq!(generation.output_file_paths, vec!["/downloads/fox.png", "/downloads/fox (1).png"]);
+  }
+}
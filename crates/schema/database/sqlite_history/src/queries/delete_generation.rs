@@ -0,0 +1,53 @@
+use crate::connection::HistoryDbConnection;
+use crate::error::SqliteHistoryError;
+use tokens::tokens::sqlite::generation_history::GenerationHistoryId;
+
+/// Remove a generation from the library. (This doesn't touch the output media.)
+/// Returns true if rows were deleted.
+pub async fn delete_generation(
+  db: &HistoryDbConnection,
+  id: &GenerationHistoryId,
+) -> Result<bool, SqliteHistoryError> {
+  let result = sqlx::query("DELETE FROM generations WHERE id = ?")
+      .bind(id.as_str())
+      .execute(db.get_pool())
+      .await?;
+
+  Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::queries::get_generation_by_id::get_generation_by_id;
+  use crate::queries::insert_generation::tests::insert_test_generation;
+  use crate::queries::search_generations::{search_generations, SearchGenerationsArgs};
+  use enums::common::generation_provider::GenerationProvider;
+  use enums::tauri::tasks::task_type::TaskType;
+  use tokens::tokens::sqlite::tasks::TaskId;
+
+  #[tokio::test]
+  async fn removes_from_library_and_search() {
+    let db = HistoryDbConnection::connect_in_memory().await.unwrap();
+    let id = insert_test_generation(&db, &TaskId::generate(), TaskType::ImageGeneration, GenerationProvider::Fal, "owl").await;
+
+    assert!(delete_generation(&db, &id).await.unwrap());
+    assert!(!delete_generation(&db, &id).await.unwrap());
+    assert!(get_generation_by_id(&db, &id).await.unwrap().is_none());
+
+    let results = search_generations(SearchGenerationsArgs {
+      db: &db,
+      maybe_query: Some("owl"),
+      maybe_generation_type: None,
+      maybe_provider: None,
+      maybe_model_type: None,
+      maybe_status: None,
+      maybe_created_after: None,
+      maybe_created_before: None,
+      maybe_limit: None,
+      maybe_offset: None,
+    }).await.unwrap();
+
+    assert!(results.is_empty());
+  }
+}
@@ -0,0 +1,142 @@
+use crate::error::SqliteHistoryError;
+use chrono::{DateTime, Utc};
+use enums::common::generation_provider::GenerationProvider;
+use enums::tauri::tasks::task_model_type::TaskModelType;
+use enums::tauri::tasks::task_status::TaskStatus;
+use enums::tauri::tasks::task_type::TaskType;
+use tokens::tokens::batch_generations::BatchGenerationToken;
+use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::sqlite::generation_history::GenerationHistoryId;
+use tokens::tokens::sqlite::tasks::TaskId;
+
+/// A generation in the user's local library.
+#[derive(Clone, Debug)]
+pub struct Generation {
+  pub id: GenerationHistoryId,
+  pub task_id: Option<TaskId>,
+  pub generation_type: TaskType,
+  pub status: TaskStatus,
+  pub provider: GenerationProvider,
+  pub model_type: Option<TaskModelType>,
+  pub frontend_model: Option<String>,
+  pub prompt: Option<String>,
+  pub negative_prompt: Option<String>,
+  pub aspect_ratio: Option<String>,
+  pub resolution: Option<String>,
+  pub seed: Option<i64>,
+  pub batch_size: Option<u32>,
+  pub duration_seconds: Option<u32>,
+  pub reference_media_tokens: Vec<MediaFileToken>,
+  pub request_json: String,
+  pub cost_in_credits: Option<u64>,
+  pub output_media_file_token: Option<MediaFileToken>,
+  pub output_batch_token: Option<BatchGenerationToken>,
+  pub output_cdn_url: Option<String>,
+  pub output_thumbnail_url_template: Option<String>,
+  pub output_file_paths: Vec<String>,
+  pub failure_message: Option<String>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+  pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Columns for `RawGeneration`, in a form that works with a `g.` table alias.
+pub(crate) const GENERATION_COLUMNS: &str = r#"
+  g.id,
+  g.task_id,
+  g.generation_type,
+  g.status,
+  g.provider,
+  g.model_type,
+  g.frontend_model,
+  g.prompt,
+  g.negative_prompt,
+  g.aspect_ratio,
+  g.resolution,
+  g.seed,
+  g.batch_size,
+  g.duration_seconds,
+  g.reference_media_tokens,
+  g.request_json,
+  g.cost_in_credits,
+  g.output_media_file_token,
+  g.output_batch_token,
+  g.output_cdn_url,
+  g.output_thumbnail_url_template,
+  g.output_file_paths,
+  g.failure_message,
+  g.created_at,
+  g.updated_at,
+  g.completed_at
+"#;
+
+#[derive(Debug)]
+#[derive(sqlx::FromRow)]
+pub(crate) struct RawGeneration {
+  pub(crate) id: String,
+  pub(crate) task_id: Option<String>,
+  pub(crate) generation_type: String,
+  pub(crate) status: String,
+  pub(crate) provider: String,
+  pub(crate) model_type: Option<String>,
+  pub(crate) frontend_model: Option<String>,
+  pub(crate) prompt: Option<String>,
+  pub(crate) negative_prompt: Option<String>,
+  pub(crate) aspect_ratio: Option<String>,
+  pub(crate) resolution: Option<String>,
+  pub(crate) seed: Option<i64>,
+  pub(crate) batch_size: Option<i64>,
+  pub(crate) duration_seconds: Option<i64>,
+  pub(crate) reference_media_tokens: String,
+  pub(crate) request_json: String,
+  pub(crate) cost_in_credits: Option<i64>,
+  pub(crate) output_media_file_token: Option<String>,
+  pub(crate) output_batch_token: Option<String>,
+  pub(crate) output_cdn_url: Option<String>,
+  pub(crate) output_thumbnail_url_template: Option<String>,
+  pub(crate) output_file_paths: String,
+  pub(crate) failure_message: Option<String>,
+  pub(crate) created_at: DateTime<Utc>,
+  pub(crate) updated_at: DateTime<Utc>,
+  pub(crate) completed_at: Option<DateTime<Utc>>,
+}
+
+impl RawGeneration {
+  pub(crate) fn into_generation(self) -> Result<Generation, SqliteHistoryError> {
+    let reference_media_tokens: Vec<String> = serde_json::from_str(&self.reference_media_tokens)?;
+    let output_file_paths: Vec<String> = serde_json::from_str(&self.output_file_paths)?;
+
+    Ok(Generation {
+      id: GenerationHistoryId::new(self.id),
+      task_id: self.task_id.map(TaskId::new),
+      generation_type: TaskType::from_str(&self.generation_type)?,
+      status: TaskStatus::from_str(&self.status)?,
+      provider: GenerationProvider::from_str(&self.provider)?,
+      model_type: self.model_type
+          .map(|model| TaskModelType::from_str(&model))
+          .transpose()?,
+      frontend_model: self.frontend_model,
+      prompt: self.prompt,
+      negative_prompt: self.negative_prompt,
+      aspect_ratio: self.aspect_ratio,
+      resolution: self.resolution,
+      seed: self.seed,
+      batch_size: self.batch_size.map(|size| size as u32),
+      duration_seconds: self.duration_seconds.map(|seconds| seconds as u32),
+      reference_media_tokens: reference_media_tokens.into_iter()
+          .map(MediaFileToken::new)
+          .collect(),
+      request_json: self.request_json,
+      cost_in_credits: self.cost_in_credits.map(|cost| cost as u64),
+      output_media_file_token: self.output_media_file_token.map(MediaFileToken::new),
+      output_batch_token: self.output_batch_token.map(BatchGenerationToken::new),
+      output_cdn_url: self.output_cdn_url,
+      output_thumbnail_url_template: self.output_thumbnail_url_template,
+      output_file_paths,
+      failure_message: self.failure_message,
+      created_at: self.created_at,
+      updated_at: self.updated_at,
+      completed_at: self.completed_at,
+    })
+  }
+}
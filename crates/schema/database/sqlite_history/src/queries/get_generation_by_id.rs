@@ -0,0 +1,18 @@
+use crate::connection::HistoryDbConnection;
+use crate::error::SqliteHistoryError;
+use crate::queries::generation::{Generation, RawGeneration, GENERATION_COLUMNS};
+use tokens::tokens::sqlite::generation_history::GenerationHistoryId;
+
+pub async fn get_generation_by_id(
+  db: &HistoryDbConnection,
+  id: &GenerationHistoryId,
+) -> Result<Option<Generation>, SqliteHistoryError> {
+  let query = format!("SELECT {GENERATION_COLUMNS} FROM generations AS g WHERE g.id = ?");
+
+  let maybe_raw = sqlx::query_as::<_, RawGeneration>(&query)
+      .bind(id.as_str())
+      .fetch_optional(db.get_pool())
+      .await?;
+
+  maybe_raw.map(|raw| raw.into_generation()).transpose()
+}
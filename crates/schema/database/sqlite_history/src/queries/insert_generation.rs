@@ -0,0 +1,175 @@
+use crate::connection::HistoryDbConnection;
+use crate::error::SqliteHistoryError;
+use enums::common::generation_provider::GenerationProvider;
+use enums::tauri::tasks::task_model_type::TaskModelType;
+use enums::tauri::tasks::task_status::TaskStatus;
+use enums::tauri::tasks::task_type::TaskType;
+use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::sqlite::generation_history::GenerationHistoryId;
+use tokens::tokens::sqlite::tasks::TaskId;
+
+pub struct InsertGenerationArgs<'a> {
+  pub db: &'a HistoryDbConnection,
+  pub task_id: Option<&'a TaskId>,
+  pub generation_type: TaskType,
+  pub provider: GenerationProvider,
+  pub model_type: Option<TaskModelType>,
+  pub frontend_model: Option<&'a str>,
+  pub prompt: Option<&'a str>,
+  pub negative_prompt: Option<&'a str>,
+  pub aspect_ratio: Option<&'a str>,
+  pub resolution: Option<&'a str>,
+  pub seed: Option<i64>,
+  pub batch_size: Option<u32>,
+  pub duration_seconds: Option<u32>,
+  pub reference_media_tokens: &'a [MediaFileToken],
+
+  /// The request that produced this generation, so that it can be re-run.
+  pub request_json: &'a str,
+
+  pub cost_in_credits: Option<u64>,
+}
+
+/// Record a newly enqueued generation. It starts out as pending.
+pub async fn insert_generation(
+  args: InsertGenerationArgs<'_>,
+) -> Result<GenerationHistoryId, SqliteHistoryError> {
+  let id = GenerationHistoryId::generate();
+
+  let reference_media_tokens = args.reference_media_tokens.iter()
+      .map(|token| token.as_str())
+      .collect::<Vec<_>>();
+
+  let reference_media_tokens = serde_json::to_string(&reference_media_tokens)?;
+
+  sqlx::query(r#"
+    INSERT INTO generations (
+      id,
+      task_id,
+      generation_type,
+      status,
+      provider,
+      model_type,
+      frontend_model,
+      prompt,
+      negative_prompt,
+      aspect_ratio,
+      resolution,
+      seed,
+      batch_size,
+      duration_seconds,
+      reference_media_tokens,
+      request_json,
+      cost_in_credits
+    )
+    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+  "#)
+      .bind(id.as_str())
+      .bind(args.task_id.map(|id| id.as_str()))
+      .bind(args.generation_type.to_str())
+      .bind(TaskStatus::Pending.to_str())
+      .bind(args.provider.to_str())
+      .bind(args.model_type.map(|model| model.to_str()))
+      .bind(args.frontend_model)
+      .bind(args.prompt)
+      .bind(args.negative_prompt)
+      .bind(args.aspect_ratio)
+      .bind(args.resolution)
+      .bind(args.seed)
+      .bind(args.batch_size)
+      .bind(args.duration_seconds)
+      .bind(reference_media_tokens)
+      .bind(args.request_json)
+      .bind(args.cost_in_credits.map(|cost| cost as i64))
+      .execute(args.db.get_pool())
+      .await?;
+
+  Ok(id)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use super::*;
+  use crate::queries::get_generation_by_id::get_generation_by_id;
+
+  pub(crate) async fn insert_test_generation(
+    db: &HistoryDbConnection,
+    task_id: &TaskId,
+    generation_type: TaskType,
+    provider: GenerationProvider,
+    prompt: &str,
+  ) -> GenerationHistoryId {
+    insert_generation(InsertGenerationArgs {
+      db,
+      task_id: Some(task_id),
+      generation_type,
+      provider,
+      model_type: None,
+      frontend_model: None,
+      prompt: Some(prompt),
+      negative_prompt: None,
+      aspect_ratio: None,
+      resolution: None,
+      seed: None,
+      batch_size: None,
+      duration_seconds: None,
+      reference_media_tokens: &[],
+      request_json: "{}",
+      cost_in_credits: None,
+    }).await.unwrap()
+  }
+
+  #[tokio::test]
+  async fn round_trip() {
+    let db = HistoryDbConnection::connect_in_memory().await.unwrap();
+    let task_id = TaskId::generate();
+    let reference_media_tokens = vec![
+      MediaFileToken::new_from_str("m_first"),
+      MediaFileToken::new_from_str("m_second"),
+    ];
+
+    let id = insert_generation(InsertGenerationArgs {
+      db: &db,
+      task_id: Some(&task_id),
+      generation_type: TaskType::ImageGeneration,
+      provider: GenerationProvider::Artcraft,
+      model_type: Some(TaskModelType::NanoBanana),
+      frontend_model: Some("nano_banana"),
+      prompt: Some("a lighthouse at dusk"),
+      negative_prompt: Some("people"),
+      aspect_ratio: Some("wide_sixteen_by_nine"),
+      resolution: Some("two_k"),
+      seed: Some(-42),
+      batch_size: Some(4),
+      duration_seconds: None,
+      reference_media_tokens: &reference_media_tokens,
+      request_json: r#"{"prompt":"a lighthouse at dusk"}"#,
+      cost_in_credits: Some(12),
+    }).await.unwrap();
+
+    let generation = get_generation_by_id(&db, &id).await.unwrap().unwrap();
+
+    assert_eq!(generation.id, id);
+    assert_eq!(generation.task_id, Some(task_id));
+    assert_eq!(generation.generation_type, TaskType::ImageGeneration);
+    assert_eq!(generation.status, TaskStatus::Pending);
+    assert_eq!(generation.provider, GenerationProvider::Artcraft);
+    assert_eq!(generation.model_type, Some(TaskModelType::NanoBanana));
+    assert_eq!(generation.prompt.as_deref(), Some("a lighthouse at dusk"));
+    assert_eq!(generation.negative_prompt.as_deref(), Some("people"));
+    assert_eq!(generation.seed, Some(-42));
+    assert_eq!(generation.batch_size, Some(4));
+    assert_eq!(generation.reference_media_tokens, reference_media_tokens);
+    assert_eq!(generation.request_json, r#"{"prompt":"a lighthouse at dusk"}"#);
+    assert_eq!(generation.cost_in_credits, Some(12));
+    assert!(generation.output_file_paths.is_empty());
+    assert_eq!(generation.completed_at, None);
+  }
+
+  #[tokio::test]
+  async fn missing() {
+    let db = HistoryDbConnection::connect_in_memory().await.unwrap();
+    let generation = get_generation_by_id(&db, &GenerationHistoryId::generate()).await.unwrap();
+    assert!(generation.is_none());
+  }
+}
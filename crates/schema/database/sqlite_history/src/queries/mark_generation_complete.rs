@@ -0,0 +1,94 @@
+use crate::connection::HistoryDbConnection;
+use crate::error::SqliteHistoryError;
+use enums::tauri::tasks::task_status::TaskStatus;
+use tokens::tokens::batch_generations::BatchGenerationToken;
+use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::sqlite::tasks::TaskId;
+
+pub struct MarkGenerationCompleteArgs<'a> {
+  pub db: &'a HistoryDbConnection,
+  pub task_id: &'a TaskId,
+  pub maybe_batch_token: Option<&'a BatchGenerationToken>,
+  pub maybe_media_file_token: Option<&'a MediaFileToken>,
+  pub maybe_cdn_url: Option<&'a str>,
+  pub maybe_thumbnail_url_template: Option<&'a str>,
+}
+
+/// Record the outputs of the generation tracked by the given task.
+/// Returns true if rows were updated (ie. the generation is in the history).
+pub async fn mark_generation_complete(
+  args: MarkGenerationCompleteArgs<'_>,
+) -> Result<bool, SqliteHistoryError> {
+  let result = sqlx::query(r#"
+    UPDATE generations
+    SET
+      status = ?,
+      output_batch_token = ?,
+      output_media_file_token = ?,
+      output_cdn_url = ?,
+      output_thumbnail_url_template = ?,
+      updated_at = unixepoch('now'),
+      completed_at = unixepoch('now')
+    WHERE task_id = ?
+  "#)
+      .bind(TaskStatus::CompleteSuccess.to_str())
+      .bind(args.maybe_batch_token.map(|token| token.as_str()))
+      .bind(args.maybe_media_file_token.map(|token| token.as_str()))
+      .bind(args.maybe_cdn_url)
+      .bind(args.maybe_thumbnail_url_template)
+      .bind(args.task_id.as_str())
+      .execute(args.db.get_pool())
+      .await?;
+
+  Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::queries::get_generation_by_id::get_generation_by_id;
+  use crate::queries::insert_generation::tests::insert_test_generation;
+  use enums::common::generation_provider::GenerationProvider;
+  use enums::tauri::tasks::task_type::TaskType;
+
+  #[tokio::test]
+  async fn records_outputs() {
+    let db = HistoryDbConnection::connect_in_memory().await.unwrap();
+    let task_id = TaskId::generate();
+    let id = insert_test_generation(&db, &task_id, TaskType::VideoGeneration, GenerationProvider::Fal, "waves").await;
+    let media_file_token = MediaFileToken::new_from_str("m_output");
+
+    let updated = mark_generation_complete(MarkGenerationCompleteArgs {
+      db: &db,
+      task_id: &task_id,
+      maybe_batch_token: None,
+      maybe_media_file_token: Some(&media_file_token),
+      maybe_cdn_url: Some("https://cdn.example.com/output.mp4"),
+      maybe_thumbnail_url_template: None,
+    }).await.unwrap();
+
+    assert!(updated);
+
+    let generation = get_generation_by_id(&db, &id).await.unwrap().unwrap();
+    assert_eq!(generation.status, TaskStatus::CompleteSuccess);
+    assert_eq!(generation.output_media_file_token, Some(media_file_token));
+    assert_eq!(generation.output_cdn_url.as_deref(), Some("https://cdn.example.com/output.mp4"));
+    assert!(generation.completed_at.is_some());
+  }
+
+  #[tokio::test]
+  async fn unknown_task() {
+    let db = HistoryDbConnection::connect_in_memory().await.unwrap();
+
+    let updated = mark_generation_complete(MarkGenerationCompleteArgs {
+      db: &db,
+      task_id: &TaskId::generate(),
+      maybe_batch_token: None,
+      maybe_media_file_token: None,
+      maybe_cdn_url: None,
+      maybe_thumbnail_url_template: None,
+    }).await.unwrap();
+
+    assert!(!updated);
+  }
+}
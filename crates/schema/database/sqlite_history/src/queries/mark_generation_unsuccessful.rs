@@ -0,0 +1,67 @@
+use crate::connection::HistoryDbConnection;
+use crate::error::SqliteHistoryError;
+use enums::tauri::tasks::task_status::TaskStatus;
+use tokens::tokens::sqlite::tasks::TaskId;
+
+pub struct MarkGenerationUnsuccessfulArgs<'a> {
+  pub db: &'a HistoryDbConnection,
+  pub task_id: &'a TaskId,
+
+  /// Typically `CompleteFailure`, `Dead`, or one of the cancellation statuses.
+  pub status: TaskStatus,
+
+  pub maybe_failure_message: Option<&'a str>,
+}
+
+/// Record that the generation tracked by the given task failed or was cancelled.
+/// Returns true if rows were updated.
+pub async fn mark_generation_unsuccessful(
+  args: MarkGenerationUnsuccessfulArgs<'_>,
+) -> Result<bool, SqliteHistoryError> {
+  let result = sqlx::query(r#"
+    UPDATE generations
+    SET
+      status = ?,
+      failure_message = ?,
+      updated_at = unixepoch('now'),
+      completed_at = unixepoch('now')
+    WHERE task_id = ?
+  "#)
+      .bind(args.status.to_str())
+      .bind(args.maybe_failure_message)
+      .bind(args.task_id.as_str())
+      .execute(args.db.get_pool())
+      .await?;
+
+  Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::queries::get_generation_by_id::get_generation_by_id;
+  use crate::queries::insert_generation::tests::insert_test_generation;
+  use enums::common::generation_provider::GenerationProvider;
+  use enums::tauri::tasks::task_type::TaskType;
+
+  #[tokio::test]
+  async fn records_failure() {
+    let db = HistoryDbConnection::connect_in_memory().await.unwrap();
+    let task_id = TaskId::generate();
+    let id = insert_test_generation(&db, &task_id, TaskType::ImageGeneration, GenerationProvider::Sora, "cat").await;
+
+    let updated = mark_generation_unsuccessful(MarkGenerationUnsuccessfulArgs {
+      db: &db,
+      task_id: &task_id,
+      status: TaskStatus::CompleteFailure,
+      maybe_failure_message: Some("content policy"),
+    }).await.unwrap();
+
+    assert!(updated);
+
+    let generation = get_generation_by_id(&db, &id).await.unwrap().unwrap();
+    assert_eq!(generation.status, TaskStatus::CompleteFailure);
+    assert_eq!(generation.failure_message.as_deref(), Some("content policy"));
+    assert!(generation.completed_at.is_some());
+  }
+}
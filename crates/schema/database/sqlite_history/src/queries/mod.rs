@@ -0,0 +1,8 @@
+pub mod add_generation_output_file_path;
+pub mod delete_generation;
+pub mod generation;
+pub mod get_generation_by_id;
+pub mod insert_generation;
+pub mod mark_generation_complete;
+pub mod mark_generation_unsuccessful;
+pub mod search_generations;
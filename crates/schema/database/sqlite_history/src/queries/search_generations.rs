@@ -0,0 +1,226 @@
+use crate::connection::HistoryDbConnection;
+use crate::error::SqliteHistoryError;
+use crate::queries::generation::{Generation, RawGeneration, GENERATION_COLUMNS};
+use chrono::{DateTime, Utc};
+use enums::common::generation_provider::GenerationProvider;
+use enums::tauri::tasks::task_model_type::TaskModelType;
+use enums::tauri::tasks::task_status::TaskStatus;
+use enums::tauri::tasks::task_type::TaskType;
+use sqlx::{QueryBuilder, Sqlite};
+
+const DEFAULT_LIMIT: u32 = 50;
+const MAX_LIMIT: u32 = 200;
+
+pub struct SearchGenerationsArgs<'a> {
+  pub db: &'a HistoryDbConnection,
+
+  /// Free text, matched against the prompt, negative prompt, and model name.
+  /// Each word is matched as a prefix, and all words must match.
+  pub maybe_query: Option<&'a str>,
+
+  pub maybe_generation_type: Option<TaskType>,
+  pub maybe_provider: Option<GenerationProvider>,
+  pub maybe_model_type: Option<TaskModelType>,
+  pub maybe_status: Option<TaskStatus>,
+  pub maybe_created_after: Option<DateTime<Utc>>,
+  pub maybe_created_before: Option<DateTime<Utc>>,
+
+  pub maybe_limit: Option<u32>,
+  pub maybe_offset: Option<u32>,
+}
+
+/// Search the library, newest first.
+pub async fn search_generations(
+  args: SearchGenerationsArgs<'_>,
+) -> Result<Vec<Generation>, SqliteHistoryError> {
+  let maybe_match_query = args.maybe_query.and_then(to_fts_match_query);
+
+  let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+    format!("SELECT {GENERATION_COLUMNS} FROM generations AS g"));
+
+  if maybe_match_query.is_some() {
+    query_builder.push(" JOIN generations_fts AS f ON f.id = g.id");
+  }
+
+  query_builder.push(" WHERE 1 = 1");
+
+  if let Some(match_query) = maybe_match_query {
+    query_builder.push(" AND generations_fts MATCH ");
+    query_builder.push_bind(match_query);
+  }
+
+  if let Some(generation_type) = args.maybe_generation_type {
+    query_builder.push(" AND g.generation_type = ");
+    query_builder.push_bind(generation_type.to_str());
+  }
+
+  if let Some(provider) = args.maybe_provider {
+    query_builder.push(" AND g.provider = ");
+    query_builder.push_bind(provider.to_str());
+  }
+
+  if let Some(model_type) = args.maybe_model_type {
+    query_builder.push(" AND g.model_type = ");
+    query_builder.push_bind(model_type.to_str());
+  }
+
+  if let Some(status) = args.maybe_status {
+    query_builder.push(" AND g.status = ");
+    query_builder.push_bind(status.to_str());
+  }
+
+  if let Some(created_after) = args.maybe_created_after {
+    query_builder.push(" AND g.created_at >= ");
+    query_builder.push_bind(created_after.timestamp());
+  }
+
+  if let Some(created_before) = args.maybe_created_before {
+    query_builder.push(" AND g.created_at < ");
+    query_builder.push_bind(created_before.timestamp());
+  }
+
+  let limit = args.maybe_limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+  // NB: rowid breaks ties between generations started in the same second.
+  query_builder.push(" ORDER BY g.created_at DESC, g.rowid DESC LIMIT ");
+  query_builder.push_bind(limit);
+  query_builder.push(" OFFSET ");
+  query_builder.push_bind(args.maybe_offset.unwrap_or(0));
+
+  let raw_generations = query_builder.build_query_as::<RawGeneration>()
+      .fetch_all(args.db.get_pool())
+      .await?;
+
+  raw_generations.into_iter()
+      .map(|raw| raw.into_generation())
+      .collect()
+}
+
+/// Turn user input into an FTS5 query. Each word becomes a quoted prefix match, so users
+/// can't (accidentally) write FTS5 syntax errors with characters like `"`, `*`, `-`, or `:`.
+/// Returns `None` if there's nothing to search for.
+fn to_fts_match_query(query: &str) -> Option<String> {
+  let terms = query.split_whitespace()
+      .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+      .collect::<Vec<_>>();
+
+  if terms.is_empty() {
+    None
+  } else {
+    Some(terms.join(" "))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::queries::insert_generation::tests::insert_test_generation;
+  use tokens::tokens::sqlite::tasks::TaskId;
+
+  fn args(db: &HistoryDbConnection) -> SearchGenerationsArgs<'_> {
+    SearchGenerationsArgs {
+      db,
+      maybe_query: None,
+      maybe_generation_type: None,
+      maybe_provider: None,
+      maybe_model_type: None,
+      maybe_status: None,
+      maybe_created_after: None,
+      maybe_created_before: None,
+      maybe_limit: None,
+      maybe_offset: None,
+    }
+  }
+
+  fn prompts(generations: &[Generation]) -> Vec<&str> {
+    generations.iter()
+        .map(|generation| generation.prompt.as_deref().unwrap_or(""))
+        .collect()
+  }
+
+  async fn seeded_db() -> HistoryDbConnection {
+    let db = HistoryDbConnection::connect_in_memory().await.unwrap();
+    insert_test_generation(&db, &TaskId::generate(), TaskType::ImageGeneration, GenerationProvider::Fal, "A red dragon over the castle").await;
+    insert_test_generation(&db, &TaskId::generate(), TaskType::VideoGeneration, GenerationProvider::Sora, "Dragonfly on a pond").await;
+    insert_test_generation(&db, &TaskId::generate(), TaskType::ImageGeneration, GenerationProvider::Grok, "Café at night").await;
+    db
+  }
+
+  #[tokio::test]
+  async fn newest_first() {
+    let db = seeded_db().await;
+    let results = search_generations(args(&db)).await.unwrap();
+    assert_eq!(prompts(&results), vec!["Café at night", "Dragonfly on a pond", "A red dragon over the castle"]);
+  }
+
+  #[tokio::test]
+  async fn full_text() {
+    let db = seeded_db().await;
+
+    let results = search_generations(SearchGenerationsArgs { maybe_query: Some("dragon"), ..args(&db) }).await.unwrap();
+    assert_eq!(prompts(&results), vec!["Dragonfly on a pond", "A red dragon over the castle"]);
+
+    let results = search_generations(SearchGenerationsArgs { maybe_query: Some("red drag"), ..args(&db) }).await.unwrap();
+    assert_eq!(prompts(&results), vec!["A red dragon over the castle"]);
+
+    // Diacritics are folded.
+    let results = search_generations(SearchGenerationsArgs { maybe_query: Some("cafe"), ..args(&db) }).await.unwrap();
+    assert_eq!(prompts(&results), vec!["Café at night"]);
+
+    // FTS5 syntax is treated as text.
+    let results = search_generations(SearchGenerationsArgs { maybe_query: Some("\"dragon* -OR:"), ..args(&db) }).await.unwrap();
+    assert!(results.is_empty());
+
+    // Blank queries don't filter.
+    let results = search_generations(SearchGenerationsArgs { maybe_query: Some("  "), ..args(&db) }).await.unwrap();
+    assert_eq!(results.len(), 3);
+  }
+
+  #[tokio::test]
+  async fn filters() {
+    let db = seeded_db().await;
+
+    let results = search_generations(SearchGenerationsArgs {
+      maybe_query: Some("dragon"),
+      maybe_generation_type: Some(TaskType::ImageGeneration),
+      ..args(&db)
+    }).await.unwrap();
+    assert_eq!(prompts(&results), vec!["A red dragon over the castle"]);
+
+    let results = search_generations(SearchGenerationsArgs {
+      maybe_provider: Some(GenerationProvider::Grok),
+      ..args(&db)
+    }).await.unwrap();
+    assert_eq!(prompts(&results), vec!["Café at night"]);
+
+    let results = search_generations(SearchGenerationsArgs {
+      maybe_status: Some(TaskStatus::CompleteSuccess),
+      ..args(&db)
+    }).await.unwrap();
+    assert!(results.is_empty());
+
+    let results = search_generations(SearchGenerationsArgs {
+      maybe_created_after: Some(Utc::now() + chrono::Duration::hours(1)),
+      ..args(&db)
+    }).await.unwrap();
+    assert!(results.is_empty());
+  }
+
+  #[tokio::test]
+  async fn paging() {
+    let db = seeded_db().await;
+    let results = search_generations(SearchGenerationsArgs {
+      maybe_limit: Some(1),
+      maybe_offset: Some(1),
+      ..args(&db)
+    }).await.unwrap();
+    assert_eq!(prompts(&results), vec!["Dragonfly on a pond"]);
+  }
+
+  #[test]
+  fn fts_match_query() {
+    assert_eq!(to_fts_match_query("red dragon"), Some("\"red\"* \"dragon\"*".to_string()));
+    assert_eq!(to_fts_match_query("say \"hi\""), Some("\"say\"* \"\"\"hi\"\"\"*".to_string()));
+    assert_eq!(to_fts_match_query(" \t"), None);
+  }
+}
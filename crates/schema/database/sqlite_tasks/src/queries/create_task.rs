@@ -6,6 +6,7 @@ use enums::tauri::tasks::task_status::TaskStatus;
 use enums::tauri::tasks::task_type::TaskType;
 use enums::tauri::ux::tauri_command_caller::TauriCommandCaller;
 use tokens::tokens::prompts::PromptToken;
+use tokens::tokens::sqlite::task_batches::TaskBatchId;
 use tokens::tokens::sqlite::tasks::TaskId;
 
 pub struct CreateTaskArgs<'a> {
@@ -21,6 +22,8 @@ pub struct CreateTaskArgs<'a> {
   pub frontend_caller: Option<TauriCommandCaller>,
   pub frontend_subscriber_id: Option<&'a str>,
   pub frontend_subscriber_payload: Option<&'a str>,
+  pub batch_id: Option<&'a TaskBatchId>,
+  pub batch_index: Option<u32>,
 }
 
 pub async fn create_task(
@@ -36,6 +39,7 @@ pub async fn create_task(
   let provider_temp = args.provider.to_string();
   let prompt_token_temp = args.prompt_token.map(|t| t.as_str());
   let frontend_caller_temp = args.frontend_caller.map(|s| s.to_str());
+  let batch_id_temp = args.batch_id.map(|t| t.as_str());
 
   let query = sqlx::query!(r#"
     INSERT INTO tasks (
@@ -50,9 +54,11 @@ pub async fn create_task(
       prompt_token,
       frontend_caller,
       frontend_subscriber_id,
-      frontend_subscriber_payload
+      frontend_subscriber_payload,
+      batch_id,
+      batch_index
     )
-    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
   "#,
       task_id_temp,
       status_temp,
@@ -65,7 +71,9 @@ pub async fn create_task(
       prompt_token_temp,
       frontend_caller_temp,
       args.frontend_subscriber_id,
-      args.frontend_subscriber_payload
+      args.frontend_subscriber_payload,
+      batch_id_temp,
+      args.batch_index
   );
 
   let _r = query.execute(args.db.get_pool()).await?;
@@ -10,6 +10,7 @@ use enums::tauri::tasks::task_type::TaskType;
 use enums::tauri::ux::tauri_command_caller::TauriCommandCaller;
 use tokens::tokens::batch_generations::BatchGenerationToken;
 use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::sqlite::task_batches::TaskBatchId;
 use tokens::tokens::sqlite::tasks::TaskId;
 
 pub struct TaskList {
@@ -26,6 +27,8 @@ pub struct TaskItem {
   pub frontend_caller: Option<TauriCommandCaller>,
   pub frontend_subscriber_id: Option<String>,
   pub frontend_subscriber_payload: Option<String>,
+  pub batch_id: Option<TaskBatchId>,
+  pub batch_index: Option<u32>,
   pub on_complete_primary_media_file_token: Option<MediaFileToken>,
   pub on_complete_primary_media_file_class: Option<TaskMediaFileClass>,
   pub on_complete_batch_token: Option<BatchGenerationToken>,
@@ -54,6 +57,8 @@ pub async fn list_tasks_for_frontend(
       frontend_caller,
       frontend_subscriber_id,
       frontend_subscriber_payload,
+      batch_id,
+      batch_index,
       on_complete_primary_media_file_token,
       on_complete_primary_media_file_class,
       on_complete_batch_token,
@@ -90,6 +95,8 @@ pub async fn list_tasks_for_frontend(
           .transpose()?,
       frontend_subscriber_id: raw.frontend_subscriber_id,
       frontend_subscriber_payload: raw.frontend_subscriber_payload,
+      batch_id: raw.batch_id.map(TaskBatchId::new),
+      batch_index: raw.batch_index.map(|index| index as u32),
       on_complete_primary_media_file_token: raw.on_complete_primary_media_file_token.map(|t| MediaFileToken::new_from_str(&t)),
       on_complete_primary_media_file_class: raw.on_complete_primary_media_file_class
           .map(|c| TaskMediaFileClass::from_str(&c))
@@ -122,6 +129,8 @@ struct TaskItemRaw {
   frontend_caller: Option<String>,
   frontend_subscriber_id: Option<String>,
   frontend_subscriber_payload: Option<String>,
+  batch_id: Option<String>,
+  batch_index: Option<i64>,
   on_complete_primary_media_file_token: Option<String>,
   on_complete_primary_media_file_class: Option<String>,
   on_complete_batch_token: Option<String>,
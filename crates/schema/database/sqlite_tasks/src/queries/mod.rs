@@ -1,6 +1,7 @@
 pub mod create_task;
 pub mod dismiss_all_tasks;
 pub mod list_non_artcraft_pending_tasks;
+pub mod get_task_by_id;
 pub mod get_task_by_provider_and_provider_job_id;
 pub mod list_tasks_by_provider_and_status;
 pub mod list_tasks_by_provider_and_tokens;
@@ -15,6 +15,7 @@ pub mod media_files;
 pub mod media_uploads;
 pub mod model_categories;
 pub mod model_weights;
+pub mod outbound_emails;
 pub mod prompt_context_items;
 pub mod prompts;
 pub mod staff_audit_logs;
@@ -23,11 +24,14 @@ pub mod trending_model_analytics;
 pub mod tts_models;
 pub mod usages;
 pub mod user_bookmarks;
+pub mod user_personal_access_tokens;
+pub mod user_rate_limit_overrides;
 pub mod user_ratings;
 pub mod users;
 pub mod voice_conversion_models;
 pub mod voice_conversion_results;
 pub mod wallet_ledger_entries;
+pub mod webhook_deliveries;
 pub mod zs_voices;
 
 // ===== Sqlite =====
@@ -0,0 +1 @@
+pub mod outbound_email_status;
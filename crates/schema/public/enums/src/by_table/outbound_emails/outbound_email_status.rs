@@ -0,0 +1,146 @@
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+use strum::EnumCount;
+#[cfg(test)]
+use strum::EnumIter;
+
+/// Used in the `outbound_emails` table in `VARCHAR(16)` field `status`.
+///
+/// Where an outbound email is in the send / retry cycle.
+///
+/// YOU CAN ADD NEW VALUES, BUT DO NOT CHANGE EXISTING VALUES WITHOUT A MIGRATION STRATEGY.
+#[cfg_attr(test, derive(EnumIter, EnumCount))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize, Serialize)]
+pub enum OutboundEmailStatus {
+  /// Enqueued and not yet attempted.
+  #[serde(rename = "pending")]
+  Pending,
+
+  /// A worker claimed the email and is sending it. If the worker dies, the email
+  /// can be reclaimed once `next_attempt_at` passes.
+  #[serde(rename = "sending")]
+  Sending,
+
+  /// The provider accepted the email.
+  #[serde(rename = "sent")]
+  Sent,
+
+  /// The last attempt failed with a transient error. Retried at `next_attempt_at`.
+  #[serde(rename = "attempt_failed")]
+  AttemptFailed,
+
+  /// Failed permanently, or ran out of attempts. Never retried.
+  #[serde(rename = "dead")]
+  Dead,
+}
+
+impl_enum_display_and_debug_using_to_str!(OutboundEmailStatus);
+impl_mysql_enum_coders!(OutboundEmailStatus);
+impl_mysql_from_row!(OutboundEmailStatus);
+
+impl OutboundEmailStatus {
+  pub fn to_str(&self) -> &'static str {
+    match self {
+      Self::Pending => "pending",
+      Self::Sending => "sending",
+      Self::Sent => "sent",
+      Self::AttemptFailed => "attempt_failed",
+      Self::Dead => "dead",
+    }
+  }
+
+  pub fn from_str(value: &str) -> Result<Self, String> {
+    match value {
+      "pending" => Ok(Self::Pending),
+      "sending" => Ok(Self::Sending),
+      "sent" => Ok(Self::Sent),
+      "attempt_failed" => Ok(Self::AttemptFailed),
+      "dead" => Ok(Self::Dead),
+      _ => Err(format!("invalid OutboundEmailStatus value: {:?}", value)),
+    }
+  }
+
+  pub fn all_variants() -> BTreeSet<Self> {
+    BTreeSet::from([
+      Self::Pending,
+      Self::Sending,
+      Self::Sent,
+      Self::AttemptFailed,
+      Self::Dead,
+    ])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::by_table::outbound_emails::outbound_email_status::OutboundEmailStatus;
+  use crate::test_helpers::assert_serialization;
+
+  mod explicit_checks {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+      assert_serialization(OutboundEmailStatus::Pending, "pending");
+      assert_serialization(OutboundEmailStatus::Sending, "sending");
+      assert_serialization(OutboundEmailStatus::Sent, "sent");
+      assert_serialization(OutboundEmailStatus::AttemptFailed, "attempt_failed");
+      assert_serialization(OutboundEmailStatus::Dead, "dead");
+    }
+
+    #[test]
+    fn to_str() {
+      assert_eq!(OutboundEmailStatus::Pending.to_str(), "pending");
+      assert_eq!(OutboundEmailStatus::Sending.to_str(), "sending");
+      assert_eq!(OutboundEmailStatus::Sent.to_str(), "sent");
+      assert_eq!(OutboundEmailStatus::AttemptFailed.to_str(), "attempt_failed");
+      assert_eq!(OutboundEmailStatus::Dead.to_str(), "dead");
+    }
+
+    #[test]
+    fn from_str() {
+      assert_eq!(OutboundEmailStatus::from_str("pending").unwrap(), OutboundEmailStatus::Pending);
+      assert_eq!(OutboundEmailStatus::from_str("sending").unwrap(), OutboundEmailStatus::Sending);
+      assert_eq!(OutboundEmailStatus::from_str("sent").unwrap(), OutboundEmailStatus::Sent);
+      assert_eq!(OutboundEmailStatus::from_str("attempt_failed").unwrap(), OutboundEmailStatus::AttemptFailed);
+      assert_eq!(OutboundEmailStatus::from_str("dead").unwrap(), OutboundEmailStatus::Dead);
+      assert!(OutboundEmailStatus::from_str("invalid").is_err());
+    }
+
+    #[test]
+    fn all_variants() {
+      const EXPECTED_COUNT: usize = 5;
+      assert_eq!(OutboundEmailStatus::all_variants().len(), EXPECTED_COUNT);
+    }
+  }
+
+  mod mechanical_checks {
+    use super::*;
+
+    #[test]
+    fn variant_length() {
+      use strum::IntoEnumIterator;
+      assert_eq!(OutboundEmailStatus::all_variants().len(), OutboundEmailStatus::iter().len());
+    }
+
+    #[test]
+    fn round_trip() {
+      for variant in OutboundEmailStatus::all_variants() {
+        assert_eq!(variant, OutboundEmailStatus::from_str(variant.to_str()).unwrap());
+        assert_eq!(variant, OutboundEmailStatus::from_str(&format!("{}", variant)).unwrap());
+        assert_eq!(variant, OutboundEmailStatus::from_str(&format!("{:?}", variant)).unwrap());
+      }
+    }
+
+    #[test]
+    fn serialized_length_ok_for_database() {
+      const MAX_LENGTH: usize = 16;
+      for variant in OutboundEmailStatus::all_variants() {
+        let serialized = variant.to_str();
+        assert!(!serialized.is_empty(), "variant {:?} is too short", variant);
+        assert!(serialized.len() <= MAX_LENGTH, "variant {:?} is too long for VARCHAR({})", variant, MAX_LENGTH);
+      }
+    }
+  }
+}
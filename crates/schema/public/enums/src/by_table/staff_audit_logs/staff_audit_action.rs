@@ -40,6 +40,18 @@ pub enum StaffAuditAction {
   /// Staff edited a user's feature flags.
   #[serde(rename = "edit_user_feature_flags")]
   EditUserFeatureFlags,
+
+  /// Staff re-ran a stored webhook delivery.
+  #[serde(rename = "redrive_webhook_delivery")]
+  RedriveWebhookDelivery,
+
+  /// Staff removed a user's two factor auth (eg. lost authenticator and recovery codes).
+  #[serde(rename = "reset_user_two_factor")]
+  ResetUserTwoFactor,
+
+  /// Staff set or removed a per-user rate limit override.
+  #[serde(rename = "edit_user_rate_limit_override")]
+  EditUserRateLimitOverride,
 }
 
 impl_enum_display_and_debug_using_to_str!(StaffAuditAction);
@@ -56,6 +68,9 @@ impl StaffAuditAction {
       Self::AddWalletBankedBalance => "add_wallet_banked_balance",
       Self::SendAlert => "send_alert",
       Self::EditUserFeatureFlags => "edit_user_feature_flags",
+      Self::RedriveWebhookDelivery => "redrive_webhook_delivery",
+      Self::ResetUserTwoFactor => "reset_user_two_factor",
+      Self::EditUserRateLimitOverride => "edit_user_rate_limit_override",
     }
   }
 
@@ -68,6 +83,9 @@ impl StaffAuditAction {
       "add_wallet_banked_balance" => Ok(Self::AddWalletBankedBalance),
       "send_alert" => Ok(Self::SendAlert),
       "edit_user_feature_flags" => Ok(Self::EditUserFeatureFlags),
+      "redrive_webhook_delivery" => Ok(Self::RedriveWebhookDelivery),
+      "reset_user_two_factor" => Ok(Self::ResetUserTwoFactor),
+      "edit_user_rate_limit_override" => Ok(Self::EditUserRateLimitOverride),
       _ => Err(format!("invalid StaffAuditAction value: {:?}", value)),
     }
   }
@@ -81,6 +99,9 @@ impl StaffAuditAction {
       Self::AddWalletBankedBalance,
       Self::SendAlert,
       Self::EditUserFeatureFlags,
+      Self::RedriveWebhookDelivery,
+      Self::ResetUserTwoFactor,
+      Self::EditUserRateLimitOverride,
     ])
   }
 }
@@ -102,6 +123,9 @@ mod tests {
       assert_serialization(StaffAuditAction::AddWalletBankedBalance, "add_wallet_banked_balance");
       assert_serialization(StaffAuditAction::SendAlert, "send_alert");
       assert_serialization(StaffAuditAction::EditUserFeatureFlags, "edit_user_feature_flags");
+      assert_serialization(StaffAuditAction::RedriveWebhookDelivery, "redrive_webhook_delivery");
+      assert_serialization(StaffAuditAction::ResetUserTwoFactor, "reset_user_two_factor");
+      assert_serialization(StaffAuditAction::EditUserRateLimitOverride, "edit_user_rate_limit_override");
     }
 
     #[test]
@@ -113,6 +137,9 @@ mod tests {
       assert_eq!(StaffAuditAction::AddWalletBankedBalance.to_str(), "add_wallet_banked_balance");
       assert_eq!(StaffAuditAction::SendAlert.to_str(), "send_alert");
       assert_eq!(StaffAuditAction::EditUserFeatureFlags.to_str(), "edit_user_feature_flags");
+      assert_eq!(StaffAuditAction::RedriveWebhookDelivery.to_str(), "redrive_webhook_delivery");
+      assert_eq!(StaffAuditAction::ResetUserTwoFactor.to_str(), "reset_user_two_factor");
+      assert_eq!(StaffAuditAction::EditUserRateLimitOverride.to_str(), "edit_user_rate_limit_override");
     }
 
     #[test]
@@ -124,12 +151,15 @@ mod tests {
       assert_eq!(StaffAuditAction::from_str("add_wallet_banked_balance").unwrap(), StaffAuditAction::AddWalletBankedBalance);
       assert_eq!(StaffAuditAction::from_str("send_alert").unwrap(), StaffAuditAction::SendAlert);
       assert_eq!(StaffAuditAction::from_str("edit_user_feature_flags").unwrap(), StaffAuditAction::EditUserFeatureFlags);
+      assert_eq!(StaffAuditAction::from_str("redrive_webhook_delivery").unwrap(), StaffAuditAction::RedriveWebhookDelivery);
+      assert_eq!(StaffAuditAction::from_str("reset_user_two_factor").unwrap(), StaffAuditAction::ResetUserTwoFactor);
+      assert_eq!(StaffAuditAction::from_str("edit_user_rate_limit_override").unwrap(), StaffAuditAction::EditUserRateLimitOverride);
       assert!(StaffAuditAction::from_str("invalid").is_err());
     }
 
     #[test]
     fn all_variants() {
-      const EXPECTED_COUNT: usize = 7;
+      const EXPECTED_COUNT: usize = 10;
       assert_eq!(StaffAuditAction::all_variants().len(), EXPECTED_COUNT);
     }
   }
@@ -20,6 +20,10 @@ pub enum StaffAuditEntityType {
   /// A wallet.
   #[serde(rename = "wallet")]
   Wallet,
+
+  /// A stored webhook delivery.
+  #[serde(rename = "webhook_delivery")]
+  WebhookDelivery,
 }
 
 impl_enum_display_and_debug_using_to_str!(StaffAuditEntityType);
@@ -31,6 +35,7 @@ impl StaffAuditEntityType {
     match self {
       Self::User => "user",
       Self::Wallet => "wallet",
+      Self::WebhookDelivery => "webhook_delivery",
     }
   }
 
@@ -38,6 +43,7 @@ impl StaffAuditEntityType {
     match value {
       "user" => Ok(Self::User),
       "wallet" => Ok(Self::Wallet),
+      "webhook_delivery" => Ok(Self::WebhookDelivery),
       _ => Err(format!("invalid StaffAuditEntityType value: {:?}", value)),
     }
   }
@@ -46,6 +52,7 @@ impl StaffAuditEntityType {
     BTreeSet::from([
       Self::User,
       Self::Wallet,
+      Self::WebhookDelivery,
     ])
   }
 }
@@ -62,24 +69,27 @@ mod tests {
     fn test_serialization() {
       assert_serialization(StaffAuditEntityType::User, "user");
       assert_serialization(StaffAuditEntityType::Wallet, "wallet");
+      assert_serialization(StaffAuditEntityType::WebhookDelivery, "webhook_delivery");
     }
 
     #[test]
     fn to_str() {
       assert_eq!(StaffAuditEntityType::User.to_str(), "user");
       assert_eq!(StaffAuditEntityType::Wallet.to_str(), "wallet");
+      assert_eq!(StaffAuditEntityType::WebhookDelivery.to_str(), "webhook_delivery");
     }
 
     #[test]
     fn from_str() {
       assert_eq!(StaffAuditEntityType::from_str("user").unwrap(), StaffAuditEntityType::User);
       assert_eq!(StaffAuditEntityType::from_str("wallet").unwrap(), StaffAuditEntityType::Wallet);
+      assert_eq!(StaffAuditEntityType::from_str("webhook_delivery").unwrap(), StaffAuditEntityType::WebhookDelivery);
       assert!(StaffAuditEntityType::from_str("invalid").is_err());
     }
 
     #[test]
     fn all_variants() {
-      const EXPECTED_COUNT: usize = 2;
+      const EXPECTED_COUNT: usize = 3;
       assert_eq!(StaffAuditEntityType::all_variants().len(), EXPECTED_COUNT);
     }
   }
@@ -0,0 +1,170 @@
+use std::collections::BTreeSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+#[cfg(test)]
+use strum::EnumCount;
+#[cfg(test)]
+use strum::EnumIter;
+use utoipa::ToSchema;
+
+/// Used in the `user_personal_access_tokens` table in a `VARCHAR` field (stored as comma separated set).
+///
+/// Each scope grants a personal access token access to a group of endpoints. Endpoints that
+/// don't name a scope can't be called with a personal access token at all.
+///
+/// DO NOT CHANGE VALUES WITHOUT A MIGRATION STRATEGY.
+#[cfg_attr(test, derive(EnumIter, EnumCount))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonalAccessTokenScope {
+  /// Estimate costs and enqueue generations (spends wallet credits).
+  Generate,
+
+  /// Read the user's media files and job results.
+  ReadMedia,
+
+  /// Create, edit, and delete the user's characters.
+  ManageCharacters,
+
+  /// Read-only access to credits, wallets, and subscriptions.
+  BillingRead,
+}
+
+impl_enum_display_and_debug_using_to_str!(PersonalAccessTokenScope);
+impl_mysql_enum_coders!(PersonalAccessTokenScope);
+impl_mysql_from_row!(PersonalAccessTokenScope);
+
+/// NB: Legacy API for older code.
+impl PersonalAccessTokenScope {
+  pub fn to_str(&self) -> &'static str {
+    match self {
+      Self::Generate => "generate",
+      Self::ReadMedia => "read_media",
+      Self::ManageCharacters => "manage_characters",
+      Self::BillingRead => "billing_read",
+    }
+  }
+
+  pub fn from_str(value: &str) -> Result<Self, String> {
+    match value {
+      "generate" => Ok(Self::Generate),
+      "read_media" => Ok(Self::ReadMedia),
+      "manage_characters" => Ok(Self::ManageCharacters),
+      "billing_read" => Ok(Self::BillingRead),
+      _ => Err(format!("invalid value: {:?}", value)),
+    }
+  }
+
+  pub fn all_variants() -> BTreeSet<Self> {
+    // NB: BTreeSet is sorted
+    // NB: BTreeSet::from() isn't const, but not worth using LazyStatic, etc.
+    BTreeSet::from([
+      Self::Generate,
+      Self::ReadMedia,
+      Self::ManageCharacters,
+      Self::BillingRead,
+    ])
+  }
+
+  /// Parse the comma separated database representation. Unknown values are dropped.
+  pub fn set_from_str(value: &str) -> BTreeSet<Self> {
+    value.split(',')
+        .map(|scope| scope.trim())
+        .filter_map(|scope| Self::from_str(scope).ok())
+        .collect()
+  }
+
+  /// The comma separated database representation.
+  pub fn set_to_string(scopes: &BTreeSet<Self>) -> String {
+    scopes.iter()
+        .map(|scope| scope.to_str())
+        .collect::<Vec<&str>>()
+        .join(",")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+  use crate::test_helpers::assert_serialization;
+
+  mod explicit_checks {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+      assert_serialization(PersonalAccessTokenScope::Generate, "generate");
+      assert_serialization(PersonalAccessTokenScope::ReadMedia, "read_media");
+      assert_serialization(PersonalAccessTokenScope::ManageCharacters, "manage_characters");
+      assert_serialization(PersonalAccessTokenScope::BillingRead, "billing_read");
+    }
+
+    #[test]
+    fn test_to_str() {
+      assert_eq!(PersonalAccessTokenScope::Generate.to_str(), "generate");
+      assert_eq!(PersonalAccessTokenScope::ReadMedia.to_str(), "read_media");
+      assert_eq!(PersonalAccessTokenScope::ManageCharacters.to_str(), "manage_characters");
+      assert_eq!(PersonalAccessTokenScope::BillingRead.to_str(), "billing_read");
+    }
+
+    #[test]
+    fn test_from_str() {
+      assert_eq!(PersonalAccessTokenScope::from_str("generate").unwrap(), PersonalAccessTokenScope::Generate);
+      assert_eq!(PersonalAccessTokenScope::from_str("read_media").unwrap(), PersonalAccessTokenScope::ReadMedia);
+      assert_eq!(PersonalAccessTokenScope::from_str("manage_characters").unwrap(), PersonalAccessTokenScope::ManageCharacters);
+      assert_eq!(PersonalAccessTokenScope::from_str("billing_read").unwrap(), PersonalAccessTokenScope::BillingRead);
+      assert!(PersonalAccessTokenScope::from_str("foo").is_err());
+    }
+
+    #[test]
+    fn all_variants() {
+      let mut variants = PersonalAccessTokenScope::all_variants();
+      assert_eq!(variants.len(), 4);
+      assert_eq!(variants.pop_first(), Some(PersonalAccessTokenScope::Generate));
+      assert_eq!(variants.pop_first(), Some(PersonalAccessTokenScope::ReadMedia));
+      assert_eq!(variants.pop_first(), Some(PersonalAccessTokenScope::ManageCharacters));
+      assert_eq!(variants.pop_first(), Some(PersonalAccessTokenScope::BillingRead));
+      assert_eq!(variants.pop_first(), None);
+    }
+
+    #[test]
+    fn test_set_round_trip() {
+      let scopes = PersonalAccessTokenScope::set_from_str("billing_read, generate,bogus");
+      assert_eq!(scopes.len(), 2);
+      assert!(scopes.contains(&PersonalAccessTokenScope::Generate));
+      assert!(scopes.contains(&PersonalAccessTokenScope::BillingRead));
+      assert_eq!(PersonalAccessTokenScope::set_to_string(&scopes), "generate,billing_read");
+      assert!(PersonalAccessTokenScope::set_from_str("").is_empty());
+    }
+  }
+
+  mod mechanical_checks {
+    use super::*;
+
+    #[test]
+    fn variant_length() {
+      use strum::IntoEnumIterator;
+      assert_eq!(PersonalAccessTokenScope::all_variants().len(), PersonalAccessTokenScope::iter().len());
+    }
+
+    #[test]
+    fn round_trip() {
+      for variant in PersonalAccessTokenScope::all_variants() {
+        assert_eq!(variant, PersonalAccessTokenScope::from_str(variant.to_str()).unwrap());
+        assert_eq!(variant, PersonalAccessTokenScope::from_str(&format!("{}", variant)).unwrap());
+        assert_eq!(variant, PersonalAccessTokenScope::from_str(&format!("{:?}", variant)).unwrap());
+      }
+    }
+
+    #[test]
+    fn serialized_length_ok_for_database() {
+      const MAX_LENGTH : usize = 32;
+      for variant in PersonalAccessTokenScope::all_variants() {
+        let serialized = variant.to_str();
+        assert!(serialized.len() > 0, "variant {:?} is too short", variant);
+        assert!(serialized.len() <= MAX_LENGTH, "variant {:?} is too long", variant);
+      }
+    }
+  }
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+#[cfg(test)]
+use strum::EnumCount;
+#[cfg(test)]
+use strum::EnumIter;
+use utoipa::ToSchema;
+
+/// Used in the `user_rate_limit_overrides` table in a `VARCHAR(16)` field.
+///
+/// Rate limit policies are declared per endpoint group rather than per endpoint, so that
+/// every way of (eg.) uploading a file counts against the same budget.
+///
+/// DO NOT CHANGE VALUES WITHOUT A MIGRATION STRATEGY.
+#[cfg_attr(test, derive(EnumIter, EnumCount))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitEndpointGroup {
+  /// Enqueueing image, video, and other generations.
+  Generation,
+
+  /// Media file uploads.
+  Uploads,
+
+  /// Login and other credential checks.
+  Auth,
+
+  /// Checking two factor codes (per user, since the password was already checked).
+  TwoFactor,
+
+  /// Sending email verification messages.
+  #[serde(rename = "email_verify")]
+  EmailVerification,
+}
+
+impl_enum_display_and_debug_using_to_str!(RateLimitEndpointGroup);
+impl_mysql_enum_coders!(RateLimitEndpointGroup);
+impl_mysql_from_row!(RateLimitEndpointGroup);
+
+/// NB: Legacy API for older code.
+impl RateLimitEndpointGroup {
+  pub fn to_str(&self) -> &'static str {
+    match self {
+      Self::Generation => "generation",
+      Self::Uploads => "uploads",
+      Self::Auth => "auth",
+      Self::TwoFactor => "two_factor",
+      Self::EmailVerification => "email_verify",
+    }
+  }
+
+  pub fn from_str(value: &str) -> Result<Self, String> {
+    match value {
+      "generation" => Ok(Self::Generation),
+      "uploads" => Ok(Self::Uploads),
+      "auth" => Ok(Self::Auth),
+      "two_factor" => Ok(Self::TwoFactor),
+      "email_verify" => Ok(Self::EmailVerification),
+      _ => Err(format!("invalid value: {:?}", value)),
+    }
+  }
+
+  pub fn all_variants() -> BTreeSet<Self> {
+    // NB: BTreeSet is sorted
+    // NB: BTreeSet::from() isn't const, but not worth using LazyStatic, etc.
+    BTreeSet::from([
+      Self::Generation,
+      Self::Uploads,
+      Self::Auth,
+      Self::TwoFactor,
+      Self::EmailVerification,
+    ])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+  use crate::test_helpers::assert_serialization;
+
+  mod explicit_checks {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+      assert_serialization(RateLimitEndpointGroup::Generation, "generation");
+      assert_serialization(RateLimitEndpointGroup::Uploads, "uploads");
+      assert_serialization(RateLimitEndpointGroup::Auth, "auth");
+      assert_serialization(RateLimitEndpointGroup::TwoFactor, "two_factor");
+      assert_serialization(RateLimitEndpointGroup::EmailVerification, "email_verify");
+    }
+
+    #[test]
+    fn test_to_str() {
+      assert_eq!(RateLimitEndpointGroup::Generation.to_str(), "generation");
+      assert_eq!(RateLimitEndpointGroup::Uploads.to_str(), "uploads");
+      assert_eq!(RateLimitEndpointGroup::Auth.to_str(), "auth");
+      assert_eq!(RateLimitEndpointGroup::TwoFactor.to_str(), "two_factor");
+      assert_eq!(RateLimitEndpointGroup::EmailVerification.to_str(), "email_verify");
+    }
+
+    #[test]
+    fn test_from_str() {
+      assert_eq!(RateLimitEndpointGroup::from_str("generation").unwrap(), RateLimitEndpointGroup::Generation);
+      assert_eq!(RateLimitEndpointGroup::from_str("uploads").unwrap(), RateLimitEndpointGroup::Uploads);
+      assert_eq!(RateLimitEndpointGroup::from_str("auth").unwrap(), RateLimitEndpointGroup::Auth);
+      assert_eq!(RateLimitEndpointGroup::from_str("two_factor").unwrap(), RateLimitEndpointGroup::TwoFactor);
+      assert_eq!(RateLimitEndpointGroup::from_str("email_verify").unwrap(), RateLimitEndpointGroup::EmailVerification);
+      assert!(RateLimitEndpointGroup::from_str("foo").is_err());
+    }
+
+    #[test]
+    fn all_variants() {
+      let mut variants = RateLimitEndpointGroup::all_variants();
+      assert_eq!(variants.len(), 5);
+      assert_eq!(variants.pop_first(), Some(RateLimitEndpointGroup::Generation));
+      assert_eq!(variants.pop_first(), Some(RateLimitEndpointGroup::Uploads));
+      assert_eq!(variants.pop_first(), Some(RateLimitEndpointGroup::Auth));
+      assert_eq!(variants.pop_first(), Some(RateLimitEndpointGroup::TwoFactor));
+      assert_eq!(variants.pop_first(), Some(RateLimitEndpointGroup::EmailVerification));
+      assert_eq!(variants.pop_first(), None);
+    }
+  }
+
+  mod mechanical_checks {
+    use super::*;
+
+    #[test]
+    fn variant_length() {
+      use strum::IntoEnumIterator;
+      assert_eq!(RateLimitEndpointGroup::all_variants().len(), RateLimitEndpointGroup::iter().len());
+    }
+
+    #[test]
+    fn round_trip() {
+      for variant in RateLimitEndpointGroup::all_variants() {
+        assert_eq!(variant, RateLimitEndpointGroup::from_str(variant.to_str()).unwrap());
+        assert_eq!(variant, RateLimitEndpointGroup::from_str(&format!("{}", variant)).unwrap());
+        assert_eq!(variant, RateLimitEndpointGroup::from_str(&format!("{:?}", variant)).unwrap());
+      }
+    }
+
+    #[test]
+    fn serialized_length_ok_for_database() {
+      const MAX_LENGTH : usize = 16;
+      for variant in RateLimitEndpointGroup::all_variants() {
+        let serialized = variant.to_str();
+        assert!(serialized.len() > 0, "variant {:?} is too short", variant);
+        assert!(serialized.len() <= MAX_LENGTH, "variant {:?} is too long", variant);
+      }
+    }
+  }
+}
@@ -37,9 +37,7 @@ pub enum UserFeatureFlag {
   #[serde(rename = "hh")]
   HappyHorse,
 
-  /// Access to Happy Horse (with a rate limit)
-  #[serde(rename = "hh_rl")]
-  HappyHorseRateLimit,
+  // NB: "hh_rl" (Happy Horse with a rate limit) was retired in favor of the rate limit policy engine.
 
   /// Access to the referrals program
   #[serde(rename = "referrals")]
@@ -61,7 +59,6 @@ impl UserFeatureFlag {
       Self::VideoStyleTransfer => "video_style_transfer",
       Self::SeedanceWhitelist => "sd_wl",
       Self::HappyHorse => "hh",
-      Self::HappyHorseRateLimit => "hh_rl",
       Self::ReferralsProgram => "referrals",
     }
   }
@@ -74,7 +71,6 @@ impl UserFeatureFlag {
       "video_style_transfer" => Ok(Self::VideoStyleTransfer),
       "sd_wl" => Ok(Self::SeedanceWhitelist),
       "hh" => Ok(Self::HappyHorse),
-      "hh_rl" => Ok(Self::HappyHorseRateLimit),
       "referrals" => Ok(Self::ReferralsProgram),
       _ => Err(format!("invalid value: {:?}", value)),
     }
@@ -87,8 +83,7 @@ impl UserFeatureFlag {
       Self::Upload3d => "Upload 3D",
       Self::VideoStyleTransfer => "Video Style Transfer",
       Self::SeedanceWhitelist => "DO NOT USE: Seedance Whitelist (legacy)",
-      Self::HappyHorse => "Happy Horse (no Rate Limit)",
-      Self::HappyHorseRateLimit => "Happy Horse (with Rate Limit)",
+      Self::HappyHorse => "Happy Horse",
       Self::ReferralsProgram => "Referrals Program",
     }
   }
@@ -100,8 +95,7 @@ impl UserFeatureFlag {
       Self::Upload3d => "Whether users are allowed to upload 3D models",
       Self::VideoStyleTransfer => "Access to video style transfer on Storyteller",
       Self::SeedanceWhitelist => "DO NOT USE THIS. IT WILL BREAK ACCOUNTS.",
-      Self::HappyHorse => "Access to Happy Horse (without a rate limit - dangerous)",
-      Self::HappyHorseRateLimit => "Access to Happy Horse (with a rate limit)",
+      Self::HappyHorse => "Access to Happy Horse (generation rate limits still apply)",
       Self::ReferralsProgram => "Access to the referrals program",
     }
   }
@@ -116,7 +110,6 @@ impl UserFeatureFlag {
       Self::VideoStyleTransfer,
       Self::SeedanceWhitelist,
       Self::HappyHorse,
-      Self::HappyHorseRateLimit,
       Self::ReferralsProgram,
     ])
   }
@@ -138,7 +131,6 @@ mod tests {
       assert_serialization(UserFeatureFlag::VideoStyleTransfer, "video_style_transfer");
       assert_serialization(UserFeatureFlag::SeedanceWhitelist, "sd_wl");
       assert_serialization(UserFeatureFlag::HappyHorse, "hh");
-      assert_serialization(UserFeatureFlag::HappyHorseRateLimit, "hh_rl");
       assert_serialization(UserFeatureFlag::ReferralsProgram, "referrals");
     }
 
@@ -150,7 +142,6 @@ mod tests {
       assert_eq!(UserFeatureFlag::VideoStyleTransfer.to_str(), "video_style_transfer");
       assert_eq!(UserFeatureFlag::SeedanceWhitelist.to_str(), "sd_wl");
       assert_eq!(UserFeatureFlag::HappyHorse.to_str(), "hh");
-      assert_eq!(UserFeatureFlag::HappyHorseRateLimit.to_str(), "hh_rl");
       assert_eq!(UserFeatureFlag::ReferralsProgram.to_str(), "referrals");
     }
 
@@ -162,7 +153,6 @@ mod tests {
       assert_eq!(UserFeatureFlag::from_str("video_style_transfer").unwrap(), UserFeatureFlag::VideoStyleTransfer);
       assert_eq!(UserFeatureFlag::from_str("sd_wl").unwrap(), UserFeatureFlag::SeedanceWhitelist);
       assert_eq!(UserFeatureFlag::from_str("hh").unwrap(), UserFeatureFlag::HappyHorse);
-      assert_eq!(UserFeatureFlag::from_str("hh_rl").unwrap(), UserFeatureFlag::HappyHorseRateLimit);
       assert_eq!(UserFeatureFlag::from_str("referrals").unwrap(), UserFeatureFlag::ReferralsProgram);
       assert!(UserFeatureFlag::from_str("foo").is_err());
     }
@@ -170,14 +160,13 @@ mod tests {
     #[test]
     fn all_variants() {
       let mut variants = UserFeatureFlag::all_variants();
-      assert_eq!(variants.len(), 8);
+      assert_eq!(variants.len(), 7);
       assert_eq!(variants.pop_first(), Some(UserFeatureFlag::ExploreMedia));
       assert_eq!(variants.pop_first(), Some(UserFeatureFlag::Studio));
       assert_eq!(variants.pop_first(), Some(UserFeatureFlag::Upload3d));
       assert_eq!(variants.pop_first(), Some(UserFeatureFlag::VideoStyleTransfer));
       assert_eq!(variants.pop_first(), Some(UserFeatureFlag::SeedanceWhitelist));
       assert_eq!(variants.pop_first(), Some(UserFeatureFlag::HappyHorse));
-      assert_eq!(variants.pop_first(), Some(UserFeatureFlag::HappyHorseRateLimit));
       assert_eq!(variants.pop_first(), Some(UserFeatureFlag::ReferralsProgram));
       assert_eq!(variants.pop_first(), None);
     }
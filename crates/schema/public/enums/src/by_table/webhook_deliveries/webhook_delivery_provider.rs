@@ -0,0 +1,115 @@
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+use strum::EnumCount;
+#[cfg(test)]
+use strum::EnumIter;
+
+/// Used in the `webhook_deliveries` table in `VARCHAR(16)` field `provider`.
+///
+/// The third party that sent the webhook.
+///
+/// YOU CAN ADD NEW VALUES, BUT DO NOT CHANGE EXISTING VALUES WITHOUT A MIGRATION STRATEGY.
+#[cfg_attr(test, derive(EnumIter, EnumCount))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize, Serialize)]
+pub enum WebhookDeliveryProvider {
+  /// Beeble SwitchX
+  #[serde(rename = "beeble")]
+  Beeble,
+
+  /// FAL
+  #[serde(rename = "fal")]
+  Fal,
+}
+
+impl_enum_display_and_debug_using_to_str!(WebhookDeliveryProvider);
+impl_mysql_enum_coders!(WebhookDeliveryProvider);
+impl_mysql_from_row!(WebhookDeliveryProvider);
+
+impl WebhookDeliveryProvider {
+  pub fn to_str(&self) -> &'static str {
+    match self {
+      Self::Beeble => "beeble",
+      Self::Fal => "fal",
+    }
+  }
+
+  pub fn from_str(value: &str) -> Result<Self, String> {
+    match value {
+      "beeble" => Ok(Self::Beeble),
+      "fal" => Ok(Self::Fal),
+      _ => Err(format!("invalid WebhookDeliveryProvider value: {:?}", value)),
+    }
+  }
+
+  pub fn all_variants() -> BTreeSet<Self> {
+    BTreeSet::from([
+      Self::Beeble,
+      Self::Fal,
+    ])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::by_table::webhook_deliveries::webhook_delivery_provider::WebhookDeliveryProvider;
+  use crate::test_helpers::assert_serialization;
+
+  mod explicit_checks {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+      assert_serialization(WebhookDeliveryProvider::Beeble, "beeble");
+      assert_serialization(WebhookDeliveryProvider::Fal, "fal");
+    }
+
+    #[test]
+    fn to_str() {
+      assert_eq!(WebhookDeliveryProvider::Beeble.to_str(), "beeble");
+      assert_eq!(WebhookDeliveryProvider::Fal.to_str(), "fal");
+    }
+
+    #[test]
+    fn from_str() {
+      assert_eq!(WebhookDeliveryProvider::from_str("beeble").unwrap(), WebhookDeliveryProvider::Beeble);
+      assert_eq!(WebhookDeliveryProvider::from_str("fal").unwrap(), WebhookDeliveryProvider::Fal);
+      assert!(WebhookDeliveryProvider::from_str("invalid").is_err());
+    }
+
+    #[test]
+    fn all_variants() {
+      const EXPECTED_COUNT: usize = 2;
+      assert_eq!(WebhookDeliveryProvider::all_variants().len(), EXPECTED_COUNT);
+    }
+  }
+
+  mod mechanical_checks {
+    use super::*;
+
+    #[test]
+    fn variant_length() {
+      use strum::IntoEnumIterator;
+      assert_eq!(WebhookDeliveryProvider::all_variants().len(), WebhookDeliveryProvider::iter().len());
+    }
+
+    #[test]
+    fn round_trip() {
+      for variant in WebhookDeliveryProvider::all_variants() {
+        assert_eq!(variant, WebhookDeliveryProvider::from_str(variant.to_str()).unwrap());
+        assert_eq!(variant, WebhookDeliveryProvider::from_str(&format!("{}", variant)).unwrap());
+        assert_eq!(variant, WebhookDeliveryProvider::from_str(&format!("{:?}", variant)).unwrap());
+      }
+    }
+
+    #[test]
+    fn serialized_length_ok_for_database() {
+      const MAX_LENGTH: usize = 16;
+      for variant in WebhookDeliveryProvider::all_variants() {
+        let serialized = variant.to_str();
+        assert!(!serialized.is_empty(), "variant {:?} is too short", variant);
+        assert!(serialized.len() <= MAX_LENGTH, "variant {:?} is too long for VARCHAR({})", variant, MAX_LENGTH);
+      }
+    }
+  }
+}
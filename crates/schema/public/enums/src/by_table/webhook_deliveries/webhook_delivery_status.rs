@@ -0,0 +1,125 @@
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+use strum::EnumCount;
+#[cfg(test)]
+use strum::EnumIter;
+
+/// Used in the `webhook_deliveries` table in `VARCHAR(16)` field `status`.
+///
+/// How far processing of a webhook delivery got.
+///
+/// YOU CAN ADD NEW VALUES, BUT DO NOT CHANGE EXISTING VALUES WITHOUT A MIGRATION STRATEGY.
+#[cfg_attr(test, derive(EnumIter, EnumCount))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize, Serialize)]
+pub enum WebhookDeliveryStatus {
+  /// The delivery was recorded and is being processed (or processing crashed).
+  #[serde(rename = "processing")]
+  Processing,
+
+  /// The delivery was processed successfully.
+  #[serde(rename = "succeeded")]
+  Succeeded,
+
+  /// Processing returned an error. The delivery can be re-driven by staff.
+  #[serde(rename = "failed")]
+  Failed,
+}
+
+impl_enum_display_and_debug_using_to_str!(WebhookDeliveryStatus);
+impl_mysql_enum_coders!(WebhookDeliveryStatus);
+impl_mysql_from_row!(WebhookDeliveryStatus);
+
+impl WebhookDeliveryStatus {
+  pub fn to_str(&self) -> &'static str {
+    match self {
+      Self::Processing => "processing",
+      Self::Succeeded => "succeeded",
+      Self::Failed => "failed",
+    }
+  }
+
+  pub fn from_str(value: &str) -> Result<Self, String> {
+    match value {
+      "processing" => Ok(Self::Processing),
+      "succeeded" => Ok(Self::Succeeded),
+      "failed" => Ok(Self::Failed),
+      _ => Err(format!("invalid WebhookDeliveryStatus value: {:?}", value)),
+    }
+  }
+
+  pub fn all_variants() -> BTreeSet<Self> {
+    BTreeSet::from([
+      Self::Processing,
+      Self::Succeeded,
+      Self::Failed,
+    ])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+  use crate::test_helpers::assert_serialization;
+
+  mod explicit_checks {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+      assert_serialization(WebhookDeliveryStatus::Processing, "processing");
+      assert_serialization(WebhookDeliveryStatus::Succeeded, "succeeded");
+      assert_serialization(WebhookDeliveryStatus::Failed, "failed");
+    }
+
+    #[test]
+    fn to_str() {
+      assert_eq!(WebhookDeliveryStatus::Processing.to_str(), "processing");
+      assert_eq!(WebhookDeliveryStatus::Succeeded.to_str(), "succeeded");
+      assert_eq!(WebhookDeliveryStatus::Failed.to_str(), "failed");
+    }
+
+    #[test]
+    fn from_str() {
+      assert_eq!(WebhookDeliveryStatus::from_str("processing").unwrap(), WebhookDeliveryStatus::Processing);
+      assert_eq!(WebhookDeliveryStatus::from_str("succeeded").unwrap(), WebhookDeliveryStatus::Succeeded);
+      assert_eq!(WebhookDeliveryStatus::from_str("failed").unwrap(), WebhookDeliveryStatus::Failed);
+      assert!(WebhookDeliveryStatus::from_str("invalid").is_err());
+    }
+
+    #[test]
+    fn all_variants() {
+      const EXPECTED_COUNT: usize = 3;
+      assert_eq!(WebhookDeliveryStatus::all_variants().len(), EXPECTED_COUNT);
+    }
+  }
+
+  mod mechanical_checks {
+    use super::*;
+
+    #[test]
+    fn variant_length() {
+      use strum::IntoEnumIterator;
+      assert_eq!(WebhookDeliveryStatus::all_variants().len(), WebhookDeliveryStatus::iter().len());
+    }
+
+    #[test]
+    fn round_trip() {
+      for variant in WebhookDeliveryStatus::all_variants() {
+        assert_eq!(variant, WebhookDeliveryStatus::from_str(variant.to_str()).unwrap());
+        assert_eq!(variant, WebhookDeliveryStatus::from_str(&format!("{}", variant)).unwrap());
+        assert_eq!(variant, WebhookDeliveryStatus::from_str(&format!("{:?}", variant)).unwrap());
+      }
+    }
+
+    #[test]
+    fn serialized_length_ok_for_database() {
+      const MAX_LENGTH: usize = 16;
+      for variant in WebhookDeliveryStatus::all_variants() {
+        let serialized = variant.to_str();
+        assert!(!serialized.is_empty(), "variant {:?} is too short", variant);
+        assert!(serialized.len() <= MAX_LENGTH, "variant {:?} is too long for VARCHAR({})", variant, MAX_LENGTH);
+      }
+    }
+  }
+}
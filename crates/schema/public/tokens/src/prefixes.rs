@@ -40,6 +40,7 @@ pub(crate) enum TokenPrefix {
   ModelWeight,
   NewsStory, // NB: aichatbot / sqlite
   PasswordReset,
+  PersonalAccessToken,
   Prompt,
   StaffAuditLog,
   Tag,
@@ -54,6 +55,7 @@ pub(crate) enum TokenPrefix {
   VoiceConversionResult,
   Wallet,
   WalletLedgerEntry,
+  WebhookDelivery,
   ZsVoice,
   ZsVoiceDataset,
   ZsVoiceDatasetSample,
@@ -63,7 +65,9 @@ pub(crate) enum TokenPrefix {
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(test, derive(EnumIter, EnumCount))]
 pub(crate) enum TauriTokenPrefix {
+  GenerationHistory,
   Task,
+  TaskBatch,
 }
 
 /// These are old-style prefixes that end in colon (:).
@@ -128,6 +132,7 @@ impl PrefixGenerator for TokenPrefix {
       Self::ModelWeight => "weight_",
       Self::NewsStory => "news_story_",
       Self::PasswordReset => "pw_reset_",
+      Self::PersonalAccessToken => "pat_",
       Self::Prompt => "prompt_",
       Self::StaffAuditLog => "stfaud_",
       Self::Tag => "tag_",
@@ -142,6 +147,7 @@ impl PrefixGenerator for TokenPrefix {
       Self::VoiceConversionResult => "vcr_",
       Self::Wallet => "wallet_",
       Self::WalletLedgerEntry => "wle_",
+      Self::WebhookDelivery => "whd_",
       Self::ZsVoice => "zsv_",
       Self::ZsVoiceDataset => "zsd_",
       Self::ZsVoiceDatasetSample => "zss_",
@@ -152,7 +158,9 @@ impl PrefixGenerator for TokenPrefix {
 impl PrefixGenerator for TauriTokenPrefix {
   fn prefix(self) -> &'static str {
     match self {
+      TauriTokenPrefix::GenerationHistory => "gen_hist_",
       TauriTokenPrefix::Task => "task_",
+      TauriTokenPrefix::TaskBatch => "task_batch_",
     }
   }
 }
@@ -18,6 +18,7 @@ pub mod media_uploads;
 pub mod model_categories;
 pub mod model_weights;
 pub mod password_reset;
+pub mod personal_access_tokens;
 pub mod prompts;
 pub mod sqlite;
 pub mod staff_audit_logs;
@@ -45,6 +46,7 @@ pub mod w2l_template_upload_jobs;
 pub mod w2l_templates;
 pub mod wallet_ledger_entries;
 pub mod wallets;
+pub mod webhook_deliveries;
 pub mod zs_voice_dataset_samples;
 pub mod zs_voice_datasets;
 pub mod zs_voices;
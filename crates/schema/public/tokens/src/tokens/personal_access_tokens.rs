@@ -0,0 +1,18 @@
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::prefixes::TokenPrefix;
+
+/// The primary key for Personal Access Tokens.
+/// This identifies the token (eg. to revoke it); it is not the secret sent as a bearer token.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "database", derive(sqlx::Type))]
+#[cfg_attr(feature = "database", sqlx(transparent))]
+pub struct PersonalAccessTokenToken(pub String);
+
+impl_string_token!(PersonalAccessTokenToken);
+impl_mysql_token_from_row!(PersonalAccessTokenToken);
+impl_crockford_generator!(PersonalAccessTokenToken, 32usize, TokenPrefix::PersonalAccessToken, CrockfordLower);
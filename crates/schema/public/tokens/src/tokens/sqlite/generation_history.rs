@@ -0,0 +1,15 @@
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::prefixes::TauriTokenPrefix;
+
+/// The primary key for the local generation history (Tauri / Sqlite)
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "database", derive(sqlx::Type))]
+#[cfg_attr(feature = "database", sqlx(transparent))]
+pub struct GenerationHistoryId(pub String);
+
+impl_string_token!(GenerationHistoryId);
+impl_crockford_generator!(GenerationHistoryId, 32usize, TauriTokenPrefix::GenerationHistory, CrockfordMixed);
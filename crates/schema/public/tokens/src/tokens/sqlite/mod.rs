@@ -1,3 +1,5 @@
+pub mod generation_history;
 pub mod news_stories;
+pub mod task_batches;
 pub mod tasks;
 pub mod tts_render_tasks;
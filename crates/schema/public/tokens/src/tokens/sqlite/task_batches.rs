@@ -0,0 +1,15 @@
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::prefixes::TauriTokenPrefix;
+
+/// Groups the tasks enqueued together by a batch generation (Tauri / Sqlite)
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "database", derive(sqlx::Type))]
+#[cfg_attr(feature = "database", sqlx(transparent))]
+pub struct TaskBatchId(pub String);
+
+impl_string_token!(TaskBatchId);
+impl_crockford_generator!(TaskBatchId, 32usize, TauriTokenPrefix::TaskBatch, CrockfordMixed);
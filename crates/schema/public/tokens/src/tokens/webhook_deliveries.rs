@@ -0,0 +1,17 @@
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::prefixes::TokenPrefix;
+
+/// The primary key for Webhook Deliveries
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "database", derive(sqlx::Type))]
+#[cfg_attr(feature = "database", sqlx(transparent))]
+pub struct WebhookDeliveryToken(pub String);
+
+impl_string_token!(WebhookDeliveryToken);
+impl_mysql_token_from_row!(WebhookDeliveryToken);
+impl_crockford_generator!(WebhookDeliveryToken, 32usize, TokenPrefix::WebhookDelivery, CrockfordLower);
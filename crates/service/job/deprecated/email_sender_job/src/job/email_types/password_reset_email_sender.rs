@@ -1,7 +1,8 @@
 use anyhow::anyhow;
 
-use email_sender::letter_exports;
-use email_sender::letter_exports::Message;
+use mailer::templates::email_branding::EmailBranding;
+use mailer::templates::email_template::EmailTemplate;
+use mailer::templates::messages::password_reset_email::PasswordResetEmail;
 use mysql_queries::payloads::email_sender_jobs::email_sender_job_args::PolymorphicEmailSenderJobArgs;
 use mysql_queries::queries::email_sender_jobs::list_available_email_sender_jobs::AvailableEmailSenderJob;
 use server_environment::ServerEnvironment;
@@ -43,53 +44,27 @@ pub async fn password_reset_email_sender(job: &AvailableEmailSenderJob, job_depe
     None => {}
   }
 
-  let from_address = "Support <support@storyteller.ai>"
-      .parse()
-      .map_err(|err| {
-        log::error!("Error parsing from address: {err}");
-        ProcessSingleJobError::InvalidJob(anyhow!("Error parsing from address: {err}"))
-      })?;
-
-  let to_address = job.destination_email_address
-      .parse()
-      .map_err(|err| {
-        log::error!("Error parsing to address: {err}");
-        ProcessSingleJobError::InvalidJob(anyhow!("Error parsing to address: {err}"))
-      })?;
-
   // TODO(bt,2023-11-12): Environmentally configure, allow overrides.
   let link = match job_dependencies.server_environment {
     ServerEnvironment::Development => format!("http://dev.fakeyou.com:7000/password-reset/verify?token={secret_key}"),
     ServerEnvironment::Production => format!("https://fakeyou.com/password-reset/verify?token={secret_key}"),
   };
 
-  let message = format!(r#"
-      <a href="{link}">Click here to reset your password!</a>
-      <br />
-      <br />
-      If you can't click the link, here's the secret reset code: {secret_key}
-      <br />
-      <br />
-      Thank You,
-      <br />
-      <br />
-      Storyteller.ai (FakeYou) Team
-    "#);
+  let template = PasswordResetEmail {
+    reset_url: link,
+    reset_code: secret_key,
+  };
 
-  let email = Message::builder()
-      .from(from_address)
-      .to(to_address)
-      .subject("FakeYou Password Reset")
-      .header(letter_exports::ContentType::TEXT_HTML)
-      .body(message)
-      .map_err(|err| {
-        log::error!("Error constructing email: {err}");
-        ProcessSingleJobError::InvalidJob(anyhow!("Error constructing email: {err}"))
-      })?;
+  let email = template.render(EmailBranding::FakeYou, &job.destination_email_address);
 
-  job_dependencies.email_sender.send_message(&email).map_err(|err| {
+  // NB: Not using the outbound queue; failed jobs are retried by the job loop.
+  job_dependencies.mailer.send(&email).await.map_err(|err| {
     log::error!("Error sending email: {err}");
-    ProcessSingleJobError::Other(anyhow!("Error sending email: {err}"))
+    if err.is_retryable() {
+      ProcessSingleJobError::Other(anyhow!("Error sending email: {err}"))
+    } else {
+      ProcessSingleJobError::InvalidJob(anyhow!("Error sending email: {err}"))
+    }
   })?;
 
   Ok(())
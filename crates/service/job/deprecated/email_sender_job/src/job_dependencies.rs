@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use r2d2_redis::r2d2;
 use r2d2_redis::RedisConnectionManager;
@@ -6,9 +7,9 @@ use sqlx::MySqlPool;
 
 use bootstrap::bootstrap::ContainerEnvironment;
 use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
-use email_sender::smtp_email_sender::SmtpEmailSender;
 use jobs_common::job_progress_reporter::job_progress_reporter::JobProgressReporterBuilder;
 use jobs_common::job_stats::JobStats;
+use mailer::transport::mailer::Mailer;
 use mysql_queries::common_inputs::container_environment_arg::ContainerEnvironmentArg;
 use server_environment::ServerEnvironment;
 
@@ -25,7 +26,7 @@ pub struct JobDependencies {
   //  connect to production Redis easily (requires lots of setup - ghosttunnel or something + IP rules)
   pub maybe_redis_pool: Option<r2d2::Pool<RedisConnectionManager>>,
 
-  pub email_sender: SmtpEmailSender,
+  pub mailer: Arc<dyn Mailer>,
 
   pub server_environment: ServerEnvironment,
 
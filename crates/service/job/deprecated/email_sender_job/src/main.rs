@@ -29,12 +29,12 @@ use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
 use config::common_env::CommonEnv;
 use shared_env_var_config::logging::DEFAULT_RUST_LOG;
 use shared_env_var_config::mysql::env_get_mysql_connection_string_or_default;
-use email_sender::smtp_email_sender::SmtpEmailSender;
 use errors::AnyhowResult;
 use jobs_common::job_progress_reporter::job_progress_reporter::JobProgressReporterBuilder;
 use jobs_common::job_progress_reporter::noop_job_progress_reporter::NoOpJobProgressReporterBuilder;
 use jobs_common::job_progress_reporter::redis_job_progress_reporter::RedisJobProgressReporterBuilder;
 use jobs_common::job_stats::JobStats;
+use mailer::transport::mailer_backend::MailerBackend;
 use mysql_queries::common_inputs::container_environment_arg::ContainerEnvironmentArg;
 use server_environment::ServerEnvironment;
 
@@ -81,11 +81,7 @@ async fn main() -> AnyhowResult<()> {
   let server_environment = ServerEnvironment::from_str(&easyenv::get_env_string_required("SERVER_ENVIRONMENT")?)
       .ok_or(anyhow!("invalid server environment"))?;
 
-  let email_sender = SmtpEmailSender::new(
-    &easyenv::get_env_string_required("SMTP_RELAY")?,
-    easyenv::get_env_string_required("SMTP_USERNAME")?,
-    easyenv::get_env_string_required("SMTP_PASSWORD")?,
-  )?;
+  let mailer = MailerBackend::from_env_or_default("smtp")?.build()?;
 
   let is_debug_worker = easyenv::get_env_bool_or_default("IS_DEBUG_WORKER", false);
 
@@ -143,7 +139,7 @@ async fn main() -> AnyhowResult<()> {
       is_debug_worker,
     },
     server_environment,
-    email_sender,
+    mailer,
     job_batch_wait_millis: common_env.job_batch_wait_millis,
     job_max_attempts: common_env.job_max_attempts as u16,
     job_batch_size: common_env.job_batch_size,
@@ -4,6 +4,8 @@ use std::time::Duration;
 use chrono::{DateTime, Utc};
 use log::{error, info};
 
+use elasticsearch_schema::documents::model_weight_document::MODEL_WEIGHT_VERSIONED_INDEX;
+use elasticsearch_schema::utils::bulk_index_documents::get_write_indices;
 use errors::AnyhowResult;
 use mysql_queries::queries::model_weights::batch_get::batch_get_model_weights_for_elastic_search_backfill::batch_get_model_weights_for_elastic_search_backfill;
 use mysql_queries::queries::model_weights::list::list_model_weight_tokens_updated_since::list_model_weight_tokens_updated_since;
@@ -50,6 +52,8 @@ pub async fn with_database_main_loop(updated_at_cursor: &mut DateTime<Utc>, job_
 
     info!("Found {} updated records", maybe_tokens.len());
 
+    let write_indices = get_write_indices(&job_state.elasticsearch, &MODEL_WEIGHT_VERSIONED_INDEX).await?;
+
     let mut last_observed_updated_at = *updated_at_cursor;
 
     while !maybe_tokens.is_empty() {
@@ -66,7 +70,7 @@ pub async fn with_database_main_loop(updated_at_cursor: &mut DateTime<Utc>, job_
       for record in records {
         let updated_at = record.updated_at.clone();
 
-        create_model_weight_document_from_record(&job_state.elasticsearch, record).await?;
+        create_model_weight_document_from_record(&job_state.elasticsearch, &write_indices, record).await?;
 
         // NB: We don't want to advance the cursor to the current second just yet, because we might be processing
         // several records with the exact same "updated_at" timestamp. We do know that due to ordering, we can update
@@ -4,6 +4,8 @@ use chrono::{DateTime, Utc};
 use elasticsearch::Elasticsearch;
 use sqlx::MySqlConnection;
 
+use elasticsearch_schema::documents::model_weight_document::MODEL_WEIGHT_VERSIONED_INDEX;
+use elasticsearch_schema::utils::bulk_index_documents::get_write_indices;
 use errors::AnyhowResult;
 use mysql_queries::queries::model_weights::batch_get::batch_get_model_weights_for_elastic_search_backfill::batch_get_model_weights_for_elastic_search_backfill;
 use tokens::tokens::model_weights::ModelWeightToken;
@@ -24,6 +26,8 @@ pub async fn copy_model_weight_records_to_documents(
 ) -> AnyhowResult<Option<Cursor>> {
   let mut last_updated_record = None;
 
+  let write_indices = get_write_indices(elasticsearch, &MODEL_WEIGHT_VERSIONED_INDEX).await?;
+
   while !tokens.is_empty() {
     // NB: This list might be very large if we query from (1) the epoch, or (2) there was a large series of updates
     let last = 50.min(tokens.len());
@@ -43,7 +47,7 @@ pub async fn copy_model_weight_records_to_documents(
         updated_at: record.updated_at.clone(),
       };
 
-      create_model_weight_document_from_record(elasticsearch, record).await?;
+      create_model_weight_document_from_record(elasticsearch, &write_indices, record).await?;
 
       let last_timestamp = last_updated_record.as_ref()
           .map(|cursor: &Cursor| cursor.updated_at)
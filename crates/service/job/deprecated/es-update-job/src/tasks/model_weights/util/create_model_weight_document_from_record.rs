@@ -1,10 +1,8 @@
-use anyhow::anyhow;
-use elasticsearch::{BulkOperation, BulkParts, Elasticsearch};
-use log::{error, info};
-use serde_json::Value;
+use elasticsearch::Elasticsearch;
+use log::info;
 
-use elasticsearch_schema::documents::model_weight_document::{MODEL_WEIGHT_INDEX, ModelWeightDocument};
-use elasticsearch_schema::traits::document::Document;
+use elasticsearch_schema::documents::model_weight_document::ModelWeightDocument;
+use elasticsearch_schema::utils::bulk_index_documents::bulk_index_documents;
 use enums::by_table::model_weights::weights_category::WeightsCategory;
 use errors::AnyhowResult;
 use mysql_queries::queries::model_weights::batch_get::batch_get_model_weights_for_elastic_search_backfill::ModelWeightForElasticsearchRecord;
@@ -12,6 +10,7 @@ use primitives::numerics::u64_to_i32_saturating::u64_to_i32_saturating;
 
 pub async fn create_model_weight_document_from_record(
   elasticsearch: &Elasticsearch,
+  write_indices: &[String],
   record: ModelWeightForElasticsearchRecord
 ) -> AnyhowResult<()> {
 
@@ -79,32 +78,5 @@ pub async fn create_model_weight_document_from_record(
     is_deleted,
   };
 
-  let op : BulkOperation<_> = BulkOperation::index(&document)
-      .id(document.get_document_id())
-      .into();
-
-  let response = elasticsearch
-      .bulk(BulkParts::Index(MODEL_WEIGHT_INDEX))
-      .body(vec![op])
-      .send()
-      .await?;
-
-  let json: Value = response.json().await?;
-
-  let had_errors = json["errors"].as_bool().unwrap_or(false);
-
-  if had_errors {
-    let failed: Vec<&Value> = json["items"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter(|v| !v["error"].is_null())
-        .collect();
-
-    error!("Errors during indexing. Failures: {}", failed.len());
-
-    return Err(anyhow!("Errors during indexing. Failures: {}", failed.len()));
-  }
-
-  Ok(())
+  bulk_index_documents(elasticsearch, write_indices, &[document]).await
 }
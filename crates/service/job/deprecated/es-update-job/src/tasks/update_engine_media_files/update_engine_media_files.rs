@@ -3,13 +3,11 @@ use std::iter::FromIterator;
 use std::sync::Arc;
 use std::time::Duration;
 
-use anyhow::anyhow;
-use elasticsearch::{BulkOperation, BulkParts, Elasticsearch};
+use elasticsearch::Elasticsearch;
 use log::{error, info};
-use serde_json::Value;
 
-use elasticsearch_schema::documents::media_file_document::{MEDIA_FILE_INDEX, MediaFileDocument};
-use elasticsearch_schema::traits::document::Document;
+use elasticsearch_schema::documents::media_file_document::{MEDIA_FILE_VERSIONED_INDEX, MediaFileDocument};
+use elasticsearch_schema::utils::bulk_index_documents::{bulk_index_documents, get_write_indices};
 use enums::by_table::media_files::media_file_class::MediaFileClass;
 use enums::by_table::media_files::media_file_engine_category::MediaFileEngineCategory;
 use enums::by_table::media_files::media_file_type::MediaFileType;
@@ -91,6 +89,9 @@ pub async fn with_database_main_loop(cursor: &mut usize, job_state: &JobState) -
 
     info!("Found {} updated records", results.len());
 
+    // NB: Resolved per query so that documents also land in an index that's mid-migration.
+    let write_indices = get_write_indices(&job_state.elasticsearch, &MEDIA_FILE_VERSIONED_INDEX).await?;
+
     while !results.is_empty() {
       let last = 50.min(results.len());
       let drained_results= results.drain(0..last)
@@ -100,7 +101,7 @@ pub async fn with_database_main_loop(cursor: &mut usize, job_state: &JobState) -
       for record in drained_results {
         let id = record.id;
 
-        create_document_from_record(&job_state.elasticsearch, record).await?;
+        create_document_from_record(&job_state.elasticsearch, &write_indices, record).await?;
 
         *cursor = id as usize;
 
@@ -116,7 +117,11 @@ pub async fn with_database_main_loop(cursor: &mut usize, job_state: &JobState) -
   }
 }
 
-async fn create_document_from_record(elasticsearch: &Elasticsearch, record: MediaFileForElasticsearchRecord) -> AnyhowResult<()> {
+async fn create_document_from_record(
+  elasticsearch: &Elasticsearch,
+  write_indices: &[String],
+  record: MediaFileForElasticsearchRecord,
+) -> AnyhowResult<()> {
   info!("Create record for {:?} - {:?}", record.token, record.maybe_title);
 
   let is_deleted = record.user_deleted_at.is_some() || record.mod_deleted_at.is_some();
@@ -164,32 +169,5 @@ async fn create_document_from_record(elasticsearch: &Elasticsearch, record: Medi
     is_deleted,
   };
 
-  let op : BulkOperation<_> = BulkOperation::index(&document)
-      .id(document.get_document_id())
-      .into();
-
-  let response = elasticsearch
-      .bulk(BulkParts::Index(MEDIA_FILE_INDEX))
-      .body(vec![op])
-      .send()
-      .await?;
-
-  let json: Value = response.json().await?;
-
-  let had_errors = json["errors"].as_bool().unwrap_or(false);
-
-  if had_errors {
-    let failed: Vec<&Value> = json["items"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter(|v| !v["error"].is_null())
-        .collect();
-
-    error!("Errors during indexing. Failures: {}", failed.len());
-
-    return Err(anyhow!("Errors during indexing. Failures: {}", failed.len()));
-  }
-
-  Ok(())
+  bulk_index_documents(elasticsearch, write_indices, &[document]).await
 }
@@ -0,0 +1,123 @@
+use std::ops::Deref;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::warn;
+
+use errors::AnyhowResult;
+use gmicloud_client::requests::poll_request_queue::poll_request_queue::poll_gmicloud_request;
+use job_runner::polling::polling_job::{JobFailure, PendingJob, PollStatus, PollingJob};
+use jobs_common::poll_backoff::ObservedJobState;
+use mysql_queries::queries::generic_inference::gmicloud::list_pending_gmicloud_jobs::{list_pending_gmicloud_jobs, PendingGmiCloudJob};
+
+use crate::job_dependencies::JobDependencies;
+use crate::process_job::process_failed_job::process_failed_job;
+use crate::process_job::process_successful_job::process_successful_job;
+
+/// A pending GmiCloud job, as seen by the runner.
+pub struct GmiCloudJob(pub PendingGmiCloudJob);
+
+impl Deref for GmiCloudJob {
+  type Target = PendingGmiCloudJob;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl PendingJob for GmiCloudJob {
+  fn job_token(&self) -> &str {
+    self.0.job_token.as_str()
+  }
+
+  fn third_party_id(&self) -> &str {
+    &self.0.request_id
+  }
+
+  fn created_at(&self) -> DateTime<Utc> {
+    self.0.created_at
+  }
+
+  fn maybe_user_token(&self) -> Option<&str> {
+    self.0.maybe_creator_user_token.as_ref().map(|token| token.as_str())
+  }
+}
+
+pub struct CompletedVideo {
+  pub video_url: String,
+  pub maybe_thumbnail_url: Option<String>,
+}
+
+pub struct GmiCloudPollingJob {
+  pub deps: JobDependencies,
+}
+
+#[async_trait]
+impl PollingJob for GmiCloudPollingJob {
+  type Job = GmiCloudJob;
+  type Output = CompletedVideo;
+
+  fn provider_name(&self) -> &'static str {
+    "GmiCloud"
+  }
+
+  async fn list_pending(&self) -> AnyhowResult<Vec<GmiCloudJob>> {
+    let jobs = list_pending_gmicloud_jobs(&self.deps.mysql_pool).await?;
+    Ok(jobs.into_iter().map(GmiCloudJob).collect())
+  }
+
+  async fn poll(&self, job: &GmiCloudJob) -> AnyhowResult<PollStatus<CompletedVideo>> {
+    let poll_result = poll_gmicloud_request(&self.deps.gmicloud_api_key, &job.request_id).await
+      .map_err(|err| anyhow!("error polling GmiCloud request: {:?}", err))?;
+
+    if poll_result.is_failed() {
+      return Ok(PollStatus::Failed { reason: "GmiCloud generation failed".to_string() });
+    }
+
+    if poll_result.is_cancelled() {
+      return Ok(PollStatus::Failed { reason: "GmiCloud request was cancelled".to_string() });
+    }
+
+    if poll_result.is_in_progress() {
+      return Ok(PollStatus::Pending(if poll_result.is_queued() {
+        ObservedJobState::Queued
+      } else {
+        ObservedJobState::Running
+      }));
+    }
+
+    if !poll_result.is_success() {
+      return Ok(PollStatus::Pending(ObservedJobState::Unknown));
+    }
+
+    let video_url = match poll_result.video_url() {
+      Some(url) => url.to_string(),
+      None => {
+        warn!(
+          "Request {} succeeded but has no video_url for job {}. Skipping.",
+          job.request_id, job.job_token.as_str()
+        );
+        return Ok(PollStatus::Pending(ObservedJobState::Unknown));
+      }
+    };
+
+    Ok(PollStatus::Succeeded(CompletedVideo {
+      video_url,
+      maybe_thumbnail_url: poll_result.thumbnail_url().map(|s| s.to_string()),
+    }))
+  }
+
+  async fn on_success(&self, job: &GmiCloudJob, output: CompletedVideo) -> AnyhowResult<()> {
+    process_successful_job(
+      &self.deps,
+      job,
+      &output.video_url,
+      output.maybe_thumbnail_url.as_deref(),
+    ).await
+  }
+
+  async fn on_failure(&self, job: &GmiCloudJob, failure: &JobFailure) {
+    process_failed_job(&self.deps, job, &failure.reason).await;
+  }
+}
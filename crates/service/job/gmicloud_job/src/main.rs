@@ -7,35 +7,30 @@
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
-#[macro_use] extern crate serde_derive;
-
 use std::time::Duration;
 
 use anyhow::anyhow;
-use log::{info, warn};
+use log::info;
 use sqlx::mysql::MySqlPoolOptions;
 
 use bootstrap::bootstrap::{bootstrap, BootstrapArgs};
 use cloud_storage::bucket_client::BucketClient;
-use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
 use shared_env_var_config::logging::DEFAULT_RUST_LOG;
 use errors::AnyhowResult;
 use gmicloud_client::creds::gmicloud_api_key::GmiCloudApiKey;
-use jobs_common::job_stats::JobStats;
+use job_runner::config::job_runner_config::JobRunnerConfig;
+use job_runner::job_runner::JobRunner;
+use job_runner::startup::build_pager::build_pager;
+use rootly_config::services::ROOTLY_SERVICE_ID_SEEDANCE2_PRO_JOB;
 use server_environment::ServerEnvironment;
 use shared_env_var_config::mysql::env_get_mysql_connection_string_or_default;
 
-use crate::http_server::run_http_server::{launch_http_server, CreateServerArgs};
-use crate::main_loop::main_loop;
+use crate::gmicloud_polling_job::GmiCloudPollingJob;
 use crate::job_dependencies::JobDependencies;
-use crate::startup::build_pager::build_pager;
 
-pub mod alert_on_error;
-pub mod http_server;
+pub mod gmicloud_polling_job;
 pub mod job_dependencies;
-pub mod main_loop;
 pub mod process_job;
-pub mod startup;
 
 // Bucket config
 const ENV_ACCESS_KEY: &str = "ACCESS_KEY";
@@ -96,66 +91,35 @@ async fn main() -> AnyhowResult<()> {
     &s3_compatible_endpoint_url,
     None,
     Some(bucket_timeout),
-  )?;
+  )?.with_env_backend_override()?;
 
   // GmiCloud API key
   let gmicloud_api_key_str = easyenv::get_env_string_required(ENV_GMICLOUD_API_KEY)?;
   let gmicloud_api_key = GmiCloudApiKey::new(gmicloud_api_key_str);
 
-  // How often to poll after a successful iteration (default: 3 seconds)
-  let poll_interval_success_millis: u64 = easyenv::get_env_num(
-    "GMICLOUD_POLL_INTERVAL_SUCCESS_MILLIS",
-    3_000,
-  )?;
-
-  // How often to poll after a failed iteration (default: 15 seconds)
-  let poll_interval_failure_millis: u64 = easyenv::get_env_num(
-    "GMICLOUD_POLL_INTERVAL_FAILURE_MILLIS",
-    15_000,
-  )?;
-
-  let application_shutdown = RelaxedAtomicBool::new(false);
-  let job_stats = JobStats::new();
-
-  // Pager setup
-  let (pager, pager_worker) = build_pager(server_environment, &container_environment.hostname);
-
-  info!("Spawning pager worker.");
-
-  std::thread::spawn(move || {
-    let rt = tokio::runtime::Runtime::new().expect("pager worker tokio runtime");
-    rt.block_on(pager_worker.run());
-  });
+  let runner_config = JobRunnerConfig::with_defaults("gmicloud-job")
+    .with_env_overrides("GMICLOUD")?;
 
-  let create_server_args = CreateServerArgs {
-    container_environment: container_environment.clone(),
-    job_stats: job_stats.clone(),
-  };
-
-  let job_dependencies = JobDependencies {
-    mysql_pool,
-    public_bucket_client,
-    gmicloud_api_key,
+  let (pager, pager_worker) = build_pager(
+    "gmicloud-job",
+    ROOTLY_SERVICE_ID_SEEDANCE2_PRO_JOB,
     server_environment,
-    pager,
-    job_stats,
-    poll_interval_success_millis,
-    poll_interval_failure_millis,
-    application_shutdown: application_shutdown.clone(),
-  };
-
-  std::thread::spawn(move || {
-    let actix_runtime = actix_web::rt::System::new();
-    let http_server_handle = launch_http_server(create_server_args);
+    &container_environment.hostname,
+  );
 
-    actix_runtime.block_on(http_server_handle)
-      .expect("HTTP server should not exit.");
+  let runner = JobRunner::new(runner_config, pager, pager_worker);
 
-    warn!("Server thread is shut down.");
-    application_shutdown.set(true);
-  });
+  let job = GmiCloudPollingJob {
+    deps: JobDependencies {
+      mysql_pool,
+      public_bucket_client,
+      gmicloud_api_key,
+      server_environment,
+      pager: runner.pager().clone(),
+    },
+  };
 
-  main_loop(job_dependencies).await;
+  runner.run(job).await?;
 
   Ok(())
 }
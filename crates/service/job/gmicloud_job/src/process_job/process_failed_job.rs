@@ -1,16 +1,49 @@
 use log::{error, warn};
 
 use enums::by_table::generic_inference_jobs::frontend_failure_category::FrontendFailureCategory;
+use jobs_common::refund_failed_job::refund_failed_job;
 use mysql_queries::queries::generic_inference::job::mark_job_failed_by_token::{mark_job_failed_by_token, MarkJobFailedByTokenArgs};
-use mysql_queries::queries::generic_inference::gmicloud::list_pending_gmicloud_jobs::PendingGmiCloudJob;
+use pager::notification::notification_details_builder::NotificationDetailsBuilder;
+use pager::notification::notification_urgency::NotificationUrgency;
 
+use crate::gmicloud_polling_job::GmiCloudJob;
 use crate::job_dependencies::JobDependencies;
 
 pub async fn process_failed_job(
   deps: &JobDependencies,
-  job: &PendingGmiCloudJob,
+  job: &GmiCloudJob,
   reason: &str,
 ) {
+  // --- Step 1: Refund before touching the job status; on a transient error the job is retried next poll. ---
+
+  let refund_result = refund_failed_job(
+    &deps.mysql_pool,
+    &job.job_token,
+    job.maybe_wallet_ledger_entry_token.as_ref(),
+  ).await;
+
+  if let Err(err) = refund_result {
+    if err.is_transient() {
+      return;
+    }
+
+    // NB: The refund will never succeed, so alert and mark the job failed anyway. Otherwise the
+    // job is re-polled (and re-refunded) forever.
+    let notification = NotificationDetailsBuilder::from_boxed_error(err.into())
+        .set_title("GmiCloud refund failed permanently".to_string())
+        .set_inference_job_token(Some(job.job_token.to_string()))
+        .set_third_party_id(Some(job.request_id.to_string()))
+        .set_user_token(job.maybe_creator_user_token.as_ref().map(|t| t.to_string()))
+        .set_urgency(Some(NotificationUrgency::Medium))
+        .build();
+
+    if let Err(pager_err) = deps.pager.enqueue_page(notification) {
+      error!("Failed to enqueue pager alert: {:?}", pager_err);
+    }
+  }
+
+  // --- Step 2: Mark the job record as failed. ---
+
   let reason_lower = reason.to_lowercase();
 
   let platform_rules_violation = reason_lower.contains("violates")
@@ -1,4 +1,3 @@
-use std::sync::Arc;
 use anyhow::anyhow;
 use log::{error, info, warn};
 
@@ -10,12 +9,12 @@ use enums::by_table::media_files::media_file_origin_product_category::MediaFileO
 use enums::by_table::media_files::media_file_type::MediaFileType;
 use errors::AnyhowResult;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
-use mysql_queries::queries::generic_inference::gmicloud::list_pending_gmicloud_jobs::PendingGmiCloudJob;
+use job_runner::alert_on_error::alert_pager_and_return_err;
 use mysql_queries::queries::media_files::create::insert_builder::media_file_insert_builder::MediaFileInsertBuilder;
 use mysql_queries::queries::generic_inference::web::mark_generic_inference_job_successfully_done_by_token::mark_generic_inference_job_successfully_done_by_token;
 use tokens::tokens::media_files::MediaFileToken;
 
-use crate::alert_on_error::alert_pager_and_return_err;
+use crate::gmicloud_polling_job::GmiCloudJob;
 use crate::job_dependencies::JobDependencies;
 
 const VIDEO_PREFIX: &str = "artcraft_";
@@ -26,7 +25,7 @@ const THUMB_PREFIX: &str = "artcraft_";
 /// Download the completed video, upload to bucket, create media file record, and mark job done.
 pub async fn process_successful_job(
   deps: &JobDependencies,
-  job: &PendingGmiCloudJob,
+  job: &GmiCloudJob,
   video_url: &str,
   maybe_thumbnail_url: Option<&str>,
 ) -> AnyhowResult<()> {
@@ -185,7 +184,7 @@ fn guess_image_format_from_url(url: &str) -> (&'static str, &'static str, MediaF
 
 async fn download_and_upload_thumbnail(
   deps: &JobDependencies,
-  job: &PendingGmiCloudJob,
+  job: &GmiCloudJob,
   thumbnail_url: &str,
 ) -> AnyhowResult<MediaFileToken> {
   info!("Downloading thumbnail for job {} from: {}", job.job_token.as_str(), thumbnail_url);
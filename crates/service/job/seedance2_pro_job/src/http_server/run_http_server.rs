@@ -1,76 +0,0 @@
-use std::sync::Arc;
-
-use actix_web::dev::Server;
-use actix_web::middleware::Logger;
-use actix_web::{web, App, HttpResponse, HttpServer};
-use log::info;
-
-use bootstrap::bootstrap::ContainerEnvironment;
-use errors::AnyhowResult;
-use jobs_common::job_stats::JobStats;
-use pager::client::pager::Pager;
-
-use crate::http_server::endpoints::health_check_handler::get_health_check_handler;
-use crate::http_server::http_server_shared_state::HttpServerSharedState;
-
-const DEFAULT_BIND_ADDRESS: &str = "0.0.0.0:11223";
-const DEFAULT_NUM_WORKERS: usize = 2;
-
-pub struct CreateServerArgs {
-  pub container_environment: ContainerEnvironment,
-  pub job_stats: JobStats,
-  pub pager: Pager,
-}
-
-pub fn run_http_server(args: CreateServerArgs) -> AnyhowResult<Server> {
-  let bind_address = easyenv::get_env_string_or_default("HTTP_BIND_ADDRESS", DEFAULT_BIND_ADDRESS);
-  let num_workers = easyenv::get_env_num("HTTP_NUM_WORKERS", DEFAULT_NUM_WORKERS)?;
-  let hostname = args.container_environment.hostname.clone();
-
-  let server_state = HttpServerSharedState {
-    job_stats: args.job_stats.clone(),
-    consecutive_failure_unhealthy_threshold: easyenv::get_env_num(
-      "CONSECUTIVE_FAILURE_UNHEALTHY_THRESHOLD",
-      3,
-    )?,
-    pager: args.pager,
-    hostname,
-  };
-
-  let server_state_arc = web::Data::new(Arc::new(server_state));
-
-  info!("Starting HTTP service (for k8s health checking).");
-
-  let log_format = "[%{HOSTNAME}e] IP=[%{X-Forwarded-For}i] \"%r\" %s %b \"%{Referer}i\" \"%{User-Agent}i\" %T";
-
-  let handle = HttpServer::new(move || {
-    App::new()
-      .app_data(server_state_arc.clone())
-      .wrap(Logger::new(&log_format).exclude("/_status"))
-      .service(
-        web::resource("/")
-          .route(web::get().to(|| HttpResponse::Ok()))
-          .route(web::head().to(|| HttpResponse::Ok())),
-      )
-      .service(
-        web::resource("/_status")
-          .route(web::get().to(get_health_check_handler))
-          .route(web::head().to(|| HttpResponse::Ok())),
-      )
-  })
-    .bind(&bind_address)
-    .unwrap_or_else(|err| {
-      eprintln!("FATAL: Failed to bind to address '{}': {}", bind_address, err);
-      eprintln!("The address is likely already in use by another process.");
-      std::process::exit(1);
-    })
-    .workers(num_workers)
-    .run();
-
-  Ok(handle)
-}
-
-pub async fn launch_http_server(args: CreateServerArgs) -> AnyhowResult<()> {
-  run_http_server(args)?.await?;
-  Ok(())
-}
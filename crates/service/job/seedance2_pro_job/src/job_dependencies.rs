@@ -1,14 +1,11 @@
-use std::sync::Arc;
-
 use chrono::Duration;
 use cloud_storage::bucket_client::BucketClient;
-use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
-use jobs_common::job_stats::JobStats;
+use job_runner::job_runner::JobRunnerContext;
+use jobs_common::poll_backoff::PollBackoffConfig;
 use pager::client::pager::Pager;
 use seedance2pro_client::creds::seedance2pro_session::Seedance2ProSession;
 use server_environment::ServerEnvironment;
 use sqlx::MySqlPool;
-use tokio::sync::Notify;
 
 #[derive(Clone)]
 pub struct JobDependencies {
@@ -26,7 +23,8 @@ pub struct JobDependencies {
 
   pub server_environment: ServerEnvironment,
 
-  pub job_stats: JobStats,
+  /// Job and poll stats (reported on the health check) and the shutdown signal.
+  pub context: JobRunnerContext,
 
   /// How long to sleep between poll iterations (milliseconds).
   pub poll_interval_millis: u64,
@@ -49,15 +47,15 @@ pub struct JobDependencies {
   /// with each attempt up to this cap.
   pub poll_retry_max_delay_millis: u64,
 
-  /// Page an alert when available Kinovi credits fall below this threshold.
-  pub credits_alert_threshold: u64,
+  /// Per-job poll backoff and the maximum job lifetime. Kinovi is polled by listing orders,
+  /// so the order list is only fetched when at least one pending job is due.
+  pub poll_backoff_config: PollBackoffConfig,
 
-  /// Set to `true` from another thread to trigger graceful shutdown.
-  pub application_shutdown: RelaxedAtomicBool,
+  /// Maximum number of matched orders processed (downloaded and uploaded) at once.
+  pub max_concurrent_polls: usize,
 
-  /// Notified when `application_shutdown` is set. Allows sleeping tasks
-  /// to wake up immediately instead of waiting for the full sleep duration.
-  pub shutdown_notify: Arc<Notify>,
+  /// Page an alert when available Kinovi credits fall below this threshold.
+  pub credits_alert_threshold: u64,
 
   /// Pager client for sending alerts.
   pub pager: Pager,
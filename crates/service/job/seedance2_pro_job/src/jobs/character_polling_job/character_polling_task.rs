@@ -1,7 +1,12 @@
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
-use log::{error, info, warn};
+use async_trait::async_trait;
+use log::{info, warn};
+
+use errors::AnyhowResult;
+use job_runner::job_runner::JobRunnerContext;
+use job_runner::polling::periodic_task::PeriodicTask;
 
 use mysql_queries::queries::generic_inference::seedance2pro::list_pending_seedance2pro_character_jobs::list_pending_seedance2pro_character_jobs;
 use seedance2pro_client::requests::poll_characters::poll_characters::{
@@ -14,28 +19,40 @@ use super::process_successful_character::process_successful_character;
 
 const POLL_ALERT_THRESHOLD: Duration = Duration::from_secs(600);
 
-pub async fn character_polling_main_loop(deps: JobDependencies) {
-  while !deps.application_shutdown.get() {
+/// Polls Kinovi's character list for our pending character creation jobs.
+pub struct CharacterPollingTask {
+  deps: JobDependencies,
+}
+
+impl CharacterPollingTask {
+  pub fn new(deps: JobDependencies) -> Self {
+    Self { deps }
+  }
+}
+
+#[async_trait]
+impl PeriodicTask for CharacterPollingTask {
+  fn name(&self) -> &'static str {
+    "Kinovi character polling"
+  }
+
+  async fn run_iteration(&mut self, _context: &JobRunnerContext) -> AnyhowResult<Duration> {
     let start = Instant::now();
-    let result = run_poll_iteration(&deps).await;
+    let result = run_poll_iteration(&self.deps).await;
     let elapsed = start.elapsed();
 
-    if let Err(err) = result {
-      error!("Error in character poll iteration: {:?}", err);
-      let _ = deps.job_stats.increment_failure_count();
-    }
-
     if elapsed > POLL_ALERT_THRESHOLD {
       warn!("Character poll iteration took {:.1}s (threshold: {}s)", elapsed.as_secs_f64(), POLL_ALERT_THRESHOLD.as_secs());
     }
 
-    tokio::select! {
-      _ = tokio::time::sleep(Duration::from_millis(deps.poll_interval_millis)) => {}
-      _ = deps.shutdown_notify.notified() => {}
-    }
+    result?;
+
+    Ok(Duration::from_millis(self.deps.poll_interval_millis))
   }
 
-  warn!("Character polling main loop is shut down.");
+  fn failure_delay(&self) -> Duration {
+    Duration::from_millis(self.deps.poll_interval_millis)
+  }
 }
 
 async fn run_poll_iteration(deps: &JobDependencies) -> anyhow::Result<()> {
@@ -68,7 +85,7 @@ async fn run_poll_iteration(deps: &JobDependencies) -> anyhow::Result<()> {
 
   // 3. Match polled characters against pending jobs.
   for character in &response.characters {
-    if deps.application_shutdown.get() {
+    if deps.context.shutdown.is_shutting_down() {
       break;
     }
 
@@ -85,14 +102,14 @@ async fn run_poll_iteration(deps: &JobDependencies) -> anyhow::Result<()> {
         );
         match process_successful_character(deps, job, character).await {
           Ok(()) => {
-            let _ = deps.job_stats.increment_success_count();
+            let _ = deps.context.job_stats.increment_success_count();
           }
           Err(err) => {
             warn!(
               "Error processing completed character {}: {:?}",
               character.character_id, err,
             );
-            let _ = deps.job_stats.increment_failure_count();
+            let _ = deps.context.job_stats.increment_failure_count();
           }
         }
       }
@@ -1,3 +1,3 @@
-pub mod character_polling_main_loop;
+pub mod character_polling_task;
 mod process_successful_character;
 mod process_failed_character;
@@ -1,7 +1,12 @@
 use std::cmp::min;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use log::{error, info, warn};
+
+use errors::AnyhowResult;
+use job_runner::job_runner::JobRunnerContext;
+use job_runner::polling::periodic_task::PeriodicTask;
 use pager::notification::notification_details_builder::NotificationDetailsBuilder;
 use pager::notification::notification_urgency::NotificationUrgency;
 use seedance2pro_client::requests::get_user_auth_details::get_user_auth_details::{
@@ -12,42 +17,58 @@ use crate::job_dependencies::JobDependencies;
 
 const CREDITS_CHECK_INTERVAL: Duration = Duration::from_secs(30);
 
-pub async fn credits_checking_main_loop(deps: JobDependencies) {
-  let mut consecutive_failures: u32 = 0;
+/// Pages when the Kinovi account runs low on credits, or when checking keeps failing.
+pub struct CreditsCheckingTask {
+  deps: JobDependencies,
+  consecutive_failures: u32,
+}
+
+impl CreditsCheckingTask {
+  pub fn new(deps: JobDependencies) -> Self {
+    Self { deps, consecutive_failures: 0 }
+  }
+}
 
-  while !deps.application_shutdown.get() {
-    match check_credits(&deps).await {
+#[async_trait]
+impl PeriodicTask for CreditsCheckingTask {
+  fn name(&self) -> &'static str {
+    "Kinovi credits checking"
+  }
+
+  /// Errors are handled here rather than by the runner: a failed credits check
+  /// shouldn't count against the health of the job polling.
+  async fn run_iteration(&mut self, _context: &JobRunnerContext) -> AnyhowResult<Duration> {
+    match check_credits(&self.deps).await {
       Ok(()) => {
-        consecutive_failures = 0;
+        self.consecutive_failures = 0;
       }
       Err(err) => {
-        consecutive_failures += 1;
-        error!("Credits check failed ({} consecutive): {:?}", consecutive_failures, err);
+        self.consecutive_failures += 1;
+        error!("Credits check failed ({} consecutive): {:?}", self.consecutive_failures, err);
 
-        if consecutive_failures >= 2 {
+        if self.consecutive_failures >= 2 {
           let notification = NotificationDetailsBuilder::from_title(
               "Kinovi credits check failing".to_string())
             .set_description(Some(format!(
               "Credits check has failed {} times in a row. Last error: {:?}",
-              consecutive_failures, err,
+              self.consecutive_failures, err,
             )))
             .set_urgency(Some(NotificationUrgency::Medium))
             .build();
 
-          if let Err(pager_err) = deps.pager.enqueue_page(notification) {
+          if let Err(pager_err) = self.deps.pager.enqueue_page(notification) {
             error!("Failed to enqueue credits check failure alert: {:?}", pager_err);
           }
         }
       }
     }
 
-    tokio::select! {
-      _ = tokio::time::sleep(CREDITS_CHECK_INTERVAL) => {}
-      _ = deps.shutdown_notify.notified() => {}
-    }
+    Ok(CREDITS_CHECK_INTERVAL)
   }
 
-  warn!("Credits checking main loop is shut down.");
+  fn failure_delay(&self) -> Duration {
+    CREDITS_CHECK_INTERVAL
+  }
 }
 
 async fn check_credits(deps: &JobDependencies) -> anyhow::Result<()> {
@@ -1 +1 @@
-pub mod credits_checking_main_loop;
+pub mod credits_checking_task;
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use job_runner::polling::polling_job::PendingJob;
+use mysql_queries::queries::generic_inference::seedance2pro::list_pending_seedance2pro_video_jobs::PendingSeedance2ProJob;
+
+/// A pending Kinovi job, as seen by the runner (eg. for `alert_pager_and_return_err`).
+pub struct KinoviJob<'a>(pub &'a PendingSeedance2ProJob);
+
+impl PendingJob for KinoviJob<'_> {
+  fn job_token(&self) -> &str {
+    self.0.job_token.as_str()
+  }
+
+  fn third_party_id(&self) -> &str {
+    &self.0.order_id
+  }
+
+  fn created_at(&self) -> DateTime<Utc> {
+    self.0.created_at
+  }
+
+  fn maybe_user_token(&self) -> Option<&str> {
+    self.0.maybe_creator_user_token.as_ref().map(|token| token.as_str())
+  }
+}
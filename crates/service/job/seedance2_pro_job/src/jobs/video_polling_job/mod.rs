@@ -1,4 +1,4 @@
-pub mod alert_on_error;
-pub mod video_polling_main_loop;
+pub mod kinovi_job;
+pub mod video_polling_task;
 pub mod process_job;
 pub mod process_orders_batch;
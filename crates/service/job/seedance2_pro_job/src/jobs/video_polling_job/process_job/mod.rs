@@ -1,2 +1,3 @@
+pub mod process_expired_job;
 pub mod process_failed_job;
 pub mod process_successful_job;
@@ -0,0 +1,25 @@
+use log::warn;
+
+use enums::by_table::generic_inference_jobs::frontend_failure_category::FrontendFailureCategory;
+use mysql_queries::queries::generic_inference::seedance2pro::list_pending_seedance2pro_video_jobs::PendingSeedance2ProJob;
+
+use crate::job_dependencies::JobDependencies;
+use crate::jobs::video_polling_job::process_job::process_failed_job::process_failed_job;
+
+/// Fail (and refund) a job that still hasn't finished after the maximum job lifetime.
+pub async fn process_expired_job(
+  deps: &JobDependencies,
+  job: &PendingSeedance2ProJob,
+) {
+  let max_lifetime_minutes = deps.poll_backoff_config.max_job_lifetime.as_secs() / 60;
+  let reason = format!("Video generation did not finish within {} minutes", max_lifetime_minutes);
+
+  warn!(
+    "Order {} for job {} exceeded the maximum job lifetime ({} minutes).",
+    job.order_id, job.job_token.as_str(), max_lifetime_minutes
+  );
+
+  let _ = deps.context.poll_stats.increment_expired_count();
+
+  process_failed_job(deps, job, &reason, Some(FrontendFailureCategory::GenerationFailed)).await;
+}
@@ -9,12 +9,13 @@ use enums::by_table::media_files::media_file_origin_product_category::MediaFileO
 use enums::by_table::media_files::media_file_type::MediaFileType;
 use errors::AnyhowResult;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
+use job_runner::alert_on_error::alert_pager_and_return_err;
 use mysql_queries::queries::generic_inference::seedance2pro::list_pending_seedance2pro_video_jobs::PendingSeedance2ProJob;
 use mysql_queries::queries::media_files::create::insert_builder::media_file_insert_builder::MediaFileInsertBuilder;
 use mysql_queries::queries::generic_inference::web::mark_generic_inference_job_successfully_done_by_token::mark_generic_inference_job_successfully_done_by_token;
 use seedance2pro_client::requests::poll_orders::poll_orders::OrderStatus;
 
-use crate::jobs::video_polling_job::alert_on_error::alert_pager_and_return_err;
+use crate::jobs::video_polling_job::kinovi_job::KinoviJob;
 use crate::job_dependencies::JobDependencies;
 
 const PREFIX: &str = "artcraft_";
@@ -57,8 +58,8 @@ pub async fn process_successful_job(
         return alert_pager_and_return_err(
           &deps.pager,
           "Seedance2Pro video download failed",
-          anyhow!("error reading video bytes: {:?}", err),
-          Some(job),
+          anyhow!("error reading video bytes: {:?}", err).into(),
+          Some(&KinoviJob(job)),
         );
       }
     },
@@ -67,8 +68,8 @@ pub async fn process_successful_job(
       return alert_pager_and_return_err(
         &deps.pager,
         "Seedance2Pro video download failed",
-        anyhow!("reqwest error downloading video: {:?}", err),
-        Some(job),
+        anyhow!("reqwest error downloading video: {:?}", err).into(),
+        Some(&KinoviJob(job)),
       );
     }
   };
@@ -104,8 +105,8 @@ pub async fn process_successful_job(
     return alert_pager_and_return_err(
       &deps.pager,
       "Seedance2Pro bucket upload failed",
-      anyhow!("error uploading video to bucket: {:?}", err),
-      Some(job),
+      anyhow!("error uploading video to bucket: {:?}", err).into(),
+      Some(&KinoviJob(job)),
     );
   }
 
@@ -145,8 +146,8 @@ pub async fn process_successful_job(
       return alert_pager_and_return_err(
         &deps.pager,
         "Seedance2Pro media file insert failed",
-        anyhow!("error inserting media file record: {:?}", err),
-        Some(job),
+        anyhow!("error inserting media file record: {:?}", err).into(),
+        Some(&KinoviJob(job)),
       );
     }
   };
@@ -171,8 +172,8 @@ pub async fn process_successful_job(
     return alert_pager_and_return_err(
       &deps.pager,
       "Seedance2Pro job completion update failed",
-      anyhow!("error marking job done: {:?}", err),
-      Some(job),
+      anyhow!("error marking job done: {:?}", err).into(),
+      Some(&KinoviJob(job)),
     );
   }
 
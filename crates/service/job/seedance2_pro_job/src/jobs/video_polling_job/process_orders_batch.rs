@@ -1,69 +1,93 @@
 use std::collections::HashMap;
+use std::time::Instant;
 
+use chrono::Utc;
+use futures::stream::{self, StreamExt};
+use jobs_common::poll_backoff::{job_age_since, ObservedJobState, PollBackoff};
 use log::{info, warn};
 use mysql_queries::queries::generic_inference::seedance2pro::list_pending_seedance2pro_video_jobs::PendingSeedance2ProJob;
 use seedance2pro_client::requests::poll_orders::poll_orders::{OrderStatus, TaskStatus};
 
 use crate::job_dependencies::JobDependencies;
-use crate::jobs::video_polling_job::process_job::process_failed_job::process_failed_job;
+use crate::jobs::video_polling_job::process_job::process_expired_job::process_expired_job;
+use crate::jobs::video_polling_job::process_job::process_failed_job::{failure_reason_for_order, frontend_failure_category_for_order, process_failed_job};
 use crate::jobs::video_polling_job::process_job::process_successful_job::process_successful_job;
 
+enum OrderOutcome {
+  Succeeded,
+  Failed,
+  /// Kinovi finished the order but we couldn't process it. Retried on a later poll.
+  ProcessingError,
+  InProgress(ObservedJobState),
+  /// Still unfinished after the maximum job lifetime, so failed and refunded.
+  Expired,
+}
+
 /// Process a batch of polled orders against the pending jobs map.
 ///
 /// Removes matched order_ids from `job_by_order_id` so they are not
-/// processed again in subsequent batches.
+/// processed again in subsequent batches. Matched orders are processed
+/// concurrently, up to `max_concurrent_polls` at a time.
 pub async fn process_orders_batch(
   deps: &JobDependencies,
   kinovi_orders: &[OrderStatus],
   job_by_order_id: &mut HashMap<String, PendingSeedance2ProJob>,
+  poll_backoff: &mut PollBackoff,
   pages_in_current_batch: u32,
 ) {
   log_batch_summary(&kinovi_orders, pages_in_current_batch);
 
   let pending_job_count = job_by_order_id.len();
+  let now_utc = Utc::now();
+
+  let matched = kinovi_orders
+    .iter()
+    .filter_map(|order| {
+      // Orders that aren't one of our pending jobs are skipped.
+      let job = job_by_order_id.remove(&order.order_id)?;
+      let is_expired = poll_backoff.is_expired(job_age_since(job.created_at, now_utc));
+      Some((job, order, is_expired))
+    })
+    .collect::<Vec<_>>();
+
+  let batch_matched = matched.len();
+
+  let outcomes = stream::iter(matched)
+    .map(|(job, order, is_expired)| async move {
+      let outcome = process_order(deps, &job, order, is_expired).await;
+      (job, outcome)
+    })
+    .buffer_unordered(deps.max_concurrent_polls.max(1))
+    .collect::<Vec<_>>()
+    .await;
 
   let mut batch_succeeded = 0u32;
   let mut batch_failed = 0u32;
   let mut batch_in_progress = 0u32;
-  let mut batch_matched = 0u32;
-
-  for order in kinovi_orders {
-    let job = match job_by_order_id.remove(&order.order_id) {
-      Some(j) => j,
-      None => continue, // Not one of our pending jobs.
-    };
+  let mut batch_expired = 0u32;
 
-    batch_matched += 1;
+  let polled_at = Instant::now();
 
-    match &order.task_status {
-      TaskStatus::Completed => {
-        info!(
-          "Order {} completed, processing job {}",
-          order.order_id,
-          job.job_token.as_str()
-        );
-        if let Err(err) = process_successful_job(deps, &job, order).await {
-          warn!(
-            "Error processing completed order {}: {:?}",
-            order.order_id, err
-          );
-          let _ = deps.job_stats.increment_failure_count();
-        } else {
-          let _ = deps.job_stats.increment_success_count();
-          batch_succeeded += 1;
-        }
+  for (job, outcome) in outcomes {
+    match outcome {
+      OrderOutcome::Succeeded => {
+        batch_succeeded += 1;
+        poll_backoff.forget(job.job_token.as_str());
       }
-      TaskStatus::Failed => {
-        process_failed_job(deps, &job, order).await;
+      OrderOutcome::Failed => {
         batch_failed += 1;
+        poll_backoff.forget(job.job_token.as_str());
       }
-      TaskStatus::Pending | TaskStatus::Processing => {
-        // Still in progress — check again next poll.
-        batch_in_progress += 1;
+      OrderOutcome::Expired => {
+        batch_expired += 1;
+        poll_backoff.forget(job.job_token.as_str());
       }
-      TaskStatus::Unknown(unknown_status) => {
-        warn!("Unknown order status: {:?}", unknown_status);
+      OrderOutcome::ProcessingError => {
+        poll_backoff.record_poll(job.job_token.as_str(), ObservedJobState::Unknown, polled_at);
+      }
+      OrderOutcome::InProgress(observed) => {
         batch_in_progress += 1;
+        poll_backoff.record_poll(job.job_token.as_str(), observed, polled_at);
       }
     }
   }
@@ -73,7 +97,7 @@ pub async fn process_orders_batch(
     {} kinovi orders in polled batch, \
     {} pending db jobs in batch at start, \
     {} kinovi orders in batch matched pending jobs \
-    (orders succeeded={}, orders failed={}, orders in_progress={}), \
+    (orders succeeded={}, orders failed={}, orders in_progress={}, orders expired={}), \
     {} pending db jobs in batch remaining",
     kinovi_orders.len(),
     pending_job_count,
@@ -81,10 +105,64 @@ pub async fn process_orders_batch(
     batch_succeeded,
     batch_failed,
     batch_in_progress,
+    batch_expired,
     job_by_order_id.len(),
   );
 }
 
+async fn process_order(
+  deps: &JobDependencies,
+  job: &PendingSeedance2ProJob,
+  order: &OrderStatus,
+  is_expired: bool,
+) -> OrderOutcome {
+  let observed = match &order.task_status {
+    TaskStatus::Completed => {
+      info!(
+        "Order {} completed, processing job {}",
+        order.order_id,
+        job.job_token.as_str()
+      );
+      if let Err(err) = process_successful_job(deps, job, order).await {
+        warn!(
+          "Error processing completed order {}: {:?}",
+          order.order_id, err
+        );
+        let _ = deps.context.job_stats.increment_failure_count();
+        return OrderOutcome::ProcessingError;
+      }
+      let _ = deps.context.job_stats.increment_success_count();
+      return OrderOutcome::Succeeded;
+    }
+    TaskStatus::Failed => {
+      let reason = failure_reason_for_order(order);
+      let frontend_failure_category = frontend_failure_category_for_order(order);
+      process_failed_job(deps, job, reason, frontend_failure_category).await;
+      return OrderOutcome::Failed;
+    }
+    TaskStatus::Cancelled => {
+      // A user-requested cancellation is only confirmed here; refund like any other failure.
+      info!("Order {} was cancelled, failing job {}", order.order_id, job.job_token.as_str());
+      process_failed_job(deps, job, "Kinovi order was cancelled", None).await;
+      return OrderOutcome::Failed;
+    }
+    TaskStatus::Pending => ObservedJobState::Queued,
+    TaskStatus::Processing => ObservedJobState::Running,
+    TaskStatus::Unknown(unknown_status) => {
+      warn!("Unknown order status: {:?}", unknown_status);
+      ObservedJobState::Unknown
+    }
+  };
+
+  // Still in progress — check again once the backoff elapses, unless it's out of time.
+  if is_expired {
+    process_expired_job(deps, job).await;
+    return OrderOutcome::Expired;
+  }
+
+  OrderOutcome::InProgress(observed)
+}
+
 fn log_batch_summary(orders: &[OrderStatus], pages_in_batch: u32) {
   let mut succeeded = 0u32;
   let mut failed = 0u32;
@@ -94,7 +172,7 @@ fn log_batch_summary(orders: &[OrderStatus], pages_in_batch: u32) {
   for order in orders {
     match &order.task_status {
       TaskStatus::Completed => succeeded += 1,
-      TaskStatus::Failed => failed += 1,
+      TaskStatus::Failed | TaskStatus::Cancelled => failed += 1,
       TaskStatus::Pending | TaskStatus::Processing => in_progress += 1,
       TaskStatus::Unknown(_) => unknown += 1,
     }
@@ -1,66 +1,88 @@
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
 use chrono::Utc;
 use enums::by_table::generic_inference_jobs::inference_job_external_third_party::InferenceJobExternalThirdParty;
 use enums::by_table::generic_inference_jobs::inference_job_type::InferenceJobType;
+use errors::AnyhowResult;
+use job_runner::alert_on_error::alert_pager_and_return_err;
+use job_runner::job_runner::JobRunnerContext;
+use job_runner::polling::periodic_task::PeriodicTask;
+use jobs_common::poll_backoff::{job_age_since, ObservedJobState, PollBackoff};
 use log::{error, info, warn};
 use pager::notification::notification_details_builder::NotificationDetailsBuilder;
 use pager::notification::notification_urgency::NotificationUrgency;
 use mysql_queries::queries::generic_inference::seedance2pro::list_pending_seedance2pro_video_jobs::{list_pending_seedance2pro_video_jobs, PendingSeedance2ProJob};
 use seedance2pro_client::requests::poll_orders::poll_orders::{poll_orders, OrderStatus, PollOrdersArgs, PollOrdersResponse, TaskStatus};
 
-use crate::jobs::video_polling_job::alert_on_error::alert_pager_and_return_err;
+use crate::jobs::video_polling_job::process_job::process_expired_job::process_expired_job;
 use crate::jobs::video_polling_job::process_orders_batch::process_orders_batch;
 use crate::job_dependencies::JobDependencies;
 
 const POLL_ALERT_THRESHOLD: Duration = Duration::from_mins(6);
 
-pub async fn video_polling_main_loop(job_dependencies: JobDependencies) {
-  while !job_dependencies.application_shutdown.get() {
+/// Polls Kinovi's order list for our pending video jobs.
+pub struct VideoPollingTask {
+  deps: JobDependencies,
+  poll_backoff: PollBackoff,
+}
+
+impl VideoPollingTask {
+  pub fn new(deps: JobDependencies) -> Self {
+    let poll_backoff = PollBackoff::new(deps.poll_backoff_config.clone());
+    Self { deps, poll_backoff }
+  }
+}
+
+#[async_trait]
+impl PeriodicTask for VideoPollingTask {
+  fn name(&self) -> &'static str {
+    "Kinovi video polling"
+  }
+
+  async fn run_iteration(&mut self, _context: &JobRunnerContext) -> AnyhowResult<Duration> {
     let start = Instant::now();
 
     //
     // Run a single polling iteration and alert if it takes too long
     //
 
-    let result = run_poll_iteration(&job_dependencies).await;
+    let result = run_poll_iteration(&self.deps, &mut self.poll_backoff).await;
 
     let elapsed = start.elapsed();
 
-    if let Err(err) = result {
-      error!("Error in poll iteration: {:?}", err);
-      let _ = alert_pager_and_return_err::<()>(&job_dependencies.pager, "Kinovi poll iteration error", err, None);
-      let _ = job_dependencies.job_stats.increment_failure_count();
-    }
-
     if elapsed > POLL_ALERT_THRESHOLD {
       warn!("Poll iteration took {:.1}s (threshold: {}s)", elapsed.as_secs_f64(), POLL_ALERT_THRESHOLD.as_secs());
 
       let notification = NotificationDetailsBuilder::from_title(
             "Kinovi poll iteration slow".to_string())
           .set_description(Some(format!(
-            "Poll iteration took {:.1} seconds, exceeding the 10-minute threshold.",
+            "Poll iteration took {:.1} seconds, exceeding the {}-minute threshold.",
             elapsed.as_secs_f64(),
+            POLL_ALERT_THRESHOLD.as_secs() / 60,
           )))
           .set_urgency(Some(NotificationUrgency::Medium))
           .build();
 
-      if let Err(pager_err) = job_dependencies.pager.enqueue_page(notification) {
+      if let Err(pager_err) = self.deps.pager.enqueue_page(notification) {
         error!("Failed to enqueue slow iteration alert: {:?}", pager_err);
       }
     }
 
-    tokio::select! {
-      _ = tokio::time::sleep(Duration::from_millis(job_dependencies.poll_interval_millis)) => {}
-      _ = job_dependencies.shutdown_notify.notified() => {}
+    if let Err(err) = result {
+      return alert_pager_and_return_err(&self.deps.pager, "Kinovi poll iteration error", err.into(), None);
     }
+
+    Ok(Duration::from_millis(self.deps.poll_interval_millis))
   }
 
-  warn!("Kinovi job runner main loop is shut down.");
+  fn failure_delay(&self) -> Duration {
+    Duration::from_millis(self.deps.poll_interval_millis)
+  }
 }
 
-async fn run_poll_iteration(deps: &JobDependencies) -> anyhow::Result<()> {
+async fn run_poll_iteration(deps: &JobDependencies, poll_backoff: &mut PollBackoff) -> anyhow::Result<()> {
   // 1. Query all (limit 25,000) non-terminal Seedance2Pro jobs from DB.
   //    This is all non-(complete_success, complete_failure) jobs.
   let (third_party, job_type) = if deps.is_alternate_mode {
@@ -79,23 +101,47 @@ async fn run_poll_iteration(deps: &JobDependencies) -> anyhow::Result<()> {
 
   let total_pending_jobs = pending_jobs.len();
 
+  poll_backoff.retain_pending(pending_jobs.iter().map(|job| job.job_token.as_str()));
+
+  // Kinovi only lets us list orders, not poll a single one, so the whole order
+  // list is fetched whenever at least one job's backoff has elapsed.
+  let now = Instant::now();
+  let now_utc = Utc::now();
+
+  let due_job_count = pending_jobs.iter()
+    .filter(|job| poll_backoff.is_due(job.job_token.as_str(), job_age_since(job.created_at, now_utc), now))
+    .count();
+
+  let _ = deps.context.poll_stats.record_iteration(
+    total_pending_jobs,
+    due_job_count,
+    total_pending_jobs - due_job_count,
+  );
+
   if pending_jobs.is_empty() {
     info!("No pending database jobs found in the database.");
     return Ok(());
   }
 
-  info!("Found {} pending database job(s).", pending_jobs.len());
+  info!("Found {} pending database job(s), {} due for polling.", total_pending_jobs, due_job_count);
 
-  let result = website_polling_loop(&deps, pending_jobs).await?;
+  if due_job_count == 0 {
+    info!("All pending database jobs are backing off. Skipping Kinovi poll.");
+    return Ok(());
+  }
+
+  let result = website_polling_loop(&deps, poll_backoff, pending_jobs).await?;
 
   info!(
     "Database + Kinovi poll iteration complete: \
     {} total database jobs, \
     {} total Kinovi pages, \
-    {} total Kinovi orders seen.",
+    {} total Kinovi orders seen, \
+    {} unmatched database jobs expired.",
     total_pending_jobs,
     result.total_pages_seen,
-    result.total_orders_seen
+    result.total_orders_seen,
+    result.total_unmatched_expired,
   );
 
   Ok(())
@@ -104,10 +150,12 @@ async fn run_poll_iteration(deps: &JobDependencies) -> anyhow::Result<()> {
 struct WebsitePollingResult {
   total_pages_seen: u32,
   total_orders_seen: u32,
+  total_unmatched_expired: u32,
 }
 
 async fn website_polling_loop(
   deps: &&JobDependencies,
+  poll_backoff: &mut PollBackoff,
   pending_jobs: Vec<PendingSeedance2ProJob>
 ) -> anyhow::Result<WebsitePollingResult> {
 
@@ -128,9 +176,12 @@ async fn website_polling_loop(
   let mut batch_orders: Vec<OrderStatus> = Vec::new();
   let mut pages_in_current_batch: u32 = 0;
 
+  let mut interrupted = false;
+
   loop {
-    if deps.application_shutdown.get() {
+    if deps.context.shutdown.is_shutting_down() {
       info!("Shutdown requested during pagination. Stopping early.");
+      interrupted = true;
       break;
     }
 
@@ -201,6 +252,7 @@ async fn website_polling_loop(
         deps,
         &batch_orders,
         &mut job_by_order_id,
+        poll_backoff,
         pages_in_current_batch
       ).await;
 
@@ -219,9 +271,29 @@ async fn website_polling_loop(
     }
   }
 
+  // Jobs we never found an order for. Expire the ones that are out of time; the
+  // rest back off as if we'd polled them and learned nothing.
+  let mut total_unmatched_expired: u32 = 0;
+
+  if !interrupted {
+    let now_utc = Utc::now();
+    let polled_at = Instant::now();
+
+    for job in job_by_order_id.values() {
+      if poll_backoff.is_expired(job_age_since(job.created_at, now_utc)) {
+        process_expired_job(deps, job).await;
+        poll_backoff.forget(job.job_token.as_str());
+        total_unmatched_expired += 1;
+      } else {
+        poll_backoff.record_poll(job.job_token.as_str(), ObservedJobState::Unknown, polled_at);
+      }
+    }
+  }
+
   Ok(WebsitePollingResult {
     total_pages_seen,
-    total_orders_seen
+    total_orders_seen,
+    total_unmatched_expired,
   })
 }
 
@@ -235,11 +307,17 @@ async fn poll_orders_with_retry(
   let max_retries = deps.poll_max_retries;
 
   for attempt in 1..=max_retries {
-    match poll_orders(PollOrdersArgs {
+    let poll_start = Instant::now();
+
+    let result = poll_orders(PollOrdersArgs {
       session: &deps.seedance2pro_session,
       cursor,
       host_override: None,
-    }).await {
+    }).await;
+
+    let _ = deps.context.poll_stats.record_poll_latency(poll_start.elapsed());
+
+    match result {
       Ok(response) => return Ok(response),
       Err(err) => {
         warn!(
@@ -251,7 +329,7 @@ async fn poll_orders_with_retry(
           return alert_pager_and_return_err(
             &deps.pager,
             "Kinovi API polling failed after retries",
-            anyhow::anyhow!("poll_orders failed after {} attempts: {:?}", attempt, err),
+            anyhow::anyhow!("poll_orders failed after {} attempts: {:?}", attempt, err).into(),
             None,
           );
         }
@@ -265,7 +343,7 @@ async fn poll_orders_with_retry(
   alert_pager_and_return_err(
     &deps.pager,
     "Kinovi API polling failed after max retries",
-    anyhow::anyhow!("poll_orders failed after {} attempts", max_retries),
+    anyhow::anyhow!("poll_orders failed after {} attempts", max_retries).into(),
     None,
   )
 }
@@ -7,37 +7,32 @@
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
-#[macro_use] extern crate serde_derive;
-
-use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::anyhow;
-use log::{info, warn};
+use log::info;
 use sqlx::mysql::MySqlPoolOptions;
-use tokio::sync::Notify;
 
 use bootstrap::bootstrap::{bootstrap, BootstrapArgs};
 use cloud_storage::bucket_client::BucketClient;
-use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
 use shared_env_var_config::logging::DEFAULT_RUST_LOG;
 use errors::AnyhowResult;
-use jobs_common::job_stats::JobStats;
+use job_runner::config::job_runner_config::JobRunnerConfig;
+use job_runner::job_runner::JobRunner;
+use job_runner::polling::periodic_task::PeriodicTask;
+use job_runner::startup::build_pager::build_pager;
+use rootly_config::services::ROOTLY_SERVICE_ID_SEEDANCE2_PRO_JOB;
 use seedance2pro_client::creds::seedance2pro_session::Seedance2ProSession;
 use server_environment::ServerEnvironment;
 use shared_env_var_config::mysql::env_get_mysql_connection_string_or_default;
 
-use crate::http_server::run_http_server::{launch_http_server, CreateServerArgs};
-use crate::jobs::character_polling_job::character_polling_main_loop::character_polling_main_loop;
-use crate::jobs::credits_checking_job::credits_checking_main_loop::credits_checking_main_loop;
-use crate::jobs::video_polling_job::video_polling_main_loop::video_polling_main_loop;
+use crate::jobs::character_polling_job::character_polling_task::CharacterPollingTask;
+use crate::jobs::credits_checking_job::credits_checking_task::CreditsCheckingTask;
+use crate::jobs::video_polling_job::video_polling_task::VideoPollingTask;
 use crate::job_dependencies::JobDependencies;
-use crate::startup::build_pager::build_pager;
 
-pub mod http_server;
 pub mod job_dependencies;
 pub mod jobs;
-pub mod startup;
 
 // Bucket config
 const ENV_ACCESS_KEY: &str = "ACCESS_KEY";
@@ -103,7 +98,7 @@ async fn main() -> AnyhowResult<()> {
     &s3_compatible_endpoint_url,
     None,
     Some(bucket_timeout),
-  )?;
+  )?.with_env_backend_override()?;
 
   // Alternate mode: uses a different Kinovi account, different job types, skips character polling.
   let is_alternate_mode = easyenv::get_env_string_optional(ENV_SEEDANCE2PRO_ALTERNATE)
@@ -121,7 +116,7 @@ async fn main() -> AnyhowResult<()> {
   let seedance2pro_cookies = easyenv::get_env_string_required(cookie_env_var)?;
   let seedance2pro_session = Seedance2ProSession::from_cookies_string(seedance2pro_cookies);
 
-  // How often to poll for results (default: 15 seconds)
+  // How often to poll for results (default: 5 seconds)
   let poll_interval_millis: u64 = easyenv::get_env_num(
     "SEEDANCE_POLL_INTERVAL_MILLIS",
     5_000,
@@ -152,6 +147,18 @@ async fn main() -> AnyhowResult<()> {
 
   info!("Poll max retries: {}, max retry delay: {}ms", poll_max_retries, poll_retry_max_delay_millis);
 
+  let mut runner_config = JobRunnerConfig::with_defaults("seedance2-pro-job");
+  runner_config.poll_interval_success = Duration::from_millis(poll_interval_millis);
+  runner_config.poll_interval_failure = Duration::from_millis(poll_interval_millis);
+  // Don't poll a job until it's this old, and start backing off from here.
+  runner_config.poll_backoff.initial_interval = Duration::from_secs(15);
+  // Fail and refund jobs that haven't finished after this long.
+  runner_config.poll_backoff.max_job_lifetime = Duration::from_secs(60 * 60 * 6);
+
+  let runner_config = runner_config.with_env_overrides("SEEDANCE")?;
+
+  info!("Max concurrent polls: {}, poll backoff: {:?}", runner_config.max_concurrent_polls, runner_config.poll_backoff);
+
   let credits_alert_threshold: u64 = easyenv::get_env_num(
     "CREDITS_ALERT_THRESHOLD",
     10_000,
@@ -159,29 +166,14 @@ async fn main() -> AnyhowResult<()> {
 
   info!("Credits alert threshold: {}", credits_alert_threshold);
 
-  let (pager, pager_worker) = build_pager(server_environment, &container_environment.hostname);
-
-  info!("Spawning pager worker.");
-
-  // NB: The pager worker uses Condvar::wait() which is a blocking syscall.
-  // It must run on a dedicated OS thread, not a tokio task, to avoid blocking
-  // the tokio runtime.
-  std::thread::spawn(move || {
-    let rt = tokio::runtime::Runtime::new().expect("pager worker tokio runtime");
-    rt.block_on(pager_worker.run());
-  });
-
-  let application_shutdown = RelaxedAtomicBool::new(false);
-  let shutdown_notify = Arc::new(Notify::new());
-  let job_stats = JobStats::new();
-
-  let pager_for_shutdown = pager.clone();
+  let (pager, pager_worker) = build_pager(
+    "seedance2-pro-job",
+    ROOTLY_SERVICE_ID_SEEDANCE2_PRO_JOB,
+    server_environment,
+    &container_environment.hostname,
+  );
 
-  let create_server_args = CreateServerArgs {
-    container_environment: container_environment.clone(),
-    job_stats: job_stats.clone(),
-    pager: pager.clone(),
-  };
+  let runner = JobRunner::new(runner_config, pager, pager_worker);
 
   let job_dependencies = JobDependencies {
     mysql_pool,
@@ -189,74 +181,31 @@ async fn main() -> AnyhowResult<()> {
     seedance2pro_session,
     is_alternate_mode,
     server_environment,
-    job_stats,
+    context: runner.context().clone(),
     poll_interval_millis,
     maybe_pages_per_batch,
     maybe_max_job_age,
     poll_max_retries,
     poll_retry_max_delay_millis,
+    poll_backoff_config: runner.config().poll_backoff.clone(),
+    max_concurrent_polls: runner.config().max_concurrent_polls,
     credits_alert_threshold,
-    application_shutdown: application_shutdown.clone(),
-    shutdown_notify: shutdown_notify.clone(),
-    pager,
+    pager: runner.pager().clone(),
   };
 
-  // HTTP server runs on a separate OS thread with its own actix System.
-  std::thread::spawn(move || {
-    let actix_runtime = actix_web::rt::System::new();
-    let http_server_handle = launch_http_server(create_server_args);
-
-    actix_runtime.block_on(http_server_handle)
-      .expect("HTTP server should not exit.");
-
-    warn!("HTTP server thread is shut down.");
-  });
-
-  // Listen for SIGTERM / Ctrl-C to trigger graceful shutdown.
-  let application_shutdown_for_signal = application_shutdown.clone();
-  let shutdown_notify_for_signal = shutdown_notify.clone();
-
-  tokio::spawn(async move {
-    match tokio::signal::ctrl_c().await {
-      Ok(()) => {
-        info!("Received shutdown signal. Shutting down...");
-        application_shutdown_for_signal.set(true);
-        shutdown_notify_for_signal.notify_waiters();
-      }
-      Err(err) => {
-        warn!("Error listening for shutdown signal: {:?}", err);
-      }
-    }
-  });
-
-  // Spawn all polling loops as concurrent tasks.
-  let video_deps = job_dependencies.clone();
-  let credits_deps = job_dependencies.clone();
-
-  let video_handle = tokio::spawn(async move {
-    video_polling_main_loop(video_deps).await;
-  });
-
-  let credits_handle = tokio::spawn(async move {
-    credits_checking_main_loop(credits_deps).await;
-  });
+  let mut tasks: Vec<Box<dyn PeriodicTask>> = vec![
+    Box::new(VideoPollingTask::new(job_dependencies.clone())),
+    Box::new(CreditsCheckingTask::new(job_dependencies.clone())),
+  ];
 
   if is_alternate_mode {
     // Alternate mode: skip character polling entirely.
     info!("Alternate mode: character polling is disabled.");
-    let _ = tokio::join!(video_handle, credits_handle);
   } else {
-    let character_deps = job_dependencies;
-    let character_handle = tokio::spawn(async move {
-      character_polling_main_loop(character_deps).await;
-    });
-    let _ = tokio::join!(video_handle, character_handle, credits_handle);
+    tasks.push(Box::new(CharacterPollingTask::new(job_dependencies)));
   }
 
-  info!("Shutting down pager worker...");
-  pager_for_shutdown.shutdown_worker();
-
-  info!("Seedance2Pro job exiting.");
+  runner.run_periodic_tasks(tasks).await?;
 
   Ok(())
 }
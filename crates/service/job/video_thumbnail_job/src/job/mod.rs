@@ -1,3 +1,2 @@
-pub mod alert_on_error;
-pub mod main_loop;
 pub mod process_single_media_file;
+pub mod thumbnail_backfill_task;
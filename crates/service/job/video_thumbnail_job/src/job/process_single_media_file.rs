@@ -1,16 +1,17 @@
 use std::path::PathBuf;
 
-use log::{error, info};
+use log::{error, info, warn};
 use tempdir::TempDir;
 
 use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path::MediaFileBucketPath;
 use bucket_paths::path_conventions::video_thumbnail_suffixes::{CURRENT_VIDEO_THUMBNAIL_VERSION, VIDEO_ANIMATED_GIF_THUMBNAIL_SUFFIX, VIDEO_STATIC_JPG_THUMBNAIL_SUFFIX};
 use ffmpeg_utils::ffmpeg::ffmpeg_video_first_frame_to_jpg_thumbnail::{ffmpeg_video_first_frame_to_jpg_thumbnail, FfmpegVideoFirstFrameToJpgThumbnailArgs};
 use ffmpeg_utils::ffmpeg::ffmpeg_video_gif_preview::{ffmpeg_video_gif_preview, FfmpegVideoGifPreviewArgs};
+use media::probe::probe_media::probe_media_file;
+use job_runner::alert_on_error::alert_pager_and_return_err;
 use mysql_queries::queries::media_files::thumbnails::list_video_media_files_without_thumbnails_for_job::VideoMediaFileWithoutThumbnail;
 use mysql_queries::queries::media_files::thumbnails::update_video_media_file_with_thumbnail::update_video_media_file_with_thumbnail;
 
-use crate::job::alert_on_error::alert_pager_and_return_err;
 use crate::job_dependencies::JobDependencies;
 
 /// A downloaded video file alongside its owning temp directory.
@@ -37,7 +38,7 @@ pub async fn process_single_media_file(
     Ok(d) => d,
     Err(err) => {
       error!("Failed to download video for {}: {:?}", media_file.token.as_str(), err);
-      return alert_pager_and_return_err(&deps.pager, "Video download failed", err);
+      return alert_pager_and_return_err(&deps.pager, "Video download failed", err.into(), None);
     }
   };
 
@@ -47,6 +48,28 @@ pub async fn process_single_media_file(
     media_file.token,
   );
 
+  // Catch files ffmpeg can't thumbnail before shelling out. If we can't probe the container
+  // natively, we leave it to ffmpeg to decide.
+  match probe_media_file(&downloaded.file_path) {
+    Ok(probe) => {
+      info!(
+        "Probed {}: container = {}, duration = {:?} ms, dimensions = {:?}, fps = {:?}",
+        media_file.token.as_str(),
+        probe.container.as_str(),
+        probe.maybe_duration_millis,
+        probe.maybe_display_dimensions(),
+        probe.maybe_frame_rate(),
+      );
+      if !probe.has_video() {
+        // NB: Bad user data, not an outage, so we don't page.
+        return Err(anyhow::anyhow!("media file {} has no video track", media_file.token.as_str()));
+      }
+    }
+    Err(err) => {
+      warn!("Could not probe video for {}: {:?}", media_file.token.as_str(), err);
+    }
+  }
+
   // Upload thumbnails to bucket beside the original video
   let video_object_path = get_video_object_path(media_file);
 
@@ -60,7 +83,7 @@ pub async fn process_single_media_file(
     },
   ) {
     error!("Failed to generate JPG thumbnail for {}: {:?}", media_file.token.as_str(), err);
-    return alert_pager_and_return_err(&deps.pager, "JPG thumbnail generation failed", err.into());
+    return alert_pager_and_return_err(&deps.pager, "JPG thumbnail generation failed", err.into(), None);
   }
 
   info!("Generated JPG thumbnail for {}", media_file.token.as_str());
@@ -69,7 +92,7 @@ pub async fn process_single_media_file(
 
   if let Err(err) = deps.public_bucket_client.upload_filename(&jpg_object_path, &jpg_path).await {
     error!("Failed to upload JPG thumbnail for {}: {:?}", media_file.token.as_str(), err);
-    return alert_pager_and_return_err(&deps.pager, "JPG thumbnail upload failed", err);
+    return alert_pager_and_return_err(&deps.pager, "JPG thumbnail upload failed", err.into(), None);
   }
 
   info!("Uploaded JPG thumbnail to {}", jpg_object_path);
@@ -84,7 +107,7 @@ pub async fn process_single_media_file(
     },
   ) {
     error!("Failed to generate GIF preview for {}: {:?}", media_file.token.as_str(), err);
-    return alert_pager_and_return_err(&deps.pager, "GIF preview generation failed", err.into());
+    return alert_pager_and_return_err(&deps.pager, "GIF preview generation failed", err.into(), None);
   }
 
   info!("Generated GIF preview for {}", media_file.token.as_str());
@@ -93,7 +116,7 @@ pub async fn process_single_media_file(
 
   if let Err(err) = deps.public_bucket_client.upload_filename(&gif_object_path, &gif_path).await {
     error!("Failed to upload GIF preview for {}: {:?}", media_file.token.as_str(), err);
-    return alert_pager_and_return_err(&deps.pager, "GIF preview upload failed", err);
+    return alert_pager_and_return_err(&deps.pager, "GIF preview upload failed", err.into(), None);
   }
 
   info!("Uploaded GIF preview to {}", gif_object_path);
@@ -107,7 +130,7 @@ pub async fn process_single_media_file(
     &deps.mysql_pool,
   ).await {
     error!("Failed to update thumbnail version for {}: {:?}", media_file.token.as_str(), err);
-    return alert_pager_and_return_err(&deps.pager, "Thumbnail DB update failed", err.into());
+    return alert_pager_and_return_err(&deps.pager, "Thumbnail DB update failed", err.into(), None);
   }
 
   info!(
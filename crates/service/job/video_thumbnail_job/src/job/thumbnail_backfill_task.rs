@@ -1,43 +1,57 @@
 use std::time::Duration;
 
-use log::{error, info, warn};
+use async_trait::async_trait;
+use log::{info, warn};
 
+use errors::AnyhowResult;
+use job_runner::alert_on_error::alert_pager_and_return_err;
+use job_runner::job_runner::JobRunnerContext;
+use job_runner::polling::periodic_task::PeriodicTask;
 use mysql_queries::queries::media_files::thumbnails::list_video_media_files_without_thumbnails_for_job::{
   list_video_media_files_without_thumbnails_for_job,
   ListVideoMediaFilesWithoutThumbnailsArgs,
 };
 
-use crate::job::alert_on_error::alert_pager_and_return_err;
 use crate::job_dependencies::JobDependencies;
 use crate::job::process_single_media_file::process_single_media_file;
 
-pub async fn main_loop(deps: JobDependencies) {
-  while !deps.application_shutdown.get() {
-    let processed_any = match run_batch_cycle(&deps).await {
-      Ok(count) => {
-        if count > 0 {
-          info!("Processed {} video thumbnail(s) this cycle.", count);
-        }
-        count > 0
-      }
-      Err(err) => {
-        error!("Error in video thumbnail batch cycle: {:?}", err);
-        let _ = alert_pager_and_return_err::<()>(&deps.pager, "Video thumbnail batch cycle error", err);
-        let _ = deps.job_stats.increment_failure_count();
-
-        // Wait before retrying after a failure.
-        tokio::time::sleep(Duration::from_millis(deps.query_failure_retry_delay_millis)).await;
-        false
-      }
+/// Generates thumbnails for video media files that don't have them yet.
+pub struct ThumbnailBackfillTask {
+  deps: JobDependencies,
+}
+
+impl ThumbnailBackfillTask {
+  pub fn new(deps: JobDependencies) -> Self {
+    Self { deps }
+  }
+}
+
+#[async_trait]
+impl PeriodicTask for ThumbnailBackfillTask {
+  fn name(&self) -> &'static str {
+    "Video thumbnail backfill"
+  }
+
+  async fn run_iteration(&mut self, _context: &JobRunnerContext) -> AnyhowResult<Duration> {
+    let count = match run_batch_cycle(&self.deps).await {
+      Ok(count) => count,
+      Err(err) => return alert_pager_and_return_err(&self.deps.pager, "Video thumbnail batch cycle error", err.into(), None),
     };
 
-    // If we didn't process anything, sleep for the poll interval before checking again.
-    if !processed_any {
-      tokio::time::sleep(Duration::from_millis(deps.poll_interval_millis)).await;
+    if count == 0 {
+      // Nothing to do, so sleep for the poll interval before checking again.
+      return Ok(Duration::from_millis(self.deps.poll_interval_millis));
     }
+
+    info!("Processed {} video thumbnail(s) this cycle.", count);
+
+    // There may be more work, so go again right away.
+    Ok(Duration::ZERO)
   }
 
-  warn!("Video thumbnail job main loop is shut down.");
+  fn failure_delay(&self) -> Duration {
+    Duration::from_millis(self.deps.query_failure_retry_delay_millis)
+  }
 }
 
 /// Run one full pagination cycle: keep querying pages of media files without thumbnails
@@ -47,7 +61,7 @@ async fn run_batch_cycle(deps: &JobDependencies) -> anyhow::Result<u64> {
   let mut total_processed: u64 = 0;
 
   loop {
-    if deps.application_shutdown.get() {
+    if deps.context.shutdown.is_shutting_down() {
       break;
     }
 
@@ -65,7 +79,7 @@ async fn run_batch_cycle(deps: &JobDependencies) -> anyhow::Result<u64> {
     }
 
     for media_file in &result.media_files {
-      if deps.application_shutdown.get() {
+      if deps.context.shutdown.is_shutting_down() {
         break;
       }
 
@@ -79,7 +93,7 @@ async fn run_batch_cycle(deps: &JobDependencies) -> anyhow::Result<u64> {
 
       match process_single_media_file(deps, media_file).await {
         Ok(()) => {
-          let _ = deps.job_stats.increment_success_count();
+          let _ = deps.context.job_stats.increment_success_count();
           total_processed += 1;
         }
         Err(err) => {
@@ -88,7 +102,7 @@ async fn run_batch_cycle(deps: &JobDependencies) -> anyhow::Result<u64> {
             media_file.token.as_str(),
             err,
           );
-          let _ = deps.job_stats.increment_failure_count();
+          let _ = deps.context.job_stats.increment_failure_count();
         }
       }
     }
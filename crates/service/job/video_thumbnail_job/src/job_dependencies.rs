@@ -1,8 +1,7 @@
 use std::path::PathBuf;
 
 use cloud_storage::bucket_client::BucketClient;
-use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
-use jobs_common::job_stats::JobStats;
+use job_runner::job_runner::JobRunnerContext;
 use pager::client::pager::Pager;
 use server_environment::ServerEnvironment;
 use sqlx::MySqlPool;
@@ -21,7 +20,8 @@ pub struct JobDependencies {
 
   pub server_environment: ServerEnvironment,
 
-  pub job_stats: JobStats,
+  /// Job stats (reported on the health check) and the shutdown signal.
+  pub context: JobRunnerContext,
 
   /// How long to sleep between poll iterations when there is no work (milliseconds).
   pub poll_interval_millis: u64,
@@ -44,9 +44,6 @@ pub struct JobDependencies {
   /// If present, only process media files where `id % number_of_shards == shard_index`.
   pub shard_info: Option<ShardInfo>,
 
-  /// Set to `true` from another thread to trigger graceful shutdown.
-  pub application_shutdown: RelaxedAtomicBool,
-
   /// Pager client for sending alerts.
   pub pager: Pager,
 }
@@ -7,33 +7,29 @@
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
-#[macro_use] extern crate serde_derive;
-
 use std::path::PathBuf;
 use std::time::Duration;
 
 use anyhow::anyhow;
-use log::{info, warn};
+use log::info;
 use sqlx::mysql::MySqlPoolOptions;
 
 use bootstrap::bootstrap::{bootstrap, BootstrapArgs};
 use cloud_storage::bucket_client::BucketClient;
-use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
 use shared_env_var_config::logging::DEFAULT_RUST_LOG;
 use errors::AnyhowResult;
-use jobs_common::job_stats::JobStats;
+use job_runner::config::job_runner_config::JobRunnerConfig;
+use job_runner::job_runner::JobRunner;
+use job_runner::startup::build_pager::build_pager;
+use rootly_config::services::ROOTLY_SERVICE_ID_VIDEO_THUMBNAIL_JOB;
 use server_environment::ServerEnvironment;
 use shared_env_var_config::mysql::env_get_mysql_connection_string_or_default;
 
-use crate::http_server::run_http_server::{launch_http_server, CreateServerArgs};
-use crate::job::main_loop::main_loop;
+use crate::job::thumbnail_backfill_task::ThumbnailBackfillTask;
 use crate::job_dependencies::{JobDependencies, ShardInfo};
-use crate::startup::build_pager::build_pager;
 
-pub mod http_server;
 pub mod job;
 pub mod job_dependencies;
-pub mod startup;
 
 // Bucket config
 const ENV_ACCESS_KEY: &str = "ACCESS_KEY";
@@ -93,7 +89,7 @@ async fn main() -> AnyhowResult<()> {
     &s3_compatible_endpoint_url,
     None,
     Some(bucket_timeout),
-  )?;
+  )?.with_env_backend_override()?;
 
   // Job polling and timing configuration
   let poll_interval_millis: u64 = easyenv::get_env_num(
@@ -146,34 +142,24 @@ async fn main() -> AnyhowResult<()> {
     }
   };
 
-  let (pager, pager_worker) = build_pager(server_environment, &container_environment.hostname);
-
-  info!("Spawning pager worker.");
+  // NB: The runner's poll settings don't apply here; the backfill task sleeps on its own schedule.
+  let runner_config = JobRunnerConfig::with_defaults("video-thumbnail-job")
+    .with_env_overrides("VIDEO_THUMBNAIL")?;
 
-  // NB: The pager worker uses Condvar::wait() which is a blocking syscall.
-  // It must run on a dedicated OS thread, not a tokio task, to avoid blocking
-  // the tokio runtime.
-  std::thread::spawn(move || {
-    let rt = tokio::runtime::Runtime::new().expect("pager worker tokio runtime");
-    rt.block_on(pager_worker.run());
-  });
-
-  let application_shutdown = RelaxedAtomicBool::new(false);
-  let job_stats = JobStats::new();
-
-  let pager_for_shutdown = pager.clone();
+  let (pager, pager_worker) = build_pager(
+    "video-thumbnail-job",
+    ROOTLY_SERVICE_ID_VIDEO_THUMBNAIL_JOB,
+    server_environment,
+    &container_environment.hostname,
+  );
 
-  let create_server_args = CreateServerArgs {
-    container_environment: container_environment.clone(),
-    job_stats: job_stats.clone(),
-    pager: pager.clone(),
-  };
+  let runner = JobRunner::new(runner_config, pager, pager_worker);
 
   let job_dependencies = JobDependencies {
     mysql_pool,
     public_bucket_client,
     server_environment,
-    job_stats,
+    context: runner.context().clone(),
     poll_interval_millis,
     query_delay_millis,
     query_failure_retry_delay_millis,
@@ -181,42 +167,10 @@ async fn main() -> AnyhowResult<()> {
     custom_page_size,
     temp_dir,
     shard_info,
-    application_shutdown: application_shutdown.clone(),
-    pager,
+    pager: runner.pager().clone(),
   };
 
-  // HTTP server runs on a separate OS thread with its own actix System.
-  std::thread::spawn(move || {
-    let actix_runtime = actix_web::rt::System::new();
-    let http_server_handle = launch_http_server(create_server_args);
-
-    actix_runtime.block_on(http_server_handle)
-      .expect("HTTP server should not exit.");
-
-    warn!("HTTP server thread is shut down.");
-  });
-
-  // Listen for SIGTERM / Ctrl-C to trigger graceful shutdown.
-  let application_shutdown_for_signal = application_shutdown.clone();
-
-  tokio::spawn(async move {
-    match tokio::signal::ctrl_c().await {
-      Ok(()) => {
-        info!("Received shutdown signal. Shutting down...");
-        application_shutdown_for_signal.set(true);
-      }
-      Err(err) => {
-        warn!("Error listening for shutdown signal: {:?}", err);
-      }
-    }
-  });
-
-  main_loop(job_dependencies).await;
-
-  info!("Shutting down pager worker...");
-  pager_for_shutdown.shutdown_worker();
-
-  info!("Video thumbnail job exiting.");
+  runner.run_periodic_tasks(vec![Box::new(ThumbnailBackfillTask::new(job_dependencies))]).await?;
 
   Ok(())
 }
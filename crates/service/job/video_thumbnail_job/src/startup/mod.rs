@@ -1 +0,0 @@
-pub mod build_pager;
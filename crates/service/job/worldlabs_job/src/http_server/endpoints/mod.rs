@@ -1 +0,0 @@
-pub mod health_check_handler;
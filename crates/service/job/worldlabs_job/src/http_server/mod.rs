@@ -1,3 +0,0 @@
-pub mod endpoints;
-pub mod http_server_shared_state;
-pub mod run_http_server;
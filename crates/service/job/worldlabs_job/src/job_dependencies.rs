@@ -1,6 +1,5 @@
 use cloud_storage::bucket_client::BucketClient;
-use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
-use jobs_common::job_stats::JobStats;
+use pager::client::pager::Pager;
 use server_environment::ServerEnvironment;
 use sqlx::MySqlPool;
 use worldlabs_api_client::credentials::world_labs_api_creds::WorldLabsApiCreds;
@@ -16,11 +15,5 @@ pub struct JobDependencies {
 
   pub server_environment: ServerEnvironment,
 
-  pub job_stats: JobStats,
-
-  /// How long to sleep between poll iterations (milliseconds).
-  pub poll_interval_millis: u64,
-
-  /// Set to `true` from another thread to trigger graceful shutdown.
-  pub application_shutdown: RelaxedAtomicBool,
+  pub pager: Pager,
 }
@@ -7,32 +7,30 @@
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
-#[macro_use] extern crate serde_derive;
-
 use std::time::Duration;
 
 use anyhow::anyhow;
-use log::{info, warn};
+use log::info;
 use sqlx::mysql::MySqlPoolOptions;
 
 use bootstrap::bootstrap::{bootstrap, BootstrapArgs};
 use cloud_storage::bucket_client::BucketClient;
-use concurrency::relaxed_atomic_bool::RelaxedAtomicBool;
 use shared_env_var_config::logging::DEFAULT_RUST_LOG;
 use errors::AnyhowResult;
-use jobs_common::job_stats::JobStats;
+use job_runner::config::job_runner_config::JobRunnerConfig;
+use job_runner::job_runner::JobRunner;
+use job_runner::startup::build_pager::build_pager;
+use rootly_config::services::ROOTLY_SERVICE_ID_SEEDANCE2_PRO_JOB;
 use server_environment::ServerEnvironment;
 use shared_env_var_config::mysql::env_get_mysql_connection_string_or_default;
 use worldlabs_api_client::credentials::world_labs_api_creds::WorldLabsApiCreds;
 
-use crate::http_server::run_http_server::{launch_http_server, CreateServerArgs};
-use crate::main_loop::main_loop;
 use crate::job_dependencies::JobDependencies;
+use crate::worldlabs_polling_job::WorldlabsPollingJob;
 
-pub mod http_server;
 pub mod job_dependencies;
-pub mod main_loop;
 pub mod process_job;
+pub mod worldlabs_polling_job;
 
 // Bucket config
 const ENV_ACCESS_KEY: &str = "ACCESS_KEY";
@@ -93,48 +91,45 @@ async fn main() -> AnyhowResult<()> {
     &s3_compatible_endpoint_url,
     None,
     Some(bucket_timeout),
-  )?;
+  )?.with_env_backend_override()?;
 
   // World Labs API credentials
   let worldlabs_api_key = easyenv::get_env_string_required(ENV_WORLDLABS_API_KEY)?;
   let worldlabs_creds = WorldLabsApiCreds::new(worldlabs_api_key);
 
   // How often to poll for results (default: 15 seconds)
-  let poll_interval_millis: u64 = easyenv::get_env_num(
+  let poll_interval = Duration::from_millis(easyenv::get_env_num(
     "WORLDLABS_POLL_INTERVAL_MILLIS",
     15_000,
-  )?;
+  )?);
 
-  let application_shutdown = RelaxedAtomicBool::new(false);
-  let job_stats = JobStats::new();
+  let mut runner_config = JobRunnerConfig::with_defaults("worldlabs-job");
+  runner_config.poll_interval_success = poll_interval;
+  runner_config.poll_interval_failure = poll_interval;
+  runner_config.poll_backoff.initial_interval = Duration::from_secs(15);
 
-  let create_server_args = CreateServerArgs {
-    container_environment: container_environment.clone(),
-    job_stats: job_stats.clone(),
-  };
+  let runner_config = runner_config.with_env_overrides("WORLDLABS")?;
 
-  let job_dependencies = JobDependencies {
-    mysql_pool,
-    public_bucket_client,
-    worldlabs_creds,
+  let (pager, pager_worker) = build_pager(
+    "worldlabs-job",
+    ROOTLY_SERVICE_ID_SEEDANCE2_PRO_JOB,
     server_environment,
-    job_stats,
-    poll_interval_millis,
-    application_shutdown: application_shutdown.clone(),
-  };
-
-  std::thread::spawn(move || {
-    let actix_runtime = actix_web::rt::System::new();
-    let http_server_handle = launch_http_server(create_server_args);
+    &container_environment.hostname,
+  );
 
-    actix_runtime.block_on(http_server_handle)
-      .expect("HTTP server should not exit.");
+  let runner = JobRunner::new(runner_config, pager, pager_worker);
 
-    warn!("Server thread is shut down.");
-    application_shutdown.set(true);
-  });
+  let job = WorldlabsPollingJob {
+    deps: JobDependencies {
+      mysql_pool,
+      public_bucket_client,
+      worldlabs_creds,
+      server_environment,
+      pager: runner.pager().clone(),
+    },
+  };
 
-  main_loop(job_dependencies).await;
+  runner.run(job).await?;
 
   Ok(())
 }
@@ -1,81 +1,44 @@
-use log::{error, info, warn};
+use log::{error, warn};
 
 use enums::by_table::generic_inference_jobs::frontend_failure_category::FrontendFailureCategory;
+use jobs_common::refund_failed_job::refund_failed_job;
 use mysql_queries::queries::generic_inference::job::mark_job_failed_by_token::{mark_job_failed_by_token, MarkJobFailedByTokenArgs};
-use mysql_queries::queries::generic_inference::worldlabs::list_pending_worldlabs_jobs::PendingWorldlabsJob;
-use mysql_queries::queries::wallets::refund::try_to_refund_ledger_entry::{try_to_refund_ledger_entry, WalletRefundOutcome};
+use pager::notification::notification_details_builder::NotificationDetailsBuilder;
+use pager::notification::notification_urgency::NotificationUrgency;
 
 use crate::job_dependencies::JobDependencies;
+use crate::worldlabs_polling_job::WorldlabsJob;
 
 pub async fn process_failed_job(
   deps: &JobDependencies,
-  job: &PendingWorldlabsJob,
+  job: &WorldlabsJob,
   reason: &str,
 ) {
-  // --- Step 1: Attempt the refund before touching the job status. ---
-  //
-  // We do this first so that a crash or error between the refund and the status update
-  // can never result in a failed job with no refund. If the refund itself fails we bail
-  // out early: the job stays pending and will be retried on the next poll cycle.
+  // --- Step 1: Refund before touching the job status; on a transient error the job is retried next poll. ---
 
-  match &job.maybe_wallet_ledger_entry_token {
-    None => {
-      warn!(
-        "Job {} has no wallet ledger entry token; skipping refund.",
-        job.job_token.as_str()
-      );
+  let refund_result = refund_failed_job(
+    &deps.mysql_pool,
+    &job.job_token,
+    job.maybe_wallet_ledger_entry_token.as_ref(),
+  ).await;
+
+  if let Err(err) = refund_result {
+    if err.is_transient() {
+      return;
     }
-    Some(ledger_token) => {
-      let mut transaction = match deps.mysql_pool.begin().await {
-        Ok(tx) => tx,
-        Err(err) => {
-          error!(
-            "Failed to begin refund transaction for job {} (ledger {}): {:?}. \
-             Job will NOT be marked failed yet and will be retried next poll.",
-            job.job_token.as_str(), ledger_token.as_str(), err
-          );
-          return;
-        }
-      };
 
-      match try_to_refund_ledger_entry(ledger_token, &mut transaction).await {
-        Ok(WalletRefundOutcome::Refunded(summary)) => {
-          info!(
-            "Refunded {} credits for failed job {} (ledger {} → refund ledger {}).",
-            summary.refund_amount,
-            job.job_token.as_str(),
-            ledger_token.as_str(),
-            summary.refund_ledger_entry_token.as_str(),
-          );
-          if let Err(err) = transaction.commit().await {
-            error!(
-              "Failed to commit refund transaction for job {} (ledger {}): {:?}. \
-               Job will NOT be marked failed yet and will be retried next poll.",
-              job.job_token.as_str(), ledger_token.as_str(), err
-            );
-            return;
-          }
-        }
-        Ok(WalletRefundOutcome::AlreadyRefunded) => {
-          info!(
-            "Ledger entry {} for job {} was already refunded; proceeding to mark job failed.",
-            ledger_token.as_str(),
-            job.job_token.as_str(),
-          );
-          let _ = transaction.rollback().await;
-        }
-        Err(err) => {
-          error!(
-            "Failed to refund ledger entry {} for job {}: {:?}. \
-             Job will NOT be marked failed yet and will be retried next poll.",
-            ledger_token.as_str(),
-            job.job_token.as_str(),
-            err,
-          );
-          let _ = transaction.rollback().await;
-          return;
-        }
-      }
+    // NB: The refund will never succeed, so alert and mark the job failed anyway. Otherwise the
+    // job is re-polled (and re-refunded) forever.
+    let notification = NotificationDetailsBuilder::from_boxed_error(err.into())
+        .set_title("Worldlabs refund failed permanently".to_string())
+        .set_inference_job_token(Some(job.job_token.to_string()))
+        .set_third_party_id(Some(job.operation_id.to_string()))
+        .set_user_token(job.maybe_creator_user_token.as_ref().map(|t| t.to_string()))
+        .set_urgency(Some(NotificationUrgency::Medium))
+        .build();
+
+    if let Err(pager_err) = deps.pager.enqueue_page(notification) {
+      error!("Failed to enqueue pager alert: {:?}", pager_err);
     }
   }
 
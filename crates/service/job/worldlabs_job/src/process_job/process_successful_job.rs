@@ -9,12 +9,15 @@ use enums::by_table::media_files::media_file_origin_product_category::MediaFileO
 use enums::by_table::media_files::media_file_type::MediaFileType;
 use errors::AnyhowResult;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
-use mysql_queries::queries::generic_inference::worldlabs::list_pending_worldlabs_jobs::PendingWorldlabsJob;
 use mysql_queries::queries::media_files::create::insert_builder::media_file_insert_builder::MediaFileInsertBuilder;
 use mysql_queries::queries::generic_inference::web::mark_generic_inference_job_successfully_done_by_token::mark_generic_inference_job_successfully_done_by_token;
+use splats::ingest_splat::{ingest_splat, IngestedSplat, PREVIEW_MIME_TYPE, SPZ_MIME_TYPE};
+use splats::splat_format::SplatFormat;
 use tokens::tokens::media_files::MediaFileToken;
 use worldlabs_api_client::api::requests::get_operation::get_operation::GetOperationResponse;
 use crate::job_dependencies::JobDependencies;
+use crate::process_job::process_failed_job::process_failed_job;
+use crate::worldlabs_polling_job::WorldlabsJob;
 
 const SPLAT_PREFIX: &str = "artcraft_";
 const SPLAT_SUFFIX: &str = ".ceramic.spz"; // NB: "ceramic" triggers some heuristics in Artcraft, such as flipping the model upside down
@@ -22,10 +25,10 @@ const SPLAT_SUFFIX: &str = ".ceramic.spz"; // NB: "ceramic" triggers some heuris
 const THUMB_PREFIX: &str = "artcraft_";
 const THUMB_SUFFIX: &str = ".png";
 
-/// Download the completed splat, upload to bucket, create media file record, and mark job done.
+/// Download and validate the completed splat, upload to bucket, create media file record, and mark job done.
 pub async fn process_successful_job(
   deps: &JobDependencies,
-  job: &PendingWorldlabsJob,
+  job: &WorldlabsJob,
   operation: &GetOperationResponse,
 ) -> AnyhowResult<()> {
   // Get the full-res splat URL from the completed world.
@@ -40,28 +43,7 @@ pub async fn process_successful_job(
       operation.operation_id
     ))?;
 
-  // --- Step 1: Download and upload the thumbnail as a cover image. ---
-
-  let maybe_cover_token = match world.assets.as_ref().and_then(|a| a.thumbnail_url.as_ref()) {
-    Some(thumbnail_url) => {
-      match download_and_upload_thumbnail(deps, job, operation, thumbnail_url).await {
-        Ok(token) => Some(token),
-        Err(err) => {
-          warn!(
-            "Failed to create thumbnail cover image for operation {}: {:?}. Continuing without cover.",
-            operation.operation_id, err
-          );
-          None
-        }
-      }
-    }
-    None => {
-      info!("No thumbnail_url for operation {}; skipping cover image.", operation.operation_id);
-      None
-    }
-  };
-
-  // --- Step 2: Download and upload the splat. ---
+  // --- Step 1: Download and validate the splat. ---
 
   info!(
     "Downloading splat for operation {} from: {}",
@@ -83,6 +65,55 @@ pub async fn process_successful_job(
     operation.operation_id
   );
 
+  // Same validation as user uploads. NB: This walks every gaussian, so keep it off the runtime.
+  let ingest_result = tokio::task::spawn_blocking(move || ingest_splat(splat_bytes, SplatFormat::Spz))
+    .await
+    .map_err(|err| anyhow!("splat ingestion task error: {:?}", err))?;
+
+  let ingested = match ingest_result {
+    Ok(ingested) => ingested,
+    Err(err) if err.is_invalid_input() => {
+      // Retrying won't fix a bad file, so fail (and refund) the job instead.
+      warn!(
+        "Operation {} produced an invalid splat: {}. Failing job {}.",
+        operation.operation_id, err, job.job_token.as_str()
+      );
+      process_failed_job(deps, job, &format!("invalid splat: {}", err)).await;
+      return Ok(());
+    }
+    Err(err) => return Err(anyhow!("error ingesting splat: {:?}", err)),
+  };
+
+  info!(
+    "Validated splat for operation {}: {:?}",
+    operation.operation_id, ingested.metadata
+  );
+
+  // --- Step 2: Upload a cover image. ---
+
+  let maybe_cover_token = match world.assets.as_ref().and_then(|a| a.thumbnail_url.as_ref()) {
+    Some(thumbnail_url) => {
+      match download_and_upload_thumbnail(deps, job, operation, thumbnail_url).await {
+        Ok(token) => Some(token),
+        Err(err) => {
+          warn!(
+            "Failed to create thumbnail cover image for operation {}: {:?}. Using splat preview instead.",
+            operation.operation_id, err
+          );
+          upload_preview_cover_image(deps, job, operation, &ingested).await
+        }
+      }
+    }
+    None => {
+      info!("No thumbnail_url for operation {}; using splat preview as cover.", operation.operation_id);
+      upload_preview_cover_image(deps, job, operation, &ingested).await
+    }
+  };
+
+  // --- Step 3: Upload the splat. ---
+
+  let splat_bytes = ingested.spz_bytes;
+
   // Hash the splat.
   let checksum = sha256_hash_bytes(&splat_bytes)
     .map_err(|err| anyhow!("error hashing splat: {:?}", err))?;
@@ -100,7 +131,7 @@ pub async fn process_successful_job(
   // Upload to public bucket.
   deps
     .public_bucket_client
-    .upload_file_with_content_type_process(object_path, &splat_bytes, "application/gzip")
+    .upload_file_with_content_type_process(object_path, &splat_bytes, SPZ_MIME_TYPE)
     .await
     .map_err(|err| anyhow!("error uploading splat to bucket: {:?}", err))?;
 
@@ -119,7 +150,7 @@ pub async fn process_successful_job(
     .media_file_type(MediaFileType::Spz)
     .media_file_origin_category(MediaFileOriginCategory::Inference)
     .media_file_origin_product_category(MediaFileOriginProductCategory::WorldGeneration)
-    .mime_type("application/gzip")
+    .mime_type(SPZ_MIME_TYPE)
     .file_size_bytes(splat_bytes.len() as u64)
     .checksum_sha2(&checksum)
     .maybe_prompt_token(job.maybe_prompt_token.as_ref())
@@ -181,7 +212,7 @@ fn guess_image_format_from_url(url: &str) -> (&'static str, &'static str, MediaF
 /// Download a thumbnail image, upload it to the public bucket, and create a media file record for it.
 async fn download_and_upload_thumbnail(
   deps: &JobDependencies,
-  job: &PendingWorldlabsJob,
+  job: &WorldlabsJob,
   operation: &GetOperationResponse,
   thumbnail_url: &str,
 ) -> AnyhowResult<MediaFileToken> {
@@ -206,7 +237,49 @@ async fn download_and_upload_thumbnail(
     operation.operation_id
   );
 
-  let checksum = sha256_hash_bytes(&thumb_bytes)
+  upload_cover_image(deps, job, operation, &thumb_bytes, suffix, mime_type, media_file_type).await
+}
+
+/// Fall back to the splat's point density preview. Failures only cost us the cover image.
+async fn upload_preview_cover_image(
+  deps: &JobDependencies,
+  job: &WorldlabsJob,
+  operation: &GetOperationResponse,
+  ingested: &IngestedSplat,
+) -> Option<MediaFileToken> {
+  let result = upload_cover_image(
+    deps,
+    job,
+    operation,
+    &ingested.preview_png_bytes,
+    THUMB_SUFFIX,
+    PREVIEW_MIME_TYPE,
+    MediaFileType::Png,
+  ).await;
+
+  match result {
+    Ok(token) => Some(token),
+    Err(err) => {
+      warn!(
+        "Failed to upload splat preview for operation {}: {:?}. Continuing without cover.",
+        operation.operation_id, err
+      );
+      None
+    }
+  }
+}
+
+/// Upload a cover image to the public bucket and create a media file record for it.
+async fn upload_cover_image(
+  deps: &JobDependencies,
+  job: &WorldlabsJob,
+  operation: &GetOperationResponse,
+  thumb_bytes: &[u8],
+  suffix: &str,
+  mime_type: &str,
+  media_file_type: MediaFileType,
+) -> AnyhowResult<MediaFileToken> {
+  let checksum = sha256_hash_bytes(thumb_bytes)
     .map_err(|err| anyhow!("error hashing thumbnail: {:?}", err))?;
 
   let bucket_path = MediaFileBucketPath::generate_new(Some(THUMB_PREFIX), Some(suffix));
@@ -216,7 +289,7 @@ async fn download_and_upload_thumbnail(
 
   deps
     .public_bucket_client
-    .upload_file_with_content_type_process(object_path, &thumb_bytes, mime_type)
+    .upload_file_with_content_type_process(object_path, thumb_bytes, mime_type)
     .await
     .map_err(|err| anyhow!("error uploading thumbnail to bucket: {:?}", err))?;
 
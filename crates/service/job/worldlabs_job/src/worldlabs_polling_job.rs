@@ -0,0 +1,93 @@
+use std::ops::Deref;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use errors::AnyhowResult;
+use job_runner::polling::polling_job::{JobFailure, PendingJob, PollStatus, PollingJob};
+use jobs_common::poll_backoff::ObservedJobState;
+use mysql_queries::queries::generic_inference::worldlabs::list_pending_worldlabs_jobs::{list_pending_worldlabs_jobs, PendingWorldlabsJob};
+use worldlabs_api_client::api::api_types::operation_id::OperationId;
+use worldlabs_api_client::api::requests::get_operation::get_operation::{get_operation, GetOperationArgs, GetOperationResponse};
+
+use crate::job_dependencies::JobDependencies;
+use crate::process_job::process_failed_job::process_failed_job;
+use crate::process_job::process_successful_job::process_successful_job;
+
+/// A pending World Labs job, as seen by the runner.
+pub struct WorldlabsJob(pub PendingWorldlabsJob);
+
+impl Deref for WorldlabsJob {
+  type Target = PendingWorldlabsJob;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl PendingJob for WorldlabsJob {
+  fn job_token(&self) -> &str {
+    self.0.job_token.as_str()
+  }
+
+  fn third_party_id(&self) -> &str {
+    &self.0.operation_id
+  }
+
+  fn created_at(&self) -> DateTime<Utc> {
+    self.0.created_at
+  }
+
+  fn maybe_user_token(&self) -> Option<&str> {
+    self.0.maybe_creator_user_token.as_ref().map(|token| token.as_str())
+  }
+}
+
+pub struct WorldlabsPollingJob {
+  pub deps: JobDependencies,
+}
+
+#[async_trait]
+impl PollingJob for WorldlabsPollingJob {
+  type Job = WorldlabsJob;
+  type Output = GetOperationResponse;
+
+  fn provider_name(&self) -> &'static str {
+    "World Labs"
+  }
+
+  async fn list_pending(&self) -> AnyhowResult<Vec<WorldlabsJob>> {
+    let jobs = list_pending_worldlabs_jobs(&self.deps.mysql_pool).await?;
+    Ok(jobs.into_iter().map(WorldlabsJob).collect())
+  }
+
+  async fn poll(&self, job: &WorldlabsJob) -> AnyhowResult<PollStatus<GetOperationResponse>> {
+    let operation_id = OperationId(job.operation_id.clone());
+
+    let operation = get_operation(GetOperationArgs {
+      creds: &self.deps.worldlabs_creds,
+      operation_id: &operation_id,
+      request_timeout: Some(Duration::from_secs(30)),
+    }).await?;
+
+    if let Some(ref op_error) = operation.error {
+      let reason = op_error.message.as_deref().unwrap_or("unknown error");
+      return Ok(PollStatus::Failed { reason: reason.to_string() });
+    }
+
+    if !operation.done {
+      return Ok(PollStatus::Pending(ObservedJobState::Running));
+    }
+
+    Ok(PollStatus::Succeeded(operation))
+  }
+
+  async fn on_success(&self, job: &WorldlabsJob, operation: GetOperationResponse) -> AnyhowResult<()> {
+    process_successful_job(&self.deps, job, &operation).await
+  }
+
+  async fn on_failure(&self, job: &WorldlabsJob, failure: &JobFailure) {
+    process_failed_job(&self.deps, job, &failure.reason).await;
+  }
+}
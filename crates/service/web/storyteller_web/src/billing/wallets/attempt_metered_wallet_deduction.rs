@@ -0,0 +1,73 @@
+use log::{error, warn};
+use mysql_queries::queries::users::user_personal_access_tokens::release_user_personal_access_token_spend::release_user_personal_access_token_spend;
+use mysql_queries::queries::users::user_personal_access_tokens::reserve_user_personal_access_token_spend::reserve_user_personal_access_token_spend;
+use sqlx::pool::PoolConnection;
+use sqlx::MySql;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use tokens::tokens::users::UserToken;
+
+use crate::billing::wallets::attempt_wallet_deduction::{attempt_wallet_deduction_else_common_web_error, attempt_wallet_deduction_with_personal_access_token_else_common_web_error, WalletDeductionResult};
+use crate::http_server::common_responses::common_web_error::CommonWebError;
+
+/// Deduct from the user's wallet, also counting the spend against the personal access token
+/// the request was made with (if any) so per-token spend limits hold.
+///
+/// The token's share is reserved first so concurrent requests can't overshoot its limit,
+/// and released again if the wallet can't cover the charge. The token is recorded on the
+/// ledger entry, so later refunds (eg. when a provider rejects the job) release it as well.
+pub async fn attempt_metered_wallet_deduction_else_common_web_error(
+  user_token: &UserToken,
+  maybe_personal_access_token: Option<&PersonalAccessTokenToken>,
+  maybe_reference_token: Option<&str>,
+  amount_to_deduct: u64,
+  connection: &mut PoolConnection<MySql>
+) -> Result<WalletDeductionResult, CommonWebError> {
+
+  let personal_access_token = match maybe_personal_access_token {
+    Some(token) => token,
+    None => {
+      return attempt_wallet_deduction_else_common_web_error(
+        user_token,
+        maybe_reference_token,
+        amount_to_deduct,
+        connection
+      ).await;
+    }
+  };
+
+  let reserved = reserve_user_personal_access_token_spend(
+    personal_access_token,
+    amount_to_deduct,
+    &mut **connection
+  ).await
+    .map_err(|err| {
+      error!("Error reserving personal access token spend: {:?}", err);
+      CommonWebError::ServerError
+    })?;
+
+  if !reserved {
+    warn!("Personal access token {:?} would exceed its spend limit ({} credits)",
+      personal_access_token, amount_to_deduct);
+    return Err(CommonWebError::PaymentRequired);
+  }
+
+  let result = attempt_wallet_deduction_with_personal_access_token_else_common_web_error(
+    user_token,
+    Some(personal_access_token),
+    maybe_reference_token,
+    amount_to_deduct,
+    connection
+  ).await;
+
+  if result.is_err() {
+    if let Err(err) = release_user_personal_access_token_spend(
+      personal_access_token,
+      amount_to_deduct,
+      &mut **connection
+    ).await {
+      error!("Error releasing personal access token spend for {:?}: {:?}", personal_access_token, err);
+    }
+  }
+
+  result
+}
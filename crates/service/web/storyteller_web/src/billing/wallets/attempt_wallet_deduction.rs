@@ -2,12 +2,14 @@ use crate::http_server::common_responses::common_web_error::CommonWebError;
 use enums::common::payments_namespace::PaymentsNamespace;
 use errors::AnyhowResult;
 use log::{error, info};
+use mysql_queries::queries::wallet_ledger_entries::set_wallet_ledger_entry_personal_access_token::set_wallet_ledger_entry_personal_access_token;
 use mysql_queries::queries::wallets::create_new_artcraft_wallet_for_owner_user::create_new_artcraft_wallet_for_owner_user;
 use mysql_queries::queries::wallets::find_primary_wallet_token_for_owner::find_primary_wallet_token_for_owner_using_connection;
 use mysql_queries::queries::wallets::spend::try_to_spend_wallet_balance::try_to_spend_wallet_balance;
 use mysql_queries::queries::wallets::spend::wallet_spend_error::WalletSpendError;
 use sqlx::pool::PoolConnection;
 use sqlx::{Acquire, MySql};
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
 use tokens::tokens::users::UserToken;
 use tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken;
 use tokens::tokens::wallets::WalletToken;
@@ -23,9 +25,28 @@ pub async fn attempt_wallet_deduction_else_common_web_error(
   amount_to_deduct: u64,
   connection: &mut PoolConnection<MySql>
 ) -> Result<WalletDeductionResult, CommonWebError> {
+  attempt_wallet_deduction_with_personal_access_token_else_common_web_error(
+    user_token,
+    None,
+    maybe_reference_token,
+    amount_to_deduct,
+    connection
+  ).await
+}
+
+/// Same as `attempt_wallet_deduction_else_common_web_error`, but also records the personal
+/// access token (if any) on the ledger entry so refunds can release the token's spend.
+pub(crate) async fn attempt_wallet_deduction_with_personal_access_token_else_common_web_error(
+  user_token: &UserToken,
+  maybe_personal_access_token: Option<&PersonalAccessTokenToken>,
+  maybe_reference_token: Option<&str>,
+  amount_to_deduct: u64,
+  connection: &mut PoolConnection<MySql>
+) -> Result<WalletDeductionResult, CommonWebError> {
 
   let result = try_wallet_deduction(
     user_token,
+    maybe_personal_access_token,
     maybe_reference_token,
     amount_to_deduct,
     connection
@@ -61,6 +82,7 @@ pub async fn attempt_wallet_deduction_else_common_web_error(
 
 async fn try_wallet_deduction(
   owner_user_token: &UserToken,
+  maybe_personal_access_token: Option<&PersonalAccessTokenToken>,
   maybe_reference_token: Option<&str>,
   amount_to_deduct: u64,
   connection: &mut PoolConnection<MySql>
@@ -77,6 +99,7 @@ async fn try_wallet_deduction(
   let result = try_wallet_deduction_with_transaction(
     owner_user_token,
     maybe_wallet_token,
+    maybe_personal_access_token,
     maybe_reference_token,
     amount_to_deduct,
     &mut transaction
@@ -101,6 +124,7 @@ async fn try_wallet_deduction(
 async fn try_wallet_deduction_with_transaction(
   owner_user_token: &UserToken,
   maybe_wallet_token: Option<WalletToken>,
+  maybe_personal_access_token: Option<&PersonalAccessTokenToken>,
   maybe_reference_token: Option<&str>,
   amount_to_deduct: u64,
   transaction: &mut sqlx::Transaction<'_, MySql>,
@@ -129,6 +153,14 @@ async fn try_wallet_deduction_with_transaction(
       err
     })?;
 
+  if let Some(personal_access_token) = maybe_personal_access_token {
+    set_wallet_ledger_entry_personal_access_token(
+      &summary.wallet_ledger_entry_token,
+      personal_access_token,
+      transaction
+    ).await?;
+  }
+
   Ok(WalletDeductionResult {
     wallet_token: summary.wallet_token,
     ledger_entry_token: summary.wallet_ledger_entry_token,
@@ -1,2 +1,3 @@
+pub mod attempt_metered_wallet_deduction;
 pub mod attempt_wallet_deduction;
-pub mod temporary_test_wallet_deduction;
\ No newline at end of file
+pub mod temporary_test_wallet_deduction;
@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use log::{info, warn};
+
+use artcraft_router::client::router_mock_client::RouterMockClient;
+use artcraft_router::mock::mock_provider_config::MockProviderConfig;
+use errors::AnyhowResult;
+
+/// Build the offline mock provider, if `ROUTER_MOCK_PROVIDER_ENABLED` is set.
+///
+/// When enabled, omni_gen sends every generation to the mock instead of a real provider.
+/// The client is built once so that submitted jobs outlive the request that created them.
+pub fn configure_mock_provider() -> AnyhowResult<Option<RouterMockClient>> {
+  if !easyenv::get_env_bool_or_default("ROUTER_MOCK_PROVIDER_ENABLED", false) {
+    return Ok(None);
+  }
+
+  warn!("Mock provider is ENABLED: omni_gen generations will not reach real providers.");
+
+  let mut config = MockProviderConfig::default();
+
+  config.queue_delay = Duration::from_millis(
+    easyenv::get_env_num("ROUTER_MOCK_PROVIDER_QUEUE_DELAY_MILLIS", config.queue_delay.as_millis() as u64)?);
+
+  config.processing_delay = Duration::from_millis(
+    easyenv::get_env_num("ROUTER_MOCK_PROVIDER_PROCESSING_DELAY_MILLIS", config.processing_delay.as_millis() as u64)?);
+
+  if let Some(media_root) = easyenv::get_env_string_optional("ROUTER_MOCK_PROVIDER_MEDIA_ROOT") {
+    config.media_root = PathBuf::from(media_root);
+  }
+
+  info!("Mock provider config: {:?}", config);
+
+  Ok(Some(RouterMockClient::new(config)))
+}
@@ -1,2 +1,3 @@
-pub mod redis_rate_limiters;
+pub mod mock_provider;
+pub mod rate_limit_engine;
 pub mod username_set;
\ No newline at end of file
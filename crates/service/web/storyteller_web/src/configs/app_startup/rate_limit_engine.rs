@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use log::info;
+use r2d2::Pool;
+use redis::Client;
+
+use errors::AnyhowResult;
+
+use crate::http_server::rate_limiting::backends::in_memory_rate_limit_backend::InMemoryRateLimitBackend;
+use crate::http_server::rate_limiting::backends::rate_limit_backend::RateLimitBackend;
+use crate::http_server::rate_limiting::backends::redis_rate_limit_backend::RedisRateLimitBackend;
+use crate::http_server::rate_limiting::rate_limit_engine::RateLimitEngine;
+use crate::http_server::rate_limiting::rate_limit_policy::RateLimitPolicy;
+
+/// Build the rate limit policy engine.
+/// Counters live in Redis unless `RATE_LIMIT_POLICY_IN_MEMORY_BACKEND` is set (eg. for local development).
+pub fn configure_rate_limit_engine(redis_pool: &Pool<Client>) -> AnyhowResult<RateLimitEngine> {
+  info!("Setting up rate limit policy engine...");
+
+  let policy = RateLimitPolicy::from_env()?;
+
+  let use_in_memory_backend = easyenv::get_env_bool_or_default("RATE_LIMIT_POLICY_IN_MEMORY_BACKEND", false);
+
+  let backend : Arc<dyn RateLimitBackend> = if use_in_memory_backend {
+    info!("Rate limit policy counters are in memory (per server).");
+    Arc::new(InMemoryRateLimitBackend::new())
+  } else {
+    Arc::new(RedisRateLimitBackend::new(redis_pool.clone()))
+  };
+
+  info!("Rate limit policy enabled: {}", policy.is_enabled());
+
+  Ok(RateLimitEngine::new(policy, backend))
+}
@@ -5,7 +5,7 @@ use reusable_types::server_environment::ServerEnvironment;
 
 use crate::configs::plans::plan::Plan;
 use crate::configs::plans::plan_list::{DEVELOPMENT_PREMIUM_PLANS_BY_SLUG, FREE_LOGGED_IN_PLAN, FREE_LOGGED_OUT_PLAN, LOYALTY_PLANS_BY_SLUG, PRODUCTION_PREMIUM_PLANS_BY_SLUG};
-use crate::http_server::session::lookup::user_session_extended::UserSessionExtended;
+use crate::http_server::session::lookup::user_session_extended::{UserSessionExtended, UserSessionPremiumPlanInfo};
 
 /// Look up the most appropriate plan for the session.
 /// This will probably grow to include a lot of factors.
@@ -14,21 +14,34 @@ pub fn get_correct_plan_for_session(
   maybe_user_session: Option<&UserSessionExtended>,
 ) -> Plan {
 
-  let user_session = match maybe_user_session {
+  get_correct_plan_for_premium_info(
+    server_environment,
+    maybe_user_session.map(|user_session| &user_session.premium))
+}
+
+/// Same as `get_correct_plan_for_session`, for callers that only have the plan details
+/// (eg. the rate limiter, which doesn't load the extended session).
+/// `None` means the visitor is logged out.
+pub fn get_correct_plan_for_premium_info(
+  server_environment: ServerEnvironment,
+  maybe_premium: Option<&UserSessionPremiumPlanInfo>,
+) -> Plan {
+
+  let premium = match maybe_premium {
     None => {
       return FREE_LOGGED_OUT_PLAN.clone();
     },
-    Some(user_session) => user_session,
+    Some(premium) => premium,
   };
 
-  premium_plan_if_available(server_environment, user_session)
-      .or_else(|| loyalty_plan_if_available(user_session))
+  premium_plan_if_available(server_environment, premium)
+      .or_else(|| loyalty_plan_if_available(premium))
       .unwrap_or(FREE_LOGGED_IN_PLAN.clone())
 }
 
 fn premium_plan_if_available(
   server_environment: ServerEnvironment,
-  user_session: &UserSessionExtended,
+  premium: &UserSessionPremiumPlanInfo,
 ) -> Option<Plan> {
 
   let now = Utc::now();
@@ -38,7 +51,7 @@ fn premium_plan_if_available(
     ServerEnvironment::Production => &PRODUCTION_PREMIUM_PLANS_BY_SLUG,
   };
 
-  let applicable_plans = user_session.premium.subscription_plans
+  let applicable_plans = premium.subscription_plans
       .iter()
       .filter(|plan| plan.subscription_expires_at.gt(&now))
       .filter_map(|plan| {
@@ -52,9 +65,9 @@ fn premium_plan_if_available(
 }
 
 fn loyalty_plan_if_available(
-  user_session: &UserSessionExtended,
+  premium: &UserSessionPremiumPlanInfo,
 ) -> Option<Plan> {
-  user_session.premium.maybe_loyalty_program_key
+  premium.maybe_loyalty_program_key
       .as_deref()
       .and_then(|loyalty_key| LOYALTY_PLANS_BY_SLUG.get(loyalty_key))
       .map(|plan| plan.clone())
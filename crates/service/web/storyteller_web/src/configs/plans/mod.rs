@@ -2,3 +2,4 @@ pub mod get_correct_plan_for_session;
 pub mod plan;
 pub mod plan_category;
 pub mod plan_list;
+pub mod rate_limit_tier;
@@ -1,6 +1,7 @@
 use chrono::Duration;
 
 use crate::configs::plans::plan_category::PlanCategory;
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
 
 const TTS_DEFAULT_PRIORITY_LEVEL : u8 = 0;
 const TTS_DEFAULT_DURATION_SECONDS : i64 = 12;
@@ -43,6 +44,9 @@ pub struct Plan {
     /// Whether this plan does not exist in Stripe and only exists as defined by this server.
     is_synthetic_plan: bool,
 
+    /// Which limits apply to the plan in the rate limit policy table.
+    rate_limit_tier: RateLimitTier,
+
     // ========== Features for lipsync (SadTalker, not Wav2Lip) ==========
 
     lipsync_requires_frontend_keepalive: bool,
@@ -96,6 +100,7 @@ impl Plan {
             stripe_price_id: builder.stripe_price_id.clone(),
             is_development_plan: builder.is_development_plan,
             is_synthetic_plan: builder.is_synthetic_plan,
+            rate_limit_tier: builder.rate_limit_tier,
             lipsync_requires_frontend_keepalive: builder.lipsync_requires_frontend_keepalive,
             rerender_requires_frontend_keepalive: builder.rerender_requires_frontend_keepalive,
             mocapnet_requires_frontend_keepalive: builder.mocapnet_requires_frontend_keepalive,
@@ -150,6 +155,10 @@ impl Plan {
         self.is_synthetic_plan
     }
 
+    pub fn rate_limit_tier(&self) -> RateLimitTier {
+        self.rate_limit_tier
+    }
+
     pub fn lipsync_requires_frontend_keepalive(&self) -> bool {
         self.lipsync_requires_frontend_keepalive
     }
@@ -205,6 +214,7 @@ pub struct PlanBuilder {
     stripe_price_id: Option<String>,
     is_development_plan: bool,
     is_synthetic_plan: bool,
+    rate_limit_tier: RateLimitTier,
 
     // ========== Features for lipsync (SadTalker, not Wav2Lip) ==========
 
@@ -258,6 +268,7 @@ impl PlanBuilder {
             stripe_price_id: None,
             is_development_plan: false,
             is_synthetic_plan: false,
+            rate_limit_tier: RateLimitTier::Free,
 
             // Lipsync (SadTalker, not Wav2Lip)
             lipsync_requires_frontend_keepalive: true,
@@ -329,6 +340,11 @@ impl PlanBuilder {
         self
     }
 
+    pub fn rate_limit_tier(mut self, value: RateLimitTier) -> Self {
+        self.rate_limit_tier = value;
+        self
+    }
+
     pub fn lipsync_requires_frontend_keepalive(mut self, value: bool) -> Self {
         self.lipsync_requires_frontend_keepalive = value;
         self
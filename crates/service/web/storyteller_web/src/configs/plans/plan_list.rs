@@ -7,6 +7,7 @@ use tts_common::priority::{FAKEYOU_ANONYMOUS_PRIORITY_LEVEL, FAKEYOU_LOGGED_IN_P
 use crate::configs::plans::plan::Plan;
 use crate::configs::plans::plan::PlanBuilder;
 use crate::configs::plans::plan_category::PlanCategory;
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
 
 /// The plan for free logged-out users.
 /// This is declared at the top level because it may need to be referenced directly.
@@ -14,6 +15,7 @@ pub static FREE_LOGGED_OUT_PLAN : Lazy<Plan> = Lazy::new(|| {
     PlanBuilder::new("free_logged_out")
         .is_synthetic_plan(true)
         .plan_category(PlanCategory::Free)
+        .rate_limit_tier(RateLimitTier::LoggedOut)
         .tts_base_priority_level(FAKEYOU_ANONYMOUS_PRIORITY_LEVEL)
         .tts_max_character_length(1024)
         .web_vc_base_priority_level(FAKEYOU_ANONYMOUS_PRIORITY_LEVEL)
@@ -28,6 +30,7 @@ pub static FREE_LOGGED_OUT_FIRST_TRY_PLAN : Lazy<Plan> = Lazy::new(|| {
     PlanBuilder::new("free_logged_out_first_try")
         .is_synthetic_plan(true)
         .plan_category(PlanCategory::Free)
+        .rate_limit_tier(RateLimitTier::LoggedOut)
         .tts_base_priority_level(FAKEYOU_LOGGED_IN_PRIORITY_LEVEL) // NB: Same as logged-in free users.
         .tts_max_character_length(1024)
         .web_vc_base_priority_level(FAKEYOU_LOGGED_IN_PRIORITY_LEVEL) // NB: Same as logged-in free users.
@@ -42,6 +45,7 @@ pub static FREE_LOGGED_IN_PLAN : Lazy<Plan> = Lazy::new(|| {
     PlanBuilder::new("free_logged_in")
         .is_synthetic_plan(true)
         .plan_category(PlanCategory::Free)
+        .rate_limit_tier(RateLimitTier::Free)
         .tts_base_priority_level(FAKEYOU_LOGGED_IN_PRIORITY_LEVEL)
         .tts_max_character_length(1024)
         .web_vc_base_priority_level(FAKEYOU_LOGGED_IN_PRIORITY_LEVEL)
@@ -57,6 +61,7 @@ pub static LOYALTY_PLANS : Lazy<HashSet<Plan>> = Lazy::new(|| {
     plans.insert(PlanBuilder::new("fakeyou_contributor")
         .is_synthetic_plan(true)
         .plan_category(PlanCategory::LoyaltyReward)
+        .rate_limit_tier(RateLimitTier::Plus)
         .tts_base_priority_level(2)
         .tts_max_character_length(2048)
         .tts_max_duration_seconds(30)
@@ -77,6 +82,7 @@ pub static DEVELOPMENT_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
     plans.insert(PlanBuilder::new("development_fakeyou_plus")
         .is_development_plan(true)
         .plan_category(PlanCategory::Paid)
+        .rate_limit_tier(RateLimitTier::Plus)
         .stripe_product_id("prod_MMxi2J5y69VPbO")
         .stripe_price_id("price_1LeDnKEU5se17MekVr1iYYNf")
         .cost_per_month_dollars(7)
@@ -90,6 +96,7 @@ pub static DEVELOPMENT_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
     plans.insert(PlanBuilder::new("development_fakeyou_pro")
         .is_development_plan(true)
         .plan_category(PlanCategory::Paid)
+        .rate_limit_tier(RateLimitTier::Pro)
         .stripe_product_id("prod_MScAZa5uk5TfDY")
         .stripe_price_id("price_1LjgwIEU5se17MekzQZUHl9W")
         .cost_per_month_dollars(15)
@@ -103,6 +110,7 @@ pub static DEVELOPMENT_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
     plans.insert(PlanBuilder::new("development_fakeyou_elite")
         .is_development_plan(true)
         .plan_category(PlanCategory::Paid)
+        .rate_limit_tier(RateLimitTier::Elite)
         .stripe_product_id("prod_MoFffbqBPt2NG5")
         .stripe_price_id("price_1M4dAbEU5se17MekEmwnee41")
         .cost_per_month_dollars(25)
@@ -125,6 +133,7 @@ pub static PRODUCTION_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
   // Old "plus" price
   plans.insert(PlanBuilder::new("fakeyou_plus")
       .plan_category(PlanCategory::Paid)
+      .rate_limit_tier(RateLimitTier::Plus)
       .stripe_product_id("prod_MoLv23HrxPiY7D")
       .stripe_price_id("price_1M4jDCEU5se17MekOaJ92HYX")
       .cost_per_month_dollars(7)
@@ -139,6 +148,7 @@ pub static PRODUCTION_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
   // New "plus" price
   plans.insert(PlanBuilder::new("fakeyou_plus_plan")
       .plan_category(PlanCategory::Paid)
+      .rate_limit_tier(RateLimitTier::Plus)
       .stripe_product_id("prod_RvC5qUgLTSLGSD")
       .stripe_price_id("price_1R1Lh8EU5se17MekaUNtWc59")
       .cost_per_month_dollars(12)
@@ -153,6 +163,7 @@ pub static PRODUCTION_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
   // Old "pro" price
   plans.insert(PlanBuilder::new("fakeyou_pro")
         .plan_category(PlanCategory::Paid)
+        .rate_limit_tier(RateLimitTier::Pro)
         .stripe_product_id("prod_MoLw8nA6eFxHzc")
         .stripe_price_id("price_1M4jEQEU5se17MeksNfA0EKm")
         .cost_per_month_dollars(15)
@@ -168,6 +179,7 @@ pub static PRODUCTION_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
   // New "pro" price
   plans.insert(PlanBuilder::new("fakeyou_pro_plan")
       .plan_category(PlanCategory::Paid)
+      .rate_limit_tier(RateLimitTier::Pro)
       .stripe_product_id("prod_RvC6nB9rw6yAsI")
       .stripe_price_id("price_1R1LhzEU5se17Mek3JLwN3Gg")
       .cost_per_month_dollars(25)
@@ -183,6 +195,7 @@ pub static PRODUCTION_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
   // Old "elite" price
   plans.insert(PlanBuilder::new("fakeyou_elite")
         .plan_category(PlanCategory::Paid)
+        .rate_limit_tier(RateLimitTier::Elite)
         .stripe_product_id("prod_MoLxQmLA4R24fv")
         .stripe_price_id("price_1M4jFREU5se17Mekc8pQaSKB")
         .cost_per_month_dollars(25)
@@ -198,6 +211,7 @@ pub static PRODUCTION_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
   // New "elite" price
   plans.insert(PlanBuilder::new("fakeyou_elite_plan")
       .plan_category(PlanCategory::Paid)
+      .rate_limit_tier(RateLimitTier::Elite)
       .stripe_product_id("prod_RvC6i84YiAYStd")
       .stripe_price_id("price_1R1LiQEU5se17MekiJdBcsHQ")
       .cost_per_month_dollars(40)
@@ -214,6 +228,7 @@ pub static PRODUCTION_PREMIUM_PLANS: Lazy<HashSet<Plan>> = Lazy::new(|| {
 
     plans.insert(PlanBuilder::new("fakeyou_basic")
         .plan_category(PlanCategory::Paid)
+        .rate_limit_tier(RateLimitTier::Plus)
         .stripe_product_id("prod_MoLyt8qMDmscjr")
         .stripe_price_id("price_1M4jGPEU5se17Mek2FiztNE5")
         .cost_per_month_dollars(3)
@@ -334,6 +349,7 @@ mod test {
 
   use crate::configs::plans::plan::Plan;
   use crate::configs::plans::plan_category::PlanCategory;
+  use crate::configs::plans::rate_limit_tier::RateLimitTier;
   use crate::configs::plans::plan_list::{ALL_PLANS_BY_SLUG, DEVELOPMENT_PREMIUM_PLANS, DEVELOPMENT_PREMIUM_PLANS_BY_SLUG, FREE_PLANS_BY_SLUG, LOYALTY_PLANS, LOYALTY_PLANS_BY_SLUG, PLANS_BY_STRIPE_PRICE_ID, PLANS_BY_STRIPE_PRODUCT_ID, PRODUCTION_PREMIUM_PLANS, PRODUCTION_PREMIUM_PLANS_BY_SLUG};
 
   // NB: We're being extremely careful in this test and all those that follow, essentially
@@ -469,6 +485,31 @@ mod test {
         assert_eq!(300, ALL_PLANS_BY_SLUG.get("development_fakeyou_elite").unwrap().tts_max_duration().num_seconds());
     }
 
+    #[test]
+    fn test_rate_limit_tiers_are_expected() {
+        // Free
+        assert_eq!(RateLimitTier::LoggedOut, ALL_PLANS_BY_SLUG.get("free_logged_out").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::LoggedOut, ALL_PLANS_BY_SLUG.get("free_logged_out_first_try").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Free, ALL_PLANS_BY_SLUG.get("free_logged_in").unwrap().rate_limit_tier());
+
+        // Loyalty-based
+        assert_eq!(RateLimitTier::Plus, ALL_PLANS_BY_SLUG.get("fakeyou_contributor").unwrap().rate_limit_tier());
+
+        // Premium (Production)
+        assert_eq!(RateLimitTier::Plus, ALL_PLANS_BY_SLUG.get("fakeyou_basic").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Plus, ALL_PLANS_BY_SLUG.get("fakeyou_plus").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Plus, ALL_PLANS_BY_SLUG.get("fakeyou_plus_plan").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Pro, ALL_PLANS_BY_SLUG.get("fakeyou_pro").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Pro, ALL_PLANS_BY_SLUG.get("fakeyou_pro_plan").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Elite, ALL_PLANS_BY_SLUG.get("fakeyou_elite").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Elite, ALL_PLANS_BY_SLUG.get("fakeyou_elite_plan").unwrap().rate_limit_tier());
+
+        // Premium (Development/Test)
+        assert_eq!(RateLimitTier::Plus, ALL_PLANS_BY_SLUG.get("development_fakeyou_plus").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Pro, ALL_PLANS_BY_SLUG.get("development_fakeyou_pro").unwrap().rate_limit_tier());
+        assert_eq!(RateLimitTier::Elite, ALL_PLANS_BY_SLUG.get("development_fakeyou_elite").unwrap().rate_limit_tier());
+    }
+
     // =================== TYPICAL FEATURE BEHAVIORS (SUBJECT TO CHANGE) =================== //
 
     #[test]
@@ -0,0 +1,40 @@
+/// Which row of the rate limit policy table a plan uses.
+/// Several plans can share a tier (eg. old and new prices of the same product).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum RateLimitTier {
+    /// Visitors without an account.
+    LoggedOut,
+    /// Logged in users without a paid or loyalty plan.
+    Free,
+    /// Entry level paid plans and loyalty rewards.
+    Plus,
+    Pro,
+    Elite,
+}
+
+impl RateLimitTier {
+    /// For callers whose plan isn't looked up.
+    pub fn for_logged_in(is_logged_in: bool) -> Self {
+        if is_logged_in { Self::Free } else { Self::LoggedOut }
+    }
+
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::LoggedOut => "logged_out",
+            Self::Free => "free",
+            Self::Plus => "plus",
+            Self::Pro => "pro",
+            Self::Elite => "elite",
+        }
+    }
+
+    pub fn all_variants() -> [Self; 5] {
+        [
+            Self::LoggedOut,
+            Self::Free,
+            Self::Plus,
+            Self::Pro,
+            Self::Elite,
+        ]
+    }
+}
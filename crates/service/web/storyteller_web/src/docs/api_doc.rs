@@ -47,6 +47,7 @@ use crate::http_server::endpoints::featured_items::create_featured_item_handler:
 use crate::http_server::endpoints::featured_items::delete_featured_item_handler::*;
 use crate::http_server::endpoints::featured_items::get_is_featured_item_handler::*;
 use crate::http_server::endpoints::image_studio::upload::upload_snapshot_media_file_handler::*;
+use crate::http_server::endpoints::inference_job::cancel::cancel_inference_job_handler::*;
 use crate::http_server::endpoints::inference_job::delete::dismiss_finished_session_jobs_handler::*;
 use crate::http_server::endpoints::inference_job::delete::terminate_inference_job_handler::*;
 use crate::http_server::endpoints::inference_job::get::batch_get_inference_job_status_handler::*;
@@ -155,6 +156,7 @@ use artcraft_api_defs::generate::video::generate_veo_2_image_to_video::GenerateV
 use artcraft_api_defs::generate::video::generate_veo_2_image_to_video::GenerateVeo2Duration;
 use artcraft_api_defs::generate::video::generate_veo_2_image_to_video::GenerateVeo2ImageToVideoRequest;
 use artcraft_api_defs::generate::video::generate_veo_2_image_to_video::GenerateVeo2ImageToVideoResponse;
+use artcraft_api_defs::jobs::cancel_inference_job::*;
 use artcraft_api_defs::jobs::list_session_jobs::*;
 use artcraft_api_defs::media_file::delete_media_file::DeleteMediaFilePathInfo;
 use artcraft_api_defs::media_file::delete_media_file::DeleteMediaFileRequest;
@@ -164,6 +166,7 @@ use artcraft_api_defs::prompts::batch_get_prompts::*;
 use artcraft_api_defs::prompts::get_prompt::*;
 use artcraft_api_defs::users::change_password::{ChangePasswordRequest, ChangePasswordResponse};
 use artcraft_api_defs::users::edit_email::{EditEmailRequest, EditEmailResponse};
+use artcraft_api_defs::users::email_verification::{RedeemEmailVerificationRequest, RedeemEmailVerificationResponse, ResendEmailVerificationResponse};
 use artcraft_api_defs::users::edit_username::{EditUsernameRequest, EditUsernameResponse};
 use billing_component::stripe::http_endpoints::checkout::create::stripe_create_checkout_session_error::CreateCheckoutSessionError;
 use billing_component::stripe::http_endpoints::checkout::create::stripe_create_checkout_session_json_handler::*;
@@ -189,9 +192,15 @@ use crate::http_server::endpoints::users::edit_email_handler::*;
 use crate::http_server::endpoints::users::edit_username_handler::*;
 use crate::http_server::endpoints::users::get_profile_handler::*;
 use crate::http_server::endpoints::users::google_sso::google_sso_handler::*;
-use artcraft_api_defs::users::login::{LoginRequest, LoginSuccessResponse, LoginErrorType};
+use artcraft_api_defs::users::login::{LoginRequest, LoginSuccessResponse, LoginErrorType, LoginTwoFactorRequest};
 use crate::http_server::endpoints::users::login_handler::LoginErrorResponse;
 use crate::http_server::endpoints::users::logout_handler::*;
+use crate::http_server::endpoints::users::redeem_email_verification_handler::*;
+use crate::http_server::endpoints::users::resend_email_verification_handler::*;
+use crate::http_server::endpoints::users::personal_access_tokens::personal_access_token_endpoint_error::PersonalAccessTokenEndpointError;
+use artcraft_api_defs::users::personal_access_tokens::*;
+use crate::http_server::endpoints::users::two_factor::two_factor_endpoint_error::TwoFactorEndpointError;
+use artcraft_api_defs::users::two_factor::*;
 use crate::http_server::endpoints::users::session_info_handler::*;
 use crate::http_server::endpoints::users::session_token_info_handler::*;
 use crate::http_server::endpoints::voice_conversion::enqueue_voice_conversion_inference_handler::*;
@@ -223,6 +232,8 @@ use enums::by_table::model_weights::{weights_category::WeightsCategory, weights_
 use enums::by_table::prompt_context_items::prompt_context_semantic_type::PromptContextSemanticType;
 use enums::by_table::prompts::prompt_type::PromptType;
 use enums::by_table::user_bookmarks::user_bookmark_entity_type::UserBookmarkEntityType;
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::by_table::user_ratings::entity_type::UserRatingEntityType;
 use enums::by_table::user_ratings::rating_value::UserRatingValue;
 use enums::by_table::users::user_feature_flag::UserFeatureFlag;
@@ -243,6 +254,7 @@ use tokens::tokens::comments::*;
 use tokens::tokens::generic_inference_jobs::*;
 use tokens::tokens::media_files::*;
 use tokens::tokens::model_weights::*;
+use tokens::tokens::personal_access_tokens::*;
 use tokens::tokens::prompts::*;
 use tokens::tokens::user_bookmarks::*;
 use tokens::tokens::users::*;
@@ -309,15 +321,21 @@ use artcraft_api_defs::omni_gen::models::omni_gen_image_models::*;
 use artcraft_api_defs::omni_gen::models::omni_gen_video_models::*;
 use artcraft_api_defs::moderation::user::list_subscribing_users_by_signup_date::*;
 use artcraft_api_defs::moderation::user::list_users_by_signup_date::*;
+use artcraft_api_defs::moderation::user::moderator_reset_user_two_factor::*;
 use artcraft_api_defs::moderation::user::user_lookup::*;
 use artcraft_api_defs::moderation::user::user_lookup_by_stripe_customer_id::*;
+use artcraft_api_defs::moderation::user_rate_limit_overrides::moderator_delete_user_rate_limit_override::*;
+use artcraft_api_defs::moderation::user_rate_limit_overrides::moderator_list_user_rate_limit_overrides::*;
+use artcraft_api_defs::moderation::user_rate_limit_overrides::moderator_set_user_rate_limit_override::*;
 use artcraft_api_defs::moderation::jobs::user::list_user_jobs::*;
+use artcraft_api_defs::moderation::provider_health::moderator_get_provider_health::*;
 use artcraft_api_defs::moderation::wallet_ledger_entries::list_wallet_ledger_entries_by_wallet::*;
 use artcraft_api_defs::moderation::wallet_ledger_entries::moderator_get_wallet_ledger_entry::*;
 use artcraft_api_defs::moderation::wallets::list_user_wallets::*;
 use artcraft_api_defs::moderation::wallets::moderator_add_banked_balance_to_wallet::*;
 use artcraft_api_defs::moderation::wallets::moderator_create_wallet_for_user::*;
 use artcraft_api_defs::moderation::wallets::moderator_get_wallet::*;
+use artcraft_api_defs::moderation::webhook_deliveries::moderator_redrive_webhook_delivery::*;
 use artcraft_api_defs::user_referral_codes::create_referral_code::*;
 use artcraft_api_defs::user_referral_codes::delete_referral_code::*;
 use artcraft_api_defs::user_referral_codes::list_referral_codes::*;
@@ -374,6 +392,7 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     crate::http_server::endpoints::generate::video::edit::beeble_switchx_edit_video_gen_handler::beeble_switchx_edit_video_gen_handler,
     crate::http_server::endpoints::generate::video::image::generate_veo_2_image_to_video_handler::generate_veo_2_image_to_video_handler,
     crate::http_server::endpoints::image_studio::upload::upload_snapshot_media_file_handler::upload_snapshot_media_file_handler,
+    crate::http_server::endpoints::inference_job::cancel::cancel_inference_job_handler::cancel_inference_job_handler,
     crate::http_server::endpoints::inference_job::delete::dismiss_finished_session_jobs_handler::dismiss_finished_session_jobs_handler,
     crate::http_server::endpoints::inference_job::delete::terminate_inference_job_handler::terminate_inference_job_handler,
     crate::http_server::endpoints::inference_job::get::batch_get_inference_job_status_handler::batch_get_inference_job_status_handler,
@@ -437,9 +456,20 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     crate::http_server::endpoints::users::get_profile_handler::get_profile_handler,
     crate::http_server::endpoints::users::google_sso::google_sso_handler::google_sso_handler,
     crate::http_server::endpoints::users::login_handler::login_handler,
+    crate::http_server::endpoints::users::login_two_factor_handler::login_two_factor_handler,
     crate::http_server::endpoints::users::logout_handler::logout_handler,
+    crate::http_server::endpoints::users::personal_access_tokens::create_personal_access_token_handler::create_personal_access_token_handler,
+    crate::http_server::endpoints::users::personal_access_tokens::list_personal_access_tokens_handler::list_personal_access_tokens_handler,
+    crate::http_server::endpoints::users::personal_access_tokens::revoke_personal_access_token_handler::revoke_personal_access_token_handler,
+    crate::http_server::endpoints::users::redeem_email_verification_handler::redeem_email_verification_handler,
+    crate::http_server::endpoints::users::resend_email_verification_handler::resend_email_verification_handler,
     crate::http_server::endpoints::users::session_info_handler::session_info_handler,
     crate::http_server::endpoints::users::session_token_info_handler::session_token_info_handler,
+    crate::http_server::endpoints::users::two_factor::confirm_two_factor_enrollment_handler::confirm_two_factor_enrollment_handler,
+    crate::http_server::endpoints::users::two_factor::disable_two_factor_handler::disable_two_factor_handler,
+    crate::http_server::endpoints::users::two_factor::get_two_factor_status_handler::get_two_factor_status_handler,
+    crate::http_server::endpoints::users::two_factor::regenerate_two_factor_recovery_codes_handler::regenerate_two_factor_recovery_codes_handler,
+    crate::http_server::endpoints::users::two_factor::start_two_factor_enrollment_handler::start_two_factor_enrollment_handler,
     crate::http_server::endpoints::voice_conversion::enqueue_voice_conversion_inference_handler::enqueue_voice_conversion_inference_handler,
     crate::http_server::endpoints::voice_designer::inference::enqueue_tts_request::enqueue_tts_request,
     crate::http_server::endpoints::voice_designer::voice_datasets::list_datasets_by_user::list_datasets_by_user_handler,
@@ -519,8 +549,12 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     crate::http_server::endpoints::moderation::info::moderator_token_info_handler::moderator_get_token_info_handler,
     crate::http_server::endpoints::moderation::user::moderator_list_subscribing_users_by_signup_date::moderator_list_subscribing_users_by_signup_date_handler,
     crate::http_server::endpoints::moderation::user::moderator_list_users_by_signup_date::moderator_list_users_by_signup_date_handler,
+    crate::http_server::endpoints::moderation::user::moderator_reset_user_two_factor_handler::moderator_reset_user_two_factor_handler,
     crate::http_server::endpoints::moderation::user::moderator_user_lookup_handler::moderator_user_lookup_handler,
     crate::http_server::endpoints::moderation::user::moderator_user_lookup_by_stripe_customer_id_handler::moderator_user_lookup_by_stripe_customer_id_handler,
+    crate::http_server::endpoints::moderation::user_rate_limit_overrides::moderator_delete_user_rate_limit_override_handler::moderator_delete_user_rate_limit_override_handler,
+    crate::http_server::endpoints::moderation::user_rate_limit_overrides::moderator_list_user_rate_limit_overrides_handler::moderator_list_user_rate_limit_overrides_handler,
+    crate::http_server::endpoints::moderation::user_rate_limit_overrides::moderator_set_user_rate_limit_override_handler::moderator_set_user_rate_limit_override_handler,
     crate::http_server::endpoints::moderation::jobs::user::list_user_jobs_handler::list_user_jobs_handler,
     crate::http_server::endpoints::moderation::wallet_ledger_entries::list_wallet_ledger_entries_by_wallet_handler::list_wallet_ledger_entries_by_wallet_handler,
     crate::http_server::endpoints::moderation::wallet_ledger_entries::moderator_get_wallet_ledger_entry_handler::moderator_get_wallet_ledger_entry_handler,
@@ -530,6 +564,7 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     crate::http_server::endpoints::moderation::wallets::moderator_get_wallet_handler::moderator_get_wallet_handler,
     crate::http_server::endpoints::moderation::debug_logs::moderation_list_debug_logs_for_token_handler::moderation_list_debug_logs_for_token_handler,
     crate::http_server::endpoints::moderation::jobs::moderation_get_job_by_token_handler::moderation_get_job_by_token_handler,
+    crate::http_server::endpoints::moderation::provider_health::moderator_get_provider_health_handler::moderator_get_provider_health_handler,
     crate::http_server::endpoints::moderation::staff_audit_logs::moderator_list_staff_audit_logs_handler::moderator_list_staff_audit_logs_handler,
     crate::http_server::endpoints::moderation::user_referrals::moderator_list_global_user_referrals_handler::moderator_list_global_user_referrals_handler,
     crate::http_server::endpoints::moderation::user_referrals::moderator_list_user_referrals_for_user_handler::moderator_list_user_referrals_for_user_handler,
@@ -537,6 +572,7 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     crate::http_server::endpoints::moderation::user_sessions::moderator_list_user_session_impersonation_requests_for_user_handler::moderator_list_user_session_impersonation_requests_for_user_handler,
     crate::http_server::endpoints::moderation::user_sessions::moderator_list_user_session_impersonation_requests_handler::moderator_list_user_session_impersonation_requests_handler,
     crate::http_server::endpoints::moderation::user_sessions::moderator_user_session_impersonation_request_handler::moderator_user_session_impersonation_request_handler,
+    crate::http_server::endpoints::moderation::webhook_deliveries::moderator_redrive_webhook_delivery_handler::moderator_redrive_webhook_delivery_handler,
     // Credits
     crate::http_server::endpoints::credits::get_session_credits_handler::get_session_credits_handler,
     // Subscriptions
@@ -559,6 +595,7 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     InferenceJobToken,
     MediaFileToken,
     ModelWeightToken,
+    PersonalAccessTokenToken,
     PromptToken,
     UserBookmarkToken,
     UserToken,
@@ -587,6 +624,8 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     PromptContextSemanticType,
     PromptType,
     MediaFileOriginModelType,
+    PersonalAccessTokenScope,
+    RateLimitEndpointGroup,
     StyleTransferName,
     UserFeatureFlag,
     WeightsCategory,
@@ -669,6 +708,9 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     GetPromptImageContextItem,
     BookmarkRow,
     ByQueueStats,
+    CancelInferenceJobOutcome,
+    CancelInferenceJobPathInfo,
+    CancelInferenceJobResponse,
     ChangeMediaFileAnimationTypeError,
     ChangeMediaFileAnimationTypeRequest,
     ChangeMediaFileEngineCategoryError,
@@ -787,6 +829,21 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     ModeratorGetWalletLedgerEntryPathInfo,
     ModeratorGetWalletLedgerEntryResponse,
     ModeratorGetWalletLedgerEntryDetails,
+    ModeratorGetProviderHealthResponse,
+    ModeratorProviderHealthDetails,
+    ModeratorRedriveWebhookDeliveryPathInfo,
+    ModeratorRedriveWebhookDeliveryResponse,
+    ModeratorResetUserTwoFactorPathInfo,
+    ModeratorResetUserTwoFactorResponse,
+    ModeratorListUserRateLimitOverridesPathInfo,
+    ModeratorListUserRateLimitOverridesResponse,
+    UserRateLimitOverride,
+    ModeratorSetUserRateLimitOverridePathInfo,
+    ModeratorSetUserRateLimitOverrideRequest,
+    ModeratorSetUserRateLimitOverrideResponse,
+    ModeratorDeleteUserRateLimitOverridePathInfo,
+    ModeratorDeleteUserRateLimitOverrideRequest,
+    ModeratorDeleteUserRateLimitOverrideResponse,
     GptImage1EditImageRequest,
     GptImage1EditImageImageSize,
     GptImage1EditImageNumImages,
@@ -798,6 +855,27 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     EditEmailError,
     EditEmailRequest,
     EditEmailResponse,
+    RedeemEmailVerificationError,
+    RedeemEmailVerificationRequest,
+    RedeemEmailVerificationResponse,
+    ResendEmailVerificationError,
+    ResendEmailVerificationResponse,
+    PersonalAccessTokenEndpointError,
+    CreatePersonalAccessTokenRequest,
+    CreatePersonalAccessTokenResponse,
+    ListPersonalAccessTokensResponse,
+    PersonalAccessTokenDetails,
+    RevokePersonalAccessTokenPathInfo,
+    RevokePersonalAccessTokenResponse,
+    TwoFactorEndpointError,
+    GetTwoFactorStatusResponse,
+    StartTwoFactorEnrollmentResponse,
+    ConfirmTwoFactorEnrollmentRequest,
+    ConfirmTwoFactorEnrollmentResponse,
+    DisableTwoFactorRequest,
+    DisableTwoFactorResponse,
+    RegenerateTwoFactorRecoveryCodesRequest,
+    RegenerateTwoFactorRecoveryCodesResponse,
     EditUsernameError,
     EditUsernameRequest,
     EditUsernameResponse,
@@ -975,6 +1053,7 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     LoginErrorType,
     LoginRequest,
     LoginSuccessResponse,
+    LoginTwoFactorRequest,
     LogoutError,
     LogoutSuccessResponse,
     MediaFileData,
@@ -1057,6 +1136,7 @@ use crate::http_server::endpoints::media_files::list::list_batch_generated_redux
     UploadNewSceneMediaFileForm,
     UploadNewSceneMediaFileSuccessResponse,
     UploadSpzMediaFileForm,
+    UploadSpzMediaFileSplatDetails,
     UploadSpzMediaFileSuccessResponse,
     UploadNewVideoMediaFileForm,
     UploadNewVideoMediaFileSuccessResponse,
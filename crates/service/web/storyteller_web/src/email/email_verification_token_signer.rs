@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use jwt_signer::jwt_signer::JwtSigner;
+use jwt_signer::jwt_signer_error::JwtSignerError;
+use tokens::tokens::users::UserToken;
+
+/**
+ * Email verification token version history
+ *
+ *  Version 1: Claims include "purpose", "user_token", "email_address", "expires_at", and "version".
+ */
+const TOKEN_VERSION : u32 = 1;
+
+/// Distinguishes these tokens from other JWTs signed with the same secret (eg. session cookies).
+const TOKEN_PURPOSE : &str = "email_verification";
+
+/// Signs and verifies the stateless tokens we email to users to confirm their address.
+///
+/// The token is bound to the email address it was sent to, so changing the account's
+/// email address invalidates any outstanding links.
+#[derive(Clone)]
+pub struct EmailVerificationTokenSigner {
+  jwt_signer: JwtSigner,
+  token_ttl: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmailVerificationTokenPayload {
+  pub user_token: UserToken,
+  pub email_address: String,
+  pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub enum EmailVerificationTokenError {
+  /// The token was forged, truncated, or was not issued for email verification.
+  InvalidToken,
+  /// The token was valid, but is past its expiry.
+  ExpiredToken,
+  /// Failure to sign the token.
+  SignerError(JwtSignerError),
+}
+
+impl Display for EmailVerificationTokenError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidToken => write!(f, "invalid email verification token"),
+      Self::ExpiredToken => write!(f, "expired email verification token"),
+      Self::SignerError(e) => write!(f, "email verification token signer error: {}", e),
+    }
+  }
+}
+
+impl Error for EmailVerificationTokenError {}
+
+impl From<JwtSignerError> for EmailVerificationTokenError {
+  fn from(value: JwtSignerError) -> Self {
+    Self::SignerError(value)
+  }
+}
+
+impl EmailVerificationTokenSigner {
+  pub fn new(hmac_secret: &str, token_ttl: Duration) -> Result<Self, EmailVerificationTokenError> {
+    Ok(Self {
+      jwt_signer: JwtSigner::new(hmac_secret)?,
+      token_ttl,
+    })
+  }
+
+  pub fn encode(
+    &self,
+    user_token: &UserToken,
+    email_address: &str,
+  ) -> Result<String, EmailVerificationTokenError> {
+    self.encode_at(user_token, email_address, Utc::now())
+  }
+
+  pub fn decode(&self, token: &str) -> Result<EmailVerificationTokenPayload, EmailVerificationTokenError> {
+    self.decode_at(token, Utc::now())
+  }
+
+  fn encode_at(
+    &self,
+    user_token: &UserToken,
+    email_address: &str,
+    now: DateTime<Utc>,
+  ) -> Result<String, EmailVerificationTokenError> {
+    let expires_at = now.timestamp() + self.token_ttl.as_secs() as i64;
+    let expires_at = expires_at.to_string();
+    let token_version = TOKEN_VERSION.to_string();
+
+    let mut claims: BTreeMap<&str, &str> = BTreeMap::new();
+
+    claims.insert("purpose", TOKEN_PURPOSE);
+    claims.insert("user_token", user_token.as_str());
+    claims.insert("email_address", email_address);
+    claims.insert("expires_at", &expires_at);
+    claims.insert("version", &token_version);
+
+    Ok(self.jwt_signer.claims_to_jwt(&claims)?)
+  }
+
+  fn decode_at(
+    &self,
+    token: &str,
+    now: DateTime<Utc>,
+  ) -> Result<EmailVerificationTokenPayload, EmailVerificationTokenError> {
+    let claims = self.jwt_signer.jwt_to_claims(token.trim())
+        .map_err(|_| EmailVerificationTokenError::InvalidToken)?;
+
+    if claims.get("purpose").map(|p| p.as_str()) != Some(TOKEN_PURPOSE) {
+      return Err(EmailVerificationTokenError::InvalidToken);
+    }
+
+    let user_token = claims.get("user_token")
+        .map(|t| UserToken::new_from_str(t))
+        .ok_or(EmailVerificationTokenError::InvalidToken)?;
+
+    let email_address = claims.get("email_address")
+        .cloned()
+        .ok_or(EmailVerificationTokenError::InvalidToken)?;
+
+    let expires_at = claims.get("expires_at")
+        .and_then(|e| e.parse::<i64>().ok())
+        .and_then(|e| Utc.timestamp_opt(e, 0).single())
+        .ok_or(EmailVerificationTokenError::InvalidToken)?;
+
+    if expires_at <= now {
+      return Err(EmailVerificationTokenError::ExpiredToken);
+    }
+
+    Ok(EmailVerificationTokenPayload {
+      user_token,
+      email_address,
+      expires_at,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+  use std::time::Duration;
+
+  use chrono::{TimeZone, Utc};
+
+  use jwt_signer::jwt_signer::JwtSigner;
+  use tokens::tokens::users::UserToken;
+
+  use crate::email::email_verification_token_signer::{EmailVerificationTokenError, EmailVerificationTokenSigner};
+
+  const ONE_DAY : Duration = Duration::from_secs(60 * 60 * 24);
+
+  fn signer() -> EmailVerificationTokenSigner {
+    EmailVerificationTokenSigner::new("fake_secret", ONE_DAY).unwrap()
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let signer = signer();
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let token = signer.encode_at(&UserToken::new_from_str("ex_user_token"), "user@example.com", now).unwrap();
+
+    let payload = signer.decode_at(&token, now).unwrap();
+
+    assert_eq!(payload.user_token.as_str(), "ex_user_token");
+    assert_eq!(payload.email_address, "user@example.com");
+    assert_eq!(payload.expires_at.timestamp(), 1_700_000_000 + 86_400);
+  }
+
+  #[test]
+  fn test_expired() {
+    let signer = signer();
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let token = signer.encode_at(&UserToken::new_from_str("ex_user_token"), "user@example.com", now).unwrap();
+
+    let later = Utc.timestamp_opt(1_700_000_000 + 86_400, 0).unwrap();
+
+    assert!(matches!(signer.decode_at(&token, later), Err(EmailVerificationTokenError::ExpiredToken)));
+  }
+
+  #[test]
+  fn test_wrong_secret() {
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let token = signer().encode_at(&UserToken::new_from_str("ex_user_token"), "user@example.com", now).unwrap();
+
+    let other_signer = EmailVerificationTokenSigner::new("other_secret", ONE_DAY).unwrap();
+
+    assert!(matches!(other_signer.decode_at(&token, now), Err(EmailVerificationTokenError::InvalidToken)));
+  }
+
+  #[test]
+  fn test_rejects_other_jwts_with_same_secret() {
+    // NB: Eg. a session cookie payload signed with the same HMAC secret.
+    let mut claims = BTreeMap::new();
+    claims.insert("user_token", "ex_user_token");
+    claims.insert("email_address", "user@example.com");
+    claims.insert("expires_at", "9999999999");
+
+    let token = JwtSigner::new("fake_secret").unwrap().claims_to_jwt(&claims).unwrap();
+
+    assert!(matches!(signer().decode(&token), Err(EmailVerificationTokenError::InvalidToken)));
+  }
+
+  #[test]
+  fn test_garbage() {
+    assert!(matches!(signer().decode("not.a.jwt"), Err(EmailVerificationTokenError::InvalidToken)));
+  }
+}
@@ -0,0 +1,13 @@
+use mailer::templates::email_branding::EmailBranding;
+
+use crate::http_server::requests::get_request_domain_branding::DomainBranding;
+
+/// Which branding to send transactional email with for a request's domain.
+pub fn get_email_branding(domain_branding: DomainBranding) -> EmailBranding {
+  match domain_branding {
+    DomainBranding::ArtCraftDotAi |
+    DomainBranding::GetArtCraft => EmailBranding::ArtCraft,
+    DomainBranding::FakeYou => EmailBranding::FakeYou,
+    DomainBranding::Storyteller => EmailBranding::Storyteller,
+  }
+}
@@ -0,0 +1,21 @@
+#[derive(Clone)]
+pub enum EmailVerificationLocation {
+  FakeYouProduction,
+  FakeYouDevelopment,
+  ArtCraftProduction,
+  ArtCraftDevelopment,
+  CustomUrl(String)
+}
+
+pub fn get_email_verification_url(verification_token: &str, location: EmailVerificationLocation) -> String {
+  let url = match location {
+    EmailVerificationLocation::ArtCraftProduction => "https://getartcraft.com/verify-email",
+    EmailVerificationLocation::FakeYouProduction => "https://fakeyou.com/verify-email",
+    EmailVerificationLocation::ArtCraftDevelopment |
+    EmailVerificationLocation::FakeYouDevelopment => {
+      "http://localhost:7000/verify-email"
+    },
+    EmailVerificationLocation::CustomUrl(ref url) => url,
+  };
+  format!("{url}?token={verification_token}")
+}
@@ -0,0 +1,34 @@
+use log::info;
+
+use errors::AnyhowResult;
+use tokens::tokens::users::UserToken;
+
+use crate::email::send_email_verification_email::{send_email_verification_email, SendEmailVerificationEmailArgs};
+use crate::http_server::requests::get_request_domain_branding::DomainBranding;
+use crate::state::server_state::ServerState;
+
+pub struct IssueEmailVerificationArgs<'a> {
+  pub user_token: &'a UserToken,
+  pub email_address: &'a str,
+  pub domain_branding: DomainBranding,
+  pub server_state: &'a ServerState,
+}
+
+/// Sign a new verification token for the user's current email address and send it.
+/// Shared by signup, email changes, and the resend endpoint.
+pub async fn issue_email_verification(args: IssueEmailVerificationArgs<'_>) -> AnyhowResult<()> {
+  let verification_token = args.server_state
+      .email_verification
+      .token_signer
+      .encode(args.user_token, args.email_address)?;
+
+  info!("Sending email verification email for user {:?}", args.user_token);
+
+  send_email_verification_email(SendEmailVerificationEmailArgs {
+    email_address_destination: args.email_address,
+    verification_token: &verification_token,
+    outbound_email_queue: &args.server_state.outbound_email_queue,
+    server_environment: args.server_state.server_environment,
+    domain_branding: args.domain_branding,
+  }).await
+}
@@ -1,2 +1,7 @@
+pub mod email_verification_token_signer;
+pub mod get_email_branding;
+pub mod get_email_verification_url;
+pub mod get_password_reset_url;
+pub mod issue_email_verification;
+pub mod send_email_verification_email;
 pub mod send_password_reset_email;
-pub mod get_password_reset_url;
\ No newline at end of file
@@ -0,0 +1,43 @@
+use crate::email::get_email_branding::get_email_branding;
+use crate::email::get_email_verification_url::{get_email_verification_url, EmailVerificationLocation};
+use crate::http_server::requests::get_request_domain_branding::DomainBranding;
+use errors::AnyhowResult;
+use mailer::queue::outbound_email_queue::OutboundEmailQueue;
+use mailer::templates::email_template::EmailTemplate;
+use mailer::templates::messages::email_verification_email::EmailVerificationEmail;
+use server_environment::ServerEnvironment;
+
+pub struct SendEmailVerificationEmailArgs<'a> {
+  pub email_address_destination: &'a str,
+  pub verification_token: &'a str,
+  pub outbound_email_queue: &'a OutboundEmailQueue,
+  pub server_environment: ServerEnvironment,
+  pub domain_branding: DomainBranding,
+}
+
+/// Enqueue the email verification email. The outbound email worker delivers it (and retries).
+pub async fn send_email_verification_email(
+  args: SendEmailVerificationEmailArgs<'_>
+) -> AnyhowResult<()> {
+  let url = match (args.domain_branding, args.server_environment) {
+    // Legacy FakeYou
+    (DomainBranding::FakeYou, ServerEnvironment::Development) => EmailVerificationLocation::FakeYouDevelopment,
+    (DomainBranding::FakeYou, ServerEnvironment::Production) => EmailVerificationLocation::FakeYouProduction,
+    // Everything else "development" is ArtCraft Development
+    (_, ServerEnvironment::Development) => EmailVerificationLocation::ArtCraftDevelopment,
+    // Everything else "production" is ArtCraft Production
+    (_, ServerEnvironment::Production) => EmailVerificationLocation::ArtCraftProduction,
+  };
+
+  let template = EmailVerificationEmail {
+    verification_url: get_email_verification_url(args.verification_token, url),
+  };
+
+  let email = template.render(
+    get_email_branding(args.domain_branding),
+    args.email_address_destination);
+
+  args.outbound_email_queue.enqueue(&email).await?;
+
+  Ok(())
+}
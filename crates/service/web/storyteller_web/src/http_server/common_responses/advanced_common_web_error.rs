@@ -2,6 +2,7 @@ use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
 use crate::http_server::common_responses::common_web_error::CommonWebError;
+use crate::http_server::rate_limiting::rate_limit_engine::RateLimitExceededError;
 use crate::http_server::session::session_checker_error::SessionCheckerError;
 use crate::http_server::web_utils::user_session::require_user_session::RequireUserSessionError;
 use actix_artcraft::sessions::anonymous_visitor_tracking::avt_cookie_payload_error::AvtCookiePayloadError;
@@ -55,6 +56,10 @@ pub enum AdvancedCommonWebError {
   /// 403 Forbidden — content was rejected, with a user-facing message.
   ContentPolicyRejectedWithMessage(String),
 
+  /// 429 Too Many Requests.
+  /// The `RateLimitHeaders` middleware adds `Retry-After` when the rate limit engine refused the request.
+  TooManyRequests,
+
   /// Uncaught errors are always 500 Internal Server Error.
   /// The user will never see the error cause or message, but our
   /// middleware will handle alerting, logging, etc.
@@ -137,6 +142,7 @@ impl Display for AdvancedCommonWebError {
       Self::Forbidden => write!(f, "Forbidden"),
       Self::ContentPolicyRejected => write!(f, "Content policy rejected"),
       Self::ContentPolicyRejectedWithMessage(msg) => write!(f, "Content policy rejected: {}", msg),
+      Self::TooManyRequests => write!(f, "Too many requests"),
       Self::UncaughtServerError(err) => write!(f, "Server error: {}", err),
       Self::UncaughtServerErrorWithInternalMessage { internal_message, error } => {
         write!(f, "Server error: {}: {}", internal_message, error)
@@ -155,6 +161,7 @@ impl std::fmt::Debug for AdvancedCommonWebError {
       Self::Forbidden => write!(f, "Forbidden"),
       Self::ContentPolicyRejected => write!(f, "ContentPolicyRejected"),
       Self::ContentPolicyRejectedWithMessage(msg) => write!(f, "ContentPolicyRejectedWithMessage({:?})", msg),
+      Self::TooManyRequests => write!(f, "TooManyRequests"),
       Self::UncaughtServerError(err) => write!(f, "UncaughtServerError({:?})", err),
       Self::UncaughtServerErrorWithInternalMessage { internal_message, error } => {
         write!(f, "UncaughtServerErrorWithInternalMessage({:?}, {:?})", internal_message, error)
@@ -185,6 +192,7 @@ impl ResponseError for AdvancedCommonWebError {
       Self::Forbidden => StatusCode::FORBIDDEN,
       Self::ContentPolicyRejected => StatusCode::FORBIDDEN,
       Self::ContentPolicyRejectedWithMessage(_) => StatusCode::FORBIDDEN,
+      Self::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
       Self::UncaughtServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
       Self::UncaughtServerErrorWithInternalMessage { .. } => StatusCode::INTERNAL_SERVER_ERROR,
     }
@@ -299,6 +307,12 @@ impl From<SessionCheckerError> for AdvancedCommonWebError {
   }
 }
 
+impl From<RateLimitExceededError> for AdvancedCommonWebError {
+  fn from(_err: RateLimitExceededError) -> Self {
+    Self::TooManyRequests
+  }
+}
+
 impl From<CommonWebError> for AdvancedCommonWebError {
   fn from(value: CommonWebError) -> Self {
     match value {
@@ -374,6 +388,7 @@ impl utoipa::ToSchema for AdvancedCommonWebError {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::http_server::rate_limiting::rate_limit_decision::RateLimitDecision;
   use actix_http::body::MessageBody;
 
   #[test]
@@ -409,6 +424,15 @@ mod tests {
     assert_eq!(error.status_code(), StatusCode::PAYMENT_REQUIRED);
   }
 
+  #[test]
+  fn rate_limit_exceeded_returns_429() {
+    let error: AdvancedCommonWebError = RateLimitExceededError {
+      decision: RateLimitDecision { allowed: false, limit: 3, remaining: 0, reset_seconds: 12 },
+    }.into();
+    assert_eq!(error.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(!error.is_server_error());
+  }
+
   #[test]
   fn uncaught_io_error_returns_500_and_hides_cause() {
     let io_err = std::io::Error::new(std::io::ErrorKind::Other, "disk exploded");
@@ -14,6 +14,7 @@ use enums::by_table::generic_inference_jobs::inference_category::InferenceCatego
 use enums::by_table::generic_inference_jobs::inference_job_product_category::InferenceJobProductCategory;
 use enums::by_table::generic_inference_jobs::inference_job_type::InferenceJobType;
 use enums::by_table::generic_inference_jobs::inference_model_type::InferenceModelType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_ip::get_request_ip;
 use mysql_queries::payloads::generic_inference_args::generic_inference_args::{GenericInferenceArgs, InferenceCategoryAbbreviated, PolymorphicInferenceArgs};
@@ -26,6 +27,8 @@ use tokens::tokens::users::UserToken;
 use tokens::tokens::voice_conversion_results::VoiceConversionResultToken;
 
 use crate::configs::plans::get_correct_plan_for_session::get_correct_plan_for_session;
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::requests::request_headers::get_routing_tag_header::get_routing_tag_header;
 use crate::http_server::requests::request_headers::has_debug_header::has_debug_header;
 use crate::http_server::session::lookup::user_session_extended::UserSessionExtended;
@@ -211,14 +214,14 @@ pub async fn enqueue_face_animation_handler(
 
   // ==================== RATE LIMIT ==================== //
 
-  let rate_limiter = match maybe_user_session {
-    None => &server_state.redis_rate_limiters.logged_out,
-    Some(ref _user) => &server_state.redis_rate_limiters.logged_in
-  };
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(EnqueueFaceAnimationError::RateLimited);
-  }
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Generation,
+    maybe_user_token: None,
+    tier: RateLimitTier::for_logged_in(maybe_user_session.is_some()),
+  }).await
+      .map_err(|_err| EnqueueFaceAnimationError::RateLimited)?;
 
   // ==================== LOOK UP MODEL INFO ==================== //
 
@@ -10,10 +10,13 @@ use actix_web::http::StatusCode;
 use actix_web::{web, HttpRequest, HttpResponse};
 use log::{info, warn};
 
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::session::lookup::user_session_extended::UserSessionExtended;
 use enums::by_table::generic_inference_jobs::inference_category::InferenceCategory;
 use enums::by_table::generic_inference_jobs::inference_job_type::InferenceJobType;
 use enums::by_table::generic_inference_jobs::inference_model_type::InferenceModelType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_header_optional::get_request_header_optional;
 use http_server_common::request::get_request_ip::get_request_ip;
@@ -148,20 +151,21 @@ pub async fn enqueue_rerender_animation_handler(
 
     // ==================== RATE LIMIT ==================== //
 
-    let rate_limiter = match maybe_user_session {
-        None => &server_state.redis_rate_limiters.logged_out,
-        Some(ref user) => {
-            if user.role.is_banned {
-                return Err(EnqueueRerenderAnimationError::NotAuthorized);
-            }
-            &server_state.redis_rate_limiters.logged_in
-        },
-    };
-
-    if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-        return Err(EnqueueRerenderAnimationError::RateLimited);
+    if let Some(ref user) = maybe_user_session {
+        if user.role.is_banned {
+            return Err(EnqueueRerenderAnimationError::NotAuthorized);
+        }
     }
 
+    enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+        http_request: &http_request,
+        server_state: &server_state,
+        endpoint_group: RateLimitEndpointGroup::Generation,
+        maybe_user_token: None,
+        tier: RateLimitTier::for_logged_in(maybe_user_session.is_some()),
+    }).await
+        .map_err(|_err| EnqueueRerenderAnimationError::RateLimited)?;
+
     // ==================== LOOK UP MODEL INFO ==================== //
 
     // TODO(bt): CHECK DATABASE FOR TOKENS!
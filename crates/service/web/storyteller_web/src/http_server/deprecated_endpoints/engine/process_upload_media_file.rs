@@ -7,6 +7,7 @@ use log::{error, info, warn};
 
 use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path::MediaFileBucketPath;
 use enums::by_table::media_files::media_file_type::MediaFileType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
 use http_server_common::request::get_request_ip::get_request_ip;
@@ -17,8 +18,10 @@ use mysql_queries::queries::idepotency_tokens::insert_idempotency_token::insert_
 use mysql_queries::queries::media_files::create::specialized_insert::insert_media_file_from_file_upload::{insert_media_file_from_file_upload, InsertMediaFileFromUploadArgs, UploadType};
 use tokens::tokens::media_files::MediaFileToken;
 
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
 use crate::http_server::endpoints::media_files::upload::upload_error::MediaFileUploadError;
 use crate::http_server::endpoints::media_files::upload::upload_generic::drain_multipart_request::{drain_multipart_request, MediaFileUploadSource};
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::state::server_state::ServerState;
 
@@ -83,14 +86,14 @@ pub async fn process_upload_media_file(
 
   // ==================== RATE LIMIT ==================== //
 
-  let rate_limiter = match maybe_user_session {
-    None => &server_state.redis_rate_limiters.file_upload_logged_out,
-    Some(ref _session) => &server_state.redis_rate_limiters.file_upload_logged_in,
-  };
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(MediaFileUploadError::RateLimited);
-  }
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Uploads,
+    maybe_user_token: None,
+    tier: RateLimitTier::for_logged_in(maybe_user_session.is_some()),
+  }).await
+      .map_err(|_err| MediaFileUploadError::RateLimited)?;
 
   // ==================== READ MULTIPART REQUEST ==================== //
 
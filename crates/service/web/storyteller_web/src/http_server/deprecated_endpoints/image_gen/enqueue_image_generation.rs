@@ -18,6 +18,7 @@ use web::Data;
 use enums::by_table::generic_inference_jobs::inference_category::InferenceCategory;
 use enums::by_table::generic_inference_jobs::inference_job_type::InferenceJobType;
 use enums::by_table::generic_inference_jobs::inference_model_type::InferenceModelType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use http_server_common::request::get_request_header_optional::get_request_header_optional;
 use http_server_common::request::get_request_ip::get_request_ip;
 use mysql_queries::payloads::generic_inference_args::generic_inference_args::{
@@ -37,6 +38,8 @@ use tokens::tokens::model_weights::ModelWeightToken;
 use tokens::tokens::users::UserToken;
 
 use crate::configs::plans::get_correct_plan_for_session::get_correct_plan_for_session;
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
 use crate::state::server_state::ServerState;
@@ -262,14 +265,14 @@ pub async fn enqueue_image_generation_request(
 
     // ==================== RATE LIMIT ==================== //
 
-    let rate_limiter = match maybe_user_session {
-        None => &server_state.redis_rate_limiters.logged_out,
-        Some(ref _user) => &server_state.redis_rate_limiters.logged_in,
-    };
-
-    if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-        return Err(EnqueueImageGenRequestError::RateLimited);
-    }
+    enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+      http_request: &http_request,
+      server_state: &server_state,
+      endpoint_group: RateLimitEndpointGroup::Generation,
+      maybe_user_token: None,
+      tier: RateLimitTier::for_logged_in(maybe_user_session.is_some()),
+    }).await
+        .map_err(|_err| EnqueueImageGenRequestError::RateLimited)?;
 
     // Get up IP address
     let ip_address = get_request_ip(&http_request);
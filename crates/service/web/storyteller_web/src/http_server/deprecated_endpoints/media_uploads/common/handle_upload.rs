@@ -8,6 +8,7 @@ use log::{error, info, warn};
 use bucket_paths::legacy::typified_paths::public::media_uploads::bucket_file_path::MediaUploadOriginalFilePath;
 use enums::by_table::media_uploads::media_upload_source::MediaUploadSource;
 use enums::by_table::media_uploads::media_upload_type::MediaUploadType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
 use http_server_common::request::get_request_ip::get_request_ip;
@@ -17,8 +18,10 @@ use mysql_queries::queries::media_uploads::get_media_upload_by_uuid::get_media_u
 use mysql_queries::queries::media_uploads::insert_media_upload::{insert_media_upload, Args};
 use tokens::tokens::media_uploads::MediaUploadToken;
 
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
 use crate::http_server::deprecated_endpoints::media_uploads::common::drain_multipart_request::{drain_multipart_request, MediaSource};
 use crate::http_server::deprecated_endpoints::media_uploads::common::upload_error::UploadError;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::state::server_state::ServerState;
 
@@ -76,14 +79,14 @@ pub async fn handle_upload(
 
   // ==================== RATE LIMIT ==================== //
 
-  let rate_limiter = match maybe_user_session {
-    None => &server_state.redis_rate_limiters.file_upload_logged_out,
-    Some(ref _session) => &server_state.redis_rate_limiters.file_upload_logged_in,
-  };
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(UploadError::RateLimited);
-  }
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Uploads,
+    maybe_user_token: None,
+    tier: RateLimitTier::for_logged_in(maybe_user_session.is_some()),
+  }).await
+      .map_err(|_err| UploadError::RateLimited)?;
 
   // ==================== READ MULTIPART REQUEST ==================== //
 
@@ -14,6 +14,7 @@ use futures::TryStreamExt;
 use log::{error, info, warn};
 use redis::Commands;
 
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_ip::get_request_ip;
 use uuid_utils::uuid::generate_random_uuid;
@@ -21,6 +22,8 @@ use mysql_queries::queries::w2l::w2l_inference_jobs::insert_w2l_inference_job_ex
 use mysql_queries::queries::w2l::w2l_templates::check_w2l_template_exists::check_w2l_template_exists;
 use redis_common::redis_keys::RedisKeys;
 
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::web_utils::read_multipart_field_bytes::checked_read_multipart_bytes;
 use crate::http_server::web_utils::read_multipart_field_bytes::read_multipart_field_as_text;
 use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
@@ -111,14 +114,14 @@ pub async fn enqueue_infer_w2l_with_uploads(
 
   // ==================== RATE LIMIT ==================== //
 
-  let rate_limiter = match maybe_session {
-    None => &server_state.redis_rate_limiters.logged_out,
-    Some(ref _user) => &server_state.redis_rate_limiters.logged_in,
-  };
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(InferW2lWithUploadError::RateLimited);
-  }
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Generation,
+    maybe_user_token: None,
+    tier: RateLimitTier::for_logged_in(maybe_session.is_some()),
+  }).await
+      .map_err(|_err| InferW2lWithUploadError::RateLimited)?;
 
   // ==================== SESSION DETAILS ==================== //
 
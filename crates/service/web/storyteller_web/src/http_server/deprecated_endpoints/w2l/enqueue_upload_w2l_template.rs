@@ -12,11 +12,14 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use log::warn;
 
 use config::is_bad_video_download_url::is_bad_video_download_url;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_ip::get_request_ip;
 use mysql_queries::queries::w2l::w2l_template_upload_jobs::insert_w2l_template_upload_job::{insert_w2l_template_upload_job, InsertW2lTemplateUploadJobArgs};
 use user_input_common::check_for_slurs::contains_slurs;
 
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::validations::validate_model_title::validate_model_title;
 use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
 use crate::state::server_state::ServerState;
@@ -89,9 +92,14 @@ pub async fn upload_w2l_template_handler(
   request: web::Json<UploadW2lTemplateRequest>,
   server_state: web::Data<Arc<ServerState>>) -> Result<HttpResponse, UploadW2lTemplateError>
 {
-  if let Err(_err) = server_state.redis_rate_limiters.model_upload.rate_limit_request(&http_request).await {
-    return Err(UploadW2lTemplateError::RateLimited);
-  }
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Uploads,
+    maybe_user_token: None,
+    tier: RateLimitTier::LoggedOut,
+  }).await
+      .map_err(|_err| UploadW2lTemplateError::RateLimited)?;
 
   let maybe_user_session = server_state
     .session_checker
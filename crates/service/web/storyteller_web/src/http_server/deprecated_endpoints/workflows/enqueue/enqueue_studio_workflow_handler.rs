@@ -14,6 +14,7 @@ use enums::by_table::generic_inference_jobs::inference_category::InferenceCatego
 use enums::by_table::generic_inference_jobs::inference_job_product_category::InferenceJobProductCategory;
 use enums::by_table::generic_inference_jobs::inference_job_type::InferenceJobType;
 use enums::by_table::generic_inference_jobs::inference_model_type::InferenceModelType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_header_optional::get_request_header_optional;
 use http_server_common::request::get_request_ip::get_request_ip;
@@ -30,10 +31,12 @@ use tokens::tokens::generic_inference_jobs::InferenceJobToken;
 
 use crate::configs::plans::get_correct_plan_for_session::get_correct_plan_for_session;
 use crate::configs::plans::plan_category::PlanCategory;
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
 use crate::http_server::deprecated_endpoints::workflows::coordinate_workflow_args::{coordinate_workflow_args, CoordinatedWorkflowArgs};
 use crate::http_server::deprecated_endpoints::workflows::enqueue::vst_common::vst_error::VstError;
 use crate::http_server::deprecated_endpoints::workflows::enqueue::vst_common::vst_request::VstRequest;
 use crate::http_server::deprecated_endpoints::workflows::enqueue::vst_common::vst_response::VstSuccessResponse;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::requests::request_headers::get_routing_tag_header::get_routing_tag_header;
 use crate::http_server::requests::request_headers::has_debug_header::has_debug_header;
 use crate::http_server::session::lookup::user_session_extended::UserSessionExtended;
@@ -144,20 +147,21 @@ pub async fn enqueue_studio_workflow_handler(
 
   // ==================== RATE LIMIT ==================== //
 
-  let rate_limiter = match maybe_user_session {
-    None => &server_state.redis_rate_limiters.logged_out,
-    Some(ref user) => {
-      if user.role.is_banned {
-        return Err(VstError::NotAuthorized);
-      }
-      &server_state.redis_rate_limiters.logged_in
-    },
-  };
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(VstError::RateLimited);
+  if let Some(ref user) = maybe_user_session {
+    if user.role.is_banned {
+      return Err(VstError::NotAuthorized);
+    }
   }
 
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Generation,
+    maybe_user_token: None,
+    tier: RateLimitTier::for_logged_in(maybe_user_session.is_some()),
+  }).await
+      .map_err(|_err| VstError::RateLimited)?;
+
   // ==================== HANDLE IDEMPOTENCY ==================== //
 
   if let Err(reason) = validate_idempotency_token_format(&request.uuid_idempotency_token) {
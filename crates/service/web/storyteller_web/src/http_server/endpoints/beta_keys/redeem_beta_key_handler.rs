@@ -7,8 +7,11 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use log::warn;
 use utoipa::ToSchema;
 
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::session::lookup::user_session_feature_flags::UserSessionFeatureFlags;
 use enums::by_table::beta_keys::beta_key_product::BetaKeyProduct;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::by_table::users::user_feature_flag::UserFeatureFlag;
 use http_server_common::request::get_request_ip::get_request_ip;
 use mysql_queries::queries::beta_keys::get_beta_key_by_value::get_beta_key_by_value;
@@ -102,11 +105,15 @@ pub async fn redeem_beta_key_handler(
         RequireUserSessionError::NotAuthorized => RedeemBetaKeyError::NotAuthorized,
       })?;
 
-  let rate_limiter = &server_state.redis_rate_limiters.logged_out;
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(RedeemBetaKeyError::RateLimited);
-  }
+  // NB: Keys are guessable secrets, so count attempts like other credential checks.
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Auth,
+    maybe_user_token: Some(&user_session.user_token),
+    tier: RateLimitTier::Free,
+  }).await
+      .map_err(|_err| RedeemBetaKeyError::RateLimited)?;
 
   let maybe_beta_key = get_beta_key_by_value(&request.beta_key, &server_state.mysql_pool)
       .await
@@ -8,6 +8,7 @@ use sqlx::Acquire;
 
 use artcraft_api_defs::characters::create_character::{CreateCharacterRequest, CreateCharacterResponse};
 use enums::by_table::characters::character_type::CharacterType;
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_ip::get_request_ip;
 use mysql_queries::queries::characters::create_pending_character::{create_pending_character, CreatePendingCharacterArgs};
@@ -58,17 +59,17 @@ pub async fn create_character_handler(
       .acquire()
       .await?;
 
-  let maybe_user_session = server_state
+  let maybe_api_caller = server_state
       .session_checker
-      .maybe_get_user_session_from_connection(&http_request, &mut mysql_connection)
+      .maybe_get_api_caller_session_from_connection(&http_request, PersonalAccessTokenScope::ManageCharacters, &mut mysql_connection)
       .await
       .map_err(|e| {
         warn!("Session checker error: {:?}", e);
         AdvancedCommonWebError::from(e)
       })?;
 
-  let user_session = match maybe_user_session {
-    Some(session) => session,
+  let user_session = match maybe_api_caller {
+    Some(api_caller) => api_caller.user_session,
     None => return Err(AdvancedCommonWebError::NotAuthorized),
   };
 
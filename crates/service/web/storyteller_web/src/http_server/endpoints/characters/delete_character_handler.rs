@@ -5,12 +5,13 @@ use actix_web::{web, HttpRequest};
 use log::{info, warn};
 
 use artcraft_api_defs::characters::delete_character::{DeleteCharacterPathInfo, DeleteCharacterResponse};
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
 use tokens::tokens::characters::CharacterToken;
 use mysql_queries::queries::characters::delete_character::delete_character;
 use mysql_queries::queries::characters::get_character_by_token_including_deleted::get_character_by_token_including_deleted;
 
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
-use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::http_server::web_utils::user_session::require_api_caller_session_using_connection::require_api_caller_session_using_connection;
 use crate::state::server_state::ServerState;
 
 /// Delete a character.
@@ -40,14 +41,17 @@ pub async fn delete_character_handler(
 
   let mut mysql_connection = server_state.mysql_pool.acquire().await?;
 
-  let user_session = require_user_session_using_connection(
+  let api_caller = require_api_caller_session_using_connection(
     &http_request,
     &server_state.session_checker,
+    PersonalAccessTokenScope::ManageCharacters,
     &mut mysql_connection,
   ).await?;
 
+  let user_session = &api_caller.user_session;
   let user_token = &user_session.user_token;
-  let is_mod = user_session.is_mod();
+  // NB: Moderator powers aren't available through personal access tokens.
+  let is_mod = user_session.is_mod() && api_caller.maybe_personal_access_token.is_none();
 
   // --- Look up the character (including deleted) ---
 
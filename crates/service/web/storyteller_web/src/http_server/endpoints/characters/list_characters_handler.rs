@@ -8,6 +8,7 @@ use artcraft_api_defs::characters::list_characters::{ListCharactersEntry, ListCh
 use artcraft_api_defs::common::responses::media_links::MediaLinks;
 use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path::MediaFileBucketPath;
 use enums::common::generation::common_model_type::CommonModelType;
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
 use mysql_queries::queries::characters::list_active_characters_for_user::list_active_characters_for_user;
 use mysql_queries::queries::media_files::get::batch_get_media_files_by_tokens::{batch_get_media_files_by_tokens_with_connection, MediaFilesByTokensRecord};
 use tokens::tokens::media_files::MediaFileToken;
@@ -16,7 +17,7 @@ use crate::http_server::common_responses::advanced_common_web_error::AdvancedCom
 use crate::http_server::common_responses::media::media_domain::MediaDomain;
 use crate::http_server::common_responses::media::media_links_builder::MediaLinksBuilder;
 use crate::http_server::endpoints::media_files::helpers::get_media_domain::get_media_domain;
-use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::http_server::web_utils::user_session::require_api_caller_session_using_connection::require_api_caller_session_using_connection;
 use crate::state::server_state::ServerState;
 
 /// List characters for the current session.
@@ -39,13 +40,14 @@ pub async fn list_characters_handler(
 
   let mut mysql_connection = server_state.mysql_pool.acquire().await?;
 
-  let user_session = require_user_session_using_connection(
+  let api_caller = require_api_caller_session_using_connection(
     &http_request,
     &server_state.session_checker,
+    PersonalAccessTokenScope::ManageCharacters,
     &mut mysql_connection,
   ).await?;
 
-  let user_token = &user_session.user_token;
+  let user_token = &api_caller.user_session.user_token;
 
   // --- Query characters ---
 
@@ -8,11 +8,14 @@ use log::{info, warn};
 
 use config::bad_urls::is_bad_tts_model_download_url;
 use enums::by_table::generic_download_jobs::generic_download_type::GenericDownloadType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_ip::get_request_ip;
 use http_server_common::response::serialize_as_json_error::serialize_as_json_error;
 use mysql_queries::queries::generic_download::web::insert_generic_download_job::{insert_generic_download_job, InsertGenericDownloadJobArgs};
 
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::http_server::validations::validate_model_title::validate_model_title;
 use crate::state::server_state::ServerState;
@@ -72,9 +75,14 @@ pub async fn enqueue_generic_download_handler(
   request: web::Json<EnqueueGenericDownloadRequest>,
   server_state: web::Data<Arc<ServerState>>) -> Result<HttpResponse, EnqueueGenericDownloadError>
 {
-  if let Err(_err) = server_state.redis_rate_limiters.model_upload.rate_limit_request(&http_request).await {
-    return Err(EnqueueGenericDownloadError::RateLimited);
-  }
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Uploads,
+    maybe_user_token: None,
+    tier: RateLimitTier::LoggedOut,
+  }).await
+      .map_err(|_err| EnqueueGenericDownloadError::RateLimited)?;
 
   let maybe_user_session = server_state
     .session_checker
@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use actix_web::web::{Json, Path};
+use actix_web::{web, HttpRequest};
+use log::{info, warn};
+use sqlx::Acquire;
+
+use artcraft_api_defs::jobs::cancel_inference_job::{CancelInferenceJobOutcome, CancelInferenceJobPathInfo, CancelInferenceJobResponse};
+use artcraft_router::api::provider::Provider;
+use artcraft_router::generate::cancel_generation::cancel_generation_outcome::CancelGenerationOutcome;
+use artcraft_router::generate::cancel_generation::cancel_generation_request::CancelGenerationRequest;
+use enums::by_table::generic_inference_jobs::inference_job_external_third_party::InferenceJobExternalThirdParty;
+use enums::common::job_status_plus::JobStatusPlus;
+use http_server_common::request::get_request_ip::get_request_ip;
+use mysql_queries::queries::generic_inference::web::get_inference_job_for_cancellation::{get_inference_job_for_cancellation, InferenceJobForCancellation};
+use mysql_queries::queries::generic_inference::web::mark_unfinished_generic_inference_job_cancelled_by_user::mark_unfinished_generic_inference_job_cancelled_by_user;
+use mysql_queries::queries::wallets::refund::try_to_refund_ledger_entry::{try_to_refund_ledger_entry, WalletRefundOutcome};
+use tokens::tokens::users::UserToken;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::endpoints::omni_gen::generate::video::helpers::build_router_client::build_router_client;
+use crate::state::server_state::ServerState;
+
+/// Cancel an in-flight job and refund its credits.
+///
+/// Unlike `DELETE /v1/jobs/job/{token}`, this only marks the job cancelled once it has
+/// really stopped: third party jobs must be cancelled with the provider, and our own
+/// jobs must not have started yet. Credits are refunded only in that case. When the
+/// provider accepts the cancellation without confirming it, the job is left as is and
+/// refunded when its failure arrives.
+///
+/// The user must own the job. Jobs created by a logged in user can only be cancelled from
+/// that user's session; jobs created while logged out are matched by IP address.
+#[utoipa::path(
+  post,
+  tag = "Jobs",
+  path = "/v1/jobs/job/{token}/cancel",
+  params(
+    ("path" = CancelInferenceJobPathInfo, description = "Path params for Request")
+  ),
+  responses(
+    (status = 200, body = CancelInferenceJobResponse),
+    (status = 401, body = AdvancedCommonWebError),
+    (status = 404, body = AdvancedCommonWebError),
+    (status = 500, body = AdvancedCommonWebError),
+  ),
+)]
+pub async fn cancel_inference_job_handler(
+  http_request: HttpRequest,
+  path: Path<CancelInferenceJobPathInfo>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<CancelInferenceJobResponse>, AdvancedCommonWebError> {
+  let mut mysql_connection = server_state.mysql_pool.acquire().await?;
+
+  let maybe_user_session = server_state
+      .session_checker
+      .maybe_get_user_session_from_connection(&http_request, &mut mysql_connection)
+      .await?;
+
+  let job = get_inference_job_for_cancellation(&path.token, &mut mysql_connection)
+      .await?
+      .ok_or(AdvancedCommonWebError::NotFound)?;
+
+  let maybe_session_user_token = maybe_user_session
+      .as_ref()
+      .map(|session| &session.user_token);
+
+  let request_ip = get_request_ip(&http_request);
+
+  if !is_job_owner(maybe_session_user_token, &request_ip, job.maybe_creator_user_token.as_ref(), &job.creator_ip_address) {
+    return Err(AdvancedCommonWebError::NotAuthorized);
+  }
+
+  if is_finished(job.status) {
+    return Ok(respond(CancelInferenceJobOutcome::AlreadyFinished, false));
+  }
+
+  let only_if_not_started = match job.maybe_external_third_party {
+    // Our own workers can't be interrupted once they pick up a job.
+    None => true,
+    Some(third_party) => match cancel_with_provider(third_party, &job, &server_state).await? {
+      CancelGenerationOutcome::Cancelled => false,
+      CancelGenerationOutcome::CancellationRequested => {
+        // Not stopped yet. The provider's webhook (or our poller) finishes the job,
+        // and failed jobs are refunded there.
+        return Ok(respond(CancelInferenceJobOutcome::CancellationRequested, false));
+      }
+      CancelGenerationOutcome::AlreadyFinished => {
+        return Ok(respond(CancelInferenceJobOutcome::AlreadyFinished, false));
+      }
+      CancelGenerationOutcome::NotCancellable => {
+        return Ok(respond(CancelInferenceJobOutcome::NotCancellable, false));
+      }
+    },
+  };
+
+  // Mark the job and refund it together, so a cancelled job is never left unrefunded.
+  let mut transaction = mysql_connection.begin().await?;
+
+  let cancelled = mark_unfinished_generic_inference_job_cancelled_by_user(
+    &job.job_token, only_if_not_started, &mut transaction).await?;
+
+  if !cancelled {
+    // The job moved on while we were looking at it.
+    transaction.rollback().await?;
+    let outcome = if only_if_not_started {
+      CancelInferenceJobOutcome::NotCancellable
+    } else {
+      CancelInferenceJobOutcome::AlreadyFinished
+    };
+    return Ok(respond(outcome, false));
+  }
+
+  let mut refunded = false;
+
+  if let Some(ledger_token) = job.maybe_wallet_ledger_entry_token.as_ref() {
+    let refund_outcome = try_to_refund_ledger_entry(ledger_token, &mut transaction)
+        .await
+        .map_err(|err| {
+          warn!("Failed to refund cancelled job {} (ledger {}): {:?}",
+            job.job_token.as_str(), ledger_token.as_str(), err);
+          AdvancedCommonWebError::server_error_with_message("could not refund cancelled job")
+        })?;
+
+    if let WalletRefundOutcome::Refunded(summary) = refund_outcome {
+      info!("Refunded {} credits for cancelled job {} (ledger {} → refund ledger {}).",
+        summary.refund_amount,
+        job.job_token.as_str(),
+        ledger_token.as_str(),
+        summary.refund_ledger_entry_token.as_str());
+      refunded = true;
+    }
+  }
+
+  transaction.commit().await?;
+
+  Ok(respond(CancelInferenceJobOutcome::Cancelled, refunded))
+}
+
+async fn cancel_with_provider(
+  third_party: InferenceJobExternalThirdParty,
+  job: &InferenceJobForCancellation,
+  server_state: &ServerState,
+) -> Result<CancelGenerationOutcome, AdvancedCommonWebError> {
+  let (provider, use_alternate_kinovi) = match third_party {
+    InferenceJobExternalThirdParty::Fal => (Provider::Fal, false),
+    InferenceJobExternalThirdParty::GmiCloud => (Provider::GmiCloud, false),
+    InferenceJobExternalThirdParty::Mock => (Provider::Mock, false),
+    InferenceJobExternalThirdParty::Seedance2Pro => (Provider::Seedance2Pro, false),
+    InferenceJobExternalThirdParty::Seedance2ProAlt => (Provider::Seedance2Pro, true),
+    InferenceJobExternalThirdParty::Beeble
+      | InferenceJobExternalThirdParty::Worldlabs => return Ok(CancelGenerationOutcome::NotCancellable),
+  };
+
+  let Some(provider_job_id) = job.maybe_external_third_party_id.clone() else {
+    return Ok(CancelGenerationOutcome::NotCancellable);
+  };
+
+  let router_client = build_router_client(provider, server_state, use_alternate_kinovi)?;
+
+  let request = CancelGenerationRequest {
+    provider,
+    provider_job_id,
+    maybe_queue_status_url: job.maybe_external_third_party_queue_url.clone(),
+  };
+
+  request.send(&router_client)
+      .await
+      .map_err(|err| {
+        warn!("Provider cancellation failed for job {}: {:?}", job.job_token.as_str(), err);
+        AdvancedCommonWebError::from_error(err)
+      })
+}
+
+/// Only a job created while logged out can be claimed by IP address; otherwise anyone
+/// sharing the creator's IP (or a logged out request from it) could cancel it.
+fn is_job_owner(
+  maybe_session_user_token: Option<&UserToken>,
+  request_ip: &str,
+  maybe_creator_user_token: Option<&UserToken>,
+  creator_ip_address: &str,
+) -> bool {
+  match maybe_creator_user_token {
+    Some(creator_user_token) => maybe_session_user_token == Some(creator_user_token),
+    None => request_ip == creator_ip_address,
+  }
+}
+
+fn is_finished(status: JobStatusPlus) -> bool {
+  match status {
+    JobStatusPlus::Pending
+      | JobStatusPlus::Started
+      | JobStatusPlus::AttemptFailed => false,
+    JobStatusPlus::CompleteSuccess
+      | JobStatusPlus::CompleteFailure
+      | JobStatusPlus::Dead
+      | JobStatusPlus::CancelledByUser
+      | JobStatusPlus::CancelledBySystem => true,
+  }
+}
+
+fn respond(outcome: CancelInferenceJobOutcome, refunded: bool) -> Json<CancelInferenceJobResponse> {
+  Json(CancelInferenceJobResponse {
+    success: true,
+    outcome,
+    refunded,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn logged_in_creator_must_use_their_session() {
+    let creator = UserToken::new_from_str("U:CREATOR");
+    let other = UserToken::new_from_str("U:OTHER");
+
+    assert!(is_job_owner(Some(&creator), "1.1.1.1", Some(&creator), "2.2.2.2"));
+    assert!(!is_job_owner(Some(&other), "2.2.2.2", Some(&creator), "2.2.2.2"));
+
+    // Sharing the creator's IP isn't enough.
+    assert!(!is_job_owner(None, "2.2.2.2", Some(&creator), "2.2.2.2"));
+  }
+
+  #[test]
+  fn logged_out_jobs_are_matched_by_ip() {
+    let user = UserToken::new_from_str("U:USER");
+
+    assert!(is_job_owner(None, "2.2.2.2", None, "2.2.2.2"));
+    assert!(is_job_owner(Some(&user), "2.2.2.2", None, "2.2.2.2"));
+    assert!(!is_job_owner(None, "1.1.1.1", None, "2.2.2.2"));
+    assert!(!is_job_owner(Some(&user), "1.1.1.1", None, "2.2.2.2"));
+  }
+}
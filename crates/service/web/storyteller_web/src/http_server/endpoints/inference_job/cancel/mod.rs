@@ -0,0 +1 @@
+pub mod cancel_inference_job_handler;
@@ -1,4 +1,5 @@
 pub mod admin;
+pub mod cancel;
 pub mod delete;
 pub mod get;
 pub mod list;
@@ -15,6 +15,7 @@ use enums::common::view_as::ViewAs;
 use enums::common::visibility::Visibility;
 use enums::no_table::style_transfer::style_transfer_name::StyleTransferName;
 use enums::by_table::media_files::media_file_origin_model_type::MediaFileOriginModelType;
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
 use log::{info, warn};
 use mysql_queries::queries::media_files::list::list_media_files_for_user::{list_media_files_for_user, ListMediaFileForUserArgs};
 use tokens::tokens::media_files::MediaFileToken;
@@ -196,25 +197,32 @@ pub async fn list_media_files_for_user_handler(
   server_state: web::Data<Arc<ServerState>>
 ) -> Result<Json<ListMediaFilesForUserSuccessResponse>, AdvancedCommonWebError>
 {
-  let maybe_user_session = server_state
+  let mut mysql_connection = server_state.mysql_pool.acquire().await?;
+
+  // NB: Scripts can see their own (non-public) files with a personal access token.
+  let maybe_api_caller = server_state
       .session_checker
-      .maybe_get_user_session(&http_request, &server_state.mysql_pool)
+      .maybe_get_api_caller_session_from_connection(&http_request, PersonalAccessTokenScope::ReadMedia, &mut mysql_connection)
       .await?;
 
+  let maybe_user_session = maybe_api_caller.as_ref()
+      .map(|api_caller| &api_caller.user_session);
+
   let mut is_author = false;
   let mut is_mod = false;
 
   // NB: Temporary rollout flag for certain file types (BVH, etc).
   let mut is_allowed_studio_access = allowed_studio_access(
-    maybe_user_session.as_ref(),
+    maybe_user_session,
     &server_state.flags
   );
 
-  match maybe_user_session {
+  match maybe_api_caller.as_ref() {
     None => {},
-    Some(session) => {
-      is_author = session.username == path.username;
-      is_mod = session.can_ban_users;
+    Some(api_caller) => {
+      is_author = api_caller.user_session.username == path.username;
+      // NB: Moderator views aren't available through personal access tokens.
+      is_mod = api_caller.user_session.can_ban_users && api_caller.maybe_personal_access_token.is_none();
     },
   };
 
@@ -9,6 +9,7 @@ use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path:
 use enums::by_table::media_files::media_file_class::MediaFileClass;
 use enums::by_table::media_files::media_file_origin_category::MediaFileOriginCategory;
 use enums::by_table::media_files::media_file_type::MediaFileType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
 use http_server_common::request::get_request_ip::get_request_ip;
@@ -22,6 +23,7 @@ use tokens::tokens::media_files::MediaFileToken;
 
 use crate::http_server::endpoints::media_files::upload::upload_error::MediaFileUploadError;
 use crate::http_server::endpoints::media_files::upload::upload_generic::drain_multipart_request::{drain_multipart_request, MediaFileUploadSource};
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_rate_limit, EnforceRateLimitArgs};
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::state::server_state::ServerState;
 
@@ -88,14 +90,15 @@ pub async fn process_upload_media_file(
 
   // ==================== RATE LIMIT ==================== //
 
-  let rate_limiter = match maybe_user_session {
-    None => &server_state.redis_rate_limiters.file_upload_logged_out,
-    Some(ref _session) => &server_state.redis_rate_limiters.file_upload_logged_in,
-  };
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(MediaFileUploadError::RateLimited);
-  }
+  enforce_rate_limit(EnforceRateLimitArgs {
+    http_request,
+    server_state,
+    endpoint_group: RateLimitEndpointGroup::Uploads,
+    maybe_user_session: maybe_user_session.as_ref(),
+    maybe_personal_access_token: None,
+    mysql_connection: &mut mysql_connection,
+  }).await
+      .map_err(|_err| MediaFileUploadError::RateLimited)?;
 
   // ==================== READ MULTIPART REQUEST ==================== //
 
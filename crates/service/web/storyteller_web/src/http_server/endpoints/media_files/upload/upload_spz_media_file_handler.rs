@@ -14,25 +14,31 @@ use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path:
 use enums::by_table::media_files::media_file_class::MediaFileClass;
 use enums::by_table::media_files::media_file_origin_category::MediaFileOriginCategory;
 use enums::by_table::media_files::media_file_type::MediaFileType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
+use errors::AnyhowResult;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
 use http_server_common::request::get_request_ip::get_request_ip;
-use mimetypes::mimetype_for_bytes::get_mimetype_for_bytes;
 use mysql_queries::queries::idepotency_tokens::insert_idempotency_token::insert_idempotency_token;
 use mysql_queries::queries::media_files::create::insert_builder::media_file_insert_builder::MediaFileInsertBuilder;
 use mysql_queries::queries::media_files::create::specialized_insert::insert_media_file_from_file_upload::{insert_media_file_from_file_upload, InsertMediaFileFromUploadArgs, UploadType};
+use splats::ingest_splat::{ingest_splat, IngestedSplat, PREVIEW_MIME_TYPE, SPZ_MIME_TYPE};
+use splats::splat_format::SplatFormat;
+use tokens::tokens::anonymous_visitor_tracking::AnonymousVisitorTrackingToken;
 use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::users::UserToken;
 
 use crate::http_server::endpoints::media_files::upload::upload_error::MediaFileUploadError;
 use crate::http_server::endpoints::media_files::upload::common_utils::try_parse_generation_provider::try_parse_generation_provider;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_rate_limit, EnforceRateLimitArgs};
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::state::server_state::ServerState;
 
-const GZIP_MIME_TYPE: &str = "application/gzip";
 const SPZ_EXTENSION: &str = ".spz";
 const CERAMIC_SPZ_EXTENSION: &str = ".ceramic.spz";
+const PREVIEW_EXTENSION: &str = ".png";
 
-/// Form-multipart request fields for SPZ (Gaussian Splat) upload.
+/// Form-multipart request fields for SPZ or PLY (Gaussian Splat) upload.
 ///
 /// IF VIEWING DOCS, PLEASE SEE BOTTOM OF PAGE `UploadSpzMediaFileForm` (Under "Schema") FOR DETAILS ON FIELDS AND NULLABILITY.
 #[derive(MultipartForm, ToSchema)]
@@ -43,7 +49,7 @@ pub struct UploadSpzMediaFileForm {
   #[schema(value_type = String, format = Binary)]
   uuid_idempotency_token: Text<String>,
 
-  /// The uploaded SPZ file
+  /// The uploaded SPZ or PLY file. PLY files are converted to SPZ.
   #[multipart(limit = "512 MiB")]
   #[schema(value_type = Vec<u8>, format = Binary)]
   file: TempFile,
@@ -68,12 +74,35 @@ pub struct UploadSpzMediaFileForm {
 pub struct UploadSpzMediaFileSuccessResponse {
   pub success: bool,
   pub media_file_token: MediaFileToken,
+  pub splat_details: UploadSpzMediaFileSplatDetails,
 }
 
-/// This endpoint is for uploading SPZ (Gaussian Splat) files.
+/// What we found in the uploaded splat.
+#[derive(Serialize, ToSchema)]
+pub struct UploadSpzMediaFileSplatDetails {
+  /// Number of gaussians.
+  pub point_count: u32,
+
+  /// Spherical harmonics degree (0-3).
+  pub sh_degree: u8,
+
+  /// Axis aligned bounds of the gaussian centers, as [x, y, z].
+  #[schema(value_type = Option<Vec<f32>>)]
+  pub maybe_bounding_box_min: Option<[f32; 3]>,
+
+  #[schema(value_type = Option<Vec<f32>>)]
+  pub maybe_bounding_box_max: Option<[f32; 3]>,
+
+  /// True if the upload was a PLY file that we converted to SPZ.
+  pub was_converted_from_ply: bool,
+}
+
+/// This endpoint is for uploading SPZ or PLY (Gaussian Splat) files.
 ///
 /// SPZ files are compressed Gaussian splat files used for 3D scene representation.
-/// The file must have a `.spz` extension and be gzip-compressed.
+/// The file must have a `.spz` or `.ply` extension. The file is fully validated (malformed or
+/// truncated splats are rejected), PLY files are converted to SPZ, and a top-down point density
+/// preview is attached as the cover image.
 #[utoipa::path(
   post,
   tag = "Media Files (Upload)",
@@ -135,14 +164,15 @@ pub async fn upload_spz_media_file_handler(
 
   // ==================== RATE LIMIT ==================== //
 
-  let rate_limiter = match maybe_user_session {
-    None => &server_state.redis_rate_limiters.file_upload_logged_out,
-    Some(ref _session) => &server_state.redis_rate_limiters.file_upload_logged_in,
-  };
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(MediaFileUploadError::RateLimited);
-  }
+  enforce_rate_limit(EnforceRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Uploads,
+    maybe_user_session: maybe_user_session.as_ref(),
+    maybe_personal_access_token: None,
+    mysql_connection: &mut mysql_connection,
+  }).await
+      .map_err(|_err| MediaFileUploadError::RateLimited)?;
 
   // ==================== HANDLE IDEMPOTENCY ==================== //
 
@@ -190,12 +220,10 @@ pub async fn upload_spz_media_file_handler(
       .map(|s| s.to_ascii_lowercase())
       .unwrap_or_default();
 
-  // Validate file extension
-  if !filename_lowercase.ends_with(".spz") {
-    return Err(MediaFileUploadError::BadInput(
-      "File must have .spz extension".to_string()
-    ));
-  }
+  let splat_format = SplatFormat::from_filename(&filename_lowercase)
+      .ok_or_else(|| MediaFileUploadError::BadInput(
+        "File must have .spz or .ply extension".to_string()
+      ))?;
 
   let mut file_bytes = Vec::new();
   form.file.file.read_to_end(&mut file_bytes)
@@ -204,18 +232,27 @@ pub async fn upload_spz_media_file_handler(
         MediaFileUploadError::ServerError
       })?;
 
-  // Validate mimetype (SPZ files are gzip-compressed)
-  let detected_mimetype = get_mimetype_for_bytes(file_bytes.as_ref());
+  // NB: Parsing and conversion walk every gaussian, so keep them off the async workers.
+  let ingested = tokio::task::spawn_blocking(move || ingest_splat(file_bytes, splat_format))
+      .await
+      .map_err(|e| {
+        error!("Splat ingestion task error: {:?}", e);
+        MediaFileUploadError::ServerError
+      })?
+      .map_err(|e| {
+        if e.is_invalid_input() {
+          MediaFileUploadError::BadInput(format!("Invalid splat file: {}", e))
+        } else {
+          error!("Splat ingestion error: {:?}", e);
+          MediaFileUploadError::ServerError
+        }
+      })?;
 
-  if detected_mimetype != Some(GZIP_MIME_TYPE) {
-    return Err(MediaFileUploadError::BadInput(
-      "SPZ file must be gzip-compressed".to_string()
-    ));
-  }
+  info!("Ingested splat ({:?}): {:?}", ingested.source_format, ingested.metadata);
 
-  let file_size_bytes = file_bytes.len();
+  let file_size_bytes = ingested.spz_bytes.len();
 
-  let hash = sha256_hash_bytes(&file_bytes)
+  let hash = sha256_hash_bytes(&ingested.spz_bytes)
       .map_err(|io_error| {
         error!("Problem hashing bytes: {:?}", io_error);
         MediaFileUploadError::ServerError
@@ -224,7 +261,7 @@ pub async fn upload_spz_media_file_handler(
   // ==================== UPLOAD AND SAVE ==================== //
 
   // Check for WorldLabs ceramic SPZ files
-  let is_world_labs_spz = filename_lowercase.contains("ceramic") && filename_lowercase.ends_with(".spz");
+  let is_world_labs_spz = filename_lowercase.contains("ceramic") && splat_format == SplatFormat::Spz;
 
   let extension = if is_world_labs_spz {
     CERAMIC_SPZ_EXTENSION
@@ -240,14 +277,29 @@ pub async fn upload_spz_media_file_handler(
 
   server_state.public_bucket_client.upload_file_with_content_type(
     public_upload_path.get_full_object_path_str(),
-    file_bytes.as_ref(),
-    GZIP_MIME_TYPE)
+    ingested.spz_bytes.as_ref(),
+    SPZ_MIME_TYPE)
       .await
       .map_err(|e| {
         warn!("Upload SPZ bytes to bucket error: {:?}", e);
         MediaFileUploadError::ServerError
       })?;
 
+  let maybe_cover_token = match upload_preview_cover_image(
+    &server_state,
+    &ingested,
+    maybe_user_token.as_ref(),
+    maybe_avt_token.as_ref(),
+    &ip_address,
+    creator_set_visibility,
+  ).await {
+    Ok(token) => Some(token),
+    Err(err) => {
+      warn!("Failed to upload splat preview; continuing without cover: {:?}", err);
+      None
+    }
+  };
+
   let maybe_generation_provider = form.maybe_generation_provider
       .as_ref()
       .and_then(|text| try_parse_generation_provider(text.as_ref()));
@@ -260,10 +312,11 @@ pub async fn upload_spz_media_file_handler(
       .creator_ip_address(&ip_address)
       .creator_set_visibility(creator_set_visibility)
       .media_file_origin_category(MediaFileOriginCategory::Upload)
-      .mime_type(GZIP_MIME_TYPE)
+      .mime_type(SPZ_MIME_TYPE)
       .file_size_bytes(file_size_bytes as u64)
       .checksum_sha2(&hash)
       .maybe_title(maybe_title.as_deref())
+      .maybe_cover_image_media_file_token(maybe_cover_token.as_ref())
       .is_intermediate_system_file(false)
       .public_bucket_directory_hash(&public_upload_path)
       .maybe_origin_filename(form.file.file_name.as_deref())
@@ -277,8 +330,57 @@ pub async fn upload_spz_media_file_handler(
 
   info!("new SPZ media file token: {:?}", &media_token);
 
+  let maybe_bounding_box = ingested.metadata.maybe_bounding_box.as_ref();
+
   Ok(Json(UploadSpzMediaFileSuccessResponse {
     success: true,
     media_file_token: media_token,
+    splat_details: UploadSpzMediaFileSplatDetails {
+      point_count: ingested.metadata.point_count,
+      sh_degree: ingested.metadata.sh_degree,
+      maybe_bounding_box_min: maybe_bounding_box.map(|bounds| bounds.min),
+      maybe_bounding_box_max: maybe_bounding_box.map(|bounds| bounds.max),
+      was_converted_from_ply: ingested.source_format == SplatFormat::Ply,
+    },
   }))
 }
+
+/// Upload the point density preview and create an (intermediate) image media file for it.
+async fn upload_preview_cover_image(
+  server_state: &ServerState,
+  ingested: &IngestedSplat,
+  maybe_user_token: Option<&UserToken>,
+  maybe_avt_token: Option<&AnonymousVisitorTrackingToken>,
+  ip_address: &str,
+  creator_set_visibility: Visibility,
+) -> AnyhowResult<MediaFileToken> {
+  let preview_bytes = &ingested.preview_png_bytes;
+
+  let hash = sha256_hash_bytes(preview_bytes)?;
+
+  let preview_path = MediaFileBucketPath::generate_new(Some("artcraft_"), Some(PREVIEW_EXTENSION));
+
+  server_state.public_bucket_client.upload_file_with_content_type(
+    preview_path.get_full_object_path_str(),
+    preview_bytes.as_ref(),
+    PREVIEW_MIME_TYPE)
+      .await?;
+
+  let token = MediaFileInsertBuilder::new()
+      .media_file_class(MediaFileClass::Image)
+      .media_file_type(MediaFileType::Png)
+      .maybe_creator_user(maybe_user_token)
+      .maybe_creator_anonymous_visitor(maybe_avt_token)
+      .creator_ip_address(ip_address)
+      .creator_set_visibility(creator_set_visibility)
+      .media_file_origin_category(MediaFileOriginCategory::Upload)
+      .mime_type(PREVIEW_MIME_TYPE)
+      .file_size_bytes(preview_bytes.len() as u64)
+      .checksum_sha2(&hash)
+      .is_intermediate_system_file(true)
+      .public_bucket_directory_hash(&preview_path)
+      .insert_pool(&server_state.mysql_pool)
+      .await?;
+
+  Ok(token)
+}
@@ -10,19 +10,21 @@ use utoipa::ToSchema;
 use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path::MediaFileBucketPath;
 use enums::by_table::media_files::media_file_class::MediaFileClass;
 use enums::by_table::media_files::media_file_type::MediaFileType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
 use http_server_common::request::get_request_ip::get_request_ip;
+use media::probe::probe_media::probe_media_bytes;
 use mimetypes::mimetype_for_bytes::get_mimetype_for_bytes;
 use mimetypes::mimetype_to_extension::mimetype_to_extension;
 use mysql_queries::queries::idepotency_tokens::insert_idempotency_token::insert_idempotency_token;
 use mysql_queries::queries::media_files::create::specialized_insert::insert_media_file_from_file_upload::{insert_media_file_from_file_upload, InsertMediaFileFromUploadArgs, UploadType};
 use tokens::tokens::media_files::MediaFileToken;
-use videos::get_mp4_info::{get_mp4_info_for_bytes, get_mp4_info_for_bytes_and_len};
 
 use crate::http_server::endpoints::media_files::upload::upload_error::MediaFileUploadError;
 use crate::http_server::endpoints::media_files::upload::upload_video_old::drain_multipart_request::drain_multipart_request;
 use crate::http_server::endpoints::media_files::upload::upload_video_old::drain_multipart_request::MediaFileUploadSource;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_rate_limit, EnforceRateLimitArgs};
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::state::server_state::ServerState;
 
@@ -95,14 +97,15 @@ pub async fn upload_video_media_file_handler(
 
   // ==================== RATE LIMIT ==================== //
 
-  let rate_limiter = match maybe_user_session {
-    None => &server_state.redis_rate_limiters.file_upload_logged_out,
-    Some(ref _session) => &server_state.redis_rate_limiters.file_upload_logged_in,
-  };
-
-  if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-    return Err(MediaFileUploadError::RateLimited);
-  }
+  enforce_rate_limit(EnforceRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Uploads,
+    maybe_user_session: maybe_user_session.as_ref(),
+    maybe_personal_access_token: None,
+    mysql_connection: &mut mysql_connection,
+  }).await
+      .map_err(|_err| MediaFileUploadError::RateLimited)?;
 
   // ==================== READ MULTIPART REQUEST ==================== //
 
@@ -186,12 +189,12 @@ pub async fn upload_video_media_file_handler(
         MediaFileUploadError::ServerError
       })?;
 
-  // ==================== FRAME RATE ==================== //
+  // ==================== VIDEO INFO ==================== //
 
-  let mp4_info = get_mp4_info_for_bytes(file_bytes.as_ref())
+  let media_probe = probe_media_bytes(file_bytes.as_ref())
       .map_err(|err| {
-        warn!("Error reading mp4 info: {:?}", err);
-        MediaFileUploadError::ServerError
+        warn!("Error probing video: {:?}", err);
+        MediaFileUploadError::BadInput("could not read video".to_string())
       })?;
 
   // ==================== UPLOAD AND SAVE ==================== //
@@ -232,7 +235,7 @@ pub async fn upload_video_media_file_handler(
     maybe_animation_type: None,
     maybe_mime_type: Some(mimetype),
     file_size_bytes: file_size_bytes as u64,
-    maybe_duration_millis: Some(mp4_info.duration_millis as u64),
+    maybe_duration_millis: media_probe.maybe_duration_millis,
     sha256_checksum: &hash,
     maybe_title: upload_media_request.title.as_deref(),
     maybe_scene_source_media_file_token: None,
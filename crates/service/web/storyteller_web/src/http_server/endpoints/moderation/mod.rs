@@ -3,11 +3,14 @@ pub mod debug_logs;
 pub mod info;
 pub mod ip_bans;
 pub mod jobs;
+pub mod provider_health;
 pub mod staff_audit_logs;
 pub mod user;
 pub mod user_bans;
 pub mod user_feature_flags;
+pub mod user_rate_limit_overrides;
 pub mod user_referrals;
 pub mod user_sessions;
 pub mod wallet_ledger_entries;
 pub mod wallets;
+pub mod webhook_deliveries;
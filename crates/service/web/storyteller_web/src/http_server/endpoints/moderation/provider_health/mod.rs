@@ -0,0 +1 @@
+pub mod moderator_get_provider_health_handler;
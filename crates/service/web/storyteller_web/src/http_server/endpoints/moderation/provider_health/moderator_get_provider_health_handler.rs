@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest};
+use log::warn;
+
+use artcraft_api_defs::moderation::provider_health::moderator_get_provider_health::{
+  ModeratorGetProviderHealthResponse,
+  ModeratorProviderHealthDetails,
+};
+use artcraft_router::health::provider_health_snapshot::ProviderHealthSnapshot;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::web_utils::user_session::require_moderator::{require_moderator, UseDatabase};
+use crate::state::server_state::ServerState;
+
+/// Circuit breaker state, error rates, and latencies of generation providers (this server instance only).
+#[utoipa::path(
+  get,
+  tag = "Moderation",
+  path = "/v1/moderation/provider_health",
+  responses(
+    (status = 200, description = "Success", body = ModeratorGetProviderHealthResponse),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Server error"),
+  ),
+)]
+pub async fn moderator_get_provider_health_handler(
+  http_request: HttpRequest,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ModeratorGetProviderHealthResponse>, AdvancedCommonWebError> {
+
+  let _user_session = require_moderator(
+    &http_request,
+    &server_state,
+    UseDatabase::GrabNewConnection,
+  ).await.map_err(|err| {
+    warn!("Moderator check failed: {:?}", err);
+    AdvancedCommonWebError::NotAuthorized
+  })?;
+
+  let providers = server_state.provider_health.snapshot_all()
+    .into_iter()
+    .map(to_details)
+    .collect();
+
+  Ok(Json(ModeratorGetProviderHealthResponse {
+    success: true,
+    providers,
+  }))
+}
+
+fn to_details(snapshot: ProviderHealthSnapshot) -> ModeratorProviderHealthDetails {
+  ModeratorProviderHealthDetails {
+    provider: serde_name(&snapshot.provider),
+    circuit_state: serde_name(&snapshot.circuit_state),
+    window_call_count: snapshot.window_call_count as u64,
+    window_failure_count: snapshot.window_failure_count as u64,
+    window_error_rate: snapshot.window_error_rate,
+    consecutive_failures: snapshot.consecutive_failures,
+    latency_p50_millis: snapshot.latency_p50_millis,
+    latency_p90_millis: snapshot.latency_p90_millis,
+    latency_p99_millis: snapshot.latency_p99_millis,
+    total_successes: snapshot.total_successes,
+    total_failures: snapshot.total_failures,
+    total_refused: snapshot.total_refused,
+    maybe_seconds_until_probe: snapshot.seconds_until_probe,
+  }
+}
+
+/// The wire name of a unit enum variant (eg. `Provider::GmiCloud` => "gmi_cloud").
+fn serde_name<T: serde::Serialize + std::fmt::Debug>(value: &T) -> String {
+  match serde_json::to_value(value) {
+    Ok(serde_json::Value::String(name)) => name,
+    _ => format!("{:?}", value),
+  }
+}
@@ -1,4 +1,5 @@
 pub mod moderator_list_subscribing_users_by_signup_date;
 pub mod moderator_list_users_by_signup_date;
+pub mod moderator_reset_user_two_factor_handler;
 pub mod moderator_user_lookup_by_stripe_customer_id_handler;
 pub mod moderator_user_lookup_handler;
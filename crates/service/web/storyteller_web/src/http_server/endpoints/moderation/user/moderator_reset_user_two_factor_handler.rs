@@ -0,0 +1,112 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use actix_web::web::{Json, Path};
+use actix_web::{web, HttpRequest};
+use log::{info, warn};
+
+use artcraft_api_defs::moderation::user::moderator_reset_user_two_factor::{
+  ModeratorResetUserTwoFactorPathInfo,
+  ModeratorResetUserTwoFactorResponse,
+};
+use enums::by_table::staff_audit_logs::staff_audit_action::StaffAuditAction;
+use enums::by_table::staff_audit_logs::staff_audit_entity_type::StaffAuditEntityType;
+use http_server_common::request::get_request_ip::get_request_ip;
+use mysql_queries::queries::staff_audit_logs::insert_staff_audit_log::{
+  insert_staff_audit_log, InsertStaffAuditLogArgs,
+};
+use mysql_queries::queries::users::user_two_factor::delete_user_two_factor_auth::delete_user_two_factor_auth;
+use mysql_queries::queries::users::user_two_factor::delete_user_two_factor_recovery_codes::delete_user_two_factor_recovery_codes;
+use tokens::tokens::users::UserToken;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::web_utils::user_session::require_moderator::{require_moderator, UseDatabase};
+use crate::state::server_state::ServerState;
+
+/// Remove a user's two factor auth and recovery codes (moderation)
+///
+/// For users locked out of their authenticator app without recovery codes. Verify
+/// the account owner out of band first. The user can log in with just their password
+/// afterwards, and may enroll again.
+#[utoipa::path(
+  post,
+  tag = "Moderation",
+  path = "/v1/moderation/users/{user_token}/two_factor/reset",
+  responses(
+    (status = 200, description = "Success", body = ModeratorResetUserTwoFactorResponse),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Server error"),
+  ),
+  params(
+    ("user_token" = UserToken, Path, description = "User to reset two factor auth for"),
+  )
+)]
+pub async fn moderator_reset_user_two_factor_handler(
+  http_request: HttpRequest,
+  path: Path<ModeratorResetUserTwoFactorPathInfo>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ModeratorResetUserTwoFactorResponse>, AdvancedCommonWebError> {
+
+  let user_session = require_moderator(&http_request, &server_state, UseDatabase::GrabNewConnection)
+    .await
+    .map_err(|err| {
+      warn!("Moderator check failed: {:?}", err);
+      AdvancedCommonWebError::NotAuthorized
+    })?;
+
+  let ip_address = get_request_ip(&http_request);
+
+  let mut transaction = server_state.mysql_pool
+    .begin()
+    .await
+    .map_err(|err| {
+      warn!("Failed to begin transaction: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  let had_two_factor = delete_user_two_factor_auth(&path.user_token, &mut *transaction)
+    .await
+    .map_err(|err| {
+      warn!("delete_user_two_factor_auth error: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  delete_user_two_factor_recovery_codes(&path.user_token, &mut *transaction)
+    .await
+    .map_err(|err| {
+      warn!("delete_user_two_factor_recovery_codes error: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  let _audit_token = insert_staff_audit_log(InsertStaffAuditLogArgs {
+    audit_action: StaffAuditAction::ResetUserTwoFactor,
+    maybe_entity_type: Some(StaffAuditEntityType::User),
+    maybe_entity_token: Some(path.user_token.as_str()),
+    staff_user_token: &user_session.user_token,
+    actor_ip_address: &ip_address,
+    mysql_executor: &mut *transaction,
+    phantom: PhantomData,
+  }).await.map_err(|err| {
+    warn!("Failed to insert staff audit log: {:?}", err);
+    AdvancedCommonWebError::from_error(err)
+  })?;
+
+  transaction.commit()
+    .await
+    .map_err(|err| {
+      warn!("Failed to commit transaction: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  info!(
+    "Moderator {} reset two factor auth for user {} (had two factor: {})",
+    user_session.user_token.as_str(),
+    path.user_token.as_str(),
+    had_two_factor,
+  );
+
+  Ok(Json(ModeratorResetUserTwoFactorResponse {
+    success: true,
+    had_two_factor,
+  }))
+}
@@ -0,0 +1,3 @@
+pub mod moderator_delete_user_rate_limit_override_handler;
+pub mod moderator_list_user_rate_limit_overrides_handler;
+pub mod moderator_set_user_rate_limit_override_handler;
@@ -0,0 +1,106 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use actix_web::web::{Json, Path};
+use actix_web::{web, HttpRequest};
+use log::{info, warn};
+
+use artcraft_api_defs::moderation::user_rate_limit_overrides::moderator_delete_user_rate_limit_override::{
+  ModeratorDeleteUserRateLimitOverridePathInfo,
+  ModeratorDeleteUserRateLimitOverrideRequest,
+  ModeratorDeleteUserRateLimitOverrideResponse,
+};
+use enums::by_table::staff_audit_logs::staff_audit_action::StaffAuditAction;
+use enums::by_table::staff_audit_logs::staff_audit_entity_type::StaffAuditEntityType;
+use http_server_common::request::get_request_ip::get_request_ip;
+use mysql_queries::queries::staff_audit_logs::insert_staff_audit_log::{
+  insert_staff_audit_log, InsertStaffAuditLogArgs,
+};
+use mysql_queries::queries::users::user_rate_limit_overrides::delete_user_rate_limit_override::delete_user_rate_limit_override;
+use tokens::tokens::users::UserToken;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::web_utils::user_session::require_moderator::{require_moderator, UseDatabase};
+use crate::state::server_state::ServerState;
+
+/// Remove a user's rate limit override for an endpoint group (moderation)
+///
+/// The user goes back to the limits for their plan.
+#[utoipa::path(
+  post,
+  tag = "Moderation",
+  path = "/v1/moderation/users/{user_token}/rate_limit_overrides/delete",
+  request_body = ModeratorDeleteUserRateLimitOverrideRequest,
+  responses(
+    (status = 200, description = "Success", body = ModeratorDeleteUserRateLimitOverrideResponse),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Server error"),
+  ),
+  params(
+    ("user_token" = UserToken, Path, description = "User to remove the override for"),
+  )
+)]
+pub async fn moderator_delete_user_rate_limit_override_handler(
+  http_request: HttpRequest,
+  path: Path<ModeratorDeleteUserRateLimitOverridePathInfo>,
+  request: Json<ModeratorDeleteUserRateLimitOverrideRequest>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ModeratorDeleteUserRateLimitOverrideResponse>, AdvancedCommonWebError> {
+
+  let user_session = require_moderator(&http_request, &server_state, UseDatabase::GrabNewConnection)
+    .await
+    .map_err(|err| {
+      warn!("Moderator check failed: {:?}", err);
+      AdvancedCommonWebError::NotAuthorized
+    })?;
+
+  let ip_address = get_request_ip(&http_request);
+
+  let mut transaction = server_state.mysql_pool
+    .begin()
+    .await
+    .map_err(|err| {
+      warn!("Failed to begin transaction: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  let had_override = delete_user_rate_limit_override(&path.user_token, request.endpoint_group, &mut *transaction)
+    .await
+    .map_err(|err| {
+      warn!("delete_user_rate_limit_override error: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  let _audit_token = insert_staff_audit_log(InsertStaffAuditLogArgs {
+    audit_action: StaffAuditAction::EditUserRateLimitOverride,
+    maybe_entity_type: Some(StaffAuditEntityType::User),
+    maybe_entity_token: Some(path.user_token.as_str()),
+    staff_user_token: &user_session.user_token,
+    actor_ip_address: &ip_address,
+    mysql_executor: &mut *transaction,
+    phantom: PhantomData,
+  }).await.map_err(|err| {
+    warn!("Failed to insert staff audit log: {:?}", err);
+    AdvancedCommonWebError::from_error(err)
+  })?;
+
+  transaction.commit()
+    .await
+    .map_err(|err| {
+      warn!("Failed to commit transaction: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  info!(
+    "Moderator {} removed {} rate limit override for user {} (had override: {})",
+    user_session.user_token.as_str(),
+    request.endpoint_group.to_str(),
+    path.user_token.as_str(),
+    had_override,
+  );
+
+  Ok(Json(ModeratorDeleteUserRateLimitOverrideResponse {
+    success: true,
+    had_override,
+  }))
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use actix_web::web::{Json, Path};
+use actix_web::{web, HttpRequest};
+use chrono::Utc;
+use log::warn;
+
+use artcraft_api_defs::moderation::user_rate_limit_overrides::moderator_list_user_rate_limit_overrides::{
+  ModeratorListUserRateLimitOverridesPathInfo,
+  ModeratorListUserRateLimitOverridesResponse,
+  UserRateLimitOverride,
+};
+use mysql_queries::queries::users::user_rate_limit_overrides::list_user_rate_limit_overrides::list_user_rate_limit_overrides;
+use tokens::tokens::users::UserToken;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::web_utils::user_session::require_moderator::{require_moderator, UseDatabase};
+use crate::state::server_state::ServerState;
+
+/// List a user's rate limit overrides (moderation)
+#[utoipa::path(
+  get,
+  tag = "Moderation",
+  path = "/v1/moderation/users/{user_token}/rate_limit_overrides",
+  responses(
+    (status = 200, description = "Success", body = ModeratorListUserRateLimitOverridesResponse),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Server error"),
+  ),
+  params(
+    ("user_token" = UserToken, Path, description = "User to list overrides for"),
+  )
+)]
+pub async fn moderator_list_user_rate_limit_overrides_handler(
+  http_request: HttpRequest,
+  path: Path<ModeratorListUserRateLimitOverridesPathInfo>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ModeratorListUserRateLimitOverridesResponse>, AdvancedCommonWebError> {
+
+  let _user_session = require_moderator(&http_request, &server_state, UseDatabase::GrabNewConnection)
+    .await
+    .map_err(|err| {
+      warn!("Moderator check failed: {:?}", err);
+      AdvancedCommonWebError::NotAuthorized
+    })?;
+
+  let records = list_user_rate_limit_overrides(&path.user_token, &server_state.mysql_pool)
+    .await
+    .map_err(|err| {
+      warn!("list_user_rate_limit_overrides error: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  let now = Utc::now();
+
+  let overrides = records.into_iter()
+    .map(|record| UserRateLimitOverride {
+      endpoint_group: record.endpoint_group,
+      is_exempt: record.is_exempt,
+      max_requests: record.max_requests,
+      window_seconds: record.window_seconds,
+      maybe_note: record.maybe_note,
+      creator_user_token: record.creator_user_token,
+      is_expired: record.maybe_expires_at.is_some_and(|expires_at| expires_at <= now),
+      maybe_expires_at: record.maybe_expires_at,
+      updated_at: record.updated_at,
+    })
+    .collect();
+
+  Ok(Json(ModeratorListUserRateLimitOverridesResponse {
+    success: true,
+    overrides,
+  }))
+}
@@ -0,0 +1,166 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use actix_web::web::{Json, Path};
+use actix_web::{web, HttpRequest};
+use chrono::{Duration, Utc};
+use log::{info, warn};
+
+use artcraft_api_defs::moderation::user_rate_limit_overrides::moderator_set_user_rate_limit_override::{
+  ModeratorSetUserRateLimitOverridePathInfo,
+  ModeratorSetUserRateLimitOverrideRequest,
+  ModeratorSetUserRateLimitOverrideResponse,
+};
+use enums::by_table::staff_audit_logs::staff_audit_action::StaffAuditAction;
+use enums::by_table::staff_audit_logs::staff_audit_entity_type::StaffAuditEntityType;
+use http_server_common::request::get_request_ip::get_request_ip;
+use mysql_queries::queries::staff_audit_logs::insert_staff_audit_log::{
+  insert_staff_audit_log, InsertStaffAuditLogArgs,
+};
+use mysql_queries::queries::users::user_rate_limit_overrides::upsert_user_rate_limit_override::{
+  upsert_user_rate_limit_override, UpsertUserRateLimitOverrideArgs,
+};
+use tokens::tokens::users::UserToken;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::web_utils::user_session::require_moderator::{require_moderator, UseDatabase};
+use crate::state::server_state::ServerState;
+
+const MAX_REQUESTS_LIMIT : u32 = 100_000;
+const MAX_WINDOW_SECONDS : u32 = 86_400;
+const MAX_EXPIRES_IN_DAYS : u32 = 365;
+const MAX_NOTE_LENGTH : usize = 255;
+
+/// Set a user's rate limit override for an endpoint group (moderation)
+///
+/// Replaces the limit from the user's plan, or exempts them entirely. Setting an
+/// override again replaces the previous one.
+#[utoipa::path(
+  post,
+  tag = "Moderation",
+  path = "/v1/moderation/users/{user_token}/rate_limit_overrides/set",
+  request_body = ModeratorSetUserRateLimitOverrideRequest,
+  responses(
+    (status = 200, description = "Success", body = ModeratorSetUserRateLimitOverrideResponse),
+    (status = 400, description = "Bad input"),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Server error"),
+  ),
+  params(
+    ("user_token" = UserToken, Path, description = "User to set the override for"),
+  )
+)]
+pub async fn moderator_set_user_rate_limit_override_handler(
+  http_request: HttpRequest,
+  path: Path<ModeratorSetUserRateLimitOverridePathInfo>,
+  request: Json<ModeratorSetUserRateLimitOverrideRequest>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ModeratorSetUserRateLimitOverrideResponse>, AdvancedCommonWebError> {
+
+  let user_session = require_moderator(&http_request, &server_state, UseDatabase::GrabNewConnection)
+    .await
+    .map_err(|err| {
+      warn!("Moderator check failed: {:?}", err);
+      AdvancedCommonWebError::NotAuthorized
+    })?;
+
+  let is_exempt = request.is_exempt.unwrap_or(false);
+
+  let (max_requests, window_seconds) = if is_exempt {
+    (request.max_requests.unwrap_or(0), request.window_seconds.unwrap_or(0))
+  } else {
+    match (request.max_requests, request.window_seconds) {
+      (Some(max_requests), Some(window_seconds)) => (max_requests, window_seconds),
+      _ => return Err(AdvancedCommonWebError::BadInputWithSimpleMessage(
+        "max_requests and window_seconds are required unless is_exempt is set".to_string())),
+    }
+  };
+
+  if !is_exempt && (max_requests == 0 || max_requests > MAX_REQUESTS_LIMIT) {
+    return Err(AdvancedCommonWebError::BadInputWithSimpleMessage(
+      format!("max_requests must be between 1 and {}", MAX_REQUESTS_LIMIT)));
+  }
+
+  if !is_exempt && (window_seconds == 0 || window_seconds > MAX_WINDOW_SECONDS) {
+    return Err(AdvancedCommonWebError::BadInputWithSimpleMessage(
+      format!("window_seconds must be between 1 and {}", MAX_WINDOW_SECONDS)));
+  }
+
+  let maybe_expires_at = match request.maybe_expires_in_days {
+    None => None,
+    Some(days) if days == 0 || days > MAX_EXPIRES_IN_DAYS => {
+      return Err(AdvancedCommonWebError::BadInputWithSimpleMessage(
+        format!("maybe_expires_in_days must be between 1 and {}", MAX_EXPIRES_IN_DAYS)));
+    }
+    Some(days) => Some(Utc::now() + Duration::days(days as i64)),
+  };
+
+  let maybe_note = request.maybe_note.as_deref()
+    .map(|note| note.trim())
+    .filter(|note| !note.is_empty());
+
+  if maybe_note.is_some_and(|note| note.chars().count() > MAX_NOTE_LENGTH) {
+    return Err(AdvancedCommonWebError::BadInputWithSimpleMessage(
+      format!("maybe_note must be at most {} characters", MAX_NOTE_LENGTH)));
+  }
+
+  let ip_address = get_request_ip(&http_request);
+
+  let mut transaction = server_state.mysql_pool
+    .begin()
+    .await
+    .map_err(|err| {
+      warn!("Failed to begin transaction: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  upsert_user_rate_limit_override(UpsertUserRateLimitOverrideArgs {
+    user_token: &path.user_token,
+    endpoint_group: request.endpoint_group,
+    is_exempt,
+    max_requests,
+    window_seconds,
+    maybe_note,
+    creator_user_token: &user_session.user_token,
+    maybe_expires_at,
+  }, &mut *transaction)
+    .await
+    .map_err(|err| {
+      warn!("upsert_user_rate_limit_override error: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  let _audit_token = insert_staff_audit_log(InsertStaffAuditLogArgs {
+    audit_action: StaffAuditAction::EditUserRateLimitOverride,
+    maybe_entity_type: Some(StaffAuditEntityType::User),
+    maybe_entity_token: Some(path.user_token.as_str()),
+    staff_user_token: &user_session.user_token,
+    actor_ip_address: &ip_address,
+    mysql_executor: &mut *transaction,
+    phantom: PhantomData,
+  }).await.map_err(|err| {
+    warn!("Failed to insert staff audit log: {:?}", err);
+    AdvancedCommonWebError::from_error(err)
+  })?;
+
+  transaction.commit()
+    .await
+    .map_err(|err| {
+      warn!("Failed to commit transaction: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  info!(
+    "Moderator {} set {} rate limit override for user {} (exempt: {}, {} per {}s)",
+    user_session.user_token.as_str(),
+    request.endpoint_group.to_str(),
+    path.user_token.as_str(),
+    is_exempt,
+    max_requests,
+    window_seconds,
+  );
+
+  Ok(Json(ModeratorSetUserRateLimitOverrideResponse {
+    success: true,
+  }))
+}
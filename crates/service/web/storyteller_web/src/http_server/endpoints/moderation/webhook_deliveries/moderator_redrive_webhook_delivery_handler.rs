@@ -0,0 +1,155 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use actix_web::web::{Json, Path};
+use actix_web::{web, HttpRequest};
+use log::{info, warn};
+
+use artcraft_api_defs::moderation::webhook_deliveries::moderator_redrive_webhook_delivery::{
+  ModeratorRedriveWebhookDeliveryPathInfo,
+  ModeratorRedriveWebhookDeliveryResponse,
+};
+use beeble_client::webhook_api::beeble_webhook_payload::parse_beeble_webhook;
+use enums::by_table::staff_audit_logs::staff_audit_action::StaffAuditAction;
+use enums::by_table::staff_audit_logs::staff_audit_entity_type::StaffAuditEntityType;
+use enums::by_table::webhook_deliveries::webhook_delivery_provider::WebhookDeliveryProvider;
+use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+use fal_client::webhook_payload::parse_raw_webhook_payload::parse_raw_webhook_payload;
+use http_server_common::request::get_request_ip::get_request_ip;
+use http_server_common::response::response_success_helpers::SimpleGenericJsonSuccess;
+use mysql_queries::queries::staff_audit_logs::insert_staff_audit_log::{
+  insert_staff_audit_log, InsertStaffAuditLogArgs,
+};
+use mysql_queries::queries::webhook_deliveries::get_webhook_delivery_by_token::{get_webhook_delivery_by_token, WebhookDelivery};
+use mysql_queries::queries::webhook_deliveries::mark_webhook_delivery_redriven::mark_webhook_delivery_redriven;
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::endpoints::webhooks::beeble::beeble_webhook_handler::process_beeble_webhook_payload;
+use crate::http_server::endpoints::webhooks::fal::process_fal_webhook_payload::process_fal_webhook_payload;
+use crate::http_server::endpoints::webhooks::webhook_delivery_ledger::record_webhook_delivery_outcome;
+use crate::http_server::web_utils::user_session::require_moderator::{require_moderator, UseDatabase};
+use crate::state::server_state::ServerState;
+
+/// Re-run a stored third party webhook delivery (moderation)
+///
+/// For deliveries we failed to process because of our own bug. Deliveries that
+/// already succeeded are refused so jobs can't be completed twice.
+#[utoipa::path(
+  post,
+  tag = "Moderation",
+  path = "/v1/moderation/webhook_delivery/{webhook_delivery_token}/redrive",
+  responses(
+    (status = 200, description = "Success", body = ModeratorRedriveWebhookDeliveryResponse),
+    (status = 400, description = "Delivery already succeeded"),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Delivery not found"),
+    (status = 500, description = "Server error"),
+  ),
+  params(
+    ("webhook_delivery_token" = WebhookDeliveryToken, Path, description = "Webhook delivery to re-drive"),
+  )
+)]
+pub async fn moderator_redrive_webhook_delivery_handler(
+  http_request: HttpRequest,
+  path: Path<ModeratorRedriveWebhookDeliveryPathInfo>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ModeratorRedriveWebhookDeliveryResponse>, AdvancedCommonWebError> {
+
+  let user_session = require_moderator(&http_request, &server_state, UseDatabase::GrabNewConnection)
+    .await
+    .map_err(|err| {
+      warn!("Moderator check failed: {:?}", err);
+      AdvancedCommonWebError::NotAuthorized
+    })?;
+
+  let ip_address = get_request_ip(&http_request);
+
+  let delivery = get_webhook_delivery_by_token(&path.webhook_delivery_token, &server_state.mysql_pool)
+    .await
+    .map_err(|err| {
+      warn!("get_webhook_delivery_by_token error: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?
+    .ok_or(AdvancedCommonWebError::NotFound)?;
+
+  if delivery.status == WebhookDeliveryStatus::Succeeded {
+    return Err(AdvancedCommonWebError::BadInputWithSimpleMessage(
+      "Webhook delivery already succeeded".to_string()));
+  }
+
+  // Claim the delivery so a concurrent re-drive (or success) can't race us.
+  let claimed = mark_webhook_delivery_redriven(
+    &delivery.token,
+    &user_session.user_token,
+    &server_state.mysql_pool,
+  )
+    .await
+    .map_err(|err| {
+      warn!("mark_webhook_delivery_redriven error: {:?}", err);
+      AdvancedCommonWebError::from_error(err)
+    })?;
+
+  if !claimed {
+    return Err(AdvancedCommonWebError::BadInputWithSimpleMessage(
+      "Webhook delivery already succeeded".to_string()));
+  }
+
+  info!(
+    "Moderator {} re-driving {} webhook delivery {} for {} (previous status: {})",
+    user_session.user_token.as_str(),
+    delivery.provider,
+    delivery.token.as_str(),
+    delivery.provider_request_id,
+    delivery.status,
+  );
+
+  let _audit_token = insert_staff_audit_log(InsertStaffAuditLogArgs {
+    audit_action: StaffAuditAction::RedriveWebhookDelivery,
+    maybe_entity_type: Some(StaffAuditEntityType::WebhookDelivery),
+    maybe_entity_token: Some(delivery.token.as_str()),
+    staff_user_token: &user_session.user_token,
+    actor_ip_address: &ip_address,
+    mysql_executor: &server_state.mysql_pool,
+    phantom: PhantomData,
+  }).await.map_err(|err| {
+    warn!("Failed to insert staff audit log: {:?}", err);
+    AdvancedCommonWebError::from_error(err)
+  })?;
+
+  let result = process_stored_delivery(&server_state, &delivery).await;
+
+  record_webhook_delivery_outcome(&server_state, &delivery.token, &result).await;
+
+  let maybe_error = result.as_ref().err().map(|err| format!("{:?}", err));
+
+  if let Some(err) = maybe_error.as_deref() {
+    warn!("Re-drive of webhook delivery {} failed: {}", delivery.token.as_str(), err);
+  }
+
+  Ok(Json(ModeratorRedriveWebhookDeliveryResponse {
+    success: true,
+    delivery_succeeded: maybe_error.is_none(),
+    maybe_error,
+  }))
+}
+
+async fn process_stored_delivery(
+  server_state: &ServerState,
+  delivery: &WebhookDelivery,
+) -> Result<Json<SimpleGenericJsonSuccess>, AdvancedCommonWebError> {
+  let raw_body = delivery.raw_body.as_str();
+
+  match delivery.provider {
+    WebhookDeliveryProvider::Fal => {
+      let webhook_payload = parse_raw_webhook_payload(raw_body)
+        .map_err(AdvancedCommonWebError::from_error)?;
+      process_fal_webhook_payload(server_state, &webhook_payload, raw_body).await
+    }
+    WebhookDeliveryProvider::Beeble => {
+      let payload = parse_beeble_webhook(raw_body)
+        .map_err(AdvancedCommonWebError::from_error)?;
+      process_beeble_webhook_payload(server_state, &payload, raw_body).await
+    }
+  }
+}
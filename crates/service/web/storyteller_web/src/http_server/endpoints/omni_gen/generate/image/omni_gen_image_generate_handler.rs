@@ -11,6 +11,8 @@ use artcraft_router::generate::generate_image::generate_image_response::Generate
 use enums::by_table::debug_logs::debug_log_type::DebugLogType;
 use enums::by_table::prompt_context_items::prompt_context_semantic_type::PromptContextSemanticType;
 use enums::by_table::prompts::prompt_type::PromptType;
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::generation::common_generation_mode::CommonGenerationMode;
 use enums::common::generation::common_model_type::CommonModelType;
 use enums::common::generation_provider::GenerationProvider;
@@ -34,6 +36,10 @@ use crate::http_server::endpoints::generate::common::payments_error_test::paymen
 use crate::http_server::endpoints::omni_gen::generate::image::hydrate_to_router_request::hydrate_to_router_request;
 use crate::http_server::endpoints::omni_gen::generate::image::pipeline_v1::run_pipeline_v1::{run_pipeline_v1, RunPipelineV1Args};
 use crate::http_server::endpoints::omni_gen::generate::image::pipeline_v2::run_pipeline_v2::{run_pipeline_v2, should_use_pipeline_v2, RunPipelineV2Args};
+use crate::http_server::endpoints::omni_gen::generate::mock::complete_mock_job::{spawn_mock_job_completion, MockJobCreator};
+use crate::http_server::endpoints::omni_gen::generate::video::insert_db_job::insert_mock_job::{insert_mock_job, InsertMockJobArgs};
+use crate::http_server::endpoints::omni_gen::generate::video::insert_db_job::shared_job_args::SharedJobArgs;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_rate_limit, EnforceRateLimitArgs};
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::state::server_state::ServerState;
 use crate::util::lookup::lookup_media_files_as_cdn_url_list_and_map::lookup_media_files_as_cdn_url_list_and_map;
@@ -49,6 +55,7 @@ use crate::util::lookup::lookup_media_files_as_cdn_url_list_and_map::lookup_medi
     (status = 400, description = "Bad input"),
     (status = 401, description = "Unauthorized"),
     (status = 402, description = "Payment required"),
+    (status = 429, description = "Too many requests"),
     (status = 500, description = "Server error"),
   ),
 )]
@@ -70,24 +77,39 @@ pub async fn omni_gen_image_generate_handler(
 
   let mut mysql_connection = server_state.mysql_pool.acquire().await?;
 
-  let maybe_user_session = server_state
+  // NB: Scripts may call this with a personal access token instead of a session.
+  let maybe_api_caller = server_state
     .session_checker
-    .maybe_get_user_session_from_connection(&http_request, &mut mysql_connection)
+    .maybe_get_api_caller_session_from_connection(&http_request, PersonalAccessTokenScope::Generate, &mut mysql_connection)
     .await
     .map_err(|e| {
       warn!("Session checker error: {:?}", e);
       AdvancedCommonWebError::from(e)
     })?;
 
-  let user_token = match maybe_user_session.as_ref() {
-    Some(session) => &session.user_token,
+  let api_caller = match maybe_api_caller.as_ref() {
+    Some(api_caller) => api_caller,
     None => return Err(AdvancedCommonWebError::NotAuthorized),
   };
 
+  let user_token = &api_caller.user_session.user_token;
+  let maybe_personal_access_token = api_caller.maybe_personal_access_token.as_ref();
+
   let maybe_avt_token = server_state
     .avt_cookie_manager
     .get_avt_token_from_request(&http_request);
 
+  // ==================== RATE LIMIT ==================== //
+
+  enforce_rate_limit(EnforceRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Generation,
+    maybe_user_session: Some(&api_caller.user_session),
+    maybe_personal_access_token,
+    mysql_connection: &mut mysql_connection,
+  }).await?;
+
   // ==================== IDEMPOTENCY ==================== //
 
   let idempotency_token = request.idempotency_token.as_deref()
@@ -134,13 +156,14 @@ pub async fn omni_gen_image_generate_handler(
 
   // ==================== PIPELINE DISPATCH ==================== //
 
-  let pipeline_result = if should_use_pipeline_v2(&router_builder) {
+  let pipeline_result = if should_use_pipeline_v2(&router_builder, &server_state) {
     info!("Using image pipeline v2");
     run_pipeline_v2(RunPipelineV2Args {
       router_builder: &router_builder,
       server_state: &server_state,
       mysql_connection: &mut mysql_connection,
       user_token,
+      maybe_personal_access_token,
       resolved_media: &resolved_media,
     }).await?
   } else {
@@ -150,6 +173,7 @@ pub async fn omni_gen_image_generate_handler(
       server_state: &server_state,
       mysql_connection: &mut mysql_connection,
       user_token,
+      maybe_personal_access_token,
       resolved_media: &resolved_media,
     }).await?
   };
@@ -178,6 +202,12 @@ pub async fn omni_gen_image_generate_handler(
     GenerateImageResponse::Fal(p) => {
       p.request_id.clone().unwrap_or_default()
     }
+    GenerateImageResponse::Mock(p) => {
+      p.job_id.clone()
+    }
+    GenerateImageResponse::Replicate(p) => {
+      p.prediction_id.clone()
+    }
   };
 
   // ==================== DB TRANSACTION ==================== //
@@ -255,40 +285,65 @@ pub async fn omni_gen_image_generate_handler(
 
   // -- Inference job --
 
-  let db_result = insert_generic_inference_job_for_fal_queue_with_apriori_job_token(
-    InsertGenericInferenceForFalWithAprioriJobTokenArgs {
-      apriori_job_token: &pipeline_result.apriori_job_token,
-      uuid_idempotency_token: &idempotency_token,
-      maybe_external_third_party_id: &external_job_id,
-      fal_category: FalCategory::ImageGeneration,
-      maybe_inference_args: None,
-      maybe_prompt_token: prompt_token.as_ref(),
-      maybe_creator_user_token: Some(user_token),
-      maybe_avt_token: maybe_avt_token.as_ref(),
-      creator_ip_address: &ip_address,
-      creator_set_visibility: Visibility::Public,
-      maybe_debug_log_event_token: Some(&debug_log_event_token),
-      mysql_executor: &mut *transaction,
-      starting_job_status_override: None,
-      maybe_frontend_failure_category: None,
-      maybe_failure_reason: None,
-      phantom: Default::default(),
-    }
-  ).await;
+  let is_mock_job = matches!(pipeline_result.response, GenerateImageResponse::Mock(_));
 
-  let job_token = match db_result {
-    Ok(token) => token,
-    Err(err) => {
+  let db_result = if is_mock_job {
+    insert_mock_job(InsertMockJobArgs {
+      external_job_id: &external_job_id,
+      fal_category: FalCategory::ImageGeneration,
+      shared: SharedJobArgs {
+        apriori_job_token: &pipeline_result.apriori_job_token,
+        idempotency_token: &idempotency_token,
+        user_token,
+        maybe_avt_token: maybe_avt_token.as_ref(),
+        maybe_prompt_token: prompt_token.as_ref(),
+        maybe_debug_log_event_token: Some(&debug_log_event_token),
+        ip_address: &ip_address,
+        transaction: &mut transaction,
+      },
+    }).await
+  } else {
+    insert_generic_inference_job_for_fal_queue_with_apriori_job_token(
+      InsertGenericInferenceForFalWithAprioriJobTokenArgs {
+        apriori_job_token: &pipeline_result.apriori_job_token,
+        uuid_idempotency_token: &idempotency_token,
+        maybe_external_third_party_id: &external_job_id,
+        fal_category: FalCategory::ImageGeneration,
+        maybe_inference_args: None,
+        maybe_prompt_token: prompt_token.as_ref(),
+        maybe_creator_user_token: Some(user_token),
+        maybe_avt_token: maybe_avt_token.as_ref(),
+        creator_ip_address: &ip_address,
+        creator_set_visibility: Visibility::Public,
+        maybe_debug_log_event_token: Some(&debug_log_event_token),
+        mysql_executor: &mut *transaction,
+        starting_job_status_override: None,
+        maybe_frontend_failure_category: None,
+        maybe_failure_reason: None,
+        phantom: Default::default(),
+      }
+    ).await.map_err(|err| {
       warn!("Error inserting inference job: {:?}", err);
-      return Err(AdvancedCommonWebError::from_error(err));
-    }
+      AdvancedCommonWebError::from_error(err)
+    })
   };
 
+  let job_token = db_result?;
+
   transaction.commit().await.map_err(|err| {
     error!("Error committing transaction: {:?}", err);
     AdvancedCommonWebError::from_error(err)
   })?;
 
+  if is_mock_job {
+    spawn_mock_job_completion(server_state.get_ref().clone(), job_token.clone(), external_job_id, MockJobCreator {
+      maybe_creator_user_token: Some(user_token.clone()),
+      maybe_avt_token: maybe_avt_token.clone(),
+      creator_ip_address: ip_address.clone(),
+      maybe_prompt_token: prompt_token.clone(),
+    });
+  }
+
   Ok(Json(OmniGenImageGenerateResponse {
     success: true,
     inference_job_token: job_token,
@@ -7,9 +7,10 @@ use artcraft_router::client::router_fal_client::RouterFalClient;
 use artcraft_router::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
 use artcraft_router::generate::generate_image::image_generation_plan::ImageGenerationPlan;
 use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
 use tokens::tokens::users::UserToken;
 
-use crate::billing::wallets::attempt_wallet_deduction::attempt_wallet_deduction_else_common_web_error;
+use crate::billing::wallets::attempt_metered_wallet_deduction::attempt_metered_wallet_deduction_else_common_web_error;
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
 use crate::http_server::endpoints::omni_gen::generate::image::pipeline_result::ImagePipelineResult;
 use crate::state::server_state::ServerState;
@@ -20,6 +21,7 @@ pub struct RunPipelineV1Args<'a> {
   pub server_state: &'a ServerState,
   pub mysql_connection: &'a mut sqlx::pool::PoolConnection<sqlx::MySql>,
   pub user_token: &'a UserToken,
+  pub maybe_personal_access_token: Option<&'a PersonalAccessTokenToken>,
   pub resolved_media: &'a MediaFilesAsCdnUrlListAndMap,
 }
 
@@ -31,6 +33,7 @@ pub async fn run_pipeline_v1(
     server_state,
     mysql_connection,
     user_token,
+    maybe_personal_access_token,
     resolved_media,
   } = args;
 
@@ -48,8 +51,9 @@ pub async fn run_pipeline_v1(
   let apriori_job_token = InferenceJobToken::generate();
 
   if cost > 0 {
-    attempt_wallet_deduction_else_common_web_error(
+    attempt_metered_wallet_deduction_else_common_web_error(
       user_token,
+      maybe_personal_access_token,
       Some(apriori_job_token.as_str()),
       cost,
       mysql_connection,
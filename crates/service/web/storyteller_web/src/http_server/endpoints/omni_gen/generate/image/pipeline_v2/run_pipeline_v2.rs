@@ -2,6 +2,7 @@ use log::{info, warn};
 
 use artcraft_router::api::image_list_ref::ImageListRef;
 use artcraft_router::api::provider::Provider;
+use artcraft_router::client::multi_router_client_builder::MultiRouterClientBuilder;
 use artcraft_router::client::router_client::RouterClient;
 use artcraft_router::client::router_fal_webhook_optional_client::RouterFalWebhookOptionalClient;
 use artcraft_router::generate::generate_image::generate_image_request_builder::GenerateImageRequestBuilder;
@@ -10,9 +11,10 @@ use artcraft_router::generate::generate_image_v2::image_generation_draft_context
 use artcraft_router::generate::generate_image_v2::image_generation_draft_or_request::ImageGenerationDraftOrRequest;
 use artcraft_router::generate::generate_image_v2::image_generation_request::ImageGenerationRequest;
 use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
 use tokens::tokens::users::UserToken;
 
-use crate::billing::wallets::attempt_wallet_deduction::attempt_wallet_deduction_else_common_web_error;
+use crate::billing::wallets::attempt_metered_wallet_deduction::attempt_metered_wallet_deduction_else_common_web_error;
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
 use crate::http_server::endpoints::omni_gen::generate::image::pipeline_result::ImagePipelineResult;
 use crate::state::server_state::ServerState;
@@ -23,15 +25,25 @@ pub struct RunPipelineV2Args<'a> {
   pub server_state: &'a ServerState,
   pub mysql_connection: &'a mut sqlx::pool::PoolConnection<sqlx::MySql>,
   pub user_token: &'a UserToken,
+  pub maybe_personal_access_token: Option<&'a PersonalAccessTokenToken>,
   pub resolved_media: &'a MediaFilesAsCdnUrlListAndMap,
 }
 
-pub fn should_use_pipeline_v2(router_builder: &GenerateImageRequestBuilder) -> bool {
+pub fn should_use_pipeline_v2(router_builder: &GenerateImageRequestBuilder, server_state: &ServerState) -> bool {
   let mut execution_builder = router_builder.clone();
-  execution_builder.provider = Provider::Fal;
+  execution_builder.provider = execution_provider(server_state);
   execution_builder.use_new_builder()
 }
 
+/// Images go to Fal, or to the mock provider when it's enabled.
+fn execution_provider(server_state: &ServerState) -> Provider {
+  if server_state.mock_provider.is_some() {
+    Provider::Mock
+  } else {
+    Provider::Fal
+  }
+}
+
 pub async fn run_pipeline_v2(
   args: RunPipelineV2Args<'_>,
 ) -> Result<ImagePipelineResult, AdvancedCommonWebError> {
@@ -40,6 +52,7 @@ pub async fn run_pipeline_v2(
     server_state,
     mysql_connection,
     user_token,
+    maybe_personal_access_token,
     resolved_media,
   } = args;
 
@@ -48,17 +61,22 @@ pub async fn run_pipeline_v2(
     resolved_media,
   );
   
-  let cost = estimate_cost_in_credits(&router_builder)?;
+  let cost = if server_state.mock_provider.is_some() {
+    0 // Mock generations are free.
+  } else {
+    estimate_cost_in_credits(&router_builder)?
+  };
 
-  let draft_or_request = build_execution_request(&hydrated_builder)?;
+  let draft_or_request = build_execution_request(&hydrated_builder, execution_provider(server_state))?;
 
   info!("Charging wallet: {} credits", cost);
 
   let apriori_job_token = InferenceJobToken::generate();
 
   if cost > 0 {
-    attempt_wallet_deduction_else_common_web_error(
+    attempt_metered_wallet_deduction_else_common_web_error(
       user_token,
+      maybe_personal_access_token,
       Some(apriori_job_token.as_str()),
       cost,
       mysql_connection,
@@ -75,9 +93,10 @@ pub async fn run_pipeline_v2(
 
 fn build_execution_request(
   router_builder: &GenerateImageRequestBuilder,
+  provider: Provider,
 ) -> Result<ImageGenerationDraftOrRequest, AdvancedCommonWebError> {
   let mut execution_builder = router_builder.clone();
-  execution_builder.provider = Provider::Fal;
+  execution_builder.provider = provider;
 
   execution_builder.build2().map_err(|e| {
     warn!("Failed to build2 for image v2 pipeline: {}", e);
@@ -170,18 +189,28 @@ fn build_router_client(
   provider: Provider,
   server_state: &ServerState,
 ) -> Result<RouterClient, AdvancedCommonWebError> {
-  match provider {
+  let builder = MultiRouterClientBuilder::new()
+    .set_health_registry(server_state.provider_health.clone());
+
+  let builder = match provider {
     Provider::Fal => {
-      let fal_client = RouterFalWebhookOptionalClient::new_with_webhook(
+      builder.set_fal_webhook_optional_client(RouterFalWebhookOptionalClient::new_with_webhook(
         server_state.fal.api_key.clone(),
         server_state.fal.webhook_url.clone(),
-      );
-      Ok(RouterClient::FalWebhookOptional(fal_client))
+      ))
+    },
+    Provider::Mock => {
+      let mock = server_state.mock_provider.clone().ok_or_else(|| {
+        AdvancedCommonWebError::server_error_with_message("The mock provider is not enabled")
+      })?;
+      builder.set_mock_client(mock)
     },
     other => {
-      Err(AdvancedCommonWebError::server_error_with_message(
+      return Err(AdvancedCommonWebError::server_error_with_message(
         &format!("Unsupported provider for image v2 generation: {:?}", other),
       ))
     },
-  }
+  };
+
+  Ok(RouterClient::Multi(builder.build()))
 }
@@ -0,0 +1,292 @@
+//! Finish omni_gen jobs that were sent to the mock provider.
+//!
+//! Mock jobs get no webhook and no poller, so the request that submits one spawns a task that
+//! follows the job's simulated state in `RouterMockClient` and stores the placeholder media
+//! when it completes. Mock jobs only live in memory, so jobs from a previous run are never
+//! finished.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use log::{error, info, warn};
+
+use artcraft_router::client::router_mock_client::RouterMockClient;
+use artcraft_router::mock::mock_job_status::{MockJobOutput, MockJobStatus};
+use bucket_paths::legacy::typified_paths::public::media_files::bucket_file_path::MediaFileBucketPath;
+use enums::by_table::media_files::media_file_class::MediaFileClass;
+use enums::by_table::media_files::media_file_origin_category::MediaFileOriginCategory;
+use enums::by_table::media_files::media_file_type::MediaFileType;
+use errors::AnyhowResult;
+use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
+use images::image_info::image_info::ImageInfo;
+use jobs_common::refund_failed_job::refund_failed_job;
+use media::probe::probe_media::probe_media_bytes;
+use mimetypes::mimetype_info::file_extension::FileExtension;
+use mysql_queries::queries::generic_inference::fal::mark_fal_generic_inference_job_successfully_done::{mark_fal_generic_inference_job_successfully_done, MarkJobArgs};
+use mysql_queries::queries::generic_inference::job::mark_job_failed_by_token::{mark_job_failed_by_token, MarkJobFailedByTokenArgs};
+use mysql_queries::queries::generic_inference::web::get_inference_job_for_cancellation::get_inference_job_for_cancellation;
+use mysql_queries::queries::media_files::create::insert_builder::media_file_insert_builder::MediaFileInsertBuilder;
+use tokens::tokens::anonymous_visitor_tracking::AnonymousVisitorTrackingToken;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::prompts::PromptToken;
+use tokens::tokens::users::UserToken;
+
+use crate::state::server_state::ServerState;
+
+const POLL_INTERVAL : Duration = Duration::from_millis(500);
+
+const PREFIX : Option<&str> = Some("artcraft_");
+
+/// Who the placeholder media file belongs to.
+pub struct MockJobCreator {
+  pub maybe_creator_user_token: Option<UserToken>,
+  pub maybe_avt_token: Option<AnonymousVisitorTrackingToken>,
+  pub creator_ip_address: String,
+  pub maybe_prompt_token: Option<PromptToken>,
+}
+
+/// Call once the transaction that inserted the job has committed.
+pub fn spawn_mock_job_completion(
+  server_state: Arc<ServerState>,
+  job_token: InferenceJobToken,
+  mock_job_id: String,
+  creator: MockJobCreator,
+) {
+  let Some(mock_client) = server_state.mock_provider.clone() else {
+    error!("Mock job {} was submitted, but the mock provider isn't enabled.", job_token.as_str());
+    return;
+  };
+
+  tokio::spawn(async move {
+    let outcome = await_mock_job(&mock_client, &mock_job_id, POLL_INTERVAL).await;
+
+    if let Err(err) = finish_mock_job(&server_state, &job_token, &creator, outcome).await {
+      error!("Error finishing mock job {} ({}): {:?}", job_token.as_str(), mock_job_id, err);
+    }
+  });
+}
+
+#[derive(Debug)]
+enum MockJobOutcome {
+  Completed(PlaceholderMedia),
+  Failed { reason: String },
+  Cancelled,
+}
+
+#[derive(Debug)]
+struct PlaceholderMedia {
+  bytes: Vec<u8>,
+  mime_type: &'static str,
+  media_file_class: MediaFileClass,
+}
+
+async fn await_mock_job(mock_client: &RouterMockClient, mock_job_id: &str, poll_interval: Duration) -> MockJobOutcome {
+  let status = match mock_client.wait_for_job(mock_job_id, poll_interval).await {
+    Ok(status) => status,
+    Err(err) => return MockJobOutcome::Failed { reason: err.to_string() },
+  };
+
+  match status {
+    MockJobStatus::Completed(output) => match read_placeholder_media(&output) {
+      Ok(media) => MockJobOutcome::Completed(media),
+      Err(err) => MockJobOutcome::Failed { reason: format!("could not read placeholder media: {}", err) },
+    },
+    MockJobStatus::Failed { reason } => MockJobOutcome::Failed { reason },
+    MockJobStatus::Cancelled => MockJobOutcome::Cancelled,
+    other @ (MockJobStatus::Queued { .. } | MockJobStatus::Processing { .. }) => MockJobOutcome::Failed {
+      reason: format!("mock job stopped before finishing: {:?}", other),
+    },
+  }
+}
+
+fn read_placeholder_media(output: &MockJobOutput) -> AnyhowResult<PlaceholderMedia> {
+  let media_file_class = match output.mime_type.split('/').next() {
+    Some("image") => MediaFileClass::Image,
+    Some("video") => MediaFileClass::Video,
+    _ => return Err(anyhow!("unsupported placeholder mime type: {}", output.mime_type)),
+  };
+
+  let bytes = std::fs::read(&output.media_path)
+      .map_err(|err| anyhow!("error reading {:?}: {:?}", output.media_path, err))?;
+
+  Ok(PlaceholderMedia {
+    bytes,
+    mime_type: output.mime_type,
+    media_file_class,
+  })
+}
+
+async fn finish_mock_job(
+  server_state: &ServerState,
+  job_token: &InferenceJobToken,
+  creator: &MockJobCreator,
+  outcome: MockJobOutcome,
+) -> AnyhowResult<()> {
+  match outcome {
+    MockJobOutcome::Completed(media) => {
+      let media_token = store_placeholder_media(server_state, creator, &media).await?;
+
+      mark_fal_generic_inference_job_successfully_done(MarkJobArgs {
+        job_token,
+        media_file_token: &media_token,
+        maybe_batch_token: None,
+        mysql_executor: &server_state.mysql_pool,
+        phantom: Default::default(),
+      }).await?;
+
+      info!("Mock job {} completed with media file {}", job_token.as_str(), media_token);
+    }
+    MockJobOutcome::Failed { reason } => {
+      warn!("Mock job {} failed: {}", job_token.as_str(), reason);
+
+      let mut mysql_connection = server_state.mysql_pool.acquire().await?;
+
+      let maybe_ledger_entry_token = get_inference_job_for_cancellation(job_token, &mut mysql_connection)
+          .await?
+          .and_then(|job| job.maybe_wallet_ledger_entry_token);
+
+      // NB: Nothing retries mock jobs, so mark the job failed even if the refund didn't go through.
+      if let Err(err) = refund_failed_job(&server_state.mysql_pool, job_token, maybe_ledger_entry_token.as_ref()).await {
+        error!("Could not refund failed mock job {}: {:?}", job_token.as_str(), err);
+      }
+
+      mark_job_failed_by_token(MarkJobFailedByTokenArgs {
+        pool: &server_state.mysql_pool,
+        job_token,
+        maybe_public_failure_reason: Some(&reason),
+        internal_debugging_failure_reason: &reason,
+        maybe_frontend_failure_category: None,
+      }).await?;
+    }
+    MockJobOutcome::Cancelled => {
+      // The cancel endpoint already marked (and refunded) the job.
+      info!("Mock job {} was cancelled", job_token.as_str());
+    }
+  }
+
+  Ok(())
+}
+
+async fn store_placeholder_media(
+  server_state: &ServerState,
+  creator: &MockJobCreator,
+  media: &PlaceholderMedia,
+) -> AnyhowResult<MediaFileToken> {
+  let media_file_type = MediaFileType::try_from_mime_type(media.mime_type)
+      .ok_or_else(|| anyhow!("unsupported media file type: {}", media.mime_type))?;
+
+  let file_extension = FileExtension::from_mimetype(media.mime_type)
+      .ok_or_else(|| anyhow!("no file extension for: {}", media.mime_type))?;
+
+  let mut maybe_frame_width = None;
+  let mut maybe_frame_height = None;
+  let mut maybe_duration_millis = None;
+
+  match media.media_file_class {
+    MediaFileClass::Image => {
+      let image_info = ImageInfo::decode_image_from_bytes(&media.bytes)?;
+      maybe_frame_width = Some(image_info.width());
+      maybe_frame_height = Some(image_info.height());
+    }
+    _ => match probe_media_bytes(&media.bytes) {
+      Ok(probe) => {
+        maybe_duration_millis = probe.maybe_duration_millis;
+        if let Some((width, height)) = probe.maybe_display_dimensions() {
+          maybe_frame_width = Some(width);
+          maybe_frame_height = Some(height);
+        }
+      }
+      Err(err) => warn!("Failed to probe placeholder media: {:?}", err),
+    },
+  }
+
+  let file_hash = sha256_hash_bytes(&media.bytes)?;
+
+  let public_upload_path = MediaFileBucketPath::generate_new(PREFIX, Some(file_extension.extension_with_period()));
+
+  server_state.public_bucket_client.upload_file_with_content_type_process(
+    public_upload_path.get_full_object_path_str(),
+    media.bytes.as_ref(),
+    media.mime_type,
+  ).await?;
+
+  let media_token = MediaFileInsertBuilder::new()
+      .maybe_creator_user(creator.maybe_creator_user_token.as_ref())
+      .maybe_creator_anonymous_visitor(creator.maybe_avt_token.as_ref())
+      .creator_ip_address(&creator.creator_ip_address)
+      .public_bucket_directory_hash(&public_upload_path)
+      .media_file_class(media.media_file_class)
+      .media_file_type(media_file_type)
+      .media_file_origin_category(MediaFileOriginCategory::Inference)
+      .mime_type(media.mime_type)
+      .file_size_bytes(media.bytes.len() as u64)
+      .maybe_frame_width(maybe_frame_width)
+      .maybe_frame_height(maybe_frame_height)
+      .maybe_duration_millis(maybe_duration_millis)
+      .checksum_sha2(&file_hash)
+      .maybe_prompt_token(creator.maybe_prompt_token.as_ref())
+      .insert_pool(&server_state.mysql_pool)
+      .await?;
+
+  Ok(media_token)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use artcraft_router::api::common_video_model::CommonVideoModel;
+  use artcraft_router::api::provider::Provider;
+  use artcraft_router::client::router_client::RouterClient;
+  use artcraft_router::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
+  use artcraft_router::generate::generate_video_v2::video_generation_draft_or_request::VideoGenerationDraftOrRequest;
+  use artcraft_router::mock::mock_provider_config::MockProviderConfig;
+
+  async fn submit_mock_video(mock_client: &RouterMockClient) -> String {
+    let builder = GenerateVideoRequestBuilder {
+      model: CommonVideoModel::Veo3,
+      provider: Provider::Mock,
+      prompt: Some("A corgi running through a field of wildflowers at sunset.".to_string()),
+      ..Default::default()
+    };
+    let request = match builder.build2().unwrap() {
+      VideoGenerationDraftOrRequest::Request(request) => request,
+      _ => panic!("expected a request (mock skips the draft)"),
+    };
+    let response = request.send_request(&RouterClient::Mock(mock_client.clone())).await.unwrap();
+    response.get_mock_payload().expect("mock payload").job_id
+  }
+
+  #[tokio::test]
+  async fn submitted_mock_video_completes_with_placeholder_media() {
+    let mock_client = RouterMockClient::new(MockProviderConfig::instant());
+    let mock_job_id = submit_mock_video(&mock_client).await;
+
+    match await_mock_job(&mock_client, &mock_job_id, Duration::from_millis(1)).await {
+      MockJobOutcome::Completed(media) => {
+        assert_eq!(media.media_file_class, MediaFileClass::Video);
+        assert_eq!(media.mime_type, "video/mp4");
+        assert!(!media.bytes.is_empty());
+      }
+      other => panic!("expected the mock job to complete, got {:?}", other),
+    }
+  }
+
+  #[tokio::test]
+  async fn cancelled_mock_job_is_not_completed() {
+    let mock_client = RouterMockClient::new(MockProviderConfig::default());
+    let mock_job_id = submit_mock_video(&mock_client).await;
+    mock_client.cancel_job(&mock_job_id).unwrap();
+
+    let outcome = await_mock_job(&mock_client, &mock_job_id, Duration::from_millis(1)).await;
+    assert!(matches!(outcome, MockJobOutcome::Cancelled), "got {:?}", outcome);
+  }
+
+  #[tokio::test]
+  async fn unknown_mock_job_fails() {
+    let mock_client = RouterMockClient::new(MockProviderConfig::instant());
+    let outcome = await_mock_job(&mock_client, "mock_video_99999999", Duration::from_millis(1)).await;
+    assert!(matches!(outcome, MockJobOutcome::Failed { .. }), "got {:?}", outcome);
+  }
+}
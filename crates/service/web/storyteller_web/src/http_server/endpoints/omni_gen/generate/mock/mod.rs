@@ -0,0 +1 @@
+pub mod complete_mock_job;
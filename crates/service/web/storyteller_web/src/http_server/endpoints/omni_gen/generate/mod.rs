@@ -1,2 +1,3 @@
 pub mod image;
+pub mod mock;
 pub mod video;
@@ -2,10 +2,11 @@
 
 use log::info;
 use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
 use tokens::tokens::users::UserToken;
 use tokens::tokens::wallet_ledger_entries::WalletLedgerEntryToken;
 
-use crate::billing::wallets::attempt_wallet_deduction::attempt_wallet_deduction_else_common_web_error;
+use crate::billing::wallets::attempt_metered_wallet_deduction::attempt_metered_wallet_deduction_else_common_web_error;
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
 
 pub struct BillWalletResult {
@@ -16,8 +17,10 @@ pub struct BillWalletResult {
 /// Generate an apriori job token and bill the user's wallet for the given cost.
 ///
 /// If cost is 0, no wallet deduction is made but the apriori token is still generated.
+/// Requests made with a personal access token also count against its spend limit.
 pub async fn bill_wallet(
   user_token: &UserToken,
+  maybe_personal_access_token: Option<&PersonalAccessTokenToken>,
   cost: u64,
   mysql_connection: &mut sqlx::pool::PoolConnection<sqlx::MySql>,
 ) -> Result<BillWalletResult, AdvancedCommonWebError> {
@@ -26,8 +29,9 @@ pub async fn bill_wallet(
   info!("Charging wallet: {} credits", cost);
 
   let maybe_wallet_ledger_entry_token = if cost > 0 {
-    let deduction_result = attempt_wallet_deduction_else_common_web_error(
+    let deduction_result = attempt_metered_wallet_deduction_else_common_web_error(
       user_token,
+      maybe_personal_access_token,
       Some(apriori_job_token.as_str()),
       cost,
       mysql_connection,
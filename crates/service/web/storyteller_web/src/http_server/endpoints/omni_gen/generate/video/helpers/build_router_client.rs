@@ -1,9 +1,12 @@
 //! Build a RouterClient for the given provider from server state.
+//! The client shares the server's provider health registry, so calls go through circuit breakers.
 
 use artcraft_router::api::provider::Provider;
+use artcraft_router::client::multi_router_client_builder::MultiRouterClientBuilder;
 use artcraft_router::client::router_client::RouterClient;
 use artcraft_router::client::router_fal_client::RouterFalClient;
 use artcraft_router::client::router_gmicloud_client::RouterGmiCloudClient;
+use artcraft_router::client::router_mock_client::RouterMockClient;
 use artcraft_router::client::router_seedance2pro_client::RouterSeedance2ProClient;
 use seedance2pro_client::creds::seedance2pro_session::Seedance2ProSession;
 
@@ -15,31 +18,78 @@ pub fn build_router_client(
   server_state: &ServerState,
   use_alternate_kinovi: bool,
 ) -> Result<RouterClient, AdvancedCommonWebError> {
-  match provider {
+  let builder = MultiRouterClientBuilder::new()
+    .set_health_registry(server_state.provider_health.clone());
+
+  let builder = match provider {
     Provider::Seedance2Pro => {
-      kinovi_provider(server_state, use_alternate_kinovi)
+      builder.set_seedance2pro_client(kinovi_client(server_state, use_alternate_kinovi))
     }
     Provider::Fal => {
-      let fal_client = RouterFalClient::new(
+      builder.set_fal_client(RouterFalClient::new(
         server_state.fal.api_key.clone(),
         server_state.fal.webhook_url.clone(),
-      );
-      Ok(RouterClient::Fal(fal_client))
+      ))
     }
     Provider::GmiCloud => {
-      Ok(RouterClient::GmiCloud(RouterGmiCloudClient::new(
+      builder.set_gmicloud_client(RouterGmiCloudClient::new(
         server_state.gmicloud.api_key.clone(),
-      )))
+      ))
+    }
+    Provider::Mock => {
+      // NB: Clones share jobs with the client built at startup.
+      builder.set_mock_client(mock_client(server_state)?)
     }
     other => {
-      Err(AdvancedCommonWebError::server_error_with_message(
+      return Err(AdvancedCommonWebError::server_error_with_message(
         &format!("Unsupported provider for video generation: {:?}", other),
       ))
     }
-  }
+  };
+
+  Ok(RouterClient::Multi(builder.build()))
+}
+
+/// Build a RouterClient with every provider that can take video jobs server-side,
+/// for use with `RouterClient::generate_video_with_failover`.
+pub fn build_failover_router_client(
+  server_state: &ServerState,
+  use_alternate_kinovi: bool,
+) -> RouterClient {
+  let client = MultiRouterClientBuilder::new()
+    .set_health_registry(server_state.provider_health.clone())
+    .set_seedance2pro_client(kinovi_client(server_state, use_alternate_kinovi))
+    .set_gmicloud_client(RouterGmiCloudClient::new(
+      server_state.gmicloud.api_key.clone(),
+    ))
+    .set_fal_client(RouterFalClient::new(
+      server_state.fal.api_key.clone(),
+      server_state.fal.webhook_url.clone(),
+    ))
+    .build();
+
+  RouterClient::Multi(client)
 }
 
-fn kinovi_provider(server_state: &ServerState, use_alternate_kinovi: bool) -> Result<RouterClient, AdvancedCommonWebError> {
+/// Build a RouterClient that only talks to the mock provider, if it's enabled.
+pub fn build_mock_router_client(server_state: &ServerState) -> Option<RouterClient> {
+  let mock = server_state.mock_provider.clone()?;
+
+  let client = MultiRouterClientBuilder::new()
+    .set_health_registry(server_state.provider_health.clone())
+    .set_mock_client(mock)
+    .build();
+
+  Some(RouterClient::Multi(client))
+}
+
+fn mock_client(server_state: &ServerState) -> Result<RouterMockClient, AdvancedCommonWebError> {
+  server_state.mock_provider.clone().ok_or_else(|| {
+    AdvancedCommonWebError::server_error_with_message("The mock provider is not enabled")
+  })
+}
+
+fn kinovi_client(server_state: &ServerState, use_alternate_kinovi: bool) -> RouterSeedance2ProClient {
   let session = if use_alternate_kinovi {
     // Alternate Kinovi
     Seedance2ProSession::from_cookies_string(
@@ -51,6 +101,6 @@ fn kinovi_provider(server_state: &ServerState, use_alternate_kinovi: bool) -> Re
       server_state.seedance2pro.cookies.clone()
     )
   };
-  
-  Ok(RouterClient::Seedance2Pro(RouterSeedance2ProClient::new(session)))
+
+  RouterSeedance2ProClient::new(session)
 }
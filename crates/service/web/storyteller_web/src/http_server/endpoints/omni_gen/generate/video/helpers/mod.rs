@@ -3,4 +3,3 @@ pub mod build_router_client;
 pub(crate) mod hydrate_router_request;
 pub mod pipeline_result;
 pub mod resolve_kinovi_character_ids;
-pub mod resolve_media_tokens_to_urls;
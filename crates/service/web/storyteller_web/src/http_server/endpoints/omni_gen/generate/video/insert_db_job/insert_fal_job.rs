@@ -6,6 +6,7 @@ use mysql_queries::queries::generic_inference::fal::insert_generic_inference_job
   insert_generic_inference_job_for_fal_queue_with_apriori_job_token,
   InsertGenericInferenceForFalWithAprioriJobTokenArgs,
 };
+use mysql_queries::queries::generic_inference::fal::set_fal_job_queue_status_url::set_fal_job_queue_status_url;
 use tokens::tokens::generic_inference_jobs::InferenceJobToken;
 
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
@@ -14,12 +15,17 @@ use super::shared_job_args::SharedJobArgs;
 
 pub struct InsertFalJobArgs<'a, 'tx> {
   pub external_job_id: &'a str,
+
+  /// Kept so the job can be cancelled with FAL later.
+  pub maybe_queue_status_url: Option<&'a str>,
+
   pub shared: SharedJobArgs<'a, 'tx>,
 }
 
 pub async fn insert_fal_job(args: InsertFalJobArgs<'_, '_>) -> Result<InferenceJobToken, AdvancedCommonWebError> {
   let InsertFalJobArgs { 
     external_job_id,
+    maybe_queue_status_url,
     shared,
   } = args;
 
@@ -44,11 +50,22 @@ pub async fn insert_fal_job(args: InsertFalJobArgs<'_, '_>) -> Result<InferenceJ
     }
   ).await;
 
-  match db_result {
-    Ok(token) => Ok(token),
+  let token = match db_result {
+    Ok(token) => token,
     Err(err) => {
       warn!("Error inserting fal inference job: {:?}", err);
-      Err(AdvancedCommonWebError::from_error(err))
+      return Err(AdvancedCommonWebError::from_error(err));
     }
+  };
+
+  if let Some(queue_status_url) = maybe_queue_status_url {
+    set_fal_job_queue_status_url(&token, queue_status_url, shared.transaction)
+      .await
+      .map_err(|err| {
+        warn!("Error storing fal queue status url: {:?}", err);
+        AdvancedCommonWebError::from_anyhow_error(err)
+      })?;
   }
+
+  Ok(token)
 }
@@ -0,0 +1,71 @@
+use log::warn;
+
+use enums::common::visibility::Visibility;
+use mysql_queries::queries::generic_inference::fal::insert_generic_inference_job_for_fal_queue::FalCategory;
+use mysql_queries::queries::generic_inference::fal::insert_generic_inference_job_for_fal_queue_with_apriori_job_token::{
+  insert_generic_inference_job_for_fal_queue_with_apriori_job_token,
+  InsertGenericInferenceForFalWithAprioriJobTokenArgs,
+};
+use mysql_queries::queries::generic_inference::mock::set_job_external_third_party_mock::set_job_external_third_party_mock;
+use tokens::tokens::generic_inference_jobs::InferenceJobToken;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+
+use super::shared_job_args::SharedJobArgs;
+
+pub struct InsertMockJobArgs<'a, 'tx> {
+  pub external_job_id: &'a str,
+
+  pub fal_category: FalCategory,
+
+  pub shared: SharedJobArgs<'a, 'tx>,
+}
+
+/// Insert a job for the mock provider. Once the transaction commits, finish it with
+/// `spawn_mock_job_completion`; nothing else will.
+pub async fn insert_mock_job(args: InsertMockJobArgs<'_, '_>) -> Result<InferenceJobToken, AdvancedCommonWebError> {
+  let InsertMockJobArgs {
+    external_job_id,
+    fal_category,
+    shared,
+  } = args;
+
+  // NB: Mock jobs have the same shape as FAL queue jobs; only the third party differs.
+  let db_result = insert_generic_inference_job_for_fal_queue_with_apriori_job_token(
+    InsertGenericInferenceForFalWithAprioriJobTokenArgs {
+      apriori_job_token: shared.apriori_job_token,
+      uuid_idempotency_token: shared.idempotency_token,
+      maybe_external_third_party_id: external_job_id,
+      fal_category,
+      maybe_prompt_token: shared.maybe_prompt_token,
+      maybe_creator_user_token: Some(shared.user_token),
+      maybe_avt_token: shared.maybe_avt_token,
+      creator_ip_address: shared.ip_address,
+      creator_set_visibility: Visibility::Public,
+      maybe_debug_log_event_token: shared.maybe_debug_log_event_token,
+      mysql_executor: &mut **shared.transaction,
+      maybe_inference_args: None,
+      starting_job_status_override: None,
+      maybe_frontend_failure_category: None,
+      maybe_failure_reason: None,
+      phantom: Default::default(),
+    }
+  ).await;
+
+  let token = match db_result {
+    Ok(token) => token,
+    Err(err) => {
+      warn!("Error inserting mock inference job: {:?}", err);
+      return Err(AdvancedCommonWebError::from_error(err));
+    }
+  };
+
+  set_job_external_third_party_mock(&token, shared.transaction)
+    .await
+    .map_err(|err| {
+      warn!("Error marking mock inference job: {:?}", err);
+      AdvancedCommonWebError::from_anyhow_error(err)
+    })?;
+
+  Ok(token)
+}
@@ -1,4 +1,5 @@
 pub mod insert_fal_job;
 pub mod insert_gmicloud_job;
+pub mod insert_mock_job;
 pub mod insert_seedance2pro_jobs;
 pub mod shared_job_args;
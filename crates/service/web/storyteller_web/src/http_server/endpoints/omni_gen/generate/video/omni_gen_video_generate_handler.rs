@@ -13,12 +13,15 @@ use artcraft_router::generate::generate_video::generate_video_response::Generate
 use enums::by_table::debug_logs::debug_log_type::DebugLogType;
 use enums::by_table::prompt_context_items::prompt_context_semantic_type::PromptContextSemanticType;
 use enums::by_table::prompts::prompt_type::PromptType;
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::generation::common_generation_mode::CommonGenerationMode;
 use enums::common::generation::common_model_type::CommonModelType;
 use enums::common::generation::common_video_model::CommonVideoModel;
 use enums::common::generation_provider::GenerationProvider;
 use http_server_common::request::get_request_ip::get_request_ip;
 use mysql_queries::queries::debug_logs::insert_debug_log::{insert_debug_log, InsertDebugLogArgs};
+use mysql_queries::queries::generic_inference::fal::insert_generic_inference_job_for_fal_queue::FalCategory;
 use mysql_queries::queries::idepotency_tokens::insert_idempotency_token::insert_idempotency_token;
 use mysql_queries::queries::prompt_context_items::insert_batch_prompt_context_items::{
   insert_batch_prompt_context_items, InsertBatchArgs, PromptContextItem,
@@ -31,14 +34,17 @@ use tokens::tokens::non_unique::debug_logs_event_token::DebugLogEventToken;
 
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
 use crate::http_server::endpoints::generate::common::payments_error_test::payments_error_test;
+use crate::http_server::endpoints::omni_gen::generate::mock::complete_mock_job::{spawn_mock_job_completion, MockJobCreator};
 use crate::http_server::endpoints::omni_gen::generate::video::helpers::hydrate_router_request::hydrate_to_router_request;
 use crate::http_server::endpoints::omni_gen::generate::video::insert_db_job::insert_fal_job::{insert_fal_job, InsertFalJobArgs};
 use crate::http_server::endpoints::omni_gen::generate::video::insert_db_job::insert_gmicloud_job::{insert_gmicloud_job, InsertGmiCloudJobArgs};
+use crate::http_server::endpoints::omni_gen::generate::video::insert_db_job::insert_mock_job::{insert_mock_job, InsertMockJobArgs};
 use crate::http_server::endpoints::omni_gen::generate::video::insert_db_job::insert_seedance2pro_jobs::{insert_seedance2pro_jobs, InsertSeedance2proJobsArgs};
 use crate::http_server::endpoints::omni_gen::generate::video::insert_db_job::shared_job_args::SharedJobArgs;
 use crate::http_server::endpoints::omni_gen::generate::video::pipeline_v1::run_pipeline_v1::{run_pipeline_v1, RunPipelineV1Args};
 use crate::http_server::endpoints::omni_gen::generate::video::pipeline_v2::run_pipeline_v2::{run_pipeline_v2, RunPipelineV2Args};
 use crate::http_server::endpoints::omni_gen::generate::video::helpers::resolve_kinovi_character_ids::resolve_kinovi_character_ids;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_rate_limit, EnforceRateLimitArgs};
 use crate::http_server::session::lookup::user_session_feature_flags::UserSessionFeatureFlags;
 use crate::http_server::validations::validate_idempotency_token_format::validate_idempotency_token_format;
 use crate::state::server_state::ServerState;
@@ -55,6 +61,7 @@ use crate::util::lookup::lookup_image_urls_as_map::lookup_image_urls_as_map;
     (status = 400, description = "Bad input"),
     (status = 401, description = "Unauthorized"),
     (status = 402, description = "Payment required"),
+    (status = 429, description = "Too many requests"),
     (status = 500, description = "Server error"),
   ),
 )]
@@ -78,21 +85,24 @@ pub async fn omni_gen_video_generate_handler(
 
   let mut mysql_connection = server_state.mysql_pool.acquire().await?;
 
-  let maybe_user_session = server_state
+  // NB: Scripts may call this with a personal access token instead of a session.
+  let maybe_api_caller = server_state
     .session_checker
-    .maybe_get_user_session_from_connection(&http_request, &mut mysql_connection)
+    .maybe_get_api_caller_session_from_connection(&http_request, PersonalAccessTokenScope::Generate, &mut mysql_connection)
     .await
     .map_err(|e| {
       warn!("Session checker error: {:?}", e);
       AdvancedCommonWebError::from(e)
     })?;
 
-  let session = match maybe_user_session.as_ref() {
-    Some(session) => session,
+  let api_caller = match maybe_api_caller.as_ref() {
+    Some(api_caller) => api_caller,
     None => return Err(AdvancedCommonWebError::NotAuthorized),
   };
 
+  let session = &api_caller.user_session;
   let user_token = &session.user_token;
+  let maybe_personal_access_token = api_caller.maybe_personal_access_token.as_ref();
 
   let user_feature_flags =
       UserSessionFeatureFlags::new(session.maybe_feature_flags.as_deref());
@@ -103,6 +113,17 @@ pub async fn omni_gen_video_generate_handler(
     .avt_cookie_manager
     .get_avt_token_from_request(&http_request);
 
+  // ==================== RATE LIMIT ==================== //
+
+  enforce_rate_limit(EnforceRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Generation,
+    maybe_user_session: Some(&api_caller.user_session),
+    maybe_personal_access_token,
+    mysql_connection: &mut mysql_connection,
+  }).await?;
+
   // ==================== IDEMPOTENCY ==================== //
 
   let idempotency_token = request.idempotency_token.as_deref()
@@ -207,6 +228,7 @@ pub async fn omni_gen_video_generate_handler(
       server_state: &server_state,
       mysql_connection: &mut mysql_connection,
       user_token,
+      maybe_personal_access_token,
       media_file_to_url_map: &media_file_to_url_map,
       kinovi_character_id_map: &kinovi_character_id_map,
       use_alternate_kinovi,
@@ -219,6 +241,7 @@ pub async fn omni_gen_video_generate_handler(
       server_state: &server_state,
       mysql_connection: &mut mysql_connection,
       user_token,
+      maybe_personal_access_token,
       media_url_map: &media_file_hydration_map,
       kinovi_character_id_map: &kinovi_character_id_map,
     }).await?
@@ -338,6 +361,8 @@ pub async fn omni_gen_video_generate_handler(
 
   // -- Inference job --
 
+  let mut maybe_mock_job_id = None;
+
   let (primary_job_token, all_job_tokens) = match &pipeline_result.response {
     GenerateVideoResponse::Seedance2Pro(payload) => {
       info!("Inserting seedance2pro job(s) with token: {:?}", pipeline_result.billing.apriori_job_token);
@@ -367,6 +392,7 @@ pub async fn omni_gen_video_generate_handler(
       info!("Inserting fal job with token: {:?}", pipeline_result.billing.apriori_job_token);
       let token = insert_fal_job(InsertFalJobArgs {
         external_job_id: external_id,
+        maybe_queue_status_url: payload.maybe_status_url.as_deref(),
         shared: SharedJobArgs {
           apriori_job_token: &pipeline_result.billing.apriori_job_token,
           idempotency_token: &idempotency_token,
@@ -411,6 +437,32 @@ pub async fn omni_gen_video_generate_handler(
         vec![token],
       )
     }
+    GenerateVideoResponse::Mock(payload) => {
+      // NB: Exercises billing and job creation without a provider. The job is completed from
+      // the mock provider's simulated state once the transaction commits.
+      info!("Inserting mock job with token: {:?}", pipeline_result.billing.apriori_job_token);
+      let token = insert_mock_job(InsertMockJobArgs {
+        external_job_id: &payload.job_id,
+        fal_category: FalCategory::VideoGeneration,
+        shared: SharedJobArgs {
+          apriori_job_token: &pipeline_result.billing.apriori_job_token,
+          idempotency_token: &idempotency_token,
+          user_token,
+          maybe_avt_token: maybe_avt_token.as_ref(),
+          maybe_prompt_token: prompt_token.as_ref(),
+          maybe_debug_log_event_token: Some(&debug_log_event_token),
+          ip_address: &ip_address,
+          transaction: &mut transaction,
+        },
+      }).await?;
+
+      maybe_mock_job_id = Some(payload.job_id.clone());
+
+      (
+        token.clone(),
+        vec![token],
+      )
+    }
     other => {
       error!("Unexpected generation response variant: {:?}", other);
       return Err(AdvancedCommonWebError::server_error_with_message("Unexpected generation response"));
@@ -422,6 +474,15 @@ pub async fn omni_gen_video_generate_handler(
     AdvancedCommonWebError::from_error(err)
   })?;
 
+  if let Some(mock_job_id) = maybe_mock_job_id {
+    spawn_mock_job_completion(server_state.get_ref().clone(), primary_job_token.clone(), mock_job_id, MockJobCreator {
+      maybe_creator_user_token: Some(user_token.clone()),
+      maybe_avt_token: maybe_avt_token.clone(),
+      creator_ip_address: ip_address.clone(),
+      maybe_prompt_token: prompt_token.clone(),
+    });
+  }
+
   Ok(Json(OmniGenVideoGenerateResponse {
     success: true,
     inference_job_token: primary_job_token,
@@ -13,6 +13,7 @@ use artcraft_router::generate::generate_video::generate_video_request_builder::G
 use enums::common::generation::common_video_model::CommonVideoModel;
 use tokens::tokens::characters::CharacterToken;
 use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
 use tokens::tokens::users::UserToken;
 
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
@@ -30,6 +31,7 @@ pub struct RunPipelineV1Args<'a> {
   pub server_state: &'a ServerState,
   pub mysql_connection: &'a mut sqlx::pool::PoolConnection<sqlx::MySql>,
   pub user_token: &'a UserToken,
+  pub maybe_personal_access_token: Option<&'a PersonalAccessTokenToken>,
   pub media_url_map: &'a Option<HashMap<MediaFileToken, Url>>,
   pub kinovi_character_id_map: &'a Option<HashMap<CharacterToken, String>>,
 }
@@ -43,6 +45,7 @@ pub async fn run_pipeline_v1(args: RunPipelineV1Args<'_>) -> Result<PipelineResu
     server_state,
     mysql_connection,
     user_token,
+    maybe_personal_access_token,
     media_url_map,
     kinovi_character_id_map,
   } = args;
@@ -96,7 +99,7 @@ pub async fn run_pipeline_v1(args: RunPipelineV1Args<'_>) -> Result<PipelineResu
   // ── Bill wallet ──
 
   let cost_in_credits = cost.cost_in_credits.unwrap_or(0);
-  let billing = bill_wallet(user_token, cost_in_credits, mysql_connection).await?;
+  let billing = bill_wallet(user_token, maybe_personal_access_token, cost_in_credits, mysql_connection).await?;
 
   // ── Execute generation via the appropriate provider ──
 
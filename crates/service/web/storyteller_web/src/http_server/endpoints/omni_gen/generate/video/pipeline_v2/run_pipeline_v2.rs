@@ -4,20 +4,20 @@ use log::{error, info, warn};
 use sqlx::pool::PoolConnection;
 use artcraft_router::api::common_video_model::CommonVideoModel;
 use artcraft_router::api::provider::Provider;
+use artcraft_router::client::video_failover_policy::{VideoFailoverPolicy, VideoProviderVariant};
+use artcraft_router::errors::artcraft_router_error::ArtcraftRouterError;
 use artcraft_router::generate::generate_video::generate_video_request_builder::GenerateVideoRequestBuilder;
-use artcraft_router::generate::generate_video::generate_video_response::GenerateVideoResponse;
 use artcraft_router::generate::generate_video_v2::video_generation_draft_context::VideoGenerationDraftContext;
-use artcraft_router::generate::generate_video_v2::video_generation_draft_or_request::VideoGenerationDraftOrRequest;
 use tokens::tokens::characters::CharacterToken;
 use tokens::tokens::media_files::MediaFileToken;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
 use tokens::tokens::users::UserToken;
 
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
 use crate::http_server::endpoint_helpers::refund_wallet_after_api_failure::refund_wallet_after_api_failure;
 use crate::http_server::endpoints::omni_gen::generate::video::helpers::bill_wallet::bill_wallet;
-use crate::http_server::endpoints::omni_gen::generate::video::helpers::build_router_client::build_router_client;
+use crate::http_server::endpoints::omni_gen::generate::video::helpers::build_router_client::{build_failover_router_client, build_mock_router_client};
 use crate::http_server::endpoints::omni_gen::generate::video::helpers::pipeline_result::PipelineResult;
-use crate::http_server::endpoints::omni_gen::generate::video::helpers::resolve_media_tokens_to_urls::resolve_media_tokens_to_urls;
 use crate::state::server_state::ServerState;
 
 pub struct RunPipelineV2Args<'a> {
@@ -25,6 +25,7 @@ pub struct RunPipelineV2Args<'a> {
   pub server_state: &'a ServerState,
   pub mysql_connection: &'a mut PoolConnection<sqlx::MySql>,
   pub user_token: &'a UserToken,
+  pub maybe_personal_access_token: Option<&'a PersonalAccessTokenToken>,
   pub media_file_to_url_map: &'a Option<HashMap<MediaFileToken, String>>,
   pub kinovi_character_id_map: &'a Option<HashMap<CharacterToken, String>>,
   pub use_alternate_kinovi: bool,
@@ -36,6 +37,7 @@ pub async fn run_pipeline_v2(args: RunPipelineV2Args<'_>) -> Result<PipelineResu
     server_state,
     mysql_connection,
     user_token,
+    maybe_personal_access_token,
     media_file_to_url_map,
     kinovi_character_id_map,
     use_alternate_kinovi,
@@ -62,26 +64,32 @@ pub async fn run_pipeline_v2(args: RunPipelineV2Args<'_>) -> Result<PipelineResu
     _ => Provider::Fal,
   };
 
-  // 1. Build execution request
-  let mut exec_builder = router_builder.clone();
-  exec_builder.provider = provider;
-
-  // GmiCloud takes URLs directly (like Fal), not media file tokens.
-  // Resolve tokens to URLs before building.
-  if matches!(provider, Provider::GmiCloud) {
-    resolve_media_tokens_to_urls(&mut exec_builder, media_file_to_url_map.as_ref());
-  }
-
-  let draft_or_request = exec_builder.build2()
-      .map_err(|e| {
-        warn!("Failed to build2 for v2 pipeline: {}", e);
-        AdvancedCommonWebError::from_error(e)
-      })?;
+  // 1. Providers to try, in order. Equivalent variants on other providers are tried when the
+  //    preferred one never receives the request or refuses it outright.
+  //    Artcraft is excluded: we are Artcraft, and the handler can't store its jobs.
+  //    With the mock provider enabled, it is the only provider.
+  let maybe_mock_client = build_mock_router_client(server_state);
+
+  let policy = if maybe_mock_client.is_some() {
+    VideoFailoverPolicy::new(vec![VideoProviderVariant::new(Provider::Mock, router_builder.model)])
+  } else {
+    let policy = VideoFailoverPolicy::default_for_model(router_builder.model)
+      .retain_providers(|provider| !matches!(provider, Provider::Artcraft));
+
+    if policy.variants.is_empty() {
+      VideoFailoverPolicy::new(vec![VideoProviderVariant::new(provider, router_builder.model)])
+    } else {
+      policy
+    }
+  };
 
   // 2. Calculate cost.
   //    For Artcraft-billable models, swap provider to Artcraft so credits = cents.
   //    For GmiCloud, use the execution request's cost directly (no Artcraft equivalent).
-  let cost = {
+  //    Mock generations are free.
+  let cost = if maybe_mock_client.is_some() {
+    0
+  } else {
     let mut cost_builder = router_builder.clone();
     cost_builder.provider = Provider::Artcraft;
 
@@ -102,76 +110,52 @@ pub async fn run_pipeline_v2(args: RunPipelineV2Args<'_>) -> Result<PipelineResu
   info!("v2 estimated cost: {} credits", cost);
 
   // 3. Bill wallet
-  let billing = bill_wallet(user_token, cost, mysql_connection).await?;
+  let billing = bill_wallet(user_token, maybe_personal_access_token, cost, mysql_connection).await?;
 
-  // 4. Upload media (if draft) and generate video.
-  //    The entire block is wrapped so Kinovi failures trigger a refund.
-  let result = upload_and_generate(
-    draft_or_request,
-    server_state,
-    media_file_to_url_map.as_ref(),
-    kinovi_character_id_map.as_ref(),
-    use_alternate_kinovi,
-  ).await;
+  // 4. Upload media (if draft) and generate video, failing over between providers.
+  let client = match maybe_mock_client {
+    Some(client) => client,
+    None => build_failover_router_client(server_state, use_alternate_kinovi),
+  };
+
+  let draft_context = VideoGenerationDraftContext {
+    client: None,
+    media_file_to_artcraft_url_map: media_file_to_url_map.as_ref(),
+    character_token_to_kinovi_id_map: kinovi_character_id_map.as_ref(),
+  };
+
+  let result = client.generate_video_with_failover(&router_builder, &policy, draft_context).await;
 
-  // 5. On failure, refund wallet for Kinovi requests.
+  // 5. On failure, refund the wallet for Kinovi requests, or when no provider took the job.
   if let Err(ref err) = result {
-    if matches!(provider, Provider::Seedance2Pro) {
+    let nothing_accepted = matches!(err, ArtcraftRouterError::FailoverExhausted(_));
+    if nothing_accepted || matches!(provider, Provider::Seedance2Pro) {
       if let Some(ledger_entry_token) = billing.maybe_wallet_ledger_entry_token.as_ref() {
-        warn!("Kinovi v2 generation failed, issuing refund for {}: {:?}", ledger_entry_token.as_str(), err);
+        warn!("v2 generation failed, issuing refund for {}: {:?}", ledger_entry_token.as_str(), err);
 
         let result = refund_wallet_after_api_failure(ledger_entry_token, mysql_connection).await;
 
         if let Err(refund_err) = result {
-          error!("Failed to refund wallet after Kinovi v2 failure: {:?}", refund_err);
+          error!("Failed to refund wallet after v2 failure: {:?}", refund_err);
         }
       }
     }
   }
 
-  let response = result?;
+  let failover_response = result.map_err(|err| {
+    warn!("v2 video generation failed: {:?}", err);
+    AdvancedCommonWebError::from_error(err)
+  })?;
 
-  info!("v2 generation response: {:?}", response);
+  for attempt in failover_response.failed_attempts.iter() {
+    warn!("v2 failover skipped {}", attempt);
+  }
 
-  Ok(PipelineResult { billing, response })
-}
+  info!("v2 job accepted by {:?}/{:?}", failover_response.provider, failover_response.model);
 
-/// Finalize the draft (uploading media if needed), then send the generation request.
-///
-/// This is the block that gets refunded on failure for Kinovi providers.
-async fn upload_and_generate(
-  draft_or_request: VideoGenerationDraftOrRequest,
-  server_state: &ServerState,
-  media_file_urls_by_token: Option<&HashMap<MediaFileToken, String>>,
-  kinovi_character_ids: Option<&HashMap<CharacterToken, String>>,
-  use_alternate_kinovi: bool,
-) -> Result<GenerateVideoResponse, AdvancedCommonWebError> {
-
-  let provider = draft_or_request.get_provider();
-  let client = build_router_client(provider, server_state, use_alternate_kinovi)?;
-
-  let video_request = match draft_or_request {
-    VideoGenerationDraftOrRequest::Request(request) => request,
-    VideoGenerationDraftOrRequest::Draft(draft) => {
-      let draft_context = VideoGenerationDraftContext {
-        client: Some(&client),
-        media_file_to_artcraft_url_map: media_file_urls_by_token,
-        character_token_to_kinovi_id_map: kinovi_character_ids,
-      };
-
-      draft.finalize(draft_context)
-          .await
-          .map_err(|err| {
-            warn!("Failed to finalize v2 draft: {:?}", err);
-            AdvancedCommonWebError::from_error(err)
-          })?
-    }
-  };
+  let response = failover_response.response;
 
-  video_request.send_request(&client)
-      .await
-      .map_err(|err| {
-        warn!("v2 video generation failed: {:?}", err);
-        AdvancedCommonWebError::from_error(err)
-      })
+  info!("v2 generation response: {:?}", response);
+
+  Ok(PipelineResult { billing, response })
 }
@@ -59,8 +59,5 @@ pub async fn can_see_happy_horse(
   let user_feature_flags =
       UserSessionFeatureFlags::new(user_session.maybe_feature_flags.as_deref());
 
-  let can_use_happy_horse = user_feature_flags.can_use_happy_horse();
-  let can_use_happy_horse_ratelimited = user_feature_flags.can_use_happy_horse_rate_limited();
-
-  Ok(can_use_happy_horse || can_use_happy_horse_ratelimited)
+  Ok(user_feature_flags.can_use_happy_horse())
 }
@@ -9,6 +9,7 @@ use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use artcraft_api_defs::credits::get_session_credits::GetSessionCreditsResponse;
 use artcraft_api_defs::subscriptions::get_session_subscription::{GetSessionSubscriptionResponse, SubscriptionInfo};
 use chrono::{DateTime, Utc};
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
 use enums::common::payments_namespace::PaymentsNamespace;
 use log::{error, warn};
 use mysql_queries::queries::users::user_subscriptions::find_subscription_for_owner_user::find_subscription_for_owner_user_using_connection;
@@ -54,17 +55,17 @@ pub async fn get_session_subscription_handler(
         CommonWebError::ServerError
       })?;
 
-  let maybe_user_session = server_state
+  let maybe_api_caller = server_state
       .session_checker
-      .maybe_get_user_session_from_connection(&http_request, &mut mysql_connection)
+      .maybe_get_api_caller_session_from_connection(&http_request, PersonalAccessTokenScope::BillingRead, &mut mysql_connection)
       .await
       .map_err(|e| {
         warn!("Session checker error: {:?}", e);
         CommonWebError::ServerError
       })?;
 
-  let user_token = match maybe_user_session {
-    Some(session) => session.user_token,
+  let user_token = match maybe_api_caller {
+    Some(api_caller) => api_caller.user_session.user_token,
     None => return Err(CommonWebError::NotAuthorized),
   };
 
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::http_server::common_responses::common_web_error::CommonWebError;
+use crate::http_server::web_utils::user_session::has_verified_email_address::has_verified_email_address;
 use crate::state::server_state::ServerState;
 use actix_web::web::Json;
 use actix_web::{web, HttpRequest};
@@ -22,6 +23,9 @@ pub struct GetOrCreateSessionArtcraftWalletResponse {
 /// NB: This endpoint was created primarily for local testing with new dev accounts
 /// It should be harmless to have in production as wallet creation is idempotent
 /// and (should be) side effect free.
+///
+/// Creating a new wallet can be gated on a verified email address
+/// (see `REQUIRE_VERIFIED_EMAIL_FOR_FREE_CREDITS`).
 #[utoipa::path(
   get,
   tag = "Wallets",
@@ -51,13 +55,15 @@ pub async fn get_or_create_session_artcraft_wallet_handler(
         CommonWebError::ServerError
       })?;
 
-  let user_token = match maybe_user_session {
-    Some(session) => session.user_token,
+  let user_session = match maybe_user_session {
+    Some(session) => session,
     None => return Err(CommonWebError::NotAuthorized),
   };
 
+  let user_token = &user_session.user_token;
+
   let maybe_wallet_token = find_primary_wallet_token_for_owner_using_connection(
-    &user_token,
+    user_token,
     PaymentsNamespace::Artcraft,
     &mut mysql_connection,
   ).await.map_err(|err| {
@@ -72,6 +78,13 @@ pub async fn get_or_create_session_artcraft_wallet_handler(
     }));
   }
 
+  // NB: New wallets come with free credits, so we can require a verified email to curb throwaway accounts.
+  if server_state.email_verification.require_verified_email_for_free_credits
+      && !has_verified_email_address(&user_session) {
+    return Err(CommonWebError::BadInputWithSimpleMessage(
+      "a verified email address is required".to_string()));
+  }
+
   let mut transaction = mysql_connection
       .begin()
       .await
@@ -81,7 +94,7 @@ pub async fn get_or_create_session_artcraft_wallet_handler(
       })?;
 
   let wallet_token = create_new_artcraft_wallet_for_owner_user(
-    &user_token,
+    user_token,
     &mut transaction,
   ).await.map_err(|err| {
     error!("Error creating artcraft wallet for user {:?}: {:?}", user_token, err);
@@ -6,7 +6,11 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Formatter;
+use std::sync::Arc;
 
+use crate::email::issue_email_verification::{issue_email_verification, IssueEmailVerificationArgs};
+use crate::http_server::requests::get_request_domain_branding::{get_request_domain_branding, DomainBranding};
+use crate::state::server_state::ServerState;
 use crate::util::lookup::resolve_referral_info::resolve_referral_info;
 use crate::http_server::validations::is_reserved_username::is_reserved_username;
 use crate::http_server::validations::validate_passwords::validate_passwords;
@@ -150,6 +154,7 @@ pub async fn create_account_handler(
   mysql_pool: web::Data<MySqlPool>,
   session_cookie_manager: web::Data<HttpUserSessionManager>,
   firehose_publisher: web::Data<FirehosePublisher>,
+  server_state: web::Data<Arc<ServerState>>,
 ) -> Result<HttpResponse, CreateAccountErrorResponse>
 {
   let mut error_fields = HashMap::new();
@@ -327,6 +332,19 @@ pub async fn create_account_handler(
       warn!("error enrolling in studio: {:?}", e);
     }).ok();
 
+  let domain_branding = get_request_domain_branding(&http_request)
+    .unwrap_or(DomainBranding::GetArtCraft);
+
+  // NB: Don't fail signup if the verification email can't be sent; it can be resent later.
+  if let Err(err) = issue_email_verification(IssueEmailVerificationArgs {
+    user_token: &new_user_data.user_token,
+    email_address: &email_address,
+    domain_branding,
+    server_state: &server_state,
+  }).await {
+    warn!("error sending email verification email: {:?}", err);
+  }
+
   let session_cookie = match session_cookie_manager.create_cookie(&session_token, &new_user_data.user_token) {
     Ok(cookie) => cookie,
     Err(_) => return Err(CreateAccountErrorResponse::server_error()),
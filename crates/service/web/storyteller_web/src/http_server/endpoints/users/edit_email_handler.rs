@@ -13,7 +13,10 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use log::warn;
 use utoipa::ToSchema;
 use artcraft_api_defs::users::edit_email::{EditEmailRequest, EditEmailResponse};
+use crate::email::issue_email_verification::{issue_email_verification, IssueEmailVerificationArgs};
+use crate::http_server::requests::get_request_domain_branding::{get_request_domain_branding, DomainBranding};
 use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
+use crate::http_server::web_utils::try_delete_session_cache::try_delete_session_cache;
 use crate::http_server::web_utils::user_session::require_user_session_extended_using_connection::require_user_session_extended_using_connection;
 use crate::state::server_state::ServerState;
 use http_server_common::request::get_request_ip::get_request_ip;
@@ -127,5 +130,22 @@ pub async fn edit_email_handler(
     }
   }
 
+  // NB: The session record caches the old address and `email_confirmed`.
+  try_delete_session_cache(&http_request, &server_state);
+
+  let domain_branding = get_request_domain_branding(&http_request)
+      .unwrap_or(DomainBranding::GetArtCraft);
+
+  // NB: The address change has already been committed, so don't fail the request if the email can't be sent.
+  // The user can request another one from the resend endpoint.
+  if let Err(err) = issue_email_verification(IssueEmailVerificationArgs {
+    user_token: &user_session.user_token_typed,
+    email_address: &email_address,
+    domain_branding,
+    server_state: &server_state,
+  }).await {
+    warn!("Error sending email verification email: {:?}", err);
+  }
+
   Ok(Json(EditEmailResponse { success: true }))
 }
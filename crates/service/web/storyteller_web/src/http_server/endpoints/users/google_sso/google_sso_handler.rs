@@ -5,13 +5,16 @@
 
 use std::fmt;
 use std::fmt::Formatter;
+use std::sync::Arc;
 
 use crate::util::lookup::resolve_referral_info::resolve_referral_info;
 use mysql_queries::queries::user_referrals::insert_user_referral::{insert_user_referral, InsertUserReferralArgs};
 use crate::http_server::endpoints::users::google_sso::check_claims::check_claims;
 use crate::http_server::endpoints::users::google_sso::handle_existing_sso_account::{handle_existing_sso_account, ExistingAccountArgs};
 use crate::http_server::endpoints::users::google_sso::handle_new_sso_account::{handle_new_sso_account, NewSsoArgs};
+use crate::http_server::session::two_factor::maybe_issue_login_two_factor_challenge::maybe_issue_login_two_factor_challenge;
 use crate::state::certs::google_sign_in_cert::GoogleSignInCert;
+use crate::state::server_state::ServerState;
 use actix_artcraft::sessions::user_sessions::http_user_session_manager::HttpUserSessionManager;
 use actix_web::error::ResponseError;
 use actix_web::http::StatusCode;
@@ -122,6 +125,10 @@ pub struct GoogleCreateAccountSuccessResponse {
 pub struct GoogleCreateAccountErrorResponse {
   pub success: bool,
   pub error_type: GoogleCreateAccountErrorType,
+
+  /// Only set for `TwoFactorRequired`. Finish signing in at `/v1/login/two_factor`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub maybe_two_factor_challenge: Option<String>,
 }
 
 #[derive(ToSchema, Copy, Clone, Debug, Serialize)]
@@ -132,6 +139,8 @@ pub enum GoogleCreateAccountErrorType {
   ServerError,
   UsernameReserved,
   UsernameTaken,
+  /// The account has two factor auth enabled; a second factor is required.
+  TwoFactorRequired,
 }
 
 impl GoogleCreateAccountErrorResponse {
@@ -139,6 +148,7 @@ impl GoogleCreateAccountErrorResponse {
     Self {
       success: false,
       error_type: GoogleCreateAccountErrorType::ServerError,
+      maybe_two_factor_challenge: None,
     }
   }
 
@@ -146,6 +156,15 @@ impl GoogleCreateAccountErrorResponse {
     Self {
       success: false,
       error_type: GoogleCreateAccountErrorType::BadRequest,
+      maybe_two_factor_challenge: None,
+    }
+  }
+
+  pub fn two_factor_required(two_factor_challenge: String) -> Self {
+    Self {
+      success: false,
+      error_type: GoogleCreateAccountErrorType::TwoFactorRequired,
+      maybe_two_factor_challenge: Some(two_factor_challenge),
     }
   }
 }
@@ -166,6 +185,7 @@ impl ResponseError for GoogleCreateAccountErrorResponse {
       GoogleCreateAccountErrorType::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
       GoogleCreateAccountErrorType::UsernameReserved => StatusCode::BAD_REQUEST,
       GoogleCreateAccountErrorType::UsernameTaken => StatusCode::BAD_REQUEST,
+      GoogleCreateAccountErrorType::TwoFactorRequired => StatusCode::UNAUTHORIZED,
     }
   }
 
@@ -196,6 +216,7 @@ pub async fn google_sso_handler(
   mysql_pool: Data<MySqlPool>,
   session_cookie_manager: Data<HttpUserSessionManager>,
   google_sign_in_cert: Data<GoogleSignInCert>,
+  server_state: Data<Arc<ServerState>>,
 ) -> Result<HttpResponse, GoogleCreateAccountErrorResponse>
 {
   let claims = check_claims(&request, &google_sign_in_cert).await?;
@@ -303,6 +324,21 @@ pub async fn google_sso_handler(
     },
   }
 
+  // NB: Signing in with Google is a first factor like any other.
+  let maybe_challenge = maybe_issue_login_two_factor_challenge(
+    &user_token,
+    &server_state.two_factor.challenge_signer,
+    &mut *mysql_connection,
+  ).await.map_err(|err| {
+    warn!("error looking up two factor auth: {:?}", err);
+    GoogleCreateAccountErrorResponse::server_error()
+  })?;
+
+  if let Some(challenge) = maybe_challenge {
+    info!("google sign in requires a second factor for user: {:?}", &user_token);
+    return Err(GoogleCreateAccountErrorResponse::two_factor_required(challenge));
+  }
+
   let ip_address = get_request_ip(&http_request);
 
   let session_token = create_user_session_with_transactor(
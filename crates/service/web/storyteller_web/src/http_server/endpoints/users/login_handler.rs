@@ -1,6 +1,7 @@
 use std::fmt;
 use std::fmt::Formatter;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use actix_artcraft::sessions::user_sessions::http_user_session_manager::HttpUserSessionManager;
 use actix_web::error::ResponseError;
@@ -9,6 +10,7 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use chrono::{Duration, Utc};
 use enums::by_table::staff_audit_logs::staff_audit_action::StaffAuditAction;
 use enums::by_table::staff_audit_logs::staff_audit_entity_type::StaffAuditEntityType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use http_server_common::request::get_request_ip::get_request_ip;
 use http_server_common::response::serialize_as_json_error::serialize_as_json_error;
 use log::{info, warn};
@@ -33,7 +35,10 @@ use utoipa::ToSchema;
 use artcraft_api_defs::users::login::{LoginErrorType, LoginRequest, LoginSuccessResponse};
 use password::errors::password_confirm_error::PasswordConfirmError;
 use tokens::tokens::users::UserToken;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_rate_limit, EnforceRateLimitArgs};
 use crate::http_server::session::lookup::user_session_feature_flags::UserSessionFeatureFlags;
+use crate::http_server::session::two_factor::maybe_issue_login_two_factor_challenge::maybe_issue_login_two_factor_challenge;
+use crate::state::server_state::ServerState;
 use crate::util::enroll_in_studio::enroll_in_studio;
 
 #[derive(Serialize, Debug, ToSchema)]
@@ -41,6 +46,10 @@ pub struct LoginErrorResponse {
   pub success: bool,
   pub error_type: LoginErrorType,
   pub error_message: String,
+
+  /// Only set for `TwoFactorRequired`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub maybe_two_factor_challenge: Option<String>,
 }
 
 // NB: Not using DeriveMore since Clion doesn't understand it.
@@ -55,7 +64,9 @@ impl ResponseError for LoginErrorResponse {
     match self.error_type {
       LoginErrorType::InvalidCredentials => StatusCode::UNAUTHORIZED,
       LoginErrorType::AccountNeedsPassword => StatusCode::UNAUTHORIZED,
+      LoginErrorType::RateLimited => StatusCode::TOO_MANY_REQUESTS,
       LoginErrorType::ServerError=> StatusCode::INTERNAL_SERVER_ERROR,
+      LoginErrorType::TwoFactorRequired => StatusCode::UNAUTHORIZED,
     }
   }
 
@@ -65,19 +76,21 @@ impl ResponseError for LoginErrorResponse {
 }
 
 impl LoginErrorResponse {
-  fn invalid_credentials() -> Self {
+  pub(crate) fn invalid_credentials() -> Self {
     Self {
       success: false,
       error_type: LoginErrorType::InvalidCredentials,
-      error_message: "invalid credentials".to_string()
+      error_message: "invalid credentials".to_string(),
+      maybe_two_factor_challenge: None,
     }
   }
   
-  fn server_error() -> Self {
+  pub(crate) fn server_error() -> Self {
     Self {
       success: false,
       error_type: LoginErrorType::ServerError,
-      error_message: "server error".to_string()
+      error_message: "server error".to_string(),
+      maybe_two_factor_challenge: None,
     }
   }
 
@@ -85,7 +98,26 @@ impl LoginErrorResponse {
     Self {
       success: false,
       error_type: LoginErrorType::AccountNeedsPassword,
-      error_message: "account was created without a password; please try password reset".to_string()
+      error_message: "account was created without a password; please try password reset".to_string(),
+      maybe_two_factor_challenge: None,
+    }
+  }
+
+  pub(crate) fn rate_limited() -> Self {
+    Self {
+      success: false,
+      error_type: LoginErrorType::RateLimited,
+      error_message: "too many attempts; please try again later".to_string(),
+      maybe_two_factor_challenge: None,
+    }
+  }
+
+  fn two_factor_required(two_factor_challenge: String) -> Self {
+    Self {
+      success: false,
+      error_type: LoginErrorType::TwoFactorRequired,
+      error_message: "two factor authentication code required".to_string(),
+      maybe_two_factor_challenge: Some(two_factor_challenge),
     }
   }
 }
@@ -96,7 +128,8 @@ impl LoginErrorResponse {
   path = "/v1/login",
   responses(
     (status = 200, description = "Found", body = LoginSuccessResponse),
-    (status = 401, description = "Invalid credentials", body = LoginErrorResponse),
+    (status = 401, description = "Invalid credentials, or a second factor is required", body = LoginErrorResponse),
+    (status = 429, description = "Too many login attempts", body = LoginErrorResponse),
     (status = 500, description = "Server error", body = LoginErrorResponse),
   ),
   params(
@@ -108,8 +141,29 @@ pub async fn login_handler(
   request: web::Json<LoginRequest>,
   session_cookie_manager: web::Data<HttpUserSessionManager>,
   mysql_pool: web::Data<MySqlPool>,
+  server_state: web::Data<Arc<ServerState>>,
 ) -> Result<HttpResponse, LoginErrorResponse>
 {
+  // NB: Callers aren't logged in yet, so this counts against the IP address.
+  let mut mysql_connection = mysql_pool.acquire()
+      .await
+      .map_err(|err| {
+        warn!("Could not acquire DB pool: {:?}", err);
+        LoginErrorResponse::server_error()
+      })?;
+
+  enforce_rate_limit(EnforceRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::Auth,
+    maybe_user_session: None,
+    maybe_personal_access_token: None,
+    mysql_connection: &mut mysql_connection,
+  }).await
+      .map_err(|_err| LoginErrorResponse::rate_limited())?;
+
+  drop(mysql_connection);
+
   let check_username_or_email = request.username_or_email.to_lowercase();
 
   // TODO(bt,2023-11-12): I need to prevent user lookup attacks.
@@ -162,6 +216,22 @@ pub async fn login_handler(
 
   let session_token = if password_error.is_none() {
     // Normal login path — password matched.
+
+    // NB: Impersonation logins (below) are deliberately exempt from the second factor.
+    let maybe_challenge = maybe_issue_login_two_factor_challenge(
+      &user.token,
+      &server_state.two_factor.challenge_signer,
+      &**mysql_pool,
+    ).await.map_err(|err| {
+      warn!("Login two factor lookup error: {:?}", err);
+      LoginErrorResponse::server_error()
+    })?;
+
+    if let Some(challenge) = maybe_challenge {
+      info!("login requires a second factor for user: {:?}", &user.token);
+      return Err(LoginErrorResponse::two_factor_required(challenge));
+    }
+
     let create_session_result =
       create_user_session(&user.token.0, &ip_address, &mysql_pool).await;
 
@@ -199,12 +269,21 @@ pub async fn login_handler(
     }
   };
 
-  let session_cookie = match session_cookie_manager.create_cookie(&session_token, &user.token) {
+  login_success_response(&session_cookie_manager, &session_token, &user.token)
+}
+
+/// Set the session cookie and also return the signed session in the body.
+pub(crate) fn login_success_response(
+  session_cookie_manager: &HttpUserSessionManager,
+  session_token: &UserSessionToken,
+  user_token: &UserToken,
+) -> Result<HttpResponse, LoginErrorResponse> {
+  let session_cookie = match session_cookie_manager.create_cookie(session_token, user_token) {
     Ok(cookie) => cookie,
     Err(_) => return Err(LoginErrorResponse::server_error()),
   };
 
-  let signed_session = match session_cookie_manager.encode_session_payload(&session_token, &user.token) {
+  let signed_session = match session_cookie_manager.encode_session_payload(session_token, user_token) {
     Ok(payload) => payload,
     Err(_) => return Err(LoginErrorResponse::server_error()),
   };
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use actix_artcraft::sessions::user_sessions::http_user_session_manager::HttpUserSessionManager;
+use actix_web::{web, HttpRequest, HttpResponse};
+use http_server_common::request::get_request_ip::get_request_ip;
+use log::{info, warn};
+use mysql_queries::queries::users::user_sessions::create_user_session::create_user_session;
+use mysql_queries::queries::users::user_two_factor::get_user_two_factor_auth::get_user_two_factor_auth;
+use tokens::tokens::user_sessions::UserSessionToken;
+
+use artcraft_api_defs::users::login::{LoginSuccessResponse, LoginTwoFactorRequest};
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::endpoints::users::login_handler::{login_success_response, LoginErrorResponse};
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
+use crate::http_server::session::two_factor::verify_two_factor_code::verify_two_factor_code;
+use crate::state::server_state::ServerState;
+
+/// Finish a login for an account with two factor auth enabled.
+///
+/// Exchanges the challenge from a `TwoFactorRequired` login error, plus an authenticator
+/// code or recovery code, for a session.
+#[utoipa::path(
+  post,
+  tag = "Users",
+  path = "/v1/login/two_factor",
+  responses(
+    (status = 200, description = "Found", body = LoginSuccessResponse),
+    (status = 401, description = "Invalid or expired challenge, or wrong code", body = LoginErrorResponse),
+    (status = 429, description = "Too many attempts", body = LoginErrorResponse),
+    (status = 500, description = "Server error", body = LoginErrorResponse),
+  ),
+  params(
+    ("request" = LoginTwoFactorRequest, description = "Payload for Request"),
+  )
+)]
+pub async fn login_two_factor_handler(
+  http_request: HttpRequest,
+  request: web::Json<LoginTwoFactorRequest>,
+  session_cookie_manager: web::Data<HttpUserSessionManager>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<HttpResponse, LoginErrorResponse>
+{
+  let user_token = server_state.two_factor
+      .challenge_signer
+      .decode(&request.two_factor_challenge)
+      .map_err(|err| {
+        info!("Rejected two factor login challenge: {:?}", err);
+        LoginErrorResponse::invalid_credentials()
+      })?;
+
+  // NB: Counted per user (not per IP) so that spreading guesses across addresses doesn't help.
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::TwoFactor,
+    maybe_user_token: Some(&user_token),
+    tier: RateLimitTier::Free,
+  }).await
+      .map_err(|_err| LoginErrorResponse::rate_limited())?;
+
+  let maybe_two_factor = get_user_two_factor_auth(&user_token, &server_state.mysql_pool)
+      .await
+      .map_err(|err| {
+        warn!("Two factor lookup error: {:?}", err);
+        LoginErrorResponse::server_error()
+      })?;
+
+  let two_factor = match maybe_two_factor {
+    Some(two_factor) if two_factor.is_enabled => two_factor,
+    _ => {
+      // NB: Two factor was disabled or reset after the challenge was issued; log in again.
+      return Err(LoginErrorResponse::invalid_credentials());
+    }
+  };
+
+  let verified = verify_two_factor_code(
+    &user_token,
+    &two_factor.totp_secret,
+    &request.code,
+    &server_state.mysql_pool,
+  ).await.map_err(|err| {
+    warn!("Two factor verification error: {:?}", err);
+    LoginErrorResponse::server_error()
+  })?;
+
+  if !verified {
+    return Err(LoginErrorResponse::invalid_credentials());
+  }
+
+  let ip_address = get_request_ip(&http_request);
+
+  let session_token = create_user_session(&user_token.0, &ip_address, &server_state.mysql_pool)
+      .await
+      .map_err(|err| {
+        warn!("login create session error: {:?}", err);
+        LoginErrorResponse::server_error()
+      })?;
+
+  info!("two factor login session created for user: {:?}", &user_token);
+
+  let session_token = UserSessionToken::new_from_str(&session_token);
+
+  login_success_response(&session_cookie_manager, &session_token, &user_token)
+}
@@ -6,8 +6,13 @@ pub mod edit_username_handler;
 pub mod get_profile_handler;
 pub mod google_sso;
 pub mod login_handler;
+pub mod login_two_factor_handler;
 pub mod logout_handler;
 pub mod password_reset_redeem_handler;
+pub mod personal_access_tokens;
 pub mod password_reset_request_handler;
+pub mod redeem_email_verification_handler;
+pub mod resend_email_verification_handler;
 pub mod session_info_handler;
 pub mod session_token_info_handler;
+pub mod two_factor;
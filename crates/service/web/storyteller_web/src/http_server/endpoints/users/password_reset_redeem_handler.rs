@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::sync::Arc;
 
 use actix_artcraft::sessions::user_sessions::http_user_session_manager::HttpUserSessionManager;
 use actix_web::http::StatusCode;
@@ -14,6 +15,9 @@ use sqlx::MySqlPool;
 use strum_macros::Display;
 use tokens::tokens::user_sessions::UserSessionToken;
 
+use crate::http_server::session::two_factor::maybe_issue_login_two_factor_challenge::maybe_issue_login_two_factor_challenge;
+use crate::state::server_state::ServerState;
+
 #[derive(Deserialize)]
 pub struct PasswordResetRedemptionRequest {
     reset_token: String,
@@ -37,6 +41,9 @@ pub enum PasswordResetRedemptionError {
     InvalidRedemption,
     PasswordsDoNotMatch,
     Internal,
+    /// The password was changed, but the account has two factor auth enabled, so no
+    /// session is issued. Finish signing in at `/v1/login/two_factor`.
+    TwoFactorRequired,
 }
 
 impl PasswordResetRedemptionError {
@@ -45,6 +52,7 @@ impl PasswordResetRedemptionError {
             Self::InvalidRedemption => "The redemption code is invalid, has already been redeemed, or has been replaced by a newer code.",
             Self::PasswordsDoNotMatch => "The passwords do not match.",
             Self::Internal => "An internal error occurred. Please try again later.",
+            Self::TwoFactorRequired => "Your password was changed. Enter a two factor code to finish signing in.",
         }
     }
 
@@ -53,6 +61,7 @@ impl PasswordResetRedemptionError {
             Self::InvalidRedemption => StatusCode::BAD_REQUEST,
             Self::PasswordsDoNotMatch => StatusCode::BAD_REQUEST,
             Self::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::TwoFactorRequired => StatusCode::UNAUTHORIZED,
         }
     }
 }
@@ -62,6 +71,18 @@ pub struct PasswordResetRedemptionErrorResponse {
     success: bool,
     kind: PasswordResetRedemptionError,
     message: String,
+
+    /// Only set for `TwoFactorRequired`. Finish signing in at `/v1/login/two_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maybe_two_factor_challenge: Option<String>,
+}
+
+impl PasswordResetRedemptionErrorResponse {
+    fn two_factor_required(two_factor_challenge: String) -> Self {
+        let kind = PasswordResetRedemptionError::TwoFactorRequired;
+        let message = kind.message().to_string();
+        Self { kind, success: false, message, maybe_two_factor_challenge: Some(two_factor_challenge) }
+    }
 }
 
 impl Display for PasswordResetRedemptionErrorResponse {
@@ -73,7 +94,7 @@ impl Display for PasswordResetRedemptionErrorResponse {
 impl From<PasswordResetRedemptionError> for PasswordResetRedemptionErrorResponse {
     fn from(value: PasswordResetRedemptionError) -> Self {
         let message = value.message().to_string();
-        Self { kind: value, success: false, message }
+        Self { kind: value, success: false, message, maybe_two_factor_challenge: None }
     }
 }
 
@@ -82,7 +103,7 @@ impl From<errors::AnyhowError> for PasswordResetRedemptionErrorResponse {
         log::error!("Internal error: {value}");
         let error = PasswordResetRedemptionError::Internal;
         let message = error.message().to_string();
-        Self { kind: error, success: false, message }
+        Self { kind: error, success: false, message, maybe_two_factor_challenge: None }
     }
 }
 
@@ -107,6 +128,7 @@ pub async fn password_reset_redeem_handler(
     request: web::Json<PasswordResetRedemptionRequest>,
     session_cookie_manager: web::Data<HttpUserSessionManager>,
     mysql_pool: web::Data<MySqlPool>,
+    server_state: web::Data<Arc<ServerState>>,
 ) -> Result<HttpResponse, PasswordResetRedemptionErrorResponse> {
 
     let new_password = request.new_password.trim();
@@ -156,6 +178,21 @@ pub async fn password_reset_redeem_handler(
         return Err(PasswordResetRedemptionError::Internal.into());
     }
 
+    // NB: Controlling the mailbox is only one factor. Users with two factor auth enabled
+    // get the same challenge as the login and SSO flows rather than a session.
+    let maybe_challenge = maybe_issue_login_two_factor_challenge(
+        &transaction_and_state.reset_state.user_token,
+        &server_state.two_factor.challenge_signer,
+        &**mysql_pool,
+    ).await.map_err(|err| {
+        error!("error looking up two factor auth: {:?}", err);
+        PasswordResetRedemptionError::Internal
+    })?;
+
+    if let Some(challenge) = maybe_challenge {
+        return Err(PasswordResetRedemptionErrorResponse::two_factor_required(challenge));
+    }
+
     let create_session_result =
         create_user_session(&transaction_and_state.reset_state.user_token.0, &ip_address, &mysql_pool).await;
 
@@ -201,3 +238,35 @@ pub async fn password_reset_redeem_handler(
         .content_type("application/json")
         .body(body))
 }
+
+#[cfg(test)]
+mod tests {
+    use actix_http::body::MessageBody;
+    use actix_web::http::StatusCode;
+    use actix_web::ResponseError;
+
+    use crate::http_server::endpoints::users::password_reset_redeem_handler::{PasswordResetRedemptionError, PasswordResetRedemptionErrorResponse};
+
+    #[test]
+    fn test_two_factor_reset_returns_challenge_without_session() {
+        let error = PasswordResetRedemptionErrorResponse::two_factor_required("challenge-123".to_string());
+        assert_eq!(error.status_code(), StatusCode::UNAUTHORIZED);
+
+        let response = error.error_response();
+        assert!(response.cookies().next().is_none());
+
+        let bytes = response.into_body().try_into_bytes().unwrap();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(body.contains("\"kind\":\"TwoFactorRequired\""));
+        assert!(body.contains("\"maybe_two_factor_challenge\":\"challenge-123\""));
+        assert!(!body.contains("signed_session"));
+    }
+
+    #[test]
+    fn test_other_errors_omit_challenge() {
+        let error = PasswordResetRedemptionErrorResponse::from(PasswordResetRedemptionError::InvalidRedemption);
+        let bytes = error.error_response().into_body().try_into_bytes().unwrap();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(!body.contains("maybe_two_factor_challenge"));
+    }
+}
@@ -165,7 +165,7 @@ pub async fn password_reset_request_handler(
     send_password_reset_email(SendPasswordResetEmailArgs {
         email_address_destination: &user.email_address,
         verification_token: &secret_key,
-        resend_api_key: &server_state.resend.api_key,
+        outbound_email_queue: &server_state.outbound_email_queue,
         server_environment,
         domain_branding,
     }).await?;
@@ -0,0 +1,146 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest};
+use chrono::{Duration, Utc};
+use log::{info, warn};
+
+use artcraft_api_defs::users::personal_access_tokens::{CreatePersonalAccessTokenRequest, CreatePersonalAccessTokenResponse};
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+use http_server_common::request::get_request_ip::get_request_ip;
+use mysql_queries::queries::users::user_personal_access_tokens::insert_user_personal_access_token::{insert_user_personal_access_token, InsertUserPersonalAccessTokenArgs};
+use mysql_queries::queries::users::user_personal_access_tokens::list_user_personal_access_tokens::list_user_personal_access_tokens;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+
+use crate::http_server::endpoints::users::personal_access_tokens::personal_access_token_endpoint_error::PersonalAccessTokenEndpointError;
+use crate::http_server::session::personal_access_tokens::personal_access_token_secret::{generate_personal_access_token_secret, hash_personal_access_token_secret, personal_access_token_display_prefix};
+use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::state::server_state::ServerState;
+
+const MAX_NAME_LENGTH: usize = 64;
+
+const MAX_EXPIRES_IN_DAYS: u32 = 365;
+
+/// Revoked tokens don't count towards this.
+const MAX_ACTIVE_TOKENS_PER_USER: usize = 20;
+
+/// Create a personal access token for calling the API from scripts.
+///
+/// This requires a session; personal access tokens can't mint more tokens.
+/// The secret is only returned here, once.
+#[utoipa::path(
+  post,
+  tag = "Users",
+  path = "/v1/user/personal_access_tokens/create",
+  responses(
+    (status = 200, description = "Success", body = CreatePersonalAccessTokenResponse),
+    (status = 400, description = "Bad input", body = PersonalAccessTokenEndpointError),
+    (status = 401, description = "Not authorized", body = PersonalAccessTokenEndpointError),
+    (status = 500, description = "Server error", body = PersonalAccessTokenEndpointError),
+  ),
+  params(
+    ("request" = CreatePersonalAccessTokenRequest, description = "Payload for Request"),
+  )
+)]
+pub async fn create_personal_access_token_handler(
+  http_request: HttpRequest,
+  request: Json<CreatePersonalAccessTokenRequest>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<CreatePersonalAccessTokenResponse>, PersonalAccessTokenEndpointError>
+{
+  let mut mysql_connection = server_state.mysql_pool
+      .acquire()
+      .await
+      .map_err(|err| {
+        warn!("MySql pool error: {:?}", err);
+        PersonalAccessTokenEndpointError::ServerError
+      })?;
+
+  let user_session = require_user_session_using_connection(
+    &http_request,
+    &server_state.session_checker,
+    &mut mysql_connection)
+      .await
+      .map_err(|_err| {
+        PersonalAccessTokenEndpointError::NotAuthorized
+      })?;
+
+  // --- Validate input ---
+
+  let name = request.name.trim();
+
+  if name.is_empty() {
+    return Err(PersonalAccessTokenEndpointError::BadInput("name is required".to_string()));
+  }
+
+  if name.chars().count() > MAX_NAME_LENGTH {
+    return Err(PersonalAccessTokenEndpointError::BadInput(
+      format!("name must be at most {} characters", MAX_NAME_LENGTH)));
+  }
+
+  let scopes = request.scopes.iter()
+      .copied()
+      .collect::<BTreeSet<PersonalAccessTokenScope>>();
+
+  if scopes.is_empty() {
+    return Err(PersonalAccessTokenEndpointError::BadInput("at least one scope is required".to_string()));
+  }
+
+  let maybe_expires_at = match request.maybe_expires_in_days {
+    None => None,
+    Some(days) if days == 0 || days > MAX_EXPIRES_IN_DAYS => {
+      return Err(PersonalAccessTokenEndpointError::BadInput(
+        format!("expiry must be between 1 and {} days", MAX_EXPIRES_IN_DAYS)));
+    }
+    Some(days) => Some(Utc::now() + Duration::days(days as i64)),
+  };
+
+  let active_tokens = list_user_personal_access_tokens(&user_session.user_token, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error listing personal access tokens: {:?}", err);
+        PersonalAccessTokenEndpointError::ServerError
+      })?;
+
+  if active_tokens.len() >= MAX_ACTIVE_TOKENS_PER_USER {
+    return Err(PersonalAccessTokenEndpointError::BadInput(
+      format!("you can have at most {} tokens; revoke one first", MAX_ACTIVE_TOKENS_PER_USER)));
+  }
+
+  // --- Create ---
+
+  let token = PersonalAccessTokenToken::generate();
+  let secret = generate_personal_access_token_secret();
+  let ip_address = get_request_ip(&http_request);
+
+  insert_user_personal_access_token(InsertUserPersonalAccessTokenArgs {
+    token: &token,
+    user_token: &user_session.user_token,
+    name,
+    secret_prefix: &personal_access_token_display_prefix(&secret),
+    secret_hash: &hash_personal_access_token_secret(&secret),
+    scopes: &PersonalAccessTokenScope::set_to_string(&scopes),
+    maybe_spend_limit_credits: request.maybe_spend_limit_credits,
+    maybe_expires_at,
+    ip_address_creation: &ip_address,
+  }, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error inserting personal access token: {:?}", err);
+        PersonalAccessTokenEndpointError::ServerError
+      })?;
+
+  info!("Personal access token {:?} created for user {:?}", token, user_session.user_token);
+
+  Ok(Json(CreatePersonalAccessTokenResponse {
+    success: true,
+    token,
+    secret,
+  }))
+}
@@ -0,0 +1,80 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::sync::Arc;
+
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest};
+use log::warn;
+
+use artcraft_api_defs::users::personal_access_tokens::{ListPersonalAccessTokensResponse, PersonalAccessTokenDetails};
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+use mysql_queries::queries::users::user_personal_access_tokens::list_user_personal_access_tokens::list_user_personal_access_tokens;
+
+use crate::http_server::endpoints::users::personal_access_tokens::personal_access_token_endpoint_error::PersonalAccessTokenEndpointError;
+use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::state::server_state::ServerState;
+
+/// List the session user's personal access tokens along with their usage and spend.
+#[utoipa::path(
+  get,
+  tag = "Users",
+  path = "/v1/user/personal_access_tokens/list",
+  responses(
+    (status = 200, description = "Success", body = ListPersonalAccessTokensResponse),
+    (status = 401, description = "Not authorized", body = PersonalAccessTokenEndpointError),
+    (status = 500, description = "Server error", body = PersonalAccessTokenEndpointError),
+  ),
+)]
+pub async fn list_personal_access_tokens_handler(
+  http_request: HttpRequest,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ListPersonalAccessTokensResponse>, PersonalAccessTokenEndpointError>
+{
+  let mut mysql_connection = server_state.mysql_pool
+      .acquire()
+      .await
+      .map_err(|err| {
+        warn!("MySql pool error: {:?}", err);
+        PersonalAccessTokenEndpointError::ServerError
+      })?;
+
+  let user_session = require_user_session_using_connection(
+    &http_request,
+    &server_state.session_checker,
+    &mut mysql_connection)
+      .await
+      .map_err(|_err| {
+        PersonalAccessTokenEndpointError::NotAuthorized
+      })?;
+
+  let records = list_user_personal_access_tokens(&user_session.user_token, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error listing personal access tokens: {:?}", err);
+        PersonalAccessTokenEndpointError::ServerError
+      })?;
+
+  let personal_access_tokens = records.into_iter()
+      .map(|record| PersonalAccessTokenDetails {
+        token: record.token,
+        name: record.name,
+        secret_prefix: record.secret_prefix,
+        scopes: PersonalAccessTokenScope::set_from_str(&record.scopes).into_iter().collect(),
+        maybe_spend_limit_credits: record.maybe_spend_limit_credits,
+        credits_spent: record.credits_spent,
+        use_count: record.use_count,
+        maybe_last_used_at: record.maybe_last_used_at,
+        maybe_last_used_ip_address: record.maybe_last_used_ip_address,
+        maybe_expires_at: record.maybe_expires_at,
+        created_at: record.created_at,
+      })
+      .collect();
+
+  Ok(Json(ListPersonalAccessTokensResponse {
+    success: true,
+    personal_access_tokens,
+  }))
+}
@@ -0,0 +1,4 @@
+pub mod create_personal_access_token_handler;
+pub mod list_personal_access_tokens_handler;
+pub mod personal_access_token_endpoint_error;
+pub mod revoke_personal_access_token_handler;
@@ -0,0 +1,46 @@
+use std::fmt;
+
+use actix_web::error::ResponseError;
+use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
+use utoipa::ToSchema;
+
+use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
+
+/// Shared by the personal access token management endpoints.
+#[derive(Debug, ToSchema)]
+pub enum PersonalAccessTokenEndpointError {
+  BadInput(String),
+  NotAuthorized,
+  NotFound,
+  ServerError,
+}
+
+impl ResponseError for PersonalAccessTokenEndpointError {
+  fn status_code(&self) -> StatusCode {
+    match *self {
+      PersonalAccessTokenEndpointError::BadInput(_) => StatusCode::BAD_REQUEST,
+      PersonalAccessTokenEndpointError::NotAuthorized => StatusCode::UNAUTHORIZED,
+      PersonalAccessTokenEndpointError::NotFound => StatusCode::NOT_FOUND,
+      PersonalAccessTokenEndpointError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    let error_reason = match self {
+      PersonalAccessTokenEndpointError::BadInput(reason) => reason.to_string(),
+      PersonalAccessTokenEndpointError::NotAuthorized => "unauthorized".to_string(),
+      PersonalAccessTokenEndpointError::NotFound => "not found".to_string(),
+      PersonalAccessTokenEndpointError::ServerError => "server error".to_string(),
+    };
+
+    to_simple_json_error(&error_reason, self.status_code())
+  }
+}
+
+// NB: Not using derive_more::Display since Clion doesn't understand it.
+impl fmt::Display for PersonalAccessTokenEndpointError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
@@ -0,0 +1,71 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::sync::Arc;
+
+use actix_web::web::{Json, Path};
+use actix_web::{web, HttpRequest};
+use log::{info, warn};
+
+use artcraft_api_defs::users::personal_access_tokens::{RevokePersonalAccessTokenPathInfo, RevokePersonalAccessTokenResponse};
+use mysql_queries::queries::users::user_personal_access_tokens::revoke_user_personal_access_token::revoke_user_personal_access_token;
+
+use crate::http_server::endpoints::users::personal_access_tokens::personal_access_token_endpoint_error::PersonalAccessTokenEndpointError;
+use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::state::server_state::ServerState;
+
+/// Revoke one of the session user's personal access tokens. It stops working immediately.
+#[utoipa::path(
+  post,
+  tag = "Users",
+  path = "/v1/user/personal_access_tokens/{token}/revoke",
+  responses(
+    (status = 200, description = "Success", body = RevokePersonalAccessTokenResponse),
+    (status = 401, description = "Not authorized", body = PersonalAccessTokenEndpointError),
+    (status = 404, description = "Not found", body = PersonalAccessTokenEndpointError),
+    (status = 500, description = "Server error", body = PersonalAccessTokenEndpointError),
+  ),
+  params(
+    ("path" = RevokePersonalAccessTokenPathInfo, description = "Path for Request"),
+  )
+)]
+pub async fn revoke_personal_access_token_handler(
+  http_request: HttpRequest,
+  path: Path<RevokePersonalAccessTokenPathInfo>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<RevokePersonalAccessTokenResponse>, PersonalAccessTokenEndpointError>
+{
+  let mut mysql_connection = server_state.mysql_pool
+      .acquire()
+      .await
+      .map_err(|err| {
+        warn!("MySql pool error: {:?}", err);
+        PersonalAccessTokenEndpointError::ServerError
+      })?;
+
+  let user_session = require_user_session_using_connection(
+    &http_request,
+    &server_state.session_checker,
+    &mut mysql_connection)
+      .await
+      .map_err(|_err| {
+        PersonalAccessTokenEndpointError::NotAuthorized
+      })?;
+
+  let revoked = revoke_user_personal_access_token(&path.token, &user_session.user_token, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error revoking personal access token: {:?}", err);
+        PersonalAccessTokenEndpointError::ServerError
+      })?;
+
+  if !revoked {
+    return Err(PersonalAccessTokenEndpointError::NotFound);
+  }
+
+  info!("Personal access token {:?} revoked by user {:?}", path.token, user_session.user_token);
+
+  Ok(Json(RevokePersonalAccessTokenResponse { success: true }))
+}
@@ -0,0 +1,107 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::fmt;
+use std::sync::Arc;
+
+use actix_web::error::ResponseError;
+use actix_web::http::StatusCode;
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest, HttpResponse};
+use log::{info, warn};
+use utoipa::ToSchema;
+
+use artcraft_api_defs::users::email_verification::{RedeemEmailVerificationRequest, RedeemEmailVerificationResponse};
+use mysql_queries::queries::users::user::update::set_email_confirmed::set_email_confirmed;
+
+use crate::email::email_verification_token_signer::EmailVerificationTokenError;
+use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
+use crate::http_server::web_utils::try_delete_session_cache::try_delete_session_cache;
+use crate::state::server_state::ServerState;
+
+#[derive(Debug, ToSchema)]
+pub enum RedeemEmailVerificationError {
+  BadInput(String),
+  ServerError,
+}
+
+impl ResponseError for RedeemEmailVerificationError {
+  fn status_code(&self) -> StatusCode {
+    match *self {
+      RedeemEmailVerificationError::BadInput(_) => StatusCode::BAD_REQUEST,
+      RedeemEmailVerificationError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    let error_reason = match self {
+      RedeemEmailVerificationError::BadInput(reason) => reason.to_string(),
+      RedeemEmailVerificationError::ServerError => "server error".to_string(),
+    };
+
+    to_simple_json_error(&error_reason, self.status_code())
+  }
+}
+
+// NB: Not using derive_more::Display since Clion doesn't understand it.
+impl fmt::Display for RedeemEmailVerificationError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+
+/// Redeem an email verification token, marking the email address as verified.
+///
+/// Non-authenticated! The signed token identifies the user and the address.
+#[utoipa::path(
+  post,
+  tag = "Users",
+  path = "/v1/user/email_verification/redeem",
+  responses(
+    (status = 200, description = "Success", body = RedeemEmailVerificationResponse),
+    (status = 400, description = "Bad input", body = RedeemEmailVerificationError),
+    (status = 500, description = "Server error", body = RedeemEmailVerificationError),
+  ),
+  params(
+    ("request" = RedeemEmailVerificationRequest, description = "Payload for Request"),
+  )
+)]
+pub async fn redeem_email_verification_handler(
+  http_request: HttpRequest,
+  request: Json<RedeemEmailVerificationRequest>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<RedeemEmailVerificationResponse>, RedeemEmailVerificationError>
+{
+  let payload = server_state.email_verification
+      .token_signer
+      .decode(&request.token)
+      .map_err(|err| match err {
+        EmailVerificationTokenError::ExpiredToken => {
+          RedeemEmailVerificationError::BadInput("verification link has expired".to_string())
+        }
+        _ => RedeemEmailVerificationError::BadInput("invalid verification token".to_string()),
+      })?;
+
+  let updated = set_email_confirmed(
+    &payload.user_token,
+    &payload.email_address,
+    &server_state.mysql_pool,
+  ).await.map_err(|err| {
+    warn!("Error confirming email: {:?}", err);
+    RedeemEmailVerificationError::ServerError
+  })?;
+
+  if !updated {
+    // NB: The user changed their email address after this token was issued.
+    return Err(RedeemEmailVerificationError::BadInput("verification link is no longer valid".to_string()));
+  }
+
+  info!("Email address verified for user {:?}", payload.user_token);
+
+  // NB: The session record caches `email_confirmed`.
+  try_delete_session_cache(&http_request, &server_state);
+
+  Ok(Json(RedeemEmailVerificationResponse { success: true }))
+}
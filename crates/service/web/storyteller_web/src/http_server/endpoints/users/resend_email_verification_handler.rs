@@ -0,0 +1,130 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::fmt;
+use std::sync::Arc;
+
+use actix_web::error::ResponseError;
+use actix_web::http::StatusCode;
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest, HttpResponse};
+use log::warn;
+use utoipa::ToSchema;
+
+use artcraft_api_defs::users::email_verification::ResendEmailVerificationResponse;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::email::issue_email_verification::{issue_email_verification, IssueEmailVerificationArgs};
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
+use crate::http_server::requests::get_request_domain_branding::{get_request_domain_branding, DomainBranding};
+use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
+use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::state::server_state::ServerState;
+
+#[derive(Debug, ToSchema)]
+pub enum ResendEmailVerificationError {
+  BadInput(String),
+  NotAuthorized,
+  RateLimited,
+  ServerError,
+}
+
+impl ResponseError for ResendEmailVerificationError {
+  fn status_code(&self) -> StatusCode {
+    match *self {
+      ResendEmailVerificationError::BadInput(_) => StatusCode::BAD_REQUEST,
+      ResendEmailVerificationError::NotAuthorized => StatusCode::UNAUTHORIZED,
+      ResendEmailVerificationError::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+      ResendEmailVerificationError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    let error_reason = match self {
+      ResendEmailVerificationError::BadInput(reason) => reason.to_string(),
+      ResendEmailVerificationError::NotAuthorized => "unauthorized".to_string(),
+      ResendEmailVerificationError::RateLimited => "rate limited".to_string(),
+      ResendEmailVerificationError::ServerError => "server error".to_string(),
+    };
+
+    to_simple_json_error(&error_reason, self.status_code())
+  }
+}
+
+// NB: Not using derive_more::Display since Clion doesn't understand it.
+impl fmt::Display for ResendEmailVerificationError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+
+/// Send a new verification email to the current user's email address.
+#[utoipa::path(
+  post,
+  tag = "Users",
+  path = "/v1/user/email_verification/resend",
+  responses(
+    (status = 200, description = "Success", body = ResendEmailVerificationResponse),
+    (status = 400, description = "Bad input", body = ResendEmailVerificationError),
+    (status = 401, description = "Not authorized", body = ResendEmailVerificationError),
+    (status = 429, description = "Rate limited", body = ResendEmailVerificationError),
+    (status = 500, description = "Server error", body = ResendEmailVerificationError),
+  ),
+)]
+pub async fn resend_email_verification_handler(
+  http_request: HttpRequest,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ResendEmailVerificationResponse>, ResendEmailVerificationError>
+{
+  let mut mysql_connection = server_state.mysql_pool
+      .acquire()
+      .await
+      .map_err(|err| {
+        warn!("MySql pool error: {:?}", err);
+        ResendEmailVerificationError::ServerError
+      })?;
+
+  let user_session = require_user_session_using_connection(
+    &http_request,
+    &server_state.session_checker,
+    &mut mysql_connection)
+      .await
+      .map_err(|_err| {
+        ResendEmailVerificationError::NotAuthorized
+      })?;
+
+  if user_session.email_is_synthetic {
+    return Err(ResendEmailVerificationError::BadInput("account does not have an email address".to_string()));
+  }
+
+  if user_session.email_confirmed || user_session.email_confirmed_by_google {
+    return Err(ResendEmailVerificationError::BadInput("email address is already verified".to_string()));
+  }
+
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::EmailVerification,
+    maybe_user_token: Some(&user_session.user_token),
+    tier: RateLimitTier::Free,
+  }).await
+      .map_err(|_err| ResendEmailVerificationError::RateLimited)?;
+
+  let domain_branding = get_request_domain_branding(&http_request)
+      .unwrap_or(DomainBranding::GetArtCraft);
+
+  issue_email_verification(IssueEmailVerificationArgs {
+    user_token: &user_session.user_token,
+    email_address: &user_session.email_address,
+    domain_branding,
+    server_state: &server_state,
+  }).await.map_err(|err| {
+    warn!("Error sending email verification email: {:?}", err);
+    ResendEmailVerificationError::ServerError
+  })?;
+
+  Ok(Json(ResendEmailVerificationResponse { success: true }))
+}
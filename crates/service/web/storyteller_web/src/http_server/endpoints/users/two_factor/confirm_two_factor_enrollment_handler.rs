@@ -0,0 +1,152 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::sync::Arc;
+
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest};
+use chrono::Utc;
+use log::{info, warn};
+use sqlx::Acquire;
+
+use artcraft_api_defs::users::two_factor::{ConfirmTwoFactorEnrollmentRequest, ConfirmTwoFactorEnrollmentResponse};
+use mysql_queries::queries::users::user_two_factor::enable_user_two_factor_auth::enable_user_two_factor_auth;
+use mysql_queries::queries::users::user_two_factor::get_user_two_factor_auth::get_user_two_factor_auth;
+use two_factor::totp_authenticator::TotpAuthenticator;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::endpoints::users::two_factor::two_factor_endpoint_error::TwoFactorEndpointError;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
+use crate::http_server::session::two_factor::issue_recovery_codes::{generate_new_recovery_codes, replace_recovery_codes};
+use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::state::server_state::ServerState;
+
+/// Finish enrolling in two factor auth by proving the authenticator app is set up.
+///
+/// Returns the user's recovery codes. These are only ever shown once.
+#[utoipa::path(
+  post,
+  tag = "Users",
+  path = "/v1/user/two_factor/enroll/confirm",
+  responses(
+    (status = 200, description = "Success", body = ConfirmTwoFactorEnrollmentResponse),
+    (status = 400, description = "Bad input", body = TwoFactorEndpointError),
+    (status = 401, description = "Not authorized", body = TwoFactorEndpointError),
+    (status = 429, description = "Rate limited", body = TwoFactorEndpointError),
+    (status = 500, description = "Server error", body = TwoFactorEndpointError),
+  ),
+  params(
+    ("request" = ConfirmTwoFactorEnrollmentRequest, description = "Payload for Request"),
+  )
+)]
+pub async fn confirm_two_factor_enrollment_handler(
+  http_request: HttpRequest,
+  request: Json<ConfirmTwoFactorEnrollmentRequest>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<ConfirmTwoFactorEnrollmentResponse>, TwoFactorEndpointError>
+{
+  let mut mysql_connection = server_state.mysql_pool
+      .acquire()
+      .await
+      .map_err(|err| {
+        warn!("MySql pool error: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let user_session = require_user_session_using_connection(
+    &http_request,
+    &server_state.session_checker,
+    &mut mysql_connection)
+      .await
+      .map_err(|_err| {
+        TwoFactorEndpointError::NotAuthorized
+      })?;
+
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::TwoFactor,
+    maybe_user_token: Some(&user_session.user_token),
+    tier: RateLimitTier::Free,
+  }).await
+      .map_err(|_err| TwoFactorEndpointError::RateLimited)?;
+
+  let maybe_two_factor = get_user_two_factor_auth(&user_session.user_token, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error looking up two factor auth: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let pending = match maybe_two_factor {
+    None => return Err(TwoFactorEndpointError::BadInput("two factor enrollment was not started".to_string())),
+    Some(two_factor) if two_factor.is_enabled => {
+      return Err(TwoFactorEndpointError::BadInput("two factor authentication is already enabled".to_string()));
+    }
+    Some(two_factor) => two_factor,
+  };
+
+  let authenticator = TotpAuthenticator::new(&pending.totp_secret, "", "")
+      .map_err(|err| {
+        warn!("Error building TOTP authenticator: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let time_step = authenticator.verify_code(&request.code, Utc::now().timestamp() as u64)
+      .ok_or_else(|| TwoFactorEndpointError::BadInput("invalid code".to_string()))?;
+
+  let recovery_codes = generate_new_recovery_codes()
+      .await
+      .map_err(|err| {
+        warn!("Error generating recovery codes: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let mut transaction = mysql_connection
+      .begin()
+      .await
+      .map_err(|err| {
+        warn!("Error starting MySQL transaction: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let enabled = enable_user_two_factor_auth(
+    &user_session.user_token,
+    &pending.totp_secret,
+    time_step,
+    &mut *transaction,
+  ).await.map_err(|err| {
+    warn!("Error enabling two factor auth: {:?}", err);
+    TwoFactorEndpointError::ServerError
+  })?;
+
+  if !enabled {
+    // NB: Enrollment was restarted (new secret) or confirmed concurrently.
+    return Err(TwoFactorEndpointError::BadInput("two factor enrollment changed; please start again".to_string()));
+  }
+
+  replace_recovery_codes(&user_session.user_token, &recovery_codes, &mut transaction)
+      .await
+      .map_err(|err| {
+        warn!("Error saving recovery codes: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  transaction
+      .commit()
+      .await
+      .map_err(|err| {
+        warn!("Error committing MySQL transaction: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  info!("Two factor auth enabled for user {:?}", user_session.user_token);
+
+  Ok(Json(ConfirmTwoFactorEnrollmentResponse {
+    success: true,
+    recovery_codes: recovery_codes.codes,
+  }))
+}
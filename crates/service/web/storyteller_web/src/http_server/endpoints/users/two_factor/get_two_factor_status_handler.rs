@@ -0,0 +1,78 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::sync::Arc;
+
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest};
+use log::warn;
+
+use artcraft_api_defs::users::two_factor::GetTwoFactorStatusResponse;
+use mysql_queries::queries::users::user_two_factor::get_user_two_factor_auth::get_user_two_factor_auth;
+use mysql_queries::queries::users::user_two_factor::list_unused_user_two_factor_recovery_codes::list_unused_user_two_factor_recovery_codes;
+
+use crate::http_server::endpoints::users::two_factor::two_factor_endpoint_error::TwoFactorEndpointError;
+use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::state::server_state::ServerState;
+
+/// Whether the current user has two factor auth enabled.
+#[utoipa::path(
+  get,
+  tag = "Users",
+  path = "/v1/user/two_factor/status",
+  responses(
+    (status = 200, description = "Success", body = GetTwoFactorStatusResponse),
+    (status = 401, description = "Not authorized", body = TwoFactorEndpointError),
+    (status = 500, description = "Server error", body = TwoFactorEndpointError),
+  ),
+)]
+pub async fn get_two_factor_status_handler(
+  http_request: HttpRequest,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<GetTwoFactorStatusResponse>, TwoFactorEndpointError>
+{
+  let mut mysql_connection = server_state.mysql_pool
+      .acquire()
+      .await
+      .map_err(|err| {
+        warn!("MySql pool error: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let user_session = require_user_session_using_connection(
+    &http_request,
+    &server_state.session_checker,
+    &mut mysql_connection)
+      .await
+      .map_err(|_err| {
+        TwoFactorEndpointError::NotAuthorized
+      })?;
+
+  let is_enabled = get_user_two_factor_auth(&user_session.user_token, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error looking up two factor auth: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?
+      .is_some_and(|two_factor| two_factor.is_enabled);
+
+  let unused_recovery_code_count = if is_enabled {
+    list_unused_user_two_factor_recovery_codes(&user_session.user_token, &mut *mysql_connection)
+        .await
+        .map_err(|err| {
+          warn!("Error listing recovery codes: {:?}", err);
+          TwoFactorEndpointError::ServerError
+        })?
+        .len()
+  } else {
+    0
+  };
+
+  Ok(Json(GetTwoFactorStatusResponse {
+    success: true,
+    is_enabled,
+    unused_recovery_code_count,
+  }))
+}
@@ -0,0 +1,6 @@
+pub mod confirm_two_factor_enrollment_handler;
+pub mod disable_two_factor_handler;
+pub mod get_two_factor_status_handler;
+pub mod regenerate_two_factor_recovery_codes_handler;
+pub mod start_two_factor_enrollment_handler;
+pub mod two_factor_endpoint_error;
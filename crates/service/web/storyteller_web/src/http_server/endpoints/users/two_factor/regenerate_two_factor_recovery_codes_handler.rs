@@ -0,0 +1,132 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::sync::Arc;
+
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest};
+use log::{info, warn};
+use sqlx::Acquire;
+
+use artcraft_api_defs::users::two_factor::{RegenerateTwoFactorRecoveryCodesRequest, RegenerateTwoFactorRecoveryCodesResponse};
+use mysql_queries::queries::users::user_two_factor::get_user_two_factor_auth::get_user_two_factor_auth;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::endpoints::users::two_factor::two_factor_endpoint_error::TwoFactorEndpointError;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
+use crate::http_server::session::two_factor::issue_recovery_codes::{generate_new_recovery_codes, replace_recovery_codes};
+use crate::http_server::session::two_factor::verify_two_factor_code::verify_two_factor_code;
+use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::state::server_state::ServerState;
+
+/// Replace all of the user's recovery codes with a fresh set.
+#[utoipa::path(
+  post,
+  tag = "Users",
+  path = "/v1/user/two_factor/recovery_codes/regenerate",
+  responses(
+    (status = 200, description = "Success", body = RegenerateTwoFactorRecoveryCodesResponse),
+    (status = 400, description = "Bad input", body = TwoFactorEndpointError),
+    (status = 401, description = "Not authorized", body = TwoFactorEndpointError),
+    (status = 429, description = "Rate limited", body = TwoFactorEndpointError),
+    (status = 500, description = "Server error", body = TwoFactorEndpointError),
+  ),
+  params(
+    ("request" = RegenerateTwoFactorRecoveryCodesRequest, description = "Payload for Request"),
+  )
+)]
+pub async fn regenerate_two_factor_recovery_codes_handler(
+  http_request: HttpRequest,
+  request: Json<RegenerateTwoFactorRecoveryCodesRequest>,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<RegenerateTwoFactorRecoveryCodesResponse>, TwoFactorEndpointError>
+{
+  let mut mysql_connection = server_state.mysql_pool
+      .acquire()
+      .await
+      .map_err(|err| {
+        warn!("MySql pool error: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let user_session = require_user_session_using_connection(
+    &http_request,
+    &server_state.session_checker,
+    &mut mysql_connection)
+      .await
+      .map_err(|_err| {
+        TwoFactorEndpointError::NotAuthorized
+      })?;
+
+  enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+    http_request: &http_request,
+    server_state: &server_state,
+    endpoint_group: RateLimitEndpointGroup::TwoFactor,
+    maybe_user_token: Some(&user_session.user_token),
+    tier: RateLimitTier::Free,
+  }).await
+      .map_err(|_err| TwoFactorEndpointError::RateLimited)?;
+
+  let two_factor = get_user_two_factor_auth(&user_session.user_token, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error looking up two factor auth: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?
+      .filter(|two_factor| two_factor.is_enabled)
+      .ok_or_else(|| TwoFactorEndpointError::BadInput("two factor authentication is not enabled".to_string()))?;
+
+  let verified = verify_two_factor_code(
+    &user_session.user_token,
+    &two_factor.totp_secret,
+    &request.code,
+    &server_state.mysql_pool,
+  ).await.map_err(|err| {
+    warn!("Two factor verification error: {:?}", err);
+    TwoFactorEndpointError::ServerError
+  })?;
+
+  if !verified {
+    return Err(TwoFactorEndpointError::BadInput("invalid code".to_string()));
+  }
+
+  let recovery_codes = generate_new_recovery_codes()
+      .await
+      .map_err(|err| {
+        warn!("Error generating recovery codes: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let mut transaction = mysql_connection
+      .begin()
+      .await
+      .map_err(|err| {
+        warn!("Error starting MySQL transaction: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  replace_recovery_codes(&user_session.user_token, &recovery_codes, &mut transaction)
+      .await
+      .map_err(|err| {
+        warn!("Error saving recovery codes: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  transaction
+      .commit()
+      .await
+      .map_err(|err| {
+        warn!("Error committing MySQL transaction: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  info!("Recovery codes regenerated for user {:?}", user_session.user_token);
+
+  Ok(Json(RegenerateTwoFactorRecoveryCodesResponse {
+    success: true,
+    recovery_codes: recovery_codes.codes,
+  }))
+}
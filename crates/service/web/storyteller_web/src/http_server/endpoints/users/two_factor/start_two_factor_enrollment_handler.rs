@@ -0,0 +1,97 @@
+// NB: Incrementally getting rid of build warnings...
+#![forbid(unused_imports)]
+#![forbid(unused_mut)]
+#![forbid(unused_variables)]
+
+use std::sync::Arc;
+
+use actix_web::web::Json;
+use actix_web::{web, HttpRequest};
+use log::warn;
+
+use artcraft_api_defs::users::two_factor::StartTwoFactorEnrollmentResponse;
+use mysql_queries::queries::users::user_two_factor::get_user_two_factor_auth::get_user_two_factor_auth;
+use mysql_queries::queries::users::user_two_factor::upsert_pending_user_two_factor_auth::upsert_pending_user_two_factor_auth;
+use two_factor::totp_authenticator::TotpAuthenticator;
+
+use crate::http_server::endpoints::users::two_factor::two_factor_endpoint_error::TwoFactorEndpointError;
+use crate::http_server::requests::get_request_domain_branding::{get_request_domain_branding, DomainBranding};
+use crate::http_server::web_utils::user_session::require_user_session_using_connection::require_user_session_using_connection;
+use crate::state::server_state::ServerState;
+
+/// Begin enrolling in two factor auth. Returns a new TOTP secret for the user's
+/// authenticator app. Nothing changes for login until the enrollment is confirmed.
+///
+/// Calling this again before confirming replaces the pending secret.
+#[utoipa::path(
+  post,
+  tag = "Users",
+  path = "/v1/user/two_factor/enroll/start",
+  responses(
+    (status = 200, description = "Success", body = StartTwoFactorEnrollmentResponse),
+    (status = 400, description = "Bad input", body = TwoFactorEndpointError),
+    (status = 401, description = "Not authorized", body = TwoFactorEndpointError),
+    (status = 500, description = "Server error", body = TwoFactorEndpointError),
+  ),
+)]
+pub async fn start_two_factor_enrollment_handler(
+  http_request: HttpRequest,
+  server_state: web::Data<Arc<ServerState>>,
+) -> Result<Json<StartTwoFactorEnrollmentResponse>, TwoFactorEndpointError>
+{
+  let mut mysql_connection = server_state.mysql_pool
+      .acquire()
+      .await
+      .map_err(|err| {
+        warn!("MySql pool error: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  let user_session = require_user_session_using_connection(
+    &http_request,
+    &server_state.session_checker,
+    &mut mysql_connection)
+      .await
+      .map_err(|_err| {
+        TwoFactorEndpointError::NotAuthorized
+      })?;
+
+  let maybe_two_factor = get_user_two_factor_auth(&user_session.user_token, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error looking up two factor auth: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  if maybe_two_factor.is_some_and(|two_factor| two_factor.is_enabled) {
+    return Err(TwoFactorEndpointError::BadInput("two factor authentication is already enabled".to_string()));
+  }
+
+  let secret = TotpAuthenticator::generate_secret_base32();
+
+  // NB: This labels the entry in the user's authenticator app.
+  let issuer = match get_request_domain_branding(&http_request) {
+    Some(DomainBranding::FakeYou) => "FakeYou",
+    Some(DomainBranding::Storyteller) => "Storyteller.ai",
+    _ => "ArtCraft",
+  };
+
+  let authenticator = TotpAuthenticator::new(&secret, issuer, &user_session.username)
+      .map_err(|err| {
+        warn!("Error building TOTP authenticator: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  upsert_pending_user_two_factor_auth(&user_session.user_token, &secret, &mut *mysql_connection)
+      .await
+      .map_err(|err| {
+        warn!("Error saving pending two factor auth: {:?}", err);
+        TwoFactorEndpointError::ServerError
+      })?;
+
+  Ok(Json(StartTwoFactorEnrollmentResponse {
+    success: true,
+    provisioning_uri: authenticator.provisioning_uri(),
+    secret,
+  }))
+}
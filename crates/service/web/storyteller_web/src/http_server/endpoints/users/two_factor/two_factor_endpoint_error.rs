@@ -0,0 +1,46 @@
+use std::fmt;
+
+use actix_web::error::ResponseError;
+use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
+use utoipa::ToSchema;
+
+use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
+
+/// Shared by the two factor settings endpoints.
+#[derive(Debug, ToSchema)]
+pub enum TwoFactorEndpointError {
+  BadInput(String),
+  NotAuthorized,
+  RateLimited,
+  ServerError,
+}
+
+impl ResponseError for TwoFactorEndpointError {
+  fn status_code(&self) -> StatusCode {
+    match *self {
+      TwoFactorEndpointError::BadInput(_) => StatusCode::BAD_REQUEST,
+      TwoFactorEndpointError::NotAuthorized => StatusCode::UNAUTHORIZED,
+      TwoFactorEndpointError::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+      TwoFactorEndpointError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    let error_reason = match self {
+      TwoFactorEndpointError::BadInput(reason) => reason.to_string(),
+      TwoFactorEndpointError::NotAuthorized => "unauthorized".to_string(),
+      TwoFactorEndpointError::RateLimited => "rate limited".to_string(),
+      TwoFactorEndpointError::ServerError => "server error".to_string(),
+    };
+
+    to_simple_json_error(&error_reason, self.status_code())
+  }
+}
+
+// NB: Not using derive_more::Display since Clion doesn't understand it.
+impl fmt::Display for TwoFactorEndpointError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
@@ -17,6 +17,7 @@ use enums::by_table::generic_inference_jobs::inference_category::InferenceCatego
 use enums::by_table::generic_inference_jobs::inference_input_source_token_type::InferenceInputSourceTokenType;
 use enums::by_table::generic_inference_jobs::inference_job_product_category::InferenceJobProductCategory;
 use enums::by_table::generic_inference_jobs::inference_job_type::InferenceJobType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_api_token::get_request_api_token;
 use http_server_common::request::get_request_header_optional::get_request_header_optional;
@@ -31,7 +32,9 @@ use tts_common::priority::{FAKEYOU_DEFAULT_VALID_API_TOKEN_PRIORITY_LEVEL, FAKEY
 
 use crate::configs::app_startup::username_set::UsernameSet;
 use crate::configs::plans::get_correct_plan_for_session::get_correct_plan_for_session;
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
 use crate::http_server::deprecated_endpoints::investor_demo::demo_cookie::request_has_demo_cookie;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::session::lookup::user_session_extended::UserSessionExtended;
 use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
 use crate::state::server_state::ServerState;
@@ -229,28 +232,33 @@ pub async fn enqueue_infer_seed_vc_handler(
     // FIXME(bt,2023-12-13): These boolean flags are a bit dangerous.
     let rate_limiter_type = get_rate_limiter_type(
       maybe_username,
-      &server_state.redis_rate_limiters.api_ai_streamer_username_set,
+      &server_state.ai_streamer_usernames,
       is_investor,
       is_from_api,
       use_high_priority_rate_limiter
     );
 
-    let rate_limiter = match rate_limiter_type {
-      RateLimiterType::LoggedOut => &server_state.redis_rate_limiters.logged_out,
-      RateLimiterType::LoggedIn => &server_state.redis_rate_limiters.logged_in,
+    let tier = match rate_limiter_type {
+      RateLimiterType::LoggedOut => RateLimitTier::LoggedOut,
+      RateLimiterType::LoggedIn => RateLimitTier::Free,
       RateLimiterType::ApiHighPriority => {
         info!("Using API high priority rate limiter");
-        &server_state.redis_rate_limiters.api_high_priority
+        RateLimitTier::Pro
       },
       RateLimiterType::ApiAiStreamer => {
         info!("Using AI streamer rate limiter");
-        &server_state.redis_rate_limiters.api_ai_streamers
+        RateLimitTier::Elite
       },
     };
 
-    if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-      return Err(InferSeedVcError::RateLimited);
-    }
+    enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+      http_request: &http_request,
+      server_state: &server_state,
+      endpoint_group: RateLimitEndpointGroup::Generation,
+      maybe_user_token: None,
+      tier,
+    }).await
+        .map_err(|_err| InferSeedVcError::RateLimited)?;
   }
 
 
@@ -21,6 +21,7 @@ use enums::by_table::generic_inference_jobs::inference_input_source_token_type::
 use enums::by_table::generic_inference_jobs::inference_job_product_category::InferenceJobProductCategory;
 use enums::by_table::generic_inference_jobs::inference_job_type::InferenceJobType;
 use enums::by_table::generic_inference_jobs::inference_model_type::InferenceModelType;
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
 use enums::common::visibility::Visibility;
 use http_server_common::request::get_request_header_optional::get_request_header_optional;
 use http_server_common::request::get_request_ip::get_request_ip;
@@ -35,7 +36,9 @@ use tokens::tokens::users::UserToken;
 use tts_common::priority::FAKEYOU_INVESTOR_PRIORITY_LEVEL;
 
 use crate::configs::plans::get_correct_plan_for_session::get_correct_plan_for_session;
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
 use crate::http_server::deprecated_endpoints::investor_demo::demo_cookie::request_has_demo_cookie;
+use crate::http_server::rate_limiting::enforce_rate_limit::{enforce_basic_rate_limit, EnforceBasicRateLimitArgs};
 use crate::http_server::web_utils::response_error_helpers::to_simple_json_error;
 use crate::state::memory_cache::model_token_to_info_cache::{ModelInfoForInferenceJob, ModelTokenToInfoCache};
 use crate::state::server_state::ServerState;
@@ -246,19 +249,15 @@ pub async fn enqueue_voice_conversion_inference_handler(
   // ==================== RATE LIMIT ==================== //
 
   if !disable_rate_limiter {
-    let mut rate_limiter = match maybe_user_session {
-      None => &server_state.redis_rate_limiters.logged_out,
-      Some(ref _session) => &server_state.redis_rate_limiters.logged_in
-    };
-
-    // TODO/TEMP
-    if is_investor {
-      rate_limiter = &server_state.redis_rate_limiters.logged_in;
-    }
-
-    if let Err(_err) = rate_limiter.rate_limit_request(&http_request).await {
-      return Err(EnqueueVoiceConversionInferenceError::RateLimited);
-    }
+    // TODO/TEMP: Investors get the logged in limits.
+    enforce_basic_rate_limit(EnforceBasicRateLimitArgs {
+      http_request: &http_request,
+      server_state: &server_state,
+      endpoint_group: RateLimitEndpointGroup::Generation,
+      maybe_user_token: None,
+      tier: RateLimitTier::for_logged_in(maybe_user_session.is_some() || is_investor),
+    }).await
+        .map_err(|_err| EnqueueVoiceConversionInferenceError::RateLimited)?;
   }
 
   // ==================== LOOK UP MODEL INFO ==================== //
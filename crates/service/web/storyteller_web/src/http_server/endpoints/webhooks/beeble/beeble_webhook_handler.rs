@@ -1,4 +1,3 @@
-use std::io::Write;
 use std::sync::Arc;
 
 use actix_web::web::Bytes;
@@ -7,7 +6,6 @@ use actix_web::{web, HttpRequest};
 use log::{error, info, warn};
 use sqlx::pool::PoolConnection;
 use sqlx::MySql;
-use tempfile::NamedTempFile;
 
 use beeble_client::webhook_api::beeble_webhook_payload::{
   parse_beeble_webhook, BeebleWebhookPayload, BeebleWebhookStatus,
@@ -19,10 +17,11 @@ use enums::by_table::media_files::media_file_class::MediaFileClass;
 use enums::by_table::media_files::media_file_origin_category::MediaFileOriginCategory;
 use enums::by_table::media_files::media_file_origin_product_category::MediaFileOriginProductCategory;
 use enums::by_table::media_files::media_file_type::MediaFileType;
-use ffmpeg_utils::ffprobe::ffprobe_get_info::ffprobe_get_info;
+use enums::by_table::webhook_deliveries::webhook_delivery_provider::WebhookDeliveryProvider;
 use filesys::path_to_string::path_to_string;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
 use http_server_common::response::response_success_helpers::SimpleGenericJsonSuccess;
+use media::probe::probe_media::probe_media_bytes;
 use mysql_queries::queries::debug_logs::insert_debug_log::{insert_debug_log, InsertDebugLogArgs};
 use mysql_queries::queries::generic_inference::beeble::get_inference_job_by_beeble_id::{
   get_inference_job_by_beeble_id_from_connection, BeebleJobDetails,
@@ -41,6 +40,7 @@ use tokens::tokens::media_files::MediaFileToken;
 
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
 use crate::http_server::endpoints::webhooks::fal::process_success::resolve_file_metadata::resolve_file_metadata;
+use crate::http_server::endpoints::webhooks::webhook_delivery_ledger::{claim_webhook_delivery, record_webhook_delivery_outcome, WebhookDeliveryClaim};
 use crate::state::server_state::ServerState;
 use crate::util::http_download_url_to_bytes::http_download_url_to_bytes;
 
@@ -76,10 +76,72 @@ pub async fn beeble_webhook_handler(
 
   info!("Beeble webhook job_id: {} (status: {:?})", job_id, payload.status);
 
-  // Step 3: Acquire a MySQL connection.
+  // Step 3: Record the delivery. Repeated deliveries are acknowledged but not reprocessed.
+  let delivery_token = match claim_webhook_delivery(
+    &server_state,
+    WebhookDeliveryProvider::Beeble,
+    job_id,
+    &raw_body,
+  ).await? {
+    WebhookDeliveryClaim::Process(token) => token,
+    WebhookDeliveryClaim::Duplicate => return Ok(SimpleGenericJsonSuccess::wrapped(true)),
+  };
+
+  // Steps 4 & 5: Look up the job and branch on status.
+  let result = process_beeble_webhook_payload(&server_state, &payload, &raw_body).await;
+
+  record_webhook_delivery_outcome(&server_state, &delivery_token, &result).await;
+
+  if let Err(ref err) = result {
+    if err.is_server_error() {
+      error!("Beeble webhook error for job_id {}: {:?}", job_id, err);
+
+      let description = format!(
+        "Beeble webhook failed for job_id: {}\n\nError: {:?}\n\nWebhook delivery (can be re-driven): {}\n\nWebhook JSON Payload: {}",
+        job_id, err, delivery_token, raw_body,
+      );
+
+      let mut builder = if let Some(cause_arc) = err.clone_cause_arc() {
+        NotificationDetailsBuilder::from_error(cause_arc)
+            .set_title(format!("Beeble webhook processing failed (job_id: {})", job_id))
+      } else {
+        NotificationDetailsBuilder::from_title(
+          format!("Beeble webhook processing failed (job_id: {})", job_id)
+        )
+      };
+
+      builder = builder
+          .set_description(Some(description))
+          .set_third_party_id(Some(job_id.to_string()))
+          .set_urgency(Some(NotificationUrgency::High))
+          .set_http_method(Some(http_request.method().to_string()))
+          .set_http_path(Some(http_request.path().to_string()));
+
+      if let Err(pager_err) = server_state.pager.enqueue_page(builder.build()) {
+        error!("Failed to enqueue Beeble webhook pager alert: {:?}", pager_err);
+      }
+    }
+  }
+
+  result
+}
+
+// =============== Processing ===============
+
+/// Look up the job for a parsed Beeble webhook and mark it completed or failed.
+///
+/// Used by the webhook endpoint and by the moderation endpoint that re-drives stored deliveries.
+pub(crate) async fn process_beeble_webhook_payload(
+  server_state: &ServerState,
+  payload: &BeebleWebhookPayload,
+  raw_body: &str,
+) -> Result<Json<SimpleGenericJsonSuccess>, AdvancedCommonWebError> {
+
+  let job_id = payload.id.as_str();
+
   let mut mysql_connection = server_state.mysql_pool.acquire().await?;
 
-  // Step 4: Look up the job by external third-party ID.
+  // Look up the job by external third-party ID.
   let job = match get_inference_job_by_beeble_id_from_connection(job_id, &mut mysql_connection).await {
     Ok(Some(record)) => record,
     Ok(None) => {
@@ -100,7 +162,7 @@ pub async fn beeble_webhook_handler(
       apriori_debug_log_event_token: Some(debug_log_event_token),
       maybe_creator_user_token: job.maybe_creator_user_token.as_ref(),
       debug_log_type: DebugLogType::BeebleWebhook,
-      message: &raw_body,
+      message: raw_body,
       mysql_executor: &mut *mysql_connection,
       phantom: Default::default(),
     }).await {
@@ -108,13 +170,13 @@ pub async fn beeble_webhook_handler(
     }
   }
 
-  // Step 5: Branch on status.
-  let result = match payload.status {
+  // Branch on status.
+  match payload.status {
     BeebleWebhookStatus::Completed => {
-      handle_completed(&server_state, &mut mysql_connection, job_id, &payload, &job).await
+      handle_completed(server_state, &mut mysql_connection, job_id, payload, &job).await
     }
     BeebleWebhookStatus::Failed => {
-      handle_failed(&mut mysql_connection, job_id, &payload, &job).await
+      handle_failed(&mut mysql_connection, job_id, payload, &job).await
     }
     BeebleWebhookStatus::Unknown => {
       warn!("Beeble webhook received unknown status for job_id {}", job_id);
@@ -122,40 +184,7 @@ pub async fn beeble_webhook_handler(
         &format!("Beeble webhook received unknown status for job_id {}", job_id),
       ))
     }
-  };
-
-  if let Err(ref err) = result {
-    if err.is_server_error() {
-      error!("Beeble webhook error for job_id {}: {:?}", job_id, err);
-
-      let description = format!(
-        "Beeble webhook failed for job_id: {}\n\nError: {:?}\n\nWebhook JSON Payload: {}",
-        job_id, err, raw_body,
-      );
-
-      let mut builder = if let Some(cause_arc) = err.clone_cause_arc() {
-        NotificationDetailsBuilder::from_error(cause_arc)
-            .set_title(format!("Beeble webhook processing failed (job_id: {})", job_id))
-      } else {
-        NotificationDetailsBuilder::from_title(
-          format!("Beeble webhook processing failed (job_id: {})", job_id)
-        )
-      };
-
-      builder = builder
-          .set_description(Some(description))
-          .set_third_party_id(Some(job_id.to_string()))
-          .set_urgency(Some(NotificationUrgency::High))
-          .set_http_method(Some(http_request.method().to_string()))
-          .set_http_path(Some(http_request.path().to_string()));
-
-      if let Err(pager_err) = server_state.pager.enqueue_page(builder.build()) {
-        error!("Failed to enqueue Beeble webhook pager alert: {:?}", pager_err);
-      }
-    }
   }
-
-  result
 }
 
 // =============== Private helpers ===============
@@ -327,21 +356,17 @@ async fn download_and_upload_video(
   let mut maybe_frame_width = None;
   let mut maybe_frame_height = None;
 
-  if let Ok(mut file) = NamedTempFile::new() {
-    let _r = file.write_all(&file_bytes);
+  match probe_media_bytes(&file_bytes) {
+    Ok(probe) => {
+      maybe_duration_millis = probe.maybe_duration_millis;
 
-    if let Ok(video_info) = ffprobe_get_info(&file.path()) {
-      maybe_duration_millis = video_info.duration
-          .as_ref()
-          .map(|duration| duration.millis as u64);
-
-      maybe_frame_width = video_info.dimensions
-          .as_ref()
-          .map(|dims| dims.width as u32);
-
-      maybe_frame_height = video_info.dimensions
-          .as_ref()
-          .map(|dims| dims.height as u32);
+      if let Some((width, height)) = probe.maybe_display_dimensions() {
+        maybe_frame_width = Some(width);
+        maybe_frame_height = Some(height);
+      }
+    }
+    Err(err) => {
+      warn!("Failed to probe video: {:?}", err);
     }
   }
 
@@ -0,0 +1,84 @@
+use log::error;
+use r2d2::Pool;
+use redis::Client;
+
+type BoxedError = Box<dyn std::error::Error + Send + Sync>;
+
+const REDIS_KEY: &str = "fal_webhook_authentication_alerts";
+
+/// After the first page in a window, page again every this many failures.
+const PAGE_EVERY_N_FAILURES: u64 = 100;
+
+/// Throttles pages for webhooks that fail authentication, so a flood of forged
+/// deliveries (or a FAL key rotation) can't flood the pager.
+///
+/// Failures are counted in a fixed window shared by every server. The first failure
+/// in a window pages; after that, only every `PAGE_EVERY_N_FAILURES`th does, with the
+/// running count. The rest are only logged.
+#[derive(Clone)]
+pub struct FalWebhookAlertThrottle {
+  redis_pool: Pool<Client>,
+  window_seconds: u32,
+}
+
+impl FalWebhookAlertThrottle {
+  pub fn new(redis_pool: Pool<Client>, window_seconds: u32) -> Self {
+    Self { redis_pool, window_seconds }
+  }
+
+  pub fn window_seconds(&self) -> u32 {
+    self.window_seconds
+  }
+
+  /// Count a failure. Returns the number of failures so far in the window if this one should page.
+  pub async fn record_failure(&self) -> Option<u64> {
+    // NB: The pool and client are blocking, so keep them off the async executor.
+    let redis_pool = self.redis_pool.clone();
+    let window_seconds = self.window_seconds;
+
+    let result : Result<u64, BoxedError> =
+        tokio::task::spawn_blocking(move || increment_blocking(&redis_pool, window_seconds))
+            .await
+            .unwrap_or_else(|err| Err(err.into()));
+
+    match result {
+      Ok(count) => Some(count).filter(|count| should_page(*count)),
+      Err(err) => {
+        error!("Could not count FAL webhook authentication failure (not paging): {}", err);
+        None
+      }
+    }
+  }
+}
+
+fn should_page(failures_in_window: u64) -> bool {
+  failures_in_window == 1 || failures_in_window % PAGE_EVERY_N_FAILURES == 0
+}
+
+fn increment_blocking(redis_pool: &Pool<Client>, window_seconds: u32) -> Result<u64, BoxedError> {
+  let mut redis = redis_pool.get()?;
+
+  // NB: `SET NX EX` starts the window (and its expiry) only if the key doesn't exist.
+  let (count,) : (u64,) = redis::pipe()
+      .atomic()
+      .cmd("SET").arg(REDIS_KEY).arg(0).arg("NX").arg("EX").arg(window_seconds).ignore()
+      .cmd("INCR").arg(REDIS_KEY)
+      .query(&mut *redis)?;
+
+  Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn pages_first_failure_then_every_hundredth() {
+    assert!(should_page(1));
+    assert!(!should_page(2));
+    assert!(!should_page(99));
+    assert!(should_page(100));
+    assert!(!should_page(101));
+    assert!(should_page(200));
+  }
+}
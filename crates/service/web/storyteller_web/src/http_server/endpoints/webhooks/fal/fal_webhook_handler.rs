@@ -1,15 +1,16 @@
 use std::sync::Arc;
 
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
-use crate::http_server::endpoints::webhooks::fal::process_failure::handle_failed_fal_webhook::handle_failed_fal_webhook;
-use crate::http_server::endpoints::webhooks::fal::process_success::handle_successful_fal_webhook::handle_successful_fal_webhook;
+use crate::http_server::endpoints::webhooks::fal::process_fal_webhook_payload::process_fal_webhook_payload;
+use crate::http_server::endpoints::webhooks::webhook_delivery_ledger::{claim_webhook_delivery, record_webhook_delivery_outcome, WebhookDeliveryClaim};
 use crate::state::server_state::ServerState;
 use actix_web::web::Bytes;
 use actix_web::web::Json;
 use actix_web::{web, HttpRequest};
-use fal_client::webhook_payload::hydrate_webhook_contents::hydrate_webhook_contents;
-use fal_client::webhook_payload::hydrated::hydrated_webhook_contents::HydratedWebhookContents;
+use enums::by_table::webhook_deliveries::webhook_delivery_provider::WebhookDeliveryProvider;
 use fal_client::webhook_payload::parse_raw_webhook_payload::parse_raw_webhook_payload;
+use fal_client::webhook_payload::signature::fal_webhook_headers::FalWebhookHeaders;
+use fal_client::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
 use http_server_common::response::response_success_helpers::SimpleGenericJsonSuccess;
 use log::{error, info, warn};
 use pager::notification::notification_details_builder::NotificationDetailsBuilder;
@@ -17,13 +18,15 @@ use pager::notification::notification_urgency::NotificationUrgency;
 
 // =============== Handler ===============
 
-// TODO(bt, 2025-06-03): Handle webhook crypto authentication
 pub async fn fal_webhook_handler(
   http_request: HttpRequest,
   request_body_bytes: Bytes,
   server_state: web::Data<Arc<ServerState>>,
 ) -> Result<Json<SimpleGenericJsonSuccess>, AdvancedCommonWebError> {
 
+  // Step 0: Authenticate the delivery (ED25519 signature, timestamp, replay).
+  let maybe_signed_request_id = authenticate_webhook(&server_state, &http_request, &request_body_bytes).await?;
+
   // Step 1: Parse bytes into a UTF-8 string and log it.
   let raw_body = String::from_utf8(request_body_bytes.to_vec())
       .map_err(|err| {
@@ -48,47 +51,40 @@ pub async fn fal_webhook_handler(
 
   let request_id = webhook_payload.request_id.as_str();
 
+  // The signature covers the body, but make sure the body is about the request FAL signed for.
+  if let Some(signed_request_id) = maybe_signed_request_id.as_deref() {
+    if signed_request_id != webhook_payload.request_id && signed_request_id != webhook_payload.gateway_request_id {
+      warn!("FAL webhook: signed request id {} does not match body request_id {}", signed_request_id, request_id);
+      enqueue_authentication_alert(&server_state, &http_request, "Signed request id does not match payload", Some(&raw_body)).await;
+      return Err(AdvancedCommonWebError::NotAuthorized);
+    }
+  }
+
   info!("FAL webhook request_id: {} (status: {:?})", request_id, webhook_payload.status);
 
-  // Step 3: Hydrate the webhook contents.
-  let hydrated_contents = hydrate_webhook_contents(&webhook_payload);
+  // Step 3: Record the delivery. Repeated deliveries are acknowledged but not reprocessed.
+  let delivery_token = match claim_webhook_delivery(
+    &server_state,
+    WebhookDeliveryProvider::Fal,
+    request_id,
+    &raw_body,
+  ).await? {
+    WebhookDeliveryClaim::Process(token) => token,
+    WebhookDeliveryClaim::Duplicate => return Ok(SimpleGenericJsonSuccess::wrapped(true)),
+  };
 
-  // Step 3.5: Acquire a MySQL connection for the handlers.
-  let mut mysql_connection = server_state.mysql_pool.acquire().await?;
+  // Step 4 & 5: Hydrate and branch on the inner payload type.
+  let result = process_fal_webhook_payload(&server_state, &webhook_payload, &raw_body).await;
 
-  // Step 4 & 5: Branch on the inner payload type.
-  let result = match hydrated_contents {
-    HydratedWebhookContents::Success(success_data) => {
-      handle_successful_fal_webhook(&server_state, &mut mysql_connection, request_id, &success_data, &raw_body, &server_state.pager).await
-    }
-    HydratedWebhookContents::Error(error_data) => {
-      handle_failed_fal_webhook(
-        &server_state,
-        &mut mysql_connection,
-        request_id,
-        &error_data,
-        webhook_payload.error.as_deref(),
-        &raw_body,
-      ).await
-    }
-    HydratedWebhookContents::PayloadError(payload_error_data) => {
-      warn!(
-        "FAL webhook payload_error for request_id {}: {}",
-        request_id, payload_error_data.payload_error,
-      );
-      Err(AdvancedCommonWebError::from_anyhow_error(
-        anyhow::anyhow!("FAL payload_error: {}", payload_error_data.payload_error)
-      ))
-    }
-  };
+  record_webhook_delivery_outcome(&server_state, &delivery_token, &result).await;
 
   if let Err(ref err) = result {
     if err.is_server_error() {
       error!("FAL webhook error for request_id {}: {:?}", request_id, err);
 
       let description = format!(
-        "FAL webhook failed for request_id: {}\n\nError: {:?}\n\nWebhook JSON Payload: {}",
-        request_id, err, raw_body,
+        "FAL webhook failed for request_id: {}\n\nError: {:?}\n\nWebhook delivery (can be re-driven): {}\n\nWebhook JSON Payload: {}",
+        request_id, err, delivery_token, raw_body,
       );
 
       let mut builder = if let Some(cause_arc) = err.clone_cause_arc() {
@@ -118,6 +114,89 @@ pub async fn fal_webhook_handler(
 
 // =============== Private helpers ===============
 
+/// Verify the delivery came from FAL. Returns the signed request id.
+/// If enforcement is disabled, failures are paged but the delivery is processed anyway (returns None).
+async fn authenticate_webhook(
+  server_state: &ServerState,
+  http_request: &HttpRequest,
+  request_body_bytes: &Bytes,
+) -> Result<Option<String>, AdvancedCommonWebError> {
+  let result = match FalWebhookHeaders::from_lookup(|name| header_value(http_request, name)) {
+    Ok(headers) => server_state.fal.webhook_verifier.verify(&headers, request_body_bytes)
+        .await
+        .map(|_| headers.request_id),
+    Err(err) => Err(err),
+  };
+
+  let err = match result {
+    Ok(request_id) => return Ok(Some(request_id)),
+    Err(err) => err,
+  };
+
+  let body = String::from_utf8_lossy(request_body_bytes);
+
+  if !server_state.fal.webhook_verification_enforced {
+    warn!("FAL webhook failed authentication (not enforced): {}", err);
+    enqueue_authentication_alert(server_state, http_request, &format!("{} (not enforced)", err), Some(&body)).await;
+    return Ok(None);
+  }
+
+  error!("FAL webhook failed authentication: {}", err);
+  enqueue_authentication_alert(server_state, http_request, &err.to_string(), Some(&body)).await;
+
+  match err {
+    // Our problem, not the sender's. Let FAL retry the delivery later.
+    FalWebhookSignatureError::JwksFetch(_)
+    | FalWebhookSignatureError::NoKeys
+    | FalWebhookSignatureError::ReplayGuard(_) => {
+      Err(AdvancedCommonWebError::from_error(err))
+    }
+    _ => Err(AdvancedCommonWebError::NotAuthorized),
+  }
+}
+
+fn header_value(http_request: &HttpRequest, name: &str) -> Option<String> {
+  http_request.headers()
+      .get(name)
+      .and_then(|value| value.to_str().ok())
+      .map(|value| value.to_string())
+}
+
+/// Send a pager alert for a delivery that could not be authenticated (possibly forged).
+/// Throttled, since anyone can send us these; the rest are only logged.
+async fn enqueue_authentication_alert(
+  server_state: &ServerState,
+  http_request: &HttpRequest,
+  reason: &str,
+  maybe_raw_body: Option<&str>,
+) {
+  let throttle = &server_state.fal.webhook_alert_throttle;
+
+  let failures_in_window = match throttle.record_failure().await {
+    Some(count) => count,
+    None => return,
+  };
+
+  let description = match maybe_raw_body {
+    Some(body) => format!("Reason: {}\n\nFailures in the last {}s: {}\n\nWebhook JSON Payload: {}",
+      reason, throttle.window_seconds(), failures_in_window, body),
+    None => format!("Reason: {}\n\nFailures in the last {}s: {}",
+      reason, throttle.window_seconds(), failures_in_window),
+  };
+
+  let notification = NotificationDetailsBuilder::from_title(
+        "FAL webhook failed authentication".to_string())
+      .set_description(Some(description))
+      .set_urgency(Some(NotificationUrgency::High))
+      .set_http_method(Some(http_request.method().to_string()))
+      .set_http_path(Some(http_request.path().to_string()))
+      .build();
+
+  if let Err(pager_err) = server_state.pager.enqueue_page(notification) {
+    error!("Failed to enqueue FAL webhook authentication alert: {:?}", pager_err);
+  }
+}
+
 /// Send a pager alert for early parse failures (before we have a request_id).
 fn enqueue_parse_error_alert<E: std::fmt::Debug>(
   server_state: &ServerState,
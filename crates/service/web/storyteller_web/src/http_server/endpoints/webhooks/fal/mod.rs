@@ -1,3 +1,6 @@
+pub mod fal_webhook_alert_throttle;
 pub mod fal_webhook_handler;
+pub(crate) mod process_fal_webhook_payload;
 pub(super) mod process_failure;
 pub(super) mod process_success;
+pub mod redis_webhook_replay_guard;
@@ -1,4 +1,5 @@
 use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::endpoint_helpers::refund_wallet_after_api_failure::refund_wallet_after_api_failure;
 use crate::state::server_state::ServerState;
 use actix_web::web::Json;
 use enums::by_table::debug_logs::debug_log_type::DebugLogType;
@@ -16,6 +17,8 @@ use sqlx::MySql;
 /// Handle a FAL webhook with status ERROR.
 ///
 /// Looks up the job by request_id and marks it as failed using the parsed error data.
+/// Any wallet charge for the job is refunded; this is also how a pending cancellation
+/// (requested through the FAL queue API) is settled once FAL confirms it.
 pub async fn handle_failed_fal_webhook(
   server_state: &ServerState,
   mysql_connection: &mut PoolConnection<MySql>,
@@ -109,6 +112,18 @@ pub async fn handle_failed_fal_webhook(
     request_id,
   );
 
+  // Refunds are idempotent, so a redelivered webhook won't refund twice.
+  if let Some(ledger_entry_token) = &job.maybe_wallet_ledger_entry_token {
+    if let Err(err) = refund_wallet_after_api_failure(ledger_entry_token, mysql_connection).await {
+      error!(
+        "Error refunding job {} for failed request_id {}: {:?}",
+        job.job_token.as_str(),
+        request_id,
+        err,
+      );
+    }
+  }
+
   Ok(SimpleGenericJsonSuccess::wrapped(true))
 }
 
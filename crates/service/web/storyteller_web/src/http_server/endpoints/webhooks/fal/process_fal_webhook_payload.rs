@@ -0,0 +1,52 @@
+use actix_web::web::Json;
+use fal_client::webhook_payload::hydrate_webhook_contents::hydrate_webhook_contents;
+use fal_client::webhook_payload::hydrated::hydrated_webhook_contents::HydratedWebhookContents;
+use fal_client::webhook_payload::raw::raw_webhook_payload::RawWebhookPayload;
+use http_server_common::response::response_success_helpers::SimpleGenericJsonSuccess;
+use log::warn;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::http_server::endpoints::webhooks::fal::process_failure::handle_failed_fal_webhook::handle_failed_fal_webhook;
+use crate::http_server::endpoints::webhooks::fal::process_success::handle_successful_fal_webhook::handle_successful_fal_webhook;
+use crate::state::server_state::ServerState;
+
+/// Hydrate a parsed FAL webhook and hand it to the success or failure handler.
+///
+/// Used by the webhook endpoint and by the moderation endpoint that re-drives stored deliveries.
+pub(crate) async fn process_fal_webhook_payload(
+  server_state: &ServerState,
+  webhook_payload: &RawWebhookPayload,
+  raw_body: &str,
+) -> Result<Json<SimpleGenericJsonSuccess>, AdvancedCommonWebError> {
+
+  let request_id = webhook_payload.request_id.as_str();
+
+  let hydrated_contents = hydrate_webhook_contents(webhook_payload);
+
+  let mut mysql_connection = server_state.mysql_pool.acquire().await?;
+
+  match hydrated_contents {
+    HydratedWebhookContents::Success(success_data) => {
+      handle_successful_fal_webhook(server_state, &mut mysql_connection, request_id, &success_data, raw_body, &server_state.pager).await
+    }
+    HydratedWebhookContents::Error(error_data) => {
+      handle_failed_fal_webhook(
+        server_state,
+        &mut mysql_connection,
+        request_id,
+        &error_data,
+        webhook_payload.error.as_deref(),
+        raw_body,
+      ).await
+    }
+    HydratedWebhookContents::PayloadError(payload_error_data) => {
+      warn!(
+        "FAL webhook payload_error for request_id {}: {}",
+        request_id, payload_error_data.payload_error,
+      );
+      Err(AdvancedCommonWebError::from_anyhow_error(
+        anyhow::anyhow!("FAL payload_error: {}", payload_error_data.payload_error)
+      ))
+    }
+  }
+}
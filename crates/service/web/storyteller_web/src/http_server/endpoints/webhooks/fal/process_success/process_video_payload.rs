@@ -10,13 +10,11 @@ use fal_client::webhook_payload::hydrated::hydrated_webhook_contents::VideoData;
 use filesys::path_to_string::path_to_string;
 use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
 use log::{error, info, warn};
+use media::probe::probe_media::probe_media_bytes;
 use mysql_queries::queries::generic_inference::fal::get_inference_job_by_fal_id::FalJobDetails;
 use mysql_queries::queries::media_files::create::insert_builder::media_file_insert_builder::MediaFileInsertBuilder;
-use std::io::Write;
-use tempfile::NamedTempFile;
 use thumbnail_generator::task_client::thumbnail_task::{ThumbnailTaskBuilder, ThumbnailTaskInputMimeType};
 use tokens::tokens::media_files::MediaFileToken;
-use ffmpeg_utils::ffprobe::ffprobe_get_info::ffprobe_get_info;
 
 const PREFIX : Option<&str> = Some("artcraft_");
 
@@ -92,22 +90,17 @@ pub async fn process_video_payload(
   let mut maybe_frame_width = None;
   let mut maybe_frame_height = None;
 
-  if let Ok(mut file) = NamedTempFile::new() {
-    // NB: Fallible
-    let _r = file.write_all(&file_bytes);
+  match probe_media_bytes(&file_bytes) {
+    Ok(probe) => {
+      maybe_duration_millis = probe.maybe_duration_millis;
 
-    if let Ok(video_info) = ffprobe_get_info(&file.path()) {
-      maybe_duration_millis = video_info.duration
-          .as_ref()
-          .map(|duration| duration.millis as u64);
-
-      maybe_frame_width = video_info.dimensions
-          .as_ref()
-          .map(|ref dims| dims.width as u32);
-
-      maybe_frame_height = video_info.dimensions
-          .as_ref()
-          .map(|dims| dims.height as u32);
+      if let Some((width, height)) = probe.maybe_display_dimensions() {
+        maybe_frame_width = Some(width);
+        maybe_frame_height = Some(height);
+      }
+    }
+    Err(err) => {
+      warn!("Failed to probe video: {:?}", err);
     }
   }
 
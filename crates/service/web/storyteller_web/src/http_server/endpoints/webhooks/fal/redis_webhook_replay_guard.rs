@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use r2d2::Pool;
+use redis::Client;
+
+use fal_client::webhook_payload::signature::fal_webhook_signature_error::FalWebhookSignatureError;
+use fal_client::webhook_payload::signature::webhook_replay_guard::{replay_key, WebhookReplayGuard};
+
+/// Remembers accepted FAL deliveries in Redis, so a captured delivery can't be
+/// replayed against a different server.
+#[derive(Clone)]
+pub struct RedisWebhookReplayGuard {
+  redis_pool: Pool<Client>,
+
+  /// Keys are kept this long past the delivery timestamp (should cover the skew in both directions).
+  retention_seconds: i64,
+}
+
+impl RedisWebhookReplayGuard {
+  pub fn new(redis_pool: Pool<Client>, max_skew_seconds: i64) -> Self {
+    Self {
+      redis_pool,
+      retention_seconds: max_skew_seconds * 2,
+    }
+  }
+}
+
+#[async_trait]
+impl WebhookReplayGuard for RedisWebhookReplayGuard {
+  async fn check_and_record(
+    &self,
+    signature: &str,
+    timestamp: i64,
+    now_unix_seconds: i64,
+  ) -> Result<bool, FalWebhookSignatureError> {
+    // NB: The pool and client are blocking, so keep them off the async executor.
+    let redis_pool = self.redis_pool.clone();
+    let key = format!("fal_webhook_delivery:{}", replay_key(signature));
+    let expire_seconds = (timestamp + self.retention_seconds - now_unix_seconds).max(1);
+
+    tokio::task::spawn_blocking(move || record_blocking(&redis_pool, &key, timestamp, expire_seconds))
+        .await
+        .map_err(|err| FalWebhookSignatureError::ReplayGuard(err.to_string()))?
+  }
+}
+
+fn record_blocking(
+  redis_pool: &Pool<Client>,
+  key: &str,
+  timestamp: i64,
+  expire_seconds: i64,
+) -> Result<bool, FalWebhookSignatureError> {
+  let mut redis = redis_pool.get()
+      .map_err(|err| FalWebhookSignatureError::ReplayGuard(err.to_string()))?;
+
+  // NB: `SET NX` only succeeds for the first delivery of a signature.
+  let maybe_set : Option<String> = redis::cmd("SET")
+      .arg(key).arg(timestamp).arg("NX").arg("EX").arg(expire_seconds)
+      .query(&mut *redis)
+      .map_err(|err| FalWebhookSignatureError::ReplayGuard(err.to_string()))?;
+
+  Ok(maybe_set.is_some())
+}
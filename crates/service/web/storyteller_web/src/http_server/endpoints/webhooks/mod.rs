@@ -1,2 +1,3 @@
 pub mod beeble;
 pub mod fal;
+pub(crate) mod webhook_delivery_ledger;
@@ -0,0 +1,200 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use log::{error, info, warn};
+
+use enums::by_table::webhook_deliveries::webhook_delivery_provider::WebhookDeliveryProvider;
+use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+use hashing::sha256::sha256_hash_bytes::sha256_hash_bytes;
+use mysql_queries::queries::webhook_deliveries::get_webhook_delivery_claim_transactional_locking::get_webhook_delivery_claim_transactional_locking;
+use mysql_queries::queries::webhook_deliveries::insert_webhook_delivery::{
+  insert_webhook_delivery, InsertWebhookDeliveryArgs, InsertWebhookDeliveryResult,
+};
+use mysql_queries::queries::webhook_deliveries::reclaim_webhook_delivery::reclaim_webhook_delivery;
+use mysql_queries::queries::webhook_deliveries::update_webhook_delivery_status::update_webhook_delivery_status;
+use tokens::tokens::webhook_deliveries::WebhookDeliveryToken;
+
+use crate::http_server::common_responses::advanced_common_web_error::AdvancedCommonWebError;
+use crate::state::server_state::ServerState;
+
+/// How long an in-flight delivery holds its claim. A provider retry that arrives after this
+/// takes the delivery over, so a crash mid-processing doesn't swallow the webhook for good.
+const PROCESSING_LEASE: Duration = Duration::from_secs(5 * 60);
+
+/// Whether the caller should process a webhook delivery.
+pub(crate) enum WebhookDeliveryClaim {
+  /// First delivery of this payload, or a retry of one that failed or went stale;
+  /// process it and record the outcome.
+  Process(WebhookDeliveryToken),
+
+  /// The same payload already succeeded, or is being processed right now.
+  /// Acknowledge it without reprocessing.
+  Duplicate,
+}
+
+/// Record an inbound webhook in the `webhook_deliveries` ledger, keyed by provider,
+/// provider request id, and a hash of the raw body.
+///
+/// A repeated delivery is only processed again if the earlier attempt failed, or if it
+/// has been in flight longer than `PROCESSING_LEASE`.
+pub(crate) async fn claim_webhook_delivery(
+  server_state: &ServerState,
+  provider: WebhookDeliveryProvider,
+  provider_request_id: &str,
+  raw_body: &str,
+) -> Result<WebhookDeliveryClaim, AdvancedCommonWebError> {
+  let payload_sha256 = sha256_hash_bytes(raw_body.as_bytes())
+      .map_err(|err| {
+        error!("Could not hash {} webhook body: {:?}", provider, err);
+        AdvancedCommonWebError::from_anyhow_error(err)
+      })?;
+
+  let result = insert_webhook_delivery(InsertWebhookDeliveryArgs {
+    provider,
+    provider_request_id,
+    payload_sha256: &payload_sha256,
+    raw_body,
+    mysql_executor: &server_state.mysql_pool,
+    phantom: PhantomData,
+  }).await.map_err(|err| {
+    // Don't process without a ledger entry; the provider will retry the delivery.
+    error!("Could not record {} webhook delivery for {}: {:?}", provider, provider_request_id, err);
+    AdvancedCommonWebError::from_error(err)
+  })?;
+
+  match result {
+    InsertWebhookDeliveryResult::Inserted(token) => {
+      info!("Recorded {} webhook delivery {} for {}", provider, token, provider_request_id);
+      Ok(WebhookDeliveryClaim::Process(token))
+    }
+    InsertWebhookDeliveryResult::AlreadyRecorded => {
+      maybe_reclaim_webhook_delivery(server_state, provider, provider_request_id, &payload_sha256).await
+    }
+  }
+}
+
+async fn maybe_reclaim_webhook_delivery(
+  server_state: &ServerState,
+  provider: WebhookDeliveryProvider,
+  provider_request_id: &str,
+  payload_sha256: &str,
+) -> Result<WebhookDeliveryClaim, AdvancedCommonWebError> {
+  let mut transaction = server_state.mysql_pool.begin()
+      .await
+      .map_err(|err| {
+        error!("Could not begin transaction for {} webhook delivery: {:?}", provider, err);
+        AdvancedCommonWebError::from_error(err)
+      })?;
+
+  let maybe_state = get_webhook_delivery_claim_transactional_locking(
+    provider,
+    provider_request_id,
+    payload_sha256,
+    &mut *transaction,
+  ).await.map_err(|err| {
+    error!("Could not look up {} webhook delivery for {}: {:?}", provider, provider_request_id, err);
+    AdvancedCommonWebError::from_error(err)
+  })?;
+
+  let state = match maybe_state {
+    Some(state) => state,
+    None => {
+      // The insert just reported this row, so it shouldn't vanish. Let the provider retry.
+      error!("{} webhook delivery for {} was recorded but not found", provider, provider_request_id);
+      return Err(AdvancedCommonWebError::from_anyhow_error(
+        anyhow::anyhow!("webhook delivery disappeared")));
+    }
+  };
+
+  let claim_age = Duration::from_secs(state.claim_age_seconds.max(0) as u64);
+
+  if !is_reclaimable(state.status, claim_age) {
+    info!("Ignoring repeated {} webhook delivery {} for {} ({}, claimed {}s ago)",
+      provider, state.token, provider_request_id, state.status, claim_age.as_secs());
+    return Ok(WebhookDeliveryClaim::Duplicate);
+  }
+
+  reclaim_webhook_delivery(&state.token, &mut *transaction)
+      .await
+      .map_err(|err| {
+        error!("Could not reclaim {} webhook delivery {}: {:?}", provider, state.token, err);
+        AdvancedCommonWebError::from_error(err)
+      })?;
+
+  transaction.commit()
+      .await
+      .map_err(|err| {
+        error!("Could not commit reclaim of {} webhook delivery {}: {:?}", provider, state.token, err);
+        AdvancedCommonWebError::from_error(err)
+      })?;
+
+  warn!("Reprocessing {} webhook delivery {} for {} (was {}, claimed {}s ago)",
+    provider, state.token, provider_request_id, state.status, claim_age.as_secs());
+
+  Ok(WebhookDeliveryClaim::Process(state.token))
+}
+
+/// A repeated delivery may be processed again if the last attempt failed, or if it is
+/// still marked in flight after the lease ran out (the processor most likely died).
+fn is_reclaimable(status: WebhookDeliveryStatus, claim_age: Duration) -> bool {
+  match status {
+    WebhookDeliveryStatus::Succeeded => false,
+    WebhookDeliveryStatus::Failed => true,
+    WebhookDeliveryStatus::Processing => claim_age >= PROCESSING_LEASE,
+  }
+}
+
+/// Store the outcome of processing a delivery. Failures to record are logged, not returned:
+/// the delivery itself has already been processed (or not) by this point.
+pub(crate) async fn record_webhook_delivery_outcome<T>(
+  server_state: &ServerState,
+  token: &WebhookDeliveryToken,
+  result: &Result<T, AdvancedCommonWebError>,
+) {
+  let (status, maybe_error) = match result {
+    Ok(_) => (WebhookDeliveryStatus::Succeeded, None),
+    Err(err) => (WebhookDeliveryStatus::Failed, Some(format!("{:?}", err))),
+  };
+
+  if let Err(err) = update_webhook_delivery_status(
+    token,
+    status,
+    maybe_error.as_deref(),
+    &server_state.mysql_pool,
+  ).await {
+    warn!("Could not update webhook delivery {} to {}: {:?}", token, status, err);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use enums::by_table::webhook_deliveries::webhook_delivery_status::WebhookDeliveryStatus;
+
+  use crate::http_server::endpoints::webhooks::webhook_delivery_ledger::{is_reclaimable, PROCESSING_LEASE};
+
+  #[test]
+  fn succeeded_deliveries_are_duplicates() {
+    assert!(!is_reclaimable(WebhookDeliveryStatus::Succeeded, Duration::from_secs(0)));
+    assert!(!is_reclaimable(WebhookDeliveryStatus::Succeeded, PROCESSING_LEASE * 10));
+  }
+
+  #[test]
+  fn failed_deliveries_are_reclaimed() {
+    assert!(is_reclaimable(WebhookDeliveryStatus::Failed, Duration::from_secs(0)));
+    assert!(is_reclaimable(WebhookDeliveryStatus::Failed, PROCESSING_LEASE * 10));
+  }
+
+  #[test]
+  fn fresh_in_flight_deliveries_are_duplicates() {
+    assert!(!is_reclaimable(WebhookDeliveryStatus::Processing, Duration::from_secs(0)));
+    assert!(!is_reclaimable(WebhookDeliveryStatus::Processing, PROCESSING_LEASE - Duration::from_secs(1)));
+  }
+
+  #[test]
+  fn stale_in_flight_deliveries_are_reclaimed() {
+    assert!(is_reclaimable(WebhookDeliveryStatus::Processing, PROCESSING_LEASE));
+    assert!(is_reclaimable(WebhookDeliveryStatus::Processing, PROCESSING_LEASE + Duration::from_secs(1)));
+  }
+}
@@ -1,2 +1,3 @@
 pub mod error_alerting_middleware;
 pub mod pushback_filter_middleware;
+pub mod rate_limit_headers_middleware;
@@ -0,0 +1,68 @@
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{Error, HttpMessage};
+
+use crate::http_server::rate_limiting::rate_limit_decision::RateLimitDecision;
+
+/// Adds `RateLimit-*` (and, when limited, `Retry-After`) headers to responses from
+/// endpoints that consulted the rate limit engine.
+///
+/// Handlers record their `RateLimitDecision` in the request extensions. Doing the
+/// headers here means they're set on error responses too, including the 429 itself.
+#[derive(Clone, Default)]
+pub struct RateLimitHeaders;
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimitHeaders
+  where
+      S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+      S::Future: 'static,
+      B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type InitError = ();
+  type Transform = RateLimitHeadersService<S>;
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    ready(Ok(RateLimitHeadersService { service }))
+  }
+}
+
+pub struct RateLimitHeadersService<S> {
+  service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitHeadersService<S>
+  where
+      S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+      S::Future: 'static,
+      B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+  actix_service::forward_ready!(service);
+
+  fn call(&self, req: ServiceRequest) -> Self::Future {
+    let fut = self.service.call(req);
+
+    Box::pin(async move {
+      let mut res = fut.await?;
+
+      let maybe_decision = res.request()
+          .extensions()
+          .get::<RateLimitDecision>()
+          .copied();
+
+      if let Some(decision) = maybe_decision {
+        decision.insert_headers(res.headers_mut());
+      }
+
+      Ok(res)
+    })
+  }
+}
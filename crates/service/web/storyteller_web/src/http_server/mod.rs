@@ -4,6 +4,7 @@ pub mod endpoint_helpers;
 pub mod deprecated_endpoints;
 pub mod endpoints;
 pub mod middleware;
+pub mod rate_limiting;
 pub mod requests;
 pub mod routes;
 pub mod session;
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::http_server::rate_limiting::backends::rate_limit_backend::{RateLimitBackend, RateLimitBackendError, RateLimitWindowCount};
+
+/// Once this many keys are tracked, expired windows are dropped.
+const PRUNE_THRESHOLD : usize = 10_000;
+
+/// Process-local counters. Each server has its own counts, so this is only
+/// suitable for tests and local development.
+#[derive(Default)]
+pub struct InMemoryRateLimitBackend {
+  windows: Mutex<HashMap<String, FixedWindow>>,
+}
+
+struct FixedWindow {
+  ends_at: Instant,
+  count: u32,
+}
+
+impl InMemoryRateLimitBackend {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  fn increment_at(&self, key: &str, window_seconds: u32, now: Instant) -> RateLimitWindowCount {
+    // NB: A panic while holding the lock can't leave the counters in a bad state.
+    let mut windows = self.windows.lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if windows.len() >= PRUNE_THRESHOLD {
+      windows.retain(|_, window| window.ends_at > now);
+    }
+
+    let window = windows.entry(key.to_string())
+        .or_insert(FixedWindow { ends_at: now, count: 0 });
+
+    if window.ends_at <= now {
+      window.ends_at = now + Duration::from_secs(window_seconds as u64);
+      window.count = 0;
+    }
+
+    window.count = window.count.saturating_add(1);
+
+    // NB: Round up so clients never retry a moment too early.
+    let remaining = window.ends_at.saturating_duration_since(now);
+    let reset_seconds = remaining.as_secs() + if remaining.subsec_nanos() > 0 { 1 } else { 0 };
+
+    RateLimitWindowCount {
+      count: window.count,
+      reset_seconds,
+    }
+  }
+}
+
+#[async_trait]
+impl RateLimitBackend for InMemoryRateLimitBackend {
+  async fn increment(&self, key: &str, window_seconds: u32) -> Result<RateLimitWindowCount, RateLimitBackendError> {
+    Ok(self.increment_at(key, window_seconds, Instant::now()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::{Duration, Instant};
+
+  use crate::http_server::rate_limiting::backends::in_memory_rate_limit_backend::InMemoryRateLimitBackend;
+
+  #[test]
+  fn test_counts_within_window() {
+    let backend = InMemoryRateLimitBackend::new();
+    let start = Instant::now();
+
+    let first = backend.increment_at("key", 60, start);
+    assert_eq!(first.count, 1);
+    assert_eq!(first.reset_seconds, 60);
+
+    let second = backend.increment_at("key", 60, start + Duration::from_millis(10_500));
+    assert_eq!(second.count, 2);
+    assert_eq!(second.reset_seconds, 50);
+  }
+
+  #[test]
+  fn test_window_resets() {
+    let backend = InMemoryRateLimitBackend::new();
+    let start = Instant::now();
+
+    backend.increment_at("key", 60, start);
+    backend.increment_at("key", 60, start + Duration::from_secs(30));
+
+    let next_window = backend.increment_at("key", 60, start + Duration::from_secs(60));
+    assert_eq!(next_window.count, 1);
+    assert_eq!(next_window.reset_seconds, 60);
+  }
+
+  #[test]
+  fn test_keys_are_independent() {
+    let backend = InMemoryRateLimitBackend::new();
+    let start = Instant::now();
+
+    backend.increment_at("a", 60, start);
+    backend.increment_at("a", 60, start);
+
+    assert_eq!(backend.increment_at("b", 60, start).count, 1);
+    assert_eq!(backend.increment_at("a", 60, start).count, 3);
+  }
+}
@@ -0,0 +1,3 @@
+pub mod in_memory_rate_limit_backend;
+pub mod rate_limit_backend;
+pub mod redis_rate_limit_backend;
@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+/// Fixed window counters for the rate limit engine.
+///
+/// Backends only count; the engine decides what the counts mean. That keeps the
+/// in-memory backend (tests, local development) and Redis (production) interchangeable.
+#[async_trait]
+pub trait RateLimitBackend: Send + Sync {
+  /// Count one request against `key`. A window starts with the first request and
+  /// lasts `window_seconds`.
+  async fn increment(&self, key: &str, window_seconds: u32) -> Result<RateLimitWindowCount, RateLimitBackendError>;
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitWindowCount {
+  /// Requests in the current window, including this one.
+  pub count: u32,
+
+  /// Seconds until the current window ends.
+  pub reset_seconds: u64,
+}
+
+#[derive(Debug)]
+pub enum RateLimitBackendError {
+  Pool(r2d2::Error),
+  Redis(redis::RedisError),
+  Task(tokio::task::JoinError),
+}
+
+impl Display for RateLimitBackendError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Pool(err) => write!(f, "rate limit backend pool error: {}", err),
+      Self::Redis(err) => write!(f, "rate limit backend redis error: {}", err),
+      Self::Task(err) => write!(f, "rate limit backend task error: {}", err),
+    }
+  }
+}
+
+impl Error for RateLimitBackendError {}
+
+impl From<r2d2::Error> for RateLimitBackendError {
+  fn from(err: r2d2::Error) -> Self {
+    Self::Pool(err)
+  }
+}
+
+impl From<redis::RedisError> for RateLimitBackendError {
+  fn from(err: redis::RedisError) -> Self {
+    Self::Redis(err)
+  }
+}
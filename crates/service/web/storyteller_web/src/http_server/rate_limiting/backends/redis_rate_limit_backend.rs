@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use r2d2::Pool;
+use redis::Client;
+
+use crate::http_server::rate_limiting::backends::rate_limit_backend::{RateLimitBackend, RateLimitBackendError, RateLimitWindowCount};
+
+/// Counters shared by every server. Each key is a Redis integer that expires
+/// with its window.
+#[derive(Clone)]
+pub struct RedisRateLimitBackend {
+  redis_pool: Pool<Client>,
+}
+
+impl RedisRateLimitBackend {
+  pub fn new(redis_pool: Pool<Client>) -> Self {
+    Self { redis_pool }
+  }
+}
+
+#[async_trait]
+impl RateLimitBackend for RedisRateLimitBackend {
+  async fn increment(&self, key: &str, window_seconds: u32) -> Result<RateLimitWindowCount, RateLimitBackendError> {
+    // NB: The pool and client are blocking, so keep them off the async executor.
+    let redis_pool = self.redis_pool.clone();
+    let key = key.to_string();
+
+    tokio::task::spawn_blocking(move || increment_blocking(&redis_pool, &key, window_seconds))
+        .await
+        .map_err(RateLimitBackendError::Task)?
+  }
+}
+
+fn increment_blocking(redis_pool: &Pool<Client>, key: &str, window_seconds: u32) -> Result<RateLimitWindowCount, RateLimitBackendError> {
+  let mut redis = redis_pool.get()?;
+
+  // NB: `SET NX EX` starts the window (and its expiry) only if the key doesn't
+  // exist, so a crash between commands can't leave a counter that never expires.
+  let (count, ttl) : (u64, i64) = redis::pipe()
+      .atomic()
+      .cmd("SET").arg(key).arg(0).arg("NX").arg("EX").arg(window_seconds).ignore()
+      .cmd("INCR").arg(key)
+      .cmd("TTL").arg(key)
+      .query(&mut *redis)?;
+
+  let reset_seconds = if ttl > 0 { ttl as u64 } else { window_seconds as u64 };
+
+  Ok(RateLimitWindowCount {
+    count: u32::try_from(count).unwrap_or(u32::MAX),
+    reset_seconds,
+  })
+}
@@ -0,0 +1,166 @@
+use actix_web::{HttpMessage, HttpRequest};
+use log::warn;
+use sqlx::pool::PoolConnection;
+use sqlx::MySql;
+
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use mysql_queries::queries::users::user_rate_limit_overrides::get_active_user_rate_limit_override::{get_active_user_rate_limit_override, ActiveUserRateLimitOverride};
+use mysql_queries::queries::users::user_sessions::get_user_session_by_token::SessionUserRecord;
+use mysql_queries::queries::users::user_subscriptions::list_active_user_subscriptions::list_active_user_subscriptions;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use tokens::tokens::users::UserToken;
+
+use crate::configs::plans::get_correct_plan_for_session::get_correct_plan_for_premium_info;
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::rate_limit_engine::RateLimitExceededError;
+use crate::http_server::rate_limiting::rate_limit_rule::RateLimitRule;
+use crate::http_server::rate_limiting::rate_limit_subject::RateLimitSubject;
+use crate::http_server::session::lookup::user_session_extended::{UserSessionPremiumPlanInfo, UserSessionSubscriptionPlan};
+use crate::state::server_state::ServerState;
+
+pub struct EnforceRateLimitArgs<'a> {
+  pub http_request: &'a HttpRequest,
+  pub server_state: &'a ServerState,
+  pub endpoint_group: RateLimitEndpointGroup,
+
+  /// The caller's session, if they're logged in (by cookie or personal access token).
+  pub maybe_user_session: Option<&'a SessionUserRecord>,
+
+  /// Set when the caller authenticated with a personal access token.
+  pub maybe_personal_access_token: Option<&'a PersonalAccessTokenToken>,
+
+  pub mysql_connection: &'a mut PoolConnection<MySql>,
+}
+
+/// Count the request against the rate limit policy for its endpoint group.
+///
+/// The limit depends on the caller's plan tier, or a moderator override for the user.
+/// The decision is stashed in the request extensions, where the `RateLimitHeaders`
+/// middleware turns it into `RateLimit-*` response headers.
+///
+/// Database and backend failures fail open.
+pub async fn enforce_rate_limit(args: EnforceRateLimitArgs<'_>) -> Result<(), RateLimitExceededError> {
+  let engine = &args.server_state.rate_limit_engine;
+
+  if !engine.is_enabled() {
+    return Ok(());
+  }
+
+  let subject = RateLimitSubject::for_request(
+    args.http_request,
+    args.maybe_user_session.map(|session| &session.user_token),
+    args.maybe_personal_access_token);
+
+  let tier = match args.maybe_user_session {
+    None => RateLimitTier::LoggedOut,
+    Some(user_session) => lookup_tier(args.server_state, user_session, args.mysql_connection).await,
+  };
+
+  let maybe_override = match subject.maybe_user_token() {
+    None => None,
+    Some(user_token) => lookup_override(user_token, args.endpoint_group, args.mysql_connection).await,
+  };
+
+  let rule = match engine.resolve_rule(args.endpoint_group, tier, maybe_override.as_ref()) {
+    None => return Ok(()), // NB: Exempt, or no rule for this tier.
+    Some(rule) => rule,
+  };
+
+  apply_rule(args.http_request, args.server_state, args.endpoint_group, &subject, tier, rule).await
+}
+
+pub struct EnforceBasicRateLimitArgs<'a> {
+  pub http_request: &'a HttpRequest,
+  pub server_state: &'a ServerState,
+  pub endpoint_group: RateLimitEndpointGroup,
+
+  /// Count the request against this user rather than the IP address.
+  pub maybe_user_token: Option<&'a UserToken>,
+
+  pub tier: RateLimitTier,
+}
+
+/// Count the request against the rate limit policy without a database lookup.
+///
+/// For older endpoints that don't have a session record or connection at hand. The
+/// caller picks the tier, and moderator overrides don't apply.
+pub async fn enforce_basic_rate_limit(args: EnforceBasicRateLimitArgs<'_>) -> Result<(), RateLimitExceededError> {
+  let engine = &args.server_state.rate_limit_engine;
+
+  if !engine.is_enabled() {
+    return Ok(());
+  }
+
+  let subject = RateLimitSubject::for_request(args.http_request, args.maybe_user_token, None);
+
+  let rule = match engine.resolve_rule(args.endpoint_group, args.tier, None) {
+    None => return Ok(()),
+    Some(rule) => rule,
+  };
+
+  apply_rule(args.http_request, args.server_state, args.endpoint_group, &subject, args.tier, rule).await
+}
+
+async fn apply_rule(
+  http_request: &HttpRequest,
+  server_state: &ServerState,
+  endpoint_group: RateLimitEndpointGroup,
+  subject: &RateLimitSubject,
+  tier: RateLimitTier,
+  rule: RateLimitRule,
+) -> Result<(), RateLimitExceededError> {
+  let decision = match server_state.rate_limit_engine.check(endpoint_group, subject, rule).await {
+    None => return Ok(()),
+    Some(decision) => decision,
+  };
+
+  http_request.extensions_mut().insert(decision);
+
+  if !decision.allowed {
+    warn!("Rate limit exceeded for {} on {} (tier {})", subject, endpoint_group.to_str(), tier.to_str());
+    return Err(RateLimitExceededError { decision });
+  }
+
+  Ok(())
+}
+
+async fn lookup_tier(
+  server_state: &ServerState,
+  user_session: &SessionUserRecord,
+  mysql_connection: &mut PoolConnection<MySql>,
+) -> RateLimitTier {
+  let subscriptions = list_active_user_subscriptions(mysql_connection, user_session.user_token.as_str())
+      .await
+      .unwrap_or_else(|err| {
+        warn!("Error looking up subscriptions for rate limit tier: {:?}", err);
+        Vec::new()
+      });
+
+  let premium = UserSessionPremiumPlanInfo {
+    maybe_stripe_customer_id: user_session.maybe_stripe_customer_id.clone(),
+    maybe_loyalty_program_key: user_session.maybe_loyalty_program_key.clone(),
+    subscription_plans: subscriptions.into_iter()
+        .map(|subscription| UserSessionSubscriptionPlan {
+          subscription_namespace: subscription.subscription_namespace,
+          subscription_product_slug: subscription.subscription_product_slug,
+          subscription_expires_at: subscription.subscription_expires_at,
+        })
+        .collect(),
+  };
+
+  get_correct_plan_for_premium_info(server_state.server_environment_old, Some(&premium))
+      .rate_limit_tier()
+}
+
+async fn lookup_override(
+  user_token: &UserToken,
+  endpoint_group: RateLimitEndpointGroup,
+  mysql_connection: &mut PoolConnection<MySql>,
+) -> Option<ActiveUserRateLimitOverride> {
+  get_active_user_rate_limit_override(user_token, endpoint_group, &mut **mysql_connection)
+      .await
+      .unwrap_or_else(|err| {
+        warn!("Error looking up rate limit override: {:?}", err);
+        None
+      })
+}
@@ -0,0 +1,7 @@
+pub mod backends;
+pub mod enforce_rate_limit;
+pub mod rate_limit_decision;
+pub mod rate_limit_engine;
+pub mod rate_limit_policy;
+pub mod rate_limit_rule;
+pub mod rate_limit_subject;
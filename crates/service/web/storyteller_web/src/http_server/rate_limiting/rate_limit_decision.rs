@@ -0,0 +1,92 @@
+use actix_http::header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER};
+
+use crate::http_server::rate_limiting::rate_limit_rule::RateLimitRule;
+
+// NB: Field names from the IETF "RateLimit header fields for HTTP" draft.
+const RATE_LIMIT_LIMIT_HEADER : &str = "ratelimit-limit";
+const RATE_LIMIT_REMAINING_HEADER : &str = "ratelimit-remaining";
+const RATE_LIMIT_RESET_HEADER : &str = "ratelimit-reset";
+
+/// The outcome of counting one request. Stored in the request extensions so the
+/// `RateLimitHeaders` middleware can describe it to the client.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitDecision {
+  pub allowed: bool,
+
+  /// Requests allowed per window.
+  pub limit: u32,
+
+  /// Requests left in the current window.
+  pub remaining: u32,
+
+  /// Seconds until the current window ends.
+  pub reset_seconds: u64,
+}
+
+impl RateLimitDecision {
+  /// `count` is the number of requests in the window, including this one.
+  pub fn from_window_count(rule: RateLimitRule, count: u32, reset_seconds: u64) -> Self {
+    Self {
+      allowed: count <= rule.max_requests,
+      limit: rule.max_requests,
+      remaining: rule.max_requests.saturating_sub(count),
+      reset_seconds,
+    }
+  }
+
+  pub fn insert_headers(&self, headers: &mut HeaderMap) {
+    headers.insert(HeaderName::from_static(RATE_LIMIT_LIMIT_HEADER), HeaderValue::from(self.limit));
+    headers.insert(HeaderName::from_static(RATE_LIMIT_REMAINING_HEADER), HeaderValue::from(self.remaining));
+    headers.insert(HeaderName::from_static(RATE_LIMIT_RESET_HEADER), HeaderValue::from(self.reset_seconds));
+
+    if !self.allowed {
+      headers.insert(RETRY_AFTER, HeaderValue::from(self.reset_seconds));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use actix_http::header::{HeaderMap, RETRY_AFTER};
+
+  use crate::http_server::rate_limiting::rate_limit_decision::RateLimitDecision;
+  use crate::http_server::rate_limiting::rate_limit_rule::RateLimitRule;
+
+  const RULE : RateLimitRule = RateLimitRule::new(3, 60);
+
+  #[test]
+  fn test_from_window_count() {
+    let decision = RateLimitDecision::from_window_count(RULE, 1, 60);
+    assert!(decision.allowed);
+    assert_eq!(decision.remaining, 2);
+
+    let decision = RateLimitDecision::from_window_count(RULE, 3, 10);
+    assert!(decision.allowed);
+    assert_eq!(decision.remaining, 0);
+
+    let decision = RateLimitDecision::from_window_count(RULE, 4, 10);
+    assert!(!decision.allowed);
+    assert_eq!(decision.remaining, 0);
+    assert_eq!(decision.limit, 3);
+  }
+
+  #[test]
+  fn test_headers_when_allowed() {
+    let mut headers = HeaderMap::new();
+    RateLimitDecision::from_window_count(RULE, 1, 42).insert_headers(&mut headers);
+
+    assert_eq!(headers.get("ratelimit-limit").unwrap(), "3");
+    assert_eq!(headers.get("ratelimit-remaining").unwrap(), "2");
+    assert_eq!(headers.get("ratelimit-reset").unwrap(), "42");
+    assert!(headers.get(RETRY_AFTER).is_none());
+  }
+
+  #[test]
+  fn test_headers_when_limited() {
+    let mut headers = HeaderMap::new();
+    RateLimitDecision::from_window_count(RULE, 4, 42).insert_headers(&mut headers);
+
+    assert_eq!(headers.get("ratelimit-remaining").unwrap(), "0");
+    assert_eq!(headers.get(RETRY_AFTER).unwrap(), "42");
+  }
+}
@@ -0,0 +1,158 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use log::warn;
+
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use mysql_queries::queries::users::user_rate_limit_overrides::get_active_user_rate_limit_override::ActiveUserRateLimitOverride;
+
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::backends::rate_limit_backend::RateLimitBackend;
+use crate::http_server::rate_limiting::rate_limit_decision::RateLimitDecision;
+use crate::http_server::rate_limiting::rate_limit_policy::RateLimitPolicy;
+use crate::http_server::rate_limiting::rate_limit_rule::RateLimitRule;
+use crate::http_server::rate_limiting::rate_limit_subject::RateLimitSubject;
+
+/// Applies the rate limit policy, counting requests with the configured backend.
+/// Cheap to clone.
+#[derive(Clone)]
+pub struct RateLimitEngine {
+  policy: Arc<RateLimitPolicy>,
+  backend: Arc<dyn RateLimitBackend>,
+}
+
+/// The request went over its limit.
+#[derive(Debug)]
+pub struct RateLimitExceededError {
+  pub decision: RateLimitDecision,
+}
+
+impl RateLimitEngine {
+  pub fn new(policy: RateLimitPolicy, backend: Arc<dyn RateLimitBackend>) -> Self {
+    Self {
+      policy: Arc::new(policy),
+      backend,
+    }
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    self.policy.is_enabled()
+  }
+
+  /// The rule for a request, taking a moderator override into account.
+  /// Returns `None` if the request isn't limited at all.
+  pub fn resolve_rule(
+    &self,
+    group: RateLimitEndpointGroup,
+    tier: RateLimitTier,
+    maybe_override: Option<&ActiveUserRateLimitOverride>,
+  ) -> Option<RateLimitRule> {
+    match maybe_override {
+      Some(record) if record.is_exempt => None,
+      Some(record) => Some(RateLimitRule::new(record.max_requests, record.window_seconds)),
+      None => self.policy.rule_for(group, tier),
+    }
+  }
+
+  /// Count the request against the subject's budget for the endpoint group.
+  /// Returns `None` if the backend is unavailable; callers should fail open.
+  pub async fn check(
+    &self,
+    group: RateLimitEndpointGroup,
+    subject: &RateLimitSubject,
+    rule: RateLimitRule,
+  ) -> Option<RateLimitDecision> {
+    // NB: The window length is part of the key so that changing a rule starts fresh counters
+    // rather than inheriting a window of a different length.
+    let key = format!("rate_limit_policy:{}:{}s:{}",
+      group.to_str(), rule.window_seconds, subject.key_fragment());
+
+    match self.backend.increment(&key, rule.window_seconds).await {
+      Ok(window) => Some(RateLimitDecision::from_window_count(rule, window.count, window.reset_seconds)),
+      Err(err) => {
+        warn!("Rate limit backend error (failing open): {}", err);
+        None
+      }
+    }
+  }
+}
+
+impl Display for RateLimitExceededError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "rate limit of {} requests exceeded; resets in {}s", self.decision.limit, self.decision.reset_seconds)
+  }
+}
+
+impl Error for RateLimitExceededError {}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+  use mysql_queries::queries::users::user_rate_limit_overrides::get_active_user_rate_limit_override::ActiveUserRateLimitOverride;
+  use tokens::tokens::users::UserToken;
+
+  use crate::configs::plans::rate_limit_tier::RateLimitTier;
+  use crate::http_server::rate_limiting::backends::in_memory_rate_limit_backend::InMemoryRateLimitBackend;
+  use crate::http_server::rate_limiting::rate_limit_engine::RateLimitEngine;
+  use crate::http_server::rate_limiting::rate_limit_policy::RateLimitPolicy;
+  use crate::http_server::rate_limiting::rate_limit_rule::RateLimitRule;
+  use crate::http_server::rate_limiting::rate_limit_subject::RateLimitSubject;
+
+  fn engine() -> RateLimitEngine {
+    RateLimitEngine::new(RateLimitPolicy::default_policy(), Arc::new(InMemoryRateLimitBackend::new()))
+  }
+
+  #[test]
+  fn test_resolve_rule_uses_tier() {
+    let engine = engine();
+    assert_eq!(engine.resolve_rule(RateLimitEndpointGroup::Generation, RateLimitTier::Free, None), Some(RateLimitRule::new(10, 60)));
+    assert_eq!(engine.resolve_rule(RateLimitEndpointGroup::Generation, RateLimitTier::Elite, None), Some(RateLimitRule::new(120, 60)));
+  }
+
+  #[test]
+  fn test_resolve_rule_uses_override() {
+    let engine = engine();
+
+    let custom = ActiveUserRateLimitOverride { is_exempt: false, max_requests: 500, window_seconds: 10 };
+    assert_eq!(engine.resolve_rule(RateLimitEndpointGroup::Uploads, RateLimitTier::Free, Some(&custom)), Some(RateLimitRule::new(500, 10)));
+
+    let exempt = ActiveUserRateLimitOverride { is_exempt: true, max_requests: 0, window_seconds: 0 };
+    assert_eq!(engine.resolve_rule(RateLimitEndpointGroup::Uploads, RateLimitTier::Free, Some(&exempt)), None);
+  }
+
+  #[tokio::test]
+  async fn test_check_denies_over_limit() {
+    let engine = engine();
+    let subject = RateLimitSubject::User(UserToken::new_from_str("U_TEST"));
+    let rule = RateLimitRule::new(2, 60);
+
+    let first = engine.check(RateLimitEndpointGroup::Auth, &subject, rule).await.unwrap();
+    assert!(first.allowed);
+    assert_eq!(first.remaining, 1);
+
+    let second = engine.check(RateLimitEndpointGroup::Auth, &subject, rule).await.unwrap();
+    assert!(second.allowed);
+    assert_eq!(second.remaining, 0);
+
+    let third = engine.check(RateLimitEndpointGroup::Auth, &subject, rule).await.unwrap();
+    assert!(!third.allowed);
+    assert_eq!(third.limit, 2);
+    assert_eq!(third.remaining, 0);
+  }
+
+  #[tokio::test]
+  async fn test_groups_and_subjects_have_separate_budgets() {
+    let engine = engine();
+    let rule = RateLimitRule::new(1, 60);
+    let user = RateLimitSubject::User(UserToken::new_from_str("U_TEST"));
+    let ip = RateLimitSubject::IpAddress("1.2.3.4".to_string());
+
+    assert!(engine.check(RateLimitEndpointGroup::Auth, &user, rule).await.unwrap().allowed);
+    assert!(engine.check(RateLimitEndpointGroup::Uploads, &user, rule).await.unwrap().allowed);
+    assert!(engine.check(RateLimitEndpointGroup::Auth, &ip, rule).await.unwrap().allowed);
+    assert!(!engine.check(RateLimitEndpointGroup::Auth, &user, rule).await.unwrap().allowed);
+  }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+use errors::AnyhowResult;
+
+use crate::configs::plans::rate_limit_tier::RateLimitTier;
+use crate::http_server::rate_limiting::rate_limit_rule::RateLimitRule;
+
+/// The default limits for every (endpoint group, plan tier) pair.
+/// Each rule can be overridden with `RATE_LIMIT_{GROUP}_{TIER}_MAX_REQUESTS` and
+/// `RATE_LIMIT_{GROUP}_{TIER}_WINDOW_SECONDS`, eg. `RATE_LIMIT_GENERATION_PRO_MAX_REQUESTS`.
+const DEFAULT_RULES : &[(RateLimitEndpointGroup, RateLimitTier, RateLimitRule)] = &[
+  (RateLimitEndpointGroup::Generation, RateLimitTier::LoggedOut, RateLimitRule::new(3, 60)),
+  (RateLimitEndpointGroup::Generation, RateLimitTier::Free, RateLimitRule::new(10, 60)),
+  (RateLimitEndpointGroup::Generation, RateLimitTier::Plus, RateLimitRule::new(30, 60)),
+  (RateLimitEndpointGroup::Generation, RateLimitTier::Pro, RateLimitRule::new(60, 60)),
+  (RateLimitEndpointGroup::Generation, RateLimitTier::Elite, RateLimitRule::new(120, 60)),
+
+  (RateLimitEndpointGroup::Uploads, RateLimitTier::LoggedOut, RateLimitRule::new(3, 30)),
+  (RateLimitEndpointGroup::Uploads, RateLimitTier::Free, RateLimitRule::new(6, 30)),
+  (RateLimitEndpointGroup::Uploads, RateLimitTier::Plus, RateLimitRule::new(20, 30)),
+  (RateLimitEndpointGroup::Uploads, RateLimitTier::Pro, RateLimitRule::new(40, 30)),
+  (RateLimitEndpointGroup::Uploads, RateLimitTier::Elite, RateLimitRule::new(60, 30)),
+
+  (RateLimitEndpointGroup::Auth, RateLimitTier::LoggedOut, RateLimitRule::new(10, 300)),
+  (RateLimitEndpointGroup::Auth, RateLimitTier::Free, RateLimitRule::new(20, 300)),
+  (RateLimitEndpointGroup::Auth, RateLimitTier::Plus, RateLimitRule::new(20, 300)),
+  (RateLimitEndpointGroup::Auth, RateLimitTier::Pro, RateLimitRule::new(20, 300)),
+  (RateLimitEndpointGroup::Auth, RateLimitTier::Elite, RateLimitRule::new(20, 300)),
+
+  // NB: Two factor codes are short; the limit guards against guessing, so it doesn't grow with the plan.
+  (RateLimitEndpointGroup::TwoFactor, RateLimitTier::LoggedOut, RateLimitRule::new(10, 900)),
+  (RateLimitEndpointGroup::TwoFactor, RateLimitTier::Free, RateLimitRule::new(10, 900)),
+  (RateLimitEndpointGroup::TwoFactor, RateLimitTier::Plus, RateLimitRule::new(10, 900)),
+  (RateLimitEndpointGroup::TwoFactor, RateLimitTier::Pro, RateLimitRule::new(10, 900)),
+  (RateLimitEndpointGroup::TwoFactor, RateLimitTier::Elite, RateLimitRule::new(10, 900)),
+
+  (RateLimitEndpointGroup::EmailVerification, RateLimitTier::LoggedOut, RateLimitRule::new(3, 3600)),
+  (RateLimitEndpointGroup::EmailVerification, RateLimitTier::Free, RateLimitRule::new(3, 3600)),
+  (RateLimitEndpointGroup::EmailVerification, RateLimitTier::Plus, RateLimitRule::new(3, 3600)),
+  (RateLimitEndpointGroup::EmailVerification, RateLimitTier::Pro, RateLimitRule::new(3, 3600)),
+  (RateLimitEndpointGroup::EmailVerification, RateLimitTier::Elite, RateLimitRule::new(3, 3600)),
+];
+
+/// Which limit applies to a request, by endpoint group and the caller's plan tier.
+#[derive(Clone, Debug)]
+pub struct RateLimitPolicy {
+  enabled: bool,
+  rules: HashMap<(RateLimitEndpointGroup, RateLimitTier), RateLimitRule>,
+}
+
+impl RateLimitPolicy {
+  /// The built in table, enabled.
+  pub fn default_policy() -> Self {
+    Self {
+      enabled: true,
+      rules: DEFAULT_RULES.iter()
+          .map(|(group, tier, rule)| ((*group, *tier), *rule))
+          .collect(),
+    }
+  }
+
+  /// The built in table with any env var overrides applied.
+  pub fn from_env() -> AnyhowResult<Self> {
+    let mut policy = Self::default_policy();
+
+    policy.enabled = easyenv::get_env_bool_or_default("RATE_LIMIT_POLICY_ENABLED", true);
+
+    for (group, tier, rule) in DEFAULT_RULES {
+      let prefix = format!("RATE_LIMIT_{}_{}", group.to_str(), tier.to_str()).to_uppercase();
+
+      let max_requests = easyenv::get_env_num(&format!("{}_MAX_REQUESTS", prefix), rule.max_requests)?;
+      let window_seconds = easyenv::get_env_num(&format!("{}_WINDOW_SECONDS", prefix), rule.window_seconds)?;
+
+      policy = policy.with_rule(*group, *tier, RateLimitRule::new(max_requests, window_seconds));
+    }
+
+    Ok(policy)
+  }
+
+  pub fn with_enabled(mut self, enabled: bool) -> Self {
+    self.enabled = enabled;
+    self
+  }
+
+  pub fn with_rule(mut self, group: RateLimitEndpointGroup, tier: RateLimitTier, rule: RateLimitRule) -> Self {
+    self.rules.insert((group, tier), rule);
+    self
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    self.enabled
+  }
+
+  pub fn rule_for(&self, group: RateLimitEndpointGroup, tier: RateLimitTier) -> Option<RateLimitRule> {
+    self.rules.get(&(group, tier)).copied()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use enums::by_table::user_rate_limit_overrides::rate_limit_endpoint_group::RateLimitEndpointGroup;
+
+  use crate::configs::plans::rate_limit_tier::RateLimitTier;
+  use crate::http_server::rate_limiting::rate_limit_policy::RateLimitPolicy;
+  use crate::http_server::rate_limiting::rate_limit_rule::RateLimitRule;
+
+  #[test]
+  fn test_every_group_and_tier_has_a_rule() {
+    let policy = RateLimitPolicy::default_policy();
+    for group in RateLimitEndpointGroup::all_variants() {
+      for tier in RateLimitTier::all_variants() {
+        assert!(policy.rule_for(group, tier).is_some(), "missing rule for {:?} / {:?}", group, tier);
+      }
+    }
+  }
+
+  #[test]
+  fn test_paid_tiers_are_never_stricter() {
+    let policy = RateLimitPolicy::default_policy();
+    for group in RateLimitEndpointGroup::all_variants() {
+      let tiers = RateLimitTier::all_variants();
+      for pair in tiers.windows(2) {
+        let lower = policy.rule_for(group, pair[0]).unwrap();
+        let higher = policy.rule_for(group, pair[1]).unwrap();
+        assert_eq!(lower.window_seconds, higher.window_seconds);
+        assert!(lower.max_requests <= higher.max_requests, "{:?}: {:?} > {:?}", group, pair[0], pair[1]);
+      }
+    }
+  }
+
+  #[test]
+  fn test_with_rule() {
+    let policy = RateLimitPolicy::default_policy()
+        .with_rule(RateLimitEndpointGroup::Auth, RateLimitTier::Free, RateLimitRule::new(1, 2));
+
+    assert_eq!(policy.rule_for(RateLimitEndpointGroup::Auth, RateLimitTier::Free), Some(RateLimitRule::new(1, 2)));
+    assert_eq!(policy.rule_for(RateLimitEndpointGroup::Auth, RateLimitTier::Pro), Some(RateLimitRule::new(20, 300)));
+  }
+}
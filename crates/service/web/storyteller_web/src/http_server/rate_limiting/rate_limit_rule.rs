@@ -0,0 +1,15 @@
+/// At most `max_requests` per fixed window of `window_seconds`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RateLimitRule {
+  pub max_requests: u32,
+  pub window_seconds: u32,
+}
+
+impl RateLimitRule {
+  pub const fn new(max_requests: u32, window_seconds: u32) -> Self {
+    Self {
+      max_requests,
+      window_seconds,
+    }
+  }
+}
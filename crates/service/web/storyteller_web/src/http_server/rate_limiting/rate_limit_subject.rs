@@ -0,0 +1,108 @@
+use std::fmt::{Display, Formatter};
+
+use actix_web::HttpRequest;
+
+use http_server_common::request::get_request_ip::get_request_ip;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+use tokens::tokens::users::UserToken;
+
+/// Who a request is counted against.
+///
+/// Scripts using a personal access token get their own budget, separate from the
+/// user's browser session, so a runaway script can't lock the user out of the website.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RateLimitSubject {
+  IpAddress(String),
+  User(UserToken),
+  PersonalAccessToken {
+    token: PersonalAccessTokenToken,
+    user_token: UserToken,
+  },
+}
+
+impl RateLimitSubject {
+  /// Personal access token if one was used, otherwise the logged in user, otherwise the IP address.
+  pub fn for_request(
+    http_request: &HttpRequest,
+    maybe_user_token: Option<&UserToken>,
+    maybe_personal_access_token: Option<&PersonalAccessTokenToken>,
+  ) -> Self {
+    match (maybe_user_token, maybe_personal_access_token) {
+      (Some(user_token), Some(token)) => Self::PersonalAccessToken {
+        token: token.clone(),
+        user_token: user_token.clone(),
+      },
+      (Some(user_token), None) => Self::User(user_token.clone()),
+      (None, _) => Self::IpAddress(get_request_ip(http_request)),
+    }
+  }
+
+  /// The user whose moderator overrides apply, if any.
+  pub fn maybe_user_token(&self) -> Option<&UserToken> {
+    match self {
+      Self::IpAddress(_) => None,
+      Self::User(user_token) => Some(user_token),
+      Self::PersonalAccessToken { user_token, .. } => Some(user_token),
+    }
+  }
+
+  /// Stable key fragment for the backend.
+  pub fn key_fragment(&self) -> String {
+    match self {
+      Self::IpAddress(ip_address) => format!("ip:{}", ip_address),
+      Self::User(user_token) => format!("user:{}", user_token.as_str()),
+      Self::PersonalAccessToken { token, .. } => format!("pat:{}", token.as_str()),
+    }
+  }
+}
+
+impl Display for RateLimitSubject {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.key_fragment())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use actix_web::test::TestRequest;
+
+  use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+  use tokens::tokens::users::UserToken;
+
+  use crate::http_server::rate_limiting::rate_limit_subject::RateLimitSubject;
+
+  #[test]
+  fn test_personal_access_token_takes_precedence() {
+    let request = TestRequest::default().to_http_request();
+    let user_token = UserToken::new_from_str("U_TEST");
+    let token = PersonalAccessTokenToken::new_from_str("pat_test");
+
+    let subject = RateLimitSubject::for_request(&request, Some(&user_token), Some(&token));
+
+    assert_eq!(subject.key_fragment(), "pat:pat_test");
+    assert_eq!(subject.maybe_user_token(), Some(&user_token));
+  }
+
+  #[test]
+  fn test_user() {
+    let request = TestRequest::default().to_http_request();
+    let user_token = UserToken::new_from_str("U_TEST");
+
+    let subject = RateLimitSubject::for_request(&request, Some(&user_token), None);
+
+    assert_eq!(subject.key_fragment(), "user:U_TEST");
+    assert_eq!(subject.maybe_user_token(), Some(&user_token));
+  }
+
+  #[test]
+  fn test_logged_out_falls_back_to_ip_address() {
+    let request = TestRequest::default()
+        .insert_header(("x-forwarded-for", "1.2.3.4"))
+        .to_http_request();
+
+    let subject = RateLimitSubject::for_request(&request, None, None);
+
+    assert_eq!(subject.key_fragment(), "ip:1.2.3.4");
+    assert_eq!(subject.maybe_user_token(), None);
+  }
+}
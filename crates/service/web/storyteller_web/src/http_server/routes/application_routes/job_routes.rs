@@ -5,6 +5,7 @@ use actix_web::{web, App, Error, HttpResponse};
 
 use actix_helpers::route_builder::RouteBuilder;
 
+use crate::http_server::endpoints::inference_job::cancel::cancel_inference_job_handler::cancel_inference_job_handler;
 use crate::http_server::endpoints::inference_job::delete::dismiss_finished_session_jobs_handler::dismiss_finished_session_jobs_handler;
 use crate::http_server::endpoints::inference_job::delete::terminate_inference_job_handler::terminate_inference_job_handler;
 use crate::http_server::endpoints::inference_job::get::batch_get_inference_job_status_handler::batch_get_inference_job_status_handler;
@@ -32,6 +33,11 @@ pub fn add_job_routes<T, B> (app: App<T>) -> App<T>
                   .route(web::delete().to(terminate_inference_job_handler))
                   .route(web::head().to(|| HttpResponse::Ok()))
             )
+            .service(
+              web::resource("/job/{token}/cancel")
+                  .route(web::post().to(cancel_inference_job_handler))
+                  .route(web::head().to(|| HttpResponse::Ok()))
+            )
             .service(
               web::resource("/batch")
                   .route(web::get().to(batch_get_inference_job_status_handler))
@@ -20,18 +20,21 @@ use crate::http_server::endpoints::moderation::ip_bans::delete_ip_ban::delete_ip
 use crate::http_server::endpoints::moderation::ip_bans::get_ip_ban::get_ip_ban_handler;
 use crate::http_server::endpoints::moderation::ip_bans::list_ip_bans::list_ip_bans_handler;
 use crate::http_server::endpoints::moderation::jobs::user::list_user_jobs_handler::list_user_jobs_handler;
+use crate::http_server::endpoints::moderation::provider_health::moderator_get_provider_health_handler::moderator_get_provider_health_handler;
 use crate::http_server::endpoints::moderation::wallet_ledger_entries::list_wallet_ledger_entries_by_wallet_handler::list_wallet_ledger_entries_by_wallet_handler;
 use crate::http_server::endpoints::moderation::wallet_ledger_entries::moderator_get_wallet_ledger_entry_handler::moderator_get_wallet_ledger_entry_handler;
 use crate::http_server::endpoints::moderation::wallets::list_user_wallets_handler::list_user_wallets_handler;
 use crate::http_server::endpoints::moderation::wallets::moderator_add_banked_balance_to_wallet_handler::moderator_add_banked_balance_to_wallet_handler;
 use crate::http_server::endpoints::moderation::wallets::moderator_create_wallet_for_user_handler::moderator_create_wallet_for_user_handler;
 use crate::http_server::endpoints::moderation::wallets::moderator_get_wallet_handler::moderator_get_wallet_handler;
+use crate::http_server::endpoints::moderation::webhook_deliveries::moderator_redrive_webhook_delivery_handler::moderator_redrive_webhook_delivery_handler;
 use crate::http_server::endpoints::moderation::jobs::get_tts_inference_queue_count::get_tts_inference_queue_count_handler;
 use crate::http_server::endpoints::moderation::jobs::get_w2l_inference_queue_count::get_w2l_inference_queue_count_handler;
 use crate::http_server::endpoints::moderation::jobs::kill_tts_inference_jobs::kill_tts_inference_jobs_handler;
 use crate::http_server::endpoints::moderation::user_bans::moderation_ban_user_handler::moderation_ban_user_handler;
 use crate::http_server::endpoints::moderation::user::moderator_list_subscribing_users_by_signup_date::moderator_list_subscribing_users_by_signup_date_handler;
 use crate::http_server::endpoints::moderation::user::moderator_list_users_by_signup_date::moderator_list_users_by_signup_date_handler;
+use crate::http_server::endpoints::moderation::user::moderator_reset_user_two_factor_handler::moderator_reset_user_two_factor_handler;
 use crate::http_server::endpoints::moderation::user::moderator_user_lookup_by_stripe_customer_id_handler::moderator_user_lookup_by_stripe_customer_id_handler;
 use crate::http_server::endpoints::moderation::user::moderator_user_lookup_handler::moderator_user_lookup_handler;
 use crate::http_server::endpoints::moderation::alerts::moderation_send_alert_handler::moderation_send_alert_handler;
@@ -41,6 +44,9 @@ use crate::http_server::endpoints::moderation::jobs::moderation_get_job_by_token
 use crate::http_server::endpoints::moderation::user_feature_flags::moderator_edit_user_feature_flags_handler::moderator_edit_user_feature_flags_handler;
 use crate::http_server::endpoints::moderation::user_feature_flags::moderator_list_all_available_user_feature_flags_handler::moderator_list_all_available_user_feature_flags_handler;
 use crate::http_server::endpoints::moderation::user_feature_flags::moderator_list_user_feature_flags_handler::moderator_list_user_feature_flags_handler;
+use crate::http_server::endpoints::moderation::user_rate_limit_overrides::moderator_delete_user_rate_limit_override_handler::moderator_delete_user_rate_limit_override_handler;
+use crate::http_server::endpoints::moderation::user_rate_limit_overrides::moderator_list_user_rate_limit_overrides_handler::moderator_list_user_rate_limit_overrides_handler;
+use crate::http_server::endpoints::moderation::user_rate_limit_overrides::moderator_set_user_rate_limit_override_handler::moderator_set_user_rate_limit_override_handler;
 use crate::http_server::endpoints::moderation::user_sessions::moderator_list_user_session_impersonation_requests_for_user_handler::moderator_list_user_session_impersonation_requests_for_user_handler;
 use crate::http_server::endpoints::moderation::user_referrals::moderator_list_global_user_referrals_handler::moderator_list_global_user_referrals_handler;
 use crate::http_server::endpoints::moderation::user_referrals::moderator_list_user_referrals_for_user_handler::moderator_list_user_referrals_for_user_handler;
@@ -97,6 +103,22 @@ pub fn add_moderator_routes<T, B> (app: App<T>) -> App<T>
                 .route(web::post().to(moderator_list_subscribing_users_by_signup_date_handler))
                 .route(web::head().to(|| HttpResponse::Ok()))
             )
+            .service(web::resource("/{user_token}/two_factor/reset")
+                .route(web::post().to(moderator_reset_user_two_factor_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+            )
+            .service(web::resource("/{user_token}/rate_limit_overrides")
+                .route(web::get().to(moderator_list_user_rate_limit_overrides_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+            )
+            .service(web::resource("/{user_token}/rate_limit_overrides/set")
+                .route(web::post().to(moderator_set_user_rate_limit_override_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+            )
+            .service(web::resource("/{user_token}/rate_limit_overrides/delete")
+                .route(web::post().to(moderator_delete_user_rate_limit_override_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+            )
         )
         .service(web::resource("/job/{token}")
             .route(web::get().to(moderation_get_job_by_token_handler))
@@ -128,6 +150,10 @@ pub fn add_moderator_routes<T, B> (app: App<T>) -> App<T>
                 .route(web::head().to(|| HttpResponse::Ok()))
             )
         )
+        .service(web::resource("/provider_health")
+            .route(web::get().to(moderator_get_provider_health_handler))
+            .route(web::head().to(|| HttpResponse::Ok()))
+        )
         .service(web::scope("/staff_audit_logs")
             .service(web::resource("/list")
                 .route(web::get().to(moderator_list_staff_audit_logs_handler))
@@ -168,6 +194,10 @@ pub fn add_moderator_routes<T, B> (app: App<T>) -> App<T>
                 .route(web::head().to(|| HttpResponse::Ok()))
             )
         )
+        .service(web::resource("/webhook_delivery/{webhook_delivery_token}/redrive")
+            .route(web::post().to(moderator_redrive_webhook_delivery_handler))
+            .route(web::head().to(|| HttpResponse::Ok()))
+        )
       )
       // NB: Old paths are not under /v1 -
       .service(web::scope("/moderation")
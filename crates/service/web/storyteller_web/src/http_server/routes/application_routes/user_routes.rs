@@ -16,11 +16,22 @@ use crate::http_server::endpoints::users::edit_username_handler::edit_username_h
 use crate::http_server::endpoints::users::get_profile_handler::get_profile_handler;
 use crate::http_server::endpoints::users::google_sso::google_sso_handler::google_sso_handler;
 use crate::http_server::endpoints::users::login_handler::login_handler;
+use crate::http_server::endpoints::users::login_two_factor_handler::login_two_factor_handler;
 use crate::http_server::endpoints::users::logout_handler::logout_handler;
 use crate::http_server::endpoints::users::password_reset_redeem_handler::password_reset_redeem_handler;
 use crate::http_server::endpoints::users::password_reset_request_handler::password_reset_request_handler;
+use crate::http_server::endpoints::users::personal_access_tokens::create_personal_access_token_handler::create_personal_access_token_handler;
+use crate::http_server::endpoints::users::personal_access_tokens::list_personal_access_tokens_handler::list_personal_access_tokens_handler;
+use crate::http_server::endpoints::users::personal_access_tokens::revoke_personal_access_token_handler::revoke_personal_access_token_handler;
+use crate::http_server::endpoints::users::redeem_email_verification_handler::redeem_email_verification_handler;
+use crate::http_server::endpoints::users::resend_email_verification_handler::resend_email_verification_handler;
 use crate::http_server::endpoints::users::session_info_handler::session_info_handler;
 use crate::http_server::endpoints::users::session_token_info_handler::session_token_info_handler;
+use crate::http_server::endpoints::users::two_factor::confirm_two_factor_enrollment_handler::confirm_two_factor_enrollment_handler;
+use crate::http_server::endpoints::users::two_factor::disable_two_factor_handler::disable_two_factor_handler;
+use crate::http_server::endpoints::users::two_factor::get_two_factor_status_handler::get_two_factor_status_handler;
+use crate::http_server::endpoints::users::two_factor::regenerate_two_factor_recovery_codes_handler::regenerate_two_factor_recovery_codes_handler;
+use crate::http_server::endpoints::users::two_factor::start_two_factor_enrollment_handler::start_two_factor_enrollment_handler;
 
 pub fn add_user_routes<T, B> (app: App<T>) -> App<T>
   where
@@ -61,6 +72,11 @@ pub fn add_user_routes<T, B> (app: App<T>) -> App<T>
               .route(web::post().to(login_handler))
               .route(web::head().to(|| HttpResponse::Ok()))
       )
+      .service(
+          web::resource("/v1/login/two_factor")
+              .route(web::post().to(login_two_factor_handler))
+              .route(web::head().to(|| HttpResponse::Ok()))
+      )
       .service(
         // TODO(bt,2022-11-16): non-/v1/ endpoints are deprecated and subject for future removal
         web::resource("/logout")
@@ -111,6 +127,56 @@ pub fn add_user_routes<T, B> (app: App<T>) -> App<T>
                 .route(web::post().to(edit_email_handler))
                 .route(web::head().to(|| HttpResponse::Ok()))
           )
+          .service(
+            web::resource("/email_verification/resend")
+                .route(web::post().to(resend_email_verification_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/email_verification/redeem")
+                .route(web::post().to(redeem_email_verification_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/two_factor/status")
+                .route(web::get().to(get_two_factor_status_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/two_factor/enroll/start")
+                .route(web::post().to(start_two_factor_enrollment_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/two_factor/enroll/confirm")
+                .route(web::post().to(confirm_two_factor_enrollment_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/two_factor/disable")
+                .route(web::post().to(disable_two_factor_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/two_factor/recovery_codes/regenerate")
+                .route(web::post().to(regenerate_two_factor_recovery_codes_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/personal_access_tokens/create")
+                .route(web::post().to(create_personal_access_token_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/personal_access_tokens/list")
+                .route(web::get().to(list_personal_access_tokens_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
+          .service(
+            web::resource("/personal_access_tokens/{token}/revoke")
+                .route(web::post().to(revoke_personal_access_token_handler))
+                .route(web::head().to(|| HttpResponse::Ok()))
+          )
           .service(
             web::resource("/edit_username")
                 .route(web::post().to(edit_username_handler))
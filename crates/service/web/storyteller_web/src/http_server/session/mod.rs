@@ -1,3 +1,5 @@
 pub mod lookup;
+pub mod personal_access_tokens;
 pub mod session_checker;
-pub mod session_checker_error;
\ No newline at end of file
+pub mod session_checker_error;
+pub mod two_factor;
@@ -0,0 +1,11 @@
+use mysql_queries::queries::users::user_sessions::get_user_session_by_token::SessionUserRecord;
+use tokens::tokens::personal_access_tokens::PersonalAccessTokenToken;
+
+/// The caller of an endpoint that accepts either a session or a personal access token.
+pub struct ApiCallerSession {
+  pub user_session: SessionUserRecord,
+
+  /// Set when the caller authenticated with a personal access token rather than a session.
+  /// Wallet spend should be metered against it.
+  pub maybe_personal_access_token: Option<PersonalAccessTokenToken>,
+}
@@ -0,0 +1,60 @@
+use actix_web::http::header;
+use actix_web::HttpRequest;
+
+/// The credential from an `Authorization: Bearer <credential>` header, if any.
+pub fn get_bearer_secret_from_request(request: &HttpRequest) -> Option<String> {
+  let value = request.headers()
+      .get(header::AUTHORIZATION)?
+      .to_str()
+      .ok()?
+      .trim();
+
+  let (scheme, credential) = value.split_once(' ')?;
+
+  if !scheme.eq_ignore_ascii_case("bearer") {
+    return None;
+  }
+
+  let credential = credential.trim();
+
+  if credential.is_empty() {
+    return None;
+  }
+
+  Some(credential.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+  use actix_web::http::header;
+  use actix_web::test::TestRequest;
+
+  use crate::http_server::session::personal_access_tokens::get_bearer_secret_from_request::get_bearer_secret_from_request;
+
+  fn secret_for_header(value: &str) -> Option<String> {
+    let request = TestRequest::default()
+        .insert_header((header::AUTHORIZATION, value))
+        .to_http_request();
+    get_bearer_secret_from_request(&request)
+  }
+
+  #[test]
+  fn test_no_header() {
+    let request = TestRequest::default().to_http_request();
+    assert_eq!(get_bearer_secret_from_request(&request), None);
+  }
+
+  #[test]
+  fn test_bearer() {
+    assert_eq!(secret_for_header("Bearer acpat_abc"), Some("acpat_abc".to_string()));
+    assert_eq!(secret_for_header("bearer  acpat_abc "), Some("acpat_abc".to_string()));
+  }
+
+  #[test]
+  fn test_other_schemes() {
+    assert_eq!(secret_for_header("Basic dXNlcjpwYXNz"), None);
+    assert_eq!(secret_for_header("Bearer"), None);
+    assert_eq!(secret_for_header("Bearer   "), None);
+    assert_eq!(secret_for_header("acpat_abc"), None);
+  }
+}
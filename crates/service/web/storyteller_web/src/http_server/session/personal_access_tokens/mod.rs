@@ -0,0 +1,3 @@
+pub mod api_caller_session;
+pub mod get_bearer_secret_from_request;
+pub mod personal_access_token_secret;
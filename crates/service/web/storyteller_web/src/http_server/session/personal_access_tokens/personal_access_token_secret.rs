@@ -0,0 +1,74 @@
+use rand::distr::{Alphanumeric, SampleString};
+use sha2::{Digest, Sha256};
+
+/// Makes leaked secrets easy to recognize (and to scan for in public code).
+const SECRET_PREFIX: &str = "acpat_";
+
+/// ~238 bits of randomness; long enough that a fast hash is safe to store.
+const SECRET_RANDOM_LENGTH: usize = 40;
+
+/// How much of the secret we keep in the clear so users can tell their tokens apart.
+const DISPLAY_PREFIX_LENGTH: usize = 12;
+
+/// A new secret. This is shown to the user once and never stored.
+pub fn generate_personal_access_token_secret() -> String {
+  let random = Alphanumeric.sample_string(&mut rand::rng(), SECRET_RANDOM_LENGTH);
+  format!("{}{}", SECRET_PREFIX, random)
+}
+
+/// Whether a bearer credential is shaped like one of our secrets (saves a database lookup).
+pub fn is_personal_access_token_secret(secret: &str) -> bool {
+  secret.len() == SECRET_PREFIX.len() + SECRET_RANDOM_LENGTH
+      && secret.starts_with(SECRET_PREFIX)
+      && secret[SECRET_PREFIX.len()..].chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Hex encoded SHA-256, which is what we store and look secrets up by.
+pub fn hash_personal_access_token_secret(secret: &str) -> String {
+  hex::encode(Sha256::digest(secret.as_bytes()))
+}
+
+/// The non-secret leading characters, eg. "acpat_Xy12Ab".
+pub fn personal_access_token_display_prefix(secret: &str) -> String {
+  secret.chars().take(DISPLAY_PREFIX_LENGTH).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http_server::session::personal_access_tokens::personal_access_token_secret::*;
+
+  #[test]
+  fn test_generated_secrets() {
+    let secret = generate_personal_access_token_secret();
+    assert!(secret.starts_with("acpat_"));
+    assert_eq!(secret.len(), 46);
+    assert!(is_personal_access_token_secret(&secret));
+    assert_ne!(secret, generate_personal_access_token_secret());
+  }
+
+  #[test]
+  fn test_is_personal_access_token_secret() {
+    assert!(!is_personal_access_token_secret(""));
+    assert!(!is_personal_access_token_secret("acpat_"));
+    assert!(!is_personal_access_token_secret("acpat_tooshort"));
+    assert!(!is_personal_access_token_secret("xxxxx_0123456789012345678901234567890123456789"));
+    assert!(!is_personal_access_token_secret("acpat_012345678901234567890123456789012345678!"));
+    assert!(is_personal_access_token_secret("acpat_0123456789012345678901234567890123456789"));
+  }
+
+  #[test]
+  fn test_hash() {
+    // NB: Don't change the hash; stored tokens depend on it.
+    assert_eq!(hash_personal_access_token_secret("abc"),
+      "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    assert_eq!(hash_personal_access_token_secret(""),
+      "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+  }
+
+  #[test]
+  fn test_display_prefix() {
+    assert_eq!(
+      personal_access_token_display_prefix("acpat_0123456789012345678901234567890123456789"),
+      "acpat_012345");
+  }
+}
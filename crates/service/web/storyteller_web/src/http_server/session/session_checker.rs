@@ -5,9 +5,15 @@
 
 use actix_artcraft::sessions::user_sessions::http_user_session_manager::HttpUserSessionManager;
 use actix_web::HttpRequest;
-use log::warn;
+use chrono::Utc;
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+use http_server_common::request::get_request_ip::get_request_ip;
+use log::{info, warn};
+use mysql_queries::queries::users::user_personal_access_tokens::get_user_personal_access_token_by_secret_hash::get_user_personal_access_token_by_secret_hash;
+use mysql_queries::queries::users::user_personal_access_tokens::record_user_personal_access_token_use::record_user_personal_access_token_use;
 use mysql_queries::queries::users::user_sessions::get_user_session_by_token::{get_user_session_by_token, get_user_session_by_token_pooled_connection, SessionUserRecord};
 use mysql_queries::queries::users::user_sessions::get_user_session_by_token_light::{get_user_session_by_token_light, SessionRecord};
+use mysql_queries::queries::users::user_sessions::get_user_session_by_user_token::get_user_session_by_user_token;
 use mysql_queries::queries::users::user_subscriptions::list_active_user_subscriptions::list_active_user_subscriptions;
 use redis_caching::redis_ttl_cache::{RedisTtlCache, RedisTtlCacheConnection};
 use redis_common::redis_cache_keys::RedisCacheKeys;
@@ -16,6 +22,9 @@ use sqlx::{Executor, MySql, MySqlPool};
 
 use crate::http_server::session::lookup::user_session_extended::{UserSessionExtended, UserSessionPreferences, UserSessionPremiumPlanInfo, UserSessionRoleAndPermissions, UserSessionSubscriptionPlan, UserSessionUserDetails};
 use crate::http_server::session::lookup::user_session_feature_flags::UserSessionFeatureFlags;
+use crate::http_server::session::personal_access_tokens::api_caller_session::ApiCallerSession;
+use crate::http_server::session::personal_access_tokens::get_bearer_secret_from_request::get_bearer_secret_from_request;
+use crate::http_server::session::personal_access_tokens::personal_access_token_secret::{hash_personal_access_token_secret, is_personal_access_token_secret};
 use crate::http_server::session::session_checker_error::SessionCheckerError;
 
 #[derive(Clone)]
@@ -180,6 +189,82 @@ impl SessionChecker {
   }
 
 
+  // ==================== ApiCallerSession ====================
+
+  /// For endpoints that scripts may call: an `Authorization: Bearer` personal access token
+  /// (which must carry `required_scope`) takes precedence over the session cookie/header.
+  ///
+  /// Other session methods ignore bearer tokens, so endpoints must opt in by calling this.
+  /// Invalid, expired and revoked tokens, and tokens without the scope, yield `None`.
+  pub async fn maybe_get_api_caller_session_from_connection(
+    &self,
+    request: &HttpRequest,
+    required_scope: PersonalAccessTokenScope,
+    mysql_connection: &mut PoolConnection<MySql>,
+  ) -> Result<Option<ApiCallerSession>, SessionCheckerError>
+  {
+    let secret = match get_bearer_secret_from_request(request) {
+      Some(secret) => secret,
+      None => {
+        let maybe_user_session = self.maybe_get_user_session_from_connection(request, mysql_connection).await?;
+        return Ok(maybe_user_session.map(|user_session| ApiCallerSession {
+          user_session,
+          maybe_personal_access_token: None,
+        }));
+      }
+    };
+
+    if !is_personal_access_token_secret(&secret) {
+      info!("Bearer credential is not a personal access token");
+      return Ok(None);
+    }
+
+    let secret_hash = hash_personal_access_token_secret(&secret);
+
+    let token_record = match get_user_personal_access_token_by_secret_hash(&secret_hash, &mut **mysql_connection).await? {
+      Some(record) => record,
+      None => {
+        info!("Unknown or revoked personal access token");
+        return Ok(None);
+      }
+    };
+
+    if let Some(expires_at) = token_record.maybe_expires_at {
+      if expires_at <= Utc::now() {
+        info!("Expired personal access token: {:?}", token_record.token);
+        return Ok(None);
+      }
+    }
+
+    let scopes = PersonalAccessTokenScope::set_from_str(&token_record.scopes);
+
+    if !scopes.contains(&required_scope) {
+      info!("Personal access token {:?} lacks scope {:?}", token_record.token, required_scope);
+      return Ok(None);
+    }
+
+    let user_session = match get_user_session_by_user_token(&mut **mysql_connection, &token_record.user_token).await? {
+      Some(user_session) => user_session,
+      None => {
+        info!("Personal access token {:?} belongs to a deleted user", token_record.token);
+        return Ok(None);
+      }
+    };
+
+    let ip_address = get_request_ip(request);
+
+    // NB: Usage stats are best effort; don't fail the request over them.
+    if let Err(err) = record_user_personal_access_token_use(&token_record.token, &ip_address, &mut **mysql_connection).await {
+      warn!("Error recording personal access token use: {:?}", err);
+    }
+
+    Ok(Some(ApiCallerSession {
+      user_session,
+      maybe_personal_access_token: Some(token_record.token),
+    }))
+  }
+
+
   // ==================== UserSessionExtended ====================
 
   //#[deprecated = "Use the PoolConnection<MySql> method instead of the MySqlPool one."]
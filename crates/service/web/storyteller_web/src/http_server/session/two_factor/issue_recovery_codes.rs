@@ -0,0 +1,42 @@
+use sqlx::MySql;
+
+use errors::AnyhowResult;
+use mysql_queries::queries::users::user_two_factor::delete_user_two_factor_recovery_codes::delete_user_two_factor_recovery_codes;
+use mysql_queries::queries::users::user_two_factor::insert_user_two_factor_recovery_code::insert_user_two_factor_recovery_code;
+use tokens::tokens::users::UserToken;
+use two_factor::recovery_codes::{generate_recovery_codes, hash_recovery_code};
+
+/// A freshly generated set of recovery codes and their hashes.
+pub struct NewRecoveryCodes {
+  /// Plaintext, shown to the user exactly once.
+  pub codes: Vec<String>,
+  hashes: Vec<String>,
+}
+
+/// Generate and hash a new set of recovery codes.
+///
+/// Call this before opening a transaction: bcrypt hashing ten codes takes a while.
+pub async fn generate_new_recovery_codes() -> AnyhowResult<NewRecoveryCodes> {
+  tokio::task::spawn_blocking(|| -> AnyhowResult<NewRecoveryCodes> {
+    let codes = generate_recovery_codes();
+    let hashes = codes.iter()
+        .map(|code| hash_recovery_code(code))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(NewRecoveryCodes { codes, hashes })
+  }).await?
+}
+
+/// Replace any existing recovery codes for the user with the new set.
+pub async fn replace_recovery_codes(
+  user_token: &UserToken,
+  new_codes: &NewRecoveryCodes,
+  transaction: &mut sqlx::Transaction<'_, MySql>,
+) -> AnyhowResult<()> {
+  delete_user_two_factor_recovery_codes(user_token, &mut **transaction).await?;
+
+  for code_hash in new_codes.hashes.iter() {
+    insert_user_two_factor_recovery_code(user_token, code_hash, &mut **transaction).await?;
+  }
+
+  Ok(())
+}
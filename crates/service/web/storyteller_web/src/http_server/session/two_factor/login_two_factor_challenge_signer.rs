@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use jwt_signer::jwt_signer::JwtSigner;
+use jwt_signer::jwt_signer_error::JwtSignerError;
+use tokens::tokens::users::UserToken;
+
+/**
+ * Login two factor challenge version history
+ *
+ *  Version 1: Claims include "purpose", "user_token", "expires_at", and "version".
+ */
+const CHALLENGE_VERSION : u32 = 1;
+
+/// Distinguishes these tokens from other JWTs signed with the same secret (eg. session cookies).
+const CHALLENGE_PURPOSE : &str = "login_two_factor";
+
+/// Signs and verifies the short-lived challenge handed out when a password (or Google)
+/// login succeeds for an account with two factor auth. The challenge proves the first
+/// factor was checked; it's exchanged for a session along with a second factor code.
+#[derive(Clone)]
+pub struct LoginTwoFactorChallengeSigner {
+  jwt_signer: JwtSigner,
+  challenge_ttl: Duration,
+}
+
+#[derive(Debug)]
+pub enum LoginTwoFactorChallengeError {
+  /// The challenge was forged, truncated, or was not issued for a two factor login.
+  InvalidChallenge,
+  /// The challenge was valid, but is past its expiry.
+  ExpiredChallenge,
+  /// Failure to sign the challenge.
+  SignerError(JwtSignerError),
+}
+
+impl Display for LoginTwoFactorChallengeError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidChallenge => write!(f, "invalid two factor login challenge"),
+      Self::ExpiredChallenge => write!(f, "expired two factor login challenge"),
+      Self::SignerError(e) => write!(f, "two factor login challenge signer error: {}", e),
+    }
+  }
+}
+
+impl Error for LoginTwoFactorChallengeError {}
+
+impl From<JwtSignerError> for LoginTwoFactorChallengeError {
+  fn from(value: JwtSignerError) -> Self {
+    Self::SignerError(value)
+  }
+}
+
+impl LoginTwoFactorChallengeSigner {
+  pub fn new(hmac_secret: &str, challenge_ttl: Duration) -> Result<Self, LoginTwoFactorChallengeError> {
+    Ok(Self {
+      jwt_signer: JwtSigner::new(hmac_secret)?,
+      challenge_ttl,
+    })
+  }
+
+  pub fn encode(&self, user_token: &UserToken) -> Result<String, LoginTwoFactorChallengeError> {
+    self.encode_at(user_token, Utc::now())
+  }
+
+  pub fn decode(&self, challenge: &str) -> Result<UserToken, LoginTwoFactorChallengeError> {
+    self.decode_at(challenge, Utc::now())
+  }
+
+  fn encode_at(
+    &self,
+    user_token: &UserToken,
+    now: DateTime<Utc>,
+  ) -> Result<String, LoginTwoFactorChallengeError> {
+    let expires_at = now.timestamp() + self.challenge_ttl.as_secs() as i64;
+    let expires_at = expires_at.to_string();
+    let challenge_version = CHALLENGE_VERSION.to_string();
+
+    let mut claims: BTreeMap<&str, &str> = BTreeMap::new();
+
+    claims.insert("purpose", CHALLENGE_PURPOSE);
+    claims.insert("user_token", user_token.as_str());
+    claims.insert("expires_at", &expires_at);
+    claims.insert("version", &challenge_version);
+
+    Ok(self.jwt_signer.claims_to_jwt(&claims)?)
+  }
+
+  fn decode_at(
+    &self,
+    challenge: &str,
+    now: DateTime<Utc>,
+  ) -> Result<UserToken, LoginTwoFactorChallengeError> {
+    let claims = self.jwt_signer.jwt_to_claims(challenge.trim())
+        .map_err(|_| LoginTwoFactorChallengeError::InvalidChallenge)?;
+
+    if claims.get("purpose").map(|p| p.as_str()) != Some(CHALLENGE_PURPOSE) {
+      return Err(LoginTwoFactorChallengeError::InvalidChallenge);
+    }
+
+    let user_token = claims.get("user_token")
+        .map(|t| UserToken::new_from_str(t))
+        .ok_or(LoginTwoFactorChallengeError::InvalidChallenge)?;
+
+    let expires_at = claims.get("expires_at")
+        .and_then(|e| e.parse::<i64>().ok())
+        .and_then(|e| Utc.timestamp_opt(e, 0).single())
+        .ok_or(LoginTwoFactorChallengeError::InvalidChallenge)?;
+
+    if expires_at <= now {
+      return Err(LoginTwoFactorChallengeError::ExpiredChallenge);
+    }
+
+    Ok(user_token)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use chrono::{TimeZone, Utc};
+
+  use tokens::tokens::users::UserToken;
+
+  use crate::email::email_verification_token_signer::EmailVerificationTokenSigner;
+  use crate::http_server::session::two_factor::login_two_factor_challenge_signer::{LoginTwoFactorChallengeError, LoginTwoFactorChallengeSigner};
+
+  const FIVE_MINUTES : Duration = Duration::from_secs(60 * 5);
+
+  fn signer() -> LoginTwoFactorChallengeSigner {
+    LoginTwoFactorChallengeSigner::new("fake_secret", FIVE_MINUTES).unwrap()
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let signer = signer();
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let challenge = signer.encode_at(&UserToken::new_from_str("ex_user_token"), now).unwrap();
+
+    let user_token = signer.decode_at(&challenge, now).unwrap();
+
+    assert_eq!(user_token.as_str(), "ex_user_token");
+  }
+
+  #[test]
+  fn test_expired() {
+    let signer = signer();
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let challenge = signer.encode_at(&UserToken::new_from_str("ex_user_token"), now).unwrap();
+
+    let later = Utc.timestamp_opt(1_700_000_000 + 300, 0).unwrap();
+
+    assert!(matches!(signer.decode_at(&challenge, later), Err(LoginTwoFactorChallengeError::ExpiredChallenge)));
+  }
+
+  #[test]
+  fn test_wrong_secret() {
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let challenge = signer().encode_at(&UserToken::new_from_str("ex_user_token"), now).unwrap();
+
+    let other_signer = LoginTwoFactorChallengeSigner::new("other_secret", FIVE_MINUTES).unwrap();
+
+    assert!(matches!(other_signer.decode_at(&challenge, now), Err(LoginTwoFactorChallengeError::InvalidChallenge)));
+  }
+
+  #[test]
+  fn test_rejects_email_verification_tokens() {
+    // NB: Both may be signed with the same HMAC secret.
+    let email_token = EmailVerificationTokenSigner::new("fake_secret", FIVE_MINUTES).unwrap()
+        .encode(&UserToken::new_from_str("ex_user_token"), "user@example.com")
+        .unwrap();
+
+    assert!(matches!(signer().decode(&email_token), Err(LoginTwoFactorChallengeError::InvalidChallenge)));
+  }
+
+  #[test]
+  fn test_garbage() {
+    assert!(matches!(signer().decode("not.a.jwt"), Err(LoginTwoFactorChallengeError::InvalidChallenge)));
+  }
+}
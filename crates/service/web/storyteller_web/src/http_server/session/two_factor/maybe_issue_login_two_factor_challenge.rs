@@ -0,0 +1,27 @@
+use sqlx::{Executor, MySql};
+
+use errors::AnyhowResult;
+use mysql_queries::queries::users::user_two_factor::get_user_two_factor_auth::get_user_two_factor_auth;
+use tokens::tokens::users::UserToken;
+
+use crate::http_server::session::two_factor::login_two_factor_challenge_signer::LoginTwoFactorChallengeSigner;
+
+/// Called once a login's first factor checks out, before a session is created.
+///
+/// Returns a signed challenge if the user has two factor auth enabled, in which case
+/// the caller must not create a session. The client trades the challenge and a second
+/// factor code for a session at `/v1/login/two_factor`.
+pub async fn maybe_issue_login_two_factor_challenge<'e, 'c, E>(
+  user_token: &'e UserToken,
+  challenge_signer: &LoginTwoFactorChallengeSigner,
+  mysql_executor: E,
+) -> AnyhowResult<Option<String>>
+  where E: 'e + Executor<'c, Database = MySql>
+{
+  let maybe_two_factor = get_user_two_factor_auth(user_token, mysql_executor).await?;
+
+  match maybe_two_factor {
+    Some(two_factor) if two_factor.is_enabled => Ok(Some(challenge_signer.encode(user_token)?)),
+    _ => Ok(None),
+  }
+}
@@ -0,0 +1,4 @@
+pub mod issue_recovery_codes;
+pub mod login_two_factor_challenge_signer;
+pub mod maybe_issue_login_two_factor_challenge;
+pub mod verify_two_factor_code;
@@ -0,0 +1,89 @@
+use chrono::Utc;
+use log::{info, warn};
+use sqlx::MySqlPool;
+
+use errors::AnyhowResult;
+use mysql_queries::queries::users::user_two_factor::list_unused_user_two_factor_recovery_codes::list_unused_user_two_factor_recovery_codes;
+use mysql_queries::queries::users::user_two_factor::mark_user_two_factor_recovery_code_used::mark_user_two_factor_recovery_code_used;
+use mysql_queries::queries::users::user_two_factor::mark_user_two_factor_time_step_used::mark_user_two_factor_time_step_used;
+use tokens::tokens::users::UserToken;
+use two_factor::recovery_codes::recovery_code_matches;
+use two_factor::totp_authenticator::TotpAuthenticator;
+use two_factor::two_factor_code::TwoFactorCode;
+
+/// Check a second factor for a user with two factor auth enabled: either a current
+/// authenticator app code or one of their unused recovery codes.
+///
+/// Successful codes are consumed: a TOTP time step can't be used twice, and recovery
+/// codes are single use. Returns false for wrong, malformed, or already-used codes.
+pub async fn verify_two_factor_code(
+  user_token: &UserToken,
+  totp_secret: &str,
+  code: &str,
+  mysql_pool: &MySqlPool,
+) -> AnyhowResult<bool> {
+  let code = match TwoFactorCode::parse(code) {
+    Some(code) => code,
+    None => return Ok(false),
+  };
+
+  match code {
+    TwoFactorCode::Totp(code) => verify_totp_code(user_token, totp_secret, &code, mysql_pool).await,
+    TwoFactorCode::RecoveryCode(code) => verify_recovery_code(user_token, &code, mysql_pool).await,
+  }
+}
+
+async fn verify_totp_code(
+  user_token: &UserToken,
+  totp_secret: &str,
+  code: &str,
+  mysql_pool: &MySqlPool,
+) -> AnyhowResult<bool> {
+  // NB: Issuer and account name only matter for the provisioning URI.
+  let authenticator = TotpAuthenticator::new(totp_secret, "", "")?;
+
+  let time_step = match authenticator.verify_code(code, Utc::now().timestamp() as u64) {
+    Some(time_step) => time_step,
+    None => return Ok(false),
+  };
+
+  // NB: Only succeeds if the step is newer than the last one used, so replays fail.
+  let marked = mark_user_two_factor_time_step_used(user_token, time_step, mysql_pool).await?;
+
+  if !marked {
+    warn!("Rejected replayed two factor code for user {:?}", user_token);
+  }
+
+  Ok(marked)
+}
+
+async fn verify_recovery_code(
+  user_token: &UserToken,
+  code: &str,
+  mysql_pool: &MySqlPool,
+) -> AnyhowResult<bool> {
+  let unused_codes = list_unused_user_two_factor_recovery_codes(user_token, mysql_pool).await?;
+
+  let code = code.to_string();
+
+  // NB: Recovery codes are bcrypt hashed, which is deliberately slow. Keep it off the executor.
+  let maybe_matched_id = tokio::task::spawn_blocking(move || {
+    unused_codes.iter()
+        .find(|unused| recovery_code_matches(&code, &unused.code_hash))
+        .map(|unused| unused.id)
+  }).await?;
+
+  let recovery_code_id = match maybe_matched_id {
+    Some(id) => id,
+    None => return Ok(false),
+  };
+
+  // NB: Guards against two concurrent requests redeeming the same code.
+  let marked = mark_user_two_factor_recovery_code_used(user_token, recovery_code_id, mysql_pool).await?;
+
+  if marked {
+    info!("Recovery code used for user {:?}", user_token);
+  }
+
+  Ok(marked)
+}
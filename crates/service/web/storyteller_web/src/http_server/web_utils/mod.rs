@@ -4,7 +4,6 @@ pub mod get_host_header;
 pub mod handle_multipart_error;
 pub mod open_zip_archive;
 pub mod read_multipart_field_bytes;
-pub mod redis_rate_limiter;
 pub mod response_error_helpers;
 pub mod response_success_helpers;
 pub mod scoped_temp_dir_creator;
@@ -0,0 +1,12 @@
+use mysql_queries::queries::users::user_sessions::get_user_session_by_token::SessionUserRecord;
+
+/// Whether the user has proven they own their email address, either by redeeming
+/// a verification link or by signing in with Google.
+///
+/// Features that are prone to throwaway-account abuse (eg. free credits) can gate on this.
+pub fn has_verified_email_address(user_session: &SessionUserRecord) -> bool {
+  if user_session.email_is_synthetic {
+    return false;
+  }
+  user_session.email_confirmed || user_session.email_confirmed_by_google
+}
@@ -1,3 +1,5 @@
+pub mod has_verified_email_address;
+pub mod require_api_caller_session_using_connection;
 pub mod require_moderator;
 pub mod require_user_session;
 pub mod require_user_session_extended_using_connection;
@@ -0,0 +1,43 @@
+use actix_web::HttpRequest;
+use log::warn;
+use sqlx::pool::PoolConnection;
+use sqlx::MySql;
+
+use enums::by_table::user_personal_access_tokens::personal_access_token_scope::PersonalAccessTokenScope;
+
+use crate::http_server::session::personal_access_tokens::api_caller_session::ApiCallerSession;
+use crate::http_server::session::session_checker::SessionChecker;
+use crate::http_server::web_utils::user_session::require_user_session::RequireUserSessionError;
+
+/// Like `require_user_session_using_connection`, but also accepts a personal access
+/// token carrying `required_scope`.
+pub async fn require_api_caller_session_using_connection(
+  http_request: &HttpRequest,
+  session_checker: &SessionChecker,
+  required_scope: PersonalAccessTokenScope,
+  mysql_connection: &mut PoolConnection<MySql>,
+) -> Result<ApiCallerSession, RequireUserSessionError> {
+
+  let maybe_api_caller = session_checker
+      .maybe_get_api_caller_session_from_connection(http_request, required_scope, mysql_connection)
+      .await
+      .map_err(|e| {
+        warn!("Session checker error: {:?}", e);
+        RequireUserSessionError::ServerError
+      })?;
+
+  let api_caller = match maybe_api_caller {
+    Some(api_caller) => api_caller,
+    None => {
+      warn!("not logged in");
+      return Err(RequireUserSessionError::NotAuthorized);
+    }
+  };
+
+  if api_caller.user_session.is_banned {
+    warn!("user is banned: {:?}", api_caller.user_session.user_token.as_str());
+    return Err(RequireUserSessionError::NotAuthorized);
+  }
+
+  Ok(api_caller)
+}
@@ -38,6 +38,7 @@ use actix_multipart::form::MultipartFormConfig;
 use actix_web::middleware::{DefaultHeaders, Logger};
 use actix_web::{middleware, web, App, HttpServer};
 use anyhow::anyhow;
+use artcraft_router::health::provider_health_registry::ProviderHealthRegistry;
 use billing_artcraft_component::utils::artcraft_stripe_config::ArtcraftStripeConfig;
 use billing_component::stripe::stripe_config::{FullUrlOrPath, StripeCheckoutConfigs, StripeConfig, StripeCustomerPortalConfigs, StripeSecrets};
 use billing_component::stripe::traits::internal_product_to_stripe_lookup::InternalProductToStripeLookup;
@@ -50,7 +51,13 @@ use elasticsearch::http::transport::Transport;
 use elasticsearch::Elasticsearch;
 use errors::AnyhowResult;
 use fal_client::creds::fal_api_key::FalApiKey;
+use fal_client::webhook_payload::signature::fal_webhook_verifier::FalWebhookVerifier;
+use fal_client::webhook_payload::signature::verify_fal_webhook_signature::DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS;
 use log::{info, warn};
+use mailer::queue::outbound_email_queue::OutboundEmailQueue;
+use mailer::queue::outbound_email_worker::OutboundEmailWorker;
+use mailer::queue::retry_policy::RetryPolicy;
+use mailer::transport::mailer_backend::MailerBackend;
 use memory_caching::arc_ttl_sieve::ArcTtlSieve;
 use memory_caching::single_item_ttl_cache::SingleItemTtlCache;
 use mysql_queries::mediators::badge_granter::BadgeGranter;
@@ -68,19 +75,26 @@ use crate::billing::internal_product_to_stripe_lookup_impl::InternalProductToStr
 use crate::billing::internal_session_cache_purge_impl::InternalSessionCachePurgeImpl;
 use crate::billing::stripe_internal_subscription_product_lookup_impl::StripeInternalSubscriptionProductLookupImpl;
 use crate::billing::stripe_internal_user_lookup_impl::StripeInternalUserLookupImpl;
-use crate::configs::app_startup::redis_rate_limiters::configure_redis_rate_limiters;
+use crate::configs::app_startup::mock_provider::configure_mock_provider;
+use crate::configs::app_startup::rate_limit_engine::configure_rate_limit_engine;
+use crate::configs::app_startup::username_set::UsernameSet;
 use crate::configs::connect_to_database::connect_to_database;
 use crate::configs::static_api_tokens::StaticApiTokenSet;
+use crate::email::email_verification_token_signer::EmailVerificationTokenSigner;
+use crate::http_server::endpoints::webhooks::fal::fal_webhook_alert_throttle::FalWebhookAlertThrottle;
+use crate::http_server::endpoints::webhooks::fal::redis_webhook_replay_guard::RedisWebhookReplayGuard;
 use crate::http_server::middleware::error_alerting_middleware::error_alerting_middleware::ErrorAlertingMiddleware;
 use crate::http_server::middleware::pushback_filter_middleware::PushbackFilter;
+use crate::http_server::middleware::rate_limit_headers_middleware::RateLimitHeaders;
 use crate::http_server::routes::add_routes::add_routes;
 use crate::http_server::session::session_checker::SessionChecker;
+use crate::http_server::session::two_factor::login_two_factor_challenge_signer::LoginTwoFactorChallengeSigner;
 use crate::http_server::web_utils::handle_multipart_error::handle_multipart_error;
 use crate::http_server::web_utils::scoped_temp_dir_creator::ScopedTempDirCreator;
 use crate::startup::build_pager::build_pager;
 use crate::state::certs::google_sign_in_cert::GoogleSignInCert;
 use crate::state::memory_cache::model_token_to_info_cache::ModelTokenToInfoCache;
-use crate::state::server_state::{BeebleData, DurableInMemoryCaches, EnvConfig, EphemeralInMemoryCaches, FalData, GmiCloudData, InMemoryCaches, OpenAiData, ResendData, Seedance2ProData, ServerInfo, ServerState, StaticFeatureFlags, StripeSettings, TrollBans, WorldLabsData};
+use crate::state::server_state::{BeebleData, DurableInMemoryCaches, EmailVerificationData, EnvConfig, EphemeralInMemoryCaches, FalData, GmiCloudData, InMemoryCaches, OpenAiData, Seedance2ProData, ServerInfo, ServerState, StaticFeatureFlags, StripeSettings, TrollBans, TwoFactorData, WorldLabsData};
 use crate::threads::db_health_checker_thread::db_health_check_status::HealthCheckStatus;
 use crate::threads::db_health_checker_thread::db_health_checker_thread::db_health_checker_thread;
 use crate::threads::poll_ip_banlist_thread::poll_ip_bans;
@@ -90,6 +104,7 @@ use crate::util::troll_user_bans::load_troll_user_ban_list_from_directory::load_
 use crate::util::troll_user_bans::troll_user_ban_list::TrollUserBanList;
 use actix_artcraft::sessions::anonymous_visitor_tracking::avt_cookie_manager::AvtCookieManager;
 use opaque_cursors::v2::opaque_cursor_encoder_v2::OpaqueCursorEncoderV2;
+use provider_http::process_default::shared_reqwest_client;
 
 pub mod billing;
 pub mod configs;
@@ -126,7 +141,7 @@ const LOG_FORMAT : &str =
 #[actix_web::main]
 async fn main() -> AnyhowResult<()> {
 
-  // NB: Both ring (via reqwest 0.12/hyper-rustls) and aws-lc-rs (via quinn/resend-rs)
+  // NB: Both ring (via reqwest 0.12/hyper-rustls) and aws-lc-rs (via quinn/resend-rs in mailer)
   // are compiled into this binary as rustls crypto providers. rustls 0.23 panics at
   // runtime if it can't auto-select a single provider, so we install ring explicitly.
   rustls::crypto::ring::default_provider()
@@ -179,6 +194,19 @@ async fn main() -> AnyhowResult<()> {
     easyenv::get_env_num("REDIS_CACHE_TTL_SECONDS", 60)?,
   );
 
+  let rate_limit_engine = configure_rate_limit_engine(&redis_pool)?;
+
+  // This is for the following users:
+  // https://www.notion.so/storytellerai/dd88609558a24196b4ddeeef6079da98
+  let ai_streamer_usernames =
+      UsernameSet::from_comma_separated(&easyenv::get_env_string_or_default("AI_STREAMER_USERNAMES", ""));
+
+  info!("AI Streamers that get the higher rate limit ({}): {:?}",
+    ai_streamer_usernames.len(),
+    ai_streamer_usernames.list_names());
+
+  let mock_provider = configure_mock_provider()?;
+
   info!("Connecting to elasticsearch...");
 
   let elasticsearch = get_elasticsearch_client()?;
@@ -226,7 +254,7 @@ async fn main() -> AnyhowResult<()> {
     &s3_compatible_endpoint_url,
     None,
     Some(bucket_timeout),
-  )?;
+  )?.with_env_backend_override()?;
 
   let public_bucket_client = BucketClient::create(
     &access_key,
@@ -236,7 +264,7 @@ async fn main() -> AnyhowResult<()> {
     &s3_compatible_endpoint_url,
     None,
     Some(bucket_timeout),
-  )?;
+  )?.with_env_backend_override()?;
 
   let auto_gc_bucket_client = BucketClient::create(
     &access_key,
@@ -246,7 +274,7 @@ async fn main() -> AnyhowResult<()> {
     &s3_compatible_endpoint_url,
     None,
     Some(bucket_timeout),
-  )?;
+  )?.with_env_backend_override()?;
 
   // In-Memory Cache
   let voice_list_cache_ttl = easyenv::get_env_duration_seconds_or_default("VOICE_LIST_CACHE_TTL_SECONDS", Duration::from_secs(60));
@@ -327,6 +355,17 @@ async fn main() -> AnyhowResult<()> {
     pager_worker.run().await;
   });
 
+  let mailer = MailerBackend::from_env_or_default("resend")?.build()?;
+
+  info!("Spawning outbound email worker thread (mailer: {}).", mailer.name());
+
+  let outbound_email_queue = OutboundEmailQueue::new(pool.clone());
+  let outbound_email_worker = OutboundEmailWorker::new(pool.clone(), mailer, RetryPolicy::default());
+
+  tokio_runtime.spawn(async move {
+    outbound_email_worker.run().await;
+  });
+
   info!("Spawning DB health checker thread.");
 
   let health_checker_pager = pager.clone();
@@ -415,12 +454,36 @@ async fn main() -> AnyhowResult<()> {
       .trim()
       .to_string();
   
+  let http_client = shared_reqwest_client();
+
   let fal_api_key = FalApiKey::new(easyenv::get_env_string_required("FAL_API_KEY")?);
   let fal_webhook_url = easyenv::get_env_string_required("FAL_WEBHOOK_URL")?;
 
+  let fal_webhook_verifier = FalWebhookVerifier::new(
+    http_client.clone(),
+    Arc::new(RedisWebhookReplayGuard::new(redis_pool.clone(), DEFAULT_MAX_TIMESTAMP_SKEW_SECONDS)),
+  );
+
+  let fal_webhook_alert_throttle = FalWebhookAlertThrottle::new(
+    redis_pool.clone(),
+    easyenv::get_env_num("FAL_WEBHOOK_ALERT_WINDOW_SECONDS", 600)?,
+  );
+
   let openai_api_key= easyenv::get_env_string_required("OPENAI_API_KEY")?;
 
-  let resend_api_key = easyenv::get_env_string_required("RESEND_API_KEY")?;
+  let email_verification_token_signer = {
+    let secret = easyenv::get_env_string_or_default("EMAIL_VERIFICATION_SECRET", &hmac_secret);
+    let ttl = easyenv::get_env_duration_seconds_or_default(
+      "EMAIL_VERIFICATION_TOKEN_TTL_SECONDS", Duration::from_secs(60 * 60 * 48));
+    EmailVerificationTokenSigner::new(&secret, ttl)?
+  };
+
+  let login_two_factor_challenge_signer = {
+    let secret = easyenv::get_env_string_or_default("TWO_FACTOR_CHALLENGE_SECRET", &hmac_secret);
+    let ttl = easyenv::get_env_duration_seconds_or_default(
+      "TWO_FACTOR_CHALLENGE_TTL_SECONDS", Duration::from_secs(60 * 5));
+    LoginTwoFactorChallengeSigner::new(&secret, ttl)?
+  };
 
   let gmicloud_api_key = easyenv::get_env_string_required("GMICLOUD_API_KEY")?;
 
@@ -464,7 +527,9 @@ async fn main() -> AnyhowResult<()> {
     elasticsearch,
     redis_pool,
     redis_ttl_cache,
-    redis_rate_limiters: configure_redis_rate_limiters()?,
+    http_client,
+    rate_limit_engine,
+    ai_streamer_usernames,
     firehose_publisher,
     badge_granter,
     avt_cookie_manager,
@@ -486,6 +551,9 @@ async fn main() -> AnyhowResult<()> {
     fal: FalData {
       api_key: fal_api_key,
       webhook_url: fal_webhook_url,
+      webhook_verifier: fal_webhook_verifier,
+      webhook_alert_throttle: fal_webhook_alert_throttle,
+      webhook_verification_enforced: easyenv::get_env_bool_or_default("FAL_WEBHOOK_VERIFICATION_ENFORCED", true),
     },
     gmicloud: GmiCloudData {
       api_key: gmicloud_client::creds::gmicloud_api_key::GmiCloudApiKey::new(gmicloud_api_key),
@@ -497,12 +565,20 @@ async fn main() -> AnyhowResult<()> {
     openai: OpenAiData {
       api_key: openai_api_key,
     },
-    resend: ResendData {
-      api_key: resend_api_key,
+    outbound_email_queue,
+    email_verification: EmailVerificationData {
+      token_signer: email_verification_token_signer,
+      require_verified_email_for_free_credits:
+          easyenv::get_env_bool_or_default("REQUIRE_VERIFIED_EMAIL_FOR_FREE_CREDITS", false),
+    },
+    two_factor: TwoFactorData {
+      challenge_signer: login_two_factor_challenge_signer,
     },
     worldlabs: WorldLabsData {
       api_key: worldlabs_api_key,
     },
+    provider_health: ProviderHealthRegistry::default(),
+    mock_provider,
     pager,
     caches: InMemoryCaches {
       durable: DurableInMemoryCaches {
@@ -712,6 +788,7 @@ pub async fn serve(server_state: ServerState) -> AnyhowResult<()>
       .wrap(DefaultHeaders::new()
         .header("X-Backend-Hostname", &hostname)
         .header("X-Build-Sha", server_state_arc.server_info.build_sha.clone()))
+      .wrap(RateLimitHeaders)
       .wrap(middleware::Condition::new(
         enable_error_alerting,
         ErrorAlertingMiddleware::new(pager_for_middleware.clone(), paging_flags_for_middleware.clone()),
@@ -1,13 +1,16 @@
 use crate::configs::app_startup::username_set::UsernameSet;
 use crate::configs::static_api_tokens::StaticApiTokenSet;
+use crate::email::email_verification_token_signer::EmailVerificationTokenSigner;
 use crate::http_server::deprecated_endpoints::categories::tts::list_fully_computed_assigned_tts_categories::list_fully_computed_assigned_tts_categories::ModelTokensByCategoryToken;
 use crate::http_server::deprecated_endpoints::leaderboard::get_leaderboard::LeaderboardInfo;
+use crate::http_server::endpoints::webhooks::fal::fal_webhook_alert_throttle::FalWebhookAlertThrottle;
 use crate::http_server::endpoints::media_files::list::list_featured_media_files_handler::ListFeaturedMediaFilesQueryParams;
 use crate::http_server::endpoints::stats::result_transformer::CacheableQueueStats;
 use crate::http_server::endpoints::tts::list_tts_models::TtsModelRecordForResponse;
 use crate::http_server::endpoints::voice_conversion::list_voice_conversion_models_handler::VoiceConversionModel;
+use crate::http_server::rate_limiting::rate_limit_engine::RateLimitEngine;
 use crate::http_server::session::session_checker::SessionChecker;
-use crate::http_server::web_utils::redis_rate_limiter::RedisRateLimiter;
+use crate::http_server::session::two_factor::login_two_factor_challenge_signer::LoginTwoFactorChallengeSigner;
 use crate::http_server::web_utils::scoped_temp_dir_creator::ScopedTempDirCreator;
 use crate::state::certs::google_sign_in_cert::GoogleSignInCert;
 use crate::state::flags::paging_flags::PagingFlags;
@@ -20,6 +23,8 @@ use actix_artcraft::sessions::anonymous_visitor_tracking::avt_cookie_manager::Av
 use actix_artcraft::sessions::user_sessions::http_user_session_manager::HttpUserSessionManager;
 use actix_helpers::middleware::banned_cidr_filter::banned_cidr_set::BannedCidrSet;
 use actix_helpers::middleware::banned_ip_filter::ip_ban_list::ip_ban_list::IpBanList;
+use artcraft_router::client::router_mock_client::RouterMockClient;
+use artcraft_router::health::provider_health_registry::ProviderHealthRegistry;
 use billing_artcraft_component::utils::artcraft_stripe_config::ArtcraftStripeConfigWithClient;
 use billing_component::stripe::stripe_config::StripeConfig;
 use chrono::{DateTime, Utc};
@@ -27,6 +32,8 @@ use cloud_storage::bucket_client::BucketClient;
 use elasticsearch::Elasticsearch;
 use beeble_client::creds::beeble_api_key::BeebleApiKey;
 use fal_client::creds::fal_api_key::FalApiKey;
+use fal_client::webhook_payload::signature::fal_webhook_verifier::FalWebhookVerifier;
+use mailer::queue::outbound_email_queue::OutboundEmailQueue;
 use memory_caching::arc_ttl_sieve::ArcTtlSieve;
 use memory_caching::single_item_ttl_cache::SingleItemTtlCache;
 use mysql_queries::mediators::badge_granter::BadgeGranter;
@@ -80,7 +87,15 @@ pub struct ServerState {
   pub redis_pool: r2d2::Pool<Client>,
   pub redis_ttl_cache: RedisTtlCache,
 
-  pub redis_rate_limiters: RedisRateLimiters,
+  /// Pooled HTTP client for outbound calls made while serving requests (eg. FAL's webhook keys).
+  /// Clones share the pool.
+  pub http_client: reqwest::Client,
+
+  /// Declarative, plan-aware rate limits for endpoint groups (generation, uploads, auth, etc.)
+  pub rate_limit_engine: RateLimitEngine,
+
+  /// Usernames of AI streamers, who get a larger TTS rate limit.
+  pub ai_streamer_usernames: UsernameSet,
 
   pub session_cookie_manager: HttpUserSessionManager,
   pub avt_cookie_manager: AvtCookieManager,
@@ -104,10 +119,22 @@ pub struct ServerState {
 
   pub openai: OpenAiData,
   
-  pub resend: ResendData,
+  /// Transactional email is enqueued here and delivered in the background.
+  pub outbound_email_queue: OutboundEmailQueue,
+
+  pub email_verification: EmailVerificationData,
+
+  pub two_factor: TwoFactorData,
 
   pub worldlabs: WorldLabsData,
 
+  /// Shared by every router client so provider circuit breakers persist across requests.
+  pub provider_health: ProviderHealthRegistry,
+
+  /// Offline stand-in for every omni_gen provider; only set when `ROUTER_MOCK_PROVIDER_ENABLED`.
+  /// Shared so mock jobs persist across requests.
+  pub mock_provider: Option<RouterMockClient>,
+
   pub pager: Pager,
 
   /// Where to store audio uploads for w2l
@@ -147,37 +174,6 @@ pub struct ServerInfo {
   pub build_sha: String,
 }
 
-/// Different rate limiters for different users
-#[derive(Clone)]
-pub struct RedisRateLimiters {
-  /// Logged out users have stricter limits
-  pub logged_out: RedisRateLimiter,
-
-  /// Logged in users have a little more leeway
-  pub logged_in: RedisRateLimiter,
-
-  /// API consumers have even higher priority
-  /// (Temporary for VidVoice.ai; a long term solution builds an in-memory cache
-  /// of these or finds a better rate limit library that allows on-demand rate
-  /// constructions)
-  pub api_high_priority: RedisRateLimiter,
-
-  /// API rate limit for AI streamers
-  pub api_ai_streamers: RedisRateLimiter,
-
-  /// Usernames of AI streamers
-  pub api_ai_streamer_username_set: UsernameSet,
-
-  /// A rate limiter for TTS and W2L uploads
-  pub model_upload: RedisRateLimiter,
-
-  /// For uploading files for voice conversion, face animator, etc.
-  pub file_upload_logged_out: RedisRateLimiter,
-
-  /// For uploading files for voice conversion, face animator, etc.
-  pub file_upload_logged_in: RedisRateLimiter,
-}
-
 /// In-memory caches of several types.
 #[derive(Clone)]
 pub struct InMemoryCaches {
@@ -324,6 +320,15 @@ pub struct TrollBans {
 pub struct FalData {
   pub api_key: FalApiKey,
   pub webhook_url: String,
+
+  /// Authenticates incoming webhook deliveries (signature, timestamp, replay).
+  pub webhook_verifier: FalWebhookVerifier,
+
+  /// Keeps webhooks that fail authentication from flooding the pager.
+  pub webhook_alert_throttle: FalWebhookAlertThrottle,
+
+  /// Reject webhooks that fail verification. Only disable in an emergency (eg. FAL changes their scheme).
+  pub webhook_verification_enforced: bool,
 }
 
 /// GmiCloud integration
@@ -355,10 +360,19 @@ pub struct OpenAiData {
   pub api_key: String,
 }
 
-/// Resend integration
+/// Email address verification
 #[derive(Clone)]
-pub struct ResendData {
-  pub api_key: String,
+pub struct EmailVerificationData {
+  pub token_signer: EmailVerificationTokenSigner,
+
+  /// If set, new accounts must verify their email address before they're granted free credits.
+  pub require_verified_email_for_free_credits: bool,
+}
+
+/// Two factor authentication
+#[derive(Clone)]
+pub struct TwoFactorData {
+  pub challenge_signer: LoginTwoFactorChallengeSigner,
 }
 
 /// World Labs integration
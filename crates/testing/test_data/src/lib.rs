@@ -15,4 +15,5 @@
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
+pub mod local_files;
 pub mod web;
@@ -0,0 +1,3 @@
+pub const JUNO_JPG_PATH : &str = "test_data/image/juno.jpg";
+
+pub const MOCHI_JPG_PATH : &str = "test_data/image/mochi.jpg";
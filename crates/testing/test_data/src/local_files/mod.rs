@@ -0,0 +1,6 @@
+//! Paths to files checked into the repository's `test_data` directory.
+//! Paths are relative to the repository root.
+
+pub mod image_files;
+pub mod splat_files;
+pub mod video_files;
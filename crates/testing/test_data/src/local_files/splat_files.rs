@@ -0,0 +1,2 @@
+/// A tiny ASCII PLY with eight gaussians; a stand-in wherever a splat file is needed.
+pub const PLACEHOLDER_CUBE_PLY_PATH : &str = "test_data/splat/ply/placeholder_cube.ply";
@@ -0,0 +1 @@
+pub const GOLDEN_SUN_GAROH_MP4_PATH : &str = "test_data/video/mp4/golden_sun_garoh.mp4";